@@ -0,0 +1,170 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+use std::io::Write;
+
+use clap::ArgMatches;
+use serde::Serialize;
+use unic::ucd::{Block, BlockIter, GeneralCategory, Name};
+
+use super::output::{parse_output_format, unsupported_output_format, write_as_json, OutputFormat};
+use super::{Error, Result};
+use crate::ucd::{block_named, code_point_to_string, string_to_code_point};
+
+pub const ARGUMENT_VALUE_NAME_BLOCK: &str = "BLOCK";
+
+#[derive(Serialize)]
+struct BlockSummary {
+    name: &'static str,
+    first_code_point: u32,
+    last_code_point: u32,
+}
+
+impl BlockSummary {
+    fn new(block: &Block) -> Self {
+        BlockSummary {
+            name: block.name,
+            first_code_point: block.range.low as u32,
+            last_code_point: block.range.high as u32,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct BlockCharacter {
+    character: char,
+    code_point: u32,
+    name: String,
+}
+
+#[derive(Serialize)]
+struct BlockContents {
+    #[serde(flatten)]
+    summary: BlockSummary,
+    characters: Vec<BlockCharacter>,
+}
+
+fn write_block_summary_as_text<W: Write>(block: &Block, writer: &mut W) -> Result<()> {
+    writeln!(
+        writer,
+        "{}..{}  {}",
+        code_point_to_string(block.range.low),
+        code_point_to_string(block.range.high),
+        block.name
+    )?;
+    Ok(())
+}
+
+fn generate_block_list_output<W: Write>(output_format: OutputFormat, writer: &mut W) -> Result<()> {
+    match output_format {
+        OutputFormat::Text => {
+            for block in BlockIter::new() {
+                write_block_summary_as_text(&block, writer)?;
+            }
+            Ok(())
+        }
+        OutputFormat::Json => {
+            let summaries: Vec<BlockSummary> = BlockIter::new()
+                .map(|block| BlockSummary::new(&block))
+                .collect();
+            write_as_json(&summaries, writer)
+        }
+        output_format => unsupported_output_format(output_format),
+    }
+}
+
+fn generate_block_contents_output<W: Write>(
+    block: &Block,
+    output_format: OutputFormat,
+    writer: &mut W,
+) -> Result<()> {
+    let characters = block
+        .range
+        .iter()
+        .filter(|chr| GeneralCategory::of(*chr) != GeneralCategory::Unassigned)
+        .map(|chr| BlockCharacter {
+            character: chr,
+            code_point: chr as u32,
+            name: match Name::of(chr) {
+                Some(name) => name.to_string(),
+                None => "".to_owned(),
+            },
+        });
+
+    match output_format {
+        OutputFormat::Text => {
+            write_block_summary_as_text(block, writer)?;
+            for character in characters {
+                writeln!(
+                    writer,
+                    "{}  {}  {}",
+                    code_point_to_string(character.character),
+                    // Control characters are not printed as is, to keep the terminal intact
+                    if character.character.is_control() {
+                        ' '
+                    } else {
+                        character.character
+                    },
+                    character.name
+                )?;
+            }
+            Ok(())
+        }
+        OutputFormat::Json => write_as_json(
+            &BlockContents {
+                summary: BlockSummary::new(block),
+                characters: characters.collect(),
+            },
+            writer,
+        ),
+        output_format => unsupported_output_format(output_format),
+    }
+}
+
+/// Finds a block by its name (e.g. "Latin Extended-A") or by a code point inside it (e.g. "U+0100").
+fn find_block(name_or_code_point: &str) -> Option<Block> {
+    match string_to_code_point(name_or_code_point) {
+        Some(code_point) => char::from_u32(code_point).and_then(Block::of),
+        None => block_named(name_or_code_point),
+    }
+}
+
+pub(super) fn generate_block_output<W: Write>(args: &ArgMatches, writer: &mut W) -> Result<()> {
+    let output_format = parse_output_format(args)?;
+    match args.value_of(ARGUMENT_VALUE_NAME_BLOCK) {
+        Some(name_or_code_point) => match find_block(name_or_code_point) {
+            Some(block) => generate_block_contents_output(&block, output_format, writer),
+            None => Err(Box::new(Error::UnrecognizedBlock(
+                name_or_code_point.to_owned(),
+            ))),
+        },
+        None => generate_block_list_output(output_format, writer),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_block() {
+        assert_eq!(find_block("Basic Latin").unwrap().name, "Basic Latin");
+        assert_eq!(
+            find_block("latin-extended-a").unwrap().name,
+            "Latin Extended-A"
+        );
+        assert_eq!(find_block("U+0100").unwrap().name, "Latin Extended-A");
+        assert!(find_block("Not A Block").is_none());
+    }
+}
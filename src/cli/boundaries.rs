@@ -0,0 +1,203 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Shows grapheme cluster, word and sentence boundaries and line break opportunities in text.
+//!
+
+use std::io::Write;
+
+use clap::ArgMatches;
+use serde::Serialize;
+
+use super::input::parse_input;
+use super::output::{parse_output_format, unsupported_output_format, write_as_json, OutputFormat};
+use super::tree::printable_character;
+use super::{Error, Result};
+use crate::ucd::{boundaries, BoundaryKind};
+
+pub const OPTION_NAME_BOUNDARY_KINDS: &str = "boundary_kinds";
+pub const OPTION_VALUE_BOUNDARY_KIND_GRAPHEME: &str = "grapheme";
+pub const OPTION_VALUE_BOUNDARY_KIND_WORD: &str = "word";
+pub const OPTION_VALUE_BOUNDARY_KIND_SENTENCE: &str = "sentence";
+pub const OPTION_VALUE_BOUNDARY_KIND_LINE: &str = "line";
+
+pub const BOUNDARY_MARKER: char = '|';
+pub const MANDATORY_BREAK_MARKER: char = '‖';
+
+const DEFAULT_BOUNDARY_KINDS: &[BoundaryKind] = &[
+    BoundaryKind::Word,
+    BoundaryKind::Sentence,
+    BoundaryKind::Line,
+];
+
+fn boundary_kind_name(kind: BoundaryKind) -> &'static str {
+    match kind {
+        BoundaryKind::Grapheme => OPTION_VALUE_BOUNDARY_KIND_GRAPHEME,
+        BoundaryKind::Word => OPTION_VALUE_BOUNDARY_KIND_WORD,
+        BoundaryKind::Sentence => OPTION_VALUE_BOUNDARY_KIND_SENTENCE,
+        BoundaryKind::Line => OPTION_VALUE_BOUNDARY_KIND_LINE,
+    }
+}
+
+fn parse_boundary_kinds(args: &ArgMatches) -> Result<Vec<BoundaryKind>> {
+    let kind_names = match args.value_of(OPTION_NAME_BOUNDARY_KINDS) {
+        Some(kind_names) => kind_names,
+        None => return Ok(DEFAULT_BOUNDARY_KINDS.to_vec()),
+    };
+
+    let mut kinds = Vec::new();
+    for kind_name in kind_names.split(',').map(str::trim) {
+        match kind_name {
+            OPTION_VALUE_BOUNDARY_KIND_GRAPHEME => kinds.push(BoundaryKind::Grapheme),
+            OPTION_VALUE_BOUNDARY_KIND_WORD => kinds.push(BoundaryKind::Word),
+            OPTION_VALUE_BOUNDARY_KIND_SENTENCE => kinds.push(BoundaryKind::Sentence),
+            OPTION_VALUE_BOUNDARY_KIND_LINE => kinds.push(BoundaryKind::Line),
+            _ => {
+                return Err(Box::new(Error::UnrecognizedBoundaryKind(
+                    kind_name.to_owned(),
+                )))
+            }
+        }
+    }
+    Ok(kinds)
+}
+
+#[derive(Serialize, Debug, Eq, PartialEq)]
+struct Segment<'a> {
+    offset: usize,
+    text: &'a str,
+    // Only line breaks can be mandatory, this is omitted for other kinds of boundaries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_mandatory_break: Option<bool>,
+}
+
+#[derive(Serialize, Debug)]
+struct Segmentation<'a> {
+    kind: &'static str,
+    segments: Vec<Segment<'a>>,
+}
+
+impl<'a> Segmentation<'a> {
+    fn new(string: &'a str, kind: BoundaryKind) -> Self {
+        let mut offset = 0;
+        let segments = boundaries(string, kind)
+            .iter()
+            .map(|boundary| {
+                let segment = Segment {
+                    offset,
+                    text: &string[offset..boundary.offset],
+                    is_mandatory_break: if kind == BoundaryKind::Line {
+                        Some(boundary.is_mandatory)
+                    } else {
+                        None
+                    },
+                };
+                offset = boundary.offset;
+                segment
+            })
+            .collect();
+
+        Segmentation {
+            kind: boundary_kind_name(kind),
+            segments,
+        }
+    }
+
+    /// Describes the segmentation as a single line, e.g. "|Hello,|·|world|".
+    fn marked_text(&self) -> String {
+        let mut marked_text = BOUNDARY_MARKER.to_string();
+        for segment in self.segments.iter() {
+            marked_text.extend(segment.text.chars().map(printable_character));
+            marked_text.push(match segment.is_mandatory_break {
+                Some(true) => MANDATORY_BREAK_MARKER,
+                _ => BOUNDARY_MARKER,
+            });
+        }
+        marked_text
+    }
+}
+
+pub(super) fn generate_boundaries_output<W: Write>(
+    args: &ArgMatches,
+    writer: &mut W,
+) -> Result<()> {
+    let output_format = parse_output_format(args)?;
+    let kinds = parse_boundary_kinds(args)?;
+    let string = parse_input(args)?.read_to_string()?;
+
+    let segmentations: Vec<Segmentation> = kinds
+        .iter()
+        .map(|kind| Segmentation::new(&string, *kind))
+        .collect();
+
+    match output_format {
+        OutputFormat::Text => {
+            let label_width = segmentations
+                .iter()
+                .map(|segmentation| segmentation.kind.len())
+                .max()
+                .unwrap_or_default();
+            for segmentation in segmentations.iter() {
+                writeln!(
+                    writer,
+                    "{:width$}  {}",
+                    segmentation.kind,
+                    segmentation.marked_text(),
+                    width = label_width
+                )?;
+            }
+            Ok(())
+        }
+        OutputFormat::Json => write_as_json(&segmentations, writer),
+        output_format => unsupported_output_format(output_format),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marked_text() {
+        let string = "Hi there.\nBye";
+        assert_eq!(
+            Segmentation::new(string, BoundaryKind::Word).marked_text(),
+            "|Hi|·|there|.|␊|Bye|"
+        );
+        assert_eq!(
+            Segmentation::new(string, BoundaryKind::Line).marked_text(),
+            "|Hi·|there.␊‖Bye‖"
+        );
+    }
+
+    #[test]
+    fn test_line_segments() {
+        assert_eq!(
+            Segmentation::new("a b", BoundaryKind::Line).segments,
+            vec![
+                Segment {
+                    offset: 0,
+                    text: "a ",
+                    is_mandatory_break: Some(false),
+                },
+                Segment {
+                    offset: 2,
+                    text: "b",
+                    is_mandatory_break: Some(true),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,197 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Maps the case of text, and reports graphemes whose length is changed by the mapping, e.g. "ß"
+//! is uppercased to "SS".
+//!
+
+use std::io::Write;
+
+use clap::ArgMatches;
+use serde::Serialize;
+use unic::segment::Graphemes;
+
+use super::input::parse_input;
+use super::output::{parse_output_format, unsupported_output_format, write_as_json, OutputFormat};
+use super::{Error, Result};
+use crate::ucd::{case_mappings, CaseLanguage, CaseOperation};
+
+pub const OPTION_NAME_CASE_MAPPING: &str = "case_mapping";
+pub const OPTION_VALUE_CASE_MAPPING_LOWER: &str = "lower";
+pub const OPTION_VALUE_CASE_MAPPING_UPPER: &str = "upper";
+pub const OPTION_VALUE_CASE_MAPPING_TITLE: &str = "title";
+pub const OPTION_VALUE_CASE_MAPPING_FOLD: &str = "fold";
+pub const OPTION_VALUE_CASE_MAPPING_SIMPLE_FOLD: &str = "simple-fold";
+
+pub const OPTION_NAME_CASE_LANGUAGE: &str = "case_language";
+
+fn parse_case_operation(args: &ArgMatches) -> Result<CaseOperation> {
+    match args.value_of(OPTION_NAME_CASE_MAPPING) {
+        Some(mapping) => match mapping {
+            OPTION_VALUE_CASE_MAPPING_LOWER => Ok(CaseOperation::Lowercase),
+            OPTION_VALUE_CASE_MAPPING_UPPER => Ok(CaseOperation::Uppercase),
+            OPTION_VALUE_CASE_MAPPING_TITLE => Ok(CaseOperation::Titlecase),
+            OPTION_VALUE_CASE_MAPPING_FOLD => Ok(CaseOperation::CaseFold),
+            OPTION_VALUE_CASE_MAPPING_SIMPLE_FOLD => Ok(CaseOperation::SimpleCaseFold),
+            _ => Err(Box::new(Error::UnrecognizedCaseMapping(mapping.to_owned()))),
+        },
+        None => Ok(CaseOperation::Lowercase),
+    }
+}
+
+/// Languages are given as language tags (e.g. "tr" or "tr-CY"), languages without their own case
+/// mappings use the default ones.
+fn parse_case_language(args: &ArgMatches) -> CaseLanguage {
+    let language_tag = args.value_of(OPTION_NAME_CASE_LANGUAGE).unwrap_or_default();
+    let language = language_tag
+        .split(&['-', '_'][..])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    match language.as_str() {
+        "tr" | "az" => CaseLanguage::Turkic,
+        "lt" => CaseLanguage::Lithuanian,
+        "el" => CaseLanguage::Greek,
+        _ => CaseLanguage::Default,
+    }
+}
+
+#[derive(Serialize, Debug, Eq, PartialEq)]
+struct Length {
+    code_points: usize,
+    utf8: usize,
+    utf16: usize,
+}
+
+impl Length {
+    fn of(string: &str) -> Self {
+        Length {
+            code_points: string.chars().count(),
+            utf8: string.len(),
+            utf16: string.encode_utf16().count(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct GraphemeMapping<'a> {
+    grapheme: &'a str,
+    mapped: String,
+    length: Length,
+    mapped_length: Length,
+}
+
+impl<'a> GraphemeMapping<'a> {
+    fn is_length_changed(&self) -> bool {
+        self.length != self.mapped_length
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct CaseConversion<'a> {
+    text: String,
+    graphemes: Vec<GraphemeMapping<'a>>,
+}
+
+impl<'a> CaseConversion<'a> {
+    fn new(string: &'a str, operation: CaseOperation, language: CaseLanguage) -> Self {
+        let mut mappings = case_mappings(string, operation, language).into_iter();
+        let graphemes: Vec<GraphemeMapping> = Graphemes::new(string)
+            .map(|grapheme| {
+                let mapped: String = mappings.by_ref().take(grapheme.chars().count()).collect();
+                GraphemeMapping {
+                    grapheme,
+                    length: Length::of(grapheme),
+                    mapped_length: Length::of(&mapped),
+                    mapped,
+                }
+            })
+            .collect();
+
+        CaseConversion {
+            text: graphemes
+                .iter()
+                .map(|grapheme| grapheme.mapped.as_str())
+                .collect(),
+            graphemes,
+        }
+    }
+}
+
+pub(super) fn generate_case_output<W: Write>(args: &ArgMatches, writer: &mut W) -> Result<()> {
+    let output_format = parse_output_format(args)?;
+    let operation = parse_case_operation(args)?;
+    let language = parse_case_language(args);
+    let string = parse_input(args)?.read_to_string()?;
+    let conversion = CaseConversion::new(&string, operation, language);
+
+    match output_format {
+        OutputFormat::Text => {
+            writeln!(writer, "{}", conversion.text)?;
+            for grapheme in conversion
+                .graphemes
+                .iter()
+                .filter(|grapheme| grapheme.is_length_changed())
+            {
+                writeln!(
+                    writer,
+                    "{} → {}  code points: {} → {}, utf-8: {} → {}, utf-16: {} → {}",
+                    grapheme.grapheme,
+                    grapheme.mapped,
+                    grapheme.length.code_points,
+                    grapheme.mapped_length.code_points,
+                    grapheme.length.utf8,
+                    grapheme.mapped_length.utf8,
+                    grapheme.length.utf16,
+                    grapheme.mapped_length.utf16,
+                )?;
+            }
+            Ok(())
+        }
+        OutputFormat::Json => write_as_json(&conversion, writer),
+        output_format => unsupported_output_format(output_format),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_conversion() {
+        let conversion =
+            CaseConversion::new("Maße", CaseOperation::Uppercase, CaseLanguage::Default);
+        assert_eq!(conversion.text, "MASSE");
+        let changed_graphemes: Vec<(&str, &str)> = conversion
+            .graphemes
+            .iter()
+            .filter(|grapheme| grapheme.is_length_changed())
+            .map(|grapheme| (grapheme.grapheme, grapheme.mapped.as_str()))
+            .collect();
+        assert_eq!(changed_graphemes, vec![("ß", "SS")]);
+    }
+
+    #[test]
+    fn test_mappings_are_grouped_by_grapheme() {
+        // U+0130 is lowercased to i followed by U+0307 COMBINING DOT ABOVE
+        let conversion =
+            CaseConversion::new("İe\u{301}", CaseOperation::Lowercase, CaseLanguage::Default);
+        assert_eq!(conversion.graphemes.len(), 2);
+        assert_eq!(conversion.graphemes[0].mapped, "i\u{307}");
+        assert_eq!(conversion.graphemes[0].mapped_length.code_points, 2);
+        assert_eq!(conversion.graphemes[1].mapped, "e\u{301}");
+        assert!(!conversion.graphemes[1].is_length_changed());
+    }
+}
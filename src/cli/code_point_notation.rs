@@ -0,0 +1,284 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Parses code points written in the notations commonly found in source code, markup and URLs:
+//!
+//! | Notation           | Example            |
+//! |--------------------|--------------------|
+//! | Unicode            | `U+1F600`          |
+//! | Rust, ES6          | `\u{1F600}`        |
+//! | UTF-16 escapes     | `\uD83D\uDE00`     |
+//! | Python, C          | `\U0001F600`       |
+//! | HTML entities      | `&#x1F600;`        |
+//! | HTML entities      | `&#128512;`        |
+//! | URL percent-escape | `%F0%9F%98%80`     |
+//! | Hexadecimal        | `0x1F600`          |
+//! | Decimal            | `128512`           |
+//!
+//! Tokens are separated by commas or whitespace, the escape notations (`\u`, `&#`, `%`) can also be
+//! written next to each other without separators, e.g. `\u{48}\u{49}`.
+//!
+//! Any code point up to U+10FFFF is accepted, including surrogate code points (e.g. `U+D800`) and
+//! unpaired UTF-16 escapes (e.g. `\uD800`), so that lone surrogates can be inspected too.
+//!
+
+use std::char;
+use std::str;
+
+use super::{Error, Result};
+use crate::ucd::MAX_CODE_POINT;
+
+type TokenResult = std::result::Result<(Vec<u32>, usize), &'static str>;
+
+fn is_separator(chr: char) -> bool {
+    chr == ',' || chr.is_whitespace()
+}
+
+pub(super) fn count_leading(string: &str, predicate: fn(&char) -> bool) -> usize {
+    string.chars().take_while(predicate).count() // Only counts ASCII characters, so len == count
+}
+
+pub(super) fn starts_with_ignoring_ascii_case(string: &str, prefix: &str) -> bool {
+    string.len() >= prefix.len()
+        && string.is_char_boundary(prefix.len())
+        && string[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+fn parse_number(digits: &str, radix: u32) -> std::result::Result<u32, &'static str> {
+    if digits.is_empty() {
+        return Err("missing digits");
+    }
+    match u32::from_str_radix(digits, radix) {
+        Ok(code_point) if code_point <= MAX_CODE_POINT => Ok(code_point),
+        _ => Err("code point is beyond U+10FFFF"),
+    }
+}
+
+// Parses a number without a closing delimiter, e.g. `U+0041`, which must not be directly followed
+// by other letters or digits.
+fn parse_undelimited_number(token: &str, prefix_length: usize, radix: u32) -> TokenResult {
+    let digits = &token[prefix_length..];
+    let digit_count = if radix == 16 {
+        count_leading(digits, char::is_ascii_hexdigit)
+    } else {
+        count_leading(digits, char::is_ascii_digit)
+    };
+    if digits[digit_count..].starts_with(|chr: char| chr.is_ascii_alphanumeric()) {
+        return Err("invalid digit");
+    }
+    let code_point = parse_number(&digits[..digit_count], radix)?;
+    Ok((vec![code_point], prefix_length + digit_count))
+}
+
+// Parses a number followed by a closing delimiter, e.g. `&#x41;` or `\u{41}`.
+fn parse_delimited_number(
+    token: &str,
+    prefix_length: usize,
+    radix: u32,
+    delimiter: char,
+) -> TokenResult {
+    let digits = &token[prefix_length..];
+    let digit_count = if radix == 16 {
+        count_leading(digits, char::is_ascii_hexdigit)
+    } else {
+        count_leading(digits, char::is_ascii_digit)
+    };
+    if !digits[digit_count..].starts_with(delimiter) {
+        return Err("missing closing delimiter");
+    }
+    let code_point = parse_number(&digits[..digit_count], radix)?;
+    Ok((
+        vec![code_point],
+        prefix_length + digit_count + delimiter.len_utf8(),
+    ))
+}
+
+// Parses `\UXXXXXXXX`, which always has exactly 8 hex digits.
+fn parse_fixed_width_escape(token: &str, digit_count: usize) -> TokenResult {
+    let prefix_length = 2;
+    let digits = &token[prefix_length..];
+    if count_leading(digits, char::is_ascii_hexdigit) < digit_count {
+        return Err("too few hex digits");
+    }
+    let code_point = parse_number(&digits[..digit_count], 16)?;
+    Ok((vec![code_point], prefix_length + digit_count))
+}
+
+// Parses consecutive `\uXXXX` escapes as UTF-16 code units, so that surrogate pairs are combined.
+// Unpaired surrogates are kept as surrogate code points. A following `\u{...}` escape is left to
+// the next token.
+fn parse_utf16_escapes(token: &str) -> TokenResult {
+    const ESCAPE_LENGTH: usize = 6;
+
+    let mut code_units = vec![];
+    let mut length = 0;
+    while token[length..].starts_with("\\u") && !token[length..].starts_with("\\u{") {
+        let digits = &token[length + 2..];
+        if count_leading(digits, char::is_ascii_hexdigit) < 4 {
+            return Err("too few hex digits");
+        }
+        code_units.push(u16::from_str_radix(&digits[..4], 16).map_err(|_| "invalid digit")?);
+        length += ESCAPE_LENGTH;
+    }
+
+    let code_points = char::decode_utf16(code_units.iter().copied())
+        .map(|decoded| match decoded {
+            Ok(chr) => chr as u32,
+            Err(error) => u32::from(error.unpaired_surrogate()),
+        })
+        .collect();
+    Ok((code_points, length))
+}
+
+// Parses consecutive `%XX` escapes as UTF-8 bytes.
+fn parse_percent_escapes(token: &str) -> TokenResult {
+    const ESCAPE_LENGTH: usize = 3;
+
+    let mut bytes = vec![];
+    let mut length = 0;
+    while token[length..].starts_with('%') {
+        let digits = &token[length + 1..];
+        if count_leading(digits, char::is_ascii_hexdigit) < 2 {
+            return Err("too few hex digits");
+        }
+        bytes.push(u8::from_str_radix(&digits[..2], 16).map_err(|_| "invalid digit")?);
+        length += ESCAPE_LENGTH;
+    }
+
+    match str::from_utf8(&bytes) {
+        Ok(string) => Ok((string.chars().map(|chr| chr as u32).collect(), length)),
+        Err(_) => Err("escaped bytes are not valid UTF-8"),
+    }
+}
+
+fn parse_token(token: &str) -> TokenResult {
+    if starts_with_ignoring_ascii_case(token, "U+") {
+        parse_undelimited_number(token, 2, 16)
+    } else if token.starts_with("\\u{") {
+        parse_delimited_number(token, 3, 16, '}')
+    } else if token.starts_with("\\U") {
+        parse_fixed_width_escape(token, 8)
+    } else if token.starts_with("\\u") {
+        parse_utf16_escapes(token)
+    } else if starts_with_ignoring_ascii_case(token, "&#x") {
+        parse_delimited_number(token, 3, 16, ';')
+    } else if token.starts_with("&#") {
+        parse_delimited_number(token, 2, 10, ';')
+    } else if token.starts_with('%') {
+        parse_percent_escapes(token)
+    } else if starts_with_ignoring_ascii_case(token, "0x") {
+        parse_undelimited_number(token, 2, 16)
+    } else if token.starts_with(|chr: char| chr.is_ascii_digit()) {
+        parse_undelimited_number(token, 0, 10)
+    } else {
+        Err("unrecognized notation")
+    }
+}
+
+/// Parses `input` as a list of code points, each of which can be written in any of the supported
+/// notations.
+pub fn parse_code_points(input: &str) -> Result<Vec<u32>> {
+    let mut code_points = vec![];
+
+    let mut offset = 0;
+    while let Some(chr) = input[offset..].chars().next() {
+        if is_separator(chr) {
+            offset += chr.len_utf8();
+            continue;
+        }
+
+        let remaining_input = &input[offset..];
+        match parse_token(remaining_input) {
+            Ok((token_code_points, token_length)) => {
+                code_points.extend(token_code_points);
+                offset += token_length;
+            }
+            Err(reason) => {
+                let token_length = remaining_input
+                    .find(is_separator)
+                    .unwrap_or(remaining_input.len());
+                return Err(Box::new(Error::InvalidCodePoint {
+                    token: remaining_input[..token_length].to_owned(),
+                    offset,
+                    reason: reason.to_owned(),
+                }));
+            }
+        }
+    }
+
+    Ok(code_points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_code_points() {
+        let grinning_face = vec![0x1F600];
+        assert_eq!(parse_code_points("U+1F600").unwrap(), grinning_face);
+        assert_eq!(parse_code_points("u+1f600").unwrap(), grinning_face);
+        assert_eq!(parse_code_points("\\u{1F600}").unwrap(), grinning_face);
+        assert_eq!(parse_code_points("\\uD83D\\uDE00").unwrap(), grinning_face);
+        assert_eq!(parse_code_points("\\U0001F600").unwrap(), grinning_face);
+        assert_eq!(parse_code_points("&#x1F600;").unwrap(), grinning_face);
+        assert_eq!(parse_code_points("&#128512;").unwrap(), grinning_face);
+        assert_eq!(parse_code_points("%F0%9F%98%80").unwrap(), grinning_face);
+        assert_eq!(parse_code_points("0x1F600").unwrap(), grinning_face);
+        assert_eq!(parse_code_points("128512").unwrap(), grinning_face);
+    }
+
+    #[test]
+    fn test_parse_mixed_code_points() {
+        assert_eq!(
+            parse_code_points("U+48, 0x49 \\u{4A}\\u004B&#76;%4D\t78,,").unwrap(),
+            vec![0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E]
+        );
+        assert!(parse_code_points("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_mixed_unicode_escapes() {
+        assert_eq!(
+            parse_code_points("\\u0041\\u{42}\\u0043").unwrap(),
+            vec![0x41, 0x42, 0x43]
+        );
+        assert_eq!(
+            parse_code_points("\\u{1F600}\\uD83D\\uDE00\\u{41}").unwrap(),
+            vec![0x1F600, 0x1F600, 0x41]
+        );
+    }
+
+    #[test]
+    fn test_parse_surrogate_code_points() {
+        assert_eq!(parse_code_points("U+D800").unwrap(), vec![0xD800]);
+        assert_eq!(parse_code_points("\\uD83D").unwrap(), vec![0xD83D]);
+        assert_eq!(
+            parse_code_points("\\uDE00\\uD83D\\uDE00").unwrap(),
+            vec![0xDE00, 0x1F600]
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_code_points() {
+        assert!(parse_code_points("U+").is_err());
+        assert!(parse_code_points("U+110000").is_err());
+        assert!(parse_code_points("%ED%A0%80").is_err());
+        assert!(parse_code_points("&#x41").is_err());
+        assert!(parse_code_points("%FF").is_err());
+        assert!(parse_code_points("0x4G").is_err());
+        assert!(parse_code_points("U+41,snowman").is_err());
+    }
+}
@@ -0,0 +1,214 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Delimiter-separated output formats, i.e. CSV and TSV, with one row per code point.
+//!
+
+use std::char::REPLACEMENT_CHARACTER;
+use std::io::{self, Write};
+
+use super::stream::Segment;
+use super::Result;
+use crate::ucd::{CharacterField, CharacterFields};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Delimiter {
+    Comma,
+    Tab,
+}
+
+impl Delimiter {
+    /// Makes `field` safe to be put in a row.
+    ///
+    /// CSV fields are quoted (as described in RFC 4180) if they contain delimiters, quotes or
+    /// line breaks. TSV has no quoting, so tabs, line breaks and backslashes are escaped with
+    /// backslashes instead. Neither format can hold other control characters, they are written as
+    /// `\uXXXX` escapes in both. Fields starting with `=`, `+`, `-` or `@` are prefixed with `'`,
+    /// so that spreadsheets don't evaluate them as formulas.
+    fn escape(self, field: &str) -> String {
+        let mut escaped_field = String::with_capacity(field.len());
+        if field.starts_with(|chr| FORMULA_PREFIXES.contains(&chr)) {
+            escaped_field.push('\'');
+        }
+        for chr in field.chars() {
+            match (self, chr) {
+                (Delimiter::Comma, '"') => escaped_field.push_str("\"\""),
+                (Delimiter::Comma, '\t') | (Delimiter::Comma, '\n') | (Delimiter::Comma, '\r') => {
+                    escaped_field.push(chr)
+                }
+                (Delimiter::Tab, '\\') => escaped_field.push_str("\\\\"),
+                (Delimiter::Tab, '\t') => escaped_field.push_str("\\t"),
+                (Delimiter::Tab, '\n') => escaped_field.push_str("\\n"),
+                (Delimiter::Tab, '\r') => escaped_field.push_str("\\r"),
+                (_, chr) if chr.is_control() => {
+                    escaped_field.push_str(&format!("\\u{:04X}", chr as u32))
+                }
+                (_, chr) => escaped_field.push(chr),
+            }
+        }
+
+        match self {
+            Delimiter::Comma
+                if field
+                    .chars()
+                    .any(|chr| chr == ',' || chr == '"' || chr == '\n' || chr == '\r') =>
+            {
+                format!("\"{}\"", escaped_field)
+            }
+            _ => escaped_field,
+        }
+    }
+}
+
+/// Leading characters which make spreadsheet applications treat a cell as a formula.
+const FORMULA_PREFIXES: [char; 4] = ['=', '+', '-', '@'];
+
+fn write_row<W: Write>(fields: &[String], delimiter: Delimiter, writer: &mut W) -> Result<()> {
+    let row: Vec<String> = fields.iter().map(|field| delimiter.escape(field)).collect();
+    // RFC 4180 requires CRLF line breaks, TSV files conventionally use LF
+    match delimiter {
+        Delimiter::Comma => write!(writer, "{}\r\n", row.join(","))?,
+        Delimiter::Tab => writeln!(writer, "{}", row.join("\t"))?,
+    }
+    Ok(())
+}
+
+pub fn write_segments_as_delimited<S, W>(
+    segments: S,
+    fields: CharacterFields,
+    delimiter: Delimiter,
+    writer: &mut W,
+) -> Result<()>
+where
+    S: Iterator<Item = io::Result<Segment>>,
+    W: Write,
+{
+    let header: Vec<String> = ["grapheme_index", "grapheme"]
+        .iter()
+        .map(|name| (*name).to_owned())
+        .chain(fields.iter().map(|field| field.name().to_owned()))
+        .collect();
+    write_row(&header, delimiter, writer)?;
+
+    for (grapheme_index, segment) in segments.enumerate() {
+        match segment? {
+            Segment::Grapheme(grapheme) => {
+                for character in grapheme.characters() {
+                    let row: Vec<String> =
+                        vec![grapheme_index.to_string(), grapheme.grapheme().to_owned()]
+                            .into_iter()
+                            .chain(fields.iter().map(|field| character.field_value(field)))
+                            .collect();
+                    write_row(&row, delimiter, writer)?;
+                }
+            }
+            // Surrogate code points don't have names, their labels are shown instead
+            Segment::CodePoint(code_point) => {
+                let row: Vec<String> = vec![
+                    grapheme_index.to_string(),
+                    REPLACEMENT_CHARACTER.to_string(),
+                ]
+                .into_iter()
+                .chain(fields.iter().map(|field| match field {
+                    CharacterField::Name => code_point.label(),
+                    _ => code_point.field_value(field),
+                }))
+                .collect();
+                write_row(&row, delimiter, writer)?;
+            }
+            // Invalid sequences don't have code points, they are described in the name column
+            Segment::InvalidSequence(diagnostic) => {
+                let row: Vec<String> = vec![
+                    grapheme_index.to_string(),
+                    REPLACEMENT_CHARACTER.to_string(),
+                ]
+                .into_iter()
+                .chain(fields.iter().map(|field| match field {
+                    CharacterField::Name => diagnostic.description(),
+                    _ => String::new(),
+                }))
+                .collect();
+                write_row(&row, delimiter, writer)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_csv_field() {
+        assert_eq!(
+            Delimiter::Comma.escape("LATIN SMALL LETTER A"),
+            "LATIN SMALL LETTER A"
+        );
+        assert_eq!(Delimiter::Comma.escape("a, b"), "\"a, b\"");
+        assert_eq!(Delimiter::Comma.escape("\"quoted\""), "\"\"\"quoted\"\"\"");
+        assert_eq!(Delimiter::Comma.escape("\n"), "\"\n\"");
+        assert_eq!(Delimiter::Comma.escape("a\tb"), "a\tb");
+    }
+
+    #[test]
+    fn test_escape_csv_control_characters() {
+        assert_eq!(Delimiter::Comma.escape("\u{7}"), "\\u0007");
+        assert_eq!(Delimiter::Comma.escape("a\u{0}b\u{85}"), "a\\u0000b\\u0085");
+        assert_eq!(Delimiter::Comma.escape("\u{1B},\r\n"), "\"\\u001B,\r\n\"");
+    }
+
+    #[test]
+    fn test_escape_tsv_field() {
+        assert_eq!(Delimiter::Tab.escape("a, \"b\""), "a, \"b\"");
+        assert_eq!(Delimiter::Tab.escape("\t\r\n\\"), "\\t\\r\\n\\\\");
+    }
+
+    #[test]
+    fn test_escape_tsv_control_characters() {
+        assert_eq!(Delimiter::Tab.escape("\u{7}"), "\\u0007");
+        assert_eq!(
+            Delimiter::Tab.escape("\u{1B}[0m\u{7F}"),
+            "\\u001B[0m\\u007F"
+        );
+        assert_eq!(Delimiter::Tab.escape("\\u0007"), "\\\\u0007");
+    }
+
+    #[test]
+    fn test_escape_formulas() {
+        for delimiter in &[Delimiter::Comma, Delimiter::Tab] {
+            assert_eq!(delimiter.escape("=1+1"), "'=1+1");
+            assert_eq!(delimiter.escape("+"), "'+");
+            assert_eq!(delimiter.escape("-1/2"), "'-1/2");
+            assert_eq!(delimiter.escape("@SUM(A1)"), "'@SUM(A1)");
+            assert_eq!(delimiter.escape("1-2"), "1-2");
+            assert_eq!(delimiter.escape(""), "");
+        }
+        assert_eq!(Delimiter::Comma.escape("=\"a\",1"), "\"'=\"\"a\"\",1\"");
+    }
+
+    #[test]
+    fn test_write_row() {
+        let fields = vec!["0".to_owned(), "e\u{301}".to_owned(), ",".to_owned()];
+
+        let mut csv = Vec::new();
+        write_row(&fields, Delimiter::Comma, &mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "0,e\u{301},\",\"\r\n");
+
+        let mut tsv = Vec::new();
+        write_row(&fields, Delimiter::Tab, &mut tsv).unwrap();
+        assert_eq!(String::from_utf8(tsv).unwrap(), "0\te\u{301}\t,\n");
+    }
+}
@@ -0,0 +1,124 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Interprets strings of decimal digits of any script as integers, e.g. "١٢٣" (Arabic-Indic
+//! digits) as 123.
+//!
+
+use std::io::Write;
+
+use clap::ArgMatches;
+use serde::Serialize;
+
+use super::input::parse_input;
+use super::output::{parse_output_format, unsupported_output_format, write_as_json, OutputFormat};
+use super::{Error, Result};
+use crate::ucd::{NumericType, NumericValue};
+
+#[derive(Serialize)]
+struct DigitString<'a> {
+    digits: &'a str,
+    value: u64,
+}
+
+/// Digits of each script are encoded in order from zero to nine, so the value of a digit is its
+/// offset from the zero digit of the same script.
+fn decimal_digit_value(chr: char) -> Option<u32> {
+    if NumericType::of(chr) != NumericType::Decimal {
+        return None;
+    }
+    NumericValue::of(chr).map(|numeric_value| numeric_value.numerator as u32)
+}
+
+fn digits_value(digits: &str) -> Result<u64> {
+    let mut value: u64 = 0;
+    let mut zero_digit = None;
+    for (offset, chr) in digits.chars().enumerate() {
+        let digit = match decimal_digit_value(chr) {
+            Some(digit) => digit,
+            None => return Err(Box::new(Error::InvalidDigit { chr, offset })),
+        };
+
+        // Mixing digits of different scripts is a common spoofing technique, e.g. "1" and "١"
+        let digit_zero_digit = chr as u32 - digit;
+        match zero_digit {
+            Some(zero_digit) if zero_digit != digit_zero_digit => {
+                return Err(Box::new(Error::MixedDigits { chr, offset }))
+            }
+            _ => zero_digit = Some(digit_zero_digit),
+        }
+
+        value = match value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(u64::from(digit)))
+        {
+            Some(value) => value,
+            None => return Err(Box::new(Error::DigitsOverflow(digits.to_owned()))),
+        };
+    }
+
+    if zero_digit.is_none() {
+        return Err(Box::new(Error::MissingInput));
+    }
+    Ok(value)
+}
+
+pub(super) fn generate_digits_output<W: Write>(args: &ArgMatches, writer: &mut W) -> Result<()> {
+    let output_format = parse_output_format(args)?;
+    let input = parse_input(args)?.read_to_string()?;
+    let digits = input.trim();
+    let value = digits_value(digits)?;
+
+    match output_format {
+        OutputFormat::Text => {
+            writeln!(writer, "{}", value)?;
+            Ok(())
+        }
+        OutputFormat::Json => write_as_json(&DigitString { digits, value }, writer),
+        output_format => unsupported_output_format(output_format),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digits_value() {
+        assert_eq!(digits_value("0123").unwrap(), 123);
+        // Arabic-Indic, Devanagari and Mathematical Bold digits
+        assert_eq!(digits_value("\u{0661}\u{0662}\u{0663}").unwrap(), 123);
+        assert_eq!(digits_value("\u{0969}\u{0966}").unwrap(), 30);
+        assert_eq!(digits_value("\u{1D7D7}\u{1D7CE}").unwrap(), 90);
+        assert_eq!(digits_value("18446744073709551615").unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_invalid_digits() {
+        assert_eq!(
+            digits_value("12a").unwrap_err().to_string(),
+            "'a' (U+0061) at offset 2 is not a decimal digit"
+        );
+        // Superscripts and Han numerals are numeric, but not decimal digits
+        assert!(digits_value("\u{00B2}").is_err());
+        assert!(digits_value("\u{4E09}").is_err());
+        assert_eq!(
+            digits_value("1\u{0662}").unwrap_err().to_string(),
+            "'\u{0662}' (U+0662) at offset 1 is a digit of a different script than the preceding digits"
+        );
+        assert!(digits_value("18446744073709551616").is_err());
+        assert!(digits_value("").is_err());
+    }
+}
@@ -0,0 +1,304 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Escapes text into string literals of programming languages (and HTML and URLs).
+//!
+
+use std::io::Write;
+
+use clap::ArgMatches;
+
+use super::input::parse_input;
+use super::{Error, Result};
+use crate::ucd::{is_invisible, CharacterField, CharacterFields, CharacterProperties};
+
+pub const OPTION_NAME_ESCAPE_LANGUAGE: &str = "escape_language";
+pub const OPTION_VALUE_ESCAPE_LANGUAGE_RUST: &str = "rust";
+pub const OPTION_VALUE_ESCAPE_LANGUAGE_JSON: &str = "json";
+pub const OPTION_VALUE_ESCAPE_LANGUAGE_JAVASCRIPT: &str = "javascript";
+pub const OPTION_VALUE_ESCAPE_LANGUAGE_PYTHON: &str = "python";
+pub const OPTION_VALUE_ESCAPE_LANGUAGE_C: &str = "c";
+pub const OPTION_VALUE_ESCAPE_LANGUAGE_JAVA: &str = "java";
+pub const OPTION_VALUE_ESCAPE_LANGUAGE_GO: &str = "go";
+pub const OPTION_VALUE_ESCAPE_LANGUAGE_HTML: &str = "html";
+pub const OPTION_VALUE_ESCAPE_LANGUAGE_URL: &str = "url";
+
+pub const OPTION_NAME_ESCAPE_SCOPE: &str = "escape_scope";
+pub const OPTION_VALUE_ESCAPE_SCOPE_ALL: &str = "all";
+pub const OPTION_VALUE_ESCAPE_SCOPE_NON_ASCII: &str = "non-ascii";
+pub const OPTION_VALUE_ESCAPE_SCOPE_INVISIBLE: &str = "invisible";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Language {
+    Rust,
+    // JSON and JavaScript share the same escape sequences
+    Json,
+    Python,
+    // C and C++
+    C,
+    Java,
+    Go,
+    Html,
+    Url,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum EscapeScope {
+    All,
+    NonAscii,
+    Invisible,
+}
+
+fn parse_language(args: &ArgMatches) -> Result<Language> {
+    match args.value_of(OPTION_NAME_ESCAPE_LANGUAGE) {
+        Some(language) => match language {
+            OPTION_VALUE_ESCAPE_LANGUAGE_RUST => Ok(Language::Rust),
+            OPTION_VALUE_ESCAPE_LANGUAGE_JSON | OPTION_VALUE_ESCAPE_LANGUAGE_JAVASCRIPT => {
+                Ok(Language::Json)
+            }
+            OPTION_VALUE_ESCAPE_LANGUAGE_PYTHON => Ok(Language::Python),
+            OPTION_VALUE_ESCAPE_LANGUAGE_C => Ok(Language::C),
+            OPTION_VALUE_ESCAPE_LANGUAGE_JAVA => Ok(Language::Java),
+            OPTION_VALUE_ESCAPE_LANGUAGE_GO => Ok(Language::Go),
+            OPTION_VALUE_ESCAPE_LANGUAGE_HTML => Ok(Language::Html),
+            OPTION_VALUE_ESCAPE_LANGUAGE_URL => Ok(Language::Url),
+            _ => Err(Box::new(Error::UnrecognizedEscapeLanguage(
+                language.to_owned(),
+            ))),
+        },
+        None => Ok(Language::Rust),
+    }
+}
+
+fn parse_escape_scope(args: &ArgMatches) -> Result<EscapeScope> {
+    match args.value_of(OPTION_NAME_ESCAPE_SCOPE) {
+        Some(escape_scope) => match escape_scope {
+            OPTION_VALUE_ESCAPE_SCOPE_ALL => Ok(EscapeScope::All),
+            OPTION_VALUE_ESCAPE_SCOPE_NON_ASCII => Ok(EscapeScope::NonAscii),
+            OPTION_VALUE_ESCAPE_SCOPE_INVISIBLE => Ok(EscapeScope::Invisible),
+            _ => Err(Box::new(Error::UnrecognizedEscapeScope(
+                escape_scope.to_owned(),
+            ))),
+        },
+        None => Ok(EscapeScope::NonAscii),
+    }
+}
+
+impl Language {
+    /// Whether `chr` can't appear as is in a literal of this language, regardless of the scope.
+    fn requires_escape(self, chr: char) -> bool {
+        if chr.is_control() {
+            return true;
+        }
+        match self {
+            Language::Rust
+            | Language::Json
+            | Language::Python
+            | Language::C
+            | Language::Java
+            | Language::Go => chr == '\\' || chr == '"',
+            Language::Html => chr == '&' || chr == '<' || chr == '>' || chr == '"',
+            Language::Url => {
+                !(chr.is_ascii_alphanumeric() || "-._~:/?#[]@!$&'()*+,;=".contains(chr))
+            }
+        }
+    }
+
+    fn escape(self, character: &CharacterProperties) -> String {
+        let code_point = character.code_point;
+        match self {
+            Language::Rust => format!("\\u{{{:x}}}", code_point),
+            Language::Json => character
+                .utf16
+                .iter()
+                .map(|code_unit| format!("\\u{:04x}", code_unit))
+                .collect(),
+            Language::Python => match code_point {
+                0..=0xFF => format!("\\x{:02x}", code_point),
+                0x100..=0xFFFF => format!("\\u{:04x}", code_point),
+                _ => format!("\\U{:08x}", code_point),
+            },
+            Language::C => character
+                .utf8
+                .iter()
+                .map(|byte| format!("\\x{:02x}", byte))
+                .collect(),
+            // Unicode escapes are translated before Java source is parsed, e.g. "\u0022" ends the
+            // literal and "\u000A" breaks the line, so these characters use escape sequences instead
+            Language::Java => match code_point {
+                0x22 => "\\\"".to_owned(),
+                0x5C => "\\\\".to_owned(),
+                0x0A => "\\n".to_owned(),
+                0x0D => "\\r".to_owned(),
+                0x09 => "\\t".to_owned(),
+                0x08 => "\\b".to_owned(),
+                0x0C => "\\f".to_owned(),
+                // Octal escapes have up to three digits, all three are written so that the escape
+                // can't absorb a following digit
+                0..=0xFF if character.character.is_control() => format!("\\{:03o}", code_point),
+                _ => character
+                    .utf16
+                    .iter()
+                    .map(|code_unit| format!("\\u{:04X}", code_unit))
+                    .collect(),
+            },
+            Language::Go => match code_point {
+                0..=0xFFFF => format!("\\u{:04x}", code_point),
+                _ => format!("\\U{:08x}", code_point),
+            },
+            Language::Html => format!("&#x{:X};", code_point),
+            Language::Url => character
+                .utf8
+                .iter()
+                .map(|byte| format!("%{:02X}", byte))
+                .collect(),
+        }
+    }
+}
+
+impl EscapeScope {
+    fn includes(self, chr: char) -> bool {
+        match self {
+            EscapeScope::All => true,
+            EscapeScope::NonAscii => !chr.is_ascii(),
+            // Spaces are invisible, but they are common enough to be left alone
+            EscapeScope::Invisible => chr != ' ' && is_invisible(chr),
+        }
+    }
+}
+
+fn escape(string: &str, language: Language, escape_scope: EscapeScope) -> String {
+    let fields = CharacterFields::from_fields(&[
+        CharacterField::CodePoint,
+        CharacterField::Utf8,
+        CharacterField::Utf16,
+    ]);
+
+    let mut escaped_string = String::with_capacity(string.len());
+    let mut follows_hex_escape = false;
+    for chr in string.chars() {
+        if escape_scope.includes(chr) || language.requires_escape(chr) {
+            escaped_string
+                .push_str(&language.escape(&CharacterProperties::with_fields(chr, fields)));
+            follows_hex_escape = language == Language::C;
+            continue;
+        }
+
+        // Hex escapes in C don't have a length limit, e.g. "\xe9a" is a single escape, the literal
+        // has to be split to end the escape
+        if follows_hex_escape && chr.is_ascii_hexdigit() {
+            escaped_string.push_str("\"\"");
+        }
+        escaped_string.push(chr);
+        follows_hex_escape = false;
+    }
+    escaped_string
+}
+
+pub(super) fn generate_escape_output<W: Write>(args: &ArgMatches, writer: &mut W) -> Result<()> {
+    let language = parse_language(args)?;
+    let escape_scope = parse_escape_scope(args)?;
+    let string = parse_input(args)?.read_to_string()?;
+    writeln!(writer, "{}", escape(&string, language, escape_scope))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_STR: &str = "caf\u{e9} \u{1F600}\u{200B}\"";
+
+    #[test]
+    fn test_escape_non_ascii() {
+        let escape_non_ascii = |language| escape(TEST_STR, language, EscapeScope::NonAscii);
+        assert_eq!(
+            escape_non_ascii(Language::Rust),
+            "caf\\u{e9} \\u{1f600}\\u{200b}\\u{22}"
+        );
+        assert_eq!(
+            escape_non_ascii(Language::Json),
+            "caf\\u00e9 \\ud83d\\ude00\\u200b\\u0022"
+        );
+        assert_eq!(
+            escape_non_ascii(Language::Python),
+            "caf\\xe9 \\U0001f600\\u200b\\x22"
+        );
+        assert_eq!(
+            escape_non_ascii(Language::C),
+            "caf\\xc3\\xa9 \\xf0\\x9f\\x98\\x80\\xe2\\x80\\x8b\\x22"
+        );
+        assert_eq!(
+            escape_non_ascii(Language::Java),
+            "caf\\u00E9 \\uD83D\\uDE00\\u200B\\\""
+        );
+        assert_eq!(
+            escape_non_ascii(Language::Go),
+            "caf\\u00e9 \\U0001f600\\u200b\\u0022"
+        );
+        assert_eq!(
+            escape_non_ascii(Language::Html),
+            "caf&#xE9; &#x1F600;&#x200B;&#x22;"
+        );
+        assert_eq!(
+            escape_non_ascii(Language::Url),
+            "caf%C3%A9%20%F0%9F%98%80%E2%80%8B%22"
+        );
+    }
+
+    #[test]
+    fn test_escape_scopes() {
+        assert_eq!(
+            escape("a\u{e9}", Language::Rust, EscapeScope::All),
+            "\\u{61}\\u{e9}"
+        );
+        assert_eq!(
+            escape(TEST_STR, Language::Rust, EscapeScope::Invisible),
+            "caf\u{e9} \u{1F600}\\u{200b}\\u{22}"
+        );
+    }
+
+    #[test]
+    fn test_escape_java_special_characters() {
+        assert_eq!(
+            escape(
+                "\"\\\n\r\t\u{8}\u{c}",
+                Language::Java,
+                EscapeScope::NonAscii
+            ),
+            "\\\"\\\\\\n\\r\\t\\b\\f"
+        );
+        assert_eq!(
+            escape(
+                "\u{0}1\u{1b}\u{7f}\u{85}",
+                Language::Java,
+                EscapeScope::NonAscii
+            ),
+            "\\0001\\033\\177\\205"
+        );
+        assert_eq!(
+            escape("a\"", Language::Java, EscapeScope::All),
+            "\\u0061\\\""
+        );
+    }
+
+    #[test]
+    fn test_escape_c_hex_digits() {
+        assert_eq!(
+            escape("\u{e9}a\u{e9}g", Language::C, EscapeScope::NonAscii),
+            "\\xc3\\xa9\"\"a\\xc3\\xa9g"
+        );
+    }
+}
@@ -0,0 +1,83 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+use std::io::Write;
+
+use serde::Serialize;
+use unic::ucd::{Block, BlockIter};
+
+use super::Result;
+use crate::ucd::{Plane, PLANE_COUNT};
+
+#[derive(Serialize)]
+struct Event {
+    name: &'static str,
+    cat: &'static str,
+    ph: char,
+    ts: u32,
+    pid: u64,
+    tid: u64,
+}
+
+fn describe_unicode_as_events() -> Result<String> {
+    let blocks: Vec<Block> = BlockIter::new().collect();
+
+    let mut events = Vec::with_capacity(blocks.len() + PLANE_COUNT as usize);
+
+    for i in 0..PLANE_COUNT {
+        let plane = Plane::at(i as usize);
+        events.push(Event {
+            name: plane.name,
+            cat: "Plane",
+            ph: 'B',
+            ts: plane.range.start,
+            pid: 0,
+            tid: 0,
+        });
+        events.push(Event {
+            name: plane.name,
+            cat: "Plane",
+            ph: 'E',
+            ts: plane.range.end,
+            pid: 0,
+            tid: 0,
+        });
+    }
+
+    for block in blocks {
+        events.push(Event {
+            name: block.name,
+            cat: "Block",
+            ph: 'B',
+            ts: block.range.low as u32,
+            pid: 0,
+            tid: 0,
+        });
+        events.push(Event {
+            name: block.name,
+            cat: "Block",
+            ph: 'E',
+            ts: block.range.high as u32,
+            pid: 0,
+            tid: 0,
+        });
+    }
+
+    Ok(serde_json::to_string_pretty(&events)?)
+}
+
+pub(super) fn generate_flamegraph_output<W: Write>(writer: &mut W) -> Result<()> {
+    writeln!(writer, "{}", describe_unicode_as_events()?)?;
+    Ok(())
+}
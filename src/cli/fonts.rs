@@ -0,0 +1,105 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+use std::io::Write;
+
+use clap::ArgMatches;
+use serde::Serialize;
+use unic::ucd::Name;
+
+use super::input::parse_input;
+use super::output::{parse_output_format, unsupported_output_format, write_as_json, OutputFormat};
+use super::Result;
+use crate::preview::{fonts_for, FontDescriptor};
+use crate::settings::get_settings;
+use crate::ucd::{code_point_to_string, TREE_GRAPH_CORNER, TREE_GRAPH_EDGE};
+
+#[derive(Serialize)]
+struct CharacterFonts {
+    character: char,
+    code_point: u32,
+    name: String,
+    fonts: Vec<FontDescriptor>,
+}
+
+fn write_character_fonts_as_text<W: Write>(
+    character_fonts: &CharacterFonts,
+    writer: &mut W,
+) -> Result<()> {
+    writeln!(
+        writer,
+        "{}  {}  {}",
+        code_point_to_string(character_fonts.character),
+        character_fonts.character,
+        character_fonts.name
+    )?;
+    if character_fonts.fonts.is_empty() {
+        writeln!(writer, "{}No fonts found", TREE_GRAPH_CORNER)?;
+    }
+    for (index, font) in character_fonts.fonts.iter().enumerate() {
+        let tree_graph = if index + 1 == character_fonts.fonts.len() {
+            TREE_GRAPH_CORNER
+        } else {
+            TREE_GRAPH_EDGE
+        };
+        writeln!(
+            writer,
+            "{}{} ({})",
+            tree_graph,
+            font.full_name,
+            font.path.display()
+        )?;
+    }
+    Ok(())
+}
+
+pub(super) fn generate_fonts_output<W: Write>(args: &ArgMatches, writer: &mut W) -> Result<()> {
+    let output_format = parse_output_format(args)?;
+    let string = parse_input(args)?.read_to_string()?;
+    let settings = get_settings();
+
+    let mut characters: Vec<char> = Vec::new();
+    for chr in string.chars() {
+        if !characters.contains(&chr) {
+            characters.push(chr);
+        }
+    }
+
+    let mut all_character_fonts = Vec::with_capacity(characters.len());
+    for chr in characters {
+        all_character_fonts.push(CharacterFonts {
+            character: chr,
+            code_point: chr as u32,
+            name: match Name::of(chr) {
+                Some(name) => name.to_string(),
+                None => "".to_owned(),
+            },
+            fonts: fonts_for(chr, &settings)?,
+        });
+    }
+
+    match output_format {
+        OutputFormat::Text => {
+            for (index, character_fonts) in all_character_fonts.iter().enumerate() {
+                if index > 0 {
+                    writeln!(writer)?;
+                }
+                write_character_fonts_as_text(character_fonts, writer)?;
+            }
+            Ok(())
+        }
+        OutputFormat::Json => write_as_json(&all_character_fonts, writer),
+        output_format => unsupported_output_format(output_format),
+    }
+}
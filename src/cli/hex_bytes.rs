@@ -0,0 +1,109 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Parses hex dumps of raw bytes, e.g. `E2 82 AC`, `E282AC`, `0xE2,0x82,0xAC` or `\xE2\x82\xAC`.
+//!
+
+use super::code_point_notation::{count_leading, starts_with_ignoring_ascii_case};
+use super::{Error, Result};
+
+fn is_separator(chr: char) -> bool {
+    chr == ',' || chr == ':' || chr.is_whitespace()
+}
+
+fn parse_token(token: &str) -> std::result::Result<(Vec<u8>, usize), &'static str> {
+    let prefix_length = if starts_with_ignoring_ascii_case(token, "0x") || token.starts_with("\\x")
+    {
+        2
+    } else {
+        0
+    };
+    let digits = &token[prefix_length..];
+    let digit_count = count_leading(digits, char::is_ascii_hexdigit);
+    if digits[digit_count..].starts_with(|chr: char| !is_separator(chr) && chr != '\\') {
+        return Err("invalid hex digit");
+    }
+    if digit_count == 0 {
+        return Err("missing digits");
+    }
+    if digit_count % 2 == 1 {
+        return Err("odd number of hex digits");
+    }
+
+    let bytes = (0..digit_count)
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&digits[index..index + 2], 16))
+        .collect::<std::result::Result<Vec<u8>, _>>()
+        .map_err(|_| "invalid hex digit")?;
+    Ok((bytes, prefix_length + digit_count))
+}
+
+/// Parses `input` as a list of hex bytes, bytes can be grouped (`E282AC`) or separated by commas,
+/// colons or whitespace.
+pub fn parse_hex_bytes(input: &str) -> Result<Vec<u8>> {
+    let mut bytes = vec![];
+
+    let mut offset = 0;
+    while let Some(chr) = input[offset..].chars().next() {
+        if is_separator(chr) {
+            offset += chr.len_utf8();
+            continue;
+        }
+
+        let remaining_input = &input[offset..];
+        match parse_token(remaining_input) {
+            Ok((token_bytes, token_length)) => {
+                bytes.extend(token_bytes);
+                offset += token_length;
+            }
+            Err(reason) => {
+                let token_length = remaining_input
+                    .find(is_separator)
+                    .unwrap_or(remaining_input.len());
+                return Err(Box::new(Error::InvalidHexBytes {
+                    token: remaining_input[..token_length].to_owned(),
+                    offset,
+                    reason,
+                }));
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex_bytes() {
+        let euro_sign = vec![0xE2, 0x82, 0xAC];
+        assert_eq!(parse_hex_bytes("E2 82 AC").unwrap(), euro_sign);
+        assert_eq!(parse_hex_bytes("e282ac").unwrap(), euro_sign);
+        assert_eq!(parse_hex_bytes("0xE2,0x82, 0xAC").unwrap(), euro_sign);
+        assert_eq!(parse_hex_bytes("\\xE2\\x82\\xAC").unwrap(), euro_sign);
+        assert_eq!(parse_hex_bytes("E2:82:AC\n").unwrap(), euro_sign);
+        assert!(parse_hex_bytes("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid_hex_bytes() {
+        assert!(parse_hex_bytes("E28").is_err());
+        assert!(parse_hex_bytes("E2 8G").is_err());
+        assert!(parse_hex_bytes("0x").is_err());
+        assert!(parse_hex_bytes("\\xE2\\x8").is_err());
+    }
+}
@@ -12,74 +12,191 @@
 // You should have received a copy of the GNU General Public License along with
 // Cicero. If not, see <https://www.gnu.org/licenses/>.
 
-use std::char;
+use std::cmp::min;
+use std::fs::File;
+use std::io::{self, Cursor, Read};
+use std::path::PathBuf;
 
 use clap::ArgMatches;
 
-use super::{
-    Error, Result, FLAG_NAME_CODE_POINT_INPUT_MODE, FLAG_NAME_GENERATE_FLAMEGRAPH,
-    FLAG_NAME_TUI_MODE,
+use super::code_point_notation::parse_code_points;
+use super::hex_bytes::parse_hex_bytes;
+use super::{Error, Result, FLAG_NAME_CODE_POINT_INPUT_MODE};
+use crate::settings::CodePointRange;
+use crate::ucd::{
+    decode_lossy, decode_lossy_to_code_points, encode_wtf8, string_to_code_point, Encoding,
 };
-use crate::ucd::string_to_code_point;
 
 pub const OPTION_NAME_INPUT_TYPE: &str = "input_type";
 pub const OPTION_VALUE_INPUT_TYPE_STRING: &str = "string";
 pub const OPTION_VALUE_INPUT_TYPE_CODE_POINTS: &str = "code-points";
+pub const OPTION_VALUE_INPUT_TYPE_AUTO: &str = "auto";
+pub const OPTION_VALUE_INPUT_TYPE_BYTES: &str = "bytes";
+
+pub const OPTION_NAME_INPUT_ENCODING: &str = "input_encoding";
+pub const OPTION_VALUE_INPUT_ENCODING_UTF8: &str = "utf-8";
+pub const OPTION_VALUE_INPUT_ENCODING_WTF8: &str = "wtf-8";
+pub const OPTION_VALUE_INPUT_ENCODING_UTF16LE: &str = "utf-16le";
+pub const OPTION_VALUE_INPUT_ENCODING_UTF16BE: &str = "utf-16be";
+pub const OPTION_VALUE_INPUT_ENCODING_UTF32LE: &str = "utf-32le";
+pub const OPTION_VALUE_INPUT_ENCODING_UTF32BE: &str = "utf-32be";
+
+pub const OPTION_NAME_INPUT_FILE: &str = "input_file";
 
 pub const ARGUMENT_VALUE_NAME_INPUT: &str = "INPUT";
+pub const ARGUMENT_VALUE_STDIN: &str = "-";
+
+fn code_point_ranges_from_input_string(input_string: &str) -> Result<Vec<CodePointRange>> {
+    let mut code_point_ranges = vec![];
+
+    let mut offset = 0;
+    for token in input_string.split(',') {
+        let trimmed_token = token.trim();
+        if !trimmed_token.is_empty() {
+            let code_point_range = match string_to_code_point(trimmed_token) {
+                Some(code_point) => CodePointRange::Raw {
+                    first: code_point,
+                    last: code_point,
+                },
+                None => trimmed_token
+                    .parse()
+                    .map_err(|reason| Error::InvalidCodePoint {
+                        token: trimmed_token.to_owned(),
+                        offset: offset + token.find(trimmed_token).unwrap_or(0),
+                        reason,
+                    })?,
+            };
+            code_point_ranges.push(code_point_range);
+        }
+        offset += token.len() + 1;
+    }
+
+    Ok(code_point_ranges)
+}
+
+// Encodes code points as WTF-8 lazily, so that large code point ranges (e.g. a whole plane) can be
+// streamed without being collected into a string first. WTF-8 is used instead of UTF-8 so that
+// surrogate code points make it through to the stream.
+struct CodePointReader<I: Iterator<Item = u32>> {
+    code_points: I,
+    pending_bytes: Vec<u8>,
+}
+
+impl<I: Iterator<Item = u32>> CodePointReader<I> {
+    fn new(code_points: I) -> Self {
+        CodePointReader {
+            code_points,
+            pending_bytes: vec![],
+        }
+    }
+}
+
+impl<I: Iterator<Item = u32>> Read for CodePointReader<I> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let mut read_count = 0;
+        while read_count < buffer.len() {
+            if self.pending_bytes.is_empty() {
+                match self.code_points.next() {
+                    Some(code_point) => encode_wtf8(code_point, &mut self.pending_bytes),
+                    None => break,
+                }
+            }
 
-fn characters_from_input_string(input_string: &str) -> Vec<char> {
-    input_string
-        .split(',')
-        .filter_map(string_to_code_point)
-        .collect()
+            let count = min(self.pending_bytes.len(), buffer.len() - read_count);
+            buffer[read_count..read_count + count].copy_from_slice(&self.pending_bytes[..count]);
+            self.pending_bytes.drain(..count);
+            read_count += count;
+        }
+        Ok(read_count)
+    }
 }
 
 #[derive(Debug)]
 pub enum Input {
     String(String),
-    Characters(Vec<char>),
-    GenerateFlamegraph,
+    CodePoints(Vec<u32>),
+    CodePointRanges(Vec<CodePointRange>),
+    Bytes(Vec<u8>, Encoding),
+    Stdin,
+    File(PathBuf, Encoding),
 }
 
-impl ToString for Input {
-    fn to_string(&self) -> String {
+impl Input {
+    pub fn encoding(&self) -> Encoding {
         match self {
-            Input::String(string) => string.clone(),
-            Input::Characters(characters) => {
-                let mut string = String::new();
-                string.reserve(characters.len());
-                for chr in characters {
-                    string.push(*chr);
-                }
-                string
+            Input::CodePoints(_) | Input::CodePointRanges(_) => Encoding::Wtf8,
+            Input::Bytes(_, encoding) | Input::File(_, encoding) => *encoding,
+            _ => Encoding::Utf8,
+        }
+    }
+
+    pub fn into_reader(self) -> Result<Box<dyn Read>> {
+        match self {
+            Input::String(string) => Ok(Box::new(Cursor::new(string.into_bytes()))),
+            Input::CodePoints(code_points) => {
+                Ok(Box::new(CodePointReader::new(code_points.into_iter())))
             }
-            Input::GenerateFlamegraph => String::new(), // This variant is not representable as a string
+            Input::CodePointRanges(code_point_ranges) => Ok(Box::new(CodePointReader::new(
+                code_point_ranges
+                    .into_iter()
+                    .flat_map(|code_point_range| code_point_range.code_points()),
+            ))),
+            Input::Bytes(bytes, _) => Ok(Box::new(Cursor::new(bytes))),
+            Input::Stdin => Ok(Box::new(io::stdin())),
+            Input::File(path, _) => match File::open(&path) {
+                Ok(file) => Ok(Box::new(file)),
+                Err(error) => Err(Box::new(Error::FailedToOpenInputFile(path, error))),
+            },
         }
     }
+
+    pub fn read_to_string(self) -> Result<String> {
+        let encoding = self.encoding();
+        let mut bytes = vec![];
+        self.into_reader()?.read_to_end(&mut bytes)?;
+        Ok(decode_lossy(encoding, &bytes))
+    }
+
+    /// Reads the input as code points, which unlike `read_to_string` keeps surrogate code points.
+    pub fn read_to_code_points(self) -> Result<Vec<u32>> {
+        let encoding = self.encoding();
+        let mut bytes = vec![];
+        self.into_reader()?.read_to_end(&mut bytes)?;
+        Ok(decode_lossy_to_code_points(encoding, &bytes))
+    }
 }
 
 pub fn parse_input(args: &ArgMatches) -> Result<Input> {
-    if args.is_present(FLAG_NAME_GENERATE_FLAMEGRAPH) {
-        return Ok(Input::GenerateFlamegraph);
+    match parse_optional_input(args)? {
+        Some(input) => Ok(input),
+        None => Err(Box::new(Error::MissingInput)),
+    }
+}
+
+/// Same as `parse_input`, except `None` is returned if neither INPUT nor `--file` is specified.
+pub fn parse_optional_input(args: &ArgMatches) -> Result<Option<Input>> {
+    if let Some(path) = args.value_of_os(OPTION_NAME_INPUT_FILE) {
+        return Ok(Some(Input::File(
+            PathBuf::from(path),
+            parse_encoding(args)?,
+        )));
     }
 
     let input_string = match args.value_of(ARGUMENT_VALUE_NAME_INPUT) {
-        Some(v) => Ok(v),
-        None => {
-            if args.is_present(FLAG_NAME_TUI_MODE) {
-                Ok("") // Fallback to an empty string
-            } else {
-                Err(Box::new(Error::MissingInput))
-            }
-        }
-    }?;
+        Some(input_string) => input_string,
+        None => return Ok(None),
+    };
 
-    match args.value_of(OPTION_NAME_INPUT_TYPE) {
+    let input: Result<Input> = match args.value_of(OPTION_NAME_INPUT_TYPE) {
         Some(input_type) => match input_type {
-            OPTION_VALUE_INPUT_TYPE_STRING => Ok(Input::String(input_string.to_owned())),
-            OPTION_VALUE_INPUT_TYPE_CODE_POINTS => Ok(Input::Characters(
-                characters_from_input_string(input_string),
+            OPTION_VALUE_INPUT_TYPE_STRING => Ok(string_input(input_string)),
+            OPTION_VALUE_INPUT_TYPE_CODE_POINTS => Ok(Input::CodePointRanges(
+                code_point_ranges_from_input_string(input_string)?,
+            )),
+            OPTION_VALUE_INPUT_TYPE_AUTO => Ok(Input::CodePoints(parse_code_points(input_string)?)),
+            OPTION_VALUE_INPUT_TYPE_BYTES => Ok(Input::Bytes(
+                parse_hex_bytes(input_string)?,
+                parse_encoding(args)?,
             )),
             _ => Err(Box::new(Error::UnrecognizedInputType(
                 input_type.to_owned(),
@@ -87,14 +204,50 @@ pub fn parse_input(args: &ArgMatches) -> Result<Input> {
         },
         None => {
             if args.is_present(FLAG_NAME_CODE_POINT_INPUT_MODE) {
-                Ok(Input::Characters(characters_from_input_string(
+                Ok(Input::CodePointRanges(code_point_ranges_from_input_string(
                     input_string,
-                )))
+                )?))
             } else {
-                Ok(Input::String(input_string.to_owned()))
+                Ok(string_input(input_string))
             }
         }
+    };
+    Ok(Some(input?))
+}
+
+fn parse_encoding(args: &ArgMatches) -> Result<Encoding> {
+    match args.value_of(OPTION_NAME_INPUT_ENCODING) {
+        Some(encoding_name) => match Encoding::from_name(encoding_name) {
+            Some(encoding) => Ok(encoding),
+            None => Err(Box::new(Error::UnrecognizedEncoding(
+                encoding_name.to_owned(),
+            ))),
+        },
+        None => Ok(Encoding::Utf8),
+    }
+}
+
+fn string_input(input_string: &str) -> Input {
+    if input_string == ARGUMENT_VALUE_STDIN {
+        Input::Stdin
+    } else {
+        Input::String(input_string.to_owned())
     }
 }
 
-// TODO: Unit tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_to_code_points() {
+        let input = Input::CodePoints(vec![0x41, 0xD800, 0xDC00, 0x1F600]);
+        assert_eq!(
+            input.read_to_code_points().unwrap(),
+            vec![0x41, 0xD800, 0xDC00, 0x1F600]
+        );
+
+        let input = Input::CodePoints(vec![0x41, 0xD800]);
+        assert_eq!(input.read_to_string().unwrap(), "A\u{FFFD}");
+    }
+}
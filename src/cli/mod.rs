@@ -18,29 +18,130 @@
 
 use std::error;
 use std::fmt;
+use std::io;
+use std::path::PathBuf;
 
+use crate::ucd::code_point_to_string;
+
+mod block;
+mod boundaries;
+mod case;
+mod code_point_notation;
+mod delimited;
+mod digits;
+mod escape;
+mod flamegraph;
+mod fonts;
+mod hex_bytes;
 mod input;
+mod normalize;
 mod output;
+mod search;
+mod stream;
+mod table;
+mod tree;
 
+pub use block::ARGUMENT_VALUE_NAME_BLOCK;
+pub use boundaries::{
+    BOUNDARY_MARKER, MANDATORY_BREAK_MARKER, OPTION_NAME_BOUNDARY_KINDS,
+    OPTION_VALUE_BOUNDARY_KIND_GRAPHEME, OPTION_VALUE_BOUNDARY_KIND_LINE,
+    OPTION_VALUE_BOUNDARY_KIND_SENTENCE, OPTION_VALUE_BOUNDARY_KIND_WORD,
+};
+pub use case::{
+    OPTION_NAME_CASE_LANGUAGE, OPTION_NAME_CASE_MAPPING, OPTION_VALUE_CASE_MAPPING_FOLD,
+    OPTION_VALUE_CASE_MAPPING_LOWER, OPTION_VALUE_CASE_MAPPING_SIMPLE_FOLD,
+    OPTION_VALUE_CASE_MAPPING_TITLE, OPTION_VALUE_CASE_MAPPING_UPPER,
+};
+pub use escape::{
+    OPTION_NAME_ESCAPE_LANGUAGE, OPTION_NAME_ESCAPE_SCOPE, OPTION_VALUE_ESCAPE_LANGUAGE_C,
+    OPTION_VALUE_ESCAPE_LANGUAGE_GO, OPTION_VALUE_ESCAPE_LANGUAGE_HTML,
+    OPTION_VALUE_ESCAPE_LANGUAGE_JAVA, OPTION_VALUE_ESCAPE_LANGUAGE_JAVASCRIPT,
+    OPTION_VALUE_ESCAPE_LANGUAGE_JSON, OPTION_VALUE_ESCAPE_LANGUAGE_PYTHON,
+    OPTION_VALUE_ESCAPE_LANGUAGE_RUST, OPTION_VALUE_ESCAPE_LANGUAGE_URL,
+    OPTION_VALUE_ESCAPE_SCOPE_ALL, OPTION_VALUE_ESCAPE_SCOPE_INVISIBLE,
+    OPTION_VALUE_ESCAPE_SCOPE_NON_ASCII,
+};
 pub use input::{
-    parse_input, ARGUMENT_VALUE_NAME_INPUT, OPTION_NAME_INPUT_TYPE,
+    parse_optional_input, ARGUMENT_VALUE_NAME_INPUT, ARGUMENT_VALUE_STDIN,
+    OPTION_NAME_INPUT_ENCODING, OPTION_NAME_INPUT_FILE, OPTION_NAME_INPUT_TYPE,
+    OPTION_VALUE_INPUT_ENCODING_UTF16BE, OPTION_VALUE_INPUT_ENCODING_UTF16LE,
+    OPTION_VALUE_INPUT_ENCODING_UTF32BE, OPTION_VALUE_INPUT_ENCODING_UTF32LE,
+    OPTION_VALUE_INPUT_ENCODING_UTF8, OPTION_VALUE_INPUT_ENCODING_WTF8,
+    OPTION_VALUE_INPUT_TYPE_AUTO, OPTION_VALUE_INPUT_TYPE_BYTES,
     OPTION_VALUE_INPUT_TYPE_CODE_POINTS, OPTION_VALUE_INPUT_TYPE_STRING,
 };
+pub use normalize::{
+    OPTION_NAME_NORMALIZATION_FORM, OPTION_VALUE_NORMALIZATION_FORM_NFC,
+    OPTION_VALUE_NORMALIZATION_FORM_NFD, OPTION_VALUE_NORMALIZATION_FORM_NFKC,
+    OPTION_VALUE_NORMALIZATION_FORM_NFKD,
+};
 pub use output::{
-    generate_output, OPTION_NAME_OUTPUT_FORMAT, OPTION_VALUE_OUTPUT_FORMAT_JSON,
-    OPTION_VALUE_OUTPUT_FORMAT_TEXT,
+    character_field_names, generate_output, OPTION_NAME_COLOR, OPTION_NAME_FIELDS,
+    OPTION_NAME_OUTPUT_FORMAT, OPTION_VALUE_COLOR_ALWAYS, OPTION_VALUE_COLOR_AUTO,
+    OPTION_VALUE_COLOR_NEVER, OPTION_VALUE_OUTPUT_FORMAT_CSV, OPTION_VALUE_OUTPUT_FORMAT_HTML,
+    OPTION_VALUE_OUTPUT_FORMAT_JSON, OPTION_VALUE_OUTPUT_FORMAT_MARKDOWN,
+    OPTION_VALUE_OUTPUT_FORMAT_NDJSON, OPTION_VALUE_OUTPUT_FORMAT_TEXT,
+    OPTION_VALUE_OUTPUT_FORMAT_TSV,
 };
+pub use search::{ARGUMENT_VALUE_NAME_QUERY, DEFAULT_SEARCH_LIMIT, OPTION_NAME_SEARCH_LIMIT};
+
+pub const SUBCOMMAND_NAME_INSPECT: &str = "inspect";
+pub const SUBCOMMAND_NAME_SEARCH: &str = "search";
+pub const SUBCOMMAND_NAME_BLOCK: &str = "block";
+pub const SUBCOMMAND_NAME_NORMALIZE: &str = "normalize";
+pub const SUBCOMMAND_NAME_FONTS: &str = "fonts";
+pub const SUBCOMMAND_NAME_ESCAPE: &str = "escape";
+pub const SUBCOMMAND_NAME_BOUNDARIES: &str = "boundaries";
+pub const SUBCOMMAND_NAME_DIGITS: &str = "digits";
+pub const SUBCOMMAND_NAME_CASE: &str = "case";
+pub const SUBCOMMAND_NAME_FLAMEGRAPH: &str = "flamegraph";
+pub const SUBCOMMAND_NAME_TUI: &str = "tui";
 
+// These flags predate subcommands, they are still accepted when no subcommand is given, so that
+// existing scripts keep working.
 pub const FLAG_NAME_TUI_MODE: &str = "tui_mode";
 pub const FLAG_NAME_GENERATE_FLAMEGRAPH: &str = "generate_flamegraph";
+
 pub const FLAG_NAME_CODE_POINT_INPUT_MODE: &str = "code_point_input_mode";
 
 pub type Result<T> = std::result::Result<T, Box<dyn error::Error>>;
 
 pub enum Error {
     MissingInput,
+    FailedToOpenInputFile(PathBuf, io::Error),
+    FailedToLoadUnihanData(PathBuf, io::Error),
     UnrecognizedInputType(String),
+    UnrecognizedEncoding(String),
     UnrecognizedOutputFormat(String),
+    UnsupportedOutputFormat(&'static str),
+    UnrecognizedField(String),
+    UnrecognizedColor(String),
+    UnrecognizedNormalizationForm(String),
+    UnrecognizedBlock(String),
+    UnrecognizedEscapeLanguage(String),
+    UnrecognizedEscapeScope(String),
+    UnrecognizedBoundaryKind(String),
+    UnrecognizedCaseMapping(String),
+    InvalidSearchLimit(String),
+    InvalidDigit {
+        chr: char,
+        offset: usize,
+    },
+    MixedDigits {
+        chr: char,
+        offset: usize,
+    },
+    DigitsOverflow(String),
+    InvalidCodePoint {
+        token: String,
+        offset: usize,
+        reason: String,
+    },
+    InvalidHexBytes {
+        token: String,
+        offset: usize,
+        reason: &'static str,
+    },
 }
 
 impl fmt::Debug for Error {
@@ -52,19 +153,247 @@ impl fmt::Debug for Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Error::MissingInput => write!(f, "An input or `--tui` flag is expected"),
+            Error::MissingInput => write!(f, "An input or `--file` is expected"),
+            Error::FailedToOpenInputFile(path, error) => {
+                write!(f, "Failed to open '{}': {}", path.display(), error)
+            }
+            Error::FailedToLoadUnihanData(path, error) => write!(
+                f,
+                "Failed to load the Unihan data from '{}': {}",
+                path.display(),
+                error
+            ),
             Error::UnrecognizedInputType(input_type) => {
                 write!(f, "Unrecognized input type '{}'", input_type)
             }
+            Error::UnrecognizedEncoding(encoding) => {
+                write!(f, "Unrecognized encoding '{}'", encoding)
+            }
             Error::UnrecognizedOutputFormat(output_format) => {
                 write!(f, "Unrecognized output format '{}'", output_format)
             }
+            Error::UnsupportedOutputFormat(output_format) => write!(
+                f,
+                "Output format '{}' is not supported by this subcommand",
+                output_format
+            ),
+            Error::UnrecognizedField(field) => write!(f, "Unrecognized field '{}'", field),
+            Error::UnrecognizedColor(color) => write!(f, "Unrecognized color option '{}'", color),
+            Error::UnrecognizedNormalizationForm(form) => {
+                write!(f, "Unrecognized normalization form '{}'", form)
+            }
+            Error::UnrecognizedBlock(block) => write!(f, "Unrecognized block '{}'", block),
+            Error::UnrecognizedEscapeLanguage(language) => {
+                write!(f, "Unrecognized escape language '{}'", language)
+            }
+            Error::UnrecognizedEscapeScope(escape_scope) => {
+                write!(f, "Unrecognized escape scope '{}'", escape_scope)
+            }
+            Error::UnrecognizedBoundaryKind(kind) => {
+                write!(f, "Unrecognized boundary kind '{}'", kind)
+            }
+            Error::UnrecognizedCaseMapping(mapping) => {
+                write!(f, "Unrecognized case mapping '{}'", mapping)
+            }
+            Error::InvalidSearchLimit(limit) => write!(f, "Invalid search limit '{}'", limit),
+            Error::InvalidDigit { chr, offset } => write!(
+                f,
+                "'{}' ({}) at offset {} is not a decimal digit",
+                chr,
+                code_point_to_string(*chr),
+                offset
+            ),
+            Error::MixedDigits { chr, offset } => write!(
+                f,
+                "'{}' ({}) at offset {} is a digit of a different script than the preceding digits",
+                chr,
+                code_point_to_string(*chr),
+                offset
+            ),
+            Error::DigitsOverflow(digits) => write!(f, "The value of '{}' is too large", digits),
+            Error::InvalidCodePoint {
+                token,
+                offset,
+                reason,
+            } => write!(
+                f,
+                "Invalid code point '{}' at offset {}, {}",
+                token, offset, reason
+            ),
+            Error::InvalidHexBytes {
+                token,
+                offset,
+                reason,
+            } => write!(
+                f,
+                "Invalid hex bytes '{}' at offset {}, {}",
+                token, offset, reason
+            ),
         }
     }
 }
 
 impl error::Error for Error {}
 
+/// Arguments for subcommands which take characters as input, e.g. `inspect` and `normalize`.
+#[macro_export]
+macro_rules! create_input_args {
+    () => {
+        [
+            Arg::with_name(cli::FLAG_NAME_CODE_POINT_INPUT_MODE)
+                .short("u")
+                .help(&format!(
+                    "Parses {} as comma separated code points or ranges\n\
+                     (e.g. U+0041, U+0400..U+04FF, block:Cyrillic, plane:1),\n\
+                     same as '--input-type={}',\n\
+                     ignored if '--input-type' is specified",
+                    cli::ARGUMENT_VALUE_NAME_INPUT,
+                    cli::OPTION_VALUE_INPUT_TYPE_CODE_POINTS,
+                )),
+            Arg::with_name(cli::OPTION_NAME_INPUT_TYPE)
+                .short("i")
+                .long("input-type")
+                .takes_value(true)
+                .value_name("TYPE")
+                .help(&format!(
+                    "Specifies input type, '{}' by default,\n\
+                     valid values: {}, {}, {}, {}\n\
+                     ('{}' accepts code points in notations like\n\
+                     U+1F600, \\u{{1F600}}, \\uD83D\\uDE00, &#x1F600;, %F0%9F%98%80,\n\
+                     0x1F600 and 128512,\n\
+                     '{}' accepts hex bytes like 'E2 82 AC' or 'D8 3D DE 00')",
+                    cli::OPTION_VALUE_INPUT_TYPE_STRING,
+                    cli::OPTION_VALUE_INPUT_TYPE_STRING,
+                    cli::OPTION_VALUE_INPUT_TYPE_CODE_POINTS,
+                    cli::OPTION_VALUE_INPUT_TYPE_AUTO,
+                    cli::OPTION_VALUE_INPUT_TYPE_BYTES,
+                    cli::OPTION_VALUE_INPUT_TYPE_AUTO,
+                    cli::OPTION_VALUE_INPUT_TYPE_BYTES,
+                )),
+            Arg::with_name(cli::OPTION_NAME_INPUT_ENCODING)
+                .short("e")
+                .long("encoding")
+                .takes_value(true)
+                .value_name("ENCODING")
+                .help(&format!(
+                    "Specifies the encoding of '--input-type={}' and FILE, '{}' by default,\n\
+                     valid values: {}, {}, {}, {}, {}, {}\n\
+                     ('{}' is UTF-8 which also allows lone surrogates)",
+                    cli::OPTION_VALUE_INPUT_TYPE_BYTES,
+                    cli::OPTION_VALUE_INPUT_ENCODING_UTF8,
+                    cli::OPTION_VALUE_INPUT_ENCODING_UTF8,
+                    cli::OPTION_VALUE_INPUT_ENCODING_WTF8,
+                    cli::OPTION_VALUE_INPUT_ENCODING_UTF16LE,
+                    cli::OPTION_VALUE_INPUT_ENCODING_UTF16BE,
+                    cli::OPTION_VALUE_INPUT_ENCODING_UTF32LE,
+                    cli::OPTION_VALUE_INPUT_ENCODING_UTF32BE,
+                    cli::OPTION_VALUE_INPUT_ENCODING_WTF8,
+                )),
+            Arg::with_name(cli::OPTION_NAME_INPUT_FILE)
+                .short("f")
+                .long("file")
+                .takes_value(true)
+                .value_name("FILE")
+                .conflicts_with_all(&[
+                    cli::ARGUMENT_VALUE_NAME_INPUT,
+                    cli::OPTION_NAME_INPUT_TYPE,
+                    cli::FLAG_NAME_CODE_POINT_INPUT_MODE,
+                ])
+                .help(&format!(
+                    "Reads FILE as text in ENCODING instead of {}",
+                    cli::ARGUMENT_VALUE_NAME_INPUT,
+                )),
+            Arg::with_name(cli::ARGUMENT_VALUE_NAME_INPUT).help(&format!(
+                "a string or comma separated code points,\n\
+                 or '{}' to read the string from stdin",
+                cli::ARGUMENT_VALUE_STDIN,
+            )),
+        ]
+    };
+}
+
+#[macro_export]
+macro_rules! create_output_format_arg {
+    ($($output_format:expr),+) => {
+        Arg::with_name(cli::OPTION_NAME_OUTPUT_FORMAT)
+            .short("o")
+            .long("output-format")
+            .takes_value(true)
+            .value_name("FORMAT")
+            .help(&format!(
+                "Specifies output format, '{}' by default,\n\
+                 valid values: {}",
+                cli::OPTION_VALUE_OUTPUT_FORMAT_TEXT,
+                [$($output_format),+].join(", "),
+            ))
+    };
+}
+
+/// The output format argument of subcommands which print character properties.
+#[macro_export]
+macro_rules! create_character_output_format_arg {
+    () => {
+        $crate::create_output_format_arg!(
+            cli::OPTION_VALUE_OUTPUT_FORMAT_TEXT,
+            cli::OPTION_VALUE_OUTPUT_FORMAT_JSON,
+            cli::OPTION_VALUE_OUTPUT_FORMAT_NDJSON,
+            cli::OPTION_VALUE_OUTPUT_FORMAT_CSV,
+            cli::OPTION_VALUE_OUTPUT_FORMAT_TSV,
+            cli::OPTION_VALUE_OUTPUT_FORMAT_MARKDOWN,
+            cli::OPTION_VALUE_OUTPUT_FORMAT_HTML
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! create_fields_arg {
+    () => {
+        Arg::with_name(cli::OPTION_NAME_FIELDS)
+            .long("fields")
+            .takes_value(true)
+            .value_name("FIELDS")
+            .help(&format!(
+                "Specifies comma separated properties to compute and output, all by default\n\
+                 (e.g. code_point,name,general_category,bidi_class),\n\
+                 valid values:\n{}",
+                cli::character_field_names(),
+            ))
+    };
+}
+
+#[macro_export]
+macro_rules! create_color_arg {
+    () => {
+        Arg::with_name(cli::OPTION_NAME_COLOR)
+            .long("color")
+            .takes_value(true)
+            .value_name("WHEN")
+            .help(&format!(
+                "Specifies when to use colors in text output, '{}' by default,\n\
+                 valid values: {} (if the output is a terminal), {}, {}",
+                cli::OPTION_VALUE_COLOR_AUTO,
+                cli::OPTION_VALUE_COLOR_AUTO,
+                cli::OPTION_VALUE_COLOR_ALWAYS,
+                cli::OPTION_VALUE_COLOR_NEVER,
+            ))
+    };
+}
+
+#[macro_export]
+macro_rules! create_search_limit_arg {
+    () => {
+        Arg::with_name(cli::OPTION_NAME_SEARCH_LIMIT)
+            .short("n")
+            .long("limit")
+            .takes_value(true)
+            .value_name("COUNT")
+            .help(&format!(
+                "Specifies the maximum number of search results, {} by default",
+                cli::DEFAULT_SEARCH_LIMIT,
+            ))
+    };
+}
+
 #[macro_export]
 macro_rules! create_cli {
     () => {
@@ -74,63 +403,306 @@ macro_rules! create_cli {
                 crate_version!(),
                 UNICODE_VERSION
             ))
-            .arg(
-                Arg::with_name(cli::FLAG_NAME_TUI_MODE)
-                    .short("t")
-                    .long("tui")
-                    .help("Shows Terminal UI"),
+            .setting(AppSettings::ArgsNegateSubcommands)
+            .setting(AppSettings::VersionlessSubcommands)
+            .after_help(&*format!(
+                "Without a subcommand, INPUT is inspected the same way as '{}' does.",
+                cli::SUBCOMMAND_NAME_INSPECT,
+            ))
+            .subcommand(
+                SubCommand::with_name(cli::SUBCOMMAND_NAME_INSPECT)
+                    .about("Shows properties of each character in INPUT")
+                    .args(&$crate::create_input_args!())
+                    .arg($crate::create_character_output_format_arg!())
+                    .arg($crate::create_fields_arg!())
+                    .arg($crate::create_color_arg!()),
             )
-            .arg(
-                Arg::with_name(cli::FLAG_NAME_GENERATE_FLAMEGRAPH)
-                    .short("g")
-                    .long("generate-flamegraph")
-                    .help(
-                        "Generate Flamegraph for all Unicode Planes,\n\
-                    which can be loaded by Chrome's tracer UI (about:tracing)",
+            .subcommand(
+                SubCommand::with_name(cli::SUBCOMMAND_NAME_SEARCH)
+                    .about(
+                        "Searches characters by name (or name alias),\n\
+                         exact matches are listed first, then prefix, word and fuzzy matches",
+                    )
+                    .arg($crate::create_search_limit_arg!())
+                    .arg($crate::create_output_format_arg!(
+                        cli::OPTION_VALUE_OUTPUT_FORMAT_TEXT,
+                        cli::OPTION_VALUE_OUTPUT_FORMAT_JSON
+                    ))
+                    .arg(
+                        Arg::with_name(cli::ARGUMENT_VALUE_NAME_QUERY)
+                            .required(true)
+                            .help("a character name, e.g. 'snowman' or 'greek small letter alpha'"),
                     ),
             )
-            .arg(
-                Arg::with_name(cli::FLAG_NAME_CODE_POINT_INPUT_MODE)
-                    .short("u")
-                    .help(&format!(
-                        "Parses {} as comma separated code points,\n\
-                     same as '--input-type={}',\n\
-                     ignored if '--input-type' is specified",
-                        cli::ARGUMENT_VALUE_NAME_INPUT,
-                        cli::OPTION_VALUE_INPUT_TYPE_CODE_POINTS,
-                    )),
+            .subcommand(
+                SubCommand::with_name(cli::SUBCOMMAND_NAME_BLOCK)
+                    .about("Lists all blocks, or characters in BLOCK")
+                    .arg($crate::create_output_format_arg!(
+                        cli::OPTION_VALUE_OUTPUT_FORMAT_TEXT,
+                        cli::OPTION_VALUE_OUTPUT_FORMAT_JSON
+                    ))
+                    .arg(
+                        Arg::with_name(cli::ARGUMENT_VALUE_NAME_BLOCK)
+                            .help("a block name (e.g. 'Latin Extended-A'), or a code point in it"),
+                    ),
             )
-            .arg(
-                Arg::with_name(cli::OPTION_NAME_OUTPUT_FORMAT)
-                    .short("o")
-                    .long("output-format")
-                    .takes_value(true)
-                    .value_name("FORMAT")
-                    .help(&format!(
-                        "Specifies output format, '{}' by default,\n\
-                     valid values: {}, {}",
+            .subcommand(
+                SubCommand::with_name(cli::SUBCOMMAND_NAME_NORMALIZE)
+                    .about(
+                        "Normalizes INPUT into one of the Unicode Normalization Forms and shows\n\
+                         graphemes which are changed, e.g. 'ﬁ' is normalized to 'fi' in NFKC",
+                    )
+                    .args(&$crate::create_input_args!())
+                    .arg($crate::create_output_format_arg!(
                         cli::OPTION_VALUE_OUTPUT_FORMAT_TEXT,
+                        cli::OPTION_VALUE_OUTPUT_FORMAT_JSON
+                    ))
+                    .arg(
+                        Arg::with_name(cli::OPTION_NAME_NORMALIZATION_FORM)
+                            .short("n")
+                            .long("form")
+                            .takes_value(true)
+                            .value_name("FORM")
+                            .help(&format!(
+                                "Specifies normalization form, '{}' by default,\n\
+                                 valid values: {}, {}, {}, {}",
+                                cli::OPTION_VALUE_NORMALIZATION_FORM_NFC,
+                                cli::OPTION_VALUE_NORMALIZATION_FORM_NFC,
+                                cli::OPTION_VALUE_NORMALIZATION_FORM_NFD,
+                                cli::OPTION_VALUE_NORMALIZATION_FORM_NFKC,
+                                cli::OPTION_VALUE_NORMALIZATION_FORM_NFKD,
+                            )),
+                    ),
+            )
+            .subcommand(
+                SubCommand::with_name(cli::SUBCOMMAND_NAME_FONTS)
+                    .about("Lists fonts on this system which support characters in INPUT")
+                    .args(&$crate::create_input_args!())
+                    .arg($crate::create_output_format_arg!(
                         cli::OPTION_VALUE_OUTPUT_FORMAT_TEXT,
-                        cli::OPTION_VALUE_OUTPUT_FORMAT_JSON,
+                        cli::OPTION_VALUE_OUTPUT_FORMAT_JSON
                     )),
             )
-            .arg(
-                Arg::with_name(cli::OPTION_NAME_INPUT_TYPE)
-                    .short("i")
-                    .long("input-type")
-                    .takes_value(true)
-                    .value_name("TYPE")
-                    .help(&format!(
-                        "Specifies input type, '{}' by default,\n\
-                     valid values: {}, {}",
-                        cli::OPTION_VALUE_INPUT_TYPE_STRING,
-                        cli::OPTION_VALUE_INPUT_TYPE_STRING,
-                        cli::OPTION_VALUE_INPUT_TYPE_CODE_POINTS,
+            .subcommand(
+                SubCommand::with_name(cli::SUBCOMMAND_NAME_ESCAPE)
+                    .about("Escapes INPUT into a string literal of a programming language")
+                    .args(&$crate::create_input_args!())
+                    .arg(
+                        Arg::with_name(cli::OPTION_NAME_ESCAPE_LANGUAGE)
+                            .short("l")
+                            .long("language")
+                            .takes_value(true)
+                            .value_name("LANGUAGE")
+                            .help(&format!(
+                                "Specifies the language of the literal, '{}' by default,\n\
+                                 valid values: {}, {}, {}, {}, {} (C and C++), {}, {}, {}, {}\n\
+                                 ('{}' uses numeric character references,\n\
+                                 '{}' uses percent-encoding)",
+                                cli::OPTION_VALUE_ESCAPE_LANGUAGE_RUST,
+                                cli::OPTION_VALUE_ESCAPE_LANGUAGE_RUST,
+                                cli::OPTION_VALUE_ESCAPE_LANGUAGE_JSON,
+                                cli::OPTION_VALUE_ESCAPE_LANGUAGE_JAVASCRIPT,
+                                cli::OPTION_VALUE_ESCAPE_LANGUAGE_PYTHON,
+                                cli::OPTION_VALUE_ESCAPE_LANGUAGE_C,
+                                cli::OPTION_VALUE_ESCAPE_LANGUAGE_JAVA,
+                                cli::OPTION_VALUE_ESCAPE_LANGUAGE_GO,
+                                cli::OPTION_VALUE_ESCAPE_LANGUAGE_HTML,
+                                cli::OPTION_VALUE_ESCAPE_LANGUAGE_URL,
+                                cli::OPTION_VALUE_ESCAPE_LANGUAGE_HTML,
+                                cli::OPTION_VALUE_ESCAPE_LANGUAGE_URL,
+                            )),
+                    )
+                    .arg(
+                        Arg::with_name(cli::OPTION_NAME_ESCAPE_SCOPE)
+                            .long("escape")
+                            .takes_value(true)
+                            .value_name("SCOPE")
+                            .help(&format!(
+                                "Specifies which characters to escape, '{}' by default,\n\
+                                 valid values: {}, {}, {}\n\
+                                 (characters which can't appear in the literal as is,\n\
+                                 e.g. quotes and controls, are always escaped)",
+                                cli::OPTION_VALUE_ESCAPE_SCOPE_NON_ASCII,
+                                cli::OPTION_VALUE_ESCAPE_SCOPE_ALL,
+                                cli::OPTION_VALUE_ESCAPE_SCOPE_NON_ASCII,
+                                cli::OPTION_VALUE_ESCAPE_SCOPE_INVISIBLE,
+                            )),
+                    ),
+            )
+            .subcommand(
+                SubCommand::with_name(cli::SUBCOMMAND_NAME_BOUNDARIES)
+                    .about(&*format!(
+                        "Shows word and sentence boundaries and line break opportunities in INPUT,\n\
+                         boundaries are marked with '{}' and mandatory line breaks with '{}'",
+                        cli::BOUNDARY_MARKER,
+                        cli::MANDATORY_BREAK_MARKER,
+                    ))
+                    .args(&$crate::create_input_args!())
+                    .arg($crate::create_output_format_arg!(
+                        cli::OPTION_VALUE_OUTPUT_FORMAT_TEXT,
+                        cli::OPTION_VALUE_OUTPUT_FORMAT_JSON
+                    ))
+                    .arg(
+                        Arg::with_name(cli::OPTION_NAME_BOUNDARY_KINDS)
+                            .short("k")
+                            .long("kinds")
+                            .takes_value(true)
+                            .value_name("KINDS")
+                            .help(&format!(
+                                "Specifies comma separated kinds of boundaries to show,\n\
+                                 '{},{},{}' by default,\n\
+                                 valid values: {}, {}, {}, {}",
+                                cli::OPTION_VALUE_BOUNDARY_KIND_WORD,
+                                cli::OPTION_VALUE_BOUNDARY_KIND_SENTENCE,
+                                cli::OPTION_VALUE_BOUNDARY_KIND_LINE,
+                                cli::OPTION_VALUE_BOUNDARY_KIND_GRAPHEME,
+                                cli::OPTION_VALUE_BOUNDARY_KIND_WORD,
+                                cli::OPTION_VALUE_BOUNDARY_KIND_SENTENCE,
+                                cli::OPTION_VALUE_BOUNDARY_KIND_LINE,
+                            )),
+                    ),
+            )
+            .subcommand(
+                SubCommand::with_name(cli::SUBCOMMAND_NAME_DIGITS)
+                    .about(
+                        "Interprets INPUT as decimal digits of any script (e.g. Arabic-Indic\n\
+                         or Devanagari digits) and prints its integer value",
+                    )
+                    .args(&$crate::create_input_args!())
+                    .arg($crate::create_output_format_arg!(
+                        cli::OPTION_VALUE_OUTPUT_FORMAT_TEXT,
+                        cli::OPTION_VALUE_OUTPUT_FORMAT_JSON
                     )),
             )
-            .arg(
-                Arg::with_name(cli::ARGUMENT_VALUE_NAME_INPUT)
-                    .help("a string or comma separated code points"),
+            .subcommand(
+                SubCommand::with_name(cli::SUBCOMMAND_NAME_CASE)
+                    .about(
+                        "Maps the case of INPUT and shows graphemes whose length is changed,\n\
+                         e.g. 'ß' is uppercased to 'SS'",
+                    )
+                    .args(&$crate::create_input_args!())
+                    .arg($crate::create_output_format_arg!(
+                        cli::OPTION_VALUE_OUTPUT_FORMAT_TEXT,
+                        cli::OPTION_VALUE_OUTPUT_FORMAT_JSON
+                    ))
+                    .arg(
+                        Arg::with_name(cli::OPTION_NAME_CASE_MAPPING)
+                            .short("m")
+                            .long("mapping")
+                            .takes_value(true)
+                            .value_name("MAPPING")
+                            .help(&format!(
+                                "Specifies the case mapping, '{}' by default,\n\
+                                 valid values: {}, {}, {}, {} (full case folding), {}",
+                                cli::OPTION_VALUE_CASE_MAPPING_LOWER,
+                                cli::OPTION_VALUE_CASE_MAPPING_LOWER,
+                                cli::OPTION_VALUE_CASE_MAPPING_UPPER,
+                                cli::OPTION_VALUE_CASE_MAPPING_TITLE,
+                                cli::OPTION_VALUE_CASE_MAPPING_FOLD,
+                                cli::OPTION_VALUE_CASE_MAPPING_SIMPLE_FOLD,
+                            )),
+                    )
+                    .arg(
+                        Arg::with_name(cli::OPTION_NAME_CASE_LANGUAGE)
+                            .short("l")
+                            .long("language")
+                            .takes_value(true)
+                            .value_name("LANGUAGE")
+                            .help(
+                                "Specifies the language of INPUT as a language tag (e.g. tr),\n\
+                                 Turkish, Azeri, Lithuanian and Greek (el, uppercase only)\n\
+                                 have their own case mappings",
+                            ),
+                    ),
+            )
+            .subcommand(
+                SubCommand::with_name(cli::SUBCOMMAND_NAME_FLAMEGRAPH).about(
+                    "Generates Flamegraph for all Unicode Planes,\n\
+                     which can be loaded by Chrome's tracer UI (about:tracing)",
+                ),
+            )
+            .subcommand(
+                SubCommand::with_name(cli::SUBCOMMAND_NAME_TUI)
+                    .about("Shows Terminal UI")
+                    .args(&$crate::create_input_args!()),
+            )
+            .args(&$crate::create_input_args!())
+            .arg($crate::create_character_output_format_arg!())
+            .arg($crate::create_fields_arg!())
+            .arg($crate::create_color_arg!())
+            // Flags from before subcommands were introduced, hidden from help
+            .args(
+                &[
+                    Arg::with_name(cli::FLAG_NAME_TUI_MODE)
+                        .short("t")
+                        .long("tui"),
+                    Arg::with_name(cli::FLAG_NAME_GENERATE_FLAMEGRAPH)
+                        .short("g")
+                        .long("generate-flamegraph"),
+                ]
+                .iter()
+                .map(|arg| arg.clone().hidden(true))
+                .collect::<Vec<Arg>>(),
             )
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use clap::{crate_version, App, AppSettings, Arg, SubCommand};
+    use unic::ucd::version::UNICODE_VERSION;
+
+    use super::*;
+    use crate::cli;
+
+    fn matches_for(args: &[&str]) -> (String, Option<String>) {
+        let matches = create_cli!().get_matches_from(args);
+        let (name, sub_matches) = matches.subcommand();
+        let input = sub_matches
+            .unwrap_or(&matches)
+            .value_of(ARGUMENT_VALUE_NAME_INPUT)
+            .map(String::from);
+        (name.to_owned(), input)
+    }
+
+    #[test]
+    fn test_subcommands_and_bare_input() {
+        assert_eq!(
+            matches_for(&["cicero", "tui"]),
+            (SUBCOMMAND_NAME_TUI.to_owned(), None)
+        );
+        assert_eq!(
+            matches_for(&["cicero", "block"]),
+            (SUBCOMMAND_NAME_BLOCK.to_owned(), None)
+        );
+        assert_eq!(
+            matches_for(&["cicero", "search", "latin"]).0,
+            SUBCOMMAND_NAME_SEARCH
+        );
+        assert_eq!(
+            matches_for(&["cicero", "latin"]),
+            (String::new(), Some("latin".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_file_input() {
+        let path = env::temp_dir().join(format!("cicero-file-input-test-{}", process::id()));
+        fs::write(&path, [0x41, 0x00, 0x3D, 0xD8, 0x00, 0xDE]).unwrap();
+        let path_string = path.to_str().unwrap();
+
+        let matches =
+            create_cli!().get_matches_from(&["cicero", "-f", path_string, "-e", "utf-16le"]);
+        let string = input::parse_input(&matches).unwrap().read_to_string();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(string.unwrap(), "A\u{1F600}");
+
+        for input_type_args in &[vec!["-i", "bytes"], vec!["-i", "string"], vec!["-u"]] {
+            let mut args = vec!["cicero", "-f", path_string];
+            args.extend(input_type_args);
+            assert!(create_cli!().get_matches_from_safe(&args).is_err());
+        }
+    }
+}
@@ -0,0 +1,181 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Normalizes text, and reports graphemes which are changed by the normalization, e.g. "ﬁ" is
+//! normalized to "fi" in NFKC.
+//!
+
+use std::io::Write;
+
+use clap::ArgMatches;
+use serde::Serialize;
+use unic::normal::StrNormalForm;
+use unic::segment::Graphemes;
+
+use super::input::parse_input;
+use super::output::{parse_output_format, unsupported_output_format, write_as_json, OutputFormat};
+use super::{Error, Result};
+use crate::ucd::code_point_to_string;
+
+pub const OPTION_NAME_NORMALIZATION_FORM: &str = "normalization_form";
+pub const OPTION_VALUE_NORMALIZATION_FORM_NFC: &str = "nfc";
+pub const OPTION_VALUE_NORMALIZATION_FORM_NFD: &str = "nfd";
+pub const OPTION_VALUE_NORMALIZATION_FORM_NFKC: &str = "nfkc";
+pub const OPTION_VALUE_NORMALIZATION_FORM_NFKD: &str = "nfkd";
+
+#[derive(Serialize, Debug)]
+struct GraphemeNormalization<'a> {
+    grapheme: &'a str,
+    normalized: String,
+}
+
+impl<'a> GraphemeNormalization<'a> {
+    fn is_changed(&self) -> bool {
+        self.grapheme != self.normalized
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct Normalization<'a> {
+    form: &'a str,
+    text: String,
+    code_points: Vec<u32>,
+    graphemes: Vec<GraphemeNormalization<'a>>,
+}
+
+impl<'a> Normalization<'a> {
+    fn new(string: &'a str, form: &'a str) -> Result<Self> {
+        let text = normalize(string, form)?;
+        // Graphemes are also normalized on their own to show which of them are changed, their
+        // normalized forms don't always make up `text` as normalization can combine characters
+        // across grapheme boundaries
+        let graphemes = Graphemes::new(string)
+            .map(|grapheme| {
+                Ok(GraphemeNormalization {
+                    grapheme,
+                    normalized: normalize(grapheme, form)?,
+                })
+            })
+            .collect::<Result<Vec<GraphemeNormalization>>>()?;
+
+        Ok(Normalization {
+            form,
+            code_points: text.chars().map(|chr| chr as u32).collect(),
+            text,
+            graphemes,
+        })
+    }
+}
+
+fn join_code_points(string: &str) -> String {
+    string
+        .chars()
+        .map(code_point_to_string)
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn normalize(string: &str, form: &str) -> Result<String> {
+    match form {
+        OPTION_VALUE_NORMALIZATION_FORM_NFC => Ok(string.nfc().collect()),
+        OPTION_VALUE_NORMALIZATION_FORM_NFD => Ok(string.nfd().collect()),
+        OPTION_VALUE_NORMALIZATION_FORM_NFKC => Ok(string.nfkc().collect()),
+        OPTION_VALUE_NORMALIZATION_FORM_NFKD => Ok(string.nfkd().collect()),
+        _ => Err(Box::new(Error::UnrecognizedNormalizationForm(
+            form.to_owned(),
+        ))),
+    }
+}
+
+pub(super) fn generate_normalize_output<W: Write>(args: &ArgMatches, writer: &mut W) -> Result<()> {
+    let output_format = parse_output_format(args)?;
+    let form = args
+        .value_of(OPTION_NAME_NORMALIZATION_FORM)
+        .unwrap_or(OPTION_VALUE_NORMALIZATION_FORM_NFC);
+    let string = parse_input(args)?.read_to_string()?;
+    let normalization = Normalization::new(&string, form)?;
+
+    match output_format {
+        OutputFormat::Text => {
+            writeln!(writer, "{}", normalization.text)?;
+            for grapheme in normalization
+                .graphemes
+                .iter()
+                .filter(|grapheme| grapheme.is_changed())
+            {
+                writeln!(
+                    writer,
+                    "{} → {}  {} → {}",
+                    grapheme.grapheme,
+                    grapheme.normalized,
+                    join_code_points(grapheme.grapheme),
+                    join_code_points(&grapheme.normalized),
+                )?;
+            }
+            Ok(())
+        }
+        OutputFormat::Json => write_as_json(&normalization, writer),
+        output_format => unsupported_output_format(output_format),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        let string = "A\u{30A}\u{FB01}";
+        assert_eq!(normalize(string, "nfc").unwrap(), "\u{C5}\u{FB01}");
+        assert_eq!(normalize(string, "nfd").unwrap(), "A\u{30A}\u{FB01}");
+        assert_eq!(normalize(string, "nfkc").unwrap(), "\u{C5}fi");
+        assert_eq!(normalize(string, "nfkd").unwrap(), "A\u{30A}fi");
+        assert!(normalize(string, "nfx").is_err());
+    }
+
+    #[test]
+    fn test_changed_graphemes() {
+        let normalization = Normalization::new("A\u{30A}b\u{FB01}\u{AC01}", "nfkd").unwrap();
+        assert_eq!(normalization.text, "A\u{30A}bfi\u{1100}\u{1161}\u{11A8}");
+        let changed_graphemes: Vec<(&str, &str)> = normalization
+            .graphemes
+            .iter()
+            .filter(|grapheme| grapheme.is_changed())
+            .map(|grapheme| (grapheme.grapheme, grapheme.normalized.as_str()))
+            .collect();
+        assert_eq!(
+            changed_graphemes,
+            vec![("\u{FB01}", "fi"), ("\u{AC01}", "\u{1100}\u{1161}\u{11A8}")]
+        );
+        assert_eq!(
+            join_code_points(&normalization.graphemes[0].normalized),
+            "U+0041 U+030A"
+        );
+    }
+
+    #[test]
+    fn test_normalization_across_graphemes() {
+        // U+3161 HANGUL LETTER EU is not a conjoining vowel, so it's a grapheme of its own, but its
+        // compatibility decomposition U+1173 composes with the leading consonant before it
+        let normalization = Normalization::new("\u{1100}\u{3161}", "nfkc").unwrap();
+        assert_eq!(normalization.text, "\u{ADF8}");
+        let graphemes: Vec<&str> = normalization
+            .graphemes
+            .iter()
+            .map(|grapheme| grapheme.normalized.as_str())
+            .collect();
+        assert_eq!(graphemes, vec!["\u{1100}", "\u{1173}"]);
+    }
+}
@@ -12,106 +12,302 @@
 // You should have received a copy of the GNU General Public License along with
 // Cicero. If not, see <https://www.gnu.org/licenses/>.
 
+use std::io::{self, Write};
+
 use clap::ArgMatches;
+use crossterm::terminal;
+use crossterm::tty::IsTty;
+use serde::ser::{SerializeSeq, Serializer};
+use serde::Serialize;
 
-use super::{parse_input, Error, Result};
-use crate::cli::input::Input;
-use crate::ucd::GraphemeProperties;
+use super::delimited::{write_segments_as_delimited, Delimiter};
+use super::input::parse_input;
+use super::stream::{GraphemeStream, Segment};
+use super::table::{write_segments_as_table, TableFormat};
+use super::tree::TreeWriter;
+use super::{
+    block, boundaries, case, digits, escape, flamegraph, fonts, normalize, search, Error, Result,
+    FLAG_NAME_GENERATE_FLAMEGRAPH, SUBCOMMAND_NAME_BLOCK, SUBCOMMAND_NAME_BOUNDARIES,
+    SUBCOMMAND_NAME_CASE, SUBCOMMAND_NAME_DIGITS, SUBCOMMAND_NAME_ESCAPE,
+    SUBCOMMAND_NAME_FLAMEGRAPH, SUBCOMMAND_NAME_FONTS, SUBCOMMAND_NAME_INSPECT,
+    SUBCOMMAND_NAME_NORMALIZE, SUBCOMMAND_NAME_SEARCH,
+};
+use crate::ucd::{unihan_load_error, CharacterField, CharacterFields};
 
 pub const OPTION_NAME_OUTPUT_FORMAT: &str = "output_format";
 pub const OPTION_VALUE_OUTPUT_FORMAT_TEXT: &str = "text";
 pub const OPTION_VALUE_OUTPUT_FORMAT_JSON: &str = "json";
+pub const OPTION_VALUE_OUTPUT_FORMAT_CSV: &str = "csv";
+pub const OPTION_VALUE_OUTPUT_FORMAT_TSV: &str = "tsv";
+pub const OPTION_VALUE_OUTPUT_FORMAT_NDJSON: &str = "ndjson";
+pub const OPTION_VALUE_OUTPUT_FORMAT_MARKDOWN: &str = "markdown";
+pub const OPTION_VALUE_OUTPUT_FORMAT_HTML: &str = "html";
 
-fn graphems_to_string(graphemes: &[GraphemeProperties]) -> String {
-    graphemes
-        .iter()
-        .map(GraphemeProperties::to_string)
-        .collect::<Vec<String>>()
-        .join("\n")
-}
+pub const OPTION_NAME_FIELDS: &str = "fields";
 
-pub fn generate_output(args: ArgMatches) -> Result<String> {
-    let input = parse_input(&args)?;
+pub const OPTION_NAME_COLOR: &str = "color";
+pub const OPTION_VALUE_COLOR_AUTO: &str = "auto";
+pub const OPTION_VALUE_COLOR_ALWAYS: &str = "always";
+pub const OPTION_VALUE_COLOR_NEVER: &str = "never";
 
-    if let Input::GenerateFlamegraph = input {
-        return chrome_tracing::describe_unicode_as_events();
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(super) enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+    Tsv,
+    Ndjson,
+    Markdown,
+    Html,
+}
+
+impl OutputFormat {
+    fn name(self) -> &'static str {
+        match self {
+            OutputFormat::Text => OPTION_VALUE_OUTPUT_FORMAT_TEXT,
+            OutputFormat::Json => OPTION_VALUE_OUTPUT_FORMAT_JSON,
+            OutputFormat::Csv => OPTION_VALUE_OUTPUT_FORMAT_CSV,
+            OutputFormat::Tsv => OPTION_VALUE_OUTPUT_FORMAT_TSV,
+            OutputFormat::Ndjson => OPTION_VALUE_OUTPUT_FORMAT_NDJSON,
+            OutputFormat::Markdown => OPTION_VALUE_OUTPUT_FORMAT_MARKDOWN,
+            OutputFormat::Html => OPTION_VALUE_OUTPUT_FORMAT_HTML,
+        }
     }
+}
 
-    let graphemes = GraphemeProperties::from_string(&input.to_string());
+pub(super) fn parse_output_format(args: &ArgMatches) -> Result<OutputFormat> {
     match args.value_of(OPTION_NAME_OUTPUT_FORMAT) {
         Some(output_format) => match output_format {
-            OPTION_VALUE_OUTPUT_FORMAT_TEXT => Ok(graphems_to_string(&graphemes)),
-            OPTION_VALUE_OUTPUT_FORMAT_JSON => Ok(serde_json::to_string_pretty(&graphemes)?),
+            OPTION_VALUE_OUTPUT_FORMAT_TEXT => Ok(OutputFormat::Text),
+            OPTION_VALUE_OUTPUT_FORMAT_JSON => Ok(OutputFormat::Json),
+            OPTION_VALUE_OUTPUT_FORMAT_CSV => Ok(OutputFormat::Csv),
+            OPTION_VALUE_OUTPUT_FORMAT_TSV => Ok(OutputFormat::Tsv),
+            OPTION_VALUE_OUTPUT_FORMAT_NDJSON => Ok(OutputFormat::Ndjson),
+            OPTION_VALUE_OUTPUT_FORMAT_MARKDOWN => Ok(OutputFormat::Markdown),
+            OPTION_VALUE_OUTPUT_FORMAT_HTML => Ok(OutputFormat::Html),
             _ => Err(Box::new(Error::UnrecognizedOutputFormat(
                 output_format.to_owned(),
             ))),
         },
-        None => Ok(graphems_to_string(&graphemes)),
+        None => Ok(OutputFormat::Text),
+    }
+}
+
+/// Lists names of all fields accepted by `--fields`, a few fields per line.
+pub fn character_field_names() -> String {
+    let field_names: Vec<&str> = CharacterFields::all()
+        .iter()
+        .map(CharacterField::name)
+        .collect();
+    field_names
+        .chunks(4)
+        .map(|field_names| field_names.join(", "))
+        .collect::<Vec<String>>()
+        .join(",\n")
+}
+
+fn parse_fields(args: &ArgMatches) -> Result<CharacterFields> {
+    let field_names = match args.value_of(OPTION_NAME_FIELDS) {
+        Some(field_names) => field_names,
+        None => return Ok(CharacterFields::all()),
+    };
+
+    let mut fields = Vec::new();
+    for field_name in field_names.split(',').map(str::trim) {
+        match CharacterField::from_name(field_name) {
+            Some(field) => fields.push(field),
+            None => return Err(Box::new(Error::UnrecognizedField(field_name.to_owned()))),
+        }
+    }
+    Ok(CharacterFields::from_fields(&fields))
+}
+
+/// Colors are used by default if the output is a terminal.
+fn parse_color(args: &ArgMatches) -> Result<bool> {
+    match args.value_of(OPTION_NAME_COLOR) {
+        Some(color) => match color {
+            OPTION_VALUE_COLOR_AUTO => Ok(io::stdout().is_tty()),
+            OPTION_VALUE_COLOR_ALWAYS => Ok(true),
+            OPTION_VALUE_COLOR_NEVER => Ok(false),
+            _ => Err(Box::new(Error::UnrecognizedColor(color.to_owned()))),
+        },
+        None => Ok(io::stdout().is_tty()),
+    }
+}
+
+fn terminal_width() -> Option<usize> {
+    if !io::stdout().is_tty() {
+        return None;
+    }
+    terminal::size().ok().map(|(width, _)| width as usize)
+}
+
+/// Output formats other than text and JSON are only implemented for character properties, other
+/// subcommands report them with this error.
+pub(super) fn unsupported_output_format(output_format: OutputFormat) -> Result<()> {
+    Err(Box::new(Error::UnsupportedOutputFormat(
+        output_format.name(),
+    )))
+}
+
+pub(super) fn write_as_json<T, W>(value: &T, writer: &mut W) -> Result<()>
+where
+    T: Serialize + ?Sized,
+    W: Write,
+{
+    serde_json::to_writer_pretty(&mut *writer, value)?;
+    writeln!(writer)?;
+    Ok(())
+}
+
+fn write_segments_as_json<S, W>(segments: S, writer: &mut W) -> Result<()>
+where
+    S: Iterator<Item = io::Result<Segment>>,
+    W: Write,
+{
+    {
+        let mut serializer = serde_json::Serializer::pretty(&mut *writer);
+        let mut sequence = serializer.serialize_seq(None)?;
+        for segment in segments {
+            sequence.serialize_element(&segment?)?;
+        }
+        sequence.end()?;
     }
+    writeln!(writer)?;
+    Ok(())
 }
 
-// TODO: Should this module be a separate file?
-mod chrome_tracing {
-    use serde::Serialize;
-    use unic::ucd::{Block, BlockIter};
-
-    use super::Result;
-    use crate::ucd::{Plane, PLANE_COUNT};
-
-    #[derive(Serialize)]
-    struct Event {
-        name: &'static str,
-        cat: &'static str,
-        ph: char,
-        ts: u32,
-        pid: u64,
-        tid: u64,
+/// Writes one compact JSON object per line, each line is flushed as soon as the segment is read so
+/// that consumers (e.g. `jq`) can process the output while the input is still being read.
+fn write_segments_as_ndjson<S, W>(segments: S, writer: &mut W) -> Result<()>
+where
+    S: Iterator<Item = io::Result<Segment>>,
+    W: Write,
+{
+    for segment in segments {
+        serde_json::to_writer(&mut *writer, &segment?)?;
+        writeln!(writer)?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+fn generate_inspect_output<W: Write>(args: &ArgMatches, writer: &mut W) -> Result<()> {
+    let output_format = parse_output_format(args)?;
+    let fields = parse_fields(args)?;
+    let input = parse_input(args)?;
+    let encoding = input.encoding();
+    let segments = GraphemeStream::new(input.into_reader()?, encoding).select_fields(fields);
+    match output_format {
+        OutputFormat::Text => TreeWriter::new(fields, parse_color(args)?, terminal_width())
+            .write_segments(segments, writer),
+        OutputFormat::Json => write_segments_as_json(segments, writer),
+        OutputFormat::Csv => {
+            write_segments_as_delimited(segments, fields, Delimiter::Comma, writer)
+        }
+        OutputFormat::Tsv => write_segments_as_delimited(segments, fields, Delimiter::Tab, writer),
+        OutputFormat::Ndjson => write_segments_as_ndjson(segments, writer),
+        OutputFormat::Markdown => {
+            write_segments_as_table(segments, fields, TableFormat::Markdown, writer)
+        }
+        OutputFormat::Html => write_segments_as_table(segments, fields, TableFormat::Html, writer),
+    }
+}
+
+pub fn generate_output<W: Write>(args: &ArgMatches, writer: &mut W) -> Result<()> {
+    generate_subcommand_output(args, writer)?;
+
+    // Unihan data is left out of the output if the database fails to load, which is reported
+    // after the output rather than failing lookups of characters which aren't in it
+    match unihan_load_error() {
+        Some(load_error) => Err(Box::new(Error::FailedToLoadUnihanData(
+            load_error.path.clone(),
+            io::Error::new(load_error.error.kind(), load_error.error.to_string()),
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn generate_subcommand_output<W: Write>(args: &ArgMatches, writer: &mut W) -> Result<()> {
+    match args.subcommand() {
+        (SUBCOMMAND_NAME_INSPECT, Some(inspect_args)) => {
+            generate_inspect_output(inspect_args, writer)
+        }
+        (SUBCOMMAND_NAME_SEARCH, Some(search_args)) => {
+            search::generate_search_output(search_args, writer)
+        }
+        (SUBCOMMAND_NAME_BLOCK, Some(block_args)) => {
+            block::generate_block_output(block_args, writer)
+        }
+        (SUBCOMMAND_NAME_NORMALIZE, Some(normalize_args)) => {
+            normalize::generate_normalize_output(normalize_args, writer)
+        }
+        (SUBCOMMAND_NAME_FONTS, Some(fonts_args)) => {
+            fonts::generate_fonts_output(fonts_args, writer)
+        }
+        (SUBCOMMAND_NAME_ESCAPE, Some(escape_args)) => {
+            escape::generate_escape_output(escape_args, writer)
+        }
+        (SUBCOMMAND_NAME_BOUNDARIES, Some(boundaries_args)) => {
+            boundaries::generate_boundaries_output(boundaries_args, writer)
+        }
+        (SUBCOMMAND_NAME_CASE, Some(case_args)) => case::generate_case_output(case_args, writer),
+        (SUBCOMMAND_NAME_DIGITS, Some(digits_args)) => {
+            digits::generate_digits_output(digits_args, writer)
+        }
+        (SUBCOMMAND_NAME_FLAMEGRAPH, Some(_)) => flamegraph::generate_flamegraph_output(writer),
+        _ => {
+            // Invocations without subcommands
+            if args.is_present(FLAG_NAME_GENERATE_FLAMEGRAPH) {
+                flamegraph::generate_flamegraph_output(writer)
+            } else {
+                generate_inspect_output(args, writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ucd::Encoding;
+
+    #[test]
+    fn test_write_segments_as_ndjson() {
+        let segments = GraphemeStream::new("ae\u{301}".as_bytes(), Encoding::Utf8)
+            .select_fields(CharacterFields::from_fields(&[CharacterField::CodePoint]));
+        let mut output = Vec::new();
+        write_segments_as_ndjson(segments, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"grapheme\":\"a\",\"characters\":[{\"code_point\":97}]}\n\
+             {\"grapheme\":\"e\u{301}\",\"characters\":[{\"code_point\":101},{\"code_point\":769}]}\n"
+        );
+    }
+
+    /// Counts how many times the output is flushed.
+    #[derive(Default)]
+    struct FlushCounter {
+        flush_count: usize,
+    }
+
+    impl Write for FlushCounter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.flush_count += 1;
+            Ok(())
+        }
     }
 
-    pub fn describe_unicode_as_events() -> Result<String> {
-        let blocks: Vec<Block> = BlockIter::new().collect();
-
-        let mut events = Vec::with_capacity(blocks.len() + PLANE_COUNT as usize);
-
-        for i in 0..PLANE_COUNT {
-            let plane = Plane::at(i as usize);
-            events.push(Event {
-                name: plane.name,
-                cat: "Plane",
-                ph: 'B',
-                ts: plane.range.start,
-                pid: 0,
-                tid: 0,
-            });
-            events.push(Event {
-                name: plane.name,
-                cat: "Plane",
-                ph: 'E',
-                ts: plane.range.end,
-                pid: 0,
-                tid: 0,
-            });
-        }
-
-        for block in blocks {
-            events.push(Event {
-                name: block.name,
-                cat: "Block",
-                ph: 'B',
-                ts: block.range.low as u32,
-                pid: 0,
-                tid: 0,
-            });
-            events.push(Event {
-                name: block.name,
-                cat: "Block",
-                ph: 'E',
-                ts: block.range.high as u32,
-                pid: 0,
-                tid: 0,
-            });
-        }
-
-        Ok(serde_json::to_string_pretty(&events)?)
+    #[test]
+    fn test_write_segments_as_ndjson_flushing() {
+        let segments = GraphemeStream::new("abc".as_bytes(), Encoding::Utf8)
+            .select_fields(CharacterFields::from_fields(&[CharacterField::CodePoint]));
+        let mut output = FlushCounter::default();
+        write_segments_as_ndjson(segments, &mut output).unwrap();
+        assert_eq!(output.flush_count, 3);
     }
 }
@@ -0,0 +1,56 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+use std::io::Write;
+
+use clap::ArgMatches;
+
+use super::output::{parse_output_format, unsupported_output_format, write_as_json, OutputFormat};
+use super::{Error, Result};
+use crate::ucd::{NameIndex, NameMatch};
+
+pub const ARGUMENT_VALUE_NAME_QUERY: &str = "QUERY";
+
+pub const OPTION_NAME_SEARCH_LIMIT: &str = "search_limit";
+pub const DEFAULT_SEARCH_LIMIT: usize = 20;
+
+fn write_name_matches_as_text<W: Write>(name_matches: &[NameMatch], writer: &mut W) -> Result<()> {
+    for name_match in name_matches {
+        writeln!(writer, "{}", name_match)?;
+    }
+    Ok(())
+}
+
+fn search_limit(args: &ArgMatches) -> Result<usize> {
+    match args.value_of(OPTION_NAME_SEARCH_LIMIT) {
+        Some(limit) => match limit.parse() {
+            Ok(limit) => Ok(limit),
+            Err(_) => Err(Box::new(Error::InvalidSearchLimit(limit.to_owned()))),
+        },
+        None => Ok(DEFAULT_SEARCH_LIMIT),
+    }
+}
+
+pub(super) fn generate_search_output<W: Write>(args: &ArgMatches, writer: &mut W) -> Result<()> {
+    let query = args
+        .value_of(ARGUMENT_VALUE_NAME_QUERY)
+        .ok_or(Error::MissingInput)?;
+    let output_format = parse_output_format(args)?;
+    let name_matches = NameIndex::new().search(query, search_limit(args)?);
+    match output_format {
+        OutputFormat::Text => write_name_matches_as_text(&name_matches, writer),
+        OutputFormat::Json => write_as_json(&name_matches, writer),
+        output_format => unsupported_output_format(output_format),
+    }
+}
@@ -0,0 +1,384 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{self, Read};
+use std::mem;
+use std::str;
+
+use serde::Serialize;
+
+use crate::ucd::{
+    decode_sequence, CharacterFields, CodePointProperties, DecodedSequence, Encoding,
+    EncodingDiagnostic, GraphemeProperties,
+};
+
+const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Serialize, Debug)]
+#[serde(untagged)]
+pub enum Segment {
+    Grapheme(GraphemeProperties),
+    // A lone surrogate code point, which can only be decoded from WTF-8, e.g. code point inputs
+    CodePoint(CodePointProperties),
+    InvalidSequence(EncodingDiagnostic),
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Segment::Grapheme(grapheme) => grapheme.fmt(f),
+            Segment::CodePoint(code_point) => code_point.fmt(f),
+            Segment::InvalidSequence(diagnostic) => diagnostic.fmt(f),
+        }
+    }
+}
+
+/// Segments text read from `reader` into graphemes, one chunk at a time, so that inputs of any
+/// size can be processed without loading them into memory.
+///
+/// Bytes which are not valid in the given encoding don't stop the stream, they are reported as
+/// `Segment::InvalidSequence` in between the graphemes around them.
+pub struct GraphemeStream<R: Read> {
+    reader: R,
+    encoding: Encoding,
+    chunk_size: usize,
+    fields: CharacterFields,
+
+    // Bytes read but not decoded yet, this is only non-empty when an encoded sequence is split by
+    // a chunk boundary.
+    undecoded_bytes: Vec<u8>,
+    decoded_byte_count: usize,
+
+    // The last grapheme decoded so far. It is held back until more text is decoded (or the end of
+    // the input is reached) because the next chunk may still extend it, e.g. with combining marks.
+    trailing_text: String,
+
+    segments: VecDeque<Segment>,
+    reached_end: bool,
+}
+
+impl<R: Read> GraphemeStream<R> {
+    pub fn new(reader: R, encoding: Encoding) -> Self {
+        GraphemeStream::with_chunk_size(reader, encoding, DEFAULT_CHUNK_SIZE)
+    }
+
+    pub fn with_chunk_size(reader: R, encoding: Encoding, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0);
+
+        GraphemeStream {
+            reader,
+            encoding,
+            chunk_size,
+            fields: CharacterFields::all(),
+            undecoded_bytes: vec![],
+            decoded_byte_count: 0,
+            trailing_text: String::new(),
+            segments: VecDeque::new(),
+            reached_end: false,
+        }
+    }
+
+    /// Only computes `fields` of characters in the graphemes.
+    pub fn select_fields(mut self, fields: CharacterFields) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    fn read_chunk(&mut self) -> io::Result<()> {
+        let start = self.undecoded_bytes.len();
+        self.undecoded_bytes.resize(start + self.chunk_size, 0);
+        let read_count = loop {
+            match self.reader.read(&mut self.undecoded_bytes[start..]) {
+                Ok(read_count) => break read_count,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => {
+                    self.undecoded_bytes.truncate(start);
+                    return Err(error);
+                }
+            }
+        };
+        self.undecoded_bytes.truncate(start + read_count);
+
+        let reached_end = read_count == 0;
+        match self.encoding {
+            Encoding::Utf8 | Encoding::Wtf8 => self.decode_utf8(reached_end),
+            _ => self.decode_code_units(reached_end),
+        }
+        if reached_end {
+            self.reached_end = true;
+            self.flush_trailing_text();
+        } else {
+            self.segment_trailing_text();
+        }
+
+        Ok(())
+    }
+
+    fn decode_utf8(&mut self, reached_end: bool) {
+        let mut decoded_length = 0;
+        while decoded_length < self.undecoded_bytes.len() {
+            let remaining_bytes = &self.undecoded_bytes[decoded_length..];
+            let valid_length = match str::from_utf8(remaining_bytes) {
+                Ok(_) => remaining_bytes.len(),
+                Err(error) => error.valid_up_to(),
+            };
+            let valid_text = str::from_utf8(&remaining_bytes[..valid_length])
+                .expect("Valid length must end at a UTF-8 sequence boundary");
+            self.trailing_text.push_str(valid_text);
+            decoded_length += valid_length;
+
+            let invalid_bytes = &self.undecoded_bytes[decoded_length..];
+            if invalid_bytes.is_empty() {
+                break;
+            }
+            let (reason, invalid_length) =
+                match decode_sequence(self.encoding, invalid_bytes, reached_end) {
+                    Some(DecodedSequence::Invalid(reason, length)) => (reason, length),
+                    Some(DecodedSequence::Surrogate(code_point, length)) => {
+                        decoded_length += length;
+                        self.push_surrogate(code_point);
+                        continue;
+                    }
+                    // Valid characters were decoded above, so the sequence must be incomplete
+                    _ => break, // Wait for the next chunk to complete this sequence
+                };
+            let diagnostic = EncodingDiagnostic {
+                encoding: self.encoding.name(),
+                byte_offset: self.decoded_byte_count + decoded_length,
+                bytes: invalid_bytes[..invalid_length].to_vec(),
+                reason,
+            };
+            decoded_length += invalid_length;
+            self.push_diagnostic(diagnostic);
+        }
+
+        self.undecoded_bytes.drain(..decoded_length);
+        self.decoded_byte_count += decoded_length;
+    }
+
+    // Decodes encodings other than UTF-8 (and WTF-8) one sequence at a time, these are rare enough
+    // that there's no need for a fast path.
+    fn decode_code_units(&mut self, reached_end: bool) {
+        let mut decoded_length = 0;
+        while let Some(sequence) = decode_sequence(
+            self.encoding,
+            &self.undecoded_bytes[decoded_length..],
+            reached_end,
+        ) {
+            match sequence {
+                DecodedSequence::Character(chr, length) => {
+                    self.trailing_text.push(chr);
+                    decoded_length += length;
+                }
+                DecodedSequence::Surrogate(code_point, length) => {
+                    decoded_length += length;
+                    self.push_surrogate(code_point);
+                }
+                DecodedSequence::Invalid(reason, length) => {
+                    let diagnostic = EncodingDiagnostic {
+                        encoding: self.encoding.name(),
+                        byte_offset: self.decoded_byte_count + decoded_length,
+                        bytes: self.undecoded_bytes[decoded_length..decoded_length + length]
+                            .to_vec(),
+                        reason,
+                    };
+                    decoded_length += length;
+                    self.push_diagnostic(diagnostic);
+                }
+            }
+        }
+
+        self.undecoded_bytes.drain(..decoded_length);
+        self.decoded_byte_count += decoded_length;
+    }
+
+    fn push_diagnostic(&mut self, diagnostic: EncodingDiagnostic) {
+        // A grapheme never extends across invalid bytes
+        self.flush_trailing_text();
+        self.segments
+            .push_back(Segment::InvalidSequence(diagnostic));
+    }
+
+    fn push_surrogate(&mut self, code_point: u32) {
+        // A surrogate code point is not a character, so it's never part of a grapheme
+        self.flush_trailing_text();
+        self.segments
+            .push_back(Segment::CodePoint(CodePointProperties::with_fields(
+                code_point,
+                self.fields,
+            )));
+    }
+
+    fn segment_trailing_text(&mut self) {
+        let mut graphemes = GraphemeProperties::from_string(&self.trailing_text, self.fields);
+        if let Some(last_grapheme) = graphemes.pop() {
+            self.trailing_text = last_grapheme.grapheme().to_owned();
+        }
+        self.segments
+            .extend(graphemes.into_iter().map(Segment::Grapheme));
+    }
+
+    fn flush_trailing_text(&mut self) {
+        let trailing_text = mem::take(&mut self.trailing_text);
+        self.segments.extend(
+            GraphemeProperties::from_string(&trailing_text, self.fields)
+                .into_iter()
+                .map(Segment::Grapheme),
+        );
+    }
+}
+
+impl<R: Read> Iterator for GraphemeStream<R> {
+    type Item = io::Result<Segment>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.segments.is_empty() && !self.reached_end {
+            if let Err(error) = self.read_chunk() {
+                self.reached_end = true;
+                return Some(Err(error));
+            }
+        }
+        self.segments.pop_front().map(Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ucd::CharacterField;
+
+    const TEST_STR: &str = "ಠ_ರೃ ab🇨🇳🇯🇵e\u{301}\u{302}";
+
+    fn stream_segments(input: &[u8], encoding: Encoding, chunk_size: usize) -> Vec<String> {
+        GraphemeStream::with_chunk_size(input, encoding, chunk_size)
+            .map(|segment| match segment.unwrap() {
+                Segment::Grapheme(grapheme) => grapheme.grapheme().to_owned(),
+                Segment::CodePoint(code_point) => code_point.field_value(CharacterField::CodePoint),
+                Segment::InvalidSequence(diagnostic) => format!(
+                    "{:?}@{}:{}",
+                    diagnostic.reason,
+                    diagnostic.byte_offset,
+                    diagnostic.bytes.len()
+                ),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_stream_matches_from_string() {
+        let expected: Vec<String> =
+            GraphemeProperties::from_string(TEST_STR, CharacterFields::all())
+                .iter()
+                .map(|grapheme| grapheme.grapheme().to_owned())
+                .collect();
+
+        for chunk_size in 1..=TEST_STR.len() + 1 {
+            assert_eq!(
+                stream_segments(TEST_STR.as_bytes(), Encoding::Utf8, chunk_size),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_selected_fields() {
+        let fields =
+            CharacterFields::from_fields(&[CharacterField::CodePoint, CharacterField::Name]);
+        let segment = GraphemeStream::new("a".as_bytes(), Encoding::Utf8)
+            .select_fields(fields)
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_string(&segment).unwrap(),
+            r#"{"grapheme":"a","characters":[{"code_point":97,"name":"LATIN SMALL LETTER A"}]}"#
+        );
+    }
+
+    #[test]
+    fn test_stream_empty_input() {
+        assert!(stream_segments(b"", Encoding::Utf8, 4).is_empty());
+    }
+
+    #[test]
+    fn test_stream_invalid_utf8() {
+        for chunk_size in 1..=8 {
+            assert_eq!(
+                stream_segments(
+                    b"a\xC0\xAFe\xCC\x81\x80\xE2\x82",
+                    Encoding::Utf8,
+                    chunk_size
+                ),
+                vec![
+                    "a",
+                    "Overlong@1:2",
+                    "e\u{301}",
+                    "StrayContinuation@6:1",
+                    "Truncated@7:2",
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_utf16() {
+        let utf16_bytes: Vec<u8> = TEST_STR
+            .encode_utf16()
+            .flat_map(|code_unit| code_unit.to_be_bytes().to_vec())
+            .collect();
+        let expected = stream_segments(TEST_STR.as_bytes(), Encoding::Utf8, 64);
+        for chunk_size in 1..=8 {
+            assert_eq!(
+                stream_segments(&utf16_bytes, Encoding::Utf16Be, chunk_size),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_invalid_utf16() {
+        for chunk_size in 1..=8 {
+            assert_eq!(
+                stream_segments(
+                    b"\x41\x00\x3D\xD8\x42\x00\x00\xDE\x3D\xD8",
+                    Encoding::Utf16Le,
+                    chunk_size
+                ),
+                vec![
+                    "A",
+                    "UnpairedSurrogate@2:2",
+                    "B",
+                    "UnpairedSurrogate@6:2",
+                    "UnpairedSurrogate@8:2",
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_wtf8() {
+        for chunk_size in 1..=8 {
+            assert_eq!(
+                stream_segments(
+                    b"e\xED\xA0\x80\xCC\x81\xED\xBF\xBF\xC0\xAF",
+                    Encoding::Wtf8,
+                    chunk_size
+                ),
+                vec!["e", "U+D800", "\u{301}", "U+DFFF", "Overlong@9:2"]
+            );
+        }
+    }
+}
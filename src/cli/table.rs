@@ -0,0 +1,277 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Table output formats for documents, i.e. Markdown and HTML, with one row per code point.
+//!
+//! Rows of code points in the same grapheme are grouped together, the same way the text output
+//! shows a grapheme above its code points.
+//!
+
+use std::char::REPLACEMENT_CHARACTER;
+use std::io::{self, Write};
+
+use unic::ucd::GeneralCategory;
+
+use super::stream::Segment;
+use super::Result;
+use crate::ucd::{code_point_to_string, is_invisible, CharacterField, CharacterFields};
+
+const DOTTED_CIRCLE: char = '\u{25CC}';
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TableFormat {
+    Markdown,
+    Html,
+}
+
+impl TableFormat {
+    /// Escapes syntax of the table format in `text`.
+    fn escape(self, text: &str) -> String {
+        let mut escaped_text = String::with_capacity(text.len());
+        for chr in text.chars() {
+            match self {
+                TableFormat::Markdown => match chr {
+                    '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '~' | '#' => {
+                        escaped_text.push('\\');
+                        escaped_text.push(chr);
+                    }
+                    '&' => escaped_text.push_str("&amp;"),
+                    _ => escaped_text.push(chr),
+                },
+                TableFormat::Html => match chr {
+                    '&' => escaped_text.push_str("&amp;"),
+                    '<' => escaped_text.push_str("&lt;"),
+                    '>' => escaped_text.push_str("&gt;"),
+                    '"' => escaped_text.push_str("&quot;"),
+                    '\'' => escaped_text.push_str("&#39;"),
+                    _ => escaped_text.push(chr),
+                },
+            }
+        }
+        escaped_text
+    }
+
+    fn code(self, text: &str) -> String {
+        match self {
+            TableFormat::Markdown => format!("`{}`", text),
+            TableFormat::Html => format!("<code>{}</code>", text),
+        }
+    }
+
+    /// Renders characters in a cell, so that they are visible and don't break the table.
+    ///
+    /// Invisible characters are shown as their code points, and a combining mark at the start of
+    /// the cell is shown on a dotted circle instead of being combined with the cell border.
+    fn characters_cell(self, characters: &str) -> String {
+        let mut cell = String::new();
+        let mut visible_characters = String::new();
+        for (index, chr) in characters.chars().enumerate() {
+            if is_invisible(chr) {
+                cell.push_str(&self.escape(&visible_characters));
+                visible_characters.clear();
+                cell.push_str(&self.code(&code_point_to_string(chr)));
+                continue;
+            }
+            if index == 0 && GeneralCategory::of(chr).is_mark() {
+                visible_characters.push(DOTTED_CIRCLE);
+            }
+            visible_characters.push(chr);
+        }
+        cell.push_str(&self.escape(&visible_characters));
+        cell
+    }
+
+    fn write_header<W: Write>(self, fields: CharacterFields, writer: &mut W) -> Result<()> {
+        let column_names: Vec<&str> = ["grapheme"]
+            .iter()
+            .copied()
+            .chain(fields.iter().map(CharacterField::name))
+            .collect();
+        match self {
+            TableFormat::Markdown => {
+                writeln!(writer, "| {} |", column_names.join(" | "))?;
+                writeln!(writer, "|{}", " --- |".repeat(column_names.len()))?;
+            }
+            TableFormat::Html => {
+                writeln!(writer, "<table>")?;
+                writeln!(writer, "<thead>")?;
+                writeln!(
+                    writer,
+                    "<tr><th>{}</th></tr>",
+                    column_names.join("</th><th>")
+                )?;
+                writeln!(writer, "</thead>")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes rows of a grapheme (or an invalid sequence), the first cell of each row is the
+    /// grapheme, which is only shown in the first row.
+    fn write_rows<W: Write>(
+        self,
+        grapheme: &str,
+        rows: &[Vec<String>],
+        writer: &mut W,
+    ) -> Result<()> {
+        let grapheme_cell = self.characters_cell(grapheme);
+        match self {
+            TableFormat::Markdown => {
+                for (index, row) in rows.iter().enumerate() {
+                    let grapheme_cell = if index == 0 {
+                        grapheme_cell.as_str()
+                    } else {
+                        ""
+                    };
+                    writeln!(writer, "| {} | {} |", grapheme_cell, row.join(" | "))?;
+                }
+            }
+            TableFormat::Html => {
+                writeln!(writer, "<tbody>")?;
+                for (index, row) in rows.iter().enumerate() {
+                    write!(writer, "<tr>")?;
+                    if index == 0 {
+                        write!(
+                            writer,
+                            "<th rowspan=\"{}\">{}</th>",
+                            rows.len(),
+                            grapheme_cell
+                        )?;
+                    }
+                    writeln!(writer, "<td>{}</td></tr>", row.join("</td><td>"))?;
+                }
+                writeln!(writer, "</tbody>")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_footer<W: Write>(self, writer: &mut W) -> Result<()> {
+        if self == TableFormat::Html {
+            writeln!(writer, "</table>")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn write_segments_as_table<S, W>(
+    segments: S,
+    fields: CharacterFields,
+    table_format: TableFormat,
+    writer: &mut W,
+) -> Result<()>
+where
+    S: Iterator<Item = io::Result<Segment>>,
+    W: Write,
+{
+    table_format.write_header(fields, writer)?;
+
+    for segment in segments {
+        match segment? {
+            Segment::Grapheme(grapheme) => {
+                let rows: Vec<Vec<String>> = grapheme
+                    .characters()
+                    .iter()
+                    .map(|character| {
+                        fields
+                            .iter()
+                            .map(|field| match field {
+                                CharacterField::Character => {
+                                    table_format.characters_cell(&character.field_value(field))
+                                }
+                                _ => table_format.escape(&character.field_value(field)),
+                            })
+                            .collect()
+                    })
+                    .collect();
+                table_format.write_rows(grapheme.grapheme(), &rows, writer)?;
+            }
+            // Surrogate code points don't have names, their labels are shown instead
+            Segment::CodePoint(code_point) => {
+                let row: Vec<String> = fields
+                    .iter()
+                    .map(|field| match field {
+                        CharacterField::Name => table_format.escape(&code_point.label()),
+                        _ => table_format.escape(&code_point.field_value(field)),
+                    })
+                    .collect();
+                table_format.write_rows(&REPLACEMENT_CHARACTER.to_string(), &[row], writer)?;
+            }
+            // Invalid sequences don't have code points, they are described in the name column
+            Segment::InvalidSequence(diagnostic) => {
+                let row: Vec<String> = fields
+                    .iter()
+                    .map(|field| match field {
+                        CharacterField::Name => table_format.escape(&diagnostic.description()),
+                        _ => String::new(),
+                    })
+                    .collect();
+                table_format.write_rows(&REPLACEMENT_CHARACTER.to_string(), &[row], writer)?;
+            }
+        }
+    }
+
+    table_format.write_footer(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_characters_cell() {
+        assert_eq!(TableFormat::Markdown.characters_cell("a|b"), "a\\|b");
+        assert_eq!(TableFormat::Markdown.characters_cell("\t"), "`U+0009`");
+        assert_eq!(
+            TableFormat::Markdown.characters_cell("a\u{200D}b"),
+            "a`U+200D`b"
+        );
+        assert_eq!(
+            TableFormat::Markdown.characters_cell("\u{301}"),
+            "\u{25CC}\u{301}"
+        );
+        assert_eq!(TableFormat::Html.characters_cell("<"), "&lt;");
+        assert_eq!(
+            TableFormat::Html.characters_cell(" "),
+            "<code>U+0020</code>"
+        );
+    }
+
+    #[test]
+    fn test_write_rows() {
+        let rows = vec![vec!["U+0065".to_owned()], vec!["U+0301".to_owned()]];
+
+        let mut markdown = Vec::new();
+        TableFormat::Markdown
+            .write_rows("e\u{301}", &rows, &mut markdown)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(markdown).unwrap(),
+            "| e\u{301} | U+0065 |\n|  | U+0301 |\n"
+        );
+
+        let mut html = Vec::new();
+        TableFormat::Html
+            .write_rows("e\u{301}", &rows, &mut html)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(html).unwrap(),
+            "<tbody>\n\
+             <tr><th rowspan=\"2\">e\u{301}</th><td>U+0065</td></tr>\n\
+             <tr><td>U+0301</td></tr>\n\
+             </tbody>\n"
+        );
+    }
+}
@@ -0,0 +1,502 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! The text output format, which shows each grapheme above a tree of its code points.
+//!
+//! Columns are aligned by their display width, and characters without a visible glyph of their
+//! own are replaced with placeholders, so that the output never messes up the terminal.
+//!
+
+use std::char::REPLACEMENT_CHARACTER;
+use std::io::{self, Write};
+
+use crossterm::style::{Color, Stylize};
+use unic::segment::Graphemes;
+use unic::ucd::GeneralCategory;
+
+use super::stream::Segment;
+use super::Result;
+use crate::ucd::{
+    code_point_label, code_point_to_string, grapheme_display_width, is_invisible,
+    text_display_width, CharacterField, CharacterFields, CharacterProperties, CodePointProperties,
+    EncodingDiagnostic, TREE_GRAPH_CORNER, TREE_GRAPH_EDGE,
+};
+
+const DOTTED_CIRCLE: char = '\u{25CC}';
+const MIDDLE_DOT: char = '\u{00B7}';
+const ELLIPSIS: char = '\u{2026}';
+
+// Widths of columns, in terminal cells
+const CODE_POINT_COLUMN_WIDTH: usize = 8; // U+10FFFF
+const CHARACTER_COLUMN_WIDTH: usize = 2;
+const UTF8_COLUMN_WIDTH: usize = 17; // utf-8:F0 9F 98 80
+const UTF16_COLUMN_WIDTH: usize = 16; // utf-16:D83D DE00
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum CharacterKind {
+    Regular,
+    CombiningMark,
+    Control,
+    Invisible,
+    Unassigned,
+}
+
+impl CharacterKind {
+    fn of(chr: char) -> Self {
+        let general_category = GeneralCategory::of(chr);
+        if general_category == GeneralCategory::Unassigned {
+            CharacterKind::Unassigned
+        } else if general_category == GeneralCategory::Control {
+            CharacterKind::Control
+        } else if is_invisible(chr) {
+            CharacterKind::Invisible
+        } else if general_category.is_mark() {
+            CharacterKind::CombiningMark
+        } else {
+            CharacterKind::Regular
+        }
+    }
+
+    fn color(self) -> Option<Color> {
+        match self {
+            CharacterKind::Regular => None,
+            CharacterKind::CombiningMark => Some(Color::Yellow),
+            CharacterKind::Control | CharacterKind::Invisible => Some(Color::Red),
+            CharacterKind::Unassigned => Some(Color::Magenta),
+        }
+    }
+}
+
+/// Returns what to print in place of `chr`, which is `chr` itself unless it has no visible glyph
+/// of its own.
+fn visible_character(chr: char, kind: CharacterKind) -> String {
+    match kind {
+        CharacterKind::Regular | CharacterKind::CombiningMark => chr.to_string(),
+        CharacterKind::Control => match chr {
+            // Control Pictures, e.g. U+2409 SYMBOL FOR HORIZONTAL TABULATION
+            '\u{0}'..='\u{1F}' => char::from_u32(0x2400 + chr as u32)
+                .unwrap_or(MIDDLE_DOT)
+                .to_string(),
+            '\u{7F}' => '\u{2421}'.to_string(),
+            _ => MIDDLE_DOT.to_string(),
+        },
+        CharacterKind::Invisible => MIDDLE_DOT.to_string(),
+        CharacterKind::Unassigned => REPLACEMENT_CHARACTER.to_string(),
+    }
+}
+
+/// Returns what to print in place of `chr` in a single line of text.
+pub(super) fn printable_character(chr: char) -> String {
+    visible_character(chr, CharacterKind::of(chr))
+}
+
+/// A piece of a line with an optional color.
+struct Span {
+    text: String,
+    color: Option<Color>,
+}
+
+impl Span {
+    fn new(text: String, color: Option<Color>) -> Self {
+        Span { text, color }
+    }
+
+    fn padded(text: String, width: usize, color: Option<Color>) -> Self {
+        let padding = width.saturating_sub(text_display_width(&text));
+        Span::new(format!("{}{}", text, " ".repeat(padding)), color)
+    }
+}
+
+pub struct TreeWriter {
+    fields: CharacterFields,
+    uses_color: bool,
+    // Lines longer than this are truncated, so that they don't wrap in a terminal
+    max_line_width: Option<usize>,
+}
+
+impl TreeWriter {
+    pub fn new(fields: CharacterFields, uses_color: bool, max_line_width: Option<usize>) -> Self {
+        TreeWriter {
+            fields,
+            uses_color,
+            max_line_width,
+        }
+    }
+
+    fn write_line<W: Write>(&self, spans: &[Span], writer: &mut W) -> Result<()> {
+        let mut line_width = 0;
+        for span in spans {
+            let mut text = span.text.as_str();
+            let mut truncated_text = String::new();
+            if let Some(max_line_width) = self.max_line_width {
+                let text_width = text_display_width(text);
+                if line_width + text_width > max_line_width {
+                    for grapheme in Graphemes::new(text) {
+                        let grapheme_width = grapheme_display_width(grapheme);
+                        if line_width + grapheme_width + 1 > max_line_width {
+                            break;
+                        }
+                        truncated_text.push_str(grapheme);
+                        line_width += grapheme_width;
+                    }
+                    truncated_text.push(ELLIPSIS);
+                    text = &truncated_text;
+                    line_width = max_line_width;
+                } else {
+                    line_width += text_width;
+                }
+            }
+
+            match span.color {
+                Some(color) if self.uses_color => write!(writer, "{}", text.with(color))?,
+                _ => write!(writer, "{}", text)?,
+            }
+            if !truncated_text.is_empty() {
+                break;
+            }
+        }
+        writeln!(writer)?;
+        Ok(())
+    }
+
+    fn grapheme_spans(grapheme: &str) -> Vec<Span> {
+        grapheme
+            .chars()
+            .enumerate()
+            .map(|(index, chr)| {
+                let kind = CharacterKind::of(chr);
+                let text = match kind {
+                    // A leading combining mark has nothing to combine with
+                    CharacterKind::CombiningMark if index == 0 => {
+                        format!("{}{}", DOTTED_CIRCLE, chr)
+                    }
+                    CharacterKind::CombiningMark => chr.to_string(),
+                    _ => visible_character(chr, kind),
+                };
+                Span::new(text, kind.color())
+            })
+            .collect()
+    }
+
+    fn character_spans(&self, character: &CharacterProperties) -> Vec<Span> {
+        let kind = CharacterKind::of(character.character);
+        let character_text = match kind {
+            CharacterKind::CombiningMark => format!("{}{}", DOTTED_CIRCLE, character.character),
+            _ => visible_character(character.character, kind),
+        };
+
+        let mut spans = vec![Span::padded(
+            code_point_to_string(character.character),
+            CODE_POINT_COLUMN_WIDTH,
+            Some(Color::Cyan),
+        )];
+
+        // Without a selection of fields, a brief summary is shown
+        if self.fields == CharacterFields::all() {
+            spans.push(Span::new("  ".to_owned(), None));
+            spans.push(Span::padded(
+                character_text,
+                CHARACTER_COLUMN_WIDTH,
+                kind.color(),
+            ));
+            spans.push(Span::new("  ".to_owned(), None));
+            spans.push(Span::padded(
+                format!("utf-8:{}", character.field_value(CharacterField::Utf8)),
+                UTF8_COLUMN_WIDTH,
+                Some(Color::DarkGrey),
+            ));
+            spans.push(Span::new("  ".to_owned(), None));
+            spans.push(Span::padded(
+                format!("utf-16:{}", character.field_value(CharacterField::Utf16)),
+                UTF16_COLUMN_WIDTH,
+                Some(Color::DarkGrey),
+            ));
+            spans.push(Span::new("  ".to_owned(), None));
+            // Code points without a name are described by their labels, e.g. "<reserved-0378>"
+            let label = || code_point_label(character.code_point).unwrap_or_default();
+            spans.push(match kind {
+                CharacterKind::Unassigned => Span::new(label(), kind.color()),
+                CharacterKind::Control if character.name.is_empty() => {
+                    Span::new("<control>".to_owned(), kind.color())
+                }
+                _ if character.name.is_empty() => Span::new(label(), None),
+                _ => Span::new(character.name.clone(), None),
+            });
+            return spans;
+        }
+
+        for field in self
+            .fields
+            .iter()
+            .filter(|field| *field != CharacterField::CodePoint)
+        {
+            spans.push(Span::new(
+                format!("  {}: ", field.name()),
+                Some(Color::DarkGrey),
+            ));
+            match field {
+                CharacterField::Character => {
+                    spans.push(Span::new(character_text.clone(), kind.color()))
+                }
+                _ => spans.push(Span::new(
+                    character
+                        .field_value(field)
+                        .chars()
+                        .map(|chr| {
+                            if chr.is_control() {
+                                visible_character(chr, CharacterKind::Control)
+                            } else {
+                                chr.to_string()
+                            }
+                        })
+                        .collect(),
+                    None,
+                )),
+            }
+        }
+        spans
+    }
+
+    // Surrogate code points are shown like unassigned characters, with only the fields which apply
+    // to them.
+    fn code_point_spans(&self, code_point: &CodePointProperties) -> Vec<Span> {
+        let color = CharacterKind::Unassigned.color();
+        let mut spans = vec![Span::padded(
+            code_point.field_value(CharacterField::CodePoint),
+            CODE_POINT_COLUMN_WIDTH,
+            Some(Color::Cyan),
+        )];
+
+        // Without a selection of fields, a brief summary is shown
+        if self.fields == CharacterFields::all() {
+            spans.push(Span::new("  ".to_owned(), None));
+            spans.push(Span::padded(
+                REPLACEMENT_CHARACTER.to_string(),
+                CHARACTER_COLUMN_WIDTH,
+                color,
+            ));
+            // Surrogate code points can't be encoded in UTF-8
+            spans.push(Span::new(" ".repeat(2 + UTF8_COLUMN_WIDTH + 2), None));
+            spans.push(Span::padded(
+                format!("utf-16:{}", code_point.field_value(CharacterField::Utf16)),
+                UTF16_COLUMN_WIDTH,
+                Some(Color::DarkGrey),
+            ));
+            spans.push(Span::new("  ".to_owned(), None));
+            spans.push(Span::new(code_point.label(), color));
+            return spans;
+        }
+
+        for field in code_point
+            .fields()
+            .iter()
+            .filter(|field| *field != CharacterField::CodePoint)
+        {
+            spans.push(Span::new(
+                format!("  {}: ", field.name()),
+                Some(Color::DarkGrey),
+            ));
+            spans.push(Span::new(code_point.field_value(field), None));
+        }
+        spans
+    }
+
+    fn diagnostic_spans(diagnostic: &EncodingDiagnostic) -> Vec<Span> {
+        vec![Span::new(diagnostic.description(), Some(Color::Red))]
+    }
+
+    fn write_tree<W: Write>(&self, rows: Vec<Vec<Span>>, writer: &mut W) -> Result<()> {
+        let row_count = rows.len();
+        for (index, mut row) in rows.into_iter().enumerate() {
+            let tree_graph = if index + 1 == row_count {
+                TREE_GRAPH_CORNER
+            } else {
+                TREE_GRAPH_EDGE
+            };
+            row.insert(0, Span::new(tree_graph.to_owned(), Some(Color::DarkGrey)));
+            self.write_line(&row, writer)?;
+        }
+        Ok(())
+    }
+
+    fn write_segment<W: Write>(&self, segment: &Segment, writer: &mut W) -> Result<()> {
+        match segment {
+            Segment::Grapheme(grapheme) => {
+                let mut spans = TreeWriter::grapheme_spans(grapheme.grapheme());
+                if let Some(emoji_sequence) = grapheme.emoji_sequence() {
+                    spans.push(Span::new(
+                        format!("  {}", emoji_sequence),
+                        Some(Color::DarkGrey),
+                    ));
+                }
+                // The brief summary shown without a selection of fields omits the width
+                if let Some(display_width) = grapheme.display_width() {
+                    if self.fields != CharacterFields::all() {
+                        spans.push(Span::new(
+                            format!("  {}: ", CharacterField::DisplayWidth.name()),
+                            Some(Color::DarkGrey),
+                        ));
+                        spans.push(Span::new(display_width.to_string(), None));
+                    }
+                }
+                self.write_line(&spans, writer)?;
+                let rows = grapheme
+                    .characters()
+                    .iter()
+                    .map(|character| self.character_spans(character))
+                    .collect();
+                self.write_tree(rows, writer)
+            }
+            Segment::CodePoint(code_point) => {
+                self.write_line(
+                    &[Span::new(
+                        REPLACEMENT_CHARACTER.to_string(),
+                        CharacterKind::Unassigned.color(),
+                    )],
+                    writer,
+                )?;
+                self.write_tree(vec![self.code_point_spans(code_point)], writer)
+            }
+            Segment::InvalidSequence(diagnostic) => {
+                self.write_line(
+                    &[Span::new(
+                        REPLACEMENT_CHARACTER.to_string(),
+                        Some(Color::Red),
+                    )],
+                    writer,
+                )?;
+                self.write_tree(vec![TreeWriter::diagnostic_spans(diagnostic)], writer)
+            }
+        }
+    }
+
+    pub fn write_segments<S, W>(&self, segments: S, writer: &mut W) -> Result<()>
+    where
+        S: Iterator<Item = io::Result<Segment>>,
+        W: Write,
+    {
+        for (index, segment) in segments.enumerate() {
+            if index > 0 {
+                writeln!(writer)?;
+            }
+            self.write_segment(&segment?, writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::stream::GraphemeStream;
+    use crate::ucd::Encoding;
+
+    fn tree(input: &str, fields: CharacterFields, max_line_width: Option<usize>) -> String {
+        let mut output = Vec::new();
+        TreeWriter::new(fields, false, max_line_width)
+            .write_segments(
+                GraphemeStream::new(input.as_bytes(), Encoding::Utf8).select_fields(fields),
+                &mut output,
+            )
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_columns_are_aligned() {
+        assert_eq!(
+            tree("e\u{301}\u{1F600}", CharacterFields::all(), None),
+            "e\u{301}\n\
+             ├── U+0065    e   utf-8:65           utf-16:0065       LATIN SMALL LETTER E\n\
+             └── U+0301    \u{25CC}\u{301}   utf-8:CC 81        utf-16:0301       COMBINING ACUTE ACCENT\n\
+             \n\
+             \u{1F600}\n\
+             └── U+1F600   \u{1F600}  utf-8:F0 9F 98 80  utf-16:D83D DE00  GRINNING FACE\n"
+        );
+    }
+
+    #[test]
+    fn test_control_characters_are_replaced() {
+        assert_eq!(
+            tree("\t", CharacterFields::all(), None),
+            "\u{2409}\n└── U+0009    \u{2409}   utf-8:09           utf-16:0009       <control>\n"
+        );
+    }
+
+    #[test]
+    fn test_code_points_without_names_are_labelled() {
+        assert_eq!(
+            tree("\u{E000}\u{FFFF}", CharacterFields::all(), None),
+            "\u{E000}\n\
+             └── U+E000    \u{E000}   utf-8:EE 80 80     utf-16:E000       <private-use-E000>\n\
+             \n\
+             \u{FFFD}\n\
+             └── U+FFFF    \u{FFFD}   utf-8:EF BF BF     utf-16:FFFF       <noncharacter-FFFF>\n"
+        );
+    }
+
+    #[test]
+    fn test_surrogate_code_points() {
+        let mut output = Vec::new();
+        let fields =
+            CharacterFields::from_fields(&[CharacterField::CodePoint, CharacterField::LineBreak]);
+        TreeWriter::new(fields, false, None)
+            .write_segments(
+                GraphemeStream::new(&b"\xED\xA0\x80"[..], Encoding::Wtf8).select_fields(fields),
+                &mut output,
+            )
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\u{FFFD}\n└── U+D800    line_break: SG\n"
+        );
+    }
+
+    #[test]
+    fn test_lines_are_truncated() {
+        let fields =
+            CharacterFields::from_fields(&[CharacterField::CodePoint, CharacterField::Name]);
+        assert_eq!(
+            tree("a", fields, Some(24)),
+            "a\n└── U+0061    name: LAT\u{2026}\n"
+        );
+    }
+
+    #[test]
+    fn test_emoji_sequences_are_described() {
+        let fields =
+            CharacterFields::from_fields(&[CharacterField::CodePoint, CharacterField::IsEmoji]);
+        assert_eq!(
+            tree("\u{1F1E8}\u{1F1F3}", fields, None),
+            "\u{1F1E8}\u{1F1F3}  flag sequence: CN\n\
+             ├── U+1F1E8   is_emoji: true\n\
+             └── U+1F1F3   is_emoji: true\n"
+        );
+    }
+
+    #[test]
+    fn test_grapheme_display_width() {
+        let fields = CharacterFields::from_fields(&[
+            CharacterField::CodePoint,
+            CharacterField::DisplayWidth,
+        ]);
+        assert_eq!(
+            tree("\u{2764}\u{FE0F}", fields, None),
+            "\u{2764}\u{FE0F}  display_width: 2\n\
+             ├── U+2764    display_width: 1\n\
+             └── U+FE0F    display_width: 0\n"
+        );
+    }
+}
@@ -14,7 +14,9 @@
 
 #![warn(clippy::all)]
 
-use clap::{crate_version, App, Arg, ArgMatches};
+use std::io::{self, BufWriter, Write};
+
+use clap::{crate_version, App, AppSettings, Arg, ArgMatches, SubCommand};
 use unic::ucd::version::UNICODE_VERSION;
 
 mod cli;
@@ -25,9 +27,14 @@ mod ucd;
 
 use cli::Result;
 
-fn run_tui(args: ArgMatches) -> Result<()> {
+fn run_tui(args: &ArgMatches) -> Result<()> {
+    let initial_code_points = match cli::parse_optional_input(args)? {
+        Some(input) => input.read_to_code_points()?,
+        None => vec![],
+    };
+
     let mut state = tui::ApplicationState::default();
-    let mut main_view = tui::MainView::new(cli::parse_input(&args)?.to_string());
+    let mut main_view = tui::MainView::new(initial_code_points);
 
     let run_result = tui::run(|terminal| {
         main_view.update(terminal, &mut state)?;
@@ -40,16 +47,25 @@ fn run_tui(args: ArgMatches) -> Result<()> {
     }
 }
 
-fn run_cli(args: ArgMatches) -> Result<()> {
-    println!("{}", cli::generate_output(args)?);
+fn run_cli(args: &ArgMatches) -> Result<()> {
+    let stdout = io::stdout();
+    let mut writer = BufWriter::new(stdout.lock());
+    cli::generate_output(args, &mut writer)?;
+    writer.flush()?;
     Ok(())
 }
 
 fn main() -> Result<()> {
     let args = create_cli!().get_matches();
-    if args.is_present(cli::FLAG_NAME_TUI_MODE) {
-        run_tui(args)
-    } else {
-        run_cli(args)
+    match args.subcommand() {
+        (cli::SUBCOMMAND_NAME_TUI, Some(tui_args)) => run_tui(tui_args),
+        (_, Some(_)) => run_cli(&args),
+        _ => {
+            if args.is_present(cli::FLAG_NAME_TUI_MODE) {
+                run_tui(&args)
+            } else {
+                run_cli(&args)
+            }
+        }
     }
 }
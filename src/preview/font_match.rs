@@ -14,11 +14,13 @@
 
 use std::path::PathBuf;
 
+use serde::Serialize;
+
 use super::{Error, Result};
 use crate::settings::Settings;
 
 #[non_exhaustive]
-#[derive(Clone)]
+#[derive(Clone, Serialize)]
 pub struct FontDescriptor {
     pub path: PathBuf,
     pub family_name: String,
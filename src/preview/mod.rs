@@ -24,6 +24,7 @@ mod font_match;
 mod stateful_vec;
 
 pub use character_preview::{CharacterPreview, RenderSize, RenderedCharacter};
+pub use font_match::{fonts_for, FontDescriptor};
 
 use std::error;
 use std::fmt;
@@ -14,7 +14,7 @@
 
 #[allow(clippy::module_inception)] // Can't think of a better name than "settings"
 mod settings;
-pub use settings::Settings;
+pub use settings::{CodePointRange, Settings};
 
 pub fn get_settings() -> Settings {
     match read_settings_file() {
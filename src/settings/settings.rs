@@ -12,15 +12,17 @@
 // You should have received a copy of the GNU General Public License along with
 // Cicero. If not, see <https://www.gnu.org/licenses/>.
 
-use std::collections::BTreeSet;
 use std::fmt;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use unic::ucd::{Block, BlockIter};
 
-use crate::ucd::{code_point_to_string, string_to_code_point, Plane};
+use crate::ucd::{
+    block_named, code_point_to_string, names_match, string_to_code_point, Plane, Range, PLANE_COUNT,
+};
 
 #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Settings {
@@ -28,6 +30,11 @@ pub struct Settings {
     pub use_fontconfig: Option<bool>,
 
     pub font_search_paths: Option<Vec<PathBuf>>,
+
+    // The directory of the extracted Unihan.zip, which contains the Unihan*.txt files. This must be
+    // declared before preview_fonts, as TOML values can't be serialized after arrays of tables
+    pub unihan_data_path: Option<PathBuf>,
+
     pub preview_fonts: Option<Vec<PreviewFontSetting>>,
 }
 
@@ -38,6 +45,7 @@ impl Default for Settings {
             use_fontconfig: Some(true),
 
             font_search_paths: None,
+            unihan_data_path: None,
             preview_fonts: None,
         }
     }
@@ -66,7 +74,9 @@ impl Settings {
             .filter(
                 |preview_font_setting| match &preview_font_setting.code_point_range {
                     Some(code_point_range) => match code_point_range {
-                        CodePointRange::Raw { first, last } => *first <= chr && chr >= *last,
+                        CodePointRange::Raw { first, last } => {
+                            *first <= chr as u32 && chr as u32 <= *last
+                        }
                         CodePointRange::Plane { name } => name == character_plane.name,
                         CodePointRange::Block { name } => match character_block {
                             Some(block) => name == block.name,
@@ -89,12 +99,109 @@ pub struct PreviewFontSetting {
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum CodePointRange {
-    Raw { first: char, last: char },
+    Raw { first: u32, last: u32 },
     Plane { name: String },
     Block { name: String },
 }
 
 const CODE_POINT_RANGE_SEPARATOR: &str = "..";
+const CODE_POINT_RANGE_PREFIX_PLANE: &str = "plane:";
+const CODE_POINT_RANGE_PREFIX_BLOCK: &str = "block:";
+
+impl CodePointRange {
+    pub fn range(&self) -> Option<Range> {
+        match self {
+            CodePointRange::Raw { first, last } => Some(Range {
+                start: *first,
+                end: *last,
+            }),
+            CodePointRange::Plane { name } => Plane::all_plane_names()
+                .iter()
+                .position(|plane_name| plane_name == name)
+                .map(|index| Plane::at(index).range),
+            CodePointRange::Block { name } => BlockIter::new()
+                .find(|block| block.name == name)
+                .map(|block| Range {
+                    start: block.range.low as u32,
+                    end: block.range.high as u32,
+                }),
+        }
+    }
+
+    /// Iterates over all code points in the range, including surrogate code points.
+    pub fn code_points(&self) -> impl Iterator<Item = u32> {
+        self.range()
+            .into_iter()
+            .flat_map(|range| range.start..=range.end)
+    }
+}
+
+fn plane_named(name: &str) -> Option<CodePointRange> {
+    Plane::all_plane_names()
+        .iter()
+        .find(|plane_name| names_match(plane_name, name))
+        .map(|plane_name| CodePointRange::Plane {
+            name: (*plane_name).to_owned(),
+        })
+}
+
+fn plane_numbered(number: &str) -> Option<CodePointRange> {
+    let index = number.trim().parse::<usize>().ok()?;
+    if index >= PLANE_COUNT as usize {
+        return None;
+    }
+    Some(CodePointRange::Plane {
+        name: Plane::at(index).name.to_owned(),
+    })
+}
+
+fn block_range_named(name: &str) -> Option<CodePointRange> {
+    block_named(name).map(|block| CodePointRange::Block {
+        name: block.name.to_owned(),
+    })
+}
+
+impl FromStr for CodePointRange {
+    type Err = String;
+
+    /// Parses either an inclusive range (e.g. "U+0400..U+04FF"), a plane name, or a block name.
+    ///
+    /// Plane and block names can be prefixed with "plane:" and "block:" respectively to avoid
+    /// ambiguity, planes can also be referred to by their numbers this way, e.g. "plane:1".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut range_components = s.split(CODE_POINT_RANGE_SEPARATOR);
+        if let (Some(first_str), Some(last_str)) =
+            (range_components.next(), range_components.next())
+        {
+            let first = string_to_code_point(first_str.trim())
+                .ok_or_else(|| format!("Invalid first code point for range: '{}'", first_str))?;
+            let last = string_to_code_point(last_str.trim())
+                .ok_or_else(|| format!("Invalid last code point for range: '{}'", last_str))?;
+            if first > last {
+                return Err(format!(
+                    "Invalid range, first code point is after last: '{}'",
+                    s
+                ));
+            }
+            return Ok(CodePointRange::Raw { first, last });
+        }
+
+        if let Some(plane) = s.strip_prefix(CODE_POINT_RANGE_PREFIX_PLANE) {
+            return plane_numbered(plane)
+                .or_else(|| plane_named(plane))
+                .ok_or_else(|| format!("Unrecognized plane: '{}'", plane));
+        }
+
+        if let Some(block) = s.strip_prefix(CODE_POINT_RANGE_PREFIX_BLOCK) {
+            return block_range_named(block)
+                .ok_or_else(|| format!("Unrecognized block: '{}'", block));
+        }
+
+        plane_named(s)
+            .or_else(|| block_range_named(s))
+            .ok_or_else(|| format!("Unrecognized code point range: '{}'", s))
+    }
+}
 
 impl Serialize for CodePointRange {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -119,36 +226,7 @@ impl<'de> Deserialize<'de> for CodePointRange {
     where
         D: Deserializer<'de>,
     {
-        struct CodePointRangeVisitor {
-            plane_names: BTreeSet<&'static str>,
-            block_names: BTreeSet<&'static str>,
-        }
-
-        impl CodePointRangeVisitor {
-            fn new() -> Self {
-                let plane_names = {
-                    let mut set = BTreeSet::new();
-                    for name in Plane::all_plane_names() {
-                        set.insert(*name);
-                    }
-                    set
-                };
-
-                let block_names = {
-                    let block_iter = BlockIter::new();
-                    let mut set = BTreeSet::new();
-                    for name in block_iter {
-                        set.insert(name.name);
-                    }
-                    set
-                };
-
-                CodePointRangeVisitor {
-                    plane_names,
-                    block_names,
-                }
-            }
-        }
+        struct CodePointRangeVisitor;
 
         impl<'de> Visitor<'de> for CodePointRangeVisitor {
             type Value = CodePointRange;
@@ -162,38 +240,11 @@ impl<'de> Deserialize<'de> for CodePointRange {
             where
                 E: serde::de::Error,
             {
-                let mut range_components = v.split(CODE_POINT_RANGE_SEPARATOR);
-                let first_str = range_components.next();
-                let last_str = range_components.next();
-                if first_str.is_some() && last_str.is_some() {
-                    let first = string_to_code_point(first_str.unwrap()).ok_or_else(|| {
-                        E::custom(format!(
-                            "Invalid first code point for range: '{}'",
-                            first_str.unwrap()
-                        ))
-                    })?;
-                    let last = string_to_code_point(last_str.unwrap()).ok_or_else(|| {
-                        E::custom(format!(
-                            "Invalid last code point for range: '{}'",
-                            last_str.unwrap()
-                        ))
-                    })?;
-                    return Ok(CodePointRange::Raw { first, last });
-                }
-
-                if self.plane_names.contains(v) {
-                    return Ok(CodePointRange::Plane { name: v.to_owned() });
-                }
-
-                if self.block_names.contains(v) {
-                    return Ok(CodePointRange::Block { name: v.to_owned() });
-                }
-
-                Err(E::custom(format!("Unrecognized code point range: '{}'", v)))
+                v.parse().map_err(E::custom)
             }
         }
 
-        deserializer.deserialize_str(CodePointRangeVisitor::new())
+        deserializer.deserialize_str(CodePointRangeVisitor)
     }
 }
 
@@ -224,6 +275,12 @@ mod tests {
                 PathBuf::from("C:\\test\\windows\\path2"),
             ]),
 
+            #[cfg(target_family = "unix")]
+            unihan_data_path: Some(PathBuf::from("/test/path/unihan")),
+
+            #[cfg(target_family = "windows")]
+            unihan_data_path: Some(PathBuf::from("C:\\test\\windows\\unihan")),
+
             preview_fonts: Some(vec![
                 PreviewFontSetting {
                     code_point_range: None,
@@ -231,8 +288,8 @@ mod tests {
                 },
                 PreviewFontSetting {
                     code_point_range: Some(CodePointRange::Raw {
-                        first: '\u{0020}',
-                        last: '\u{00FF}',
+                        first: 0x0020,
+                        last: 0x00FF,
                     }),
                     font_name: "TestFontName-Regular".to_owned(),
                 },
@@ -272,6 +329,129 @@ mod tests {
         assert_eq!(deserialized_settings, get_test_settings());
     }
 
+    #[test]
+    fn test_get_preview_fonts_for() {
+        let settings = Settings {
+            preview_fonts: Some(vec![
+                PreviewFontSetting {
+                    code_point_range: Some(CodePointRange::Raw {
+                        first: 0x0020,
+                        last: 0x00FF,
+                    }),
+                    font_name: "Latin".to_owned(),
+                },
+                PreviewFontSetting {
+                    code_point_range: None,
+                    font_name: "Fallback".to_owned(),
+                },
+            ]),
+            ..Settings::default()
+        };
+        assert_eq!(
+            settings.get_preview_fonts_for(' '),
+            vec!["Latin", "Fallback"]
+        );
+        assert_eq!(
+            settings.get_preview_fonts_for('a'),
+            vec!["Latin", "Fallback"]
+        );
+        assert_eq!(
+            settings.get_preview_fonts_for('\u{00FF}'),
+            vec!["Latin", "Fallback"]
+        );
+        assert_eq!(settings.get_preview_fonts_for('\u{1F}'), vec!["Fallback"]);
+        assert_eq!(settings.get_preview_fonts_for('\u{0100}'), vec!["Fallback"]);
+    }
+
+    #[test]
+    fn test_code_point_range_from_str() {
+        assert_eq!(
+            "U+0400..U+04FF".parse(),
+            Ok(CodePointRange::Raw {
+                first: 0x0400,
+                last: 0x04FF,
+            })
+        );
+        assert_eq!(
+            "block:latin_extended-a".parse(),
+            Ok(CodePointRange::Block {
+                name: "Latin Extended-A".to_owned(),
+            })
+        );
+        assert_eq!(
+            "plane:1".parse(),
+            Ok(CodePointRange::Plane {
+                name: "Supplementary Multilingual Plane".to_owned(),
+            })
+        );
+        assert_eq!(
+            "Basic Multilingual Plane".parse(),
+            Ok(CodePointRange::Plane {
+                name: "Basic Multilingual Plane".to_owned(),
+            })
+        );
+
+        assert!("U+04FF..U+0400".parse::<CodePointRange>().is_err());
+        assert!("U+0400..".parse::<CodePointRange>().is_err());
+        assert!("plane:17".parse::<CodePointRange>().is_err());
+        assert!("Not A Block".parse::<CodePointRange>().is_err());
+    }
+
+    #[test]
+    fn test_code_point_range_deserialization() {
+        #[derive(Debug, Deserialize)]
+        struct Ranges {
+            ranges: Vec<CodePointRange>,
+        }
+
+        let deserialized_ranges: Ranges = toml::from_str(
+            r#"ranges = [
+                "Cyrillic",
+                "block:latin_extended-a",
+                "Supplementary Multilingual Plane",
+                "plane:2",
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            deserialized_ranges.ranges,
+            vec![
+                CodePointRange::Block {
+                    name: "Cyrillic".to_owned(),
+                },
+                CodePointRange::Block {
+                    name: "Latin Extended-A".to_owned(),
+                },
+                CodePointRange::Plane {
+                    name: "Supplementary Multilingual Plane".to_owned(),
+                },
+                CodePointRange::Plane {
+                    name: "Supplementary Ideographic Plane".to_owned(),
+                },
+            ]
+        );
+
+        assert!(toml::from_str::<Ranges>(r#"ranges = ["Not A Block"]"#).is_err());
+        assert!(toml::from_str::<Ranges>(r#"ranges = ["plane:17"]"#).is_err());
+    }
+
+    #[test]
+    fn test_code_point_range_code_points() {
+        let range = CodePointRange::Raw {
+            first: 0xDFFE,
+            last: 0xE001,
+        };
+        assert_eq!(
+            range.code_points().collect::<Vec<u32>>(),
+            vec![0xDFFE, 0xDFFF, 0xE000, 0xE001]
+        );
+
+        let plane = CodePointRange::Plane {
+            name: "Supplementary Private Use Area (Plane 16)".to_owned(),
+        };
+        assert_eq!(plane.code_points().count(), 0x1_0000);
+    }
+
     #[cfg(not(target_family = "unix"))]
     #[test]
     fn test_use_fontconfig_is_ignored_for_non_unix_targets() {
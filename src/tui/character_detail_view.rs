@@ -27,18 +27,16 @@ pub struct CharacterDetailView {
 
 impl CharacterDetailView {
     pub fn new(
-        chr: char,
+        code_point: u32,
         selected_preview_font_path: &Option<PathBuf>,
         settings: &Settings,
     ) -> Self {
         CharacterDetailView {
-            character_preview_canvas: CharacterPreviewCanvas::try_new(
-                chr,
-                selected_preview_font_path,
-                settings,
-            )
-            .ok(),
-            character_property_view: CharacterPropertyView::new(chr),
+            // Surrogate code points aren't characters, there's nothing to preview
+            character_preview_canvas: char::from_u32(code_point).and_then(|chr| {
+                CharacterPreviewCanvas::try_new(chr, selected_preview_font_path, settings).ok()
+            }),
+            character_property_view: CharacterPropertyView::new(code_point),
         }
     }
 
@@ -20,7 +20,10 @@ use tui::style::{Color, Modifier, Style};
 use tui::widgets::{Block, Borders, List, ListState, Text};
 
 use super::main_view::TerminalFrame;
-use crate::ucd::{code_point_to_string, CharacterProperties};
+use crate::ucd::{
+    code_point_label, code_point_to_string, CharacterFields, CharacterProperties, CodePointKind,
+    CodePointProperties,
+};
 
 const NOT_AVAILABLE_DISPLAY_TEXT: &str = "N/A";
 
@@ -61,7 +64,15 @@ impl PropertyRow {
                 "Code Point",
                 code_point_to_string(character_properties.character),
             ),
-            PropertyRow::new("Name", character_properties.name.clone()),
+            PropertyRow::new(
+                "Name",
+                if character_properties.name.is_empty() {
+                    // e.g. "<private-use-E000>" for code points without a name
+                    code_point_label(character_properties.code_point).unwrap_or_default()
+                } else {
+                    character_properties.name.clone()
+                },
+            ),
             PropertyRow::new(
                 "Age",
                 format!(
@@ -84,9 +95,25 @@ impl PropertyRow {
                 "General Category",
                 character_properties.general_category.to_string(),
             ),
-            PropertyRow::default(),
+            PropertyRow::new(
+                "Code Point Kind",
+                character_properties
+                    .code_point_kind
+                    .map(|kind| kind.to_string())
+                    .unwrap_or_else(|| NOT_AVAILABLE_DISPLAY_TEXT.to_owned()),
+            ),
+            PropertyRow::new("Script", character_properties.script.to_string()),
         ];
 
+        for (index, script) in character_properties.script_extensions.iter().enumerate() {
+            property_rows.push(PropertyRow::new(
+                if index == 0 { "Script Extensions" } else { "" },
+                script.to_string(),
+            ));
+        }
+
+        property_rows.push(PropertyRow::default());
+
         property_rows.extend(PropertyRow::from_name_aliases(
             "Name Corrections",
             character_properties.name_corrections,
@@ -122,6 +149,42 @@ impl PropertyRow {
             "Lowercase",
             &character_properties.lowercase.as_ref(),
         ));
+        property_rows.extend(PropertyRow::from_optional_character_components(
+            "Titlecase",
+            &character_properties.titlecase.as_ref(),
+        ));
+        property_rows.extend(PropertyRow::from_optional_character_components(
+            "Case Folding",
+            &character_properties.case_folding.as_ref(),
+        ));
+        property_rows.push(PropertyRow::from_optional_character(
+            "Simple Case Folding",
+            character_properties.simple_case_folding,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Changes When Lowercased",
+            character_properties.changes_when_lowercased,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Changes When Uppercased",
+            character_properties.changes_when_uppercased,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Changes When Titlecased",
+            character_properties.changes_when_titlecased,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Changes When Casefolded",
+            character_properties.changes_when_casefolded,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Changes When Casemapped",
+            character_properties.changes_when_casemapped,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Changes When NFKC Casefolded",
+            character_properties.changes_when_nfkc_casefolded,
+        ));
 
         property_rows.push(PropertyRow::default());
 
@@ -137,8 +200,8 @@ impl PropertyRow {
                     decomposition.decomposition_type.to_string(),
                 ));
                 property_rows.extend(PropertyRow::from_character_components(
-                    "Decompositions",
-                    &decomposition.components,
+                    "Decomposition Mapping",
+                    &decomposition.mapping,
                 ));
             }
             None => {
@@ -147,11 +210,49 @@ impl PropertyRow {
                     NOT_AVAILABLE_DISPLAY_TEXT.to_owned(),
                 ));
                 property_rows.push(PropertyRow::new(
-                    "Decompositions",
+                    "Decomposition Mapping",
                     NOT_AVAILABLE_DISPLAY_TEXT.to_owned(),
                 ));
             }
         }
+        property_rows.extend(PropertyRow::from_optional_character_components(
+            "Canonical Decomposition",
+            &character_properties.canonical_decomposition.as_ref(),
+        ));
+        property_rows.extend(PropertyRow::from_optional_character_components(
+            "Compatibility Decomposition",
+            &character_properties.compatibility_decomposition.as_ref(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "NFC Quick Check",
+            character_properties.nfc_quick_check.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "NFD Quick Check",
+            character_properties.nfd_quick_check.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "NFKC Quick Check",
+            character_properties.nfkc_quick_check.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "NFKD Quick Check",
+            character_properties.nfkd_quick_check.to_string(),
+        ));
+
+        property_rows.push(PropertyRow::default());
+
+        property_rows.push(PropertyRow::new(
+            "Numeric Type",
+            character_properties.numeric_type.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "Numeric Value",
+            character_properties
+                .numeric_value
+                .map(|numeric_value| numeric_value.to_string())
+                .unwrap_or_else(|| NOT_AVAILABLE_DISPLAY_TEXT.to_owned()),
+        ));
 
         property_rows.push(PropertyRow::default());
 
@@ -170,6 +271,168 @@ impl PropertyRow {
 
         property_rows.push(PropertyRow::default());
 
+        property_rows.push(PropertyRow::new(
+            "Grapheme Cluster Break",
+            character_properties.grapheme_cluster_break.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "Word Break",
+            character_properties.word_break.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "Sentence Break",
+            character_properties.sentence_break.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "Line Break",
+            character_properties.line_break.to_string(),
+        ));
+
+        property_rows.push(PropertyRow::default());
+
+        property_rows.push(PropertyRow::new(
+            "East Asian Width",
+            character_properties.east_asian_width.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "Display Width",
+            match character_properties.display_width {
+                1 => "1 column".to_owned(),
+                width => format!("{} columns", width),
+            },
+        ));
+
+        property_rows.push(PropertyRow::default());
+
+        property_rows.push(PropertyRow::new(
+            "Joining Type",
+            character_properties.joining_type.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "Joining Group",
+            character_properties.joining_group.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "Hangul Syllable Type",
+            character_properties.hangul_syllable_type.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "Indic Syllabic Category",
+            character_properties.indic_syllabic_category.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "Indic Positional Category",
+            character_properties.indic_positional_category.to_string(),
+        ));
+        property_rows.push(PropertyRow::new(
+            "Vertical Orientation",
+            character_properties.vertical_orientation.to_string(),
+        ));
+
+        property_rows.push(PropertyRow::default());
+
+        property_rows.push(PropertyRow::from_bool(
+            "Is Emoji",
+            character_properties.is_emoji,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Emoji Presentation",
+            character_properties.is_emoji_presentation,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Emoji Modifier",
+            character_properties.is_emoji_modifier,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Emoji Modifier Base",
+            character_properties.is_emoji_modifier_base,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Emoji Component",
+            character_properties.is_emoji_component,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Extended Pictographic",
+            character_properties.is_extended_pictographic,
+        ));
+
+        property_rows.push(PropertyRow::default());
+
+        property_rows.push(PropertyRow::from_bool(
+            "Is Alphabetic",
+            character_properties.is_alphabetic,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is White Space",
+            character_properties.is_white_space,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Default Ignorable Code Point",
+            character_properties.is_default_ignorable_code_point,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Noncharacter Code Point",
+            character_properties.is_noncharacter_code_point,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is ID Start",
+            character_properties.is_id_start,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is ID Continue",
+            character_properties.is_id_continue,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is XID Start",
+            character_properties.is_xid_start,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is XID Continue",
+            character_properties.is_xid_continue,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Pattern Syntax",
+            character_properties.is_pattern_syntax,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Pattern White Space",
+            character_properties.is_pattern_white_space,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Dash",
+            character_properties.is_dash,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Hyphen",
+            character_properties.is_hyphen,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Quotation Mark",
+            character_properties.is_quotation_mark,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Diacritic",
+            character_properties.is_diacritic,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Extender",
+            character_properties.is_extender,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Join Control",
+            character_properties.is_join_control,
+        ));
+        property_rows.push(PropertyRow::from_bool(
+            "Is Variation Selector",
+            character_properties.is_variation_selector,
+        ));
+
+        property_rows.push(PropertyRow::default());
+
+        property_rows.push(PropertyRow::from_optional_str(
+            "Definition",
+            character_properties.definition,
+        ));
         property_rows.push(PropertyRow::new(
             "Mandarin",
             character_properties
@@ -177,6 +440,38 @@ impl PropertyRow {
                 .unwrap_or(NOT_AVAILABLE_DISPLAY_TEXT)
                 .to_owned(),
         ));
+        property_rows.push(PropertyRow::from_optional_str(
+            "Cantonese",
+            character_properties.cantonese,
+        ));
+        property_rows.push(PropertyRow::from_optional_str(
+            "Japanese On",
+            character_properties.japanese_on,
+        ));
+        property_rows.push(PropertyRow::from_optional_str(
+            "Japanese Kun",
+            character_properties.japanese_kun,
+        ));
+        property_rows.push(PropertyRow::from_optional_str(
+            "Korean",
+            character_properties.korean,
+        ));
+        property_rows.push(PropertyRow::from_optional_str(
+            "Hangul Reading",
+            character_properties.hangul_reading,
+        ));
+        property_rows.push(PropertyRow::from_optional_str(
+            "Vietnamese",
+            character_properties.vietnamese,
+        ));
+        property_rows.push(PropertyRow::from_optional_str(
+            "Radical-Stroke Counts",
+            character_properties.radical_stroke_counts,
+        ));
+        property_rows.push(PropertyRow::from_optional_str(
+            "Total Strokes",
+            character_properties.total_strokes,
+        ));
         property_rows.push(PropertyRow::from_optional_character(
             "Traditional Variant",
             character_properties.traditional_variant,
@@ -185,6 +480,18 @@ impl PropertyRow {
             "Simplified Variant",
             character_properties.simplified_variant,
         ));
+        property_rows.extend(PropertyRow::from_variants(
+            "Semantic Variants",
+            character_properties.semantic_variants,
+        ));
+        property_rows.extend(PropertyRow::from_variants(
+            "Specialized Semantic Variants",
+            character_properties.specialized_semantic_variants,
+        ));
+        property_rows.extend(PropertyRow::from_variants(
+            "Z-Variants",
+            character_properties.z_variants,
+        ));
 
         property_rows.push(PropertyRow::default());
 
@@ -200,6 +507,38 @@ impl PropertyRow {
         property_rows
     }
 
+    fn from_code_point_properties(code_point_properties: &CodePointProperties) -> Vec<Self> {
+        vec![
+            PropertyRow::new(
+                "Code Point",
+                code_point_to_string(code_point_properties.code_point),
+            ),
+            PropertyRow::new("Name", code_point_properties.label()),
+            PropertyRow::new("Age", format!("Unicode {}", code_point_properties.age)),
+            PropertyRow::new("Plane", code_point_properties.plane_name.to_owned()),
+            PropertyRow::new(
+                "Block",
+                code_point_properties
+                    .block_name
+                    .unwrap_or(NOT_AVAILABLE_DISPLAY_TEXT)
+                    .to_owned(),
+            ),
+            PropertyRow::new(
+                "General Category",
+                code_point_properties.general_category.to_string(),
+            ),
+            PropertyRow::new("Code Point Kind", CodePointKind::Surrogate.to_string()),
+            PropertyRow::new("Script", code_point_properties.script.to_string()),
+            PropertyRow::default(),
+            PropertyRow::new("Line Break", code_point_properties.line_break.to_string()),
+            PropertyRow::default(),
+            PropertyRow::new(
+                "UTF-16",
+                format!("{:#06x}", [code_point_properties.utf16].as_hex()),
+            ),
+        ]
+    }
+
     fn from_name_aliases(title: &'static str, name_aliases: NameAliases) -> Vec<Self> {
         let mut property_rows = vec![];
         match name_aliases {
@@ -243,6 +582,26 @@ impl PropertyRow {
         }
     }
 
+    fn from_variants(title: &'static str, variants: &[char]) -> Vec<Self> {
+        if variants.is_empty() {
+            vec![PropertyRow::new(
+                title,
+                NOT_AVAILABLE_DISPLAY_TEXT.to_owned(),
+            )]
+        } else {
+            PropertyRow::from_character_components(title, variants)
+        }
+    }
+
+    fn from_optional_str(title: &'static str, optional_str: Option<&str>) -> Self {
+        PropertyRow::new(
+            title,
+            optional_str
+                .unwrap_or(NOT_AVAILABLE_DISPLAY_TEXT)
+                .to_owned(),
+        )
+    }
+
     fn from_character(title: &'static str, chr: char) -> Self {
         PropertyRow::new(title, format!("{} {}", code_point_to_string(chr), chr))
     }
@@ -263,8 +622,14 @@ impl PropertyRow {
     }
 }
 
+// Surrogate code points aren't characters, only the properties which apply to them are shown
+enum Properties {
+    Character(CharacterProperties),
+    CodePoint(CodePointProperties),
+}
+
 pub struct CharacterPropertyView {
-    character_properties: CharacterProperties,
+    properties: Properties,
 
     // The character properties are drawn in two Lists, one on the left hand side for the titles,
     // one on the right hand side for the values. Since they must be "scrolling" as if they were the
@@ -274,9 +639,16 @@ pub struct CharacterPropertyView {
 }
 
 impl CharacterPropertyView {
-    pub fn new(chr: char) -> Self {
+    pub fn new(code_point: u32) -> Self {
+        let properties = match char::from_u32(code_point) {
+            Some(chr) => Properties::Character(CharacterProperties::new(chr)),
+            None => Properties::CodePoint(CodePointProperties::with_fields(
+                code_point,
+                CharacterFields::all(),
+            )),
+        };
         CharacterPropertyView {
-            character_properties: CharacterProperties::new(chr),
+            properties,
             shared_list_state: ListState::default(),
             rows: vec![],
         }
@@ -299,7 +671,14 @@ impl CharacterPropertyView {
                 .horizontal_margin(1)
                 .split(rect);
 
-            self.rows = PropertyRow::from_character_properties(&self.character_properties);
+            self.rows = match &self.properties {
+                Properties::Character(character_properties) => {
+                    PropertyRow::from_character_properties(character_properties)
+                }
+                Properties::CodePoint(code_point_properties) => {
+                    PropertyRow::from_code_point_properties(code_point_properties)
+                }
+            };
 
             let title_list = List::new(self.rows.iter().map(|row| {
                 Text::Styled(
@@ -318,7 +697,10 @@ impl CharacterPropertyView {
 
         // Draw borders
         {
-            let code_point_description = code_point_to_string(self.character_properties.character);
+            let code_point_description = code_point_to_string(match &self.properties {
+                Properties::Character(character_properties) => character_properties.code_point,
+                Properties::CodePoint(code_point_properties) => code_point_properties.code_point,
+            });
             let block = Block::default()
                 .borders(Borders::ALL)
                 .title(&code_point_description);
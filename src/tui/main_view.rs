@@ -13,8 +13,9 @@
 // Cicero. If not, see <https://www.gnu.org/licenses/>.
 
 use std::io::Stdout;
+use std::time::Duration;
 
-use crossterm::event::{read, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{poll, read, Event, KeyCode, KeyEvent, KeyModifiers};
 use crossterm::Result;
 use tui::backend::CrosstermBackend;
 use tui::layout::{Alignment, Constraint, Direction, Layout, Rect};
@@ -25,26 +26,48 @@ use unic::ucd::UNICODE_VERSION;
 
 use super::character_detail_view::CharacterDetailView;
 use super::renderer::ApplicationTerminal;
+use super::search_view::SearchView;
 use super::stateful_graphemes::StatefulGraphemes;
 use super::ApplicationState;
+use crate::ucd::{
+    build_name_index_in_background, built_name_index, is_unihan_database_loading,
+    load_unihan_database_in_background, unihan_load_error,
+};
 
 pub type TerminalFrame<'a> = Frame<'a, CrosstermBackend<Stdout>>;
 
+const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
 pub struct MainView {
-    user_input: String,
+    // Code points rather than a string, so that surrogate code points can be inspected as well
+    user_input: Vec<u32>,
     graphemes: StatefulGraphemes,
     character_detail_view: Option<CharacterDetailView>,
+    search_view: Option<SearchView>,
+
+    // The Unihan database is loaded in the background, the detail is shown again once it's loaded
+    is_unihan_database_loading: bool,
+
+    // Building the name index takes a moment, so it's built in the background as well, and the
+    // query is matched again once it's built
+    is_name_index_building: bool,
 }
 
 const PAGE_CONTROL_STEP_SIZE: usize = 10;
+const LOADING_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 impl MainView {
-    pub fn new(user_input: String) -> Self {
+    pub fn new(user_input: Vec<u32>) -> Self {
         let graphemes = StatefulGraphemes::new(&user_input);
+        load_unihan_database_in_background();
+        build_name_index_in_background();
         MainView {
             user_input,
             graphemes,
             character_detail_view: None,
+            search_view: None,
+            is_unihan_database_loading: true,
+            is_name_index_building: true,
         }
     }
 
@@ -53,6 +76,22 @@ impl MainView {
         terminal: &mut ApplicationTerminal,
         app_state: &mut ApplicationState,
     ) -> Result<()> {
+        if self.is_unihan_database_loading && !is_unihan_database_loading() {
+            self.is_unihan_database_loading = false;
+            if self.character_detail_view.is_some() {
+                self.update_showing_detail(app_state);
+            }
+        }
+
+        if self.is_name_index_building {
+            if let Some(name_index) = built_name_index() {
+                self.is_name_index_building = false;
+                if let Some(search_view) = &mut self.search_view {
+                    search_view.update_name_matches(name_index);
+                }
+            }
+        }
+
         terminal.draw(|mut frame| {
             let chunks = Layout::default()
                 .constraints(
@@ -68,7 +107,9 @@ impl MainView {
 
             self.draw_user_input(&mut frame, chunks[0]);
 
-            if self.character_detail_view.is_some() {
+            if let Some(search_view) = &mut self.search_view {
+                search_view.draw(&mut frame, chunks[1]);
+            } else if self.character_detail_view.is_some() {
                 let grapheme_list_chunks = Layout::default()
                     .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)].as_ref())
                     .direction(Direction::Horizontal)
@@ -86,6 +127,13 @@ impl MainView {
             self.draw_status_bar(&mut frame, chunks[2]);
         })?;
 
+        // Don't block on waiting for events while loading, to redraw once it's done
+        if (self.is_unihan_database_loading || self.is_name_index_building)
+            && !poll(LOADING_POLL_INTERVAL)?
+        {
+            return Ok(());
+        }
+
         if let Event::Key(event) = read()? {
             self.handle_key_event(event, app_state);
         }
@@ -94,7 +142,12 @@ impl MainView {
     }
 
     fn draw_user_input(&mut self, frame: &mut TerminalFrame, rect: Rect) {
-        let user_input_items = [Text::raw(&self.user_input)];
+        let user_input_text: String = self
+            .user_input
+            .iter()
+            .map(|code_point| char::from_u32(*code_point).unwrap_or(REPLACEMENT_CHARACTER))
+            .collect();
+        let user_input_items = [Text::raw(user_input_text)];
         let user_input_paragraph = Paragraph::new(user_input_items.iter())
             .block(Block::default().borders(Borders::ALL).title("Input"))
             .style(Style::default().fg(Color::Yellow));
@@ -128,23 +181,51 @@ impl MainView {
             .direction(Direction::Horizontal)
             .split(rect);
 
-        let help_item = if self.character_detail_view.is_some() {
-            [Text::raw("[ESC]: Hide Detail | [C-D][C-U]: Scroll Detail")]
+        let help_item = if self.search_view.is_some() {
+            [Text::raw("[ESC]: Close Search | [ENTER]: Insert Character")]
+        } else if self.character_detail_view.is_some() {
+            [Text::raw(
+                "[ESC]: Hide Detail | [C-D][C-U]: Scroll Detail | [C-F]: Search",
+            )]
         } else {
-            [Text::raw("[ESC]: Quit")]
+            [Text::raw("[ESC]: Quit | [C-F]: Search")]
         };
         let help_text =
             Paragraph::new(help_item.iter()).style(Style::default().fg(Color::LightGreen));
         frame.render_widget(help_text, status_bar_chunks[0]);
 
-        let unicode_version_item = [Text::raw(format!("Unicode Version {}", UNICODE_VERSION))];
-        let unicode_version_text = Paragraph::new(unicode_version_item.iter())
-            .style(Style::default().fg(Color::LightGreen))
+        let is_indexing_names = self.search_view.is_some() && self.is_name_index_building;
+        let (status_item, status_color) = if is_indexing_names {
+            ([Text::raw("Indexing Names...")], Color::LightGreen)
+        } else if self.is_unihan_database_loading {
+            ([Text::raw("Loading Unihan Data...")], Color::LightGreen)
+        } else if let Some(load_error) = unihan_load_error() {
+            (
+                [Text::raw(format!(
+                    "Failed to load Unihan data from '{}': {}",
+                    load_error.path.display(),
+                    load_error.error
+                ))],
+                Color::LightRed,
+            )
+        } else {
+            (
+                [Text::raw(format!("Unicode Version {}", UNICODE_VERSION))],
+                Color::LightGreen,
+            )
+        };
+        let status_text = Paragraph::new(status_item.iter())
+            .style(Style::default().fg(status_color))
             .alignment(Alignment::Right);
-        frame.render_widget(unicode_version_text, status_bar_chunks[1]);
+        frame.render_widget(status_text, status_bar_chunks[1]);
     }
 
     fn handle_key_event(&mut self, event: KeyEvent, app_state: &mut ApplicationState) {
+        if self.search_view.is_some() {
+            self.handle_search_key_event(event, app_state);
+            return;
+        }
+
         match event.code {
             KeyCode::Esc => {
                 if self.character_detail_view.is_some() {
@@ -201,7 +282,44 @@ impl MainView {
         };
     }
 
+    fn handle_search_key_event(&mut self, event: KeyEvent, app_state: &ApplicationState) {
+        let search_view = self.search_view.as_mut().unwrap();
+        let name_index = built_name_index();
+        match event.code {
+            KeyCode::Esc => self.search_view = None,
+            KeyCode::Up => search_view.select_previous(),
+            KeyCode::Down => search_view.select_next(),
+            KeyCode::Enter => {
+                if let Some(chr) = search_view.selected_character() {
+                    self.search_view = None;
+                    self.user_input.push(chr as u32);
+                    self.graphemes = StatefulGraphemes::new(&self.user_input);
+                    self.graphemes
+                        .state
+                        .select(Some(self.graphemes.rows.len() - 1));
+                    if self.character_detail_view.is_some() {
+                        self.update_showing_detail(app_state);
+                    }
+                }
+            }
+            // Shortcuts like Ctrl+C are not part of the query
+            KeyCode::Char(c)
+                if event.modifiers == KeyModifiers::NONE
+                    || event.modifiers == KeyModifiers::SHIFT =>
+            {
+                search_view.push_query_character(c, name_index)
+            }
+            KeyCode::Backspace => search_view.pop_query_character(name_index),
+            _ => {}
+        }
+    }
+
     fn handle_character_input(&mut self, chr: char, modifiers: KeyModifiers) {
+        if chr == 'f' && modifiers.contains(KeyModifiers::CONTROL) {
+            self.search_view = Some(SearchView::new());
+            return;
+        }
+
         if chr == 'u'
             && modifiers.contains(KeyModifiers::CONTROL)
             && self.character_detail_view.is_some()
@@ -218,15 +336,15 @@ impl MainView {
             return;
         }
 
-        self.user_input.push(chr);
+        self.user_input.push(chr as u32);
         self.graphemes = StatefulGraphemes::new(&self.user_input);
     }
 
     fn update_showing_detail(&mut self, app_state: &ApplicationState) {
         if let Some(selected_row_index) = self.graphemes.state.selected() {
-            if let Some(chr) = self.graphemes.rows[selected_row_index].code_point {
+            if let Some(code_point) = self.graphemes.rows[selected_row_index].code_point {
                 self.character_detail_view = Some(CharacterDetailView::new(
-                    chr,
+                    code_point,
                     &app_state.selected_font_path,
                     &app_state.settings,
                 ));
@@ -21,6 +21,7 @@ mod character_preview_canvas;
 mod character_property_view;
 mod main_view;
 mod renderer;
+mod search_view;
 mod stateful_graphemes;
 
 pub use main_view::MainView;
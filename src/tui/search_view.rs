@@ -0,0 +1,112 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+use tui::layout::{Constraint, Direction, Layout, Rect};
+use tui::style::{Color, Modifier, Style};
+use tui::widgets::{Block, Borders, List, ListState, Paragraph, Text};
+
+use super::main_view::TerminalFrame;
+use crate::ucd::{NameIndex, NameMatch};
+
+const SEARCH_RESULT_LIMIT: usize = 100;
+
+pub struct SearchView {
+    query: String,
+    name_matches: Vec<NameMatch>,
+    state: ListState,
+}
+
+impl SearchView {
+    pub fn new() -> Self {
+        SearchView {
+            query: String::new(),
+            name_matches: vec![],
+            state: ListState::default(),
+        }
+    }
+
+    pub fn draw(&mut self, frame: &mut TerminalFrame, rect: Rect) {
+        let chunks = Layout::default()
+            .constraints([Constraint::Length(3), Constraint::Min(3)].as_ref())
+            .direction(Direction::Vertical)
+            .split(rect);
+
+        let query_items = [Text::raw(&self.query)];
+        let query_paragraph = Paragraph::new(query_items.iter())
+            .block(Block::default().borders(Borders::ALL).title("Search Name"))
+            .style(Style::default().fg(Color::Yellow));
+        frame.render_widget(query_paragraph, chunks[0]);
+
+        let results_list = List::new(
+            self.name_matches
+                .iter()
+                .map(|name_match| Text::raw(name_match.to_string())),
+        )
+        .block(Block::default().borders(Borders::ALL).title("Results"))
+        .style(Style::default())
+        .highlight_style(
+            Style::default()
+                .fg(Color::LightGreen)
+                .modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(">");
+        frame.render_stateful_widget(results_list, chunks[1], &mut self.state);
+    }
+
+    // The query can be typed while the name index is still being built, it's matched once it's
+    // built (see `update_name_matches`)
+    pub fn push_query_character(&mut self, chr: char, name_index: Option<&NameIndex>) {
+        self.query.push(chr);
+        if let Some(name_index) = name_index {
+            self.update_name_matches(name_index);
+        }
+    }
+
+    pub fn pop_query_character(&mut self, name_index: Option<&NameIndex>) {
+        self.query.pop();
+        if let Some(name_index) = name_index {
+            self.update_name_matches(name_index);
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if let Some(selected) = self.state.selected() {
+            if selected + 1 < self.name_matches.len() {
+                self.state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if let Some(selected) = self.state.selected() {
+            if selected > 0 {
+                self.state.select(Some(selected - 1));
+            }
+        }
+    }
+
+    pub fn selected_character(&self) -> Option<char> {
+        let selected = self.state.selected()?;
+        Some(self.name_matches.get(selected)?.character)
+    }
+
+    pub fn update_name_matches(&mut self, name_index: &NameIndex) {
+        self.name_matches = name_index.search(&self.query, SEARCH_RESULT_LIMIT);
+        self.state.select(if self.name_matches.is_empty() {
+            None
+        } else {
+            Some(0)
+        });
+    }
+}
@@ -19,25 +19,38 @@ use tui::widgets::ListState;
 use unic::segment::Graphemes;
 use unic::ucd::name::Name;
 
-use crate::ucd::code_point_to_string;
+use crate::ucd::{code_point_label, code_point_to_string};
 
+const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+// Code points rather than characters, so that surrogate code points can be inspected as well
 #[derive(Default)]
 pub struct GraphemeRow {
-    pub code_point: Option<char>,
+    pub code_point: Option<u32>,
 }
 
 impl GraphemeRow {
-    pub fn new(chr: char) -> Self {
+    pub fn new(code_point: u32) -> Self {
         GraphemeRow {
-            code_point: Some(chr),
+            code_point: Some(code_point),
         }
     }
 }
 
 impl fmt::Display for GraphemeRow {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.code_point {
-            None => write!(f, ""),
+        let code_point = match self.code_point {
+            None => return write!(f, ""),
+            Some(code_point) => code_point,
+        };
+        match char::from_u32(code_point) {
+            None => write!(
+                f,
+                "{}  {}  {}",
+                code_point_to_string(code_point),
+                REPLACEMENT_CHARACTER,
+                code_point_label(code_point).unwrap_or_default()
+            ),
             Some(chr) => {
                 let code_point_str = code_point_to_string(chr);
                 let name = match Name::of(chr) {
@@ -60,10 +73,8 @@ pub struct StatefulGraphemes {
 }
 
 impl StatefulGraphemes {
-    pub fn new(s: &str) -> Self {
-        let graphemes: Vec<Vec<char>> = Graphemes::new(s)
-            .map(|grapheme| grapheme.chars().collect())
-            .collect();
+    pub fn new(code_points: &[u32]) -> Self {
+        let graphemes = segment_graphemes(code_points);
 
         let mut state = ListState::default();
         if !graphemes.is_empty() {
@@ -79,7 +90,7 @@ impl StatefulGraphemes {
         grapheme_end_row_indices.reserve(graphemes.len());
 
         for (i, grapheme) in graphemes.iter().enumerate() {
-            for (j, chr) in grapheme.iter().enumerate() {
+            for (j, code_point) in grapheme.iter().enumerate() {
                 if j == 0 {
                     grapheme_start_row_indices.insert(rows.len());
                 }
@@ -88,7 +99,7 @@ impl StatefulGraphemes {
                     grapheme_end_row_indices.insert(rows.len());
                 }
 
-                rows.push(GraphemeRow::new(*chr));
+                rows.push(GraphemeRow::new(*code_point));
             }
 
             if i + 1 < graphemes.len() {
@@ -165,15 +176,45 @@ impl StatefulGraphemes {
     }
 }
 
+/// Segments `code_points` into graphemes. Surrogate code points can't be in a `str`, they are
+/// graphemes on their own like controls (GB4, GB5), so the text between them is segmented apart.
+fn segment_graphemes(code_points: &[u32]) -> Vec<Vec<u32>> {
+    fn push_text_graphemes(text: &str, graphemes: &mut Vec<Vec<u32>>) {
+        graphemes.extend(
+            Graphemes::new(text).map(|grapheme| grapheme.chars().map(|chr| chr as u32).collect()),
+        );
+    }
+
+    let mut graphemes = vec![];
+    let mut text = String::new();
+    for &code_point in code_points {
+        match char::from_u32(code_point) {
+            Some(chr) => text.push(chr),
+            None => {
+                push_text_graphemes(&text, &mut graphemes);
+                text.clear();
+                graphemes.push(vec![code_point]);
+            }
+        }
+    }
+    push_text_graphemes(&text, &mut graphemes);
+    graphemes
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ucd::CodePointKind;
 
     const TEST_STR: &str = "ಠ_ರೃ ab";
 
+    fn code_points_of(s: &str) -> Vec<u32> {
+        s.chars().map(|chr| chr as u32).collect()
+    }
+
     #[test]
     fn test_new_stateful_graphemes() {
-        let graphemes = StatefulGraphemes::new(TEST_STR);
+        let graphemes = StatefulGraphemes::new(&code_points_of(TEST_STR));
 
         assert_eq!(graphemes.rows.len(), 12);
 
@@ -211,7 +252,7 @@ mod tests {
 
     #[test]
     fn test_select_next() {
-        let mut graphemes = StatefulGraphemes::new(TEST_STR);
+        let mut graphemes = StatefulGraphemes::new(&code_points_of(TEST_STR));
 
         graphemes.select_next();
         assert_eq!(graphemes.state.selected().unwrap(), 2);
@@ -236,7 +277,7 @@ mod tests {
 
     #[test]
     fn test_select_next_n() {
-        let mut graphemes = StatefulGraphemes::new(TEST_STR);
+        let mut graphemes = StatefulGraphemes::new(&code_points_of(TEST_STR));
 
         graphemes.select_next_n(2);
         assert_eq!(graphemes.state.selected().unwrap(), 4);
@@ -247,7 +288,7 @@ mod tests {
 
     #[test]
     fn test_select_previous() {
-        let mut graphemes = StatefulGraphemes::new(TEST_STR);
+        let mut graphemes = StatefulGraphemes::new(&code_points_of(TEST_STR));
         graphemes.state.select(Some(11));
 
         graphemes.select_previous();
@@ -273,7 +314,7 @@ mod tests {
 
     #[test]
     fn test_select_previous_n() {
-        let mut graphemes = StatefulGraphemes::new(TEST_STR);
+        let mut graphemes = StatefulGraphemes::new(&code_points_of(TEST_STR));
         graphemes.state.select(Some(11));
 
         graphemes.select_previous_n(3);
@@ -282,4 +323,32 @@ mod tests {
         graphemes.select_previous_n(42);
         assert_eq!(graphemes.state.selected().unwrap(), 0);
     }
+
+    #[test]
+    fn test_surrogate_graphemes() {
+        let graphemes = StatefulGraphemes::new(&[0x61, 0xD800, 0x0301, 0xDC00]);
+
+        let code_points: Vec<Option<u32>> =
+            graphemes.rows.iter().map(|row| row.code_point).collect();
+        assert_eq!(
+            code_points,
+            vec![
+                Some(0x61),
+                None,
+                Some(0xD800),
+                None,
+                Some(0x0301),
+                None,
+                Some(0xDC00)
+            ]
+        );
+        assert_eq!(
+            CodePointKind::of(graphemes.rows[2].code_point.unwrap()),
+            CodePointKind::Surrogate
+        );
+        assert_eq!(
+            graphemes.rows[2].to_string(),
+            "U+D800  \u{FFFD}  <surrogate-D800>"
+        );
+    }
 }
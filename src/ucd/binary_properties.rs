@@ -0,0 +1,87 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Binary properties which are not yet provided by `unic::ucd`.
+//!
+
+mod tables;
+
+use super::is_in_ranges;
+
+pub fn is_default_ignorable_code_point(chr: char) -> bool {
+    is_in_ranges(tables::DEFAULT_IGNORABLE_CODE_POINT_RANGES, chr)
+}
+
+pub fn is_noncharacter_code_point(chr: char) -> bool {
+    is_in_ranges(tables::NONCHARACTER_CODE_POINT_RANGES, chr)
+}
+
+pub fn is_dash(chr: char) -> bool {
+    is_in_ranges(tables::DASH_RANGES, chr)
+}
+
+/// Hyphen is deprecated (UAX #44), but it is still the simplest way to tell hyphens from dashes.
+pub fn is_hyphen(chr: char) -> bool {
+    is_in_ranges(tables::HYPHEN_RANGES, chr)
+}
+
+pub fn is_quotation_mark(chr: char) -> bool {
+    is_in_ranges(tables::QUOTATION_MARK_RANGES, chr)
+}
+
+pub fn is_diacritic(chr: char) -> bool {
+    is_in_ranges(tables::DIACRITIC_RANGES, chr)
+}
+
+pub fn is_extender(chr: char) -> bool {
+    is_in_ranges(tables::EXTENDER_RANGES, chr)
+}
+
+pub fn is_join_control(chr: char) -> bool {
+    is_in_ranges(tables::JOIN_CONTROL_RANGES, chr)
+}
+
+pub fn is_variation_selector(chr: char) -> bool {
+    is_in_ranges(tables::VARIATION_SELECTOR_RANGES, chr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_properties() {
+        assert!(is_default_ignorable_code_point('\u{00AD}'));
+        assert!(is_default_ignorable_code_point('\u{E0FFF}'));
+        assert!(!is_default_ignorable_code_point(' '));
+        assert!(is_noncharacter_code_point('\u{FDD0}'));
+        assert!(is_noncharacter_code_point('\u{10FFFF}'));
+        assert!(!is_noncharacter_code_point('\u{FFFD}'));
+        assert!(is_dash('-'));
+        assert!(is_dash('\u{2014}'));
+        assert!(is_hyphen('-'));
+        assert!(!is_hyphen('\u{2014}'));
+        assert!(is_quotation_mark('"'));
+        assert!(is_quotation_mark('\u{300C}'));
+        assert!(is_diacritic('^'));
+        assert!(is_diacritic('\u{0301}'));
+        assert!(is_extender('\u{00B7}'));
+        assert!(is_join_control('\u{200D}'));
+        assert!(!is_join_control('\u{200B}'));
+        assert!(is_variation_selector('\u{FE0F}'));
+        assert!(is_variation_selector('\u{E0100}'));
+        assert!(!is_variation_selector('a'));
+    }
+}
@@ -0,0 +1,332 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (PropList.txt and
+// DerivedCoreProperties.txt), limited to the characters assigned in Unicode 13.0.
+
+pub(super) const DEFAULT_IGNORABLE_CODE_POINT_RANGES: &[(u32, u32)] = &[
+    (0x00AD, 0x00AD),
+    (0x034F, 0x034F),
+    (0x061C, 0x061C),
+    (0x115F, 0x1160),
+    (0x17B4, 0x17B5),
+    (0x180B, 0x180E),
+    (0x200B, 0x200F),
+    (0x202A, 0x202E),
+    (0x2060, 0x206F),
+    (0x3164, 0x3164),
+    (0xFE00, 0xFE0F),
+    (0xFEFF, 0xFEFF),
+    (0xFFA0, 0xFFA0),
+    (0xFFF0, 0xFFF8),
+    (0x1BCA0, 0x1BCA3),
+    (0x1D173, 0x1D17A),
+    (0xE0000, 0xE0FFF),
+];
+
+pub(super) const NONCHARACTER_CODE_POINT_RANGES: &[(u32, u32)] = &[
+    (0xFDD0, 0xFDEF),
+    (0xFFFE, 0xFFFF),
+    (0x1FFFE, 0x1FFFF),
+    (0x2FFFE, 0x2FFFF),
+    (0x3FFFE, 0x3FFFF),
+    (0x4FFFE, 0x4FFFF),
+    (0x5FFFE, 0x5FFFF),
+    (0x6FFFE, 0x6FFFF),
+    (0x7FFFE, 0x7FFFF),
+    (0x8FFFE, 0x8FFFF),
+    (0x9FFFE, 0x9FFFF),
+    (0xAFFFE, 0xAFFFF),
+    (0xBFFFE, 0xBFFFF),
+    (0xCFFFE, 0xCFFFF),
+    (0xDFFFE, 0xDFFFF),
+    (0xEFFFE, 0xEFFFF),
+    (0xFFFFE, 0xFFFFF),
+    (0x10FFFE, 0x10FFFF),
+];
+
+pub(super) const DASH_RANGES: &[(u32, u32)] = &[
+    (0x002D, 0x002D),
+    (0x058A, 0x058A),
+    (0x05BE, 0x05BE),
+    (0x1400, 0x1400),
+    (0x1806, 0x1806),
+    (0x2010, 0x2015),
+    (0x2053, 0x2053),
+    (0x207B, 0x207B),
+    (0x208B, 0x208B),
+    (0x2212, 0x2212),
+    (0x2E17, 0x2E17),
+    (0x2E1A, 0x2E1A),
+    (0x2E3A, 0x2E3B),
+    (0x2E40, 0x2E40),
+    (0x301C, 0x301C),
+    (0x3030, 0x3030),
+    (0x30A0, 0x30A0),
+    (0xFE31, 0xFE32),
+    (0xFE58, 0xFE58),
+    (0xFE63, 0xFE63),
+    (0xFF0D, 0xFF0D),
+    (0x10EAD, 0x10EAD),
+];
+
+pub(super) const HYPHEN_RANGES: &[(u32, u32)] = &[
+    (0x002D, 0x002D),
+    (0x00AD, 0x00AD),
+    (0x058A, 0x058A),
+    (0x1806, 0x1806),
+    (0x2010, 0x2011),
+    (0x2E17, 0x2E17),
+    (0x30FB, 0x30FB),
+    (0xFE63, 0xFE63),
+    (0xFF0D, 0xFF0D),
+    (0xFF65, 0xFF65),
+];
+
+pub(super) const QUOTATION_MARK_RANGES: &[(u32, u32)] = &[
+    (0x0022, 0x0022),
+    (0x0027, 0x0027),
+    (0x00AB, 0x00AB),
+    (0x00BB, 0x00BB),
+    (0x2018, 0x201F),
+    (0x2039, 0x203A),
+    (0x2E42, 0x2E42),
+    (0x300C, 0x300F),
+    (0x301D, 0x301F),
+    (0xFE41, 0xFE44),
+    (0xFF02, 0xFF02),
+    (0xFF07, 0xFF07),
+    (0xFF62, 0xFF63),
+];
+
+pub(super) const DIACRITIC_RANGES: &[(u32, u32)] = &[
+    (0x005E, 0x005E),
+    (0x0060, 0x0060),
+    (0x00A8, 0x00A8),
+    (0x00AF, 0x00AF),
+    (0x00B4, 0x00B4),
+    (0x00B7, 0x00B8),
+    (0x02B0, 0x034E),
+    (0x0350, 0x0357),
+    (0x035D, 0x0362),
+    (0x0374, 0x0375),
+    (0x037A, 0x037A),
+    (0x0384, 0x0385),
+    (0x0483, 0x0487),
+    (0x0559, 0x0559),
+    (0x0591, 0x05A1),
+    (0x05A3, 0x05BD),
+    (0x05BF, 0x05BF),
+    (0x05C1, 0x05C2),
+    (0x05C4, 0x05C4),
+    (0x064B, 0x0652),
+    (0x0657, 0x0658),
+    (0x06DF, 0x06E0),
+    (0x06E5, 0x06E6),
+    (0x06EA, 0x06EC),
+    (0x0730, 0x074A),
+    (0x07A6, 0x07B0),
+    (0x07EB, 0x07F5),
+    (0x0818, 0x0819),
+    (0x08E3, 0x08FE),
+    (0x093C, 0x093C),
+    (0x094D, 0x094D),
+    (0x0951, 0x0954),
+    (0x0971, 0x0971),
+    (0x09BC, 0x09BC),
+    (0x09CD, 0x09CD),
+    (0x0A3C, 0x0A3C),
+    (0x0A4D, 0x0A4D),
+    (0x0ABC, 0x0ABC),
+    (0x0ACD, 0x0ACD),
+    (0x0AFD, 0x0AFF),
+    (0x0B3C, 0x0B3C),
+    (0x0B4D, 0x0B4D),
+    (0x0B55, 0x0B55),
+    (0x0BCD, 0x0BCD),
+    (0x0C4D, 0x0C4D),
+    (0x0CBC, 0x0CBC),
+    (0x0CCD, 0x0CCD),
+    (0x0D3B, 0x0D3C),
+    (0x0D4D, 0x0D4D),
+    (0x0DCA, 0x0DCA),
+    (0x0E47, 0x0E4C),
+    (0x0E4E, 0x0E4E),
+    (0x0EBA, 0x0EBA),
+    (0x0EC8, 0x0ECC),
+    (0x0F18, 0x0F19),
+    (0x0F35, 0x0F35),
+    (0x0F37, 0x0F37),
+    (0x0F39, 0x0F39),
+    (0x0F3E, 0x0F3F),
+    (0x0F82, 0x0F84),
+    (0x0F86, 0x0F87),
+    (0x0FC6, 0x0FC6),
+    (0x1037, 0x1037),
+    (0x1039, 0x103A),
+    (0x1063, 0x1064),
+    (0x1069, 0x106D),
+    (0x1087, 0x108D),
+    (0x108F, 0x108F),
+    (0x109A, 0x109B),
+    (0x135D, 0x135F),
+    (0x1714, 0x1714),
+    (0x17C9, 0x17D3),
+    (0x17DD, 0x17DD),
+    (0x1939, 0x193B),
+    (0x1A75, 0x1A7C),
+    (0x1A7F, 0x1A7F),
+    (0x1AB0, 0x1ABE),
+    (0x1B34, 0x1B34),
+    (0x1B44, 0x1B44),
+    (0x1B6B, 0x1B73),
+    (0x1BAA, 0x1BAB),
+    (0x1C36, 0x1C37),
+    (0x1C78, 0x1C7D),
+    (0x1CD0, 0x1CE8),
+    (0x1CED, 0x1CED),
+    (0x1CF4, 0x1CF4),
+    (0x1CF7, 0x1CF9),
+    (0x1D2C, 0x1D6A),
+    (0x1DC4, 0x1DCF),
+    (0x1DF5, 0x1DF9),
+    (0x1DFB, 0x1DFF),
+    (0x1FBD, 0x1FBD),
+    (0x1FBF, 0x1FC1),
+    (0x1FCD, 0x1FCF),
+    (0x1FDD, 0x1FDF),
+    (0x1FED, 0x1FEF),
+    (0x1FFD, 0x1FFE),
+    (0x2CEF, 0x2CF1),
+    (0x2E2F, 0x2E2F),
+    (0x302A, 0x302F),
+    (0x3099, 0x309C),
+    (0x30FC, 0x30FC),
+    (0xA66F, 0xA66F),
+    (0xA67C, 0xA67D),
+    (0xA67F, 0xA67F),
+    (0xA69C, 0xA69D),
+    (0xA6F0, 0xA6F1),
+    (0xA700, 0xA721),
+    (0xA788, 0xA78A),
+    (0xA7F8, 0xA7F9),
+    (0xA8C4, 0xA8C4),
+    (0xA8E0, 0xA8F1),
+    (0xA92B, 0xA92E),
+    (0xA953, 0xA953),
+    (0xA9B3, 0xA9B3),
+    (0xA9C0, 0xA9C0),
+    (0xA9E5, 0xA9E5),
+    (0xAA7B, 0xAA7D),
+    (0xAABF, 0xAAC2),
+    (0xAAF6, 0xAAF6),
+    (0xAB5B, 0xAB5F),
+    (0xAB69, 0xAB6B),
+    (0xABEC, 0xABED),
+    (0xFB1E, 0xFB1E),
+    (0xFE20, 0xFE2F),
+    (0xFF3E, 0xFF3E),
+    (0xFF40, 0xFF40),
+    (0xFF70, 0xFF70),
+    (0xFF9E, 0xFF9F),
+    (0xFFE3, 0xFFE3),
+    (0x102E0, 0x102E0),
+    (0x10AE5, 0x10AE6),
+    (0x10D22, 0x10D27),
+    (0x10F46, 0x10F50),
+    (0x11046, 0x11046),
+    (0x110B9, 0x110BA),
+    (0x11133, 0x11134),
+    (0x11173, 0x11173),
+    (0x111C0, 0x111C0),
+    (0x111CA, 0x111CC),
+    (0x11235, 0x11236),
+    (0x112E9, 0x112EA),
+    (0x1133C, 0x1133C),
+    (0x1134D, 0x1134D),
+    (0x11366, 0x1136C),
+    (0x11370, 0x11374),
+    (0x11442, 0x11442),
+    (0x11446, 0x11446),
+    (0x114C2, 0x114C3),
+    (0x115BF, 0x115C0),
+    (0x1163F, 0x1163F),
+    (0x116B6, 0x116B7),
+    (0x1172B, 0x1172B),
+    (0x11839, 0x1183A),
+    (0x1193D, 0x1193E),
+    (0x11943, 0x11943),
+    (0x119E0, 0x119E0),
+    (0x11A34, 0x11A34),
+    (0x11A47, 0x11A47),
+    (0x11A99, 0x11A99),
+    (0x11C3F, 0x11C3F),
+    (0x11D42, 0x11D42),
+    (0x11D44, 0x11D45),
+    (0x11D97, 0x11D97),
+    (0x16AF0, 0x16AF4),
+    (0x16B30, 0x16B36),
+    (0x16F8F, 0x16F9F),
+    (0x16FF0, 0x16FF1),
+    (0x1D167, 0x1D169),
+    (0x1D16D, 0x1D172),
+    (0x1D17B, 0x1D182),
+    (0x1D185, 0x1D18B),
+    (0x1D1AA, 0x1D1AD),
+    (0x1E130, 0x1E136),
+    (0x1E2EC, 0x1E2EF),
+    (0x1E8D0, 0x1E8D6),
+    (0x1E944, 0x1E946),
+    (0x1E948, 0x1E94A),
+];
+
+pub(super) const EXTENDER_RANGES: &[(u32, u32)] = &[
+    (0x00B7, 0x00B7),
+    (0x02D0, 0x02D1),
+    (0x0640, 0x0640),
+    (0x07FA, 0x07FA),
+    (0x0B55, 0x0B55),
+    (0x0E46, 0x0E46),
+    (0x0EC6, 0x0EC6),
+    (0x180A, 0x180A),
+    (0x1843, 0x1843),
+    (0x1AA7, 0x1AA7),
+    (0x1C36, 0x1C36),
+    (0x1C7B, 0x1C7B),
+    (0x3005, 0x3005),
+    (0x3031, 0x3035),
+    (0x309D, 0x309E),
+    (0x30FC, 0x30FE),
+    (0xA015, 0xA015),
+    (0xA60C, 0xA60C),
+    (0xA9CF, 0xA9CF),
+    (0xA9E6, 0xA9E6),
+    (0xAA70, 0xAA70),
+    (0xAADD, 0xAADD),
+    (0xAAF3, 0xAAF4),
+    (0xFF70, 0xFF70),
+    (0x1135D, 0x1135D),
+    (0x115C6, 0x115C8),
+    (0x11A98, 0x11A98),
+    (0x16B42, 0x16B43),
+    (0x16FE0, 0x16FE1),
+    (0x16FE3, 0x16FE3),
+    (0x1E13C, 0x1E13D),
+    (0x1E944, 0x1E946),
+];
+
+pub(super) const JOIN_CONTROL_RANGES: &[(u32, u32)] = &[(0x200C, 0x200D)];
+
+pub(super) const VARIATION_SELECTOR_RANGES: &[(u32, u32)] =
+    &[(0x180B, 0x180D), (0xFE00, 0xFE0F), (0xE0100, 0xE01EF)];
@@ -0,0 +1,587 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Text boundaries, i.e. grapheme cluster, word and sentence boundaries (UAX #29) and line break
+//! opportunities (UAX #14).
+//!
+//! `unic::segment` only implements grapheme cluster and word boundaries, sentence boundaries and
+//! line break opportunities are implemented here with the default rules, without tailoring.
+//!
+
+use unic::segment::{GraphemeIndices, WordBoundIndices};
+use unic::ucd::{GeneralCategory, SentenceBreak};
+
+use super::LineBreak;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BoundaryKind {
+    Grapheme,
+    Word,
+    Sentence,
+    Line,
+}
+
+/// A boundary in a string, as a byte offset.
+///
+/// Only line break opportunities can be mandatory, e.g. after a line feed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Boundary {
+    pub offset: usize,
+    pub is_mandatory: bool,
+}
+
+impl Boundary {
+    fn new(offset: usize) -> Self {
+        Boundary {
+            offset,
+            is_mandatory: false,
+        }
+    }
+}
+
+/// Finds boundaries of `kind` in `string`, including the end of `string` but not the start.
+pub fn boundaries(string: &str, kind: BoundaryKind) -> Vec<Boundary> {
+    match kind {
+        BoundaryKind::Grapheme => GraphemeIndices::new(string)
+            .map(|(offset, grapheme)| Boundary::new(offset + grapheme.len()))
+            .collect(),
+        BoundaryKind::Word => WordBoundIndices::new(string)
+            .map(|(offset, word)| Boundary::new(offset + word.len()))
+            .collect(),
+        BoundaryKind::Sentence => sentence_boundaries(string),
+        BoundaryKind::Line => line_break_opportunities(string),
+    }
+}
+
+fn is_paragraph_separator(sentence_break: SentenceBreak) -> bool {
+    matches!(
+        sentence_break,
+        SentenceBreak::Sep | SentenceBreak::CR | SentenceBreak::LF
+    )
+}
+
+fn is_sentence_terminator(sentence_break: SentenceBreak) -> bool {
+    matches!(sentence_break, SentenceBreak::ATerm | SentenceBreak::STerm)
+}
+
+/// Whether there is a sentence boundary before `units[index]`, where `units` are the sentence
+/// break classes of the text with Extend and Format characters removed (SB5).
+fn is_sentence_boundary(units: &[SentenceBreak], index: usize) -> bool {
+    let previous = units[index - 1];
+    let current = units[index];
+
+    // SB3, SB4
+    if previous == SentenceBreak::CR && current == SentenceBreak::LF {
+        return false;
+    }
+    if is_paragraph_separator(previous) {
+        return true;
+    }
+
+    // SB6, SB7
+    if previous == SentenceBreak::ATerm && current == SentenceBreak::Numeric {
+        return false;
+    }
+    if index >= 2
+        && matches!(
+            units[index - 2],
+            SentenceBreak::Upper | SentenceBreak::Lower
+        )
+        && previous == SentenceBreak::ATerm
+        && current == SentenceBreak::Upper
+    {
+        return false;
+    }
+
+    // The rest of the rules apply after `SATerm Close* Sp*`
+    let spaces = units[..index]
+        .iter()
+        .rev()
+        .take_while(|unit| **unit == SentenceBreak::Sp)
+        .count();
+    let closes = units[..index - spaces]
+        .iter()
+        .rev()
+        .take_while(|unit| **unit == SentenceBreak::Close)
+        .count();
+    let terminator = match (index - spaces - closes).checked_sub(1) {
+        Some(terminator_index) if is_sentence_terminator(units[terminator_index]) => {
+            units[terminator_index]
+        }
+        _ => return false, // SB998
+    };
+
+    // SB8, a full stop followed by a lowercase letter is not the end of a sentence, e.g. "etc. and"
+    if terminator == SentenceBreak::ATerm {
+        let next_letter = units[index..].iter().find(|unit| {
+            matches!(
+                unit,
+                SentenceBreak::OLetter | SentenceBreak::Upper | SentenceBreak::Lower
+            ) || is_paragraph_separator(**unit)
+                || is_sentence_terminator(**unit)
+        });
+        if next_letter == Some(&SentenceBreak::Lower) {
+            return false;
+        }
+    }
+
+    // SB8a
+    if current == SentenceBreak::SContinue || is_sentence_terminator(current) {
+        return false;
+    }
+
+    // SB9
+    if spaces == 0 && current == SentenceBreak::Close {
+        return false;
+    }
+
+    // SB10, SB11
+    !(current == SentenceBreak::Sp || is_paragraph_separator(current))
+}
+
+fn sentence_boundaries(string: &str) -> Vec<Boundary> {
+    // Extend and Format characters are ignored (SB5), i.e. they are attached to the preceding
+    // character, unless it's a paragraph separator
+    let mut offsets = vec![];
+    let mut units = vec![];
+    for (offset, chr) in string.char_indices() {
+        let sentence_break = SentenceBreak::of(chr);
+        let is_ignored = matches!(
+            sentence_break,
+            SentenceBreak::Extend | SentenceBreak::Format
+        ) && matches!(units.last(), Some(previous) if !is_paragraph_separator(*previous));
+        if !is_ignored {
+            offsets.push(offset);
+            units.push(sentence_break);
+        }
+    }
+
+    let mut boundaries: Vec<Boundary> = (1..units.len())
+        .filter(|index| is_sentence_boundary(&units, *index))
+        .map(|index| Boundary::new(offsets[index]))
+        .collect();
+    if !string.is_empty() {
+        boundaries.push(Boundary::new(string.len()));
+    }
+    boundaries
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum LineBreakAction {
+    Mandatory,
+    Allowed,
+    Prohibited,
+}
+
+/// Resolves line break classes which don't have their own rules (LB1).
+fn resolved_line_break(chr: char) -> LineBreak {
+    match LineBreak::of(chr) {
+        LineBreak::Ambiguous | LineBreak::Surrogate | LineBreak::Unknown => LineBreak::Alphabetic,
+        LineBreak::ComplexContext => match GeneralCategory::of(chr) {
+            GeneralCategory::NonspacingMark | GeneralCategory::SpacingMark => {
+                LineBreak::CombiningMark
+            }
+            _ => LineBreak::Alphabetic,
+        },
+        LineBreak::ConditionalJapaneseStarter => LineBreak::Nonstarter,
+        line_break => line_break,
+    }
+}
+
+fn is_hard_line_break(line_break: LineBreak) -> bool {
+    matches!(
+        line_break,
+        LineBreak::MandatoryBreak
+            | LineBreak::CarriageReturn
+            | LineBreak::LineFeed
+            | LineBreak::NextLine
+    )
+}
+
+fn is_alphabetic(line_break: LineBreak) -> bool {
+    matches!(line_break, LineBreak::Alphabetic | LineBreak::HebrewLetter)
+}
+
+fn is_hangul_syllable(line_break: LineBreak) -> bool {
+    matches!(
+        line_break,
+        LineBreak::JL | LineBreak::JV | LineBreak::JT | LineBreak::H2 | LineBreak::H3
+    )
+}
+
+/// Whether `chr` is fullwidth, wide or halfwidth punctuation, e.g. U+FF08 FULLWIDTH LEFT
+/// PARENTHESIS, which are excluded from LB30. These are the characters of the OP class whose
+/// East_Asian_Width is F, W or H. No character of the CP class has one of these widths.
+fn is_wide_punctuation(chr: char) -> bool {
+    matches!(
+        chr,
+        '\u{2329}'
+            | '\u{3008}'
+            | '\u{300A}'
+            | '\u{300C}'
+            | '\u{300E}'
+            | '\u{3010}'
+            | '\u{3014}'
+            | '\u{3016}'
+            | '\u{3018}'
+            | '\u{301A}'
+            | '\u{301D}'
+            | '\u{FE17}'
+            | '\u{FE35}'
+            | '\u{FE37}'
+            | '\u{FE39}'
+            | '\u{FE3B}'
+            | '\u{FE3D}'
+            | '\u{FE3F}'
+            | '\u{FE41}'
+            | '\u{FE43}'
+            | '\u{FE47}'
+            | '\u{FE59}'
+            | '\u{FE5B}'
+            | '\u{FE5D}'
+            | '\u{FF08}'
+            | '\u{FF3B}'
+            | '\u{FF5B}'
+            | '\u{FF5F}'
+            | '\u{FF62}'
+    )
+}
+
+/// The state of the line breaking algorithm before a character.
+struct LineBreakContext {
+    before: LineBreak,
+    before_chr: char,
+    // The class before `before`
+    before_before: LineBreak,
+    // The class before the spaces preceding the character, or `before` if there are no spaces
+    before_spaces: LineBreak,
+    follows_zwj: bool,
+    regional_indicator_count: usize,
+}
+
+impl LineBreakContext {
+    fn new(chr: char) -> Self {
+        let line_break = match resolved_line_break(chr) {
+            // LB10, a combining mark at the start of text is treated as alphabetic
+            LineBreak::CombiningMark | LineBreak::ZWJ => LineBreak::Alphabetic,
+            line_break => line_break,
+        };
+        LineBreakContext {
+            before: line_break,
+            before_chr: chr,
+            before_before: LineBreak::Unknown,
+            before_spaces: line_break,
+            follows_zwj: chr == '\u{200D}',
+            regional_indicator_count: (line_break == LineBreak::RegionalIndicator) as usize,
+        }
+    }
+
+    /// Decides whether there is a line break opportunity before `after` (LB4 to LB31).
+    fn action(&self, after: LineBreak, after_chr: char) -> LineBreakAction {
+        use LineBreak::*;
+        use LineBreakAction::*;
+
+        let LineBreakContext {
+            before,
+            before_chr,
+            before_before,
+            before_spaces,
+            ..
+        } = *self;
+
+        // LB4, LB5
+        if before == MandatoryBreak {
+            return Mandatory;
+        }
+        if before == CarriageReturn && after == LineFeed {
+            return Prohibited;
+        }
+        if is_hard_line_break(before) {
+            return Mandatory;
+        }
+
+        // LB6, LB7
+        if is_hard_line_break(after) || after == Space || after == ZWSpace {
+            return Prohibited;
+        }
+
+        // LB8, LB8a
+        if before_spaces == ZWSpace {
+            return Allowed;
+        }
+        if self.follows_zwj {
+            return Prohibited;
+        }
+
+        // LB30a, regional indicators are paired up
+        if before == RegionalIndicator
+            && after == RegionalIndicator
+            && self.regional_indicator_count % 2 == 1
+        {
+            return Prohibited;
+        }
+
+        match (before, after) {
+            // LB11
+            (WordJoiner, _) | (_, WordJoiner) => Prohibited,
+            // LB12, LB12a
+            (Glue, _) => Prohibited,
+            (_, Glue) if !matches!(before, Space | BreakAfter | Hyphen) => Prohibited,
+            // LB13
+            (_, ClosePunctuation)
+            | (_, CloseParenthesis)
+            | (_, Exclamation)
+            | (_, InfixNumeric)
+            | (_, BreakSymbols) => Prohibited,
+            // LB14 to LB17
+            _ if before_spaces == OpenPunctuation => Prohibited,
+            (_, OpenPunctuation) if before_spaces == Quotation => Prohibited,
+            (_, Nonstarter) if matches!(before_spaces, ClosePunctuation | CloseParenthesis) => {
+                Prohibited
+            }
+            (_, BreakBoth) if before_spaces == BreakBoth => Prohibited,
+            // LB18
+            (Space, _) => Allowed,
+            // LB19
+            (Quotation, _) | (_, Quotation) => Prohibited,
+            // LB20
+            (ContingentBreak, _) | (_, ContingentBreak) => Allowed,
+            // LB21, LB21a, LB21b
+            (_, BreakAfter) | (_, Hyphen) | (_, Nonstarter) | (BreakBefore, _) => Prohibited,
+            (Hyphen, _) | (BreakAfter, _) if before_before == HebrewLetter => Prohibited,
+            (BreakSymbols, HebrewLetter) => Prohibited,
+            // LB22
+            (_, Inseparable) => Prohibited,
+            // LB23, LB23a
+            (Alphabetic, Numeric) | (HebrewLetter, Numeric) => Prohibited,
+            (Numeric, Alphabetic) | (Numeric, HebrewLetter) => Prohibited,
+            (PrefixNumeric, Ideographic) | (PrefixNumeric, EBase) | (PrefixNumeric, EModifier) => {
+                Prohibited
+            }
+            (Ideographic, PostfixNumeric)
+            | (EBase, PostfixNumeric)
+            | (EModifier, PostfixNumeric) => Prohibited,
+            // LB24
+            (PrefixNumeric, _) | (PostfixNumeric, _) if is_alphabetic(after) => Prohibited,
+            (_, PrefixNumeric) | (_, PostfixNumeric) if is_alphabetic(before) => Prohibited,
+            // LB25
+            (ClosePunctuation, PostfixNumeric)
+            | (CloseParenthesis, PostfixNumeric)
+            | (ClosePunctuation, PrefixNumeric)
+            | (CloseParenthesis, PrefixNumeric)
+            | (Numeric, PostfixNumeric)
+            | (Numeric, PrefixNumeric)
+            | (PostfixNumeric, OpenPunctuation)
+            | (PostfixNumeric, Numeric)
+            | (PrefixNumeric, OpenPunctuation)
+            | (PrefixNumeric, Numeric)
+            | (Hyphen, Numeric)
+            | (InfixNumeric, Numeric)
+            | (Numeric, Numeric)
+            | (BreakSymbols, Numeric) => Prohibited,
+            // LB26
+            (JL, JL) | (JL, JV) | (JL, H2) | (JL, H3) => Prohibited,
+            (JV, JV) | (JV, JT) | (H2, JV) | (H2, JT) => Prohibited,
+            (JT, JT) | (H3, JT) => Prohibited,
+            // LB27
+            (_, PostfixNumeric) if is_hangul_syllable(before) => Prohibited,
+            (PrefixNumeric, _) if is_hangul_syllable(after) => Prohibited,
+            // LB28, LB29
+            _ if is_alphabetic(before) && is_alphabetic(after) => Prohibited,
+            (InfixNumeric, _) if is_alphabetic(after) => Prohibited,
+            // LB30
+            (_, OpenPunctuation)
+                if (is_alphabetic(before) || before == Numeric)
+                    && !is_wide_punctuation(after_chr) =>
+            {
+                Prohibited
+            }
+            (CloseParenthesis, _)
+                if (is_alphabetic(after) || after == Numeric)
+                    && !is_wide_punctuation(before_chr) =>
+            {
+                Prohibited
+            }
+            // LB30b
+            (EBase, EModifier) => Prohibited,
+            // LB31
+            _ => Allowed,
+        }
+    }
+
+    /// Moves past a character of class `after`.
+    fn advance(&mut self, after: LineBreak, after_chr: char) {
+        self.regional_indicator_count = if after == LineBreak::RegionalIndicator {
+            self.regional_indicator_count + 1
+        } else {
+            0
+        };
+        if after != LineBreak::Space {
+            self.before_spaces = after;
+        }
+        self.before_before = self.before;
+        self.before = after;
+        self.before_chr = after_chr;
+        self.follows_zwj = after_chr == '\u{200D}';
+    }
+}
+
+fn line_break_opportunities(string: &str) -> Vec<Boundary> {
+    let mut characters = string.char_indices();
+    let mut context = match characters.next() {
+        Some((_, chr)) => LineBreakContext::new(chr),
+        None => return vec![],
+    };
+
+    let mut opportunities = vec![];
+    for (offset, chr) in characters {
+        let mut after = resolved_line_break(chr);
+        if matches!(after, LineBreak::CombiningMark | LineBreak::ZWJ) {
+            if is_hard_line_break(context.before)
+                || matches!(context.before, LineBreak::Space | LineBreak::ZWSpace)
+            {
+                // LB10, combining marks without a base character are treated as alphabetic
+                after = LineBreak::Alphabetic;
+            } else {
+                // LB9, combining marks take the class of their base character
+                context.follows_zwj = chr == '\u{200D}';
+                continue;
+            }
+        }
+
+        match context.action(after, chr) {
+            LineBreakAction::Mandatory => opportunities.push(Boundary {
+                offset,
+                is_mandatory: true,
+            }),
+            LineBreakAction::Allowed => opportunities.push(Boundary::new(offset)),
+            LineBreakAction::Prohibited => {}
+        }
+        context.advance(after, chr);
+    }
+
+    // LB3
+    opportunities.push(Boundary {
+        offset: string.len(),
+        is_mandatory: true,
+    });
+    opportunities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segments(string: &str, kind: BoundaryKind) -> Vec<&str> {
+        let mut start = 0;
+        boundaries(string, kind)
+            .iter()
+            .map(|boundary| {
+                let segment = &string[start..boundary.offset];
+                start = boundary.offset;
+                segment
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        assert_eq!(
+            segments("Hello, world.", BoundaryKind::Word),
+            vec!["Hello", ",", " ", "world", "."]
+        );
+    }
+
+    #[test]
+    fn test_sentence_boundaries() {
+        assert_eq!(
+            segments(
+                "Apples, pears etc. are fruits. He said \"Hi!\" Then left.\nBye",
+                BoundaryKind::Sentence
+            ),
+            vec![
+                "Apples, pears etc. are fruits. ",
+                "He said \"Hi!\" ",
+                "Then left.\n",
+                "Bye"
+            ]
+        );
+        assert_eq!(
+            segments("3.14 is pi. ", BoundaryKind::Sentence),
+            vec!["3.14 is pi. "]
+        );
+        assert!(segments("", BoundaryKind::Sentence).is_empty());
+    }
+
+    #[test]
+    fn test_line_break_opportunities() {
+        assert_eq!(
+            segments(
+                "The quick (\"brown\") fox can't jump 32.3 feet, right?",
+                BoundaryKind::Line
+            ),
+            vec![
+                "The ",
+                "quick ",
+                "(\"brown\") ",
+                "fox ",
+                "can't ",
+                "jump ",
+                "32.3 ",
+                "feet, ",
+                "right?"
+            ]
+        );
+        assert_eq!(
+            segments("e\u{301}-mail\r\nnext", BoundaryKind::Line),
+            vec!["e\u{301}-", "mail\r\n", "next"]
+        );
+        assert_eq!(
+            segments(
+                "中文字\u{1F1E8}\u{1F1F3}\u{1F1EF}\u{1F1F5}",
+                BoundaryKind::Line
+            ),
+            vec!["中", "文", "字", "\u{1F1E8}\u{1F1F3}", "\u{1F1EF}\u{1F1F5}"]
+        );
+
+        let boundaries = boundaries("a\nb", BoundaryKind::Line);
+        assert_eq!(
+            boundaries,
+            vec![
+                Boundary {
+                    offset: 2,
+                    is_mandatory: true
+                },
+                Boundary {
+                    offset: 3,
+                    is_mandatory: true
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_break_opportunities_before_wide_punctuation() {
+        assert_eq!(segments("f(x)", BoundaryKind::Line), vec!["f(x)"]);
+        assert_eq!(
+            segments("f\u{FF08}x\u{FF09}", BoundaryKind::Line),
+            vec!["f", "\u{FF08}x\u{FF09}"]
+        );
+        assert_eq!(
+            segments("f\u{FF62}x\u{FF63}", BoundaryKind::Line),
+            vec!["f", "\u{FF62}x\u{FF63}"]
+        );
+    }
+}
@@ -0,0 +1,566 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Case mapping and case folding, including the full and language-sensitive mappings of
+//! SpecialCasing.txt, which are not yet provided by `unic::ucd`.
+//!
+//! Mapping strings follows "Default Case Conversion" in section 3.13 of the Unicode Standard.
+//!
+
+mod tables;
+
+use unic::ucd::normal::compose;
+use unic::ucd::{CanonicalCombiningClass, GeneralCategory};
+
+use super::{boundaries, full_canonical_decomposition, is_in_ranges, BoundaryKind, Script};
+
+const COMBINING_DOT_ABOVE: char = '\u{0307}';
+const COMBINING_DIAERESIS: char = '\u{0308}';
+const COMBINING_GREEK_DIALYTIKA_TONOS: char = '\u{0344}';
+const GREEK_CAPITAL_LETTER_SIGMA: char = '\u{03A3}';
+
+// Accents and breathing marks, which are removed when uppercasing Greek
+const GREEK_ACCENTS: [char; 6] = [
+    '\u{0300}', '\u{0301}', '\u{0313}', '\u{0314}', '\u{0342}', '\u{0343}',
+];
+const GREEK_VOWELS: &str = "\u{03B1}\u{03B5}\u{03B7}\u{03B9}\u{03BF}\u{03C5}\u{03C9}\
+                            \u{0391}\u{0395}\u{0397}\u{0399}\u{039F}\u{03A5}\u{03A9}";
+
+// Canonical combining classes which stop the search for the context of a conditional mapping
+const CCC_NOT_REORDERED: u8 = 0;
+const CCC_ABOVE: u8 = 230;
+
+/// Languages with their own rules in SpecialCasing.txt.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CaseLanguage {
+    Default,
+    // Turkish and Azeri, which have dotted and dotless I
+    Turkic,
+    // Lithuanian, which keeps the dot of i and j under accents
+    Lithuanian,
+    // Greek, whose uppercase letters have no accents (as in CLDR's el-Upper transform)
+    Greek,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CaseOperation {
+    Lowercase,
+    Uppercase,
+    Titlecase,
+    CaseFold,
+    SimpleCaseFold,
+}
+
+fn mapping_of(mappings: &'static [(char, &'static [char])], chr: char) -> Option<&'static [char]> {
+    mappings
+        .binary_search_by_key(&chr, |(from, _)| *from)
+        .ok()
+        .map(|index| mappings[index].1)
+}
+
+/// The full uppercase mapping of `chr`, `None` if it is unchanged.
+pub fn uppercase_of(chr: char) -> Option<&'static [char]> {
+    mapping_of(tables::UPPERCASE_MAPPINGS, chr)
+}
+
+/// The full lowercase mapping of `chr`, `None` if it is unchanged.
+pub fn lowercase_of(chr: char) -> Option<&'static [char]> {
+    mapping_of(tables::LOWERCASE_MAPPINGS, chr)
+}
+
+/// The full titlecase mapping of `chr`, `None` if it is unchanged.
+pub fn titlecase_of(chr: char) -> Option<&'static [char]> {
+    mapping_of(tables::TITLECASE_MAPPINGS, chr)
+}
+
+/// The full case folding of `chr`, `None` if it is unchanged.
+pub fn case_folding_of(chr: char) -> Option<&'static [char]> {
+    mapping_of(tables::CASE_FOLDINGS, chr)
+}
+
+/// The simple (single character) case folding of `chr`, `None` if it is unchanged.
+pub fn simple_case_folding_of(chr: char) -> Option<char> {
+    tables::SIMPLE_CASE_FOLDINGS
+        .binary_search_by_key(&chr, |(from, _)| *from)
+        .ok()
+        .map(|index| tables::SIMPLE_CASE_FOLDINGS[index].1)
+}
+
+pub fn changes_when_lowercased(chr: char) -> bool {
+    is_in_ranges(tables::CHANGES_WHEN_LOWERCASED_RANGES, chr)
+}
+
+pub fn changes_when_uppercased(chr: char) -> bool {
+    is_in_ranges(tables::CHANGES_WHEN_UPPERCASED_RANGES, chr)
+}
+
+pub fn changes_when_titlecased(chr: char) -> bool {
+    is_in_ranges(tables::CHANGES_WHEN_TITLECASED_RANGES, chr)
+}
+
+pub fn changes_when_casefolded(chr: char) -> bool {
+    is_in_ranges(tables::CHANGES_WHEN_CASEFOLDED_RANGES, chr)
+}
+
+pub fn changes_when_casemapped(chr: char) -> bool {
+    is_in_ranges(tables::CHANGES_WHEN_CASEMAPPED_RANGES, chr)
+}
+
+pub fn changes_when_nfkc_casefolded(chr: char) -> bool {
+    is_in_ranges(tables::CHANGES_WHEN_NFKC_CASEFOLDED_RANGES, chr)
+}
+
+fn is_cased(chr: char) -> bool {
+    is_in_ranges(tables::CASED_RANGES, chr)
+}
+
+fn is_case_ignorable(chr: char) -> bool {
+    is_in_ranges(tables::CASE_IGNORABLE_RANGES, chr)
+}
+
+fn is_soft_dotted(chr: char) -> bool {
+    is_in_ranges(tables::SOFT_DOTTED_RANGES, chr)
+}
+
+fn ccc(chr: char) -> u8 {
+    CanonicalCombiningClass::of(chr).number()
+}
+
+// The casing contexts of SpecialCasing.txt, see table 3-17 of the Unicode Standard
+
+fn is_final_sigma(characters: &[char], index: usize) -> bool {
+    let is_not_case_ignorable = |chr: &&char| !is_case_ignorable(**chr);
+    let previous = characters[..index].iter().rev().find(is_not_case_ignorable);
+    let next = characters[index + 1..].iter().find(is_not_case_ignorable);
+    matches!(previous, Some(chr) if is_cased(*chr)) && !matches!(next, Some(chr) if is_cased(*chr))
+}
+
+// Other contexts only look at characters up to the next base character or combining mark above
+fn ends_context(chr: char) -> bool {
+    matches!(ccc(chr), CCC_NOT_REORDERED | CCC_ABOVE)
+}
+
+fn is_after_soft_dotted(characters: &[char], index: usize) -> bool {
+    let previous = characters[..index]
+        .iter()
+        .rev()
+        .find(|chr| is_soft_dotted(**chr) || ends_context(**chr));
+    matches!(previous, Some(chr) if is_soft_dotted(*chr))
+}
+
+fn is_more_above(characters: &[char], index: usize) -> bool {
+    let next = characters[index + 1..]
+        .iter()
+        .find(|chr| ends_context(**chr));
+    matches!(next, Some(chr) if ccc(*chr) == CCC_ABOVE)
+}
+
+fn is_before_dot(characters: &[char], index: usize) -> bool {
+    let next = characters[index + 1..]
+        .iter()
+        .find(|chr| **chr == COMBINING_DOT_ABOVE || ends_context(**chr));
+    next == Some(&COMBINING_DOT_ABOVE)
+}
+
+fn is_after_i(characters: &[char], index: usize) -> bool {
+    let previous = characters[..index]
+        .iter()
+        .rev()
+        .find(|chr| **chr == 'I' || ends_context(**chr));
+    previous == Some(&'I')
+}
+
+/// The conditional mappings of SpecialCasing.txt, which depend on the language or the
+/// surrounding characters.
+fn conditional_mapping(
+    characters: &[char],
+    index: usize,
+    operation: CaseOperation,
+    language: CaseLanguage,
+) -> Option<&'static [char]> {
+    use CaseLanguage::{Lithuanian, Turkic};
+
+    let chr = characters[index];
+    match operation {
+        CaseOperation::Lowercase => match (language, chr) {
+            (Turkic, '\u{0130}') => Some(&['i']),
+            (Turkic, COMBINING_DOT_ABOVE) if is_after_i(characters, index) => Some(&[]),
+            (Turkic, 'I') if !is_before_dot(characters, index) => Some(&['\u{0131}']),
+            (Lithuanian, 'I') if is_more_above(characters, index) => {
+                Some(&['i', COMBINING_DOT_ABOVE])
+            }
+            (Lithuanian, 'J') if is_more_above(characters, index) => {
+                Some(&['j', COMBINING_DOT_ABOVE])
+            }
+            (Lithuanian, '\u{012E}') if is_more_above(characters, index) => {
+                Some(&['\u{012F}', COMBINING_DOT_ABOVE])
+            }
+            (Lithuanian, '\u{00CC}') => Some(&['i', COMBINING_DOT_ABOVE, '\u{0300}']),
+            (Lithuanian, '\u{00CD}') => Some(&['i', COMBINING_DOT_ABOVE, '\u{0301}']),
+            (Lithuanian, '\u{0128}') => Some(&['i', COMBINING_DOT_ABOVE, '\u{0303}']),
+            (_, GREEK_CAPITAL_LETTER_SIGMA) if is_final_sigma(characters, index) => {
+                Some(&['\u{03C2}'])
+            }
+            _ => None,
+        },
+        CaseOperation::Uppercase | CaseOperation::Titlecase => match (language, chr) {
+            (Turkic, 'i') => Some(&['\u{0130}']),
+            (Lithuanian, COMBINING_DOT_ABOVE) if is_after_soft_dotted(characters, index) => {
+                Some(&[])
+            }
+            _ => None,
+        },
+        CaseOperation::CaseFold | CaseOperation::SimpleCaseFold => match (language, chr) {
+            (Turkic, 'I') => Some(&['\u{0131}']),
+            (Turkic, '\u{0130}') => Some(&['i']),
+            _ => None,
+        },
+    }
+}
+
+fn map_character(
+    characters: &[char],
+    index: usize,
+    operation: CaseOperation,
+    language: CaseLanguage,
+) -> String {
+    let chr = characters[index];
+    if let Some(mapping) = conditional_mapping(characters, index, operation, language) {
+        return mapping.iter().collect();
+    }
+
+    let mapping = match operation {
+        CaseOperation::Lowercase => lowercase_of(chr),
+        CaseOperation::Uppercase => uppercase_of(chr),
+        CaseOperation::Titlecase => titlecase_of(chr),
+        CaseOperation::CaseFold => case_folding_of(chr),
+        CaseOperation::SimpleCaseFold => {
+            return simple_case_folding_of(chr).unwrap_or(chr).to_string();
+        }
+    };
+    match mapping {
+        Some(mapping) => mapping.iter().collect(),
+        None => chr.to_string(),
+    }
+}
+
+/// A letter of `characters` as its canonical decomposition, followed by the combining marks after
+/// it, e.g. "\u{1F00}\u{0301}" is a letter α with U+0313 and U+0301.
+struct DecomposedLetter {
+    base: char,
+    marks: Vec<char>,
+}
+
+impl DecomposedLetter {
+    fn at(characters: &[char], index: usize) -> Self {
+        let chr = characters[index];
+        let mut components = full_canonical_decomposition(chr).unwrap_or_else(|| vec![chr]);
+        let base = components.remove(0);
+        components.extend(
+            characters[index + 1..]
+                .iter()
+                .take_while(|chr| ccc(**chr) != CCC_NOT_REORDERED),
+        );
+        DecomposedLetter {
+            base,
+            marks: components,
+        }
+    }
+
+    fn has_accent(&self) -> bool {
+        self.marks
+            .iter()
+            .any(|mark| GREEK_ACCENTS.contains(mark) || *mark == COMBINING_GREEK_DIALYTIKA_TONOS)
+    }
+
+    fn has_diaeresis(&self) -> bool {
+        self.marks
+            .iter()
+            .any(|mark| *mark == COMBINING_DIAERESIS || *mark == COMBINING_GREEK_DIALYTIKA_TONOS)
+    }
+}
+
+/// The index of the letter which the character at `index` belongs to, i.e. the character itself
+/// unless it's a combining mark.
+fn letter_index(characters: &[char], index: usize) -> usize {
+    (0..=index)
+        .rev()
+        .find(|index| ccc(characters[*index]) == CCC_NOT_REORDERED)
+        .unwrap_or(0)
+}
+
+fn is_letter(chr: char) -> bool {
+    GeneralCategory::of(chr).is_letter()
+}
+
+/// Whether the letter at `index` is the conjunction "ή" (or), which keeps its accent so that it's
+/// not confused with the article "η".
+fn is_disjunctive_eta(characters: &[char], index: usize, letter: &DecomposedLetter) -> bool {
+    let next_index = index
+        + 1
+        + characters[index + 1..]
+            .iter()
+            .take_while(|chr| ccc(**chr) != CCC_NOT_REORDERED)
+            .count();
+    matches!(letter.base, '\u{03B7}' | '\u{0397}')
+        && letter.has_accent()
+        && !(index > 0 && is_letter(characters[letter_index(characters, index - 1)]))
+        && !matches!(characters.get(next_index), Some(chr) if is_letter(*chr))
+}
+
+/// Whether the letter at `index` is an ι or υ after an accented vowel, which is not a diphthong
+/// with the vowel, e.g. "άι" is uppercased to "ΑΪ". The diaeresis keeps this apparent once the
+/// accent is removed.
+fn needs_diaeresis(characters: &[char], index: usize, letter: &DecomposedLetter) -> bool {
+    if !matches!(
+        letter.base,
+        '\u{03B9}' | '\u{03C5}' | '\u{0399}' | '\u{03A5}'
+    ) || letter.has_accent()
+        || letter.has_diaeresis()
+        || index == 0
+    {
+        return false;
+    }
+    let previous_index = letter_index(characters, index - 1);
+    let previous = DecomposedLetter::at(characters, previous_index);
+    GREEK_VOWELS.contains(previous.base)
+        && previous.has_accent()
+        && !previous.has_diaeresis()
+        && !is_disjunctive_eta(characters, previous_index, &previous)
+}
+
+/// Uppercases the character at `index` of Greek text, removing accents and breathing marks
+/// (except the diaeresis) and adding a diaeresis where it's needed to tell vowels apart from
+/// diphthongs. Mappings of other scripts are not changed.
+fn greek_uppercase(characters: &[char], index: usize) -> String {
+    let chr = characters[index];
+    let letter_index = letter_index(characters, index);
+    let letter = DecomposedLetter::at(characters, letter_index);
+    if Script::of(letter.base) != Script::Greek {
+        return map_character(
+            characters,
+            index,
+            CaseOperation::Uppercase,
+            CaseLanguage::Default,
+        );
+    }
+
+    let keeps_accents = is_disjunctive_eta(characters, letter_index, &letter);
+    let map_mark = |mark: char| match mark {
+        _ if keeps_accents => Some(mark),
+        COMBINING_GREEK_DIALYTIKA_TONOS => Some(COMBINING_DIAERESIS),
+        _ if GREEK_ACCENTS.contains(&mark) => None,
+        // Ypogegrammeni is uppercased to a capital iota after the letter
+        _ => Some(mark),
+    };
+
+    let mut components: Vec<char> = if letter_index == index {
+        let mut components = full_canonical_decomposition(chr).unwrap_or_else(|| vec![chr]);
+        let base = components.remove(0);
+        let mut uppercased: Vec<char> = uppercase_of(base).map_or(vec![base], <[char]>::to_vec);
+        uppercased.extend(components.into_iter().filter_map(map_mark));
+        if needs_diaeresis(characters, index, &letter) {
+            uppercased.push(COMBINING_DIAERESIS);
+        }
+        uppercased
+    } else {
+        map_mark(chr).into_iter().collect()
+    };
+    components = components
+        .into_iter()
+        .flat_map(|chr| uppercase_of(chr).map_or(vec![chr], <[char]>::to_vec))
+        .collect();
+
+    // Composes the result again, e.g. to U+03AA GREEK CAPITAL LETTER IOTA WITH DIALYTIKA
+    let mut composed = String::new();
+    let mut components = components.into_iter();
+    if let Some(mut previous) = components.next() {
+        for chr in components {
+            match compose(previous, chr) {
+                Some(composition) => previous = composition,
+                None => {
+                    composed.push(previous);
+                    previous = chr;
+                }
+            }
+        }
+        composed.push(previous);
+    }
+    composed
+}
+
+/// Maps the case of each character of `string`, in the same order as `string.chars()`. Characters
+/// may be mapped to several characters (e.g. "ß" to "SS") or removed.
+///
+/// Titlecasing maps the first cased character of each word to titlecase, and the rest of the word
+/// to lowercase.
+pub fn case_mappings(
+    string: &str,
+    operation: CaseOperation,
+    language: CaseLanguage,
+) -> Vec<String> {
+    let characters: Vec<char> = string.chars().collect();
+    if operation == CaseOperation::Uppercase && language == CaseLanguage::Greek {
+        return (0..characters.len())
+            .map(|index| greek_uppercase(&characters, index))
+            .collect();
+    }
+    if operation != CaseOperation::Titlecase {
+        return (0..characters.len())
+            .map(|index| map_character(&characters, index, operation, language))
+            .collect();
+    }
+
+    let word_boundaries: Vec<usize> = boundaries(string, BoundaryKind::Word)
+        .iter()
+        .map(|boundary| boundary.offset)
+        .collect();
+    let mut is_titlecased = false;
+    string
+        .char_indices()
+        .enumerate()
+        .map(|(index, (offset, chr))| {
+            if word_boundaries.binary_search(&offset).is_ok() {
+                is_titlecased = false;
+            }
+            if !is_titlecased && is_cased(chr) {
+                is_titlecased = true;
+                map_character(&characters, index, CaseOperation::Titlecase, language)
+            } else {
+                map_character(&characters, index, CaseOperation::Lowercase, language)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(string: &str, operation: CaseOperation, language: CaseLanguage) -> String {
+        case_mappings(string, operation, language).concat()
+    }
+
+    #[test]
+    fn test_character_mappings() {
+        assert_eq!(uppercase_of('a'), Some(&['A'][..]));
+        assert_eq!(uppercase_of('A'), None);
+        assert_eq!(uppercase_of('\u{00DF}'), Some(&['S', 'S'][..]));
+        assert_eq!(titlecase_of('\u{00DF}'), Some(&['S', 's'][..]));
+        assert_eq!(titlecase_of('\u{01C6}'), Some(&['\u{01C5}'][..]));
+        assert_eq!(lowercase_of('\u{0130}'), Some(&['i', '\u{0307}'][..]));
+        assert_eq!(case_folding_of('\u{1E9E}'), Some(&['s', 's'][..]));
+        assert_eq!(simple_case_folding_of('\u{1E9E}'), Some('\u{00DF}'));
+        assert_eq!(simple_case_folding_of('a'), None);
+        assert!(changes_when_uppercased('a'));
+        assert!(!changes_when_uppercased('A'));
+        assert!(changes_when_casefolded('\u{00DF}'));
+        assert!(changes_when_nfkc_casefolded('\u{00AD}'));
+    }
+
+    #[test]
+    fn test_default_case_conversion() {
+        use CaseLanguage::Default;
+
+        assert_eq!(
+            convert("Straße", CaseOperation::Uppercase, Default),
+            "STRASSE"
+        );
+        assert_eq!(
+            convert("Straße", CaseOperation::CaseFold, Default),
+            "strasse"
+        );
+        assert_eq!(
+            convert("Straße", CaseOperation::SimpleCaseFold, Default),
+            "straße"
+        );
+        assert_eq!(
+            convert("ǆemal o'neil, ﬁsh", CaseOperation::Titlecase, Default),
+            "ǅemal O'neil, Fish"
+        );
+        // Final sigma
+        assert_eq!(
+            convert("ΟΔΥΣΣΕΥΣ Σ", CaseOperation::Lowercase, Default),
+            "οδυσσευς σ"
+        );
+    }
+
+    #[test]
+    fn test_language_sensitive_case_conversion() {
+        assert_eq!(
+            convert("Iıİi", CaseOperation::Lowercase, CaseLanguage::Turkic),
+            "ııii"
+        );
+        assert_eq!(
+            convert("Iıİi", CaseOperation::Uppercase, CaseLanguage::Turkic),
+            "IIİİ"
+        );
+        assert_eq!(
+            convert("I\u{0307}", CaseOperation::Lowercase, CaseLanguage::Turkic),
+            "i"
+        );
+        assert_eq!(
+            convert("Iİ", CaseOperation::CaseFold, CaseLanguage::Turkic),
+            "ıi"
+        );
+        assert_eq!(
+            convert(
+                "I\u{0300}Ì",
+                CaseOperation::Lowercase,
+                CaseLanguage::Lithuanian
+            ),
+            "i\u{0307}\u{0300}i\u{0307}\u{0300}"
+        );
+        assert_eq!(
+            convert(
+                "i\u{0307}\u{0300}",
+                CaseOperation::Uppercase,
+                CaseLanguage::Lithuanian
+            ),
+            "I\u{0300}"
+        );
+    }
+
+    #[test]
+    fn test_greek_uppercasing() {
+        let uppercase = |string| convert(string, CaseOperation::Uppercase, CaseLanguage::Greek);
+
+        assert_eq!(
+            uppercase("άδικος, κείμενο, ίριδα"),
+            "ΑΔΙΚΟΣ, ΚΕΙΜΕΝΟ, ΙΡΙΔΑ"
+        );
+        assert_eq!(uppercase("Πατάτα"), "ΠΑΤΑΤΑ");
+        // Breathing marks and perispomeni
+        assert_eq!(uppercase("ἀλήθεια, ὑπό, τῶν"), "ΑΛΗΘΕΙΑ, ΥΠΟ, ΤΩΝ");
+        // Decomposed accents
+        assert_eq!(uppercase("ε\u{0301}να"), "ΕΝΑ");
+        // The diaeresis is kept, and added to vowels after an accented vowel
+        assert_eq!(uppercase("προϊόν, ΐ"), "ΠΡΟΪΟΝ, Ϊ");
+        assert_eq!(uppercase("Μάιος, τρόλεϊ, άυλος"), "ΜΑΪΟΣ, ΤΡΟΛΕΪ, ΑΫΛΟΣ");
+        assert_eq!(uppercase("παιδιά, αύριο"), "ΠΑΙΔΙΑ, ΑΥΡΙΟ");
+        // Ypogegrammeni becomes a capital iota
+        assert_eq!(uppercase("ᾠδή"), "ΩΙΔΗ");
+        // The conjunction "ή" keeps its accent
+        assert_eq!(uppercase("ή"), "Ή");
+        assert_eq!(uppercase("μία ή δύο"), "ΜΙΑ Ή ΔΥΟ");
+        assert_eq!(uppercase("ήταν"), "ΗΤΑΝ");
+        // Other scripts are unaffected
+        assert_eq!(uppercase("café straße"), "CAFÉ STRASSE");
+        assert_eq!(
+            convert("Άδικος", CaseOperation::Lowercase, CaseLanguage::Greek),
+            "άδικος"
+        );
+    }
+}
@@ -0,0 +1,11239 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (UnicodeData.txt, SpecialCasing.txt,
+// CaseFolding.txt, PropList.txt and DerivedCoreProperties.txt), limited to the characters
+// assigned in Unicode 13.0. Mappings only list characters which are changed, and the conditional
+// mappings of SpecialCasing.txt are implemented in `case.rs`.
+
+pub(super) const UPPERCASE_MAPPINGS: &[(char, &[char])] = &[
+    ('\u{0061}', &['\u{0041}']),
+    ('\u{0062}', &['\u{0042}']),
+    ('\u{0063}', &['\u{0043}']),
+    ('\u{0064}', &['\u{0044}']),
+    ('\u{0065}', &['\u{0045}']),
+    ('\u{0066}', &['\u{0046}']),
+    ('\u{0067}', &['\u{0047}']),
+    ('\u{0068}', &['\u{0048}']),
+    ('\u{0069}', &['\u{0049}']),
+    ('\u{006A}', &['\u{004A}']),
+    ('\u{006B}', &['\u{004B}']),
+    ('\u{006C}', &['\u{004C}']),
+    ('\u{006D}', &['\u{004D}']),
+    ('\u{006E}', &['\u{004E}']),
+    ('\u{006F}', &['\u{004F}']),
+    ('\u{0070}', &['\u{0050}']),
+    ('\u{0071}', &['\u{0051}']),
+    ('\u{0072}', &['\u{0052}']),
+    ('\u{0073}', &['\u{0053}']),
+    ('\u{0074}', &['\u{0054}']),
+    ('\u{0075}', &['\u{0055}']),
+    ('\u{0076}', &['\u{0056}']),
+    ('\u{0077}', &['\u{0057}']),
+    ('\u{0078}', &['\u{0058}']),
+    ('\u{0079}', &['\u{0059}']),
+    ('\u{007A}', &['\u{005A}']),
+    ('\u{00B5}', &['\u{039C}']),
+    ('\u{00DF}', &['\u{0053}', '\u{0053}']),
+    ('\u{00E0}', &['\u{00C0}']),
+    ('\u{00E1}', &['\u{00C1}']),
+    ('\u{00E2}', &['\u{00C2}']),
+    ('\u{00E3}', &['\u{00C3}']),
+    ('\u{00E4}', &['\u{00C4}']),
+    ('\u{00E5}', &['\u{00C5}']),
+    ('\u{00E6}', &['\u{00C6}']),
+    ('\u{00E7}', &['\u{00C7}']),
+    ('\u{00E8}', &['\u{00C8}']),
+    ('\u{00E9}', &['\u{00C9}']),
+    ('\u{00EA}', &['\u{00CA}']),
+    ('\u{00EB}', &['\u{00CB}']),
+    ('\u{00EC}', &['\u{00CC}']),
+    ('\u{00ED}', &['\u{00CD}']),
+    ('\u{00EE}', &['\u{00CE}']),
+    ('\u{00EF}', &['\u{00CF}']),
+    ('\u{00F0}', &['\u{00D0}']),
+    ('\u{00F1}', &['\u{00D1}']),
+    ('\u{00F2}', &['\u{00D2}']),
+    ('\u{00F3}', &['\u{00D3}']),
+    ('\u{00F4}', &['\u{00D4}']),
+    ('\u{00F5}', &['\u{00D5}']),
+    ('\u{00F6}', &['\u{00D6}']),
+    ('\u{00F8}', &['\u{00D8}']),
+    ('\u{00F9}', &['\u{00D9}']),
+    ('\u{00FA}', &['\u{00DA}']),
+    ('\u{00FB}', &['\u{00DB}']),
+    ('\u{00FC}', &['\u{00DC}']),
+    ('\u{00FD}', &['\u{00DD}']),
+    ('\u{00FE}', &['\u{00DE}']),
+    ('\u{00FF}', &['\u{0178}']),
+    ('\u{0101}', &['\u{0100}']),
+    ('\u{0103}', &['\u{0102}']),
+    ('\u{0105}', &['\u{0104}']),
+    ('\u{0107}', &['\u{0106}']),
+    ('\u{0109}', &['\u{0108}']),
+    ('\u{010B}', &['\u{010A}']),
+    ('\u{010D}', &['\u{010C}']),
+    ('\u{010F}', &['\u{010E}']),
+    ('\u{0111}', &['\u{0110}']),
+    ('\u{0113}', &['\u{0112}']),
+    ('\u{0115}', &['\u{0114}']),
+    ('\u{0117}', &['\u{0116}']),
+    ('\u{0119}', &['\u{0118}']),
+    ('\u{011B}', &['\u{011A}']),
+    ('\u{011D}', &['\u{011C}']),
+    ('\u{011F}', &['\u{011E}']),
+    ('\u{0121}', &['\u{0120}']),
+    ('\u{0123}', &['\u{0122}']),
+    ('\u{0125}', &['\u{0124}']),
+    ('\u{0127}', &['\u{0126}']),
+    ('\u{0129}', &['\u{0128}']),
+    ('\u{012B}', &['\u{012A}']),
+    ('\u{012D}', &['\u{012C}']),
+    ('\u{012F}', &['\u{012E}']),
+    ('\u{0131}', &['\u{0049}']),
+    ('\u{0133}', &['\u{0132}']),
+    ('\u{0135}', &['\u{0134}']),
+    ('\u{0137}', &['\u{0136}']),
+    ('\u{013A}', &['\u{0139}']),
+    ('\u{013C}', &['\u{013B}']),
+    ('\u{013E}', &['\u{013D}']),
+    ('\u{0140}', &['\u{013F}']),
+    ('\u{0142}', &['\u{0141}']),
+    ('\u{0144}', &['\u{0143}']),
+    ('\u{0146}', &['\u{0145}']),
+    ('\u{0148}', &['\u{0147}']),
+    ('\u{0149}', &['\u{02BC}', '\u{004E}']),
+    ('\u{014B}', &['\u{014A}']),
+    ('\u{014D}', &['\u{014C}']),
+    ('\u{014F}', &['\u{014E}']),
+    ('\u{0151}', &['\u{0150}']),
+    ('\u{0153}', &['\u{0152}']),
+    ('\u{0155}', &['\u{0154}']),
+    ('\u{0157}', &['\u{0156}']),
+    ('\u{0159}', &['\u{0158}']),
+    ('\u{015B}', &['\u{015A}']),
+    ('\u{015D}', &['\u{015C}']),
+    ('\u{015F}', &['\u{015E}']),
+    ('\u{0161}', &['\u{0160}']),
+    ('\u{0163}', &['\u{0162}']),
+    ('\u{0165}', &['\u{0164}']),
+    ('\u{0167}', &['\u{0166}']),
+    ('\u{0169}', &['\u{0168}']),
+    ('\u{016B}', &['\u{016A}']),
+    ('\u{016D}', &['\u{016C}']),
+    ('\u{016F}', &['\u{016E}']),
+    ('\u{0171}', &['\u{0170}']),
+    ('\u{0173}', &['\u{0172}']),
+    ('\u{0175}', &['\u{0174}']),
+    ('\u{0177}', &['\u{0176}']),
+    ('\u{017A}', &['\u{0179}']),
+    ('\u{017C}', &['\u{017B}']),
+    ('\u{017E}', &['\u{017D}']),
+    ('\u{017F}', &['\u{0053}']),
+    ('\u{0180}', &['\u{0243}']),
+    ('\u{0183}', &['\u{0182}']),
+    ('\u{0185}', &['\u{0184}']),
+    ('\u{0188}', &['\u{0187}']),
+    ('\u{018C}', &['\u{018B}']),
+    ('\u{0192}', &['\u{0191}']),
+    ('\u{0195}', &['\u{01F6}']),
+    ('\u{0199}', &['\u{0198}']),
+    ('\u{019A}', &['\u{023D}']),
+    ('\u{019E}', &['\u{0220}']),
+    ('\u{01A1}', &['\u{01A0}']),
+    ('\u{01A3}', &['\u{01A2}']),
+    ('\u{01A5}', &['\u{01A4}']),
+    ('\u{01A8}', &['\u{01A7}']),
+    ('\u{01AD}', &['\u{01AC}']),
+    ('\u{01B0}', &['\u{01AF}']),
+    ('\u{01B4}', &['\u{01B3}']),
+    ('\u{01B6}', &['\u{01B5}']),
+    ('\u{01B9}', &['\u{01B8}']),
+    ('\u{01BD}', &['\u{01BC}']),
+    ('\u{01BF}', &['\u{01F7}']),
+    ('\u{01C5}', &['\u{01C4}']),
+    ('\u{01C6}', &['\u{01C4}']),
+    ('\u{01C8}', &['\u{01C7}']),
+    ('\u{01C9}', &['\u{01C7}']),
+    ('\u{01CB}', &['\u{01CA}']),
+    ('\u{01CC}', &['\u{01CA}']),
+    ('\u{01CE}', &['\u{01CD}']),
+    ('\u{01D0}', &['\u{01CF}']),
+    ('\u{01D2}', &['\u{01D1}']),
+    ('\u{01D4}', &['\u{01D3}']),
+    ('\u{01D6}', &['\u{01D5}']),
+    ('\u{01D8}', &['\u{01D7}']),
+    ('\u{01DA}', &['\u{01D9}']),
+    ('\u{01DC}', &['\u{01DB}']),
+    ('\u{01DD}', &['\u{018E}']),
+    ('\u{01DF}', &['\u{01DE}']),
+    ('\u{01E1}', &['\u{01E0}']),
+    ('\u{01E3}', &['\u{01E2}']),
+    ('\u{01E5}', &['\u{01E4}']),
+    ('\u{01E7}', &['\u{01E6}']),
+    ('\u{01E9}', &['\u{01E8}']),
+    ('\u{01EB}', &['\u{01EA}']),
+    ('\u{01ED}', &['\u{01EC}']),
+    ('\u{01EF}', &['\u{01EE}']),
+    ('\u{01F0}', &['\u{004A}', '\u{030C}']),
+    ('\u{01F2}', &['\u{01F1}']),
+    ('\u{01F3}', &['\u{01F1}']),
+    ('\u{01F5}', &['\u{01F4}']),
+    ('\u{01F9}', &['\u{01F8}']),
+    ('\u{01FB}', &['\u{01FA}']),
+    ('\u{01FD}', &['\u{01FC}']),
+    ('\u{01FF}', &['\u{01FE}']),
+    ('\u{0201}', &['\u{0200}']),
+    ('\u{0203}', &['\u{0202}']),
+    ('\u{0205}', &['\u{0204}']),
+    ('\u{0207}', &['\u{0206}']),
+    ('\u{0209}', &['\u{0208}']),
+    ('\u{020B}', &['\u{020A}']),
+    ('\u{020D}', &['\u{020C}']),
+    ('\u{020F}', &['\u{020E}']),
+    ('\u{0211}', &['\u{0210}']),
+    ('\u{0213}', &['\u{0212}']),
+    ('\u{0215}', &['\u{0214}']),
+    ('\u{0217}', &['\u{0216}']),
+    ('\u{0219}', &['\u{0218}']),
+    ('\u{021B}', &['\u{021A}']),
+    ('\u{021D}', &['\u{021C}']),
+    ('\u{021F}', &['\u{021E}']),
+    ('\u{0223}', &['\u{0222}']),
+    ('\u{0225}', &['\u{0224}']),
+    ('\u{0227}', &['\u{0226}']),
+    ('\u{0229}', &['\u{0228}']),
+    ('\u{022B}', &['\u{022A}']),
+    ('\u{022D}', &['\u{022C}']),
+    ('\u{022F}', &['\u{022E}']),
+    ('\u{0231}', &['\u{0230}']),
+    ('\u{0233}', &['\u{0232}']),
+    ('\u{023C}', &['\u{023B}']),
+    ('\u{023F}', &['\u{2C7E}']),
+    ('\u{0240}', &['\u{2C7F}']),
+    ('\u{0242}', &['\u{0241}']),
+    ('\u{0247}', &['\u{0246}']),
+    ('\u{0249}', &['\u{0248}']),
+    ('\u{024B}', &['\u{024A}']),
+    ('\u{024D}', &['\u{024C}']),
+    ('\u{024F}', &['\u{024E}']),
+    ('\u{0250}', &['\u{2C6F}']),
+    ('\u{0251}', &['\u{2C6D}']),
+    ('\u{0252}', &['\u{2C70}']),
+    ('\u{0253}', &['\u{0181}']),
+    ('\u{0254}', &['\u{0186}']),
+    ('\u{0256}', &['\u{0189}']),
+    ('\u{0257}', &['\u{018A}']),
+    ('\u{0259}', &['\u{018F}']),
+    ('\u{025B}', &['\u{0190}']),
+    ('\u{025C}', &['\u{A7AB}']),
+    ('\u{0260}', &['\u{0193}']),
+    ('\u{0261}', &['\u{A7AC}']),
+    ('\u{0263}', &['\u{0194}']),
+    ('\u{0265}', &['\u{A78D}']),
+    ('\u{0266}', &['\u{A7AA}']),
+    ('\u{0268}', &['\u{0197}']),
+    ('\u{0269}', &['\u{0196}']),
+    ('\u{026A}', &['\u{A7AE}']),
+    ('\u{026B}', &['\u{2C62}']),
+    ('\u{026C}', &['\u{A7AD}']),
+    ('\u{026F}', &['\u{019C}']),
+    ('\u{0271}', &['\u{2C6E}']),
+    ('\u{0272}', &['\u{019D}']),
+    ('\u{0275}', &['\u{019F}']),
+    ('\u{027D}', &['\u{2C64}']),
+    ('\u{0280}', &['\u{01A6}']),
+    ('\u{0282}', &['\u{A7C5}']),
+    ('\u{0283}', &['\u{01A9}']),
+    ('\u{0287}', &['\u{A7B1}']),
+    ('\u{0288}', &['\u{01AE}']),
+    ('\u{0289}', &['\u{0244}']),
+    ('\u{028A}', &['\u{01B1}']),
+    ('\u{028B}', &['\u{01B2}']),
+    ('\u{028C}', &['\u{0245}']),
+    ('\u{0292}', &['\u{01B7}']),
+    ('\u{029D}', &['\u{A7B2}']),
+    ('\u{029E}', &['\u{A7B0}']),
+    ('\u{0345}', &['\u{0399}']),
+    ('\u{0371}', &['\u{0370}']),
+    ('\u{0373}', &['\u{0372}']),
+    ('\u{0377}', &['\u{0376}']),
+    ('\u{037B}', &['\u{03FD}']),
+    ('\u{037C}', &['\u{03FE}']),
+    ('\u{037D}', &['\u{03FF}']),
+    ('\u{0390}', &['\u{0399}', '\u{0308}', '\u{0301}']),
+    ('\u{03AC}', &['\u{0386}']),
+    ('\u{03AD}', &['\u{0388}']),
+    ('\u{03AE}', &['\u{0389}']),
+    ('\u{03AF}', &['\u{038A}']),
+    ('\u{03B0}', &['\u{03A5}', '\u{0308}', '\u{0301}']),
+    ('\u{03B1}', &['\u{0391}']),
+    ('\u{03B2}', &['\u{0392}']),
+    ('\u{03B3}', &['\u{0393}']),
+    ('\u{03B4}', &['\u{0394}']),
+    ('\u{03B5}', &['\u{0395}']),
+    ('\u{03B6}', &['\u{0396}']),
+    ('\u{03B7}', &['\u{0397}']),
+    ('\u{03B8}', &['\u{0398}']),
+    ('\u{03B9}', &['\u{0399}']),
+    ('\u{03BA}', &['\u{039A}']),
+    ('\u{03BB}', &['\u{039B}']),
+    ('\u{03BC}', &['\u{039C}']),
+    ('\u{03BD}', &['\u{039D}']),
+    ('\u{03BE}', &['\u{039E}']),
+    ('\u{03BF}', &['\u{039F}']),
+    ('\u{03C0}', &['\u{03A0}']),
+    ('\u{03C1}', &['\u{03A1}']),
+    ('\u{03C2}', &['\u{03A3}']),
+    ('\u{03C3}', &['\u{03A3}']),
+    ('\u{03C4}', &['\u{03A4}']),
+    ('\u{03C5}', &['\u{03A5}']),
+    ('\u{03C6}', &['\u{03A6}']),
+    ('\u{03C7}', &['\u{03A7}']),
+    ('\u{03C8}', &['\u{03A8}']),
+    ('\u{03C9}', &['\u{03A9}']),
+    ('\u{03CA}', &['\u{03AA}']),
+    ('\u{03CB}', &['\u{03AB}']),
+    ('\u{03CC}', &['\u{038C}']),
+    ('\u{03CD}', &['\u{038E}']),
+    ('\u{03CE}', &['\u{038F}']),
+    ('\u{03D0}', &['\u{0392}']),
+    ('\u{03D1}', &['\u{0398}']),
+    ('\u{03D5}', &['\u{03A6}']),
+    ('\u{03D6}', &['\u{03A0}']),
+    ('\u{03D7}', &['\u{03CF}']),
+    ('\u{03D9}', &['\u{03D8}']),
+    ('\u{03DB}', &['\u{03DA}']),
+    ('\u{03DD}', &['\u{03DC}']),
+    ('\u{03DF}', &['\u{03DE}']),
+    ('\u{03E1}', &['\u{03E0}']),
+    ('\u{03E3}', &['\u{03E2}']),
+    ('\u{03E5}', &['\u{03E4}']),
+    ('\u{03E7}', &['\u{03E6}']),
+    ('\u{03E9}', &['\u{03E8}']),
+    ('\u{03EB}', &['\u{03EA}']),
+    ('\u{03ED}', &['\u{03EC}']),
+    ('\u{03EF}', &['\u{03EE}']),
+    ('\u{03F0}', &['\u{039A}']),
+    ('\u{03F1}', &['\u{03A1}']),
+    ('\u{03F2}', &['\u{03F9}']),
+    ('\u{03F3}', &['\u{037F}']),
+    ('\u{03F5}', &['\u{0395}']),
+    ('\u{03F8}', &['\u{03F7}']),
+    ('\u{03FB}', &['\u{03FA}']),
+    ('\u{0430}', &['\u{0410}']),
+    ('\u{0431}', &['\u{0411}']),
+    ('\u{0432}', &['\u{0412}']),
+    ('\u{0433}', &['\u{0413}']),
+    ('\u{0434}', &['\u{0414}']),
+    ('\u{0435}', &['\u{0415}']),
+    ('\u{0436}', &['\u{0416}']),
+    ('\u{0437}', &['\u{0417}']),
+    ('\u{0438}', &['\u{0418}']),
+    ('\u{0439}', &['\u{0419}']),
+    ('\u{043A}', &['\u{041A}']),
+    ('\u{043B}', &['\u{041B}']),
+    ('\u{043C}', &['\u{041C}']),
+    ('\u{043D}', &['\u{041D}']),
+    ('\u{043E}', &['\u{041E}']),
+    ('\u{043F}', &['\u{041F}']),
+    ('\u{0440}', &['\u{0420}']),
+    ('\u{0441}', &['\u{0421}']),
+    ('\u{0442}', &['\u{0422}']),
+    ('\u{0443}', &['\u{0423}']),
+    ('\u{0444}', &['\u{0424}']),
+    ('\u{0445}', &['\u{0425}']),
+    ('\u{0446}', &['\u{0426}']),
+    ('\u{0447}', &['\u{0427}']),
+    ('\u{0448}', &['\u{0428}']),
+    ('\u{0449}', &['\u{0429}']),
+    ('\u{044A}', &['\u{042A}']),
+    ('\u{044B}', &['\u{042B}']),
+    ('\u{044C}', &['\u{042C}']),
+    ('\u{044D}', &['\u{042D}']),
+    ('\u{044E}', &['\u{042E}']),
+    ('\u{044F}', &['\u{042F}']),
+    ('\u{0450}', &['\u{0400}']),
+    ('\u{0451}', &['\u{0401}']),
+    ('\u{0452}', &['\u{0402}']),
+    ('\u{0453}', &['\u{0403}']),
+    ('\u{0454}', &['\u{0404}']),
+    ('\u{0455}', &['\u{0405}']),
+    ('\u{0456}', &['\u{0406}']),
+    ('\u{0457}', &['\u{0407}']),
+    ('\u{0458}', &['\u{0408}']),
+    ('\u{0459}', &['\u{0409}']),
+    ('\u{045A}', &['\u{040A}']),
+    ('\u{045B}', &['\u{040B}']),
+    ('\u{045C}', &['\u{040C}']),
+    ('\u{045D}', &['\u{040D}']),
+    ('\u{045E}', &['\u{040E}']),
+    ('\u{045F}', &['\u{040F}']),
+    ('\u{0461}', &['\u{0460}']),
+    ('\u{0463}', &['\u{0462}']),
+    ('\u{0465}', &['\u{0464}']),
+    ('\u{0467}', &['\u{0466}']),
+    ('\u{0469}', &['\u{0468}']),
+    ('\u{046B}', &['\u{046A}']),
+    ('\u{046D}', &['\u{046C}']),
+    ('\u{046F}', &['\u{046E}']),
+    ('\u{0471}', &['\u{0470}']),
+    ('\u{0473}', &['\u{0472}']),
+    ('\u{0475}', &['\u{0474}']),
+    ('\u{0477}', &['\u{0476}']),
+    ('\u{0479}', &['\u{0478}']),
+    ('\u{047B}', &['\u{047A}']),
+    ('\u{047D}', &['\u{047C}']),
+    ('\u{047F}', &['\u{047E}']),
+    ('\u{0481}', &['\u{0480}']),
+    ('\u{048B}', &['\u{048A}']),
+    ('\u{048D}', &['\u{048C}']),
+    ('\u{048F}', &['\u{048E}']),
+    ('\u{0491}', &['\u{0490}']),
+    ('\u{0493}', &['\u{0492}']),
+    ('\u{0495}', &['\u{0494}']),
+    ('\u{0497}', &['\u{0496}']),
+    ('\u{0499}', &['\u{0498}']),
+    ('\u{049B}', &['\u{049A}']),
+    ('\u{049D}', &['\u{049C}']),
+    ('\u{049F}', &['\u{049E}']),
+    ('\u{04A1}', &['\u{04A0}']),
+    ('\u{04A3}', &['\u{04A2}']),
+    ('\u{04A5}', &['\u{04A4}']),
+    ('\u{04A7}', &['\u{04A6}']),
+    ('\u{04A9}', &['\u{04A8}']),
+    ('\u{04AB}', &['\u{04AA}']),
+    ('\u{04AD}', &['\u{04AC}']),
+    ('\u{04AF}', &['\u{04AE}']),
+    ('\u{04B1}', &['\u{04B0}']),
+    ('\u{04B3}', &['\u{04B2}']),
+    ('\u{04B5}', &['\u{04B4}']),
+    ('\u{04B7}', &['\u{04B6}']),
+    ('\u{04B9}', &['\u{04B8}']),
+    ('\u{04BB}', &['\u{04BA}']),
+    ('\u{04BD}', &['\u{04BC}']),
+    ('\u{04BF}', &['\u{04BE}']),
+    ('\u{04C2}', &['\u{04C1}']),
+    ('\u{04C4}', &['\u{04C3}']),
+    ('\u{04C6}', &['\u{04C5}']),
+    ('\u{04C8}', &['\u{04C7}']),
+    ('\u{04CA}', &['\u{04C9}']),
+    ('\u{04CC}', &['\u{04CB}']),
+    ('\u{04CE}', &['\u{04CD}']),
+    ('\u{04CF}', &['\u{04C0}']),
+    ('\u{04D1}', &['\u{04D0}']),
+    ('\u{04D3}', &['\u{04D2}']),
+    ('\u{04D5}', &['\u{04D4}']),
+    ('\u{04D7}', &['\u{04D6}']),
+    ('\u{04D9}', &['\u{04D8}']),
+    ('\u{04DB}', &['\u{04DA}']),
+    ('\u{04DD}', &['\u{04DC}']),
+    ('\u{04DF}', &['\u{04DE}']),
+    ('\u{04E1}', &['\u{04E0}']),
+    ('\u{04E3}', &['\u{04E2}']),
+    ('\u{04E5}', &['\u{04E4}']),
+    ('\u{04E7}', &['\u{04E6}']),
+    ('\u{04E9}', &['\u{04E8}']),
+    ('\u{04EB}', &['\u{04EA}']),
+    ('\u{04ED}', &['\u{04EC}']),
+    ('\u{04EF}', &['\u{04EE}']),
+    ('\u{04F1}', &['\u{04F0}']),
+    ('\u{04F3}', &['\u{04F2}']),
+    ('\u{04F5}', &['\u{04F4}']),
+    ('\u{04F7}', &['\u{04F6}']),
+    ('\u{04F9}', &['\u{04F8}']),
+    ('\u{04FB}', &['\u{04FA}']),
+    ('\u{04FD}', &['\u{04FC}']),
+    ('\u{04FF}', &['\u{04FE}']),
+    ('\u{0501}', &['\u{0500}']),
+    ('\u{0503}', &['\u{0502}']),
+    ('\u{0505}', &['\u{0504}']),
+    ('\u{0507}', &['\u{0506}']),
+    ('\u{0509}', &['\u{0508}']),
+    ('\u{050B}', &['\u{050A}']),
+    ('\u{050D}', &['\u{050C}']),
+    ('\u{050F}', &['\u{050E}']),
+    ('\u{0511}', &['\u{0510}']),
+    ('\u{0513}', &['\u{0512}']),
+    ('\u{0515}', &['\u{0514}']),
+    ('\u{0517}', &['\u{0516}']),
+    ('\u{0519}', &['\u{0518}']),
+    ('\u{051B}', &['\u{051A}']),
+    ('\u{051D}', &['\u{051C}']),
+    ('\u{051F}', &['\u{051E}']),
+    ('\u{0521}', &['\u{0520}']),
+    ('\u{0523}', &['\u{0522}']),
+    ('\u{0525}', &['\u{0524}']),
+    ('\u{0527}', &['\u{0526}']),
+    ('\u{0529}', &['\u{0528}']),
+    ('\u{052B}', &['\u{052A}']),
+    ('\u{052D}', &['\u{052C}']),
+    ('\u{052F}', &['\u{052E}']),
+    ('\u{0561}', &['\u{0531}']),
+    ('\u{0562}', &['\u{0532}']),
+    ('\u{0563}', &['\u{0533}']),
+    ('\u{0564}', &['\u{0534}']),
+    ('\u{0565}', &['\u{0535}']),
+    ('\u{0566}', &['\u{0536}']),
+    ('\u{0567}', &['\u{0537}']),
+    ('\u{0568}', &['\u{0538}']),
+    ('\u{0569}', &['\u{0539}']),
+    ('\u{056A}', &['\u{053A}']),
+    ('\u{056B}', &['\u{053B}']),
+    ('\u{056C}', &['\u{053C}']),
+    ('\u{056D}', &['\u{053D}']),
+    ('\u{056E}', &['\u{053E}']),
+    ('\u{056F}', &['\u{053F}']),
+    ('\u{0570}', &['\u{0540}']),
+    ('\u{0571}', &['\u{0541}']),
+    ('\u{0572}', &['\u{0542}']),
+    ('\u{0573}', &['\u{0543}']),
+    ('\u{0574}', &['\u{0544}']),
+    ('\u{0575}', &['\u{0545}']),
+    ('\u{0576}', &['\u{0546}']),
+    ('\u{0577}', &['\u{0547}']),
+    ('\u{0578}', &['\u{0548}']),
+    ('\u{0579}', &['\u{0549}']),
+    ('\u{057A}', &['\u{054A}']),
+    ('\u{057B}', &['\u{054B}']),
+    ('\u{057C}', &['\u{054C}']),
+    ('\u{057D}', &['\u{054D}']),
+    ('\u{057E}', &['\u{054E}']),
+    ('\u{057F}', &['\u{054F}']),
+    ('\u{0580}', &['\u{0550}']),
+    ('\u{0581}', &['\u{0551}']),
+    ('\u{0582}', &['\u{0552}']),
+    ('\u{0583}', &['\u{0553}']),
+    ('\u{0584}', &['\u{0554}']),
+    ('\u{0585}', &['\u{0555}']),
+    ('\u{0586}', &['\u{0556}']),
+    ('\u{0587}', &['\u{0535}', '\u{0552}']),
+    ('\u{10D0}', &['\u{1C90}']),
+    ('\u{10D1}', &['\u{1C91}']),
+    ('\u{10D2}', &['\u{1C92}']),
+    ('\u{10D3}', &['\u{1C93}']),
+    ('\u{10D4}', &['\u{1C94}']),
+    ('\u{10D5}', &['\u{1C95}']),
+    ('\u{10D6}', &['\u{1C96}']),
+    ('\u{10D7}', &['\u{1C97}']),
+    ('\u{10D8}', &['\u{1C98}']),
+    ('\u{10D9}', &['\u{1C99}']),
+    ('\u{10DA}', &['\u{1C9A}']),
+    ('\u{10DB}', &['\u{1C9B}']),
+    ('\u{10DC}', &['\u{1C9C}']),
+    ('\u{10DD}', &['\u{1C9D}']),
+    ('\u{10DE}', &['\u{1C9E}']),
+    ('\u{10DF}', &['\u{1C9F}']),
+    ('\u{10E0}', &['\u{1CA0}']),
+    ('\u{10E1}', &['\u{1CA1}']),
+    ('\u{10E2}', &['\u{1CA2}']),
+    ('\u{10E3}', &['\u{1CA3}']),
+    ('\u{10E4}', &['\u{1CA4}']),
+    ('\u{10E5}', &['\u{1CA5}']),
+    ('\u{10E6}', &['\u{1CA6}']),
+    ('\u{10E7}', &['\u{1CA7}']),
+    ('\u{10E8}', &['\u{1CA8}']),
+    ('\u{10E9}', &['\u{1CA9}']),
+    ('\u{10EA}', &['\u{1CAA}']),
+    ('\u{10EB}', &['\u{1CAB}']),
+    ('\u{10EC}', &['\u{1CAC}']),
+    ('\u{10ED}', &['\u{1CAD}']),
+    ('\u{10EE}', &['\u{1CAE}']),
+    ('\u{10EF}', &['\u{1CAF}']),
+    ('\u{10F0}', &['\u{1CB0}']),
+    ('\u{10F1}', &['\u{1CB1}']),
+    ('\u{10F2}', &['\u{1CB2}']),
+    ('\u{10F3}', &['\u{1CB3}']),
+    ('\u{10F4}', &['\u{1CB4}']),
+    ('\u{10F5}', &['\u{1CB5}']),
+    ('\u{10F6}', &['\u{1CB6}']),
+    ('\u{10F7}', &['\u{1CB7}']),
+    ('\u{10F8}', &['\u{1CB8}']),
+    ('\u{10F9}', &['\u{1CB9}']),
+    ('\u{10FA}', &['\u{1CBA}']),
+    ('\u{10FD}', &['\u{1CBD}']),
+    ('\u{10FE}', &['\u{1CBE}']),
+    ('\u{10FF}', &['\u{1CBF}']),
+    ('\u{13F8}', &['\u{13F0}']),
+    ('\u{13F9}', &['\u{13F1}']),
+    ('\u{13FA}', &['\u{13F2}']),
+    ('\u{13FB}', &['\u{13F3}']),
+    ('\u{13FC}', &['\u{13F4}']),
+    ('\u{13FD}', &['\u{13F5}']),
+    ('\u{1C80}', &['\u{0412}']),
+    ('\u{1C81}', &['\u{0414}']),
+    ('\u{1C82}', &['\u{041E}']),
+    ('\u{1C83}', &['\u{0421}']),
+    ('\u{1C84}', &['\u{0422}']),
+    ('\u{1C85}', &['\u{0422}']),
+    ('\u{1C86}', &['\u{042A}']),
+    ('\u{1C87}', &['\u{0462}']),
+    ('\u{1C88}', &['\u{A64A}']),
+    ('\u{1D79}', &['\u{A77D}']),
+    ('\u{1D7D}', &['\u{2C63}']),
+    ('\u{1D8E}', &['\u{A7C6}']),
+    ('\u{1E01}', &['\u{1E00}']),
+    ('\u{1E03}', &['\u{1E02}']),
+    ('\u{1E05}', &['\u{1E04}']),
+    ('\u{1E07}', &['\u{1E06}']),
+    ('\u{1E09}', &['\u{1E08}']),
+    ('\u{1E0B}', &['\u{1E0A}']),
+    ('\u{1E0D}', &['\u{1E0C}']),
+    ('\u{1E0F}', &['\u{1E0E}']),
+    ('\u{1E11}', &['\u{1E10}']),
+    ('\u{1E13}', &['\u{1E12}']),
+    ('\u{1E15}', &['\u{1E14}']),
+    ('\u{1E17}', &['\u{1E16}']),
+    ('\u{1E19}', &['\u{1E18}']),
+    ('\u{1E1B}', &['\u{1E1A}']),
+    ('\u{1E1D}', &['\u{1E1C}']),
+    ('\u{1E1F}', &['\u{1E1E}']),
+    ('\u{1E21}', &['\u{1E20}']),
+    ('\u{1E23}', &['\u{1E22}']),
+    ('\u{1E25}', &['\u{1E24}']),
+    ('\u{1E27}', &['\u{1E26}']),
+    ('\u{1E29}', &['\u{1E28}']),
+    ('\u{1E2B}', &['\u{1E2A}']),
+    ('\u{1E2D}', &['\u{1E2C}']),
+    ('\u{1E2F}', &['\u{1E2E}']),
+    ('\u{1E31}', &['\u{1E30}']),
+    ('\u{1E33}', &['\u{1E32}']),
+    ('\u{1E35}', &['\u{1E34}']),
+    ('\u{1E37}', &['\u{1E36}']),
+    ('\u{1E39}', &['\u{1E38}']),
+    ('\u{1E3B}', &['\u{1E3A}']),
+    ('\u{1E3D}', &['\u{1E3C}']),
+    ('\u{1E3F}', &['\u{1E3E}']),
+    ('\u{1E41}', &['\u{1E40}']),
+    ('\u{1E43}', &['\u{1E42}']),
+    ('\u{1E45}', &['\u{1E44}']),
+    ('\u{1E47}', &['\u{1E46}']),
+    ('\u{1E49}', &['\u{1E48}']),
+    ('\u{1E4B}', &['\u{1E4A}']),
+    ('\u{1E4D}', &['\u{1E4C}']),
+    ('\u{1E4F}', &['\u{1E4E}']),
+    ('\u{1E51}', &['\u{1E50}']),
+    ('\u{1E53}', &['\u{1E52}']),
+    ('\u{1E55}', &['\u{1E54}']),
+    ('\u{1E57}', &['\u{1E56}']),
+    ('\u{1E59}', &['\u{1E58}']),
+    ('\u{1E5B}', &['\u{1E5A}']),
+    ('\u{1E5D}', &['\u{1E5C}']),
+    ('\u{1E5F}', &['\u{1E5E}']),
+    ('\u{1E61}', &['\u{1E60}']),
+    ('\u{1E63}', &['\u{1E62}']),
+    ('\u{1E65}', &['\u{1E64}']),
+    ('\u{1E67}', &['\u{1E66}']),
+    ('\u{1E69}', &['\u{1E68}']),
+    ('\u{1E6B}', &['\u{1E6A}']),
+    ('\u{1E6D}', &['\u{1E6C}']),
+    ('\u{1E6F}', &['\u{1E6E}']),
+    ('\u{1E71}', &['\u{1E70}']),
+    ('\u{1E73}', &['\u{1E72}']),
+    ('\u{1E75}', &['\u{1E74}']),
+    ('\u{1E77}', &['\u{1E76}']),
+    ('\u{1E79}', &['\u{1E78}']),
+    ('\u{1E7B}', &['\u{1E7A}']),
+    ('\u{1E7D}', &['\u{1E7C}']),
+    ('\u{1E7F}', &['\u{1E7E}']),
+    ('\u{1E81}', &['\u{1E80}']),
+    ('\u{1E83}', &['\u{1E82}']),
+    ('\u{1E85}', &['\u{1E84}']),
+    ('\u{1E87}', &['\u{1E86}']),
+    ('\u{1E89}', &['\u{1E88}']),
+    ('\u{1E8B}', &['\u{1E8A}']),
+    ('\u{1E8D}', &['\u{1E8C}']),
+    ('\u{1E8F}', &['\u{1E8E}']),
+    ('\u{1E91}', &['\u{1E90}']),
+    ('\u{1E93}', &['\u{1E92}']),
+    ('\u{1E95}', &['\u{1E94}']),
+    ('\u{1E96}', &['\u{0048}', '\u{0331}']),
+    ('\u{1E97}', &['\u{0054}', '\u{0308}']),
+    ('\u{1E98}', &['\u{0057}', '\u{030A}']),
+    ('\u{1E99}', &['\u{0059}', '\u{030A}']),
+    ('\u{1E9A}', &['\u{0041}', '\u{02BE}']),
+    ('\u{1E9B}', &['\u{1E60}']),
+    ('\u{1EA1}', &['\u{1EA0}']),
+    ('\u{1EA3}', &['\u{1EA2}']),
+    ('\u{1EA5}', &['\u{1EA4}']),
+    ('\u{1EA7}', &['\u{1EA6}']),
+    ('\u{1EA9}', &['\u{1EA8}']),
+    ('\u{1EAB}', &['\u{1EAA}']),
+    ('\u{1EAD}', &['\u{1EAC}']),
+    ('\u{1EAF}', &['\u{1EAE}']),
+    ('\u{1EB1}', &['\u{1EB0}']),
+    ('\u{1EB3}', &['\u{1EB2}']),
+    ('\u{1EB5}', &['\u{1EB4}']),
+    ('\u{1EB7}', &['\u{1EB6}']),
+    ('\u{1EB9}', &['\u{1EB8}']),
+    ('\u{1EBB}', &['\u{1EBA}']),
+    ('\u{1EBD}', &['\u{1EBC}']),
+    ('\u{1EBF}', &['\u{1EBE}']),
+    ('\u{1EC1}', &['\u{1EC0}']),
+    ('\u{1EC3}', &['\u{1EC2}']),
+    ('\u{1EC5}', &['\u{1EC4}']),
+    ('\u{1EC7}', &['\u{1EC6}']),
+    ('\u{1EC9}', &['\u{1EC8}']),
+    ('\u{1ECB}', &['\u{1ECA}']),
+    ('\u{1ECD}', &['\u{1ECC}']),
+    ('\u{1ECF}', &['\u{1ECE}']),
+    ('\u{1ED1}', &['\u{1ED0}']),
+    ('\u{1ED3}', &['\u{1ED2}']),
+    ('\u{1ED5}', &['\u{1ED4}']),
+    ('\u{1ED7}', &['\u{1ED6}']),
+    ('\u{1ED9}', &['\u{1ED8}']),
+    ('\u{1EDB}', &['\u{1EDA}']),
+    ('\u{1EDD}', &['\u{1EDC}']),
+    ('\u{1EDF}', &['\u{1EDE}']),
+    ('\u{1EE1}', &['\u{1EE0}']),
+    ('\u{1EE3}', &['\u{1EE2}']),
+    ('\u{1EE5}', &['\u{1EE4}']),
+    ('\u{1EE7}', &['\u{1EE6}']),
+    ('\u{1EE9}', &['\u{1EE8}']),
+    ('\u{1EEB}', &['\u{1EEA}']),
+    ('\u{1EED}', &['\u{1EEC}']),
+    ('\u{1EEF}', &['\u{1EEE}']),
+    ('\u{1EF1}', &['\u{1EF0}']),
+    ('\u{1EF3}', &['\u{1EF2}']),
+    ('\u{1EF5}', &['\u{1EF4}']),
+    ('\u{1EF7}', &['\u{1EF6}']),
+    ('\u{1EF9}', &['\u{1EF8}']),
+    ('\u{1EFB}', &['\u{1EFA}']),
+    ('\u{1EFD}', &['\u{1EFC}']),
+    ('\u{1EFF}', &['\u{1EFE}']),
+    ('\u{1F00}', &['\u{1F08}']),
+    ('\u{1F01}', &['\u{1F09}']),
+    ('\u{1F02}', &['\u{1F0A}']),
+    ('\u{1F03}', &['\u{1F0B}']),
+    ('\u{1F04}', &['\u{1F0C}']),
+    ('\u{1F05}', &['\u{1F0D}']),
+    ('\u{1F06}', &['\u{1F0E}']),
+    ('\u{1F07}', &['\u{1F0F}']),
+    ('\u{1F10}', &['\u{1F18}']),
+    ('\u{1F11}', &['\u{1F19}']),
+    ('\u{1F12}', &['\u{1F1A}']),
+    ('\u{1F13}', &['\u{1F1B}']),
+    ('\u{1F14}', &['\u{1F1C}']),
+    ('\u{1F15}', &['\u{1F1D}']),
+    ('\u{1F20}', &['\u{1F28}']),
+    ('\u{1F21}', &['\u{1F29}']),
+    ('\u{1F22}', &['\u{1F2A}']),
+    ('\u{1F23}', &['\u{1F2B}']),
+    ('\u{1F24}', &['\u{1F2C}']),
+    ('\u{1F25}', &['\u{1F2D}']),
+    ('\u{1F26}', &['\u{1F2E}']),
+    ('\u{1F27}', &['\u{1F2F}']),
+    ('\u{1F30}', &['\u{1F38}']),
+    ('\u{1F31}', &['\u{1F39}']),
+    ('\u{1F32}', &['\u{1F3A}']),
+    ('\u{1F33}', &['\u{1F3B}']),
+    ('\u{1F34}', &['\u{1F3C}']),
+    ('\u{1F35}', &['\u{1F3D}']),
+    ('\u{1F36}', &['\u{1F3E}']),
+    ('\u{1F37}', &['\u{1F3F}']),
+    ('\u{1F40}', &['\u{1F48}']),
+    ('\u{1F41}', &['\u{1F49}']),
+    ('\u{1F42}', &['\u{1F4A}']),
+    ('\u{1F43}', &['\u{1F4B}']),
+    ('\u{1F44}', &['\u{1F4C}']),
+    ('\u{1F45}', &['\u{1F4D}']),
+    ('\u{1F50}', &['\u{03A5}', '\u{0313}']),
+    ('\u{1F51}', &['\u{1F59}']),
+    ('\u{1F52}', &['\u{03A5}', '\u{0313}', '\u{0300}']),
+    ('\u{1F53}', &['\u{1F5B}']),
+    ('\u{1F54}', &['\u{03A5}', '\u{0313}', '\u{0301}']),
+    ('\u{1F55}', &['\u{1F5D}']),
+    ('\u{1F56}', &['\u{03A5}', '\u{0313}', '\u{0342}']),
+    ('\u{1F57}', &['\u{1F5F}']),
+    ('\u{1F60}', &['\u{1F68}']),
+    ('\u{1F61}', &['\u{1F69}']),
+    ('\u{1F62}', &['\u{1F6A}']),
+    ('\u{1F63}', &['\u{1F6B}']),
+    ('\u{1F64}', &['\u{1F6C}']),
+    ('\u{1F65}', &['\u{1F6D}']),
+    ('\u{1F66}', &['\u{1F6E}']),
+    ('\u{1F67}', &['\u{1F6F}']),
+    ('\u{1F70}', &['\u{1FBA}']),
+    ('\u{1F71}', &['\u{1FBB}']),
+    ('\u{1F72}', &['\u{1FC8}']),
+    ('\u{1F73}', &['\u{1FC9}']),
+    ('\u{1F74}', &['\u{1FCA}']),
+    ('\u{1F75}', &['\u{1FCB}']),
+    ('\u{1F76}', &['\u{1FDA}']),
+    ('\u{1F77}', &['\u{1FDB}']),
+    ('\u{1F78}', &['\u{1FF8}']),
+    ('\u{1F79}', &['\u{1FF9}']),
+    ('\u{1F7A}', &['\u{1FEA}']),
+    ('\u{1F7B}', &['\u{1FEB}']),
+    ('\u{1F7C}', &['\u{1FFA}']),
+    ('\u{1F7D}', &['\u{1FFB}']),
+    ('\u{1F80}', &['\u{1F08}', '\u{0399}']),
+    ('\u{1F81}', &['\u{1F09}', '\u{0399}']),
+    ('\u{1F82}', &['\u{1F0A}', '\u{0399}']),
+    ('\u{1F83}', &['\u{1F0B}', '\u{0399}']),
+    ('\u{1F84}', &['\u{1F0C}', '\u{0399}']),
+    ('\u{1F85}', &['\u{1F0D}', '\u{0399}']),
+    ('\u{1F86}', &['\u{1F0E}', '\u{0399}']),
+    ('\u{1F87}', &['\u{1F0F}', '\u{0399}']),
+    ('\u{1F88}', &['\u{1F08}', '\u{0399}']),
+    ('\u{1F89}', &['\u{1F09}', '\u{0399}']),
+    ('\u{1F8A}', &['\u{1F0A}', '\u{0399}']),
+    ('\u{1F8B}', &['\u{1F0B}', '\u{0399}']),
+    ('\u{1F8C}', &['\u{1F0C}', '\u{0399}']),
+    ('\u{1F8D}', &['\u{1F0D}', '\u{0399}']),
+    ('\u{1F8E}', &['\u{1F0E}', '\u{0399}']),
+    ('\u{1F8F}', &['\u{1F0F}', '\u{0399}']),
+    ('\u{1F90}', &['\u{1F28}', '\u{0399}']),
+    ('\u{1F91}', &['\u{1F29}', '\u{0399}']),
+    ('\u{1F92}', &['\u{1F2A}', '\u{0399}']),
+    ('\u{1F93}', &['\u{1F2B}', '\u{0399}']),
+    ('\u{1F94}', &['\u{1F2C}', '\u{0399}']),
+    ('\u{1F95}', &['\u{1F2D}', '\u{0399}']),
+    ('\u{1F96}', &['\u{1F2E}', '\u{0399}']),
+    ('\u{1F97}', &['\u{1F2F}', '\u{0399}']),
+    ('\u{1F98}', &['\u{1F28}', '\u{0399}']),
+    ('\u{1F99}', &['\u{1F29}', '\u{0399}']),
+    ('\u{1F9A}', &['\u{1F2A}', '\u{0399}']),
+    ('\u{1F9B}', &['\u{1F2B}', '\u{0399}']),
+    ('\u{1F9C}', &['\u{1F2C}', '\u{0399}']),
+    ('\u{1F9D}', &['\u{1F2D}', '\u{0399}']),
+    ('\u{1F9E}', &['\u{1F2E}', '\u{0399}']),
+    ('\u{1F9F}', &['\u{1F2F}', '\u{0399}']),
+    ('\u{1FA0}', &['\u{1F68}', '\u{0399}']),
+    ('\u{1FA1}', &['\u{1F69}', '\u{0399}']),
+    ('\u{1FA2}', &['\u{1F6A}', '\u{0399}']),
+    ('\u{1FA3}', &['\u{1F6B}', '\u{0399}']),
+    ('\u{1FA4}', &['\u{1F6C}', '\u{0399}']),
+    ('\u{1FA5}', &['\u{1F6D}', '\u{0399}']),
+    ('\u{1FA6}', &['\u{1F6E}', '\u{0399}']),
+    ('\u{1FA7}', &['\u{1F6F}', '\u{0399}']),
+    ('\u{1FA8}', &['\u{1F68}', '\u{0399}']),
+    ('\u{1FA9}', &['\u{1F69}', '\u{0399}']),
+    ('\u{1FAA}', &['\u{1F6A}', '\u{0399}']),
+    ('\u{1FAB}', &['\u{1F6B}', '\u{0399}']),
+    ('\u{1FAC}', &['\u{1F6C}', '\u{0399}']),
+    ('\u{1FAD}', &['\u{1F6D}', '\u{0399}']),
+    ('\u{1FAE}', &['\u{1F6E}', '\u{0399}']),
+    ('\u{1FAF}', &['\u{1F6F}', '\u{0399}']),
+    ('\u{1FB0}', &['\u{1FB8}']),
+    ('\u{1FB1}', &['\u{1FB9}']),
+    ('\u{1FB2}', &['\u{1FBA}', '\u{0399}']),
+    ('\u{1FB3}', &['\u{0391}', '\u{0399}']),
+    ('\u{1FB4}', &['\u{0386}', '\u{0399}']),
+    ('\u{1FB6}', &['\u{0391}', '\u{0342}']),
+    ('\u{1FB7}', &['\u{0391}', '\u{0342}', '\u{0399}']),
+    ('\u{1FBC}', &['\u{0391}', '\u{0399}']),
+    ('\u{1FBE}', &['\u{0399}']),
+    ('\u{1FC2}', &['\u{1FCA}', '\u{0399}']),
+    ('\u{1FC3}', &['\u{0397}', '\u{0399}']),
+    ('\u{1FC4}', &['\u{0389}', '\u{0399}']),
+    ('\u{1FC6}', &['\u{0397}', '\u{0342}']),
+    ('\u{1FC7}', &['\u{0397}', '\u{0342}', '\u{0399}']),
+    ('\u{1FCC}', &['\u{0397}', '\u{0399}']),
+    ('\u{1FD0}', &['\u{1FD8}']),
+    ('\u{1FD1}', &['\u{1FD9}']),
+    ('\u{1FD2}', &['\u{0399}', '\u{0308}', '\u{0300}']),
+    ('\u{1FD3}', &['\u{0399}', '\u{0308}', '\u{0301}']),
+    ('\u{1FD6}', &['\u{0399}', '\u{0342}']),
+    ('\u{1FD7}', &['\u{0399}', '\u{0308}', '\u{0342}']),
+    ('\u{1FE0}', &['\u{1FE8}']),
+    ('\u{1FE1}', &['\u{1FE9}']),
+    ('\u{1FE2}', &['\u{03A5}', '\u{0308}', '\u{0300}']),
+    ('\u{1FE3}', &['\u{03A5}', '\u{0308}', '\u{0301}']),
+    ('\u{1FE4}', &['\u{03A1}', '\u{0313}']),
+    ('\u{1FE5}', &['\u{1FEC}']),
+    ('\u{1FE6}', &['\u{03A5}', '\u{0342}']),
+    ('\u{1FE7}', &['\u{03A5}', '\u{0308}', '\u{0342}']),
+    ('\u{1FF2}', &['\u{1FFA}', '\u{0399}']),
+    ('\u{1FF3}', &['\u{03A9}', '\u{0399}']),
+    ('\u{1FF4}', &['\u{038F}', '\u{0399}']),
+    ('\u{1FF6}', &['\u{03A9}', '\u{0342}']),
+    ('\u{1FF7}', &['\u{03A9}', '\u{0342}', '\u{0399}']),
+    ('\u{1FFC}', &['\u{03A9}', '\u{0399}']),
+    ('\u{214E}', &['\u{2132}']),
+    ('\u{2170}', &['\u{2160}']),
+    ('\u{2171}', &['\u{2161}']),
+    ('\u{2172}', &['\u{2162}']),
+    ('\u{2173}', &['\u{2163}']),
+    ('\u{2174}', &['\u{2164}']),
+    ('\u{2175}', &['\u{2165}']),
+    ('\u{2176}', &['\u{2166}']),
+    ('\u{2177}', &['\u{2167}']),
+    ('\u{2178}', &['\u{2168}']),
+    ('\u{2179}', &['\u{2169}']),
+    ('\u{217A}', &['\u{216A}']),
+    ('\u{217B}', &['\u{216B}']),
+    ('\u{217C}', &['\u{216C}']),
+    ('\u{217D}', &['\u{216D}']),
+    ('\u{217E}', &['\u{216E}']),
+    ('\u{217F}', &['\u{216F}']),
+    ('\u{2184}', &['\u{2183}']),
+    ('\u{24D0}', &['\u{24B6}']),
+    ('\u{24D1}', &['\u{24B7}']),
+    ('\u{24D2}', &['\u{24B8}']),
+    ('\u{24D3}', &['\u{24B9}']),
+    ('\u{24D4}', &['\u{24BA}']),
+    ('\u{24D5}', &['\u{24BB}']),
+    ('\u{24D6}', &['\u{24BC}']),
+    ('\u{24D7}', &['\u{24BD}']),
+    ('\u{24D8}', &['\u{24BE}']),
+    ('\u{24D9}', &['\u{24BF}']),
+    ('\u{24DA}', &['\u{24C0}']),
+    ('\u{24DB}', &['\u{24C1}']),
+    ('\u{24DC}', &['\u{24C2}']),
+    ('\u{24DD}', &['\u{24C3}']),
+    ('\u{24DE}', &['\u{24C4}']),
+    ('\u{24DF}', &['\u{24C5}']),
+    ('\u{24E0}', &['\u{24C6}']),
+    ('\u{24E1}', &['\u{24C7}']),
+    ('\u{24E2}', &['\u{24C8}']),
+    ('\u{24E3}', &['\u{24C9}']),
+    ('\u{24E4}', &['\u{24CA}']),
+    ('\u{24E5}', &['\u{24CB}']),
+    ('\u{24E6}', &['\u{24CC}']),
+    ('\u{24E7}', &['\u{24CD}']),
+    ('\u{24E8}', &['\u{24CE}']),
+    ('\u{24E9}', &['\u{24CF}']),
+    ('\u{2C30}', &['\u{2C00}']),
+    ('\u{2C31}', &['\u{2C01}']),
+    ('\u{2C32}', &['\u{2C02}']),
+    ('\u{2C33}', &['\u{2C03}']),
+    ('\u{2C34}', &['\u{2C04}']),
+    ('\u{2C35}', &['\u{2C05}']),
+    ('\u{2C36}', &['\u{2C06}']),
+    ('\u{2C37}', &['\u{2C07}']),
+    ('\u{2C38}', &['\u{2C08}']),
+    ('\u{2C39}', &['\u{2C09}']),
+    ('\u{2C3A}', &['\u{2C0A}']),
+    ('\u{2C3B}', &['\u{2C0B}']),
+    ('\u{2C3C}', &['\u{2C0C}']),
+    ('\u{2C3D}', &['\u{2C0D}']),
+    ('\u{2C3E}', &['\u{2C0E}']),
+    ('\u{2C3F}', &['\u{2C0F}']),
+    ('\u{2C40}', &['\u{2C10}']),
+    ('\u{2C41}', &['\u{2C11}']),
+    ('\u{2C42}', &['\u{2C12}']),
+    ('\u{2C43}', &['\u{2C13}']),
+    ('\u{2C44}', &['\u{2C14}']),
+    ('\u{2C45}', &['\u{2C15}']),
+    ('\u{2C46}', &['\u{2C16}']),
+    ('\u{2C47}', &['\u{2C17}']),
+    ('\u{2C48}', &['\u{2C18}']),
+    ('\u{2C49}', &['\u{2C19}']),
+    ('\u{2C4A}', &['\u{2C1A}']),
+    ('\u{2C4B}', &['\u{2C1B}']),
+    ('\u{2C4C}', &['\u{2C1C}']),
+    ('\u{2C4D}', &['\u{2C1D}']),
+    ('\u{2C4E}', &['\u{2C1E}']),
+    ('\u{2C4F}', &['\u{2C1F}']),
+    ('\u{2C50}', &['\u{2C20}']),
+    ('\u{2C51}', &['\u{2C21}']),
+    ('\u{2C52}', &['\u{2C22}']),
+    ('\u{2C53}', &['\u{2C23}']),
+    ('\u{2C54}', &['\u{2C24}']),
+    ('\u{2C55}', &['\u{2C25}']),
+    ('\u{2C56}', &['\u{2C26}']),
+    ('\u{2C57}', &['\u{2C27}']),
+    ('\u{2C58}', &['\u{2C28}']),
+    ('\u{2C59}', &['\u{2C29}']),
+    ('\u{2C5A}', &['\u{2C2A}']),
+    ('\u{2C5B}', &['\u{2C2B}']),
+    ('\u{2C5C}', &['\u{2C2C}']),
+    ('\u{2C5D}', &['\u{2C2D}']),
+    ('\u{2C5E}', &['\u{2C2E}']),
+    ('\u{2C61}', &['\u{2C60}']),
+    ('\u{2C65}', &['\u{023A}']),
+    ('\u{2C66}', &['\u{023E}']),
+    ('\u{2C68}', &['\u{2C67}']),
+    ('\u{2C6A}', &['\u{2C69}']),
+    ('\u{2C6C}', &['\u{2C6B}']),
+    ('\u{2C73}', &['\u{2C72}']),
+    ('\u{2C76}', &['\u{2C75}']),
+    ('\u{2C81}', &['\u{2C80}']),
+    ('\u{2C83}', &['\u{2C82}']),
+    ('\u{2C85}', &['\u{2C84}']),
+    ('\u{2C87}', &['\u{2C86}']),
+    ('\u{2C89}', &['\u{2C88}']),
+    ('\u{2C8B}', &['\u{2C8A}']),
+    ('\u{2C8D}', &['\u{2C8C}']),
+    ('\u{2C8F}', &['\u{2C8E}']),
+    ('\u{2C91}', &['\u{2C90}']),
+    ('\u{2C93}', &['\u{2C92}']),
+    ('\u{2C95}', &['\u{2C94}']),
+    ('\u{2C97}', &['\u{2C96}']),
+    ('\u{2C99}', &['\u{2C98}']),
+    ('\u{2C9B}', &['\u{2C9A}']),
+    ('\u{2C9D}', &['\u{2C9C}']),
+    ('\u{2C9F}', &['\u{2C9E}']),
+    ('\u{2CA1}', &['\u{2CA0}']),
+    ('\u{2CA3}', &['\u{2CA2}']),
+    ('\u{2CA5}', &['\u{2CA4}']),
+    ('\u{2CA7}', &['\u{2CA6}']),
+    ('\u{2CA9}', &['\u{2CA8}']),
+    ('\u{2CAB}', &['\u{2CAA}']),
+    ('\u{2CAD}', &['\u{2CAC}']),
+    ('\u{2CAF}', &['\u{2CAE}']),
+    ('\u{2CB1}', &['\u{2CB0}']),
+    ('\u{2CB3}', &['\u{2CB2}']),
+    ('\u{2CB5}', &['\u{2CB4}']),
+    ('\u{2CB7}', &['\u{2CB6}']),
+    ('\u{2CB9}', &['\u{2CB8}']),
+    ('\u{2CBB}', &['\u{2CBA}']),
+    ('\u{2CBD}', &['\u{2CBC}']),
+    ('\u{2CBF}', &['\u{2CBE}']),
+    ('\u{2CC1}', &['\u{2CC0}']),
+    ('\u{2CC3}', &['\u{2CC2}']),
+    ('\u{2CC5}', &['\u{2CC4}']),
+    ('\u{2CC7}', &['\u{2CC6}']),
+    ('\u{2CC9}', &['\u{2CC8}']),
+    ('\u{2CCB}', &['\u{2CCA}']),
+    ('\u{2CCD}', &['\u{2CCC}']),
+    ('\u{2CCF}', &['\u{2CCE}']),
+    ('\u{2CD1}', &['\u{2CD0}']),
+    ('\u{2CD3}', &['\u{2CD2}']),
+    ('\u{2CD5}', &['\u{2CD4}']),
+    ('\u{2CD7}', &['\u{2CD6}']),
+    ('\u{2CD9}', &['\u{2CD8}']),
+    ('\u{2CDB}', &['\u{2CDA}']),
+    ('\u{2CDD}', &['\u{2CDC}']),
+    ('\u{2CDF}', &['\u{2CDE}']),
+    ('\u{2CE1}', &['\u{2CE0}']),
+    ('\u{2CE3}', &['\u{2CE2}']),
+    ('\u{2CEC}', &['\u{2CEB}']),
+    ('\u{2CEE}', &['\u{2CED}']),
+    ('\u{2CF3}', &['\u{2CF2}']),
+    ('\u{2D00}', &['\u{10A0}']),
+    ('\u{2D01}', &['\u{10A1}']),
+    ('\u{2D02}', &['\u{10A2}']),
+    ('\u{2D03}', &['\u{10A3}']),
+    ('\u{2D04}', &['\u{10A4}']),
+    ('\u{2D05}', &['\u{10A5}']),
+    ('\u{2D06}', &['\u{10A6}']),
+    ('\u{2D07}', &['\u{10A7}']),
+    ('\u{2D08}', &['\u{10A8}']),
+    ('\u{2D09}', &['\u{10A9}']),
+    ('\u{2D0A}', &['\u{10AA}']),
+    ('\u{2D0B}', &['\u{10AB}']),
+    ('\u{2D0C}', &['\u{10AC}']),
+    ('\u{2D0D}', &['\u{10AD}']),
+    ('\u{2D0E}', &['\u{10AE}']),
+    ('\u{2D0F}', &['\u{10AF}']),
+    ('\u{2D10}', &['\u{10B0}']),
+    ('\u{2D11}', &['\u{10B1}']),
+    ('\u{2D12}', &['\u{10B2}']),
+    ('\u{2D13}', &['\u{10B3}']),
+    ('\u{2D14}', &['\u{10B4}']),
+    ('\u{2D15}', &['\u{10B5}']),
+    ('\u{2D16}', &['\u{10B6}']),
+    ('\u{2D17}', &['\u{10B7}']),
+    ('\u{2D18}', &['\u{10B8}']),
+    ('\u{2D19}', &['\u{10B9}']),
+    ('\u{2D1A}', &['\u{10BA}']),
+    ('\u{2D1B}', &['\u{10BB}']),
+    ('\u{2D1C}', &['\u{10BC}']),
+    ('\u{2D1D}', &['\u{10BD}']),
+    ('\u{2D1E}', &['\u{10BE}']),
+    ('\u{2D1F}', &['\u{10BF}']),
+    ('\u{2D20}', &['\u{10C0}']),
+    ('\u{2D21}', &['\u{10C1}']),
+    ('\u{2D22}', &['\u{10C2}']),
+    ('\u{2D23}', &['\u{10C3}']),
+    ('\u{2D24}', &['\u{10C4}']),
+    ('\u{2D25}', &['\u{10C5}']),
+    ('\u{2D27}', &['\u{10C7}']),
+    ('\u{2D2D}', &['\u{10CD}']),
+    ('\u{A641}', &['\u{A640}']),
+    ('\u{A643}', &['\u{A642}']),
+    ('\u{A645}', &['\u{A644}']),
+    ('\u{A647}', &['\u{A646}']),
+    ('\u{A649}', &['\u{A648}']),
+    ('\u{A64B}', &['\u{A64A}']),
+    ('\u{A64D}', &['\u{A64C}']),
+    ('\u{A64F}', &['\u{A64E}']),
+    ('\u{A651}', &['\u{A650}']),
+    ('\u{A653}', &['\u{A652}']),
+    ('\u{A655}', &['\u{A654}']),
+    ('\u{A657}', &['\u{A656}']),
+    ('\u{A659}', &['\u{A658}']),
+    ('\u{A65B}', &['\u{A65A}']),
+    ('\u{A65D}', &['\u{A65C}']),
+    ('\u{A65F}', &['\u{A65E}']),
+    ('\u{A661}', &['\u{A660}']),
+    ('\u{A663}', &['\u{A662}']),
+    ('\u{A665}', &['\u{A664}']),
+    ('\u{A667}', &['\u{A666}']),
+    ('\u{A669}', &['\u{A668}']),
+    ('\u{A66B}', &['\u{A66A}']),
+    ('\u{A66D}', &['\u{A66C}']),
+    ('\u{A681}', &['\u{A680}']),
+    ('\u{A683}', &['\u{A682}']),
+    ('\u{A685}', &['\u{A684}']),
+    ('\u{A687}', &['\u{A686}']),
+    ('\u{A689}', &['\u{A688}']),
+    ('\u{A68B}', &['\u{A68A}']),
+    ('\u{A68D}', &['\u{A68C}']),
+    ('\u{A68F}', &['\u{A68E}']),
+    ('\u{A691}', &['\u{A690}']),
+    ('\u{A693}', &['\u{A692}']),
+    ('\u{A695}', &['\u{A694}']),
+    ('\u{A697}', &['\u{A696}']),
+    ('\u{A699}', &['\u{A698}']),
+    ('\u{A69B}', &['\u{A69A}']),
+    ('\u{A723}', &['\u{A722}']),
+    ('\u{A725}', &['\u{A724}']),
+    ('\u{A727}', &['\u{A726}']),
+    ('\u{A729}', &['\u{A728}']),
+    ('\u{A72B}', &['\u{A72A}']),
+    ('\u{A72D}', &['\u{A72C}']),
+    ('\u{A72F}', &['\u{A72E}']),
+    ('\u{A733}', &['\u{A732}']),
+    ('\u{A735}', &['\u{A734}']),
+    ('\u{A737}', &['\u{A736}']),
+    ('\u{A739}', &['\u{A738}']),
+    ('\u{A73B}', &['\u{A73A}']),
+    ('\u{A73D}', &['\u{A73C}']),
+    ('\u{A73F}', &['\u{A73E}']),
+    ('\u{A741}', &['\u{A740}']),
+    ('\u{A743}', &['\u{A742}']),
+    ('\u{A745}', &['\u{A744}']),
+    ('\u{A747}', &['\u{A746}']),
+    ('\u{A749}', &['\u{A748}']),
+    ('\u{A74B}', &['\u{A74A}']),
+    ('\u{A74D}', &['\u{A74C}']),
+    ('\u{A74F}', &['\u{A74E}']),
+    ('\u{A751}', &['\u{A750}']),
+    ('\u{A753}', &['\u{A752}']),
+    ('\u{A755}', &['\u{A754}']),
+    ('\u{A757}', &['\u{A756}']),
+    ('\u{A759}', &['\u{A758}']),
+    ('\u{A75B}', &['\u{A75A}']),
+    ('\u{A75D}', &['\u{A75C}']),
+    ('\u{A75F}', &['\u{A75E}']),
+    ('\u{A761}', &['\u{A760}']),
+    ('\u{A763}', &['\u{A762}']),
+    ('\u{A765}', &['\u{A764}']),
+    ('\u{A767}', &['\u{A766}']),
+    ('\u{A769}', &['\u{A768}']),
+    ('\u{A76B}', &['\u{A76A}']),
+    ('\u{A76D}', &['\u{A76C}']),
+    ('\u{A76F}', &['\u{A76E}']),
+    ('\u{A77A}', &['\u{A779}']),
+    ('\u{A77C}', &['\u{A77B}']),
+    ('\u{A77F}', &['\u{A77E}']),
+    ('\u{A781}', &['\u{A780}']),
+    ('\u{A783}', &['\u{A782}']),
+    ('\u{A785}', &['\u{A784}']),
+    ('\u{A787}', &['\u{A786}']),
+    ('\u{A78C}', &['\u{A78B}']),
+    ('\u{A791}', &['\u{A790}']),
+    ('\u{A793}', &['\u{A792}']),
+    ('\u{A794}', &['\u{A7C4}']),
+    ('\u{A797}', &['\u{A796}']),
+    ('\u{A799}', &['\u{A798}']),
+    ('\u{A79B}', &['\u{A79A}']),
+    ('\u{A79D}', &['\u{A79C}']),
+    ('\u{A79F}', &['\u{A79E}']),
+    ('\u{A7A1}', &['\u{A7A0}']),
+    ('\u{A7A3}', &['\u{A7A2}']),
+    ('\u{A7A5}', &['\u{A7A4}']),
+    ('\u{A7A7}', &['\u{A7A6}']),
+    ('\u{A7A9}', &['\u{A7A8}']),
+    ('\u{A7B5}', &['\u{A7B4}']),
+    ('\u{A7B7}', &['\u{A7B6}']),
+    ('\u{A7B9}', &['\u{A7B8}']),
+    ('\u{A7BB}', &['\u{A7BA}']),
+    ('\u{A7BD}', &['\u{A7BC}']),
+    ('\u{A7BF}', &['\u{A7BE}']),
+    ('\u{A7C3}', &['\u{A7C2}']),
+    ('\u{A7C8}', &['\u{A7C7}']),
+    ('\u{A7CA}', &['\u{A7C9}']),
+    ('\u{A7F6}', &['\u{A7F5}']),
+    ('\u{AB53}', &['\u{A7B3}']),
+    ('\u{AB70}', &['\u{13A0}']),
+    ('\u{AB71}', &['\u{13A1}']),
+    ('\u{AB72}', &['\u{13A2}']),
+    ('\u{AB73}', &['\u{13A3}']),
+    ('\u{AB74}', &['\u{13A4}']),
+    ('\u{AB75}', &['\u{13A5}']),
+    ('\u{AB76}', &['\u{13A6}']),
+    ('\u{AB77}', &['\u{13A7}']),
+    ('\u{AB78}', &['\u{13A8}']),
+    ('\u{AB79}', &['\u{13A9}']),
+    ('\u{AB7A}', &['\u{13AA}']),
+    ('\u{AB7B}', &['\u{13AB}']),
+    ('\u{AB7C}', &['\u{13AC}']),
+    ('\u{AB7D}', &['\u{13AD}']),
+    ('\u{AB7E}', &['\u{13AE}']),
+    ('\u{AB7F}', &['\u{13AF}']),
+    ('\u{AB80}', &['\u{13B0}']),
+    ('\u{AB81}', &['\u{13B1}']),
+    ('\u{AB82}', &['\u{13B2}']),
+    ('\u{AB83}', &['\u{13B3}']),
+    ('\u{AB84}', &['\u{13B4}']),
+    ('\u{AB85}', &['\u{13B5}']),
+    ('\u{AB86}', &['\u{13B6}']),
+    ('\u{AB87}', &['\u{13B7}']),
+    ('\u{AB88}', &['\u{13B8}']),
+    ('\u{AB89}', &['\u{13B9}']),
+    ('\u{AB8A}', &['\u{13BA}']),
+    ('\u{AB8B}', &['\u{13BB}']),
+    ('\u{AB8C}', &['\u{13BC}']),
+    ('\u{AB8D}', &['\u{13BD}']),
+    ('\u{AB8E}', &['\u{13BE}']),
+    ('\u{AB8F}', &['\u{13BF}']),
+    ('\u{AB90}', &['\u{13C0}']),
+    ('\u{AB91}', &['\u{13C1}']),
+    ('\u{AB92}', &['\u{13C2}']),
+    ('\u{AB93}', &['\u{13C3}']),
+    ('\u{AB94}', &['\u{13C4}']),
+    ('\u{AB95}', &['\u{13C5}']),
+    ('\u{AB96}', &['\u{13C6}']),
+    ('\u{AB97}', &['\u{13C7}']),
+    ('\u{AB98}', &['\u{13C8}']),
+    ('\u{AB99}', &['\u{13C9}']),
+    ('\u{AB9A}', &['\u{13CA}']),
+    ('\u{AB9B}', &['\u{13CB}']),
+    ('\u{AB9C}', &['\u{13CC}']),
+    ('\u{AB9D}', &['\u{13CD}']),
+    ('\u{AB9E}', &['\u{13CE}']),
+    ('\u{AB9F}', &['\u{13CF}']),
+    ('\u{ABA0}', &['\u{13D0}']),
+    ('\u{ABA1}', &['\u{13D1}']),
+    ('\u{ABA2}', &['\u{13D2}']),
+    ('\u{ABA3}', &['\u{13D3}']),
+    ('\u{ABA4}', &['\u{13D4}']),
+    ('\u{ABA5}', &['\u{13D5}']),
+    ('\u{ABA6}', &['\u{13D6}']),
+    ('\u{ABA7}', &['\u{13D7}']),
+    ('\u{ABA8}', &['\u{13D8}']),
+    ('\u{ABA9}', &['\u{13D9}']),
+    ('\u{ABAA}', &['\u{13DA}']),
+    ('\u{ABAB}', &['\u{13DB}']),
+    ('\u{ABAC}', &['\u{13DC}']),
+    ('\u{ABAD}', &['\u{13DD}']),
+    ('\u{ABAE}', &['\u{13DE}']),
+    ('\u{ABAF}', &['\u{13DF}']),
+    ('\u{ABB0}', &['\u{13E0}']),
+    ('\u{ABB1}', &['\u{13E1}']),
+    ('\u{ABB2}', &['\u{13E2}']),
+    ('\u{ABB3}', &['\u{13E3}']),
+    ('\u{ABB4}', &['\u{13E4}']),
+    ('\u{ABB5}', &['\u{13E5}']),
+    ('\u{ABB6}', &['\u{13E6}']),
+    ('\u{ABB7}', &['\u{13E7}']),
+    ('\u{ABB8}', &['\u{13E8}']),
+    ('\u{ABB9}', &['\u{13E9}']),
+    ('\u{ABBA}', &['\u{13EA}']),
+    ('\u{ABBB}', &['\u{13EB}']),
+    ('\u{ABBC}', &['\u{13EC}']),
+    ('\u{ABBD}', &['\u{13ED}']),
+    ('\u{ABBE}', &['\u{13EE}']),
+    ('\u{ABBF}', &['\u{13EF}']),
+    ('\u{FB00}', &['\u{0046}', '\u{0046}']),
+    ('\u{FB01}', &['\u{0046}', '\u{0049}']),
+    ('\u{FB02}', &['\u{0046}', '\u{004C}']),
+    ('\u{FB03}', &['\u{0046}', '\u{0046}', '\u{0049}']),
+    ('\u{FB04}', &['\u{0046}', '\u{0046}', '\u{004C}']),
+    ('\u{FB05}', &['\u{0053}', '\u{0054}']),
+    ('\u{FB06}', &['\u{0053}', '\u{0054}']),
+    ('\u{FB13}', &['\u{0544}', '\u{0546}']),
+    ('\u{FB14}', &['\u{0544}', '\u{0535}']),
+    ('\u{FB15}', &['\u{0544}', '\u{053B}']),
+    ('\u{FB16}', &['\u{054E}', '\u{0546}']),
+    ('\u{FB17}', &['\u{0544}', '\u{053D}']),
+    ('\u{FF41}', &['\u{FF21}']),
+    ('\u{FF42}', &['\u{FF22}']),
+    ('\u{FF43}', &['\u{FF23}']),
+    ('\u{FF44}', &['\u{FF24}']),
+    ('\u{FF45}', &['\u{FF25}']),
+    ('\u{FF46}', &['\u{FF26}']),
+    ('\u{FF47}', &['\u{FF27}']),
+    ('\u{FF48}', &['\u{FF28}']),
+    ('\u{FF49}', &['\u{FF29}']),
+    ('\u{FF4A}', &['\u{FF2A}']),
+    ('\u{FF4B}', &['\u{FF2B}']),
+    ('\u{FF4C}', &['\u{FF2C}']),
+    ('\u{FF4D}', &['\u{FF2D}']),
+    ('\u{FF4E}', &['\u{FF2E}']),
+    ('\u{FF4F}', &['\u{FF2F}']),
+    ('\u{FF50}', &['\u{FF30}']),
+    ('\u{FF51}', &['\u{FF31}']),
+    ('\u{FF52}', &['\u{FF32}']),
+    ('\u{FF53}', &['\u{FF33}']),
+    ('\u{FF54}', &['\u{FF34}']),
+    ('\u{FF55}', &['\u{FF35}']),
+    ('\u{FF56}', &['\u{FF36}']),
+    ('\u{FF57}', &['\u{FF37}']),
+    ('\u{FF58}', &['\u{FF38}']),
+    ('\u{FF59}', &['\u{FF39}']),
+    ('\u{FF5A}', &['\u{FF3A}']),
+    ('\u{10428}', &['\u{10400}']),
+    ('\u{10429}', &['\u{10401}']),
+    ('\u{1042A}', &['\u{10402}']),
+    ('\u{1042B}', &['\u{10403}']),
+    ('\u{1042C}', &['\u{10404}']),
+    ('\u{1042D}', &['\u{10405}']),
+    ('\u{1042E}', &['\u{10406}']),
+    ('\u{1042F}', &['\u{10407}']),
+    ('\u{10430}', &['\u{10408}']),
+    ('\u{10431}', &['\u{10409}']),
+    ('\u{10432}', &['\u{1040A}']),
+    ('\u{10433}', &['\u{1040B}']),
+    ('\u{10434}', &['\u{1040C}']),
+    ('\u{10435}', &['\u{1040D}']),
+    ('\u{10436}', &['\u{1040E}']),
+    ('\u{10437}', &['\u{1040F}']),
+    ('\u{10438}', &['\u{10410}']),
+    ('\u{10439}', &['\u{10411}']),
+    ('\u{1043A}', &['\u{10412}']),
+    ('\u{1043B}', &['\u{10413}']),
+    ('\u{1043C}', &['\u{10414}']),
+    ('\u{1043D}', &['\u{10415}']),
+    ('\u{1043E}', &['\u{10416}']),
+    ('\u{1043F}', &['\u{10417}']),
+    ('\u{10440}', &['\u{10418}']),
+    ('\u{10441}', &['\u{10419}']),
+    ('\u{10442}', &['\u{1041A}']),
+    ('\u{10443}', &['\u{1041B}']),
+    ('\u{10444}', &['\u{1041C}']),
+    ('\u{10445}', &['\u{1041D}']),
+    ('\u{10446}', &['\u{1041E}']),
+    ('\u{10447}', &['\u{1041F}']),
+    ('\u{10448}', &['\u{10420}']),
+    ('\u{10449}', &['\u{10421}']),
+    ('\u{1044A}', &['\u{10422}']),
+    ('\u{1044B}', &['\u{10423}']),
+    ('\u{1044C}', &['\u{10424}']),
+    ('\u{1044D}', &['\u{10425}']),
+    ('\u{1044E}', &['\u{10426}']),
+    ('\u{1044F}', &['\u{10427}']),
+    ('\u{104D8}', &['\u{104B0}']),
+    ('\u{104D9}', &['\u{104B1}']),
+    ('\u{104DA}', &['\u{104B2}']),
+    ('\u{104DB}', &['\u{104B3}']),
+    ('\u{104DC}', &['\u{104B4}']),
+    ('\u{104DD}', &['\u{104B5}']),
+    ('\u{104DE}', &['\u{104B6}']),
+    ('\u{104DF}', &['\u{104B7}']),
+    ('\u{104E0}', &['\u{104B8}']),
+    ('\u{104E1}', &['\u{104B9}']),
+    ('\u{104E2}', &['\u{104BA}']),
+    ('\u{104E3}', &['\u{104BB}']),
+    ('\u{104E4}', &['\u{104BC}']),
+    ('\u{104E5}', &['\u{104BD}']),
+    ('\u{104E6}', &['\u{104BE}']),
+    ('\u{104E7}', &['\u{104BF}']),
+    ('\u{104E8}', &['\u{104C0}']),
+    ('\u{104E9}', &['\u{104C1}']),
+    ('\u{104EA}', &['\u{104C2}']),
+    ('\u{104EB}', &['\u{104C3}']),
+    ('\u{104EC}', &['\u{104C4}']),
+    ('\u{104ED}', &['\u{104C5}']),
+    ('\u{104EE}', &['\u{104C6}']),
+    ('\u{104EF}', &['\u{104C7}']),
+    ('\u{104F0}', &['\u{104C8}']),
+    ('\u{104F1}', &['\u{104C9}']),
+    ('\u{104F2}', &['\u{104CA}']),
+    ('\u{104F3}', &['\u{104CB}']),
+    ('\u{104F4}', &['\u{104CC}']),
+    ('\u{104F5}', &['\u{104CD}']),
+    ('\u{104F6}', &['\u{104CE}']),
+    ('\u{104F7}', &['\u{104CF}']),
+    ('\u{104F8}', &['\u{104D0}']),
+    ('\u{104F9}', &['\u{104D1}']),
+    ('\u{104FA}', &['\u{104D2}']),
+    ('\u{104FB}', &['\u{104D3}']),
+    ('\u{10CC0}', &['\u{10C80}']),
+    ('\u{10CC1}', &['\u{10C81}']),
+    ('\u{10CC2}', &['\u{10C82}']),
+    ('\u{10CC3}', &['\u{10C83}']),
+    ('\u{10CC4}', &['\u{10C84}']),
+    ('\u{10CC5}', &['\u{10C85}']),
+    ('\u{10CC6}', &['\u{10C86}']),
+    ('\u{10CC7}', &['\u{10C87}']),
+    ('\u{10CC8}', &['\u{10C88}']),
+    ('\u{10CC9}', &['\u{10C89}']),
+    ('\u{10CCA}', &['\u{10C8A}']),
+    ('\u{10CCB}', &['\u{10C8B}']),
+    ('\u{10CCC}', &['\u{10C8C}']),
+    ('\u{10CCD}', &['\u{10C8D}']),
+    ('\u{10CCE}', &['\u{10C8E}']),
+    ('\u{10CCF}', &['\u{10C8F}']),
+    ('\u{10CD0}', &['\u{10C90}']),
+    ('\u{10CD1}', &['\u{10C91}']),
+    ('\u{10CD2}', &['\u{10C92}']),
+    ('\u{10CD3}', &['\u{10C93}']),
+    ('\u{10CD4}', &['\u{10C94}']),
+    ('\u{10CD5}', &['\u{10C95}']),
+    ('\u{10CD6}', &['\u{10C96}']),
+    ('\u{10CD7}', &['\u{10C97}']),
+    ('\u{10CD8}', &['\u{10C98}']),
+    ('\u{10CD9}', &['\u{10C99}']),
+    ('\u{10CDA}', &['\u{10C9A}']),
+    ('\u{10CDB}', &['\u{10C9B}']),
+    ('\u{10CDC}', &['\u{10C9C}']),
+    ('\u{10CDD}', &['\u{10C9D}']),
+    ('\u{10CDE}', &['\u{10C9E}']),
+    ('\u{10CDF}', &['\u{10C9F}']),
+    ('\u{10CE0}', &['\u{10CA0}']),
+    ('\u{10CE1}', &['\u{10CA1}']),
+    ('\u{10CE2}', &['\u{10CA2}']),
+    ('\u{10CE3}', &['\u{10CA3}']),
+    ('\u{10CE4}', &['\u{10CA4}']),
+    ('\u{10CE5}', &['\u{10CA5}']),
+    ('\u{10CE6}', &['\u{10CA6}']),
+    ('\u{10CE7}', &['\u{10CA7}']),
+    ('\u{10CE8}', &['\u{10CA8}']),
+    ('\u{10CE9}', &['\u{10CA9}']),
+    ('\u{10CEA}', &['\u{10CAA}']),
+    ('\u{10CEB}', &['\u{10CAB}']),
+    ('\u{10CEC}', &['\u{10CAC}']),
+    ('\u{10CED}', &['\u{10CAD}']),
+    ('\u{10CEE}', &['\u{10CAE}']),
+    ('\u{10CEF}', &['\u{10CAF}']),
+    ('\u{10CF0}', &['\u{10CB0}']),
+    ('\u{10CF1}', &['\u{10CB1}']),
+    ('\u{10CF2}', &['\u{10CB2}']),
+    ('\u{118C0}', &['\u{118A0}']),
+    ('\u{118C1}', &['\u{118A1}']),
+    ('\u{118C2}', &['\u{118A2}']),
+    ('\u{118C3}', &['\u{118A3}']),
+    ('\u{118C4}', &['\u{118A4}']),
+    ('\u{118C5}', &['\u{118A5}']),
+    ('\u{118C6}', &['\u{118A6}']),
+    ('\u{118C7}', &['\u{118A7}']),
+    ('\u{118C8}', &['\u{118A8}']),
+    ('\u{118C9}', &['\u{118A9}']),
+    ('\u{118CA}', &['\u{118AA}']),
+    ('\u{118CB}', &['\u{118AB}']),
+    ('\u{118CC}', &['\u{118AC}']),
+    ('\u{118CD}', &['\u{118AD}']),
+    ('\u{118CE}', &['\u{118AE}']),
+    ('\u{118CF}', &['\u{118AF}']),
+    ('\u{118D0}', &['\u{118B0}']),
+    ('\u{118D1}', &['\u{118B1}']),
+    ('\u{118D2}', &['\u{118B2}']),
+    ('\u{118D3}', &['\u{118B3}']),
+    ('\u{118D4}', &['\u{118B4}']),
+    ('\u{118D5}', &['\u{118B5}']),
+    ('\u{118D6}', &['\u{118B6}']),
+    ('\u{118D7}', &['\u{118B7}']),
+    ('\u{118D8}', &['\u{118B8}']),
+    ('\u{118D9}', &['\u{118B9}']),
+    ('\u{118DA}', &['\u{118BA}']),
+    ('\u{118DB}', &['\u{118BB}']),
+    ('\u{118DC}', &['\u{118BC}']),
+    ('\u{118DD}', &['\u{118BD}']),
+    ('\u{118DE}', &['\u{118BE}']),
+    ('\u{118DF}', &['\u{118BF}']),
+    ('\u{16E60}', &['\u{16E40}']),
+    ('\u{16E61}', &['\u{16E41}']),
+    ('\u{16E62}', &['\u{16E42}']),
+    ('\u{16E63}', &['\u{16E43}']),
+    ('\u{16E64}', &['\u{16E44}']),
+    ('\u{16E65}', &['\u{16E45}']),
+    ('\u{16E66}', &['\u{16E46}']),
+    ('\u{16E67}', &['\u{16E47}']),
+    ('\u{16E68}', &['\u{16E48}']),
+    ('\u{16E69}', &['\u{16E49}']),
+    ('\u{16E6A}', &['\u{16E4A}']),
+    ('\u{16E6B}', &['\u{16E4B}']),
+    ('\u{16E6C}', &['\u{16E4C}']),
+    ('\u{16E6D}', &['\u{16E4D}']),
+    ('\u{16E6E}', &['\u{16E4E}']),
+    ('\u{16E6F}', &['\u{16E4F}']),
+    ('\u{16E70}', &['\u{16E50}']),
+    ('\u{16E71}', &['\u{16E51}']),
+    ('\u{16E72}', &['\u{16E52}']),
+    ('\u{16E73}', &['\u{16E53}']),
+    ('\u{16E74}', &['\u{16E54}']),
+    ('\u{16E75}', &['\u{16E55}']),
+    ('\u{16E76}', &['\u{16E56}']),
+    ('\u{16E77}', &['\u{16E57}']),
+    ('\u{16E78}', &['\u{16E58}']),
+    ('\u{16E79}', &['\u{16E59}']),
+    ('\u{16E7A}', &['\u{16E5A}']),
+    ('\u{16E7B}', &['\u{16E5B}']),
+    ('\u{16E7C}', &['\u{16E5C}']),
+    ('\u{16E7D}', &['\u{16E5D}']),
+    ('\u{16E7E}', &['\u{16E5E}']),
+    ('\u{16E7F}', &['\u{16E5F}']),
+    ('\u{1E922}', &['\u{1E900}']),
+    ('\u{1E923}', &['\u{1E901}']),
+    ('\u{1E924}', &['\u{1E902}']),
+    ('\u{1E925}', &['\u{1E903}']),
+    ('\u{1E926}', &['\u{1E904}']),
+    ('\u{1E927}', &['\u{1E905}']),
+    ('\u{1E928}', &['\u{1E906}']),
+    ('\u{1E929}', &['\u{1E907}']),
+    ('\u{1E92A}', &['\u{1E908}']),
+    ('\u{1E92B}', &['\u{1E909}']),
+    ('\u{1E92C}', &['\u{1E90A}']),
+    ('\u{1E92D}', &['\u{1E90B}']),
+    ('\u{1E92E}', &['\u{1E90C}']),
+    ('\u{1E92F}', &['\u{1E90D}']),
+    ('\u{1E930}', &['\u{1E90E}']),
+    ('\u{1E931}', &['\u{1E90F}']),
+    ('\u{1E932}', &['\u{1E910}']),
+    ('\u{1E933}', &['\u{1E911}']),
+    ('\u{1E934}', &['\u{1E912}']),
+    ('\u{1E935}', &['\u{1E913}']),
+    ('\u{1E936}', &['\u{1E914}']),
+    ('\u{1E937}', &['\u{1E915}']),
+    ('\u{1E938}', &['\u{1E916}']),
+    ('\u{1E939}', &['\u{1E917}']),
+    ('\u{1E93A}', &['\u{1E918}']),
+    ('\u{1E93B}', &['\u{1E919}']),
+    ('\u{1E93C}', &['\u{1E91A}']),
+    ('\u{1E93D}', &['\u{1E91B}']),
+    ('\u{1E93E}', &['\u{1E91C}']),
+    ('\u{1E93F}', &['\u{1E91D}']),
+    ('\u{1E940}', &['\u{1E91E}']),
+    ('\u{1E941}', &['\u{1E91F}']),
+    ('\u{1E942}', &['\u{1E920}']),
+    ('\u{1E943}', &['\u{1E921}']),
+];
+
+pub(super) const LOWERCASE_MAPPINGS: &[(char, &[char])] = &[
+    ('\u{0041}', &['\u{0061}']),
+    ('\u{0042}', &['\u{0062}']),
+    ('\u{0043}', &['\u{0063}']),
+    ('\u{0044}', &['\u{0064}']),
+    ('\u{0045}', &['\u{0065}']),
+    ('\u{0046}', &['\u{0066}']),
+    ('\u{0047}', &['\u{0067}']),
+    ('\u{0048}', &['\u{0068}']),
+    ('\u{0049}', &['\u{0069}']),
+    ('\u{004A}', &['\u{006A}']),
+    ('\u{004B}', &['\u{006B}']),
+    ('\u{004C}', &['\u{006C}']),
+    ('\u{004D}', &['\u{006D}']),
+    ('\u{004E}', &['\u{006E}']),
+    ('\u{004F}', &['\u{006F}']),
+    ('\u{0050}', &['\u{0070}']),
+    ('\u{0051}', &['\u{0071}']),
+    ('\u{0052}', &['\u{0072}']),
+    ('\u{0053}', &['\u{0073}']),
+    ('\u{0054}', &['\u{0074}']),
+    ('\u{0055}', &['\u{0075}']),
+    ('\u{0056}', &['\u{0076}']),
+    ('\u{0057}', &['\u{0077}']),
+    ('\u{0058}', &['\u{0078}']),
+    ('\u{0059}', &['\u{0079}']),
+    ('\u{005A}', &['\u{007A}']),
+    ('\u{00C0}', &['\u{00E0}']),
+    ('\u{00C1}', &['\u{00E1}']),
+    ('\u{00C2}', &['\u{00E2}']),
+    ('\u{00C3}', &['\u{00E3}']),
+    ('\u{00C4}', &['\u{00E4}']),
+    ('\u{00C5}', &['\u{00E5}']),
+    ('\u{00C6}', &['\u{00E6}']),
+    ('\u{00C7}', &['\u{00E7}']),
+    ('\u{00C8}', &['\u{00E8}']),
+    ('\u{00C9}', &['\u{00E9}']),
+    ('\u{00CA}', &['\u{00EA}']),
+    ('\u{00CB}', &['\u{00EB}']),
+    ('\u{00CC}', &['\u{00EC}']),
+    ('\u{00CD}', &['\u{00ED}']),
+    ('\u{00CE}', &['\u{00EE}']),
+    ('\u{00CF}', &['\u{00EF}']),
+    ('\u{00D0}', &['\u{00F0}']),
+    ('\u{00D1}', &['\u{00F1}']),
+    ('\u{00D2}', &['\u{00F2}']),
+    ('\u{00D3}', &['\u{00F3}']),
+    ('\u{00D4}', &['\u{00F4}']),
+    ('\u{00D5}', &['\u{00F5}']),
+    ('\u{00D6}', &['\u{00F6}']),
+    ('\u{00D8}', &['\u{00F8}']),
+    ('\u{00D9}', &['\u{00F9}']),
+    ('\u{00DA}', &['\u{00FA}']),
+    ('\u{00DB}', &['\u{00FB}']),
+    ('\u{00DC}', &['\u{00FC}']),
+    ('\u{00DD}', &['\u{00FD}']),
+    ('\u{00DE}', &['\u{00FE}']),
+    ('\u{0100}', &['\u{0101}']),
+    ('\u{0102}', &['\u{0103}']),
+    ('\u{0104}', &['\u{0105}']),
+    ('\u{0106}', &['\u{0107}']),
+    ('\u{0108}', &['\u{0109}']),
+    ('\u{010A}', &['\u{010B}']),
+    ('\u{010C}', &['\u{010D}']),
+    ('\u{010E}', &['\u{010F}']),
+    ('\u{0110}', &['\u{0111}']),
+    ('\u{0112}', &['\u{0113}']),
+    ('\u{0114}', &['\u{0115}']),
+    ('\u{0116}', &['\u{0117}']),
+    ('\u{0118}', &['\u{0119}']),
+    ('\u{011A}', &['\u{011B}']),
+    ('\u{011C}', &['\u{011D}']),
+    ('\u{011E}', &['\u{011F}']),
+    ('\u{0120}', &['\u{0121}']),
+    ('\u{0122}', &['\u{0123}']),
+    ('\u{0124}', &['\u{0125}']),
+    ('\u{0126}', &['\u{0127}']),
+    ('\u{0128}', &['\u{0129}']),
+    ('\u{012A}', &['\u{012B}']),
+    ('\u{012C}', &['\u{012D}']),
+    ('\u{012E}', &['\u{012F}']),
+    ('\u{0130}', &['\u{0069}', '\u{0307}']),
+    ('\u{0132}', &['\u{0133}']),
+    ('\u{0134}', &['\u{0135}']),
+    ('\u{0136}', &['\u{0137}']),
+    ('\u{0139}', &['\u{013A}']),
+    ('\u{013B}', &['\u{013C}']),
+    ('\u{013D}', &['\u{013E}']),
+    ('\u{013F}', &['\u{0140}']),
+    ('\u{0141}', &['\u{0142}']),
+    ('\u{0143}', &['\u{0144}']),
+    ('\u{0145}', &['\u{0146}']),
+    ('\u{0147}', &['\u{0148}']),
+    ('\u{014A}', &['\u{014B}']),
+    ('\u{014C}', &['\u{014D}']),
+    ('\u{014E}', &['\u{014F}']),
+    ('\u{0150}', &['\u{0151}']),
+    ('\u{0152}', &['\u{0153}']),
+    ('\u{0154}', &['\u{0155}']),
+    ('\u{0156}', &['\u{0157}']),
+    ('\u{0158}', &['\u{0159}']),
+    ('\u{015A}', &['\u{015B}']),
+    ('\u{015C}', &['\u{015D}']),
+    ('\u{015E}', &['\u{015F}']),
+    ('\u{0160}', &['\u{0161}']),
+    ('\u{0162}', &['\u{0163}']),
+    ('\u{0164}', &['\u{0165}']),
+    ('\u{0166}', &['\u{0167}']),
+    ('\u{0168}', &['\u{0169}']),
+    ('\u{016A}', &['\u{016B}']),
+    ('\u{016C}', &['\u{016D}']),
+    ('\u{016E}', &['\u{016F}']),
+    ('\u{0170}', &['\u{0171}']),
+    ('\u{0172}', &['\u{0173}']),
+    ('\u{0174}', &['\u{0175}']),
+    ('\u{0176}', &['\u{0177}']),
+    ('\u{0178}', &['\u{00FF}']),
+    ('\u{0179}', &['\u{017A}']),
+    ('\u{017B}', &['\u{017C}']),
+    ('\u{017D}', &['\u{017E}']),
+    ('\u{0181}', &['\u{0253}']),
+    ('\u{0182}', &['\u{0183}']),
+    ('\u{0184}', &['\u{0185}']),
+    ('\u{0186}', &['\u{0254}']),
+    ('\u{0187}', &['\u{0188}']),
+    ('\u{0189}', &['\u{0256}']),
+    ('\u{018A}', &['\u{0257}']),
+    ('\u{018B}', &['\u{018C}']),
+    ('\u{018E}', &['\u{01DD}']),
+    ('\u{018F}', &['\u{0259}']),
+    ('\u{0190}', &['\u{025B}']),
+    ('\u{0191}', &['\u{0192}']),
+    ('\u{0193}', &['\u{0260}']),
+    ('\u{0194}', &['\u{0263}']),
+    ('\u{0196}', &['\u{0269}']),
+    ('\u{0197}', &['\u{0268}']),
+    ('\u{0198}', &['\u{0199}']),
+    ('\u{019C}', &['\u{026F}']),
+    ('\u{019D}', &['\u{0272}']),
+    ('\u{019F}', &['\u{0275}']),
+    ('\u{01A0}', &['\u{01A1}']),
+    ('\u{01A2}', &['\u{01A3}']),
+    ('\u{01A4}', &['\u{01A5}']),
+    ('\u{01A6}', &['\u{0280}']),
+    ('\u{01A7}', &['\u{01A8}']),
+    ('\u{01A9}', &['\u{0283}']),
+    ('\u{01AC}', &['\u{01AD}']),
+    ('\u{01AE}', &['\u{0288}']),
+    ('\u{01AF}', &['\u{01B0}']),
+    ('\u{01B1}', &['\u{028A}']),
+    ('\u{01B2}', &['\u{028B}']),
+    ('\u{01B3}', &['\u{01B4}']),
+    ('\u{01B5}', &['\u{01B6}']),
+    ('\u{01B7}', &['\u{0292}']),
+    ('\u{01B8}', &['\u{01B9}']),
+    ('\u{01BC}', &['\u{01BD}']),
+    ('\u{01C4}', &['\u{01C6}']),
+    ('\u{01C5}', &['\u{01C6}']),
+    ('\u{01C7}', &['\u{01C9}']),
+    ('\u{01C8}', &['\u{01C9}']),
+    ('\u{01CA}', &['\u{01CC}']),
+    ('\u{01CB}', &['\u{01CC}']),
+    ('\u{01CD}', &['\u{01CE}']),
+    ('\u{01CF}', &['\u{01D0}']),
+    ('\u{01D1}', &['\u{01D2}']),
+    ('\u{01D3}', &['\u{01D4}']),
+    ('\u{01D5}', &['\u{01D6}']),
+    ('\u{01D7}', &['\u{01D8}']),
+    ('\u{01D9}', &['\u{01DA}']),
+    ('\u{01DB}', &['\u{01DC}']),
+    ('\u{01DE}', &['\u{01DF}']),
+    ('\u{01E0}', &['\u{01E1}']),
+    ('\u{01E2}', &['\u{01E3}']),
+    ('\u{01E4}', &['\u{01E5}']),
+    ('\u{01E6}', &['\u{01E7}']),
+    ('\u{01E8}', &['\u{01E9}']),
+    ('\u{01EA}', &['\u{01EB}']),
+    ('\u{01EC}', &['\u{01ED}']),
+    ('\u{01EE}', &['\u{01EF}']),
+    ('\u{01F1}', &['\u{01F3}']),
+    ('\u{01F2}', &['\u{01F3}']),
+    ('\u{01F4}', &['\u{01F5}']),
+    ('\u{01F6}', &['\u{0195}']),
+    ('\u{01F7}', &['\u{01BF}']),
+    ('\u{01F8}', &['\u{01F9}']),
+    ('\u{01FA}', &['\u{01FB}']),
+    ('\u{01FC}', &['\u{01FD}']),
+    ('\u{01FE}', &['\u{01FF}']),
+    ('\u{0200}', &['\u{0201}']),
+    ('\u{0202}', &['\u{0203}']),
+    ('\u{0204}', &['\u{0205}']),
+    ('\u{0206}', &['\u{0207}']),
+    ('\u{0208}', &['\u{0209}']),
+    ('\u{020A}', &['\u{020B}']),
+    ('\u{020C}', &['\u{020D}']),
+    ('\u{020E}', &['\u{020F}']),
+    ('\u{0210}', &['\u{0211}']),
+    ('\u{0212}', &['\u{0213}']),
+    ('\u{0214}', &['\u{0215}']),
+    ('\u{0216}', &['\u{0217}']),
+    ('\u{0218}', &['\u{0219}']),
+    ('\u{021A}', &['\u{021B}']),
+    ('\u{021C}', &['\u{021D}']),
+    ('\u{021E}', &['\u{021F}']),
+    ('\u{0220}', &['\u{019E}']),
+    ('\u{0222}', &['\u{0223}']),
+    ('\u{0224}', &['\u{0225}']),
+    ('\u{0226}', &['\u{0227}']),
+    ('\u{0228}', &['\u{0229}']),
+    ('\u{022A}', &['\u{022B}']),
+    ('\u{022C}', &['\u{022D}']),
+    ('\u{022E}', &['\u{022F}']),
+    ('\u{0230}', &['\u{0231}']),
+    ('\u{0232}', &['\u{0233}']),
+    ('\u{023A}', &['\u{2C65}']),
+    ('\u{023B}', &['\u{023C}']),
+    ('\u{023D}', &['\u{019A}']),
+    ('\u{023E}', &['\u{2C66}']),
+    ('\u{0241}', &['\u{0242}']),
+    ('\u{0243}', &['\u{0180}']),
+    ('\u{0244}', &['\u{0289}']),
+    ('\u{0245}', &['\u{028C}']),
+    ('\u{0246}', &['\u{0247}']),
+    ('\u{0248}', &['\u{0249}']),
+    ('\u{024A}', &['\u{024B}']),
+    ('\u{024C}', &['\u{024D}']),
+    ('\u{024E}', &['\u{024F}']),
+    ('\u{0370}', &['\u{0371}']),
+    ('\u{0372}', &['\u{0373}']),
+    ('\u{0376}', &['\u{0377}']),
+    ('\u{037F}', &['\u{03F3}']),
+    ('\u{0386}', &['\u{03AC}']),
+    ('\u{0388}', &['\u{03AD}']),
+    ('\u{0389}', &['\u{03AE}']),
+    ('\u{038A}', &['\u{03AF}']),
+    ('\u{038C}', &['\u{03CC}']),
+    ('\u{038E}', &['\u{03CD}']),
+    ('\u{038F}', &['\u{03CE}']),
+    ('\u{0391}', &['\u{03B1}']),
+    ('\u{0392}', &['\u{03B2}']),
+    ('\u{0393}', &['\u{03B3}']),
+    ('\u{0394}', &['\u{03B4}']),
+    ('\u{0395}', &['\u{03B5}']),
+    ('\u{0396}', &['\u{03B6}']),
+    ('\u{0397}', &['\u{03B7}']),
+    ('\u{0398}', &['\u{03B8}']),
+    ('\u{0399}', &['\u{03B9}']),
+    ('\u{039A}', &['\u{03BA}']),
+    ('\u{039B}', &['\u{03BB}']),
+    ('\u{039C}', &['\u{03BC}']),
+    ('\u{039D}', &['\u{03BD}']),
+    ('\u{039E}', &['\u{03BE}']),
+    ('\u{039F}', &['\u{03BF}']),
+    ('\u{03A0}', &['\u{03C0}']),
+    ('\u{03A1}', &['\u{03C1}']),
+    ('\u{03A3}', &['\u{03C3}']),
+    ('\u{03A4}', &['\u{03C4}']),
+    ('\u{03A5}', &['\u{03C5}']),
+    ('\u{03A6}', &['\u{03C6}']),
+    ('\u{03A7}', &['\u{03C7}']),
+    ('\u{03A8}', &['\u{03C8}']),
+    ('\u{03A9}', &['\u{03C9}']),
+    ('\u{03AA}', &['\u{03CA}']),
+    ('\u{03AB}', &['\u{03CB}']),
+    ('\u{03CF}', &['\u{03D7}']),
+    ('\u{03D8}', &['\u{03D9}']),
+    ('\u{03DA}', &['\u{03DB}']),
+    ('\u{03DC}', &['\u{03DD}']),
+    ('\u{03DE}', &['\u{03DF}']),
+    ('\u{03E0}', &['\u{03E1}']),
+    ('\u{03E2}', &['\u{03E3}']),
+    ('\u{03E4}', &['\u{03E5}']),
+    ('\u{03E6}', &['\u{03E7}']),
+    ('\u{03E8}', &['\u{03E9}']),
+    ('\u{03EA}', &['\u{03EB}']),
+    ('\u{03EC}', &['\u{03ED}']),
+    ('\u{03EE}', &['\u{03EF}']),
+    ('\u{03F4}', &['\u{03B8}']),
+    ('\u{03F7}', &['\u{03F8}']),
+    ('\u{03F9}', &['\u{03F2}']),
+    ('\u{03FA}', &['\u{03FB}']),
+    ('\u{03FD}', &['\u{037B}']),
+    ('\u{03FE}', &['\u{037C}']),
+    ('\u{03FF}', &['\u{037D}']),
+    ('\u{0400}', &['\u{0450}']),
+    ('\u{0401}', &['\u{0451}']),
+    ('\u{0402}', &['\u{0452}']),
+    ('\u{0403}', &['\u{0453}']),
+    ('\u{0404}', &['\u{0454}']),
+    ('\u{0405}', &['\u{0455}']),
+    ('\u{0406}', &['\u{0456}']),
+    ('\u{0407}', &['\u{0457}']),
+    ('\u{0408}', &['\u{0458}']),
+    ('\u{0409}', &['\u{0459}']),
+    ('\u{040A}', &['\u{045A}']),
+    ('\u{040B}', &['\u{045B}']),
+    ('\u{040C}', &['\u{045C}']),
+    ('\u{040D}', &['\u{045D}']),
+    ('\u{040E}', &['\u{045E}']),
+    ('\u{040F}', &['\u{045F}']),
+    ('\u{0410}', &['\u{0430}']),
+    ('\u{0411}', &['\u{0431}']),
+    ('\u{0412}', &['\u{0432}']),
+    ('\u{0413}', &['\u{0433}']),
+    ('\u{0414}', &['\u{0434}']),
+    ('\u{0415}', &['\u{0435}']),
+    ('\u{0416}', &['\u{0436}']),
+    ('\u{0417}', &['\u{0437}']),
+    ('\u{0418}', &['\u{0438}']),
+    ('\u{0419}', &['\u{0439}']),
+    ('\u{041A}', &['\u{043A}']),
+    ('\u{041B}', &['\u{043B}']),
+    ('\u{041C}', &['\u{043C}']),
+    ('\u{041D}', &['\u{043D}']),
+    ('\u{041E}', &['\u{043E}']),
+    ('\u{041F}', &['\u{043F}']),
+    ('\u{0420}', &['\u{0440}']),
+    ('\u{0421}', &['\u{0441}']),
+    ('\u{0422}', &['\u{0442}']),
+    ('\u{0423}', &['\u{0443}']),
+    ('\u{0424}', &['\u{0444}']),
+    ('\u{0425}', &['\u{0445}']),
+    ('\u{0426}', &['\u{0446}']),
+    ('\u{0427}', &['\u{0447}']),
+    ('\u{0428}', &['\u{0448}']),
+    ('\u{0429}', &['\u{0449}']),
+    ('\u{042A}', &['\u{044A}']),
+    ('\u{042B}', &['\u{044B}']),
+    ('\u{042C}', &['\u{044C}']),
+    ('\u{042D}', &['\u{044D}']),
+    ('\u{042E}', &['\u{044E}']),
+    ('\u{042F}', &['\u{044F}']),
+    ('\u{0460}', &['\u{0461}']),
+    ('\u{0462}', &['\u{0463}']),
+    ('\u{0464}', &['\u{0465}']),
+    ('\u{0466}', &['\u{0467}']),
+    ('\u{0468}', &['\u{0469}']),
+    ('\u{046A}', &['\u{046B}']),
+    ('\u{046C}', &['\u{046D}']),
+    ('\u{046E}', &['\u{046F}']),
+    ('\u{0470}', &['\u{0471}']),
+    ('\u{0472}', &['\u{0473}']),
+    ('\u{0474}', &['\u{0475}']),
+    ('\u{0476}', &['\u{0477}']),
+    ('\u{0478}', &['\u{0479}']),
+    ('\u{047A}', &['\u{047B}']),
+    ('\u{047C}', &['\u{047D}']),
+    ('\u{047E}', &['\u{047F}']),
+    ('\u{0480}', &['\u{0481}']),
+    ('\u{048A}', &['\u{048B}']),
+    ('\u{048C}', &['\u{048D}']),
+    ('\u{048E}', &['\u{048F}']),
+    ('\u{0490}', &['\u{0491}']),
+    ('\u{0492}', &['\u{0493}']),
+    ('\u{0494}', &['\u{0495}']),
+    ('\u{0496}', &['\u{0497}']),
+    ('\u{0498}', &['\u{0499}']),
+    ('\u{049A}', &['\u{049B}']),
+    ('\u{049C}', &['\u{049D}']),
+    ('\u{049E}', &['\u{049F}']),
+    ('\u{04A0}', &['\u{04A1}']),
+    ('\u{04A2}', &['\u{04A3}']),
+    ('\u{04A4}', &['\u{04A5}']),
+    ('\u{04A6}', &['\u{04A7}']),
+    ('\u{04A8}', &['\u{04A9}']),
+    ('\u{04AA}', &['\u{04AB}']),
+    ('\u{04AC}', &['\u{04AD}']),
+    ('\u{04AE}', &['\u{04AF}']),
+    ('\u{04B0}', &['\u{04B1}']),
+    ('\u{04B2}', &['\u{04B3}']),
+    ('\u{04B4}', &['\u{04B5}']),
+    ('\u{04B6}', &['\u{04B7}']),
+    ('\u{04B8}', &['\u{04B9}']),
+    ('\u{04BA}', &['\u{04BB}']),
+    ('\u{04BC}', &['\u{04BD}']),
+    ('\u{04BE}', &['\u{04BF}']),
+    ('\u{04C0}', &['\u{04CF}']),
+    ('\u{04C1}', &['\u{04C2}']),
+    ('\u{04C3}', &['\u{04C4}']),
+    ('\u{04C5}', &['\u{04C6}']),
+    ('\u{04C7}', &['\u{04C8}']),
+    ('\u{04C9}', &['\u{04CA}']),
+    ('\u{04CB}', &['\u{04CC}']),
+    ('\u{04CD}', &['\u{04CE}']),
+    ('\u{04D0}', &['\u{04D1}']),
+    ('\u{04D2}', &['\u{04D3}']),
+    ('\u{04D4}', &['\u{04D5}']),
+    ('\u{04D6}', &['\u{04D7}']),
+    ('\u{04D8}', &['\u{04D9}']),
+    ('\u{04DA}', &['\u{04DB}']),
+    ('\u{04DC}', &['\u{04DD}']),
+    ('\u{04DE}', &['\u{04DF}']),
+    ('\u{04E0}', &['\u{04E1}']),
+    ('\u{04E2}', &['\u{04E3}']),
+    ('\u{04E4}', &['\u{04E5}']),
+    ('\u{04E6}', &['\u{04E7}']),
+    ('\u{04E8}', &['\u{04E9}']),
+    ('\u{04EA}', &['\u{04EB}']),
+    ('\u{04EC}', &['\u{04ED}']),
+    ('\u{04EE}', &['\u{04EF}']),
+    ('\u{04F0}', &['\u{04F1}']),
+    ('\u{04F2}', &['\u{04F3}']),
+    ('\u{04F4}', &['\u{04F5}']),
+    ('\u{04F6}', &['\u{04F7}']),
+    ('\u{04F8}', &['\u{04F9}']),
+    ('\u{04FA}', &['\u{04FB}']),
+    ('\u{04FC}', &['\u{04FD}']),
+    ('\u{04FE}', &['\u{04FF}']),
+    ('\u{0500}', &['\u{0501}']),
+    ('\u{0502}', &['\u{0503}']),
+    ('\u{0504}', &['\u{0505}']),
+    ('\u{0506}', &['\u{0507}']),
+    ('\u{0508}', &['\u{0509}']),
+    ('\u{050A}', &['\u{050B}']),
+    ('\u{050C}', &['\u{050D}']),
+    ('\u{050E}', &['\u{050F}']),
+    ('\u{0510}', &['\u{0511}']),
+    ('\u{0512}', &['\u{0513}']),
+    ('\u{0514}', &['\u{0515}']),
+    ('\u{0516}', &['\u{0517}']),
+    ('\u{0518}', &['\u{0519}']),
+    ('\u{051A}', &['\u{051B}']),
+    ('\u{051C}', &['\u{051D}']),
+    ('\u{051E}', &['\u{051F}']),
+    ('\u{0520}', &['\u{0521}']),
+    ('\u{0522}', &['\u{0523}']),
+    ('\u{0524}', &['\u{0525}']),
+    ('\u{0526}', &['\u{0527}']),
+    ('\u{0528}', &['\u{0529}']),
+    ('\u{052A}', &['\u{052B}']),
+    ('\u{052C}', &['\u{052D}']),
+    ('\u{052E}', &['\u{052F}']),
+    ('\u{0531}', &['\u{0561}']),
+    ('\u{0532}', &['\u{0562}']),
+    ('\u{0533}', &['\u{0563}']),
+    ('\u{0534}', &['\u{0564}']),
+    ('\u{0535}', &['\u{0565}']),
+    ('\u{0536}', &['\u{0566}']),
+    ('\u{0537}', &['\u{0567}']),
+    ('\u{0538}', &['\u{0568}']),
+    ('\u{0539}', &['\u{0569}']),
+    ('\u{053A}', &['\u{056A}']),
+    ('\u{053B}', &['\u{056B}']),
+    ('\u{053C}', &['\u{056C}']),
+    ('\u{053D}', &['\u{056D}']),
+    ('\u{053E}', &['\u{056E}']),
+    ('\u{053F}', &['\u{056F}']),
+    ('\u{0540}', &['\u{0570}']),
+    ('\u{0541}', &['\u{0571}']),
+    ('\u{0542}', &['\u{0572}']),
+    ('\u{0543}', &['\u{0573}']),
+    ('\u{0544}', &['\u{0574}']),
+    ('\u{0545}', &['\u{0575}']),
+    ('\u{0546}', &['\u{0576}']),
+    ('\u{0547}', &['\u{0577}']),
+    ('\u{0548}', &['\u{0578}']),
+    ('\u{0549}', &['\u{0579}']),
+    ('\u{054A}', &['\u{057A}']),
+    ('\u{054B}', &['\u{057B}']),
+    ('\u{054C}', &['\u{057C}']),
+    ('\u{054D}', &['\u{057D}']),
+    ('\u{054E}', &['\u{057E}']),
+    ('\u{054F}', &['\u{057F}']),
+    ('\u{0550}', &['\u{0580}']),
+    ('\u{0551}', &['\u{0581}']),
+    ('\u{0552}', &['\u{0582}']),
+    ('\u{0553}', &['\u{0583}']),
+    ('\u{0554}', &['\u{0584}']),
+    ('\u{0555}', &['\u{0585}']),
+    ('\u{0556}', &['\u{0586}']),
+    ('\u{10A0}', &['\u{2D00}']),
+    ('\u{10A1}', &['\u{2D01}']),
+    ('\u{10A2}', &['\u{2D02}']),
+    ('\u{10A3}', &['\u{2D03}']),
+    ('\u{10A4}', &['\u{2D04}']),
+    ('\u{10A5}', &['\u{2D05}']),
+    ('\u{10A6}', &['\u{2D06}']),
+    ('\u{10A7}', &['\u{2D07}']),
+    ('\u{10A8}', &['\u{2D08}']),
+    ('\u{10A9}', &['\u{2D09}']),
+    ('\u{10AA}', &['\u{2D0A}']),
+    ('\u{10AB}', &['\u{2D0B}']),
+    ('\u{10AC}', &['\u{2D0C}']),
+    ('\u{10AD}', &['\u{2D0D}']),
+    ('\u{10AE}', &['\u{2D0E}']),
+    ('\u{10AF}', &['\u{2D0F}']),
+    ('\u{10B0}', &['\u{2D10}']),
+    ('\u{10B1}', &['\u{2D11}']),
+    ('\u{10B2}', &['\u{2D12}']),
+    ('\u{10B3}', &['\u{2D13}']),
+    ('\u{10B4}', &['\u{2D14}']),
+    ('\u{10B5}', &['\u{2D15}']),
+    ('\u{10B6}', &['\u{2D16}']),
+    ('\u{10B7}', &['\u{2D17}']),
+    ('\u{10B8}', &['\u{2D18}']),
+    ('\u{10B9}', &['\u{2D19}']),
+    ('\u{10BA}', &['\u{2D1A}']),
+    ('\u{10BB}', &['\u{2D1B}']),
+    ('\u{10BC}', &['\u{2D1C}']),
+    ('\u{10BD}', &['\u{2D1D}']),
+    ('\u{10BE}', &['\u{2D1E}']),
+    ('\u{10BF}', &['\u{2D1F}']),
+    ('\u{10C0}', &['\u{2D20}']),
+    ('\u{10C1}', &['\u{2D21}']),
+    ('\u{10C2}', &['\u{2D22}']),
+    ('\u{10C3}', &['\u{2D23}']),
+    ('\u{10C4}', &['\u{2D24}']),
+    ('\u{10C5}', &['\u{2D25}']),
+    ('\u{10C7}', &['\u{2D27}']),
+    ('\u{10CD}', &['\u{2D2D}']),
+    ('\u{13A0}', &['\u{AB70}']),
+    ('\u{13A1}', &['\u{AB71}']),
+    ('\u{13A2}', &['\u{AB72}']),
+    ('\u{13A3}', &['\u{AB73}']),
+    ('\u{13A4}', &['\u{AB74}']),
+    ('\u{13A5}', &['\u{AB75}']),
+    ('\u{13A6}', &['\u{AB76}']),
+    ('\u{13A7}', &['\u{AB77}']),
+    ('\u{13A8}', &['\u{AB78}']),
+    ('\u{13A9}', &['\u{AB79}']),
+    ('\u{13AA}', &['\u{AB7A}']),
+    ('\u{13AB}', &['\u{AB7B}']),
+    ('\u{13AC}', &['\u{AB7C}']),
+    ('\u{13AD}', &['\u{AB7D}']),
+    ('\u{13AE}', &['\u{AB7E}']),
+    ('\u{13AF}', &['\u{AB7F}']),
+    ('\u{13B0}', &['\u{AB80}']),
+    ('\u{13B1}', &['\u{AB81}']),
+    ('\u{13B2}', &['\u{AB82}']),
+    ('\u{13B3}', &['\u{AB83}']),
+    ('\u{13B4}', &['\u{AB84}']),
+    ('\u{13B5}', &['\u{AB85}']),
+    ('\u{13B6}', &['\u{AB86}']),
+    ('\u{13B7}', &['\u{AB87}']),
+    ('\u{13B8}', &['\u{AB88}']),
+    ('\u{13B9}', &['\u{AB89}']),
+    ('\u{13BA}', &['\u{AB8A}']),
+    ('\u{13BB}', &['\u{AB8B}']),
+    ('\u{13BC}', &['\u{AB8C}']),
+    ('\u{13BD}', &['\u{AB8D}']),
+    ('\u{13BE}', &['\u{AB8E}']),
+    ('\u{13BF}', &['\u{AB8F}']),
+    ('\u{13C0}', &['\u{AB90}']),
+    ('\u{13C1}', &['\u{AB91}']),
+    ('\u{13C2}', &['\u{AB92}']),
+    ('\u{13C3}', &['\u{AB93}']),
+    ('\u{13C4}', &['\u{AB94}']),
+    ('\u{13C5}', &['\u{AB95}']),
+    ('\u{13C6}', &['\u{AB96}']),
+    ('\u{13C7}', &['\u{AB97}']),
+    ('\u{13C8}', &['\u{AB98}']),
+    ('\u{13C9}', &['\u{AB99}']),
+    ('\u{13CA}', &['\u{AB9A}']),
+    ('\u{13CB}', &['\u{AB9B}']),
+    ('\u{13CC}', &['\u{AB9C}']),
+    ('\u{13CD}', &['\u{AB9D}']),
+    ('\u{13CE}', &['\u{AB9E}']),
+    ('\u{13CF}', &['\u{AB9F}']),
+    ('\u{13D0}', &['\u{ABA0}']),
+    ('\u{13D1}', &['\u{ABA1}']),
+    ('\u{13D2}', &['\u{ABA2}']),
+    ('\u{13D3}', &['\u{ABA3}']),
+    ('\u{13D4}', &['\u{ABA4}']),
+    ('\u{13D5}', &['\u{ABA5}']),
+    ('\u{13D6}', &['\u{ABA6}']),
+    ('\u{13D7}', &['\u{ABA7}']),
+    ('\u{13D8}', &['\u{ABA8}']),
+    ('\u{13D9}', &['\u{ABA9}']),
+    ('\u{13DA}', &['\u{ABAA}']),
+    ('\u{13DB}', &['\u{ABAB}']),
+    ('\u{13DC}', &['\u{ABAC}']),
+    ('\u{13DD}', &['\u{ABAD}']),
+    ('\u{13DE}', &['\u{ABAE}']),
+    ('\u{13DF}', &['\u{ABAF}']),
+    ('\u{13E0}', &['\u{ABB0}']),
+    ('\u{13E1}', &['\u{ABB1}']),
+    ('\u{13E2}', &['\u{ABB2}']),
+    ('\u{13E3}', &['\u{ABB3}']),
+    ('\u{13E4}', &['\u{ABB4}']),
+    ('\u{13E5}', &['\u{ABB5}']),
+    ('\u{13E6}', &['\u{ABB6}']),
+    ('\u{13E7}', &['\u{ABB7}']),
+    ('\u{13E8}', &['\u{ABB8}']),
+    ('\u{13E9}', &['\u{ABB9}']),
+    ('\u{13EA}', &['\u{ABBA}']),
+    ('\u{13EB}', &['\u{ABBB}']),
+    ('\u{13EC}', &['\u{ABBC}']),
+    ('\u{13ED}', &['\u{ABBD}']),
+    ('\u{13EE}', &['\u{ABBE}']),
+    ('\u{13EF}', &['\u{ABBF}']),
+    ('\u{13F0}', &['\u{13F8}']),
+    ('\u{13F1}', &['\u{13F9}']),
+    ('\u{13F2}', &['\u{13FA}']),
+    ('\u{13F3}', &['\u{13FB}']),
+    ('\u{13F4}', &['\u{13FC}']),
+    ('\u{13F5}', &['\u{13FD}']),
+    ('\u{1C90}', &['\u{10D0}']),
+    ('\u{1C91}', &['\u{10D1}']),
+    ('\u{1C92}', &['\u{10D2}']),
+    ('\u{1C93}', &['\u{10D3}']),
+    ('\u{1C94}', &['\u{10D4}']),
+    ('\u{1C95}', &['\u{10D5}']),
+    ('\u{1C96}', &['\u{10D6}']),
+    ('\u{1C97}', &['\u{10D7}']),
+    ('\u{1C98}', &['\u{10D8}']),
+    ('\u{1C99}', &['\u{10D9}']),
+    ('\u{1C9A}', &['\u{10DA}']),
+    ('\u{1C9B}', &['\u{10DB}']),
+    ('\u{1C9C}', &['\u{10DC}']),
+    ('\u{1C9D}', &['\u{10DD}']),
+    ('\u{1C9E}', &['\u{10DE}']),
+    ('\u{1C9F}', &['\u{10DF}']),
+    ('\u{1CA0}', &['\u{10E0}']),
+    ('\u{1CA1}', &['\u{10E1}']),
+    ('\u{1CA2}', &['\u{10E2}']),
+    ('\u{1CA3}', &['\u{10E3}']),
+    ('\u{1CA4}', &['\u{10E4}']),
+    ('\u{1CA5}', &['\u{10E5}']),
+    ('\u{1CA6}', &['\u{10E6}']),
+    ('\u{1CA7}', &['\u{10E7}']),
+    ('\u{1CA8}', &['\u{10E8}']),
+    ('\u{1CA9}', &['\u{10E9}']),
+    ('\u{1CAA}', &['\u{10EA}']),
+    ('\u{1CAB}', &['\u{10EB}']),
+    ('\u{1CAC}', &['\u{10EC}']),
+    ('\u{1CAD}', &['\u{10ED}']),
+    ('\u{1CAE}', &['\u{10EE}']),
+    ('\u{1CAF}', &['\u{10EF}']),
+    ('\u{1CB0}', &['\u{10F0}']),
+    ('\u{1CB1}', &['\u{10F1}']),
+    ('\u{1CB2}', &['\u{10F2}']),
+    ('\u{1CB3}', &['\u{10F3}']),
+    ('\u{1CB4}', &['\u{10F4}']),
+    ('\u{1CB5}', &['\u{10F5}']),
+    ('\u{1CB6}', &['\u{10F6}']),
+    ('\u{1CB7}', &['\u{10F7}']),
+    ('\u{1CB8}', &['\u{10F8}']),
+    ('\u{1CB9}', &['\u{10F9}']),
+    ('\u{1CBA}', &['\u{10FA}']),
+    ('\u{1CBD}', &['\u{10FD}']),
+    ('\u{1CBE}', &['\u{10FE}']),
+    ('\u{1CBF}', &['\u{10FF}']),
+    ('\u{1E00}', &['\u{1E01}']),
+    ('\u{1E02}', &['\u{1E03}']),
+    ('\u{1E04}', &['\u{1E05}']),
+    ('\u{1E06}', &['\u{1E07}']),
+    ('\u{1E08}', &['\u{1E09}']),
+    ('\u{1E0A}', &['\u{1E0B}']),
+    ('\u{1E0C}', &['\u{1E0D}']),
+    ('\u{1E0E}', &['\u{1E0F}']),
+    ('\u{1E10}', &['\u{1E11}']),
+    ('\u{1E12}', &['\u{1E13}']),
+    ('\u{1E14}', &['\u{1E15}']),
+    ('\u{1E16}', &['\u{1E17}']),
+    ('\u{1E18}', &['\u{1E19}']),
+    ('\u{1E1A}', &['\u{1E1B}']),
+    ('\u{1E1C}', &['\u{1E1D}']),
+    ('\u{1E1E}', &['\u{1E1F}']),
+    ('\u{1E20}', &['\u{1E21}']),
+    ('\u{1E22}', &['\u{1E23}']),
+    ('\u{1E24}', &['\u{1E25}']),
+    ('\u{1E26}', &['\u{1E27}']),
+    ('\u{1E28}', &['\u{1E29}']),
+    ('\u{1E2A}', &['\u{1E2B}']),
+    ('\u{1E2C}', &['\u{1E2D}']),
+    ('\u{1E2E}', &['\u{1E2F}']),
+    ('\u{1E30}', &['\u{1E31}']),
+    ('\u{1E32}', &['\u{1E33}']),
+    ('\u{1E34}', &['\u{1E35}']),
+    ('\u{1E36}', &['\u{1E37}']),
+    ('\u{1E38}', &['\u{1E39}']),
+    ('\u{1E3A}', &['\u{1E3B}']),
+    ('\u{1E3C}', &['\u{1E3D}']),
+    ('\u{1E3E}', &['\u{1E3F}']),
+    ('\u{1E40}', &['\u{1E41}']),
+    ('\u{1E42}', &['\u{1E43}']),
+    ('\u{1E44}', &['\u{1E45}']),
+    ('\u{1E46}', &['\u{1E47}']),
+    ('\u{1E48}', &['\u{1E49}']),
+    ('\u{1E4A}', &['\u{1E4B}']),
+    ('\u{1E4C}', &['\u{1E4D}']),
+    ('\u{1E4E}', &['\u{1E4F}']),
+    ('\u{1E50}', &['\u{1E51}']),
+    ('\u{1E52}', &['\u{1E53}']),
+    ('\u{1E54}', &['\u{1E55}']),
+    ('\u{1E56}', &['\u{1E57}']),
+    ('\u{1E58}', &['\u{1E59}']),
+    ('\u{1E5A}', &['\u{1E5B}']),
+    ('\u{1E5C}', &['\u{1E5D}']),
+    ('\u{1E5E}', &['\u{1E5F}']),
+    ('\u{1E60}', &['\u{1E61}']),
+    ('\u{1E62}', &['\u{1E63}']),
+    ('\u{1E64}', &['\u{1E65}']),
+    ('\u{1E66}', &['\u{1E67}']),
+    ('\u{1E68}', &['\u{1E69}']),
+    ('\u{1E6A}', &['\u{1E6B}']),
+    ('\u{1E6C}', &['\u{1E6D}']),
+    ('\u{1E6E}', &['\u{1E6F}']),
+    ('\u{1E70}', &['\u{1E71}']),
+    ('\u{1E72}', &['\u{1E73}']),
+    ('\u{1E74}', &['\u{1E75}']),
+    ('\u{1E76}', &['\u{1E77}']),
+    ('\u{1E78}', &['\u{1E79}']),
+    ('\u{1E7A}', &['\u{1E7B}']),
+    ('\u{1E7C}', &['\u{1E7D}']),
+    ('\u{1E7E}', &['\u{1E7F}']),
+    ('\u{1E80}', &['\u{1E81}']),
+    ('\u{1E82}', &['\u{1E83}']),
+    ('\u{1E84}', &['\u{1E85}']),
+    ('\u{1E86}', &['\u{1E87}']),
+    ('\u{1E88}', &['\u{1E89}']),
+    ('\u{1E8A}', &['\u{1E8B}']),
+    ('\u{1E8C}', &['\u{1E8D}']),
+    ('\u{1E8E}', &['\u{1E8F}']),
+    ('\u{1E90}', &['\u{1E91}']),
+    ('\u{1E92}', &['\u{1E93}']),
+    ('\u{1E94}', &['\u{1E95}']),
+    ('\u{1E9E}', &['\u{00DF}']),
+    ('\u{1EA0}', &['\u{1EA1}']),
+    ('\u{1EA2}', &['\u{1EA3}']),
+    ('\u{1EA4}', &['\u{1EA5}']),
+    ('\u{1EA6}', &['\u{1EA7}']),
+    ('\u{1EA8}', &['\u{1EA9}']),
+    ('\u{1EAA}', &['\u{1EAB}']),
+    ('\u{1EAC}', &['\u{1EAD}']),
+    ('\u{1EAE}', &['\u{1EAF}']),
+    ('\u{1EB0}', &['\u{1EB1}']),
+    ('\u{1EB2}', &['\u{1EB3}']),
+    ('\u{1EB4}', &['\u{1EB5}']),
+    ('\u{1EB6}', &['\u{1EB7}']),
+    ('\u{1EB8}', &['\u{1EB9}']),
+    ('\u{1EBA}', &['\u{1EBB}']),
+    ('\u{1EBC}', &['\u{1EBD}']),
+    ('\u{1EBE}', &['\u{1EBF}']),
+    ('\u{1EC0}', &['\u{1EC1}']),
+    ('\u{1EC2}', &['\u{1EC3}']),
+    ('\u{1EC4}', &['\u{1EC5}']),
+    ('\u{1EC6}', &['\u{1EC7}']),
+    ('\u{1EC8}', &['\u{1EC9}']),
+    ('\u{1ECA}', &['\u{1ECB}']),
+    ('\u{1ECC}', &['\u{1ECD}']),
+    ('\u{1ECE}', &['\u{1ECF}']),
+    ('\u{1ED0}', &['\u{1ED1}']),
+    ('\u{1ED2}', &['\u{1ED3}']),
+    ('\u{1ED4}', &['\u{1ED5}']),
+    ('\u{1ED6}', &['\u{1ED7}']),
+    ('\u{1ED8}', &['\u{1ED9}']),
+    ('\u{1EDA}', &['\u{1EDB}']),
+    ('\u{1EDC}', &['\u{1EDD}']),
+    ('\u{1EDE}', &['\u{1EDF}']),
+    ('\u{1EE0}', &['\u{1EE1}']),
+    ('\u{1EE2}', &['\u{1EE3}']),
+    ('\u{1EE4}', &['\u{1EE5}']),
+    ('\u{1EE6}', &['\u{1EE7}']),
+    ('\u{1EE8}', &['\u{1EE9}']),
+    ('\u{1EEA}', &['\u{1EEB}']),
+    ('\u{1EEC}', &['\u{1EED}']),
+    ('\u{1EEE}', &['\u{1EEF}']),
+    ('\u{1EF0}', &['\u{1EF1}']),
+    ('\u{1EF2}', &['\u{1EF3}']),
+    ('\u{1EF4}', &['\u{1EF5}']),
+    ('\u{1EF6}', &['\u{1EF7}']),
+    ('\u{1EF8}', &['\u{1EF9}']),
+    ('\u{1EFA}', &['\u{1EFB}']),
+    ('\u{1EFC}', &['\u{1EFD}']),
+    ('\u{1EFE}', &['\u{1EFF}']),
+    ('\u{1F08}', &['\u{1F00}']),
+    ('\u{1F09}', &['\u{1F01}']),
+    ('\u{1F0A}', &['\u{1F02}']),
+    ('\u{1F0B}', &['\u{1F03}']),
+    ('\u{1F0C}', &['\u{1F04}']),
+    ('\u{1F0D}', &['\u{1F05}']),
+    ('\u{1F0E}', &['\u{1F06}']),
+    ('\u{1F0F}', &['\u{1F07}']),
+    ('\u{1F18}', &['\u{1F10}']),
+    ('\u{1F19}', &['\u{1F11}']),
+    ('\u{1F1A}', &['\u{1F12}']),
+    ('\u{1F1B}', &['\u{1F13}']),
+    ('\u{1F1C}', &['\u{1F14}']),
+    ('\u{1F1D}', &['\u{1F15}']),
+    ('\u{1F28}', &['\u{1F20}']),
+    ('\u{1F29}', &['\u{1F21}']),
+    ('\u{1F2A}', &['\u{1F22}']),
+    ('\u{1F2B}', &['\u{1F23}']),
+    ('\u{1F2C}', &['\u{1F24}']),
+    ('\u{1F2D}', &['\u{1F25}']),
+    ('\u{1F2E}', &['\u{1F26}']),
+    ('\u{1F2F}', &['\u{1F27}']),
+    ('\u{1F38}', &['\u{1F30}']),
+    ('\u{1F39}', &['\u{1F31}']),
+    ('\u{1F3A}', &['\u{1F32}']),
+    ('\u{1F3B}', &['\u{1F33}']),
+    ('\u{1F3C}', &['\u{1F34}']),
+    ('\u{1F3D}', &['\u{1F35}']),
+    ('\u{1F3E}', &['\u{1F36}']),
+    ('\u{1F3F}', &['\u{1F37}']),
+    ('\u{1F48}', &['\u{1F40}']),
+    ('\u{1F49}', &['\u{1F41}']),
+    ('\u{1F4A}', &['\u{1F42}']),
+    ('\u{1F4B}', &['\u{1F43}']),
+    ('\u{1F4C}', &['\u{1F44}']),
+    ('\u{1F4D}', &['\u{1F45}']),
+    ('\u{1F59}', &['\u{1F51}']),
+    ('\u{1F5B}', &['\u{1F53}']),
+    ('\u{1F5D}', &['\u{1F55}']),
+    ('\u{1F5F}', &['\u{1F57}']),
+    ('\u{1F68}', &['\u{1F60}']),
+    ('\u{1F69}', &['\u{1F61}']),
+    ('\u{1F6A}', &['\u{1F62}']),
+    ('\u{1F6B}', &['\u{1F63}']),
+    ('\u{1F6C}', &['\u{1F64}']),
+    ('\u{1F6D}', &['\u{1F65}']),
+    ('\u{1F6E}', &['\u{1F66}']),
+    ('\u{1F6F}', &['\u{1F67}']),
+    ('\u{1F88}', &['\u{1F80}']),
+    ('\u{1F89}', &['\u{1F81}']),
+    ('\u{1F8A}', &['\u{1F82}']),
+    ('\u{1F8B}', &['\u{1F83}']),
+    ('\u{1F8C}', &['\u{1F84}']),
+    ('\u{1F8D}', &['\u{1F85}']),
+    ('\u{1F8E}', &['\u{1F86}']),
+    ('\u{1F8F}', &['\u{1F87}']),
+    ('\u{1F98}', &['\u{1F90}']),
+    ('\u{1F99}', &['\u{1F91}']),
+    ('\u{1F9A}', &['\u{1F92}']),
+    ('\u{1F9B}', &['\u{1F93}']),
+    ('\u{1F9C}', &['\u{1F94}']),
+    ('\u{1F9D}', &['\u{1F95}']),
+    ('\u{1F9E}', &['\u{1F96}']),
+    ('\u{1F9F}', &['\u{1F97}']),
+    ('\u{1FA8}', &['\u{1FA0}']),
+    ('\u{1FA9}', &['\u{1FA1}']),
+    ('\u{1FAA}', &['\u{1FA2}']),
+    ('\u{1FAB}', &['\u{1FA3}']),
+    ('\u{1FAC}', &['\u{1FA4}']),
+    ('\u{1FAD}', &['\u{1FA5}']),
+    ('\u{1FAE}', &['\u{1FA6}']),
+    ('\u{1FAF}', &['\u{1FA7}']),
+    ('\u{1FB8}', &['\u{1FB0}']),
+    ('\u{1FB9}', &['\u{1FB1}']),
+    ('\u{1FBA}', &['\u{1F70}']),
+    ('\u{1FBB}', &['\u{1F71}']),
+    ('\u{1FBC}', &['\u{1FB3}']),
+    ('\u{1FC8}', &['\u{1F72}']),
+    ('\u{1FC9}', &['\u{1F73}']),
+    ('\u{1FCA}', &['\u{1F74}']),
+    ('\u{1FCB}', &['\u{1F75}']),
+    ('\u{1FCC}', &['\u{1FC3}']),
+    ('\u{1FD8}', &['\u{1FD0}']),
+    ('\u{1FD9}', &['\u{1FD1}']),
+    ('\u{1FDA}', &['\u{1F76}']),
+    ('\u{1FDB}', &['\u{1F77}']),
+    ('\u{1FE8}', &['\u{1FE0}']),
+    ('\u{1FE9}', &['\u{1FE1}']),
+    ('\u{1FEA}', &['\u{1F7A}']),
+    ('\u{1FEB}', &['\u{1F7B}']),
+    ('\u{1FEC}', &['\u{1FE5}']),
+    ('\u{1FF8}', &['\u{1F78}']),
+    ('\u{1FF9}', &['\u{1F79}']),
+    ('\u{1FFA}', &['\u{1F7C}']),
+    ('\u{1FFB}', &['\u{1F7D}']),
+    ('\u{1FFC}', &['\u{1FF3}']),
+    ('\u{2126}', &['\u{03C9}']),
+    ('\u{212A}', &['\u{006B}']),
+    ('\u{212B}', &['\u{00E5}']),
+    ('\u{2132}', &['\u{214E}']),
+    ('\u{2160}', &['\u{2170}']),
+    ('\u{2161}', &['\u{2171}']),
+    ('\u{2162}', &['\u{2172}']),
+    ('\u{2163}', &['\u{2173}']),
+    ('\u{2164}', &['\u{2174}']),
+    ('\u{2165}', &['\u{2175}']),
+    ('\u{2166}', &['\u{2176}']),
+    ('\u{2167}', &['\u{2177}']),
+    ('\u{2168}', &['\u{2178}']),
+    ('\u{2169}', &['\u{2179}']),
+    ('\u{216A}', &['\u{217A}']),
+    ('\u{216B}', &['\u{217B}']),
+    ('\u{216C}', &['\u{217C}']),
+    ('\u{216D}', &['\u{217D}']),
+    ('\u{216E}', &['\u{217E}']),
+    ('\u{216F}', &['\u{217F}']),
+    ('\u{2183}', &['\u{2184}']),
+    ('\u{24B6}', &['\u{24D0}']),
+    ('\u{24B7}', &['\u{24D1}']),
+    ('\u{24B8}', &['\u{24D2}']),
+    ('\u{24B9}', &['\u{24D3}']),
+    ('\u{24BA}', &['\u{24D4}']),
+    ('\u{24BB}', &['\u{24D5}']),
+    ('\u{24BC}', &['\u{24D6}']),
+    ('\u{24BD}', &['\u{24D7}']),
+    ('\u{24BE}', &['\u{24D8}']),
+    ('\u{24BF}', &['\u{24D9}']),
+    ('\u{24C0}', &['\u{24DA}']),
+    ('\u{24C1}', &['\u{24DB}']),
+    ('\u{24C2}', &['\u{24DC}']),
+    ('\u{24C3}', &['\u{24DD}']),
+    ('\u{24C4}', &['\u{24DE}']),
+    ('\u{24C5}', &['\u{24DF}']),
+    ('\u{24C6}', &['\u{24E0}']),
+    ('\u{24C7}', &['\u{24E1}']),
+    ('\u{24C8}', &['\u{24E2}']),
+    ('\u{24C9}', &['\u{24E3}']),
+    ('\u{24CA}', &['\u{24E4}']),
+    ('\u{24CB}', &['\u{24E5}']),
+    ('\u{24CC}', &['\u{24E6}']),
+    ('\u{24CD}', &['\u{24E7}']),
+    ('\u{24CE}', &['\u{24E8}']),
+    ('\u{24CF}', &['\u{24E9}']),
+    ('\u{2C00}', &['\u{2C30}']),
+    ('\u{2C01}', &['\u{2C31}']),
+    ('\u{2C02}', &['\u{2C32}']),
+    ('\u{2C03}', &['\u{2C33}']),
+    ('\u{2C04}', &['\u{2C34}']),
+    ('\u{2C05}', &['\u{2C35}']),
+    ('\u{2C06}', &['\u{2C36}']),
+    ('\u{2C07}', &['\u{2C37}']),
+    ('\u{2C08}', &['\u{2C38}']),
+    ('\u{2C09}', &['\u{2C39}']),
+    ('\u{2C0A}', &['\u{2C3A}']),
+    ('\u{2C0B}', &['\u{2C3B}']),
+    ('\u{2C0C}', &['\u{2C3C}']),
+    ('\u{2C0D}', &['\u{2C3D}']),
+    ('\u{2C0E}', &['\u{2C3E}']),
+    ('\u{2C0F}', &['\u{2C3F}']),
+    ('\u{2C10}', &['\u{2C40}']),
+    ('\u{2C11}', &['\u{2C41}']),
+    ('\u{2C12}', &['\u{2C42}']),
+    ('\u{2C13}', &['\u{2C43}']),
+    ('\u{2C14}', &['\u{2C44}']),
+    ('\u{2C15}', &['\u{2C45}']),
+    ('\u{2C16}', &['\u{2C46}']),
+    ('\u{2C17}', &['\u{2C47}']),
+    ('\u{2C18}', &['\u{2C48}']),
+    ('\u{2C19}', &['\u{2C49}']),
+    ('\u{2C1A}', &['\u{2C4A}']),
+    ('\u{2C1B}', &['\u{2C4B}']),
+    ('\u{2C1C}', &['\u{2C4C}']),
+    ('\u{2C1D}', &['\u{2C4D}']),
+    ('\u{2C1E}', &['\u{2C4E}']),
+    ('\u{2C1F}', &['\u{2C4F}']),
+    ('\u{2C20}', &['\u{2C50}']),
+    ('\u{2C21}', &['\u{2C51}']),
+    ('\u{2C22}', &['\u{2C52}']),
+    ('\u{2C23}', &['\u{2C53}']),
+    ('\u{2C24}', &['\u{2C54}']),
+    ('\u{2C25}', &['\u{2C55}']),
+    ('\u{2C26}', &['\u{2C56}']),
+    ('\u{2C27}', &['\u{2C57}']),
+    ('\u{2C28}', &['\u{2C58}']),
+    ('\u{2C29}', &['\u{2C59}']),
+    ('\u{2C2A}', &['\u{2C5A}']),
+    ('\u{2C2B}', &['\u{2C5B}']),
+    ('\u{2C2C}', &['\u{2C5C}']),
+    ('\u{2C2D}', &['\u{2C5D}']),
+    ('\u{2C2E}', &['\u{2C5E}']),
+    ('\u{2C60}', &['\u{2C61}']),
+    ('\u{2C62}', &['\u{026B}']),
+    ('\u{2C63}', &['\u{1D7D}']),
+    ('\u{2C64}', &['\u{027D}']),
+    ('\u{2C67}', &['\u{2C68}']),
+    ('\u{2C69}', &['\u{2C6A}']),
+    ('\u{2C6B}', &['\u{2C6C}']),
+    ('\u{2C6D}', &['\u{0251}']),
+    ('\u{2C6E}', &['\u{0271}']),
+    ('\u{2C6F}', &['\u{0250}']),
+    ('\u{2C70}', &['\u{0252}']),
+    ('\u{2C72}', &['\u{2C73}']),
+    ('\u{2C75}', &['\u{2C76}']),
+    ('\u{2C7E}', &['\u{023F}']),
+    ('\u{2C7F}', &['\u{0240}']),
+    ('\u{2C80}', &['\u{2C81}']),
+    ('\u{2C82}', &['\u{2C83}']),
+    ('\u{2C84}', &['\u{2C85}']),
+    ('\u{2C86}', &['\u{2C87}']),
+    ('\u{2C88}', &['\u{2C89}']),
+    ('\u{2C8A}', &['\u{2C8B}']),
+    ('\u{2C8C}', &['\u{2C8D}']),
+    ('\u{2C8E}', &['\u{2C8F}']),
+    ('\u{2C90}', &['\u{2C91}']),
+    ('\u{2C92}', &['\u{2C93}']),
+    ('\u{2C94}', &['\u{2C95}']),
+    ('\u{2C96}', &['\u{2C97}']),
+    ('\u{2C98}', &['\u{2C99}']),
+    ('\u{2C9A}', &['\u{2C9B}']),
+    ('\u{2C9C}', &['\u{2C9D}']),
+    ('\u{2C9E}', &['\u{2C9F}']),
+    ('\u{2CA0}', &['\u{2CA1}']),
+    ('\u{2CA2}', &['\u{2CA3}']),
+    ('\u{2CA4}', &['\u{2CA5}']),
+    ('\u{2CA6}', &['\u{2CA7}']),
+    ('\u{2CA8}', &['\u{2CA9}']),
+    ('\u{2CAA}', &['\u{2CAB}']),
+    ('\u{2CAC}', &['\u{2CAD}']),
+    ('\u{2CAE}', &['\u{2CAF}']),
+    ('\u{2CB0}', &['\u{2CB1}']),
+    ('\u{2CB2}', &['\u{2CB3}']),
+    ('\u{2CB4}', &['\u{2CB5}']),
+    ('\u{2CB6}', &['\u{2CB7}']),
+    ('\u{2CB8}', &['\u{2CB9}']),
+    ('\u{2CBA}', &['\u{2CBB}']),
+    ('\u{2CBC}', &['\u{2CBD}']),
+    ('\u{2CBE}', &['\u{2CBF}']),
+    ('\u{2CC0}', &['\u{2CC1}']),
+    ('\u{2CC2}', &['\u{2CC3}']),
+    ('\u{2CC4}', &['\u{2CC5}']),
+    ('\u{2CC6}', &['\u{2CC7}']),
+    ('\u{2CC8}', &['\u{2CC9}']),
+    ('\u{2CCA}', &['\u{2CCB}']),
+    ('\u{2CCC}', &['\u{2CCD}']),
+    ('\u{2CCE}', &['\u{2CCF}']),
+    ('\u{2CD0}', &['\u{2CD1}']),
+    ('\u{2CD2}', &['\u{2CD3}']),
+    ('\u{2CD4}', &['\u{2CD5}']),
+    ('\u{2CD6}', &['\u{2CD7}']),
+    ('\u{2CD8}', &['\u{2CD9}']),
+    ('\u{2CDA}', &['\u{2CDB}']),
+    ('\u{2CDC}', &['\u{2CDD}']),
+    ('\u{2CDE}', &['\u{2CDF}']),
+    ('\u{2CE0}', &['\u{2CE1}']),
+    ('\u{2CE2}', &['\u{2CE3}']),
+    ('\u{2CEB}', &['\u{2CEC}']),
+    ('\u{2CED}', &['\u{2CEE}']),
+    ('\u{2CF2}', &['\u{2CF3}']),
+    ('\u{A640}', &['\u{A641}']),
+    ('\u{A642}', &['\u{A643}']),
+    ('\u{A644}', &['\u{A645}']),
+    ('\u{A646}', &['\u{A647}']),
+    ('\u{A648}', &['\u{A649}']),
+    ('\u{A64A}', &['\u{A64B}']),
+    ('\u{A64C}', &['\u{A64D}']),
+    ('\u{A64E}', &['\u{A64F}']),
+    ('\u{A650}', &['\u{A651}']),
+    ('\u{A652}', &['\u{A653}']),
+    ('\u{A654}', &['\u{A655}']),
+    ('\u{A656}', &['\u{A657}']),
+    ('\u{A658}', &['\u{A659}']),
+    ('\u{A65A}', &['\u{A65B}']),
+    ('\u{A65C}', &['\u{A65D}']),
+    ('\u{A65E}', &['\u{A65F}']),
+    ('\u{A660}', &['\u{A661}']),
+    ('\u{A662}', &['\u{A663}']),
+    ('\u{A664}', &['\u{A665}']),
+    ('\u{A666}', &['\u{A667}']),
+    ('\u{A668}', &['\u{A669}']),
+    ('\u{A66A}', &['\u{A66B}']),
+    ('\u{A66C}', &['\u{A66D}']),
+    ('\u{A680}', &['\u{A681}']),
+    ('\u{A682}', &['\u{A683}']),
+    ('\u{A684}', &['\u{A685}']),
+    ('\u{A686}', &['\u{A687}']),
+    ('\u{A688}', &['\u{A689}']),
+    ('\u{A68A}', &['\u{A68B}']),
+    ('\u{A68C}', &['\u{A68D}']),
+    ('\u{A68E}', &['\u{A68F}']),
+    ('\u{A690}', &['\u{A691}']),
+    ('\u{A692}', &['\u{A693}']),
+    ('\u{A694}', &['\u{A695}']),
+    ('\u{A696}', &['\u{A697}']),
+    ('\u{A698}', &['\u{A699}']),
+    ('\u{A69A}', &['\u{A69B}']),
+    ('\u{A722}', &['\u{A723}']),
+    ('\u{A724}', &['\u{A725}']),
+    ('\u{A726}', &['\u{A727}']),
+    ('\u{A728}', &['\u{A729}']),
+    ('\u{A72A}', &['\u{A72B}']),
+    ('\u{A72C}', &['\u{A72D}']),
+    ('\u{A72E}', &['\u{A72F}']),
+    ('\u{A732}', &['\u{A733}']),
+    ('\u{A734}', &['\u{A735}']),
+    ('\u{A736}', &['\u{A737}']),
+    ('\u{A738}', &['\u{A739}']),
+    ('\u{A73A}', &['\u{A73B}']),
+    ('\u{A73C}', &['\u{A73D}']),
+    ('\u{A73E}', &['\u{A73F}']),
+    ('\u{A740}', &['\u{A741}']),
+    ('\u{A742}', &['\u{A743}']),
+    ('\u{A744}', &['\u{A745}']),
+    ('\u{A746}', &['\u{A747}']),
+    ('\u{A748}', &['\u{A749}']),
+    ('\u{A74A}', &['\u{A74B}']),
+    ('\u{A74C}', &['\u{A74D}']),
+    ('\u{A74E}', &['\u{A74F}']),
+    ('\u{A750}', &['\u{A751}']),
+    ('\u{A752}', &['\u{A753}']),
+    ('\u{A754}', &['\u{A755}']),
+    ('\u{A756}', &['\u{A757}']),
+    ('\u{A758}', &['\u{A759}']),
+    ('\u{A75A}', &['\u{A75B}']),
+    ('\u{A75C}', &['\u{A75D}']),
+    ('\u{A75E}', &['\u{A75F}']),
+    ('\u{A760}', &['\u{A761}']),
+    ('\u{A762}', &['\u{A763}']),
+    ('\u{A764}', &['\u{A765}']),
+    ('\u{A766}', &['\u{A767}']),
+    ('\u{A768}', &['\u{A769}']),
+    ('\u{A76A}', &['\u{A76B}']),
+    ('\u{A76C}', &['\u{A76D}']),
+    ('\u{A76E}', &['\u{A76F}']),
+    ('\u{A779}', &['\u{A77A}']),
+    ('\u{A77B}', &['\u{A77C}']),
+    ('\u{A77D}', &['\u{1D79}']),
+    ('\u{A77E}', &['\u{A77F}']),
+    ('\u{A780}', &['\u{A781}']),
+    ('\u{A782}', &['\u{A783}']),
+    ('\u{A784}', &['\u{A785}']),
+    ('\u{A786}', &['\u{A787}']),
+    ('\u{A78B}', &['\u{A78C}']),
+    ('\u{A78D}', &['\u{0265}']),
+    ('\u{A790}', &['\u{A791}']),
+    ('\u{A792}', &['\u{A793}']),
+    ('\u{A796}', &['\u{A797}']),
+    ('\u{A798}', &['\u{A799}']),
+    ('\u{A79A}', &['\u{A79B}']),
+    ('\u{A79C}', &['\u{A79D}']),
+    ('\u{A79E}', &['\u{A79F}']),
+    ('\u{A7A0}', &['\u{A7A1}']),
+    ('\u{A7A2}', &['\u{A7A3}']),
+    ('\u{A7A4}', &['\u{A7A5}']),
+    ('\u{A7A6}', &['\u{A7A7}']),
+    ('\u{A7A8}', &['\u{A7A9}']),
+    ('\u{A7AA}', &['\u{0266}']),
+    ('\u{A7AB}', &['\u{025C}']),
+    ('\u{A7AC}', &['\u{0261}']),
+    ('\u{A7AD}', &['\u{026C}']),
+    ('\u{A7AE}', &['\u{026A}']),
+    ('\u{A7B0}', &['\u{029E}']),
+    ('\u{A7B1}', &['\u{0287}']),
+    ('\u{A7B2}', &['\u{029D}']),
+    ('\u{A7B3}', &['\u{AB53}']),
+    ('\u{A7B4}', &['\u{A7B5}']),
+    ('\u{A7B6}', &['\u{A7B7}']),
+    ('\u{A7B8}', &['\u{A7B9}']),
+    ('\u{A7BA}', &['\u{A7BB}']),
+    ('\u{A7BC}', &['\u{A7BD}']),
+    ('\u{A7BE}', &['\u{A7BF}']),
+    ('\u{A7C2}', &['\u{A7C3}']),
+    ('\u{A7C4}', &['\u{A794}']),
+    ('\u{A7C5}', &['\u{0282}']),
+    ('\u{A7C6}', &['\u{1D8E}']),
+    ('\u{A7C7}', &['\u{A7C8}']),
+    ('\u{A7C9}', &['\u{A7CA}']),
+    ('\u{A7F5}', &['\u{A7F6}']),
+    ('\u{FF21}', &['\u{FF41}']),
+    ('\u{FF22}', &['\u{FF42}']),
+    ('\u{FF23}', &['\u{FF43}']),
+    ('\u{FF24}', &['\u{FF44}']),
+    ('\u{FF25}', &['\u{FF45}']),
+    ('\u{FF26}', &['\u{FF46}']),
+    ('\u{FF27}', &['\u{FF47}']),
+    ('\u{FF28}', &['\u{FF48}']),
+    ('\u{FF29}', &['\u{FF49}']),
+    ('\u{FF2A}', &['\u{FF4A}']),
+    ('\u{FF2B}', &['\u{FF4B}']),
+    ('\u{FF2C}', &['\u{FF4C}']),
+    ('\u{FF2D}', &['\u{FF4D}']),
+    ('\u{FF2E}', &['\u{FF4E}']),
+    ('\u{FF2F}', &['\u{FF4F}']),
+    ('\u{FF30}', &['\u{FF50}']),
+    ('\u{FF31}', &['\u{FF51}']),
+    ('\u{FF32}', &['\u{FF52}']),
+    ('\u{FF33}', &['\u{FF53}']),
+    ('\u{FF34}', &['\u{FF54}']),
+    ('\u{FF35}', &['\u{FF55}']),
+    ('\u{FF36}', &['\u{FF56}']),
+    ('\u{FF37}', &['\u{FF57}']),
+    ('\u{FF38}', &['\u{FF58}']),
+    ('\u{FF39}', &['\u{FF59}']),
+    ('\u{FF3A}', &['\u{FF5A}']),
+    ('\u{10400}', &['\u{10428}']),
+    ('\u{10401}', &['\u{10429}']),
+    ('\u{10402}', &['\u{1042A}']),
+    ('\u{10403}', &['\u{1042B}']),
+    ('\u{10404}', &['\u{1042C}']),
+    ('\u{10405}', &['\u{1042D}']),
+    ('\u{10406}', &['\u{1042E}']),
+    ('\u{10407}', &['\u{1042F}']),
+    ('\u{10408}', &['\u{10430}']),
+    ('\u{10409}', &['\u{10431}']),
+    ('\u{1040A}', &['\u{10432}']),
+    ('\u{1040B}', &['\u{10433}']),
+    ('\u{1040C}', &['\u{10434}']),
+    ('\u{1040D}', &['\u{10435}']),
+    ('\u{1040E}', &['\u{10436}']),
+    ('\u{1040F}', &['\u{10437}']),
+    ('\u{10410}', &['\u{10438}']),
+    ('\u{10411}', &['\u{10439}']),
+    ('\u{10412}', &['\u{1043A}']),
+    ('\u{10413}', &['\u{1043B}']),
+    ('\u{10414}', &['\u{1043C}']),
+    ('\u{10415}', &['\u{1043D}']),
+    ('\u{10416}', &['\u{1043E}']),
+    ('\u{10417}', &['\u{1043F}']),
+    ('\u{10418}', &['\u{10440}']),
+    ('\u{10419}', &['\u{10441}']),
+    ('\u{1041A}', &['\u{10442}']),
+    ('\u{1041B}', &['\u{10443}']),
+    ('\u{1041C}', &['\u{10444}']),
+    ('\u{1041D}', &['\u{10445}']),
+    ('\u{1041E}', &['\u{10446}']),
+    ('\u{1041F}', &['\u{10447}']),
+    ('\u{10420}', &['\u{10448}']),
+    ('\u{10421}', &['\u{10449}']),
+    ('\u{10422}', &['\u{1044A}']),
+    ('\u{10423}', &['\u{1044B}']),
+    ('\u{10424}', &['\u{1044C}']),
+    ('\u{10425}', &['\u{1044D}']),
+    ('\u{10426}', &['\u{1044E}']),
+    ('\u{10427}', &['\u{1044F}']),
+    ('\u{104B0}', &['\u{104D8}']),
+    ('\u{104B1}', &['\u{104D9}']),
+    ('\u{104B2}', &['\u{104DA}']),
+    ('\u{104B3}', &['\u{104DB}']),
+    ('\u{104B4}', &['\u{104DC}']),
+    ('\u{104B5}', &['\u{104DD}']),
+    ('\u{104B6}', &['\u{104DE}']),
+    ('\u{104B7}', &['\u{104DF}']),
+    ('\u{104B8}', &['\u{104E0}']),
+    ('\u{104B9}', &['\u{104E1}']),
+    ('\u{104BA}', &['\u{104E2}']),
+    ('\u{104BB}', &['\u{104E3}']),
+    ('\u{104BC}', &['\u{104E4}']),
+    ('\u{104BD}', &['\u{104E5}']),
+    ('\u{104BE}', &['\u{104E6}']),
+    ('\u{104BF}', &['\u{104E7}']),
+    ('\u{104C0}', &['\u{104E8}']),
+    ('\u{104C1}', &['\u{104E9}']),
+    ('\u{104C2}', &['\u{104EA}']),
+    ('\u{104C3}', &['\u{104EB}']),
+    ('\u{104C4}', &['\u{104EC}']),
+    ('\u{104C5}', &['\u{104ED}']),
+    ('\u{104C6}', &['\u{104EE}']),
+    ('\u{104C7}', &['\u{104EF}']),
+    ('\u{104C8}', &['\u{104F0}']),
+    ('\u{104C9}', &['\u{104F1}']),
+    ('\u{104CA}', &['\u{104F2}']),
+    ('\u{104CB}', &['\u{104F3}']),
+    ('\u{104CC}', &['\u{104F4}']),
+    ('\u{104CD}', &['\u{104F5}']),
+    ('\u{104CE}', &['\u{104F6}']),
+    ('\u{104CF}', &['\u{104F7}']),
+    ('\u{104D0}', &['\u{104F8}']),
+    ('\u{104D1}', &['\u{104F9}']),
+    ('\u{104D2}', &['\u{104FA}']),
+    ('\u{104D3}', &['\u{104FB}']),
+    ('\u{10C80}', &['\u{10CC0}']),
+    ('\u{10C81}', &['\u{10CC1}']),
+    ('\u{10C82}', &['\u{10CC2}']),
+    ('\u{10C83}', &['\u{10CC3}']),
+    ('\u{10C84}', &['\u{10CC4}']),
+    ('\u{10C85}', &['\u{10CC5}']),
+    ('\u{10C86}', &['\u{10CC6}']),
+    ('\u{10C87}', &['\u{10CC7}']),
+    ('\u{10C88}', &['\u{10CC8}']),
+    ('\u{10C89}', &['\u{10CC9}']),
+    ('\u{10C8A}', &['\u{10CCA}']),
+    ('\u{10C8B}', &['\u{10CCB}']),
+    ('\u{10C8C}', &['\u{10CCC}']),
+    ('\u{10C8D}', &['\u{10CCD}']),
+    ('\u{10C8E}', &['\u{10CCE}']),
+    ('\u{10C8F}', &['\u{10CCF}']),
+    ('\u{10C90}', &['\u{10CD0}']),
+    ('\u{10C91}', &['\u{10CD1}']),
+    ('\u{10C92}', &['\u{10CD2}']),
+    ('\u{10C93}', &['\u{10CD3}']),
+    ('\u{10C94}', &['\u{10CD4}']),
+    ('\u{10C95}', &['\u{10CD5}']),
+    ('\u{10C96}', &['\u{10CD6}']),
+    ('\u{10C97}', &['\u{10CD7}']),
+    ('\u{10C98}', &['\u{10CD8}']),
+    ('\u{10C99}', &['\u{10CD9}']),
+    ('\u{10C9A}', &['\u{10CDA}']),
+    ('\u{10C9B}', &['\u{10CDB}']),
+    ('\u{10C9C}', &['\u{10CDC}']),
+    ('\u{10C9D}', &['\u{10CDD}']),
+    ('\u{10C9E}', &['\u{10CDE}']),
+    ('\u{10C9F}', &['\u{10CDF}']),
+    ('\u{10CA0}', &['\u{10CE0}']),
+    ('\u{10CA1}', &['\u{10CE1}']),
+    ('\u{10CA2}', &['\u{10CE2}']),
+    ('\u{10CA3}', &['\u{10CE3}']),
+    ('\u{10CA4}', &['\u{10CE4}']),
+    ('\u{10CA5}', &['\u{10CE5}']),
+    ('\u{10CA6}', &['\u{10CE6}']),
+    ('\u{10CA7}', &['\u{10CE7}']),
+    ('\u{10CA8}', &['\u{10CE8}']),
+    ('\u{10CA9}', &['\u{10CE9}']),
+    ('\u{10CAA}', &['\u{10CEA}']),
+    ('\u{10CAB}', &['\u{10CEB}']),
+    ('\u{10CAC}', &['\u{10CEC}']),
+    ('\u{10CAD}', &['\u{10CED}']),
+    ('\u{10CAE}', &['\u{10CEE}']),
+    ('\u{10CAF}', &['\u{10CEF}']),
+    ('\u{10CB0}', &['\u{10CF0}']),
+    ('\u{10CB1}', &['\u{10CF1}']),
+    ('\u{10CB2}', &['\u{10CF2}']),
+    ('\u{118A0}', &['\u{118C0}']),
+    ('\u{118A1}', &['\u{118C1}']),
+    ('\u{118A2}', &['\u{118C2}']),
+    ('\u{118A3}', &['\u{118C3}']),
+    ('\u{118A4}', &['\u{118C4}']),
+    ('\u{118A5}', &['\u{118C5}']),
+    ('\u{118A6}', &['\u{118C6}']),
+    ('\u{118A7}', &['\u{118C7}']),
+    ('\u{118A8}', &['\u{118C8}']),
+    ('\u{118A9}', &['\u{118C9}']),
+    ('\u{118AA}', &['\u{118CA}']),
+    ('\u{118AB}', &['\u{118CB}']),
+    ('\u{118AC}', &['\u{118CC}']),
+    ('\u{118AD}', &['\u{118CD}']),
+    ('\u{118AE}', &['\u{118CE}']),
+    ('\u{118AF}', &['\u{118CF}']),
+    ('\u{118B0}', &['\u{118D0}']),
+    ('\u{118B1}', &['\u{118D1}']),
+    ('\u{118B2}', &['\u{118D2}']),
+    ('\u{118B3}', &['\u{118D3}']),
+    ('\u{118B4}', &['\u{118D4}']),
+    ('\u{118B5}', &['\u{118D5}']),
+    ('\u{118B6}', &['\u{118D6}']),
+    ('\u{118B7}', &['\u{118D7}']),
+    ('\u{118B8}', &['\u{118D8}']),
+    ('\u{118B9}', &['\u{118D9}']),
+    ('\u{118BA}', &['\u{118DA}']),
+    ('\u{118BB}', &['\u{118DB}']),
+    ('\u{118BC}', &['\u{118DC}']),
+    ('\u{118BD}', &['\u{118DD}']),
+    ('\u{118BE}', &['\u{118DE}']),
+    ('\u{118BF}', &['\u{118DF}']),
+    ('\u{16E40}', &['\u{16E60}']),
+    ('\u{16E41}', &['\u{16E61}']),
+    ('\u{16E42}', &['\u{16E62}']),
+    ('\u{16E43}', &['\u{16E63}']),
+    ('\u{16E44}', &['\u{16E64}']),
+    ('\u{16E45}', &['\u{16E65}']),
+    ('\u{16E46}', &['\u{16E66}']),
+    ('\u{16E47}', &['\u{16E67}']),
+    ('\u{16E48}', &['\u{16E68}']),
+    ('\u{16E49}', &['\u{16E69}']),
+    ('\u{16E4A}', &['\u{16E6A}']),
+    ('\u{16E4B}', &['\u{16E6B}']),
+    ('\u{16E4C}', &['\u{16E6C}']),
+    ('\u{16E4D}', &['\u{16E6D}']),
+    ('\u{16E4E}', &['\u{16E6E}']),
+    ('\u{16E4F}', &['\u{16E6F}']),
+    ('\u{16E50}', &['\u{16E70}']),
+    ('\u{16E51}', &['\u{16E71}']),
+    ('\u{16E52}', &['\u{16E72}']),
+    ('\u{16E53}', &['\u{16E73}']),
+    ('\u{16E54}', &['\u{16E74}']),
+    ('\u{16E55}', &['\u{16E75}']),
+    ('\u{16E56}', &['\u{16E76}']),
+    ('\u{16E57}', &['\u{16E77}']),
+    ('\u{16E58}', &['\u{16E78}']),
+    ('\u{16E59}', &['\u{16E79}']),
+    ('\u{16E5A}', &['\u{16E7A}']),
+    ('\u{16E5B}', &['\u{16E7B}']),
+    ('\u{16E5C}', &['\u{16E7C}']),
+    ('\u{16E5D}', &['\u{16E7D}']),
+    ('\u{16E5E}', &['\u{16E7E}']),
+    ('\u{16E5F}', &['\u{16E7F}']),
+    ('\u{1E900}', &['\u{1E922}']),
+    ('\u{1E901}', &['\u{1E923}']),
+    ('\u{1E902}', &['\u{1E924}']),
+    ('\u{1E903}', &['\u{1E925}']),
+    ('\u{1E904}', &['\u{1E926}']),
+    ('\u{1E905}', &['\u{1E927}']),
+    ('\u{1E906}', &['\u{1E928}']),
+    ('\u{1E907}', &['\u{1E929}']),
+    ('\u{1E908}', &['\u{1E92A}']),
+    ('\u{1E909}', &['\u{1E92B}']),
+    ('\u{1E90A}', &['\u{1E92C}']),
+    ('\u{1E90B}', &['\u{1E92D}']),
+    ('\u{1E90C}', &['\u{1E92E}']),
+    ('\u{1E90D}', &['\u{1E92F}']),
+    ('\u{1E90E}', &['\u{1E930}']),
+    ('\u{1E90F}', &['\u{1E931}']),
+    ('\u{1E910}', &['\u{1E932}']),
+    ('\u{1E911}', &['\u{1E933}']),
+    ('\u{1E912}', &['\u{1E934}']),
+    ('\u{1E913}', &['\u{1E935}']),
+    ('\u{1E914}', &['\u{1E936}']),
+    ('\u{1E915}', &['\u{1E937}']),
+    ('\u{1E916}', &['\u{1E938}']),
+    ('\u{1E917}', &['\u{1E939}']),
+    ('\u{1E918}', &['\u{1E93A}']),
+    ('\u{1E919}', &['\u{1E93B}']),
+    ('\u{1E91A}', &['\u{1E93C}']),
+    ('\u{1E91B}', &['\u{1E93D}']),
+    ('\u{1E91C}', &['\u{1E93E}']),
+    ('\u{1E91D}', &['\u{1E93F}']),
+    ('\u{1E91E}', &['\u{1E940}']),
+    ('\u{1E91F}', &['\u{1E941}']),
+    ('\u{1E920}', &['\u{1E942}']),
+    ('\u{1E921}', &['\u{1E943}']),
+];
+
+pub(super) const TITLECASE_MAPPINGS: &[(char, &[char])] = &[
+    ('\u{0061}', &['\u{0041}']),
+    ('\u{0062}', &['\u{0042}']),
+    ('\u{0063}', &['\u{0043}']),
+    ('\u{0064}', &['\u{0044}']),
+    ('\u{0065}', &['\u{0045}']),
+    ('\u{0066}', &['\u{0046}']),
+    ('\u{0067}', &['\u{0047}']),
+    ('\u{0068}', &['\u{0048}']),
+    ('\u{0069}', &['\u{0049}']),
+    ('\u{006A}', &['\u{004A}']),
+    ('\u{006B}', &['\u{004B}']),
+    ('\u{006C}', &['\u{004C}']),
+    ('\u{006D}', &['\u{004D}']),
+    ('\u{006E}', &['\u{004E}']),
+    ('\u{006F}', &['\u{004F}']),
+    ('\u{0070}', &['\u{0050}']),
+    ('\u{0071}', &['\u{0051}']),
+    ('\u{0072}', &['\u{0052}']),
+    ('\u{0073}', &['\u{0053}']),
+    ('\u{0074}', &['\u{0054}']),
+    ('\u{0075}', &['\u{0055}']),
+    ('\u{0076}', &['\u{0056}']),
+    ('\u{0077}', &['\u{0057}']),
+    ('\u{0078}', &['\u{0058}']),
+    ('\u{0079}', &['\u{0059}']),
+    ('\u{007A}', &['\u{005A}']),
+    ('\u{00B5}', &['\u{039C}']),
+    ('\u{00DF}', &['\u{0053}', '\u{0073}']),
+    ('\u{00E0}', &['\u{00C0}']),
+    ('\u{00E1}', &['\u{00C1}']),
+    ('\u{00E2}', &['\u{00C2}']),
+    ('\u{00E3}', &['\u{00C3}']),
+    ('\u{00E4}', &['\u{00C4}']),
+    ('\u{00E5}', &['\u{00C5}']),
+    ('\u{00E6}', &['\u{00C6}']),
+    ('\u{00E7}', &['\u{00C7}']),
+    ('\u{00E8}', &['\u{00C8}']),
+    ('\u{00E9}', &['\u{00C9}']),
+    ('\u{00EA}', &['\u{00CA}']),
+    ('\u{00EB}', &['\u{00CB}']),
+    ('\u{00EC}', &['\u{00CC}']),
+    ('\u{00ED}', &['\u{00CD}']),
+    ('\u{00EE}', &['\u{00CE}']),
+    ('\u{00EF}', &['\u{00CF}']),
+    ('\u{00F0}', &['\u{00D0}']),
+    ('\u{00F1}', &['\u{00D1}']),
+    ('\u{00F2}', &['\u{00D2}']),
+    ('\u{00F3}', &['\u{00D3}']),
+    ('\u{00F4}', &['\u{00D4}']),
+    ('\u{00F5}', &['\u{00D5}']),
+    ('\u{00F6}', &['\u{00D6}']),
+    ('\u{00F8}', &['\u{00D8}']),
+    ('\u{00F9}', &['\u{00D9}']),
+    ('\u{00FA}', &['\u{00DA}']),
+    ('\u{00FB}', &['\u{00DB}']),
+    ('\u{00FC}', &['\u{00DC}']),
+    ('\u{00FD}', &['\u{00DD}']),
+    ('\u{00FE}', &['\u{00DE}']),
+    ('\u{00FF}', &['\u{0178}']),
+    ('\u{0101}', &['\u{0100}']),
+    ('\u{0103}', &['\u{0102}']),
+    ('\u{0105}', &['\u{0104}']),
+    ('\u{0107}', &['\u{0106}']),
+    ('\u{0109}', &['\u{0108}']),
+    ('\u{010B}', &['\u{010A}']),
+    ('\u{010D}', &['\u{010C}']),
+    ('\u{010F}', &['\u{010E}']),
+    ('\u{0111}', &['\u{0110}']),
+    ('\u{0113}', &['\u{0112}']),
+    ('\u{0115}', &['\u{0114}']),
+    ('\u{0117}', &['\u{0116}']),
+    ('\u{0119}', &['\u{0118}']),
+    ('\u{011B}', &['\u{011A}']),
+    ('\u{011D}', &['\u{011C}']),
+    ('\u{011F}', &['\u{011E}']),
+    ('\u{0121}', &['\u{0120}']),
+    ('\u{0123}', &['\u{0122}']),
+    ('\u{0125}', &['\u{0124}']),
+    ('\u{0127}', &['\u{0126}']),
+    ('\u{0129}', &['\u{0128}']),
+    ('\u{012B}', &['\u{012A}']),
+    ('\u{012D}', &['\u{012C}']),
+    ('\u{012F}', &['\u{012E}']),
+    ('\u{0131}', &['\u{0049}']),
+    ('\u{0133}', &['\u{0132}']),
+    ('\u{0135}', &['\u{0134}']),
+    ('\u{0137}', &['\u{0136}']),
+    ('\u{013A}', &['\u{0139}']),
+    ('\u{013C}', &['\u{013B}']),
+    ('\u{013E}', &['\u{013D}']),
+    ('\u{0140}', &['\u{013F}']),
+    ('\u{0142}', &['\u{0141}']),
+    ('\u{0144}', &['\u{0143}']),
+    ('\u{0146}', &['\u{0145}']),
+    ('\u{0148}', &['\u{0147}']),
+    ('\u{0149}', &['\u{02BC}', '\u{004E}']),
+    ('\u{014B}', &['\u{014A}']),
+    ('\u{014D}', &['\u{014C}']),
+    ('\u{014F}', &['\u{014E}']),
+    ('\u{0151}', &['\u{0150}']),
+    ('\u{0153}', &['\u{0152}']),
+    ('\u{0155}', &['\u{0154}']),
+    ('\u{0157}', &['\u{0156}']),
+    ('\u{0159}', &['\u{0158}']),
+    ('\u{015B}', &['\u{015A}']),
+    ('\u{015D}', &['\u{015C}']),
+    ('\u{015F}', &['\u{015E}']),
+    ('\u{0161}', &['\u{0160}']),
+    ('\u{0163}', &['\u{0162}']),
+    ('\u{0165}', &['\u{0164}']),
+    ('\u{0167}', &['\u{0166}']),
+    ('\u{0169}', &['\u{0168}']),
+    ('\u{016B}', &['\u{016A}']),
+    ('\u{016D}', &['\u{016C}']),
+    ('\u{016F}', &['\u{016E}']),
+    ('\u{0171}', &['\u{0170}']),
+    ('\u{0173}', &['\u{0172}']),
+    ('\u{0175}', &['\u{0174}']),
+    ('\u{0177}', &['\u{0176}']),
+    ('\u{017A}', &['\u{0179}']),
+    ('\u{017C}', &['\u{017B}']),
+    ('\u{017E}', &['\u{017D}']),
+    ('\u{017F}', &['\u{0053}']),
+    ('\u{0180}', &['\u{0243}']),
+    ('\u{0183}', &['\u{0182}']),
+    ('\u{0185}', &['\u{0184}']),
+    ('\u{0188}', &['\u{0187}']),
+    ('\u{018C}', &['\u{018B}']),
+    ('\u{0192}', &['\u{0191}']),
+    ('\u{0195}', &['\u{01F6}']),
+    ('\u{0199}', &['\u{0198}']),
+    ('\u{019A}', &['\u{023D}']),
+    ('\u{019E}', &['\u{0220}']),
+    ('\u{01A1}', &['\u{01A0}']),
+    ('\u{01A3}', &['\u{01A2}']),
+    ('\u{01A5}', &['\u{01A4}']),
+    ('\u{01A8}', &['\u{01A7}']),
+    ('\u{01AD}', &['\u{01AC}']),
+    ('\u{01B0}', &['\u{01AF}']),
+    ('\u{01B4}', &['\u{01B3}']),
+    ('\u{01B6}', &['\u{01B5}']),
+    ('\u{01B9}', &['\u{01B8}']),
+    ('\u{01BD}', &['\u{01BC}']),
+    ('\u{01BF}', &['\u{01F7}']),
+    ('\u{01C4}', &['\u{01C5}']),
+    ('\u{01C6}', &['\u{01C5}']),
+    ('\u{01C7}', &['\u{01C8}']),
+    ('\u{01C9}', &['\u{01C8}']),
+    ('\u{01CA}', &['\u{01CB}']),
+    ('\u{01CC}', &['\u{01CB}']),
+    ('\u{01CE}', &['\u{01CD}']),
+    ('\u{01D0}', &['\u{01CF}']),
+    ('\u{01D2}', &['\u{01D1}']),
+    ('\u{01D4}', &['\u{01D3}']),
+    ('\u{01D6}', &['\u{01D5}']),
+    ('\u{01D8}', &['\u{01D7}']),
+    ('\u{01DA}', &['\u{01D9}']),
+    ('\u{01DC}', &['\u{01DB}']),
+    ('\u{01DD}', &['\u{018E}']),
+    ('\u{01DF}', &['\u{01DE}']),
+    ('\u{01E1}', &['\u{01E0}']),
+    ('\u{01E3}', &['\u{01E2}']),
+    ('\u{01E5}', &['\u{01E4}']),
+    ('\u{01E7}', &['\u{01E6}']),
+    ('\u{01E9}', &['\u{01E8}']),
+    ('\u{01EB}', &['\u{01EA}']),
+    ('\u{01ED}', &['\u{01EC}']),
+    ('\u{01EF}', &['\u{01EE}']),
+    ('\u{01F0}', &['\u{004A}', '\u{030C}']),
+    ('\u{01F1}', &['\u{01F2}']),
+    ('\u{01F3}', &['\u{01F2}']),
+    ('\u{01F5}', &['\u{01F4}']),
+    ('\u{01F9}', &['\u{01F8}']),
+    ('\u{01FB}', &['\u{01FA}']),
+    ('\u{01FD}', &['\u{01FC}']),
+    ('\u{01FF}', &['\u{01FE}']),
+    ('\u{0201}', &['\u{0200}']),
+    ('\u{0203}', &['\u{0202}']),
+    ('\u{0205}', &['\u{0204}']),
+    ('\u{0207}', &['\u{0206}']),
+    ('\u{0209}', &['\u{0208}']),
+    ('\u{020B}', &['\u{020A}']),
+    ('\u{020D}', &['\u{020C}']),
+    ('\u{020F}', &['\u{020E}']),
+    ('\u{0211}', &['\u{0210}']),
+    ('\u{0213}', &['\u{0212}']),
+    ('\u{0215}', &['\u{0214}']),
+    ('\u{0217}', &['\u{0216}']),
+    ('\u{0219}', &['\u{0218}']),
+    ('\u{021B}', &['\u{021A}']),
+    ('\u{021D}', &['\u{021C}']),
+    ('\u{021F}', &['\u{021E}']),
+    ('\u{0223}', &['\u{0222}']),
+    ('\u{0225}', &['\u{0224}']),
+    ('\u{0227}', &['\u{0226}']),
+    ('\u{0229}', &['\u{0228}']),
+    ('\u{022B}', &['\u{022A}']),
+    ('\u{022D}', &['\u{022C}']),
+    ('\u{022F}', &['\u{022E}']),
+    ('\u{0231}', &['\u{0230}']),
+    ('\u{0233}', &['\u{0232}']),
+    ('\u{023C}', &['\u{023B}']),
+    ('\u{023F}', &['\u{2C7E}']),
+    ('\u{0240}', &['\u{2C7F}']),
+    ('\u{0242}', &['\u{0241}']),
+    ('\u{0247}', &['\u{0246}']),
+    ('\u{0249}', &['\u{0248}']),
+    ('\u{024B}', &['\u{024A}']),
+    ('\u{024D}', &['\u{024C}']),
+    ('\u{024F}', &['\u{024E}']),
+    ('\u{0250}', &['\u{2C6F}']),
+    ('\u{0251}', &['\u{2C6D}']),
+    ('\u{0252}', &['\u{2C70}']),
+    ('\u{0253}', &['\u{0181}']),
+    ('\u{0254}', &['\u{0186}']),
+    ('\u{0256}', &['\u{0189}']),
+    ('\u{0257}', &['\u{018A}']),
+    ('\u{0259}', &['\u{018F}']),
+    ('\u{025B}', &['\u{0190}']),
+    ('\u{025C}', &['\u{A7AB}']),
+    ('\u{0260}', &['\u{0193}']),
+    ('\u{0261}', &['\u{A7AC}']),
+    ('\u{0263}', &['\u{0194}']),
+    ('\u{0265}', &['\u{A78D}']),
+    ('\u{0266}', &['\u{A7AA}']),
+    ('\u{0268}', &['\u{0197}']),
+    ('\u{0269}', &['\u{0196}']),
+    ('\u{026A}', &['\u{A7AE}']),
+    ('\u{026B}', &['\u{2C62}']),
+    ('\u{026C}', &['\u{A7AD}']),
+    ('\u{026F}', &['\u{019C}']),
+    ('\u{0271}', &['\u{2C6E}']),
+    ('\u{0272}', &['\u{019D}']),
+    ('\u{0275}', &['\u{019F}']),
+    ('\u{027D}', &['\u{2C64}']),
+    ('\u{0280}', &['\u{01A6}']),
+    ('\u{0282}', &['\u{A7C5}']),
+    ('\u{0283}', &['\u{01A9}']),
+    ('\u{0287}', &['\u{A7B1}']),
+    ('\u{0288}', &['\u{01AE}']),
+    ('\u{0289}', &['\u{0244}']),
+    ('\u{028A}', &['\u{01B1}']),
+    ('\u{028B}', &['\u{01B2}']),
+    ('\u{028C}', &['\u{0245}']),
+    ('\u{0292}', &['\u{01B7}']),
+    ('\u{029D}', &['\u{A7B2}']),
+    ('\u{029E}', &['\u{A7B0}']),
+    ('\u{0345}', &['\u{0399}']),
+    ('\u{0371}', &['\u{0370}']),
+    ('\u{0373}', &['\u{0372}']),
+    ('\u{0377}', &['\u{0376}']),
+    ('\u{037B}', &['\u{03FD}']),
+    ('\u{037C}', &['\u{03FE}']),
+    ('\u{037D}', &['\u{03FF}']),
+    ('\u{0390}', &['\u{0399}', '\u{0308}', '\u{0301}']),
+    ('\u{03AC}', &['\u{0386}']),
+    ('\u{03AD}', &['\u{0388}']),
+    ('\u{03AE}', &['\u{0389}']),
+    ('\u{03AF}', &['\u{038A}']),
+    ('\u{03B0}', &['\u{03A5}', '\u{0308}', '\u{0301}']),
+    ('\u{03B1}', &['\u{0391}']),
+    ('\u{03B2}', &['\u{0392}']),
+    ('\u{03B3}', &['\u{0393}']),
+    ('\u{03B4}', &['\u{0394}']),
+    ('\u{03B5}', &['\u{0395}']),
+    ('\u{03B6}', &['\u{0396}']),
+    ('\u{03B7}', &['\u{0397}']),
+    ('\u{03B8}', &['\u{0398}']),
+    ('\u{03B9}', &['\u{0399}']),
+    ('\u{03BA}', &['\u{039A}']),
+    ('\u{03BB}', &['\u{039B}']),
+    ('\u{03BC}', &['\u{039C}']),
+    ('\u{03BD}', &['\u{039D}']),
+    ('\u{03BE}', &['\u{039E}']),
+    ('\u{03BF}', &['\u{039F}']),
+    ('\u{03C0}', &['\u{03A0}']),
+    ('\u{03C1}', &['\u{03A1}']),
+    ('\u{03C2}', &['\u{03A3}']),
+    ('\u{03C3}', &['\u{03A3}']),
+    ('\u{03C4}', &['\u{03A4}']),
+    ('\u{03C5}', &['\u{03A5}']),
+    ('\u{03C6}', &['\u{03A6}']),
+    ('\u{03C7}', &['\u{03A7}']),
+    ('\u{03C8}', &['\u{03A8}']),
+    ('\u{03C9}', &['\u{03A9}']),
+    ('\u{03CA}', &['\u{03AA}']),
+    ('\u{03CB}', &['\u{03AB}']),
+    ('\u{03CC}', &['\u{038C}']),
+    ('\u{03CD}', &['\u{038E}']),
+    ('\u{03CE}', &['\u{038F}']),
+    ('\u{03D0}', &['\u{0392}']),
+    ('\u{03D1}', &['\u{0398}']),
+    ('\u{03D5}', &['\u{03A6}']),
+    ('\u{03D6}', &['\u{03A0}']),
+    ('\u{03D7}', &['\u{03CF}']),
+    ('\u{03D9}', &['\u{03D8}']),
+    ('\u{03DB}', &['\u{03DA}']),
+    ('\u{03DD}', &['\u{03DC}']),
+    ('\u{03DF}', &['\u{03DE}']),
+    ('\u{03E1}', &['\u{03E0}']),
+    ('\u{03E3}', &['\u{03E2}']),
+    ('\u{03E5}', &['\u{03E4}']),
+    ('\u{03E7}', &['\u{03E6}']),
+    ('\u{03E9}', &['\u{03E8}']),
+    ('\u{03EB}', &['\u{03EA}']),
+    ('\u{03ED}', &['\u{03EC}']),
+    ('\u{03EF}', &['\u{03EE}']),
+    ('\u{03F0}', &['\u{039A}']),
+    ('\u{03F1}', &['\u{03A1}']),
+    ('\u{03F2}', &['\u{03F9}']),
+    ('\u{03F3}', &['\u{037F}']),
+    ('\u{03F5}', &['\u{0395}']),
+    ('\u{03F8}', &['\u{03F7}']),
+    ('\u{03FB}', &['\u{03FA}']),
+    ('\u{0430}', &['\u{0410}']),
+    ('\u{0431}', &['\u{0411}']),
+    ('\u{0432}', &['\u{0412}']),
+    ('\u{0433}', &['\u{0413}']),
+    ('\u{0434}', &['\u{0414}']),
+    ('\u{0435}', &['\u{0415}']),
+    ('\u{0436}', &['\u{0416}']),
+    ('\u{0437}', &['\u{0417}']),
+    ('\u{0438}', &['\u{0418}']),
+    ('\u{0439}', &['\u{0419}']),
+    ('\u{043A}', &['\u{041A}']),
+    ('\u{043B}', &['\u{041B}']),
+    ('\u{043C}', &['\u{041C}']),
+    ('\u{043D}', &['\u{041D}']),
+    ('\u{043E}', &['\u{041E}']),
+    ('\u{043F}', &['\u{041F}']),
+    ('\u{0440}', &['\u{0420}']),
+    ('\u{0441}', &['\u{0421}']),
+    ('\u{0442}', &['\u{0422}']),
+    ('\u{0443}', &['\u{0423}']),
+    ('\u{0444}', &['\u{0424}']),
+    ('\u{0445}', &['\u{0425}']),
+    ('\u{0446}', &['\u{0426}']),
+    ('\u{0447}', &['\u{0427}']),
+    ('\u{0448}', &['\u{0428}']),
+    ('\u{0449}', &['\u{0429}']),
+    ('\u{044A}', &['\u{042A}']),
+    ('\u{044B}', &['\u{042B}']),
+    ('\u{044C}', &['\u{042C}']),
+    ('\u{044D}', &['\u{042D}']),
+    ('\u{044E}', &['\u{042E}']),
+    ('\u{044F}', &['\u{042F}']),
+    ('\u{0450}', &['\u{0400}']),
+    ('\u{0451}', &['\u{0401}']),
+    ('\u{0452}', &['\u{0402}']),
+    ('\u{0453}', &['\u{0403}']),
+    ('\u{0454}', &['\u{0404}']),
+    ('\u{0455}', &['\u{0405}']),
+    ('\u{0456}', &['\u{0406}']),
+    ('\u{0457}', &['\u{0407}']),
+    ('\u{0458}', &['\u{0408}']),
+    ('\u{0459}', &['\u{0409}']),
+    ('\u{045A}', &['\u{040A}']),
+    ('\u{045B}', &['\u{040B}']),
+    ('\u{045C}', &['\u{040C}']),
+    ('\u{045D}', &['\u{040D}']),
+    ('\u{045E}', &['\u{040E}']),
+    ('\u{045F}', &['\u{040F}']),
+    ('\u{0461}', &['\u{0460}']),
+    ('\u{0463}', &['\u{0462}']),
+    ('\u{0465}', &['\u{0464}']),
+    ('\u{0467}', &['\u{0466}']),
+    ('\u{0469}', &['\u{0468}']),
+    ('\u{046B}', &['\u{046A}']),
+    ('\u{046D}', &['\u{046C}']),
+    ('\u{046F}', &['\u{046E}']),
+    ('\u{0471}', &['\u{0470}']),
+    ('\u{0473}', &['\u{0472}']),
+    ('\u{0475}', &['\u{0474}']),
+    ('\u{0477}', &['\u{0476}']),
+    ('\u{0479}', &['\u{0478}']),
+    ('\u{047B}', &['\u{047A}']),
+    ('\u{047D}', &['\u{047C}']),
+    ('\u{047F}', &['\u{047E}']),
+    ('\u{0481}', &['\u{0480}']),
+    ('\u{048B}', &['\u{048A}']),
+    ('\u{048D}', &['\u{048C}']),
+    ('\u{048F}', &['\u{048E}']),
+    ('\u{0491}', &['\u{0490}']),
+    ('\u{0493}', &['\u{0492}']),
+    ('\u{0495}', &['\u{0494}']),
+    ('\u{0497}', &['\u{0496}']),
+    ('\u{0499}', &['\u{0498}']),
+    ('\u{049B}', &['\u{049A}']),
+    ('\u{049D}', &['\u{049C}']),
+    ('\u{049F}', &['\u{049E}']),
+    ('\u{04A1}', &['\u{04A0}']),
+    ('\u{04A3}', &['\u{04A2}']),
+    ('\u{04A5}', &['\u{04A4}']),
+    ('\u{04A7}', &['\u{04A6}']),
+    ('\u{04A9}', &['\u{04A8}']),
+    ('\u{04AB}', &['\u{04AA}']),
+    ('\u{04AD}', &['\u{04AC}']),
+    ('\u{04AF}', &['\u{04AE}']),
+    ('\u{04B1}', &['\u{04B0}']),
+    ('\u{04B3}', &['\u{04B2}']),
+    ('\u{04B5}', &['\u{04B4}']),
+    ('\u{04B7}', &['\u{04B6}']),
+    ('\u{04B9}', &['\u{04B8}']),
+    ('\u{04BB}', &['\u{04BA}']),
+    ('\u{04BD}', &['\u{04BC}']),
+    ('\u{04BF}', &['\u{04BE}']),
+    ('\u{04C2}', &['\u{04C1}']),
+    ('\u{04C4}', &['\u{04C3}']),
+    ('\u{04C6}', &['\u{04C5}']),
+    ('\u{04C8}', &['\u{04C7}']),
+    ('\u{04CA}', &['\u{04C9}']),
+    ('\u{04CC}', &['\u{04CB}']),
+    ('\u{04CE}', &['\u{04CD}']),
+    ('\u{04CF}', &['\u{04C0}']),
+    ('\u{04D1}', &['\u{04D0}']),
+    ('\u{04D3}', &['\u{04D2}']),
+    ('\u{04D5}', &['\u{04D4}']),
+    ('\u{04D7}', &['\u{04D6}']),
+    ('\u{04D9}', &['\u{04D8}']),
+    ('\u{04DB}', &['\u{04DA}']),
+    ('\u{04DD}', &['\u{04DC}']),
+    ('\u{04DF}', &['\u{04DE}']),
+    ('\u{04E1}', &['\u{04E0}']),
+    ('\u{04E3}', &['\u{04E2}']),
+    ('\u{04E5}', &['\u{04E4}']),
+    ('\u{04E7}', &['\u{04E6}']),
+    ('\u{04E9}', &['\u{04E8}']),
+    ('\u{04EB}', &['\u{04EA}']),
+    ('\u{04ED}', &['\u{04EC}']),
+    ('\u{04EF}', &['\u{04EE}']),
+    ('\u{04F1}', &['\u{04F0}']),
+    ('\u{04F3}', &['\u{04F2}']),
+    ('\u{04F5}', &['\u{04F4}']),
+    ('\u{04F7}', &['\u{04F6}']),
+    ('\u{04F9}', &['\u{04F8}']),
+    ('\u{04FB}', &['\u{04FA}']),
+    ('\u{04FD}', &['\u{04FC}']),
+    ('\u{04FF}', &['\u{04FE}']),
+    ('\u{0501}', &['\u{0500}']),
+    ('\u{0503}', &['\u{0502}']),
+    ('\u{0505}', &['\u{0504}']),
+    ('\u{0507}', &['\u{0506}']),
+    ('\u{0509}', &['\u{0508}']),
+    ('\u{050B}', &['\u{050A}']),
+    ('\u{050D}', &['\u{050C}']),
+    ('\u{050F}', &['\u{050E}']),
+    ('\u{0511}', &['\u{0510}']),
+    ('\u{0513}', &['\u{0512}']),
+    ('\u{0515}', &['\u{0514}']),
+    ('\u{0517}', &['\u{0516}']),
+    ('\u{0519}', &['\u{0518}']),
+    ('\u{051B}', &['\u{051A}']),
+    ('\u{051D}', &['\u{051C}']),
+    ('\u{051F}', &['\u{051E}']),
+    ('\u{0521}', &['\u{0520}']),
+    ('\u{0523}', &['\u{0522}']),
+    ('\u{0525}', &['\u{0524}']),
+    ('\u{0527}', &['\u{0526}']),
+    ('\u{0529}', &['\u{0528}']),
+    ('\u{052B}', &['\u{052A}']),
+    ('\u{052D}', &['\u{052C}']),
+    ('\u{052F}', &['\u{052E}']),
+    ('\u{0561}', &['\u{0531}']),
+    ('\u{0562}', &['\u{0532}']),
+    ('\u{0563}', &['\u{0533}']),
+    ('\u{0564}', &['\u{0534}']),
+    ('\u{0565}', &['\u{0535}']),
+    ('\u{0566}', &['\u{0536}']),
+    ('\u{0567}', &['\u{0537}']),
+    ('\u{0568}', &['\u{0538}']),
+    ('\u{0569}', &['\u{0539}']),
+    ('\u{056A}', &['\u{053A}']),
+    ('\u{056B}', &['\u{053B}']),
+    ('\u{056C}', &['\u{053C}']),
+    ('\u{056D}', &['\u{053D}']),
+    ('\u{056E}', &['\u{053E}']),
+    ('\u{056F}', &['\u{053F}']),
+    ('\u{0570}', &['\u{0540}']),
+    ('\u{0571}', &['\u{0541}']),
+    ('\u{0572}', &['\u{0542}']),
+    ('\u{0573}', &['\u{0543}']),
+    ('\u{0574}', &['\u{0544}']),
+    ('\u{0575}', &['\u{0545}']),
+    ('\u{0576}', &['\u{0546}']),
+    ('\u{0577}', &['\u{0547}']),
+    ('\u{0578}', &['\u{0548}']),
+    ('\u{0579}', &['\u{0549}']),
+    ('\u{057A}', &['\u{054A}']),
+    ('\u{057B}', &['\u{054B}']),
+    ('\u{057C}', &['\u{054C}']),
+    ('\u{057D}', &['\u{054D}']),
+    ('\u{057E}', &['\u{054E}']),
+    ('\u{057F}', &['\u{054F}']),
+    ('\u{0580}', &['\u{0550}']),
+    ('\u{0581}', &['\u{0551}']),
+    ('\u{0582}', &['\u{0552}']),
+    ('\u{0583}', &['\u{0553}']),
+    ('\u{0584}', &['\u{0554}']),
+    ('\u{0585}', &['\u{0555}']),
+    ('\u{0586}', &['\u{0556}']),
+    ('\u{0587}', &['\u{0535}', '\u{0582}']),
+    ('\u{13F8}', &['\u{13F0}']),
+    ('\u{13F9}', &['\u{13F1}']),
+    ('\u{13FA}', &['\u{13F2}']),
+    ('\u{13FB}', &['\u{13F3}']),
+    ('\u{13FC}', &['\u{13F4}']),
+    ('\u{13FD}', &['\u{13F5}']),
+    ('\u{1C80}', &['\u{0412}']),
+    ('\u{1C81}', &['\u{0414}']),
+    ('\u{1C82}', &['\u{041E}']),
+    ('\u{1C83}', &['\u{0421}']),
+    ('\u{1C84}', &['\u{0422}']),
+    ('\u{1C85}', &['\u{0422}']),
+    ('\u{1C86}', &['\u{042A}']),
+    ('\u{1C87}', &['\u{0462}']),
+    ('\u{1C88}', &['\u{A64A}']),
+    ('\u{1D79}', &['\u{A77D}']),
+    ('\u{1D7D}', &['\u{2C63}']),
+    ('\u{1D8E}', &['\u{A7C6}']),
+    ('\u{1E01}', &['\u{1E00}']),
+    ('\u{1E03}', &['\u{1E02}']),
+    ('\u{1E05}', &['\u{1E04}']),
+    ('\u{1E07}', &['\u{1E06}']),
+    ('\u{1E09}', &['\u{1E08}']),
+    ('\u{1E0B}', &['\u{1E0A}']),
+    ('\u{1E0D}', &['\u{1E0C}']),
+    ('\u{1E0F}', &['\u{1E0E}']),
+    ('\u{1E11}', &['\u{1E10}']),
+    ('\u{1E13}', &['\u{1E12}']),
+    ('\u{1E15}', &['\u{1E14}']),
+    ('\u{1E17}', &['\u{1E16}']),
+    ('\u{1E19}', &['\u{1E18}']),
+    ('\u{1E1B}', &['\u{1E1A}']),
+    ('\u{1E1D}', &['\u{1E1C}']),
+    ('\u{1E1F}', &['\u{1E1E}']),
+    ('\u{1E21}', &['\u{1E20}']),
+    ('\u{1E23}', &['\u{1E22}']),
+    ('\u{1E25}', &['\u{1E24}']),
+    ('\u{1E27}', &['\u{1E26}']),
+    ('\u{1E29}', &['\u{1E28}']),
+    ('\u{1E2B}', &['\u{1E2A}']),
+    ('\u{1E2D}', &['\u{1E2C}']),
+    ('\u{1E2F}', &['\u{1E2E}']),
+    ('\u{1E31}', &['\u{1E30}']),
+    ('\u{1E33}', &['\u{1E32}']),
+    ('\u{1E35}', &['\u{1E34}']),
+    ('\u{1E37}', &['\u{1E36}']),
+    ('\u{1E39}', &['\u{1E38}']),
+    ('\u{1E3B}', &['\u{1E3A}']),
+    ('\u{1E3D}', &['\u{1E3C}']),
+    ('\u{1E3F}', &['\u{1E3E}']),
+    ('\u{1E41}', &['\u{1E40}']),
+    ('\u{1E43}', &['\u{1E42}']),
+    ('\u{1E45}', &['\u{1E44}']),
+    ('\u{1E47}', &['\u{1E46}']),
+    ('\u{1E49}', &['\u{1E48}']),
+    ('\u{1E4B}', &['\u{1E4A}']),
+    ('\u{1E4D}', &['\u{1E4C}']),
+    ('\u{1E4F}', &['\u{1E4E}']),
+    ('\u{1E51}', &['\u{1E50}']),
+    ('\u{1E53}', &['\u{1E52}']),
+    ('\u{1E55}', &['\u{1E54}']),
+    ('\u{1E57}', &['\u{1E56}']),
+    ('\u{1E59}', &['\u{1E58}']),
+    ('\u{1E5B}', &['\u{1E5A}']),
+    ('\u{1E5D}', &['\u{1E5C}']),
+    ('\u{1E5F}', &['\u{1E5E}']),
+    ('\u{1E61}', &['\u{1E60}']),
+    ('\u{1E63}', &['\u{1E62}']),
+    ('\u{1E65}', &['\u{1E64}']),
+    ('\u{1E67}', &['\u{1E66}']),
+    ('\u{1E69}', &['\u{1E68}']),
+    ('\u{1E6B}', &['\u{1E6A}']),
+    ('\u{1E6D}', &['\u{1E6C}']),
+    ('\u{1E6F}', &['\u{1E6E}']),
+    ('\u{1E71}', &['\u{1E70}']),
+    ('\u{1E73}', &['\u{1E72}']),
+    ('\u{1E75}', &['\u{1E74}']),
+    ('\u{1E77}', &['\u{1E76}']),
+    ('\u{1E79}', &['\u{1E78}']),
+    ('\u{1E7B}', &['\u{1E7A}']),
+    ('\u{1E7D}', &['\u{1E7C}']),
+    ('\u{1E7F}', &['\u{1E7E}']),
+    ('\u{1E81}', &['\u{1E80}']),
+    ('\u{1E83}', &['\u{1E82}']),
+    ('\u{1E85}', &['\u{1E84}']),
+    ('\u{1E87}', &['\u{1E86}']),
+    ('\u{1E89}', &['\u{1E88}']),
+    ('\u{1E8B}', &['\u{1E8A}']),
+    ('\u{1E8D}', &['\u{1E8C}']),
+    ('\u{1E8F}', &['\u{1E8E}']),
+    ('\u{1E91}', &['\u{1E90}']),
+    ('\u{1E93}', &['\u{1E92}']),
+    ('\u{1E95}', &['\u{1E94}']),
+    ('\u{1E96}', &['\u{0048}', '\u{0331}']),
+    ('\u{1E97}', &['\u{0054}', '\u{0308}']),
+    ('\u{1E98}', &['\u{0057}', '\u{030A}']),
+    ('\u{1E99}', &['\u{0059}', '\u{030A}']),
+    ('\u{1E9A}', &['\u{0041}', '\u{02BE}']),
+    ('\u{1E9B}', &['\u{1E60}']),
+    ('\u{1EA1}', &['\u{1EA0}']),
+    ('\u{1EA3}', &['\u{1EA2}']),
+    ('\u{1EA5}', &['\u{1EA4}']),
+    ('\u{1EA7}', &['\u{1EA6}']),
+    ('\u{1EA9}', &['\u{1EA8}']),
+    ('\u{1EAB}', &['\u{1EAA}']),
+    ('\u{1EAD}', &['\u{1EAC}']),
+    ('\u{1EAF}', &['\u{1EAE}']),
+    ('\u{1EB1}', &['\u{1EB0}']),
+    ('\u{1EB3}', &['\u{1EB2}']),
+    ('\u{1EB5}', &['\u{1EB4}']),
+    ('\u{1EB7}', &['\u{1EB6}']),
+    ('\u{1EB9}', &['\u{1EB8}']),
+    ('\u{1EBB}', &['\u{1EBA}']),
+    ('\u{1EBD}', &['\u{1EBC}']),
+    ('\u{1EBF}', &['\u{1EBE}']),
+    ('\u{1EC1}', &['\u{1EC0}']),
+    ('\u{1EC3}', &['\u{1EC2}']),
+    ('\u{1EC5}', &['\u{1EC4}']),
+    ('\u{1EC7}', &['\u{1EC6}']),
+    ('\u{1EC9}', &['\u{1EC8}']),
+    ('\u{1ECB}', &['\u{1ECA}']),
+    ('\u{1ECD}', &['\u{1ECC}']),
+    ('\u{1ECF}', &['\u{1ECE}']),
+    ('\u{1ED1}', &['\u{1ED0}']),
+    ('\u{1ED3}', &['\u{1ED2}']),
+    ('\u{1ED5}', &['\u{1ED4}']),
+    ('\u{1ED7}', &['\u{1ED6}']),
+    ('\u{1ED9}', &['\u{1ED8}']),
+    ('\u{1EDB}', &['\u{1EDA}']),
+    ('\u{1EDD}', &['\u{1EDC}']),
+    ('\u{1EDF}', &['\u{1EDE}']),
+    ('\u{1EE1}', &['\u{1EE0}']),
+    ('\u{1EE3}', &['\u{1EE2}']),
+    ('\u{1EE5}', &['\u{1EE4}']),
+    ('\u{1EE7}', &['\u{1EE6}']),
+    ('\u{1EE9}', &['\u{1EE8}']),
+    ('\u{1EEB}', &['\u{1EEA}']),
+    ('\u{1EED}', &['\u{1EEC}']),
+    ('\u{1EEF}', &['\u{1EEE}']),
+    ('\u{1EF1}', &['\u{1EF0}']),
+    ('\u{1EF3}', &['\u{1EF2}']),
+    ('\u{1EF5}', &['\u{1EF4}']),
+    ('\u{1EF7}', &['\u{1EF6}']),
+    ('\u{1EF9}', &['\u{1EF8}']),
+    ('\u{1EFB}', &['\u{1EFA}']),
+    ('\u{1EFD}', &['\u{1EFC}']),
+    ('\u{1EFF}', &['\u{1EFE}']),
+    ('\u{1F00}', &['\u{1F08}']),
+    ('\u{1F01}', &['\u{1F09}']),
+    ('\u{1F02}', &['\u{1F0A}']),
+    ('\u{1F03}', &['\u{1F0B}']),
+    ('\u{1F04}', &['\u{1F0C}']),
+    ('\u{1F05}', &['\u{1F0D}']),
+    ('\u{1F06}', &['\u{1F0E}']),
+    ('\u{1F07}', &['\u{1F0F}']),
+    ('\u{1F10}', &['\u{1F18}']),
+    ('\u{1F11}', &['\u{1F19}']),
+    ('\u{1F12}', &['\u{1F1A}']),
+    ('\u{1F13}', &['\u{1F1B}']),
+    ('\u{1F14}', &['\u{1F1C}']),
+    ('\u{1F15}', &['\u{1F1D}']),
+    ('\u{1F20}', &['\u{1F28}']),
+    ('\u{1F21}', &['\u{1F29}']),
+    ('\u{1F22}', &['\u{1F2A}']),
+    ('\u{1F23}', &['\u{1F2B}']),
+    ('\u{1F24}', &['\u{1F2C}']),
+    ('\u{1F25}', &['\u{1F2D}']),
+    ('\u{1F26}', &['\u{1F2E}']),
+    ('\u{1F27}', &['\u{1F2F}']),
+    ('\u{1F30}', &['\u{1F38}']),
+    ('\u{1F31}', &['\u{1F39}']),
+    ('\u{1F32}', &['\u{1F3A}']),
+    ('\u{1F33}', &['\u{1F3B}']),
+    ('\u{1F34}', &['\u{1F3C}']),
+    ('\u{1F35}', &['\u{1F3D}']),
+    ('\u{1F36}', &['\u{1F3E}']),
+    ('\u{1F37}', &['\u{1F3F}']),
+    ('\u{1F40}', &['\u{1F48}']),
+    ('\u{1F41}', &['\u{1F49}']),
+    ('\u{1F42}', &['\u{1F4A}']),
+    ('\u{1F43}', &['\u{1F4B}']),
+    ('\u{1F44}', &['\u{1F4C}']),
+    ('\u{1F45}', &['\u{1F4D}']),
+    ('\u{1F50}', &['\u{03A5}', '\u{0313}']),
+    ('\u{1F51}', &['\u{1F59}']),
+    ('\u{1F52}', &['\u{03A5}', '\u{0313}', '\u{0300}']),
+    ('\u{1F53}', &['\u{1F5B}']),
+    ('\u{1F54}', &['\u{03A5}', '\u{0313}', '\u{0301}']),
+    ('\u{1F55}', &['\u{1F5D}']),
+    ('\u{1F56}', &['\u{03A5}', '\u{0313}', '\u{0342}']),
+    ('\u{1F57}', &['\u{1F5F}']),
+    ('\u{1F60}', &['\u{1F68}']),
+    ('\u{1F61}', &['\u{1F69}']),
+    ('\u{1F62}', &['\u{1F6A}']),
+    ('\u{1F63}', &['\u{1F6B}']),
+    ('\u{1F64}', &['\u{1F6C}']),
+    ('\u{1F65}', &['\u{1F6D}']),
+    ('\u{1F66}', &['\u{1F6E}']),
+    ('\u{1F67}', &['\u{1F6F}']),
+    ('\u{1F70}', &['\u{1FBA}']),
+    ('\u{1F71}', &['\u{1FBB}']),
+    ('\u{1F72}', &['\u{1FC8}']),
+    ('\u{1F73}', &['\u{1FC9}']),
+    ('\u{1F74}', &['\u{1FCA}']),
+    ('\u{1F75}', &['\u{1FCB}']),
+    ('\u{1F76}', &['\u{1FDA}']),
+    ('\u{1F77}', &['\u{1FDB}']),
+    ('\u{1F78}', &['\u{1FF8}']),
+    ('\u{1F79}', &['\u{1FF9}']),
+    ('\u{1F7A}', &['\u{1FEA}']),
+    ('\u{1F7B}', &['\u{1FEB}']),
+    ('\u{1F7C}', &['\u{1FFA}']),
+    ('\u{1F7D}', &['\u{1FFB}']),
+    ('\u{1F80}', &['\u{1F88}']),
+    ('\u{1F81}', &['\u{1F89}']),
+    ('\u{1F82}', &['\u{1F8A}']),
+    ('\u{1F83}', &['\u{1F8B}']),
+    ('\u{1F84}', &['\u{1F8C}']),
+    ('\u{1F85}', &['\u{1F8D}']),
+    ('\u{1F86}', &['\u{1F8E}']),
+    ('\u{1F87}', &['\u{1F8F}']),
+    ('\u{1F90}', &['\u{1F98}']),
+    ('\u{1F91}', &['\u{1F99}']),
+    ('\u{1F92}', &['\u{1F9A}']),
+    ('\u{1F93}', &['\u{1F9B}']),
+    ('\u{1F94}', &['\u{1F9C}']),
+    ('\u{1F95}', &['\u{1F9D}']),
+    ('\u{1F96}', &['\u{1F9E}']),
+    ('\u{1F97}', &['\u{1F9F}']),
+    ('\u{1FA0}', &['\u{1FA8}']),
+    ('\u{1FA1}', &['\u{1FA9}']),
+    ('\u{1FA2}', &['\u{1FAA}']),
+    ('\u{1FA3}', &['\u{1FAB}']),
+    ('\u{1FA4}', &['\u{1FAC}']),
+    ('\u{1FA5}', &['\u{1FAD}']),
+    ('\u{1FA6}', &['\u{1FAE}']),
+    ('\u{1FA7}', &['\u{1FAF}']),
+    ('\u{1FB0}', &['\u{1FB8}']),
+    ('\u{1FB1}', &['\u{1FB9}']),
+    ('\u{1FB2}', &['\u{1FBA}', '\u{0345}']),
+    ('\u{1FB3}', &['\u{1FBC}']),
+    ('\u{1FB4}', &['\u{0386}', '\u{0345}']),
+    ('\u{1FB6}', &['\u{0391}', '\u{0342}']),
+    ('\u{1FB7}', &['\u{0391}', '\u{0342}', '\u{0345}']),
+    ('\u{1FBE}', &['\u{0399}']),
+    ('\u{1FC2}', &['\u{1FCA}', '\u{0345}']),
+    ('\u{1FC3}', &['\u{1FCC}']),
+    ('\u{1FC4}', &['\u{0389}', '\u{0345}']),
+    ('\u{1FC6}', &['\u{0397}', '\u{0342}']),
+    ('\u{1FC7}', &['\u{0397}', '\u{0342}', '\u{0345}']),
+    ('\u{1FD0}', &['\u{1FD8}']),
+    ('\u{1FD1}', &['\u{1FD9}']),
+    ('\u{1FD2}', &['\u{0399}', '\u{0308}', '\u{0300}']),
+    ('\u{1FD3}', &['\u{0399}', '\u{0308}', '\u{0301}']),
+    ('\u{1FD6}', &['\u{0399}', '\u{0342}']),
+    ('\u{1FD7}', &['\u{0399}', '\u{0308}', '\u{0342}']),
+    ('\u{1FE0}', &['\u{1FE8}']),
+    ('\u{1FE1}', &['\u{1FE9}']),
+    ('\u{1FE2}', &['\u{03A5}', '\u{0308}', '\u{0300}']),
+    ('\u{1FE3}', &['\u{03A5}', '\u{0308}', '\u{0301}']),
+    ('\u{1FE4}', &['\u{03A1}', '\u{0313}']),
+    ('\u{1FE5}', &['\u{1FEC}']),
+    ('\u{1FE6}', &['\u{03A5}', '\u{0342}']),
+    ('\u{1FE7}', &['\u{03A5}', '\u{0308}', '\u{0342}']),
+    ('\u{1FF2}', &['\u{1FFA}', '\u{0345}']),
+    ('\u{1FF3}', &['\u{1FFC}']),
+    ('\u{1FF4}', &['\u{038F}', '\u{0345}']),
+    ('\u{1FF6}', &['\u{03A9}', '\u{0342}']),
+    ('\u{1FF7}', &['\u{03A9}', '\u{0342}', '\u{0345}']),
+    ('\u{214E}', &['\u{2132}']),
+    ('\u{2170}', &['\u{2160}']),
+    ('\u{2171}', &['\u{2161}']),
+    ('\u{2172}', &['\u{2162}']),
+    ('\u{2173}', &['\u{2163}']),
+    ('\u{2174}', &['\u{2164}']),
+    ('\u{2175}', &['\u{2165}']),
+    ('\u{2176}', &['\u{2166}']),
+    ('\u{2177}', &['\u{2167}']),
+    ('\u{2178}', &['\u{2168}']),
+    ('\u{2179}', &['\u{2169}']),
+    ('\u{217A}', &['\u{216A}']),
+    ('\u{217B}', &['\u{216B}']),
+    ('\u{217C}', &['\u{216C}']),
+    ('\u{217D}', &['\u{216D}']),
+    ('\u{217E}', &['\u{216E}']),
+    ('\u{217F}', &['\u{216F}']),
+    ('\u{2184}', &['\u{2183}']),
+    ('\u{24D0}', &['\u{24B6}']),
+    ('\u{24D1}', &['\u{24B7}']),
+    ('\u{24D2}', &['\u{24B8}']),
+    ('\u{24D3}', &['\u{24B9}']),
+    ('\u{24D4}', &['\u{24BA}']),
+    ('\u{24D5}', &['\u{24BB}']),
+    ('\u{24D6}', &['\u{24BC}']),
+    ('\u{24D7}', &['\u{24BD}']),
+    ('\u{24D8}', &['\u{24BE}']),
+    ('\u{24D9}', &['\u{24BF}']),
+    ('\u{24DA}', &['\u{24C0}']),
+    ('\u{24DB}', &['\u{24C1}']),
+    ('\u{24DC}', &['\u{24C2}']),
+    ('\u{24DD}', &['\u{24C3}']),
+    ('\u{24DE}', &['\u{24C4}']),
+    ('\u{24DF}', &['\u{24C5}']),
+    ('\u{24E0}', &['\u{24C6}']),
+    ('\u{24E1}', &['\u{24C7}']),
+    ('\u{24E2}', &['\u{24C8}']),
+    ('\u{24E3}', &['\u{24C9}']),
+    ('\u{24E4}', &['\u{24CA}']),
+    ('\u{24E5}', &['\u{24CB}']),
+    ('\u{24E6}', &['\u{24CC}']),
+    ('\u{24E7}', &['\u{24CD}']),
+    ('\u{24E8}', &['\u{24CE}']),
+    ('\u{24E9}', &['\u{24CF}']),
+    ('\u{2C30}', &['\u{2C00}']),
+    ('\u{2C31}', &['\u{2C01}']),
+    ('\u{2C32}', &['\u{2C02}']),
+    ('\u{2C33}', &['\u{2C03}']),
+    ('\u{2C34}', &['\u{2C04}']),
+    ('\u{2C35}', &['\u{2C05}']),
+    ('\u{2C36}', &['\u{2C06}']),
+    ('\u{2C37}', &['\u{2C07}']),
+    ('\u{2C38}', &['\u{2C08}']),
+    ('\u{2C39}', &['\u{2C09}']),
+    ('\u{2C3A}', &['\u{2C0A}']),
+    ('\u{2C3B}', &['\u{2C0B}']),
+    ('\u{2C3C}', &['\u{2C0C}']),
+    ('\u{2C3D}', &['\u{2C0D}']),
+    ('\u{2C3E}', &['\u{2C0E}']),
+    ('\u{2C3F}', &['\u{2C0F}']),
+    ('\u{2C40}', &['\u{2C10}']),
+    ('\u{2C41}', &['\u{2C11}']),
+    ('\u{2C42}', &['\u{2C12}']),
+    ('\u{2C43}', &['\u{2C13}']),
+    ('\u{2C44}', &['\u{2C14}']),
+    ('\u{2C45}', &['\u{2C15}']),
+    ('\u{2C46}', &['\u{2C16}']),
+    ('\u{2C47}', &['\u{2C17}']),
+    ('\u{2C48}', &['\u{2C18}']),
+    ('\u{2C49}', &['\u{2C19}']),
+    ('\u{2C4A}', &['\u{2C1A}']),
+    ('\u{2C4B}', &['\u{2C1B}']),
+    ('\u{2C4C}', &['\u{2C1C}']),
+    ('\u{2C4D}', &['\u{2C1D}']),
+    ('\u{2C4E}', &['\u{2C1E}']),
+    ('\u{2C4F}', &['\u{2C1F}']),
+    ('\u{2C50}', &['\u{2C20}']),
+    ('\u{2C51}', &['\u{2C21}']),
+    ('\u{2C52}', &['\u{2C22}']),
+    ('\u{2C53}', &['\u{2C23}']),
+    ('\u{2C54}', &['\u{2C24}']),
+    ('\u{2C55}', &['\u{2C25}']),
+    ('\u{2C56}', &['\u{2C26}']),
+    ('\u{2C57}', &['\u{2C27}']),
+    ('\u{2C58}', &['\u{2C28}']),
+    ('\u{2C59}', &['\u{2C29}']),
+    ('\u{2C5A}', &['\u{2C2A}']),
+    ('\u{2C5B}', &['\u{2C2B}']),
+    ('\u{2C5C}', &['\u{2C2C}']),
+    ('\u{2C5D}', &['\u{2C2D}']),
+    ('\u{2C5E}', &['\u{2C2E}']),
+    ('\u{2C61}', &['\u{2C60}']),
+    ('\u{2C65}', &['\u{023A}']),
+    ('\u{2C66}', &['\u{023E}']),
+    ('\u{2C68}', &['\u{2C67}']),
+    ('\u{2C6A}', &['\u{2C69}']),
+    ('\u{2C6C}', &['\u{2C6B}']),
+    ('\u{2C73}', &['\u{2C72}']),
+    ('\u{2C76}', &['\u{2C75}']),
+    ('\u{2C81}', &['\u{2C80}']),
+    ('\u{2C83}', &['\u{2C82}']),
+    ('\u{2C85}', &['\u{2C84}']),
+    ('\u{2C87}', &['\u{2C86}']),
+    ('\u{2C89}', &['\u{2C88}']),
+    ('\u{2C8B}', &['\u{2C8A}']),
+    ('\u{2C8D}', &['\u{2C8C}']),
+    ('\u{2C8F}', &['\u{2C8E}']),
+    ('\u{2C91}', &['\u{2C90}']),
+    ('\u{2C93}', &['\u{2C92}']),
+    ('\u{2C95}', &['\u{2C94}']),
+    ('\u{2C97}', &['\u{2C96}']),
+    ('\u{2C99}', &['\u{2C98}']),
+    ('\u{2C9B}', &['\u{2C9A}']),
+    ('\u{2C9D}', &['\u{2C9C}']),
+    ('\u{2C9F}', &['\u{2C9E}']),
+    ('\u{2CA1}', &['\u{2CA0}']),
+    ('\u{2CA3}', &['\u{2CA2}']),
+    ('\u{2CA5}', &['\u{2CA4}']),
+    ('\u{2CA7}', &['\u{2CA6}']),
+    ('\u{2CA9}', &['\u{2CA8}']),
+    ('\u{2CAB}', &['\u{2CAA}']),
+    ('\u{2CAD}', &['\u{2CAC}']),
+    ('\u{2CAF}', &['\u{2CAE}']),
+    ('\u{2CB1}', &['\u{2CB0}']),
+    ('\u{2CB3}', &['\u{2CB2}']),
+    ('\u{2CB5}', &['\u{2CB4}']),
+    ('\u{2CB7}', &['\u{2CB6}']),
+    ('\u{2CB9}', &['\u{2CB8}']),
+    ('\u{2CBB}', &['\u{2CBA}']),
+    ('\u{2CBD}', &['\u{2CBC}']),
+    ('\u{2CBF}', &['\u{2CBE}']),
+    ('\u{2CC1}', &['\u{2CC0}']),
+    ('\u{2CC3}', &['\u{2CC2}']),
+    ('\u{2CC5}', &['\u{2CC4}']),
+    ('\u{2CC7}', &['\u{2CC6}']),
+    ('\u{2CC9}', &['\u{2CC8}']),
+    ('\u{2CCB}', &['\u{2CCA}']),
+    ('\u{2CCD}', &['\u{2CCC}']),
+    ('\u{2CCF}', &['\u{2CCE}']),
+    ('\u{2CD1}', &['\u{2CD0}']),
+    ('\u{2CD3}', &['\u{2CD2}']),
+    ('\u{2CD5}', &['\u{2CD4}']),
+    ('\u{2CD7}', &['\u{2CD6}']),
+    ('\u{2CD9}', &['\u{2CD8}']),
+    ('\u{2CDB}', &['\u{2CDA}']),
+    ('\u{2CDD}', &['\u{2CDC}']),
+    ('\u{2CDF}', &['\u{2CDE}']),
+    ('\u{2CE1}', &['\u{2CE0}']),
+    ('\u{2CE3}', &['\u{2CE2}']),
+    ('\u{2CEC}', &['\u{2CEB}']),
+    ('\u{2CEE}', &['\u{2CED}']),
+    ('\u{2CF3}', &['\u{2CF2}']),
+    ('\u{2D00}', &['\u{10A0}']),
+    ('\u{2D01}', &['\u{10A1}']),
+    ('\u{2D02}', &['\u{10A2}']),
+    ('\u{2D03}', &['\u{10A3}']),
+    ('\u{2D04}', &['\u{10A4}']),
+    ('\u{2D05}', &['\u{10A5}']),
+    ('\u{2D06}', &['\u{10A6}']),
+    ('\u{2D07}', &['\u{10A7}']),
+    ('\u{2D08}', &['\u{10A8}']),
+    ('\u{2D09}', &['\u{10A9}']),
+    ('\u{2D0A}', &['\u{10AA}']),
+    ('\u{2D0B}', &['\u{10AB}']),
+    ('\u{2D0C}', &['\u{10AC}']),
+    ('\u{2D0D}', &['\u{10AD}']),
+    ('\u{2D0E}', &['\u{10AE}']),
+    ('\u{2D0F}', &['\u{10AF}']),
+    ('\u{2D10}', &['\u{10B0}']),
+    ('\u{2D11}', &['\u{10B1}']),
+    ('\u{2D12}', &['\u{10B2}']),
+    ('\u{2D13}', &['\u{10B3}']),
+    ('\u{2D14}', &['\u{10B4}']),
+    ('\u{2D15}', &['\u{10B5}']),
+    ('\u{2D16}', &['\u{10B6}']),
+    ('\u{2D17}', &['\u{10B7}']),
+    ('\u{2D18}', &['\u{10B8}']),
+    ('\u{2D19}', &['\u{10B9}']),
+    ('\u{2D1A}', &['\u{10BA}']),
+    ('\u{2D1B}', &['\u{10BB}']),
+    ('\u{2D1C}', &['\u{10BC}']),
+    ('\u{2D1D}', &['\u{10BD}']),
+    ('\u{2D1E}', &['\u{10BE}']),
+    ('\u{2D1F}', &['\u{10BF}']),
+    ('\u{2D20}', &['\u{10C0}']),
+    ('\u{2D21}', &['\u{10C1}']),
+    ('\u{2D22}', &['\u{10C2}']),
+    ('\u{2D23}', &['\u{10C3}']),
+    ('\u{2D24}', &['\u{10C4}']),
+    ('\u{2D25}', &['\u{10C5}']),
+    ('\u{2D27}', &['\u{10C7}']),
+    ('\u{2D2D}', &['\u{10CD}']),
+    ('\u{A641}', &['\u{A640}']),
+    ('\u{A643}', &['\u{A642}']),
+    ('\u{A645}', &['\u{A644}']),
+    ('\u{A647}', &['\u{A646}']),
+    ('\u{A649}', &['\u{A648}']),
+    ('\u{A64B}', &['\u{A64A}']),
+    ('\u{A64D}', &['\u{A64C}']),
+    ('\u{A64F}', &['\u{A64E}']),
+    ('\u{A651}', &['\u{A650}']),
+    ('\u{A653}', &['\u{A652}']),
+    ('\u{A655}', &['\u{A654}']),
+    ('\u{A657}', &['\u{A656}']),
+    ('\u{A659}', &['\u{A658}']),
+    ('\u{A65B}', &['\u{A65A}']),
+    ('\u{A65D}', &['\u{A65C}']),
+    ('\u{A65F}', &['\u{A65E}']),
+    ('\u{A661}', &['\u{A660}']),
+    ('\u{A663}', &['\u{A662}']),
+    ('\u{A665}', &['\u{A664}']),
+    ('\u{A667}', &['\u{A666}']),
+    ('\u{A669}', &['\u{A668}']),
+    ('\u{A66B}', &['\u{A66A}']),
+    ('\u{A66D}', &['\u{A66C}']),
+    ('\u{A681}', &['\u{A680}']),
+    ('\u{A683}', &['\u{A682}']),
+    ('\u{A685}', &['\u{A684}']),
+    ('\u{A687}', &['\u{A686}']),
+    ('\u{A689}', &['\u{A688}']),
+    ('\u{A68B}', &['\u{A68A}']),
+    ('\u{A68D}', &['\u{A68C}']),
+    ('\u{A68F}', &['\u{A68E}']),
+    ('\u{A691}', &['\u{A690}']),
+    ('\u{A693}', &['\u{A692}']),
+    ('\u{A695}', &['\u{A694}']),
+    ('\u{A697}', &['\u{A696}']),
+    ('\u{A699}', &['\u{A698}']),
+    ('\u{A69B}', &['\u{A69A}']),
+    ('\u{A723}', &['\u{A722}']),
+    ('\u{A725}', &['\u{A724}']),
+    ('\u{A727}', &['\u{A726}']),
+    ('\u{A729}', &['\u{A728}']),
+    ('\u{A72B}', &['\u{A72A}']),
+    ('\u{A72D}', &['\u{A72C}']),
+    ('\u{A72F}', &['\u{A72E}']),
+    ('\u{A733}', &['\u{A732}']),
+    ('\u{A735}', &['\u{A734}']),
+    ('\u{A737}', &['\u{A736}']),
+    ('\u{A739}', &['\u{A738}']),
+    ('\u{A73B}', &['\u{A73A}']),
+    ('\u{A73D}', &['\u{A73C}']),
+    ('\u{A73F}', &['\u{A73E}']),
+    ('\u{A741}', &['\u{A740}']),
+    ('\u{A743}', &['\u{A742}']),
+    ('\u{A745}', &['\u{A744}']),
+    ('\u{A747}', &['\u{A746}']),
+    ('\u{A749}', &['\u{A748}']),
+    ('\u{A74B}', &['\u{A74A}']),
+    ('\u{A74D}', &['\u{A74C}']),
+    ('\u{A74F}', &['\u{A74E}']),
+    ('\u{A751}', &['\u{A750}']),
+    ('\u{A753}', &['\u{A752}']),
+    ('\u{A755}', &['\u{A754}']),
+    ('\u{A757}', &['\u{A756}']),
+    ('\u{A759}', &['\u{A758}']),
+    ('\u{A75B}', &['\u{A75A}']),
+    ('\u{A75D}', &['\u{A75C}']),
+    ('\u{A75F}', &['\u{A75E}']),
+    ('\u{A761}', &['\u{A760}']),
+    ('\u{A763}', &['\u{A762}']),
+    ('\u{A765}', &['\u{A764}']),
+    ('\u{A767}', &['\u{A766}']),
+    ('\u{A769}', &['\u{A768}']),
+    ('\u{A76B}', &['\u{A76A}']),
+    ('\u{A76D}', &['\u{A76C}']),
+    ('\u{A76F}', &['\u{A76E}']),
+    ('\u{A77A}', &['\u{A779}']),
+    ('\u{A77C}', &['\u{A77B}']),
+    ('\u{A77F}', &['\u{A77E}']),
+    ('\u{A781}', &['\u{A780}']),
+    ('\u{A783}', &['\u{A782}']),
+    ('\u{A785}', &['\u{A784}']),
+    ('\u{A787}', &['\u{A786}']),
+    ('\u{A78C}', &['\u{A78B}']),
+    ('\u{A791}', &['\u{A790}']),
+    ('\u{A793}', &['\u{A792}']),
+    ('\u{A794}', &['\u{A7C4}']),
+    ('\u{A797}', &['\u{A796}']),
+    ('\u{A799}', &['\u{A798}']),
+    ('\u{A79B}', &['\u{A79A}']),
+    ('\u{A79D}', &['\u{A79C}']),
+    ('\u{A79F}', &['\u{A79E}']),
+    ('\u{A7A1}', &['\u{A7A0}']),
+    ('\u{A7A3}', &['\u{A7A2}']),
+    ('\u{A7A5}', &['\u{A7A4}']),
+    ('\u{A7A7}', &['\u{A7A6}']),
+    ('\u{A7A9}', &['\u{A7A8}']),
+    ('\u{A7B5}', &['\u{A7B4}']),
+    ('\u{A7B7}', &['\u{A7B6}']),
+    ('\u{A7B9}', &['\u{A7B8}']),
+    ('\u{A7BB}', &['\u{A7BA}']),
+    ('\u{A7BD}', &['\u{A7BC}']),
+    ('\u{A7BF}', &['\u{A7BE}']),
+    ('\u{A7C3}', &['\u{A7C2}']),
+    ('\u{A7C8}', &['\u{A7C7}']),
+    ('\u{A7CA}', &['\u{A7C9}']),
+    ('\u{A7F6}', &['\u{A7F5}']),
+    ('\u{AB53}', &['\u{A7B3}']),
+    ('\u{AB70}', &['\u{13A0}']),
+    ('\u{AB71}', &['\u{13A1}']),
+    ('\u{AB72}', &['\u{13A2}']),
+    ('\u{AB73}', &['\u{13A3}']),
+    ('\u{AB74}', &['\u{13A4}']),
+    ('\u{AB75}', &['\u{13A5}']),
+    ('\u{AB76}', &['\u{13A6}']),
+    ('\u{AB77}', &['\u{13A7}']),
+    ('\u{AB78}', &['\u{13A8}']),
+    ('\u{AB79}', &['\u{13A9}']),
+    ('\u{AB7A}', &['\u{13AA}']),
+    ('\u{AB7B}', &['\u{13AB}']),
+    ('\u{AB7C}', &['\u{13AC}']),
+    ('\u{AB7D}', &['\u{13AD}']),
+    ('\u{AB7E}', &['\u{13AE}']),
+    ('\u{AB7F}', &['\u{13AF}']),
+    ('\u{AB80}', &['\u{13B0}']),
+    ('\u{AB81}', &['\u{13B1}']),
+    ('\u{AB82}', &['\u{13B2}']),
+    ('\u{AB83}', &['\u{13B3}']),
+    ('\u{AB84}', &['\u{13B4}']),
+    ('\u{AB85}', &['\u{13B5}']),
+    ('\u{AB86}', &['\u{13B6}']),
+    ('\u{AB87}', &['\u{13B7}']),
+    ('\u{AB88}', &['\u{13B8}']),
+    ('\u{AB89}', &['\u{13B9}']),
+    ('\u{AB8A}', &['\u{13BA}']),
+    ('\u{AB8B}', &['\u{13BB}']),
+    ('\u{AB8C}', &['\u{13BC}']),
+    ('\u{AB8D}', &['\u{13BD}']),
+    ('\u{AB8E}', &['\u{13BE}']),
+    ('\u{AB8F}', &['\u{13BF}']),
+    ('\u{AB90}', &['\u{13C0}']),
+    ('\u{AB91}', &['\u{13C1}']),
+    ('\u{AB92}', &['\u{13C2}']),
+    ('\u{AB93}', &['\u{13C3}']),
+    ('\u{AB94}', &['\u{13C4}']),
+    ('\u{AB95}', &['\u{13C5}']),
+    ('\u{AB96}', &['\u{13C6}']),
+    ('\u{AB97}', &['\u{13C7}']),
+    ('\u{AB98}', &['\u{13C8}']),
+    ('\u{AB99}', &['\u{13C9}']),
+    ('\u{AB9A}', &['\u{13CA}']),
+    ('\u{AB9B}', &['\u{13CB}']),
+    ('\u{AB9C}', &['\u{13CC}']),
+    ('\u{AB9D}', &['\u{13CD}']),
+    ('\u{AB9E}', &['\u{13CE}']),
+    ('\u{AB9F}', &['\u{13CF}']),
+    ('\u{ABA0}', &['\u{13D0}']),
+    ('\u{ABA1}', &['\u{13D1}']),
+    ('\u{ABA2}', &['\u{13D2}']),
+    ('\u{ABA3}', &['\u{13D3}']),
+    ('\u{ABA4}', &['\u{13D4}']),
+    ('\u{ABA5}', &['\u{13D5}']),
+    ('\u{ABA6}', &['\u{13D6}']),
+    ('\u{ABA7}', &['\u{13D7}']),
+    ('\u{ABA8}', &['\u{13D8}']),
+    ('\u{ABA9}', &['\u{13D9}']),
+    ('\u{ABAA}', &['\u{13DA}']),
+    ('\u{ABAB}', &['\u{13DB}']),
+    ('\u{ABAC}', &['\u{13DC}']),
+    ('\u{ABAD}', &['\u{13DD}']),
+    ('\u{ABAE}', &['\u{13DE}']),
+    ('\u{ABAF}', &['\u{13DF}']),
+    ('\u{ABB0}', &['\u{13E0}']),
+    ('\u{ABB1}', &['\u{13E1}']),
+    ('\u{ABB2}', &['\u{13E2}']),
+    ('\u{ABB3}', &['\u{13E3}']),
+    ('\u{ABB4}', &['\u{13E4}']),
+    ('\u{ABB5}', &['\u{13E5}']),
+    ('\u{ABB6}', &['\u{13E6}']),
+    ('\u{ABB7}', &['\u{13E7}']),
+    ('\u{ABB8}', &['\u{13E8}']),
+    ('\u{ABB9}', &['\u{13E9}']),
+    ('\u{ABBA}', &['\u{13EA}']),
+    ('\u{ABBB}', &['\u{13EB}']),
+    ('\u{ABBC}', &['\u{13EC}']),
+    ('\u{ABBD}', &['\u{13ED}']),
+    ('\u{ABBE}', &['\u{13EE}']),
+    ('\u{ABBF}', &['\u{13EF}']),
+    ('\u{FB00}', &['\u{0046}', '\u{0066}']),
+    ('\u{FB01}', &['\u{0046}', '\u{0069}']),
+    ('\u{FB02}', &['\u{0046}', '\u{006C}']),
+    ('\u{FB03}', &['\u{0046}', '\u{0066}', '\u{0069}']),
+    ('\u{FB04}', &['\u{0046}', '\u{0066}', '\u{006C}']),
+    ('\u{FB05}', &['\u{0053}', '\u{0074}']),
+    ('\u{FB06}', &['\u{0053}', '\u{0074}']),
+    ('\u{FB13}', &['\u{0544}', '\u{0576}']),
+    ('\u{FB14}', &['\u{0544}', '\u{0565}']),
+    ('\u{FB15}', &['\u{0544}', '\u{056B}']),
+    ('\u{FB16}', &['\u{054E}', '\u{0576}']),
+    ('\u{FB17}', &['\u{0544}', '\u{056D}']),
+    ('\u{FF41}', &['\u{FF21}']),
+    ('\u{FF42}', &['\u{FF22}']),
+    ('\u{FF43}', &['\u{FF23}']),
+    ('\u{FF44}', &['\u{FF24}']),
+    ('\u{FF45}', &['\u{FF25}']),
+    ('\u{FF46}', &['\u{FF26}']),
+    ('\u{FF47}', &['\u{FF27}']),
+    ('\u{FF48}', &['\u{FF28}']),
+    ('\u{FF49}', &['\u{FF29}']),
+    ('\u{FF4A}', &['\u{FF2A}']),
+    ('\u{FF4B}', &['\u{FF2B}']),
+    ('\u{FF4C}', &['\u{FF2C}']),
+    ('\u{FF4D}', &['\u{FF2D}']),
+    ('\u{FF4E}', &['\u{FF2E}']),
+    ('\u{FF4F}', &['\u{FF2F}']),
+    ('\u{FF50}', &['\u{FF30}']),
+    ('\u{FF51}', &['\u{FF31}']),
+    ('\u{FF52}', &['\u{FF32}']),
+    ('\u{FF53}', &['\u{FF33}']),
+    ('\u{FF54}', &['\u{FF34}']),
+    ('\u{FF55}', &['\u{FF35}']),
+    ('\u{FF56}', &['\u{FF36}']),
+    ('\u{FF57}', &['\u{FF37}']),
+    ('\u{FF58}', &['\u{FF38}']),
+    ('\u{FF59}', &['\u{FF39}']),
+    ('\u{FF5A}', &['\u{FF3A}']),
+    ('\u{10428}', &['\u{10400}']),
+    ('\u{10429}', &['\u{10401}']),
+    ('\u{1042A}', &['\u{10402}']),
+    ('\u{1042B}', &['\u{10403}']),
+    ('\u{1042C}', &['\u{10404}']),
+    ('\u{1042D}', &['\u{10405}']),
+    ('\u{1042E}', &['\u{10406}']),
+    ('\u{1042F}', &['\u{10407}']),
+    ('\u{10430}', &['\u{10408}']),
+    ('\u{10431}', &['\u{10409}']),
+    ('\u{10432}', &['\u{1040A}']),
+    ('\u{10433}', &['\u{1040B}']),
+    ('\u{10434}', &['\u{1040C}']),
+    ('\u{10435}', &['\u{1040D}']),
+    ('\u{10436}', &['\u{1040E}']),
+    ('\u{10437}', &['\u{1040F}']),
+    ('\u{10438}', &['\u{10410}']),
+    ('\u{10439}', &['\u{10411}']),
+    ('\u{1043A}', &['\u{10412}']),
+    ('\u{1043B}', &['\u{10413}']),
+    ('\u{1043C}', &['\u{10414}']),
+    ('\u{1043D}', &['\u{10415}']),
+    ('\u{1043E}', &['\u{10416}']),
+    ('\u{1043F}', &['\u{10417}']),
+    ('\u{10440}', &['\u{10418}']),
+    ('\u{10441}', &['\u{10419}']),
+    ('\u{10442}', &['\u{1041A}']),
+    ('\u{10443}', &['\u{1041B}']),
+    ('\u{10444}', &['\u{1041C}']),
+    ('\u{10445}', &['\u{1041D}']),
+    ('\u{10446}', &['\u{1041E}']),
+    ('\u{10447}', &['\u{1041F}']),
+    ('\u{10448}', &['\u{10420}']),
+    ('\u{10449}', &['\u{10421}']),
+    ('\u{1044A}', &['\u{10422}']),
+    ('\u{1044B}', &['\u{10423}']),
+    ('\u{1044C}', &['\u{10424}']),
+    ('\u{1044D}', &['\u{10425}']),
+    ('\u{1044E}', &['\u{10426}']),
+    ('\u{1044F}', &['\u{10427}']),
+    ('\u{104D8}', &['\u{104B0}']),
+    ('\u{104D9}', &['\u{104B1}']),
+    ('\u{104DA}', &['\u{104B2}']),
+    ('\u{104DB}', &['\u{104B3}']),
+    ('\u{104DC}', &['\u{104B4}']),
+    ('\u{104DD}', &['\u{104B5}']),
+    ('\u{104DE}', &['\u{104B6}']),
+    ('\u{104DF}', &['\u{104B7}']),
+    ('\u{104E0}', &['\u{104B8}']),
+    ('\u{104E1}', &['\u{104B9}']),
+    ('\u{104E2}', &['\u{104BA}']),
+    ('\u{104E3}', &['\u{104BB}']),
+    ('\u{104E4}', &['\u{104BC}']),
+    ('\u{104E5}', &['\u{104BD}']),
+    ('\u{104E6}', &['\u{104BE}']),
+    ('\u{104E7}', &['\u{104BF}']),
+    ('\u{104E8}', &['\u{104C0}']),
+    ('\u{104E9}', &['\u{104C1}']),
+    ('\u{104EA}', &['\u{104C2}']),
+    ('\u{104EB}', &['\u{104C3}']),
+    ('\u{104EC}', &['\u{104C4}']),
+    ('\u{104ED}', &['\u{104C5}']),
+    ('\u{104EE}', &['\u{104C6}']),
+    ('\u{104EF}', &['\u{104C7}']),
+    ('\u{104F0}', &['\u{104C8}']),
+    ('\u{104F1}', &['\u{104C9}']),
+    ('\u{104F2}', &['\u{104CA}']),
+    ('\u{104F3}', &['\u{104CB}']),
+    ('\u{104F4}', &['\u{104CC}']),
+    ('\u{104F5}', &['\u{104CD}']),
+    ('\u{104F6}', &['\u{104CE}']),
+    ('\u{104F7}', &['\u{104CF}']),
+    ('\u{104F8}', &['\u{104D0}']),
+    ('\u{104F9}', &['\u{104D1}']),
+    ('\u{104FA}', &['\u{104D2}']),
+    ('\u{104FB}', &['\u{104D3}']),
+    ('\u{10CC0}', &['\u{10C80}']),
+    ('\u{10CC1}', &['\u{10C81}']),
+    ('\u{10CC2}', &['\u{10C82}']),
+    ('\u{10CC3}', &['\u{10C83}']),
+    ('\u{10CC4}', &['\u{10C84}']),
+    ('\u{10CC5}', &['\u{10C85}']),
+    ('\u{10CC6}', &['\u{10C86}']),
+    ('\u{10CC7}', &['\u{10C87}']),
+    ('\u{10CC8}', &['\u{10C88}']),
+    ('\u{10CC9}', &['\u{10C89}']),
+    ('\u{10CCA}', &['\u{10C8A}']),
+    ('\u{10CCB}', &['\u{10C8B}']),
+    ('\u{10CCC}', &['\u{10C8C}']),
+    ('\u{10CCD}', &['\u{10C8D}']),
+    ('\u{10CCE}', &['\u{10C8E}']),
+    ('\u{10CCF}', &['\u{10C8F}']),
+    ('\u{10CD0}', &['\u{10C90}']),
+    ('\u{10CD1}', &['\u{10C91}']),
+    ('\u{10CD2}', &['\u{10C92}']),
+    ('\u{10CD3}', &['\u{10C93}']),
+    ('\u{10CD4}', &['\u{10C94}']),
+    ('\u{10CD5}', &['\u{10C95}']),
+    ('\u{10CD6}', &['\u{10C96}']),
+    ('\u{10CD7}', &['\u{10C97}']),
+    ('\u{10CD8}', &['\u{10C98}']),
+    ('\u{10CD9}', &['\u{10C99}']),
+    ('\u{10CDA}', &['\u{10C9A}']),
+    ('\u{10CDB}', &['\u{10C9B}']),
+    ('\u{10CDC}', &['\u{10C9C}']),
+    ('\u{10CDD}', &['\u{10C9D}']),
+    ('\u{10CDE}', &['\u{10C9E}']),
+    ('\u{10CDF}', &['\u{10C9F}']),
+    ('\u{10CE0}', &['\u{10CA0}']),
+    ('\u{10CE1}', &['\u{10CA1}']),
+    ('\u{10CE2}', &['\u{10CA2}']),
+    ('\u{10CE3}', &['\u{10CA3}']),
+    ('\u{10CE4}', &['\u{10CA4}']),
+    ('\u{10CE5}', &['\u{10CA5}']),
+    ('\u{10CE6}', &['\u{10CA6}']),
+    ('\u{10CE7}', &['\u{10CA7}']),
+    ('\u{10CE8}', &['\u{10CA8}']),
+    ('\u{10CE9}', &['\u{10CA9}']),
+    ('\u{10CEA}', &['\u{10CAA}']),
+    ('\u{10CEB}', &['\u{10CAB}']),
+    ('\u{10CEC}', &['\u{10CAC}']),
+    ('\u{10CED}', &['\u{10CAD}']),
+    ('\u{10CEE}', &['\u{10CAE}']),
+    ('\u{10CEF}', &['\u{10CAF}']),
+    ('\u{10CF0}', &['\u{10CB0}']),
+    ('\u{10CF1}', &['\u{10CB1}']),
+    ('\u{10CF2}', &['\u{10CB2}']),
+    ('\u{118C0}', &['\u{118A0}']),
+    ('\u{118C1}', &['\u{118A1}']),
+    ('\u{118C2}', &['\u{118A2}']),
+    ('\u{118C3}', &['\u{118A3}']),
+    ('\u{118C4}', &['\u{118A4}']),
+    ('\u{118C5}', &['\u{118A5}']),
+    ('\u{118C6}', &['\u{118A6}']),
+    ('\u{118C7}', &['\u{118A7}']),
+    ('\u{118C8}', &['\u{118A8}']),
+    ('\u{118C9}', &['\u{118A9}']),
+    ('\u{118CA}', &['\u{118AA}']),
+    ('\u{118CB}', &['\u{118AB}']),
+    ('\u{118CC}', &['\u{118AC}']),
+    ('\u{118CD}', &['\u{118AD}']),
+    ('\u{118CE}', &['\u{118AE}']),
+    ('\u{118CF}', &['\u{118AF}']),
+    ('\u{118D0}', &['\u{118B0}']),
+    ('\u{118D1}', &['\u{118B1}']),
+    ('\u{118D2}', &['\u{118B2}']),
+    ('\u{118D3}', &['\u{118B3}']),
+    ('\u{118D4}', &['\u{118B4}']),
+    ('\u{118D5}', &['\u{118B5}']),
+    ('\u{118D6}', &['\u{118B6}']),
+    ('\u{118D7}', &['\u{118B7}']),
+    ('\u{118D8}', &['\u{118B8}']),
+    ('\u{118D9}', &['\u{118B9}']),
+    ('\u{118DA}', &['\u{118BA}']),
+    ('\u{118DB}', &['\u{118BB}']),
+    ('\u{118DC}', &['\u{118BC}']),
+    ('\u{118DD}', &['\u{118BD}']),
+    ('\u{118DE}', &['\u{118BE}']),
+    ('\u{118DF}', &['\u{118BF}']),
+    ('\u{16E60}', &['\u{16E40}']),
+    ('\u{16E61}', &['\u{16E41}']),
+    ('\u{16E62}', &['\u{16E42}']),
+    ('\u{16E63}', &['\u{16E43}']),
+    ('\u{16E64}', &['\u{16E44}']),
+    ('\u{16E65}', &['\u{16E45}']),
+    ('\u{16E66}', &['\u{16E46}']),
+    ('\u{16E67}', &['\u{16E47}']),
+    ('\u{16E68}', &['\u{16E48}']),
+    ('\u{16E69}', &['\u{16E49}']),
+    ('\u{16E6A}', &['\u{16E4A}']),
+    ('\u{16E6B}', &['\u{16E4B}']),
+    ('\u{16E6C}', &['\u{16E4C}']),
+    ('\u{16E6D}', &['\u{16E4D}']),
+    ('\u{16E6E}', &['\u{16E4E}']),
+    ('\u{16E6F}', &['\u{16E4F}']),
+    ('\u{16E70}', &['\u{16E50}']),
+    ('\u{16E71}', &['\u{16E51}']),
+    ('\u{16E72}', &['\u{16E52}']),
+    ('\u{16E73}', &['\u{16E53}']),
+    ('\u{16E74}', &['\u{16E54}']),
+    ('\u{16E75}', &['\u{16E55}']),
+    ('\u{16E76}', &['\u{16E56}']),
+    ('\u{16E77}', &['\u{16E57}']),
+    ('\u{16E78}', &['\u{16E58}']),
+    ('\u{16E79}', &['\u{16E59}']),
+    ('\u{16E7A}', &['\u{16E5A}']),
+    ('\u{16E7B}', &['\u{16E5B}']),
+    ('\u{16E7C}', &['\u{16E5C}']),
+    ('\u{16E7D}', &['\u{16E5D}']),
+    ('\u{16E7E}', &['\u{16E5E}']),
+    ('\u{16E7F}', &['\u{16E5F}']),
+    ('\u{1E922}', &['\u{1E900}']),
+    ('\u{1E923}', &['\u{1E901}']),
+    ('\u{1E924}', &['\u{1E902}']),
+    ('\u{1E925}', &['\u{1E903}']),
+    ('\u{1E926}', &['\u{1E904}']),
+    ('\u{1E927}', &['\u{1E905}']),
+    ('\u{1E928}', &['\u{1E906}']),
+    ('\u{1E929}', &['\u{1E907}']),
+    ('\u{1E92A}', &['\u{1E908}']),
+    ('\u{1E92B}', &['\u{1E909}']),
+    ('\u{1E92C}', &['\u{1E90A}']),
+    ('\u{1E92D}', &['\u{1E90B}']),
+    ('\u{1E92E}', &['\u{1E90C}']),
+    ('\u{1E92F}', &['\u{1E90D}']),
+    ('\u{1E930}', &['\u{1E90E}']),
+    ('\u{1E931}', &['\u{1E90F}']),
+    ('\u{1E932}', &['\u{1E910}']),
+    ('\u{1E933}', &['\u{1E911}']),
+    ('\u{1E934}', &['\u{1E912}']),
+    ('\u{1E935}', &['\u{1E913}']),
+    ('\u{1E936}', &['\u{1E914}']),
+    ('\u{1E937}', &['\u{1E915}']),
+    ('\u{1E938}', &['\u{1E916}']),
+    ('\u{1E939}', &['\u{1E917}']),
+    ('\u{1E93A}', &['\u{1E918}']),
+    ('\u{1E93B}', &['\u{1E919}']),
+    ('\u{1E93C}', &['\u{1E91A}']),
+    ('\u{1E93D}', &['\u{1E91B}']),
+    ('\u{1E93E}', &['\u{1E91C}']),
+    ('\u{1E93F}', &['\u{1E91D}']),
+    ('\u{1E940}', &['\u{1E91E}']),
+    ('\u{1E941}', &['\u{1E91F}']),
+    ('\u{1E942}', &['\u{1E920}']),
+    ('\u{1E943}', &['\u{1E921}']),
+];
+
+pub(super) const CASE_FOLDINGS: &[(char, &[char])] = &[
+    ('\u{0041}', &['\u{0061}']),
+    ('\u{0042}', &['\u{0062}']),
+    ('\u{0043}', &['\u{0063}']),
+    ('\u{0044}', &['\u{0064}']),
+    ('\u{0045}', &['\u{0065}']),
+    ('\u{0046}', &['\u{0066}']),
+    ('\u{0047}', &['\u{0067}']),
+    ('\u{0048}', &['\u{0068}']),
+    ('\u{0049}', &['\u{0069}']),
+    ('\u{004A}', &['\u{006A}']),
+    ('\u{004B}', &['\u{006B}']),
+    ('\u{004C}', &['\u{006C}']),
+    ('\u{004D}', &['\u{006D}']),
+    ('\u{004E}', &['\u{006E}']),
+    ('\u{004F}', &['\u{006F}']),
+    ('\u{0050}', &['\u{0070}']),
+    ('\u{0051}', &['\u{0071}']),
+    ('\u{0052}', &['\u{0072}']),
+    ('\u{0053}', &['\u{0073}']),
+    ('\u{0054}', &['\u{0074}']),
+    ('\u{0055}', &['\u{0075}']),
+    ('\u{0056}', &['\u{0076}']),
+    ('\u{0057}', &['\u{0077}']),
+    ('\u{0058}', &['\u{0078}']),
+    ('\u{0059}', &['\u{0079}']),
+    ('\u{005A}', &['\u{007A}']),
+    ('\u{00B5}', &['\u{03BC}']),
+    ('\u{00C0}', &['\u{00E0}']),
+    ('\u{00C1}', &['\u{00E1}']),
+    ('\u{00C2}', &['\u{00E2}']),
+    ('\u{00C3}', &['\u{00E3}']),
+    ('\u{00C4}', &['\u{00E4}']),
+    ('\u{00C5}', &['\u{00E5}']),
+    ('\u{00C6}', &['\u{00E6}']),
+    ('\u{00C7}', &['\u{00E7}']),
+    ('\u{00C8}', &['\u{00E8}']),
+    ('\u{00C9}', &['\u{00E9}']),
+    ('\u{00CA}', &['\u{00EA}']),
+    ('\u{00CB}', &['\u{00EB}']),
+    ('\u{00CC}', &['\u{00EC}']),
+    ('\u{00CD}', &['\u{00ED}']),
+    ('\u{00CE}', &['\u{00EE}']),
+    ('\u{00CF}', &['\u{00EF}']),
+    ('\u{00D0}', &['\u{00F0}']),
+    ('\u{00D1}', &['\u{00F1}']),
+    ('\u{00D2}', &['\u{00F2}']),
+    ('\u{00D3}', &['\u{00F3}']),
+    ('\u{00D4}', &['\u{00F4}']),
+    ('\u{00D5}', &['\u{00F5}']),
+    ('\u{00D6}', &['\u{00F6}']),
+    ('\u{00D8}', &['\u{00F8}']),
+    ('\u{00D9}', &['\u{00F9}']),
+    ('\u{00DA}', &['\u{00FA}']),
+    ('\u{00DB}', &['\u{00FB}']),
+    ('\u{00DC}', &['\u{00FC}']),
+    ('\u{00DD}', &['\u{00FD}']),
+    ('\u{00DE}', &['\u{00FE}']),
+    ('\u{00DF}', &['\u{0073}', '\u{0073}']),
+    ('\u{0100}', &['\u{0101}']),
+    ('\u{0102}', &['\u{0103}']),
+    ('\u{0104}', &['\u{0105}']),
+    ('\u{0106}', &['\u{0107}']),
+    ('\u{0108}', &['\u{0109}']),
+    ('\u{010A}', &['\u{010B}']),
+    ('\u{010C}', &['\u{010D}']),
+    ('\u{010E}', &['\u{010F}']),
+    ('\u{0110}', &['\u{0111}']),
+    ('\u{0112}', &['\u{0113}']),
+    ('\u{0114}', &['\u{0115}']),
+    ('\u{0116}', &['\u{0117}']),
+    ('\u{0118}', &['\u{0119}']),
+    ('\u{011A}', &['\u{011B}']),
+    ('\u{011C}', &['\u{011D}']),
+    ('\u{011E}', &['\u{011F}']),
+    ('\u{0120}', &['\u{0121}']),
+    ('\u{0122}', &['\u{0123}']),
+    ('\u{0124}', &['\u{0125}']),
+    ('\u{0126}', &['\u{0127}']),
+    ('\u{0128}', &['\u{0129}']),
+    ('\u{012A}', &['\u{012B}']),
+    ('\u{012C}', &['\u{012D}']),
+    ('\u{012E}', &['\u{012F}']),
+    ('\u{0130}', &['\u{0069}', '\u{0307}']),
+    ('\u{0132}', &['\u{0133}']),
+    ('\u{0134}', &['\u{0135}']),
+    ('\u{0136}', &['\u{0137}']),
+    ('\u{0139}', &['\u{013A}']),
+    ('\u{013B}', &['\u{013C}']),
+    ('\u{013D}', &['\u{013E}']),
+    ('\u{013F}', &['\u{0140}']),
+    ('\u{0141}', &['\u{0142}']),
+    ('\u{0143}', &['\u{0144}']),
+    ('\u{0145}', &['\u{0146}']),
+    ('\u{0147}', &['\u{0148}']),
+    ('\u{0149}', &['\u{02BC}', '\u{006E}']),
+    ('\u{014A}', &['\u{014B}']),
+    ('\u{014C}', &['\u{014D}']),
+    ('\u{014E}', &['\u{014F}']),
+    ('\u{0150}', &['\u{0151}']),
+    ('\u{0152}', &['\u{0153}']),
+    ('\u{0154}', &['\u{0155}']),
+    ('\u{0156}', &['\u{0157}']),
+    ('\u{0158}', &['\u{0159}']),
+    ('\u{015A}', &['\u{015B}']),
+    ('\u{015C}', &['\u{015D}']),
+    ('\u{015E}', &['\u{015F}']),
+    ('\u{0160}', &['\u{0161}']),
+    ('\u{0162}', &['\u{0163}']),
+    ('\u{0164}', &['\u{0165}']),
+    ('\u{0166}', &['\u{0167}']),
+    ('\u{0168}', &['\u{0169}']),
+    ('\u{016A}', &['\u{016B}']),
+    ('\u{016C}', &['\u{016D}']),
+    ('\u{016E}', &['\u{016F}']),
+    ('\u{0170}', &['\u{0171}']),
+    ('\u{0172}', &['\u{0173}']),
+    ('\u{0174}', &['\u{0175}']),
+    ('\u{0176}', &['\u{0177}']),
+    ('\u{0178}', &['\u{00FF}']),
+    ('\u{0179}', &['\u{017A}']),
+    ('\u{017B}', &['\u{017C}']),
+    ('\u{017D}', &['\u{017E}']),
+    ('\u{017F}', &['\u{0073}']),
+    ('\u{0181}', &['\u{0253}']),
+    ('\u{0182}', &['\u{0183}']),
+    ('\u{0184}', &['\u{0185}']),
+    ('\u{0186}', &['\u{0254}']),
+    ('\u{0187}', &['\u{0188}']),
+    ('\u{0189}', &['\u{0256}']),
+    ('\u{018A}', &['\u{0257}']),
+    ('\u{018B}', &['\u{018C}']),
+    ('\u{018E}', &['\u{01DD}']),
+    ('\u{018F}', &['\u{0259}']),
+    ('\u{0190}', &['\u{025B}']),
+    ('\u{0191}', &['\u{0192}']),
+    ('\u{0193}', &['\u{0260}']),
+    ('\u{0194}', &['\u{0263}']),
+    ('\u{0196}', &['\u{0269}']),
+    ('\u{0197}', &['\u{0268}']),
+    ('\u{0198}', &['\u{0199}']),
+    ('\u{019C}', &['\u{026F}']),
+    ('\u{019D}', &['\u{0272}']),
+    ('\u{019F}', &['\u{0275}']),
+    ('\u{01A0}', &['\u{01A1}']),
+    ('\u{01A2}', &['\u{01A3}']),
+    ('\u{01A4}', &['\u{01A5}']),
+    ('\u{01A6}', &['\u{0280}']),
+    ('\u{01A7}', &['\u{01A8}']),
+    ('\u{01A9}', &['\u{0283}']),
+    ('\u{01AC}', &['\u{01AD}']),
+    ('\u{01AE}', &['\u{0288}']),
+    ('\u{01AF}', &['\u{01B0}']),
+    ('\u{01B1}', &['\u{028A}']),
+    ('\u{01B2}', &['\u{028B}']),
+    ('\u{01B3}', &['\u{01B4}']),
+    ('\u{01B5}', &['\u{01B6}']),
+    ('\u{01B7}', &['\u{0292}']),
+    ('\u{01B8}', &['\u{01B9}']),
+    ('\u{01BC}', &['\u{01BD}']),
+    ('\u{01C4}', &['\u{01C6}']),
+    ('\u{01C5}', &['\u{01C6}']),
+    ('\u{01C7}', &['\u{01C9}']),
+    ('\u{01C8}', &['\u{01C9}']),
+    ('\u{01CA}', &['\u{01CC}']),
+    ('\u{01CB}', &['\u{01CC}']),
+    ('\u{01CD}', &['\u{01CE}']),
+    ('\u{01CF}', &['\u{01D0}']),
+    ('\u{01D1}', &['\u{01D2}']),
+    ('\u{01D3}', &['\u{01D4}']),
+    ('\u{01D5}', &['\u{01D6}']),
+    ('\u{01D7}', &['\u{01D8}']),
+    ('\u{01D9}', &['\u{01DA}']),
+    ('\u{01DB}', &['\u{01DC}']),
+    ('\u{01DE}', &['\u{01DF}']),
+    ('\u{01E0}', &['\u{01E1}']),
+    ('\u{01E2}', &['\u{01E3}']),
+    ('\u{01E4}', &['\u{01E5}']),
+    ('\u{01E6}', &['\u{01E7}']),
+    ('\u{01E8}', &['\u{01E9}']),
+    ('\u{01EA}', &['\u{01EB}']),
+    ('\u{01EC}', &['\u{01ED}']),
+    ('\u{01EE}', &['\u{01EF}']),
+    ('\u{01F0}', &['\u{006A}', '\u{030C}']),
+    ('\u{01F1}', &['\u{01F3}']),
+    ('\u{01F2}', &['\u{01F3}']),
+    ('\u{01F4}', &['\u{01F5}']),
+    ('\u{01F6}', &['\u{0195}']),
+    ('\u{01F7}', &['\u{01BF}']),
+    ('\u{01F8}', &['\u{01F9}']),
+    ('\u{01FA}', &['\u{01FB}']),
+    ('\u{01FC}', &['\u{01FD}']),
+    ('\u{01FE}', &['\u{01FF}']),
+    ('\u{0200}', &['\u{0201}']),
+    ('\u{0202}', &['\u{0203}']),
+    ('\u{0204}', &['\u{0205}']),
+    ('\u{0206}', &['\u{0207}']),
+    ('\u{0208}', &['\u{0209}']),
+    ('\u{020A}', &['\u{020B}']),
+    ('\u{020C}', &['\u{020D}']),
+    ('\u{020E}', &['\u{020F}']),
+    ('\u{0210}', &['\u{0211}']),
+    ('\u{0212}', &['\u{0213}']),
+    ('\u{0214}', &['\u{0215}']),
+    ('\u{0216}', &['\u{0217}']),
+    ('\u{0218}', &['\u{0219}']),
+    ('\u{021A}', &['\u{021B}']),
+    ('\u{021C}', &['\u{021D}']),
+    ('\u{021E}', &['\u{021F}']),
+    ('\u{0220}', &['\u{019E}']),
+    ('\u{0222}', &['\u{0223}']),
+    ('\u{0224}', &['\u{0225}']),
+    ('\u{0226}', &['\u{0227}']),
+    ('\u{0228}', &['\u{0229}']),
+    ('\u{022A}', &['\u{022B}']),
+    ('\u{022C}', &['\u{022D}']),
+    ('\u{022E}', &['\u{022F}']),
+    ('\u{0230}', &['\u{0231}']),
+    ('\u{0232}', &['\u{0233}']),
+    ('\u{023A}', &['\u{2C65}']),
+    ('\u{023B}', &['\u{023C}']),
+    ('\u{023D}', &['\u{019A}']),
+    ('\u{023E}', &['\u{2C66}']),
+    ('\u{0241}', &['\u{0242}']),
+    ('\u{0243}', &['\u{0180}']),
+    ('\u{0244}', &['\u{0289}']),
+    ('\u{0245}', &['\u{028C}']),
+    ('\u{0246}', &['\u{0247}']),
+    ('\u{0248}', &['\u{0249}']),
+    ('\u{024A}', &['\u{024B}']),
+    ('\u{024C}', &['\u{024D}']),
+    ('\u{024E}', &['\u{024F}']),
+    ('\u{0345}', &['\u{03B9}']),
+    ('\u{0370}', &['\u{0371}']),
+    ('\u{0372}', &['\u{0373}']),
+    ('\u{0376}', &['\u{0377}']),
+    ('\u{037F}', &['\u{03F3}']),
+    ('\u{0386}', &['\u{03AC}']),
+    ('\u{0388}', &['\u{03AD}']),
+    ('\u{0389}', &['\u{03AE}']),
+    ('\u{038A}', &['\u{03AF}']),
+    ('\u{038C}', &['\u{03CC}']),
+    ('\u{038E}', &['\u{03CD}']),
+    ('\u{038F}', &['\u{03CE}']),
+    ('\u{0390}', &['\u{03B9}', '\u{0308}', '\u{0301}']),
+    ('\u{0391}', &['\u{03B1}']),
+    ('\u{0392}', &['\u{03B2}']),
+    ('\u{0393}', &['\u{03B3}']),
+    ('\u{0394}', &['\u{03B4}']),
+    ('\u{0395}', &['\u{03B5}']),
+    ('\u{0396}', &['\u{03B6}']),
+    ('\u{0397}', &['\u{03B7}']),
+    ('\u{0398}', &['\u{03B8}']),
+    ('\u{0399}', &['\u{03B9}']),
+    ('\u{039A}', &['\u{03BA}']),
+    ('\u{039B}', &['\u{03BB}']),
+    ('\u{039C}', &['\u{03BC}']),
+    ('\u{039D}', &['\u{03BD}']),
+    ('\u{039E}', &['\u{03BE}']),
+    ('\u{039F}', &['\u{03BF}']),
+    ('\u{03A0}', &['\u{03C0}']),
+    ('\u{03A1}', &['\u{03C1}']),
+    ('\u{03A3}', &['\u{03C3}']),
+    ('\u{03A4}', &['\u{03C4}']),
+    ('\u{03A5}', &['\u{03C5}']),
+    ('\u{03A6}', &['\u{03C6}']),
+    ('\u{03A7}', &['\u{03C7}']),
+    ('\u{03A8}', &['\u{03C8}']),
+    ('\u{03A9}', &['\u{03C9}']),
+    ('\u{03AA}', &['\u{03CA}']),
+    ('\u{03AB}', &['\u{03CB}']),
+    ('\u{03B0}', &['\u{03C5}', '\u{0308}', '\u{0301}']),
+    ('\u{03C2}', &['\u{03C3}']),
+    ('\u{03CF}', &['\u{03D7}']),
+    ('\u{03D0}', &['\u{03B2}']),
+    ('\u{03D1}', &['\u{03B8}']),
+    ('\u{03D5}', &['\u{03C6}']),
+    ('\u{03D6}', &['\u{03C0}']),
+    ('\u{03D8}', &['\u{03D9}']),
+    ('\u{03DA}', &['\u{03DB}']),
+    ('\u{03DC}', &['\u{03DD}']),
+    ('\u{03DE}', &['\u{03DF}']),
+    ('\u{03E0}', &['\u{03E1}']),
+    ('\u{03E2}', &['\u{03E3}']),
+    ('\u{03E4}', &['\u{03E5}']),
+    ('\u{03E6}', &['\u{03E7}']),
+    ('\u{03E8}', &['\u{03E9}']),
+    ('\u{03EA}', &['\u{03EB}']),
+    ('\u{03EC}', &['\u{03ED}']),
+    ('\u{03EE}', &['\u{03EF}']),
+    ('\u{03F0}', &['\u{03BA}']),
+    ('\u{03F1}', &['\u{03C1}']),
+    ('\u{03F4}', &['\u{03B8}']),
+    ('\u{03F5}', &['\u{03B5}']),
+    ('\u{03F7}', &['\u{03F8}']),
+    ('\u{03F9}', &['\u{03F2}']),
+    ('\u{03FA}', &['\u{03FB}']),
+    ('\u{03FD}', &['\u{037B}']),
+    ('\u{03FE}', &['\u{037C}']),
+    ('\u{03FF}', &['\u{037D}']),
+    ('\u{0400}', &['\u{0450}']),
+    ('\u{0401}', &['\u{0451}']),
+    ('\u{0402}', &['\u{0452}']),
+    ('\u{0403}', &['\u{0453}']),
+    ('\u{0404}', &['\u{0454}']),
+    ('\u{0405}', &['\u{0455}']),
+    ('\u{0406}', &['\u{0456}']),
+    ('\u{0407}', &['\u{0457}']),
+    ('\u{0408}', &['\u{0458}']),
+    ('\u{0409}', &['\u{0459}']),
+    ('\u{040A}', &['\u{045A}']),
+    ('\u{040B}', &['\u{045B}']),
+    ('\u{040C}', &['\u{045C}']),
+    ('\u{040D}', &['\u{045D}']),
+    ('\u{040E}', &['\u{045E}']),
+    ('\u{040F}', &['\u{045F}']),
+    ('\u{0410}', &['\u{0430}']),
+    ('\u{0411}', &['\u{0431}']),
+    ('\u{0412}', &['\u{0432}']),
+    ('\u{0413}', &['\u{0433}']),
+    ('\u{0414}', &['\u{0434}']),
+    ('\u{0415}', &['\u{0435}']),
+    ('\u{0416}', &['\u{0436}']),
+    ('\u{0417}', &['\u{0437}']),
+    ('\u{0418}', &['\u{0438}']),
+    ('\u{0419}', &['\u{0439}']),
+    ('\u{041A}', &['\u{043A}']),
+    ('\u{041B}', &['\u{043B}']),
+    ('\u{041C}', &['\u{043C}']),
+    ('\u{041D}', &['\u{043D}']),
+    ('\u{041E}', &['\u{043E}']),
+    ('\u{041F}', &['\u{043F}']),
+    ('\u{0420}', &['\u{0440}']),
+    ('\u{0421}', &['\u{0441}']),
+    ('\u{0422}', &['\u{0442}']),
+    ('\u{0423}', &['\u{0443}']),
+    ('\u{0424}', &['\u{0444}']),
+    ('\u{0425}', &['\u{0445}']),
+    ('\u{0426}', &['\u{0446}']),
+    ('\u{0427}', &['\u{0447}']),
+    ('\u{0428}', &['\u{0448}']),
+    ('\u{0429}', &['\u{0449}']),
+    ('\u{042A}', &['\u{044A}']),
+    ('\u{042B}', &['\u{044B}']),
+    ('\u{042C}', &['\u{044C}']),
+    ('\u{042D}', &['\u{044D}']),
+    ('\u{042E}', &['\u{044E}']),
+    ('\u{042F}', &['\u{044F}']),
+    ('\u{0460}', &['\u{0461}']),
+    ('\u{0462}', &['\u{0463}']),
+    ('\u{0464}', &['\u{0465}']),
+    ('\u{0466}', &['\u{0467}']),
+    ('\u{0468}', &['\u{0469}']),
+    ('\u{046A}', &['\u{046B}']),
+    ('\u{046C}', &['\u{046D}']),
+    ('\u{046E}', &['\u{046F}']),
+    ('\u{0470}', &['\u{0471}']),
+    ('\u{0472}', &['\u{0473}']),
+    ('\u{0474}', &['\u{0475}']),
+    ('\u{0476}', &['\u{0477}']),
+    ('\u{0478}', &['\u{0479}']),
+    ('\u{047A}', &['\u{047B}']),
+    ('\u{047C}', &['\u{047D}']),
+    ('\u{047E}', &['\u{047F}']),
+    ('\u{0480}', &['\u{0481}']),
+    ('\u{048A}', &['\u{048B}']),
+    ('\u{048C}', &['\u{048D}']),
+    ('\u{048E}', &['\u{048F}']),
+    ('\u{0490}', &['\u{0491}']),
+    ('\u{0492}', &['\u{0493}']),
+    ('\u{0494}', &['\u{0495}']),
+    ('\u{0496}', &['\u{0497}']),
+    ('\u{0498}', &['\u{0499}']),
+    ('\u{049A}', &['\u{049B}']),
+    ('\u{049C}', &['\u{049D}']),
+    ('\u{049E}', &['\u{049F}']),
+    ('\u{04A0}', &['\u{04A1}']),
+    ('\u{04A2}', &['\u{04A3}']),
+    ('\u{04A4}', &['\u{04A5}']),
+    ('\u{04A6}', &['\u{04A7}']),
+    ('\u{04A8}', &['\u{04A9}']),
+    ('\u{04AA}', &['\u{04AB}']),
+    ('\u{04AC}', &['\u{04AD}']),
+    ('\u{04AE}', &['\u{04AF}']),
+    ('\u{04B0}', &['\u{04B1}']),
+    ('\u{04B2}', &['\u{04B3}']),
+    ('\u{04B4}', &['\u{04B5}']),
+    ('\u{04B6}', &['\u{04B7}']),
+    ('\u{04B8}', &['\u{04B9}']),
+    ('\u{04BA}', &['\u{04BB}']),
+    ('\u{04BC}', &['\u{04BD}']),
+    ('\u{04BE}', &['\u{04BF}']),
+    ('\u{04C0}', &['\u{04CF}']),
+    ('\u{04C1}', &['\u{04C2}']),
+    ('\u{04C3}', &['\u{04C4}']),
+    ('\u{04C5}', &['\u{04C6}']),
+    ('\u{04C7}', &['\u{04C8}']),
+    ('\u{04C9}', &['\u{04CA}']),
+    ('\u{04CB}', &['\u{04CC}']),
+    ('\u{04CD}', &['\u{04CE}']),
+    ('\u{04D0}', &['\u{04D1}']),
+    ('\u{04D2}', &['\u{04D3}']),
+    ('\u{04D4}', &['\u{04D5}']),
+    ('\u{04D6}', &['\u{04D7}']),
+    ('\u{04D8}', &['\u{04D9}']),
+    ('\u{04DA}', &['\u{04DB}']),
+    ('\u{04DC}', &['\u{04DD}']),
+    ('\u{04DE}', &['\u{04DF}']),
+    ('\u{04E0}', &['\u{04E1}']),
+    ('\u{04E2}', &['\u{04E3}']),
+    ('\u{04E4}', &['\u{04E5}']),
+    ('\u{04E6}', &['\u{04E7}']),
+    ('\u{04E8}', &['\u{04E9}']),
+    ('\u{04EA}', &['\u{04EB}']),
+    ('\u{04EC}', &['\u{04ED}']),
+    ('\u{04EE}', &['\u{04EF}']),
+    ('\u{04F0}', &['\u{04F1}']),
+    ('\u{04F2}', &['\u{04F3}']),
+    ('\u{04F4}', &['\u{04F5}']),
+    ('\u{04F6}', &['\u{04F7}']),
+    ('\u{04F8}', &['\u{04F9}']),
+    ('\u{04FA}', &['\u{04FB}']),
+    ('\u{04FC}', &['\u{04FD}']),
+    ('\u{04FE}', &['\u{04FF}']),
+    ('\u{0500}', &['\u{0501}']),
+    ('\u{0502}', &['\u{0503}']),
+    ('\u{0504}', &['\u{0505}']),
+    ('\u{0506}', &['\u{0507}']),
+    ('\u{0508}', &['\u{0509}']),
+    ('\u{050A}', &['\u{050B}']),
+    ('\u{050C}', &['\u{050D}']),
+    ('\u{050E}', &['\u{050F}']),
+    ('\u{0510}', &['\u{0511}']),
+    ('\u{0512}', &['\u{0513}']),
+    ('\u{0514}', &['\u{0515}']),
+    ('\u{0516}', &['\u{0517}']),
+    ('\u{0518}', &['\u{0519}']),
+    ('\u{051A}', &['\u{051B}']),
+    ('\u{051C}', &['\u{051D}']),
+    ('\u{051E}', &['\u{051F}']),
+    ('\u{0520}', &['\u{0521}']),
+    ('\u{0522}', &['\u{0523}']),
+    ('\u{0524}', &['\u{0525}']),
+    ('\u{0526}', &['\u{0527}']),
+    ('\u{0528}', &['\u{0529}']),
+    ('\u{052A}', &['\u{052B}']),
+    ('\u{052C}', &['\u{052D}']),
+    ('\u{052E}', &['\u{052F}']),
+    ('\u{0531}', &['\u{0561}']),
+    ('\u{0532}', &['\u{0562}']),
+    ('\u{0533}', &['\u{0563}']),
+    ('\u{0534}', &['\u{0564}']),
+    ('\u{0535}', &['\u{0565}']),
+    ('\u{0536}', &['\u{0566}']),
+    ('\u{0537}', &['\u{0567}']),
+    ('\u{0538}', &['\u{0568}']),
+    ('\u{0539}', &['\u{0569}']),
+    ('\u{053A}', &['\u{056A}']),
+    ('\u{053B}', &['\u{056B}']),
+    ('\u{053C}', &['\u{056C}']),
+    ('\u{053D}', &['\u{056D}']),
+    ('\u{053E}', &['\u{056E}']),
+    ('\u{053F}', &['\u{056F}']),
+    ('\u{0540}', &['\u{0570}']),
+    ('\u{0541}', &['\u{0571}']),
+    ('\u{0542}', &['\u{0572}']),
+    ('\u{0543}', &['\u{0573}']),
+    ('\u{0544}', &['\u{0574}']),
+    ('\u{0545}', &['\u{0575}']),
+    ('\u{0546}', &['\u{0576}']),
+    ('\u{0547}', &['\u{0577}']),
+    ('\u{0548}', &['\u{0578}']),
+    ('\u{0549}', &['\u{0579}']),
+    ('\u{054A}', &['\u{057A}']),
+    ('\u{054B}', &['\u{057B}']),
+    ('\u{054C}', &['\u{057C}']),
+    ('\u{054D}', &['\u{057D}']),
+    ('\u{054E}', &['\u{057E}']),
+    ('\u{054F}', &['\u{057F}']),
+    ('\u{0550}', &['\u{0580}']),
+    ('\u{0551}', &['\u{0581}']),
+    ('\u{0552}', &['\u{0582}']),
+    ('\u{0553}', &['\u{0583}']),
+    ('\u{0554}', &['\u{0584}']),
+    ('\u{0555}', &['\u{0585}']),
+    ('\u{0556}', &['\u{0586}']),
+    ('\u{0587}', &['\u{0565}', '\u{0582}']),
+    ('\u{10A0}', &['\u{2D00}']),
+    ('\u{10A1}', &['\u{2D01}']),
+    ('\u{10A2}', &['\u{2D02}']),
+    ('\u{10A3}', &['\u{2D03}']),
+    ('\u{10A4}', &['\u{2D04}']),
+    ('\u{10A5}', &['\u{2D05}']),
+    ('\u{10A6}', &['\u{2D06}']),
+    ('\u{10A7}', &['\u{2D07}']),
+    ('\u{10A8}', &['\u{2D08}']),
+    ('\u{10A9}', &['\u{2D09}']),
+    ('\u{10AA}', &['\u{2D0A}']),
+    ('\u{10AB}', &['\u{2D0B}']),
+    ('\u{10AC}', &['\u{2D0C}']),
+    ('\u{10AD}', &['\u{2D0D}']),
+    ('\u{10AE}', &['\u{2D0E}']),
+    ('\u{10AF}', &['\u{2D0F}']),
+    ('\u{10B0}', &['\u{2D10}']),
+    ('\u{10B1}', &['\u{2D11}']),
+    ('\u{10B2}', &['\u{2D12}']),
+    ('\u{10B3}', &['\u{2D13}']),
+    ('\u{10B4}', &['\u{2D14}']),
+    ('\u{10B5}', &['\u{2D15}']),
+    ('\u{10B6}', &['\u{2D16}']),
+    ('\u{10B7}', &['\u{2D17}']),
+    ('\u{10B8}', &['\u{2D18}']),
+    ('\u{10B9}', &['\u{2D19}']),
+    ('\u{10BA}', &['\u{2D1A}']),
+    ('\u{10BB}', &['\u{2D1B}']),
+    ('\u{10BC}', &['\u{2D1C}']),
+    ('\u{10BD}', &['\u{2D1D}']),
+    ('\u{10BE}', &['\u{2D1E}']),
+    ('\u{10BF}', &['\u{2D1F}']),
+    ('\u{10C0}', &['\u{2D20}']),
+    ('\u{10C1}', &['\u{2D21}']),
+    ('\u{10C2}', &['\u{2D22}']),
+    ('\u{10C3}', &['\u{2D23}']),
+    ('\u{10C4}', &['\u{2D24}']),
+    ('\u{10C5}', &['\u{2D25}']),
+    ('\u{10C7}', &['\u{2D27}']),
+    ('\u{10CD}', &['\u{2D2D}']),
+    ('\u{13F8}', &['\u{13F0}']),
+    ('\u{13F9}', &['\u{13F1}']),
+    ('\u{13FA}', &['\u{13F2}']),
+    ('\u{13FB}', &['\u{13F3}']),
+    ('\u{13FC}', &['\u{13F4}']),
+    ('\u{13FD}', &['\u{13F5}']),
+    ('\u{1C80}', &['\u{0432}']),
+    ('\u{1C81}', &['\u{0434}']),
+    ('\u{1C82}', &['\u{043E}']),
+    ('\u{1C83}', &['\u{0441}']),
+    ('\u{1C84}', &['\u{0442}']),
+    ('\u{1C85}', &['\u{0442}']),
+    ('\u{1C86}', &['\u{044A}']),
+    ('\u{1C87}', &['\u{0463}']),
+    ('\u{1C88}', &['\u{A64B}']),
+    ('\u{1C90}', &['\u{10D0}']),
+    ('\u{1C91}', &['\u{10D1}']),
+    ('\u{1C92}', &['\u{10D2}']),
+    ('\u{1C93}', &['\u{10D3}']),
+    ('\u{1C94}', &['\u{10D4}']),
+    ('\u{1C95}', &['\u{10D5}']),
+    ('\u{1C96}', &['\u{10D6}']),
+    ('\u{1C97}', &['\u{10D7}']),
+    ('\u{1C98}', &['\u{10D8}']),
+    ('\u{1C99}', &['\u{10D9}']),
+    ('\u{1C9A}', &['\u{10DA}']),
+    ('\u{1C9B}', &['\u{10DB}']),
+    ('\u{1C9C}', &['\u{10DC}']),
+    ('\u{1C9D}', &['\u{10DD}']),
+    ('\u{1C9E}', &['\u{10DE}']),
+    ('\u{1C9F}', &['\u{10DF}']),
+    ('\u{1CA0}', &['\u{10E0}']),
+    ('\u{1CA1}', &['\u{10E1}']),
+    ('\u{1CA2}', &['\u{10E2}']),
+    ('\u{1CA3}', &['\u{10E3}']),
+    ('\u{1CA4}', &['\u{10E4}']),
+    ('\u{1CA5}', &['\u{10E5}']),
+    ('\u{1CA6}', &['\u{10E6}']),
+    ('\u{1CA7}', &['\u{10E7}']),
+    ('\u{1CA8}', &['\u{10E8}']),
+    ('\u{1CA9}', &['\u{10E9}']),
+    ('\u{1CAA}', &['\u{10EA}']),
+    ('\u{1CAB}', &['\u{10EB}']),
+    ('\u{1CAC}', &['\u{10EC}']),
+    ('\u{1CAD}', &['\u{10ED}']),
+    ('\u{1CAE}', &['\u{10EE}']),
+    ('\u{1CAF}', &['\u{10EF}']),
+    ('\u{1CB0}', &['\u{10F0}']),
+    ('\u{1CB1}', &['\u{10F1}']),
+    ('\u{1CB2}', &['\u{10F2}']),
+    ('\u{1CB3}', &['\u{10F3}']),
+    ('\u{1CB4}', &['\u{10F4}']),
+    ('\u{1CB5}', &['\u{10F5}']),
+    ('\u{1CB6}', &['\u{10F6}']),
+    ('\u{1CB7}', &['\u{10F7}']),
+    ('\u{1CB8}', &['\u{10F8}']),
+    ('\u{1CB9}', &['\u{10F9}']),
+    ('\u{1CBA}', &['\u{10FA}']),
+    ('\u{1CBD}', &['\u{10FD}']),
+    ('\u{1CBE}', &['\u{10FE}']),
+    ('\u{1CBF}', &['\u{10FF}']),
+    ('\u{1E00}', &['\u{1E01}']),
+    ('\u{1E02}', &['\u{1E03}']),
+    ('\u{1E04}', &['\u{1E05}']),
+    ('\u{1E06}', &['\u{1E07}']),
+    ('\u{1E08}', &['\u{1E09}']),
+    ('\u{1E0A}', &['\u{1E0B}']),
+    ('\u{1E0C}', &['\u{1E0D}']),
+    ('\u{1E0E}', &['\u{1E0F}']),
+    ('\u{1E10}', &['\u{1E11}']),
+    ('\u{1E12}', &['\u{1E13}']),
+    ('\u{1E14}', &['\u{1E15}']),
+    ('\u{1E16}', &['\u{1E17}']),
+    ('\u{1E18}', &['\u{1E19}']),
+    ('\u{1E1A}', &['\u{1E1B}']),
+    ('\u{1E1C}', &['\u{1E1D}']),
+    ('\u{1E1E}', &['\u{1E1F}']),
+    ('\u{1E20}', &['\u{1E21}']),
+    ('\u{1E22}', &['\u{1E23}']),
+    ('\u{1E24}', &['\u{1E25}']),
+    ('\u{1E26}', &['\u{1E27}']),
+    ('\u{1E28}', &['\u{1E29}']),
+    ('\u{1E2A}', &['\u{1E2B}']),
+    ('\u{1E2C}', &['\u{1E2D}']),
+    ('\u{1E2E}', &['\u{1E2F}']),
+    ('\u{1E30}', &['\u{1E31}']),
+    ('\u{1E32}', &['\u{1E33}']),
+    ('\u{1E34}', &['\u{1E35}']),
+    ('\u{1E36}', &['\u{1E37}']),
+    ('\u{1E38}', &['\u{1E39}']),
+    ('\u{1E3A}', &['\u{1E3B}']),
+    ('\u{1E3C}', &['\u{1E3D}']),
+    ('\u{1E3E}', &['\u{1E3F}']),
+    ('\u{1E40}', &['\u{1E41}']),
+    ('\u{1E42}', &['\u{1E43}']),
+    ('\u{1E44}', &['\u{1E45}']),
+    ('\u{1E46}', &['\u{1E47}']),
+    ('\u{1E48}', &['\u{1E49}']),
+    ('\u{1E4A}', &['\u{1E4B}']),
+    ('\u{1E4C}', &['\u{1E4D}']),
+    ('\u{1E4E}', &['\u{1E4F}']),
+    ('\u{1E50}', &['\u{1E51}']),
+    ('\u{1E52}', &['\u{1E53}']),
+    ('\u{1E54}', &['\u{1E55}']),
+    ('\u{1E56}', &['\u{1E57}']),
+    ('\u{1E58}', &['\u{1E59}']),
+    ('\u{1E5A}', &['\u{1E5B}']),
+    ('\u{1E5C}', &['\u{1E5D}']),
+    ('\u{1E5E}', &['\u{1E5F}']),
+    ('\u{1E60}', &['\u{1E61}']),
+    ('\u{1E62}', &['\u{1E63}']),
+    ('\u{1E64}', &['\u{1E65}']),
+    ('\u{1E66}', &['\u{1E67}']),
+    ('\u{1E68}', &['\u{1E69}']),
+    ('\u{1E6A}', &['\u{1E6B}']),
+    ('\u{1E6C}', &['\u{1E6D}']),
+    ('\u{1E6E}', &['\u{1E6F}']),
+    ('\u{1E70}', &['\u{1E71}']),
+    ('\u{1E72}', &['\u{1E73}']),
+    ('\u{1E74}', &['\u{1E75}']),
+    ('\u{1E76}', &['\u{1E77}']),
+    ('\u{1E78}', &['\u{1E79}']),
+    ('\u{1E7A}', &['\u{1E7B}']),
+    ('\u{1E7C}', &['\u{1E7D}']),
+    ('\u{1E7E}', &['\u{1E7F}']),
+    ('\u{1E80}', &['\u{1E81}']),
+    ('\u{1E82}', &['\u{1E83}']),
+    ('\u{1E84}', &['\u{1E85}']),
+    ('\u{1E86}', &['\u{1E87}']),
+    ('\u{1E88}', &['\u{1E89}']),
+    ('\u{1E8A}', &['\u{1E8B}']),
+    ('\u{1E8C}', &['\u{1E8D}']),
+    ('\u{1E8E}', &['\u{1E8F}']),
+    ('\u{1E90}', &['\u{1E91}']),
+    ('\u{1E92}', &['\u{1E93}']),
+    ('\u{1E94}', &['\u{1E95}']),
+    ('\u{1E96}', &['\u{0068}', '\u{0331}']),
+    ('\u{1E97}', &['\u{0074}', '\u{0308}']),
+    ('\u{1E98}', &['\u{0077}', '\u{030A}']),
+    ('\u{1E99}', &['\u{0079}', '\u{030A}']),
+    ('\u{1E9A}', &['\u{0061}', '\u{02BE}']),
+    ('\u{1E9B}', &['\u{1E61}']),
+    ('\u{1E9E}', &['\u{0073}', '\u{0073}']),
+    ('\u{1EA0}', &['\u{1EA1}']),
+    ('\u{1EA2}', &['\u{1EA3}']),
+    ('\u{1EA4}', &['\u{1EA5}']),
+    ('\u{1EA6}', &['\u{1EA7}']),
+    ('\u{1EA8}', &['\u{1EA9}']),
+    ('\u{1EAA}', &['\u{1EAB}']),
+    ('\u{1EAC}', &['\u{1EAD}']),
+    ('\u{1EAE}', &['\u{1EAF}']),
+    ('\u{1EB0}', &['\u{1EB1}']),
+    ('\u{1EB2}', &['\u{1EB3}']),
+    ('\u{1EB4}', &['\u{1EB5}']),
+    ('\u{1EB6}', &['\u{1EB7}']),
+    ('\u{1EB8}', &['\u{1EB9}']),
+    ('\u{1EBA}', &['\u{1EBB}']),
+    ('\u{1EBC}', &['\u{1EBD}']),
+    ('\u{1EBE}', &['\u{1EBF}']),
+    ('\u{1EC0}', &['\u{1EC1}']),
+    ('\u{1EC2}', &['\u{1EC3}']),
+    ('\u{1EC4}', &['\u{1EC5}']),
+    ('\u{1EC6}', &['\u{1EC7}']),
+    ('\u{1EC8}', &['\u{1EC9}']),
+    ('\u{1ECA}', &['\u{1ECB}']),
+    ('\u{1ECC}', &['\u{1ECD}']),
+    ('\u{1ECE}', &['\u{1ECF}']),
+    ('\u{1ED0}', &['\u{1ED1}']),
+    ('\u{1ED2}', &['\u{1ED3}']),
+    ('\u{1ED4}', &['\u{1ED5}']),
+    ('\u{1ED6}', &['\u{1ED7}']),
+    ('\u{1ED8}', &['\u{1ED9}']),
+    ('\u{1EDA}', &['\u{1EDB}']),
+    ('\u{1EDC}', &['\u{1EDD}']),
+    ('\u{1EDE}', &['\u{1EDF}']),
+    ('\u{1EE0}', &['\u{1EE1}']),
+    ('\u{1EE2}', &['\u{1EE3}']),
+    ('\u{1EE4}', &['\u{1EE5}']),
+    ('\u{1EE6}', &['\u{1EE7}']),
+    ('\u{1EE8}', &['\u{1EE9}']),
+    ('\u{1EEA}', &['\u{1EEB}']),
+    ('\u{1EEC}', &['\u{1EED}']),
+    ('\u{1EEE}', &['\u{1EEF}']),
+    ('\u{1EF0}', &['\u{1EF1}']),
+    ('\u{1EF2}', &['\u{1EF3}']),
+    ('\u{1EF4}', &['\u{1EF5}']),
+    ('\u{1EF6}', &['\u{1EF7}']),
+    ('\u{1EF8}', &['\u{1EF9}']),
+    ('\u{1EFA}', &['\u{1EFB}']),
+    ('\u{1EFC}', &['\u{1EFD}']),
+    ('\u{1EFE}', &['\u{1EFF}']),
+    ('\u{1F08}', &['\u{1F00}']),
+    ('\u{1F09}', &['\u{1F01}']),
+    ('\u{1F0A}', &['\u{1F02}']),
+    ('\u{1F0B}', &['\u{1F03}']),
+    ('\u{1F0C}', &['\u{1F04}']),
+    ('\u{1F0D}', &['\u{1F05}']),
+    ('\u{1F0E}', &['\u{1F06}']),
+    ('\u{1F0F}', &['\u{1F07}']),
+    ('\u{1F18}', &['\u{1F10}']),
+    ('\u{1F19}', &['\u{1F11}']),
+    ('\u{1F1A}', &['\u{1F12}']),
+    ('\u{1F1B}', &['\u{1F13}']),
+    ('\u{1F1C}', &['\u{1F14}']),
+    ('\u{1F1D}', &['\u{1F15}']),
+    ('\u{1F28}', &['\u{1F20}']),
+    ('\u{1F29}', &['\u{1F21}']),
+    ('\u{1F2A}', &['\u{1F22}']),
+    ('\u{1F2B}', &['\u{1F23}']),
+    ('\u{1F2C}', &['\u{1F24}']),
+    ('\u{1F2D}', &['\u{1F25}']),
+    ('\u{1F2E}', &['\u{1F26}']),
+    ('\u{1F2F}', &['\u{1F27}']),
+    ('\u{1F38}', &['\u{1F30}']),
+    ('\u{1F39}', &['\u{1F31}']),
+    ('\u{1F3A}', &['\u{1F32}']),
+    ('\u{1F3B}', &['\u{1F33}']),
+    ('\u{1F3C}', &['\u{1F34}']),
+    ('\u{1F3D}', &['\u{1F35}']),
+    ('\u{1F3E}', &['\u{1F36}']),
+    ('\u{1F3F}', &['\u{1F37}']),
+    ('\u{1F48}', &['\u{1F40}']),
+    ('\u{1F49}', &['\u{1F41}']),
+    ('\u{1F4A}', &['\u{1F42}']),
+    ('\u{1F4B}', &['\u{1F43}']),
+    ('\u{1F4C}', &['\u{1F44}']),
+    ('\u{1F4D}', &['\u{1F45}']),
+    ('\u{1F50}', &['\u{03C5}', '\u{0313}']),
+    ('\u{1F52}', &['\u{03C5}', '\u{0313}', '\u{0300}']),
+    ('\u{1F54}', &['\u{03C5}', '\u{0313}', '\u{0301}']),
+    ('\u{1F56}', &['\u{03C5}', '\u{0313}', '\u{0342}']),
+    ('\u{1F59}', &['\u{1F51}']),
+    ('\u{1F5B}', &['\u{1F53}']),
+    ('\u{1F5D}', &['\u{1F55}']),
+    ('\u{1F5F}', &['\u{1F57}']),
+    ('\u{1F68}', &['\u{1F60}']),
+    ('\u{1F69}', &['\u{1F61}']),
+    ('\u{1F6A}', &['\u{1F62}']),
+    ('\u{1F6B}', &['\u{1F63}']),
+    ('\u{1F6C}', &['\u{1F64}']),
+    ('\u{1F6D}', &['\u{1F65}']),
+    ('\u{1F6E}', &['\u{1F66}']),
+    ('\u{1F6F}', &['\u{1F67}']),
+    ('\u{1F80}', &['\u{1F00}', '\u{03B9}']),
+    ('\u{1F81}', &['\u{1F01}', '\u{03B9}']),
+    ('\u{1F82}', &['\u{1F02}', '\u{03B9}']),
+    ('\u{1F83}', &['\u{1F03}', '\u{03B9}']),
+    ('\u{1F84}', &['\u{1F04}', '\u{03B9}']),
+    ('\u{1F85}', &['\u{1F05}', '\u{03B9}']),
+    ('\u{1F86}', &['\u{1F06}', '\u{03B9}']),
+    ('\u{1F87}', &['\u{1F07}', '\u{03B9}']),
+    ('\u{1F88}', &['\u{1F00}', '\u{03B9}']),
+    ('\u{1F89}', &['\u{1F01}', '\u{03B9}']),
+    ('\u{1F8A}', &['\u{1F02}', '\u{03B9}']),
+    ('\u{1F8B}', &['\u{1F03}', '\u{03B9}']),
+    ('\u{1F8C}', &['\u{1F04}', '\u{03B9}']),
+    ('\u{1F8D}', &['\u{1F05}', '\u{03B9}']),
+    ('\u{1F8E}', &['\u{1F06}', '\u{03B9}']),
+    ('\u{1F8F}', &['\u{1F07}', '\u{03B9}']),
+    ('\u{1F90}', &['\u{1F20}', '\u{03B9}']),
+    ('\u{1F91}', &['\u{1F21}', '\u{03B9}']),
+    ('\u{1F92}', &['\u{1F22}', '\u{03B9}']),
+    ('\u{1F93}', &['\u{1F23}', '\u{03B9}']),
+    ('\u{1F94}', &['\u{1F24}', '\u{03B9}']),
+    ('\u{1F95}', &['\u{1F25}', '\u{03B9}']),
+    ('\u{1F96}', &['\u{1F26}', '\u{03B9}']),
+    ('\u{1F97}', &['\u{1F27}', '\u{03B9}']),
+    ('\u{1F98}', &['\u{1F20}', '\u{03B9}']),
+    ('\u{1F99}', &['\u{1F21}', '\u{03B9}']),
+    ('\u{1F9A}', &['\u{1F22}', '\u{03B9}']),
+    ('\u{1F9B}', &['\u{1F23}', '\u{03B9}']),
+    ('\u{1F9C}', &['\u{1F24}', '\u{03B9}']),
+    ('\u{1F9D}', &['\u{1F25}', '\u{03B9}']),
+    ('\u{1F9E}', &['\u{1F26}', '\u{03B9}']),
+    ('\u{1F9F}', &['\u{1F27}', '\u{03B9}']),
+    ('\u{1FA0}', &['\u{1F60}', '\u{03B9}']),
+    ('\u{1FA1}', &['\u{1F61}', '\u{03B9}']),
+    ('\u{1FA2}', &['\u{1F62}', '\u{03B9}']),
+    ('\u{1FA3}', &['\u{1F63}', '\u{03B9}']),
+    ('\u{1FA4}', &['\u{1F64}', '\u{03B9}']),
+    ('\u{1FA5}', &['\u{1F65}', '\u{03B9}']),
+    ('\u{1FA6}', &['\u{1F66}', '\u{03B9}']),
+    ('\u{1FA7}', &['\u{1F67}', '\u{03B9}']),
+    ('\u{1FA8}', &['\u{1F60}', '\u{03B9}']),
+    ('\u{1FA9}', &['\u{1F61}', '\u{03B9}']),
+    ('\u{1FAA}', &['\u{1F62}', '\u{03B9}']),
+    ('\u{1FAB}', &['\u{1F63}', '\u{03B9}']),
+    ('\u{1FAC}', &['\u{1F64}', '\u{03B9}']),
+    ('\u{1FAD}', &['\u{1F65}', '\u{03B9}']),
+    ('\u{1FAE}', &['\u{1F66}', '\u{03B9}']),
+    ('\u{1FAF}', &['\u{1F67}', '\u{03B9}']),
+    ('\u{1FB2}', &['\u{1F70}', '\u{03B9}']),
+    ('\u{1FB3}', &['\u{03B1}', '\u{03B9}']),
+    ('\u{1FB4}', &['\u{03AC}', '\u{03B9}']),
+    ('\u{1FB6}', &['\u{03B1}', '\u{0342}']),
+    ('\u{1FB7}', &['\u{03B1}', '\u{0342}', '\u{03B9}']),
+    ('\u{1FB8}', &['\u{1FB0}']),
+    ('\u{1FB9}', &['\u{1FB1}']),
+    ('\u{1FBA}', &['\u{1F70}']),
+    ('\u{1FBB}', &['\u{1F71}']),
+    ('\u{1FBC}', &['\u{03B1}', '\u{03B9}']),
+    ('\u{1FBE}', &['\u{03B9}']),
+    ('\u{1FC2}', &['\u{1F74}', '\u{03B9}']),
+    ('\u{1FC3}', &['\u{03B7}', '\u{03B9}']),
+    ('\u{1FC4}', &['\u{03AE}', '\u{03B9}']),
+    ('\u{1FC6}', &['\u{03B7}', '\u{0342}']),
+    ('\u{1FC7}', &['\u{03B7}', '\u{0342}', '\u{03B9}']),
+    ('\u{1FC8}', &['\u{1F72}']),
+    ('\u{1FC9}', &['\u{1F73}']),
+    ('\u{1FCA}', &['\u{1F74}']),
+    ('\u{1FCB}', &['\u{1F75}']),
+    ('\u{1FCC}', &['\u{03B7}', '\u{03B9}']),
+    ('\u{1FD2}', &['\u{03B9}', '\u{0308}', '\u{0300}']),
+    ('\u{1FD3}', &['\u{03B9}', '\u{0308}', '\u{0301}']),
+    ('\u{1FD6}', &['\u{03B9}', '\u{0342}']),
+    ('\u{1FD7}', &['\u{03B9}', '\u{0308}', '\u{0342}']),
+    ('\u{1FD8}', &['\u{1FD0}']),
+    ('\u{1FD9}', &['\u{1FD1}']),
+    ('\u{1FDA}', &['\u{1F76}']),
+    ('\u{1FDB}', &['\u{1F77}']),
+    ('\u{1FE2}', &['\u{03C5}', '\u{0308}', '\u{0300}']),
+    ('\u{1FE3}', &['\u{03C5}', '\u{0308}', '\u{0301}']),
+    ('\u{1FE4}', &['\u{03C1}', '\u{0313}']),
+    ('\u{1FE6}', &['\u{03C5}', '\u{0342}']),
+    ('\u{1FE7}', &['\u{03C5}', '\u{0308}', '\u{0342}']),
+    ('\u{1FE8}', &['\u{1FE0}']),
+    ('\u{1FE9}', &['\u{1FE1}']),
+    ('\u{1FEA}', &['\u{1F7A}']),
+    ('\u{1FEB}', &['\u{1F7B}']),
+    ('\u{1FEC}', &['\u{1FE5}']),
+    ('\u{1FF2}', &['\u{1F7C}', '\u{03B9}']),
+    ('\u{1FF3}', &['\u{03C9}', '\u{03B9}']),
+    ('\u{1FF4}', &['\u{03CE}', '\u{03B9}']),
+    ('\u{1FF6}', &['\u{03C9}', '\u{0342}']),
+    ('\u{1FF7}', &['\u{03C9}', '\u{0342}', '\u{03B9}']),
+    ('\u{1FF8}', &['\u{1F78}']),
+    ('\u{1FF9}', &['\u{1F79}']),
+    ('\u{1FFA}', &['\u{1F7C}']),
+    ('\u{1FFB}', &['\u{1F7D}']),
+    ('\u{1FFC}', &['\u{03C9}', '\u{03B9}']),
+    ('\u{2126}', &['\u{03C9}']),
+    ('\u{212A}', &['\u{006B}']),
+    ('\u{212B}', &['\u{00E5}']),
+    ('\u{2132}', &['\u{214E}']),
+    ('\u{2160}', &['\u{2170}']),
+    ('\u{2161}', &['\u{2171}']),
+    ('\u{2162}', &['\u{2172}']),
+    ('\u{2163}', &['\u{2173}']),
+    ('\u{2164}', &['\u{2174}']),
+    ('\u{2165}', &['\u{2175}']),
+    ('\u{2166}', &['\u{2176}']),
+    ('\u{2167}', &['\u{2177}']),
+    ('\u{2168}', &['\u{2178}']),
+    ('\u{2169}', &['\u{2179}']),
+    ('\u{216A}', &['\u{217A}']),
+    ('\u{216B}', &['\u{217B}']),
+    ('\u{216C}', &['\u{217C}']),
+    ('\u{216D}', &['\u{217D}']),
+    ('\u{216E}', &['\u{217E}']),
+    ('\u{216F}', &['\u{217F}']),
+    ('\u{2183}', &['\u{2184}']),
+    ('\u{24B6}', &['\u{24D0}']),
+    ('\u{24B7}', &['\u{24D1}']),
+    ('\u{24B8}', &['\u{24D2}']),
+    ('\u{24B9}', &['\u{24D3}']),
+    ('\u{24BA}', &['\u{24D4}']),
+    ('\u{24BB}', &['\u{24D5}']),
+    ('\u{24BC}', &['\u{24D6}']),
+    ('\u{24BD}', &['\u{24D7}']),
+    ('\u{24BE}', &['\u{24D8}']),
+    ('\u{24BF}', &['\u{24D9}']),
+    ('\u{24C0}', &['\u{24DA}']),
+    ('\u{24C1}', &['\u{24DB}']),
+    ('\u{24C2}', &['\u{24DC}']),
+    ('\u{24C3}', &['\u{24DD}']),
+    ('\u{24C4}', &['\u{24DE}']),
+    ('\u{24C5}', &['\u{24DF}']),
+    ('\u{24C6}', &['\u{24E0}']),
+    ('\u{24C7}', &['\u{24E1}']),
+    ('\u{24C8}', &['\u{24E2}']),
+    ('\u{24C9}', &['\u{24E3}']),
+    ('\u{24CA}', &['\u{24E4}']),
+    ('\u{24CB}', &['\u{24E5}']),
+    ('\u{24CC}', &['\u{24E6}']),
+    ('\u{24CD}', &['\u{24E7}']),
+    ('\u{24CE}', &['\u{24E8}']),
+    ('\u{24CF}', &['\u{24E9}']),
+    ('\u{2C00}', &['\u{2C30}']),
+    ('\u{2C01}', &['\u{2C31}']),
+    ('\u{2C02}', &['\u{2C32}']),
+    ('\u{2C03}', &['\u{2C33}']),
+    ('\u{2C04}', &['\u{2C34}']),
+    ('\u{2C05}', &['\u{2C35}']),
+    ('\u{2C06}', &['\u{2C36}']),
+    ('\u{2C07}', &['\u{2C37}']),
+    ('\u{2C08}', &['\u{2C38}']),
+    ('\u{2C09}', &['\u{2C39}']),
+    ('\u{2C0A}', &['\u{2C3A}']),
+    ('\u{2C0B}', &['\u{2C3B}']),
+    ('\u{2C0C}', &['\u{2C3C}']),
+    ('\u{2C0D}', &['\u{2C3D}']),
+    ('\u{2C0E}', &['\u{2C3E}']),
+    ('\u{2C0F}', &['\u{2C3F}']),
+    ('\u{2C10}', &['\u{2C40}']),
+    ('\u{2C11}', &['\u{2C41}']),
+    ('\u{2C12}', &['\u{2C42}']),
+    ('\u{2C13}', &['\u{2C43}']),
+    ('\u{2C14}', &['\u{2C44}']),
+    ('\u{2C15}', &['\u{2C45}']),
+    ('\u{2C16}', &['\u{2C46}']),
+    ('\u{2C17}', &['\u{2C47}']),
+    ('\u{2C18}', &['\u{2C48}']),
+    ('\u{2C19}', &['\u{2C49}']),
+    ('\u{2C1A}', &['\u{2C4A}']),
+    ('\u{2C1B}', &['\u{2C4B}']),
+    ('\u{2C1C}', &['\u{2C4C}']),
+    ('\u{2C1D}', &['\u{2C4D}']),
+    ('\u{2C1E}', &['\u{2C4E}']),
+    ('\u{2C1F}', &['\u{2C4F}']),
+    ('\u{2C20}', &['\u{2C50}']),
+    ('\u{2C21}', &['\u{2C51}']),
+    ('\u{2C22}', &['\u{2C52}']),
+    ('\u{2C23}', &['\u{2C53}']),
+    ('\u{2C24}', &['\u{2C54}']),
+    ('\u{2C25}', &['\u{2C55}']),
+    ('\u{2C26}', &['\u{2C56}']),
+    ('\u{2C27}', &['\u{2C57}']),
+    ('\u{2C28}', &['\u{2C58}']),
+    ('\u{2C29}', &['\u{2C59}']),
+    ('\u{2C2A}', &['\u{2C5A}']),
+    ('\u{2C2B}', &['\u{2C5B}']),
+    ('\u{2C2C}', &['\u{2C5C}']),
+    ('\u{2C2D}', &['\u{2C5D}']),
+    ('\u{2C2E}', &['\u{2C5E}']),
+    ('\u{2C60}', &['\u{2C61}']),
+    ('\u{2C62}', &['\u{026B}']),
+    ('\u{2C63}', &['\u{1D7D}']),
+    ('\u{2C64}', &['\u{027D}']),
+    ('\u{2C67}', &['\u{2C68}']),
+    ('\u{2C69}', &['\u{2C6A}']),
+    ('\u{2C6B}', &['\u{2C6C}']),
+    ('\u{2C6D}', &['\u{0251}']),
+    ('\u{2C6E}', &['\u{0271}']),
+    ('\u{2C6F}', &['\u{0250}']),
+    ('\u{2C70}', &['\u{0252}']),
+    ('\u{2C72}', &['\u{2C73}']),
+    ('\u{2C75}', &['\u{2C76}']),
+    ('\u{2C7E}', &['\u{023F}']),
+    ('\u{2C7F}', &['\u{0240}']),
+    ('\u{2C80}', &['\u{2C81}']),
+    ('\u{2C82}', &['\u{2C83}']),
+    ('\u{2C84}', &['\u{2C85}']),
+    ('\u{2C86}', &['\u{2C87}']),
+    ('\u{2C88}', &['\u{2C89}']),
+    ('\u{2C8A}', &['\u{2C8B}']),
+    ('\u{2C8C}', &['\u{2C8D}']),
+    ('\u{2C8E}', &['\u{2C8F}']),
+    ('\u{2C90}', &['\u{2C91}']),
+    ('\u{2C92}', &['\u{2C93}']),
+    ('\u{2C94}', &['\u{2C95}']),
+    ('\u{2C96}', &['\u{2C97}']),
+    ('\u{2C98}', &['\u{2C99}']),
+    ('\u{2C9A}', &['\u{2C9B}']),
+    ('\u{2C9C}', &['\u{2C9D}']),
+    ('\u{2C9E}', &['\u{2C9F}']),
+    ('\u{2CA0}', &['\u{2CA1}']),
+    ('\u{2CA2}', &['\u{2CA3}']),
+    ('\u{2CA4}', &['\u{2CA5}']),
+    ('\u{2CA6}', &['\u{2CA7}']),
+    ('\u{2CA8}', &['\u{2CA9}']),
+    ('\u{2CAA}', &['\u{2CAB}']),
+    ('\u{2CAC}', &['\u{2CAD}']),
+    ('\u{2CAE}', &['\u{2CAF}']),
+    ('\u{2CB0}', &['\u{2CB1}']),
+    ('\u{2CB2}', &['\u{2CB3}']),
+    ('\u{2CB4}', &['\u{2CB5}']),
+    ('\u{2CB6}', &['\u{2CB7}']),
+    ('\u{2CB8}', &['\u{2CB9}']),
+    ('\u{2CBA}', &['\u{2CBB}']),
+    ('\u{2CBC}', &['\u{2CBD}']),
+    ('\u{2CBE}', &['\u{2CBF}']),
+    ('\u{2CC0}', &['\u{2CC1}']),
+    ('\u{2CC2}', &['\u{2CC3}']),
+    ('\u{2CC4}', &['\u{2CC5}']),
+    ('\u{2CC6}', &['\u{2CC7}']),
+    ('\u{2CC8}', &['\u{2CC9}']),
+    ('\u{2CCA}', &['\u{2CCB}']),
+    ('\u{2CCC}', &['\u{2CCD}']),
+    ('\u{2CCE}', &['\u{2CCF}']),
+    ('\u{2CD0}', &['\u{2CD1}']),
+    ('\u{2CD2}', &['\u{2CD3}']),
+    ('\u{2CD4}', &['\u{2CD5}']),
+    ('\u{2CD6}', &['\u{2CD7}']),
+    ('\u{2CD8}', &['\u{2CD9}']),
+    ('\u{2CDA}', &['\u{2CDB}']),
+    ('\u{2CDC}', &['\u{2CDD}']),
+    ('\u{2CDE}', &['\u{2CDF}']),
+    ('\u{2CE0}', &['\u{2CE1}']),
+    ('\u{2CE2}', &['\u{2CE3}']),
+    ('\u{2CEB}', &['\u{2CEC}']),
+    ('\u{2CED}', &['\u{2CEE}']),
+    ('\u{2CF2}', &['\u{2CF3}']),
+    ('\u{A640}', &['\u{A641}']),
+    ('\u{A642}', &['\u{A643}']),
+    ('\u{A644}', &['\u{A645}']),
+    ('\u{A646}', &['\u{A647}']),
+    ('\u{A648}', &['\u{A649}']),
+    ('\u{A64A}', &['\u{A64B}']),
+    ('\u{A64C}', &['\u{A64D}']),
+    ('\u{A64E}', &['\u{A64F}']),
+    ('\u{A650}', &['\u{A651}']),
+    ('\u{A652}', &['\u{A653}']),
+    ('\u{A654}', &['\u{A655}']),
+    ('\u{A656}', &['\u{A657}']),
+    ('\u{A658}', &['\u{A659}']),
+    ('\u{A65A}', &['\u{A65B}']),
+    ('\u{A65C}', &['\u{A65D}']),
+    ('\u{A65E}', &['\u{A65F}']),
+    ('\u{A660}', &['\u{A661}']),
+    ('\u{A662}', &['\u{A663}']),
+    ('\u{A664}', &['\u{A665}']),
+    ('\u{A666}', &['\u{A667}']),
+    ('\u{A668}', &['\u{A669}']),
+    ('\u{A66A}', &['\u{A66B}']),
+    ('\u{A66C}', &['\u{A66D}']),
+    ('\u{A680}', &['\u{A681}']),
+    ('\u{A682}', &['\u{A683}']),
+    ('\u{A684}', &['\u{A685}']),
+    ('\u{A686}', &['\u{A687}']),
+    ('\u{A688}', &['\u{A689}']),
+    ('\u{A68A}', &['\u{A68B}']),
+    ('\u{A68C}', &['\u{A68D}']),
+    ('\u{A68E}', &['\u{A68F}']),
+    ('\u{A690}', &['\u{A691}']),
+    ('\u{A692}', &['\u{A693}']),
+    ('\u{A694}', &['\u{A695}']),
+    ('\u{A696}', &['\u{A697}']),
+    ('\u{A698}', &['\u{A699}']),
+    ('\u{A69A}', &['\u{A69B}']),
+    ('\u{A722}', &['\u{A723}']),
+    ('\u{A724}', &['\u{A725}']),
+    ('\u{A726}', &['\u{A727}']),
+    ('\u{A728}', &['\u{A729}']),
+    ('\u{A72A}', &['\u{A72B}']),
+    ('\u{A72C}', &['\u{A72D}']),
+    ('\u{A72E}', &['\u{A72F}']),
+    ('\u{A732}', &['\u{A733}']),
+    ('\u{A734}', &['\u{A735}']),
+    ('\u{A736}', &['\u{A737}']),
+    ('\u{A738}', &['\u{A739}']),
+    ('\u{A73A}', &['\u{A73B}']),
+    ('\u{A73C}', &['\u{A73D}']),
+    ('\u{A73E}', &['\u{A73F}']),
+    ('\u{A740}', &['\u{A741}']),
+    ('\u{A742}', &['\u{A743}']),
+    ('\u{A744}', &['\u{A745}']),
+    ('\u{A746}', &['\u{A747}']),
+    ('\u{A748}', &['\u{A749}']),
+    ('\u{A74A}', &['\u{A74B}']),
+    ('\u{A74C}', &['\u{A74D}']),
+    ('\u{A74E}', &['\u{A74F}']),
+    ('\u{A750}', &['\u{A751}']),
+    ('\u{A752}', &['\u{A753}']),
+    ('\u{A754}', &['\u{A755}']),
+    ('\u{A756}', &['\u{A757}']),
+    ('\u{A758}', &['\u{A759}']),
+    ('\u{A75A}', &['\u{A75B}']),
+    ('\u{A75C}', &['\u{A75D}']),
+    ('\u{A75E}', &['\u{A75F}']),
+    ('\u{A760}', &['\u{A761}']),
+    ('\u{A762}', &['\u{A763}']),
+    ('\u{A764}', &['\u{A765}']),
+    ('\u{A766}', &['\u{A767}']),
+    ('\u{A768}', &['\u{A769}']),
+    ('\u{A76A}', &['\u{A76B}']),
+    ('\u{A76C}', &['\u{A76D}']),
+    ('\u{A76E}', &['\u{A76F}']),
+    ('\u{A779}', &['\u{A77A}']),
+    ('\u{A77B}', &['\u{A77C}']),
+    ('\u{A77D}', &['\u{1D79}']),
+    ('\u{A77E}', &['\u{A77F}']),
+    ('\u{A780}', &['\u{A781}']),
+    ('\u{A782}', &['\u{A783}']),
+    ('\u{A784}', &['\u{A785}']),
+    ('\u{A786}', &['\u{A787}']),
+    ('\u{A78B}', &['\u{A78C}']),
+    ('\u{A78D}', &['\u{0265}']),
+    ('\u{A790}', &['\u{A791}']),
+    ('\u{A792}', &['\u{A793}']),
+    ('\u{A796}', &['\u{A797}']),
+    ('\u{A798}', &['\u{A799}']),
+    ('\u{A79A}', &['\u{A79B}']),
+    ('\u{A79C}', &['\u{A79D}']),
+    ('\u{A79E}', &['\u{A79F}']),
+    ('\u{A7A0}', &['\u{A7A1}']),
+    ('\u{A7A2}', &['\u{A7A3}']),
+    ('\u{A7A4}', &['\u{A7A5}']),
+    ('\u{A7A6}', &['\u{A7A7}']),
+    ('\u{A7A8}', &['\u{A7A9}']),
+    ('\u{A7AA}', &['\u{0266}']),
+    ('\u{A7AB}', &['\u{025C}']),
+    ('\u{A7AC}', &['\u{0261}']),
+    ('\u{A7AD}', &['\u{026C}']),
+    ('\u{A7AE}', &['\u{026A}']),
+    ('\u{A7B0}', &['\u{029E}']),
+    ('\u{A7B1}', &['\u{0287}']),
+    ('\u{A7B2}', &['\u{029D}']),
+    ('\u{A7B3}', &['\u{AB53}']),
+    ('\u{A7B4}', &['\u{A7B5}']),
+    ('\u{A7B6}', &['\u{A7B7}']),
+    ('\u{A7B8}', &['\u{A7B9}']),
+    ('\u{A7BA}', &['\u{A7BB}']),
+    ('\u{A7BC}', &['\u{A7BD}']),
+    ('\u{A7BE}', &['\u{A7BF}']),
+    ('\u{A7C2}', &['\u{A7C3}']),
+    ('\u{A7C4}', &['\u{A794}']),
+    ('\u{A7C5}', &['\u{0282}']),
+    ('\u{A7C6}', &['\u{1D8E}']),
+    ('\u{A7C7}', &['\u{A7C8}']),
+    ('\u{A7C9}', &['\u{A7CA}']),
+    ('\u{A7F5}', &['\u{A7F6}']),
+    ('\u{AB70}', &['\u{13A0}']),
+    ('\u{AB71}', &['\u{13A1}']),
+    ('\u{AB72}', &['\u{13A2}']),
+    ('\u{AB73}', &['\u{13A3}']),
+    ('\u{AB74}', &['\u{13A4}']),
+    ('\u{AB75}', &['\u{13A5}']),
+    ('\u{AB76}', &['\u{13A6}']),
+    ('\u{AB77}', &['\u{13A7}']),
+    ('\u{AB78}', &['\u{13A8}']),
+    ('\u{AB79}', &['\u{13A9}']),
+    ('\u{AB7A}', &['\u{13AA}']),
+    ('\u{AB7B}', &['\u{13AB}']),
+    ('\u{AB7C}', &['\u{13AC}']),
+    ('\u{AB7D}', &['\u{13AD}']),
+    ('\u{AB7E}', &['\u{13AE}']),
+    ('\u{AB7F}', &['\u{13AF}']),
+    ('\u{AB80}', &['\u{13B0}']),
+    ('\u{AB81}', &['\u{13B1}']),
+    ('\u{AB82}', &['\u{13B2}']),
+    ('\u{AB83}', &['\u{13B3}']),
+    ('\u{AB84}', &['\u{13B4}']),
+    ('\u{AB85}', &['\u{13B5}']),
+    ('\u{AB86}', &['\u{13B6}']),
+    ('\u{AB87}', &['\u{13B7}']),
+    ('\u{AB88}', &['\u{13B8}']),
+    ('\u{AB89}', &['\u{13B9}']),
+    ('\u{AB8A}', &['\u{13BA}']),
+    ('\u{AB8B}', &['\u{13BB}']),
+    ('\u{AB8C}', &['\u{13BC}']),
+    ('\u{AB8D}', &['\u{13BD}']),
+    ('\u{AB8E}', &['\u{13BE}']),
+    ('\u{AB8F}', &['\u{13BF}']),
+    ('\u{AB90}', &['\u{13C0}']),
+    ('\u{AB91}', &['\u{13C1}']),
+    ('\u{AB92}', &['\u{13C2}']),
+    ('\u{AB93}', &['\u{13C3}']),
+    ('\u{AB94}', &['\u{13C4}']),
+    ('\u{AB95}', &['\u{13C5}']),
+    ('\u{AB96}', &['\u{13C6}']),
+    ('\u{AB97}', &['\u{13C7}']),
+    ('\u{AB98}', &['\u{13C8}']),
+    ('\u{AB99}', &['\u{13C9}']),
+    ('\u{AB9A}', &['\u{13CA}']),
+    ('\u{AB9B}', &['\u{13CB}']),
+    ('\u{AB9C}', &['\u{13CC}']),
+    ('\u{AB9D}', &['\u{13CD}']),
+    ('\u{AB9E}', &['\u{13CE}']),
+    ('\u{AB9F}', &['\u{13CF}']),
+    ('\u{ABA0}', &['\u{13D0}']),
+    ('\u{ABA1}', &['\u{13D1}']),
+    ('\u{ABA2}', &['\u{13D2}']),
+    ('\u{ABA3}', &['\u{13D3}']),
+    ('\u{ABA4}', &['\u{13D4}']),
+    ('\u{ABA5}', &['\u{13D5}']),
+    ('\u{ABA6}', &['\u{13D6}']),
+    ('\u{ABA7}', &['\u{13D7}']),
+    ('\u{ABA8}', &['\u{13D8}']),
+    ('\u{ABA9}', &['\u{13D9}']),
+    ('\u{ABAA}', &['\u{13DA}']),
+    ('\u{ABAB}', &['\u{13DB}']),
+    ('\u{ABAC}', &['\u{13DC}']),
+    ('\u{ABAD}', &['\u{13DD}']),
+    ('\u{ABAE}', &['\u{13DE}']),
+    ('\u{ABAF}', &['\u{13DF}']),
+    ('\u{ABB0}', &['\u{13E0}']),
+    ('\u{ABB1}', &['\u{13E1}']),
+    ('\u{ABB2}', &['\u{13E2}']),
+    ('\u{ABB3}', &['\u{13E3}']),
+    ('\u{ABB4}', &['\u{13E4}']),
+    ('\u{ABB5}', &['\u{13E5}']),
+    ('\u{ABB6}', &['\u{13E6}']),
+    ('\u{ABB7}', &['\u{13E7}']),
+    ('\u{ABB8}', &['\u{13E8}']),
+    ('\u{ABB9}', &['\u{13E9}']),
+    ('\u{ABBA}', &['\u{13EA}']),
+    ('\u{ABBB}', &['\u{13EB}']),
+    ('\u{ABBC}', &['\u{13EC}']),
+    ('\u{ABBD}', &['\u{13ED}']),
+    ('\u{ABBE}', &['\u{13EE}']),
+    ('\u{ABBF}', &['\u{13EF}']),
+    ('\u{FB00}', &['\u{0066}', '\u{0066}']),
+    ('\u{FB01}', &['\u{0066}', '\u{0069}']),
+    ('\u{FB02}', &['\u{0066}', '\u{006C}']),
+    ('\u{FB03}', &['\u{0066}', '\u{0066}', '\u{0069}']),
+    ('\u{FB04}', &['\u{0066}', '\u{0066}', '\u{006C}']),
+    ('\u{FB05}', &['\u{0073}', '\u{0074}']),
+    ('\u{FB06}', &['\u{0073}', '\u{0074}']),
+    ('\u{FB13}', &['\u{0574}', '\u{0576}']),
+    ('\u{FB14}', &['\u{0574}', '\u{0565}']),
+    ('\u{FB15}', &['\u{0574}', '\u{056B}']),
+    ('\u{FB16}', &['\u{057E}', '\u{0576}']),
+    ('\u{FB17}', &['\u{0574}', '\u{056D}']),
+    ('\u{FF21}', &['\u{FF41}']),
+    ('\u{FF22}', &['\u{FF42}']),
+    ('\u{FF23}', &['\u{FF43}']),
+    ('\u{FF24}', &['\u{FF44}']),
+    ('\u{FF25}', &['\u{FF45}']),
+    ('\u{FF26}', &['\u{FF46}']),
+    ('\u{FF27}', &['\u{FF47}']),
+    ('\u{FF28}', &['\u{FF48}']),
+    ('\u{FF29}', &['\u{FF49}']),
+    ('\u{FF2A}', &['\u{FF4A}']),
+    ('\u{FF2B}', &['\u{FF4B}']),
+    ('\u{FF2C}', &['\u{FF4C}']),
+    ('\u{FF2D}', &['\u{FF4D}']),
+    ('\u{FF2E}', &['\u{FF4E}']),
+    ('\u{FF2F}', &['\u{FF4F}']),
+    ('\u{FF30}', &['\u{FF50}']),
+    ('\u{FF31}', &['\u{FF51}']),
+    ('\u{FF32}', &['\u{FF52}']),
+    ('\u{FF33}', &['\u{FF53}']),
+    ('\u{FF34}', &['\u{FF54}']),
+    ('\u{FF35}', &['\u{FF55}']),
+    ('\u{FF36}', &['\u{FF56}']),
+    ('\u{FF37}', &['\u{FF57}']),
+    ('\u{FF38}', &['\u{FF58}']),
+    ('\u{FF39}', &['\u{FF59}']),
+    ('\u{FF3A}', &['\u{FF5A}']),
+    ('\u{10400}', &['\u{10428}']),
+    ('\u{10401}', &['\u{10429}']),
+    ('\u{10402}', &['\u{1042A}']),
+    ('\u{10403}', &['\u{1042B}']),
+    ('\u{10404}', &['\u{1042C}']),
+    ('\u{10405}', &['\u{1042D}']),
+    ('\u{10406}', &['\u{1042E}']),
+    ('\u{10407}', &['\u{1042F}']),
+    ('\u{10408}', &['\u{10430}']),
+    ('\u{10409}', &['\u{10431}']),
+    ('\u{1040A}', &['\u{10432}']),
+    ('\u{1040B}', &['\u{10433}']),
+    ('\u{1040C}', &['\u{10434}']),
+    ('\u{1040D}', &['\u{10435}']),
+    ('\u{1040E}', &['\u{10436}']),
+    ('\u{1040F}', &['\u{10437}']),
+    ('\u{10410}', &['\u{10438}']),
+    ('\u{10411}', &['\u{10439}']),
+    ('\u{10412}', &['\u{1043A}']),
+    ('\u{10413}', &['\u{1043B}']),
+    ('\u{10414}', &['\u{1043C}']),
+    ('\u{10415}', &['\u{1043D}']),
+    ('\u{10416}', &['\u{1043E}']),
+    ('\u{10417}', &['\u{1043F}']),
+    ('\u{10418}', &['\u{10440}']),
+    ('\u{10419}', &['\u{10441}']),
+    ('\u{1041A}', &['\u{10442}']),
+    ('\u{1041B}', &['\u{10443}']),
+    ('\u{1041C}', &['\u{10444}']),
+    ('\u{1041D}', &['\u{10445}']),
+    ('\u{1041E}', &['\u{10446}']),
+    ('\u{1041F}', &['\u{10447}']),
+    ('\u{10420}', &['\u{10448}']),
+    ('\u{10421}', &['\u{10449}']),
+    ('\u{10422}', &['\u{1044A}']),
+    ('\u{10423}', &['\u{1044B}']),
+    ('\u{10424}', &['\u{1044C}']),
+    ('\u{10425}', &['\u{1044D}']),
+    ('\u{10426}', &['\u{1044E}']),
+    ('\u{10427}', &['\u{1044F}']),
+    ('\u{104B0}', &['\u{104D8}']),
+    ('\u{104B1}', &['\u{104D9}']),
+    ('\u{104B2}', &['\u{104DA}']),
+    ('\u{104B3}', &['\u{104DB}']),
+    ('\u{104B4}', &['\u{104DC}']),
+    ('\u{104B5}', &['\u{104DD}']),
+    ('\u{104B6}', &['\u{104DE}']),
+    ('\u{104B7}', &['\u{104DF}']),
+    ('\u{104B8}', &['\u{104E0}']),
+    ('\u{104B9}', &['\u{104E1}']),
+    ('\u{104BA}', &['\u{104E2}']),
+    ('\u{104BB}', &['\u{104E3}']),
+    ('\u{104BC}', &['\u{104E4}']),
+    ('\u{104BD}', &['\u{104E5}']),
+    ('\u{104BE}', &['\u{104E6}']),
+    ('\u{104BF}', &['\u{104E7}']),
+    ('\u{104C0}', &['\u{104E8}']),
+    ('\u{104C1}', &['\u{104E9}']),
+    ('\u{104C2}', &['\u{104EA}']),
+    ('\u{104C3}', &['\u{104EB}']),
+    ('\u{104C4}', &['\u{104EC}']),
+    ('\u{104C5}', &['\u{104ED}']),
+    ('\u{104C6}', &['\u{104EE}']),
+    ('\u{104C7}', &['\u{104EF}']),
+    ('\u{104C8}', &['\u{104F0}']),
+    ('\u{104C9}', &['\u{104F1}']),
+    ('\u{104CA}', &['\u{104F2}']),
+    ('\u{104CB}', &['\u{104F3}']),
+    ('\u{104CC}', &['\u{104F4}']),
+    ('\u{104CD}', &['\u{104F5}']),
+    ('\u{104CE}', &['\u{104F6}']),
+    ('\u{104CF}', &['\u{104F7}']),
+    ('\u{104D0}', &['\u{104F8}']),
+    ('\u{104D1}', &['\u{104F9}']),
+    ('\u{104D2}', &['\u{104FA}']),
+    ('\u{104D3}', &['\u{104FB}']),
+    ('\u{10C80}', &['\u{10CC0}']),
+    ('\u{10C81}', &['\u{10CC1}']),
+    ('\u{10C82}', &['\u{10CC2}']),
+    ('\u{10C83}', &['\u{10CC3}']),
+    ('\u{10C84}', &['\u{10CC4}']),
+    ('\u{10C85}', &['\u{10CC5}']),
+    ('\u{10C86}', &['\u{10CC6}']),
+    ('\u{10C87}', &['\u{10CC7}']),
+    ('\u{10C88}', &['\u{10CC8}']),
+    ('\u{10C89}', &['\u{10CC9}']),
+    ('\u{10C8A}', &['\u{10CCA}']),
+    ('\u{10C8B}', &['\u{10CCB}']),
+    ('\u{10C8C}', &['\u{10CCC}']),
+    ('\u{10C8D}', &['\u{10CCD}']),
+    ('\u{10C8E}', &['\u{10CCE}']),
+    ('\u{10C8F}', &['\u{10CCF}']),
+    ('\u{10C90}', &['\u{10CD0}']),
+    ('\u{10C91}', &['\u{10CD1}']),
+    ('\u{10C92}', &['\u{10CD2}']),
+    ('\u{10C93}', &['\u{10CD3}']),
+    ('\u{10C94}', &['\u{10CD4}']),
+    ('\u{10C95}', &['\u{10CD5}']),
+    ('\u{10C96}', &['\u{10CD6}']),
+    ('\u{10C97}', &['\u{10CD7}']),
+    ('\u{10C98}', &['\u{10CD8}']),
+    ('\u{10C99}', &['\u{10CD9}']),
+    ('\u{10C9A}', &['\u{10CDA}']),
+    ('\u{10C9B}', &['\u{10CDB}']),
+    ('\u{10C9C}', &['\u{10CDC}']),
+    ('\u{10C9D}', &['\u{10CDD}']),
+    ('\u{10C9E}', &['\u{10CDE}']),
+    ('\u{10C9F}', &['\u{10CDF}']),
+    ('\u{10CA0}', &['\u{10CE0}']),
+    ('\u{10CA1}', &['\u{10CE1}']),
+    ('\u{10CA2}', &['\u{10CE2}']),
+    ('\u{10CA3}', &['\u{10CE3}']),
+    ('\u{10CA4}', &['\u{10CE4}']),
+    ('\u{10CA5}', &['\u{10CE5}']),
+    ('\u{10CA6}', &['\u{10CE6}']),
+    ('\u{10CA7}', &['\u{10CE7}']),
+    ('\u{10CA8}', &['\u{10CE8}']),
+    ('\u{10CA9}', &['\u{10CE9}']),
+    ('\u{10CAA}', &['\u{10CEA}']),
+    ('\u{10CAB}', &['\u{10CEB}']),
+    ('\u{10CAC}', &['\u{10CEC}']),
+    ('\u{10CAD}', &['\u{10CED}']),
+    ('\u{10CAE}', &['\u{10CEE}']),
+    ('\u{10CAF}', &['\u{10CEF}']),
+    ('\u{10CB0}', &['\u{10CF0}']),
+    ('\u{10CB1}', &['\u{10CF1}']),
+    ('\u{10CB2}', &['\u{10CF2}']),
+    ('\u{118A0}', &['\u{118C0}']),
+    ('\u{118A1}', &['\u{118C1}']),
+    ('\u{118A2}', &['\u{118C2}']),
+    ('\u{118A3}', &['\u{118C3}']),
+    ('\u{118A4}', &['\u{118C4}']),
+    ('\u{118A5}', &['\u{118C5}']),
+    ('\u{118A6}', &['\u{118C6}']),
+    ('\u{118A7}', &['\u{118C7}']),
+    ('\u{118A8}', &['\u{118C8}']),
+    ('\u{118A9}', &['\u{118C9}']),
+    ('\u{118AA}', &['\u{118CA}']),
+    ('\u{118AB}', &['\u{118CB}']),
+    ('\u{118AC}', &['\u{118CC}']),
+    ('\u{118AD}', &['\u{118CD}']),
+    ('\u{118AE}', &['\u{118CE}']),
+    ('\u{118AF}', &['\u{118CF}']),
+    ('\u{118B0}', &['\u{118D0}']),
+    ('\u{118B1}', &['\u{118D1}']),
+    ('\u{118B2}', &['\u{118D2}']),
+    ('\u{118B3}', &['\u{118D3}']),
+    ('\u{118B4}', &['\u{118D4}']),
+    ('\u{118B5}', &['\u{118D5}']),
+    ('\u{118B6}', &['\u{118D6}']),
+    ('\u{118B7}', &['\u{118D7}']),
+    ('\u{118B8}', &['\u{118D8}']),
+    ('\u{118B9}', &['\u{118D9}']),
+    ('\u{118BA}', &['\u{118DA}']),
+    ('\u{118BB}', &['\u{118DB}']),
+    ('\u{118BC}', &['\u{118DC}']),
+    ('\u{118BD}', &['\u{118DD}']),
+    ('\u{118BE}', &['\u{118DE}']),
+    ('\u{118BF}', &['\u{118DF}']),
+    ('\u{16E40}', &['\u{16E60}']),
+    ('\u{16E41}', &['\u{16E61}']),
+    ('\u{16E42}', &['\u{16E62}']),
+    ('\u{16E43}', &['\u{16E63}']),
+    ('\u{16E44}', &['\u{16E64}']),
+    ('\u{16E45}', &['\u{16E65}']),
+    ('\u{16E46}', &['\u{16E66}']),
+    ('\u{16E47}', &['\u{16E67}']),
+    ('\u{16E48}', &['\u{16E68}']),
+    ('\u{16E49}', &['\u{16E69}']),
+    ('\u{16E4A}', &['\u{16E6A}']),
+    ('\u{16E4B}', &['\u{16E6B}']),
+    ('\u{16E4C}', &['\u{16E6C}']),
+    ('\u{16E4D}', &['\u{16E6D}']),
+    ('\u{16E4E}', &['\u{16E6E}']),
+    ('\u{16E4F}', &['\u{16E6F}']),
+    ('\u{16E50}', &['\u{16E70}']),
+    ('\u{16E51}', &['\u{16E71}']),
+    ('\u{16E52}', &['\u{16E72}']),
+    ('\u{16E53}', &['\u{16E73}']),
+    ('\u{16E54}', &['\u{16E74}']),
+    ('\u{16E55}', &['\u{16E75}']),
+    ('\u{16E56}', &['\u{16E76}']),
+    ('\u{16E57}', &['\u{16E77}']),
+    ('\u{16E58}', &['\u{16E78}']),
+    ('\u{16E59}', &['\u{16E79}']),
+    ('\u{16E5A}', &['\u{16E7A}']),
+    ('\u{16E5B}', &['\u{16E7B}']),
+    ('\u{16E5C}', &['\u{16E7C}']),
+    ('\u{16E5D}', &['\u{16E7D}']),
+    ('\u{16E5E}', &['\u{16E7E}']),
+    ('\u{16E5F}', &['\u{16E7F}']),
+    ('\u{1E900}', &['\u{1E922}']),
+    ('\u{1E901}', &['\u{1E923}']),
+    ('\u{1E902}', &['\u{1E924}']),
+    ('\u{1E903}', &['\u{1E925}']),
+    ('\u{1E904}', &['\u{1E926}']),
+    ('\u{1E905}', &['\u{1E927}']),
+    ('\u{1E906}', &['\u{1E928}']),
+    ('\u{1E907}', &['\u{1E929}']),
+    ('\u{1E908}', &['\u{1E92A}']),
+    ('\u{1E909}', &['\u{1E92B}']),
+    ('\u{1E90A}', &['\u{1E92C}']),
+    ('\u{1E90B}', &['\u{1E92D}']),
+    ('\u{1E90C}', &['\u{1E92E}']),
+    ('\u{1E90D}', &['\u{1E92F}']),
+    ('\u{1E90E}', &['\u{1E930}']),
+    ('\u{1E90F}', &['\u{1E931}']),
+    ('\u{1E910}', &['\u{1E932}']),
+    ('\u{1E911}', &['\u{1E933}']),
+    ('\u{1E912}', &['\u{1E934}']),
+    ('\u{1E913}', &['\u{1E935}']),
+    ('\u{1E914}', &['\u{1E936}']),
+    ('\u{1E915}', &['\u{1E937}']),
+    ('\u{1E916}', &['\u{1E938}']),
+    ('\u{1E917}', &['\u{1E939}']),
+    ('\u{1E918}', &['\u{1E93A}']),
+    ('\u{1E919}', &['\u{1E93B}']),
+    ('\u{1E91A}', &['\u{1E93C}']),
+    ('\u{1E91B}', &['\u{1E93D}']),
+    ('\u{1E91C}', &['\u{1E93E}']),
+    ('\u{1E91D}', &['\u{1E93F}']),
+    ('\u{1E91E}', &['\u{1E940}']),
+    ('\u{1E91F}', &['\u{1E941}']),
+    ('\u{1E920}', &['\u{1E942}']),
+    ('\u{1E921}', &['\u{1E943}']),
+];
+
+pub(super) const SIMPLE_CASE_FOLDINGS: &[(char, char)] = &[
+    ('\u{0041}', '\u{0061}'),
+    ('\u{0042}', '\u{0062}'),
+    ('\u{0043}', '\u{0063}'),
+    ('\u{0044}', '\u{0064}'),
+    ('\u{0045}', '\u{0065}'),
+    ('\u{0046}', '\u{0066}'),
+    ('\u{0047}', '\u{0067}'),
+    ('\u{0048}', '\u{0068}'),
+    ('\u{0049}', '\u{0069}'),
+    ('\u{004A}', '\u{006A}'),
+    ('\u{004B}', '\u{006B}'),
+    ('\u{004C}', '\u{006C}'),
+    ('\u{004D}', '\u{006D}'),
+    ('\u{004E}', '\u{006E}'),
+    ('\u{004F}', '\u{006F}'),
+    ('\u{0050}', '\u{0070}'),
+    ('\u{0051}', '\u{0071}'),
+    ('\u{0052}', '\u{0072}'),
+    ('\u{0053}', '\u{0073}'),
+    ('\u{0054}', '\u{0074}'),
+    ('\u{0055}', '\u{0075}'),
+    ('\u{0056}', '\u{0076}'),
+    ('\u{0057}', '\u{0077}'),
+    ('\u{0058}', '\u{0078}'),
+    ('\u{0059}', '\u{0079}'),
+    ('\u{005A}', '\u{007A}'),
+    ('\u{00B5}', '\u{03BC}'),
+    ('\u{00C0}', '\u{00E0}'),
+    ('\u{00C1}', '\u{00E1}'),
+    ('\u{00C2}', '\u{00E2}'),
+    ('\u{00C3}', '\u{00E3}'),
+    ('\u{00C4}', '\u{00E4}'),
+    ('\u{00C5}', '\u{00E5}'),
+    ('\u{00C6}', '\u{00E6}'),
+    ('\u{00C7}', '\u{00E7}'),
+    ('\u{00C8}', '\u{00E8}'),
+    ('\u{00C9}', '\u{00E9}'),
+    ('\u{00CA}', '\u{00EA}'),
+    ('\u{00CB}', '\u{00EB}'),
+    ('\u{00CC}', '\u{00EC}'),
+    ('\u{00CD}', '\u{00ED}'),
+    ('\u{00CE}', '\u{00EE}'),
+    ('\u{00CF}', '\u{00EF}'),
+    ('\u{00D0}', '\u{00F0}'),
+    ('\u{00D1}', '\u{00F1}'),
+    ('\u{00D2}', '\u{00F2}'),
+    ('\u{00D3}', '\u{00F3}'),
+    ('\u{00D4}', '\u{00F4}'),
+    ('\u{00D5}', '\u{00F5}'),
+    ('\u{00D6}', '\u{00F6}'),
+    ('\u{00D8}', '\u{00F8}'),
+    ('\u{00D9}', '\u{00F9}'),
+    ('\u{00DA}', '\u{00FA}'),
+    ('\u{00DB}', '\u{00FB}'),
+    ('\u{00DC}', '\u{00FC}'),
+    ('\u{00DD}', '\u{00FD}'),
+    ('\u{00DE}', '\u{00FE}'),
+    ('\u{0100}', '\u{0101}'),
+    ('\u{0102}', '\u{0103}'),
+    ('\u{0104}', '\u{0105}'),
+    ('\u{0106}', '\u{0107}'),
+    ('\u{0108}', '\u{0109}'),
+    ('\u{010A}', '\u{010B}'),
+    ('\u{010C}', '\u{010D}'),
+    ('\u{010E}', '\u{010F}'),
+    ('\u{0110}', '\u{0111}'),
+    ('\u{0112}', '\u{0113}'),
+    ('\u{0114}', '\u{0115}'),
+    ('\u{0116}', '\u{0117}'),
+    ('\u{0118}', '\u{0119}'),
+    ('\u{011A}', '\u{011B}'),
+    ('\u{011C}', '\u{011D}'),
+    ('\u{011E}', '\u{011F}'),
+    ('\u{0120}', '\u{0121}'),
+    ('\u{0122}', '\u{0123}'),
+    ('\u{0124}', '\u{0125}'),
+    ('\u{0126}', '\u{0127}'),
+    ('\u{0128}', '\u{0129}'),
+    ('\u{012A}', '\u{012B}'),
+    ('\u{012C}', '\u{012D}'),
+    ('\u{012E}', '\u{012F}'),
+    ('\u{0132}', '\u{0133}'),
+    ('\u{0134}', '\u{0135}'),
+    ('\u{0136}', '\u{0137}'),
+    ('\u{0139}', '\u{013A}'),
+    ('\u{013B}', '\u{013C}'),
+    ('\u{013D}', '\u{013E}'),
+    ('\u{013F}', '\u{0140}'),
+    ('\u{0141}', '\u{0142}'),
+    ('\u{0143}', '\u{0144}'),
+    ('\u{0145}', '\u{0146}'),
+    ('\u{0147}', '\u{0148}'),
+    ('\u{014A}', '\u{014B}'),
+    ('\u{014C}', '\u{014D}'),
+    ('\u{014E}', '\u{014F}'),
+    ('\u{0150}', '\u{0151}'),
+    ('\u{0152}', '\u{0153}'),
+    ('\u{0154}', '\u{0155}'),
+    ('\u{0156}', '\u{0157}'),
+    ('\u{0158}', '\u{0159}'),
+    ('\u{015A}', '\u{015B}'),
+    ('\u{015C}', '\u{015D}'),
+    ('\u{015E}', '\u{015F}'),
+    ('\u{0160}', '\u{0161}'),
+    ('\u{0162}', '\u{0163}'),
+    ('\u{0164}', '\u{0165}'),
+    ('\u{0166}', '\u{0167}'),
+    ('\u{0168}', '\u{0169}'),
+    ('\u{016A}', '\u{016B}'),
+    ('\u{016C}', '\u{016D}'),
+    ('\u{016E}', '\u{016F}'),
+    ('\u{0170}', '\u{0171}'),
+    ('\u{0172}', '\u{0173}'),
+    ('\u{0174}', '\u{0175}'),
+    ('\u{0176}', '\u{0177}'),
+    ('\u{0178}', '\u{00FF}'),
+    ('\u{0179}', '\u{017A}'),
+    ('\u{017B}', '\u{017C}'),
+    ('\u{017D}', '\u{017E}'),
+    ('\u{017F}', '\u{0073}'),
+    ('\u{0181}', '\u{0253}'),
+    ('\u{0182}', '\u{0183}'),
+    ('\u{0184}', '\u{0185}'),
+    ('\u{0186}', '\u{0254}'),
+    ('\u{0187}', '\u{0188}'),
+    ('\u{0189}', '\u{0256}'),
+    ('\u{018A}', '\u{0257}'),
+    ('\u{018B}', '\u{018C}'),
+    ('\u{018E}', '\u{01DD}'),
+    ('\u{018F}', '\u{0259}'),
+    ('\u{0190}', '\u{025B}'),
+    ('\u{0191}', '\u{0192}'),
+    ('\u{0193}', '\u{0260}'),
+    ('\u{0194}', '\u{0263}'),
+    ('\u{0196}', '\u{0269}'),
+    ('\u{0197}', '\u{0268}'),
+    ('\u{0198}', '\u{0199}'),
+    ('\u{019C}', '\u{026F}'),
+    ('\u{019D}', '\u{0272}'),
+    ('\u{019F}', '\u{0275}'),
+    ('\u{01A0}', '\u{01A1}'),
+    ('\u{01A2}', '\u{01A3}'),
+    ('\u{01A4}', '\u{01A5}'),
+    ('\u{01A6}', '\u{0280}'),
+    ('\u{01A7}', '\u{01A8}'),
+    ('\u{01A9}', '\u{0283}'),
+    ('\u{01AC}', '\u{01AD}'),
+    ('\u{01AE}', '\u{0288}'),
+    ('\u{01AF}', '\u{01B0}'),
+    ('\u{01B1}', '\u{028A}'),
+    ('\u{01B2}', '\u{028B}'),
+    ('\u{01B3}', '\u{01B4}'),
+    ('\u{01B5}', '\u{01B6}'),
+    ('\u{01B7}', '\u{0292}'),
+    ('\u{01B8}', '\u{01B9}'),
+    ('\u{01BC}', '\u{01BD}'),
+    ('\u{01C4}', '\u{01C6}'),
+    ('\u{01C5}', '\u{01C6}'),
+    ('\u{01C7}', '\u{01C9}'),
+    ('\u{01C8}', '\u{01C9}'),
+    ('\u{01CA}', '\u{01CC}'),
+    ('\u{01CB}', '\u{01CC}'),
+    ('\u{01CD}', '\u{01CE}'),
+    ('\u{01CF}', '\u{01D0}'),
+    ('\u{01D1}', '\u{01D2}'),
+    ('\u{01D3}', '\u{01D4}'),
+    ('\u{01D5}', '\u{01D6}'),
+    ('\u{01D7}', '\u{01D8}'),
+    ('\u{01D9}', '\u{01DA}'),
+    ('\u{01DB}', '\u{01DC}'),
+    ('\u{01DE}', '\u{01DF}'),
+    ('\u{01E0}', '\u{01E1}'),
+    ('\u{01E2}', '\u{01E3}'),
+    ('\u{01E4}', '\u{01E5}'),
+    ('\u{01E6}', '\u{01E7}'),
+    ('\u{01E8}', '\u{01E9}'),
+    ('\u{01EA}', '\u{01EB}'),
+    ('\u{01EC}', '\u{01ED}'),
+    ('\u{01EE}', '\u{01EF}'),
+    ('\u{01F1}', '\u{01F3}'),
+    ('\u{01F2}', '\u{01F3}'),
+    ('\u{01F4}', '\u{01F5}'),
+    ('\u{01F6}', '\u{0195}'),
+    ('\u{01F7}', '\u{01BF}'),
+    ('\u{01F8}', '\u{01F9}'),
+    ('\u{01FA}', '\u{01FB}'),
+    ('\u{01FC}', '\u{01FD}'),
+    ('\u{01FE}', '\u{01FF}'),
+    ('\u{0200}', '\u{0201}'),
+    ('\u{0202}', '\u{0203}'),
+    ('\u{0204}', '\u{0205}'),
+    ('\u{0206}', '\u{0207}'),
+    ('\u{0208}', '\u{0209}'),
+    ('\u{020A}', '\u{020B}'),
+    ('\u{020C}', '\u{020D}'),
+    ('\u{020E}', '\u{020F}'),
+    ('\u{0210}', '\u{0211}'),
+    ('\u{0212}', '\u{0213}'),
+    ('\u{0214}', '\u{0215}'),
+    ('\u{0216}', '\u{0217}'),
+    ('\u{0218}', '\u{0219}'),
+    ('\u{021A}', '\u{021B}'),
+    ('\u{021C}', '\u{021D}'),
+    ('\u{021E}', '\u{021F}'),
+    ('\u{0220}', '\u{019E}'),
+    ('\u{0222}', '\u{0223}'),
+    ('\u{0224}', '\u{0225}'),
+    ('\u{0226}', '\u{0227}'),
+    ('\u{0228}', '\u{0229}'),
+    ('\u{022A}', '\u{022B}'),
+    ('\u{022C}', '\u{022D}'),
+    ('\u{022E}', '\u{022F}'),
+    ('\u{0230}', '\u{0231}'),
+    ('\u{0232}', '\u{0233}'),
+    ('\u{023A}', '\u{2C65}'),
+    ('\u{023B}', '\u{023C}'),
+    ('\u{023D}', '\u{019A}'),
+    ('\u{023E}', '\u{2C66}'),
+    ('\u{0241}', '\u{0242}'),
+    ('\u{0243}', '\u{0180}'),
+    ('\u{0244}', '\u{0289}'),
+    ('\u{0245}', '\u{028C}'),
+    ('\u{0246}', '\u{0247}'),
+    ('\u{0248}', '\u{0249}'),
+    ('\u{024A}', '\u{024B}'),
+    ('\u{024C}', '\u{024D}'),
+    ('\u{024E}', '\u{024F}'),
+    ('\u{0345}', '\u{03B9}'),
+    ('\u{0370}', '\u{0371}'),
+    ('\u{0372}', '\u{0373}'),
+    ('\u{0376}', '\u{0377}'),
+    ('\u{037F}', '\u{03F3}'),
+    ('\u{0386}', '\u{03AC}'),
+    ('\u{0388}', '\u{03AD}'),
+    ('\u{0389}', '\u{03AE}'),
+    ('\u{038A}', '\u{03AF}'),
+    ('\u{038C}', '\u{03CC}'),
+    ('\u{038E}', '\u{03CD}'),
+    ('\u{038F}', '\u{03CE}'),
+    ('\u{0391}', '\u{03B1}'),
+    ('\u{0392}', '\u{03B2}'),
+    ('\u{0393}', '\u{03B3}'),
+    ('\u{0394}', '\u{03B4}'),
+    ('\u{0395}', '\u{03B5}'),
+    ('\u{0396}', '\u{03B6}'),
+    ('\u{0397}', '\u{03B7}'),
+    ('\u{0398}', '\u{03B8}'),
+    ('\u{0399}', '\u{03B9}'),
+    ('\u{039A}', '\u{03BA}'),
+    ('\u{039B}', '\u{03BB}'),
+    ('\u{039C}', '\u{03BC}'),
+    ('\u{039D}', '\u{03BD}'),
+    ('\u{039E}', '\u{03BE}'),
+    ('\u{039F}', '\u{03BF}'),
+    ('\u{03A0}', '\u{03C0}'),
+    ('\u{03A1}', '\u{03C1}'),
+    ('\u{03A3}', '\u{03C3}'),
+    ('\u{03A4}', '\u{03C4}'),
+    ('\u{03A5}', '\u{03C5}'),
+    ('\u{03A6}', '\u{03C6}'),
+    ('\u{03A7}', '\u{03C7}'),
+    ('\u{03A8}', '\u{03C8}'),
+    ('\u{03A9}', '\u{03C9}'),
+    ('\u{03AA}', '\u{03CA}'),
+    ('\u{03AB}', '\u{03CB}'),
+    ('\u{03C2}', '\u{03C3}'),
+    ('\u{03CF}', '\u{03D7}'),
+    ('\u{03D0}', '\u{03B2}'),
+    ('\u{03D1}', '\u{03B8}'),
+    ('\u{03D5}', '\u{03C6}'),
+    ('\u{03D6}', '\u{03C0}'),
+    ('\u{03D8}', '\u{03D9}'),
+    ('\u{03DA}', '\u{03DB}'),
+    ('\u{03DC}', '\u{03DD}'),
+    ('\u{03DE}', '\u{03DF}'),
+    ('\u{03E0}', '\u{03E1}'),
+    ('\u{03E2}', '\u{03E3}'),
+    ('\u{03E4}', '\u{03E5}'),
+    ('\u{03E6}', '\u{03E7}'),
+    ('\u{03E8}', '\u{03E9}'),
+    ('\u{03EA}', '\u{03EB}'),
+    ('\u{03EC}', '\u{03ED}'),
+    ('\u{03EE}', '\u{03EF}'),
+    ('\u{03F0}', '\u{03BA}'),
+    ('\u{03F1}', '\u{03C1}'),
+    ('\u{03F4}', '\u{03B8}'),
+    ('\u{03F5}', '\u{03B5}'),
+    ('\u{03F7}', '\u{03F8}'),
+    ('\u{03F9}', '\u{03F2}'),
+    ('\u{03FA}', '\u{03FB}'),
+    ('\u{03FD}', '\u{037B}'),
+    ('\u{03FE}', '\u{037C}'),
+    ('\u{03FF}', '\u{037D}'),
+    ('\u{0400}', '\u{0450}'),
+    ('\u{0401}', '\u{0451}'),
+    ('\u{0402}', '\u{0452}'),
+    ('\u{0403}', '\u{0453}'),
+    ('\u{0404}', '\u{0454}'),
+    ('\u{0405}', '\u{0455}'),
+    ('\u{0406}', '\u{0456}'),
+    ('\u{0407}', '\u{0457}'),
+    ('\u{0408}', '\u{0458}'),
+    ('\u{0409}', '\u{0459}'),
+    ('\u{040A}', '\u{045A}'),
+    ('\u{040B}', '\u{045B}'),
+    ('\u{040C}', '\u{045C}'),
+    ('\u{040D}', '\u{045D}'),
+    ('\u{040E}', '\u{045E}'),
+    ('\u{040F}', '\u{045F}'),
+    ('\u{0410}', '\u{0430}'),
+    ('\u{0411}', '\u{0431}'),
+    ('\u{0412}', '\u{0432}'),
+    ('\u{0413}', '\u{0433}'),
+    ('\u{0414}', '\u{0434}'),
+    ('\u{0415}', '\u{0435}'),
+    ('\u{0416}', '\u{0436}'),
+    ('\u{0417}', '\u{0437}'),
+    ('\u{0418}', '\u{0438}'),
+    ('\u{0419}', '\u{0439}'),
+    ('\u{041A}', '\u{043A}'),
+    ('\u{041B}', '\u{043B}'),
+    ('\u{041C}', '\u{043C}'),
+    ('\u{041D}', '\u{043D}'),
+    ('\u{041E}', '\u{043E}'),
+    ('\u{041F}', '\u{043F}'),
+    ('\u{0420}', '\u{0440}'),
+    ('\u{0421}', '\u{0441}'),
+    ('\u{0422}', '\u{0442}'),
+    ('\u{0423}', '\u{0443}'),
+    ('\u{0424}', '\u{0444}'),
+    ('\u{0425}', '\u{0445}'),
+    ('\u{0426}', '\u{0446}'),
+    ('\u{0427}', '\u{0447}'),
+    ('\u{0428}', '\u{0448}'),
+    ('\u{0429}', '\u{0449}'),
+    ('\u{042A}', '\u{044A}'),
+    ('\u{042B}', '\u{044B}'),
+    ('\u{042C}', '\u{044C}'),
+    ('\u{042D}', '\u{044D}'),
+    ('\u{042E}', '\u{044E}'),
+    ('\u{042F}', '\u{044F}'),
+    ('\u{0460}', '\u{0461}'),
+    ('\u{0462}', '\u{0463}'),
+    ('\u{0464}', '\u{0465}'),
+    ('\u{0466}', '\u{0467}'),
+    ('\u{0468}', '\u{0469}'),
+    ('\u{046A}', '\u{046B}'),
+    ('\u{046C}', '\u{046D}'),
+    ('\u{046E}', '\u{046F}'),
+    ('\u{0470}', '\u{0471}'),
+    ('\u{0472}', '\u{0473}'),
+    ('\u{0474}', '\u{0475}'),
+    ('\u{0476}', '\u{0477}'),
+    ('\u{0478}', '\u{0479}'),
+    ('\u{047A}', '\u{047B}'),
+    ('\u{047C}', '\u{047D}'),
+    ('\u{047E}', '\u{047F}'),
+    ('\u{0480}', '\u{0481}'),
+    ('\u{048A}', '\u{048B}'),
+    ('\u{048C}', '\u{048D}'),
+    ('\u{048E}', '\u{048F}'),
+    ('\u{0490}', '\u{0491}'),
+    ('\u{0492}', '\u{0493}'),
+    ('\u{0494}', '\u{0495}'),
+    ('\u{0496}', '\u{0497}'),
+    ('\u{0498}', '\u{0499}'),
+    ('\u{049A}', '\u{049B}'),
+    ('\u{049C}', '\u{049D}'),
+    ('\u{049E}', '\u{049F}'),
+    ('\u{04A0}', '\u{04A1}'),
+    ('\u{04A2}', '\u{04A3}'),
+    ('\u{04A4}', '\u{04A5}'),
+    ('\u{04A6}', '\u{04A7}'),
+    ('\u{04A8}', '\u{04A9}'),
+    ('\u{04AA}', '\u{04AB}'),
+    ('\u{04AC}', '\u{04AD}'),
+    ('\u{04AE}', '\u{04AF}'),
+    ('\u{04B0}', '\u{04B1}'),
+    ('\u{04B2}', '\u{04B3}'),
+    ('\u{04B4}', '\u{04B5}'),
+    ('\u{04B6}', '\u{04B7}'),
+    ('\u{04B8}', '\u{04B9}'),
+    ('\u{04BA}', '\u{04BB}'),
+    ('\u{04BC}', '\u{04BD}'),
+    ('\u{04BE}', '\u{04BF}'),
+    ('\u{04C0}', '\u{04CF}'),
+    ('\u{04C1}', '\u{04C2}'),
+    ('\u{04C3}', '\u{04C4}'),
+    ('\u{04C5}', '\u{04C6}'),
+    ('\u{04C7}', '\u{04C8}'),
+    ('\u{04C9}', '\u{04CA}'),
+    ('\u{04CB}', '\u{04CC}'),
+    ('\u{04CD}', '\u{04CE}'),
+    ('\u{04D0}', '\u{04D1}'),
+    ('\u{04D2}', '\u{04D3}'),
+    ('\u{04D4}', '\u{04D5}'),
+    ('\u{04D6}', '\u{04D7}'),
+    ('\u{04D8}', '\u{04D9}'),
+    ('\u{04DA}', '\u{04DB}'),
+    ('\u{04DC}', '\u{04DD}'),
+    ('\u{04DE}', '\u{04DF}'),
+    ('\u{04E0}', '\u{04E1}'),
+    ('\u{04E2}', '\u{04E3}'),
+    ('\u{04E4}', '\u{04E5}'),
+    ('\u{04E6}', '\u{04E7}'),
+    ('\u{04E8}', '\u{04E9}'),
+    ('\u{04EA}', '\u{04EB}'),
+    ('\u{04EC}', '\u{04ED}'),
+    ('\u{04EE}', '\u{04EF}'),
+    ('\u{04F0}', '\u{04F1}'),
+    ('\u{04F2}', '\u{04F3}'),
+    ('\u{04F4}', '\u{04F5}'),
+    ('\u{04F6}', '\u{04F7}'),
+    ('\u{04F8}', '\u{04F9}'),
+    ('\u{04FA}', '\u{04FB}'),
+    ('\u{04FC}', '\u{04FD}'),
+    ('\u{04FE}', '\u{04FF}'),
+    ('\u{0500}', '\u{0501}'),
+    ('\u{0502}', '\u{0503}'),
+    ('\u{0504}', '\u{0505}'),
+    ('\u{0506}', '\u{0507}'),
+    ('\u{0508}', '\u{0509}'),
+    ('\u{050A}', '\u{050B}'),
+    ('\u{050C}', '\u{050D}'),
+    ('\u{050E}', '\u{050F}'),
+    ('\u{0510}', '\u{0511}'),
+    ('\u{0512}', '\u{0513}'),
+    ('\u{0514}', '\u{0515}'),
+    ('\u{0516}', '\u{0517}'),
+    ('\u{0518}', '\u{0519}'),
+    ('\u{051A}', '\u{051B}'),
+    ('\u{051C}', '\u{051D}'),
+    ('\u{051E}', '\u{051F}'),
+    ('\u{0520}', '\u{0521}'),
+    ('\u{0522}', '\u{0523}'),
+    ('\u{0524}', '\u{0525}'),
+    ('\u{0526}', '\u{0527}'),
+    ('\u{0528}', '\u{0529}'),
+    ('\u{052A}', '\u{052B}'),
+    ('\u{052C}', '\u{052D}'),
+    ('\u{052E}', '\u{052F}'),
+    ('\u{0531}', '\u{0561}'),
+    ('\u{0532}', '\u{0562}'),
+    ('\u{0533}', '\u{0563}'),
+    ('\u{0534}', '\u{0564}'),
+    ('\u{0535}', '\u{0565}'),
+    ('\u{0536}', '\u{0566}'),
+    ('\u{0537}', '\u{0567}'),
+    ('\u{0538}', '\u{0568}'),
+    ('\u{0539}', '\u{0569}'),
+    ('\u{053A}', '\u{056A}'),
+    ('\u{053B}', '\u{056B}'),
+    ('\u{053C}', '\u{056C}'),
+    ('\u{053D}', '\u{056D}'),
+    ('\u{053E}', '\u{056E}'),
+    ('\u{053F}', '\u{056F}'),
+    ('\u{0540}', '\u{0570}'),
+    ('\u{0541}', '\u{0571}'),
+    ('\u{0542}', '\u{0572}'),
+    ('\u{0543}', '\u{0573}'),
+    ('\u{0544}', '\u{0574}'),
+    ('\u{0545}', '\u{0575}'),
+    ('\u{0546}', '\u{0576}'),
+    ('\u{0547}', '\u{0577}'),
+    ('\u{0548}', '\u{0578}'),
+    ('\u{0549}', '\u{0579}'),
+    ('\u{054A}', '\u{057A}'),
+    ('\u{054B}', '\u{057B}'),
+    ('\u{054C}', '\u{057C}'),
+    ('\u{054D}', '\u{057D}'),
+    ('\u{054E}', '\u{057E}'),
+    ('\u{054F}', '\u{057F}'),
+    ('\u{0550}', '\u{0580}'),
+    ('\u{0551}', '\u{0581}'),
+    ('\u{0552}', '\u{0582}'),
+    ('\u{0553}', '\u{0583}'),
+    ('\u{0554}', '\u{0584}'),
+    ('\u{0555}', '\u{0585}'),
+    ('\u{0556}', '\u{0586}'),
+    ('\u{10A0}', '\u{2D00}'),
+    ('\u{10A1}', '\u{2D01}'),
+    ('\u{10A2}', '\u{2D02}'),
+    ('\u{10A3}', '\u{2D03}'),
+    ('\u{10A4}', '\u{2D04}'),
+    ('\u{10A5}', '\u{2D05}'),
+    ('\u{10A6}', '\u{2D06}'),
+    ('\u{10A7}', '\u{2D07}'),
+    ('\u{10A8}', '\u{2D08}'),
+    ('\u{10A9}', '\u{2D09}'),
+    ('\u{10AA}', '\u{2D0A}'),
+    ('\u{10AB}', '\u{2D0B}'),
+    ('\u{10AC}', '\u{2D0C}'),
+    ('\u{10AD}', '\u{2D0D}'),
+    ('\u{10AE}', '\u{2D0E}'),
+    ('\u{10AF}', '\u{2D0F}'),
+    ('\u{10B0}', '\u{2D10}'),
+    ('\u{10B1}', '\u{2D11}'),
+    ('\u{10B2}', '\u{2D12}'),
+    ('\u{10B3}', '\u{2D13}'),
+    ('\u{10B4}', '\u{2D14}'),
+    ('\u{10B5}', '\u{2D15}'),
+    ('\u{10B6}', '\u{2D16}'),
+    ('\u{10B7}', '\u{2D17}'),
+    ('\u{10B8}', '\u{2D18}'),
+    ('\u{10B9}', '\u{2D19}'),
+    ('\u{10BA}', '\u{2D1A}'),
+    ('\u{10BB}', '\u{2D1B}'),
+    ('\u{10BC}', '\u{2D1C}'),
+    ('\u{10BD}', '\u{2D1D}'),
+    ('\u{10BE}', '\u{2D1E}'),
+    ('\u{10BF}', '\u{2D1F}'),
+    ('\u{10C0}', '\u{2D20}'),
+    ('\u{10C1}', '\u{2D21}'),
+    ('\u{10C2}', '\u{2D22}'),
+    ('\u{10C3}', '\u{2D23}'),
+    ('\u{10C4}', '\u{2D24}'),
+    ('\u{10C5}', '\u{2D25}'),
+    ('\u{10C7}', '\u{2D27}'),
+    ('\u{10CD}', '\u{2D2D}'),
+    ('\u{13F8}', '\u{13F0}'),
+    ('\u{13F9}', '\u{13F1}'),
+    ('\u{13FA}', '\u{13F2}'),
+    ('\u{13FB}', '\u{13F3}'),
+    ('\u{13FC}', '\u{13F4}'),
+    ('\u{13FD}', '\u{13F5}'),
+    ('\u{1C80}', '\u{0432}'),
+    ('\u{1C81}', '\u{0434}'),
+    ('\u{1C82}', '\u{043E}'),
+    ('\u{1C83}', '\u{0441}'),
+    ('\u{1C84}', '\u{0442}'),
+    ('\u{1C85}', '\u{0442}'),
+    ('\u{1C86}', '\u{044A}'),
+    ('\u{1C87}', '\u{0463}'),
+    ('\u{1C88}', '\u{A64B}'),
+    ('\u{1C90}', '\u{10D0}'),
+    ('\u{1C91}', '\u{10D1}'),
+    ('\u{1C92}', '\u{10D2}'),
+    ('\u{1C93}', '\u{10D3}'),
+    ('\u{1C94}', '\u{10D4}'),
+    ('\u{1C95}', '\u{10D5}'),
+    ('\u{1C96}', '\u{10D6}'),
+    ('\u{1C97}', '\u{10D7}'),
+    ('\u{1C98}', '\u{10D8}'),
+    ('\u{1C99}', '\u{10D9}'),
+    ('\u{1C9A}', '\u{10DA}'),
+    ('\u{1C9B}', '\u{10DB}'),
+    ('\u{1C9C}', '\u{10DC}'),
+    ('\u{1C9D}', '\u{10DD}'),
+    ('\u{1C9E}', '\u{10DE}'),
+    ('\u{1C9F}', '\u{10DF}'),
+    ('\u{1CA0}', '\u{10E0}'),
+    ('\u{1CA1}', '\u{10E1}'),
+    ('\u{1CA2}', '\u{10E2}'),
+    ('\u{1CA3}', '\u{10E3}'),
+    ('\u{1CA4}', '\u{10E4}'),
+    ('\u{1CA5}', '\u{10E5}'),
+    ('\u{1CA6}', '\u{10E6}'),
+    ('\u{1CA7}', '\u{10E7}'),
+    ('\u{1CA8}', '\u{10E8}'),
+    ('\u{1CA9}', '\u{10E9}'),
+    ('\u{1CAA}', '\u{10EA}'),
+    ('\u{1CAB}', '\u{10EB}'),
+    ('\u{1CAC}', '\u{10EC}'),
+    ('\u{1CAD}', '\u{10ED}'),
+    ('\u{1CAE}', '\u{10EE}'),
+    ('\u{1CAF}', '\u{10EF}'),
+    ('\u{1CB0}', '\u{10F0}'),
+    ('\u{1CB1}', '\u{10F1}'),
+    ('\u{1CB2}', '\u{10F2}'),
+    ('\u{1CB3}', '\u{10F3}'),
+    ('\u{1CB4}', '\u{10F4}'),
+    ('\u{1CB5}', '\u{10F5}'),
+    ('\u{1CB6}', '\u{10F6}'),
+    ('\u{1CB7}', '\u{10F7}'),
+    ('\u{1CB8}', '\u{10F8}'),
+    ('\u{1CB9}', '\u{10F9}'),
+    ('\u{1CBA}', '\u{10FA}'),
+    ('\u{1CBD}', '\u{10FD}'),
+    ('\u{1CBE}', '\u{10FE}'),
+    ('\u{1CBF}', '\u{10FF}'),
+    ('\u{1E00}', '\u{1E01}'),
+    ('\u{1E02}', '\u{1E03}'),
+    ('\u{1E04}', '\u{1E05}'),
+    ('\u{1E06}', '\u{1E07}'),
+    ('\u{1E08}', '\u{1E09}'),
+    ('\u{1E0A}', '\u{1E0B}'),
+    ('\u{1E0C}', '\u{1E0D}'),
+    ('\u{1E0E}', '\u{1E0F}'),
+    ('\u{1E10}', '\u{1E11}'),
+    ('\u{1E12}', '\u{1E13}'),
+    ('\u{1E14}', '\u{1E15}'),
+    ('\u{1E16}', '\u{1E17}'),
+    ('\u{1E18}', '\u{1E19}'),
+    ('\u{1E1A}', '\u{1E1B}'),
+    ('\u{1E1C}', '\u{1E1D}'),
+    ('\u{1E1E}', '\u{1E1F}'),
+    ('\u{1E20}', '\u{1E21}'),
+    ('\u{1E22}', '\u{1E23}'),
+    ('\u{1E24}', '\u{1E25}'),
+    ('\u{1E26}', '\u{1E27}'),
+    ('\u{1E28}', '\u{1E29}'),
+    ('\u{1E2A}', '\u{1E2B}'),
+    ('\u{1E2C}', '\u{1E2D}'),
+    ('\u{1E2E}', '\u{1E2F}'),
+    ('\u{1E30}', '\u{1E31}'),
+    ('\u{1E32}', '\u{1E33}'),
+    ('\u{1E34}', '\u{1E35}'),
+    ('\u{1E36}', '\u{1E37}'),
+    ('\u{1E38}', '\u{1E39}'),
+    ('\u{1E3A}', '\u{1E3B}'),
+    ('\u{1E3C}', '\u{1E3D}'),
+    ('\u{1E3E}', '\u{1E3F}'),
+    ('\u{1E40}', '\u{1E41}'),
+    ('\u{1E42}', '\u{1E43}'),
+    ('\u{1E44}', '\u{1E45}'),
+    ('\u{1E46}', '\u{1E47}'),
+    ('\u{1E48}', '\u{1E49}'),
+    ('\u{1E4A}', '\u{1E4B}'),
+    ('\u{1E4C}', '\u{1E4D}'),
+    ('\u{1E4E}', '\u{1E4F}'),
+    ('\u{1E50}', '\u{1E51}'),
+    ('\u{1E52}', '\u{1E53}'),
+    ('\u{1E54}', '\u{1E55}'),
+    ('\u{1E56}', '\u{1E57}'),
+    ('\u{1E58}', '\u{1E59}'),
+    ('\u{1E5A}', '\u{1E5B}'),
+    ('\u{1E5C}', '\u{1E5D}'),
+    ('\u{1E5E}', '\u{1E5F}'),
+    ('\u{1E60}', '\u{1E61}'),
+    ('\u{1E62}', '\u{1E63}'),
+    ('\u{1E64}', '\u{1E65}'),
+    ('\u{1E66}', '\u{1E67}'),
+    ('\u{1E68}', '\u{1E69}'),
+    ('\u{1E6A}', '\u{1E6B}'),
+    ('\u{1E6C}', '\u{1E6D}'),
+    ('\u{1E6E}', '\u{1E6F}'),
+    ('\u{1E70}', '\u{1E71}'),
+    ('\u{1E72}', '\u{1E73}'),
+    ('\u{1E74}', '\u{1E75}'),
+    ('\u{1E76}', '\u{1E77}'),
+    ('\u{1E78}', '\u{1E79}'),
+    ('\u{1E7A}', '\u{1E7B}'),
+    ('\u{1E7C}', '\u{1E7D}'),
+    ('\u{1E7E}', '\u{1E7F}'),
+    ('\u{1E80}', '\u{1E81}'),
+    ('\u{1E82}', '\u{1E83}'),
+    ('\u{1E84}', '\u{1E85}'),
+    ('\u{1E86}', '\u{1E87}'),
+    ('\u{1E88}', '\u{1E89}'),
+    ('\u{1E8A}', '\u{1E8B}'),
+    ('\u{1E8C}', '\u{1E8D}'),
+    ('\u{1E8E}', '\u{1E8F}'),
+    ('\u{1E90}', '\u{1E91}'),
+    ('\u{1E92}', '\u{1E93}'),
+    ('\u{1E94}', '\u{1E95}'),
+    ('\u{1E9B}', '\u{1E61}'),
+    ('\u{1E9E}', '\u{00DF}'),
+    ('\u{1EA0}', '\u{1EA1}'),
+    ('\u{1EA2}', '\u{1EA3}'),
+    ('\u{1EA4}', '\u{1EA5}'),
+    ('\u{1EA6}', '\u{1EA7}'),
+    ('\u{1EA8}', '\u{1EA9}'),
+    ('\u{1EAA}', '\u{1EAB}'),
+    ('\u{1EAC}', '\u{1EAD}'),
+    ('\u{1EAE}', '\u{1EAF}'),
+    ('\u{1EB0}', '\u{1EB1}'),
+    ('\u{1EB2}', '\u{1EB3}'),
+    ('\u{1EB4}', '\u{1EB5}'),
+    ('\u{1EB6}', '\u{1EB7}'),
+    ('\u{1EB8}', '\u{1EB9}'),
+    ('\u{1EBA}', '\u{1EBB}'),
+    ('\u{1EBC}', '\u{1EBD}'),
+    ('\u{1EBE}', '\u{1EBF}'),
+    ('\u{1EC0}', '\u{1EC1}'),
+    ('\u{1EC2}', '\u{1EC3}'),
+    ('\u{1EC4}', '\u{1EC5}'),
+    ('\u{1EC6}', '\u{1EC7}'),
+    ('\u{1EC8}', '\u{1EC9}'),
+    ('\u{1ECA}', '\u{1ECB}'),
+    ('\u{1ECC}', '\u{1ECD}'),
+    ('\u{1ECE}', '\u{1ECF}'),
+    ('\u{1ED0}', '\u{1ED1}'),
+    ('\u{1ED2}', '\u{1ED3}'),
+    ('\u{1ED4}', '\u{1ED5}'),
+    ('\u{1ED6}', '\u{1ED7}'),
+    ('\u{1ED8}', '\u{1ED9}'),
+    ('\u{1EDA}', '\u{1EDB}'),
+    ('\u{1EDC}', '\u{1EDD}'),
+    ('\u{1EDE}', '\u{1EDF}'),
+    ('\u{1EE0}', '\u{1EE1}'),
+    ('\u{1EE2}', '\u{1EE3}'),
+    ('\u{1EE4}', '\u{1EE5}'),
+    ('\u{1EE6}', '\u{1EE7}'),
+    ('\u{1EE8}', '\u{1EE9}'),
+    ('\u{1EEA}', '\u{1EEB}'),
+    ('\u{1EEC}', '\u{1EED}'),
+    ('\u{1EEE}', '\u{1EEF}'),
+    ('\u{1EF0}', '\u{1EF1}'),
+    ('\u{1EF2}', '\u{1EF3}'),
+    ('\u{1EF4}', '\u{1EF5}'),
+    ('\u{1EF6}', '\u{1EF7}'),
+    ('\u{1EF8}', '\u{1EF9}'),
+    ('\u{1EFA}', '\u{1EFB}'),
+    ('\u{1EFC}', '\u{1EFD}'),
+    ('\u{1EFE}', '\u{1EFF}'),
+    ('\u{1F08}', '\u{1F00}'),
+    ('\u{1F09}', '\u{1F01}'),
+    ('\u{1F0A}', '\u{1F02}'),
+    ('\u{1F0B}', '\u{1F03}'),
+    ('\u{1F0C}', '\u{1F04}'),
+    ('\u{1F0D}', '\u{1F05}'),
+    ('\u{1F0E}', '\u{1F06}'),
+    ('\u{1F0F}', '\u{1F07}'),
+    ('\u{1F18}', '\u{1F10}'),
+    ('\u{1F19}', '\u{1F11}'),
+    ('\u{1F1A}', '\u{1F12}'),
+    ('\u{1F1B}', '\u{1F13}'),
+    ('\u{1F1C}', '\u{1F14}'),
+    ('\u{1F1D}', '\u{1F15}'),
+    ('\u{1F28}', '\u{1F20}'),
+    ('\u{1F29}', '\u{1F21}'),
+    ('\u{1F2A}', '\u{1F22}'),
+    ('\u{1F2B}', '\u{1F23}'),
+    ('\u{1F2C}', '\u{1F24}'),
+    ('\u{1F2D}', '\u{1F25}'),
+    ('\u{1F2E}', '\u{1F26}'),
+    ('\u{1F2F}', '\u{1F27}'),
+    ('\u{1F38}', '\u{1F30}'),
+    ('\u{1F39}', '\u{1F31}'),
+    ('\u{1F3A}', '\u{1F32}'),
+    ('\u{1F3B}', '\u{1F33}'),
+    ('\u{1F3C}', '\u{1F34}'),
+    ('\u{1F3D}', '\u{1F35}'),
+    ('\u{1F3E}', '\u{1F36}'),
+    ('\u{1F3F}', '\u{1F37}'),
+    ('\u{1F48}', '\u{1F40}'),
+    ('\u{1F49}', '\u{1F41}'),
+    ('\u{1F4A}', '\u{1F42}'),
+    ('\u{1F4B}', '\u{1F43}'),
+    ('\u{1F4C}', '\u{1F44}'),
+    ('\u{1F4D}', '\u{1F45}'),
+    ('\u{1F59}', '\u{1F51}'),
+    ('\u{1F5B}', '\u{1F53}'),
+    ('\u{1F5D}', '\u{1F55}'),
+    ('\u{1F5F}', '\u{1F57}'),
+    ('\u{1F68}', '\u{1F60}'),
+    ('\u{1F69}', '\u{1F61}'),
+    ('\u{1F6A}', '\u{1F62}'),
+    ('\u{1F6B}', '\u{1F63}'),
+    ('\u{1F6C}', '\u{1F64}'),
+    ('\u{1F6D}', '\u{1F65}'),
+    ('\u{1F6E}', '\u{1F66}'),
+    ('\u{1F6F}', '\u{1F67}'),
+    ('\u{1F88}', '\u{1F80}'),
+    ('\u{1F89}', '\u{1F81}'),
+    ('\u{1F8A}', '\u{1F82}'),
+    ('\u{1F8B}', '\u{1F83}'),
+    ('\u{1F8C}', '\u{1F84}'),
+    ('\u{1F8D}', '\u{1F85}'),
+    ('\u{1F8E}', '\u{1F86}'),
+    ('\u{1F8F}', '\u{1F87}'),
+    ('\u{1F98}', '\u{1F90}'),
+    ('\u{1F99}', '\u{1F91}'),
+    ('\u{1F9A}', '\u{1F92}'),
+    ('\u{1F9B}', '\u{1F93}'),
+    ('\u{1F9C}', '\u{1F94}'),
+    ('\u{1F9D}', '\u{1F95}'),
+    ('\u{1F9E}', '\u{1F96}'),
+    ('\u{1F9F}', '\u{1F97}'),
+    ('\u{1FA8}', '\u{1FA0}'),
+    ('\u{1FA9}', '\u{1FA1}'),
+    ('\u{1FAA}', '\u{1FA2}'),
+    ('\u{1FAB}', '\u{1FA3}'),
+    ('\u{1FAC}', '\u{1FA4}'),
+    ('\u{1FAD}', '\u{1FA5}'),
+    ('\u{1FAE}', '\u{1FA6}'),
+    ('\u{1FAF}', '\u{1FA7}'),
+    ('\u{1FB8}', '\u{1FB0}'),
+    ('\u{1FB9}', '\u{1FB1}'),
+    ('\u{1FBA}', '\u{1F70}'),
+    ('\u{1FBB}', '\u{1F71}'),
+    ('\u{1FBC}', '\u{1FB3}'),
+    ('\u{1FBE}', '\u{03B9}'),
+    ('\u{1FC8}', '\u{1F72}'),
+    ('\u{1FC9}', '\u{1F73}'),
+    ('\u{1FCA}', '\u{1F74}'),
+    ('\u{1FCB}', '\u{1F75}'),
+    ('\u{1FCC}', '\u{1FC3}'),
+    ('\u{1FD8}', '\u{1FD0}'),
+    ('\u{1FD9}', '\u{1FD1}'),
+    ('\u{1FDA}', '\u{1F76}'),
+    ('\u{1FDB}', '\u{1F77}'),
+    ('\u{1FE8}', '\u{1FE0}'),
+    ('\u{1FE9}', '\u{1FE1}'),
+    ('\u{1FEA}', '\u{1F7A}'),
+    ('\u{1FEB}', '\u{1F7B}'),
+    ('\u{1FEC}', '\u{1FE5}'),
+    ('\u{1FF8}', '\u{1F78}'),
+    ('\u{1FF9}', '\u{1F79}'),
+    ('\u{1FFA}', '\u{1F7C}'),
+    ('\u{1FFB}', '\u{1F7D}'),
+    ('\u{1FFC}', '\u{1FF3}'),
+    ('\u{2126}', '\u{03C9}'),
+    ('\u{212A}', '\u{006B}'),
+    ('\u{212B}', '\u{00E5}'),
+    ('\u{2132}', '\u{214E}'),
+    ('\u{2160}', '\u{2170}'),
+    ('\u{2161}', '\u{2171}'),
+    ('\u{2162}', '\u{2172}'),
+    ('\u{2163}', '\u{2173}'),
+    ('\u{2164}', '\u{2174}'),
+    ('\u{2165}', '\u{2175}'),
+    ('\u{2166}', '\u{2176}'),
+    ('\u{2167}', '\u{2177}'),
+    ('\u{2168}', '\u{2178}'),
+    ('\u{2169}', '\u{2179}'),
+    ('\u{216A}', '\u{217A}'),
+    ('\u{216B}', '\u{217B}'),
+    ('\u{216C}', '\u{217C}'),
+    ('\u{216D}', '\u{217D}'),
+    ('\u{216E}', '\u{217E}'),
+    ('\u{216F}', '\u{217F}'),
+    ('\u{2183}', '\u{2184}'),
+    ('\u{24B6}', '\u{24D0}'),
+    ('\u{24B7}', '\u{24D1}'),
+    ('\u{24B8}', '\u{24D2}'),
+    ('\u{24B9}', '\u{24D3}'),
+    ('\u{24BA}', '\u{24D4}'),
+    ('\u{24BB}', '\u{24D5}'),
+    ('\u{24BC}', '\u{24D6}'),
+    ('\u{24BD}', '\u{24D7}'),
+    ('\u{24BE}', '\u{24D8}'),
+    ('\u{24BF}', '\u{24D9}'),
+    ('\u{24C0}', '\u{24DA}'),
+    ('\u{24C1}', '\u{24DB}'),
+    ('\u{24C2}', '\u{24DC}'),
+    ('\u{24C3}', '\u{24DD}'),
+    ('\u{24C4}', '\u{24DE}'),
+    ('\u{24C5}', '\u{24DF}'),
+    ('\u{24C6}', '\u{24E0}'),
+    ('\u{24C7}', '\u{24E1}'),
+    ('\u{24C8}', '\u{24E2}'),
+    ('\u{24C9}', '\u{24E3}'),
+    ('\u{24CA}', '\u{24E4}'),
+    ('\u{24CB}', '\u{24E5}'),
+    ('\u{24CC}', '\u{24E6}'),
+    ('\u{24CD}', '\u{24E7}'),
+    ('\u{24CE}', '\u{24E8}'),
+    ('\u{24CF}', '\u{24E9}'),
+    ('\u{2C00}', '\u{2C30}'),
+    ('\u{2C01}', '\u{2C31}'),
+    ('\u{2C02}', '\u{2C32}'),
+    ('\u{2C03}', '\u{2C33}'),
+    ('\u{2C04}', '\u{2C34}'),
+    ('\u{2C05}', '\u{2C35}'),
+    ('\u{2C06}', '\u{2C36}'),
+    ('\u{2C07}', '\u{2C37}'),
+    ('\u{2C08}', '\u{2C38}'),
+    ('\u{2C09}', '\u{2C39}'),
+    ('\u{2C0A}', '\u{2C3A}'),
+    ('\u{2C0B}', '\u{2C3B}'),
+    ('\u{2C0C}', '\u{2C3C}'),
+    ('\u{2C0D}', '\u{2C3D}'),
+    ('\u{2C0E}', '\u{2C3E}'),
+    ('\u{2C0F}', '\u{2C3F}'),
+    ('\u{2C10}', '\u{2C40}'),
+    ('\u{2C11}', '\u{2C41}'),
+    ('\u{2C12}', '\u{2C42}'),
+    ('\u{2C13}', '\u{2C43}'),
+    ('\u{2C14}', '\u{2C44}'),
+    ('\u{2C15}', '\u{2C45}'),
+    ('\u{2C16}', '\u{2C46}'),
+    ('\u{2C17}', '\u{2C47}'),
+    ('\u{2C18}', '\u{2C48}'),
+    ('\u{2C19}', '\u{2C49}'),
+    ('\u{2C1A}', '\u{2C4A}'),
+    ('\u{2C1B}', '\u{2C4B}'),
+    ('\u{2C1C}', '\u{2C4C}'),
+    ('\u{2C1D}', '\u{2C4D}'),
+    ('\u{2C1E}', '\u{2C4E}'),
+    ('\u{2C1F}', '\u{2C4F}'),
+    ('\u{2C20}', '\u{2C50}'),
+    ('\u{2C21}', '\u{2C51}'),
+    ('\u{2C22}', '\u{2C52}'),
+    ('\u{2C23}', '\u{2C53}'),
+    ('\u{2C24}', '\u{2C54}'),
+    ('\u{2C25}', '\u{2C55}'),
+    ('\u{2C26}', '\u{2C56}'),
+    ('\u{2C27}', '\u{2C57}'),
+    ('\u{2C28}', '\u{2C58}'),
+    ('\u{2C29}', '\u{2C59}'),
+    ('\u{2C2A}', '\u{2C5A}'),
+    ('\u{2C2B}', '\u{2C5B}'),
+    ('\u{2C2C}', '\u{2C5C}'),
+    ('\u{2C2D}', '\u{2C5D}'),
+    ('\u{2C2E}', '\u{2C5E}'),
+    ('\u{2C60}', '\u{2C61}'),
+    ('\u{2C62}', '\u{026B}'),
+    ('\u{2C63}', '\u{1D7D}'),
+    ('\u{2C64}', '\u{027D}'),
+    ('\u{2C67}', '\u{2C68}'),
+    ('\u{2C69}', '\u{2C6A}'),
+    ('\u{2C6B}', '\u{2C6C}'),
+    ('\u{2C6D}', '\u{0251}'),
+    ('\u{2C6E}', '\u{0271}'),
+    ('\u{2C6F}', '\u{0250}'),
+    ('\u{2C70}', '\u{0252}'),
+    ('\u{2C72}', '\u{2C73}'),
+    ('\u{2C75}', '\u{2C76}'),
+    ('\u{2C7E}', '\u{023F}'),
+    ('\u{2C7F}', '\u{0240}'),
+    ('\u{2C80}', '\u{2C81}'),
+    ('\u{2C82}', '\u{2C83}'),
+    ('\u{2C84}', '\u{2C85}'),
+    ('\u{2C86}', '\u{2C87}'),
+    ('\u{2C88}', '\u{2C89}'),
+    ('\u{2C8A}', '\u{2C8B}'),
+    ('\u{2C8C}', '\u{2C8D}'),
+    ('\u{2C8E}', '\u{2C8F}'),
+    ('\u{2C90}', '\u{2C91}'),
+    ('\u{2C92}', '\u{2C93}'),
+    ('\u{2C94}', '\u{2C95}'),
+    ('\u{2C96}', '\u{2C97}'),
+    ('\u{2C98}', '\u{2C99}'),
+    ('\u{2C9A}', '\u{2C9B}'),
+    ('\u{2C9C}', '\u{2C9D}'),
+    ('\u{2C9E}', '\u{2C9F}'),
+    ('\u{2CA0}', '\u{2CA1}'),
+    ('\u{2CA2}', '\u{2CA3}'),
+    ('\u{2CA4}', '\u{2CA5}'),
+    ('\u{2CA6}', '\u{2CA7}'),
+    ('\u{2CA8}', '\u{2CA9}'),
+    ('\u{2CAA}', '\u{2CAB}'),
+    ('\u{2CAC}', '\u{2CAD}'),
+    ('\u{2CAE}', '\u{2CAF}'),
+    ('\u{2CB0}', '\u{2CB1}'),
+    ('\u{2CB2}', '\u{2CB3}'),
+    ('\u{2CB4}', '\u{2CB5}'),
+    ('\u{2CB6}', '\u{2CB7}'),
+    ('\u{2CB8}', '\u{2CB9}'),
+    ('\u{2CBA}', '\u{2CBB}'),
+    ('\u{2CBC}', '\u{2CBD}'),
+    ('\u{2CBE}', '\u{2CBF}'),
+    ('\u{2CC0}', '\u{2CC1}'),
+    ('\u{2CC2}', '\u{2CC3}'),
+    ('\u{2CC4}', '\u{2CC5}'),
+    ('\u{2CC6}', '\u{2CC7}'),
+    ('\u{2CC8}', '\u{2CC9}'),
+    ('\u{2CCA}', '\u{2CCB}'),
+    ('\u{2CCC}', '\u{2CCD}'),
+    ('\u{2CCE}', '\u{2CCF}'),
+    ('\u{2CD0}', '\u{2CD1}'),
+    ('\u{2CD2}', '\u{2CD3}'),
+    ('\u{2CD4}', '\u{2CD5}'),
+    ('\u{2CD6}', '\u{2CD7}'),
+    ('\u{2CD8}', '\u{2CD9}'),
+    ('\u{2CDA}', '\u{2CDB}'),
+    ('\u{2CDC}', '\u{2CDD}'),
+    ('\u{2CDE}', '\u{2CDF}'),
+    ('\u{2CE0}', '\u{2CE1}'),
+    ('\u{2CE2}', '\u{2CE3}'),
+    ('\u{2CEB}', '\u{2CEC}'),
+    ('\u{2CED}', '\u{2CEE}'),
+    ('\u{2CF2}', '\u{2CF3}'),
+    ('\u{A640}', '\u{A641}'),
+    ('\u{A642}', '\u{A643}'),
+    ('\u{A644}', '\u{A645}'),
+    ('\u{A646}', '\u{A647}'),
+    ('\u{A648}', '\u{A649}'),
+    ('\u{A64A}', '\u{A64B}'),
+    ('\u{A64C}', '\u{A64D}'),
+    ('\u{A64E}', '\u{A64F}'),
+    ('\u{A650}', '\u{A651}'),
+    ('\u{A652}', '\u{A653}'),
+    ('\u{A654}', '\u{A655}'),
+    ('\u{A656}', '\u{A657}'),
+    ('\u{A658}', '\u{A659}'),
+    ('\u{A65A}', '\u{A65B}'),
+    ('\u{A65C}', '\u{A65D}'),
+    ('\u{A65E}', '\u{A65F}'),
+    ('\u{A660}', '\u{A661}'),
+    ('\u{A662}', '\u{A663}'),
+    ('\u{A664}', '\u{A665}'),
+    ('\u{A666}', '\u{A667}'),
+    ('\u{A668}', '\u{A669}'),
+    ('\u{A66A}', '\u{A66B}'),
+    ('\u{A66C}', '\u{A66D}'),
+    ('\u{A680}', '\u{A681}'),
+    ('\u{A682}', '\u{A683}'),
+    ('\u{A684}', '\u{A685}'),
+    ('\u{A686}', '\u{A687}'),
+    ('\u{A688}', '\u{A689}'),
+    ('\u{A68A}', '\u{A68B}'),
+    ('\u{A68C}', '\u{A68D}'),
+    ('\u{A68E}', '\u{A68F}'),
+    ('\u{A690}', '\u{A691}'),
+    ('\u{A692}', '\u{A693}'),
+    ('\u{A694}', '\u{A695}'),
+    ('\u{A696}', '\u{A697}'),
+    ('\u{A698}', '\u{A699}'),
+    ('\u{A69A}', '\u{A69B}'),
+    ('\u{A722}', '\u{A723}'),
+    ('\u{A724}', '\u{A725}'),
+    ('\u{A726}', '\u{A727}'),
+    ('\u{A728}', '\u{A729}'),
+    ('\u{A72A}', '\u{A72B}'),
+    ('\u{A72C}', '\u{A72D}'),
+    ('\u{A72E}', '\u{A72F}'),
+    ('\u{A732}', '\u{A733}'),
+    ('\u{A734}', '\u{A735}'),
+    ('\u{A736}', '\u{A737}'),
+    ('\u{A738}', '\u{A739}'),
+    ('\u{A73A}', '\u{A73B}'),
+    ('\u{A73C}', '\u{A73D}'),
+    ('\u{A73E}', '\u{A73F}'),
+    ('\u{A740}', '\u{A741}'),
+    ('\u{A742}', '\u{A743}'),
+    ('\u{A744}', '\u{A745}'),
+    ('\u{A746}', '\u{A747}'),
+    ('\u{A748}', '\u{A749}'),
+    ('\u{A74A}', '\u{A74B}'),
+    ('\u{A74C}', '\u{A74D}'),
+    ('\u{A74E}', '\u{A74F}'),
+    ('\u{A750}', '\u{A751}'),
+    ('\u{A752}', '\u{A753}'),
+    ('\u{A754}', '\u{A755}'),
+    ('\u{A756}', '\u{A757}'),
+    ('\u{A758}', '\u{A759}'),
+    ('\u{A75A}', '\u{A75B}'),
+    ('\u{A75C}', '\u{A75D}'),
+    ('\u{A75E}', '\u{A75F}'),
+    ('\u{A760}', '\u{A761}'),
+    ('\u{A762}', '\u{A763}'),
+    ('\u{A764}', '\u{A765}'),
+    ('\u{A766}', '\u{A767}'),
+    ('\u{A768}', '\u{A769}'),
+    ('\u{A76A}', '\u{A76B}'),
+    ('\u{A76C}', '\u{A76D}'),
+    ('\u{A76E}', '\u{A76F}'),
+    ('\u{A779}', '\u{A77A}'),
+    ('\u{A77B}', '\u{A77C}'),
+    ('\u{A77D}', '\u{1D79}'),
+    ('\u{A77E}', '\u{A77F}'),
+    ('\u{A780}', '\u{A781}'),
+    ('\u{A782}', '\u{A783}'),
+    ('\u{A784}', '\u{A785}'),
+    ('\u{A786}', '\u{A787}'),
+    ('\u{A78B}', '\u{A78C}'),
+    ('\u{A78D}', '\u{0265}'),
+    ('\u{A790}', '\u{A791}'),
+    ('\u{A792}', '\u{A793}'),
+    ('\u{A796}', '\u{A797}'),
+    ('\u{A798}', '\u{A799}'),
+    ('\u{A79A}', '\u{A79B}'),
+    ('\u{A79C}', '\u{A79D}'),
+    ('\u{A79E}', '\u{A79F}'),
+    ('\u{A7A0}', '\u{A7A1}'),
+    ('\u{A7A2}', '\u{A7A3}'),
+    ('\u{A7A4}', '\u{A7A5}'),
+    ('\u{A7A6}', '\u{A7A7}'),
+    ('\u{A7A8}', '\u{A7A9}'),
+    ('\u{A7AA}', '\u{0266}'),
+    ('\u{A7AB}', '\u{025C}'),
+    ('\u{A7AC}', '\u{0261}'),
+    ('\u{A7AD}', '\u{026C}'),
+    ('\u{A7AE}', '\u{026A}'),
+    ('\u{A7B0}', '\u{029E}'),
+    ('\u{A7B1}', '\u{0287}'),
+    ('\u{A7B2}', '\u{029D}'),
+    ('\u{A7B3}', '\u{AB53}'),
+    ('\u{A7B4}', '\u{A7B5}'),
+    ('\u{A7B6}', '\u{A7B7}'),
+    ('\u{A7B8}', '\u{A7B9}'),
+    ('\u{A7BA}', '\u{A7BB}'),
+    ('\u{A7BC}', '\u{A7BD}'),
+    ('\u{A7BE}', '\u{A7BF}'),
+    ('\u{A7C2}', '\u{A7C3}'),
+    ('\u{A7C4}', '\u{A794}'),
+    ('\u{A7C5}', '\u{0282}'),
+    ('\u{A7C6}', '\u{1D8E}'),
+    ('\u{A7C7}', '\u{A7C8}'),
+    ('\u{A7C9}', '\u{A7CA}'),
+    ('\u{A7F5}', '\u{A7F6}'),
+    ('\u{AB70}', '\u{13A0}'),
+    ('\u{AB71}', '\u{13A1}'),
+    ('\u{AB72}', '\u{13A2}'),
+    ('\u{AB73}', '\u{13A3}'),
+    ('\u{AB74}', '\u{13A4}'),
+    ('\u{AB75}', '\u{13A5}'),
+    ('\u{AB76}', '\u{13A6}'),
+    ('\u{AB77}', '\u{13A7}'),
+    ('\u{AB78}', '\u{13A8}'),
+    ('\u{AB79}', '\u{13A9}'),
+    ('\u{AB7A}', '\u{13AA}'),
+    ('\u{AB7B}', '\u{13AB}'),
+    ('\u{AB7C}', '\u{13AC}'),
+    ('\u{AB7D}', '\u{13AD}'),
+    ('\u{AB7E}', '\u{13AE}'),
+    ('\u{AB7F}', '\u{13AF}'),
+    ('\u{AB80}', '\u{13B0}'),
+    ('\u{AB81}', '\u{13B1}'),
+    ('\u{AB82}', '\u{13B2}'),
+    ('\u{AB83}', '\u{13B3}'),
+    ('\u{AB84}', '\u{13B4}'),
+    ('\u{AB85}', '\u{13B5}'),
+    ('\u{AB86}', '\u{13B6}'),
+    ('\u{AB87}', '\u{13B7}'),
+    ('\u{AB88}', '\u{13B8}'),
+    ('\u{AB89}', '\u{13B9}'),
+    ('\u{AB8A}', '\u{13BA}'),
+    ('\u{AB8B}', '\u{13BB}'),
+    ('\u{AB8C}', '\u{13BC}'),
+    ('\u{AB8D}', '\u{13BD}'),
+    ('\u{AB8E}', '\u{13BE}'),
+    ('\u{AB8F}', '\u{13BF}'),
+    ('\u{AB90}', '\u{13C0}'),
+    ('\u{AB91}', '\u{13C1}'),
+    ('\u{AB92}', '\u{13C2}'),
+    ('\u{AB93}', '\u{13C3}'),
+    ('\u{AB94}', '\u{13C4}'),
+    ('\u{AB95}', '\u{13C5}'),
+    ('\u{AB96}', '\u{13C6}'),
+    ('\u{AB97}', '\u{13C7}'),
+    ('\u{AB98}', '\u{13C8}'),
+    ('\u{AB99}', '\u{13C9}'),
+    ('\u{AB9A}', '\u{13CA}'),
+    ('\u{AB9B}', '\u{13CB}'),
+    ('\u{AB9C}', '\u{13CC}'),
+    ('\u{AB9D}', '\u{13CD}'),
+    ('\u{AB9E}', '\u{13CE}'),
+    ('\u{AB9F}', '\u{13CF}'),
+    ('\u{ABA0}', '\u{13D0}'),
+    ('\u{ABA1}', '\u{13D1}'),
+    ('\u{ABA2}', '\u{13D2}'),
+    ('\u{ABA3}', '\u{13D3}'),
+    ('\u{ABA4}', '\u{13D4}'),
+    ('\u{ABA5}', '\u{13D5}'),
+    ('\u{ABA6}', '\u{13D6}'),
+    ('\u{ABA7}', '\u{13D7}'),
+    ('\u{ABA8}', '\u{13D8}'),
+    ('\u{ABA9}', '\u{13D9}'),
+    ('\u{ABAA}', '\u{13DA}'),
+    ('\u{ABAB}', '\u{13DB}'),
+    ('\u{ABAC}', '\u{13DC}'),
+    ('\u{ABAD}', '\u{13DD}'),
+    ('\u{ABAE}', '\u{13DE}'),
+    ('\u{ABAF}', '\u{13DF}'),
+    ('\u{ABB0}', '\u{13E0}'),
+    ('\u{ABB1}', '\u{13E1}'),
+    ('\u{ABB2}', '\u{13E2}'),
+    ('\u{ABB3}', '\u{13E3}'),
+    ('\u{ABB4}', '\u{13E4}'),
+    ('\u{ABB5}', '\u{13E5}'),
+    ('\u{ABB6}', '\u{13E6}'),
+    ('\u{ABB7}', '\u{13E7}'),
+    ('\u{ABB8}', '\u{13E8}'),
+    ('\u{ABB9}', '\u{13E9}'),
+    ('\u{ABBA}', '\u{13EA}'),
+    ('\u{ABBB}', '\u{13EB}'),
+    ('\u{ABBC}', '\u{13EC}'),
+    ('\u{ABBD}', '\u{13ED}'),
+    ('\u{ABBE}', '\u{13EE}'),
+    ('\u{ABBF}', '\u{13EF}'),
+    ('\u{FF21}', '\u{FF41}'),
+    ('\u{FF22}', '\u{FF42}'),
+    ('\u{FF23}', '\u{FF43}'),
+    ('\u{FF24}', '\u{FF44}'),
+    ('\u{FF25}', '\u{FF45}'),
+    ('\u{FF26}', '\u{FF46}'),
+    ('\u{FF27}', '\u{FF47}'),
+    ('\u{FF28}', '\u{FF48}'),
+    ('\u{FF29}', '\u{FF49}'),
+    ('\u{FF2A}', '\u{FF4A}'),
+    ('\u{FF2B}', '\u{FF4B}'),
+    ('\u{FF2C}', '\u{FF4C}'),
+    ('\u{FF2D}', '\u{FF4D}'),
+    ('\u{FF2E}', '\u{FF4E}'),
+    ('\u{FF2F}', '\u{FF4F}'),
+    ('\u{FF30}', '\u{FF50}'),
+    ('\u{FF31}', '\u{FF51}'),
+    ('\u{FF32}', '\u{FF52}'),
+    ('\u{FF33}', '\u{FF53}'),
+    ('\u{FF34}', '\u{FF54}'),
+    ('\u{FF35}', '\u{FF55}'),
+    ('\u{FF36}', '\u{FF56}'),
+    ('\u{FF37}', '\u{FF57}'),
+    ('\u{FF38}', '\u{FF58}'),
+    ('\u{FF39}', '\u{FF59}'),
+    ('\u{FF3A}', '\u{FF5A}'),
+    ('\u{10400}', '\u{10428}'),
+    ('\u{10401}', '\u{10429}'),
+    ('\u{10402}', '\u{1042A}'),
+    ('\u{10403}', '\u{1042B}'),
+    ('\u{10404}', '\u{1042C}'),
+    ('\u{10405}', '\u{1042D}'),
+    ('\u{10406}', '\u{1042E}'),
+    ('\u{10407}', '\u{1042F}'),
+    ('\u{10408}', '\u{10430}'),
+    ('\u{10409}', '\u{10431}'),
+    ('\u{1040A}', '\u{10432}'),
+    ('\u{1040B}', '\u{10433}'),
+    ('\u{1040C}', '\u{10434}'),
+    ('\u{1040D}', '\u{10435}'),
+    ('\u{1040E}', '\u{10436}'),
+    ('\u{1040F}', '\u{10437}'),
+    ('\u{10410}', '\u{10438}'),
+    ('\u{10411}', '\u{10439}'),
+    ('\u{10412}', '\u{1043A}'),
+    ('\u{10413}', '\u{1043B}'),
+    ('\u{10414}', '\u{1043C}'),
+    ('\u{10415}', '\u{1043D}'),
+    ('\u{10416}', '\u{1043E}'),
+    ('\u{10417}', '\u{1043F}'),
+    ('\u{10418}', '\u{10440}'),
+    ('\u{10419}', '\u{10441}'),
+    ('\u{1041A}', '\u{10442}'),
+    ('\u{1041B}', '\u{10443}'),
+    ('\u{1041C}', '\u{10444}'),
+    ('\u{1041D}', '\u{10445}'),
+    ('\u{1041E}', '\u{10446}'),
+    ('\u{1041F}', '\u{10447}'),
+    ('\u{10420}', '\u{10448}'),
+    ('\u{10421}', '\u{10449}'),
+    ('\u{10422}', '\u{1044A}'),
+    ('\u{10423}', '\u{1044B}'),
+    ('\u{10424}', '\u{1044C}'),
+    ('\u{10425}', '\u{1044D}'),
+    ('\u{10426}', '\u{1044E}'),
+    ('\u{10427}', '\u{1044F}'),
+    ('\u{104B0}', '\u{104D8}'),
+    ('\u{104B1}', '\u{104D9}'),
+    ('\u{104B2}', '\u{104DA}'),
+    ('\u{104B3}', '\u{104DB}'),
+    ('\u{104B4}', '\u{104DC}'),
+    ('\u{104B5}', '\u{104DD}'),
+    ('\u{104B6}', '\u{104DE}'),
+    ('\u{104B7}', '\u{104DF}'),
+    ('\u{104B8}', '\u{104E0}'),
+    ('\u{104B9}', '\u{104E1}'),
+    ('\u{104BA}', '\u{104E2}'),
+    ('\u{104BB}', '\u{104E3}'),
+    ('\u{104BC}', '\u{104E4}'),
+    ('\u{104BD}', '\u{104E5}'),
+    ('\u{104BE}', '\u{104E6}'),
+    ('\u{104BF}', '\u{104E7}'),
+    ('\u{104C0}', '\u{104E8}'),
+    ('\u{104C1}', '\u{104E9}'),
+    ('\u{104C2}', '\u{104EA}'),
+    ('\u{104C3}', '\u{104EB}'),
+    ('\u{104C4}', '\u{104EC}'),
+    ('\u{104C5}', '\u{104ED}'),
+    ('\u{104C6}', '\u{104EE}'),
+    ('\u{104C7}', '\u{104EF}'),
+    ('\u{104C8}', '\u{104F0}'),
+    ('\u{104C9}', '\u{104F1}'),
+    ('\u{104CA}', '\u{104F2}'),
+    ('\u{104CB}', '\u{104F3}'),
+    ('\u{104CC}', '\u{104F4}'),
+    ('\u{104CD}', '\u{104F5}'),
+    ('\u{104CE}', '\u{104F6}'),
+    ('\u{104CF}', '\u{104F7}'),
+    ('\u{104D0}', '\u{104F8}'),
+    ('\u{104D1}', '\u{104F9}'),
+    ('\u{104D2}', '\u{104FA}'),
+    ('\u{104D3}', '\u{104FB}'),
+    ('\u{10C80}', '\u{10CC0}'),
+    ('\u{10C81}', '\u{10CC1}'),
+    ('\u{10C82}', '\u{10CC2}'),
+    ('\u{10C83}', '\u{10CC3}'),
+    ('\u{10C84}', '\u{10CC4}'),
+    ('\u{10C85}', '\u{10CC5}'),
+    ('\u{10C86}', '\u{10CC6}'),
+    ('\u{10C87}', '\u{10CC7}'),
+    ('\u{10C88}', '\u{10CC8}'),
+    ('\u{10C89}', '\u{10CC9}'),
+    ('\u{10C8A}', '\u{10CCA}'),
+    ('\u{10C8B}', '\u{10CCB}'),
+    ('\u{10C8C}', '\u{10CCC}'),
+    ('\u{10C8D}', '\u{10CCD}'),
+    ('\u{10C8E}', '\u{10CCE}'),
+    ('\u{10C8F}', '\u{10CCF}'),
+    ('\u{10C90}', '\u{10CD0}'),
+    ('\u{10C91}', '\u{10CD1}'),
+    ('\u{10C92}', '\u{10CD2}'),
+    ('\u{10C93}', '\u{10CD3}'),
+    ('\u{10C94}', '\u{10CD4}'),
+    ('\u{10C95}', '\u{10CD5}'),
+    ('\u{10C96}', '\u{10CD6}'),
+    ('\u{10C97}', '\u{10CD7}'),
+    ('\u{10C98}', '\u{10CD8}'),
+    ('\u{10C99}', '\u{10CD9}'),
+    ('\u{10C9A}', '\u{10CDA}'),
+    ('\u{10C9B}', '\u{10CDB}'),
+    ('\u{10C9C}', '\u{10CDC}'),
+    ('\u{10C9D}', '\u{10CDD}'),
+    ('\u{10C9E}', '\u{10CDE}'),
+    ('\u{10C9F}', '\u{10CDF}'),
+    ('\u{10CA0}', '\u{10CE0}'),
+    ('\u{10CA1}', '\u{10CE1}'),
+    ('\u{10CA2}', '\u{10CE2}'),
+    ('\u{10CA3}', '\u{10CE3}'),
+    ('\u{10CA4}', '\u{10CE4}'),
+    ('\u{10CA5}', '\u{10CE5}'),
+    ('\u{10CA6}', '\u{10CE6}'),
+    ('\u{10CA7}', '\u{10CE7}'),
+    ('\u{10CA8}', '\u{10CE8}'),
+    ('\u{10CA9}', '\u{10CE9}'),
+    ('\u{10CAA}', '\u{10CEA}'),
+    ('\u{10CAB}', '\u{10CEB}'),
+    ('\u{10CAC}', '\u{10CEC}'),
+    ('\u{10CAD}', '\u{10CED}'),
+    ('\u{10CAE}', '\u{10CEE}'),
+    ('\u{10CAF}', '\u{10CEF}'),
+    ('\u{10CB0}', '\u{10CF0}'),
+    ('\u{10CB1}', '\u{10CF1}'),
+    ('\u{10CB2}', '\u{10CF2}'),
+    ('\u{118A0}', '\u{118C0}'),
+    ('\u{118A1}', '\u{118C1}'),
+    ('\u{118A2}', '\u{118C2}'),
+    ('\u{118A3}', '\u{118C3}'),
+    ('\u{118A4}', '\u{118C4}'),
+    ('\u{118A5}', '\u{118C5}'),
+    ('\u{118A6}', '\u{118C6}'),
+    ('\u{118A7}', '\u{118C7}'),
+    ('\u{118A8}', '\u{118C8}'),
+    ('\u{118A9}', '\u{118C9}'),
+    ('\u{118AA}', '\u{118CA}'),
+    ('\u{118AB}', '\u{118CB}'),
+    ('\u{118AC}', '\u{118CC}'),
+    ('\u{118AD}', '\u{118CD}'),
+    ('\u{118AE}', '\u{118CE}'),
+    ('\u{118AF}', '\u{118CF}'),
+    ('\u{118B0}', '\u{118D0}'),
+    ('\u{118B1}', '\u{118D1}'),
+    ('\u{118B2}', '\u{118D2}'),
+    ('\u{118B3}', '\u{118D3}'),
+    ('\u{118B4}', '\u{118D4}'),
+    ('\u{118B5}', '\u{118D5}'),
+    ('\u{118B6}', '\u{118D6}'),
+    ('\u{118B7}', '\u{118D7}'),
+    ('\u{118B8}', '\u{118D8}'),
+    ('\u{118B9}', '\u{118D9}'),
+    ('\u{118BA}', '\u{118DA}'),
+    ('\u{118BB}', '\u{118DB}'),
+    ('\u{118BC}', '\u{118DC}'),
+    ('\u{118BD}', '\u{118DD}'),
+    ('\u{118BE}', '\u{118DE}'),
+    ('\u{118BF}', '\u{118DF}'),
+    ('\u{16E40}', '\u{16E60}'),
+    ('\u{16E41}', '\u{16E61}'),
+    ('\u{16E42}', '\u{16E62}'),
+    ('\u{16E43}', '\u{16E63}'),
+    ('\u{16E44}', '\u{16E64}'),
+    ('\u{16E45}', '\u{16E65}'),
+    ('\u{16E46}', '\u{16E66}'),
+    ('\u{16E47}', '\u{16E67}'),
+    ('\u{16E48}', '\u{16E68}'),
+    ('\u{16E49}', '\u{16E69}'),
+    ('\u{16E4A}', '\u{16E6A}'),
+    ('\u{16E4B}', '\u{16E6B}'),
+    ('\u{16E4C}', '\u{16E6C}'),
+    ('\u{16E4D}', '\u{16E6D}'),
+    ('\u{16E4E}', '\u{16E6E}'),
+    ('\u{16E4F}', '\u{16E6F}'),
+    ('\u{16E50}', '\u{16E70}'),
+    ('\u{16E51}', '\u{16E71}'),
+    ('\u{16E52}', '\u{16E72}'),
+    ('\u{16E53}', '\u{16E73}'),
+    ('\u{16E54}', '\u{16E74}'),
+    ('\u{16E55}', '\u{16E75}'),
+    ('\u{16E56}', '\u{16E76}'),
+    ('\u{16E57}', '\u{16E77}'),
+    ('\u{16E58}', '\u{16E78}'),
+    ('\u{16E59}', '\u{16E79}'),
+    ('\u{16E5A}', '\u{16E7A}'),
+    ('\u{16E5B}', '\u{16E7B}'),
+    ('\u{16E5C}', '\u{16E7C}'),
+    ('\u{16E5D}', '\u{16E7D}'),
+    ('\u{16E5E}', '\u{16E7E}'),
+    ('\u{16E5F}', '\u{16E7F}'),
+    ('\u{1E900}', '\u{1E922}'),
+    ('\u{1E901}', '\u{1E923}'),
+    ('\u{1E902}', '\u{1E924}'),
+    ('\u{1E903}', '\u{1E925}'),
+    ('\u{1E904}', '\u{1E926}'),
+    ('\u{1E905}', '\u{1E927}'),
+    ('\u{1E906}', '\u{1E928}'),
+    ('\u{1E907}', '\u{1E929}'),
+    ('\u{1E908}', '\u{1E92A}'),
+    ('\u{1E909}', '\u{1E92B}'),
+    ('\u{1E90A}', '\u{1E92C}'),
+    ('\u{1E90B}', '\u{1E92D}'),
+    ('\u{1E90C}', '\u{1E92E}'),
+    ('\u{1E90D}', '\u{1E92F}'),
+    ('\u{1E90E}', '\u{1E930}'),
+    ('\u{1E90F}', '\u{1E931}'),
+    ('\u{1E910}', '\u{1E932}'),
+    ('\u{1E911}', '\u{1E933}'),
+    ('\u{1E912}', '\u{1E934}'),
+    ('\u{1E913}', '\u{1E935}'),
+    ('\u{1E914}', '\u{1E936}'),
+    ('\u{1E915}', '\u{1E937}'),
+    ('\u{1E916}', '\u{1E938}'),
+    ('\u{1E917}', '\u{1E939}'),
+    ('\u{1E918}', '\u{1E93A}'),
+    ('\u{1E919}', '\u{1E93B}'),
+    ('\u{1E91A}', '\u{1E93C}'),
+    ('\u{1E91B}', '\u{1E93D}'),
+    ('\u{1E91C}', '\u{1E93E}'),
+    ('\u{1E91D}', '\u{1E93F}'),
+    ('\u{1E91E}', '\u{1E940}'),
+    ('\u{1E91F}', '\u{1E941}'),
+    ('\u{1E920}', '\u{1E942}'),
+    ('\u{1E921}', '\u{1E943}'),
+];
+
+pub(super) const CASED_RANGES: &[(u32, u32)] = &[
+    (0x0041, 0x005A),
+    (0x0061, 0x007A),
+    (0x00AA, 0x00AA),
+    (0x00B5, 0x00B5),
+    (0x00BA, 0x00BA),
+    (0x00C0, 0x00D6),
+    (0x00D8, 0x00F6),
+    (0x00F8, 0x01BA),
+    (0x01BC, 0x01BF),
+    (0x01C4, 0x0293),
+    (0x0295, 0x02B8),
+    (0x02C0, 0x02C1),
+    (0x02E0, 0x02E4),
+    (0x0345, 0x0345),
+    (0x0370, 0x0373),
+    (0x0376, 0x0377),
+    (0x037A, 0x037D),
+    (0x037F, 0x037F),
+    (0x0386, 0x0386),
+    (0x0388, 0x038A),
+    (0x038C, 0x038C),
+    (0x038E, 0x03A1),
+    (0x03A3, 0x03F5),
+    (0x03F7, 0x0481),
+    (0x048A, 0x052F),
+    (0x0531, 0x0556),
+    (0x0560, 0x0588),
+    (0x10A0, 0x10C5),
+    (0x10C7, 0x10C7),
+    (0x10CD, 0x10CD),
+    (0x10D0, 0x10FA),
+    (0x10FD, 0x10FF),
+    (0x13A0, 0x13F5),
+    (0x13F8, 0x13FD),
+    (0x1C80, 0x1C88),
+    (0x1C90, 0x1CBA),
+    (0x1CBD, 0x1CBF),
+    (0x1D00, 0x1DBF),
+    (0x1E00, 0x1F15),
+    (0x1F18, 0x1F1D),
+    (0x1F20, 0x1F45),
+    (0x1F48, 0x1F4D),
+    (0x1F50, 0x1F57),
+    (0x1F59, 0x1F59),
+    (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D),
+    (0x1F5F, 0x1F7D),
+    (0x1F80, 0x1FB4),
+    (0x1FB6, 0x1FBC),
+    (0x1FBE, 0x1FBE),
+    (0x1FC2, 0x1FC4),
+    (0x1FC6, 0x1FCC),
+    (0x1FD0, 0x1FD3),
+    (0x1FD6, 0x1FDB),
+    (0x1FE0, 0x1FEC),
+    (0x1FF2, 0x1FF4),
+    (0x1FF6, 0x1FFC),
+    (0x2071, 0x2071),
+    (0x207F, 0x207F),
+    (0x2090, 0x209C),
+    (0x2102, 0x2102),
+    (0x2107, 0x2107),
+    (0x210A, 0x2113),
+    (0x2115, 0x2115),
+    (0x2119, 0x211D),
+    (0x2124, 0x2124),
+    (0x2126, 0x2126),
+    (0x2128, 0x2128),
+    (0x212A, 0x212D),
+    (0x212F, 0x2134),
+    (0x2139, 0x2139),
+    (0x213C, 0x213F),
+    (0x2145, 0x2149),
+    (0x214E, 0x214E),
+    (0x2160, 0x217F),
+    (0x2183, 0x2184),
+    (0x24B6, 0x24E9),
+    (0x2C00, 0x2C2E),
+    (0x2C30, 0x2C5E),
+    (0x2C60, 0x2CE4),
+    (0x2CEB, 0x2CEE),
+    (0x2CF2, 0x2CF3),
+    (0x2D00, 0x2D25),
+    (0x2D27, 0x2D27),
+    (0x2D2D, 0x2D2D),
+    (0xA640, 0xA66D),
+    (0xA680, 0xA69D),
+    (0xA722, 0xA787),
+    (0xA78B, 0xA78E),
+    (0xA790, 0xA7BF),
+    (0xA7C2, 0xA7CA),
+    (0xA7F5, 0xA7F6),
+    (0xA7F8, 0xA7FA),
+    (0xAB30, 0xAB5A),
+    (0xAB5C, 0xAB68),
+    (0xAB70, 0xABBF),
+    (0xFB00, 0xFB06),
+    (0xFB13, 0xFB17),
+    (0xFF21, 0xFF3A),
+    (0xFF41, 0xFF5A),
+    (0x10400, 0x1044F),
+    (0x104B0, 0x104D3),
+    (0x104D8, 0x104FB),
+    (0x10C80, 0x10CB2),
+    (0x10CC0, 0x10CF2),
+    (0x118A0, 0x118DF),
+    (0x16E40, 0x16E7F),
+    (0x1D400, 0x1D454),
+    (0x1D456, 0x1D49C),
+    (0x1D49E, 0x1D49F),
+    (0x1D4A2, 0x1D4A2),
+    (0x1D4A5, 0x1D4A6),
+    (0x1D4A9, 0x1D4AC),
+    (0x1D4AE, 0x1D4B9),
+    (0x1D4BB, 0x1D4BB),
+    (0x1D4BD, 0x1D4C3),
+    (0x1D4C5, 0x1D505),
+    (0x1D507, 0x1D50A),
+    (0x1D50D, 0x1D514),
+    (0x1D516, 0x1D51C),
+    (0x1D51E, 0x1D539),
+    (0x1D53B, 0x1D53E),
+    (0x1D540, 0x1D544),
+    (0x1D546, 0x1D546),
+    (0x1D54A, 0x1D550),
+    (0x1D552, 0x1D6A5),
+    (0x1D6A8, 0x1D6C0),
+    (0x1D6C2, 0x1D6DA),
+    (0x1D6DC, 0x1D6FA),
+    (0x1D6FC, 0x1D714),
+    (0x1D716, 0x1D734),
+    (0x1D736, 0x1D74E),
+    (0x1D750, 0x1D76E),
+    (0x1D770, 0x1D788),
+    (0x1D78A, 0x1D7A8),
+    (0x1D7AA, 0x1D7C2),
+    (0x1D7C4, 0x1D7CB),
+    (0x1E900, 0x1E943),
+    (0x1F130, 0x1F149),
+    (0x1F150, 0x1F169),
+    (0x1F170, 0x1F189),
+];
+
+pub(super) const CASE_IGNORABLE_RANGES: &[(u32, u32)] = &[
+    (0x0027, 0x0027),
+    (0x002E, 0x002E),
+    (0x003A, 0x003A),
+    (0x005E, 0x005E),
+    (0x0060, 0x0060),
+    (0x00A8, 0x00A8),
+    (0x00AD, 0x00AD),
+    (0x00AF, 0x00AF),
+    (0x00B4, 0x00B4),
+    (0x00B7, 0x00B8),
+    (0x02B0, 0x036F),
+    (0x0374, 0x0375),
+    (0x037A, 0x037A),
+    (0x0384, 0x0385),
+    (0x0387, 0x0387),
+    (0x0483, 0x0489),
+    (0x0559, 0x0559),
+    (0x055F, 0x055F),
+    (0x0591, 0x05BD),
+    (0x05BF, 0x05BF),
+    (0x05C1, 0x05C2),
+    (0x05C4, 0x05C5),
+    (0x05C7, 0x05C7),
+    (0x05F4, 0x05F4),
+    (0x0600, 0x0605),
+    (0x0610, 0x061A),
+    (0x061C, 0x061C),
+    (0x0640, 0x0640),
+    (0x064B, 0x065F),
+    (0x0670, 0x0670),
+    (0x06D6, 0x06DD),
+    (0x06DF, 0x06E8),
+    (0x06EA, 0x06ED),
+    (0x070F, 0x070F),
+    (0x0711, 0x0711),
+    (0x0730, 0x074A),
+    (0x07A6, 0x07B0),
+    (0x07EB, 0x07F5),
+    (0x07FA, 0x07FA),
+    (0x07FD, 0x07FD),
+    (0x0816, 0x082D),
+    (0x0859, 0x085B),
+    (0x08D3, 0x0902),
+    (0x093A, 0x093A),
+    (0x093C, 0x093C),
+    (0x0941, 0x0948),
+    (0x094D, 0x094D),
+    (0x0951, 0x0957),
+    (0x0962, 0x0963),
+    (0x0971, 0x0971),
+    (0x0981, 0x0981),
+    (0x09BC, 0x09BC),
+    (0x09C1, 0x09C4),
+    (0x09CD, 0x09CD),
+    (0x09E2, 0x09E3),
+    (0x09FE, 0x09FE),
+    (0x0A01, 0x0A02),
+    (0x0A3C, 0x0A3C),
+    (0x0A41, 0x0A42),
+    (0x0A47, 0x0A48),
+    (0x0A4B, 0x0A4D),
+    (0x0A51, 0x0A51),
+    (0x0A70, 0x0A71),
+    (0x0A75, 0x0A75),
+    (0x0A81, 0x0A82),
+    (0x0ABC, 0x0ABC),
+    (0x0AC1, 0x0AC5),
+    (0x0AC7, 0x0AC8),
+    (0x0ACD, 0x0ACD),
+    (0x0AE2, 0x0AE3),
+    (0x0AFA, 0x0AFF),
+    (0x0B01, 0x0B01),
+    (0x0B3C, 0x0B3C),
+    (0x0B3F, 0x0B3F),
+    (0x0B41, 0x0B44),
+    (0x0B4D, 0x0B4D),
+    (0x0B55, 0x0B56),
+    (0x0B62, 0x0B63),
+    (0x0B82, 0x0B82),
+    (0x0BC0, 0x0BC0),
+    (0x0BCD, 0x0BCD),
+    (0x0C00, 0x0C00),
+    (0x0C04, 0x0C04),
+    (0x0C3E, 0x0C40),
+    (0x0C46, 0x0C48),
+    (0x0C4A, 0x0C4D),
+    (0x0C55, 0x0C56),
+    (0x0C62, 0x0C63),
+    (0x0C81, 0x0C81),
+    (0x0CBC, 0x0CBC),
+    (0x0CBF, 0x0CBF),
+    (0x0CC6, 0x0CC6),
+    (0x0CCC, 0x0CCD),
+    (0x0CE2, 0x0CE3),
+    (0x0D00, 0x0D01),
+    (0x0D3B, 0x0D3C),
+    (0x0D41, 0x0D44),
+    (0x0D4D, 0x0D4D),
+    (0x0D62, 0x0D63),
+    (0x0D81, 0x0D81),
+    (0x0DCA, 0x0DCA),
+    (0x0DD2, 0x0DD4),
+    (0x0DD6, 0x0DD6),
+    (0x0E31, 0x0E31),
+    (0x0E34, 0x0E3A),
+    (0x0E46, 0x0E4E),
+    (0x0EB1, 0x0EB1),
+    (0x0EB4, 0x0EBC),
+    (0x0EC6, 0x0EC6),
+    (0x0EC8, 0x0ECD),
+    (0x0F18, 0x0F19),
+    (0x0F35, 0x0F35),
+    (0x0F37, 0x0F37),
+    (0x0F39, 0x0F39),
+    (0x0F71, 0x0F7E),
+    (0x0F80, 0x0F84),
+    (0x0F86, 0x0F87),
+    (0x0F8D, 0x0F97),
+    (0x0F99, 0x0FBC),
+    (0x0FC6, 0x0FC6),
+    (0x102D, 0x1030),
+    (0x1032, 0x1037),
+    (0x1039, 0x103A),
+    (0x103D, 0x103E),
+    (0x1058, 0x1059),
+    (0x105E, 0x1060),
+    (0x1071, 0x1074),
+    (0x1082, 0x1082),
+    (0x1085, 0x1086),
+    (0x108D, 0x108D),
+    (0x109D, 0x109D),
+    (0x10FC, 0x10FC),
+    (0x135D, 0x135F),
+    (0x1712, 0x1714),
+    (0x1732, 0x1733),
+    (0x1752, 0x1753),
+    (0x1772, 0x1773),
+    (0x17B4, 0x17B5),
+    (0x17B7, 0x17BD),
+    (0x17C6, 0x17C6),
+    (0x17C9, 0x17D3),
+    (0x17D7, 0x17D7),
+    (0x17DD, 0x17DD),
+    (0x180B, 0x180E),
+    (0x1843, 0x1843),
+    (0x1885, 0x1886),
+    (0x18A9, 0x18A9),
+    (0x1920, 0x1922),
+    (0x1927, 0x1928),
+    (0x1932, 0x1932),
+    (0x1939, 0x193B),
+    (0x1A17, 0x1A18),
+    (0x1A1B, 0x1A1B),
+    (0x1A56, 0x1A56),
+    (0x1A58, 0x1A5E),
+    (0x1A60, 0x1A60),
+    (0x1A62, 0x1A62),
+    (0x1A65, 0x1A6C),
+    (0x1A73, 0x1A7C),
+    (0x1A7F, 0x1A7F),
+    (0x1AA7, 0x1AA7),
+    (0x1AB0, 0x1AC0),
+    (0x1B00, 0x1B03),
+    (0x1B34, 0x1B34),
+    (0x1B36, 0x1B3A),
+    (0x1B3C, 0x1B3C),
+    (0x1B42, 0x1B42),
+    (0x1B6B, 0x1B73),
+    (0x1B80, 0x1B81),
+    (0x1BA2, 0x1BA5),
+    (0x1BA8, 0x1BA9),
+    (0x1BAB, 0x1BAD),
+    (0x1BE6, 0x1BE6),
+    (0x1BE8, 0x1BE9),
+    (0x1BED, 0x1BED),
+    (0x1BEF, 0x1BF1),
+    (0x1C2C, 0x1C33),
+    (0x1C36, 0x1C37),
+    (0x1C78, 0x1C7D),
+    (0x1CD0, 0x1CD2),
+    (0x1CD4, 0x1CE0),
+    (0x1CE2, 0x1CE8),
+    (0x1CED, 0x1CED),
+    (0x1CF4, 0x1CF4),
+    (0x1CF8, 0x1CF9),
+    (0x1D2C, 0x1D6A),
+    (0x1D78, 0x1D78),
+    (0x1D9B, 0x1DF9),
+    (0x1DFB, 0x1DFF),
+    (0x1FBD, 0x1FBD),
+    (0x1FBF, 0x1FC1),
+    (0x1FCD, 0x1FCF),
+    (0x1FDD, 0x1FDF),
+    (0x1FED, 0x1FEF),
+    (0x1FFD, 0x1FFE),
+    (0x200B, 0x200F),
+    (0x2018, 0x2019),
+    (0x2024, 0x2024),
+    (0x2027, 0x2027),
+    (0x202A, 0x202E),
+    (0x2060, 0x2064),
+    (0x2066, 0x206F),
+    (0x2071, 0x2071),
+    (0x207F, 0x207F),
+    (0x2090, 0x209C),
+    (0x20D0, 0x20F0),
+    (0x2C7C, 0x2C7D),
+    (0x2CEF, 0x2CF1),
+    (0x2D6F, 0x2D6F),
+    (0x2D7F, 0x2D7F),
+    (0x2DE0, 0x2DFF),
+    (0x2E2F, 0x2E2F),
+    (0x3005, 0x3005),
+    (0x302A, 0x302D),
+    (0x3031, 0x3035),
+    (0x303B, 0x303B),
+    (0x3099, 0x309E),
+    (0x30FC, 0x30FE),
+    (0xA015, 0xA015),
+    (0xA4F8, 0xA4FD),
+    (0xA60C, 0xA60C),
+    (0xA66F, 0xA672),
+    (0xA674, 0xA67D),
+    (0xA67F, 0xA67F),
+    (0xA69C, 0xA69F),
+    (0xA6F0, 0xA6F1),
+    (0xA700, 0xA721),
+    (0xA770, 0xA770),
+    (0xA788, 0xA78A),
+    (0xA7F8, 0xA7F9),
+    (0xA802, 0xA802),
+    (0xA806, 0xA806),
+    (0xA80B, 0xA80B),
+    (0xA825, 0xA826),
+    (0xA82C, 0xA82C),
+    (0xA8C4, 0xA8C5),
+    (0xA8E0, 0xA8F1),
+    (0xA8FF, 0xA8FF),
+    (0xA926, 0xA92D),
+    (0xA947, 0xA951),
+    (0xA980, 0xA982),
+    (0xA9B3, 0xA9B3),
+    (0xA9B6, 0xA9B9),
+    (0xA9BC, 0xA9BD),
+    (0xA9CF, 0xA9CF),
+    (0xA9E5, 0xA9E6),
+    (0xAA29, 0xAA2E),
+    (0xAA31, 0xAA32),
+    (0xAA35, 0xAA36),
+    (0xAA43, 0xAA43),
+    (0xAA4C, 0xAA4C),
+    (0xAA70, 0xAA70),
+    (0xAA7C, 0xAA7C),
+    (0xAAB0, 0xAAB0),
+    (0xAAB2, 0xAAB4),
+    (0xAAB7, 0xAAB8),
+    (0xAABE, 0xAABF),
+    (0xAAC1, 0xAAC1),
+    (0xAADD, 0xAADD),
+    (0xAAEC, 0xAAED),
+    (0xAAF3, 0xAAF4),
+    (0xAAF6, 0xAAF6),
+    (0xAB5B, 0xAB5F),
+    (0xAB69, 0xAB6B),
+    (0xABE5, 0xABE5),
+    (0xABE8, 0xABE8),
+    (0xABED, 0xABED),
+    (0xFB1E, 0xFB1E),
+    (0xFBB2, 0xFBC1),
+    (0xFE00, 0xFE0F),
+    (0xFE13, 0xFE13),
+    (0xFE20, 0xFE2F),
+    (0xFE52, 0xFE52),
+    (0xFE55, 0xFE55),
+    (0xFEFF, 0xFEFF),
+    (0xFF07, 0xFF07),
+    (0xFF0E, 0xFF0E),
+    (0xFF1A, 0xFF1A),
+    (0xFF3E, 0xFF3E),
+    (0xFF40, 0xFF40),
+    (0xFF70, 0xFF70),
+    (0xFF9E, 0xFF9F),
+    (0xFFE3, 0xFFE3),
+    (0xFFF9, 0xFFFB),
+    (0x101FD, 0x101FD),
+    (0x102E0, 0x102E0),
+    (0x10376, 0x1037A),
+    (0x10A01, 0x10A03),
+    (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A0F),
+    (0x10A38, 0x10A3A),
+    (0x10A3F, 0x10A3F),
+    (0x10AE5, 0x10AE6),
+    (0x10D24, 0x10D27),
+    (0x10EAB, 0x10EAC),
+    (0x10F46, 0x10F50),
+    (0x11001, 0x11001),
+    (0x11038, 0x11046),
+    (0x1107F, 0x11081),
+    (0x110B3, 0x110B6),
+    (0x110B9, 0x110BA),
+    (0x110BD, 0x110BD),
+    (0x110CD, 0x110CD),
+    (0x11100, 0x11102),
+    (0x11127, 0x1112B),
+    (0x1112D, 0x11134),
+    (0x11173, 0x11173),
+    (0x11180, 0x11181),
+    (0x111B6, 0x111BE),
+    (0x111C9, 0x111CC),
+    (0x111CF, 0x111CF),
+    (0x1122F, 0x11231),
+    (0x11234, 0x11234),
+    (0x11236, 0x11237),
+    (0x1123E, 0x1123E),
+    (0x112DF, 0x112DF),
+    (0x112E3, 0x112EA),
+    (0x11300, 0x11301),
+    (0x1133B, 0x1133C),
+    (0x11340, 0x11340),
+    (0x11366, 0x1136C),
+    (0x11370, 0x11374),
+    (0x11438, 0x1143F),
+    (0x11442, 0x11444),
+    (0x11446, 0x11446),
+    (0x1145E, 0x1145E),
+    (0x114B3, 0x114B8),
+    (0x114BA, 0x114BA),
+    (0x114BF, 0x114C0),
+    (0x114C2, 0x114C3),
+    (0x115B2, 0x115B5),
+    (0x115BC, 0x115BD),
+    (0x115BF, 0x115C0),
+    (0x115DC, 0x115DD),
+    (0x11633, 0x1163A),
+    (0x1163D, 0x1163D),
+    (0x1163F, 0x11640),
+    (0x116AB, 0x116AB),
+    (0x116AD, 0x116AD),
+    (0x116B0, 0x116B5),
+    (0x116B7, 0x116B7),
+    (0x1171D, 0x1171F),
+    (0x11722, 0x11725),
+    (0x11727, 0x1172B),
+    (0x1182F, 0x11837),
+    (0x11839, 0x1183A),
+    (0x1193B, 0x1193C),
+    (0x1193E, 0x1193E),
+    (0x11943, 0x11943),
+    (0x119D4, 0x119D7),
+    (0x119DA, 0x119DB),
+    (0x119E0, 0x119E0),
+    (0x11A01, 0x11A0A),
+    (0x11A33, 0x11A38),
+    (0x11A3B, 0x11A3E),
+    (0x11A47, 0x11A47),
+    (0x11A51, 0x11A56),
+    (0x11A59, 0x11A5B),
+    (0x11A8A, 0x11A96),
+    (0x11A98, 0x11A99),
+    (0x11C30, 0x11C36),
+    (0x11C38, 0x11C3D),
+    (0x11C3F, 0x11C3F),
+    (0x11C92, 0x11CA7),
+    (0x11CAA, 0x11CB0),
+    (0x11CB2, 0x11CB3),
+    (0x11CB5, 0x11CB6),
+    (0x11D31, 0x11D36),
+    (0x11D3A, 0x11D3A),
+    (0x11D3C, 0x11D3D),
+    (0x11D3F, 0x11D45),
+    (0x11D47, 0x11D47),
+    (0x11D90, 0x11D91),
+    (0x11D95, 0x11D95),
+    (0x11D97, 0x11D97),
+    (0x11EF3, 0x11EF4),
+    (0x13430, 0x13438),
+    (0x16AF0, 0x16AF4),
+    (0x16B30, 0x16B36),
+    (0x16B40, 0x16B43),
+    (0x16F4F, 0x16F4F),
+    (0x16F8F, 0x16F9F),
+    (0x16FE0, 0x16FE1),
+    (0x16FE3, 0x16FE4),
+    (0x1BC9D, 0x1BC9E),
+    (0x1BCA0, 0x1BCA3),
+    (0x1D167, 0x1D169),
+    (0x1D173, 0x1D182),
+    (0x1D185, 0x1D18B),
+    (0x1D1AA, 0x1D1AD),
+    (0x1D242, 0x1D244),
+    (0x1DA00, 0x1DA36),
+    (0x1DA3B, 0x1DA6C),
+    (0x1DA75, 0x1DA75),
+    (0x1DA84, 0x1DA84),
+    (0x1DA9B, 0x1DA9F),
+    (0x1DAA1, 0x1DAAF),
+    (0x1E000, 0x1E006),
+    (0x1E008, 0x1E018),
+    (0x1E01B, 0x1E021),
+    (0x1E023, 0x1E024),
+    (0x1E026, 0x1E02A),
+    (0x1E130, 0x1E13D),
+    (0x1E2EC, 0x1E2EF),
+    (0x1E8D0, 0x1E8D6),
+    (0x1E944, 0x1E94B),
+    (0x1F3FB, 0x1F3FF),
+    (0xE0001, 0xE0001),
+    (0xE0020, 0xE007F),
+    (0xE0100, 0xE01EF),
+];
+
+pub(super) const SOFT_DOTTED_RANGES: &[(u32, u32)] = &[
+    (0x0069, 0x006A),
+    (0x012F, 0x012F),
+    (0x0249, 0x0249),
+    (0x0268, 0x0268),
+    (0x029D, 0x029D),
+    (0x02B2, 0x02B2),
+    (0x03F3, 0x03F3),
+    (0x0456, 0x0456),
+    (0x0458, 0x0458),
+    (0x1D62, 0x1D62),
+    (0x1D96, 0x1D96),
+    (0x1DA4, 0x1DA4),
+    (0x1DA8, 0x1DA8),
+    (0x1E2D, 0x1E2D),
+    (0x1ECB, 0x1ECB),
+    (0x2071, 0x2071),
+    (0x2148, 0x2149),
+    (0x2C7C, 0x2C7C),
+    (0x1D422, 0x1D423),
+    (0x1D456, 0x1D457),
+    (0x1D48A, 0x1D48B),
+    (0x1D4BE, 0x1D4BF),
+    (0x1D4F2, 0x1D4F3),
+    (0x1D526, 0x1D527),
+    (0x1D55A, 0x1D55B),
+    (0x1D58E, 0x1D58F),
+    (0x1D5C2, 0x1D5C3),
+    (0x1D5F6, 0x1D5F7),
+    (0x1D62A, 0x1D62B),
+    (0x1D65E, 0x1D65F),
+    (0x1D692, 0x1D693),
+];
+
+pub(super) const CHANGES_WHEN_LOWERCASED_RANGES: &[(u32, u32)] = &[
+    (0x0041, 0x005A),
+    (0x00C0, 0x00D6),
+    (0x00D8, 0x00DE),
+    (0x0100, 0x0100),
+    (0x0102, 0x0102),
+    (0x0104, 0x0104),
+    (0x0106, 0x0106),
+    (0x0108, 0x0108),
+    (0x010A, 0x010A),
+    (0x010C, 0x010C),
+    (0x010E, 0x010E),
+    (0x0110, 0x0110),
+    (0x0112, 0x0112),
+    (0x0114, 0x0114),
+    (0x0116, 0x0116),
+    (0x0118, 0x0118),
+    (0x011A, 0x011A),
+    (0x011C, 0x011C),
+    (0x011E, 0x011E),
+    (0x0120, 0x0120),
+    (0x0122, 0x0122),
+    (0x0124, 0x0124),
+    (0x0126, 0x0126),
+    (0x0128, 0x0128),
+    (0x012A, 0x012A),
+    (0x012C, 0x012C),
+    (0x012E, 0x012E),
+    (0x0130, 0x0130),
+    (0x0132, 0x0132),
+    (0x0134, 0x0134),
+    (0x0136, 0x0136),
+    (0x0139, 0x0139),
+    (0x013B, 0x013B),
+    (0x013D, 0x013D),
+    (0x013F, 0x013F),
+    (0x0141, 0x0141),
+    (0x0143, 0x0143),
+    (0x0145, 0x0145),
+    (0x0147, 0x0147),
+    (0x014A, 0x014A),
+    (0x014C, 0x014C),
+    (0x014E, 0x014E),
+    (0x0150, 0x0150),
+    (0x0152, 0x0152),
+    (0x0154, 0x0154),
+    (0x0156, 0x0156),
+    (0x0158, 0x0158),
+    (0x015A, 0x015A),
+    (0x015C, 0x015C),
+    (0x015E, 0x015E),
+    (0x0160, 0x0160),
+    (0x0162, 0x0162),
+    (0x0164, 0x0164),
+    (0x0166, 0x0166),
+    (0x0168, 0x0168),
+    (0x016A, 0x016A),
+    (0x016C, 0x016C),
+    (0x016E, 0x016E),
+    (0x0170, 0x0170),
+    (0x0172, 0x0172),
+    (0x0174, 0x0174),
+    (0x0176, 0x0176),
+    (0x0178, 0x0179),
+    (0x017B, 0x017B),
+    (0x017D, 0x017D),
+    (0x0181, 0x0182),
+    (0x0184, 0x0184),
+    (0x0186, 0x0187),
+    (0x0189, 0x018B),
+    (0x018E, 0x0191),
+    (0x0193, 0x0194),
+    (0x0196, 0x0198),
+    (0x019C, 0x019D),
+    (0x019F, 0x01A0),
+    (0x01A2, 0x01A2),
+    (0x01A4, 0x01A4),
+    (0x01A6, 0x01A7),
+    (0x01A9, 0x01A9),
+    (0x01AC, 0x01AC),
+    (0x01AE, 0x01AF),
+    (0x01B1, 0x01B3),
+    (0x01B5, 0x01B5),
+    (0x01B7, 0x01B8),
+    (0x01BC, 0x01BC),
+    (0x01C4, 0x01C5),
+    (0x01C7, 0x01C8),
+    (0x01CA, 0x01CB),
+    (0x01CD, 0x01CD),
+    (0x01CF, 0x01CF),
+    (0x01D1, 0x01D1),
+    (0x01D3, 0x01D3),
+    (0x01D5, 0x01D5),
+    (0x01D7, 0x01D7),
+    (0x01D9, 0x01D9),
+    (0x01DB, 0x01DB),
+    (0x01DE, 0x01DE),
+    (0x01E0, 0x01E0),
+    (0x01E2, 0x01E2),
+    (0x01E4, 0x01E4),
+    (0x01E6, 0x01E6),
+    (0x01E8, 0x01E8),
+    (0x01EA, 0x01EA),
+    (0x01EC, 0x01EC),
+    (0x01EE, 0x01EE),
+    (0x01F1, 0x01F2),
+    (0x01F4, 0x01F4),
+    (0x01F6, 0x01F8),
+    (0x01FA, 0x01FA),
+    (0x01FC, 0x01FC),
+    (0x01FE, 0x01FE),
+    (0x0200, 0x0200),
+    (0x0202, 0x0202),
+    (0x0204, 0x0204),
+    (0x0206, 0x0206),
+    (0x0208, 0x0208),
+    (0x020A, 0x020A),
+    (0x020C, 0x020C),
+    (0x020E, 0x020E),
+    (0x0210, 0x0210),
+    (0x0212, 0x0212),
+    (0x0214, 0x0214),
+    (0x0216, 0x0216),
+    (0x0218, 0x0218),
+    (0x021A, 0x021A),
+    (0x021C, 0x021C),
+    (0x021E, 0x021E),
+    (0x0220, 0x0220),
+    (0x0222, 0x0222),
+    (0x0224, 0x0224),
+    (0x0226, 0x0226),
+    (0x0228, 0x0228),
+    (0x022A, 0x022A),
+    (0x022C, 0x022C),
+    (0x022E, 0x022E),
+    (0x0230, 0x0230),
+    (0x0232, 0x0232),
+    (0x023A, 0x023B),
+    (0x023D, 0x023E),
+    (0x0241, 0x0241),
+    (0x0243, 0x0246),
+    (0x0248, 0x0248),
+    (0x024A, 0x024A),
+    (0x024C, 0x024C),
+    (0x024E, 0x024E),
+    (0x0370, 0x0370),
+    (0x0372, 0x0372),
+    (0x0376, 0x0376),
+    (0x037F, 0x037F),
+    (0x0386, 0x0386),
+    (0x0388, 0x038A),
+    (0x038C, 0x038C),
+    (0x038E, 0x038F),
+    (0x0391, 0x03A1),
+    (0x03A3, 0x03AB),
+    (0x03CF, 0x03CF),
+    (0x03D8, 0x03D8),
+    (0x03DA, 0x03DA),
+    (0x03DC, 0x03DC),
+    (0x03DE, 0x03DE),
+    (0x03E0, 0x03E0),
+    (0x03E2, 0x03E2),
+    (0x03E4, 0x03E4),
+    (0x03E6, 0x03E6),
+    (0x03E8, 0x03E8),
+    (0x03EA, 0x03EA),
+    (0x03EC, 0x03EC),
+    (0x03EE, 0x03EE),
+    (0x03F4, 0x03F4),
+    (0x03F7, 0x03F7),
+    (0x03F9, 0x03FA),
+    (0x03FD, 0x042F),
+    (0x0460, 0x0460),
+    (0x0462, 0x0462),
+    (0x0464, 0x0464),
+    (0x0466, 0x0466),
+    (0x0468, 0x0468),
+    (0x046A, 0x046A),
+    (0x046C, 0x046C),
+    (0x046E, 0x046E),
+    (0x0470, 0x0470),
+    (0x0472, 0x0472),
+    (0x0474, 0x0474),
+    (0x0476, 0x0476),
+    (0x0478, 0x0478),
+    (0x047A, 0x047A),
+    (0x047C, 0x047C),
+    (0x047E, 0x047E),
+    (0x0480, 0x0480),
+    (0x048A, 0x048A),
+    (0x048C, 0x048C),
+    (0x048E, 0x048E),
+    (0x0490, 0x0490),
+    (0x0492, 0x0492),
+    (0x0494, 0x0494),
+    (0x0496, 0x0496),
+    (0x0498, 0x0498),
+    (0x049A, 0x049A),
+    (0x049C, 0x049C),
+    (0x049E, 0x049E),
+    (0x04A0, 0x04A0),
+    (0x04A2, 0x04A2),
+    (0x04A4, 0x04A4),
+    (0x04A6, 0x04A6),
+    (0x04A8, 0x04A8),
+    (0x04AA, 0x04AA),
+    (0x04AC, 0x04AC),
+    (0x04AE, 0x04AE),
+    (0x04B0, 0x04B0),
+    (0x04B2, 0x04B2),
+    (0x04B4, 0x04B4),
+    (0x04B6, 0x04B6),
+    (0x04B8, 0x04B8),
+    (0x04BA, 0x04BA),
+    (0x04BC, 0x04BC),
+    (0x04BE, 0x04BE),
+    (0x04C0, 0x04C1),
+    (0x04C3, 0x04C3),
+    (0x04C5, 0x04C5),
+    (0x04C7, 0x04C7),
+    (0x04C9, 0x04C9),
+    (0x04CB, 0x04CB),
+    (0x04CD, 0x04CD),
+    (0x04D0, 0x04D0),
+    (0x04D2, 0x04D2),
+    (0x04D4, 0x04D4),
+    (0x04D6, 0x04D6),
+    (0x04D8, 0x04D8),
+    (0x04DA, 0x04DA),
+    (0x04DC, 0x04DC),
+    (0x04DE, 0x04DE),
+    (0x04E0, 0x04E0),
+    (0x04E2, 0x04E2),
+    (0x04E4, 0x04E4),
+    (0x04E6, 0x04E6),
+    (0x04E8, 0x04E8),
+    (0x04EA, 0x04EA),
+    (0x04EC, 0x04EC),
+    (0x04EE, 0x04EE),
+    (0x04F0, 0x04F0),
+    (0x04F2, 0x04F2),
+    (0x04F4, 0x04F4),
+    (0x04F6, 0x04F6),
+    (0x04F8, 0x04F8),
+    (0x04FA, 0x04FA),
+    (0x04FC, 0x04FC),
+    (0x04FE, 0x04FE),
+    (0x0500, 0x0500),
+    (0x0502, 0x0502),
+    (0x0504, 0x0504),
+    (0x0506, 0x0506),
+    (0x0508, 0x0508),
+    (0x050A, 0x050A),
+    (0x050C, 0x050C),
+    (0x050E, 0x050E),
+    (0x0510, 0x0510),
+    (0x0512, 0x0512),
+    (0x0514, 0x0514),
+    (0x0516, 0x0516),
+    (0x0518, 0x0518),
+    (0x051A, 0x051A),
+    (0x051C, 0x051C),
+    (0x051E, 0x051E),
+    (0x0520, 0x0520),
+    (0x0522, 0x0522),
+    (0x0524, 0x0524),
+    (0x0526, 0x0526),
+    (0x0528, 0x0528),
+    (0x052A, 0x052A),
+    (0x052C, 0x052C),
+    (0x052E, 0x052E),
+    (0x0531, 0x0556),
+    (0x10A0, 0x10C5),
+    (0x10C7, 0x10C7),
+    (0x10CD, 0x10CD),
+    (0x13A0, 0x13F5),
+    (0x1C90, 0x1CBA),
+    (0x1CBD, 0x1CBF),
+    (0x1E00, 0x1E00),
+    (0x1E02, 0x1E02),
+    (0x1E04, 0x1E04),
+    (0x1E06, 0x1E06),
+    (0x1E08, 0x1E08),
+    (0x1E0A, 0x1E0A),
+    (0x1E0C, 0x1E0C),
+    (0x1E0E, 0x1E0E),
+    (0x1E10, 0x1E10),
+    (0x1E12, 0x1E12),
+    (0x1E14, 0x1E14),
+    (0x1E16, 0x1E16),
+    (0x1E18, 0x1E18),
+    (0x1E1A, 0x1E1A),
+    (0x1E1C, 0x1E1C),
+    (0x1E1E, 0x1E1E),
+    (0x1E20, 0x1E20),
+    (0x1E22, 0x1E22),
+    (0x1E24, 0x1E24),
+    (0x1E26, 0x1E26),
+    (0x1E28, 0x1E28),
+    (0x1E2A, 0x1E2A),
+    (0x1E2C, 0x1E2C),
+    (0x1E2E, 0x1E2E),
+    (0x1E30, 0x1E30),
+    (0x1E32, 0x1E32),
+    (0x1E34, 0x1E34),
+    (0x1E36, 0x1E36),
+    (0x1E38, 0x1E38),
+    (0x1E3A, 0x1E3A),
+    (0x1E3C, 0x1E3C),
+    (0x1E3E, 0x1E3E),
+    (0x1E40, 0x1E40),
+    (0x1E42, 0x1E42),
+    (0x1E44, 0x1E44),
+    (0x1E46, 0x1E46),
+    (0x1E48, 0x1E48),
+    (0x1E4A, 0x1E4A),
+    (0x1E4C, 0x1E4C),
+    (0x1E4E, 0x1E4E),
+    (0x1E50, 0x1E50),
+    (0x1E52, 0x1E52),
+    (0x1E54, 0x1E54),
+    (0x1E56, 0x1E56),
+    (0x1E58, 0x1E58),
+    (0x1E5A, 0x1E5A),
+    (0x1E5C, 0x1E5C),
+    (0x1E5E, 0x1E5E),
+    (0x1E60, 0x1E60),
+    (0x1E62, 0x1E62),
+    (0x1E64, 0x1E64),
+    (0x1E66, 0x1E66),
+    (0x1E68, 0x1E68),
+    (0x1E6A, 0x1E6A),
+    (0x1E6C, 0x1E6C),
+    (0x1E6E, 0x1E6E),
+    (0x1E70, 0x1E70),
+    (0x1E72, 0x1E72),
+    (0x1E74, 0x1E74),
+    (0x1E76, 0x1E76),
+    (0x1E78, 0x1E78),
+    (0x1E7A, 0x1E7A),
+    (0x1E7C, 0x1E7C),
+    (0x1E7E, 0x1E7E),
+    (0x1E80, 0x1E80),
+    (0x1E82, 0x1E82),
+    (0x1E84, 0x1E84),
+    (0x1E86, 0x1E86),
+    (0x1E88, 0x1E88),
+    (0x1E8A, 0x1E8A),
+    (0x1E8C, 0x1E8C),
+    (0x1E8E, 0x1E8E),
+    (0x1E90, 0x1E90),
+    (0x1E92, 0x1E92),
+    (0x1E94, 0x1E94),
+    (0x1E9E, 0x1E9E),
+    (0x1EA0, 0x1EA0),
+    (0x1EA2, 0x1EA2),
+    (0x1EA4, 0x1EA4),
+    (0x1EA6, 0x1EA6),
+    (0x1EA8, 0x1EA8),
+    (0x1EAA, 0x1EAA),
+    (0x1EAC, 0x1EAC),
+    (0x1EAE, 0x1EAE),
+    (0x1EB0, 0x1EB0),
+    (0x1EB2, 0x1EB2),
+    (0x1EB4, 0x1EB4),
+    (0x1EB6, 0x1EB6),
+    (0x1EB8, 0x1EB8),
+    (0x1EBA, 0x1EBA),
+    (0x1EBC, 0x1EBC),
+    (0x1EBE, 0x1EBE),
+    (0x1EC0, 0x1EC0),
+    (0x1EC2, 0x1EC2),
+    (0x1EC4, 0x1EC4),
+    (0x1EC6, 0x1EC6),
+    (0x1EC8, 0x1EC8),
+    (0x1ECA, 0x1ECA),
+    (0x1ECC, 0x1ECC),
+    (0x1ECE, 0x1ECE),
+    (0x1ED0, 0x1ED0),
+    (0x1ED2, 0x1ED2),
+    (0x1ED4, 0x1ED4),
+    (0x1ED6, 0x1ED6),
+    (0x1ED8, 0x1ED8),
+    (0x1EDA, 0x1EDA),
+    (0x1EDC, 0x1EDC),
+    (0x1EDE, 0x1EDE),
+    (0x1EE0, 0x1EE0),
+    (0x1EE2, 0x1EE2),
+    (0x1EE4, 0x1EE4),
+    (0x1EE6, 0x1EE6),
+    (0x1EE8, 0x1EE8),
+    (0x1EEA, 0x1EEA),
+    (0x1EEC, 0x1EEC),
+    (0x1EEE, 0x1EEE),
+    (0x1EF0, 0x1EF0),
+    (0x1EF2, 0x1EF2),
+    (0x1EF4, 0x1EF4),
+    (0x1EF6, 0x1EF6),
+    (0x1EF8, 0x1EF8),
+    (0x1EFA, 0x1EFA),
+    (0x1EFC, 0x1EFC),
+    (0x1EFE, 0x1EFE),
+    (0x1F08, 0x1F0F),
+    (0x1F18, 0x1F1D),
+    (0x1F28, 0x1F2F),
+    (0x1F38, 0x1F3F),
+    (0x1F48, 0x1F4D),
+    (0x1F59, 0x1F59),
+    (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D),
+    (0x1F5F, 0x1F5F),
+    (0x1F68, 0x1F6F),
+    (0x1F88, 0x1F8F),
+    (0x1F98, 0x1F9F),
+    (0x1FA8, 0x1FAF),
+    (0x1FB8, 0x1FBC),
+    (0x1FC8, 0x1FCC),
+    (0x1FD8, 0x1FDB),
+    (0x1FE8, 0x1FEC),
+    (0x1FF8, 0x1FFC),
+    (0x2126, 0x2126),
+    (0x212A, 0x212B),
+    (0x2132, 0x2132),
+    (0x2160, 0x216F),
+    (0x2183, 0x2183),
+    (0x24B6, 0x24CF),
+    (0x2C00, 0x2C2E),
+    (0x2C60, 0x2C60),
+    (0x2C62, 0x2C64),
+    (0x2C67, 0x2C67),
+    (0x2C69, 0x2C69),
+    (0x2C6B, 0x2C6B),
+    (0x2C6D, 0x2C70),
+    (0x2C72, 0x2C72),
+    (0x2C75, 0x2C75),
+    (0x2C7E, 0x2C80),
+    (0x2C82, 0x2C82),
+    (0x2C84, 0x2C84),
+    (0x2C86, 0x2C86),
+    (0x2C88, 0x2C88),
+    (0x2C8A, 0x2C8A),
+    (0x2C8C, 0x2C8C),
+    (0x2C8E, 0x2C8E),
+    (0x2C90, 0x2C90),
+    (0x2C92, 0x2C92),
+    (0x2C94, 0x2C94),
+    (0x2C96, 0x2C96),
+    (0x2C98, 0x2C98),
+    (0x2C9A, 0x2C9A),
+    (0x2C9C, 0x2C9C),
+    (0x2C9E, 0x2C9E),
+    (0x2CA0, 0x2CA0),
+    (0x2CA2, 0x2CA2),
+    (0x2CA4, 0x2CA4),
+    (0x2CA6, 0x2CA6),
+    (0x2CA8, 0x2CA8),
+    (0x2CAA, 0x2CAA),
+    (0x2CAC, 0x2CAC),
+    (0x2CAE, 0x2CAE),
+    (0x2CB0, 0x2CB0),
+    (0x2CB2, 0x2CB2),
+    (0x2CB4, 0x2CB4),
+    (0x2CB6, 0x2CB6),
+    (0x2CB8, 0x2CB8),
+    (0x2CBA, 0x2CBA),
+    (0x2CBC, 0x2CBC),
+    (0x2CBE, 0x2CBE),
+    (0x2CC0, 0x2CC0),
+    (0x2CC2, 0x2CC2),
+    (0x2CC4, 0x2CC4),
+    (0x2CC6, 0x2CC6),
+    (0x2CC8, 0x2CC8),
+    (0x2CCA, 0x2CCA),
+    (0x2CCC, 0x2CCC),
+    (0x2CCE, 0x2CCE),
+    (0x2CD0, 0x2CD0),
+    (0x2CD2, 0x2CD2),
+    (0x2CD4, 0x2CD4),
+    (0x2CD6, 0x2CD6),
+    (0x2CD8, 0x2CD8),
+    (0x2CDA, 0x2CDA),
+    (0x2CDC, 0x2CDC),
+    (0x2CDE, 0x2CDE),
+    (0x2CE0, 0x2CE0),
+    (0x2CE2, 0x2CE2),
+    (0x2CEB, 0x2CEB),
+    (0x2CED, 0x2CED),
+    (0x2CF2, 0x2CF2),
+    (0xA640, 0xA640),
+    (0xA642, 0xA642),
+    (0xA644, 0xA644),
+    (0xA646, 0xA646),
+    (0xA648, 0xA648),
+    (0xA64A, 0xA64A),
+    (0xA64C, 0xA64C),
+    (0xA64E, 0xA64E),
+    (0xA650, 0xA650),
+    (0xA652, 0xA652),
+    (0xA654, 0xA654),
+    (0xA656, 0xA656),
+    (0xA658, 0xA658),
+    (0xA65A, 0xA65A),
+    (0xA65C, 0xA65C),
+    (0xA65E, 0xA65E),
+    (0xA660, 0xA660),
+    (0xA662, 0xA662),
+    (0xA664, 0xA664),
+    (0xA666, 0xA666),
+    (0xA668, 0xA668),
+    (0xA66A, 0xA66A),
+    (0xA66C, 0xA66C),
+    (0xA680, 0xA680),
+    (0xA682, 0xA682),
+    (0xA684, 0xA684),
+    (0xA686, 0xA686),
+    (0xA688, 0xA688),
+    (0xA68A, 0xA68A),
+    (0xA68C, 0xA68C),
+    (0xA68E, 0xA68E),
+    (0xA690, 0xA690),
+    (0xA692, 0xA692),
+    (0xA694, 0xA694),
+    (0xA696, 0xA696),
+    (0xA698, 0xA698),
+    (0xA69A, 0xA69A),
+    (0xA722, 0xA722),
+    (0xA724, 0xA724),
+    (0xA726, 0xA726),
+    (0xA728, 0xA728),
+    (0xA72A, 0xA72A),
+    (0xA72C, 0xA72C),
+    (0xA72E, 0xA72E),
+    (0xA732, 0xA732),
+    (0xA734, 0xA734),
+    (0xA736, 0xA736),
+    (0xA738, 0xA738),
+    (0xA73A, 0xA73A),
+    (0xA73C, 0xA73C),
+    (0xA73E, 0xA73E),
+    (0xA740, 0xA740),
+    (0xA742, 0xA742),
+    (0xA744, 0xA744),
+    (0xA746, 0xA746),
+    (0xA748, 0xA748),
+    (0xA74A, 0xA74A),
+    (0xA74C, 0xA74C),
+    (0xA74E, 0xA74E),
+    (0xA750, 0xA750),
+    (0xA752, 0xA752),
+    (0xA754, 0xA754),
+    (0xA756, 0xA756),
+    (0xA758, 0xA758),
+    (0xA75A, 0xA75A),
+    (0xA75C, 0xA75C),
+    (0xA75E, 0xA75E),
+    (0xA760, 0xA760),
+    (0xA762, 0xA762),
+    (0xA764, 0xA764),
+    (0xA766, 0xA766),
+    (0xA768, 0xA768),
+    (0xA76A, 0xA76A),
+    (0xA76C, 0xA76C),
+    (0xA76E, 0xA76E),
+    (0xA779, 0xA779),
+    (0xA77B, 0xA77B),
+    (0xA77D, 0xA77E),
+    (0xA780, 0xA780),
+    (0xA782, 0xA782),
+    (0xA784, 0xA784),
+    (0xA786, 0xA786),
+    (0xA78B, 0xA78B),
+    (0xA78D, 0xA78D),
+    (0xA790, 0xA790),
+    (0xA792, 0xA792),
+    (0xA796, 0xA796),
+    (0xA798, 0xA798),
+    (0xA79A, 0xA79A),
+    (0xA79C, 0xA79C),
+    (0xA79E, 0xA79E),
+    (0xA7A0, 0xA7A0),
+    (0xA7A2, 0xA7A2),
+    (0xA7A4, 0xA7A4),
+    (0xA7A6, 0xA7A6),
+    (0xA7A8, 0xA7A8),
+    (0xA7AA, 0xA7AE),
+    (0xA7B0, 0xA7B4),
+    (0xA7B6, 0xA7B6),
+    (0xA7B8, 0xA7B8),
+    (0xA7BA, 0xA7BA),
+    (0xA7BC, 0xA7BC),
+    (0xA7BE, 0xA7BE),
+    (0xA7C2, 0xA7C2),
+    (0xA7C4, 0xA7C7),
+    (0xA7C9, 0xA7C9),
+    (0xA7F5, 0xA7F5),
+    (0xFF21, 0xFF3A),
+    (0x10400, 0x10427),
+    (0x104B0, 0x104D3),
+    (0x10C80, 0x10CB2),
+    (0x118A0, 0x118BF),
+    (0x16E40, 0x16E5F),
+    (0x1E900, 0x1E921),
+];
+
+pub(super) const CHANGES_WHEN_UPPERCASED_RANGES: &[(u32, u32)] = &[
+    (0x0061, 0x007A),
+    (0x00B5, 0x00B5),
+    (0x00DF, 0x00F6),
+    (0x00F8, 0x00FF),
+    (0x0101, 0x0101),
+    (0x0103, 0x0103),
+    (0x0105, 0x0105),
+    (0x0107, 0x0107),
+    (0x0109, 0x0109),
+    (0x010B, 0x010B),
+    (0x010D, 0x010D),
+    (0x010F, 0x010F),
+    (0x0111, 0x0111),
+    (0x0113, 0x0113),
+    (0x0115, 0x0115),
+    (0x0117, 0x0117),
+    (0x0119, 0x0119),
+    (0x011B, 0x011B),
+    (0x011D, 0x011D),
+    (0x011F, 0x011F),
+    (0x0121, 0x0121),
+    (0x0123, 0x0123),
+    (0x0125, 0x0125),
+    (0x0127, 0x0127),
+    (0x0129, 0x0129),
+    (0x012B, 0x012B),
+    (0x012D, 0x012D),
+    (0x012F, 0x012F),
+    (0x0131, 0x0131),
+    (0x0133, 0x0133),
+    (0x0135, 0x0135),
+    (0x0137, 0x0137),
+    (0x013A, 0x013A),
+    (0x013C, 0x013C),
+    (0x013E, 0x013E),
+    (0x0140, 0x0140),
+    (0x0142, 0x0142),
+    (0x0144, 0x0144),
+    (0x0146, 0x0146),
+    (0x0148, 0x0149),
+    (0x014B, 0x014B),
+    (0x014D, 0x014D),
+    (0x014F, 0x014F),
+    (0x0151, 0x0151),
+    (0x0153, 0x0153),
+    (0x0155, 0x0155),
+    (0x0157, 0x0157),
+    (0x0159, 0x0159),
+    (0x015B, 0x015B),
+    (0x015D, 0x015D),
+    (0x015F, 0x015F),
+    (0x0161, 0x0161),
+    (0x0163, 0x0163),
+    (0x0165, 0x0165),
+    (0x0167, 0x0167),
+    (0x0169, 0x0169),
+    (0x016B, 0x016B),
+    (0x016D, 0x016D),
+    (0x016F, 0x016F),
+    (0x0171, 0x0171),
+    (0x0173, 0x0173),
+    (0x0175, 0x0175),
+    (0x0177, 0x0177),
+    (0x017A, 0x017A),
+    (0x017C, 0x017C),
+    (0x017E, 0x0180),
+    (0x0183, 0x0183),
+    (0x0185, 0x0185),
+    (0x0188, 0x0188),
+    (0x018C, 0x018C),
+    (0x0192, 0x0192),
+    (0x0195, 0x0195),
+    (0x0199, 0x019A),
+    (0x019E, 0x019E),
+    (0x01A1, 0x01A1),
+    (0x01A3, 0x01A3),
+    (0x01A5, 0x01A5),
+    (0x01A8, 0x01A8),
+    (0x01AD, 0x01AD),
+    (0x01B0, 0x01B0),
+    (0x01B4, 0x01B4),
+    (0x01B6, 0x01B6),
+    (0x01B9, 0x01B9),
+    (0x01BD, 0x01BD),
+    (0x01BF, 0x01BF),
+    (0x01C5, 0x01C6),
+    (0x01C8, 0x01C9),
+    (0x01CB, 0x01CC),
+    (0x01CE, 0x01CE),
+    (0x01D0, 0x01D0),
+    (0x01D2, 0x01D2),
+    (0x01D4, 0x01D4),
+    (0x01D6, 0x01D6),
+    (0x01D8, 0x01D8),
+    (0x01DA, 0x01DA),
+    (0x01DC, 0x01DD),
+    (0x01DF, 0x01DF),
+    (0x01E1, 0x01E1),
+    (0x01E3, 0x01E3),
+    (0x01E5, 0x01E5),
+    (0x01E7, 0x01E7),
+    (0x01E9, 0x01E9),
+    (0x01EB, 0x01EB),
+    (0x01ED, 0x01ED),
+    (0x01EF, 0x01F0),
+    (0x01F2, 0x01F3),
+    (0x01F5, 0x01F5),
+    (0x01F9, 0x01F9),
+    (0x01FB, 0x01FB),
+    (0x01FD, 0x01FD),
+    (0x01FF, 0x01FF),
+    (0x0201, 0x0201),
+    (0x0203, 0x0203),
+    (0x0205, 0x0205),
+    (0x0207, 0x0207),
+    (0x0209, 0x0209),
+    (0x020B, 0x020B),
+    (0x020D, 0x020D),
+    (0x020F, 0x020F),
+    (0x0211, 0x0211),
+    (0x0213, 0x0213),
+    (0x0215, 0x0215),
+    (0x0217, 0x0217),
+    (0x0219, 0x0219),
+    (0x021B, 0x021B),
+    (0x021D, 0x021D),
+    (0x021F, 0x021F),
+    (0x0223, 0x0223),
+    (0x0225, 0x0225),
+    (0x0227, 0x0227),
+    (0x0229, 0x0229),
+    (0x022B, 0x022B),
+    (0x022D, 0x022D),
+    (0x022F, 0x022F),
+    (0x0231, 0x0231),
+    (0x0233, 0x0233),
+    (0x023C, 0x023C),
+    (0x023F, 0x0240),
+    (0x0242, 0x0242),
+    (0x0247, 0x0247),
+    (0x0249, 0x0249),
+    (0x024B, 0x024B),
+    (0x024D, 0x024D),
+    (0x024F, 0x0254),
+    (0x0256, 0x0257),
+    (0x0259, 0x0259),
+    (0x025B, 0x025C),
+    (0x0260, 0x0261),
+    (0x0263, 0x0263),
+    (0x0265, 0x0266),
+    (0x0268, 0x026C),
+    (0x026F, 0x026F),
+    (0x0271, 0x0272),
+    (0x0275, 0x0275),
+    (0x027D, 0x027D),
+    (0x0280, 0x0280),
+    (0x0282, 0x0283),
+    (0x0287, 0x028C),
+    (0x0292, 0x0292),
+    (0x029D, 0x029E),
+    (0x0345, 0x0345),
+    (0x0371, 0x0371),
+    (0x0373, 0x0373),
+    (0x0377, 0x0377),
+    (0x037B, 0x037D),
+    (0x0390, 0x0390),
+    (0x03AC, 0x03CE),
+    (0x03D0, 0x03D1),
+    (0x03D5, 0x03D7),
+    (0x03D9, 0x03D9),
+    (0x03DB, 0x03DB),
+    (0x03DD, 0x03DD),
+    (0x03DF, 0x03DF),
+    (0x03E1, 0x03E1),
+    (0x03E3, 0x03E3),
+    (0x03E5, 0x03E5),
+    (0x03E7, 0x03E7),
+    (0x03E9, 0x03E9),
+    (0x03EB, 0x03EB),
+    (0x03ED, 0x03ED),
+    (0x03EF, 0x03F3),
+    (0x03F5, 0x03F5),
+    (0x03F8, 0x03F8),
+    (0x03FB, 0x03FB),
+    (0x0430, 0x045F),
+    (0x0461, 0x0461),
+    (0x0463, 0x0463),
+    (0x0465, 0x0465),
+    (0x0467, 0x0467),
+    (0x0469, 0x0469),
+    (0x046B, 0x046B),
+    (0x046D, 0x046D),
+    (0x046F, 0x046F),
+    (0x0471, 0x0471),
+    (0x0473, 0x0473),
+    (0x0475, 0x0475),
+    (0x0477, 0x0477),
+    (0x0479, 0x0479),
+    (0x047B, 0x047B),
+    (0x047D, 0x047D),
+    (0x047F, 0x047F),
+    (0x0481, 0x0481),
+    (0x048B, 0x048B),
+    (0x048D, 0x048D),
+    (0x048F, 0x048F),
+    (0x0491, 0x0491),
+    (0x0493, 0x0493),
+    (0x0495, 0x0495),
+    (0x0497, 0x0497),
+    (0x0499, 0x0499),
+    (0x049B, 0x049B),
+    (0x049D, 0x049D),
+    (0x049F, 0x049F),
+    (0x04A1, 0x04A1),
+    (0x04A3, 0x04A3),
+    (0x04A5, 0x04A5),
+    (0x04A7, 0x04A7),
+    (0x04A9, 0x04A9),
+    (0x04AB, 0x04AB),
+    (0x04AD, 0x04AD),
+    (0x04AF, 0x04AF),
+    (0x04B1, 0x04B1),
+    (0x04B3, 0x04B3),
+    (0x04B5, 0x04B5),
+    (0x04B7, 0x04B7),
+    (0x04B9, 0x04B9),
+    (0x04BB, 0x04BB),
+    (0x04BD, 0x04BD),
+    (0x04BF, 0x04BF),
+    (0x04C2, 0x04C2),
+    (0x04C4, 0x04C4),
+    (0x04C6, 0x04C6),
+    (0x04C8, 0x04C8),
+    (0x04CA, 0x04CA),
+    (0x04CC, 0x04CC),
+    (0x04CE, 0x04CF),
+    (0x04D1, 0x04D1),
+    (0x04D3, 0x04D3),
+    (0x04D5, 0x04D5),
+    (0x04D7, 0x04D7),
+    (0x04D9, 0x04D9),
+    (0x04DB, 0x04DB),
+    (0x04DD, 0x04DD),
+    (0x04DF, 0x04DF),
+    (0x04E1, 0x04E1),
+    (0x04E3, 0x04E3),
+    (0x04E5, 0x04E5),
+    (0x04E7, 0x04E7),
+    (0x04E9, 0x04E9),
+    (0x04EB, 0x04EB),
+    (0x04ED, 0x04ED),
+    (0x04EF, 0x04EF),
+    (0x04F1, 0x04F1),
+    (0x04F3, 0x04F3),
+    (0x04F5, 0x04F5),
+    (0x04F7, 0x04F7),
+    (0x04F9, 0x04F9),
+    (0x04FB, 0x04FB),
+    (0x04FD, 0x04FD),
+    (0x04FF, 0x04FF),
+    (0x0501, 0x0501),
+    (0x0503, 0x0503),
+    (0x0505, 0x0505),
+    (0x0507, 0x0507),
+    (0x0509, 0x0509),
+    (0x050B, 0x050B),
+    (0x050D, 0x050D),
+    (0x050F, 0x050F),
+    (0x0511, 0x0511),
+    (0x0513, 0x0513),
+    (0x0515, 0x0515),
+    (0x0517, 0x0517),
+    (0x0519, 0x0519),
+    (0x051B, 0x051B),
+    (0x051D, 0x051D),
+    (0x051F, 0x051F),
+    (0x0521, 0x0521),
+    (0x0523, 0x0523),
+    (0x0525, 0x0525),
+    (0x0527, 0x0527),
+    (0x0529, 0x0529),
+    (0x052B, 0x052B),
+    (0x052D, 0x052D),
+    (0x052F, 0x052F),
+    (0x0561, 0x0587),
+    (0x10D0, 0x10FA),
+    (0x10FD, 0x10FF),
+    (0x13F8, 0x13FD),
+    (0x1C80, 0x1C88),
+    (0x1D79, 0x1D79),
+    (0x1D7D, 0x1D7D),
+    (0x1D8E, 0x1D8E),
+    (0x1E01, 0x1E01),
+    (0x1E03, 0x1E03),
+    (0x1E05, 0x1E05),
+    (0x1E07, 0x1E07),
+    (0x1E09, 0x1E09),
+    (0x1E0B, 0x1E0B),
+    (0x1E0D, 0x1E0D),
+    (0x1E0F, 0x1E0F),
+    (0x1E11, 0x1E11),
+    (0x1E13, 0x1E13),
+    (0x1E15, 0x1E15),
+    (0x1E17, 0x1E17),
+    (0x1E19, 0x1E19),
+    (0x1E1B, 0x1E1B),
+    (0x1E1D, 0x1E1D),
+    (0x1E1F, 0x1E1F),
+    (0x1E21, 0x1E21),
+    (0x1E23, 0x1E23),
+    (0x1E25, 0x1E25),
+    (0x1E27, 0x1E27),
+    (0x1E29, 0x1E29),
+    (0x1E2B, 0x1E2B),
+    (0x1E2D, 0x1E2D),
+    (0x1E2F, 0x1E2F),
+    (0x1E31, 0x1E31),
+    (0x1E33, 0x1E33),
+    (0x1E35, 0x1E35),
+    (0x1E37, 0x1E37),
+    (0x1E39, 0x1E39),
+    (0x1E3B, 0x1E3B),
+    (0x1E3D, 0x1E3D),
+    (0x1E3F, 0x1E3F),
+    (0x1E41, 0x1E41),
+    (0x1E43, 0x1E43),
+    (0x1E45, 0x1E45),
+    (0x1E47, 0x1E47),
+    (0x1E49, 0x1E49),
+    (0x1E4B, 0x1E4B),
+    (0x1E4D, 0x1E4D),
+    (0x1E4F, 0x1E4F),
+    (0x1E51, 0x1E51),
+    (0x1E53, 0x1E53),
+    (0x1E55, 0x1E55),
+    (0x1E57, 0x1E57),
+    (0x1E59, 0x1E59),
+    (0x1E5B, 0x1E5B),
+    (0x1E5D, 0x1E5D),
+    (0x1E5F, 0x1E5F),
+    (0x1E61, 0x1E61),
+    (0x1E63, 0x1E63),
+    (0x1E65, 0x1E65),
+    (0x1E67, 0x1E67),
+    (0x1E69, 0x1E69),
+    (0x1E6B, 0x1E6B),
+    (0x1E6D, 0x1E6D),
+    (0x1E6F, 0x1E6F),
+    (0x1E71, 0x1E71),
+    (0x1E73, 0x1E73),
+    (0x1E75, 0x1E75),
+    (0x1E77, 0x1E77),
+    (0x1E79, 0x1E79),
+    (0x1E7B, 0x1E7B),
+    (0x1E7D, 0x1E7D),
+    (0x1E7F, 0x1E7F),
+    (0x1E81, 0x1E81),
+    (0x1E83, 0x1E83),
+    (0x1E85, 0x1E85),
+    (0x1E87, 0x1E87),
+    (0x1E89, 0x1E89),
+    (0x1E8B, 0x1E8B),
+    (0x1E8D, 0x1E8D),
+    (0x1E8F, 0x1E8F),
+    (0x1E91, 0x1E91),
+    (0x1E93, 0x1E93),
+    (0x1E95, 0x1E9B),
+    (0x1EA1, 0x1EA1),
+    (0x1EA3, 0x1EA3),
+    (0x1EA5, 0x1EA5),
+    (0x1EA7, 0x1EA7),
+    (0x1EA9, 0x1EA9),
+    (0x1EAB, 0x1EAB),
+    (0x1EAD, 0x1EAD),
+    (0x1EAF, 0x1EAF),
+    (0x1EB1, 0x1EB1),
+    (0x1EB3, 0x1EB3),
+    (0x1EB5, 0x1EB5),
+    (0x1EB7, 0x1EB7),
+    (0x1EB9, 0x1EB9),
+    (0x1EBB, 0x1EBB),
+    (0x1EBD, 0x1EBD),
+    (0x1EBF, 0x1EBF),
+    (0x1EC1, 0x1EC1),
+    (0x1EC3, 0x1EC3),
+    (0x1EC5, 0x1EC5),
+    (0x1EC7, 0x1EC7),
+    (0x1EC9, 0x1EC9),
+    (0x1ECB, 0x1ECB),
+    (0x1ECD, 0x1ECD),
+    (0x1ECF, 0x1ECF),
+    (0x1ED1, 0x1ED1),
+    (0x1ED3, 0x1ED3),
+    (0x1ED5, 0x1ED5),
+    (0x1ED7, 0x1ED7),
+    (0x1ED9, 0x1ED9),
+    (0x1EDB, 0x1EDB),
+    (0x1EDD, 0x1EDD),
+    (0x1EDF, 0x1EDF),
+    (0x1EE1, 0x1EE1),
+    (0x1EE3, 0x1EE3),
+    (0x1EE5, 0x1EE5),
+    (0x1EE7, 0x1EE7),
+    (0x1EE9, 0x1EE9),
+    (0x1EEB, 0x1EEB),
+    (0x1EED, 0x1EED),
+    (0x1EEF, 0x1EEF),
+    (0x1EF1, 0x1EF1),
+    (0x1EF3, 0x1EF3),
+    (0x1EF5, 0x1EF5),
+    (0x1EF7, 0x1EF7),
+    (0x1EF9, 0x1EF9),
+    (0x1EFB, 0x1EFB),
+    (0x1EFD, 0x1EFD),
+    (0x1EFF, 0x1F07),
+    (0x1F10, 0x1F15),
+    (0x1F20, 0x1F27),
+    (0x1F30, 0x1F37),
+    (0x1F40, 0x1F45),
+    (0x1F50, 0x1F57),
+    (0x1F60, 0x1F67),
+    (0x1F70, 0x1F7D),
+    (0x1F80, 0x1FB4),
+    (0x1FB6, 0x1FB7),
+    (0x1FBC, 0x1FBC),
+    (0x1FBE, 0x1FBE),
+    (0x1FC2, 0x1FC4),
+    (0x1FC6, 0x1FC7),
+    (0x1FCC, 0x1FCC),
+    (0x1FD0, 0x1FD3),
+    (0x1FD6, 0x1FD7),
+    (0x1FE0, 0x1FE7),
+    (0x1FF2, 0x1FF4),
+    (0x1FF6, 0x1FF7),
+    (0x1FFC, 0x1FFC),
+    (0x214E, 0x214E),
+    (0x2170, 0x217F),
+    (0x2184, 0x2184),
+    (0x24D0, 0x24E9),
+    (0x2C30, 0x2C5E),
+    (0x2C61, 0x2C61),
+    (0x2C65, 0x2C66),
+    (0x2C68, 0x2C68),
+    (0x2C6A, 0x2C6A),
+    (0x2C6C, 0x2C6C),
+    (0x2C73, 0x2C73),
+    (0x2C76, 0x2C76),
+    (0x2C81, 0x2C81),
+    (0x2C83, 0x2C83),
+    (0x2C85, 0x2C85),
+    (0x2C87, 0x2C87),
+    (0x2C89, 0x2C89),
+    (0x2C8B, 0x2C8B),
+    (0x2C8D, 0x2C8D),
+    (0x2C8F, 0x2C8F),
+    (0x2C91, 0x2C91),
+    (0x2C93, 0x2C93),
+    (0x2C95, 0x2C95),
+    (0x2C97, 0x2C97),
+    (0x2C99, 0x2C99),
+    (0x2C9B, 0x2C9B),
+    (0x2C9D, 0x2C9D),
+    (0x2C9F, 0x2C9F),
+    (0x2CA1, 0x2CA1),
+    (0x2CA3, 0x2CA3),
+    (0x2CA5, 0x2CA5),
+    (0x2CA7, 0x2CA7),
+    (0x2CA9, 0x2CA9),
+    (0x2CAB, 0x2CAB),
+    (0x2CAD, 0x2CAD),
+    (0x2CAF, 0x2CAF),
+    (0x2CB1, 0x2CB1),
+    (0x2CB3, 0x2CB3),
+    (0x2CB5, 0x2CB5),
+    (0x2CB7, 0x2CB7),
+    (0x2CB9, 0x2CB9),
+    (0x2CBB, 0x2CBB),
+    (0x2CBD, 0x2CBD),
+    (0x2CBF, 0x2CBF),
+    (0x2CC1, 0x2CC1),
+    (0x2CC3, 0x2CC3),
+    (0x2CC5, 0x2CC5),
+    (0x2CC7, 0x2CC7),
+    (0x2CC9, 0x2CC9),
+    (0x2CCB, 0x2CCB),
+    (0x2CCD, 0x2CCD),
+    (0x2CCF, 0x2CCF),
+    (0x2CD1, 0x2CD1),
+    (0x2CD3, 0x2CD3),
+    (0x2CD5, 0x2CD5),
+    (0x2CD7, 0x2CD7),
+    (0x2CD9, 0x2CD9),
+    (0x2CDB, 0x2CDB),
+    (0x2CDD, 0x2CDD),
+    (0x2CDF, 0x2CDF),
+    (0x2CE1, 0x2CE1),
+    (0x2CE3, 0x2CE3),
+    (0x2CEC, 0x2CEC),
+    (0x2CEE, 0x2CEE),
+    (0x2CF3, 0x2CF3),
+    (0x2D00, 0x2D25),
+    (0x2D27, 0x2D27),
+    (0x2D2D, 0x2D2D),
+    (0xA641, 0xA641),
+    (0xA643, 0xA643),
+    (0xA645, 0xA645),
+    (0xA647, 0xA647),
+    (0xA649, 0xA649),
+    (0xA64B, 0xA64B),
+    (0xA64D, 0xA64D),
+    (0xA64F, 0xA64F),
+    (0xA651, 0xA651),
+    (0xA653, 0xA653),
+    (0xA655, 0xA655),
+    (0xA657, 0xA657),
+    (0xA659, 0xA659),
+    (0xA65B, 0xA65B),
+    (0xA65D, 0xA65D),
+    (0xA65F, 0xA65F),
+    (0xA661, 0xA661),
+    (0xA663, 0xA663),
+    (0xA665, 0xA665),
+    (0xA667, 0xA667),
+    (0xA669, 0xA669),
+    (0xA66B, 0xA66B),
+    (0xA66D, 0xA66D),
+    (0xA681, 0xA681),
+    (0xA683, 0xA683),
+    (0xA685, 0xA685),
+    (0xA687, 0xA687),
+    (0xA689, 0xA689),
+    (0xA68B, 0xA68B),
+    (0xA68D, 0xA68D),
+    (0xA68F, 0xA68F),
+    (0xA691, 0xA691),
+    (0xA693, 0xA693),
+    (0xA695, 0xA695),
+    (0xA697, 0xA697),
+    (0xA699, 0xA699),
+    (0xA69B, 0xA69B),
+    (0xA723, 0xA723),
+    (0xA725, 0xA725),
+    (0xA727, 0xA727),
+    (0xA729, 0xA729),
+    (0xA72B, 0xA72B),
+    (0xA72D, 0xA72D),
+    (0xA72F, 0xA72F),
+    (0xA733, 0xA733),
+    (0xA735, 0xA735),
+    (0xA737, 0xA737),
+    (0xA739, 0xA739),
+    (0xA73B, 0xA73B),
+    (0xA73D, 0xA73D),
+    (0xA73F, 0xA73F),
+    (0xA741, 0xA741),
+    (0xA743, 0xA743),
+    (0xA745, 0xA745),
+    (0xA747, 0xA747),
+    (0xA749, 0xA749),
+    (0xA74B, 0xA74B),
+    (0xA74D, 0xA74D),
+    (0xA74F, 0xA74F),
+    (0xA751, 0xA751),
+    (0xA753, 0xA753),
+    (0xA755, 0xA755),
+    (0xA757, 0xA757),
+    (0xA759, 0xA759),
+    (0xA75B, 0xA75B),
+    (0xA75D, 0xA75D),
+    (0xA75F, 0xA75F),
+    (0xA761, 0xA761),
+    (0xA763, 0xA763),
+    (0xA765, 0xA765),
+    (0xA767, 0xA767),
+    (0xA769, 0xA769),
+    (0xA76B, 0xA76B),
+    (0xA76D, 0xA76D),
+    (0xA76F, 0xA76F),
+    (0xA77A, 0xA77A),
+    (0xA77C, 0xA77C),
+    (0xA77F, 0xA77F),
+    (0xA781, 0xA781),
+    (0xA783, 0xA783),
+    (0xA785, 0xA785),
+    (0xA787, 0xA787),
+    (0xA78C, 0xA78C),
+    (0xA791, 0xA791),
+    (0xA793, 0xA794),
+    (0xA797, 0xA797),
+    (0xA799, 0xA799),
+    (0xA79B, 0xA79B),
+    (0xA79D, 0xA79D),
+    (0xA79F, 0xA79F),
+    (0xA7A1, 0xA7A1),
+    (0xA7A3, 0xA7A3),
+    (0xA7A5, 0xA7A5),
+    (0xA7A7, 0xA7A7),
+    (0xA7A9, 0xA7A9),
+    (0xA7B5, 0xA7B5),
+    (0xA7B7, 0xA7B7),
+    (0xA7B9, 0xA7B9),
+    (0xA7BB, 0xA7BB),
+    (0xA7BD, 0xA7BD),
+    (0xA7BF, 0xA7BF),
+    (0xA7C3, 0xA7C3),
+    (0xA7C8, 0xA7C8),
+    (0xA7CA, 0xA7CA),
+    (0xA7F6, 0xA7F6),
+    (0xAB53, 0xAB53),
+    (0xAB70, 0xABBF),
+    (0xFB00, 0xFB06),
+    (0xFB13, 0xFB17),
+    (0xFF41, 0xFF5A),
+    (0x10428, 0x1044F),
+    (0x104D8, 0x104FB),
+    (0x10CC0, 0x10CF2),
+    (0x118C0, 0x118DF),
+    (0x16E60, 0x16E7F),
+    (0x1E922, 0x1E943),
+];
+
+pub(super) const CHANGES_WHEN_TITLECASED_RANGES: &[(u32, u32)] = &[
+    (0x0061, 0x007A),
+    (0x00B5, 0x00B5),
+    (0x00DF, 0x00F6),
+    (0x00F8, 0x00FF),
+    (0x0101, 0x0101),
+    (0x0103, 0x0103),
+    (0x0105, 0x0105),
+    (0x0107, 0x0107),
+    (0x0109, 0x0109),
+    (0x010B, 0x010B),
+    (0x010D, 0x010D),
+    (0x010F, 0x010F),
+    (0x0111, 0x0111),
+    (0x0113, 0x0113),
+    (0x0115, 0x0115),
+    (0x0117, 0x0117),
+    (0x0119, 0x0119),
+    (0x011B, 0x011B),
+    (0x011D, 0x011D),
+    (0x011F, 0x011F),
+    (0x0121, 0x0121),
+    (0x0123, 0x0123),
+    (0x0125, 0x0125),
+    (0x0127, 0x0127),
+    (0x0129, 0x0129),
+    (0x012B, 0x012B),
+    (0x012D, 0x012D),
+    (0x012F, 0x012F),
+    (0x0131, 0x0131),
+    (0x0133, 0x0133),
+    (0x0135, 0x0135),
+    (0x0137, 0x0137),
+    (0x013A, 0x013A),
+    (0x013C, 0x013C),
+    (0x013E, 0x013E),
+    (0x0140, 0x0140),
+    (0x0142, 0x0142),
+    (0x0144, 0x0144),
+    (0x0146, 0x0146),
+    (0x0148, 0x0149),
+    (0x014B, 0x014B),
+    (0x014D, 0x014D),
+    (0x014F, 0x014F),
+    (0x0151, 0x0151),
+    (0x0153, 0x0153),
+    (0x0155, 0x0155),
+    (0x0157, 0x0157),
+    (0x0159, 0x0159),
+    (0x015B, 0x015B),
+    (0x015D, 0x015D),
+    (0x015F, 0x015F),
+    (0x0161, 0x0161),
+    (0x0163, 0x0163),
+    (0x0165, 0x0165),
+    (0x0167, 0x0167),
+    (0x0169, 0x0169),
+    (0x016B, 0x016B),
+    (0x016D, 0x016D),
+    (0x016F, 0x016F),
+    (0x0171, 0x0171),
+    (0x0173, 0x0173),
+    (0x0175, 0x0175),
+    (0x0177, 0x0177),
+    (0x017A, 0x017A),
+    (0x017C, 0x017C),
+    (0x017E, 0x0180),
+    (0x0183, 0x0183),
+    (0x0185, 0x0185),
+    (0x0188, 0x0188),
+    (0x018C, 0x018C),
+    (0x0192, 0x0192),
+    (0x0195, 0x0195),
+    (0x0199, 0x019A),
+    (0x019E, 0x019E),
+    (0x01A1, 0x01A1),
+    (0x01A3, 0x01A3),
+    (0x01A5, 0x01A5),
+    (0x01A8, 0x01A8),
+    (0x01AD, 0x01AD),
+    (0x01B0, 0x01B0),
+    (0x01B4, 0x01B4),
+    (0x01B6, 0x01B6),
+    (0x01B9, 0x01B9),
+    (0x01BD, 0x01BD),
+    (0x01BF, 0x01BF),
+    (0x01C4, 0x01C4),
+    (0x01C6, 0x01C7),
+    (0x01C9, 0x01CA),
+    (0x01CC, 0x01CC),
+    (0x01CE, 0x01CE),
+    (0x01D0, 0x01D0),
+    (0x01D2, 0x01D2),
+    (0x01D4, 0x01D4),
+    (0x01D6, 0x01D6),
+    (0x01D8, 0x01D8),
+    (0x01DA, 0x01DA),
+    (0x01DC, 0x01DD),
+    (0x01DF, 0x01DF),
+    (0x01E1, 0x01E1),
+    (0x01E3, 0x01E3),
+    (0x01E5, 0x01E5),
+    (0x01E7, 0x01E7),
+    (0x01E9, 0x01E9),
+    (0x01EB, 0x01EB),
+    (0x01ED, 0x01ED),
+    (0x01EF, 0x01F1),
+    (0x01F3, 0x01F3),
+    (0x01F5, 0x01F5),
+    (0x01F9, 0x01F9),
+    (0x01FB, 0x01FB),
+    (0x01FD, 0x01FD),
+    (0x01FF, 0x01FF),
+    (0x0201, 0x0201),
+    (0x0203, 0x0203),
+    (0x0205, 0x0205),
+    (0x0207, 0x0207),
+    (0x0209, 0x0209),
+    (0x020B, 0x020B),
+    (0x020D, 0x020D),
+    (0x020F, 0x020F),
+    (0x0211, 0x0211),
+    (0x0213, 0x0213),
+    (0x0215, 0x0215),
+    (0x0217, 0x0217),
+    (0x0219, 0x0219),
+    (0x021B, 0x021B),
+    (0x021D, 0x021D),
+    (0x021F, 0x021F),
+    (0x0223, 0x0223),
+    (0x0225, 0x0225),
+    (0x0227, 0x0227),
+    (0x0229, 0x0229),
+    (0x022B, 0x022B),
+    (0x022D, 0x022D),
+    (0x022F, 0x022F),
+    (0x0231, 0x0231),
+    (0x0233, 0x0233),
+    (0x023C, 0x023C),
+    (0x023F, 0x0240),
+    (0x0242, 0x0242),
+    (0x0247, 0x0247),
+    (0x0249, 0x0249),
+    (0x024B, 0x024B),
+    (0x024D, 0x024D),
+    (0x024F, 0x0254),
+    (0x0256, 0x0257),
+    (0x0259, 0x0259),
+    (0x025B, 0x025C),
+    (0x0260, 0x0261),
+    (0x0263, 0x0263),
+    (0x0265, 0x0266),
+    (0x0268, 0x026C),
+    (0x026F, 0x026F),
+    (0x0271, 0x0272),
+    (0x0275, 0x0275),
+    (0x027D, 0x027D),
+    (0x0280, 0x0280),
+    (0x0282, 0x0283),
+    (0x0287, 0x028C),
+    (0x0292, 0x0292),
+    (0x029D, 0x029E),
+    (0x0345, 0x0345),
+    (0x0371, 0x0371),
+    (0x0373, 0x0373),
+    (0x0377, 0x0377),
+    (0x037B, 0x037D),
+    (0x0390, 0x0390),
+    (0x03AC, 0x03CE),
+    (0x03D0, 0x03D1),
+    (0x03D5, 0x03D7),
+    (0x03D9, 0x03D9),
+    (0x03DB, 0x03DB),
+    (0x03DD, 0x03DD),
+    (0x03DF, 0x03DF),
+    (0x03E1, 0x03E1),
+    (0x03E3, 0x03E3),
+    (0x03E5, 0x03E5),
+    (0x03E7, 0x03E7),
+    (0x03E9, 0x03E9),
+    (0x03EB, 0x03EB),
+    (0x03ED, 0x03ED),
+    (0x03EF, 0x03F3),
+    (0x03F5, 0x03F5),
+    (0x03F8, 0x03F8),
+    (0x03FB, 0x03FB),
+    (0x0430, 0x045F),
+    (0x0461, 0x0461),
+    (0x0463, 0x0463),
+    (0x0465, 0x0465),
+    (0x0467, 0x0467),
+    (0x0469, 0x0469),
+    (0x046B, 0x046B),
+    (0x046D, 0x046D),
+    (0x046F, 0x046F),
+    (0x0471, 0x0471),
+    (0x0473, 0x0473),
+    (0x0475, 0x0475),
+    (0x0477, 0x0477),
+    (0x0479, 0x0479),
+    (0x047B, 0x047B),
+    (0x047D, 0x047D),
+    (0x047F, 0x047F),
+    (0x0481, 0x0481),
+    (0x048B, 0x048B),
+    (0x048D, 0x048D),
+    (0x048F, 0x048F),
+    (0x0491, 0x0491),
+    (0x0493, 0x0493),
+    (0x0495, 0x0495),
+    (0x0497, 0x0497),
+    (0x0499, 0x0499),
+    (0x049B, 0x049B),
+    (0x049D, 0x049D),
+    (0x049F, 0x049F),
+    (0x04A1, 0x04A1),
+    (0x04A3, 0x04A3),
+    (0x04A5, 0x04A5),
+    (0x04A7, 0x04A7),
+    (0x04A9, 0x04A9),
+    (0x04AB, 0x04AB),
+    (0x04AD, 0x04AD),
+    (0x04AF, 0x04AF),
+    (0x04B1, 0x04B1),
+    (0x04B3, 0x04B3),
+    (0x04B5, 0x04B5),
+    (0x04B7, 0x04B7),
+    (0x04B9, 0x04B9),
+    (0x04BB, 0x04BB),
+    (0x04BD, 0x04BD),
+    (0x04BF, 0x04BF),
+    (0x04C2, 0x04C2),
+    (0x04C4, 0x04C4),
+    (0x04C6, 0x04C6),
+    (0x04C8, 0x04C8),
+    (0x04CA, 0x04CA),
+    (0x04CC, 0x04CC),
+    (0x04CE, 0x04CF),
+    (0x04D1, 0x04D1),
+    (0x04D3, 0x04D3),
+    (0x04D5, 0x04D5),
+    (0x04D7, 0x04D7),
+    (0x04D9, 0x04D9),
+    (0x04DB, 0x04DB),
+    (0x04DD, 0x04DD),
+    (0x04DF, 0x04DF),
+    (0x04E1, 0x04E1),
+    (0x04E3, 0x04E3),
+    (0x04E5, 0x04E5),
+    (0x04E7, 0x04E7),
+    (0x04E9, 0x04E9),
+    (0x04EB, 0x04EB),
+    (0x04ED, 0x04ED),
+    (0x04EF, 0x04EF),
+    (0x04F1, 0x04F1),
+    (0x04F3, 0x04F3),
+    (0x04F5, 0x04F5),
+    (0x04F7, 0x04F7),
+    (0x04F9, 0x04F9),
+    (0x04FB, 0x04FB),
+    (0x04FD, 0x04FD),
+    (0x04FF, 0x04FF),
+    (0x0501, 0x0501),
+    (0x0503, 0x0503),
+    (0x0505, 0x0505),
+    (0x0507, 0x0507),
+    (0x0509, 0x0509),
+    (0x050B, 0x050B),
+    (0x050D, 0x050D),
+    (0x050F, 0x050F),
+    (0x0511, 0x0511),
+    (0x0513, 0x0513),
+    (0x0515, 0x0515),
+    (0x0517, 0x0517),
+    (0x0519, 0x0519),
+    (0x051B, 0x051B),
+    (0x051D, 0x051D),
+    (0x051F, 0x051F),
+    (0x0521, 0x0521),
+    (0x0523, 0x0523),
+    (0x0525, 0x0525),
+    (0x0527, 0x0527),
+    (0x0529, 0x0529),
+    (0x052B, 0x052B),
+    (0x052D, 0x052D),
+    (0x052F, 0x052F),
+    (0x0561, 0x0587),
+    (0x13F8, 0x13FD),
+    (0x1C80, 0x1C88),
+    (0x1D79, 0x1D79),
+    (0x1D7D, 0x1D7D),
+    (0x1D8E, 0x1D8E),
+    (0x1E01, 0x1E01),
+    (0x1E03, 0x1E03),
+    (0x1E05, 0x1E05),
+    (0x1E07, 0x1E07),
+    (0x1E09, 0x1E09),
+    (0x1E0B, 0x1E0B),
+    (0x1E0D, 0x1E0D),
+    (0x1E0F, 0x1E0F),
+    (0x1E11, 0x1E11),
+    (0x1E13, 0x1E13),
+    (0x1E15, 0x1E15),
+    (0x1E17, 0x1E17),
+    (0x1E19, 0x1E19),
+    (0x1E1B, 0x1E1B),
+    (0x1E1D, 0x1E1D),
+    (0x1E1F, 0x1E1F),
+    (0x1E21, 0x1E21),
+    (0x1E23, 0x1E23),
+    (0x1E25, 0x1E25),
+    (0x1E27, 0x1E27),
+    (0x1E29, 0x1E29),
+    (0x1E2B, 0x1E2B),
+    (0x1E2D, 0x1E2D),
+    (0x1E2F, 0x1E2F),
+    (0x1E31, 0x1E31),
+    (0x1E33, 0x1E33),
+    (0x1E35, 0x1E35),
+    (0x1E37, 0x1E37),
+    (0x1E39, 0x1E39),
+    (0x1E3B, 0x1E3B),
+    (0x1E3D, 0x1E3D),
+    (0x1E3F, 0x1E3F),
+    (0x1E41, 0x1E41),
+    (0x1E43, 0x1E43),
+    (0x1E45, 0x1E45),
+    (0x1E47, 0x1E47),
+    (0x1E49, 0x1E49),
+    (0x1E4B, 0x1E4B),
+    (0x1E4D, 0x1E4D),
+    (0x1E4F, 0x1E4F),
+    (0x1E51, 0x1E51),
+    (0x1E53, 0x1E53),
+    (0x1E55, 0x1E55),
+    (0x1E57, 0x1E57),
+    (0x1E59, 0x1E59),
+    (0x1E5B, 0x1E5B),
+    (0x1E5D, 0x1E5D),
+    (0x1E5F, 0x1E5F),
+    (0x1E61, 0x1E61),
+    (0x1E63, 0x1E63),
+    (0x1E65, 0x1E65),
+    (0x1E67, 0x1E67),
+    (0x1E69, 0x1E69),
+    (0x1E6B, 0x1E6B),
+    (0x1E6D, 0x1E6D),
+    (0x1E6F, 0x1E6F),
+    (0x1E71, 0x1E71),
+    (0x1E73, 0x1E73),
+    (0x1E75, 0x1E75),
+    (0x1E77, 0x1E77),
+    (0x1E79, 0x1E79),
+    (0x1E7B, 0x1E7B),
+    (0x1E7D, 0x1E7D),
+    (0x1E7F, 0x1E7F),
+    (0x1E81, 0x1E81),
+    (0x1E83, 0x1E83),
+    (0x1E85, 0x1E85),
+    (0x1E87, 0x1E87),
+    (0x1E89, 0x1E89),
+    (0x1E8B, 0x1E8B),
+    (0x1E8D, 0x1E8D),
+    (0x1E8F, 0x1E8F),
+    (0x1E91, 0x1E91),
+    (0x1E93, 0x1E93),
+    (0x1E95, 0x1E9B),
+    (0x1EA1, 0x1EA1),
+    (0x1EA3, 0x1EA3),
+    (0x1EA5, 0x1EA5),
+    (0x1EA7, 0x1EA7),
+    (0x1EA9, 0x1EA9),
+    (0x1EAB, 0x1EAB),
+    (0x1EAD, 0x1EAD),
+    (0x1EAF, 0x1EAF),
+    (0x1EB1, 0x1EB1),
+    (0x1EB3, 0x1EB3),
+    (0x1EB5, 0x1EB5),
+    (0x1EB7, 0x1EB7),
+    (0x1EB9, 0x1EB9),
+    (0x1EBB, 0x1EBB),
+    (0x1EBD, 0x1EBD),
+    (0x1EBF, 0x1EBF),
+    (0x1EC1, 0x1EC1),
+    (0x1EC3, 0x1EC3),
+    (0x1EC5, 0x1EC5),
+    (0x1EC7, 0x1EC7),
+    (0x1EC9, 0x1EC9),
+    (0x1ECB, 0x1ECB),
+    (0x1ECD, 0x1ECD),
+    (0x1ECF, 0x1ECF),
+    (0x1ED1, 0x1ED1),
+    (0x1ED3, 0x1ED3),
+    (0x1ED5, 0x1ED5),
+    (0x1ED7, 0x1ED7),
+    (0x1ED9, 0x1ED9),
+    (0x1EDB, 0x1EDB),
+    (0x1EDD, 0x1EDD),
+    (0x1EDF, 0x1EDF),
+    (0x1EE1, 0x1EE1),
+    (0x1EE3, 0x1EE3),
+    (0x1EE5, 0x1EE5),
+    (0x1EE7, 0x1EE7),
+    (0x1EE9, 0x1EE9),
+    (0x1EEB, 0x1EEB),
+    (0x1EED, 0x1EED),
+    (0x1EEF, 0x1EEF),
+    (0x1EF1, 0x1EF1),
+    (0x1EF3, 0x1EF3),
+    (0x1EF5, 0x1EF5),
+    (0x1EF7, 0x1EF7),
+    (0x1EF9, 0x1EF9),
+    (0x1EFB, 0x1EFB),
+    (0x1EFD, 0x1EFD),
+    (0x1EFF, 0x1F07),
+    (0x1F10, 0x1F15),
+    (0x1F20, 0x1F27),
+    (0x1F30, 0x1F37),
+    (0x1F40, 0x1F45),
+    (0x1F50, 0x1F57),
+    (0x1F60, 0x1F67),
+    (0x1F70, 0x1F7D),
+    (0x1F80, 0x1F87),
+    (0x1F90, 0x1F97),
+    (0x1FA0, 0x1FA7),
+    (0x1FB0, 0x1FB4),
+    (0x1FB6, 0x1FB7),
+    (0x1FBE, 0x1FBE),
+    (0x1FC2, 0x1FC4),
+    (0x1FC6, 0x1FC7),
+    (0x1FD0, 0x1FD3),
+    (0x1FD6, 0x1FD7),
+    (0x1FE0, 0x1FE7),
+    (0x1FF2, 0x1FF4),
+    (0x1FF6, 0x1FF7),
+    (0x214E, 0x214E),
+    (0x2170, 0x217F),
+    (0x2184, 0x2184),
+    (0x24D0, 0x24E9),
+    (0x2C30, 0x2C5E),
+    (0x2C61, 0x2C61),
+    (0x2C65, 0x2C66),
+    (0x2C68, 0x2C68),
+    (0x2C6A, 0x2C6A),
+    (0x2C6C, 0x2C6C),
+    (0x2C73, 0x2C73),
+    (0x2C76, 0x2C76),
+    (0x2C81, 0x2C81),
+    (0x2C83, 0x2C83),
+    (0x2C85, 0x2C85),
+    (0x2C87, 0x2C87),
+    (0x2C89, 0x2C89),
+    (0x2C8B, 0x2C8B),
+    (0x2C8D, 0x2C8D),
+    (0x2C8F, 0x2C8F),
+    (0x2C91, 0x2C91),
+    (0x2C93, 0x2C93),
+    (0x2C95, 0x2C95),
+    (0x2C97, 0x2C97),
+    (0x2C99, 0x2C99),
+    (0x2C9B, 0x2C9B),
+    (0x2C9D, 0x2C9D),
+    (0x2C9F, 0x2C9F),
+    (0x2CA1, 0x2CA1),
+    (0x2CA3, 0x2CA3),
+    (0x2CA5, 0x2CA5),
+    (0x2CA7, 0x2CA7),
+    (0x2CA9, 0x2CA9),
+    (0x2CAB, 0x2CAB),
+    (0x2CAD, 0x2CAD),
+    (0x2CAF, 0x2CAF),
+    (0x2CB1, 0x2CB1),
+    (0x2CB3, 0x2CB3),
+    (0x2CB5, 0x2CB5),
+    (0x2CB7, 0x2CB7),
+    (0x2CB9, 0x2CB9),
+    (0x2CBB, 0x2CBB),
+    (0x2CBD, 0x2CBD),
+    (0x2CBF, 0x2CBF),
+    (0x2CC1, 0x2CC1),
+    (0x2CC3, 0x2CC3),
+    (0x2CC5, 0x2CC5),
+    (0x2CC7, 0x2CC7),
+    (0x2CC9, 0x2CC9),
+    (0x2CCB, 0x2CCB),
+    (0x2CCD, 0x2CCD),
+    (0x2CCF, 0x2CCF),
+    (0x2CD1, 0x2CD1),
+    (0x2CD3, 0x2CD3),
+    (0x2CD5, 0x2CD5),
+    (0x2CD7, 0x2CD7),
+    (0x2CD9, 0x2CD9),
+    (0x2CDB, 0x2CDB),
+    (0x2CDD, 0x2CDD),
+    (0x2CDF, 0x2CDF),
+    (0x2CE1, 0x2CE1),
+    (0x2CE3, 0x2CE3),
+    (0x2CEC, 0x2CEC),
+    (0x2CEE, 0x2CEE),
+    (0x2CF3, 0x2CF3),
+    (0x2D00, 0x2D25),
+    (0x2D27, 0x2D27),
+    (0x2D2D, 0x2D2D),
+    (0xA641, 0xA641),
+    (0xA643, 0xA643),
+    (0xA645, 0xA645),
+    (0xA647, 0xA647),
+    (0xA649, 0xA649),
+    (0xA64B, 0xA64B),
+    (0xA64D, 0xA64D),
+    (0xA64F, 0xA64F),
+    (0xA651, 0xA651),
+    (0xA653, 0xA653),
+    (0xA655, 0xA655),
+    (0xA657, 0xA657),
+    (0xA659, 0xA659),
+    (0xA65B, 0xA65B),
+    (0xA65D, 0xA65D),
+    (0xA65F, 0xA65F),
+    (0xA661, 0xA661),
+    (0xA663, 0xA663),
+    (0xA665, 0xA665),
+    (0xA667, 0xA667),
+    (0xA669, 0xA669),
+    (0xA66B, 0xA66B),
+    (0xA66D, 0xA66D),
+    (0xA681, 0xA681),
+    (0xA683, 0xA683),
+    (0xA685, 0xA685),
+    (0xA687, 0xA687),
+    (0xA689, 0xA689),
+    (0xA68B, 0xA68B),
+    (0xA68D, 0xA68D),
+    (0xA68F, 0xA68F),
+    (0xA691, 0xA691),
+    (0xA693, 0xA693),
+    (0xA695, 0xA695),
+    (0xA697, 0xA697),
+    (0xA699, 0xA699),
+    (0xA69B, 0xA69B),
+    (0xA723, 0xA723),
+    (0xA725, 0xA725),
+    (0xA727, 0xA727),
+    (0xA729, 0xA729),
+    (0xA72B, 0xA72B),
+    (0xA72D, 0xA72D),
+    (0xA72F, 0xA72F),
+    (0xA733, 0xA733),
+    (0xA735, 0xA735),
+    (0xA737, 0xA737),
+    (0xA739, 0xA739),
+    (0xA73B, 0xA73B),
+    (0xA73D, 0xA73D),
+    (0xA73F, 0xA73F),
+    (0xA741, 0xA741),
+    (0xA743, 0xA743),
+    (0xA745, 0xA745),
+    (0xA747, 0xA747),
+    (0xA749, 0xA749),
+    (0xA74B, 0xA74B),
+    (0xA74D, 0xA74D),
+    (0xA74F, 0xA74F),
+    (0xA751, 0xA751),
+    (0xA753, 0xA753),
+    (0xA755, 0xA755),
+    (0xA757, 0xA757),
+    (0xA759, 0xA759),
+    (0xA75B, 0xA75B),
+    (0xA75D, 0xA75D),
+    (0xA75F, 0xA75F),
+    (0xA761, 0xA761),
+    (0xA763, 0xA763),
+    (0xA765, 0xA765),
+    (0xA767, 0xA767),
+    (0xA769, 0xA769),
+    (0xA76B, 0xA76B),
+    (0xA76D, 0xA76D),
+    (0xA76F, 0xA76F),
+    (0xA77A, 0xA77A),
+    (0xA77C, 0xA77C),
+    (0xA77F, 0xA77F),
+    (0xA781, 0xA781),
+    (0xA783, 0xA783),
+    (0xA785, 0xA785),
+    (0xA787, 0xA787),
+    (0xA78C, 0xA78C),
+    (0xA791, 0xA791),
+    (0xA793, 0xA794),
+    (0xA797, 0xA797),
+    (0xA799, 0xA799),
+    (0xA79B, 0xA79B),
+    (0xA79D, 0xA79D),
+    (0xA79F, 0xA79F),
+    (0xA7A1, 0xA7A1),
+    (0xA7A3, 0xA7A3),
+    (0xA7A5, 0xA7A5),
+    (0xA7A7, 0xA7A7),
+    (0xA7A9, 0xA7A9),
+    (0xA7B5, 0xA7B5),
+    (0xA7B7, 0xA7B7),
+    (0xA7B9, 0xA7B9),
+    (0xA7BB, 0xA7BB),
+    (0xA7BD, 0xA7BD),
+    (0xA7BF, 0xA7BF),
+    (0xA7C3, 0xA7C3),
+    (0xA7C8, 0xA7C8),
+    (0xA7CA, 0xA7CA),
+    (0xA7F6, 0xA7F6),
+    (0xAB53, 0xAB53),
+    (0xAB70, 0xABBF),
+    (0xFB00, 0xFB06),
+    (0xFB13, 0xFB17),
+    (0xFF41, 0xFF5A),
+    (0x10428, 0x1044F),
+    (0x104D8, 0x104FB),
+    (0x10CC0, 0x10CF2),
+    (0x118C0, 0x118DF),
+    (0x16E60, 0x16E7F),
+    (0x1E922, 0x1E943),
+];
+
+pub(super) const CHANGES_WHEN_CASEFOLDED_RANGES: &[(u32, u32)] = &[
+    (0x0041, 0x005A),
+    (0x00B5, 0x00B5),
+    (0x00C0, 0x00D6),
+    (0x00D8, 0x00DF),
+    (0x0100, 0x0100),
+    (0x0102, 0x0102),
+    (0x0104, 0x0104),
+    (0x0106, 0x0106),
+    (0x0108, 0x0108),
+    (0x010A, 0x010A),
+    (0x010C, 0x010C),
+    (0x010E, 0x010E),
+    (0x0110, 0x0110),
+    (0x0112, 0x0112),
+    (0x0114, 0x0114),
+    (0x0116, 0x0116),
+    (0x0118, 0x0118),
+    (0x011A, 0x011A),
+    (0x011C, 0x011C),
+    (0x011E, 0x011E),
+    (0x0120, 0x0120),
+    (0x0122, 0x0122),
+    (0x0124, 0x0124),
+    (0x0126, 0x0126),
+    (0x0128, 0x0128),
+    (0x012A, 0x012A),
+    (0x012C, 0x012C),
+    (0x012E, 0x012E),
+    (0x0130, 0x0130),
+    (0x0132, 0x0132),
+    (0x0134, 0x0134),
+    (0x0136, 0x0136),
+    (0x0139, 0x0139),
+    (0x013B, 0x013B),
+    (0x013D, 0x013D),
+    (0x013F, 0x013F),
+    (0x0141, 0x0141),
+    (0x0143, 0x0143),
+    (0x0145, 0x0145),
+    (0x0147, 0x0147),
+    (0x0149, 0x014A),
+    (0x014C, 0x014C),
+    (0x014E, 0x014E),
+    (0x0150, 0x0150),
+    (0x0152, 0x0152),
+    (0x0154, 0x0154),
+    (0x0156, 0x0156),
+    (0x0158, 0x0158),
+    (0x015A, 0x015A),
+    (0x015C, 0x015C),
+    (0x015E, 0x015E),
+    (0x0160, 0x0160),
+    (0x0162, 0x0162),
+    (0x0164, 0x0164),
+    (0x0166, 0x0166),
+    (0x0168, 0x0168),
+    (0x016A, 0x016A),
+    (0x016C, 0x016C),
+    (0x016E, 0x016E),
+    (0x0170, 0x0170),
+    (0x0172, 0x0172),
+    (0x0174, 0x0174),
+    (0x0176, 0x0176),
+    (0x0178, 0x0179),
+    (0x017B, 0x017B),
+    (0x017D, 0x017D),
+    (0x017F, 0x017F),
+    (0x0181, 0x0182),
+    (0x0184, 0x0184),
+    (0x0186, 0x0187),
+    (0x0189, 0x018B),
+    (0x018E, 0x0191),
+    (0x0193, 0x0194),
+    (0x0196, 0x0198),
+    (0x019C, 0x019D),
+    (0x019F, 0x01A0),
+    (0x01A2, 0x01A2),
+    (0x01A4, 0x01A4),
+    (0x01A6, 0x01A7),
+    (0x01A9, 0x01A9),
+    (0x01AC, 0x01AC),
+    (0x01AE, 0x01AF),
+    (0x01B1, 0x01B3),
+    (0x01B5, 0x01B5),
+    (0x01B7, 0x01B8),
+    (0x01BC, 0x01BC),
+    (0x01C4, 0x01C5),
+    (0x01C7, 0x01C8),
+    (0x01CA, 0x01CB),
+    (0x01CD, 0x01CD),
+    (0x01CF, 0x01CF),
+    (0x01D1, 0x01D1),
+    (0x01D3, 0x01D3),
+    (0x01D5, 0x01D5),
+    (0x01D7, 0x01D7),
+    (0x01D9, 0x01D9),
+    (0x01DB, 0x01DB),
+    (0x01DE, 0x01DE),
+    (0x01E0, 0x01E0),
+    (0x01E2, 0x01E2),
+    (0x01E4, 0x01E4),
+    (0x01E6, 0x01E6),
+    (0x01E8, 0x01E8),
+    (0x01EA, 0x01EA),
+    (0x01EC, 0x01EC),
+    (0x01EE, 0x01EE),
+    (0x01F1, 0x01F2),
+    (0x01F4, 0x01F4),
+    (0x01F6, 0x01F8),
+    (0x01FA, 0x01FA),
+    (0x01FC, 0x01FC),
+    (0x01FE, 0x01FE),
+    (0x0200, 0x0200),
+    (0x0202, 0x0202),
+    (0x0204, 0x0204),
+    (0x0206, 0x0206),
+    (0x0208, 0x0208),
+    (0x020A, 0x020A),
+    (0x020C, 0x020C),
+    (0x020E, 0x020E),
+    (0x0210, 0x0210),
+    (0x0212, 0x0212),
+    (0x0214, 0x0214),
+    (0x0216, 0x0216),
+    (0x0218, 0x0218),
+    (0x021A, 0x021A),
+    (0x021C, 0x021C),
+    (0x021E, 0x021E),
+    (0x0220, 0x0220),
+    (0x0222, 0x0222),
+    (0x0224, 0x0224),
+    (0x0226, 0x0226),
+    (0x0228, 0x0228),
+    (0x022A, 0x022A),
+    (0x022C, 0x022C),
+    (0x022E, 0x022E),
+    (0x0230, 0x0230),
+    (0x0232, 0x0232),
+    (0x023A, 0x023B),
+    (0x023D, 0x023E),
+    (0x0241, 0x0241),
+    (0x0243, 0x0246),
+    (0x0248, 0x0248),
+    (0x024A, 0x024A),
+    (0x024C, 0x024C),
+    (0x024E, 0x024E),
+    (0x0345, 0x0345),
+    (0x0370, 0x0370),
+    (0x0372, 0x0372),
+    (0x0376, 0x0376),
+    (0x037F, 0x037F),
+    (0x0386, 0x0386),
+    (0x0388, 0x038A),
+    (0x038C, 0x038C),
+    (0x038E, 0x038F),
+    (0x0391, 0x03A1),
+    (0x03A3, 0x03AB),
+    (0x03C2, 0x03C2),
+    (0x03CF, 0x03D1),
+    (0x03D5, 0x03D6),
+    (0x03D8, 0x03D8),
+    (0x03DA, 0x03DA),
+    (0x03DC, 0x03DC),
+    (0x03DE, 0x03DE),
+    (0x03E0, 0x03E0),
+    (0x03E2, 0x03E2),
+    (0x03E4, 0x03E4),
+    (0x03E6, 0x03E6),
+    (0x03E8, 0x03E8),
+    (0x03EA, 0x03EA),
+    (0x03EC, 0x03EC),
+    (0x03EE, 0x03EE),
+    (0x03F0, 0x03F1),
+    (0x03F4, 0x03F5),
+    (0x03F7, 0x03F7),
+    (0x03F9, 0x03FA),
+    (0x03FD, 0x042F),
+    (0x0460, 0x0460),
+    (0x0462, 0x0462),
+    (0x0464, 0x0464),
+    (0x0466, 0x0466),
+    (0x0468, 0x0468),
+    (0x046A, 0x046A),
+    (0x046C, 0x046C),
+    (0x046E, 0x046E),
+    (0x0470, 0x0470),
+    (0x0472, 0x0472),
+    (0x0474, 0x0474),
+    (0x0476, 0x0476),
+    (0x0478, 0x0478),
+    (0x047A, 0x047A),
+    (0x047C, 0x047C),
+    (0x047E, 0x047E),
+    (0x0480, 0x0480),
+    (0x048A, 0x048A),
+    (0x048C, 0x048C),
+    (0x048E, 0x048E),
+    (0x0490, 0x0490),
+    (0x0492, 0x0492),
+    (0x0494, 0x0494),
+    (0x0496, 0x0496),
+    (0x0498, 0x0498),
+    (0x049A, 0x049A),
+    (0x049C, 0x049C),
+    (0x049E, 0x049E),
+    (0x04A0, 0x04A0),
+    (0x04A2, 0x04A2),
+    (0x04A4, 0x04A4),
+    (0x04A6, 0x04A6),
+    (0x04A8, 0x04A8),
+    (0x04AA, 0x04AA),
+    (0x04AC, 0x04AC),
+    (0x04AE, 0x04AE),
+    (0x04B0, 0x04B0),
+    (0x04B2, 0x04B2),
+    (0x04B4, 0x04B4),
+    (0x04B6, 0x04B6),
+    (0x04B8, 0x04B8),
+    (0x04BA, 0x04BA),
+    (0x04BC, 0x04BC),
+    (0x04BE, 0x04BE),
+    (0x04C0, 0x04C1),
+    (0x04C3, 0x04C3),
+    (0x04C5, 0x04C5),
+    (0x04C7, 0x04C7),
+    (0x04C9, 0x04C9),
+    (0x04CB, 0x04CB),
+    (0x04CD, 0x04CD),
+    (0x04D0, 0x04D0),
+    (0x04D2, 0x04D2),
+    (0x04D4, 0x04D4),
+    (0x04D6, 0x04D6),
+    (0x04D8, 0x04D8),
+    (0x04DA, 0x04DA),
+    (0x04DC, 0x04DC),
+    (0x04DE, 0x04DE),
+    (0x04E0, 0x04E0),
+    (0x04E2, 0x04E2),
+    (0x04E4, 0x04E4),
+    (0x04E6, 0x04E6),
+    (0x04E8, 0x04E8),
+    (0x04EA, 0x04EA),
+    (0x04EC, 0x04EC),
+    (0x04EE, 0x04EE),
+    (0x04F0, 0x04F0),
+    (0x04F2, 0x04F2),
+    (0x04F4, 0x04F4),
+    (0x04F6, 0x04F6),
+    (0x04F8, 0x04F8),
+    (0x04FA, 0x04FA),
+    (0x04FC, 0x04FC),
+    (0x04FE, 0x04FE),
+    (0x0500, 0x0500),
+    (0x0502, 0x0502),
+    (0x0504, 0x0504),
+    (0x0506, 0x0506),
+    (0x0508, 0x0508),
+    (0x050A, 0x050A),
+    (0x050C, 0x050C),
+    (0x050E, 0x050E),
+    (0x0510, 0x0510),
+    (0x0512, 0x0512),
+    (0x0514, 0x0514),
+    (0x0516, 0x0516),
+    (0x0518, 0x0518),
+    (0x051A, 0x051A),
+    (0x051C, 0x051C),
+    (0x051E, 0x051E),
+    (0x0520, 0x0520),
+    (0x0522, 0x0522),
+    (0x0524, 0x0524),
+    (0x0526, 0x0526),
+    (0x0528, 0x0528),
+    (0x052A, 0x052A),
+    (0x052C, 0x052C),
+    (0x052E, 0x052E),
+    (0x0531, 0x0556),
+    (0x0587, 0x0587),
+    (0x10A0, 0x10C5),
+    (0x10C7, 0x10C7),
+    (0x10CD, 0x10CD),
+    (0x13F8, 0x13FD),
+    (0x1C80, 0x1C88),
+    (0x1C90, 0x1CBA),
+    (0x1CBD, 0x1CBF),
+    (0x1E00, 0x1E00),
+    (0x1E02, 0x1E02),
+    (0x1E04, 0x1E04),
+    (0x1E06, 0x1E06),
+    (0x1E08, 0x1E08),
+    (0x1E0A, 0x1E0A),
+    (0x1E0C, 0x1E0C),
+    (0x1E0E, 0x1E0E),
+    (0x1E10, 0x1E10),
+    (0x1E12, 0x1E12),
+    (0x1E14, 0x1E14),
+    (0x1E16, 0x1E16),
+    (0x1E18, 0x1E18),
+    (0x1E1A, 0x1E1A),
+    (0x1E1C, 0x1E1C),
+    (0x1E1E, 0x1E1E),
+    (0x1E20, 0x1E20),
+    (0x1E22, 0x1E22),
+    (0x1E24, 0x1E24),
+    (0x1E26, 0x1E26),
+    (0x1E28, 0x1E28),
+    (0x1E2A, 0x1E2A),
+    (0x1E2C, 0x1E2C),
+    (0x1E2E, 0x1E2E),
+    (0x1E30, 0x1E30),
+    (0x1E32, 0x1E32),
+    (0x1E34, 0x1E34),
+    (0x1E36, 0x1E36),
+    (0x1E38, 0x1E38),
+    (0x1E3A, 0x1E3A),
+    (0x1E3C, 0x1E3C),
+    (0x1E3E, 0x1E3E),
+    (0x1E40, 0x1E40),
+    (0x1E42, 0x1E42),
+    (0x1E44, 0x1E44),
+    (0x1E46, 0x1E46),
+    (0x1E48, 0x1E48),
+    (0x1E4A, 0x1E4A),
+    (0x1E4C, 0x1E4C),
+    (0x1E4E, 0x1E4E),
+    (0x1E50, 0x1E50),
+    (0x1E52, 0x1E52),
+    (0x1E54, 0x1E54),
+    (0x1E56, 0x1E56),
+    (0x1E58, 0x1E58),
+    (0x1E5A, 0x1E5A),
+    (0x1E5C, 0x1E5C),
+    (0x1E5E, 0x1E5E),
+    (0x1E60, 0x1E60),
+    (0x1E62, 0x1E62),
+    (0x1E64, 0x1E64),
+    (0x1E66, 0x1E66),
+    (0x1E68, 0x1E68),
+    (0x1E6A, 0x1E6A),
+    (0x1E6C, 0x1E6C),
+    (0x1E6E, 0x1E6E),
+    (0x1E70, 0x1E70),
+    (0x1E72, 0x1E72),
+    (0x1E74, 0x1E74),
+    (0x1E76, 0x1E76),
+    (0x1E78, 0x1E78),
+    (0x1E7A, 0x1E7A),
+    (0x1E7C, 0x1E7C),
+    (0x1E7E, 0x1E7E),
+    (0x1E80, 0x1E80),
+    (0x1E82, 0x1E82),
+    (0x1E84, 0x1E84),
+    (0x1E86, 0x1E86),
+    (0x1E88, 0x1E88),
+    (0x1E8A, 0x1E8A),
+    (0x1E8C, 0x1E8C),
+    (0x1E8E, 0x1E8E),
+    (0x1E90, 0x1E90),
+    (0x1E92, 0x1E92),
+    (0x1E94, 0x1E94),
+    (0x1E9A, 0x1E9B),
+    (0x1E9E, 0x1E9E),
+    (0x1EA0, 0x1EA0),
+    (0x1EA2, 0x1EA2),
+    (0x1EA4, 0x1EA4),
+    (0x1EA6, 0x1EA6),
+    (0x1EA8, 0x1EA8),
+    (0x1EAA, 0x1EAA),
+    (0x1EAC, 0x1EAC),
+    (0x1EAE, 0x1EAE),
+    (0x1EB0, 0x1EB0),
+    (0x1EB2, 0x1EB2),
+    (0x1EB4, 0x1EB4),
+    (0x1EB6, 0x1EB6),
+    (0x1EB8, 0x1EB8),
+    (0x1EBA, 0x1EBA),
+    (0x1EBC, 0x1EBC),
+    (0x1EBE, 0x1EBE),
+    (0x1EC0, 0x1EC0),
+    (0x1EC2, 0x1EC2),
+    (0x1EC4, 0x1EC4),
+    (0x1EC6, 0x1EC6),
+    (0x1EC8, 0x1EC8),
+    (0x1ECA, 0x1ECA),
+    (0x1ECC, 0x1ECC),
+    (0x1ECE, 0x1ECE),
+    (0x1ED0, 0x1ED0),
+    (0x1ED2, 0x1ED2),
+    (0x1ED4, 0x1ED4),
+    (0x1ED6, 0x1ED6),
+    (0x1ED8, 0x1ED8),
+    (0x1EDA, 0x1EDA),
+    (0x1EDC, 0x1EDC),
+    (0x1EDE, 0x1EDE),
+    (0x1EE0, 0x1EE0),
+    (0x1EE2, 0x1EE2),
+    (0x1EE4, 0x1EE4),
+    (0x1EE6, 0x1EE6),
+    (0x1EE8, 0x1EE8),
+    (0x1EEA, 0x1EEA),
+    (0x1EEC, 0x1EEC),
+    (0x1EEE, 0x1EEE),
+    (0x1EF0, 0x1EF0),
+    (0x1EF2, 0x1EF2),
+    (0x1EF4, 0x1EF4),
+    (0x1EF6, 0x1EF6),
+    (0x1EF8, 0x1EF8),
+    (0x1EFA, 0x1EFA),
+    (0x1EFC, 0x1EFC),
+    (0x1EFE, 0x1EFE),
+    (0x1F08, 0x1F0F),
+    (0x1F18, 0x1F1D),
+    (0x1F28, 0x1F2F),
+    (0x1F38, 0x1F3F),
+    (0x1F48, 0x1F4D),
+    (0x1F59, 0x1F59),
+    (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D),
+    (0x1F5F, 0x1F5F),
+    (0x1F68, 0x1F6F),
+    (0x1F80, 0x1FAF),
+    (0x1FB2, 0x1FB4),
+    (0x1FB7, 0x1FBC),
+    (0x1FC2, 0x1FC4),
+    (0x1FC7, 0x1FCC),
+    (0x1FD8, 0x1FDB),
+    (0x1FE8, 0x1FEC),
+    (0x1FF2, 0x1FF4),
+    (0x1FF7, 0x1FFC),
+    (0x2126, 0x2126),
+    (0x212A, 0x212B),
+    (0x2132, 0x2132),
+    (0x2160, 0x216F),
+    (0x2183, 0x2183),
+    (0x24B6, 0x24CF),
+    (0x2C00, 0x2C2E),
+    (0x2C60, 0x2C60),
+    (0x2C62, 0x2C64),
+    (0x2C67, 0x2C67),
+    (0x2C69, 0x2C69),
+    (0x2C6B, 0x2C6B),
+    (0x2C6D, 0x2C70),
+    (0x2C72, 0x2C72),
+    (0x2C75, 0x2C75),
+    (0x2C7E, 0x2C80),
+    (0x2C82, 0x2C82),
+    (0x2C84, 0x2C84),
+    (0x2C86, 0x2C86),
+    (0x2C88, 0x2C88),
+    (0x2C8A, 0x2C8A),
+    (0x2C8C, 0x2C8C),
+    (0x2C8E, 0x2C8E),
+    (0x2C90, 0x2C90),
+    (0x2C92, 0x2C92),
+    (0x2C94, 0x2C94),
+    (0x2C96, 0x2C96),
+    (0x2C98, 0x2C98),
+    (0x2C9A, 0x2C9A),
+    (0x2C9C, 0x2C9C),
+    (0x2C9E, 0x2C9E),
+    (0x2CA0, 0x2CA0),
+    (0x2CA2, 0x2CA2),
+    (0x2CA4, 0x2CA4),
+    (0x2CA6, 0x2CA6),
+    (0x2CA8, 0x2CA8),
+    (0x2CAA, 0x2CAA),
+    (0x2CAC, 0x2CAC),
+    (0x2CAE, 0x2CAE),
+    (0x2CB0, 0x2CB0),
+    (0x2CB2, 0x2CB2),
+    (0x2CB4, 0x2CB4),
+    (0x2CB6, 0x2CB6),
+    (0x2CB8, 0x2CB8),
+    (0x2CBA, 0x2CBA),
+    (0x2CBC, 0x2CBC),
+    (0x2CBE, 0x2CBE),
+    (0x2CC0, 0x2CC0),
+    (0x2CC2, 0x2CC2),
+    (0x2CC4, 0x2CC4),
+    (0x2CC6, 0x2CC6),
+    (0x2CC8, 0x2CC8),
+    (0x2CCA, 0x2CCA),
+    (0x2CCC, 0x2CCC),
+    (0x2CCE, 0x2CCE),
+    (0x2CD0, 0x2CD0),
+    (0x2CD2, 0x2CD2),
+    (0x2CD4, 0x2CD4),
+    (0x2CD6, 0x2CD6),
+    (0x2CD8, 0x2CD8),
+    (0x2CDA, 0x2CDA),
+    (0x2CDC, 0x2CDC),
+    (0x2CDE, 0x2CDE),
+    (0x2CE0, 0x2CE0),
+    (0x2CE2, 0x2CE2),
+    (0x2CEB, 0x2CEB),
+    (0x2CED, 0x2CED),
+    (0x2CF2, 0x2CF2),
+    (0xA640, 0xA640),
+    (0xA642, 0xA642),
+    (0xA644, 0xA644),
+    (0xA646, 0xA646),
+    (0xA648, 0xA648),
+    (0xA64A, 0xA64A),
+    (0xA64C, 0xA64C),
+    (0xA64E, 0xA64E),
+    (0xA650, 0xA650),
+    (0xA652, 0xA652),
+    (0xA654, 0xA654),
+    (0xA656, 0xA656),
+    (0xA658, 0xA658),
+    (0xA65A, 0xA65A),
+    (0xA65C, 0xA65C),
+    (0xA65E, 0xA65E),
+    (0xA660, 0xA660),
+    (0xA662, 0xA662),
+    (0xA664, 0xA664),
+    (0xA666, 0xA666),
+    (0xA668, 0xA668),
+    (0xA66A, 0xA66A),
+    (0xA66C, 0xA66C),
+    (0xA680, 0xA680),
+    (0xA682, 0xA682),
+    (0xA684, 0xA684),
+    (0xA686, 0xA686),
+    (0xA688, 0xA688),
+    (0xA68A, 0xA68A),
+    (0xA68C, 0xA68C),
+    (0xA68E, 0xA68E),
+    (0xA690, 0xA690),
+    (0xA692, 0xA692),
+    (0xA694, 0xA694),
+    (0xA696, 0xA696),
+    (0xA698, 0xA698),
+    (0xA69A, 0xA69A),
+    (0xA722, 0xA722),
+    (0xA724, 0xA724),
+    (0xA726, 0xA726),
+    (0xA728, 0xA728),
+    (0xA72A, 0xA72A),
+    (0xA72C, 0xA72C),
+    (0xA72E, 0xA72E),
+    (0xA732, 0xA732),
+    (0xA734, 0xA734),
+    (0xA736, 0xA736),
+    (0xA738, 0xA738),
+    (0xA73A, 0xA73A),
+    (0xA73C, 0xA73C),
+    (0xA73E, 0xA73E),
+    (0xA740, 0xA740),
+    (0xA742, 0xA742),
+    (0xA744, 0xA744),
+    (0xA746, 0xA746),
+    (0xA748, 0xA748),
+    (0xA74A, 0xA74A),
+    (0xA74C, 0xA74C),
+    (0xA74E, 0xA74E),
+    (0xA750, 0xA750),
+    (0xA752, 0xA752),
+    (0xA754, 0xA754),
+    (0xA756, 0xA756),
+    (0xA758, 0xA758),
+    (0xA75A, 0xA75A),
+    (0xA75C, 0xA75C),
+    (0xA75E, 0xA75E),
+    (0xA760, 0xA760),
+    (0xA762, 0xA762),
+    (0xA764, 0xA764),
+    (0xA766, 0xA766),
+    (0xA768, 0xA768),
+    (0xA76A, 0xA76A),
+    (0xA76C, 0xA76C),
+    (0xA76E, 0xA76E),
+    (0xA779, 0xA779),
+    (0xA77B, 0xA77B),
+    (0xA77D, 0xA77E),
+    (0xA780, 0xA780),
+    (0xA782, 0xA782),
+    (0xA784, 0xA784),
+    (0xA786, 0xA786),
+    (0xA78B, 0xA78B),
+    (0xA78D, 0xA78D),
+    (0xA790, 0xA790),
+    (0xA792, 0xA792),
+    (0xA796, 0xA796),
+    (0xA798, 0xA798),
+    (0xA79A, 0xA79A),
+    (0xA79C, 0xA79C),
+    (0xA79E, 0xA79E),
+    (0xA7A0, 0xA7A0),
+    (0xA7A2, 0xA7A2),
+    (0xA7A4, 0xA7A4),
+    (0xA7A6, 0xA7A6),
+    (0xA7A8, 0xA7A8),
+    (0xA7AA, 0xA7AE),
+    (0xA7B0, 0xA7B4),
+    (0xA7B6, 0xA7B6),
+    (0xA7B8, 0xA7B8),
+    (0xA7BA, 0xA7BA),
+    (0xA7BC, 0xA7BC),
+    (0xA7BE, 0xA7BE),
+    (0xA7C2, 0xA7C2),
+    (0xA7C4, 0xA7C7),
+    (0xA7C9, 0xA7C9),
+    (0xA7F5, 0xA7F5),
+    (0xAB70, 0xABBF),
+    (0xFB00, 0xFB06),
+    (0xFB13, 0xFB17),
+    (0xFF21, 0xFF3A),
+    (0x10400, 0x10427),
+    (0x104B0, 0x104D3),
+    (0x10C80, 0x10CB2),
+    (0x118A0, 0x118BF),
+    (0x16E40, 0x16E5F),
+    (0x1E900, 0x1E921),
+];
+
+pub(super) const CHANGES_WHEN_CASEMAPPED_RANGES: &[(u32, u32)] = &[
+    (0x0041, 0x005A),
+    (0x0061, 0x007A),
+    (0x00B5, 0x00B5),
+    (0x00C0, 0x00D6),
+    (0x00D8, 0x00F6),
+    (0x00F8, 0x0137),
+    (0x0139, 0x018C),
+    (0x018E, 0x019A),
+    (0x019C, 0x01A9),
+    (0x01AC, 0x01B9),
+    (0x01BC, 0x01BD),
+    (0x01BF, 0x01BF),
+    (0x01C4, 0x0220),
+    (0x0222, 0x0233),
+    (0x023A, 0x0254),
+    (0x0256, 0x0257),
+    (0x0259, 0x0259),
+    (0x025B, 0x025C),
+    (0x0260, 0x0261),
+    (0x0263, 0x0263),
+    (0x0265, 0x0266),
+    (0x0268, 0x026C),
+    (0x026F, 0x026F),
+    (0x0271, 0x0272),
+    (0x0275, 0x0275),
+    (0x027D, 0x027D),
+    (0x0280, 0x0280),
+    (0x0282, 0x0283),
+    (0x0287, 0x028C),
+    (0x0292, 0x0292),
+    (0x029D, 0x029E),
+    (0x0345, 0x0345),
+    (0x0370, 0x0373),
+    (0x0376, 0x0377),
+    (0x037B, 0x037D),
+    (0x037F, 0x037F),
+    (0x0386, 0x0386),
+    (0x0388, 0x038A),
+    (0x038C, 0x038C),
+    (0x038E, 0x03A1),
+    (0x03A3, 0x03D1),
+    (0x03D5, 0x03F5),
+    (0x03F7, 0x03FB),
+    (0x03FD, 0x0481),
+    (0x048A, 0x052F),
+    (0x0531, 0x0556),
+    (0x0561, 0x0587),
+    (0x10A0, 0x10C5),
+    (0x10C7, 0x10C7),
+    (0x10CD, 0x10CD),
+    (0x10D0, 0x10FA),
+    (0x10FD, 0x10FF),
+    (0x13A0, 0x13F5),
+    (0x13F8, 0x13FD),
+    (0x1C80, 0x1C88),
+    (0x1C90, 0x1CBA),
+    (0x1CBD, 0x1CBF),
+    (0x1D79, 0x1D79),
+    (0x1D7D, 0x1D7D),
+    (0x1D8E, 0x1D8E),
+    (0x1E00, 0x1E9B),
+    (0x1E9E, 0x1E9E),
+    (0x1EA0, 0x1F15),
+    (0x1F18, 0x1F1D),
+    (0x1F20, 0x1F45),
+    (0x1F48, 0x1F4D),
+    (0x1F50, 0x1F57),
+    (0x1F59, 0x1F59),
+    (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D),
+    (0x1F5F, 0x1F7D),
+    (0x1F80, 0x1FB4),
+    (0x1FB6, 0x1FBC),
+    (0x1FBE, 0x1FBE),
+    (0x1FC2, 0x1FC4),
+    (0x1FC6, 0x1FCC),
+    (0x1FD0, 0x1FD3),
+    (0x1FD6, 0x1FDB),
+    (0x1FE0, 0x1FEC),
+    (0x1FF2, 0x1FF4),
+    (0x1FF6, 0x1FFC),
+    (0x2126, 0x2126),
+    (0x212A, 0x212B),
+    (0x2132, 0x2132),
+    (0x214E, 0x214E),
+    (0x2160, 0x217F),
+    (0x2183, 0x2184),
+    (0x24B6, 0x24E9),
+    (0x2C00, 0x2C2E),
+    (0x2C30, 0x2C5E),
+    (0x2C60, 0x2C70),
+    (0x2C72, 0x2C73),
+    (0x2C75, 0x2C76),
+    (0x2C7E, 0x2CE3),
+    (0x2CEB, 0x2CEE),
+    (0x2CF2, 0x2CF3),
+    (0x2D00, 0x2D25),
+    (0x2D27, 0x2D27),
+    (0x2D2D, 0x2D2D),
+    (0xA640, 0xA66D),
+    (0xA680, 0xA69B),
+    (0xA722, 0xA72F),
+    (0xA732, 0xA76F),
+    (0xA779, 0xA787),
+    (0xA78B, 0xA78D),
+    (0xA790, 0xA794),
+    (0xA796, 0xA7AE),
+    (0xA7B0, 0xA7BF),
+    (0xA7C2, 0xA7CA),
+    (0xA7F5, 0xA7F6),
+    (0xAB53, 0xAB53),
+    (0xAB70, 0xABBF),
+    (0xFB00, 0xFB06),
+    (0xFB13, 0xFB17),
+    (0xFF21, 0xFF3A),
+    (0xFF41, 0xFF5A),
+    (0x10400, 0x1044F),
+    (0x104B0, 0x104D3),
+    (0x104D8, 0x104FB),
+    (0x10C80, 0x10CB2),
+    (0x10CC0, 0x10CF2),
+    (0x118A0, 0x118DF),
+    (0x16E40, 0x16E7F),
+    (0x1E900, 0x1E943),
+];
+
+pub(super) const CHANGES_WHEN_NFKC_CASEFOLDED_RANGES: &[(u32, u32)] = &[
+    (0x0041, 0x005A),
+    (0x00A0, 0x00A0),
+    (0x00A8, 0x00A8),
+    (0x00AA, 0x00AA),
+    (0x00AD, 0x00AD),
+    (0x00AF, 0x00AF),
+    (0x00B2, 0x00B5),
+    (0x00B8, 0x00BA),
+    (0x00BC, 0x00BE),
+    (0x00C0, 0x00D6),
+    (0x00D8, 0x00DF),
+    (0x0100, 0x0100),
+    (0x0102, 0x0102),
+    (0x0104, 0x0104),
+    (0x0106, 0x0106),
+    (0x0108, 0x0108),
+    (0x010A, 0x010A),
+    (0x010C, 0x010C),
+    (0x010E, 0x010E),
+    (0x0110, 0x0110),
+    (0x0112, 0x0112),
+    (0x0114, 0x0114),
+    (0x0116, 0x0116),
+    (0x0118, 0x0118),
+    (0x011A, 0x011A),
+    (0x011C, 0x011C),
+    (0x011E, 0x011E),
+    (0x0120, 0x0120),
+    (0x0122, 0x0122),
+    (0x0124, 0x0124),
+    (0x0126, 0x0126),
+    (0x0128, 0x0128),
+    (0x012A, 0x012A),
+    (0x012C, 0x012C),
+    (0x012E, 0x012E),
+    (0x0130, 0x0130),
+    (0x0132, 0x0134),
+    (0x0136, 0x0136),
+    (0x0139, 0x0139),
+    (0x013B, 0x013B),
+    (0x013D, 0x013D),
+    (0x013F, 0x0141),
+    (0x0143, 0x0143),
+    (0x0145, 0x0145),
+    (0x0147, 0x0147),
+    (0x0149, 0x014A),
+    (0x014C, 0x014C),
+    (0x014E, 0x014E),
+    (0x0150, 0x0150),
+    (0x0152, 0x0152),
+    (0x0154, 0x0154),
+    (0x0156, 0x0156),
+    (0x0158, 0x0158),
+    (0x015A, 0x015A),
+    (0x015C, 0x015C),
+    (0x015E, 0x015E),
+    (0x0160, 0x0160),
+    (0x0162, 0x0162),
+    (0x0164, 0x0164),
+    (0x0166, 0x0166),
+    (0x0168, 0x0168),
+    (0x016A, 0x016A),
+    (0x016C, 0x016C),
+    (0x016E, 0x016E),
+    (0x0170, 0x0170),
+    (0x0172, 0x0172),
+    (0x0174, 0x0174),
+    (0x0176, 0x0176),
+    (0x0178, 0x0179),
+    (0x017B, 0x017B),
+    (0x017D, 0x017D),
+    (0x017F, 0x017F),
+    (0x0181, 0x0182),
+    (0x0184, 0x0184),
+    (0x0186, 0x0187),
+    (0x0189, 0x018B),
+    (0x018E, 0x0191),
+    (0x0193, 0x0194),
+    (0x0196, 0x0198),
+    (0x019C, 0x019D),
+    (0x019F, 0x01A0),
+    (0x01A2, 0x01A2),
+    (0x01A4, 0x01A4),
+    (0x01A6, 0x01A7),
+    (0x01A9, 0x01A9),
+    (0x01AC, 0x01AC),
+    (0x01AE, 0x01AF),
+    (0x01B1, 0x01B3),
+    (0x01B5, 0x01B5),
+    (0x01B7, 0x01B8),
+    (0x01BC, 0x01BC),
+    (0x01C4, 0x01CD),
+    (0x01CF, 0x01CF),
+    (0x01D1, 0x01D1),
+    (0x01D3, 0x01D3),
+    (0x01D5, 0x01D5),
+    (0x01D7, 0x01D7),
+    (0x01D9, 0x01D9),
+    (0x01DB, 0x01DB),
+    (0x01DE, 0x01DE),
+    (0x01E0, 0x01E0),
+    (0x01E2, 0x01E2),
+    (0x01E4, 0x01E4),
+    (0x01E6, 0x01E6),
+    (0x01E8, 0x01E8),
+    (0x01EA, 0x01EA),
+    (0x01EC, 0x01EC),
+    (0x01EE, 0x01EE),
+    (0x01F1, 0x01F4),
+    (0x01F6, 0x01F8),
+    (0x01FA, 0x01FA),
+    (0x01FC, 0x01FC),
+    (0x01FE, 0x01FE),
+    (0x0200, 0x0200),
+    (0x0202, 0x0202),
+    (0x0204, 0x0204),
+    (0x0206, 0x0206),
+    (0x0208, 0x0208),
+    (0x020A, 0x020A),
+    (0x020C, 0x020C),
+    (0x020E, 0x020E),
+    (0x0210, 0x0210),
+    (0x0212, 0x0212),
+    (0x0214, 0x0214),
+    (0x0216, 0x0216),
+    (0x0218, 0x0218),
+    (0x021A, 0x021A),
+    (0x021C, 0x021C),
+    (0x021E, 0x021E),
+    (0x0220, 0x0220),
+    (0x0222, 0x0222),
+    (0x0224, 0x0224),
+    (0x0226, 0x0226),
+    (0x0228, 0x0228),
+    (0x022A, 0x022A),
+    (0x022C, 0x022C),
+    (0x022E, 0x022E),
+    (0x0230, 0x0230),
+    (0x0232, 0x0232),
+    (0x023A, 0x023B),
+    (0x023D, 0x023E),
+    (0x0241, 0x0241),
+    (0x0243, 0x0246),
+    (0x0248, 0x0248),
+    (0x024A, 0x024A),
+    (0x024C, 0x024C),
+    (0x024E, 0x024E),
+    (0x02B0, 0x02B8),
+    (0x02D8, 0x02DD),
+    (0x02E0, 0x02E4),
+    (0x0340, 0x0341),
+    (0x0343, 0x0345),
+    (0x034F, 0x034F),
+    (0x0370, 0x0370),
+    (0x0372, 0x0372),
+    (0x0374, 0x0374),
+    (0x0376, 0x0376),
+    (0x037A, 0x037A),
+    (0x037E, 0x037F),
+    (0x0384, 0x038A),
+    (0x038C, 0x038C),
+    (0x038E, 0x038F),
+    (0x0391, 0x03A1),
+    (0x03A3, 0x03AB),
+    (0x03C2, 0x03C2),
+    (0x03CF, 0x03D6),
+    (0x03D8, 0x03D8),
+    (0x03DA, 0x03DA),
+    (0x03DC, 0x03DC),
+    (0x03DE, 0x03DE),
+    (0x03E0, 0x03E0),
+    (0x03E2, 0x03E2),
+    (0x03E4, 0x03E4),
+    (0x03E6, 0x03E6),
+    (0x03E8, 0x03E8),
+    (0x03EA, 0x03EA),
+    (0x03EC, 0x03EC),
+    (0x03EE, 0x03EE),
+    (0x03F0, 0x03F2),
+    (0x03F4, 0x03F5),
+    (0x03F7, 0x03F7),
+    (0x03F9, 0x03FA),
+    (0x03FD, 0x042F),
+    (0x0460, 0x0460),
+    (0x0462, 0x0462),
+    (0x0464, 0x0464),
+    (0x0466, 0x0466),
+    (0x0468, 0x0468),
+    (0x046A, 0x046A),
+    (0x046C, 0x046C),
+    (0x046E, 0x046E),
+    (0x0470, 0x0470),
+    (0x0472, 0x0472),
+    (0x0474, 0x0474),
+    (0x0476, 0x0476),
+    (0x0478, 0x0478),
+    (0x047A, 0x047A),
+    (0x047C, 0x047C),
+    (0x047E, 0x047E),
+    (0x0480, 0x0480),
+    (0x048A, 0x048A),
+    (0x048C, 0x048C),
+    (0x048E, 0x048E),
+    (0x0490, 0x0490),
+    (0x0492, 0x0492),
+    (0x0494, 0x0494),
+    (0x0496, 0x0496),
+    (0x0498, 0x0498),
+    (0x049A, 0x049A),
+    (0x049C, 0x049C),
+    (0x049E, 0x049E),
+    (0x04A0, 0x04A0),
+    (0x04A2, 0x04A2),
+    (0x04A4, 0x04A4),
+    (0x04A6, 0x04A6),
+    (0x04A8, 0x04A8),
+    (0x04AA, 0x04AA),
+    (0x04AC, 0x04AC),
+    (0x04AE, 0x04AE),
+    (0x04B0, 0x04B0),
+    (0x04B2, 0x04B2),
+    (0x04B4, 0x04B4),
+    (0x04B6, 0x04B6),
+    (0x04B8, 0x04B8),
+    (0x04BA, 0x04BA),
+    (0x04BC, 0x04BC),
+    (0x04BE, 0x04BE),
+    (0x04C0, 0x04C1),
+    (0x04C3, 0x04C3),
+    (0x04C5, 0x04C5),
+    (0x04C7, 0x04C7),
+    (0x04C9, 0x04C9),
+    (0x04CB, 0x04CB),
+    (0x04CD, 0x04CD),
+    (0x04D0, 0x04D0),
+    (0x04D2, 0x04D2),
+    (0x04D4, 0x04D4),
+    (0x04D6, 0x04D6),
+    (0x04D8, 0x04D8),
+    (0x04DA, 0x04DA),
+    (0x04DC, 0x04DC),
+    (0x04DE, 0x04DE),
+    (0x04E0, 0x04E0),
+    (0x04E2, 0x04E2),
+    (0x04E4, 0x04E4),
+    (0x04E6, 0x04E6),
+    (0x04E8, 0x04E8),
+    (0x04EA, 0x04EA),
+    (0x04EC, 0x04EC),
+    (0x04EE, 0x04EE),
+    (0x04F0, 0x04F0),
+    (0x04F2, 0x04F2),
+    (0x04F4, 0x04F4),
+    (0x04F6, 0x04F6),
+    (0x04F8, 0x04F8),
+    (0x04FA, 0x04FA),
+    (0x04FC, 0x04FC),
+    (0x04FE, 0x04FE),
+    (0x0500, 0x0500),
+    (0x0502, 0x0502),
+    (0x0504, 0x0504),
+    (0x0506, 0x0506),
+    (0x0508, 0x0508),
+    (0x050A, 0x050A),
+    (0x050C, 0x050C),
+    (0x050E, 0x050E),
+    (0x0510, 0x0510),
+    (0x0512, 0x0512),
+    (0x0514, 0x0514),
+    (0x0516, 0x0516),
+    (0x0518, 0x0518),
+    (0x051A, 0x051A),
+    (0x051C, 0x051C),
+    (0x051E, 0x051E),
+    (0x0520, 0x0520),
+    (0x0522, 0x0522),
+    (0x0524, 0x0524),
+    (0x0526, 0x0526),
+    (0x0528, 0x0528),
+    (0x052A, 0x052A),
+    (0x052C, 0x052C),
+    (0x052E, 0x052E),
+    (0x0531, 0x0556),
+    (0x0587, 0x0587),
+    (0x061C, 0x061C),
+    (0x0675, 0x0678),
+    (0x0958, 0x095F),
+    (0x09DC, 0x09DD),
+    (0x09DF, 0x09DF),
+    (0x0A33, 0x0A33),
+    (0x0A36, 0x0A36),
+    (0x0A59, 0x0A5B),
+    (0x0A5E, 0x0A5E),
+    (0x0B5C, 0x0B5D),
+    (0x0E33, 0x0E33),
+    (0x0EB3, 0x0EB3),
+    (0x0EDC, 0x0EDD),
+    (0x0F0C, 0x0F0C),
+    (0x0F43, 0x0F43),
+    (0x0F4D, 0x0F4D),
+    (0x0F52, 0x0F52),
+    (0x0F57, 0x0F57),
+    (0x0F5C, 0x0F5C),
+    (0x0F69, 0x0F69),
+    (0x0F73, 0x0F73),
+    (0x0F75, 0x0F79),
+    (0x0F81, 0x0F81),
+    (0x0F93, 0x0F93),
+    (0x0F9D, 0x0F9D),
+    (0x0FA2, 0x0FA2),
+    (0x0FA7, 0x0FA7),
+    (0x0FAC, 0x0FAC),
+    (0x0FB9, 0x0FB9),
+    (0x10A0, 0x10C5),
+    (0x10C7, 0x10C7),
+    (0x10CD, 0x10CD),
+    (0x10FC, 0x10FC),
+    (0x115F, 0x1160),
+    (0x13F8, 0x13FD),
+    (0x17B4, 0x17B5),
+    (0x180B, 0x180E),
+    (0x1C80, 0x1C88),
+    (0x1C90, 0x1CBA),
+    (0x1CBD, 0x1CBF),
+    (0x1D2C, 0x1D2E),
+    (0x1D30, 0x1D3A),
+    (0x1D3C, 0x1D4D),
+    (0x1D4F, 0x1D6A),
+    (0x1D78, 0x1D78),
+    (0x1D9B, 0x1DBF),
+    (0x1E00, 0x1E00),
+    (0x1E02, 0x1E02),
+    (0x1E04, 0x1E04),
+    (0x1E06, 0x1E06),
+    (0x1E08, 0x1E08),
+    (0x1E0A, 0x1E0A),
+    (0x1E0C, 0x1E0C),
+    (0x1E0E, 0x1E0E),
+    (0x1E10, 0x1E10),
+    (0x1E12, 0x1E12),
+    (0x1E14, 0x1E14),
+    (0x1E16, 0x1E16),
+    (0x1E18, 0x1E18),
+    (0x1E1A, 0x1E1A),
+    (0x1E1C, 0x1E1C),
+    (0x1E1E, 0x1E1E),
+    (0x1E20, 0x1E20),
+    (0x1E22, 0x1E22),
+    (0x1E24, 0x1E24),
+    (0x1E26, 0x1E26),
+    (0x1E28, 0x1E28),
+    (0x1E2A, 0x1E2A),
+    (0x1E2C, 0x1E2C),
+    (0x1E2E, 0x1E2E),
+    (0x1E30, 0x1E30),
+    (0x1E32, 0x1E32),
+    (0x1E34, 0x1E34),
+    (0x1E36, 0x1E36),
+    (0x1E38, 0x1E38),
+    (0x1E3A, 0x1E3A),
+    (0x1E3C, 0x1E3C),
+    (0x1E3E, 0x1E3E),
+    (0x1E40, 0x1E40),
+    (0x1E42, 0x1E42),
+    (0x1E44, 0x1E44),
+    (0x1E46, 0x1E46),
+    (0x1E48, 0x1E48),
+    (0x1E4A, 0x1E4A),
+    (0x1E4C, 0x1E4C),
+    (0x1E4E, 0x1E4E),
+    (0x1E50, 0x1E50),
+    (0x1E52, 0x1E52),
+    (0x1E54, 0x1E54),
+    (0x1E56, 0x1E56),
+    (0x1E58, 0x1E58),
+    (0x1E5A, 0x1E5A),
+    (0x1E5C, 0x1E5C),
+    (0x1E5E, 0x1E5E),
+    (0x1E60, 0x1E60),
+    (0x1E62, 0x1E62),
+    (0x1E64, 0x1E64),
+    (0x1E66, 0x1E66),
+    (0x1E68, 0x1E68),
+    (0x1E6A, 0x1E6A),
+    (0x1E6C, 0x1E6C),
+    (0x1E6E, 0x1E6E),
+    (0x1E70, 0x1E70),
+    (0x1E72, 0x1E72),
+    (0x1E74, 0x1E74),
+    (0x1E76, 0x1E76),
+    (0x1E78, 0x1E78),
+    (0x1E7A, 0x1E7A),
+    (0x1E7C, 0x1E7C),
+    (0x1E7E, 0x1E7E),
+    (0x1E80, 0x1E80),
+    (0x1E82, 0x1E82),
+    (0x1E84, 0x1E84),
+    (0x1E86, 0x1E86),
+    (0x1E88, 0x1E88),
+    (0x1E8A, 0x1E8A),
+    (0x1E8C, 0x1E8C),
+    (0x1E8E, 0x1E8E),
+    (0x1E90, 0x1E90),
+    (0x1E92, 0x1E92),
+    (0x1E94, 0x1E94),
+    (0x1E9A, 0x1E9B),
+    (0x1E9E, 0x1E9E),
+    (0x1EA0, 0x1EA0),
+    (0x1EA2, 0x1EA2),
+    (0x1EA4, 0x1EA4),
+    (0x1EA6, 0x1EA6),
+    (0x1EA8, 0x1EA8),
+    (0x1EAA, 0x1EAA),
+    (0x1EAC, 0x1EAC),
+    (0x1EAE, 0x1EAE),
+    (0x1EB0, 0x1EB0),
+    (0x1EB2, 0x1EB2),
+    (0x1EB4, 0x1EB4),
+    (0x1EB6, 0x1EB6),
+    (0x1EB8, 0x1EB8),
+    (0x1EBA, 0x1EBA),
+    (0x1EBC, 0x1EBC),
+    (0x1EBE, 0x1EBE),
+    (0x1EC0, 0x1EC0),
+    (0x1EC2, 0x1EC2),
+    (0x1EC4, 0x1EC4),
+    (0x1EC6, 0x1EC6),
+    (0x1EC8, 0x1EC8),
+    (0x1ECA, 0x1ECA),
+    (0x1ECC, 0x1ECC),
+    (0x1ECE, 0x1ECE),
+    (0x1ED0, 0x1ED0),
+    (0x1ED2, 0x1ED2),
+    (0x1ED4, 0x1ED4),
+    (0x1ED6, 0x1ED6),
+    (0x1ED8, 0x1ED8),
+    (0x1EDA, 0x1EDA),
+    (0x1EDC, 0x1EDC),
+    (0x1EDE, 0x1EDE),
+    (0x1EE0, 0x1EE0),
+    (0x1EE2, 0x1EE2),
+    (0x1EE4, 0x1EE4),
+    (0x1EE6, 0x1EE6),
+    (0x1EE8, 0x1EE8),
+    (0x1EEA, 0x1EEA),
+    (0x1EEC, 0x1EEC),
+    (0x1EEE, 0x1EEE),
+    (0x1EF0, 0x1EF0),
+    (0x1EF2, 0x1EF2),
+    (0x1EF4, 0x1EF4),
+    (0x1EF6, 0x1EF6),
+    (0x1EF8, 0x1EF8),
+    (0x1EFA, 0x1EFA),
+    (0x1EFC, 0x1EFC),
+    (0x1EFE, 0x1EFE),
+    (0x1F08, 0x1F0F),
+    (0x1F18, 0x1F1D),
+    (0x1F28, 0x1F2F),
+    (0x1F38, 0x1F3F),
+    (0x1F48, 0x1F4D),
+    (0x1F59, 0x1F59),
+    (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D),
+    (0x1F5F, 0x1F5F),
+    (0x1F68, 0x1F6F),
+    (0x1F71, 0x1F71),
+    (0x1F73, 0x1F73),
+    (0x1F75, 0x1F75),
+    (0x1F77, 0x1F77),
+    (0x1F79, 0x1F79),
+    (0x1F7B, 0x1F7B),
+    (0x1F7D, 0x1F7D),
+    (0x1F80, 0x1FAF),
+    (0x1FB2, 0x1FB4),
+    (0x1FB7, 0x1FC4),
+    (0x1FC7, 0x1FCF),
+    (0x1FD3, 0x1FD3),
+    (0x1FD8, 0x1FDB),
+    (0x1FDD, 0x1FDF),
+    (0x1FE3, 0x1FE3),
+    (0x1FE8, 0x1FEF),
+    (0x1FF2, 0x1FF4),
+    (0x1FF7, 0x1FFE),
+    (0x2000, 0x200F),
+    (0x2011, 0x2011),
+    (0x2017, 0x2017),
+    (0x2024, 0x2026),
+    (0x202A, 0x202F),
+    (0x2033, 0x2034),
+    (0x2036, 0x2037),
+    (0x203C, 0x203C),
+    (0x203E, 0x203E),
+    (0x2047, 0x2049),
+    (0x2057, 0x2057),
+    (0x205F, 0x2071),
+    (0x2074, 0x208E),
+    (0x2090, 0x209C),
+    (0x20A8, 0x20A8),
+    (0x2100, 0x2103),
+    (0x2105, 0x2107),
+    (0x2109, 0x2113),
+    (0x2115, 0x2116),
+    (0x2119, 0x211D),
+    (0x2120, 0x2122),
+    (0x2124, 0x2124),
+    (0x2126, 0x2126),
+    (0x2128, 0x2128),
+    (0x212A, 0x212D),
+    (0x212F, 0x2139),
+    (0x213B, 0x2140),
+    (0x2145, 0x2149),
+    (0x2150, 0x217F),
+    (0x2183, 0x2183),
+    (0x2189, 0x2189),
+    (0x222C, 0x222D),
+    (0x222F, 0x2230),
+    (0x2329, 0x232A),
+    (0x2460, 0x24EA),
+    (0x2A0C, 0x2A0C),
+    (0x2A74, 0x2A76),
+    (0x2ADC, 0x2ADC),
+    (0x2C00, 0x2C2E),
+    (0x2C60, 0x2C60),
+    (0x2C62, 0x2C64),
+    (0x2C67, 0x2C67),
+    (0x2C69, 0x2C69),
+    (0x2C6B, 0x2C6B),
+    (0x2C6D, 0x2C70),
+    (0x2C72, 0x2C72),
+    (0x2C75, 0x2C75),
+    (0x2C7C, 0x2C80),
+    (0x2C82, 0x2C82),
+    (0x2C84, 0x2C84),
+    (0x2C86, 0x2C86),
+    (0x2C88, 0x2C88),
+    (0x2C8A, 0x2C8A),
+    (0x2C8C, 0x2C8C),
+    (0x2C8E, 0x2C8E),
+    (0x2C90, 0x2C90),
+    (0x2C92, 0x2C92),
+    (0x2C94, 0x2C94),
+    (0x2C96, 0x2C96),
+    (0x2C98, 0x2C98),
+    (0x2C9A, 0x2C9A),
+    (0x2C9C, 0x2C9C),
+    (0x2C9E, 0x2C9E),
+    (0x2CA0, 0x2CA0),
+    (0x2CA2, 0x2CA2),
+    (0x2CA4, 0x2CA4),
+    (0x2CA6, 0x2CA6),
+    (0x2CA8, 0x2CA8),
+    (0x2CAA, 0x2CAA),
+    (0x2CAC, 0x2CAC),
+    (0x2CAE, 0x2CAE),
+    (0x2CB0, 0x2CB0),
+    (0x2CB2, 0x2CB2),
+    (0x2CB4, 0x2CB4),
+    (0x2CB6, 0x2CB6),
+    (0x2CB8, 0x2CB8),
+    (0x2CBA, 0x2CBA),
+    (0x2CBC, 0x2CBC),
+    (0x2CBE, 0x2CBE),
+    (0x2CC0, 0x2CC0),
+    (0x2CC2, 0x2CC2),
+    (0x2CC4, 0x2CC4),
+    (0x2CC6, 0x2CC6),
+    (0x2CC8, 0x2CC8),
+    (0x2CCA, 0x2CCA),
+    (0x2CCC, 0x2CCC),
+    (0x2CCE, 0x2CCE),
+    (0x2CD0, 0x2CD0),
+    (0x2CD2, 0x2CD2),
+    (0x2CD4, 0x2CD4),
+    (0x2CD6, 0x2CD6),
+    (0x2CD8, 0x2CD8),
+    (0x2CDA, 0x2CDA),
+    (0x2CDC, 0x2CDC),
+    (0x2CDE, 0x2CDE),
+    (0x2CE0, 0x2CE0),
+    (0x2CE2, 0x2CE2),
+    (0x2CEB, 0x2CEB),
+    (0x2CED, 0x2CED),
+    (0x2CF2, 0x2CF2),
+    (0x2D6F, 0x2D6F),
+    (0x2E9F, 0x2E9F),
+    (0x2EF3, 0x2EF3),
+    (0x2F00, 0x2FD5),
+    (0x3000, 0x3000),
+    (0x3036, 0x3036),
+    (0x3038, 0x303A),
+    (0x309B, 0x309C),
+    (0x309F, 0x309F),
+    (0x30FF, 0x30FF),
+    (0x3131, 0x318E),
+    (0x3192, 0x319F),
+    (0x3200, 0x321E),
+    (0x3220, 0x3247),
+    (0x3250, 0x327E),
+    (0x3280, 0x33FF),
+    (0xA640, 0xA640),
+    (0xA642, 0xA642),
+    (0xA644, 0xA644),
+    (0xA646, 0xA646),
+    (0xA648, 0xA648),
+    (0xA64A, 0xA64A),
+    (0xA64C, 0xA64C),
+    (0xA64E, 0xA64E),
+    (0xA650, 0xA650),
+    (0xA652, 0xA652),
+    (0xA654, 0xA654),
+    (0xA656, 0xA656),
+    (0xA658, 0xA658),
+    (0xA65A, 0xA65A),
+    (0xA65C, 0xA65C),
+    (0xA65E, 0xA65E),
+    (0xA660, 0xA660),
+    (0xA662, 0xA662),
+    (0xA664, 0xA664),
+    (0xA666, 0xA666),
+    (0xA668, 0xA668),
+    (0xA66A, 0xA66A),
+    (0xA66C, 0xA66C),
+    (0xA680, 0xA680),
+    (0xA682, 0xA682),
+    (0xA684, 0xA684),
+    (0xA686, 0xA686),
+    (0xA688, 0xA688),
+    (0xA68A, 0xA68A),
+    (0xA68C, 0xA68C),
+    (0xA68E, 0xA68E),
+    (0xA690, 0xA690),
+    (0xA692, 0xA692),
+    (0xA694, 0xA694),
+    (0xA696, 0xA696),
+    (0xA698, 0xA698),
+    (0xA69A, 0xA69A),
+    (0xA69C, 0xA69D),
+    (0xA722, 0xA722),
+    (0xA724, 0xA724),
+    (0xA726, 0xA726),
+    (0xA728, 0xA728),
+    (0xA72A, 0xA72A),
+    (0xA72C, 0xA72C),
+    (0xA72E, 0xA72E),
+    (0xA732, 0xA732),
+    (0xA734, 0xA734),
+    (0xA736, 0xA736),
+    (0xA738, 0xA738),
+    (0xA73A, 0xA73A),
+    (0xA73C, 0xA73C),
+    (0xA73E, 0xA73E),
+    (0xA740, 0xA740),
+    (0xA742, 0xA742),
+    (0xA744, 0xA744),
+    (0xA746, 0xA746),
+    (0xA748, 0xA748),
+    (0xA74A, 0xA74A),
+    (0xA74C, 0xA74C),
+    (0xA74E, 0xA74E),
+    (0xA750, 0xA750),
+    (0xA752, 0xA752),
+    (0xA754, 0xA754),
+    (0xA756, 0xA756),
+    (0xA758, 0xA758),
+    (0xA75A, 0xA75A),
+    (0xA75C, 0xA75C),
+    (0xA75E, 0xA75E),
+    (0xA760, 0xA760),
+    (0xA762, 0xA762),
+    (0xA764, 0xA764),
+    (0xA766, 0xA766),
+    (0xA768, 0xA768),
+    (0xA76A, 0xA76A),
+    (0xA76C, 0xA76C),
+    (0xA76E, 0xA76E),
+    (0xA770, 0xA770),
+    (0xA779, 0xA779),
+    (0xA77B, 0xA77B),
+    (0xA77D, 0xA77E),
+    (0xA780, 0xA780),
+    (0xA782, 0xA782),
+    (0xA784, 0xA784),
+    (0xA786, 0xA786),
+    (0xA78B, 0xA78B),
+    (0xA78D, 0xA78D),
+    (0xA790, 0xA790),
+    (0xA792, 0xA792),
+    (0xA796, 0xA796),
+    (0xA798, 0xA798),
+    (0xA79A, 0xA79A),
+    (0xA79C, 0xA79C),
+    (0xA79E, 0xA79E),
+    (0xA7A0, 0xA7A0),
+    (0xA7A2, 0xA7A2),
+    (0xA7A4, 0xA7A4),
+    (0xA7A6, 0xA7A6),
+    (0xA7A8, 0xA7A8),
+    (0xA7AA, 0xA7AE),
+    (0xA7B0, 0xA7B4),
+    (0xA7B6, 0xA7B6),
+    (0xA7B8, 0xA7B8),
+    (0xA7BA, 0xA7BA),
+    (0xA7BC, 0xA7BC),
+    (0xA7BE, 0xA7BE),
+    (0xA7C2, 0xA7C2),
+    (0xA7C4, 0xA7C7),
+    (0xA7C9, 0xA7C9),
+    (0xA7F5, 0xA7F5),
+    (0xA7F8, 0xA7F9),
+    (0xAB5C, 0xAB5F),
+    (0xAB69, 0xAB69),
+    (0xAB70, 0xABBF),
+    (0xF900, 0xFA0D),
+    (0xFA10, 0xFA10),
+    (0xFA12, 0xFA12),
+    (0xFA15, 0xFA1E),
+    (0xFA20, 0xFA20),
+    (0xFA22, 0xFA22),
+    (0xFA25, 0xFA26),
+    (0xFA2A, 0xFA6D),
+    (0xFA70, 0xFAD9),
+    (0xFB00, 0xFB06),
+    (0xFB13, 0xFB17),
+    (0xFB1D, 0xFB1D),
+    (0xFB1F, 0xFB36),
+    (0xFB38, 0xFB3C),
+    (0xFB3E, 0xFB3E),
+    (0xFB40, 0xFB41),
+    (0xFB43, 0xFB44),
+    (0xFB46, 0xFBB1),
+    (0xFBD3, 0xFD3D),
+    (0xFD50, 0xFD8F),
+    (0xFD92, 0xFDC7),
+    (0xFDF0, 0xFDFC),
+    (0xFE00, 0xFE19),
+    (0xFE30, 0xFE44),
+    (0xFE47, 0xFE52),
+    (0xFE54, 0xFE66),
+    (0xFE68, 0xFE6B),
+    (0xFE70, 0xFE72),
+    (0xFE74, 0xFE74),
+    (0xFE76, 0xFEFC),
+    (0xFEFF, 0xFEFF),
+    (0xFF01, 0xFFBE),
+    (0xFFC2, 0xFFC7),
+    (0xFFCA, 0xFFCF),
+    (0xFFD2, 0xFFD7),
+    (0xFFDA, 0xFFDC),
+    (0xFFE0, 0xFFE6),
+    (0xFFE8, 0xFFEE),
+    (0xFFF0, 0xFFF8),
+    (0x10400, 0x10427),
+    (0x104B0, 0x104D3),
+    (0x10C80, 0x10CB2),
+    (0x118A0, 0x118BF),
+    (0x16E40, 0x16E5F),
+    (0x1BCA0, 0x1BCA3),
+    (0x1D15E, 0x1D164),
+    (0x1D173, 0x1D17A),
+    (0x1D1BB, 0x1D1C0),
+    (0x1D400, 0x1D454),
+    (0x1D456, 0x1D49C),
+    (0x1D49E, 0x1D49F),
+    (0x1D4A2, 0x1D4A2),
+    (0x1D4A5, 0x1D4A6),
+    (0x1D4A9, 0x1D4AC),
+    (0x1D4AE, 0x1D4B9),
+    (0x1D4BB, 0x1D4BB),
+    (0x1D4BD, 0x1D4C3),
+    (0x1D4C5, 0x1D505),
+    (0x1D507, 0x1D50A),
+    (0x1D50D, 0x1D514),
+    (0x1D516, 0x1D51C),
+    (0x1D51E, 0x1D539),
+    (0x1D53B, 0x1D53E),
+    (0x1D540, 0x1D544),
+    (0x1D546, 0x1D546),
+    (0x1D54A, 0x1D550),
+    (0x1D552, 0x1D6A5),
+    (0x1D6A8, 0x1D7CB),
+    (0x1D7CE, 0x1D7FF),
+    (0x1E900, 0x1E921),
+    (0x1EE00, 0x1EE03),
+    (0x1EE05, 0x1EE1F),
+    (0x1EE21, 0x1EE22),
+    (0x1EE24, 0x1EE24),
+    (0x1EE27, 0x1EE27),
+    (0x1EE29, 0x1EE32),
+    (0x1EE34, 0x1EE37),
+    (0x1EE39, 0x1EE39),
+    (0x1EE3B, 0x1EE3B),
+    (0x1EE42, 0x1EE42),
+    (0x1EE47, 0x1EE47),
+    (0x1EE49, 0x1EE49),
+    (0x1EE4B, 0x1EE4B),
+    (0x1EE4D, 0x1EE4F),
+    (0x1EE51, 0x1EE52),
+    (0x1EE54, 0x1EE54),
+    (0x1EE57, 0x1EE57),
+    (0x1EE59, 0x1EE59),
+    (0x1EE5B, 0x1EE5B),
+    (0x1EE5D, 0x1EE5D),
+    (0x1EE5F, 0x1EE5F),
+    (0x1EE61, 0x1EE62),
+    (0x1EE64, 0x1EE64),
+    (0x1EE67, 0x1EE6A),
+    (0x1EE6C, 0x1EE72),
+    (0x1EE74, 0x1EE77),
+    (0x1EE79, 0x1EE7C),
+    (0x1EE7E, 0x1EE7E),
+    (0x1EE80, 0x1EE89),
+    (0x1EE8B, 0x1EE9B),
+    (0x1EEA1, 0x1EEA3),
+    (0x1EEA5, 0x1EEA9),
+    (0x1EEAB, 0x1EEBB),
+    (0x1F100, 0x1F10A),
+    (0x1F110, 0x1F12E),
+    (0x1F130, 0x1F14F),
+    (0x1F16A, 0x1F16C),
+    (0x1F190, 0x1F190),
+    (0x1F200, 0x1F202),
+    (0x1F210, 0x1F23B),
+    (0x1F240, 0x1F248),
+    (0x1F250, 0x1F251),
+    (0x1FBF0, 0x1FBF9),
+    (0x2F800, 0x2FA1D),
+    (0xE0000, 0xE0FFF),
+];
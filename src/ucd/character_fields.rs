@@ -0,0 +1,385 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+/// Properties of a character which can be selected individually, e.g. with `--fields`.
+///
+/// Fields are named after the corresponding members of `CharacterProperties`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CharacterField {
+    Character,
+    CodePoint,
+    Utf16,
+    Utf8,
+    Name,
+    Age,
+    GeneralCategory,
+    CodePointKind,
+    PlaneName,
+    BlockName,
+    Script,
+    ScriptExtensions,
+    NameCorrections,
+    ControlCodeNames,
+    AlternativeNames,
+    Figments,
+    NameAbbreviations,
+    IsCased,
+    Uppercase,
+    Lowercase,
+    Titlecase,
+    CaseFolding,
+    SimpleCaseFolding,
+    ChangesWhenLowercased,
+    ChangesWhenUppercased,
+    ChangesWhenTitlecased,
+    ChangesWhenCasefolded,
+    ChangesWhenCasemapped,
+    ChangesWhenNfkcCasefolded,
+    Ccc,
+    Decomposition,
+    CanonicalDecomposition,
+    CompatibilityDecomposition,
+    NfcQuickCheck,
+    NfdQuickCheck,
+    NfkcQuickCheck,
+    NfkdQuickCheck,
+    NumericType,
+    NumericValue,
+    BidiClass,
+    IsBidiControl,
+    IsBidiMirrored,
+    GraphemeClusterBreak,
+    WordBreak,
+    SentenceBreak,
+    LineBreak,
+    EastAsianWidth,
+    DisplayWidth,
+    JoiningType,
+    JoiningGroup,
+    HangulSyllableType,
+    IndicSyllabicCategory,
+    IndicPositionalCategory,
+    VerticalOrientation,
+    IsEmoji,
+    IsEmojiPresentation,
+    IsEmojiModifier,
+    IsEmojiModifierBase,
+    IsEmojiComponent,
+    IsExtendedPictographic,
+    IsAlphabetic,
+    IsWhiteSpace,
+    IsDefaultIgnorableCodePoint,
+    IsNoncharacterCodePoint,
+    IsIdStart,
+    IsIdContinue,
+    IsXidStart,
+    IsXidContinue,
+    IsPatternSyntax,
+    IsPatternWhiteSpace,
+    IsDash,
+    IsHyphen,
+    IsQuotationMark,
+    IsDiacritic,
+    IsExtender,
+    IsJoinControl,
+    IsVariationSelector,
+    SimplifiedVariant,
+    TraditionalVariant,
+    Mandarin,
+    Definition,
+    Cantonese,
+    JapaneseOn,
+    JapaneseKun,
+    Korean,
+    HangulReading,
+    Vietnamese,
+    RadicalStrokeCounts,
+    TotalStrokes,
+    SemanticVariants,
+    SpecializedSemanticVariants,
+    ZVariants,
+}
+
+const ALL_FIELDS: [CharacterField; 92] = [
+    CharacterField::Character,
+    CharacterField::CodePoint,
+    CharacterField::Utf16,
+    CharacterField::Utf8,
+    CharacterField::Name,
+    CharacterField::Age,
+    CharacterField::GeneralCategory,
+    CharacterField::CodePointKind,
+    CharacterField::PlaneName,
+    CharacterField::BlockName,
+    CharacterField::Script,
+    CharacterField::ScriptExtensions,
+    CharacterField::NameCorrections,
+    CharacterField::ControlCodeNames,
+    CharacterField::AlternativeNames,
+    CharacterField::Figments,
+    CharacterField::NameAbbreviations,
+    CharacterField::IsCased,
+    CharacterField::Uppercase,
+    CharacterField::Lowercase,
+    CharacterField::Titlecase,
+    CharacterField::CaseFolding,
+    CharacterField::SimpleCaseFolding,
+    CharacterField::ChangesWhenLowercased,
+    CharacterField::ChangesWhenUppercased,
+    CharacterField::ChangesWhenTitlecased,
+    CharacterField::ChangesWhenCasefolded,
+    CharacterField::ChangesWhenCasemapped,
+    CharacterField::ChangesWhenNfkcCasefolded,
+    CharacterField::Ccc,
+    CharacterField::Decomposition,
+    CharacterField::CanonicalDecomposition,
+    CharacterField::CompatibilityDecomposition,
+    CharacterField::NfcQuickCheck,
+    CharacterField::NfdQuickCheck,
+    CharacterField::NfkcQuickCheck,
+    CharacterField::NfkdQuickCheck,
+    CharacterField::NumericType,
+    CharacterField::NumericValue,
+    CharacterField::BidiClass,
+    CharacterField::IsBidiControl,
+    CharacterField::IsBidiMirrored,
+    CharacterField::GraphemeClusterBreak,
+    CharacterField::WordBreak,
+    CharacterField::SentenceBreak,
+    CharacterField::LineBreak,
+    CharacterField::EastAsianWidth,
+    CharacterField::DisplayWidth,
+    CharacterField::JoiningType,
+    CharacterField::JoiningGroup,
+    CharacterField::HangulSyllableType,
+    CharacterField::IndicSyllabicCategory,
+    CharacterField::IndicPositionalCategory,
+    CharacterField::VerticalOrientation,
+    CharacterField::IsEmoji,
+    CharacterField::IsEmojiPresentation,
+    CharacterField::IsEmojiModifier,
+    CharacterField::IsEmojiModifierBase,
+    CharacterField::IsEmojiComponent,
+    CharacterField::IsExtendedPictographic,
+    CharacterField::IsAlphabetic,
+    CharacterField::IsWhiteSpace,
+    CharacterField::IsDefaultIgnorableCodePoint,
+    CharacterField::IsNoncharacterCodePoint,
+    CharacterField::IsIdStart,
+    CharacterField::IsIdContinue,
+    CharacterField::IsXidStart,
+    CharacterField::IsXidContinue,
+    CharacterField::IsPatternSyntax,
+    CharacterField::IsPatternWhiteSpace,
+    CharacterField::IsDash,
+    CharacterField::IsHyphen,
+    CharacterField::IsQuotationMark,
+    CharacterField::IsDiacritic,
+    CharacterField::IsExtender,
+    CharacterField::IsJoinControl,
+    CharacterField::IsVariationSelector,
+    CharacterField::SimplifiedVariant,
+    CharacterField::TraditionalVariant,
+    CharacterField::Mandarin,
+    CharacterField::Definition,
+    CharacterField::Cantonese,
+    CharacterField::JapaneseOn,
+    CharacterField::JapaneseKun,
+    CharacterField::Korean,
+    CharacterField::HangulReading,
+    CharacterField::Vietnamese,
+    CharacterField::RadicalStrokeCounts,
+    CharacterField::TotalStrokes,
+    CharacterField::SemanticVariants,
+    CharacterField::SpecializedSemanticVariants,
+    CharacterField::ZVariants,
+];
+
+impl CharacterField {
+    pub fn name(self) -> &'static str {
+        match self {
+            CharacterField::Character => "character",
+            CharacterField::CodePoint => "code_point",
+            CharacterField::Utf16 => "utf16",
+            CharacterField::Utf8 => "utf8",
+            CharacterField::Name => "name",
+            CharacterField::Age => "age",
+            CharacterField::GeneralCategory => "general_category",
+            CharacterField::CodePointKind => "code_point_kind",
+            CharacterField::PlaneName => "plane_name",
+            CharacterField::BlockName => "block_name",
+            CharacterField::Script => "script",
+            CharacterField::ScriptExtensions => "script_extensions",
+            CharacterField::NameCorrections => "name_corrections",
+            CharacterField::ControlCodeNames => "control_code_names",
+            CharacterField::AlternativeNames => "alternative_names",
+            CharacterField::Figments => "figments",
+            CharacterField::NameAbbreviations => "name_abbreviations",
+            CharacterField::IsCased => "is_cased",
+            CharacterField::Uppercase => "uppercase",
+            CharacterField::Lowercase => "lowercase",
+            CharacterField::Titlecase => "titlecase",
+            CharacterField::CaseFolding => "case_folding",
+            CharacterField::SimpleCaseFolding => "simple_case_folding",
+            CharacterField::ChangesWhenLowercased => "changes_when_lowercased",
+            CharacterField::ChangesWhenUppercased => "changes_when_uppercased",
+            CharacterField::ChangesWhenTitlecased => "changes_when_titlecased",
+            CharacterField::ChangesWhenCasefolded => "changes_when_casefolded",
+            CharacterField::ChangesWhenCasemapped => "changes_when_casemapped",
+            CharacterField::ChangesWhenNfkcCasefolded => "changes_when_nfkc_casefolded",
+            CharacterField::Ccc => "ccc",
+            CharacterField::Decomposition => "decomposition",
+            CharacterField::CanonicalDecomposition => "canonical_decomposition",
+            CharacterField::CompatibilityDecomposition => "compatibility_decomposition",
+            CharacterField::NfcQuickCheck => "nfc_quick_check",
+            CharacterField::NfdQuickCheck => "nfd_quick_check",
+            CharacterField::NfkcQuickCheck => "nfkc_quick_check",
+            CharacterField::NfkdQuickCheck => "nfkd_quick_check",
+            CharacterField::NumericType => "numeric_type",
+            CharacterField::NumericValue => "numeric_value",
+            CharacterField::BidiClass => "bidi_class",
+            CharacterField::IsBidiControl => "is_bidi_control",
+            CharacterField::IsBidiMirrored => "is_bidi_mirrored",
+            CharacterField::GraphemeClusterBreak => "grapheme_cluster_break",
+            CharacterField::WordBreak => "word_break",
+            CharacterField::SentenceBreak => "sentence_break",
+            CharacterField::LineBreak => "line_break",
+            CharacterField::EastAsianWidth => "east_asian_width",
+            CharacterField::DisplayWidth => "display_width",
+            CharacterField::JoiningType => "joining_type",
+            CharacterField::JoiningGroup => "joining_group",
+            CharacterField::HangulSyllableType => "hangul_syllable_type",
+            CharacterField::IndicSyllabicCategory => "indic_syllabic_category",
+            CharacterField::IndicPositionalCategory => "indic_positional_category",
+            CharacterField::VerticalOrientation => "vertical_orientation",
+            CharacterField::IsEmoji => "is_emoji",
+            CharacterField::IsEmojiPresentation => "is_emoji_presentation",
+            CharacterField::IsEmojiModifier => "is_emoji_modifier",
+            CharacterField::IsEmojiModifierBase => "is_emoji_modifier_base",
+            CharacterField::IsEmojiComponent => "is_emoji_component",
+            CharacterField::IsExtendedPictographic => "is_extended_pictographic",
+            CharacterField::IsAlphabetic => "is_alphabetic",
+            CharacterField::IsWhiteSpace => "is_white_space",
+            CharacterField::IsDefaultIgnorableCodePoint => "is_default_ignorable_code_point",
+            CharacterField::IsNoncharacterCodePoint => "is_noncharacter_code_point",
+            CharacterField::IsIdStart => "is_id_start",
+            CharacterField::IsIdContinue => "is_id_continue",
+            CharacterField::IsXidStart => "is_xid_start",
+            CharacterField::IsXidContinue => "is_xid_continue",
+            CharacterField::IsPatternSyntax => "is_pattern_syntax",
+            CharacterField::IsPatternWhiteSpace => "is_pattern_white_space",
+            CharacterField::IsDash => "is_dash",
+            CharacterField::IsHyphen => "is_hyphen",
+            CharacterField::IsQuotationMark => "is_quotation_mark",
+            CharacterField::IsDiacritic => "is_diacritic",
+            CharacterField::IsExtender => "is_extender",
+            CharacterField::IsJoinControl => "is_join_control",
+            CharacterField::IsVariationSelector => "is_variation_selector",
+            CharacterField::SimplifiedVariant => "simplified_variant",
+            CharacterField::TraditionalVariant => "traditional_variant",
+            CharacterField::Mandarin => "mandarin",
+            CharacterField::Definition => "definition",
+            CharacterField::Cantonese => "cantonese",
+            CharacterField::JapaneseOn => "japanese_on",
+            CharacterField::JapaneseKun => "japanese_kun",
+            CharacterField::Korean => "korean",
+            CharacterField::HangulReading => "hangul_reading",
+            CharacterField::Vietnamese => "vietnamese",
+            CharacterField::RadicalStrokeCounts => "radical_stroke_counts",
+            CharacterField::TotalStrokes => "total_strokes",
+            CharacterField::SemanticVariants => "semantic_variants",
+            CharacterField::SpecializedSemanticVariants => "specialized_semantic_variants",
+            CharacterField::ZVariants => "z_variants",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        ALL_FIELDS
+            .iter()
+            .copied()
+            .find(|field| field.name() == name)
+    }
+}
+
+/// A set of `CharacterField`s, iterated in the order they are declared.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CharacterFields(u128);
+
+impl CharacterFields {
+    pub fn all() -> Self {
+        CharacterFields::from_fields(&ALL_FIELDS)
+    }
+
+    pub fn from_fields(fields: &[CharacterField]) -> Self {
+        CharacterFields(
+            fields
+                .iter()
+                .fold(0, |bits, field| bits | CharacterFields::bit(*field)),
+        )
+    }
+
+    fn bit(field: CharacterField) -> u128 {
+        1 << field as u128
+    }
+
+    pub fn contains(self, field: CharacterField) -> bool {
+        self.0 & CharacterFields::bit(field) != 0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = CharacterField> {
+        ALL_FIELDS
+            .iter()
+            .copied()
+            .filter(move |field| self.contains(*field))
+    }
+}
+
+impl Default for CharacterFields {
+    fn default() -> Self {
+        CharacterFields::all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_names() {
+        for field in ALL_FIELDS.iter() {
+            assert_eq!(CharacterField::from_name(field.name()), Some(*field));
+        }
+        assert_eq!(CharacterField::from_name("not_a_field"), None);
+    }
+
+    #[test]
+    fn test_fields_are_iterated_in_declaration_order() {
+        let fields = CharacterFields::from_fields(&[
+            CharacterField::BidiClass,
+            CharacterField::Name,
+            CharacterField::CodePoint,
+        ]);
+        assert!(fields.contains(CharacterField::Name));
+        assert!(!fields.contains(CharacterField::Age));
+        assert_eq!(
+            fields.iter().collect::<Vec<CharacterField>>(),
+            vec![
+                CharacterField::CodePoint,
+                CharacterField::Name,
+                CharacterField::BidiClass
+            ]
+        );
+        assert_eq!(CharacterFields::all().iter().count(), ALL_FIELDS.len());
+    }
+}
@@ -13,39 +13,90 @@
 // Cicero. If not, see <https://www.gnu.org/licenses/>.
 
 use hex_slice::AsHex;
+use serde::ser::{SerializeMap, Serializer};
 use serde::Serialize;
 use unic::char::property::EnumeratedCharProperty;
+use unic::emoji::char::{
+    is_emoji, is_emoji_component, is_emoji_modifier, is_emoji_modifier_base, is_emoji_presentation,
+};
 use unic::segment::Graphemes;
 use unic::ucd::{
     bidi::{is_bidi_control, is_bidi_mirrored, BidiClass},
+    common::{is_alphabetic, is_white_space},
+    ident::{
+        is_id_continue, is_id_start, is_pattern_syntax, is_pattern_whitespace, is_xid_continue,
+        is_xid_start,
+    },
     is_cased, mandarin_of, name_aliases_of,
-    normal::{decompose_compatible, DecompositionType},
+    normal::DecompositionType,
     simplified_variant_of, traditional_variant_of, Age, Block, CanonicalCombiningClass,
-    GeneralCategory, Name, NameAliasType,
+    GeneralCategory, GraphemeClusterBreak, Name, NameAliasType, SentenceBreak, WordBreak,
 };
 
 use std::fmt;
 
-use super::{code_point_to_string, Plane};
+use super::{
+    case_folding_of, changes_when_casefolded, changes_when_casemapped, changes_when_lowercased,
+    changes_when_nfkc_casefolded, changes_when_titlecased, changes_when_uppercased,
+    character_display_width, code_point_to_string, decomposition_mapping,
+    full_canonical_decomposition, full_compatibility_decomposition, grapheme_display_width,
+    is_dash, is_default_ignorable_code_point, is_diacritic, is_extended_pictographic, is_extender,
+    is_hyphen, is_join_control, is_noncharacter_code_point, is_quotation_mark,
+    is_variation_selector, lowercase_of, simple_case_folding_of, titlecase_of, unihan_entry_of,
+    uppercase_of, CharacterField, CharacterFields, CodePointKind, EastAsianWidth, EmojiSequence,
+    HangulSyllableType, IndicPositionalCategory, IndicSyllabicCategory, JoiningGroup, JoiningType,
+    LineBreak, NfcQuickCheck, NfdQuickCheck, NfkcQuickCheck, NfkdQuickCheck, NumericType,
+    NumericValue, Plane, Script, VerticalOrientation,
+};
 
-const TREE_GRAPH_EDGE: &str = "├── ";
-const TREE_GRAPH_CORNER: &str = "└── ";
+pub const TREE_GRAPH_EDGE: &str = "├── ";
+pub const TREE_GRAPH_CORNER: &str = "└── ";
 
 #[derive(Serialize, Debug)]
 pub struct GraphemeProperties {
     grapheme: String,
+    // Only computed if the display width of characters is selected
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_sequence: Option<EmojiSequence>,
     characters: Vec<CharacterProperties>,
 }
 
 impl GraphemeProperties {
-    pub fn from_string(string: &str) -> Vec<GraphemeProperties> {
+    pub fn from_string(string: &str, fields: CharacterFields) -> Vec<GraphemeProperties> {
         Graphemes::new(string)
             .map(|grapheme| GraphemeProperties {
                 grapheme: grapheme.to_owned(),
-                characters: grapheme.chars().map(CharacterProperties::new).collect(),
+                display_width: if fields.contains(CharacterField::DisplayWidth) {
+                    Some(grapheme_display_width(grapheme))
+                } else {
+                    None
+                },
+                emoji_sequence: EmojiSequence::of(grapheme),
+                characters: grapheme
+                    .chars()
+                    .map(|chr| CharacterProperties::with_fields(chr, fields))
+                    .collect(),
             })
             .collect()
     }
+
+    pub fn grapheme(&self) -> &str {
+        &self.grapheme
+    }
+
+    pub fn display_width(&self) -> Option<usize> {
+        self.display_width
+    }
+
+    pub fn emoji_sequence(&self) -> Option<&EmojiSequence> {
+        self.emoji_sequence.as_ref()
+    }
+
+    pub fn characters(&self) -> &[CharacterProperties] {
+        &self.characters
+    }
 }
 
 impl fmt::Display for GraphemeProperties {
@@ -63,9 +114,10 @@ impl fmt::Display for GraphemeProperties {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Debug)]
 pub struct CharacterProperties {
     pub character: char,
+    fields: CharacterFields,
 
     pub code_point: u32,
     pub utf16: Vec<u16>,
@@ -73,8 +125,11 @@ pub struct CharacterProperties {
     pub name: String,
     pub age: Option<String>,
     pub general_category: StringValuedProperty,
+    pub code_point_kind: Option<CodePointKind>,
     pub plane_name: &'static str,
     pub block_name: Option<&'static str>,
+    pub script: StringValuedProperty,
+    pub script_extensions: Vec<StringValuedProperty>,
 
     pub name_corrections: Option<&'static [&'static str]>,
     pub control_code_names: Option<&'static [&'static str]>,
@@ -83,77 +138,608 @@ pub struct CharacterProperties {
     pub name_abbreviations: Option<&'static [&'static str]>,
 
     pub is_cased: bool,
-    // TODO: Implement titlecase in rust-unic
-    pub uppercase: Option<Vec<char>>, // TODO: Implement uppercase in rust-unic
-    pub lowercase: Option<Vec<char>>, // TODO: Implement lowercase in rust-unic
+    // Case mappings are full mappings (e.g. "SS" for "ß") and only available for cased characters
+    pub uppercase: Option<Vec<char>>,
+    pub lowercase: Option<Vec<char>>,
+    pub titlecase: Option<Vec<char>>,
+    pub case_folding: Option<Vec<char>>,
+    pub simple_case_folding: Option<char>,
+    pub changes_when_lowercased: bool,
+    pub changes_when_uppercased: bool,
+    pub changes_when_titlecased: bool,
+    pub changes_when_casefolded: bool,
+    pub changes_when_casemapped: bool,
+    pub changes_when_nfkc_casefolded: bool,
 
     pub ccc: u8,
     pub decomposition: Option<Decomposition>,
+    pub canonical_decomposition: Option<Vec<char>>,
+    pub compatibility_decomposition: Option<Vec<char>>,
+    pub nfc_quick_check: StringValuedProperty,
+    pub nfd_quick_check: StringValuedProperty,
+    pub nfkc_quick_check: StringValuedProperty,
+    pub nfkd_quick_check: StringValuedProperty,
+
+    pub numeric_type: StringValuedProperty,
+    pub numeric_value: Option<NumericValue>,
 
     pub bidi_class: StringValuedProperty,
     pub is_bidi_control: bool,
     pub is_bidi_mirrored: bool,
 
+    pub grapheme_cluster_break: StringValuedProperty,
+    pub word_break: StringValuedProperty,
+    pub sentence_break: StringValuedProperty,
+    pub line_break: StringValuedProperty,
+
+    pub east_asian_width: StringValuedProperty,
+    pub display_width: usize,
+
+    pub joining_type: StringValuedProperty,
+    pub joining_group: StringValuedProperty,
+    pub hangul_syllable_type: StringValuedProperty,
+    pub indic_syllabic_category: StringValuedProperty,
+    pub indic_positional_category: StringValuedProperty,
+    pub vertical_orientation: StringValuedProperty,
+
+    pub is_emoji: bool,
+    pub is_emoji_presentation: bool,
+    pub is_emoji_modifier: bool,
+    pub is_emoji_modifier_base: bool,
+    pub is_emoji_component: bool,
+    pub is_extended_pictographic: bool,
+
+    pub is_alphabetic: bool,
+    pub is_white_space: bool,
+    pub is_default_ignorable_code_point: bool,
+    pub is_noncharacter_code_point: bool,
+    pub is_id_start: bool,
+    pub is_id_continue: bool,
+    pub is_xid_start: bool,
+    pub is_xid_continue: bool,
+    pub is_pattern_syntax: bool,
+    pub is_pattern_white_space: bool,
+    pub is_dash: bool,
+    pub is_hyphen: bool,
+    pub is_quotation_mark: bool,
+    pub is_diacritic: bool,
+    pub is_extender: bool,
+    pub is_join_control: bool,
+    pub is_variation_selector: bool,
+
     pub simplified_variant: Option<char>,
     pub traditional_variant: Option<char>,
     pub mandarin: Option<&'static str>,
+    pub definition: Option<&'static str>,
+    pub cantonese: Option<&'static str>,
+    pub japanese_on: Option<&'static str>,
+    pub japanese_kun: Option<&'static str>,
+    pub korean: Option<&'static str>,
+    pub hangul_reading: Option<&'static str>,
+    pub vietnamese: Option<&'static str>,
+    pub radical_stroke_counts: Option<&'static str>,
+    pub total_strokes: Option<&'static str>,
+    pub semantic_variants: &'static [char],
+    pub specialized_semantic_variants: &'static [char],
+    pub z_variants: &'static [char],
 }
 
 impl CharacterProperties {
     pub fn new(character: char) -> Self {
+        CharacterProperties::with_fields(character, CharacterFields::all())
+    }
+
+    /// Computes only the properties in `fields`, other properties are left empty and are omitted
+    /// when serialized.
+    pub fn with_fields(character: char, fields: CharacterFields) -> Self {
         let mut utf16_storage = [0; 2];
         let utf16_slice = character.encode_utf16(&mut utf16_storage);
 
         let mut utf8_storage = [0; 4];
         let utf8_slice = character.encode_utf8(&mut utf8_storage);
 
+        let selected = |field| fields.contains(field);
+
         CharacterProperties {
             character,
+            fields,
 
             code_point: character as u32,
-            utf16: utf16_slice.to_vec(),
-            utf8: utf8_slice.as_bytes().to_vec(),
+            utf16: if_selected(selected(CharacterField::Utf16), || utf16_slice.to_vec()),
+            utf8: if_selected(selected(CharacterField::Utf8), || {
+                utf8_slice.as_bytes().to_vec()
+            }),
 
-            name: match Name::of(character) {
-                Some(name) => name.to_string(),
-                None => "".to_owned(),
-            },
-            age: Age::of(character).map(|age| age.actual().to_string()),
-            general_category: StringValuedProperty::new(GeneralCategory::of(character)),
-            plane_name: Plane::of(character).name,
-            block_name: match Block::of(character) {
-                Some(block) => Some(block.name),
-                None => None,
-            },
+            name: if_selected(selected(CharacterField::Name), || {
+                match Name::of(character) {
+                    Some(name) => name.to_string(),
+                    None => "".to_owned(),
+                }
+            }),
+            age: if_selected(selected(CharacterField::Age), || {
+                Age::of(character).map(|age| age.actual().to_string())
+            }),
+            general_category: if_selected(selected(CharacterField::GeneralCategory), || {
+                StringValuedProperty::new(GeneralCategory::of(character))
+            }),
+            code_point_kind: if_selected(selected(CharacterField::CodePointKind), || {
+                Some(CodePointKind::of(character as u32))
+            }),
+            plane_name: if_selected(selected(CharacterField::PlaneName), || {
+                Plane::of(character).name
+            }),
+            block_name: if_selected(selected(CharacterField::BlockName), || {
+                Block::of(character).map(|block| block.name)
+            }),
+            script: if_selected(selected(CharacterField::Script), || {
+                StringValuedProperty::new(Script::of(character))
+            }),
+            script_extensions: if_selected(selected(CharacterField::ScriptExtensions), || {
+                Script::extensions_of(character)
+                    .iter()
+                    .map(|script| StringValuedProperty::new(*script))
+                    .collect()
+            }),
 
-            name_corrections: name_aliases_of(character, NameAliasType::NameCorrections),
-            control_code_names: name_aliases_of(character, NameAliasType::ControlCodeNames),
-            alternative_names: name_aliases_of(character, NameAliasType::AlternateNames),
-            figments: name_aliases_of(character, NameAliasType::Figments),
-            name_abbreviations: name_aliases_of(character, NameAliasType::NameAbbreviations),
+            name_corrections: if_selected(selected(CharacterField::NameCorrections), || {
+                name_aliases_of(character, NameAliasType::NameCorrections)
+            }),
+            control_code_names: if_selected(selected(CharacterField::ControlCodeNames), || {
+                name_aliases_of(character, NameAliasType::ControlCodeNames)
+            }),
+            alternative_names: if_selected(selected(CharacterField::AlternativeNames), || {
+                name_aliases_of(character, NameAliasType::AlternateNames)
+            }),
+            figments: if_selected(selected(CharacterField::Figments), || {
+                name_aliases_of(character, NameAliasType::Figments)
+            }),
+            name_abbreviations: if_selected(selected(CharacterField::NameAbbreviations), || {
+                name_aliases_of(character, NameAliasType::NameAbbreviations)
+            }),
 
-            is_cased: is_cased(character),
-            uppercase: if is_cased(character) {
-                Some(character.to_uppercase().collect())
-            } else {
-                None
-            },
-            lowercase: if is_cased(character) {
-                Some(character.to_lowercase().collect())
-            } else {
-                None
-            },
+            is_cased: if_selected(selected(CharacterField::IsCased), || is_cased(character)),
+            uppercase: if_selected(selected(CharacterField::Uppercase), || {
+                case_mapping(character, uppercase_of(character))
+            }),
+            lowercase: if_selected(selected(CharacterField::Lowercase), || {
+                case_mapping(character, lowercase_of(character))
+            }),
+            titlecase: if_selected(selected(CharacterField::Titlecase), || {
+                case_mapping(character, titlecase_of(character))
+            }),
+            case_folding: if_selected(selected(CharacterField::CaseFolding), || {
+                case_mapping(character, case_folding_of(character))
+            }),
+            simple_case_folding: if_selected(selected(CharacterField::SimpleCaseFolding), || {
+                if is_cased(character) {
+                    Some(simple_case_folding_of(character).unwrap_or(character))
+                } else {
+                    None
+                }
+            }),
+            changes_when_lowercased: if_selected(
+                selected(CharacterField::ChangesWhenLowercased),
+                || changes_when_lowercased(character),
+            ),
+            changes_when_uppercased: if_selected(
+                selected(CharacterField::ChangesWhenUppercased),
+                || changes_when_uppercased(character),
+            ),
+            changes_when_titlecased: if_selected(
+                selected(CharacterField::ChangesWhenTitlecased),
+                || changes_when_titlecased(character),
+            ),
+            changes_when_casefolded: if_selected(
+                selected(CharacterField::ChangesWhenCasefolded),
+                || changes_when_casefolded(character),
+            ),
+            changes_when_casemapped: if_selected(
+                selected(CharacterField::ChangesWhenCasemapped),
+                || changes_when_casemapped(character),
+            ),
+            changes_when_nfkc_casefolded: if_selected(
+                selected(CharacterField::ChangesWhenNfkcCasefolded),
+                || changes_when_nfkc_casefolded(character),
+            ),
+
+            ccc: if_selected(selected(CharacterField::Ccc), || {
+                CanonicalCombiningClass::of(character).number()
+            }),
+            decomposition: if_selected(selected(CharacterField::Decomposition), || {
+                Decomposition::new(character)
+            }),
+
+            canonical_decomposition: if_selected(
+                selected(CharacterField::CanonicalDecomposition),
+                || full_canonical_decomposition(character),
+            ),
+            compatibility_decomposition: if_selected(
+                selected(CharacterField::CompatibilityDecomposition),
+                || full_compatibility_decomposition(character),
+            ),
+            nfc_quick_check: if_selected(selected(CharacterField::NfcQuickCheck), || {
+                StringValuedProperty::new(NfcQuickCheck::of(character))
+            }),
+            nfd_quick_check: if_selected(selected(CharacterField::NfdQuickCheck), || {
+                StringValuedProperty::new(NfdQuickCheck::of(character))
+            }),
+            nfkc_quick_check: if_selected(selected(CharacterField::NfkcQuickCheck), || {
+                StringValuedProperty::new(NfkcQuickCheck::of(character))
+            }),
+            nfkd_quick_check: if_selected(selected(CharacterField::NfkdQuickCheck), || {
+                StringValuedProperty::new(NfkdQuickCheck::of(character))
+            }),
+            numeric_type: if_selected(selected(CharacterField::NumericType), || {
+                StringValuedProperty::new(NumericType::of(character))
+            }),
+            numeric_value: if_selected(selected(CharacterField::NumericValue), || {
+                NumericValue::of(character)
+            }),
+
+            bidi_class: if_selected(selected(CharacterField::BidiClass), || {
+                StringValuedProperty::new(BidiClass::of(character))
+            }),
+            is_bidi_control: if_selected(selected(CharacterField::IsBidiControl), || {
+                is_bidi_control(character)
+            }),
+            is_bidi_mirrored: if_selected(selected(CharacterField::IsBidiMirrored), || {
+                is_bidi_mirrored(character)
+            }),
+
+            grapheme_cluster_break: if_selected(
+                selected(CharacterField::GraphemeClusterBreak),
+                || StringValuedProperty::new(GraphemeClusterBreak::of(character)),
+            ),
+            word_break: if_selected(selected(CharacterField::WordBreak), || {
+                StringValuedProperty::new(WordBreak::of(character))
+            }),
+            sentence_break: if_selected(selected(CharacterField::SentenceBreak), || {
+                StringValuedProperty::new(SentenceBreak::of(character))
+            }),
+            line_break: if_selected(selected(CharacterField::LineBreak), || {
+                StringValuedProperty::new(LineBreak::of(character))
+            }),
+
+            east_asian_width: if_selected(selected(CharacterField::EastAsianWidth), || {
+                StringValuedProperty::new(EastAsianWidth::of(character))
+            }),
+            display_width: if_selected(selected(CharacterField::DisplayWidth), || {
+                character_display_width(character)
+            }),
 
-            ccc: CanonicalCombiningClass::of(character).number(),
-            decomposition: Decomposition::new(character),
+            joining_type: if_selected(selected(CharacterField::JoiningType), || {
+                StringValuedProperty::new(JoiningType::of(character))
+            }),
+            joining_group: if_selected(selected(CharacterField::JoiningGroup), || {
+                StringValuedProperty::new(JoiningGroup::of(character))
+            }),
+            hangul_syllable_type: if_selected(selected(CharacterField::HangulSyllableType), || {
+                StringValuedProperty::new(HangulSyllableType::of(character))
+            }),
+            indic_syllabic_category: if_selected(
+                selected(CharacterField::IndicSyllabicCategory),
+                || StringValuedProperty::new(IndicSyllabicCategory::of(character)),
+            ),
+            indic_positional_category: if_selected(
+                selected(CharacterField::IndicPositionalCategory),
+                || StringValuedProperty::new(IndicPositionalCategory::of(character)),
+            ),
+            vertical_orientation: if_selected(
+                selected(CharacterField::VerticalOrientation),
+                || StringValuedProperty::new(VerticalOrientation::of(character)),
+            ),
 
-            bidi_class: StringValuedProperty::new(BidiClass::of(character)),
-            is_bidi_control: is_bidi_control(character),
-            is_bidi_mirrored: is_bidi_mirrored(character),
+            is_emoji: if_selected(selected(CharacterField::IsEmoji), || is_emoji(character)),
+            is_emoji_presentation: if_selected(
+                selected(CharacterField::IsEmojiPresentation),
+                || is_emoji_presentation(character),
+            ),
+            is_emoji_modifier: if_selected(selected(CharacterField::IsEmojiModifier), || {
+                is_emoji_modifier(character)
+            }),
+            is_emoji_modifier_base: if_selected(
+                selected(CharacterField::IsEmojiModifierBase),
+                || is_emoji_modifier_base(character),
+            ),
+            is_emoji_component: if_selected(selected(CharacterField::IsEmojiComponent), || {
+                is_emoji_component(character)
+            }),
+            is_extended_pictographic: if_selected(
+                selected(CharacterField::IsExtendedPictographic),
+                || is_extended_pictographic(character),
+            ),
 
-            mandarin: mandarin_of(character),
-            traditional_variant: traditional_variant_of(character),
-            simplified_variant: simplified_variant_of(character),
+            is_alphabetic: if_selected(selected(CharacterField::IsAlphabetic), || {
+                is_alphabetic(character)
+            }),
+            is_white_space: if_selected(selected(CharacterField::IsWhiteSpace), || {
+                is_white_space(character)
+            }),
+            is_default_ignorable_code_point: if_selected(
+                selected(CharacterField::IsDefaultIgnorableCodePoint),
+                || is_default_ignorable_code_point(character),
+            ),
+            is_noncharacter_code_point: if_selected(
+                selected(CharacterField::IsNoncharacterCodePoint),
+                || is_noncharacter_code_point(character),
+            ),
+            is_id_start: if_selected(selected(CharacterField::IsIdStart), || {
+                is_id_start(character)
+            }),
+            is_id_continue: if_selected(selected(CharacterField::IsIdContinue), || {
+                is_id_continue(character)
+            }),
+            is_xid_start: if_selected(selected(CharacterField::IsXidStart), || {
+                is_xid_start(character)
+            }),
+            is_xid_continue: if_selected(selected(CharacterField::IsXidContinue), || {
+                is_xid_continue(character)
+            }),
+            is_pattern_syntax: if_selected(selected(CharacterField::IsPatternSyntax), || {
+                is_pattern_syntax(character)
+            }),
+            is_pattern_white_space: if_selected(
+                selected(CharacterField::IsPatternWhiteSpace),
+                || is_pattern_whitespace(character),
+            ),
+            is_dash: if_selected(selected(CharacterField::IsDash), || is_dash(character)),
+            is_hyphen: if_selected(selected(CharacterField::IsHyphen), || is_hyphen(character)),
+            is_quotation_mark: if_selected(selected(CharacterField::IsQuotationMark), || {
+                is_quotation_mark(character)
+            }),
+            is_diacritic: if_selected(selected(CharacterField::IsDiacritic), || {
+                is_diacritic(character)
+            }),
+            is_extender: if_selected(selected(CharacterField::IsExtender), || {
+                is_extender(character)
+            }),
+            is_join_control: if_selected(selected(CharacterField::IsJoinControl), || {
+                is_join_control(character)
+            }),
+            is_variation_selector: if_selected(
+                selected(CharacterField::IsVariationSelector),
+                || is_variation_selector(character),
+            ),
+
+            mandarin: if_selected(selected(CharacterField::Mandarin), || {
+                mandarin_of(character)
+            }),
+            traditional_variant: if_selected(selected(CharacterField::TraditionalVariant), || {
+                traditional_variant_of(character)
+            }),
+            simplified_variant: if_selected(selected(CharacterField::SimplifiedVariant), || {
+                simplified_variant_of(character)
+            }),
+            definition: if_selected(selected(CharacterField::Definition), || {
+                unihan_entry_of(character).and_then(|entry| entry.definition.as_deref())
+            }),
+            cantonese: if_selected(selected(CharacterField::Cantonese), || {
+                unihan_entry_of(character).and_then(|entry| entry.cantonese.as_deref())
+            }),
+            japanese_on: if_selected(selected(CharacterField::JapaneseOn), || {
+                unihan_entry_of(character).and_then(|entry| entry.japanese_on.as_deref())
+            }),
+            japanese_kun: if_selected(selected(CharacterField::JapaneseKun), || {
+                unihan_entry_of(character).and_then(|entry| entry.japanese_kun.as_deref())
+            }),
+            korean: if_selected(selected(CharacterField::Korean), || {
+                unihan_entry_of(character).and_then(|entry| entry.korean.as_deref())
+            }),
+            hangul_reading: if_selected(selected(CharacterField::HangulReading), || {
+                unihan_entry_of(character).and_then(|entry| entry.hangul_reading.as_deref())
+            }),
+            vietnamese: if_selected(selected(CharacterField::Vietnamese), || {
+                unihan_entry_of(character).and_then(|entry| entry.vietnamese.as_deref())
+            }),
+            radical_stroke_counts: if_selected(
+                selected(CharacterField::RadicalStrokeCounts),
+                || {
+                    unihan_entry_of(character)
+                        .and_then(|entry| entry.radical_stroke_counts.as_deref())
+                },
+            ),
+            total_strokes: if_selected(selected(CharacterField::TotalStrokes), || {
+                unihan_entry_of(character).and_then(|entry| entry.total_strokes.as_deref())
+            }),
+            semantic_variants: if_selected(selected(CharacterField::SemanticVariants), || {
+                unihan_entry_of(character)
+                    .map(|entry| entry.semantic_variants.as_slice())
+                    .unwrap_or_default()
+            }),
+            specialized_semantic_variants: if_selected(
+                selected(CharacterField::SpecializedSemanticVariants),
+                || {
+                    unihan_entry_of(character)
+                        .map(|entry| entry.specialized_semantic_variants.as_slice())
+                        .unwrap_or_default()
+                },
+            ),
+            z_variants: if_selected(selected(CharacterField::ZVariants), || {
+                unihan_entry_of(character)
+                    .map(|entry| entry.z_variants.as_slice())
+                    .unwrap_or_default()
+            }),
+        }
+    }
+
+    /// Describes `field` as a single line of text, e.g. for a table cell.
+    pub fn field_value(&self, field: CharacterField) -> String {
+        fn join_names(names: Option<&'static [&'static str]>) -> String {
+            names.map(|names| names.join("; ")).unwrap_or_default()
+        }
+
+        fn join_code_points(characters: &[char]) -> String {
+            characters
+                .iter()
+                .map(|chr| code_point_to_string(*chr))
+                .collect::<Vec<String>>()
+                .join(" ")
+        }
+
+        match field {
+            CharacterField::Character => self.character.to_string(),
+            CharacterField::CodePoint => code_point_to_string(self.character),
+            CharacterField::Utf16 => self
+                .utf16
+                .iter()
+                .map(|code_unit| format!("{:04X}", code_unit))
+                .collect::<Vec<String>>()
+                .join(" "),
+            CharacterField::Utf8 => self
+                .utf8
+                .iter()
+                .map(|byte| format!("{:02X}", byte))
+                .collect::<Vec<String>>()
+                .join(" "),
+            CharacterField::Name => self.name.clone(),
+            CharacterField::Age => self.age.clone().unwrap_or_default(),
+            CharacterField::GeneralCategory => self.general_category.abbr.to_owned(),
+            CharacterField::CodePointKind => self
+                .code_point_kind
+                .map(|kind| kind.name().to_owned())
+                .unwrap_or_default(),
+            CharacterField::PlaneName => self.plane_name.to_owned(),
+            CharacterField::BlockName => self.block_name.unwrap_or_default().to_owned(),
+            CharacterField::Script => self.script.abbr.to_owned(),
+            CharacterField::ScriptExtensions => self
+                .script_extensions
+                .iter()
+                .map(|script| script.abbr)
+                .collect::<Vec<&str>>()
+                .join(" "),
+            CharacterField::NameCorrections => join_names(self.name_corrections),
+            CharacterField::ControlCodeNames => join_names(self.control_code_names),
+            CharacterField::AlternativeNames => join_names(self.alternative_names),
+            CharacterField::Figments => join_names(self.figments),
+            CharacterField::NameAbbreviations => join_names(self.name_abbreviations),
+            CharacterField::IsCased => self.is_cased.to_string(),
+            CharacterField::Uppercase => self
+                .uppercase
+                .as_ref()
+                .map(|uppercase| join_code_points(uppercase))
+                .unwrap_or_default(),
+            CharacterField::Lowercase => self
+                .lowercase
+                .as_ref()
+                .map(|lowercase| join_code_points(lowercase))
+                .unwrap_or_default(),
+            CharacterField::Titlecase => self
+                .titlecase
+                .as_ref()
+                .map(|titlecase| join_code_points(titlecase))
+                .unwrap_or_default(),
+            CharacterField::CaseFolding => self
+                .case_folding
+                .as_ref()
+                .map(|case_folding| join_code_points(case_folding))
+                .unwrap_or_default(),
+            CharacterField::SimpleCaseFolding => self
+                .simple_case_folding
+                .map(code_point_to_string)
+                .unwrap_or_default(),
+            CharacterField::ChangesWhenLowercased => self.changes_when_lowercased.to_string(),
+            CharacterField::ChangesWhenUppercased => self.changes_when_uppercased.to_string(),
+            CharacterField::ChangesWhenTitlecased => self.changes_when_titlecased.to_string(),
+            CharacterField::ChangesWhenCasefolded => self.changes_when_casefolded.to_string(),
+            CharacterField::ChangesWhenCasemapped => self.changes_when_casemapped.to_string(),
+            CharacterField::ChangesWhenNfkcCasefolded => {
+                self.changes_when_nfkc_casefolded.to_string()
+            }
+            CharacterField::Ccc => self.ccc.to_string(),
+            CharacterField::Decomposition => match &self.decomposition {
+                Some(decomposition) => format!(
+                    "{}: {}",
+                    decomposition.decomposition_type.abbr,
+                    join_code_points(&decomposition.mapping)
+                ),
+                None => "".to_owned(),
+            },
+            CharacterField::CanonicalDecomposition => self
+                .canonical_decomposition
+                .as_ref()
+                .map(|decomposition| join_code_points(decomposition))
+                .unwrap_or_default(),
+            CharacterField::CompatibilityDecomposition => self
+                .compatibility_decomposition
+                .as_ref()
+                .map(|decomposition| join_code_points(decomposition))
+                .unwrap_or_default(),
+            CharacterField::NfcQuickCheck => self.nfc_quick_check.abbr.to_owned(),
+            CharacterField::NfdQuickCheck => self.nfd_quick_check.abbr.to_owned(),
+            CharacterField::NfkcQuickCheck => self.nfkc_quick_check.abbr.to_owned(),
+            CharacterField::NfkdQuickCheck => self.nfkd_quick_check.abbr.to_owned(),
+            CharacterField::NumericType => self.numeric_type.abbr.to_owned(),
+            CharacterField::NumericValue => self
+                .numeric_value
+                .map(|numeric_value| numeric_value.to_string())
+                .unwrap_or_default(),
+            CharacterField::BidiClass => self.bidi_class.abbr.to_owned(),
+            CharacterField::IsBidiControl => self.is_bidi_control.to_string(),
+            CharacterField::IsBidiMirrored => self.is_bidi_mirrored.to_string(),
+            CharacterField::GraphemeClusterBreak => self.grapheme_cluster_break.abbr.to_owned(),
+            CharacterField::WordBreak => self.word_break.abbr.to_owned(),
+            CharacterField::SentenceBreak => self.sentence_break.abbr.to_owned(),
+            CharacterField::LineBreak => self.line_break.abbr.to_owned(),
+            CharacterField::EastAsianWidth => self.east_asian_width.abbr.to_owned(),
+            CharacterField::DisplayWidth => self.display_width.to_string(),
+            CharacterField::JoiningType => self.joining_type.abbr.to_owned(),
+            CharacterField::JoiningGroup => self.joining_group.abbr.to_owned(),
+            CharacterField::HangulSyllableType => self.hangul_syllable_type.abbr.to_owned(),
+            CharacterField::IndicSyllabicCategory => self.indic_syllabic_category.abbr.to_owned(),
+            CharacterField::IndicPositionalCategory => {
+                self.indic_positional_category.abbr.to_owned()
+            }
+            CharacterField::VerticalOrientation => self.vertical_orientation.abbr.to_owned(),
+            CharacterField::IsEmoji => self.is_emoji.to_string(),
+            CharacterField::IsEmojiPresentation => self.is_emoji_presentation.to_string(),
+            CharacterField::IsEmojiModifier => self.is_emoji_modifier.to_string(),
+            CharacterField::IsEmojiModifierBase => self.is_emoji_modifier_base.to_string(),
+            CharacterField::IsEmojiComponent => self.is_emoji_component.to_string(),
+            CharacterField::IsExtendedPictographic => self.is_extended_pictographic.to_string(),
+            CharacterField::IsAlphabetic => self.is_alphabetic.to_string(),
+            CharacterField::IsWhiteSpace => self.is_white_space.to_string(),
+            CharacterField::IsDefaultIgnorableCodePoint => {
+                self.is_default_ignorable_code_point.to_string()
+            }
+            CharacterField::IsNoncharacterCodePoint => self.is_noncharacter_code_point.to_string(),
+            CharacterField::IsIdStart => self.is_id_start.to_string(),
+            CharacterField::IsIdContinue => self.is_id_continue.to_string(),
+            CharacterField::IsXidStart => self.is_xid_start.to_string(),
+            CharacterField::IsXidContinue => self.is_xid_continue.to_string(),
+            CharacterField::IsPatternSyntax => self.is_pattern_syntax.to_string(),
+            CharacterField::IsPatternWhiteSpace => self.is_pattern_white_space.to_string(),
+            CharacterField::IsDash => self.is_dash.to_string(),
+            CharacterField::IsHyphen => self.is_hyphen.to_string(),
+            CharacterField::IsQuotationMark => self.is_quotation_mark.to_string(),
+            CharacterField::IsDiacritic => self.is_diacritic.to_string(),
+            CharacterField::IsExtender => self.is_extender.to_string(),
+            CharacterField::IsJoinControl => self.is_join_control.to_string(),
+            CharacterField::IsVariationSelector => self.is_variation_selector.to_string(),
+            CharacterField::SimplifiedVariant => self
+                .simplified_variant
+                .map(code_point_to_string)
+                .unwrap_or_default(),
+            CharacterField::TraditionalVariant => self
+                .traditional_variant
+                .map(code_point_to_string)
+                .unwrap_or_default(),
+            CharacterField::Mandarin => self.mandarin.unwrap_or_default().to_owned(),
+            CharacterField::Definition => self.definition.unwrap_or_default().to_owned(),
+            CharacterField::Cantonese => self.cantonese.unwrap_or_default().to_owned(),
+            CharacterField::JapaneseOn => self.japanese_on.unwrap_or_default().to_owned(),
+            CharacterField::JapaneseKun => self.japanese_kun.unwrap_or_default().to_owned(),
+            CharacterField::Korean => self.korean.unwrap_or_default().to_owned(),
+            CharacterField::HangulReading => self.hangul_reading.unwrap_or_default().to_owned(),
+            CharacterField::Vietnamese => self.vietnamese.unwrap_or_default().to_owned(),
+            CharacterField::RadicalStrokeCounts => {
+                self.radical_stroke_counts.unwrap_or_default().to_owned()
+            }
+            CharacterField::TotalStrokes => self.total_strokes.unwrap_or_default().to_owned(),
+            CharacterField::SemanticVariants => join_code_points(self.semantic_variants),
+            CharacterField::SpecializedSemanticVariants => {
+                join_code_points(self.specialized_semantic_variants)
+            }
+            CharacterField::ZVariants => join_code_points(self.z_variants),
         }
     }
 
@@ -195,22 +781,248 @@ impl CharacterProperties {
     }
 }
 
+/// The full case mapping of a cased character, which maps to itself unless `mapping` is given.
+fn case_mapping(chr: char, mapping: Option<&'static [char]>) -> Option<Vec<char>> {
+    if is_cased(chr) {
+        Some(mapping.map_or_else(|| vec![chr], <[char]>::to_vec))
+    } else {
+        None
+    }
+}
+
+fn if_selected<T: Default>(is_selected: bool, compute: impl FnOnce() -> T) -> T {
+    if is_selected {
+        compute()
+    } else {
+        T::default()
+    }
+}
+
 impl fmt::Display for CharacterProperties {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(
-            f,
-            "{} (utf-16:{:#06x} utf-8:{:#04x})  {}  {}",
-            code_point_to_string(self.character),
-            self.utf16.as_hex(),
-            self.utf8.as_hex(),
-            self.character,
-            self.name,
-        )?;
-        Ok(())
+        // Without a selection of fields, a brief summary is shown
+        if self.fields == CharacterFields::all() {
+            writeln!(
+                f,
+                "{} (utf-16:{:#06x} utf-8:{:#04x})  {}  {}",
+                code_point_to_string(self.character),
+                self.utf16.as_hex(),
+                self.utf8.as_hex(),
+                self.character,
+                self.name,
+            )?;
+            return Ok(());
+        }
+
+        write!(f, "{}", code_point_to_string(self.character))?;
+        for field in self
+            .fields
+            .iter()
+            .filter(|field| *field != CharacterField::CodePoint)
+        {
+            write!(f, "  {}: {}", field.name(), self.field_value(field))?;
+        }
+        writeln!(f)
     }
 }
 
-#[derive(Serialize, Debug)]
+impl Serialize for CharacterProperties {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for field in self.fields.iter() {
+            let name = field.name();
+            match field {
+                CharacterField::Character => map.serialize_entry(name, &self.character)?,
+                CharacterField::CodePoint => map.serialize_entry(name, &self.code_point)?,
+                CharacterField::Utf16 => map.serialize_entry(name, &self.utf16)?,
+                CharacterField::Utf8 => map.serialize_entry(name, &self.utf8)?,
+                CharacterField::Name => map.serialize_entry(name, &self.name)?,
+                CharacterField::Age => map.serialize_entry(name, &self.age)?,
+                CharacterField::GeneralCategory => {
+                    map.serialize_entry(name, &self.general_category)?
+                }
+                CharacterField::CodePointKind => {
+                    map.serialize_entry(name, &self.code_point_kind)?
+                }
+                CharacterField::PlaneName => map.serialize_entry(name, &self.plane_name)?,
+                CharacterField::BlockName => map.serialize_entry(name, &self.block_name)?,
+                CharacterField::Script => map.serialize_entry(name, &self.script)?,
+                CharacterField::ScriptExtensions => {
+                    map.serialize_entry(name, &self.script_extensions)?
+                }
+                CharacterField::NameCorrections => {
+                    map.serialize_entry(name, &self.name_corrections)?
+                }
+                CharacterField::ControlCodeNames => {
+                    map.serialize_entry(name, &self.control_code_names)?
+                }
+                CharacterField::AlternativeNames => {
+                    map.serialize_entry(name, &self.alternative_names)?
+                }
+                CharacterField::Figments => map.serialize_entry(name, &self.figments)?,
+                CharacterField::NameAbbreviations => {
+                    map.serialize_entry(name, &self.name_abbreviations)?
+                }
+                CharacterField::IsCased => map.serialize_entry(name, &self.is_cased)?,
+                CharacterField::Uppercase => map.serialize_entry(name, &self.uppercase)?,
+                CharacterField::Lowercase => map.serialize_entry(name, &self.lowercase)?,
+                CharacterField::Titlecase => map.serialize_entry(name, &self.titlecase)?,
+                CharacterField::CaseFolding => map.serialize_entry(name, &self.case_folding)?,
+                CharacterField::SimpleCaseFolding => {
+                    map.serialize_entry(name, &self.simple_case_folding)?
+                }
+                CharacterField::ChangesWhenLowercased => {
+                    map.serialize_entry(name, &self.changes_when_lowercased)?
+                }
+                CharacterField::ChangesWhenUppercased => {
+                    map.serialize_entry(name, &self.changes_when_uppercased)?
+                }
+                CharacterField::ChangesWhenTitlecased => {
+                    map.serialize_entry(name, &self.changes_when_titlecased)?
+                }
+                CharacterField::ChangesWhenCasefolded => {
+                    map.serialize_entry(name, &self.changes_when_casefolded)?
+                }
+                CharacterField::ChangesWhenCasemapped => {
+                    map.serialize_entry(name, &self.changes_when_casemapped)?
+                }
+                CharacterField::ChangesWhenNfkcCasefolded => {
+                    map.serialize_entry(name, &self.changes_when_nfkc_casefolded)?
+                }
+                CharacterField::Ccc => map.serialize_entry(name, &self.ccc)?,
+                CharacterField::Decomposition => map.serialize_entry(name, &self.decomposition)?,
+                CharacterField::CanonicalDecomposition => {
+                    map.serialize_entry(name, &self.canonical_decomposition)?
+                }
+                CharacterField::CompatibilityDecomposition => {
+                    map.serialize_entry(name, &self.compatibility_decomposition)?
+                }
+                CharacterField::NfcQuickCheck => {
+                    map.serialize_entry(name, &self.nfc_quick_check)?
+                }
+                CharacterField::NfdQuickCheck => {
+                    map.serialize_entry(name, &self.nfd_quick_check)?
+                }
+                CharacterField::NfkcQuickCheck => {
+                    map.serialize_entry(name, &self.nfkc_quick_check)?
+                }
+                CharacterField::NfkdQuickCheck => {
+                    map.serialize_entry(name, &self.nfkd_quick_check)?
+                }
+                CharacterField::NumericType => map.serialize_entry(name, &self.numeric_type)?,
+                CharacterField::NumericValue => map.serialize_entry(name, &self.numeric_value)?,
+                CharacterField::BidiClass => map.serialize_entry(name, &self.bidi_class)?,
+                CharacterField::IsBidiControl => {
+                    map.serialize_entry(name, &self.is_bidi_control)?
+                }
+                CharacterField::IsBidiMirrored => {
+                    map.serialize_entry(name, &self.is_bidi_mirrored)?
+                }
+                CharacterField::GraphemeClusterBreak => {
+                    map.serialize_entry(name, &self.grapheme_cluster_break)?
+                }
+                CharacterField::WordBreak => map.serialize_entry(name, &self.word_break)?,
+                CharacterField::SentenceBreak => map.serialize_entry(name, &self.sentence_break)?,
+                CharacterField::LineBreak => map.serialize_entry(name, &self.line_break)?,
+                CharacterField::EastAsianWidth => {
+                    map.serialize_entry(name, &self.east_asian_width)?
+                }
+                CharacterField::DisplayWidth => map.serialize_entry(name, &self.display_width)?,
+                CharacterField::JoiningType => map.serialize_entry(name, &self.joining_type)?,
+                CharacterField::JoiningGroup => map.serialize_entry(name, &self.joining_group)?,
+                CharacterField::HangulSyllableType => {
+                    map.serialize_entry(name, &self.hangul_syllable_type)?
+                }
+                CharacterField::IndicSyllabicCategory => {
+                    map.serialize_entry(name, &self.indic_syllabic_category)?
+                }
+                CharacterField::IndicPositionalCategory => {
+                    map.serialize_entry(name, &self.indic_positional_category)?
+                }
+                CharacterField::VerticalOrientation => {
+                    map.serialize_entry(name, &self.vertical_orientation)?
+                }
+                CharacterField::IsEmoji => map.serialize_entry(name, &self.is_emoji)?,
+                CharacterField::IsEmojiPresentation => {
+                    map.serialize_entry(name, &self.is_emoji_presentation)?
+                }
+                CharacterField::IsEmojiModifier => {
+                    map.serialize_entry(name, &self.is_emoji_modifier)?
+                }
+                CharacterField::IsEmojiModifierBase => {
+                    map.serialize_entry(name, &self.is_emoji_modifier_base)?
+                }
+                CharacterField::IsEmojiComponent => {
+                    map.serialize_entry(name, &self.is_emoji_component)?
+                }
+                CharacterField::IsExtendedPictographic => {
+                    map.serialize_entry(name, &self.is_extended_pictographic)?
+                }
+                CharacterField::IsAlphabetic => map.serialize_entry(name, &self.is_alphabetic)?,
+                CharacterField::IsWhiteSpace => map.serialize_entry(name, &self.is_white_space)?,
+                CharacterField::IsDefaultIgnorableCodePoint => {
+                    map.serialize_entry(name, &self.is_default_ignorable_code_point)?
+                }
+                CharacterField::IsNoncharacterCodePoint => {
+                    map.serialize_entry(name, &self.is_noncharacter_code_point)?
+                }
+                CharacterField::IsIdStart => map.serialize_entry(name, &self.is_id_start)?,
+                CharacterField::IsIdContinue => map.serialize_entry(name, &self.is_id_continue)?,
+                CharacterField::IsXidStart => map.serialize_entry(name, &self.is_xid_start)?,
+                CharacterField::IsXidContinue => {
+                    map.serialize_entry(name, &self.is_xid_continue)?
+                }
+                CharacterField::IsPatternSyntax => {
+                    map.serialize_entry(name, &self.is_pattern_syntax)?
+                }
+                CharacterField::IsPatternWhiteSpace => {
+                    map.serialize_entry(name, &self.is_pattern_white_space)?
+                }
+                CharacterField::IsDash => map.serialize_entry(name, &self.is_dash)?,
+                CharacterField::IsHyphen => map.serialize_entry(name, &self.is_hyphen)?,
+                CharacterField::IsQuotationMark => {
+                    map.serialize_entry(name, &self.is_quotation_mark)?
+                }
+                CharacterField::IsDiacritic => map.serialize_entry(name, &self.is_diacritic)?,
+                CharacterField::IsExtender => map.serialize_entry(name, &self.is_extender)?,
+                CharacterField::IsJoinControl => {
+                    map.serialize_entry(name, &self.is_join_control)?
+                }
+                CharacterField::IsVariationSelector => {
+                    map.serialize_entry(name, &self.is_variation_selector)?
+                }
+                CharacterField::SimplifiedVariant => {
+                    map.serialize_entry(name, &self.simplified_variant)?
+                }
+                CharacterField::TraditionalVariant => {
+                    map.serialize_entry(name, &self.traditional_variant)?
+                }
+                CharacterField::Mandarin => map.serialize_entry(name, &self.mandarin)?,
+                CharacterField::Definition => map.serialize_entry(name, &self.definition)?,
+                CharacterField::Cantonese => map.serialize_entry(name, &self.cantonese)?,
+                CharacterField::JapaneseOn => map.serialize_entry(name, &self.japanese_on)?,
+                CharacterField::JapaneseKun => map.serialize_entry(name, &self.japanese_kun)?,
+                CharacterField::Korean => map.serialize_entry(name, &self.korean)?,
+                CharacterField::HangulReading => map.serialize_entry(name, &self.hangul_reading)?,
+                CharacterField::Vietnamese => map.serialize_entry(name, &self.vietnamese)?,
+                CharacterField::RadicalStrokeCounts => {
+                    map.serialize_entry(name, &self.radical_stroke_counts)?
+                }
+                CharacterField::TotalStrokes => map.serialize_entry(name, &self.total_strokes)?,
+                CharacterField::SemanticVariants => {
+                    map.serialize_entry(name, &self.semantic_variants)?
+                }
+                CharacterField::SpecializedSemanticVariants => {
+                    map.serialize_entry(name, &self.specialized_semantic_variants)?
+                }
+                CharacterField::ZVariants => map.serialize_entry(name, &self.z_variants)?,
+            }
+        }
+        map.end()
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
 pub struct StringValuedProperty {
     pub abbr: &'static str,
     pub long: &'static str,
@@ -236,22 +1048,44 @@ impl fmt::Display for StringValuedProperty {
     }
 }
 
+/// The Decomposition_Type of a character with its decomposition.
 #[derive(Serialize, Debug)]
 pub struct Decomposition {
     pub decomposition_type: StringValuedProperty,
+    // The full compatibility decomposition, e.g. U+01D5 is decomposed to U+0055 U+0308 U+0304
     pub components: Vec<char>,
+    // The single level Decomposition_Mapping, e.g. U+01D5 is mapped to U+00DC U+0304
+    pub mapping: Vec<char>,
 }
 
 impl Decomposition {
     pub fn new(chr: char) -> Option<Self> {
         let decomposition_type = StringValuedProperty::new(DecompositionType::of(chr)?);
-
-        let mut components = vec![];
-        decompose_compatible(chr, |component| components.push(component));
+        let mapping = decomposition_mapping(chr)?;
+        let components = full_compatibility_decomposition(chr).unwrap_or_else(|| mapping.clone());
 
         Some(Decomposition {
             decomposition_type,
             components,
+            mapping,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decomposition() {
+        assert!(Decomposition::new('a').is_none());
+
+        let decomposition = Decomposition::new('\u{01D5}').unwrap();
+        assert_eq!(decomposition.components, vec!['U', '\u{0308}', '\u{0304}']);
+        assert_eq!(decomposition.mapping, vec!['\u{00DC}', '\u{0304}']);
+
+        let json = serde_json::to_value(Decomposition::new('\u{FB01}').unwrap()).unwrap();
+        assert_eq!(json["components"], serde_json::json!(["f", "i"]));
+        assert_eq!(json["mapping"], serde_json::json!(["f", "i"]));
+    }
+}
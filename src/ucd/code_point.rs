@@ -0,0 +1,306 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Code points which are not (necessarily) characters, i.e. surrogate code points, noncharacters,
+//! private use and reserved code points.
+//!
+//! Code points are `u32`s here, so that surrogate code points can be inspected as well.
+//!
+
+use std::char;
+use std::fmt;
+
+use hex_slice::AsHex;
+use serde::ser::{SerializeMap, Serializer};
+use serde::Serialize;
+use unic::ucd::GeneralCategory;
+
+use super::character_properties::{StringValuedProperty, TREE_GRAPH_CORNER};
+use super::{
+    code_point_to_string, is_noncharacter_code_point, CharacterField, CharacterFields, LineBreak,
+    Plane, Script, MAX_CODE_POINT,
+};
+
+const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+// Blocks of surrogate code points, which are missing in `unic::ucd::Block` because its ranges are
+// made of `char`s.
+const SURROGATE_BLOCKS: &[(u32, u32, &str)] = &[
+    (0xD800, 0xDB7F, "High Surrogates"),
+    (0xDB80, 0xDBFF, "High Private Use Surrogates"),
+    (0xDC00, 0xDFFF, "Low Surrogates"),
+];
+
+// All surrogate code points were assigned in Unicode 2.0, according to DerivedAge.txt
+const SURROGATE_AGE: &str = "2.0.0";
+
+// Fields which are defined for surrogate code points, others only apply to characters
+const SURROGATE_FIELDS: &[CharacterField] = &[
+    CharacterField::CodePoint,
+    CharacterField::Utf16,
+    CharacterField::Age,
+    CharacterField::GeneralCategory,
+    CharacterField::CodePointKind,
+    CharacterField::PlaneName,
+    CharacterField::BlockName,
+    CharacterField::Script,
+    CharacterField::LineBreak,
+];
+
+/// The basic types of code points (The Unicode Standard, Table 2-3), except that graphic, format
+/// and control characters are all considered assigned.
+#[derive(Serialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CodePointKind {
+    Assigned,
+    PrivateUse,
+    Noncharacter,
+    Surrogate,
+    Reserved,
+}
+
+impl CodePointKind {
+    pub fn of(code_point: u32) -> Self {
+        assert!(code_point <= MAX_CODE_POINT);
+
+        let chr = match char::from_u32(code_point) {
+            Some(chr) => chr,
+            None => return CodePointKind::Surrogate,
+        };
+        if is_noncharacter_code_point(chr) {
+            return CodePointKind::Noncharacter;
+        }
+        match GeneralCategory::of(chr) {
+            GeneralCategory::PrivateUse => CodePointKind::PrivateUse,
+            GeneralCategory::Unassigned => CodePointKind::Reserved,
+            _ => CodePointKind::Assigned,
+        }
+    }
+
+    /// The name used in JSON output, e.g. "private_use".
+    pub fn name(self) -> &'static str {
+        match self {
+            CodePointKind::Assigned => "assigned",
+            CodePointKind::PrivateUse => "private_use",
+            CodePointKind::Noncharacter => "noncharacter",
+            CodePointKind::Surrogate => "surrogate",
+            CodePointKind::Reserved => "reserved",
+        }
+    }
+}
+
+impl fmt::Display for CodePointKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            CodePointKind::Assigned => "Assigned",
+            CodePointKind::PrivateUse => "Private Use",
+            CodePointKind::Noncharacter => "Noncharacter",
+            CodePointKind::Surrogate => "Surrogate",
+            CodePointKind::Reserved => "Reserved",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+/// The code point label (The Unicode Standard, Section 4.8) of a code point without a name, e.g.
+/// "<control-0009>" or "<surrogate-D800>".
+///
+/// `None` is returned for assigned characters other than controls, which all have names.
+pub fn code_point_label(code_point: u32) -> Option<String> {
+    let prefix = match CodePointKind::of(code_point) {
+        CodePointKind::Assigned => match char::from_u32(code_point).map(GeneralCategory::of) {
+            Some(GeneralCategory::Control) => "control",
+            _ => return None,
+        },
+        CodePointKind::PrivateUse => "private-use",
+        CodePointKind::Noncharacter => "noncharacter",
+        CodePointKind::Surrogate => "surrogate",
+        CodePointKind::Reserved => "reserved",
+    };
+    Some(format!("<{}-{:04X}>", prefix, code_point))
+}
+
+/// Properties of a surrogate code point, which isn't a character so only the fields in
+/// `SURROGATE_FIELDS` apply to it.
+#[derive(Debug)]
+pub struct CodePointProperties {
+    fields: CharacterFields,
+
+    pub code_point: u32,
+    pub utf16: u16,
+    pub age: &'static str,
+    pub general_category: StringValuedProperty,
+    pub plane_name: &'static str,
+    pub block_name: Option<&'static str>,
+    pub script: StringValuedProperty,
+    pub line_break: StringValuedProperty,
+}
+
+impl CodePointProperties {
+    /// Computes the properties of the surrogate `code_point`, only `fields` which apply to
+    /// surrogate code points are kept.
+    pub fn with_fields(code_point: u32, fields: CharacterFields) -> Self {
+        assert_eq!(CodePointKind::of(code_point), CodePointKind::Surrogate);
+
+        let surrogate_fields: Vec<CharacterField> = fields
+            .iter()
+            .filter(|field| SURROGATE_FIELDS.contains(field))
+            .collect();
+
+        CodePointProperties {
+            fields: CharacterFields::from_fields(&surrogate_fields),
+
+            code_point,
+            utf16: code_point as u16,
+            age: SURROGATE_AGE,
+            general_category: StringValuedProperty::new(GeneralCategory::Surrogate),
+            plane_name: Plane::of_code_point(code_point).name,
+            block_name: SURROGATE_BLOCKS
+                .iter()
+                .find(|(first, last, _)| *first <= code_point && code_point <= *last)
+                .map(|(_, _, name)| *name),
+            script: StringValuedProperty::new(Script::Unknown),
+            line_break: StringValuedProperty::new(LineBreak::Surrogate),
+        }
+    }
+
+    /// The code point label, e.g. "<surrogate-D800>", which takes the place of a name.
+    pub fn label(&self) -> String {
+        code_point_label(self.code_point).unwrap_or_default()
+    }
+
+    /// Describes `field` as a single line of text, fields which don't apply to surrogate code
+    /// points are empty.
+    pub fn field_value(&self, field: CharacterField) -> String {
+        if !self.fields.contains(field) {
+            return String::new();
+        }
+
+        match field {
+            CharacterField::CodePoint => code_point_to_string(self.code_point),
+            CharacterField::Utf16 => format!("{:04X}", self.utf16),
+            CharacterField::Age => self.age.to_owned(),
+            CharacterField::GeneralCategory => self.general_category.abbr.to_owned(),
+            CharacterField::CodePointKind => CodePointKind::Surrogate.name().to_owned(),
+            CharacterField::PlaneName => self.plane_name.to_owned(),
+            CharacterField::BlockName => self.block_name.unwrap_or_default().to_owned(),
+            CharacterField::Script => self.script.abbr.to_owned(),
+            CharacterField::LineBreak => self.line_break.abbr.to_owned(),
+            _ => String::new(),
+        }
+    }
+
+    /// The selected fields which apply to surrogate code points.
+    pub fn fields(&self) -> CharacterFields {
+        self.fields
+    }
+}
+
+impl fmt::Display for CodePointProperties {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", REPLACEMENT_CHARACTER)?;
+        writeln!(
+            f,
+            "{}{} (utf-16:{:#06x})  {}",
+            TREE_GRAPH_CORNER,
+            code_point_to_string(self.code_point),
+            [self.utf16].as_hex(),
+            self.label(),
+        )
+    }
+}
+
+impl Serialize for CodePointProperties {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for field in self.fields.iter() {
+            let name = field.name();
+            match field {
+                CharacterField::CodePoint => map.serialize_entry(name, &self.code_point)?,
+                CharacterField::Utf16 => map.serialize_entry(name, &[self.utf16])?,
+                CharacterField::Age => map.serialize_entry(name, self.age)?,
+                CharacterField::GeneralCategory => {
+                    map.serialize_entry(name, &self.general_category)?
+                }
+                CharacterField::CodePointKind => {
+                    map.serialize_entry(name, &CodePointKind::Surrogate)?
+                }
+                CharacterField::PlaneName => map.serialize_entry(name, &self.plane_name)?,
+                CharacterField::BlockName => map.serialize_entry(name, &self.block_name)?,
+                CharacterField::Script => map.serialize_entry(name, &self.script)?,
+                CharacterField::LineBreak => map.serialize_entry(name, &self.line_break)?,
+                _ => {}
+            }
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_point_kind() {
+        assert_eq!(CodePointKind::of(0x41), CodePointKind::Assigned);
+        assert_eq!(CodePointKind::of(0x09), CodePointKind::Assigned);
+        assert_eq!(CodePointKind::of(0xE000), CodePointKind::PrivateUse);
+        assert_eq!(CodePointKind::of(0x10_FFFD), CodePointKind::PrivateUse);
+        assert_eq!(CodePointKind::of(0xFDD0), CodePointKind::Noncharacter);
+        assert_eq!(CodePointKind::of(0x10_FFFF), CodePointKind::Noncharacter);
+        assert_eq!(CodePointKind::of(0xD800), CodePointKind::Surrogate);
+        assert_eq!(CodePointKind::of(0xDFFF), CodePointKind::Surrogate);
+        assert_eq!(CodePointKind::of(0x0378), CodePointKind::Reserved);
+    }
+
+    #[test]
+    fn test_code_point_label() {
+        assert_eq!(code_point_label(0x41), None);
+        assert_eq!(code_point_label(0x09).unwrap(), "<control-0009>");
+        assert_eq!(code_point_label(0xE000).unwrap(), "<private-use-E000>");
+        assert_eq!(code_point_label(0xFFFF).unwrap(), "<noncharacter-FFFF>");
+        assert_eq!(code_point_label(0xD800).unwrap(), "<surrogate-D800>");
+        assert_eq!(code_point_label(0x0378).unwrap(), "<reserved-0378>");
+    }
+
+    #[test]
+    fn test_surrogate_properties() {
+        let properties = CodePointProperties::with_fields(0xDB80, CharacterFields::all());
+        assert_eq!(properties.field_value(CharacterField::CodePoint), "U+DB80");
+        assert_eq!(properties.field_value(CharacterField::Utf16), "DB80");
+        assert_eq!(
+            properties.field_value(CharacterField::GeneralCategory),
+            "Cs"
+        );
+        assert_eq!(
+            properties.field_value(CharacterField::BlockName),
+            "High Private Use Surrogates"
+        );
+        assert_eq!(properties.field_value(CharacterField::LineBreak), "SG");
+        assert_eq!(properties.field_value(CharacterField::Utf8), "");
+        assert_eq!(properties.label(), "<surrogate-DB80>");
+
+        let fields = CharacterFields::from_fields(&[CharacterField::Name, CharacterField::Age]);
+        let properties = CodePointProperties::with_fields(0xD800, fields);
+        assert_eq!(
+            properties.fields(),
+            CharacterFields::from_fields(&[CharacterField::Age])
+        );
+        assert_eq!(
+            serde_json::to_string(&properties).unwrap(),
+            r#"{"age":"2.0.0"}"#
+        );
+    }
+}
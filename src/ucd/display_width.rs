@@ -0,0 +1,128 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Estimates how many columns text takes in a terminal.
+//!
+//! Terminals don't agree on the width of some characters, the estimation here is what most modern
+//! terminals do, e.g. ambiguous width characters are narrow and emoji sequences are wide.
+//!
+
+use unic::emoji::char::{is_emoji, is_emoji_modifier};
+use unic::segment::Graphemes;
+use unic::ucd::GeneralCategory;
+
+use super::EastAsianWidth;
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+
+fn is_regional_indicator(chr: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&chr)
+}
+
+/// The number of columns `chr` takes on its own, 0 for characters which are not displayed (e.g.
+/// controls) or are displayed together with the preceding character (e.g. combining marks).
+pub fn character_display_width(chr: char) -> usize {
+    match chr {
+        // Hangul vowels and final consonants, which are combined with a leading consonant
+        '\u{1160}'..='\u{11FF}' | '\u{D7B0}'..='\u{D7FF}' => return 0,
+        _ => {}
+    }
+
+    match GeneralCategory::of(chr) {
+        GeneralCategory::Control
+        | GeneralCategory::Format
+        | GeneralCategory::NonspacingMark
+        | GeneralCategory::EnclosingMark => 0,
+        _ => match EastAsianWidth::of(chr) {
+            EastAsianWidth::Wide | EastAsianWidth::Fullwidth => 2,
+            _ => 1,
+        },
+    }
+}
+
+/// The number of columns `grapheme` takes, which is the sum of the widths of its characters unless
+/// it is an emoji sequence (e.g. a flag or a ZWJ sequence), which is shown as a single wide emoji.
+pub fn grapheme_display_width(grapheme: &str) -> usize {
+    let mut characters = grapheme.chars();
+    let base = match characters.next() {
+        Some(base) => base,
+        None => return 0,
+    };
+
+    let is_emoji_sequence = if is_regional_indicator(base) {
+        characters.any(is_regional_indicator)
+    } else {
+        is_emoji(base)
+            && characters.any(|chr| {
+                chr == VARIATION_SELECTOR_16 || chr == ZERO_WIDTH_JOINER || is_emoji_modifier(chr)
+            })
+    };
+    if is_emoji_sequence {
+        return 2;
+    }
+
+    grapheme.chars().map(character_display_width).sum()
+}
+
+/// The number of columns `text` takes, which is the sum of the widths of its graphemes.
+pub fn text_display_width(text: &str) -> usize {
+    Graphemes::new(text).map(grapheme_display_width).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_character_display_width() {
+        assert_eq!(character_display_width('a'), 1);
+        assert_eq!(character_display_width('中'), 2);
+        assert_eq!(character_display_width('\u{FF21}'), 2);
+        assert_eq!(character_display_width('\u{FF71}'), 1);
+        assert_eq!(character_display_width('\u{00B1}'), 1);
+        assert_eq!(character_display_width('\u{1F600}'), 2);
+        assert_eq!(character_display_width('\u{0301}'), 0);
+        assert_eq!(character_display_width('\u{200B}'), 0);
+        assert_eq!(character_display_width('\t'), 0);
+    }
+
+    #[test]
+    fn test_grapheme_display_width() {
+        assert_eq!(grapheme_display_width(""), 0);
+        assert_eq!(grapheme_display_width("e\u{301}"), 1);
+        // Hangul syllable made of conjoining jamo
+        assert_eq!(grapheme_display_width("\u{1100}\u{1161}\u{11A8}"), 2);
+        // Flag
+        assert_eq!(grapheme_display_width("\u{1F1E8}\u{1F1F3}"), 2);
+        // Emoji presentation, modifier and ZWJ sequences
+        assert_eq!(grapheme_display_width("\u{2764}"), 1);
+        assert_eq!(grapheme_display_width("\u{2764}\u{FE0F}"), 2);
+        assert_eq!(grapheme_display_width("\u{1F44D}\u{1F3FD}"), 2);
+        assert_eq!(
+            grapheme_display_width("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}"),
+            2
+        );
+        assert_eq!(grapheme_display_width("1\u{FE0F}\u{20E3}"), 2);
+    }
+
+    #[test]
+    fn test_text_display_width() {
+        assert_eq!(text_display_width(""), 0);
+        assert_eq!(text_display_width("cafe\u{301}"), 4);
+        assert_eq!(text_display_width("\u{4E2D}\u{6587}"), 4);
+        assert_eq!(text_display_width("\u{1F1E8}\u{1F1F3}\u{2764}\u{FE0F}!"), 5);
+    }
+}
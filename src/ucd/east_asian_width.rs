@@ -0,0 +1,61 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! The East_Asian_Width property, which is not yet provided by `unic::ucd`.
+//!
+
+mod tables;
+
+enumerated_property! {
+    /// Values of the East_Asian_Width property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    pub enum EastAsianWidth {
+        abbr => "ea",
+        long => "East_Asian_Width",
+        human => "East Asian Width",
+        default => Neutral,
+        values => {
+            Ambiguous,
+            Fullwidth,
+            Halfwidth,
+            Neutral,
+            Narrow,
+            Wide,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unic::char::property::EnumeratedCharProperty;
+
+    use super::*;
+
+    #[test]
+    fn test_east_asian_width_of() {
+        assert_eq!(EastAsianWidth::of('a'), EastAsianWidth::Narrow);
+        assert_eq!(EastAsianWidth::of('中'), EastAsianWidth::Wide);
+        assert_eq!(EastAsianWidth::of('\u{FF21}'), EastAsianWidth::Fullwidth);
+        assert_eq!(EastAsianWidth::of('\u{FF71}'), EastAsianWidth::Halfwidth);
+        assert_eq!(EastAsianWidth::of('\u{00B1}'), EastAsianWidth::Ambiguous);
+        assert_eq!(EastAsianWidth::of('\u{0300}'), EastAsianWidth::Ambiguous);
+        assert_eq!(EastAsianWidth::of('\u{1F600}'), EastAsianWidth::Wide);
+        assert_eq!(EastAsianWidth::of('\u{0378}'), EastAsianWidth::Neutral);
+        // Unassigned code points in CJK blocks are wide
+        assert_eq!(EastAsianWidth::of('\u{2FFFD}'), EastAsianWidth::Wide);
+
+        assert_eq!(EastAsianWidth::Narrow.abbr_name(), "Na");
+    }
+}
@@ -0,0 +1,350 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (EastAsianWidth.txt and PropertyValueAliases.txt),
+// limited to the characters assigned in Unicode 13.0.
+
+use super::EastAsianWidth;
+
+pub(super) const VALUES: &[EastAsianWidth] = &[
+    EastAsianWidth::Ambiguous,
+    EastAsianWidth::Fullwidth,
+    EastAsianWidth::Halfwidth,
+    EastAsianWidth::Neutral,
+    EastAsianWidth::Narrow,
+    EastAsianWidth::Wide,
+];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[
+    ("A", "Ambiguous", "Ambiguous"),
+    ("F", "Fullwidth", "Fullwidth"),
+    ("H", "Halfwidth", "Halfwidth"),
+    ("N", "Neutral", "Neutral"),
+    ("Na", "Narrow", "Narrow"),
+    ("W", "Wide", "Wide"),
+];
+
+pub(super) const RANGES: &[(u32, u32, EastAsianWidth)] = &[
+    (0x0020, 0x007E, EastAsianWidth::Narrow),
+    (0x00A1, 0x00A1, EastAsianWidth::Ambiguous),
+    (0x00A2, 0x00A3, EastAsianWidth::Narrow),
+    (0x00A4, 0x00A4, EastAsianWidth::Ambiguous),
+    (0x00A5, 0x00A6, EastAsianWidth::Narrow),
+    (0x00A7, 0x00A8, EastAsianWidth::Ambiguous),
+    (0x00AA, 0x00AA, EastAsianWidth::Ambiguous),
+    (0x00AC, 0x00AC, EastAsianWidth::Narrow),
+    (0x00AD, 0x00AE, EastAsianWidth::Ambiguous),
+    (0x00AF, 0x00AF, EastAsianWidth::Narrow),
+    (0x00B0, 0x00B4, EastAsianWidth::Ambiguous),
+    (0x00B6, 0x00BA, EastAsianWidth::Ambiguous),
+    (0x00BC, 0x00BF, EastAsianWidth::Ambiguous),
+    (0x00C6, 0x00C6, EastAsianWidth::Ambiguous),
+    (0x00D0, 0x00D0, EastAsianWidth::Ambiguous),
+    (0x00D7, 0x00D8, EastAsianWidth::Ambiguous),
+    (0x00DE, 0x00E1, EastAsianWidth::Ambiguous),
+    (0x00E6, 0x00E6, EastAsianWidth::Ambiguous),
+    (0x00E8, 0x00EA, EastAsianWidth::Ambiguous),
+    (0x00EC, 0x00ED, EastAsianWidth::Ambiguous),
+    (0x00F0, 0x00F0, EastAsianWidth::Ambiguous),
+    (0x00F2, 0x00F3, EastAsianWidth::Ambiguous),
+    (0x00F7, 0x00FA, EastAsianWidth::Ambiguous),
+    (0x00FC, 0x00FC, EastAsianWidth::Ambiguous),
+    (0x00FE, 0x00FE, EastAsianWidth::Ambiguous),
+    (0x0101, 0x0101, EastAsianWidth::Ambiguous),
+    (0x0111, 0x0111, EastAsianWidth::Ambiguous),
+    (0x0113, 0x0113, EastAsianWidth::Ambiguous),
+    (0x011B, 0x011B, EastAsianWidth::Ambiguous),
+    (0x0126, 0x0127, EastAsianWidth::Ambiguous),
+    (0x012B, 0x012B, EastAsianWidth::Ambiguous),
+    (0x0131, 0x0133, EastAsianWidth::Ambiguous),
+    (0x0138, 0x0138, EastAsianWidth::Ambiguous),
+    (0x013F, 0x0142, EastAsianWidth::Ambiguous),
+    (0x0144, 0x0144, EastAsianWidth::Ambiguous),
+    (0x0148, 0x014B, EastAsianWidth::Ambiguous),
+    (0x014D, 0x014D, EastAsianWidth::Ambiguous),
+    (0x0152, 0x0153, EastAsianWidth::Ambiguous),
+    (0x0166, 0x0167, EastAsianWidth::Ambiguous),
+    (0x016B, 0x016B, EastAsianWidth::Ambiguous),
+    (0x01CE, 0x01CE, EastAsianWidth::Ambiguous),
+    (0x01D0, 0x01D0, EastAsianWidth::Ambiguous),
+    (0x01D2, 0x01D2, EastAsianWidth::Ambiguous),
+    (0x01D4, 0x01D4, EastAsianWidth::Ambiguous),
+    (0x01D6, 0x01D6, EastAsianWidth::Ambiguous),
+    (0x01D8, 0x01D8, EastAsianWidth::Ambiguous),
+    (0x01DA, 0x01DA, EastAsianWidth::Ambiguous),
+    (0x01DC, 0x01DC, EastAsianWidth::Ambiguous),
+    (0x0251, 0x0251, EastAsianWidth::Ambiguous),
+    (0x0261, 0x0261, EastAsianWidth::Ambiguous),
+    (0x02C4, 0x02C4, EastAsianWidth::Ambiguous),
+    (0x02C7, 0x02C7, EastAsianWidth::Ambiguous),
+    (0x02C9, 0x02CB, EastAsianWidth::Ambiguous),
+    (0x02CD, 0x02CD, EastAsianWidth::Ambiguous),
+    (0x02D0, 0x02D0, EastAsianWidth::Ambiguous),
+    (0x02D8, 0x02DB, EastAsianWidth::Ambiguous),
+    (0x02DD, 0x02DD, EastAsianWidth::Ambiguous),
+    (0x02DF, 0x02DF, EastAsianWidth::Ambiguous),
+    (0x0300, 0x036F, EastAsianWidth::Ambiguous),
+    (0x0391, 0x03A1, EastAsianWidth::Ambiguous),
+    (0x03A3, 0x03A9, EastAsianWidth::Ambiguous),
+    (0x03B1, 0x03C1, EastAsianWidth::Ambiguous),
+    (0x03C3, 0x03C9, EastAsianWidth::Ambiguous),
+    (0x0401, 0x0401, EastAsianWidth::Ambiguous),
+    (0x0410, 0x044F, EastAsianWidth::Ambiguous),
+    (0x0451, 0x0451, EastAsianWidth::Ambiguous),
+    (0x1100, 0x115F, EastAsianWidth::Wide),
+    (0x2010, 0x2010, EastAsianWidth::Ambiguous),
+    (0x2013, 0x2016, EastAsianWidth::Ambiguous),
+    (0x2018, 0x2019, EastAsianWidth::Ambiguous),
+    (0x201C, 0x201D, EastAsianWidth::Ambiguous),
+    (0x2020, 0x2022, EastAsianWidth::Ambiguous),
+    (0x2024, 0x2027, EastAsianWidth::Ambiguous),
+    (0x2030, 0x2030, EastAsianWidth::Ambiguous),
+    (0x2032, 0x2033, EastAsianWidth::Ambiguous),
+    (0x2035, 0x2035, EastAsianWidth::Ambiguous),
+    (0x203B, 0x203B, EastAsianWidth::Ambiguous),
+    (0x203E, 0x203E, EastAsianWidth::Ambiguous),
+    (0x2074, 0x2074, EastAsianWidth::Ambiguous),
+    (0x207F, 0x207F, EastAsianWidth::Ambiguous),
+    (0x2081, 0x2084, EastAsianWidth::Ambiguous),
+    (0x20A9, 0x20A9, EastAsianWidth::Halfwidth),
+    (0x20AC, 0x20AC, EastAsianWidth::Ambiguous),
+    (0x2103, 0x2103, EastAsianWidth::Ambiguous),
+    (0x2105, 0x2105, EastAsianWidth::Ambiguous),
+    (0x2109, 0x2109, EastAsianWidth::Ambiguous),
+    (0x2113, 0x2113, EastAsianWidth::Ambiguous),
+    (0x2116, 0x2116, EastAsianWidth::Ambiguous),
+    (0x2121, 0x2122, EastAsianWidth::Ambiguous),
+    (0x2126, 0x2126, EastAsianWidth::Ambiguous),
+    (0x212B, 0x212B, EastAsianWidth::Ambiguous),
+    (0x2153, 0x2154, EastAsianWidth::Ambiguous),
+    (0x215B, 0x215E, EastAsianWidth::Ambiguous),
+    (0x2160, 0x216B, EastAsianWidth::Ambiguous),
+    (0x2170, 0x2179, EastAsianWidth::Ambiguous),
+    (0x2189, 0x2189, EastAsianWidth::Ambiguous),
+    (0x2190, 0x2199, EastAsianWidth::Ambiguous),
+    (0x21B8, 0x21B9, EastAsianWidth::Ambiguous),
+    (0x21D2, 0x21D2, EastAsianWidth::Ambiguous),
+    (0x21D4, 0x21D4, EastAsianWidth::Ambiguous),
+    (0x21E7, 0x21E7, EastAsianWidth::Ambiguous),
+    (0x2200, 0x2200, EastAsianWidth::Ambiguous),
+    (0x2202, 0x2203, EastAsianWidth::Ambiguous),
+    (0x2207, 0x2208, EastAsianWidth::Ambiguous),
+    (0x220B, 0x220B, EastAsianWidth::Ambiguous),
+    (0x220F, 0x220F, EastAsianWidth::Ambiguous),
+    (0x2211, 0x2211, EastAsianWidth::Ambiguous),
+    (0x2215, 0x2215, EastAsianWidth::Ambiguous),
+    (0x221A, 0x221A, EastAsianWidth::Ambiguous),
+    (0x221D, 0x2220, EastAsianWidth::Ambiguous),
+    (0x2223, 0x2223, EastAsianWidth::Ambiguous),
+    (0x2225, 0x2225, EastAsianWidth::Ambiguous),
+    (0x2227, 0x222C, EastAsianWidth::Ambiguous),
+    (0x222E, 0x222E, EastAsianWidth::Ambiguous),
+    (0x2234, 0x2237, EastAsianWidth::Ambiguous),
+    (0x223C, 0x223D, EastAsianWidth::Ambiguous),
+    (0x2248, 0x2248, EastAsianWidth::Ambiguous),
+    (0x224C, 0x224C, EastAsianWidth::Ambiguous),
+    (0x2252, 0x2252, EastAsianWidth::Ambiguous),
+    (0x2260, 0x2261, EastAsianWidth::Ambiguous),
+    (0x2264, 0x2267, EastAsianWidth::Ambiguous),
+    (0x226A, 0x226B, EastAsianWidth::Ambiguous),
+    (0x226E, 0x226F, EastAsianWidth::Ambiguous),
+    (0x2282, 0x2283, EastAsianWidth::Ambiguous),
+    (0x2286, 0x2287, EastAsianWidth::Ambiguous),
+    (0x2295, 0x2295, EastAsianWidth::Ambiguous),
+    (0x2299, 0x2299, EastAsianWidth::Ambiguous),
+    (0x22A5, 0x22A5, EastAsianWidth::Ambiguous),
+    (0x22BF, 0x22BF, EastAsianWidth::Ambiguous),
+    (0x2312, 0x2312, EastAsianWidth::Ambiguous),
+    (0x231A, 0x231B, EastAsianWidth::Wide),
+    (0x2329, 0x232A, EastAsianWidth::Wide),
+    (0x23E9, 0x23EC, EastAsianWidth::Wide),
+    (0x23F0, 0x23F0, EastAsianWidth::Wide),
+    (0x23F3, 0x23F3, EastAsianWidth::Wide),
+    (0x2460, 0x24E9, EastAsianWidth::Ambiguous),
+    (0x24EB, 0x254B, EastAsianWidth::Ambiguous),
+    (0x2550, 0x2573, EastAsianWidth::Ambiguous),
+    (0x2580, 0x258F, EastAsianWidth::Ambiguous),
+    (0x2592, 0x2595, EastAsianWidth::Ambiguous),
+    (0x25A0, 0x25A1, EastAsianWidth::Ambiguous),
+    (0x25A3, 0x25A9, EastAsianWidth::Ambiguous),
+    (0x25B2, 0x25B3, EastAsianWidth::Ambiguous),
+    (0x25B6, 0x25B7, EastAsianWidth::Ambiguous),
+    (0x25BC, 0x25BD, EastAsianWidth::Ambiguous),
+    (0x25C0, 0x25C1, EastAsianWidth::Ambiguous),
+    (0x25C6, 0x25C8, EastAsianWidth::Ambiguous),
+    (0x25CB, 0x25CB, EastAsianWidth::Ambiguous),
+    (0x25CE, 0x25D1, EastAsianWidth::Ambiguous),
+    (0x25E2, 0x25E5, EastAsianWidth::Ambiguous),
+    (0x25EF, 0x25EF, EastAsianWidth::Ambiguous),
+    (0x25FD, 0x25FE, EastAsianWidth::Wide),
+    (0x2605, 0x2606, EastAsianWidth::Ambiguous),
+    (0x2609, 0x2609, EastAsianWidth::Ambiguous),
+    (0x260E, 0x260F, EastAsianWidth::Ambiguous),
+    (0x2614, 0x2615, EastAsianWidth::Wide),
+    (0x261C, 0x261C, EastAsianWidth::Ambiguous),
+    (0x261E, 0x261E, EastAsianWidth::Ambiguous),
+    (0x2640, 0x2640, EastAsianWidth::Ambiguous),
+    (0x2642, 0x2642, EastAsianWidth::Ambiguous),
+    (0x2648, 0x2653, EastAsianWidth::Wide),
+    (0x2660, 0x2661, EastAsianWidth::Ambiguous),
+    (0x2663, 0x2665, EastAsianWidth::Ambiguous),
+    (0x2667, 0x266A, EastAsianWidth::Ambiguous),
+    (0x266C, 0x266D, EastAsianWidth::Ambiguous),
+    (0x266F, 0x266F, EastAsianWidth::Ambiguous),
+    (0x267F, 0x267F, EastAsianWidth::Wide),
+    (0x2693, 0x2693, EastAsianWidth::Wide),
+    (0x269E, 0x269F, EastAsianWidth::Ambiguous),
+    (0x26A1, 0x26A1, EastAsianWidth::Wide),
+    (0x26AA, 0x26AB, EastAsianWidth::Wide),
+    (0x26BD, 0x26BE, EastAsianWidth::Wide),
+    (0x26BF, 0x26BF, EastAsianWidth::Ambiguous),
+    (0x26C4, 0x26C5, EastAsianWidth::Wide),
+    (0x26C6, 0x26CD, EastAsianWidth::Ambiguous),
+    (0x26CE, 0x26CE, EastAsianWidth::Wide),
+    (0x26CF, 0x26D3, EastAsianWidth::Ambiguous),
+    (0x26D4, 0x26D4, EastAsianWidth::Wide),
+    (0x26D5, 0x26E1, EastAsianWidth::Ambiguous),
+    (0x26E3, 0x26E3, EastAsianWidth::Ambiguous),
+    (0x26E8, 0x26E9, EastAsianWidth::Ambiguous),
+    (0x26EA, 0x26EA, EastAsianWidth::Wide),
+    (0x26EB, 0x26F1, EastAsianWidth::Ambiguous),
+    (0x26F2, 0x26F3, EastAsianWidth::Wide),
+    (0x26F4, 0x26F4, EastAsianWidth::Ambiguous),
+    (0x26F5, 0x26F5, EastAsianWidth::Wide),
+    (0x26F6, 0x26F9, EastAsianWidth::Ambiguous),
+    (0x26FA, 0x26FA, EastAsianWidth::Wide),
+    (0x26FB, 0x26FC, EastAsianWidth::Ambiguous),
+    (0x26FD, 0x26FD, EastAsianWidth::Wide),
+    (0x26FE, 0x26FF, EastAsianWidth::Ambiguous),
+    (0x2705, 0x2705, EastAsianWidth::Wide),
+    (0x270A, 0x270B, EastAsianWidth::Wide),
+    (0x2728, 0x2728, EastAsianWidth::Wide),
+    (0x273D, 0x273D, EastAsianWidth::Ambiguous),
+    (0x274C, 0x274C, EastAsianWidth::Wide),
+    (0x274E, 0x274E, EastAsianWidth::Wide),
+    (0x2753, 0x2755, EastAsianWidth::Wide),
+    (0x2757, 0x2757, EastAsianWidth::Wide),
+    (0x2776, 0x277F, EastAsianWidth::Ambiguous),
+    (0x2795, 0x2797, EastAsianWidth::Wide),
+    (0x27B0, 0x27B0, EastAsianWidth::Wide),
+    (0x27BF, 0x27BF, EastAsianWidth::Wide),
+    (0x27E6, 0x27ED, EastAsianWidth::Narrow),
+    (0x2985, 0x2986, EastAsianWidth::Narrow),
+    (0x2B1B, 0x2B1C, EastAsianWidth::Wide),
+    (0x2B50, 0x2B50, EastAsianWidth::Wide),
+    (0x2B55, 0x2B55, EastAsianWidth::Wide),
+    (0x2B56, 0x2B59, EastAsianWidth::Ambiguous),
+    (0x2E80, 0x2E99, EastAsianWidth::Wide),
+    (0x2E9B, 0x2EF3, EastAsianWidth::Wide),
+    (0x2F00, 0x2FD5, EastAsianWidth::Wide),
+    (0x2FF0, 0x2FFB, EastAsianWidth::Wide),
+    (0x3000, 0x3000, EastAsianWidth::Fullwidth),
+    (0x3001, 0x303E, EastAsianWidth::Wide),
+    (0x3041, 0x3096, EastAsianWidth::Wide),
+    (0x3099, 0x30FF, EastAsianWidth::Wide),
+    (0x3105, 0x312F, EastAsianWidth::Wide),
+    (0x3131, 0x318E, EastAsianWidth::Wide),
+    (0x3190, 0x31E3, EastAsianWidth::Wide),
+    (0x31F0, 0x321E, EastAsianWidth::Wide),
+    (0x3220, 0x3247, EastAsianWidth::Wide),
+    (0x3248, 0x324F, EastAsianWidth::Ambiguous),
+    (0x3250, 0x4DBF, EastAsianWidth::Wide),
+    (0x4E00, 0xA48C, EastAsianWidth::Wide),
+    (0xA490, 0xA4C6, EastAsianWidth::Wide),
+    (0xA960, 0xA97C, EastAsianWidth::Wide),
+    (0xAC00, 0xD7A3, EastAsianWidth::Wide),
+    (0xE000, 0xF8FF, EastAsianWidth::Ambiguous),
+    (0xF900, 0xFAFF, EastAsianWidth::Wide),
+    (0xFE00, 0xFE0F, EastAsianWidth::Ambiguous),
+    (0xFE10, 0xFE19, EastAsianWidth::Wide),
+    (0xFE30, 0xFE52, EastAsianWidth::Wide),
+    (0xFE54, 0xFE66, EastAsianWidth::Wide),
+    (0xFE68, 0xFE6B, EastAsianWidth::Wide),
+    (0xFF01, 0xFF60, EastAsianWidth::Fullwidth),
+    (0xFF61, 0xFFBE, EastAsianWidth::Halfwidth),
+    (0xFFC2, 0xFFC7, EastAsianWidth::Halfwidth),
+    (0xFFCA, 0xFFCF, EastAsianWidth::Halfwidth),
+    (0xFFD2, 0xFFD7, EastAsianWidth::Halfwidth),
+    (0xFFDA, 0xFFDC, EastAsianWidth::Halfwidth),
+    (0xFFE0, 0xFFE6, EastAsianWidth::Fullwidth),
+    (0xFFE8, 0xFFEE, EastAsianWidth::Halfwidth),
+    (0xFFFD, 0xFFFD, EastAsianWidth::Ambiguous),
+    (0x16FE0, 0x16FE4, EastAsianWidth::Wide),
+    (0x16FF0, 0x16FF1, EastAsianWidth::Wide),
+    (0x17000, 0x187F7, EastAsianWidth::Wide),
+    (0x18800, 0x18CD5, EastAsianWidth::Wide),
+    (0x18D00, 0x18D08, EastAsianWidth::Wide),
+    (0x1B000, 0x1B11E, EastAsianWidth::Wide),
+    (0x1B150, 0x1B152, EastAsianWidth::Wide),
+    (0x1B164, 0x1B167, EastAsianWidth::Wide),
+    (0x1B170, 0x1B2FB, EastAsianWidth::Wide),
+    (0x1F004, 0x1F004, EastAsianWidth::Wide),
+    (0x1F0CF, 0x1F0CF, EastAsianWidth::Wide),
+    (0x1F100, 0x1F10A, EastAsianWidth::Ambiguous),
+    (0x1F110, 0x1F12D, EastAsianWidth::Ambiguous),
+    (0x1F130, 0x1F169, EastAsianWidth::Ambiguous),
+    (0x1F170, 0x1F18D, EastAsianWidth::Ambiguous),
+    (0x1F18E, 0x1F18E, EastAsianWidth::Wide),
+    (0x1F18F, 0x1F190, EastAsianWidth::Ambiguous),
+    (0x1F191, 0x1F19A, EastAsianWidth::Wide),
+    (0x1F19B, 0x1F1AC, EastAsianWidth::Ambiguous),
+    (0x1F200, 0x1F202, EastAsianWidth::Wide),
+    (0x1F210, 0x1F23B, EastAsianWidth::Wide),
+    (0x1F240, 0x1F248, EastAsianWidth::Wide),
+    (0x1F250, 0x1F251, EastAsianWidth::Wide),
+    (0x1F260, 0x1F265, EastAsianWidth::Wide),
+    (0x1F300, 0x1F320, EastAsianWidth::Wide),
+    (0x1F32D, 0x1F335, EastAsianWidth::Wide),
+    (0x1F337, 0x1F37C, EastAsianWidth::Wide),
+    (0x1F37E, 0x1F393, EastAsianWidth::Wide),
+    (0x1F3A0, 0x1F3CA, EastAsianWidth::Wide),
+    (0x1F3CF, 0x1F3D3, EastAsianWidth::Wide),
+    (0x1F3E0, 0x1F3F0, EastAsianWidth::Wide),
+    (0x1F3F4, 0x1F3F4, EastAsianWidth::Wide),
+    (0x1F3F8, 0x1F43E, EastAsianWidth::Wide),
+    (0x1F440, 0x1F440, EastAsianWidth::Wide),
+    (0x1F442, 0x1F4FC, EastAsianWidth::Wide),
+    (0x1F4FF, 0x1F53D, EastAsianWidth::Wide),
+    (0x1F54B, 0x1F54E, EastAsianWidth::Wide),
+    (0x1F550, 0x1F567, EastAsianWidth::Wide),
+    (0x1F57A, 0x1F57A, EastAsianWidth::Wide),
+    (0x1F595, 0x1F596, EastAsianWidth::Wide),
+    (0x1F5A4, 0x1F5A4, EastAsianWidth::Wide),
+    (0x1F5FB, 0x1F64F, EastAsianWidth::Wide),
+    (0x1F680, 0x1F6C5, EastAsianWidth::Wide),
+    (0x1F6CC, 0x1F6CC, EastAsianWidth::Wide),
+    (0x1F6D0, 0x1F6D2, EastAsianWidth::Wide),
+    (0x1F6D5, 0x1F6D7, EastAsianWidth::Wide),
+    (0x1F6EB, 0x1F6EC, EastAsianWidth::Wide),
+    (0x1F6F4, 0x1F6FC, EastAsianWidth::Wide),
+    (0x1F7E0, 0x1F7EB, EastAsianWidth::Wide),
+    (0x1F90C, 0x1F93A, EastAsianWidth::Wide),
+    (0x1F93C, 0x1F945, EastAsianWidth::Wide),
+    (0x1F947, 0x1F978, EastAsianWidth::Wide),
+    (0x1F97A, 0x1F9CB, EastAsianWidth::Wide),
+    (0x1F9CD, 0x1F9FF, EastAsianWidth::Wide),
+    (0x1FA70, 0x1FA74, EastAsianWidth::Wide),
+    (0x1FA78, 0x1FA7A, EastAsianWidth::Wide),
+    (0x1FA80, 0x1FA86, EastAsianWidth::Wide),
+    (0x1FA90, 0x1FAA8, EastAsianWidth::Wide),
+    (0x1FAB0, 0x1FAB6, EastAsianWidth::Wide),
+    (0x1FAC0, 0x1FAC2, EastAsianWidth::Wide),
+    (0x1FAD0, 0x1FAD6, EastAsianWidth::Wide),
+    (0x20000, 0x2FFFD, EastAsianWidth::Wide),
+    (0x30000, 0x3FFFD, EastAsianWidth::Wide),
+    (0xE0100, 0xE01EF, EastAsianWidth::Ambiguous),
+    (0xF0000, 0xFFFFD, EastAsianWidth::Ambiguous),
+    (0x100000, 0x10FFFD, EastAsianWidth::Ambiguous),
+];
@@ -0,0 +1,248 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Supplements `unic::emoji::char` with Extended_Pictographic, and recognizes the emoji sequences
+//! defined in [UTS #51](https://www.unicode.org/reports/tr51/#Definitions).
+//!
+
+mod tables;
+
+use std::fmt;
+
+use serde::Serialize;
+use unic::emoji::char::{is_emoji, is_emoji_modifier, is_emoji_modifier_base};
+use unic::ucd::Name;
+
+use super::is_in_ranges;
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+const COMBINING_ENCLOSING_KEYCAP: char = '\u{20E3}';
+const WAVING_BLACK_FLAG: char = '\u{1F3F4}';
+const CANCEL_TAG: char = '\u{E007F}';
+const HEAVY_BLACK_HEART: char = '\u{2764}';
+const KISS_MARK: char = '\u{1F48B}';
+
+// MAN, WOMAN, BOY, GIRL, ADULT and CHILD
+const FAMILY_MEMBERS: &[char] = &[
+    '\u{1F468}',
+    '\u{1F469}',
+    '\u{1F466}',
+    '\u{1F467}',
+    '\u{1F9D1}',
+    '\u{1F9D2}',
+];
+
+// Emoji modifiers are named after the Fitzpatrick scale, CLDR names them after skin tones
+const SKIN_TONE_NAMES: &[&str] = &[
+    "light skin tone",
+    "medium-light skin tone",
+    "medium skin tone",
+    "medium-dark skin tone",
+    "dark skin tone",
+];
+
+pub fn is_extended_pictographic(chr: char) -> bool {
+    is_in_ranges(tables::EXTENDED_PICTOGRAPHIC_RANGES, chr)
+}
+
+fn is_regional_indicator(chr: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&chr)
+}
+
+fn is_tag(chr: char) -> bool {
+    ('\u{E0020}'..='\u{E007E}').contains(&chr)
+}
+
+fn lowercase_name(chr: char) -> String {
+    match Name::of(chr) {
+        Some(name) => name.to_string().to_lowercase(),
+        None => chr.to_string(),
+    }
+}
+
+fn skin_tone_name(modifier: char) -> &'static str {
+    SKIN_TONE_NAMES[(modifier as u32 - 0x1F3FB) as usize]
+}
+
+#[derive(Serialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EmojiSequenceKind {
+    Keycap,
+    Flag,
+    Modifier,
+    Zwj,
+}
+
+impl fmt::Display for EmojiSequenceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            EmojiSequenceKind::Keycap => "keycap",
+            EmojiSequenceKind::Flag => "flag",
+            EmojiSequenceKind::Modifier => "modifier",
+            EmojiSequenceKind::Zwj => "ZWJ",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+/// An emoji sequence which is displayed as a single emoji, e.g. "family (woman, woman, girl)" for
+/// U+1F469 U+200D U+1F469 U+200D U+1F467.
+#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct EmojiSequence {
+    pub kind: EmojiSequenceKind,
+    pub description: String,
+}
+
+impl EmojiSequence {
+    fn new(kind: EmojiSequenceKind, description: String) -> Self {
+        EmojiSequence { kind, description }
+    }
+
+    /// Recognizes `grapheme` as an emoji sequence, `None` for single emoji and other text.
+    pub fn of(grapheme: &str) -> Option<Self> {
+        let characters: Vec<char> = grapheme.chars().collect();
+        match characters.as_slice() {
+            [key, VARIATION_SELECTOR_16, COMBINING_ENCLOSING_KEYCAP]
+                if key.is_ascii_digit() || *key == '#' || *key == '*' =>
+            {
+                Some(EmojiSequence::new(
+                    EmojiSequenceKind::Keycap,
+                    key.to_string(),
+                ))
+            }
+            [first, second] if is_regional_indicator(*first) && is_regional_indicator(*second) => {
+                // Regional indicators map to the letters of a region code, e.g. "CN"
+                let region = [first, second]
+                    .iter()
+                    .map(|chr| (b'A' + (**chr as u32 - 0x1F1E6) as u8) as char)
+                    .collect();
+                Some(EmojiSequence::new(EmojiSequenceKind::Flag, region))
+            }
+            [WAVING_BLACK_FLAG, tags @ .., CANCEL_TAG]
+                if !tags.is_empty() && tags.iter().all(|chr| is_tag(*chr)) =>
+            {
+                // Tags map to ASCII, e.g. "gbsct" for Scotland
+                let subdivision = tags
+                    .iter()
+                    .map(|chr| (*chr as u32 - 0xE0000) as u8 as char)
+                    .collect();
+                Some(EmojiSequence::new(EmojiSequenceKind::Flag, subdivision))
+            }
+            _ if characters.contains(&ZERO_WIDTH_JOINER) => EmojiSequence::zwj_sequence(grapheme),
+            [base, modifier] if is_emoji_modifier_base(*base) && is_emoji_modifier(*modifier) => {
+                Some(EmojiSequence::new(
+                    EmojiSequenceKind::Modifier,
+                    format!("{}, {}", lowercase_name(*base), skin_tone_name(*modifier)),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn zwj_sequence(grapheme: &str) -> Option<Self> {
+        // Each element of a ZWJ sequence starts with an emoji, which may be followed by a
+        // presentation selector, an emoji modifier or tags
+        let mut bases = Vec::new();
+        for element in grapheme.split(ZERO_WIDTH_JOINER) {
+            match element.chars().next() {
+                Some(base) if is_emoji(base) || is_extended_pictographic(base) => bases.push(base),
+                _ => return None,
+            }
+        }
+
+        let names = bases
+            .iter()
+            .map(|base| lowercase_name(*base))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let description = if bases.iter().all(|base| FAMILY_MEMBERS.contains(base)) {
+            format!("family ({})", names)
+        } else if bases.contains(&HEAVY_BLACK_HEART) && bases.contains(&KISS_MARK) {
+            format!("kiss ({})", names)
+        } else if bases.contains(&HEAVY_BLACK_HEART) && bases.len() > 2 {
+            format!("couple with heart ({})", names)
+        } else {
+            names
+        };
+        Some(EmojiSequence::new(EmojiSequenceKind::Zwj, description))
+    }
+}
+
+impl fmt::Display for EmojiSequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} sequence: {}", self.kind, self.description)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn description(grapheme: &str) -> Option<String> {
+        EmojiSequence::of(grapheme).map(|sequence| sequence.to_string())
+    }
+
+    #[test]
+    fn test_is_extended_pictographic() {
+        assert!(is_extended_pictographic('\u{00A9}'));
+        assert!(is_extended_pictographic('\u{1F600}'));
+        // Reserved for future emoji
+        assert!(is_extended_pictographic('\u{1FFFD}'));
+        assert!(!is_extended_pictographic('a'));
+        assert!(!is_extended_pictographic('\u{1F1E8}'));
+    }
+
+    #[test]
+    fn test_emoji_sequences() {
+        assert_eq!(
+            description("1\u{FE0F}\u{20E3}"),
+            Some("keycap sequence: 1".to_owned())
+        );
+        assert_eq!(
+            description("\u{1F1E8}\u{1F1F3}"),
+            Some("flag sequence: CN".to_owned())
+        );
+        assert_eq!(
+            description("\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}"),
+            Some("flag sequence: gbsct".to_owned())
+        );
+        assert_eq!(
+            description("\u{1F44D}\u{1F3FD}"),
+            Some("modifier sequence: thumbs up sign, medium skin tone".to_owned())
+        );
+        assert_eq!(
+            description("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}"),
+            Some("ZWJ sequence: family (woman, woman, girl)".to_owned())
+        );
+        assert_eq!(
+            description("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}"),
+            Some("ZWJ sequence: couple with heart (woman, heavy black heart, man)".to_owned())
+        );
+        assert_eq!(
+            description("\u{1F3C3}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}"),
+            Some("ZWJ sequence: runner, female sign".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_non_sequences() {
+        assert_eq!(description(""), None);
+        assert_eq!(description("\u{1F600}"), None);
+        assert_eq!(description("e\u{301}"), None);
+        assert_eq!(description("1\u{20E3}"), None);
+        assert_eq!(description("a\u{200D}b"), None);
+    }
+}
@@ -0,0 +1,526 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+use std::convert::TryInto;
+use std::fmt;
+use std::str;
+
+use hex_slice::AsHex;
+use serde::Serialize;
+
+use super::character_properties::TREE_GRAPH_CORNER;
+
+const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+/// Encodings supported for byte input.
+///
+/// WTF-8 is UTF-8 which also allows isolated surrogate code points, encoded like other code points
+/// in the Basic Multilingual Plane. This is how code point inputs (which may contain lone
+/// surrogates) are fed through the same stream as text.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Encoding {
+    Utf8,
+    Wtf8,
+    Utf16Le,
+    Utf16Be,
+    Utf32Le,
+    Utf32Be,
+}
+
+impl Encoding {
+    pub fn name(self) -> &'static str {
+        match self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Wtf8 => "WTF-8",
+            Encoding::Utf16Le => "UTF-16LE",
+            Encoding::Utf16Be => "UTF-16BE",
+            Encoding::Utf32Le => "UTF-32LE",
+            Encoding::Utf32Be => "UTF-32BE",
+        }
+    }
+
+    /// Looks up an encoding by name, ignoring case and hyphens, e.g. `utf-16le` and `UTF16LE` are
+    /// both accepted.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalized_name: String = name
+            .chars()
+            .filter(|chr| *chr != '-' && *chr != '_')
+            .collect::<String>()
+            .to_lowercase();
+        match normalized_name.as_str() {
+            "utf8" => Some(Encoding::Utf8),
+            "wtf8" => Some(Encoding::Wtf8),
+            "utf16le" => Some(Encoding::Utf16Le),
+            "utf16be" => Some(Encoding::Utf16Be),
+            "utf32le" => Some(Encoding::Utf32Le),
+            "utf32be" => Some(Encoding::Utf32Be),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvalidSequenceReason {
+    Overlong,
+    StrayContinuation,
+    Truncated,
+    EncodedSurrogate,
+    OutOfRange,
+    InvalidByte,
+    UnpairedSurrogate,
+}
+
+impl fmt::Display for InvalidSequenceReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            InvalidSequenceReason::Overlong => "overlong encoding",
+            InvalidSequenceReason::StrayContinuation => "stray continuation byte",
+            InvalidSequenceReason::Truncated => "truncated sequence",
+            InvalidSequenceReason::EncodedSurrogate => "encoded surrogate",
+            InvalidSequenceReason::OutOfRange => "code point beyond U+10FFFF",
+            InvalidSequenceReason::InvalidByte => "invalid byte",
+            InvalidSequenceReason::UnpairedSurrogate => "unpaired surrogate",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+/// Describes a sequence of bytes which can not be decoded, e.g. in a file which is not valid UTF-8.
+#[derive(Serialize, Debug, Eq, PartialEq)]
+pub struct EncodingDiagnostic {
+    pub encoding: &'static str,
+    pub byte_offset: usize,
+    pub bytes: Vec<u8>,
+    pub reason: InvalidSequenceReason,
+}
+
+impl EncodingDiagnostic {
+    /// Describes the invalid sequence in a single line, e.g. for a table cell.
+    pub fn description(&self) -> String {
+        format!(
+            "Invalid {} at byte {}, {}",
+            self.encoding, self.byte_offset, self.reason
+        )
+    }
+}
+
+impl fmt::Display for EncodingDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", REPLACEMENT_CHARACTER)?;
+        writeln!(
+            f,
+            "{}Invalid {} at byte {} ({:#04x})  {}",
+            TREE_GRAPH_CORNER,
+            self.encoding,
+            self.byte_offset,
+            self.bytes.as_hex(),
+            self.reason,
+        )
+    }
+}
+
+fn is_utf8_continuation_byte(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+/// Classifies the invalid UTF-8 sequence at the start of `bytes`, returns the reason and the
+/// length of the offending sequence.
+///
+/// `None` is returned if `bytes` is a valid but incomplete UTF-8 sequence, i.e. more bytes are
+/// needed to tell whether it's valid or not.
+pub fn invalid_utf8_sequence(bytes: &[u8]) -> Option<(InvalidSequenceReason, usize)> {
+    let lead_byte = *bytes.first()?;
+    let (expected_length, minimum_code_point) = match lead_byte {
+        0x00..=0x7F => return None,
+        0x80..=0xBF => return Some((InvalidSequenceReason::StrayContinuation, 1)),
+        0xC0..=0xDF => (2, 0x80),
+        0xE0..=0xEF => (3, 0x800),
+        0xF0..=0xF7 => (4, 0x1_0000),
+        0xF8..=0xFF => return Some((InvalidSequenceReason::InvalidByte, 1)),
+    };
+
+    let continuation_bytes: Vec<u8> = bytes[1..]
+        .iter()
+        .take(expected_length - 1)
+        .take_while(|byte| is_utf8_continuation_byte(**byte))
+        .copied()
+        .collect();
+    let length = 1 + continuation_bytes.len();
+    if length < expected_length {
+        if length == bytes.len() {
+            return None; // Reached the end of `bytes`, the sequence may still be completed
+        }
+        return Some((InvalidSequenceReason::Truncated, length));
+    }
+
+    let lead_bits = u32::from(lead_byte) & (0x7F >> expected_length);
+    let code_point = continuation_bytes
+        .iter()
+        .fold(lead_bits, |code_point, byte| {
+            (code_point << 6) | (u32::from(*byte) & 0b0011_1111)
+        });
+    if code_point < minimum_code_point {
+        Some((InvalidSequenceReason::Overlong, length))
+    } else if (0xD800..=0xDFFF).contains(&code_point) {
+        Some((InvalidSequenceReason::EncodedSurrogate, length))
+    } else if code_point > 0x10_FFFF {
+        Some((InvalidSequenceReason::OutOfRange, length))
+    } else {
+        None // This is a valid sequence
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DecodedSequence {
+    Character(char, usize),
+    /// An isolated surrogate code point, only encodings which can represent them (i.e. WTF-8)
+    /// decode these.
+    Surrogate(u32, usize),
+    Invalid(InvalidSequenceReason, usize),
+}
+
+/// Encodes `code_point` as WTF-8, which is the same as UTF-8 except that surrogate code points are
+/// also allowed.
+pub fn encode_wtf8(code_point: u32, bytes: &mut Vec<u8>) {
+    match code_point {
+        0x00..=0x7F => bytes.push(code_point as u8),
+        0x80..=0x7FF => bytes.extend_from_slice(&[
+            0b1100_0000 | (code_point >> 6) as u8,
+            0b1000_0000 | (code_point & 0b0011_1111) as u8,
+        ]),
+        0x800..=0xFFFF => bytes.extend_from_slice(&[
+            0b1110_0000 | (code_point >> 12) as u8,
+            0b1000_0000 | ((code_point >> 6) & 0b0011_1111) as u8,
+            0b1000_0000 | (code_point & 0b0011_1111) as u8,
+        ]),
+        _ => bytes.extend_from_slice(&[
+            0b1111_0000 | (code_point >> 18) as u8,
+            0b1000_0000 | ((code_point >> 12) & 0b0011_1111) as u8,
+            0b1000_0000 | ((code_point >> 6) & 0b0011_1111) as u8,
+            0b1000_0000 | (code_point & 0b0011_1111) as u8,
+        ]),
+    }
+}
+
+fn utf8_sequence_length(lead_byte: u8) -> usize {
+    match lead_byte {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+fn decode_utf8_sequence(bytes: &[u8]) -> Option<DecodedSequence> {
+    if let Some((reason, length)) = invalid_utf8_sequence(bytes) {
+        return Some(DecodedSequence::Invalid(reason, length));
+    }
+
+    let length = utf8_sequence_length(*bytes.first()?);
+    let chr = str::from_utf8(bytes.get(..length)?).ok()?.chars().next()?;
+    Some(DecodedSequence::Character(chr, length))
+}
+
+fn decode_wtf8_sequence(bytes: &[u8]) -> Option<DecodedSequence> {
+    match decode_utf8_sequence(bytes)? {
+        DecodedSequence::Invalid(InvalidSequenceReason::EncodedSurrogate, length) => {
+            let code_point = (u32::from(bytes[0]) & 0b0000_1111) << 12
+                | (u32::from(bytes[1]) & 0b0011_1111) << 6
+                | (u32::from(bytes[2]) & 0b0011_1111);
+            Some(DecodedSequence::Surrogate(code_point, length))
+        }
+        sequence => Some(sequence),
+    }
+}
+
+fn decode_utf16_sequence(
+    bytes: &[u8],
+    is_big_endian: bool,
+    is_final: bool,
+) -> Option<DecodedSequence> {
+    let code_unit_at = |index: usize| -> Option<u16> {
+        let code_unit_bytes = [*bytes.get(index)?, *bytes.get(index + 1)?];
+        Some(if is_big_endian {
+            u16::from_be_bytes(code_unit_bytes)
+        } else {
+            u16::from_le_bytes(code_unit_bytes)
+        })
+    };
+
+    let lead_unit = code_unit_at(0)?;
+    match lead_unit {
+        0xD800..=0xDBFF => match code_unit_at(2) {
+            Some(trail_unit @ 0xDC00..=0xDFFF) => {
+                let code_point = 0x1_0000
+                    + ((u32::from(lead_unit) - 0xD800) << 10)
+                    + (u32::from(trail_unit) - 0xDC00);
+                let chr = std::char::from_u32(code_point)?;
+                Some(DecodedSequence::Character(chr, 4))
+            }
+            Some(_) => Some(DecodedSequence::Invalid(
+                InvalidSequenceReason::UnpairedSurrogate,
+                2,
+            )),
+            None if is_final => Some(DecodedSequence::Invalid(
+                InvalidSequenceReason::UnpairedSurrogate,
+                2,
+            )),
+            None => None,
+        },
+        0xDC00..=0xDFFF => Some(DecodedSequence::Invalid(
+            InvalidSequenceReason::UnpairedSurrogate,
+            2,
+        )),
+        _ => {
+            let chr = std::char::from_u32(u32::from(lead_unit))?;
+            Some(DecodedSequence::Character(chr, 2))
+        }
+    }
+}
+
+fn decode_utf32_sequence(bytes: &[u8], is_big_endian: bool) -> Option<DecodedSequence> {
+    let code_unit_bytes: [u8; 4] = bytes.get(..4)?.try_into().ok()?;
+    let code_point = if is_big_endian {
+        u32::from_be_bytes(code_unit_bytes)
+    } else {
+        u32::from_le_bytes(code_unit_bytes)
+    };
+    Some(match std::char::from_u32(code_point) {
+        Some(chr) => DecodedSequence::Character(chr, 4),
+        None if code_point > 0x10_FFFF => {
+            DecodedSequence::Invalid(InvalidSequenceReason::OutOfRange, 4)
+        }
+        None => DecodedSequence::Invalid(InvalidSequenceReason::EncodedSurrogate, 4),
+    })
+}
+
+/// Decodes the first character of `bytes` in `encoding`, returns the character or the reason why
+/// the sequence is invalid, together with the number of bytes consumed.
+///
+/// `None` is returned if `bytes` is empty, or if more bytes are needed to decode the sequence and
+/// `is_final` is `false`. If `is_final` is `true`, an incomplete sequence is reported as
+/// `InvalidSequenceReason::Truncated` instead.
+pub fn decode_sequence(
+    encoding: Encoding,
+    bytes: &[u8],
+    is_final: bool,
+) -> Option<DecodedSequence> {
+    let sequence = match encoding {
+        Encoding::Utf8 => decode_utf8_sequence(bytes),
+        Encoding::Wtf8 => decode_wtf8_sequence(bytes),
+        Encoding::Utf16Le => decode_utf16_sequence(bytes, false, is_final),
+        Encoding::Utf16Be => decode_utf16_sequence(bytes, true, is_final),
+        Encoding::Utf32Le => decode_utf32_sequence(bytes, false),
+        Encoding::Utf32Be => decode_utf32_sequence(bytes, true),
+    };
+    match sequence {
+        None if is_final && !bytes.is_empty() => Some(DecodedSequence::Invalid(
+            InvalidSequenceReason::Truncated,
+            bytes.len(),
+        )),
+        sequence => sequence,
+    }
+}
+
+/// Decodes `bytes` in `encoding`, replacing invalid sequences and surrogates with U+FFFD.
+pub fn decode_lossy(encoding: Encoding, bytes: &[u8]) -> String {
+    let mut string = String::with_capacity(bytes.len());
+    let mut offset = 0;
+    while let Some(sequence) = decode_sequence(encoding, &bytes[offset..], true) {
+        match sequence {
+            DecodedSequence::Character(chr, length) => {
+                string.push(chr);
+                offset += length;
+            }
+            DecodedSequence::Surrogate(_, length) | DecodedSequence::Invalid(_, length) => {
+                string.push(REPLACEMENT_CHARACTER);
+                offset += length;
+            }
+        }
+    }
+    string
+}
+
+/// Decodes `bytes` in `encoding` into code points, replacing invalid sequences with U+FFFD, unlike
+/// `decode_lossy` surrogate code points are kept.
+pub fn decode_lossy_to_code_points(encoding: Encoding, bytes: &[u8]) -> Vec<u32> {
+    let mut code_points = Vec::with_capacity(bytes.len());
+    let mut offset = 0;
+    while let Some(sequence) = decode_sequence(encoding, &bytes[offset..], true) {
+        match sequence {
+            DecodedSequence::Character(chr, length) => {
+                code_points.push(chr as u32);
+                offset += length;
+            }
+            DecodedSequence::Surrogate(code_point, length) => {
+                code_points.push(code_point);
+                offset += length;
+            }
+            DecodedSequence::Invalid(_, length) => {
+                code_points.push(REPLACEMENT_CHARACTER as u32);
+                offset += length;
+            }
+        }
+    }
+    code_points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_utf8_sequence() {
+        assert_eq!(
+            invalid_utf8_sequence(b"\xC0\xAF"),
+            Some((InvalidSequenceReason::Overlong, 2))
+        );
+        assert_eq!(
+            invalid_utf8_sequence(b"\xE0\x80\xAF"),
+            Some((InvalidSequenceReason::Overlong, 3))
+        );
+        assert_eq!(
+            invalid_utf8_sequence(b"\x80abc"),
+            Some((InvalidSequenceReason::StrayContinuation, 1))
+        );
+        assert_eq!(
+            invalid_utf8_sequence(b"\xE2\x82a"),
+            Some((InvalidSequenceReason::Truncated, 2))
+        );
+        assert_eq!(
+            invalid_utf8_sequence(b"\xED\xA0\x80"),
+            Some((InvalidSequenceReason::EncodedSurrogate, 3))
+        );
+        assert_eq!(
+            invalid_utf8_sequence(b"\xF4\x90\x80\x80"),
+            Some((InvalidSequenceReason::OutOfRange, 4))
+        );
+        assert_eq!(
+            invalid_utf8_sequence(b"\xFF"),
+            Some((InvalidSequenceReason::InvalidByte, 1))
+        );
+
+        assert_eq!(invalid_utf8_sequence(b"\xE2\x82"), None);
+        assert_eq!(invalid_utf8_sequence(b"\xE2\x82\xAC"), None);
+        assert_eq!(invalid_utf8_sequence(b""), None);
+    }
+
+    #[test]
+    fn test_encoding_from_name() {
+        assert_eq!(Encoding::from_name("utf-8"), Some(Encoding::Utf8));
+        assert_eq!(Encoding::from_name("UTF16LE"), Some(Encoding::Utf16Le));
+        assert_eq!(Encoding::from_name("utf_32-be"), Some(Encoding::Utf32Be));
+        assert_eq!(Encoding::from_name("WTF-8"), Some(Encoding::Wtf8));
+        assert_eq!(Encoding::from_name("latin-1"), None);
+    }
+
+    #[test]
+    fn test_decode_sequence() {
+        use DecodedSequence::{Character, Invalid, Surrogate};
+
+        assert_eq!(
+            decode_sequence(Encoding::Utf8, b"\xE2\x82\xAC", false),
+            Some(Character('\u{20AC}', 3))
+        );
+        assert_eq!(decode_sequence(Encoding::Utf8, b"\xE2\x82", false), None);
+        assert_eq!(
+            decode_sequence(Encoding::Utf8, b"\xE2\x82", true),
+            Some(Invalid(InvalidSequenceReason::Truncated, 2))
+        );
+
+        assert_eq!(
+            decode_sequence(Encoding::Utf16Be, b"\xD8\x3D\xDE\x00", false),
+            Some(Character('\u{1F600}', 4))
+        );
+        assert_eq!(
+            decode_sequence(Encoding::Utf16Le, b"\x3D\xD8\x00\xDE", false),
+            Some(Character('\u{1F600}', 4))
+        );
+        assert_eq!(
+            decode_sequence(Encoding::Utf16Be, b"\xD8\x3D\x00\x41", false),
+            Some(Invalid(InvalidSequenceReason::UnpairedSurrogate, 2))
+        );
+        assert_eq!(
+            decode_sequence(Encoding::Utf16Be, b"\xDE\x00", false),
+            Some(Invalid(InvalidSequenceReason::UnpairedSurrogate, 2))
+        );
+        assert_eq!(decode_sequence(Encoding::Utf16Be, b"\xD8\x3D", false), None);
+        assert_eq!(
+            decode_sequence(Encoding::Utf16Be, b"\xD8\x3D", true),
+            Some(Invalid(InvalidSequenceReason::UnpairedSurrogate, 2))
+        );
+        assert_eq!(
+            decode_sequence(Encoding::Utf16Be, b"\x00", true),
+            Some(Invalid(InvalidSequenceReason::Truncated, 1))
+        );
+
+        assert_eq!(
+            decode_sequence(Encoding::Utf32Le, b"\x00\xF6\x01\x00", false),
+            Some(Character('\u{1F600}', 4))
+        );
+        assert_eq!(
+            decode_sequence(Encoding::Utf32Be, b"\x00\x11\x00\x00", false),
+            Some(Invalid(InvalidSequenceReason::OutOfRange, 4))
+        );
+        assert_eq!(
+            decode_sequence(Encoding::Utf32Be, b"\x00\x00\xD8\x00", false),
+            Some(Invalid(InvalidSequenceReason::EncodedSurrogate, 4))
+        );
+
+        assert_eq!(
+            decode_sequence(Encoding::Wtf8, b"\xED\xA0\x80", false),
+            Some(Surrogate(0xD800, 3))
+        );
+        assert_eq!(
+            decode_sequence(Encoding::Wtf8, b"\xE2\x82\xAC", false),
+            Some(Character('\u{20AC}', 3))
+        );
+        assert_eq!(
+            decode_sequence(Encoding::Wtf8, b"\xC0\xAF", false),
+            Some(Invalid(InvalidSequenceReason::Overlong, 2))
+        );
+
+        assert_eq!(decode_sequence(Encoding::Utf32Be, b"", true), None);
+    }
+
+    #[test]
+    fn test_encode_wtf8() {
+        for chr in &['A', '\u{E9}', '\u{20AC}', '\u{1F600}', '\u{10FFFF}'] {
+            let mut bytes = vec![];
+            encode_wtf8(*chr as u32, &mut bytes);
+            assert_eq!(bytes, chr.to_string().into_bytes());
+        }
+
+        let mut bytes = vec![];
+        encode_wtf8(0xDFFF, &mut bytes);
+        assert_eq!(bytes, b"\xED\xBF\xBF");
+    }
+
+    #[test]
+    fn test_decode_lossy() {
+        assert_eq!(
+            decode_lossy(Encoding::Utf16Be, b"\x00\x41\xDE\x00\x00\x42\x00"),
+            "A\u{FFFD}B\u{FFFD}"
+        );
+    }
+
+    #[test]
+    fn test_decode_lossy_to_code_points() {
+        assert_eq!(
+            decode_lossy_to_code_points(Encoding::Wtf8, b"A\xED\xA0\x80\xFFB"),
+            vec![0x41, 0xD800, 0xFFFD, 0x42]
+        );
+    }
+}
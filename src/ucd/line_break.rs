@@ -0,0 +1,99 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! The Line_Break property, which is not yet provided by `unic::ucd`.
+//!
+
+mod tables;
+
+enumerated_property! {
+    /// Values of the Line_Break property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    #[allow(clippy::upper_case_acronyms)] // For JL, ZWJ, etc.
+    pub enum LineBreak {
+        abbr => "lb",
+        long => "Line_Break",
+        human => "Line Break",
+        default => Unknown,
+        values => {
+            Ambiguous,
+            Alphabetic,
+            BreakBoth,
+            BreakAfter,
+            BreakBefore,
+            MandatoryBreak,
+            ContingentBreak,
+            ConditionalJapaneseStarter,
+            ClosePunctuation,
+            CombiningMark,
+            CloseParenthesis,
+            CarriageReturn,
+            EBase,
+            EModifier,
+            Exclamation,
+            Glue,
+            H2,
+            H3,
+            HebrewLetter,
+            Hyphen,
+            Ideographic,
+            Inseparable,
+            InfixNumeric,
+            JL,
+            JT,
+            JV,
+            LineFeed,
+            NextLine,
+            Nonstarter,
+            Numeric,
+            OpenPunctuation,
+            PostfixNumeric,
+            PrefixNumeric,
+            Quotation,
+            RegionalIndicator,
+            ComplexContext,
+            Surrogate,
+            Space,
+            BreakSymbols,
+            WordJoiner,
+            Unknown,
+            ZWSpace,
+            ZWJ,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unic::char::property::EnumeratedCharProperty;
+
+    use super::*;
+
+    #[test]
+    fn test_line_break_of() {
+        assert_eq!(LineBreak::of('a'), LineBreak::Alphabetic);
+        assert_eq!(LineBreak::of(' '), LineBreak::Space);
+        assert_eq!(LineBreak::of('-'), LineBreak::Hyphen);
+        assert_eq!(LineBreak::of('中'), LineBreak::Ideographic);
+        assert_eq!(LineBreak::of('\u{0E01}'), LineBreak::ComplexContext);
+        assert_eq!(LineBreak::of('\u{0378}'), LineBreak::Unknown);
+        // Unassigned code points in some blocks have other values than Unknown
+        assert_eq!(LineBreak::of('\u{2FFFD}'), LineBreak::Ideographic);
+
+        assert_eq!(LineBreak::ZWSpace.abbr_name(), "ZW");
+        assert_eq!(LineBreak::BreakAfter.long_name(), "Break_After");
+        assert_eq!(LineBreak::BreakAfter.human_name(), "Break After");
+    }
+}
@@ -0,0 +1,2853 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated by tools/generate_ucd_tables.pl from the Unicode Character Database 13.0.0
+// (LineBreak.txt and PropertyValueAliases.txt).
+
+use super::LineBreak;
+
+pub(super) const VALUES: &[LineBreak] = &[
+    LineBreak::Ambiguous,
+    LineBreak::Alphabetic,
+    LineBreak::BreakBoth,
+    LineBreak::BreakAfter,
+    LineBreak::BreakBefore,
+    LineBreak::MandatoryBreak,
+    LineBreak::ContingentBreak,
+    LineBreak::ConditionalJapaneseStarter,
+    LineBreak::ClosePunctuation,
+    LineBreak::CombiningMark,
+    LineBreak::CloseParenthesis,
+    LineBreak::CarriageReturn,
+    LineBreak::EBase,
+    LineBreak::EModifier,
+    LineBreak::Exclamation,
+    LineBreak::Glue,
+    LineBreak::H2,
+    LineBreak::H3,
+    LineBreak::HebrewLetter,
+    LineBreak::Hyphen,
+    LineBreak::Ideographic,
+    LineBreak::Inseparable,
+    LineBreak::InfixNumeric,
+    LineBreak::JL,
+    LineBreak::JT,
+    LineBreak::JV,
+    LineBreak::LineFeed,
+    LineBreak::NextLine,
+    LineBreak::Nonstarter,
+    LineBreak::Numeric,
+    LineBreak::OpenPunctuation,
+    LineBreak::PostfixNumeric,
+    LineBreak::PrefixNumeric,
+    LineBreak::Quotation,
+    LineBreak::RegionalIndicator,
+    LineBreak::ComplexContext,
+    LineBreak::Surrogate,
+    LineBreak::Space,
+    LineBreak::BreakSymbols,
+    LineBreak::WordJoiner,
+    LineBreak::Unknown,
+    LineBreak::ZWSpace,
+    LineBreak::ZWJ,
+];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[
+    ("AI", "Ambiguous", "Ambiguous"),
+    ("AL", "Alphabetic", "Alphabetic"),
+    ("B2", "Break_Both", "Break Both"),
+    ("BA", "Break_After", "Break After"),
+    ("BB", "Break_Before", "Break Before"),
+    ("BK", "Mandatory_Break", "Mandatory Break"),
+    ("CB", "Contingent_Break", "Contingent Break"),
+    (
+        "CJ",
+        "Conditional_Japanese_Starter",
+        "Conditional Japanese Starter",
+    ),
+    ("CL", "Close_Punctuation", "Close Punctuation"),
+    ("CM", "Combining_Mark", "Combining Mark"),
+    ("CP", "Close_Parenthesis", "Close Parenthesis"),
+    ("CR", "Carriage_Return", "Carriage Return"),
+    ("EB", "E_Base", "E Base"),
+    ("EM", "E_Modifier", "E Modifier"),
+    ("EX", "Exclamation", "Exclamation"),
+    ("GL", "Glue", "Glue"),
+    ("H2", "H2", "H2"),
+    ("H3", "H3", "H3"),
+    ("HL", "Hebrew_Letter", "Hebrew Letter"),
+    ("HY", "Hyphen", "Hyphen"),
+    ("ID", "Ideographic", "Ideographic"),
+    ("IN", "Inseparable", "Inseparable"),
+    ("IS", "Infix_Numeric", "Infix Numeric"),
+    ("JL", "JL", "JL"),
+    ("JT", "JT", "JT"),
+    ("JV", "JV", "JV"),
+    ("LF", "Line_Feed", "Line Feed"),
+    ("NL", "Next_Line", "Next Line"),
+    ("NS", "Nonstarter", "Nonstarter"),
+    ("NU", "Numeric", "Numeric"),
+    ("OP", "Open_Punctuation", "Open Punctuation"),
+    ("PO", "Postfix_Numeric", "Postfix Numeric"),
+    ("PR", "Prefix_Numeric", "Prefix Numeric"),
+    ("QU", "Quotation", "Quotation"),
+    ("RI", "Regional_Indicator", "Regional Indicator"),
+    ("SA", "Complex_Context", "Complex Context"),
+    ("SG", "Surrogate", "Surrogate"),
+    ("SP", "Space", "Space"),
+    ("SY", "Break_Symbols", "Break Symbols"),
+    ("WJ", "Word_Joiner", "Word Joiner"),
+    ("XX", "Unknown", "Unknown"),
+    ("ZW", "ZWSpace", "ZWSpace"),
+    ("ZWJ", "ZWJ", "ZWJ"),
+];
+
+pub(super) const RANGES: &[(u32, u32, LineBreak)] = &[
+    (0x0000, 0x0008, LineBreak::CombiningMark),
+    (0x0009, 0x0009, LineBreak::BreakAfter),
+    (0x000A, 0x000A, LineBreak::LineFeed),
+    (0x000B, 0x000C, LineBreak::MandatoryBreak),
+    (0x000D, 0x000D, LineBreak::CarriageReturn),
+    (0x000E, 0x001F, LineBreak::CombiningMark),
+    (0x0020, 0x0020, LineBreak::Space),
+    (0x0021, 0x0021, LineBreak::Exclamation),
+    (0x0022, 0x0022, LineBreak::Quotation),
+    (0x0023, 0x0023, LineBreak::Alphabetic),
+    (0x0024, 0x0024, LineBreak::PrefixNumeric),
+    (0x0025, 0x0025, LineBreak::PostfixNumeric),
+    (0x0026, 0x0026, LineBreak::Alphabetic),
+    (0x0027, 0x0027, LineBreak::Quotation),
+    (0x0028, 0x0028, LineBreak::OpenPunctuation),
+    (0x0029, 0x0029, LineBreak::CloseParenthesis),
+    (0x002A, 0x002A, LineBreak::Alphabetic),
+    (0x002B, 0x002B, LineBreak::PrefixNumeric),
+    (0x002C, 0x002C, LineBreak::InfixNumeric),
+    (0x002D, 0x002D, LineBreak::Hyphen),
+    (0x002E, 0x002E, LineBreak::InfixNumeric),
+    (0x002F, 0x002F, LineBreak::BreakSymbols),
+    (0x0030, 0x0039, LineBreak::Numeric),
+    (0x003A, 0x003B, LineBreak::InfixNumeric),
+    (0x003C, 0x003E, LineBreak::Alphabetic),
+    (0x003F, 0x003F, LineBreak::Exclamation),
+    (0x0040, 0x005A, LineBreak::Alphabetic),
+    (0x005B, 0x005B, LineBreak::OpenPunctuation),
+    (0x005C, 0x005C, LineBreak::PrefixNumeric),
+    (0x005D, 0x005D, LineBreak::CloseParenthesis),
+    (0x005E, 0x007A, LineBreak::Alphabetic),
+    (0x007B, 0x007B, LineBreak::OpenPunctuation),
+    (0x007C, 0x007C, LineBreak::BreakAfter),
+    (0x007D, 0x007D, LineBreak::ClosePunctuation),
+    (0x007E, 0x007E, LineBreak::Alphabetic),
+    (0x007F, 0x0084, LineBreak::CombiningMark),
+    (0x0085, 0x0085, LineBreak::NextLine),
+    (0x0086, 0x009F, LineBreak::CombiningMark),
+    (0x00A0, 0x00A0, LineBreak::Glue),
+    (0x00A1, 0x00A1, LineBreak::OpenPunctuation),
+    (0x00A2, 0x00A2, LineBreak::PostfixNumeric),
+    (0x00A3, 0x00A5, LineBreak::PrefixNumeric),
+    (0x00A6, 0x00A6, LineBreak::Alphabetic),
+    (0x00A7, 0x00A8, LineBreak::Ambiguous),
+    (0x00A9, 0x00A9, LineBreak::Alphabetic),
+    (0x00AA, 0x00AA, LineBreak::Ambiguous),
+    (0x00AB, 0x00AB, LineBreak::Quotation),
+    (0x00AC, 0x00AC, LineBreak::Alphabetic),
+    (0x00AD, 0x00AD, LineBreak::BreakAfter),
+    (0x00AE, 0x00AF, LineBreak::Alphabetic),
+    (0x00B0, 0x00B0, LineBreak::PostfixNumeric),
+    (0x00B1, 0x00B1, LineBreak::PrefixNumeric),
+    (0x00B2, 0x00B3, LineBreak::Ambiguous),
+    (0x00B4, 0x00B4, LineBreak::BreakBefore),
+    (0x00B5, 0x00B5, LineBreak::Alphabetic),
+    (0x00B6, 0x00BA, LineBreak::Ambiguous),
+    (0x00BB, 0x00BB, LineBreak::Quotation),
+    (0x00BC, 0x00BE, LineBreak::Ambiguous),
+    (0x00BF, 0x00BF, LineBreak::OpenPunctuation),
+    (0x00C0, 0x00D6, LineBreak::Alphabetic),
+    (0x00D7, 0x00D7, LineBreak::Ambiguous),
+    (0x00D8, 0x00F6, LineBreak::Alphabetic),
+    (0x00F7, 0x00F7, LineBreak::Ambiguous),
+    (0x00F8, 0x02C6, LineBreak::Alphabetic),
+    (0x02C7, 0x02C7, LineBreak::Ambiguous),
+    (0x02C8, 0x02C8, LineBreak::BreakBefore),
+    (0x02C9, 0x02CB, LineBreak::Ambiguous),
+    (0x02CC, 0x02CC, LineBreak::BreakBefore),
+    (0x02CD, 0x02CD, LineBreak::Ambiguous),
+    (0x02CE, 0x02CF, LineBreak::Alphabetic),
+    (0x02D0, 0x02D0, LineBreak::Ambiguous),
+    (0x02D1, 0x02D7, LineBreak::Alphabetic),
+    (0x02D8, 0x02DB, LineBreak::Ambiguous),
+    (0x02DC, 0x02DC, LineBreak::Alphabetic),
+    (0x02DD, 0x02DD, LineBreak::Ambiguous),
+    (0x02DE, 0x02DE, LineBreak::Alphabetic),
+    (0x02DF, 0x02DF, LineBreak::BreakBefore),
+    (0x02E0, 0x02FF, LineBreak::Alphabetic),
+    (0x0300, 0x034E, LineBreak::CombiningMark),
+    (0x034F, 0x034F, LineBreak::Glue),
+    (0x0350, 0x035B, LineBreak::CombiningMark),
+    (0x035C, 0x0362, LineBreak::Glue),
+    (0x0363, 0x036F, LineBreak::CombiningMark),
+    (0x0370, 0x0377, LineBreak::Alphabetic),
+    (0x037A, 0x037D, LineBreak::Alphabetic),
+    (0x037E, 0x037E, LineBreak::InfixNumeric),
+    (0x037F, 0x037F, LineBreak::Alphabetic),
+    (0x0384, 0x038A, LineBreak::Alphabetic),
+    (0x038C, 0x038C, LineBreak::Alphabetic),
+    (0x038E, 0x03A1, LineBreak::Alphabetic),
+    (0x03A3, 0x0482, LineBreak::Alphabetic),
+    (0x0483, 0x0489, LineBreak::CombiningMark),
+    (0x048A, 0x052F, LineBreak::Alphabetic),
+    (0x0531, 0x0556, LineBreak::Alphabetic),
+    (0x0559, 0x0588, LineBreak::Alphabetic),
+    (0x0589, 0x0589, LineBreak::InfixNumeric),
+    (0x058A, 0x058A, LineBreak::BreakAfter),
+    (0x058D, 0x058E, LineBreak::Alphabetic),
+    (0x058F, 0x058F, LineBreak::PrefixNumeric),
+    (0x0591, 0x05BD, LineBreak::CombiningMark),
+    (0x05BE, 0x05BE, LineBreak::BreakAfter),
+    (0x05BF, 0x05BF, LineBreak::CombiningMark),
+    (0x05C0, 0x05C0, LineBreak::Alphabetic),
+    (0x05C1, 0x05C2, LineBreak::CombiningMark),
+    (0x05C3, 0x05C3, LineBreak::Alphabetic),
+    (0x05C4, 0x05C5, LineBreak::CombiningMark),
+    (0x05C6, 0x05C6, LineBreak::Exclamation),
+    (0x05C7, 0x05C7, LineBreak::CombiningMark),
+    (0x05D0, 0x05EA, LineBreak::HebrewLetter),
+    (0x05EF, 0x05F2, LineBreak::HebrewLetter),
+    (0x05F3, 0x05F4, LineBreak::Alphabetic),
+    (0x0600, 0x0608, LineBreak::Alphabetic),
+    (0x0609, 0x060B, LineBreak::PostfixNumeric),
+    (0x060C, 0x060D, LineBreak::InfixNumeric),
+    (0x060E, 0x060F, LineBreak::Alphabetic),
+    (0x0610, 0x061A, LineBreak::CombiningMark),
+    (0x061B, 0x061B, LineBreak::Exclamation),
+    (0x061C, 0x061C, LineBreak::CombiningMark),
+    (0x061E, 0x061F, LineBreak::Exclamation),
+    (0x0620, 0x064A, LineBreak::Alphabetic),
+    (0x064B, 0x065F, LineBreak::CombiningMark),
+    (0x0660, 0x0669, LineBreak::Numeric),
+    (0x066A, 0x066A, LineBreak::PostfixNumeric),
+    (0x066B, 0x066C, LineBreak::Numeric),
+    (0x066D, 0x066F, LineBreak::Alphabetic),
+    (0x0670, 0x0670, LineBreak::CombiningMark),
+    (0x0671, 0x06D3, LineBreak::Alphabetic),
+    (0x06D4, 0x06D4, LineBreak::Exclamation),
+    (0x06D5, 0x06D5, LineBreak::Alphabetic),
+    (0x06D6, 0x06DC, LineBreak::CombiningMark),
+    (0x06DD, 0x06DE, LineBreak::Alphabetic),
+    (0x06DF, 0x06E4, LineBreak::CombiningMark),
+    (0x06E5, 0x06E6, LineBreak::Alphabetic),
+    (0x06E7, 0x06E8, LineBreak::CombiningMark),
+    (0x06E9, 0x06E9, LineBreak::Alphabetic),
+    (0x06EA, 0x06ED, LineBreak::CombiningMark),
+    (0x06EE, 0x06EF, LineBreak::Alphabetic),
+    (0x06F0, 0x06F9, LineBreak::Numeric),
+    (0x06FA, 0x070D, LineBreak::Alphabetic),
+    (0x070F, 0x0710, LineBreak::Alphabetic),
+    (0x0711, 0x0711, LineBreak::CombiningMark),
+    (0x0712, 0x072F, LineBreak::Alphabetic),
+    (0x0730, 0x074A, LineBreak::CombiningMark),
+    (0x074D, 0x07A5, LineBreak::Alphabetic),
+    (0x07A6, 0x07B0, LineBreak::CombiningMark),
+    (0x07B1, 0x07B1, LineBreak::Alphabetic),
+    (0x07C0, 0x07C9, LineBreak::Numeric),
+    (0x07CA, 0x07EA, LineBreak::Alphabetic),
+    (0x07EB, 0x07F3, LineBreak::CombiningMark),
+    (0x07F4, 0x07F7, LineBreak::Alphabetic),
+    (0x07F8, 0x07F8, LineBreak::InfixNumeric),
+    (0x07F9, 0x07F9, LineBreak::Exclamation),
+    (0x07FA, 0x07FA, LineBreak::Alphabetic),
+    (0x07FD, 0x07FD, LineBreak::CombiningMark),
+    (0x07FE, 0x07FF, LineBreak::PrefixNumeric),
+    (0x0800, 0x0815, LineBreak::Alphabetic),
+    (0x0816, 0x0819, LineBreak::CombiningMark),
+    (0x081A, 0x081A, LineBreak::Alphabetic),
+    (0x081B, 0x0823, LineBreak::CombiningMark),
+    (0x0824, 0x0824, LineBreak::Alphabetic),
+    (0x0825, 0x0827, LineBreak::CombiningMark),
+    (0x0828, 0x0828, LineBreak::Alphabetic),
+    (0x0829, 0x082D, LineBreak::CombiningMark),
+    (0x0830, 0x083E, LineBreak::Alphabetic),
+    (0x0840, 0x0858, LineBreak::Alphabetic),
+    (0x0859, 0x085B, LineBreak::CombiningMark),
+    (0x085E, 0x085E, LineBreak::Alphabetic),
+    (0x0860, 0x086A, LineBreak::Alphabetic),
+    (0x08A0, 0x08B4, LineBreak::Alphabetic),
+    (0x08B6, 0x08C7, LineBreak::Alphabetic),
+    (0x08D3, 0x08E1, LineBreak::CombiningMark),
+    (0x08E2, 0x08E2, LineBreak::Alphabetic),
+    (0x08E3, 0x0903, LineBreak::CombiningMark),
+    (0x0904, 0x0939, LineBreak::Alphabetic),
+    (0x093A, 0x093C, LineBreak::CombiningMark),
+    (0x093D, 0x093D, LineBreak::Alphabetic),
+    (0x093E, 0x094F, LineBreak::CombiningMark),
+    (0x0950, 0x0950, LineBreak::Alphabetic),
+    (0x0951, 0x0957, LineBreak::CombiningMark),
+    (0x0958, 0x0961, LineBreak::Alphabetic),
+    (0x0962, 0x0963, LineBreak::CombiningMark),
+    (0x0964, 0x0965, LineBreak::BreakAfter),
+    (0x0966, 0x096F, LineBreak::Numeric),
+    (0x0970, 0x0980, LineBreak::Alphabetic),
+    (0x0981, 0x0983, LineBreak::CombiningMark),
+    (0x0985, 0x098C, LineBreak::Alphabetic),
+    (0x098F, 0x0990, LineBreak::Alphabetic),
+    (0x0993, 0x09A8, LineBreak::Alphabetic),
+    (0x09AA, 0x09B0, LineBreak::Alphabetic),
+    (0x09B2, 0x09B2, LineBreak::Alphabetic),
+    (0x09B6, 0x09B9, LineBreak::Alphabetic),
+    (0x09BC, 0x09BC, LineBreak::CombiningMark),
+    (0x09BD, 0x09BD, LineBreak::Alphabetic),
+    (0x09BE, 0x09C4, LineBreak::CombiningMark),
+    (0x09C7, 0x09C8, LineBreak::CombiningMark),
+    (0x09CB, 0x09CD, LineBreak::CombiningMark),
+    (0x09CE, 0x09CE, LineBreak::Alphabetic),
+    (0x09D7, 0x09D7, LineBreak::CombiningMark),
+    (0x09DC, 0x09DD, LineBreak::Alphabetic),
+    (0x09DF, 0x09E1, LineBreak::Alphabetic),
+    (0x09E2, 0x09E3, LineBreak::CombiningMark),
+    (0x09E6, 0x09EF, LineBreak::Numeric),
+    (0x09F0, 0x09F1, LineBreak::Alphabetic),
+    (0x09F2, 0x09F3, LineBreak::PostfixNumeric),
+    (0x09F4, 0x09F8, LineBreak::Alphabetic),
+    (0x09F9, 0x09F9, LineBreak::PostfixNumeric),
+    (0x09FA, 0x09FA, LineBreak::Alphabetic),
+    (0x09FB, 0x09FB, LineBreak::PrefixNumeric),
+    (0x09FC, 0x09FD, LineBreak::Alphabetic),
+    (0x09FE, 0x09FE, LineBreak::CombiningMark),
+    (0x0A01, 0x0A03, LineBreak::CombiningMark),
+    (0x0A05, 0x0A0A, LineBreak::Alphabetic),
+    (0x0A0F, 0x0A10, LineBreak::Alphabetic),
+    (0x0A13, 0x0A28, LineBreak::Alphabetic),
+    (0x0A2A, 0x0A30, LineBreak::Alphabetic),
+    (0x0A32, 0x0A33, LineBreak::Alphabetic),
+    (0x0A35, 0x0A36, LineBreak::Alphabetic),
+    (0x0A38, 0x0A39, LineBreak::Alphabetic),
+    (0x0A3C, 0x0A3C, LineBreak::CombiningMark),
+    (0x0A3E, 0x0A42, LineBreak::CombiningMark),
+    (0x0A47, 0x0A48, LineBreak::CombiningMark),
+    (0x0A4B, 0x0A4D, LineBreak::CombiningMark),
+    (0x0A51, 0x0A51, LineBreak::CombiningMark),
+    (0x0A59, 0x0A5C, LineBreak::Alphabetic),
+    (0x0A5E, 0x0A5E, LineBreak::Alphabetic),
+    (0x0A66, 0x0A6F, LineBreak::Numeric),
+    (0x0A70, 0x0A71, LineBreak::CombiningMark),
+    (0x0A72, 0x0A74, LineBreak::Alphabetic),
+    (0x0A75, 0x0A75, LineBreak::CombiningMark),
+    (0x0A76, 0x0A76, LineBreak::Alphabetic),
+    (0x0A81, 0x0A83, LineBreak::CombiningMark),
+    (0x0A85, 0x0A8D, LineBreak::Alphabetic),
+    (0x0A8F, 0x0A91, LineBreak::Alphabetic),
+    (0x0A93, 0x0AA8, LineBreak::Alphabetic),
+    (0x0AAA, 0x0AB0, LineBreak::Alphabetic),
+    (0x0AB2, 0x0AB3, LineBreak::Alphabetic),
+    (0x0AB5, 0x0AB9, LineBreak::Alphabetic),
+    (0x0ABC, 0x0ABC, LineBreak::CombiningMark),
+    (0x0ABD, 0x0ABD, LineBreak::Alphabetic),
+    (0x0ABE, 0x0AC5, LineBreak::CombiningMark),
+    (0x0AC7, 0x0AC9, LineBreak::CombiningMark),
+    (0x0ACB, 0x0ACD, LineBreak::CombiningMark),
+    (0x0AD0, 0x0AD0, LineBreak::Alphabetic),
+    (0x0AE0, 0x0AE1, LineBreak::Alphabetic),
+    (0x0AE2, 0x0AE3, LineBreak::CombiningMark),
+    (0x0AE6, 0x0AEF, LineBreak::Numeric),
+    (0x0AF0, 0x0AF0, LineBreak::Alphabetic),
+    (0x0AF1, 0x0AF1, LineBreak::PrefixNumeric),
+    (0x0AF9, 0x0AF9, LineBreak::Alphabetic),
+    (0x0AFA, 0x0AFF, LineBreak::CombiningMark),
+    (0x0B01, 0x0B03, LineBreak::CombiningMark),
+    (0x0B05, 0x0B0C, LineBreak::Alphabetic),
+    (0x0B0F, 0x0B10, LineBreak::Alphabetic),
+    (0x0B13, 0x0B28, LineBreak::Alphabetic),
+    (0x0B2A, 0x0B30, LineBreak::Alphabetic),
+    (0x0B32, 0x0B33, LineBreak::Alphabetic),
+    (0x0B35, 0x0B39, LineBreak::Alphabetic),
+    (0x0B3C, 0x0B3C, LineBreak::CombiningMark),
+    (0x0B3D, 0x0B3D, LineBreak::Alphabetic),
+    (0x0B3E, 0x0B44, LineBreak::CombiningMark),
+    (0x0B47, 0x0B48, LineBreak::CombiningMark),
+    (0x0B4B, 0x0B4D, LineBreak::CombiningMark),
+    (0x0B55, 0x0B57, LineBreak::CombiningMark),
+    (0x0B5C, 0x0B5D, LineBreak::Alphabetic),
+    (0x0B5F, 0x0B61, LineBreak::Alphabetic),
+    (0x0B62, 0x0B63, LineBreak::CombiningMark),
+    (0x0B66, 0x0B6F, LineBreak::Numeric),
+    (0x0B70, 0x0B77, LineBreak::Alphabetic),
+    (0x0B82, 0x0B82, LineBreak::CombiningMark),
+    (0x0B83, 0x0B83, LineBreak::Alphabetic),
+    (0x0B85, 0x0B8A, LineBreak::Alphabetic),
+    (0x0B8E, 0x0B90, LineBreak::Alphabetic),
+    (0x0B92, 0x0B95, LineBreak::Alphabetic),
+    (0x0B99, 0x0B9A, LineBreak::Alphabetic),
+    (0x0B9C, 0x0B9C, LineBreak::Alphabetic),
+    (0x0B9E, 0x0B9F, LineBreak::Alphabetic),
+    (0x0BA3, 0x0BA4, LineBreak::Alphabetic),
+    (0x0BA8, 0x0BAA, LineBreak::Alphabetic),
+    (0x0BAE, 0x0BB9, LineBreak::Alphabetic),
+    (0x0BBE, 0x0BC2, LineBreak::CombiningMark),
+    (0x0BC6, 0x0BC8, LineBreak::CombiningMark),
+    (0x0BCA, 0x0BCD, LineBreak::CombiningMark),
+    (0x0BD0, 0x0BD0, LineBreak::Alphabetic),
+    (0x0BD7, 0x0BD7, LineBreak::CombiningMark),
+    (0x0BE6, 0x0BEF, LineBreak::Numeric),
+    (0x0BF0, 0x0BF8, LineBreak::Alphabetic),
+    (0x0BF9, 0x0BF9, LineBreak::PrefixNumeric),
+    (0x0BFA, 0x0BFA, LineBreak::Alphabetic),
+    (0x0C00, 0x0C04, LineBreak::CombiningMark),
+    (0x0C05, 0x0C0C, LineBreak::Alphabetic),
+    (0x0C0E, 0x0C10, LineBreak::Alphabetic),
+    (0x0C12, 0x0C28, LineBreak::Alphabetic),
+    (0x0C2A, 0x0C39, LineBreak::Alphabetic),
+    (0x0C3D, 0x0C3D, LineBreak::Alphabetic),
+    (0x0C3E, 0x0C44, LineBreak::CombiningMark),
+    (0x0C46, 0x0C48, LineBreak::CombiningMark),
+    (0x0C4A, 0x0C4D, LineBreak::CombiningMark),
+    (0x0C55, 0x0C56, LineBreak::CombiningMark),
+    (0x0C58, 0x0C5A, LineBreak::Alphabetic),
+    (0x0C60, 0x0C61, LineBreak::Alphabetic),
+    (0x0C62, 0x0C63, LineBreak::CombiningMark),
+    (0x0C66, 0x0C6F, LineBreak::Numeric),
+    (0x0C77, 0x0C77, LineBreak::BreakBefore),
+    (0x0C78, 0x0C80, LineBreak::Alphabetic),
+    (0x0C81, 0x0C83, LineBreak::CombiningMark),
+    (0x0C84, 0x0C84, LineBreak::BreakBefore),
+    (0x0C85, 0x0C8C, LineBreak::Alphabetic),
+    (0x0C8E, 0x0C90, LineBreak::Alphabetic),
+    (0x0C92, 0x0CA8, LineBreak::Alphabetic),
+    (0x0CAA, 0x0CB3, LineBreak::Alphabetic),
+    (0x0CB5, 0x0CB9, LineBreak::Alphabetic),
+    (0x0CBC, 0x0CBC, LineBreak::CombiningMark),
+    (0x0CBD, 0x0CBD, LineBreak::Alphabetic),
+    (0x0CBE, 0x0CC4, LineBreak::CombiningMark),
+    (0x0CC6, 0x0CC8, LineBreak::CombiningMark),
+    (0x0CCA, 0x0CCD, LineBreak::CombiningMark),
+    (0x0CD5, 0x0CD6, LineBreak::CombiningMark),
+    (0x0CDE, 0x0CDE, LineBreak::Alphabetic),
+    (0x0CE0, 0x0CE1, LineBreak::Alphabetic),
+    (0x0CE2, 0x0CE3, LineBreak::CombiningMark),
+    (0x0CE6, 0x0CEF, LineBreak::Numeric),
+    (0x0CF1, 0x0CF2, LineBreak::Alphabetic),
+    (0x0D00, 0x0D03, LineBreak::CombiningMark),
+    (0x0D04, 0x0D0C, LineBreak::Alphabetic),
+    (0x0D0E, 0x0D10, LineBreak::Alphabetic),
+    (0x0D12, 0x0D3A, LineBreak::Alphabetic),
+    (0x0D3B, 0x0D3C, LineBreak::CombiningMark),
+    (0x0D3D, 0x0D3D, LineBreak::Alphabetic),
+    (0x0D3E, 0x0D44, LineBreak::CombiningMark),
+    (0x0D46, 0x0D48, LineBreak::CombiningMark),
+    (0x0D4A, 0x0D4D, LineBreak::CombiningMark),
+    (0x0D4E, 0x0D4F, LineBreak::Alphabetic),
+    (0x0D54, 0x0D56, LineBreak::Alphabetic),
+    (0x0D57, 0x0D57, LineBreak::CombiningMark),
+    (0x0D58, 0x0D61, LineBreak::Alphabetic),
+    (0x0D62, 0x0D63, LineBreak::CombiningMark),
+    (0x0D66, 0x0D6F, LineBreak::Numeric),
+    (0x0D70, 0x0D78, LineBreak::Alphabetic),
+    (0x0D79, 0x0D79, LineBreak::PostfixNumeric),
+    (0x0D7A, 0x0D7F, LineBreak::Alphabetic),
+    (0x0D81, 0x0D83, LineBreak::CombiningMark),
+    (0x0D85, 0x0D96, LineBreak::Alphabetic),
+    (0x0D9A, 0x0DB1, LineBreak::Alphabetic),
+    (0x0DB3, 0x0DBB, LineBreak::Alphabetic),
+    (0x0DBD, 0x0DBD, LineBreak::Alphabetic),
+    (0x0DC0, 0x0DC6, LineBreak::Alphabetic),
+    (0x0DCA, 0x0DCA, LineBreak::CombiningMark),
+    (0x0DCF, 0x0DD4, LineBreak::CombiningMark),
+    (0x0DD6, 0x0DD6, LineBreak::CombiningMark),
+    (0x0DD8, 0x0DDF, LineBreak::CombiningMark),
+    (0x0DE6, 0x0DEF, LineBreak::Numeric),
+    (0x0DF2, 0x0DF3, LineBreak::CombiningMark),
+    (0x0DF4, 0x0DF4, LineBreak::Alphabetic),
+    (0x0E01, 0x0E3A, LineBreak::ComplexContext),
+    (0x0E3F, 0x0E3F, LineBreak::PrefixNumeric),
+    (0x0E40, 0x0E4E, LineBreak::ComplexContext),
+    (0x0E4F, 0x0E4F, LineBreak::Alphabetic),
+    (0x0E50, 0x0E59, LineBreak::Numeric),
+    (0x0E5A, 0x0E5B, LineBreak::BreakAfter),
+    (0x0E81, 0x0E82, LineBreak::ComplexContext),
+    (0x0E84, 0x0E84, LineBreak::ComplexContext),
+    (0x0E86, 0x0E8A, LineBreak::ComplexContext),
+    (0x0E8C, 0x0EA3, LineBreak::ComplexContext),
+    (0x0EA5, 0x0EA5, LineBreak::ComplexContext),
+    (0x0EA7, 0x0EBD, LineBreak::ComplexContext),
+    (0x0EC0, 0x0EC4, LineBreak::ComplexContext),
+    (0x0EC6, 0x0EC6, LineBreak::ComplexContext),
+    (0x0EC8, 0x0ECD, LineBreak::ComplexContext),
+    (0x0ED0, 0x0ED9, LineBreak::Numeric),
+    (0x0EDC, 0x0EDF, LineBreak::ComplexContext),
+    (0x0F00, 0x0F00, LineBreak::Alphabetic),
+    (0x0F01, 0x0F04, LineBreak::BreakBefore),
+    (0x0F05, 0x0F05, LineBreak::Alphabetic),
+    (0x0F06, 0x0F07, LineBreak::BreakBefore),
+    (0x0F08, 0x0F08, LineBreak::Glue),
+    (0x0F09, 0x0F0A, LineBreak::BreakBefore),
+    (0x0F0B, 0x0F0B, LineBreak::BreakAfter),
+    (0x0F0C, 0x0F0C, LineBreak::Glue),
+    (0x0F0D, 0x0F11, LineBreak::Exclamation),
+    (0x0F12, 0x0F12, LineBreak::Glue),
+    (0x0F13, 0x0F13, LineBreak::Alphabetic),
+    (0x0F14, 0x0F14, LineBreak::Exclamation),
+    (0x0F15, 0x0F17, LineBreak::Alphabetic),
+    (0x0F18, 0x0F19, LineBreak::CombiningMark),
+    (0x0F1A, 0x0F1F, LineBreak::Alphabetic),
+    (0x0F20, 0x0F29, LineBreak::Numeric),
+    (0x0F2A, 0x0F33, LineBreak::Alphabetic),
+    (0x0F34, 0x0F34, LineBreak::BreakAfter),
+    (0x0F35, 0x0F35, LineBreak::CombiningMark),
+    (0x0F36, 0x0F36, LineBreak::Alphabetic),
+    (0x0F37, 0x0F37, LineBreak::CombiningMark),
+    (0x0F38, 0x0F38, LineBreak::Alphabetic),
+    (0x0F39, 0x0F39, LineBreak::CombiningMark),
+    (0x0F3A, 0x0F3A, LineBreak::OpenPunctuation),
+    (0x0F3B, 0x0F3B, LineBreak::ClosePunctuation),
+    (0x0F3C, 0x0F3C, LineBreak::OpenPunctuation),
+    (0x0F3D, 0x0F3D, LineBreak::ClosePunctuation),
+    (0x0F3E, 0x0F3F, LineBreak::CombiningMark),
+    (0x0F40, 0x0F47, LineBreak::Alphabetic),
+    (0x0F49, 0x0F6C, LineBreak::Alphabetic),
+    (0x0F71, 0x0F7E, LineBreak::CombiningMark),
+    (0x0F7F, 0x0F7F, LineBreak::BreakAfter),
+    (0x0F80, 0x0F84, LineBreak::CombiningMark),
+    (0x0F85, 0x0F85, LineBreak::BreakAfter),
+    (0x0F86, 0x0F87, LineBreak::CombiningMark),
+    (0x0F88, 0x0F8C, LineBreak::Alphabetic),
+    (0x0F8D, 0x0F97, LineBreak::CombiningMark),
+    (0x0F99, 0x0FBC, LineBreak::CombiningMark),
+    (0x0FBE, 0x0FBF, LineBreak::BreakAfter),
+    (0x0FC0, 0x0FC5, LineBreak::Alphabetic),
+    (0x0FC6, 0x0FC6, LineBreak::CombiningMark),
+    (0x0FC7, 0x0FCC, LineBreak::Alphabetic),
+    (0x0FCE, 0x0FCF, LineBreak::Alphabetic),
+    (0x0FD0, 0x0FD1, LineBreak::BreakBefore),
+    (0x0FD2, 0x0FD2, LineBreak::BreakAfter),
+    (0x0FD3, 0x0FD3, LineBreak::BreakBefore),
+    (0x0FD4, 0x0FD8, LineBreak::Alphabetic),
+    (0x0FD9, 0x0FDA, LineBreak::Glue),
+    (0x1000, 0x103F, LineBreak::ComplexContext),
+    (0x1040, 0x1049, LineBreak::Numeric),
+    (0x104A, 0x104B, LineBreak::BreakAfter),
+    (0x104C, 0x104F, LineBreak::Alphabetic),
+    (0x1050, 0x108F, LineBreak::ComplexContext),
+    (0x1090, 0x1099, LineBreak::Numeric),
+    (0x109A, 0x109F, LineBreak::ComplexContext),
+    (0x10A0, 0x10C5, LineBreak::Alphabetic),
+    (0x10C7, 0x10C7, LineBreak::Alphabetic),
+    (0x10CD, 0x10CD, LineBreak::Alphabetic),
+    (0x10D0, 0x10FF, LineBreak::Alphabetic),
+    (0x1100, 0x115F, LineBreak::JL),
+    (0x1160, 0x11A7, LineBreak::JV),
+    (0x11A8, 0x11FF, LineBreak::JT),
+    (0x1200, 0x1248, LineBreak::Alphabetic),
+    (0x124A, 0x124D, LineBreak::Alphabetic),
+    (0x1250, 0x1256, LineBreak::Alphabetic),
+    (0x1258, 0x1258, LineBreak::Alphabetic),
+    (0x125A, 0x125D, LineBreak::Alphabetic),
+    (0x1260, 0x1288, LineBreak::Alphabetic),
+    (0x128A, 0x128D, LineBreak::Alphabetic),
+    (0x1290, 0x12B0, LineBreak::Alphabetic),
+    (0x12B2, 0x12B5, LineBreak::Alphabetic),
+    (0x12B8, 0x12BE, LineBreak::Alphabetic),
+    (0x12C0, 0x12C0, LineBreak::Alphabetic),
+    (0x12C2, 0x12C5, LineBreak::Alphabetic),
+    (0x12C8, 0x12D6, LineBreak::Alphabetic),
+    (0x12D8, 0x1310, LineBreak::Alphabetic),
+    (0x1312, 0x1315, LineBreak::Alphabetic),
+    (0x1318, 0x135A, LineBreak::Alphabetic),
+    (0x135D, 0x135F, LineBreak::CombiningMark),
+    (0x1360, 0x1360, LineBreak::Alphabetic),
+    (0x1361, 0x1361, LineBreak::BreakAfter),
+    (0x1362, 0x137C, LineBreak::Alphabetic),
+    (0x1380, 0x1399, LineBreak::Alphabetic),
+    (0x13A0, 0x13F5, LineBreak::Alphabetic),
+    (0x13F8, 0x13FD, LineBreak::Alphabetic),
+    (0x1400, 0x1400, LineBreak::BreakAfter),
+    (0x1401, 0x167F, LineBreak::Alphabetic),
+    (0x1680, 0x1680, LineBreak::BreakAfter),
+    (0x1681, 0x169A, LineBreak::Alphabetic),
+    (0x169B, 0x169B, LineBreak::OpenPunctuation),
+    (0x169C, 0x169C, LineBreak::ClosePunctuation),
+    (0x16A0, 0x16EA, LineBreak::Alphabetic),
+    (0x16EB, 0x16ED, LineBreak::BreakAfter),
+    (0x16EE, 0x16F8, LineBreak::Alphabetic),
+    (0x1700, 0x170C, LineBreak::Alphabetic),
+    (0x170E, 0x1711, LineBreak::Alphabetic),
+    (0x1712, 0x1714, LineBreak::CombiningMark),
+    (0x1720, 0x1731, LineBreak::Alphabetic),
+    (0x1732, 0x1734, LineBreak::CombiningMark),
+    (0x1735, 0x1736, LineBreak::BreakAfter),
+    (0x1740, 0x1751, LineBreak::Alphabetic),
+    (0x1752, 0x1753, LineBreak::CombiningMark),
+    (0x1760, 0x176C, LineBreak::Alphabetic),
+    (0x176E, 0x1770, LineBreak::Alphabetic),
+    (0x1772, 0x1773, LineBreak::CombiningMark),
+    (0x1780, 0x17D3, LineBreak::ComplexContext),
+    (0x17D4, 0x17D5, LineBreak::BreakAfter),
+    (0x17D6, 0x17D6, LineBreak::Nonstarter),
+    (0x17D7, 0x17D7, LineBreak::ComplexContext),
+    (0x17D8, 0x17D8, LineBreak::BreakAfter),
+    (0x17D9, 0x17D9, LineBreak::Alphabetic),
+    (0x17DA, 0x17DA, LineBreak::BreakAfter),
+    (0x17DB, 0x17DB, LineBreak::PrefixNumeric),
+    (0x17DC, 0x17DD, LineBreak::ComplexContext),
+    (0x17E0, 0x17E9, LineBreak::Numeric),
+    (0x17F0, 0x17F9, LineBreak::Alphabetic),
+    (0x1800, 0x1801, LineBreak::Alphabetic),
+    (0x1802, 0x1803, LineBreak::Exclamation),
+    (0x1804, 0x1805, LineBreak::BreakAfter),
+    (0x1806, 0x1806, LineBreak::BreakBefore),
+    (0x1807, 0x1807, LineBreak::Alphabetic),
+    (0x1808, 0x1809, LineBreak::Exclamation),
+    (0x180A, 0x180A, LineBreak::Alphabetic),
+    (0x180B, 0x180D, LineBreak::CombiningMark),
+    (0x180E, 0x180E, LineBreak::Glue),
+    (0x1810, 0x1819, LineBreak::Numeric),
+    (0x1820, 0x1878, LineBreak::Alphabetic),
+    (0x1880, 0x1884, LineBreak::Alphabetic),
+    (0x1885, 0x1886, LineBreak::CombiningMark),
+    (0x1887, 0x18A8, LineBreak::Alphabetic),
+    (0x18A9, 0x18A9, LineBreak::CombiningMark),
+    (0x18AA, 0x18AA, LineBreak::Alphabetic),
+    (0x18B0, 0x18F5, LineBreak::Alphabetic),
+    (0x1900, 0x191E, LineBreak::Alphabetic),
+    (0x1920, 0x192B, LineBreak::CombiningMark),
+    (0x1930, 0x193B, LineBreak::CombiningMark),
+    (0x1940, 0x1940, LineBreak::Alphabetic),
+    (0x1944, 0x1945, LineBreak::Exclamation),
+    (0x1946, 0x194F, LineBreak::Numeric),
+    (0x1950, 0x196D, LineBreak::ComplexContext),
+    (0x1970, 0x1974, LineBreak::ComplexContext),
+    (0x1980, 0x19AB, LineBreak::ComplexContext),
+    (0x19B0, 0x19C9, LineBreak::ComplexContext),
+    (0x19D0, 0x19D9, LineBreak::Numeric),
+    (0x19DA, 0x19DA, LineBreak::ComplexContext),
+    (0x19DE, 0x19DF, LineBreak::ComplexContext),
+    (0x19E0, 0x1A16, LineBreak::Alphabetic),
+    (0x1A17, 0x1A1B, LineBreak::CombiningMark),
+    (0x1A1E, 0x1A1F, LineBreak::Alphabetic),
+    (0x1A20, 0x1A5E, LineBreak::ComplexContext),
+    (0x1A60, 0x1A7C, LineBreak::ComplexContext),
+    (0x1A7F, 0x1A7F, LineBreak::CombiningMark),
+    (0x1A80, 0x1A89, LineBreak::Numeric),
+    (0x1A90, 0x1A99, LineBreak::Numeric),
+    (0x1AA0, 0x1AAD, LineBreak::ComplexContext),
+    (0x1AB0, 0x1AC0, LineBreak::CombiningMark),
+    (0x1B00, 0x1B04, LineBreak::CombiningMark),
+    (0x1B05, 0x1B33, LineBreak::Alphabetic),
+    (0x1B34, 0x1B44, LineBreak::CombiningMark),
+    (0x1B45, 0x1B4B, LineBreak::Alphabetic),
+    (0x1B50, 0x1B59, LineBreak::Numeric),
+    (0x1B5A, 0x1B5B, LineBreak::BreakAfter),
+    (0x1B5C, 0x1B5C, LineBreak::Alphabetic),
+    (0x1B5D, 0x1B60, LineBreak::BreakAfter),
+    (0x1B61, 0x1B6A, LineBreak::Alphabetic),
+    (0x1B6B, 0x1B73, LineBreak::CombiningMark),
+    (0x1B74, 0x1B7C, LineBreak::Alphabetic),
+    (0x1B80, 0x1B82, LineBreak::CombiningMark),
+    (0x1B83, 0x1BA0, LineBreak::Alphabetic),
+    (0x1BA1, 0x1BAD, LineBreak::CombiningMark),
+    (0x1BAE, 0x1BAF, LineBreak::Alphabetic),
+    (0x1BB0, 0x1BB9, LineBreak::Numeric),
+    (0x1BBA, 0x1BE5, LineBreak::Alphabetic),
+    (0x1BE6, 0x1BF3, LineBreak::CombiningMark),
+    (0x1BFC, 0x1C23, LineBreak::Alphabetic),
+    (0x1C24, 0x1C37, LineBreak::CombiningMark),
+    (0x1C3B, 0x1C3F, LineBreak::BreakAfter),
+    (0x1C40, 0x1C49, LineBreak::Numeric),
+    (0x1C4D, 0x1C4F, LineBreak::Alphabetic),
+    (0x1C50, 0x1C59, LineBreak::Numeric),
+    (0x1C5A, 0x1C7D, LineBreak::Alphabetic),
+    (0x1C7E, 0x1C7F, LineBreak::BreakAfter),
+    (0x1C80, 0x1C88, LineBreak::Alphabetic),
+    (0x1C90, 0x1CBA, LineBreak::Alphabetic),
+    (0x1CBD, 0x1CC7, LineBreak::Alphabetic),
+    (0x1CD0, 0x1CD2, LineBreak::CombiningMark),
+    (0x1CD3, 0x1CD3, LineBreak::Alphabetic),
+    (0x1CD4, 0x1CE8, LineBreak::CombiningMark),
+    (0x1CE9, 0x1CEC, LineBreak::Alphabetic),
+    (0x1CED, 0x1CED, LineBreak::CombiningMark),
+    (0x1CEE, 0x1CF3, LineBreak::Alphabetic),
+    (0x1CF4, 0x1CF4, LineBreak::CombiningMark),
+    (0x1CF5, 0x1CF6, LineBreak::Alphabetic),
+    (0x1CF7, 0x1CF9, LineBreak::CombiningMark),
+    (0x1CFA, 0x1CFA, LineBreak::Alphabetic),
+    (0x1D00, 0x1DBF, LineBreak::Alphabetic),
+    (0x1DC0, 0x1DF9, LineBreak::CombiningMark),
+    (0x1DFB, 0x1DFF, LineBreak::CombiningMark),
+    (0x1E00, 0x1F15, LineBreak::Alphabetic),
+    (0x1F18, 0x1F1D, LineBreak::Alphabetic),
+    (0x1F20, 0x1F45, LineBreak::Alphabetic),
+    (0x1F48, 0x1F4D, LineBreak::Alphabetic),
+    (0x1F50, 0x1F57, LineBreak::Alphabetic),
+    (0x1F59, 0x1F59, LineBreak::Alphabetic),
+    (0x1F5B, 0x1F5B, LineBreak::Alphabetic),
+    (0x1F5D, 0x1F5D, LineBreak::Alphabetic),
+    (0x1F5F, 0x1F7D, LineBreak::Alphabetic),
+    (0x1F80, 0x1FB4, LineBreak::Alphabetic),
+    (0x1FB6, 0x1FC4, LineBreak::Alphabetic),
+    (0x1FC6, 0x1FD3, LineBreak::Alphabetic),
+    (0x1FD6, 0x1FDB, LineBreak::Alphabetic),
+    (0x1FDD, 0x1FEF, LineBreak::Alphabetic),
+    (0x1FF2, 0x1FF4, LineBreak::Alphabetic),
+    (0x1FF6, 0x1FFC, LineBreak::Alphabetic),
+    (0x1FFD, 0x1FFD, LineBreak::BreakBefore),
+    (0x1FFE, 0x1FFE, LineBreak::Alphabetic),
+    (0x2000, 0x2006, LineBreak::BreakAfter),
+    (0x2007, 0x2007, LineBreak::Glue),
+    (0x2008, 0x200A, LineBreak::BreakAfter),
+    (0x200B, 0x200B, LineBreak::ZWSpace),
+    (0x200C, 0x200C, LineBreak::CombiningMark),
+    (0x200D, 0x200D, LineBreak::ZWJ),
+    (0x200E, 0x200F, LineBreak::CombiningMark),
+    (0x2010, 0x2010, LineBreak::BreakAfter),
+    (0x2011, 0x2011, LineBreak::Glue),
+    (0x2012, 0x2013, LineBreak::BreakAfter),
+    (0x2014, 0x2014, LineBreak::BreakBoth),
+    (0x2015, 0x2016, LineBreak::Ambiguous),
+    (0x2017, 0x2017, LineBreak::Alphabetic),
+    (0x2018, 0x2019, LineBreak::Quotation),
+    (0x201A, 0x201A, LineBreak::OpenPunctuation),
+    (0x201B, 0x201D, LineBreak::Quotation),
+    (0x201E, 0x201E, LineBreak::OpenPunctuation),
+    (0x201F, 0x201F, LineBreak::Quotation),
+    (0x2020, 0x2021, LineBreak::Ambiguous),
+    (0x2022, 0x2023, LineBreak::Alphabetic),
+    (0x2024, 0x2026, LineBreak::Inseparable),
+    (0x2027, 0x2027, LineBreak::BreakAfter),
+    (0x2028, 0x2029, LineBreak::MandatoryBreak),
+    (0x202A, 0x202E, LineBreak::CombiningMark),
+    (0x202F, 0x202F, LineBreak::Glue),
+    (0x2030, 0x2037, LineBreak::PostfixNumeric),
+    (0x2038, 0x2038, LineBreak::Alphabetic),
+    (0x2039, 0x203A, LineBreak::Quotation),
+    (0x203B, 0x203B, LineBreak::Ambiguous),
+    (0x203C, 0x203D, LineBreak::Nonstarter),
+    (0x203E, 0x2043, LineBreak::Alphabetic),
+    (0x2044, 0x2044, LineBreak::InfixNumeric),
+    (0x2045, 0x2045, LineBreak::OpenPunctuation),
+    (0x2046, 0x2046, LineBreak::ClosePunctuation),
+    (0x2047, 0x2049, LineBreak::Nonstarter),
+    (0x204A, 0x2055, LineBreak::Alphabetic),
+    (0x2056, 0x2056, LineBreak::BreakAfter),
+    (0x2057, 0x2057, LineBreak::Alphabetic),
+    (0x2058, 0x205B, LineBreak::BreakAfter),
+    (0x205C, 0x205C, LineBreak::Alphabetic),
+    (0x205D, 0x205F, LineBreak::BreakAfter),
+    (0x2060, 0x2060, LineBreak::WordJoiner),
+    (0x2061, 0x2064, LineBreak::Alphabetic),
+    (0x2066, 0x206F, LineBreak::CombiningMark),
+    (0x2070, 0x2071, LineBreak::Alphabetic),
+    (0x2074, 0x2074, LineBreak::Ambiguous),
+    (0x2075, 0x207C, LineBreak::Alphabetic),
+    (0x207D, 0x207D, LineBreak::OpenPunctuation),
+    (0x207E, 0x207E, LineBreak::ClosePunctuation),
+    (0x207F, 0x207F, LineBreak::Ambiguous),
+    (0x2080, 0x2080, LineBreak::Alphabetic),
+    (0x2081, 0x2084, LineBreak::Ambiguous),
+    (0x2085, 0x208C, LineBreak::Alphabetic),
+    (0x208D, 0x208D, LineBreak::OpenPunctuation),
+    (0x208E, 0x208E, LineBreak::ClosePunctuation),
+    (0x2090, 0x209C, LineBreak::Alphabetic),
+    (0x20A0, 0x20A6, LineBreak::PrefixNumeric),
+    (0x20A7, 0x20A7, LineBreak::PostfixNumeric),
+    (0x20A8, 0x20B5, LineBreak::PrefixNumeric),
+    (0x20B6, 0x20B6, LineBreak::PostfixNumeric),
+    (0x20B7, 0x20BA, LineBreak::PrefixNumeric),
+    (0x20BB, 0x20BB, LineBreak::PostfixNumeric),
+    (0x20BC, 0x20BD, LineBreak::PrefixNumeric),
+    (0x20BE, 0x20BE, LineBreak::PostfixNumeric),
+    (0x20BF, 0x20CF, LineBreak::PrefixNumeric),
+    (0x20D0, 0x20F0, LineBreak::CombiningMark),
+    (0x2100, 0x2102, LineBreak::Alphabetic),
+    (0x2103, 0x2103, LineBreak::PostfixNumeric),
+    (0x2104, 0x2104, LineBreak::Alphabetic),
+    (0x2105, 0x2105, LineBreak::Ambiguous),
+    (0x2106, 0x2108, LineBreak::Alphabetic),
+    (0x2109, 0x2109, LineBreak::PostfixNumeric),
+    (0x210A, 0x2112, LineBreak::Alphabetic),
+    (0x2113, 0x2113, LineBreak::Ambiguous),
+    (0x2114, 0x2115, LineBreak::Alphabetic),
+    (0x2116, 0x2116, LineBreak::PrefixNumeric),
+    (0x2117, 0x2120, LineBreak::Alphabetic),
+    (0x2121, 0x2122, LineBreak::Ambiguous),
+    (0x2123, 0x212A, LineBreak::Alphabetic),
+    (0x212B, 0x212B, LineBreak::Ambiguous),
+    (0x212C, 0x2153, LineBreak::Alphabetic),
+    (0x2154, 0x2155, LineBreak::Ambiguous),
+    (0x2156, 0x215A, LineBreak::Alphabetic),
+    (0x215B, 0x215B, LineBreak::Ambiguous),
+    (0x215C, 0x215D, LineBreak::Alphabetic),
+    (0x215E, 0x215E, LineBreak::Ambiguous),
+    (0x215F, 0x215F, LineBreak::Alphabetic),
+    (0x2160, 0x216B, LineBreak::Ambiguous),
+    (0x216C, 0x216F, LineBreak::Alphabetic),
+    (0x2170, 0x2179, LineBreak::Ambiguous),
+    (0x217A, 0x2188, LineBreak::Alphabetic),
+    (0x2189, 0x2189, LineBreak::Ambiguous),
+    (0x218A, 0x218B, LineBreak::Alphabetic),
+    (0x2190, 0x2199, LineBreak::Ambiguous),
+    (0x219A, 0x21D1, LineBreak::Alphabetic),
+    (0x21D2, 0x21D2, LineBreak::Ambiguous),
+    (0x21D3, 0x21D3, LineBreak::Alphabetic),
+    (0x21D4, 0x21D4, LineBreak::Ambiguous),
+    (0x21D5, 0x21FF, LineBreak::Alphabetic),
+    (0x2200, 0x2200, LineBreak::Ambiguous),
+    (0x2201, 0x2201, LineBreak::Alphabetic),
+    (0x2202, 0x2203, LineBreak::Ambiguous),
+    (0x2204, 0x2206, LineBreak::Alphabetic),
+    (0x2207, 0x2208, LineBreak::Ambiguous),
+    (0x2209, 0x220A, LineBreak::Alphabetic),
+    (0x220B, 0x220B, LineBreak::Ambiguous),
+    (0x220C, 0x220E, LineBreak::Alphabetic),
+    (0x220F, 0x220F, LineBreak::Ambiguous),
+    (0x2210, 0x2210, LineBreak::Alphabetic),
+    (0x2211, 0x2211, LineBreak::Ambiguous),
+    (0x2212, 0x2213, LineBreak::PrefixNumeric),
+    (0x2214, 0x2214, LineBreak::Alphabetic),
+    (0x2215, 0x2215, LineBreak::Ambiguous),
+    (0x2216, 0x2219, LineBreak::Alphabetic),
+    (0x221A, 0x221A, LineBreak::Ambiguous),
+    (0x221B, 0x221C, LineBreak::Alphabetic),
+    (0x221D, 0x2220, LineBreak::Ambiguous),
+    (0x2221, 0x2222, LineBreak::Alphabetic),
+    (0x2223, 0x2223, LineBreak::Ambiguous),
+    (0x2224, 0x2224, LineBreak::Alphabetic),
+    (0x2225, 0x2225, LineBreak::Ambiguous),
+    (0x2226, 0x2226, LineBreak::Alphabetic),
+    (0x2227, 0x222C, LineBreak::Ambiguous),
+    (0x222D, 0x222D, LineBreak::Alphabetic),
+    (0x222E, 0x222E, LineBreak::Ambiguous),
+    (0x222F, 0x2233, LineBreak::Alphabetic),
+    (0x2234, 0x2237, LineBreak::Ambiguous),
+    (0x2238, 0x223B, LineBreak::Alphabetic),
+    (0x223C, 0x223D, LineBreak::Ambiguous),
+    (0x223E, 0x2247, LineBreak::Alphabetic),
+    (0x2248, 0x2248, LineBreak::Ambiguous),
+    (0x2249, 0x224B, LineBreak::Alphabetic),
+    (0x224C, 0x224C, LineBreak::Ambiguous),
+    (0x224D, 0x2251, LineBreak::Alphabetic),
+    (0x2252, 0x2252, LineBreak::Ambiguous),
+    (0x2253, 0x225F, LineBreak::Alphabetic),
+    (0x2260, 0x2261, LineBreak::Ambiguous),
+    (0x2262, 0x2263, LineBreak::Alphabetic),
+    (0x2264, 0x2267, LineBreak::Ambiguous),
+    (0x2268, 0x2269, LineBreak::Alphabetic),
+    (0x226A, 0x226B, LineBreak::Ambiguous),
+    (0x226C, 0x226D, LineBreak::Alphabetic),
+    (0x226E, 0x226F, LineBreak::Ambiguous),
+    (0x2270, 0x2281, LineBreak::Alphabetic),
+    (0x2282, 0x2283, LineBreak::Ambiguous),
+    (0x2284, 0x2285, LineBreak::Alphabetic),
+    (0x2286, 0x2287, LineBreak::Ambiguous),
+    (0x2288, 0x2294, LineBreak::Alphabetic),
+    (0x2295, 0x2295, LineBreak::Ambiguous),
+    (0x2296, 0x2298, LineBreak::Alphabetic),
+    (0x2299, 0x2299, LineBreak::Ambiguous),
+    (0x229A, 0x22A4, LineBreak::Alphabetic),
+    (0x22A5, 0x22A5, LineBreak::Ambiguous),
+    (0x22A6, 0x22BE, LineBreak::Alphabetic),
+    (0x22BF, 0x22BF, LineBreak::Ambiguous),
+    (0x22C0, 0x22EE, LineBreak::Alphabetic),
+    (0x22EF, 0x22EF, LineBreak::Inseparable),
+    (0x22F0, 0x2307, LineBreak::Alphabetic),
+    (0x2308, 0x2308, LineBreak::OpenPunctuation),
+    (0x2309, 0x2309, LineBreak::ClosePunctuation),
+    (0x230A, 0x230A, LineBreak::OpenPunctuation),
+    (0x230B, 0x230B, LineBreak::ClosePunctuation),
+    (0x230C, 0x2311, LineBreak::Alphabetic),
+    (0x2312, 0x2312, LineBreak::Ambiguous),
+    (0x2313, 0x2319, LineBreak::Alphabetic),
+    (0x231A, 0x231B, LineBreak::Ideographic),
+    (0x231C, 0x2328, LineBreak::Alphabetic),
+    (0x2329, 0x2329, LineBreak::OpenPunctuation),
+    (0x232A, 0x232A, LineBreak::ClosePunctuation),
+    (0x232B, 0x23EF, LineBreak::Alphabetic),
+    (0x23F0, 0x23F3, LineBreak::Ideographic),
+    (0x23F4, 0x2426, LineBreak::Alphabetic),
+    (0x2440, 0x244A, LineBreak::Alphabetic),
+    (0x2460, 0x24FE, LineBreak::Ambiguous),
+    (0x24FF, 0x24FF, LineBreak::Alphabetic),
+    (0x2500, 0x254B, LineBreak::Ambiguous),
+    (0x254C, 0x254F, LineBreak::Alphabetic),
+    (0x2550, 0x2574, LineBreak::Ambiguous),
+    (0x2575, 0x257F, LineBreak::Alphabetic),
+    (0x2580, 0x258F, LineBreak::Ambiguous),
+    (0x2590, 0x2591, LineBreak::Alphabetic),
+    (0x2592, 0x2595, LineBreak::Ambiguous),
+    (0x2596, 0x259F, LineBreak::Alphabetic),
+    (0x25A0, 0x25A1, LineBreak::Ambiguous),
+    (0x25A2, 0x25A2, LineBreak::Alphabetic),
+    (0x25A3, 0x25A9, LineBreak::Ambiguous),
+    (0x25AA, 0x25B1, LineBreak::Alphabetic),
+    (0x25B2, 0x25B3, LineBreak::Ambiguous),
+    (0x25B4, 0x25B5, LineBreak::Alphabetic),
+    (0x25B6, 0x25B7, LineBreak::Ambiguous),
+    (0x25B8, 0x25BB, LineBreak::Alphabetic),
+    (0x25BC, 0x25BD, LineBreak::Ambiguous),
+    (0x25BE, 0x25BF, LineBreak::Alphabetic),
+    (0x25C0, 0x25C1, LineBreak::Ambiguous),
+    (0x25C2, 0x25C5, LineBreak::Alphabetic),
+    (0x25C6, 0x25C8, LineBreak::Ambiguous),
+    (0x25C9, 0x25CA, LineBreak::Alphabetic),
+    (0x25CB, 0x25CB, LineBreak::Ambiguous),
+    (0x25CC, 0x25CD, LineBreak::Alphabetic),
+    (0x25CE, 0x25D1, LineBreak::Ambiguous),
+    (0x25D2, 0x25E1, LineBreak::Alphabetic),
+    (0x25E2, 0x25E5, LineBreak::Ambiguous),
+    (0x25E6, 0x25EE, LineBreak::Alphabetic),
+    (0x25EF, 0x25EF, LineBreak::Ambiguous),
+    (0x25F0, 0x25FF, LineBreak::Alphabetic),
+    (0x2600, 0x2603, LineBreak::Ideographic),
+    (0x2604, 0x2604, LineBreak::Alphabetic),
+    (0x2605, 0x2606, LineBreak::Ambiguous),
+    (0x2607, 0x2608, LineBreak::Alphabetic),
+    (0x2609, 0x2609, LineBreak::Ambiguous),
+    (0x260A, 0x260D, LineBreak::Alphabetic),
+    (0x260E, 0x260F, LineBreak::Ambiguous),
+    (0x2610, 0x2613, LineBreak::Alphabetic),
+    (0x2614, 0x2615, LineBreak::Ideographic),
+    (0x2616, 0x2617, LineBreak::Ambiguous),
+    (0x2618, 0x2618, LineBreak::Ideographic),
+    (0x2619, 0x2619, LineBreak::Alphabetic),
+    (0x261A, 0x261C, LineBreak::Ideographic),
+    (0x261D, 0x261D, LineBreak::EBase),
+    (0x261E, 0x261F, LineBreak::Ideographic),
+    (0x2620, 0x2638, LineBreak::Alphabetic),
+    (0x2639, 0x263B, LineBreak::Ideographic),
+    (0x263C, 0x263F, LineBreak::Alphabetic),
+    (0x2640, 0x2640, LineBreak::Ambiguous),
+    (0x2641, 0x2641, LineBreak::Alphabetic),
+    (0x2642, 0x2642, LineBreak::Ambiguous),
+    (0x2643, 0x265F, LineBreak::Alphabetic),
+    (0x2660, 0x2661, LineBreak::Ambiguous),
+    (0x2662, 0x2662, LineBreak::Alphabetic),
+    (0x2663, 0x2665, LineBreak::Ambiguous),
+    (0x2666, 0x2666, LineBreak::Alphabetic),
+    (0x2667, 0x2667, LineBreak::Ambiguous),
+    (0x2668, 0x2668, LineBreak::Ideographic),
+    (0x2669, 0x266A, LineBreak::Ambiguous),
+    (0x266B, 0x266B, LineBreak::Alphabetic),
+    (0x266C, 0x266D, LineBreak::Ambiguous),
+    (0x266E, 0x266E, LineBreak::Alphabetic),
+    (0x266F, 0x266F, LineBreak::Ambiguous),
+    (0x2670, 0x267E, LineBreak::Alphabetic),
+    (0x267F, 0x267F, LineBreak::Ideographic),
+    (0x2680, 0x269D, LineBreak::Alphabetic),
+    (0x269E, 0x269F, LineBreak::Ambiguous),
+    (0x26A0, 0x26BC, LineBreak::Alphabetic),
+    (0x26BD, 0x26C8, LineBreak::Ideographic),
+    (0x26C9, 0x26CC, LineBreak::Ambiguous),
+    (0x26CD, 0x26CD, LineBreak::Ideographic),
+    (0x26CE, 0x26CE, LineBreak::Alphabetic),
+    (0x26CF, 0x26D1, LineBreak::Ideographic),
+    (0x26D2, 0x26D2, LineBreak::Ambiguous),
+    (0x26D3, 0x26D4, LineBreak::Ideographic),
+    (0x26D5, 0x26D7, LineBreak::Ambiguous),
+    (0x26D8, 0x26D9, LineBreak::Ideographic),
+    (0x26DA, 0x26DB, LineBreak::Ambiguous),
+    (0x26DC, 0x26DC, LineBreak::Ideographic),
+    (0x26DD, 0x26DE, LineBreak::Ambiguous),
+    (0x26DF, 0x26E1, LineBreak::Ideographic),
+    (0x26E2, 0x26E2, LineBreak::Alphabetic),
+    (0x26E3, 0x26E3, LineBreak::Ambiguous),
+    (0x26E4, 0x26E7, LineBreak::Alphabetic),
+    (0x26E8, 0x26E9, LineBreak::Ambiguous),
+    (0x26EA, 0x26EA, LineBreak::Ideographic),
+    (0x26EB, 0x26F0, LineBreak::Ambiguous),
+    (0x26F1, 0x26F5, LineBreak::Ideographic),
+    (0x26F6, 0x26F6, LineBreak::Ambiguous),
+    (0x26F7, 0x26F8, LineBreak::Ideographic),
+    (0x26F9, 0x26F9, LineBreak::EBase),
+    (0x26FA, 0x26FA, LineBreak::Ideographic),
+    (0x26FB, 0x26FC, LineBreak::Ambiguous),
+    (0x26FD, 0x2704, LineBreak::Ideographic),
+    (0x2705, 0x2707, LineBreak::Alphabetic),
+    (0x2708, 0x2709, LineBreak::Ideographic),
+    (0x270A, 0x270D, LineBreak::EBase),
+    (0x270E, 0x2756, LineBreak::Alphabetic),
+    (0x2757, 0x2757, LineBreak::Ambiguous),
+    (0x2758, 0x275A, LineBreak::Alphabetic),
+    (0x275B, 0x2760, LineBreak::Quotation),
+    (0x2761, 0x2761, LineBreak::Alphabetic),
+    (0x2762, 0x2763, LineBreak::Exclamation),
+    (0x2764, 0x2764, LineBreak::Ideographic),
+    (0x2765, 0x2767, LineBreak::Alphabetic),
+    (0x2768, 0x2768, LineBreak::OpenPunctuation),
+    (0x2769, 0x2769, LineBreak::ClosePunctuation),
+    (0x276A, 0x276A, LineBreak::OpenPunctuation),
+    (0x276B, 0x276B, LineBreak::ClosePunctuation),
+    (0x276C, 0x276C, LineBreak::OpenPunctuation),
+    (0x276D, 0x276D, LineBreak::ClosePunctuation),
+    (0x276E, 0x276E, LineBreak::OpenPunctuation),
+    (0x276F, 0x276F, LineBreak::ClosePunctuation),
+    (0x2770, 0x2770, LineBreak::OpenPunctuation),
+    (0x2771, 0x2771, LineBreak::ClosePunctuation),
+    (0x2772, 0x2772, LineBreak::OpenPunctuation),
+    (0x2773, 0x2773, LineBreak::ClosePunctuation),
+    (0x2774, 0x2774, LineBreak::OpenPunctuation),
+    (0x2775, 0x2775, LineBreak::ClosePunctuation),
+    (0x2776, 0x2793, LineBreak::Ambiguous),
+    (0x2794, 0x27C4, LineBreak::Alphabetic),
+    (0x27C5, 0x27C5, LineBreak::OpenPunctuation),
+    (0x27C6, 0x27C6, LineBreak::ClosePunctuation),
+    (0x27C7, 0x27E5, LineBreak::Alphabetic),
+    (0x27E6, 0x27E6, LineBreak::OpenPunctuation),
+    (0x27E7, 0x27E7, LineBreak::ClosePunctuation),
+    (0x27E8, 0x27E8, LineBreak::OpenPunctuation),
+    (0x27E9, 0x27E9, LineBreak::ClosePunctuation),
+    (0x27EA, 0x27EA, LineBreak::OpenPunctuation),
+    (0x27EB, 0x27EB, LineBreak::ClosePunctuation),
+    (0x27EC, 0x27EC, LineBreak::OpenPunctuation),
+    (0x27ED, 0x27ED, LineBreak::ClosePunctuation),
+    (0x27EE, 0x27EE, LineBreak::OpenPunctuation),
+    (0x27EF, 0x27EF, LineBreak::ClosePunctuation),
+    (0x27F0, 0x2982, LineBreak::Alphabetic),
+    (0x2983, 0x2983, LineBreak::OpenPunctuation),
+    (0x2984, 0x2984, LineBreak::ClosePunctuation),
+    (0x2985, 0x2985, LineBreak::OpenPunctuation),
+    (0x2986, 0x2986, LineBreak::ClosePunctuation),
+    (0x2987, 0x2987, LineBreak::OpenPunctuation),
+    (0x2988, 0x2988, LineBreak::ClosePunctuation),
+    (0x2989, 0x2989, LineBreak::OpenPunctuation),
+    (0x298A, 0x298A, LineBreak::ClosePunctuation),
+    (0x298B, 0x298B, LineBreak::OpenPunctuation),
+    (0x298C, 0x298C, LineBreak::ClosePunctuation),
+    (0x298D, 0x298D, LineBreak::OpenPunctuation),
+    (0x298E, 0x298E, LineBreak::ClosePunctuation),
+    (0x298F, 0x298F, LineBreak::OpenPunctuation),
+    (0x2990, 0x2990, LineBreak::ClosePunctuation),
+    (0x2991, 0x2991, LineBreak::OpenPunctuation),
+    (0x2992, 0x2992, LineBreak::ClosePunctuation),
+    (0x2993, 0x2993, LineBreak::OpenPunctuation),
+    (0x2994, 0x2994, LineBreak::ClosePunctuation),
+    (0x2995, 0x2995, LineBreak::OpenPunctuation),
+    (0x2996, 0x2996, LineBreak::ClosePunctuation),
+    (0x2997, 0x2997, LineBreak::OpenPunctuation),
+    (0x2998, 0x2998, LineBreak::ClosePunctuation),
+    (0x2999, 0x29D7, LineBreak::Alphabetic),
+    (0x29D8, 0x29D8, LineBreak::OpenPunctuation),
+    (0x29D9, 0x29D9, LineBreak::ClosePunctuation),
+    (0x29DA, 0x29DA, LineBreak::OpenPunctuation),
+    (0x29DB, 0x29DB, LineBreak::ClosePunctuation),
+    (0x29DC, 0x29FB, LineBreak::Alphabetic),
+    (0x29FC, 0x29FC, LineBreak::OpenPunctuation),
+    (0x29FD, 0x29FD, LineBreak::ClosePunctuation),
+    (0x29FE, 0x2B54, LineBreak::Alphabetic),
+    (0x2B55, 0x2B59, LineBreak::Ambiguous),
+    (0x2B5A, 0x2B73, LineBreak::Alphabetic),
+    (0x2B76, 0x2B95, LineBreak::Alphabetic),
+    (0x2B97, 0x2C2E, LineBreak::Alphabetic),
+    (0x2C30, 0x2C5E, LineBreak::Alphabetic),
+    (0x2C60, 0x2CEE, LineBreak::Alphabetic),
+    (0x2CEF, 0x2CF1, LineBreak::CombiningMark),
+    (0x2CF2, 0x2CF3, LineBreak::Alphabetic),
+    (0x2CF9, 0x2CF9, LineBreak::Exclamation),
+    (0x2CFA, 0x2CFC, LineBreak::BreakAfter),
+    (0x2CFD, 0x2CFD, LineBreak::Alphabetic),
+    (0x2CFE, 0x2CFE, LineBreak::Exclamation),
+    (0x2CFF, 0x2CFF, LineBreak::BreakAfter),
+    (0x2D00, 0x2D25, LineBreak::Alphabetic),
+    (0x2D27, 0x2D27, LineBreak::Alphabetic),
+    (0x2D2D, 0x2D2D, LineBreak::Alphabetic),
+    (0x2D30, 0x2D67, LineBreak::Alphabetic),
+    (0x2D6F, 0x2D6F, LineBreak::Alphabetic),
+    (0x2D70, 0x2D70, LineBreak::BreakAfter),
+    (0x2D7F, 0x2D7F, LineBreak::CombiningMark),
+    (0x2D80, 0x2D96, LineBreak::Alphabetic),
+    (0x2DA0, 0x2DA6, LineBreak::Alphabetic),
+    (0x2DA8, 0x2DAE, LineBreak::Alphabetic),
+    (0x2DB0, 0x2DB6, LineBreak::Alphabetic),
+    (0x2DB8, 0x2DBE, LineBreak::Alphabetic),
+    (0x2DC0, 0x2DC6, LineBreak::Alphabetic),
+    (0x2DC8, 0x2DCE, LineBreak::Alphabetic),
+    (0x2DD0, 0x2DD6, LineBreak::Alphabetic),
+    (0x2DD8, 0x2DDE, LineBreak::Alphabetic),
+    (0x2DE0, 0x2DFF, LineBreak::CombiningMark),
+    (0x2E00, 0x2E0D, LineBreak::Quotation),
+    (0x2E0E, 0x2E15, LineBreak::BreakAfter),
+    (0x2E16, 0x2E16, LineBreak::Alphabetic),
+    (0x2E17, 0x2E17, LineBreak::BreakAfter),
+    (0x2E18, 0x2E18, LineBreak::OpenPunctuation),
+    (0x2E19, 0x2E19, LineBreak::BreakAfter),
+    (0x2E1A, 0x2E1B, LineBreak::Alphabetic),
+    (0x2E1C, 0x2E1D, LineBreak::Quotation),
+    (0x2E1E, 0x2E1F, LineBreak::Alphabetic),
+    (0x2E20, 0x2E21, LineBreak::Quotation),
+    (0x2E22, 0x2E22, LineBreak::OpenPunctuation),
+    (0x2E23, 0x2E23, LineBreak::ClosePunctuation),
+    (0x2E24, 0x2E24, LineBreak::OpenPunctuation),
+    (0x2E25, 0x2E25, LineBreak::ClosePunctuation),
+    (0x2E26, 0x2E26, LineBreak::OpenPunctuation),
+    (0x2E27, 0x2E27, LineBreak::ClosePunctuation),
+    (0x2E28, 0x2E28, LineBreak::OpenPunctuation),
+    (0x2E29, 0x2E29, LineBreak::ClosePunctuation),
+    (0x2E2A, 0x2E2D, LineBreak::BreakAfter),
+    (0x2E2E, 0x2E2E, LineBreak::Exclamation),
+    (0x2E2F, 0x2E2F, LineBreak::Alphabetic),
+    (0x2E30, 0x2E31, LineBreak::BreakAfter),
+    (0x2E32, 0x2E32, LineBreak::Alphabetic),
+    (0x2E33, 0x2E34, LineBreak::BreakAfter),
+    (0x2E35, 0x2E39, LineBreak::Alphabetic),
+    (0x2E3A, 0x2E3B, LineBreak::BreakBoth),
+    (0x2E3C, 0x2E3E, LineBreak::BreakAfter),
+    (0x2E3F, 0x2E3F, LineBreak::Alphabetic),
+    (0x2E40, 0x2E41, LineBreak::BreakAfter),
+    (0x2E42, 0x2E42, LineBreak::OpenPunctuation),
+    (0x2E43, 0x2E4A, LineBreak::BreakAfter),
+    (0x2E4B, 0x2E4B, LineBreak::Alphabetic),
+    (0x2E4C, 0x2E4C, LineBreak::BreakAfter),
+    (0x2E4D, 0x2E4D, LineBreak::Alphabetic),
+    (0x2E4E, 0x2E4F, LineBreak::BreakAfter),
+    (0x2E50, 0x2E52, LineBreak::Alphabetic),
+    (0x2E80, 0x2E99, LineBreak::Ideographic),
+    (0x2E9B, 0x2EF3, LineBreak::Ideographic),
+    (0x2F00, 0x2FD5, LineBreak::Ideographic),
+    (0x2FF0, 0x2FFB, LineBreak::Ideographic),
+    (0x3000, 0x3000, LineBreak::BreakAfter),
+    (0x3001, 0x3002, LineBreak::ClosePunctuation),
+    (0x3003, 0x3004, LineBreak::Ideographic),
+    (0x3005, 0x3005, LineBreak::Nonstarter),
+    (0x3006, 0x3007, LineBreak::Ideographic),
+    (0x3008, 0x3008, LineBreak::OpenPunctuation),
+    (0x3009, 0x3009, LineBreak::ClosePunctuation),
+    (0x300A, 0x300A, LineBreak::OpenPunctuation),
+    (0x300B, 0x300B, LineBreak::ClosePunctuation),
+    (0x300C, 0x300C, LineBreak::OpenPunctuation),
+    (0x300D, 0x300D, LineBreak::ClosePunctuation),
+    (0x300E, 0x300E, LineBreak::OpenPunctuation),
+    (0x300F, 0x300F, LineBreak::ClosePunctuation),
+    (0x3010, 0x3010, LineBreak::OpenPunctuation),
+    (0x3011, 0x3011, LineBreak::ClosePunctuation),
+    (0x3012, 0x3013, LineBreak::Ideographic),
+    (0x3014, 0x3014, LineBreak::OpenPunctuation),
+    (0x3015, 0x3015, LineBreak::ClosePunctuation),
+    (0x3016, 0x3016, LineBreak::OpenPunctuation),
+    (0x3017, 0x3017, LineBreak::ClosePunctuation),
+    (0x3018, 0x3018, LineBreak::OpenPunctuation),
+    (0x3019, 0x3019, LineBreak::ClosePunctuation),
+    (0x301A, 0x301A, LineBreak::OpenPunctuation),
+    (0x301B, 0x301B, LineBreak::ClosePunctuation),
+    (0x301C, 0x301C, LineBreak::Nonstarter),
+    (0x301D, 0x301D, LineBreak::OpenPunctuation),
+    (0x301E, 0x301F, LineBreak::ClosePunctuation),
+    (0x3020, 0x3029, LineBreak::Ideographic),
+    (0x302A, 0x302F, LineBreak::CombiningMark),
+    (0x3030, 0x3034, LineBreak::Ideographic),
+    (0x3035, 0x3035, LineBreak::CombiningMark),
+    (0x3036, 0x303A, LineBreak::Ideographic),
+    (0x303B, 0x303C, LineBreak::Nonstarter),
+    (0x303D, 0x303F, LineBreak::Ideographic),
+    (0x3041, 0x3041, LineBreak::ConditionalJapaneseStarter),
+    (0x3042, 0x3042, LineBreak::Ideographic),
+    (0x3043, 0x3043, LineBreak::ConditionalJapaneseStarter),
+    (0x3044, 0x3044, LineBreak::Ideographic),
+    (0x3045, 0x3045, LineBreak::ConditionalJapaneseStarter),
+    (0x3046, 0x3046, LineBreak::Ideographic),
+    (0x3047, 0x3047, LineBreak::ConditionalJapaneseStarter),
+    (0x3048, 0x3048, LineBreak::Ideographic),
+    (0x3049, 0x3049, LineBreak::ConditionalJapaneseStarter),
+    (0x304A, 0x3062, LineBreak::Ideographic),
+    (0x3063, 0x3063, LineBreak::ConditionalJapaneseStarter),
+    (0x3064, 0x3082, LineBreak::Ideographic),
+    (0x3083, 0x3083, LineBreak::ConditionalJapaneseStarter),
+    (0x3084, 0x3084, LineBreak::Ideographic),
+    (0x3085, 0x3085, LineBreak::ConditionalJapaneseStarter),
+    (0x3086, 0x3086, LineBreak::Ideographic),
+    (0x3087, 0x3087, LineBreak::ConditionalJapaneseStarter),
+    (0x3088, 0x308D, LineBreak::Ideographic),
+    (0x308E, 0x308E, LineBreak::ConditionalJapaneseStarter),
+    (0x308F, 0x3094, LineBreak::Ideographic),
+    (0x3095, 0x3096, LineBreak::ConditionalJapaneseStarter),
+    (0x3099, 0x309A, LineBreak::CombiningMark),
+    (0x309B, 0x309E, LineBreak::Nonstarter),
+    (0x309F, 0x309F, LineBreak::Ideographic),
+    (0x30A0, 0x30A0, LineBreak::Nonstarter),
+    (0x30A1, 0x30A1, LineBreak::ConditionalJapaneseStarter),
+    (0x30A2, 0x30A2, LineBreak::Ideographic),
+    (0x30A3, 0x30A3, LineBreak::ConditionalJapaneseStarter),
+    (0x30A4, 0x30A4, LineBreak::Ideographic),
+    (0x30A5, 0x30A5, LineBreak::ConditionalJapaneseStarter),
+    (0x30A6, 0x30A6, LineBreak::Ideographic),
+    (0x30A7, 0x30A7, LineBreak::ConditionalJapaneseStarter),
+    (0x30A8, 0x30A8, LineBreak::Ideographic),
+    (0x30A9, 0x30A9, LineBreak::ConditionalJapaneseStarter),
+    (0x30AA, 0x30C2, LineBreak::Ideographic),
+    (0x30C3, 0x30C3, LineBreak::ConditionalJapaneseStarter),
+    (0x30C4, 0x30E2, LineBreak::Ideographic),
+    (0x30E3, 0x30E3, LineBreak::ConditionalJapaneseStarter),
+    (0x30E4, 0x30E4, LineBreak::Ideographic),
+    (0x30E5, 0x30E5, LineBreak::ConditionalJapaneseStarter),
+    (0x30E6, 0x30E6, LineBreak::Ideographic),
+    (0x30E7, 0x30E7, LineBreak::ConditionalJapaneseStarter),
+    (0x30E8, 0x30ED, LineBreak::Ideographic),
+    (0x30EE, 0x30EE, LineBreak::ConditionalJapaneseStarter),
+    (0x30EF, 0x30F4, LineBreak::Ideographic),
+    (0x30F5, 0x30F6, LineBreak::ConditionalJapaneseStarter),
+    (0x30F7, 0x30FA, LineBreak::Ideographic),
+    (0x30FB, 0x30FB, LineBreak::Nonstarter),
+    (0x30FC, 0x30FC, LineBreak::ConditionalJapaneseStarter),
+    (0x30FD, 0x30FE, LineBreak::Nonstarter),
+    (0x30FF, 0x30FF, LineBreak::Ideographic),
+    (0x3105, 0x312F, LineBreak::Ideographic),
+    (0x3131, 0x318E, LineBreak::Ideographic),
+    (0x3190, 0x31E3, LineBreak::Ideographic),
+    (0x31F0, 0x31FF, LineBreak::ConditionalJapaneseStarter),
+    (0x3200, 0x321E, LineBreak::Ideographic),
+    (0x3220, 0x3247, LineBreak::Ideographic),
+    (0x3248, 0x324F, LineBreak::Ambiguous),
+    (0x3250, 0x4DBF, LineBreak::Ideographic),
+    (0x4DC0, 0x4DFF, LineBreak::Alphabetic),
+    (0x4E00, 0xA014, LineBreak::Ideographic),
+    (0xA015, 0xA015, LineBreak::Nonstarter),
+    (0xA016, 0xA48C, LineBreak::Ideographic),
+    (0xA490, 0xA4C6, LineBreak::Ideographic),
+    (0xA4D0, 0xA4FD, LineBreak::Alphabetic),
+    (0xA4FE, 0xA4FF, LineBreak::BreakAfter),
+    (0xA500, 0xA60C, LineBreak::Alphabetic),
+    (0xA60D, 0xA60D, LineBreak::BreakAfter),
+    (0xA60E, 0xA60E, LineBreak::Exclamation),
+    (0xA60F, 0xA60F, LineBreak::BreakAfter),
+    (0xA610, 0xA61F, LineBreak::Alphabetic),
+    (0xA620, 0xA629, LineBreak::Numeric),
+    (0xA62A, 0xA62B, LineBreak::Alphabetic),
+    (0xA640, 0xA66E, LineBreak::Alphabetic),
+    (0xA66F, 0xA672, LineBreak::CombiningMark),
+    (0xA673, 0xA673, LineBreak::Alphabetic),
+    (0xA674, 0xA67D, LineBreak::CombiningMark),
+    (0xA67E, 0xA69D, LineBreak::Alphabetic),
+    (0xA69E, 0xA69F, LineBreak::CombiningMark),
+    (0xA6A0, 0xA6EF, LineBreak::Alphabetic),
+    (0xA6F0, 0xA6F1, LineBreak::CombiningMark),
+    (0xA6F2, 0xA6F2, LineBreak::Alphabetic),
+    (0xA6F3, 0xA6F7, LineBreak::BreakAfter),
+    (0xA700, 0xA7BF, LineBreak::Alphabetic),
+    (0xA7C2, 0xA7CA, LineBreak::Alphabetic),
+    (0xA7F5, 0xA801, LineBreak::Alphabetic),
+    (0xA802, 0xA802, LineBreak::CombiningMark),
+    (0xA803, 0xA805, LineBreak::Alphabetic),
+    (0xA806, 0xA806, LineBreak::CombiningMark),
+    (0xA807, 0xA80A, LineBreak::Alphabetic),
+    (0xA80B, 0xA80B, LineBreak::CombiningMark),
+    (0xA80C, 0xA822, LineBreak::Alphabetic),
+    (0xA823, 0xA827, LineBreak::CombiningMark),
+    (0xA828, 0xA82B, LineBreak::Alphabetic),
+    (0xA82C, 0xA82C, LineBreak::CombiningMark),
+    (0xA830, 0xA837, LineBreak::Alphabetic),
+    (0xA838, 0xA838, LineBreak::PostfixNumeric),
+    (0xA839, 0xA839, LineBreak::Alphabetic),
+    (0xA840, 0xA873, LineBreak::Alphabetic),
+    (0xA874, 0xA875, LineBreak::BreakBefore),
+    (0xA876, 0xA877, LineBreak::Exclamation),
+    (0xA880, 0xA881, LineBreak::CombiningMark),
+    (0xA882, 0xA8B3, LineBreak::Alphabetic),
+    (0xA8B4, 0xA8C5, LineBreak::CombiningMark),
+    (0xA8CE, 0xA8CF, LineBreak::BreakAfter),
+    (0xA8D0, 0xA8D9, LineBreak::Numeric),
+    (0xA8E0, 0xA8F1, LineBreak::CombiningMark),
+    (0xA8F2, 0xA8FB, LineBreak::Alphabetic),
+    (0xA8FC, 0xA8FC, LineBreak::BreakBefore),
+    (0xA8FD, 0xA8FE, LineBreak::Alphabetic),
+    (0xA8FF, 0xA8FF, LineBreak::CombiningMark),
+    (0xA900, 0xA909, LineBreak::Numeric),
+    (0xA90A, 0xA925, LineBreak::Alphabetic),
+    (0xA926, 0xA92D, LineBreak::CombiningMark),
+    (0xA92E, 0xA92F, LineBreak::BreakAfter),
+    (0xA930, 0xA946, LineBreak::Alphabetic),
+    (0xA947, 0xA953, LineBreak::CombiningMark),
+    (0xA95F, 0xA95F, LineBreak::Alphabetic),
+    (0xA960, 0xA97C, LineBreak::JL),
+    (0xA980, 0xA983, LineBreak::CombiningMark),
+    (0xA984, 0xA9B2, LineBreak::Alphabetic),
+    (0xA9B3, 0xA9C0, LineBreak::CombiningMark),
+    (0xA9C1, 0xA9C6, LineBreak::Alphabetic),
+    (0xA9C7, 0xA9C9, LineBreak::BreakAfter),
+    (0xA9CA, 0xA9CD, LineBreak::Alphabetic),
+    (0xA9CF, 0xA9CF, LineBreak::Alphabetic),
+    (0xA9D0, 0xA9D9, LineBreak::Numeric),
+    (0xA9DE, 0xA9DF, LineBreak::Alphabetic),
+    (0xA9E0, 0xA9EF, LineBreak::ComplexContext),
+    (0xA9F0, 0xA9F9, LineBreak::Numeric),
+    (0xA9FA, 0xA9FE, LineBreak::ComplexContext),
+    (0xAA00, 0xAA28, LineBreak::Alphabetic),
+    (0xAA29, 0xAA36, LineBreak::CombiningMark),
+    (0xAA40, 0xAA42, LineBreak::Alphabetic),
+    (0xAA43, 0xAA43, LineBreak::CombiningMark),
+    (0xAA44, 0xAA4B, LineBreak::Alphabetic),
+    (0xAA4C, 0xAA4D, LineBreak::CombiningMark),
+    (0xAA50, 0xAA59, LineBreak::Numeric),
+    (0xAA5C, 0xAA5C, LineBreak::Alphabetic),
+    (0xAA5D, 0xAA5F, LineBreak::BreakAfter),
+    (0xAA60, 0xAAC2, LineBreak::ComplexContext),
+    (0xAADB, 0xAADF, LineBreak::ComplexContext),
+    (0xAAE0, 0xAAEA, LineBreak::Alphabetic),
+    (0xAAEB, 0xAAEF, LineBreak::CombiningMark),
+    (0xAAF0, 0xAAF1, LineBreak::BreakAfter),
+    (0xAAF2, 0xAAF4, LineBreak::Alphabetic),
+    (0xAAF5, 0xAAF6, LineBreak::CombiningMark),
+    (0xAB01, 0xAB06, LineBreak::Alphabetic),
+    (0xAB09, 0xAB0E, LineBreak::Alphabetic),
+    (0xAB11, 0xAB16, LineBreak::Alphabetic),
+    (0xAB20, 0xAB26, LineBreak::Alphabetic),
+    (0xAB28, 0xAB2E, LineBreak::Alphabetic),
+    (0xAB30, 0xAB6B, LineBreak::Alphabetic),
+    (0xAB70, 0xABE2, LineBreak::Alphabetic),
+    (0xABE3, 0xABEA, LineBreak::CombiningMark),
+    (0xABEB, 0xABEB, LineBreak::BreakAfter),
+    (0xABEC, 0xABED, LineBreak::CombiningMark),
+    (0xABF0, 0xABF9, LineBreak::Numeric),
+    (0xAC00, 0xAC00, LineBreak::H2),
+    (0xAC01, 0xAC1B, LineBreak::H3),
+    (0xAC1C, 0xAC1C, LineBreak::H2),
+    (0xAC1D, 0xAC37, LineBreak::H3),
+    (0xAC38, 0xAC38, LineBreak::H2),
+    (0xAC39, 0xAC53, LineBreak::H3),
+    (0xAC54, 0xAC54, LineBreak::H2),
+    (0xAC55, 0xAC6F, LineBreak::H3),
+    (0xAC70, 0xAC70, LineBreak::H2),
+    (0xAC71, 0xAC8B, LineBreak::H3),
+    (0xAC8C, 0xAC8C, LineBreak::H2),
+    (0xAC8D, 0xACA7, LineBreak::H3),
+    (0xACA8, 0xACA8, LineBreak::H2),
+    (0xACA9, 0xACC3, LineBreak::H3),
+    (0xACC4, 0xACC4, LineBreak::H2),
+    (0xACC5, 0xACDF, LineBreak::H3),
+    (0xACE0, 0xACE0, LineBreak::H2),
+    (0xACE1, 0xACFB, LineBreak::H3),
+    (0xACFC, 0xACFC, LineBreak::H2),
+    (0xACFD, 0xAD17, LineBreak::H3),
+    (0xAD18, 0xAD18, LineBreak::H2),
+    (0xAD19, 0xAD33, LineBreak::H3),
+    (0xAD34, 0xAD34, LineBreak::H2),
+    (0xAD35, 0xAD4F, LineBreak::H3),
+    (0xAD50, 0xAD50, LineBreak::H2),
+    (0xAD51, 0xAD6B, LineBreak::H3),
+    (0xAD6C, 0xAD6C, LineBreak::H2),
+    (0xAD6D, 0xAD87, LineBreak::H3),
+    (0xAD88, 0xAD88, LineBreak::H2),
+    (0xAD89, 0xADA3, LineBreak::H3),
+    (0xADA4, 0xADA4, LineBreak::H2),
+    (0xADA5, 0xADBF, LineBreak::H3),
+    (0xADC0, 0xADC0, LineBreak::H2),
+    (0xADC1, 0xADDB, LineBreak::H3),
+    (0xADDC, 0xADDC, LineBreak::H2),
+    (0xADDD, 0xADF7, LineBreak::H3),
+    (0xADF8, 0xADF8, LineBreak::H2),
+    (0xADF9, 0xAE13, LineBreak::H3),
+    (0xAE14, 0xAE14, LineBreak::H2),
+    (0xAE15, 0xAE2F, LineBreak::H3),
+    (0xAE30, 0xAE30, LineBreak::H2),
+    (0xAE31, 0xAE4B, LineBreak::H3),
+    (0xAE4C, 0xAE4C, LineBreak::H2),
+    (0xAE4D, 0xAE67, LineBreak::H3),
+    (0xAE68, 0xAE68, LineBreak::H2),
+    (0xAE69, 0xAE83, LineBreak::H3),
+    (0xAE84, 0xAE84, LineBreak::H2),
+    (0xAE85, 0xAE9F, LineBreak::H3),
+    (0xAEA0, 0xAEA0, LineBreak::H2),
+    (0xAEA1, 0xAEBB, LineBreak::H3),
+    (0xAEBC, 0xAEBC, LineBreak::H2),
+    (0xAEBD, 0xAED7, LineBreak::H3),
+    (0xAED8, 0xAED8, LineBreak::H2),
+    (0xAED9, 0xAEF3, LineBreak::H3),
+    (0xAEF4, 0xAEF4, LineBreak::H2),
+    (0xAEF5, 0xAF0F, LineBreak::H3),
+    (0xAF10, 0xAF10, LineBreak::H2),
+    (0xAF11, 0xAF2B, LineBreak::H3),
+    (0xAF2C, 0xAF2C, LineBreak::H2),
+    (0xAF2D, 0xAF47, LineBreak::H3),
+    (0xAF48, 0xAF48, LineBreak::H2),
+    (0xAF49, 0xAF63, LineBreak::H3),
+    (0xAF64, 0xAF64, LineBreak::H2),
+    (0xAF65, 0xAF7F, LineBreak::H3),
+    (0xAF80, 0xAF80, LineBreak::H2),
+    (0xAF81, 0xAF9B, LineBreak::H3),
+    (0xAF9C, 0xAF9C, LineBreak::H2),
+    (0xAF9D, 0xAFB7, LineBreak::H3),
+    (0xAFB8, 0xAFB8, LineBreak::H2),
+    (0xAFB9, 0xAFD3, LineBreak::H3),
+    (0xAFD4, 0xAFD4, LineBreak::H2),
+    (0xAFD5, 0xAFEF, LineBreak::H3),
+    (0xAFF0, 0xAFF0, LineBreak::H2),
+    (0xAFF1, 0xB00B, LineBreak::H3),
+    (0xB00C, 0xB00C, LineBreak::H2),
+    (0xB00D, 0xB027, LineBreak::H3),
+    (0xB028, 0xB028, LineBreak::H2),
+    (0xB029, 0xB043, LineBreak::H3),
+    (0xB044, 0xB044, LineBreak::H2),
+    (0xB045, 0xB05F, LineBreak::H3),
+    (0xB060, 0xB060, LineBreak::H2),
+    (0xB061, 0xB07B, LineBreak::H3),
+    (0xB07C, 0xB07C, LineBreak::H2),
+    (0xB07D, 0xB097, LineBreak::H3),
+    (0xB098, 0xB098, LineBreak::H2),
+    (0xB099, 0xB0B3, LineBreak::H3),
+    (0xB0B4, 0xB0B4, LineBreak::H2),
+    (0xB0B5, 0xB0CF, LineBreak::H3),
+    (0xB0D0, 0xB0D0, LineBreak::H2),
+    (0xB0D1, 0xB0EB, LineBreak::H3),
+    (0xB0EC, 0xB0EC, LineBreak::H2),
+    (0xB0ED, 0xB107, LineBreak::H3),
+    (0xB108, 0xB108, LineBreak::H2),
+    (0xB109, 0xB123, LineBreak::H3),
+    (0xB124, 0xB124, LineBreak::H2),
+    (0xB125, 0xB13F, LineBreak::H3),
+    (0xB140, 0xB140, LineBreak::H2),
+    (0xB141, 0xB15B, LineBreak::H3),
+    (0xB15C, 0xB15C, LineBreak::H2),
+    (0xB15D, 0xB177, LineBreak::H3),
+    (0xB178, 0xB178, LineBreak::H2),
+    (0xB179, 0xB193, LineBreak::H3),
+    (0xB194, 0xB194, LineBreak::H2),
+    (0xB195, 0xB1AF, LineBreak::H3),
+    (0xB1B0, 0xB1B0, LineBreak::H2),
+    (0xB1B1, 0xB1CB, LineBreak::H3),
+    (0xB1CC, 0xB1CC, LineBreak::H2),
+    (0xB1CD, 0xB1E7, LineBreak::H3),
+    (0xB1E8, 0xB1E8, LineBreak::H2),
+    (0xB1E9, 0xB203, LineBreak::H3),
+    (0xB204, 0xB204, LineBreak::H2),
+    (0xB205, 0xB21F, LineBreak::H3),
+    (0xB220, 0xB220, LineBreak::H2),
+    (0xB221, 0xB23B, LineBreak::H3),
+    (0xB23C, 0xB23C, LineBreak::H2),
+    (0xB23D, 0xB257, LineBreak::H3),
+    (0xB258, 0xB258, LineBreak::H2),
+    (0xB259, 0xB273, LineBreak::H3),
+    (0xB274, 0xB274, LineBreak::H2),
+    (0xB275, 0xB28F, LineBreak::H3),
+    (0xB290, 0xB290, LineBreak::H2),
+    (0xB291, 0xB2AB, LineBreak::H3),
+    (0xB2AC, 0xB2AC, LineBreak::H2),
+    (0xB2AD, 0xB2C7, LineBreak::H3),
+    (0xB2C8, 0xB2C8, LineBreak::H2),
+    (0xB2C9, 0xB2E3, LineBreak::H3),
+    (0xB2E4, 0xB2E4, LineBreak::H2),
+    (0xB2E5, 0xB2FF, LineBreak::H3),
+    (0xB300, 0xB300, LineBreak::H2),
+    (0xB301, 0xB31B, LineBreak::H3),
+    (0xB31C, 0xB31C, LineBreak::H2),
+    (0xB31D, 0xB337, LineBreak::H3),
+    (0xB338, 0xB338, LineBreak::H2),
+    (0xB339, 0xB353, LineBreak::H3),
+    (0xB354, 0xB354, LineBreak::H2),
+    (0xB355, 0xB36F, LineBreak::H3),
+    (0xB370, 0xB370, LineBreak::H2),
+    (0xB371, 0xB38B, LineBreak::H3),
+    (0xB38C, 0xB38C, LineBreak::H2),
+    (0xB38D, 0xB3A7, LineBreak::H3),
+    (0xB3A8, 0xB3A8, LineBreak::H2),
+    (0xB3A9, 0xB3C3, LineBreak::H3),
+    (0xB3C4, 0xB3C4, LineBreak::H2),
+    (0xB3C5, 0xB3DF, LineBreak::H3),
+    (0xB3E0, 0xB3E0, LineBreak::H2),
+    (0xB3E1, 0xB3FB, LineBreak::H3),
+    (0xB3FC, 0xB3FC, LineBreak::H2),
+    (0xB3FD, 0xB417, LineBreak::H3),
+    (0xB418, 0xB418, LineBreak::H2),
+    (0xB419, 0xB433, LineBreak::H3),
+    (0xB434, 0xB434, LineBreak::H2),
+    (0xB435, 0xB44F, LineBreak::H3),
+    (0xB450, 0xB450, LineBreak::H2),
+    (0xB451, 0xB46B, LineBreak::H3),
+    (0xB46C, 0xB46C, LineBreak::H2),
+    (0xB46D, 0xB487, LineBreak::H3),
+    (0xB488, 0xB488, LineBreak::H2),
+    (0xB489, 0xB4A3, LineBreak::H3),
+    (0xB4A4, 0xB4A4, LineBreak::H2),
+    (0xB4A5, 0xB4BF, LineBreak::H3),
+    (0xB4C0, 0xB4C0, LineBreak::H2),
+    (0xB4C1, 0xB4DB, LineBreak::H3),
+    (0xB4DC, 0xB4DC, LineBreak::H2),
+    (0xB4DD, 0xB4F7, LineBreak::H3),
+    (0xB4F8, 0xB4F8, LineBreak::H2),
+    (0xB4F9, 0xB513, LineBreak::H3),
+    (0xB514, 0xB514, LineBreak::H2),
+    (0xB515, 0xB52F, LineBreak::H3),
+    (0xB530, 0xB530, LineBreak::H2),
+    (0xB531, 0xB54B, LineBreak::H3),
+    (0xB54C, 0xB54C, LineBreak::H2),
+    (0xB54D, 0xB567, LineBreak::H3),
+    (0xB568, 0xB568, LineBreak::H2),
+    (0xB569, 0xB583, LineBreak::H3),
+    (0xB584, 0xB584, LineBreak::H2),
+    (0xB585, 0xB59F, LineBreak::H3),
+    (0xB5A0, 0xB5A0, LineBreak::H2),
+    (0xB5A1, 0xB5BB, LineBreak::H3),
+    (0xB5BC, 0xB5BC, LineBreak::H2),
+    (0xB5BD, 0xB5D7, LineBreak::H3),
+    (0xB5D8, 0xB5D8, LineBreak::H2),
+    (0xB5D9, 0xB5F3, LineBreak::H3),
+    (0xB5F4, 0xB5F4, LineBreak::H2),
+    (0xB5F5, 0xB60F, LineBreak::H3),
+    (0xB610, 0xB610, LineBreak::H2),
+    (0xB611, 0xB62B, LineBreak::H3),
+    (0xB62C, 0xB62C, LineBreak::H2),
+    (0xB62D, 0xB647, LineBreak::H3),
+    (0xB648, 0xB648, LineBreak::H2),
+    (0xB649, 0xB663, LineBreak::H3),
+    (0xB664, 0xB664, LineBreak::H2),
+    (0xB665, 0xB67F, LineBreak::H3),
+    (0xB680, 0xB680, LineBreak::H2),
+    (0xB681, 0xB69B, LineBreak::H3),
+    (0xB69C, 0xB69C, LineBreak::H2),
+    (0xB69D, 0xB6B7, LineBreak::H3),
+    (0xB6B8, 0xB6B8, LineBreak::H2),
+    (0xB6B9, 0xB6D3, LineBreak::H3),
+    (0xB6D4, 0xB6D4, LineBreak::H2),
+    (0xB6D5, 0xB6EF, LineBreak::H3),
+    (0xB6F0, 0xB6F0, LineBreak::H2),
+    (0xB6F1, 0xB70B, LineBreak::H3),
+    (0xB70C, 0xB70C, LineBreak::H2),
+    (0xB70D, 0xB727, LineBreak::H3),
+    (0xB728, 0xB728, LineBreak::H2),
+    (0xB729, 0xB743, LineBreak::H3),
+    (0xB744, 0xB744, LineBreak::H2),
+    (0xB745, 0xB75F, LineBreak::H3),
+    (0xB760, 0xB760, LineBreak::H2),
+    (0xB761, 0xB77B, LineBreak::H3),
+    (0xB77C, 0xB77C, LineBreak::H2),
+    (0xB77D, 0xB797, LineBreak::H3),
+    (0xB798, 0xB798, LineBreak::H2),
+    (0xB799, 0xB7B3, LineBreak::H3),
+    (0xB7B4, 0xB7B4, LineBreak::H2),
+    (0xB7B5, 0xB7CF, LineBreak::H3),
+    (0xB7D0, 0xB7D0, LineBreak::H2),
+    (0xB7D1, 0xB7EB, LineBreak::H3),
+    (0xB7EC, 0xB7EC, LineBreak::H2),
+    (0xB7ED, 0xB807, LineBreak::H3),
+    (0xB808, 0xB808, LineBreak::H2),
+    (0xB809, 0xB823, LineBreak::H3),
+    (0xB824, 0xB824, LineBreak::H2),
+    (0xB825, 0xB83F, LineBreak::H3),
+    (0xB840, 0xB840, LineBreak::H2),
+    (0xB841, 0xB85B, LineBreak::H3),
+    (0xB85C, 0xB85C, LineBreak::H2),
+    (0xB85D, 0xB877, LineBreak::H3),
+    (0xB878, 0xB878, LineBreak::H2),
+    (0xB879, 0xB893, LineBreak::H3),
+    (0xB894, 0xB894, LineBreak::H2),
+    (0xB895, 0xB8AF, LineBreak::H3),
+    (0xB8B0, 0xB8B0, LineBreak::H2),
+    (0xB8B1, 0xB8CB, LineBreak::H3),
+    (0xB8CC, 0xB8CC, LineBreak::H2),
+    (0xB8CD, 0xB8E7, LineBreak::H3),
+    (0xB8E8, 0xB8E8, LineBreak::H2),
+    (0xB8E9, 0xB903, LineBreak::H3),
+    (0xB904, 0xB904, LineBreak::H2),
+    (0xB905, 0xB91F, LineBreak::H3),
+    (0xB920, 0xB920, LineBreak::H2),
+    (0xB921, 0xB93B, LineBreak::H3),
+    (0xB93C, 0xB93C, LineBreak::H2),
+    (0xB93D, 0xB957, LineBreak::H3),
+    (0xB958, 0xB958, LineBreak::H2),
+    (0xB959, 0xB973, LineBreak::H3),
+    (0xB974, 0xB974, LineBreak::H2),
+    (0xB975, 0xB98F, LineBreak::H3),
+    (0xB990, 0xB990, LineBreak::H2),
+    (0xB991, 0xB9AB, LineBreak::H3),
+    (0xB9AC, 0xB9AC, LineBreak::H2),
+    (0xB9AD, 0xB9C7, LineBreak::H3),
+    (0xB9C8, 0xB9C8, LineBreak::H2),
+    (0xB9C9, 0xB9E3, LineBreak::H3),
+    (0xB9E4, 0xB9E4, LineBreak::H2),
+    (0xB9E5, 0xB9FF, LineBreak::H3),
+    (0xBA00, 0xBA00, LineBreak::H2),
+    (0xBA01, 0xBA1B, LineBreak::H3),
+    (0xBA1C, 0xBA1C, LineBreak::H2),
+    (0xBA1D, 0xBA37, LineBreak::H3),
+    (0xBA38, 0xBA38, LineBreak::H2),
+    (0xBA39, 0xBA53, LineBreak::H3),
+    (0xBA54, 0xBA54, LineBreak::H2),
+    (0xBA55, 0xBA6F, LineBreak::H3),
+    (0xBA70, 0xBA70, LineBreak::H2),
+    (0xBA71, 0xBA8B, LineBreak::H3),
+    (0xBA8C, 0xBA8C, LineBreak::H2),
+    (0xBA8D, 0xBAA7, LineBreak::H3),
+    (0xBAA8, 0xBAA8, LineBreak::H2),
+    (0xBAA9, 0xBAC3, LineBreak::H3),
+    (0xBAC4, 0xBAC4, LineBreak::H2),
+    (0xBAC5, 0xBADF, LineBreak::H3),
+    (0xBAE0, 0xBAE0, LineBreak::H2),
+    (0xBAE1, 0xBAFB, LineBreak::H3),
+    (0xBAFC, 0xBAFC, LineBreak::H2),
+    (0xBAFD, 0xBB17, LineBreak::H3),
+    (0xBB18, 0xBB18, LineBreak::H2),
+    (0xBB19, 0xBB33, LineBreak::H3),
+    (0xBB34, 0xBB34, LineBreak::H2),
+    (0xBB35, 0xBB4F, LineBreak::H3),
+    (0xBB50, 0xBB50, LineBreak::H2),
+    (0xBB51, 0xBB6B, LineBreak::H3),
+    (0xBB6C, 0xBB6C, LineBreak::H2),
+    (0xBB6D, 0xBB87, LineBreak::H3),
+    (0xBB88, 0xBB88, LineBreak::H2),
+    (0xBB89, 0xBBA3, LineBreak::H3),
+    (0xBBA4, 0xBBA4, LineBreak::H2),
+    (0xBBA5, 0xBBBF, LineBreak::H3),
+    (0xBBC0, 0xBBC0, LineBreak::H2),
+    (0xBBC1, 0xBBDB, LineBreak::H3),
+    (0xBBDC, 0xBBDC, LineBreak::H2),
+    (0xBBDD, 0xBBF7, LineBreak::H3),
+    (0xBBF8, 0xBBF8, LineBreak::H2),
+    (0xBBF9, 0xBC13, LineBreak::H3),
+    (0xBC14, 0xBC14, LineBreak::H2),
+    (0xBC15, 0xBC2F, LineBreak::H3),
+    (0xBC30, 0xBC30, LineBreak::H2),
+    (0xBC31, 0xBC4B, LineBreak::H3),
+    (0xBC4C, 0xBC4C, LineBreak::H2),
+    (0xBC4D, 0xBC67, LineBreak::H3),
+    (0xBC68, 0xBC68, LineBreak::H2),
+    (0xBC69, 0xBC83, LineBreak::H3),
+    (0xBC84, 0xBC84, LineBreak::H2),
+    (0xBC85, 0xBC9F, LineBreak::H3),
+    (0xBCA0, 0xBCA0, LineBreak::H2),
+    (0xBCA1, 0xBCBB, LineBreak::H3),
+    (0xBCBC, 0xBCBC, LineBreak::H2),
+    (0xBCBD, 0xBCD7, LineBreak::H3),
+    (0xBCD8, 0xBCD8, LineBreak::H2),
+    (0xBCD9, 0xBCF3, LineBreak::H3),
+    (0xBCF4, 0xBCF4, LineBreak::H2),
+    (0xBCF5, 0xBD0F, LineBreak::H3),
+    (0xBD10, 0xBD10, LineBreak::H2),
+    (0xBD11, 0xBD2B, LineBreak::H3),
+    (0xBD2C, 0xBD2C, LineBreak::H2),
+    (0xBD2D, 0xBD47, LineBreak::H3),
+    (0xBD48, 0xBD48, LineBreak::H2),
+    (0xBD49, 0xBD63, LineBreak::H3),
+    (0xBD64, 0xBD64, LineBreak::H2),
+    (0xBD65, 0xBD7F, LineBreak::H3),
+    (0xBD80, 0xBD80, LineBreak::H2),
+    (0xBD81, 0xBD9B, LineBreak::H3),
+    (0xBD9C, 0xBD9C, LineBreak::H2),
+    (0xBD9D, 0xBDB7, LineBreak::H3),
+    (0xBDB8, 0xBDB8, LineBreak::H2),
+    (0xBDB9, 0xBDD3, LineBreak::H3),
+    (0xBDD4, 0xBDD4, LineBreak::H2),
+    (0xBDD5, 0xBDEF, LineBreak::H3),
+    (0xBDF0, 0xBDF0, LineBreak::H2),
+    (0xBDF1, 0xBE0B, LineBreak::H3),
+    (0xBE0C, 0xBE0C, LineBreak::H2),
+    (0xBE0D, 0xBE27, LineBreak::H3),
+    (0xBE28, 0xBE28, LineBreak::H2),
+    (0xBE29, 0xBE43, LineBreak::H3),
+    (0xBE44, 0xBE44, LineBreak::H2),
+    (0xBE45, 0xBE5F, LineBreak::H3),
+    (0xBE60, 0xBE60, LineBreak::H2),
+    (0xBE61, 0xBE7B, LineBreak::H3),
+    (0xBE7C, 0xBE7C, LineBreak::H2),
+    (0xBE7D, 0xBE97, LineBreak::H3),
+    (0xBE98, 0xBE98, LineBreak::H2),
+    (0xBE99, 0xBEB3, LineBreak::H3),
+    (0xBEB4, 0xBEB4, LineBreak::H2),
+    (0xBEB5, 0xBECF, LineBreak::H3),
+    (0xBED0, 0xBED0, LineBreak::H2),
+    (0xBED1, 0xBEEB, LineBreak::H3),
+    (0xBEEC, 0xBEEC, LineBreak::H2),
+    (0xBEED, 0xBF07, LineBreak::H3),
+    (0xBF08, 0xBF08, LineBreak::H2),
+    (0xBF09, 0xBF23, LineBreak::H3),
+    (0xBF24, 0xBF24, LineBreak::H2),
+    (0xBF25, 0xBF3F, LineBreak::H3),
+    (0xBF40, 0xBF40, LineBreak::H2),
+    (0xBF41, 0xBF5B, LineBreak::H3),
+    (0xBF5C, 0xBF5C, LineBreak::H2),
+    (0xBF5D, 0xBF77, LineBreak::H3),
+    (0xBF78, 0xBF78, LineBreak::H2),
+    (0xBF79, 0xBF93, LineBreak::H3),
+    (0xBF94, 0xBF94, LineBreak::H2),
+    (0xBF95, 0xBFAF, LineBreak::H3),
+    (0xBFB0, 0xBFB0, LineBreak::H2),
+    (0xBFB1, 0xBFCB, LineBreak::H3),
+    (0xBFCC, 0xBFCC, LineBreak::H2),
+    (0xBFCD, 0xBFE7, LineBreak::H3),
+    (0xBFE8, 0xBFE8, LineBreak::H2),
+    (0xBFE9, 0xC003, LineBreak::H3),
+    (0xC004, 0xC004, LineBreak::H2),
+    (0xC005, 0xC01F, LineBreak::H3),
+    (0xC020, 0xC020, LineBreak::H2),
+    (0xC021, 0xC03B, LineBreak::H3),
+    (0xC03C, 0xC03C, LineBreak::H2),
+    (0xC03D, 0xC057, LineBreak::H3),
+    (0xC058, 0xC058, LineBreak::H2),
+    (0xC059, 0xC073, LineBreak::H3),
+    (0xC074, 0xC074, LineBreak::H2),
+    (0xC075, 0xC08F, LineBreak::H3),
+    (0xC090, 0xC090, LineBreak::H2),
+    (0xC091, 0xC0AB, LineBreak::H3),
+    (0xC0AC, 0xC0AC, LineBreak::H2),
+    (0xC0AD, 0xC0C7, LineBreak::H3),
+    (0xC0C8, 0xC0C8, LineBreak::H2),
+    (0xC0C9, 0xC0E3, LineBreak::H3),
+    (0xC0E4, 0xC0E4, LineBreak::H2),
+    (0xC0E5, 0xC0FF, LineBreak::H3),
+    (0xC100, 0xC100, LineBreak::H2),
+    (0xC101, 0xC11B, LineBreak::H3),
+    (0xC11C, 0xC11C, LineBreak::H2),
+    (0xC11D, 0xC137, LineBreak::H3),
+    (0xC138, 0xC138, LineBreak::H2),
+    (0xC139, 0xC153, LineBreak::H3),
+    (0xC154, 0xC154, LineBreak::H2),
+    (0xC155, 0xC16F, LineBreak::H3),
+    (0xC170, 0xC170, LineBreak::H2),
+    (0xC171, 0xC18B, LineBreak::H3),
+    (0xC18C, 0xC18C, LineBreak::H2),
+    (0xC18D, 0xC1A7, LineBreak::H3),
+    (0xC1A8, 0xC1A8, LineBreak::H2),
+    (0xC1A9, 0xC1C3, LineBreak::H3),
+    (0xC1C4, 0xC1C4, LineBreak::H2),
+    (0xC1C5, 0xC1DF, LineBreak::H3),
+    (0xC1E0, 0xC1E0, LineBreak::H2),
+    (0xC1E1, 0xC1FB, LineBreak::H3),
+    (0xC1FC, 0xC1FC, LineBreak::H2),
+    (0xC1FD, 0xC217, LineBreak::H3),
+    (0xC218, 0xC218, LineBreak::H2),
+    (0xC219, 0xC233, LineBreak::H3),
+    (0xC234, 0xC234, LineBreak::H2),
+    (0xC235, 0xC24F, LineBreak::H3),
+    (0xC250, 0xC250, LineBreak::H2),
+    (0xC251, 0xC26B, LineBreak::H3),
+    (0xC26C, 0xC26C, LineBreak::H2),
+    (0xC26D, 0xC287, LineBreak::H3),
+    (0xC288, 0xC288, LineBreak::H2),
+    (0xC289, 0xC2A3, LineBreak::H3),
+    (0xC2A4, 0xC2A4, LineBreak::H2),
+    (0xC2A5, 0xC2BF, LineBreak::H3),
+    (0xC2C0, 0xC2C0, LineBreak::H2),
+    (0xC2C1, 0xC2DB, LineBreak::H3),
+    (0xC2DC, 0xC2DC, LineBreak::H2),
+    (0xC2DD, 0xC2F7, LineBreak::H3),
+    (0xC2F8, 0xC2F8, LineBreak::H2),
+    (0xC2F9, 0xC313, LineBreak::H3),
+    (0xC314, 0xC314, LineBreak::H2),
+    (0xC315, 0xC32F, LineBreak::H3),
+    (0xC330, 0xC330, LineBreak::H2),
+    (0xC331, 0xC34B, LineBreak::H3),
+    (0xC34C, 0xC34C, LineBreak::H2),
+    (0xC34D, 0xC367, LineBreak::H3),
+    (0xC368, 0xC368, LineBreak::H2),
+    (0xC369, 0xC383, LineBreak::H3),
+    (0xC384, 0xC384, LineBreak::H2),
+    (0xC385, 0xC39F, LineBreak::H3),
+    (0xC3A0, 0xC3A0, LineBreak::H2),
+    (0xC3A1, 0xC3BB, LineBreak::H3),
+    (0xC3BC, 0xC3BC, LineBreak::H2),
+    (0xC3BD, 0xC3D7, LineBreak::H3),
+    (0xC3D8, 0xC3D8, LineBreak::H2),
+    (0xC3D9, 0xC3F3, LineBreak::H3),
+    (0xC3F4, 0xC3F4, LineBreak::H2),
+    (0xC3F5, 0xC40F, LineBreak::H3),
+    (0xC410, 0xC410, LineBreak::H2),
+    (0xC411, 0xC42B, LineBreak::H3),
+    (0xC42C, 0xC42C, LineBreak::H2),
+    (0xC42D, 0xC447, LineBreak::H3),
+    (0xC448, 0xC448, LineBreak::H2),
+    (0xC449, 0xC463, LineBreak::H3),
+    (0xC464, 0xC464, LineBreak::H2),
+    (0xC465, 0xC47F, LineBreak::H3),
+    (0xC480, 0xC480, LineBreak::H2),
+    (0xC481, 0xC49B, LineBreak::H3),
+    (0xC49C, 0xC49C, LineBreak::H2),
+    (0xC49D, 0xC4B7, LineBreak::H3),
+    (0xC4B8, 0xC4B8, LineBreak::H2),
+    (0xC4B9, 0xC4D3, LineBreak::H3),
+    (0xC4D4, 0xC4D4, LineBreak::H2),
+    (0xC4D5, 0xC4EF, LineBreak::H3),
+    (0xC4F0, 0xC4F0, LineBreak::H2),
+    (0xC4F1, 0xC50B, LineBreak::H3),
+    (0xC50C, 0xC50C, LineBreak::H2),
+    (0xC50D, 0xC527, LineBreak::H3),
+    (0xC528, 0xC528, LineBreak::H2),
+    (0xC529, 0xC543, LineBreak::H3),
+    (0xC544, 0xC544, LineBreak::H2),
+    (0xC545, 0xC55F, LineBreak::H3),
+    (0xC560, 0xC560, LineBreak::H2),
+    (0xC561, 0xC57B, LineBreak::H3),
+    (0xC57C, 0xC57C, LineBreak::H2),
+    (0xC57D, 0xC597, LineBreak::H3),
+    (0xC598, 0xC598, LineBreak::H2),
+    (0xC599, 0xC5B3, LineBreak::H3),
+    (0xC5B4, 0xC5B4, LineBreak::H2),
+    (0xC5B5, 0xC5CF, LineBreak::H3),
+    (0xC5D0, 0xC5D0, LineBreak::H2),
+    (0xC5D1, 0xC5EB, LineBreak::H3),
+    (0xC5EC, 0xC5EC, LineBreak::H2),
+    (0xC5ED, 0xC607, LineBreak::H3),
+    (0xC608, 0xC608, LineBreak::H2),
+    (0xC609, 0xC623, LineBreak::H3),
+    (0xC624, 0xC624, LineBreak::H2),
+    (0xC625, 0xC63F, LineBreak::H3),
+    (0xC640, 0xC640, LineBreak::H2),
+    (0xC641, 0xC65B, LineBreak::H3),
+    (0xC65C, 0xC65C, LineBreak::H2),
+    (0xC65D, 0xC677, LineBreak::H3),
+    (0xC678, 0xC678, LineBreak::H2),
+    (0xC679, 0xC693, LineBreak::H3),
+    (0xC694, 0xC694, LineBreak::H2),
+    (0xC695, 0xC6AF, LineBreak::H3),
+    (0xC6B0, 0xC6B0, LineBreak::H2),
+    (0xC6B1, 0xC6CB, LineBreak::H3),
+    (0xC6CC, 0xC6CC, LineBreak::H2),
+    (0xC6CD, 0xC6E7, LineBreak::H3),
+    (0xC6E8, 0xC6E8, LineBreak::H2),
+    (0xC6E9, 0xC703, LineBreak::H3),
+    (0xC704, 0xC704, LineBreak::H2),
+    (0xC705, 0xC71F, LineBreak::H3),
+    (0xC720, 0xC720, LineBreak::H2),
+    (0xC721, 0xC73B, LineBreak::H3),
+    (0xC73C, 0xC73C, LineBreak::H2),
+    (0xC73D, 0xC757, LineBreak::H3),
+    (0xC758, 0xC758, LineBreak::H2),
+    (0xC759, 0xC773, LineBreak::H3),
+    (0xC774, 0xC774, LineBreak::H2),
+    (0xC775, 0xC78F, LineBreak::H3),
+    (0xC790, 0xC790, LineBreak::H2),
+    (0xC791, 0xC7AB, LineBreak::H3),
+    (0xC7AC, 0xC7AC, LineBreak::H2),
+    (0xC7AD, 0xC7C7, LineBreak::H3),
+    (0xC7C8, 0xC7C8, LineBreak::H2),
+    (0xC7C9, 0xC7E3, LineBreak::H3),
+    (0xC7E4, 0xC7E4, LineBreak::H2),
+    (0xC7E5, 0xC7FF, LineBreak::H3),
+    (0xC800, 0xC800, LineBreak::H2),
+    (0xC801, 0xC81B, LineBreak::H3),
+    (0xC81C, 0xC81C, LineBreak::H2),
+    (0xC81D, 0xC837, LineBreak::H3),
+    (0xC838, 0xC838, LineBreak::H2),
+    (0xC839, 0xC853, LineBreak::H3),
+    (0xC854, 0xC854, LineBreak::H2),
+    (0xC855, 0xC86F, LineBreak::H3),
+    (0xC870, 0xC870, LineBreak::H2),
+    (0xC871, 0xC88B, LineBreak::H3),
+    (0xC88C, 0xC88C, LineBreak::H2),
+    (0xC88D, 0xC8A7, LineBreak::H3),
+    (0xC8A8, 0xC8A8, LineBreak::H2),
+    (0xC8A9, 0xC8C3, LineBreak::H3),
+    (0xC8C4, 0xC8C4, LineBreak::H2),
+    (0xC8C5, 0xC8DF, LineBreak::H3),
+    (0xC8E0, 0xC8E0, LineBreak::H2),
+    (0xC8E1, 0xC8FB, LineBreak::H3),
+    (0xC8FC, 0xC8FC, LineBreak::H2),
+    (0xC8FD, 0xC917, LineBreak::H3),
+    (0xC918, 0xC918, LineBreak::H2),
+    (0xC919, 0xC933, LineBreak::H3),
+    (0xC934, 0xC934, LineBreak::H2),
+    (0xC935, 0xC94F, LineBreak::H3),
+    (0xC950, 0xC950, LineBreak::H2),
+    (0xC951, 0xC96B, LineBreak::H3),
+    (0xC96C, 0xC96C, LineBreak::H2),
+    (0xC96D, 0xC987, LineBreak::H3),
+    (0xC988, 0xC988, LineBreak::H2),
+    (0xC989, 0xC9A3, LineBreak::H3),
+    (0xC9A4, 0xC9A4, LineBreak::H2),
+    (0xC9A5, 0xC9BF, LineBreak::H3),
+    (0xC9C0, 0xC9C0, LineBreak::H2),
+    (0xC9C1, 0xC9DB, LineBreak::H3),
+    (0xC9DC, 0xC9DC, LineBreak::H2),
+    (0xC9DD, 0xC9F7, LineBreak::H3),
+    (0xC9F8, 0xC9F8, LineBreak::H2),
+    (0xC9F9, 0xCA13, LineBreak::H3),
+    (0xCA14, 0xCA14, LineBreak::H2),
+    (0xCA15, 0xCA2F, LineBreak::H3),
+    (0xCA30, 0xCA30, LineBreak::H2),
+    (0xCA31, 0xCA4B, LineBreak::H3),
+    (0xCA4C, 0xCA4C, LineBreak::H2),
+    (0xCA4D, 0xCA67, LineBreak::H3),
+    (0xCA68, 0xCA68, LineBreak::H2),
+    (0xCA69, 0xCA83, LineBreak::H3),
+    (0xCA84, 0xCA84, LineBreak::H2),
+    (0xCA85, 0xCA9F, LineBreak::H3),
+    (0xCAA0, 0xCAA0, LineBreak::H2),
+    (0xCAA1, 0xCABB, LineBreak::H3),
+    (0xCABC, 0xCABC, LineBreak::H2),
+    (0xCABD, 0xCAD7, LineBreak::H3),
+    (0xCAD8, 0xCAD8, LineBreak::H2),
+    (0xCAD9, 0xCAF3, LineBreak::H3),
+    (0xCAF4, 0xCAF4, LineBreak::H2),
+    (0xCAF5, 0xCB0F, LineBreak::H3),
+    (0xCB10, 0xCB10, LineBreak::H2),
+    (0xCB11, 0xCB2B, LineBreak::H3),
+    (0xCB2C, 0xCB2C, LineBreak::H2),
+    (0xCB2D, 0xCB47, LineBreak::H3),
+    (0xCB48, 0xCB48, LineBreak::H2),
+    (0xCB49, 0xCB63, LineBreak::H3),
+    (0xCB64, 0xCB64, LineBreak::H2),
+    (0xCB65, 0xCB7F, LineBreak::H3),
+    (0xCB80, 0xCB80, LineBreak::H2),
+    (0xCB81, 0xCB9B, LineBreak::H3),
+    (0xCB9C, 0xCB9C, LineBreak::H2),
+    (0xCB9D, 0xCBB7, LineBreak::H3),
+    (0xCBB8, 0xCBB8, LineBreak::H2),
+    (0xCBB9, 0xCBD3, LineBreak::H3),
+    (0xCBD4, 0xCBD4, LineBreak::H2),
+    (0xCBD5, 0xCBEF, LineBreak::H3),
+    (0xCBF0, 0xCBF0, LineBreak::H2),
+    (0xCBF1, 0xCC0B, LineBreak::H3),
+    (0xCC0C, 0xCC0C, LineBreak::H2),
+    (0xCC0D, 0xCC27, LineBreak::H3),
+    (0xCC28, 0xCC28, LineBreak::H2),
+    (0xCC29, 0xCC43, LineBreak::H3),
+    (0xCC44, 0xCC44, LineBreak::H2),
+    (0xCC45, 0xCC5F, LineBreak::H3),
+    (0xCC60, 0xCC60, LineBreak::H2),
+    (0xCC61, 0xCC7B, LineBreak::H3),
+    (0xCC7C, 0xCC7C, LineBreak::H2),
+    (0xCC7D, 0xCC97, LineBreak::H3),
+    (0xCC98, 0xCC98, LineBreak::H2),
+    (0xCC99, 0xCCB3, LineBreak::H3),
+    (0xCCB4, 0xCCB4, LineBreak::H2),
+    (0xCCB5, 0xCCCF, LineBreak::H3),
+    (0xCCD0, 0xCCD0, LineBreak::H2),
+    (0xCCD1, 0xCCEB, LineBreak::H3),
+    (0xCCEC, 0xCCEC, LineBreak::H2),
+    (0xCCED, 0xCD07, LineBreak::H3),
+    (0xCD08, 0xCD08, LineBreak::H2),
+    (0xCD09, 0xCD23, LineBreak::H3),
+    (0xCD24, 0xCD24, LineBreak::H2),
+    (0xCD25, 0xCD3F, LineBreak::H3),
+    (0xCD40, 0xCD40, LineBreak::H2),
+    (0xCD41, 0xCD5B, LineBreak::H3),
+    (0xCD5C, 0xCD5C, LineBreak::H2),
+    (0xCD5D, 0xCD77, LineBreak::H3),
+    (0xCD78, 0xCD78, LineBreak::H2),
+    (0xCD79, 0xCD93, LineBreak::H3),
+    (0xCD94, 0xCD94, LineBreak::H2),
+    (0xCD95, 0xCDAF, LineBreak::H3),
+    (0xCDB0, 0xCDB0, LineBreak::H2),
+    (0xCDB1, 0xCDCB, LineBreak::H3),
+    (0xCDCC, 0xCDCC, LineBreak::H2),
+    (0xCDCD, 0xCDE7, LineBreak::H3),
+    (0xCDE8, 0xCDE8, LineBreak::H2),
+    (0xCDE9, 0xCE03, LineBreak::H3),
+    (0xCE04, 0xCE04, LineBreak::H2),
+    (0xCE05, 0xCE1F, LineBreak::H3),
+    (0xCE20, 0xCE20, LineBreak::H2),
+    (0xCE21, 0xCE3B, LineBreak::H3),
+    (0xCE3C, 0xCE3C, LineBreak::H2),
+    (0xCE3D, 0xCE57, LineBreak::H3),
+    (0xCE58, 0xCE58, LineBreak::H2),
+    (0xCE59, 0xCE73, LineBreak::H3),
+    (0xCE74, 0xCE74, LineBreak::H2),
+    (0xCE75, 0xCE8F, LineBreak::H3),
+    (0xCE90, 0xCE90, LineBreak::H2),
+    (0xCE91, 0xCEAB, LineBreak::H3),
+    (0xCEAC, 0xCEAC, LineBreak::H2),
+    (0xCEAD, 0xCEC7, LineBreak::H3),
+    (0xCEC8, 0xCEC8, LineBreak::H2),
+    (0xCEC9, 0xCEE3, LineBreak::H3),
+    (0xCEE4, 0xCEE4, LineBreak::H2),
+    (0xCEE5, 0xCEFF, LineBreak::H3),
+    (0xCF00, 0xCF00, LineBreak::H2),
+    (0xCF01, 0xCF1B, LineBreak::H3),
+    (0xCF1C, 0xCF1C, LineBreak::H2),
+    (0xCF1D, 0xCF37, LineBreak::H3),
+    (0xCF38, 0xCF38, LineBreak::H2),
+    (0xCF39, 0xCF53, LineBreak::H3),
+    (0xCF54, 0xCF54, LineBreak::H2),
+    (0xCF55, 0xCF6F, LineBreak::H3),
+    (0xCF70, 0xCF70, LineBreak::H2),
+    (0xCF71, 0xCF8B, LineBreak::H3),
+    (0xCF8C, 0xCF8C, LineBreak::H2),
+    (0xCF8D, 0xCFA7, LineBreak::H3),
+    (0xCFA8, 0xCFA8, LineBreak::H2),
+    (0xCFA9, 0xCFC3, LineBreak::H3),
+    (0xCFC4, 0xCFC4, LineBreak::H2),
+    (0xCFC5, 0xCFDF, LineBreak::H3),
+    (0xCFE0, 0xCFE0, LineBreak::H2),
+    (0xCFE1, 0xCFFB, LineBreak::H3),
+    (0xCFFC, 0xCFFC, LineBreak::H2),
+    (0xCFFD, 0xD017, LineBreak::H3),
+    (0xD018, 0xD018, LineBreak::H2),
+    (0xD019, 0xD033, LineBreak::H3),
+    (0xD034, 0xD034, LineBreak::H2),
+    (0xD035, 0xD04F, LineBreak::H3),
+    (0xD050, 0xD050, LineBreak::H2),
+    (0xD051, 0xD06B, LineBreak::H3),
+    (0xD06C, 0xD06C, LineBreak::H2),
+    (0xD06D, 0xD087, LineBreak::H3),
+    (0xD088, 0xD088, LineBreak::H2),
+    (0xD089, 0xD0A3, LineBreak::H3),
+    (0xD0A4, 0xD0A4, LineBreak::H2),
+    (0xD0A5, 0xD0BF, LineBreak::H3),
+    (0xD0C0, 0xD0C0, LineBreak::H2),
+    (0xD0C1, 0xD0DB, LineBreak::H3),
+    (0xD0DC, 0xD0DC, LineBreak::H2),
+    (0xD0DD, 0xD0F7, LineBreak::H3),
+    (0xD0F8, 0xD0F8, LineBreak::H2),
+    (0xD0F9, 0xD113, LineBreak::H3),
+    (0xD114, 0xD114, LineBreak::H2),
+    (0xD115, 0xD12F, LineBreak::H3),
+    (0xD130, 0xD130, LineBreak::H2),
+    (0xD131, 0xD14B, LineBreak::H3),
+    (0xD14C, 0xD14C, LineBreak::H2),
+    (0xD14D, 0xD167, LineBreak::H3),
+    (0xD168, 0xD168, LineBreak::H2),
+    (0xD169, 0xD183, LineBreak::H3),
+    (0xD184, 0xD184, LineBreak::H2),
+    (0xD185, 0xD19F, LineBreak::H3),
+    (0xD1A0, 0xD1A0, LineBreak::H2),
+    (0xD1A1, 0xD1BB, LineBreak::H3),
+    (0xD1BC, 0xD1BC, LineBreak::H2),
+    (0xD1BD, 0xD1D7, LineBreak::H3),
+    (0xD1D8, 0xD1D8, LineBreak::H2),
+    (0xD1D9, 0xD1F3, LineBreak::H3),
+    (0xD1F4, 0xD1F4, LineBreak::H2),
+    (0xD1F5, 0xD20F, LineBreak::H3),
+    (0xD210, 0xD210, LineBreak::H2),
+    (0xD211, 0xD22B, LineBreak::H3),
+    (0xD22C, 0xD22C, LineBreak::H2),
+    (0xD22D, 0xD247, LineBreak::H3),
+    (0xD248, 0xD248, LineBreak::H2),
+    (0xD249, 0xD263, LineBreak::H3),
+    (0xD264, 0xD264, LineBreak::H2),
+    (0xD265, 0xD27F, LineBreak::H3),
+    (0xD280, 0xD280, LineBreak::H2),
+    (0xD281, 0xD29B, LineBreak::H3),
+    (0xD29C, 0xD29C, LineBreak::H2),
+    (0xD29D, 0xD2B7, LineBreak::H3),
+    (0xD2B8, 0xD2B8, LineBreak::H2),
+    (0xD2B9, 0xD2D3, LineBreak::H3),
+    (0xD2D4, 0xD2D4, LineBreak::H2),
+    (0xD2D5, 0xD2EF, LineBreak::H3),
+    (0xD2F0, 0xD2F0, LineBreak::H2),
+    (0xD2F1, 0xD30B, LineBreak::H3),
+    (0xD30C, 0xD30C, LineBreak::H2),
+    (0xD30D, 0xD327, LineBreak::H3),
+    (0xD328, 0xD328, LineBreak::H2),
+    (0xD329, 0xD343, LineBreak::H3),
+    (0xD344, 0xD344, LineBreak::H2),
+    (0xD345, 0xD35F, LineBreak::H3),
+    (0xD360, 0xD360, LineBreak::H2),
+    (0xD361, 0xD37B, LineBreak::H3),
+    (0xD37C, 0xD37C, LineBreak::H2),
+    (0xD37D, 0xD397, LineBreak::H3),
+    (0xD398, 0xD398, LineBreak::H2),
+    (0xD399, 0xD3B3, LineBreak::H3),
+    (0xD3B4, 0xD3B4, LineBreak::H2),
+    (0xD3B5, 0xD3CF, LineBreak::H3),
+    (0xD3D0, 0xD3D0, LineBreak::H2),
+    (0xD3D1, 0xD3EB, LineBreak::H3),
+    (0xD3EC, 0xD3EC, LineBreak::H2),
+    (0xD3ED, 0xD407, LineBreak::H3),
+    (0xD408, 0xD408, LineBreak::H2),
+    (0xD409, 0xD423, LineBreak::H3),
+    (0xD424, 0xD424, LineBreak::H2),
+    (0xD425, 0xD43F, LineBreak::H3),
+    (0xD440, 0xD440, LineBreak::H2),
+    (0xD441, 0xD45B, LineBreak::H3),
+    (0xD45C, 0xD45C, LineBreak::H2),
+    (0xD45D, 0xD477, LineBreak::H3),
+    (0xD478, 0xD478, LineBreak::H2),
+    (0xD479, 0xD493, LineBreak::H3),
+    (0xD494, 0xD494, LineBreak::H2),
+    (0xD495, 0xD4AF, LineBreak::H3),
+    (0xD4B0, 0xD4B0, LineBreak::H2),
+    (0xD4B1, 0xD4CB, LineBreak::H3),
+    (0xD4CC, 0xD4CC, LineBreak::H2),
+    (0xD4CD, 0xD4E7, LineBreak::H3),
+    (0xD4E8, 0xD4E8, LineBreak::H2),
+    (0xD4E9, 0xD503, LineBreak::H3),
+    (0xD504, 0xD504, LineBreak::H2),
+    (0xD505, 0xD51F, LineBreak::H3),
+    (0xD520, 0xD520, LineBreak::H2),
+    (0xD521, 0xD53B, LineBreak::H3),
+    (0xD53C, 0xD53C, LineBreak::H2),
+    (0xD53D, 0xD557, LineBreak::H3),
+    (0xD558, 0xD558, LineBreak::H2),
+    (0xD559, 0xD573, LineBreak::H3),
+    (0xD574, 0xD574, LineBreak::H2),
+    (0xD575, 0xD58F, LineBreak::H3),
+    (0xD590, 0xD590, LineBreak::H2),
+    (0xD591, 0xD5AB, LineBreak::H3),
+    (0xD5AC, 0xD5AC, LineBreak::H2),
+    (0xD5AD, 0xD5C7, LineBreak::H3),
+    (0xD5C8, 0xD5C8, LineBreak::H2),
+    (0xD5C9, 0xD5E3, LineBreak::H3),
+    (0xD5E4, 0xD5E4, LineBreak::H2),
+    (0xD5E5, 0xD5FF, LineBreak::H3),
+    (0xD600, 0xD600, LineBreak::H2),
+    (0xD601, 0xD61B, LineBreak::H3),
+    (0xD61C, 0xD61C, LineBreak::H2),
+    (0xD61D, 0xD637, LineBreak::H3),
+    (0xD638, 0xD638, LineBreak::H2),
+    (0xD639, 0xD653, LineBreak::H3),
+    (0xD654, 0xD654, LineBreak::H2),
+    (0xD655, 0xD66F, LineBreak::H3),
+    (0xD670, 0xD670, LineBreak::H2),
+    (0xD671, 0xD68B, LineBreak::H3),
+    (0xD68C, 0xD68C, LineBreak::H2),
+    (0xD68D, 0xD6A7, LineBreak::H3),
+    (0xD6A8, 0xD6A8, LineBreak::H2),
+    (0xD6A9, 0xD6C3, LineBreak::H3),
+    (0xD6C4, 0xD6C4, LineBreak::H2),
+    (0xD6C5, 0xD6DF, LineBreak::H3),
+    (0xD6E0, 0xD6E0, LineBreak::H2),
+    (0xD6E1, 0xD6FB, LineBreak::H3),
+    (0xD6FC, 0xD6FC, LineBreak::H2),
+    (0xD6FD, 0xD717, LineBreak::H3),
+    (0xD718, 0xD718, LineBreak::H2),
+    (0xD719, 0xD733, LineBreak::H3),
+    (0xD734, 0xD734, LineBreak::H2),
+    (0xD735, 0xD74F, LineBreak::H3),
+    (0xD750, 0xD750, LineBreak::H2),
+    (0xD751, 0xD76B, LineBreak::H3),
+    (0xD76C, 0xD76C, LineBreak::H2),
+    (0xD76D, 0xD787, LineBreak::H3),
+    (0xD788, 0xD788, LineBreak::H2),
+    (0xD789, 0xD7A3, LineBreak::H3),
+    (0xD7B0, 0xD7C6, LineBreak::JV),
+    (0xD7CB, 0xD7FB, LineBreak::JT),
+    (0xD800, 0xDFFF, LineBreak::Surrogate),
+    (0xF900, 0xFAFF, LineBreak::Ideographic),
+    (0xFB00, 0xFB06, LineBreak::Alphabetic),
+    (0xFB13, 0xFB17, LineBreak::Alphabetic),
+    (0xFB1D, 0xFB1D, LineBreak::HebrewLetter),
+    (0xFB1E, 0xFB1E, LineBreak::CombiningMark),
+    (0xFB1F, 0xFB28, LineBreak::HebrewLetter),
+    (0xFB29, 0xFB29, LineBreak::Alphabetic),
+    (0xFB2A, 0xFB36, LineBreak::HebrewLetter),
+    (0xFB38, 0xFB3C, LineBreak::HebrewLetter),
+    (0xFB3E, 0xFB3E, LineBreak::HebrewLetter),
+    (0xFB40, 0xFB41, LineBreak::HebrewLetter),
+    (0xFB43, 0xFB44, LineBreak::HebrewLetter),
+    (0xFB46, 0xFB4F, LineBreak::HebrewLetter),
+    (0xFB50, 0xFBC1, LineBreak::Alphabetic),
+    (0xFBD3, 0xFD3D, LineBreak::Alphabetic),
+    (0xFD3E, 0xFD3E, LineBreak::ClosePunctuation),
+    (0xFD3F, 0xFD3F, LineBreak::OpenPunctuation),
+    (0xFD50, 0xFD8F, LineBreak::Alphabetic),
+    (0xFD92, 0xFDC7, LineBreak::Alphabetic),
+    (0xFDF0, 0xFDFB, LineBreak::Alphabetic),
+    (0xFDFC, 0xFDFC, LineBreak::PostfixNumeric),
+    (0xFDFD, 0xFDFD, LineBreak::Alphabetic),
+    (0xFE00, 0xFE0F, LineBreak::CombiningMark),
+    (0xFE10, 0xFE10, LineBreak::InfixNumeric),
+    (0xFE11, 0xFE12, LineBreak::ClosePunctuation),
+    (0xFE13, 0xFE14, LineBreak::InfixNumeric),
+    (0xFE15, 0xFE16, LineBreak::Exclamation),
+    (0xFE17, 0xFE17, LineBreak::OpenPunctuation),
+    (0xFE18, 0xFE18, LineBreak::ClosePunctuation),
+    (0xFE19, 0xFE19, LineBreak::Inseparable),
+    (0xFE20, 0xFE2F, LineBreak::CombiningMark),
+    (0xFE30, 0xFE34, LineBreak::Ideographic),
+    (0xFE35, 0xFE35, LineBreak::OpenPunctuation),
+    (0xFE36, 0xFE36, LineBreak::ClosePunctuation),
+    (0xFE37, 0xFE37, LineBreak::OpenPunctuation),
+    (0xFE38, 0xFE38, LineBreak::ClosePunctuation),
+    (0xFE39, 0xFE39, LineBreak::OpenPunctuation),
+    (0xFE3A, 0xFE3A, LineBreak::ClosePunctuation),
+    (0xFE3B, 0xFE3B, LineBreak::OpenPunctuation),
+    (0xFE3C, 0xFE3C, LineBreak::ClosePunctuation),
+    (0xFE3D, 0xFE3D, LineBreak::OpenPunctuation),
+    (0xFE3E, 0xFE3E, LineBreak::ClosePunctuation),
+    (0xFE3F, 0xFE3F, LineBreak::OpenPunctuation),
+    (0xFE40, 0xFE40, LineBreak::ClosePunctuation),
+    (0xFE41, 0xFE41, LineBreak::OpenPunctuation),
+    (0xFE42, 0xFE42, LineBreak::ClosePunctuation),
+    (0xFE43, 0xFE43, LineBreak::OpenPunctuation),
+    (0xFE44, 0xFE44, LineBreak::ClosePunctuation),
+    (0xFE45, 0xFE46, LineBreak::Ideographic),
+    (0xFE47, 0xFE47, LineBreak::OpenPunctuation),
+    (0xFE48, 0xFE48, LineBreak::ClosePunctuation),
+    (0xFE49, 0xFE4F, LineBreak::Ideographic),
+    (0xFE50, 0xFE50, LineBreak::ClosePunctuation),
+    (0xFE51, 0xFE51, LineBreak::Ideographic),
+    (0xFE52, 0xFE52, LineBreak::ClosePunctuation),
+    (0xFE54, 0xFE55, LineBreak::Nonstarter),
+    (0xFE56, 0xFE57, LineBreak::Exclamation),
+    (0xFE58, 0xFE58, LineBreak::Ideographic),
+    (0xFE59, 0xFE59, LineBreak::OpenPunctuation),
+    (0xFE5A, 0xFE5A, LineBreak::ClosePunctuation),
+    (0xFE5B, 0xFE5B, LineBreak::OpenPunctuation),
+    (0xFE5C, 0xFE5C, LineBreak::ClosePunctuation),
+    (0xFE5D, 0xFE5D, LineBreak::OpenPunctuation),
+    (0xFE5E, 0xFE5E, LineBreak::ClosePunctuation),
+    (0xFE5F, 0xFE66, LineBreak::Ideographic),
+    (0xFE68, 0xFE68, LineBreak::Ideographic),
+    (0xFE69, 0xFE69, LineBreak::PrefixNumeric),
+    (0xFE6A, 0xFE6A, LineBreak::PostfixNumeric),
+    (0xFE6B, 0xFE6B, LineBreak::Ideographic),
+    (0xFE70, 0xFE74, LineBreak::Alphabetic),
+    (0xFE76, 0xFEFC, LineBreak::Alphabetic),
+    (0xFEFF, 0xFEFF, LineBreak::WordJoiner),
+    (0xFF01, 0xFF01, LineBreak::Exclamation),
+    (0xFF02, 0xFF03, LineBreak::Ideographic),
+    (0xFF04, 0xFF04, LineBreak::PrefixNumeric),
+    (0xFF05, 0xFF05, LineBreak::PostfixNumeric),
+    (0xFF06, 0xFF07, LineBreak::Ideographic),
+    (0xFF08, 0xFF08, LineBreak::OpenPunctuation),
+    (0xFF09, 0xFF09, LineBreak::ClosePunctuation),
+    (0xFF0A, 0xFF0B, LineBreak::Ideographic),
+    (0xFF0C, 0xFF0C, LineBreak::ClosePunctuation),
+    (0xFF0D, 0xFF0D, LineBreak::Ideographic),
+    (0xFF0E, 0xFF0E, LineBreak::ClosePunctuation),
+    (0xFF0F, 0xFF19, LineBreak::Ideographic),
+    (0xFF1A, 0xFF1B, LineBreak::Nonstarter),
+    (0xFF1C, 0xFF1E, LineBreak::Ideographic),
+    (0xFF1F, 0xFF1F, LineBreak::Exclamation),
+    (0xFF20, 0xFF3A, LineBreak::Ideographic),
+    (0xFF3B, 0xFF3B, LineBreak::OpenPunctuation),
+    (0xFF3C, 0xFF3C, LineBreak::Ideographic),
+    (0xFF3D, 0xFF3D, LineBreak::ClosePunctuation),
+    (0xFF3E, 0xFF5A, LineBreak::Ideographic),
+    (0xFF5B, 0xFF5B, LineBreak::OpenPunctuation),
+    (0xFF5C, 0xFF5C, LineBreak::Ideographic),
+    (0xFF5D, 0xFF5D, LineBreak::ClosePunctuation),
+    (0xFF5E, 0xFF5E, LineBreak::Ideographic),
+    (0xFF5F, 0xFF5F, LineBreak::OpenPunctuation),
+    (0xFF60, 0xFF61, LineBreak::ClosePunctuation),
+    (0xFF62, 0xFF62, LineBreak::OpenPunctuation),
+    (0xFF63, 0xFF64, LineBreak::ClosePunctuation),
+    (0xFF65, 0xFF65, LineBreak::Nonstarter),
+    (0xFF66, 0xFF66, LineBreak::Ideographic),
+    (0xFF67, 0xFF70, LineBreak::ConditionalJapaneseStarter),
+    (0xFF71, 0xFF9D, LineBreak::Ideographic),
+    (0xFF9E, 0xFF9F, LineBreak::Nonstarter),
+    (0xFFA0, 0xFFBE, LineBreak::Ideographic),
+    (0xFFC2, 0xFFC7, LineBreak::Ideographic),
+    (0xFFCA, 0xFFCF, LineBreak::Ideographic),
+    (0xFFD2, 0xFFD7, LineBreak::Ideographic),
+    (0xFFDA, 0xFFDC, LineBreak::Ideographic),
+    (0xFFE0, 0xFFE0, LineBreak::PostfixNumeric),
+    (0xFFE1, 0xFFE1, LineBreak::PrefixNumeric),
+    (0xFFE2, 0xFFE4, LineBreak::Ideographic),
+    (0xFFE5, 0xFFE6, LineBreak::PrefixNumeric),
+    (0xFFE8, 0xFFEE, LineBreak::Alphabetic),
+    (0xFFF9, 0xFFFB, LineBreak::CombiningMark),
+    (0xFFFC, 0xFFFC, LineBreak::ContingentBreak),
+    (0xFFFD, 0xFFFD, LineBreak::Ambiguous),
+    (0x10000, 0x1000B, LineBreak::Alphabetic),
+    (0x1000D, 0x10026, LineBreak::Alphabetic),
+    (0x10028, 0x1003A, LineBreak::Alphabetic),
+    (0x1003C, 0x1003D, LineBreak::Alphabetic),
+    (0x1003F, 0x1004D, LineBreak::Alphabetic),
+    (0x10050, 0x1005D, LineBreak::Alphabetic),
+    (0x10080, 0x100FA, LineBreak::Alphabetic),
+    (0x10100, 0x10102, LineBreak::BreakAfter),
+    (0x10107, 0x10133, LineBreak::Alphabetic),
+    (0x10137, 0x1018E, LineBreak::Alphabetic),
+    (0x10190, 0x1019C, LineBreak::Alphabetic),
+    (0x101A0, 0x101A0, LineBreak::Alphabetic),
+    (0x101D0, 0x101FC, LineBreak::Alphabetic),
+    (0x101FD, 0x101FD, LineBreak::CombiningMark),
+    (0x10280, 0x1029C, LineBreak::Alphabetic),
+    (0x102A0, 0x102D0, LineBreak::Alphabetic),
+    (0x102E0, 0x102E0, LineBreak::CombiningMark),
+    (0x102E1, 0x102FB, LineBreak::Alphabetic),
+    (0x10300, 0x10323, LineBreak::Alphabetic),
+    (0x1032D, 0x1034A, LineBreak::Alphabetic),
+    (0x10350, 0x10375, LineBreak::Alphabetic),
+    (0x10376, 0x1037A, LineBreak::CombiningMark),
+    (0x10380, 0x1039D, LineBreak::Alphabetic),
+    (0x1039F, 0x1039F, LineBreak::BreakAfter),
+    (0x103A0, 0x103C3, LineBreak::Alphabetic),
+    (0x103C8, 0x103CF, LineBreak::Alphabetic),
+    (0x103D0, 0x103D0, LineBreak::BreakAfter),
+    (0x103D1, 0x103D5, LineBreak::Alphabetic),
+    (0x10400, 0x1049D, LineBreak::Alphabetic),
+    (0x104A0, 0x104A9, LineBreak::Numeric),
+    (0x104B0, 0x104D3, LineBreak::Alphabetic),
+    (0x104D8, 0x104FB, LineBreak::Alphabetic),
+    (0x10500, 0x10527, LineBreak::Alphabetic),
+    (0x10530, 0x10563, LineBreak::Alphabetic),
+    (0x1056F, 0x1056F, LineBreak::Alphabetic),
+    (0x10600, 0x10736, LineBreak::Alphabetic),
+    (0x10740, 0x10755, LineBreak::Alphabetic),
+    (0x10760, 0x10767, LineBreak::Alphabetic),
+    (0x10800, 0x10805, LineBreak::Alphabetic),
+    (0x10808, 0x10808, LineBreak::Alphabetic),
+    (0x1080A, 0x10835, LineBreak::Alphabetic),
+    (0x10837, 0x10838, LineBreak::Alphabetic),
+    (0x1083C, 0x1083C, LineBreak::Alphabetic),
+    (0x1083F, 0x10855, LineBreak::Alphabetic),
+    (0x10857, 0x10857, LineBreak::BreakAfter),
+    (0x10858, 0x1089E, LineBreak::Alphabetic),
+    (0x108A7, 0x108AF, LineBreak::Alphabetic),
+    (0x108E0, 0x108F2, LineBreak::Alphabetic),
+    (0x108F4, 0x108F5, LineBreak::Alphabetic),
+    (0x108FB, 0x1091B, LineBreak::Alphabetic),
+    (0x1091F, 0x1091F, LineBreak::BreakAfter),
+    (0x10920, 0x10939, LineBreak::Alphabetic),
+    (0x1093F, 0x1093F, LineBreak::Alphabetic),
+    (0x10980, 0x109B7, LineBreak::Alphabetic),
+    (0x109BC, 0x109CF, LineBreak::Alphabetic),
+    (0x109D2, 0x10A00, LineBreak::Alphabetic),
+    (0x10A01, 0x10A03, LineBreak::CombiningMark),
+    (0x10A05, 0x10A06, LineBreak::CombiningMark),
+    (0x10A0C, 0x10A0F, LineBreak::CombiningMark),
+    (0x10A10, 0x10A13, LineBreak::Alphabetic),
+    (0x10A15, 0x10A17, LineBreak::Alphabetic),
+    (0x10A19, 0x10A35, LineBreak::Alphabetic),
+    (0x10A38, 0x10A3A, LineBreak::CombiningMark),
+    (0x10A3F, 0x10A3F, LineBreak::CombiningMark),
+    (0x10A40, 0x10A48, LineBreak::Alphabetic),
+    (0x10A50, 0x10A57, LineBreak::BreakAfter),
+    (0x10A58, 0x10A58, LineBreak::Alphabetic),
+    (0x10A60, 0x10A9F, LineBreak::Alphabetic),
+    (0x10AC0, 0x10AE4, LineBreak::Alphabetic),
+    (0x10AE5, 0x10AE6, LineBreak::CombiningMark),
+    (0x10AEB, 0x10AEF, LineBreak::Alphabetic),
+    (0x10AF0, 0x10AF5, LineBreak::BreakAfter),
+    (0x10AF6, 0x10AF6, LineBreak::Inseparable),
+    (0x10B00, 0x10B35, LineBreak::Alphabetic),
+    (0x10B39, 0x10B3F, LineBreak::BreakAfter),
+    (0x10B40, 0x10B55, LineBreak::Alphabetic),
+    (0x10B58, 0x10B72, LineBreak::Alphabetic),
+    (0x10B78, 0x10B91, LineBreak::Alphabetic),
+    (0x10B99, 0x10B9C, LineBreak::Alphabetic),
+    (0x10BA9, 0x10BAF, LineBreak::Alphabetic),
+    (0x10C00, 0x10C48, LineBreak::Alphabetic),
+    (0x10C80, 0x10CB2, LineBreak::Alphabetic),
+    (0x10CC0, 0x10CF2, LineBreak::Alphabetic),
+    (0x10CFA, 0x10D23, LineBreak::Alphabetic),
+    (0x10D24, 0x10D27, LineBreak::CombiningMark),
+    (0x10D30, 0x10D39, LineBreak::Numeric),
+    (0x10E60, 0x10E7E, LineBreak::Alphabetic),
+    (0x10E80, 0x10EA9, LineBreak::Alphabetic),
+    (0x10EAB, 0x10EAC, LineBreak::CombiningMark),
+    (0x10EAD, 0x10EAD, LineBreak::BreakAfter),
+    (0x10EB0, 0x10EB1, LineBreak::Alphabetic),
+    (0x10F00, 0x10F27, LineBreak::Alphabetic),
+    (0x10F30, 0x10F45, LineBreak::Alphabetic),
+    (0x10F46, 0x10F50, LineBreak::CombiningMark),
+    (0x10F51, 0x10F59, LineBreak::Alphabetic),
+    (0x10FB0, 0x10FCB, LineBreak::Alphabetic),
+    (0x10FE0, 0x10FF6, LineBreak::Alphabetic),
+    (0x11000, 0x11002, LineBreak::CombiningMark),
+    (0x11003, 0x11037, LineBreak::Alphabetic),
+    (0x11038, 0x11046, LineBreak::CombiningMark),
+    (0x11047, 0x11048, LineBreak::BreakAfter),
+    (0x11049, 0x1104D, LineBreak::Alphabetic),
+    (0x11052, 0x11065, LineBreak::Alphabetic),
+    (0x11066, 0x1106F, LineBreak::Numeric),
+    (0x1107F, 0x11082, LineBreak::CombiningMark),
+    (0x11083, 0x110AF, LineBreak::Alphabetic),
+    (0x110B0, 0x110BA, LineBreak::CombiningMark),
+    (0x110BB, 0x110BD, LineBreak::Alphabetic),
+    (0x110BE, 0x110C1, LineBreak::BreakAfter),
+    (0x110CD, 0x110CD, LineBreak::Alphabetic),
+    (0x110D0, 0x110E8, LineBreak::Alphabetic),
+    (0x110F0, 0x110F9, LineBreak::Numeric),
+    (0x11100, 0x11102, LineBreak::CombiningMark),
+    (0x11103, 0x11126, LineBreak::Alphabetic),
+    (0x11127, 0x11134, LineBreak::CombiningMark),
+    (0x11136, 0x1113F, LineBreak::Numeric),
+    (0x11140, 0x11143, LineBreak::BreakAfter),
+    (0x11144, 0x11144, LineBreak::Alphabetic),
+    (0x11145, 0x11146, LineBreak::CombiningMark),
+    (0x11147, 0x11147, LineBreak::Alphabetic),
+    (0x11150, 0x11172, LineBreak::Alphabetic),
+    (0x11173, 0x11173, LineBreak::CombiningMark),
+    (0x11174, 0x11174, LineBreak::Alphabetic),
+    (0x11175, 0x11175, LineBreak::BreakBefore),
+    (0x11176, 0x11176, LineBreak::Alphabetic),
+    (0x11180, 0x11182, LineBreak::CombiningMark),
+    (0x11183, 0x111B2, LineBreak::Alphabetic),
+    (0x111B3, 0x111C0, LineBreak::CombiningMark),
+    (0x111C1, 0x111C4, LineBreak::Alphabetic),
+    (0x111C5, 0x111C6, LineBreak::BreakAfter),
+    (0x111C7, 0x111C7, LineBreak::Alphabetic),
+    (0x111C8, 0x111C8, LineBreak::BreakAfter),
+    (0x111C9, 0x111CC, LineBreak::CombiningMark),
+    (0x111CD, 0x111CD, LineBreak::Alphabetic),
+    (0x111CE, 0x111CF, LineBreak::CombiningMark),
+    (0x111D0, 0x111D9, LineBreak::Numeric),
+    (0x111DA, 0x111DA, LineBreak::Alphabetic),
+    (0x111DB, 0x111DB, LineBreak::BreakBefore),
+    (0x111DC, 0x111DC, LineBreak::Alphabetic),
+    (0x111DD, 0x111DF, LineBreak::BreakAfter),
+    (0x111E1, 0x111F4, LineBreak::Alphabetic),
+    (0x11200, 0x11211, LineBreak::Alphabetic),
+    (0x11213, 0x1122B, LineBreak::Alphabetic),
+    (0x1122C, 0x11237, LineBreak::CombiningMark),
+    (0x11238, 0x11239, LineBreak::BreakAfter),
+    (0x1123A, 0x1123A, LineBreak::Alphabetic),
+    (0x1123B, 0x1123C, LineBreak::BreakAfter),
+    (0x1123D, 0x1123D, LineBreak::Alphabetic),
+    (0x1123E, 0x1123E, LineBreak::CombiningMark),
+    (0x11280, 0x11286, LineBreak::Alphabetic),
+    (0x11288, 0x11288, LineBreak::Alphabetic),
+    (0x1128A, 0x1128D, LineBreak::Alphabetic),
+    (0x1128F, 0x1129D, LineBreak::Alphabetic),
+    (0x1129F, 0x112A8, LineBreak::Alphabetic),
+    (0x112A9, 0x112A9, LineBreak::BreakAfter),
+    (0x112B0, 0x112DE, LineBreak::Alphabetic),
+    (0x112DF, 0x112EA, LineBreak::CombiningMark),
+    (0x112F0, 0x112F9, LineBreak::Numeric),
+    (0x11300, 0x11303, LineBreak::CombiningMark),
+    (0x11305, 0x1130C, LineBreak::Alphabetic),
+    (0x1130F, 0x11310, LineBreak::Alphabetic),
+    (0x11313, 0x11328, LineBreak::Alphabetic),
+    (0x1132A, 0x11330, LineBreak::Alphabetic),
+    (0x11332, 0x11333, LineBreak::Alphabetic),
+    (0x11335, 0x11339, LineBreak::Alphabetic),
+    (0x1133B, 0x1133C, LineBreak::CombiningMark),
+    (0x1133D, 0x1133D, LineBreak::Alphabetic),
+    (0x1133E, 0x11344, LineBreak::CombiningMark),
+    (0x11347, 0x11348, LineBreak::CombiningMark),
+    (0x1134B, 0x1134D, LineBreak::CombiningMark),
+    (0x11350, 0x11350, LineBreak::Alphabetic),
+    (0x11357, 0x11357, LineBreak::CombiningMark),
+    (0x1135D, 0x11361, LineBreak::Alphabetic),
+    (0x11362, 0x11363, LineBreak::CombiningMark),
+    (0x11366, 0x1136C, LineBreak::CombiningMark),
+    (0x11370, 0x11374, LineBreak::CombiningMark),
+    (0x11400, 0x11434, LineBreak::Alphabetic),
+    (0x11435, 0x11446, LineBreak::CombiningMark),
+    (0x11447, 0x1144A, LineBreak::Alphabetic),
+    (0x1144B, 0x1144E, LineBreak::BreakAfter),
+    (0x1144F, 0x1144F, LineBreak::Alphabetic),
+    (0x11450, 0x11459, LineBreak::Numeric),
+    (0x1145A, 0x1145B, LineBreak::BreakAfter),
+    (0x1145D, 0x1145D, LineBreak::Alphabetic),
+    (0x1145E, 0x1145E, LineBreak::CombiningMark),
+    (0x1145F, 0x11461, LineBreak::Alphabetic),
+    (0x11480, 0x114AF, LineBreak::Alphabetic),
+    (0x114B0, 0x114C3, LineBreak::CombiningMark),
+    (0x114C4, 0x114C7, LineBreak::Alphabetic),
+    (0x114D0, 0x114D9, LineBreak::Numeric),
+    (0x11580, 0x115AE, LineBreak::Alphabetic),
+    (0x115AF, 0x115B5, LineBreak::CombiningMark),
+    (0x115B8, 0x115C0, LineBreak::CombiningMark),
+    (0x115C1, 0x115C1, LineBreak::BreakBefore),
+    (0x115C2, 0x115C3, LineBreak::BreakAfter),
+    (0x115C4, 0x115C5, LineBreak::Exclamation),
+    (0x115C6, 0x115C8, LineBreak::Alphabetic),
+    (0x115C9, 0x115D7, LineBreak::BreakAfter),
+    (0x115D8, 0x115DB, LineBreak::Alphabetic),
+    (0x115DC, 0x115DD, LineBreak::CombiningMark),
+    (0x11600, 0x1162F, LineBreak::Alphabetic),
+    (0x11630, 0x11640, LineBreak::CombiningMark),
+    (0x11641, 0x11642, LineBreak::BreakAfter),
+    (0x11643, 0x11644, LineBreak::Alphabetic),
+    (0x11650, 0x11659, LineBreak::Numeric),
+    (0x11660, 0x1166C, LineBreak::BreakBefore),
+    (0x11680, 0x116AA, LineBreak::Alphabetic),
+    (0x116AB, 0x116B7, LineBreak::CombiningMark),
+    (0x116B8, 0x116B8, LineBreak::Alphabetic),
+    (0x116C0, 0x116C9, LineBreak::Numeric),
+    (0x11700, 0x1171A, LineBreak::ComplexContext),
+    (0x1171D, 0x1172B, LineBreak::ComplexContext),
+    (0x11730, 0x11739, LineBreak::Numeric),
+    (0x1173A, 0x1173B, LineBreak::ComplexContext),
+    (0x1173C, 0x1173E, LineBreak::BreakAfter),
+    (0x1173F, 0x1173F, LineBreak::ComplexContext),
+    (0x11800, 0x1182B, LineBreak::Alphabetic),
+    (0x1182C, 0x1183A, LineBreak::CombiningMark),
+    (0x1183B, 0x1183B, LineBreak::Alphabetic),
+    (0x118A0, 0x118DF, LineBreak::Alphabetic),
+    (0x118E0, 0x118E9, LineBreak::Numeric),
+    (0x118EA, 0x118F2, LineBreak::Alphabetic),
+    (0x118FF, 0x11906, LineBreak::Alphabetic),
+    (0x11909, 0x11909, LineBreak::Alphabetic),
+    (0x1190C, 0x11913, LineBreak::Alphabetic),
+    (0x11915, 0x11916, LineBreak::Alphabetic),
+    (0x11918, 0x1192F, LineBreak::Alphabetic),
+    (0x11930, 0x11935, LineBreak::CombiningMark),
+    (0x11937, 0x11938, LineBreak::CombiningMark),
+    (0x1193B, 0x1193E, LineBreak::CombiningMark),
+    (0x1193F, 0x1193F, LineBreak::Alphabetic),
+    (0x11940, 0x11940, LineBreak::CombiningMark),
+    (0x11941, 0x11941, LineBreak::Alphabetic),
+    (0x11942, 0x11943, LineBreak::CombiningMark),
+    (0x11944, 0x11946, LineBreak::BreakAfter),
+    (0x11950, 0x11959, LineBreak::Numeric),
+    (0x119A0, 0x119A7, LineBreak::Alphabetic),
+    (0x119AA, 0x119D0, LineBreak::Alphabetic),
+    (0x119D1, 0x119D7, LineBreak::CombiningMark),
+    (0x119DA, 0x119E0, LineBreak::CombiningMark),
+    (0x119E1, 0x119E1, LineBreak::Alphabetic),
+    (0x119E2, 0x119E2, LineBreak::BreakBefore),
+    (0x119E3, 0x119E3, LineBreak::Alphabetic),
+    (0x119E4, 0x119E4, LineBreak::CombiningMark),
+    (0x11A00, 0x11A00, LineBreak::Alphabetic),
+    (0x11A01, 0x11A0A, LineBreak::CombiningMark),
+    (0x11A0B, 0x11A32, LineBreak::Alphabetic),
+    (0x11A33, 0x11A39, LineBreak::CombiningMark),
+    (0x11A3A, 0x11A3A, LineBreak::Alphabetic),
+    (0x11A3B, 0x11A3E, LineBreak::CombiningMark),
+    (0x11A3F, 0x11A3F, LineBreak::BreakBefore),
+    (0x11A40, 0x11A40, LineBreak::Alphabetic),
+    (0x11A41, 0x11A44, LineBreak::BreakAfter),
+    (0x11A45, 0x11A45, LineBreak::BreakBefore),
+    (0x11A46, 0x11A46, LineBreak::Alphabetic),
+    (0x11A47, 0x11A47, LineBreak::CombiningMark),
+    (0x11A50, 0x11A50, LineBreak::Alphabetic),
+    (0x11A51, 0x11A5B, LineBreak::CombiningMark),
+    (0x11A5C, 0x11A89, LineBreak::Alphabetic),
+    (0x11A8A, 0x11A99, LineBreak::CombiningMark),
+    (0x11A9A, 0x11A9C, LineBreak::BreakAfter),
+    (0x11A9D, 0x11A9D, LineBreak::Alphabetic),
+    (0x11A9E, 0x11AA0, LineBreak::BreakBefore),
+    (0x11AA1, 0x11AA2, LineBreak::BreakAfter),
+    (0x11AC0, 0x11AF8, LineBreak::Alphabetic),
+    (0x11C00, 0x11C08, LineBreak::Alphabetic),
+    (0x11C0A, 0x11C2E, LineBreak::Alphabetic),
+    (0x11C2F, 0x11C36, LineBreak::CombiningMark),
+    (0x11C38, 0x11C3F, LineBreak::CombiningMark),
+    (0x11C40, 0x11C40, LineBreak::Alphabetic),
+    (0x11C41, 0x11C45, LineBreak::BreakAfter),
+    (0x11C50, 0x11C59, LineBreak::Numeric),
+    (0x11C5A, 0x11C6C, LineBreak::Alphabetic),
+    (0x11C70, 0x11C70, LineBreak::BreakBefore),
+    (0x11C71, 0x11C71, LineBreak::Exclamation),
+    (0x11C72, 0x11C8F, LineBreak::Alphabetic),
+    (0x11C92, 0x11CA7, LineBreak::CombiningMark),
+    (0x11CA9, 0x11CB6, LineBreak::CombiningMark),
+    (0x11D00, 0x11D06, LineBreak::Alphabetic),
+    (0x11D08, 0x11D09, LineBreak::Alphabetic),
+    (0x11D0B, 0x11D30, LineBreak::Alphabetic),
+    (0x11D31, 0x11D36, LineBreak::CombiningMark),
+    (0x11D3A, 0x11D3A, LineBreak::CombiningMark),
+    (0x11D3C, 0x11D3D, LineBreak::CombiningMark),
+    (0x11D3F, 0x11D45, LineBreak::CombiningMark),
+    (0x11D46, 0x11D46, LineBreak::Alphabetic),
+    (0x11D47, 0x11D47, LineBreak::CombiningMark),
+    (0x11D50, 0x11D59, LineBreak::Numeric),
+    (0x11D60, 0x11D65, LineBreak::Alphabetic),
+    (0x11D67, 0x11D68, LineBreak::Alphabetic),
+    (0x11D6A, 0x11D89, LineBreak::Alphabetic),
+    (0x11D8A, 0x11D8E, LineBreak::CombiningMark),
+    (0x11D90, 0x11D91, LineBreak::CombiningMark),
+    (0x11D93, 0x11D97, LineBreak::CombiningMark),
+    (0x11D98, 0x11D98, LineBreak::Alphabetic),
+    (0x11DA0, 0x11DA9, LineBreak::Numeric),
+    (0x11EE0, 0x11EF2, LineBreak::Alphabetic),
+    (0x11EF3, 0x11EF6, LineBreak::CombiningMark),
+    (0x11EF7, 0x11EF8, LineBreak::Alphabetic),
+    (0x11FB0, 0x11FB0, LineBreak::Alphabetic),
+    (0x11FC0, 0x11FDC, LineBreak::Alphabetic),
+    (0x11FDD, 0x11FE0, LineBreak::PostfixNumeric),
+    (0x11FE1, 0x11FF1, LineBreak::Alphabetic),
+    (0x11FFF, 0x11FFF, LineBreak::BreakAfter),
+    (0x12000, 0x12399, LineBreak::Alphabetic),
+    (0x12400, 0x1246E, LineBreak::Alphabetic),
+    (0x12470, 0x12474, LineBreak::BreakAfter),
+    (0x12480, 0x12543, LineBreak::Alphabetic),
+    (0x13000, 0x13257, LineBreak::Alphabetic),
+    (0x13258, 0x1325A, LineBreak::OpenPunctuation),
+    (0x1325B, 0x1325D, LineBreak::ClosePunctuation),
+    (0x1325E, 0x13281, LineBreak::Alphabetic),
+    (0x13282, 0x13282, LineBreak::ClosePunctuation),
+    (0x13283, 0x13285, LineBreak::Alphabetic),
+    (0x13286, 0x13286, LineBreak::OpenPunctuation),
+    (0x13287, 0x13287, LineBreak::ClosePunctuation),
+    (0x13288, 0x13288, LineBreak::OpenPunctuation),
+    (0x13289, 0x13289, LineBreak::ClosePunctuation),
+    (0x1328A, 0x13378, LineBreak::Alphabetic),
+    (0x13379, 0x13379, LineBreak::OpenPunctuation),
+    (0x1337A, 0x1337B, LineBreak::ClosePunctuation),
+    (0x1337C, 0x1342E, LineBreak::Alphabetic),
+    (0x13430, 0x13436, LineBreak::Glue),
+    (0x13437, 0x13437, LineBreak::OpenPunctuation),
+    (0x13438, 0x13438, LineBreak::ClosePunctuation),
+    (0x14400, 0x145CD, LineBreak::Alphabetic),
+    (0x145CE, 0x145CE, LineBreak::OpenPunctuation),
+    (0x145CF, 0x145CF, LineBreak::ClosePunctuation),
+    (0x145D0, 0x14646, LineBreak::Alphabetic),
+    (0x16800, 0x16A38, LineBreak::Alphabetic),
+    (0x16A40, 0x16A5E, LineBreak::Alphabetic),
+    (0x16A60, 0x16A69, LineBreak::Numeric),
+    (0x16A6E, 0x16A6F, LineBreak::BreakAfter),
+    (0x16AD0, 0x16AED, LineBreak::Alphabetic),
+    (0x16AF0, 0x16AF4, LineBreak::CombiningMark),
+    (0x16AF5, 0x16AF5, LineBreak::BreakAfter),
+    (0x16B00, 0x16B2F, LineBreak::Alphabetic),
+    (0x16B30, 0x16B36, LineBreak::CombiningMark),
+    (0x16B37, 0x16B39, LineBreak::BreakAfter),
+    (0x16B3A, 0x16B43, LineBreak::Alphabetic),
+    (0x16B44, 0x16B44, LineBreak::BreakAfter),
+    (0x16B45, 0x16B45, LineBreak::Alphabetic),
+    (0x16B50, 0x16B59, LineBreak::Numeric),
+    (0x16B5B, 0x16B61, LineBreak::Alphabetic),
+    (0x16B63, 0x16B77, LineBreak::Alphabetic),
+    (0x16B7D, 0x16B8F, LineBreak::Alphabetic),
+    (0x16E40, 0x16E96, LineBreak::Alphabetic),
+    (0x16E97, 0x16E98, LineBreak::BreakAfter),
+    (0x16E99, 0x16E9A, LineBreak::Alphabetic),
+    (0x16F00, 0x16F4A, LineBreak::Alphabetic),
+    (0x16F4F, 0x16F4F, LineBreak::CombiningMark),
+    (0x16F50, 0x16F50, LineBreak::Alphabetic),
+    (0x16F51, 0x16F87, LineBreak::CombiningMark),
+    (0x16F8F, 0x16F92, LineBreak::CombiningMark),
+    (0x16F93, 0x16F9F, LineBreak::Alphabetic),
+    (0x16FE0, 0x16FE3, LineBreak::Nonstarter),
+    (0x16FE4, 0x16FE4, LineBreak::Glue),
+    (0x16FF0, 0x16FF1, LineBreak::CombiningMark),
+    (0x17000, 0x187F7, LineBreak::Ideographic),
+    (0x18800, 0x18AFF, LineBreak::Ideographic),
+    (0x18B00, 0x18CD5, LineBreak::Alphabetic),
+    (0x18D00, 0x18D08, LineBreak::Ideographic),
+    (0x1B000, 0x1B11E, LineBreak::Ideographic),
+    (0x1B150, 0x1B152, LineBreak::ConditionalJapaneseStarter),
+    (0x1B164, 0x1B167, LineBreak::ConditionalJapaneseStarter),
+    (0x1B170, 0x1B2FB, LineBreak::Ideographic),
+    (0x1BC00, 0x1BC6A, LineBreak::Alphabetic),
+    (0x1BC70, 0x1BC7C, LineBreak::Alphabetic),
+    (0x1BC80, 0x1BC88, LineBreak::Alphabetic),
+    (0x1BC90, 0x1BC99, LineBreak::Alphabetic),
+    (0x1BC9C, 0x1BC9C, LineBreak::Alphabetic),
+    (0x1BC9D, 0x1BC9E, LineBreak::CombiningMark),
+    (0x1BC9F, 0x1BC9F, LineBreak::BreakAfter),
+    (0x1BCA0, 0x1BCA3, LineBreak::CombiningMark),
+    (0x1D000, 0x1D0F5, LineBreak::Alphabetic),
+    (0x1D100, 0x1D126, LineBreak::Alphabetic),
+    (0x1D129, 0x1D164, LineBreak::Alphabetic),
+    (0x1D165, 0x1D169, LineBreak::CombiningMark),
+    (0x1D16A, 0x1D16C, LineBreak::Alphabetic),
+    (0x1D16D, 0x1D182, LineBreak::CombiningMark),
+    (0x1D183, 0x1D184, LineBreak::Alphabetic),
+    (0x1D185, 0x1D18B, LineBreak::CombiningMark),
+    (0x1D18C, 0x1D1A9, LineBreak::Alphabetic),
+    (0x1D1AA, 0x1D1AD, LineBreak::CombiningMark),
+    (0x1D1AE, 0x1D1E8, LineBreak::Alphabetic),
+    (0x1D200, 0x1D241, LineBreak::Alphabetic),
+    (0x1D242, 0x1D244, LineBreak::CombiningMark),
+    (0x1D245, 0x1D245, LineBreak::Alphabetic),
+    (0x1D2E0, 0x1D2F3, LineBreak::Alphabetic),
+    (0x1D300, 0x1D356, LineBreak::Alphabetic),
+    (0x1D360, 0x1D378, LineBreak::Alphabetic),
+    (0x1D400, 0x1D454, LineBreak::Alphabetic),
+    (0x1D456, 0x1D49C, LineBreak::Alphabetic),
+    (0x1D49E, 0x1D49F, LineBreak::Alphabetic),
+    (0x1D4A2, 0x1D4A2, LineBreak::Alphabetic),
+    (0x1D4A5, 0x1D4A6, LineBreak::Alphabetic),
+    (0x1D4A9, 0x1D4AC, LineBreak::Alphabetic),
+    (0x1D4AE, 0x1D4B9, LineBreak::Alphabetic),
+    (0x1D4BB, 0x1D4BB, LineBreak::Alphabetic),
+    (0x1D4BD, 0x1D4C3, LineBreak::Alphabetic),
+    (0x1D4C5, 0x1D505, LineBreak::Alphabetic),
+    (0x1D507, 0x1D50A, LineBreak::Alphabetic),
+    (0x1D50D, 0x1D514, LineBreak::Alphabetic),
+    (0x1D516, 0x1D51C, LineBreak::Alphabetic),
+    (0x1D51E, 0x1D539, LineBreak::Alphabetic),
+    (0x1D53B, 0x1D53E, LineBreak::Alphabetic),
+    (0x1D540, 0x1D544, LineBreak::Alphabetic),
+    (0x1D546, 0x1D546, LineBreak::Alphabetic),
+    (0x1D54A, 0x1D550, LineBreak::Alphabetic),
+    (0x1D552, 0x1D6A5, LineBreak::Alphabetic),
+    (0x1D6A8, 0x1D7CB, LineBreak::Alphabetic),
+    (0x1D7CE, 0x1D7FF, LineBreak::Numeric),
+    (0x1D800, 0x1D9FF, LineBreak::Alphabetic),
+    (0x1DA00, 0x1DA36, LineBreak::CombiningMark),
+    (0x1DA37, 0x1DA3A, LineBreak::Alphabetic),
+    (0x1DA3B, 0x1DA6C, LineBreak::CombiningMark),
+    (0x1DA6D, 0x1DA74, LineBreak::Alphabetic),
+    (0x1DA75, 0x1DA75, LineBreak::CombiningMark),
+    (0x1DA76, 0x1DA83, LineBreak::Alphabetic),
+    (0x1DA84, 0x1DA84, LineBreak::CombiningMark),
+    (0x1DA85, 0x1DA86, LineBreak::Alphabetic),
+    (0x1DA87, 0x1DA8A, LineBreak::BreakAfter),
+    (0x1DA8B, 0x1DA8B, LineBreak::Alphabetic),
+    (0x1DA9B, 0x1DA9F, LineBreak::CombiningMark),
+    (0x1DAA1, 0x1DAAF, LineBreak::CombiningMark),
+    (0x1E000, 0x1E006, LineBreak::CombiningMark),
+    (0x1E008, 0x1E018, LineBreak::CombiningMark),
+    (0x1E01B, 0x1E021, LineBreak::CombiningMark),
+    (0x1E023, 0x1E024, LineBreak::CombiningMark),
+    (0x1E026, 0x1E02A, LineBreak::CombiningMark),
+    (0x1E100, 0x1E12C, LineBreak::Alphabetic),
+    (0x1E130, 0x1E136, LineBreak::CombiningMark),
+    (0x1E137, 0x1E13D, LineBreak::Alphabetic),
+    (0x1E140, 0x1E149, LineBreak::Numeric),
+    (0x1E14E, 0x1E14F, LineBreak::Alphabetic),
+    (0x1E2C0, 0x1E2EB, LineBreak::Alphabetic),
+    (0x1E2EC, 0x1E2EF, LineBreak::CombiningMark),
+    (0x1E2F0, 0x1E2F9, LineBreak::Numeric),
+    (0x1E2FF, 0x1E2FF, LineBreak::PrefixNumeric),
+    (0x1E800, 0x1E8C4, LineBreak::Alphabetic),
+    (0x1E8C7, 0x1E8CF, LineBreak::Alphabetic),
+    (0x1E8D0, 0x1E8D6, LineBreak::CombiningMark),
+    (0x1E900, 0x1E943, LineBreak::Alphabetic),
+    (0x1E944, 0x1E94A, LineBreak::CombiningMark),
+    (0x1E94B, 0x1E94B, LineBreak::Alphabetic),
+    (0x1E950, 0x1E959, LineBreak::Numeric),
+    (0x1E95E, 0x1E95F, LineBreak::OpenPunctuation),
+    (0x1EC71, 0x1ECAB, LineBreak::Alphabetic),
+    (0x1ECAC, 0x1ECAC, LineBreak::PostfixNumeric),
+    (0x1ECAD, 0x1ECAF, LineBreak::Alphabetic),
+    (0x1ECB0, 0x1ECB0, LineBreak::PostfixNumeric),
+    (0x1ECB1, 0x1ECB4, LineBreak::Alphabetic),
+    (0x1ED01, 0x1ED3D, LineBreak::Alphabetic),
+    (0x1EE00, 0x1EE03, LineBreak::Alphabetic),
+    (0x1EE05, 0x1EE1F, LineBreak::Alphabetic),
+    (0x1EE21, 0x1EE22, LineBreak::Alphabetic),
+    (0x1EE24, 0x1EE24, LineBreak::Alphabetic),
+    (0x1EE27, 0x1EE27, LineBreak::Alphabetic),
+    (0x1EE29, 0x1EE32, LineBreak::Alphabetic),
+    (0x1EE34, 0x1EE37, LineBreak::Alphabetic),
+    (0x1EE39, 0x1EE39, LineBreak::Alphabetic),
+    (0x1EE3B, 0x1EE3B, LineBreak::Alphabetic),
+    (0x1EE42, 0x1EE42, LineBreak::Alphabetic),
+    (0x1EE47, 0x1EE47, LineBreak::Alphabetic),
+    (0x1EE49, 0x1EE49, LineBreak::Alphabetic),
+    (0x1EE4B, 0x1EE4B, LineBreak::Alphabetic),
+    (0x1EE4D, 0x1EE4F, LineBreak::Alphabetic),
+    (0x1EE51, 0x1EE52, LineBreak::Alphabetic),
+    (0x1EE54, 0x1EE54, LineBreak::Alphabetic),
+    (0x1EE57, 0x1EE57, LineBreak::Alphabetic),
+    (0x1EE59, 0x1EE59, LineBreak::Alphabetic),
+    (0x1EE5B, 0x1EE5B, LineBreak::Alphabetic),
+    (0x1EE5D, 0x1EE5D, LineBreak::Alphabetic),
+    (0x1EE5F, 0x1EE5F, LineBreak::Alphabetic),
+    (0x1EE61, 0x1EE62, LineBreak::Alphabetic),
+    (0x1EE64, 0x1EE64, LineBreak::Alphabetic),
+    (0x1EE67, 0x1EE6A, LineBreak::Alphabetic),
+    (0x1EE6C, 0x1EE72, LineBreak::Alphabetic),
+    (0x1EE74, 0x1EE77, LineBreak::Alphabetic),
+    (0x1EE79, 0x1EE7C, LineBreak::Alphabetic),
+    (0x1EE7E, 0x1EE7E, LineBreak::Alphabetic),
+    (0x1EE80, 0x1EE89, LineBreak::Alphabetic),
+    (0x1EE8B, 0x1EE9B, LineBreak::Alphabetic),
+    (0x1EEA1, 0x1EEA3, LineBreak::Alphabetic),
+    (0x1EEA5, 0x1EEA9, LineBreak::Alphabetic),
+    (0x1EEAB, 0x1EEBB, LineBreak::Alphabetic),
+    (0x1EEF0, 0x1EEF1, LineBreak::Alphabetic),
+    (0x1F000, 0x1F0FF, LineBreak::Ideographic),
+    (0x1F100, 0x1F10C, LineBreak::Ambiguous),
+    (0x1F10D, 0x1F10F, LineBreak::Ideographic),
+    (0x1F110, 0x1F12D, LineBreak::Ambiguous),
+    (0x1F12E, 0x1F12F, LineBreak::Alphabetic),
+    (0x1F130, 0x1F169, LineBreak::Ambiguous),
+    (0x1F16A, 0x1F16C, LineBreak::Alphabetic),
+    (0x1F16D, 0x1F16F, LineBreak::Ideographic),
+    (0x1F170, 0x1F1AC, LineBreak::Ambiguous),
+    (0x1F1AD, 0x1F1E5, LineBreak::Ideographic),
+    (0x1F1E6, 0x1F1FF, LineBreak::RegionalIndicator),
+    (0x1F200, 0x1F384, LineBreak::Ideographic),
+    (0x1F385, 0x1F385, LineBreak::EBase),
+    (0x1F386, 0x1F39B, LineBreak::Ideographic),
+    (0x1F39C, 0x1F39D, LineBreak::Alphabetic),
+    (0x1F39E, 0x1F3B4, LineBreak::Ideographic),
+    (0x1F3B5, 0x1F3B6, LineBreak::Alphabetic),
+    (0x1F3B7, 0x1F3BB, LineBreak::Ideographic),
+    (0x1F3BC, 0x1F3BC, LineBreak::Alphabetic),
+    (0x1F3BD, 0x1F3C1, LineBreak::Ideographic),
+    (0x1F3C2, 0x1F3C4, LineBreak::EBase),
+    (0x1F3C5, 0x1F3C6, LineBreak::Ideographic),
+    (0x1F3C7, 0x1F3C7, LineBreak::EBase),
+    (0x1F3C8, 0x1F3C9, LineBreak::Ideographic),
+    (0x1F3CA, 0x1F3CC, LineBreak::EBase),
+    (0x1F3CD, 0x1F3FA, LineBreak::Ideographic),
+    (0x1F3FB, 0x1F3FF, LineBreak::EModifier),
+    (0x1F400, 0x1F441, LineBreak::Ideographic),
+    (0x1F442, 0x1F443, LineBreak::EBase),
+    (0x1F444, 0x1F445, LineBreak::Ideographic),
+    (0x1F446, 0x1F450, LineBreak::EBase),
+    (0x1F451, 0x1F465, LineBreak::Ideographic),
+    (0x1F466, 0x1F478, LineBreak::EBase),
+    (0x1F479, 0x1F47B, LineBreak::Ideographic),
+    (0x1F47C, 0x1F47C, LineBreak::EBase),
+    (0x1F47D, 0x1F480, LineBreak::Ideographic),
+    (0x1F481, 0x1F483, LineBreak::EBase),
+    (0x1F484, 0x1F484, LineBreak::Ideographic),
+    (0x1F485, 0x1F487, LineBreak::EBase),
+    (0x1F488, 0x1F48E, LineBreak::Ideographic),
+    (0x1F48F, 0x1F48F, LineBreak::EBase),
+    (0x1F490, 0x1F490, LineBreak::Ideographic),
+    (0x1F491, 0x1F491, LineBreak::EBase),
+    (0x1F492, 0x1F49F, LineBreak::Ideographic),
+    (0x1F4A0, 0x1F4A0, LineBreak::Alphabetic),
+    (0x1F4A1, 0x1F4A1, LineBreak::Ideographic),
+    (0x1F4A2, 0x1F4A2, LineBreak::Alphabetic),
+    (0x1F4A3, 0x1F4A3, LineBreak::Ideographic),
+    (0x1F4A4, 0x1F4A4, LineBreak::Alphabetic),
+    (0x1F4A5, 0x1F4A9, LineBreak::Ideographic),
+    (0x1F4AA, 0x1F4AA, LineBreak::EBase),
+    (0x1F4AB, 0x1F4AE, LineBreak::Ideographic),
+    (0x1F4AF, 0x1F4AF, LineBreak::Alphabetic),
+    (0x1F4B0, 0x1F4B0, LineBreak::Ideographic),
+    (0x1F4B1, 0x1F4B2, LineBreak::Alphabetic),
+    (0x1F4B3, 0x1F4FF, LineBreak::Ideographic),
+    (0x1F500, 0x1F506, LineBreak::Alphabetic),
+    (0x1F507, 0x1F516, LineBreak::Ideographic),
+    (0x1F517, 0x1F524, LineBreak::Alphabetic),
+    (0x1F525, 0x1F531, LineBreak::Ideographic),
+    (0x1F532, 0x1F549, LineBreak::Alphabetic),
+    (0x1F54A, 0x1F573, LineBreak::Ideographic),
+    (0x1F574, 0x1F575, LineBreak::EBase),
+    (0x1F576, 0x1F579, LineBreak::Ideographic),
+    (0x1F57A, 0x1F57A, LineBreak::EBase),
+    (0x1F57B, 0x1F58F, LineBreak::Ideographic),
+    (0x1F590, 0x1F590, LineBreak::EBase),
+    (0x1F591, 0x1F594, LineBreak::Ideographic),
+    (0x1F595, 0x1F596, LineBreak::EBase),
+    (0x1F597, 0x1F5D3, LineBreak::Ideographic),
+    (0x1F5D4, 0x1F5DB, LineBreak::Alphabetic),
+    (0x1F5DC, 0x1F5F3, LineBreak::Ideographic),
+    (0x1F5F4, 0x1F5F9, LineBreak::Alphabetic),
+    (0x1F5FA, 0x1F644, LineBreak::Ideographic),
+    (0x1F645, 0x1F647, LineBreak::EBase),
+    (0x1F648, 0x1F64A, LineBreak::Ideographic),
+    (0x1F64B, 0x1F64F, LineBreak::EBase),
+    (0x1F650, 0x1F675, LineBreak::Alphabetic),
+    (0x1F676, 0x1F678, LineBreak::Quotation),
+    (0x1F679, 0x1F67B, LineBreak::Nonstarter),
+    (0x1F67C, 0x1F67F, LineBreak::Alphabetic),
+    (0x1F680, 0x1F6A2, LineBreak::Ideographic),
+    (0x1F6A3, 0x1F6A3, LineBreak::EBase),
+    (0x1F6A4, 0x1F6B3, LineBreak::Ideographic),
+    (0x1F6B4, 0x1F6B6, LineBreak::EBase),
+    (0x1F6B7, 0x1F6BF, LineBreak::Ideographic),
+    (0x1F6C0, 0x1F6C0, LineBreak::EBase),
+    (0x1F6C1, 0x1F6CB, LineBreak::Ideographic),
+    (0x1F6CC, 0x1F6CC, LineBreak::EBase),
+    (0x1F6CD, 0x1F6FF, LineBreak::Ideographic),
+    (0x1F700, 0x1F773, LineBreak::Alphabetic),
+    (0x1F774, 0x1F77F, LineBreak::Ideographic),
+    (0x1F780, 0x1F7D4, LineBreak::Alphabetic),
+    (0x1F7D5, 0x1F7FF, LineBreak::Ideographic),
+    (0x1F800, 0x1F80B, LineBreak::Alphabetic),
+    (0x1F80C, 0x1F80F, LineBreak::Ideographic),
+    (0x1F810, 0x1F847, LineBreak::Alphabetic),
+    (0x1F848, 0x1F84F, LineBreak::Ideographic),
+    (0x1F850, 0x1F859, LineBreak::Alphabetic),
+    (0x1F85A, 0x1F85F, LineBreak::Ideographic),
+    (0x1F860, 0x1F887, LineBreak::Alphabetic),
+    (0x1F888, 0x1F88F, LineBreak::Ideographic),
+    (0x1F890, 0x1F8AD, LineBreak::Alphabetic),
+    (0x1F8AE, 0x1F8FF, LineBreak::Ideographic),
+    (0x1F900, 0x1F90B, LineBreak::Alphabetic),
+    (0x1F90C, 0x1F90C, LineBreak::EBase),
+    (0x1F90D, 0x1F90E, LineBreak::Ideographic),
+    (0x1F90F, 0x1F90F, LineBreak::EBase),
+    (0x1F910, 0x1F917, LineBreak::Ideographic),
+    (0x1F918, 0x1F91F, LineBreak::EBase),
+    (0x1F920, 0x1F925, LineBreak::Ideographic),
+    (0x1F926, 0x1F926, LineBreak::EBase),
+    (0x1F927, 0x1F92F, LineBreak::Ideographic),
+    (0x1F930, 0x1F939, LineBreak::EBase),
+    (0x1F93A, 0x1F93B, LineBreak::Ideographic),
+    (0x1F93C, 0x1F93E, LineBreak::EBase),
+    (0x1F93F, 0x1F976, LineBreak::Ideographic),
+    (0x1F977, 0x1F977, LineBreak::EBase),
+    (0x1F978, 0x1F9B4, LineBreak::Ideographic),
+    (0x1F9B5, 0x1F9B6, LineBreak::EBase),
+    (0x1F9B7, 0x1F9B7, LineBreak::Ideographic),
+    (0x1F9B8, 0x1F9B9, LineBreak::EBase),
+    (0x1F9BA, 0x1F9BA, LineBreak::Ideographic),
+    (0x1F9BB, 0x1F9BB, LineBreak::EBase),
+    (0x1F9BC, 0x1F9CC, LineBreak::Ideographic),
+    (0x1F9CD, 0x1F9CF, LineBreak::EBase),
+    (0x1F9D0, 0x1F9D0, LineBreak::Ideographic),
+    (0x1F9D1, 0x1F9DD, LineBreak::EBase),
+    (0x1F9DE, 0x1F9FF, LineBreak::Ideographic),
+    (0x1FA00, 0x1FA53, LineBreak::Alphabetic),
+    (0x1FA54, 0x1FAFF, LineBreak::Ideographic),
+    (0x1FB00, 0x1FB92, LineBreak::Alphabetic),
+    (0x1FB94, 0x1FBCA, LineBreak::Alphabetic),
+    (0x1FBF0, 0x1FBF9, LineBreak::Numeric),
+    (0x1FC00, 0x1FFFD, LineBreak::Ideographic),
+    (0x20000, 0x2FFFD, LineBreak::Ideographic),
+    (0x30000, 0x3FFFD, LineBreak::Ideographic),
+    (0xE0001, 0xE0001, LineBreak::CombiningMark),
+    (0xE0020, 0xE007F, LineBreak::CombiningMark),
+    (0xE0100, 0xE01EF, LineBreak::CombiningMark),
+];
@@ -0,0 +1,163 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+/// Defines an enumerated character property which is not provided by `unic::ucd`, e.g. `Script`.
+///
+/// Values are looked up in the `VALUES`, `NAMES` and `RANGES` tables of a `tables` module next to
+/// the property, which are generated from the Unicode Character Database by
+/// `tools/generate_ucd_tables.pl`. Code points which are not in any of the `RANGES` have the
+/// `default` value. Modules defining several properties name the tables module of each property
+/// with `tables`, e.g. `tables => nfc_quick_check`.
+macro_rules! enumerated_property {
+    (
+        $(#[$attribute:meta])*
+        pub enum $property:ident {
+            abbr => $abbr:expr,
+            long => $long:expr,
+            human => $human:expr,
+            default => $default:ident,
+            values => { $($value:ident,)+ }
+        }
+    ) => {
+        enumerated_property! {
+            $(#[$attribute])*
+            pub enum $property {
+                abbr => $abbr,
+                long => $long,
+                human => $human,
+                default => $default,
+                tables => tables,
+                values => { $($value,)+ }
+            }
+        }
+    };
+    (
+        $(#[$attribute:meta])*
+        pub enum $property:ident {
+            abbr => $abbr:expr,
+            long => $long:expr,
+            human => $human:expr,
+            default => $default:ident,
+            tables => $tables:ident,
+            values => { $($value:ident,)+ }
+        }
+    ) => {
+        $(#[$attribute])*
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+        pub enum $property {
+            $($value,)+
+        }
+
+        impl $property {
+            pub fn of(chr: char) -> Self {
+                $crate::ucd::find_range($tables::RANGES, chr)
+                    .map_or($property::$default, |(_, _, value)| *value)
+            }
+
+            fn names(self) -> (&'static str, &'static str, &'static str) {
+                $tables::NAMES[self as usize]
+            }
+        }
+
+        #[cfg(test)]
+        impl $property {
+            /// Checks that `NAMES` are in the order of `VALUES`, which are in the order of the
+            /// enum, and that `RANGES` are sorted and don't overlap, as `of` looks them up with a
+            /// binary search.
+            pub(crate) fn assert_tables_are_valid() {
+                assert_eq!($tables::VALUES.len(), $tables::NAMES.len());
+                for (index, value) in $tables::VALUES.iter().enumerate() {
+                    assert_eq!(*value as usize, index);
+                }
+                for (first, last, _) in $tables::RANGES {
+                    assert!(first <= last);
+                }
+                for ranges in $tables::RANGES.windows(2) {
+                    assert!(ranges[0].1 < ranges[1].0);
+                }
+            }
+        }
+
+        impl Default for $property {
+            fn default() -> Self {
+                $property::$default
+            }
+        }
+
+        impl unic::char::property::TotalCharProperty for $property {
+            fn of(chr: char) -> Self {
+                Self::of(chr)
+            }
+        }
+
+        impl unic::char::property::CharProperty for $property {
+            fn prop_abbr_name() -> &'static str {
+                $abbr
+            }
+
+            fn prop_long_name() -> &'static str {
+                $long
+            }
+
+            fn prop_human_name() -> &'static str {
+                $human
+            }
+        }
+
+        impl unic::char::property::EnumeratedCharProperty for $property {
+            fn all_values() -> &'static [Self] {
+                $tables::VALUES
+            }
+
+            fn abbr_name(&self) -> &'static str {
+                self.names().0
+            }
+
+            fn long_name(&self) -> &'static str {
+                self.names().1
+            }
+
+            fn human_name(&self) -> &'static str {
+                self.names().2
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ucd::{
+        EastAsianWidth, HangulSyllableType, IndicPositionalCategory, IndicSyllabicCategory,
+        JoiningGroup, JoiningType, LineBreak, NfcQuickCheck, NfdQuickCheck, NfkcQuickCheck,
+        NfkdQuickCheck, NumericType, Script, VerticalOrientation,
+    };
+
+    #[test]
+    fn test_enumerated_property_tables() {
+        Script::assert_tables_are_valid();
+        LineBreak::assert_tables_are_valid();
+        EastAsianWidth::assert_tables_are_valid();
+        NumericType::assert_tables_are_valid();
+        NfcQuickCheck::assert_tables_are_valid();
+        NfdQuickCheck::assert_tables_are_valid();
+        NfkcQuickCheck::assert_tables_are_valid();
+        NfkdQuickCheck::assert_tables_are_valid();
+        JoiningType::assert_tables_are_valid();
+        JoiningGroup::assert_tables_are_valid();
+        HangulSyllableType::assert_tables_are_valid();
+        IndicSyllabicCategory::assert_tables_are_valid();
+        IndicPositionalCategory::assert_tables_are_valid();
+        VerticalOrientation::assert_tables_are_valid();
+    }
+}
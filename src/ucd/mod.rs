@@ -21,11 +21,74 @@
 //! Cicero is a Unicode tool after all, so sometimes it needs to work with all aspects of Unicode.
 //!
 
+#[macro_use]
+mod macros;
+
+mod binary_properties;
+mod boundaries;
+mod case;
+mod character_fields;
 mod character_properties;
+mod code_point;
+mod display_width;
+mod east_asian_width;
+mod emoji;
+mod encoding_diagnostic;
+mod line_break;
+mod name_index;
+mod normalization;
+mod numeric;
 mod plane;
+mod script;
+mod shaping;
+mod unihan;
 
-pub use character_properties::{CharacterProperties, GraphemeProperties};
+pub use binary_properties::{
+    is_dash, is_default_ignorable_code_point, is_diacritic, is_extender, is_hyphen,
+    is_join_control, is_noncharacter_code_point, is_quotation_mark, is_variation_selector,
+};
+pub use boundaries::{boundaries, BoundaryKind};
+pub use case::{
+    case_folding_of, case_mappings, changes_when_casefolded, changes_when_casemapped,
+    changes_when_lowercased, changes_when_nfkc_casefolded, changes_when_titlecased,
+    changes_when_uppercased, lowercase_of, simple_case_folding_of, titlecase_of, uppercase_of,
+    CaseLanguage, CaseOperation,
+};
+pub use character_fields::{CharacterField, CharacterFields};
+pub use character_properties::{
+    CharacterProperties, GraphemeProperties, TREE_GRAPH_CORNER, TREE_GRAPH_EDGE,
+};
+pub use code_point::{code_point_label, CodePointKind, CodePointProperties};
+pub use display_width::{character_display_width, grapheme_display_width, text_display_width};
+pub use east_asian_width::EastAsianWidth;
+pub use emoji::{is_extended_pictographic, EmojiSequence};
+pub use encoding_diagnostic::{
+    decode_lossy, decode_lossy_to_code_points, decode_sequence, encode_wtf8, DecodedSequence,
+    Encoding, EncodingDiagnostic,
+};
+pub use line_break::LineBreak;
+pub use name_index::{build_name_index_in_background, built_name_index, NameIndex, NameMatch};
+pub use normalization::{
+    decomposition_mapping, full_canonical_decomposition, full_compatibility_decomposition,
+    NfcQuickCheck, NfdQuickCheck, NfkcQuickCheck, NfkdQuickCheck,
+};
+pub use numeric::{NumericType, NumericValue};
 pub use plane::{Plane, PLANE_COUNT};
+pub use script::Script;
+pub use shaping::{
+    HangulSyllableType, IndicPositionalCategory, IndicSyllabicCategory, JoiningGroup, JoiningType,
+    VerticalOrientation,
+};
+pub use unihan::{
+    is_unihan_database_loading, load_unihan_database_in_background, unihan_entry_of,
+    unihan_load_error,
+};
+
+use std::cmp::Ordering;
+
+use unic::ucd::{Block, BlockIter, GeneralCategory};
+
+pub const MAX_CODE_POINT: u32 = 0x10_FFFF;
 
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Range {
@@ -33,19 +96,76 @@ pub struct Range {
     pub end: u32,
 }
 
-pub fn code_point_to_string(chr: char) -> String {
-    format!("U+{:04X}", chr as u32)
+/// Matches names of properties (e.g. plane and block names) ignoring case, whitespace, underscores
+/// and hyphens, e.g. "latin_extended_a" matches "Latin Extended-A".
+pub fn names_match(name: &str, other_name: &str) -> bool {
+    let loose_name = |name: &str| -> String {
+        name.chars()
+            .filter(|chr| !chr.is_whitespace() && *chr != '_' && *chr != '-')
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    loose_name(name) == loose_name(other_name)
+}
+
+pub fn block_named(name: &str) -> Option<Block> {
+    BlockIter::new().find(|block| names_match(block.name, name))
+}
+
+/// Whether `chr` has no visible glyph of its own, i.e. separators (including spaces), controls,
+/// format characters and unassigned code points.
+pub fn is_invisible(chr: char) -> bool {
+    let general_category = GeneralCategory::of(chr);
+    general_category.is_separator()
+        || matches!(
+            general_category,
+            GeneralCategory::Control | GeneralCategory::Format | GeneralCategory::Unassigned
+        )
+}
+
+/// Finds the index of the range containing `chr` in `ranges`, which are sorted and don't overlap,
+/// `bounds` returns the first and last code points of a range.
+fn range_index<R>(ranges: &[R], chr: char, bounds: impl Fn(&R) -> (u32, u32)) -> Option<usize> {
+    let code_point = chr as u32;
+    ranges
+        .binary_search_by(|range| {
+            let (first, last) = bounds(range);
+            if code_point < first {
+                Ordering::Greater
+            } else if code_point > last {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+}
+
+/// Finds the range containing `chr` in `ranges` of (first, last, value) code points, which are
+/// sorted and don't overlap.
+fn find_range<T>(ranges: &'static [(u32, u32, T)], chr: char) -> Option<&'static (u32, u32, T)> {
+    range_index(ranges, chr, |(first, last, _)| (*first, *last)).map(|index| &ranges[index])
+}
+
+/// Whether `chr` is in `ranges` of (first, last) code points, which are sorted and don't overlap.
+fn is_in_ranges(ranges: &'static [(u32, u32)], chr: char) -> bool {
+    range_index(ranges, chr, |range| *range).is_some()
+}
+
+pub fn code_point_to_string<C: Into<u32>>(code_point: C) -> String {
+    format!("U+{:04X}", code_point.into())
 }
 
-pub fn string_to_code_point(input: &str) -> Option<char> {
+/// Parses "U+XXXX" notation into a code point, which can be any value in 0..=0x10FFFF including
+/// surrogate code points.
+pub fn string_to_code_point(input: &str) -> Option<u32> {
     if !input.to_lowercase().starts_with("u+") || input[2..].is_empty() {
         return None;
     }
 
-    use std::char;
     match u32::from_str_radix(&input[2..], 16) {
-        Ok(code_point) => char::from_u32(code_point),
-        Err(_) => None,
+        Ok(code_point) if code_point <= MAX_CODE_POINT => Some(code_point),
+        _ => None,
     }
 }
 
@@ -53,24 +173,56 @@ pub fn string_to_code_point(input: &str) -> Option<char> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_invisible() {
+        assert!(is_invisible(' '));
+        assert!(is_invisible('\t'));
+        assert!(is_invisible('\u{200B}'));
+        assert!(is_invisible('\u{2029}'));
+        assert!(!is_invisible('a'));
+        assert!(!is_invisible('\u{301}'));
+    }
+
+    #[test]
+    fn test_range_lookup() {
+        const RANGES: &[(u32, u32)] = &[(0x41, 0x5A), (0x61, 0x61), (0x10000, 0x1FFFF)];
+        const VALUE_RANGES: &[(u32, u32, u8)] = &[(0x41, 0x5A, 1), (0x61, 0x61, 2)];
+
+        assert!(is_in_ranges(RANGES, 'A'));
+        assert!(is_in_ranges(RANGES, 'Z'));
+        assert!(is_in_ranges(RANGES, 'a'));
+        assert!(is_in_ranges(RANGES, '\u{1F600}'));
+        assert!(!is_in_ranges(RANGES, '@'));
+        assert!(!is_in_ranges(RANGES, 'b'));
+        assert!(!is_in_ranges(&[], 'a'));
+
+        assert_eq!(find_range(VALUE_RANGES, 'M'), Some(&(0x41, 0x5A, 1)));
+        assert_eq!(find_range(VALUE_RANGES, 'a'), Some(&(0x61, 0x61, 2)));
+        assert_eq!(find_range(VALUE_RANGES, '['), None);
+    }
+
     #[test]
     fn test_code_point_to_string() {
         assert_eq!("U+0020", code_point_to_string('\u{0020}'));
         assert_eq!("U+34FF", code_point_to_string('\u{34FF}'));
         assert_eq!("U+10FFFF", code_point_to_string('\u{10FFFF}'));
+        assert_eq!("U+D800", code_point_to_string(0xD800_u32));
     }
 
     #[test]
     fn test_string_to_code_point() {
-        assert_eq!(Some('\u{0020}'), string_to_code_point("U+20"));
-        assert_eq!(Some('\u{0020}'), string_to_code_point("U+0020"));
-        assert_eq!(Some('\u{34FF}'), string_to_code_point("U+34FF"));
-        assert_eq!(Some('\u{10FFFF}'), string_to_code_point("U+10FFFF"));
-
-        assert_eq!(Some('\u{0020}'), string_to_code_point("u+20"));
-        assert_eq!(Some('\u{0020}'), string_to_code_point("u+0020"));
-        assert_eq!(Some('\u{34ff}'), string_to_code_point("u+34ff"));
-        assert_eq!(Some('\u{10ffff}'), string_to_code_point("u+10ffff"));
+        assert_eq!(Some(0x0020), string_to_code_point("U+20"));
+        assert_eq!(Some(0x0020), string_to_code_point("U+0020"));
+        assert_eq!(Some(0x34FF), string_to_code_point("U+34FF"));
+        assert_eq!(Some(0x10FFFF), string_to_code_point("U+10FFFF"));
+
+        assert_eq!(Some(0x0020), string_to_code_point("u+20"));
+        assert_eq!(Some(0x0020), string_to_code_point("u+0020"));
+        assert_eq!(Some(0x34ff), string_to_code_point("u+34ff"));
+        assert_eq!(Some(0x10ffff), string_to_code_point("u+10ffff"));
+
+        assert_eq!(Some(0xD800), string_to_code_point("U+D800"));
+        assert_eq!(Some(0xDFFF), string_to_code_point("U+DFFF"));
 
         assert_eq!(None, string_to_code_point("Invalid"));
         assert_eq!(None, string_to_code_point("U+11FFFF"));
@@ -0,0 +1,369 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Looks up characters by their names and name aliases.
+//!
+
+use std::char;
+use std::cmp::{max, min};
+use std::collections::HashSet;
+use std::fmt;
+use std::iter;
+use std::ops::Range;
+use std::sync::OnceLock;
+use std::thread;
+
+use serde::Serialize;
+use unic::ucd::{name_aliases_of, Name, NameAliasType};
+
+use super::code_point_to_string;
+
+// Query words shorter than this only match whole words, e.g. "A" shouldn't match every name
+// containing a word starting with "A".
+const MINIMUM_FUZZY_WORD_LENGTH: usize = 3;
+
+/// How a query matches a name, from the most to the least relevant.
+#[derive(Serialize, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchType {
+    Exact,
+    Prefix,
+    Word,
+    Fuzzy,
+}
+
+impl fmt::Display for MatchType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            MatchType::Exact => "exact",
+            MatchType::Prefix => "prefix",
+            MatchType::Word => "word",
+            MatchType::Fuzzy => "fuzzy",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct NameMatch {
+    pub character: char,
+    pub code_point: u32,
+    pub name: String,
+    pub matched_name: String,
+    pub match_type: MatchType,
+}
+
+impl fmt::Display for NameMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}  {}  {}",
+            code_point_to_string(self.character),
+            self.character,
+            self.name,
+        )?;
+        if self.matched_name != self.name {
+            write!(f, "  (alias: {})", self.matched_name)?;
+        }
+        write!(f, "  [{}]", self.match_type)
+    }
+}
+
+struct NameEntry {
+    character: char,
+    name: String,
+    loose_name: String,
+    // Byte ranges of the words in `name`, computed once so that searching doesn't allocate
+    word_ranges: Vec<Range<usize>>,
+    is_alias: bool,
+}
+
+/// An index of the names and name aliases of all assigned characters.
+pub struct NameIndex {
+    entries: Vec<NameEntry>,
+}
+
+impl Default for NameIndex {
+    fn default() -> Self {
+        NameIndex::new()
+    }
+}
+
+impl NameIndex {
+    pub fn new() -> Self {
+        let mut entries = vec![];
+        for chr in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            if let Some(name) = Name::of(chr) {
+                entries.push(NameEntry::new(chr, name.to_string(), false));
+            }
+            for alias in name_aliases(chr) {
+                entries.push(NameEntry::new(chr, alias.to_owned(), true));
+            }
+        }
+        NameIndex { entries }
+    }
+
+    /// Returns up to `limit` characters whose names match `query`, most relevant first.
+    ///
+    /// Names are matched loosely, ignoring case, spaces, hyphens and underscores, so that
+    /// "greek small letter alpha", "GREEK_SMALL_LETTER_ALPHA" and "alpha" all find U+03B1.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<NameMatch> {
+        let query = normalize_name(query);
+        let query_words = split_name(&query);
+        if query_words.is_empty() {
+            return vec![];
+        }
+        let loose_query = loose_name(&query);
+
+        let mut ranked_entries: Vec<(MatchType, &NameEntry)> = self
+            .entries
+            .iter()
+            .filter_map(|entry| Some((entry.match_type(&loose_query, &query_words)?, entry)))
+            .collect();
+        ranked_entries.sort_by(|(lhs_type, lhs_entry), (rhs_type, rhs_entry)| {
+            lhs_type
+                .cmp(rhs_type)
+                .then_with(|| lhs_entry.is_alias.cmp(&rhs_entry.is_alias))
+                .then_with(|| lhs_entry.word_count().cmp(&rhs_entry.word_count()))
+                .then_with(|| lhs_entry.character.cmp(&rhs_entry.character))
+        });
+
+        // A character is only listed once, with its most relevant name
+        let mut matched_characters = HashSet::new();
+        ranked_entries
+            .into_iter()
+            .filter(|(_, entry)| matched_characters.insert(entry.character))
+            .take(limit)
+            .map(|(match_type, entry)| NameMatch {
+                character: entry.character,
+                code_point: entry.character as u32,
+                name: match Name::of(entry.character) {
+                    Some(name) => name.to_string(),
+                    None => "".to_owned(),
+                },
+                matched_name: entry.name.clone(),
+                match_type,
+            })
+            .collect()
+    }
+}
+
+impl NameEntry {
+    fn new(character: char, name: String, is_alias: bool) -> Self {
+        NameEntry {
+            character,
+            loose_name: loose_name(&name),
+            word_ranges: word_ranges(&name),
+            name,
+            is_alias,
+        }
+    }
+
+    fn word_count(&self) -> usize {
+        self.word_ranges.len()
+    }
+
+    fn words(&self) -> impl Iterator<Item = &str> {
+        self.word_ranges
+            .iter()
+            .map(move |range| &self.name[range.clone()])
+    }
+
+    fn match_type(&self, loose_query: &str, query_words: &[&str]) -> Option<MatchType> {
+        if self.loose_name == loose_query {
+            return Some(MatchType::Exact);
+        } else if self.loose_name.starts_with(loose_query) {
+            return Some(MatchType::Prefix);
+        }
+
+        if query_words
+            .iter()
+            .all(|query_word| self.words().any(|word| word == *query_word))
+        {
+            Some(MatchType::Word)
+        } else if query_words.iter().all(|query_word| {
+            self.words()
+                .any(|word| is_fuzzy_word_match(query_word, word))
+        }) {
+            Some(MatchType::Fuzzy)
+        } else {
+            None
+        }
+    }
+}
+
+fn name_aliases(chr: char) -> Vec<&'static str> {
+    let aliases = [
+        name_aliases_of(chr, NameAliasType::NameCorrections),
+        name_aliases_of(chr, NameAliasType::ControlCodeNames),
+        name_aliases_of(chr, NameAliasType::AlternateNames),
+        name_aliases_of(chr, NameAliasType::Figments),
+        name_aliases_of(chr, NameAliasType::NameAbbreviations),
+    ];
+    aliases
+        .iter()
+        .flat_map(|aliases| aliases.unwrap_or(&[]).iter().copied())
+        .collect()
+}
+
+fn normalize_name(name: &str) -> String {
+    name.to_uppercase()
+        .replace('_', " ")
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+// Names without separators, e.g. both "SNOW MAN" and "SNOWMAN" become "SNOWMAN".
+fn loose_name(normalized_name: &str) -> String {
+    normalized_name
+        .chars()
+        .filter(|chr| *chr != ' ' && *chr != '-')
+        .collect()
+}
+
+fn split_name(name: &str) -> Vec<&str> {
+    name.split(is_word_separator)
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn is_word_separator(chr: char) -> bool {
+    chr == ' ' || chr == '-'
+}
+
+fn word_ranges(name: &str) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+    let mut start = 0;
+    for (index, chr) in name.char_indices().chain(iter::once((name.len(), ' '))) {
+        if is_word_separator(chr) {
+            if index > start {
+                ranges.push(start..index);
+            }
+            start = index + chr.len_utf8();
+        }
+    }
+    ranges
+}
+
+fn is_fuzzy_word_match(query_word: &str, word: &str) -> bool {
+    if query_word.len() < MINIMUM_FUZZY_WORD_LENGTH {
+        return query_word == word;
+    }
+    if word.starts_with(query_word) {
+        return true;
+    }
+
+    // Allows one typo in short words and two in longer ones
+    let maximum_distance = if query_word.len() < 6 { 1 } else { 2 };
+    let length_difference = max(query_word.len(), word.len()) - min(query_word.len(), word.len());
+    length_difference <= maximum_distance && edit_distance(query_word, word) <= maximum_distance
+}
+
+// Levenshtein distance, names only contain ASCII so comparing bytes is sufficient.
+fn edit_distance(lhs: &str, rhs: &str) -> usize {
+    let rhs = rhs.as_bytes();
+    let mut distances: Vec<usize> = (0..=rhs.len()).collect();
+    for (lhs_index, lhs_byte) in lhs.bytes().enumerate() {
+        let mut diagonal_distance = distances[0];
+        distances[0] = lhs_index + 1;
+        for (rhs_index, rhs_byte) in rhs.iter().enumerate() {
+            let substitution_distance = if lhs_byte == *rhs_byte {
+                diagonal_distance
+            } else {
+                diagonal_distance + 1
+            };
+            diagonal_distance = distances[rhs_index + 1];
+            distances[rhs_index + 1] = min(
+                substitution_distance,
+                min(distances[rhs_index], distances[rhs_index + 1]) + 1,
+            );
+        }
+    }
+    distances[rhs.len()]
+}
+
+static NAME_INDEX: OnceLock<NameIndex> = OnceLock::new();
+
+/// Starts building the shared name index on another thread, so that e.g. a user interface doesn't
+/// block on it. It's available from `built_name_index` once it's built.
+pub fn build_name_index_in_background() {
+    thread::spawn(|| NAME_INDEX.get_or_init(NameIndex::new));
+}
+
+/// The shared name index, which is `None` until it's built by `build_name_index_in_background`.
+pub fn built_name_index() -> Option<&'static NameIndex> {
+    NAME_INDEX.get()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_ranges() {
+        let name = "LATIN SMALL LETTER A WITH DOT-ABOVE";
+        let words: Vec<&str> = word_ranges(name)
+            .into_iter()
+            .map(|range| &name[range])
+            .collect();
+        assert_eq!(words, split_name(name));
+        assert_eq!(words.len(), 7);
+        assert!(word_ranges("").is_empty());
+        assert_eq!(word_ranges(" A--B "), vec![1..2, 4..5]);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("SNOWMAN", "SNOWMAN"), 0);
+        assert_eq!(edit_distance("SNOWMN", "SNOWMAN"), 1);
+        assert_eq!(edit_distance("SNOAMN", "SNOWMAN"), 2);
+        assert_eq!(edit_distance("", "ABC"), 3);
+    }
+
+    #[test]
+    fn test_search() {
+        let index = NameIndex::new();
+
+        let first_match = |query| index.search(query, 1).pop().unwrap();
+
+        let alpha = first_match("greek small letter alpha");
+        assert_eq!(alpha.character, '\u{03B1}');
+        assert_eq!(alpha.match_type, MatchType::Exact);
+
+        assert_eq!(first_match("snow-man").character, '\u{2603}');
+
+        let snowman = first_match("snowman");
+        assert_eq!(snowman.character, '\u{2603}');
+        assert_eq!(snowman.match_type, MatchType::Exact);
+
+        assert_eq!(
+            first_match("GREEK_SMALL_LETTER_ALP").match_type,
+            MatchType::Prefix
+        );
+        assert_eq!(first_match("alpha small greek").character, '\u{03B1}');
+        assert_eq!(first_match("alpha small greek").match_type, MatchType::Word);
+        assert_eq!(first_match("snowmn").character, '\u{2603}');
+        assert_eq!(first_match("snowmn").match_type, MatchType::Fuzzy);
+
+        let line_feed = first_match("line feed");
+        assert_eq!(line_feed.character, '\u{000A}');
+        assert_eq!(line_feed.matched_name, "LINE FEED");
+
+        assert!(index.search("", 10).is_empty());
+        assert!(index.search("qqqqqqqq", 10).is_empty());
+        assert_eq!(index.search("alpha", 3).len(), 3);
+    }
+}
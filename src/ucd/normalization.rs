@@ -0,0 +1,207 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Decompositions and the quick check properties of normalization forms, which are not yet
+//! provided by `unic::ucd`.
+//!
+
+mod nfc_quick_check;
+mod nfd_quick_check;
+mod nfkc_quick_check;
+mod nfkd_quick_check;
+
+use unic::ucd::hangul::{compose_syllable, decompose_syllable, is_syllable};
+use unic::ucd::normal::{
+    canonical_decomposition, compatibility_decomposition, decompose_canonical, decompose_compatible,
+};
+
+enumerated_property! {
+    /// Values of the NFC_Quick_Check property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    pub enum NfcQuickCheck {
+        abbr => "NFC_QC",
+        long => "NFC_Quick_Check",
+        human => "NFC Quick Check",
+        default => Yes,
+        tables => nfc_quick_check,
+        values => {
+            Maybe,
+            No,
+            Yes,
+        }
+    }
+}
+
+enumerated_property! {
+    /// Values of the NFD_Quick_Check property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    pub enum NfdQuickCheck {
+        abbr => "NFD_QC",
+        long => "NFD_Quick_Check",
+        human => "NFD Quick Check",
+        default => Yes,
+        tables => nfd_quick_check,
+        values => {
+            No,
+            Yes,
+        }
+    }
+}
+
+enumerated_property! {
+    /// Values of the NFKC_Quick_Check property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    pub enum NfkcQuickCheck {
+        abbr => "NFKC_QC",
+        long => "NFKC_Quick_Check",
+        human => "NFKC Quick Check",
+        default => Yes,
+        tables => nfkc_quick_check,
+        values => {
+            Maybe,
+            No,
+            Yes,
+        }
+    }
+}
+
+enumerated_property! {
+    /// Values of the NFKD_Quick_Check property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    pub enum NfkdQuickCheck {
+        abbr => "NFKD_QC",
+        long => "NFKD_Quick_Check",
+        human => "NFKD Quick Check",
+        default => Yes,
+        tables => nfkd_quick_check,
+        values => {
+            No,
+            Yes,
+        }
+    }
+}
+
+/// The Decomposition_Mapping of `chr`, which is a single level of decomposition, e.g. U+01D5 LATIN
+/// CAPITAL LETTER U WITH DIAERESIS AND MACRON is mapped to U+00DC U+0304, not U+0055 U+0308 U+0304.
+///
+/// Hangul syllables are decomposed algorithmically: LV syllables are mapped to their leading
+/// consonant and vowel, and LVT syllables to their LV syllable and trailing consonant.
+pub fn decomposition_mapping(chr: char) -> Option<Vec<char>> {
+    if is_syllable(chr) {
+        let mut jamos = vec![];
+        decompose_syllable(chr, &mut |jamo| jamos.push(jamo));
+        if let [leading, vowel, trailing] = jamos[..] {
+            let syllable = compose_syllable(leading, vowel)?;
+            return Some(vec![syllable, trailing]);
+        }
+        return Some(jamos);
+    }
+
+    canonical_decomposition(chr)
+        .or_else(|| compatibility_decomposition(chr))
+        .map(<[char]>::to_vec)
+}
+
+/// The full canonical decomposition of `chr` (as in NFD), or `None` if `chr` has no canonical
+/// decomposition.
+pub fn full_canonical_decomposition(chr: char) -> Option<Vec<char>> {
+    let mut components = vec![];
+    decompose_canonical(chr, |component| components.push(component));
+    if components == [chr] {
+        None
+    } else {
+        Some(components)
+    }
+}
+
+/// The full compatibility decomposition of `chr` (as in NFKD), or `None` if `chr` has neither a
+/// canonical nor a compatibility decomposition.
+pub fn full_compatibility_decomposition(chr: char) -> Option<Vec<char>> {
+    let mut components = vec![];
+    decompose_compatible(chr, |component| components.push(component));
+    if components == [chr] {
+        None
+    } else {
+        Some(components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unic::char::property::EnumeratedCharProperty;
+
+    use super::*;
+
+    #[test]
+    fn test_quick_checks_of() {
+        assert_eq!(NfcQuickCheck::of('a'), NfcQuickCheck::Yes);
+        assert_eq!(NfcQuickCheck::of('\u{0301}'), NfcQuickCheck::Maybe);
+        assert_eq!(NfcQuickCheck::of('\u{0344}'), NfcQuickCheck::No);
+        assert_eq!(NfdQuickCheck::of('\u{00C5}'), NfdQuickCheck::No);
+        assert_eq!(NfdQuickCheck::of('\u{AC00}'), NfdQuickCheck::No);
+        assert_eq!(NfkcQuickCheck::of('\u{FB01}'), NfkcQuickCheck::No);
+        assert_eq!(NfkdQuickCheck::of('\u{FB01}'), NfkdQuickCheck::No);
+        assert_eq!(NfdQuickCheck::of('\u{FB01}'), NfdQuickCheck::Yes);
+
+        assert_eq!(NfcQuickCheck::Maybe.abbr_name(), "M");
+    }
+
+    #[test]
+    fn test_decomposition_mapping() {
+        assert_eq!(decomposition_mapping('a'), None);
+        assert_eq!(
+            decomposition_mapping('\u{01D5}'),
+            Some(vec!['\u{00DC}', '\u{0304}'])
+        );
+        assert_eq!(decomposition_mapping('\u{FB01}'), Some(vec!['f', 'i']));
+        // LV and LVT Hangul syllables
+        assert_eq!(
+            decomposition_mapping('\u{AC00}'),
+            Some(vec!['\u{1100}', '\u{1161}'])
+        );
+        assert_eq!(
+            decomposition_mapping('\u{AC01}'),
+            Some(vec!['\u{AC00}', '\u{11A8}'])
+        );
+    }
+
+    #[test]
+    fn test_full_decompositions() {
+        assert_eq!(full_canonical_decomposition('a'), None);
+        assert_eq!(
+            full_canonical_decomposition('\u{01D5}'),
+            Some(vec!['U', '\u{0308}', '\u{0304}'])
+        );
+        assert_eq!(
+            full_canonical_decomposition('\u{AC01}'),
+            Some(vec!['\u{1100}', '\u{1161}', '\u{11A8}'])
+        );
+        assert_eq!(full_canonical_decomposition('\u{FB01}'), None);
+        assert_eq!(
+            full_compatibility_decomposition('\u{FB01}'),
+            Some(vec!['f', 'i'])
+        );
+        // U+1E9B LATIN SMALL LETTER LONG S WITH DOT ABOVE canonically decomposes to a long s, which
+        // has a compatibility decomposition itself
+        assert_eq!(
+            full_canonical_decomposition('\u{1E9B}'),
+            Some(vec!['\u{017F}', '\u{0307}'])
+        );
+        assert_eq!(
+            full_compatibility_decomposition('\u{1E9B}'),
+            Some(vec!['s', '\u{0307}'])
+        );
+    }
+}
@@ -0,0 +1,146 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (DerivedNormalizationProps.txt and
+// PropertyValueAliases.txt), limited to the characters assigned in Unicode 13.0.
+
+use super::NfcQuickCheck;
+
+pub(super) const VALUES: &[NfcQuickCheck] =
+    &[NfcQuickCheck::Maybe, NfcQuickCheck::No, NfcQuickCheck::Yes];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[
+    ("M", "Maybe", "Maybe"),
+    ("N", "No", "No"),
+    ("Y", "Yes", "Yes"),
+];
+
+pub(super) const RANGES: &[(u32, u32, NfcQuickCheck)] = &[
+    (0x0300, 0x0304, NfcQuickCheck::Maybe),
+    (0x0306, 0x030C, NfcQuickCheck::Maybe),
+    (0x030F, 0x030F, NfcQuickCheck::Maybe),
+    (0x0311, 0x0311, NfcQuickCheck::Maybe),
+    (0x0313, 0x0314, NfcQuickCheck::Maybe),
+    (0x031B, 0x031B, NfcQuickCheck::Maybe),
+    (0x0323, 0x0328, NfcQuickCheck::Maybe),
+    (0x032D, 0x032E, NfcQuickCheck::Maybe),
+    (0x0330, 0x0331, NfcQuickCheck::Maybe),
+    (0x0338, 0x0338, NfcQuickCheck::Maybe),
+    (0x0340, 0x0341, NfcQuickCheck::No),
+    (0x0342, 0x0342, NfcQuickCheck::Maybe),
+    (0x0343, 0x0344, NfcQuickCheck::No),
+    (0x0345, 0x0345, NfcQuickCheck::Maybe),
+    (0x0374, 0x0374, NfcQuickCheck::No),
+    (0x037E, 0x037E, NfcQuickCheck::No),
+    (0x0387, 0x0387, NfcQuickCheck::No),
+    (0x0653, 0x0655, NfcQuickCheck::Maybe),
+    (0x093C, 0x093C, NfcQuickCheck::Maybe),
+    (0x0958, 0x095F, NfcQuickCheck::No),
+    (0x09BE, 0x09BE, NfcQuickCheck::Maybe),
+    (0x09D7, 0x09D7, NfcQuickCheck::Maybe),
+    (0x09DC, 0x09DD, NfcQuickCheck::No),
+    (0x09DF, 0x09DF, NfcQuickCheck::No),
+    (0x0A33, 0x0A33, NfcQuickCheck::No),
+    (0x0A36, 0x0A36, NfcQuickCheck::No),
+    (0x0A59, 0x0A5B, NfcQuickCheck::No),
+    (0x0A5E, 0x0A5E, NfcQuickCheck::No),
+    (0x0B3E, 0x0B3E, NfcQuickCheck::Maybe),
+    (0x0B56, 0x0B57, NfcQuickCheck::Maybe),
+    (0x0B5C, 0x0B5D, NfcQuickCheck::No),
+    (0x0BBE, 0x0BBE, NfcQuickCheck::Maybe),
+    (0x0BD7, 0x0BD7, NfcQuickCheck::Maybe),
+    (0x0C56, 0x0C56, NfcQuickCheck::Maybe),
+    (0x0CC2, 0x0CC2, NfcQuickCheck::Maybe),
+    (0x0CD5, 0x0CD6, NfcQuickCheck::Maybe),
+    (0x0D3E, 0x0D3E, NfcQuickCheck::Maybe),
+    (0x0D57, 0x0D57, NfcQuickCheck::Maybe),
+    (0x0DCA, 0x0DCA, NfcQuickCheck::Maybe),
+    (0x0DCF, 0x0DCF, NfcQuickCheck::Maybe),
+    (0x0DDF, 0x0DDF, NfcQuickCheck::Maybe),
+    (0x0F43, 0x0F43, NfcQuickCheck::No),
+    (0x0F4D, 0x0F4D, NfcQuickCheck::No),
+    (0x0F52, 0x0F52, NfcQuickCheck::No),
+    (0x0F57, 0x0F57, NfcQuickCheck::No),
+    (0x0F5C, 0x0F5C, NfcQuickCheck::No),
+    (0x0F69, 0x0F69, NfcQuickCheck::No),
+    (0x0F73, 0x0F73, NfcQuickCheck::No),
+    (0x0F75, 0x0F76, NfcQuickCheck::No),
+    (0x0F78, 0x0F78, NfcQuickCheck::No),
+    (0x0F81, 0x0F81, NfcQuickCheck::No),
+    (0x0F93, 0x0F93, NfcQuickCheck::No),
+    (0x0F9D, 0x0F9D, NfcQuickCheck::No),
+    (0x0FA2, 0x0FA2, NfcQuickCheck::No),
+    (0x0FA7, 0x0FA7, NfcQuickCheck::No),
+    (0x0FAC, 0x0FAC, NfcQuickCheck::No),
+    (0x0FB9, 0x0FB9, NfcQuickCheck::No),
+    (0x102E, 0x102E, NfcQuickCheck::Maybe),
+    (0x1161, 0x1175, NfcQuickCheck::Maybe),
+    (0x11A8, 0x11C2, NfcQuickCheck::Maybe),
+    (0x1B35, 0x1B35, NfcQuickCheck::Maybe),
+    (0x1F71, 0x1F71, NfcQuickCheck::No),
+    (0x1F73, 0x1F73, NfcQuickCheck::No),
+    (0x1F75, 0x1F75, NfcQuickCheck::No),
+    (0x1F77, 0x1F77, NfcQuickCheck::No),
+    (0x1F79, 0x1F79, NfcQuickCheck::No),
+    (0x1F7B, 0x1F7B, NfcQuickCheck::No),
+    (0x1F7D, 0x1F7D, NfcQuickCheck::No),
+    (0x1FBB, 0x1FBB, NfcQuickCheck::No),
+    (0x1FBE, 0x1FBE, NfcQuickCheck::No),
+    (0x1FC9, 0x1FC9, NfcQuickCheck::No),
+    (0x1FCB, 0x1FCB, NfcQuickCheck::No),
+    (0x1FD3, 0x1FD3, NfcQuickCheck::No),
+    (0x1FDB, 0x1FDB, NfcQuickCheck::No),
+    (0x1FE3, 0x1FE3, NfcQuickCheck::No),
+    (0x1FEB, 0x1FEB, NfcQuickCheck::No),
+    (0x1FEE, 0x1FEF, NfcQuickCheck::No),
+    (0x1FF9, 0x1FF9, NfcQuickCheck::No),
+    (0x1FFB, 0x1FFB, NfcQuickCheck::No),
+    (0x1FFD, 0x1FFD, NfcQuickCheck::No),
+    (0x2000, 0x2001, NfcQuickCheck::No),
+    (0x2126, 0x2126, NfcQuickCheck::No),
+    (0x212A, 0x212B, NfcQuickCheck::No),
+    (0x2329, 0x232A, NfcQuickCheck::No),
+    (0x2ADC, 0x2ADC, NfcQuickCheck::No),
+    (0x3099, 0x309A, NfcQuickCheck::Maybe),
+    (0xF900, 0xFA0D, NfcQuickCheck::No),
+    (0xFA10, 0xFA10, NfcQuickCheck::No),
+    (0xFA12, 0xFA12, NfcQuickCheck::No),
+    (0xFA15, 0xFA1E, NfcQuickCheck::No),
+    (0xFA20, 0xFA20, NfcQuickCheck::No),
+    (0xFA22, 0xFA22, NfcQuickCheck::No),
+    (0xFA25, 0xFA26, NfcQuickCheck::No),
+    (0xFA2A, 0xFA6D, NfcQuickCheck::No),
+    (0xFA70, 0xFAD9, NfcQuickCheck::No),
+    (0xFB1D, 0xFB1D, NfcQuickCheck::No),
+    (0xFB1F, 0xFB1F, NfcQuickCheck::No),
+    (0xFB2A, 0xFB36, NfcQuickCheck::No),
+    (0xFB38, 0xFB3C, NfcQuickCheck::No),
+    (0xFB3E, 0xFB3E, NfcQuickCheck::No),
+    (0xFB40, 0xFB41, NfcQuickCheck::No),
+    (0xFB43, 0xFB44, NfcQuickCheck::No),
+    (0xFB46, 0xFB4E, NfcQuickCheck::No),
+    (0x110BA, 0x110BA, NfcQuickCheck::Maybe),
+    (0x11127, 0x11127, NfcQuickCheck::Maybe),
+    (0x1133E, 0x1133E, NfcQuickCheck::Maybe),
+    (0x11357, 0x11357, NfcQuickCheck::Maybe),
+    (0x114B0, 0x114B0, NfcQuickCheck::Maybe),
+    (0x114BA, 0x114BA, NfcQuickCheck::Maybe),
+    (0x114BD, 0x114BD, NfcQuickCheck::Maybe),
+    (0x115AF, 0x115AF, NfcQuickCheck::Maybe),
+    (0x11930, 0x11930, NfcQuickCheck::Maybe),
+    (0x1D15E, 0x1D164, NfcQuickCheck::No),
+    (0x1D1BB, 0x1D1C0, NfcQuickCheck::No),
+    (0x2F800, 0x2FA1D, NfcQuickCheck::No),
+];
@@ -0,0 +1,258 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (DerivedNormalizationProps.txt and
+// PropertyValueAliases.txt), limited to the characters assigned in Unicode 13.0.
+
+use super::NfdQuickCheck;
+
+pub(super) const VALUES: &[NfdQuickCheck] = &[NfdQuickCheck::No, NfdQuickCheck::Yes];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[("N", "No", "No"), ("Y", "Yes", "Yes")];
+
+pub(super) const RANGES: &[(u32, u32, NfdQuickCheck)] = &[
+    (0x00C0, 0x00C5, NfdQuickCheck::No),
+    (0x00C7, 0x00CF, NfdQuickCheck::No),
+    (0x00D1, 0x00D6, NfdQuickCheck::No),
+    (0x00D9, 0x00DD, NfdQuickCheck::No),
+    (0x00E0, 0x00E5, NfdQuickCheck::No),
+    (0x00E7, 0x00EF, NfdQuickCheck::No),
+    (0x00F1, 0x00F6, NfdQuickCheck::No),
+    (0x00F9, 0x00FD, NfdQuickCheck::No),
+    (0x00FF, 0x010F, NfdQuickCheck::No),
+    (0x0112, 0x0125, NfdQuickCheck::No),
+    (0x0128, 0x0130, NfdQuickCheck::No),
+    (0x0134, 0x0137, NfdQuickCheck::No),
+    (0x0139, 0x013E, NfdQuickCheck::No),
+    (0x0143, 0x0148, NfdQuickCheck::No),
+    (0x014C, 0x0151, NfdQuickCheck::No),
+    (0x0154, 0x0165, NfdQuickCheck::No),
+    (0x0168, 0x017E, NfdQuickCheck::No),
+    (0x01A0, 0x01A1, NfdQuickCheck::No),
+    (0x01AF, 0x01B0, NfdQuickCheck::No),
+    (0x01CD, 0x01DC, NfdQuickCheck::No),
+    (0x01DE, 0x01E3, NfdQuickCheck::No),
+    (0x01E6, 0x01F0, NfdQuickCheck::No),
+    (0x01F4, 0x01F5, NfdQuickCheck::No),
+    (0x01F8, 0x021B, NfdQuickCheck::No),
+    (0x021E, 0x021F, NfdQuickCheck::No),
+    (0x0226, 0x0233, NfdQuickCheck::No),
+    (0x0340, 0x0341, NfdQuickCheck::No),
+    (0x0343, 0x0344, NfdQuickCheck::No),
+    (0x0374, 0x0374, NfdQuickCheck::No),
+    (0x037E, 0x037E, NfdQuickCheck::No),
+    (0x0385, 0x038A, NfdQuickCheck::No),
+    (0x038C, 0x038C, NfdQuickCheck::No),
+    (0x038E, 0x0390, NfdQuickCheck::No),
+    (0x03AA, 0x03B0, NfdQuickCheck::No),
+    (0x03CA, 0x03CE, NfdQuickCheck::No),
+    (0x03D3, 0x03D4, NfdQuickCheck::No),
+    (0x0400, 0x0401, NfdQuickCheck::No),
+    (0x0403, 0x0403, NfdQuickCheck::No),
+    (0x0407, 0x0407, NfdQuickCheck::No),
+    (0x040C, 0x040E, NfdQuickCheck::No),
+    (0x0419, 0x0419, NfdQuickCheck::No),
+    (0x0439, 0x0439, NfdQuickCheck::No),
+    (0x0450, 0x0451, NfdQuickCheck::No),
+    (0x0453, 0x0453, NfdQuickCheck::No),
+    (0x0457, 0x0457, NfdQuickCheck::No),
+    (0x045C, 0x045E, NfdQuickCheck::No),
+    (0x0476, 0x0477, NfdQuickCheck::No),
+    (0x04C1, 0x04C2, NfdQuickCheck::No),
+    (0x04D0, 0x04D3, NfdQuickCheck::No),
+    (0x04D6, 0x04D7, NfdQuickCheck::No),
+    (0x04DA, 0x04DF, NfdQuickCheck::No),
+    (0x04E2, 0x04E7, NfdQuickCheck::No),
+    (0x04EA, 0x04F5, NfdQuickCheck::No),
+    (0x04F8, 0x04F9, NfdQuickCheck::No),
+    (0x0622, 0x0626, NfdQuickCheck::No),
+    (0x06C0, 0x06C0, NfdQuickCheck::No),
+    (0x06C2, 0x06C2, NfdQuickCheck::No),
+    (0x06D3, 0x06D3, NfdQuickCheck::No),
+    (0x0929, 0x0929, NfdQuickCheck::No),
+    (0x0931, 0x0931, NfdQuickCheck::No),
+    (0x0934, 0x0934, NfdQuickCheck::No),
+    (0x0958, 0x095F, NfdQuickCheck::No),
+    (0x09CB, 0x09CC, NfdQuickCheck::No),
+    (0x09DC, 0x09DD, NfdQuickCheck::No),
+    (0x09DF, 0x09DF, NfdQuickCheck::No),
+    (0x0A33, 0x0A33, NfdQuickCheck::No),
+    (0x0A36, 0x0A36, NfdQuickCheck::No),
+    (0x0A59, 0x0A5B, NfdQuickCheck::No),
+    (0x0A5E, 0x0A5E, NfdQuickCheck::No),
+    (0x0B48, 0x0B48, NfdQuickCheck::No),
+    (0x0B4B, 0x0B4C, NfdQuickCheck::No),
+    (0x0B5C, 0x0B5D, NfdQuickCheck::No),
+    (0x0B94, 0x0B94, NfdQuickCheck::No),
+    (0x0BCA, 0x0BCC, NfdQuickCheck::No),
+    (0x0C48, 0x0C48, NfdQuickCheck::No),
+    (0x0CC0, 0x0CC0, NfdQuickCheck::No),
+    (0x0CC7, 0x0CC8, NfdQuickCheck::No),
+    (0x0CCA, 0x0CCB, NfdQuickCheck::No),
+    (0x0D4A, 0x0D4C, NfdQuickCheck::No),
+    (0x0DDA, 0x0DDA, NfdQuickCheck::No),
+    (0x0DDC, 0x0DDE, NfdQuickCheck::No),
+    (0x0F43, 0x0F43, NfdQuickCheck::No),
+    (0x0F4D, 0x0F4D, NfdQuickCheck::No),
+    (0x0F52, 0x0F52, NfdQuickCheck::No),
+    (0x0F57, 0x0F57, NfdQuickCheck::No),
+    (0x0F5C, 0x0F5C, NfdQuickCheck::No),
+    (0x0F69, 0x0F69, NfdQuickCheck::No),
+    (0x0F73, 0x0F73, NfdQuickCheck::No),
+    (0x0F75, 0x0F76, NfdQuickCheck::No),
+    (0x0F78, 0x0F78, NfdQuickCheck::No),
+    (0x0F81, 0x0F81, NfdQuickCheck::No),
+    (0x0F93, 0x0F93, NfdQuickCheck::No),
+    (0x0F9D, 0x0F9D, NfdQuickCheck::No),
+    (0x0FA2, 0x0FA2, NfdQuickCheck::No),
+    (0x0FA7, 0x0FA7, NfdQuickCheck::No),
+    (0x0FAC, 0x0FAC, NfdQuickCheck::No),
+    (0x0FB9, 0x0FB9, NfdQuickCheck::No),
+    (0x1026, 0x1026, NfdQuickCheck::No),
+    (0x1B06, 0x1B06, NfdQuickCheck::No),
+    (0x1B08, 0x1B08, NfdQuickCheck::No),
+    (0x1B0A, 0x1B0A, NfdQuickCheck::No),
+    (0x1B0C, 0x1B0C, NfdQuickCheck::No),
+    (0x1B0E, 0x1B0E, NfdQuickCheck::No),
+    (0x1B12, 0x1B12, NfdQuickCheck::No),
+    (0x1B3B, 0x1B3B, NfdQuickCheck::No),
+    (0x1B3D, 0x1B3D, NfdQuickCheck::No),
+    (0x1B40, 0x1B41, NfdQuickCheck::No),
+    (0x1B43, 0x1B43, NfdQuickCheck::No),
+    (0x1E00, 0x1E99, NfdQuickCheck::No),
+    (0x1E9B, 0x1E9B, NfdQuickCheck::No),
+    (0x1EA0, 0x1EF9, NfdQuickCheck::No),
+    (0x1F00, 0x1F15, NfdQuickCheck::No),
+    (0x1F18, 0x1F1D, NfdQuickCheck::No),
+    (0x1F20, 0x1F45, NfdQuickCheck::No),
+    (0x1F48, 0x1F4D, NfdQuickCheck::No),
+    (0x1F50, 0x1F57, NfdQuickCheck::No),
+    (0x1F59, 0x1F59, NfdQuickCheck::No),
+    (0x1F5B, 0x1F5B, NfdQuickCheck::No),
+    (0x1F5D, 0x1F5D, NfdQuickCheck::No),
+    (0x1F5F, 0x1F7D, NfdQuickCheck::No),
+    (0x1F80, 0x1FB4, NfdQuickCheck::No),
+    (0x1FB6, 0x1FBC, NfdQuickCheck::No),
+    (0x1FBE, 0x1FBE, NfdQuickCheck::No),
+    (0x1FC1, 0x1FC4, NfdQuickCheck::No),
+    (0x1FC6, 0x1FD3, NfdQuickCheck::No),
+    (0x1FD6, 0x1FDB, NfdQuickCheck::No),
+    (0x1FDD, 0x1FEF, NfdQuickCheck::No),
+    (0x1FF2, 0x1FF4, NfdQuickCheck::No),
+    (0x1FF6, 0x1FFD, NfdQuickCheck::No),
+    (0x2000, 0x2001, NfdQuickCheck::No),
+    (0x2126, 0x2126, NfdQuickCheck::No),
+    (0x212A, 0x212B, NfdQuickCheck::No),
+    (0x219A, 0x219B, NfdQuickCheck::No),
+    (0x21AE, 0x21AE, NfdQuickCheck::No),
+    (0x21CD, 0x21CF, NfdQuickCheck::No),
+    (0x2204, 0x2204, NfdQuickCheck::No),
+    (0x2209, 0x2209, NfdQuickCheck::No),
+    (0x220C, 0x220C, NfdQuickCheck::No),
+    (0x2224, 0x2224, NfdQuickCheck::No),
+    (0x2226, 0x2226, NfdQuickCheck::No),
+    (0x2241, 0x2241, NfdQuickCheck::No),
+    (0x2244, 0x2244, NfdQuickCheck::No),
+    (0x2247, 0x2247, NfdQuickCheck::No),
+    (0x2249, 0x2249, NfdQuickCheck::No),
+    (0x2260, 0x2260, NfdQuickCheck::No),
+    (0x2262, 0x2262, NfdQuickCheck::No),
+    (0x226D, 0x2271, NfdQuickCheck::No),
+    (0x2274, 0x2275, NfdQuickCheck::No),
+    (0x2278, 0x2279, NfdQuickCheck::No),
+    (0x2280, 0x2281, NfdQuickCheck::No),
+    (0x2284, 0x2285, NfdQuickCheck::No),
+    (0x2288, 0x2289, NfdQuickCheck::No),
+    (0x22AC, 0x22AF, NfdQuickCheck::No),
+    (0x22E0, 0x22E3, NfdQuickCheck::No),
+    (0x22EA, 0x22ED, NfdQuickCheck::No),
+    (0x2329, 0x232A, NfdQuickCheck::No),
+    (0x2ADC, 0x2ADC, NfdQuickCheck::No),
+    (0x304C, 0x304C, NfdQuickCheck::No),
+    (0x304E, 0x304E, NfdQuickCheck::No),
+    (0x3050, 0x3050, NfdQuickCheck::No),
+    (0x3052, 0x3052, NfdQuickCheck::No),
+    (0x3054, 0x3054, NfdQuickCheck::No),
+    (0x3056, 0x3056, NfdQuickCheck::No),
+    (0x3058, 0x3058, NfdQuickCheck::No),
+    (0x305A, 0x305A, NfdQuickCheck::No),
+    (0x305C, 0x305C, NfdQuickCheck::No),
+    (0x305E, 0x305E, NfdQuickCheck::No),
+    (0x3060, 0x3060, NfdQuickCheck::No),
+    (0x3062, 0x3062, NfdQuickCheck::No),
+    (0x3065, 0x3065, NfdQuickCheck::No),
+    (0x3067, 0x3067, NfdQuickCheck::No),
+    (0x3069, 0x3069, NfdQuickCheck::No),
+    (0x3070, 0x3071, NfdQuickCheck::No),
+    (0x3073, 0x3074, NfdQuickCheck::No),
+    (0x3076, 0x3077, NfdQuickCheck::No),
+    (0x3079, 0x307A, NfdQuickCheck::No),
+    (0x307C, 0x307D, NfdQuickCheck::No),
+    (0x3094, 0x3094, NfdQuickCheck::No),
+    (0x309E, 0x309E, NfdQuickCheck::No),
+    (0x30AC, 0x30AC, NfdQuickCheck::No),
+    (0x30AE, 0x30AE, NfdQuickCheck::No),
+    (0x30B0, 0x30B0, NfdQuickCheck::No),
+    (0x30B2, 0x30B2, NfdQuickCheck::No),
+    (0x30B4, 0x30B4, NfdQuickCheck::No),
+    (0x30B6, 0x30B6, NfdQuickCheck::No),
+    (0x30B8, 0x30B8, NfdQuickCheck::No),
+    (0x30BA, 0x30BA, NfdQuickCheck::No),
+    (0x30BC, 0x30BC, NfdQuickCheck::No),
+    (0x30BE, 0x30BE, NfdQuickCheck::No),
+    (0x30C0, 0x30C0, NfdQuickCheck::No),
+    (0x30C2, 0x30C2, NfdQuickCheck::No),
+    (0x30C5, 0x30C5, NfdQuickCheck::No),
+    (0x30C7, 0x30C7, NfdQuickCheck::No),
+    (0x30C9, 0x30C9, NfdQuickCheck::No),
+    (0x30D0, 0x30D1, NfdQuickCheck::No),
+    (0x30D3, 0x30D4, NfdQuickCheck::No),
+    (0x30D6, 0x30D7, NfdQuickCheck::No),
+    (0x30D9, 0x30DA, NfdQuickCheck::No),
+    (0x30DC, 0x30DD, NfdQuickCheck::No),
+    (0x30F4, 0x30F4, NfdQuickCheck::No),
+    (0x30F7, 0x30FA, NfdQuickCheck::No),
+    (0x30FE, 0x30FE, NfdQuickCheck::No),
+    (0xAC00, 0xD7A3, NfdQuickCheck::No),
+    (0xF900, 0xFA0D, NfdQuickCheck::No),
+    (0xFA10, 0xFA10, NfdQuickCheck::No),
+    (0xFA12, 0xFA12, NfdQuickCheck::No),
+    (0xFA15, 0xFA1E, NfdQuickCheck::No),
+    (0xFA20, 0xFA20, NfdQuickCheck::No),
+    (0xFA22, 0xFA22, NfdQuickCheck::No),
+    (0xFA25, 0xFA26, NfdQuickCheck::No),
+    (0xFA2A, 0xFA6D, NfdQuickCheck::No),
+    (0xFA70, 0xFAD9, NfdQuickCheck::No),
+    (0xFB1D, 0xFB1D, NfdQuickCheck::No),
+    (0xFB1F, 0xFB1F, NfdQuickCheck::No),
+    (0xFB2A, 0xFB36, NfdQuickCheck::No),
+    (0xFB38, 0xFB3C, NfdQuickCheck::No),
+    (0xFB3E, 0xFB3E, NfdQuickCheck::No),
+    (0xFB40, 0xFB41, NfdQuickCheck::No),
+    (0xFB43, 0xFB44, NfdQuickCheck::No),
+    (0xFB46, 0xFB4E, NfdQuickCheck::No),
+    (0x1109A, 0x1109A, NfdQuickCheck::No),
+    (0x1109C, 0x1109C, NfdQuickCheck::No),
+    (0x110AB, 0x110AB, NfdQuickCheck::No),
+    (0x1112E, 0x1112F, NfdQuickCheck::No),
+    (0x1134B, 0x1134C, NfdQuickCheck::No),
+    (0x114BB, 0x114BC, NfdQuickCheck::No),
+    (0x114BE, 0x114BE, NfdQuickCheck::No),
+    (0x115BA, 0x115BB, NfdQuickCheck::No),
+    (0x11938, 0x11938, NfdQuickCheck::No),
+    (0x1D15E, 0x1D164, NfdQuickCheck::No),
+    (0x1D1BB, 0x1D1C0, NfdQuickCheck::No),
+    (0x2F800, 0x2FA1D, NfdQuickCheck::No),
+];
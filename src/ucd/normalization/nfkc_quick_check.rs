@@ -0,0 +1,326 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (DerivedNormalizationProps.txt and
+// PropertyValueAliases.txt), limited to the characters assigned in Unicode 13.0.
+
+use super::NfkcQuickCheck;
+
+pub(super) const VALUES: &[NfkcQuickCheck] = &[
+    NfkcQuickCheck::Maybe,
+    NfkcQuickCheck::No,
+    NfkcQuickCheck::Yes,
+];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[
+    ("M", "Maybe", "Maybe"),
+    ("N", "No", "No"),
+    ("Y", "Yes", "Yes"),
+];
+
+pub(super) const RANGES: &[(u32, u32, NfkcQuickCheck)] = &[
+    (0x00A0, 0x00A0, NfkcQuickCheck::No),
+    (0x00A8, 0x00A8, NfkcQuickCheck::No),
+    (0x00AA, 0x00AA, NfkcQuickCheck::No),
+    (0x00AF, 0x00AF, NfkcQuickCheck::No),
+    (0x00B2, 0x00B5, NfkcQuickCheck::No),
+    (0x00B8, 0x00BA, NfkcQuickCheck::No),
+    (0x00BC, 0x00BE, NfkcQuickCheck::No),
+    (0x0132, 0x0133, NfkcQuickCheck::No),
+    (0x013F, 0x0140, NfkcQuickCheck::No),
+    (0x0149, 0x0149, NfkcQuickCheck::No),
+    (0x017F, 0x017F, NfkcQuickCheck::No),
+    (0x01C4, 0x01CC, NfkcQuickCheck::No),
+    (0x01F1, 0x01F3, NfkcQuickCheck::No),
+    (0x02B0, 0x02B8, NfkcQuickCheck::No),
+    (0x02D8, 0x02DD, NfkcQuickCheck::No),
+    (0x02E0, 0x02E4, NfkcQuickCheck::No),
+    (0x0300, 0x0304, NfkcQuickCheck::Maybe),
+    (0x0306, 0x030C, NfkcQuickCheck::Maybe),
+    (0x030F, 0x030F, NfkcQuickCheck::Maybe),
+    (0x0311, 0x0311, NfkcQuickCheck::Maybe),
+    (0x0313, 0x0314, NfkcQuickCheck::Maybe),
+    (0x031B, 0x031B, NfkcQuickCheck::Maybe),
+    (0x0323, 0x0328, NfkcQuickCheck::Maybe),
+    (0x032D, 0x032E, NfkcQuickCheck::Maybe),
+    (0x0330, 0x0331, NfkcQuickCheck::Maybe),
+    (0x0338, 0x0338, NfkcQuickCheck::Maybe),
+    (0x0340, 0x0341, NfkcQuickCheck::No),
+    (0x0342, 0x0342, NfkcQuickCheck::Maybe),
+    (0x0343, 0x0344, NfkcQuickCheck::No),
+    (0x0345, 0x0345, NfkcQuickCheck::Maybe),
+    (0x0374, 0x0374, NfkcQuickCheck::No),
+    (0x037A, 0x037A, NfkcQuickCheck::No),
+    (0x037E, 0x037E, NfkcQuickCheck::No),
+    (0x0384, 0x0385, NfkcQuickCheck::No),
+    (0x0387, 0x0387, NfkcQuickCheck::No),
+    (0x03D0, 0x03D6, NfkcQuickCheck::No),
+    (0x03F0, 0x03F2, NfkcQuickCheck::No),
+    (0x03F4, 0x03F5, NfkcQuickCheck::No),
+    (0x03F9, 0x03F9, NfkcQuickCheck::No),
+    (0x0587, 0x0587, NfkcQuickCheck::No),
+    (0x0653, 0x0655, NfkcQuickCheck::Maybe),
+    (0x0675, 0x0678, NfkcQuickCheck::No),
+    (0x093C, 0x093C, NfkcQuickCheck::Maybe),
+    (0x0958, 0x095F, NfkcQuickCheck::No),
+    (0x09BE, 0x09BE, NfkcQuickCheck::Maybe),
+    (0x09D7, 0x09D7, NfkcQuickCheck::Maybe),
+    (0x09DC, 0x09DD, NfkcQuickCheck::No),
+    (0x09DF, 0x09DF, NfkcQuickCheck::No),
+    (0x0A33, 0x0A33, NfkcQuickCheck::No),
+    (0x0A36, 0x0A36, NfkcQuickCheck::No),
+    (0x0A59, 0x0A5B, NfkcQuickCheck::No),
+    (0x0A5E, 0x0A5E, NfkcQuickCheck::No),
+    (0x0B3E, 0x0B3E, NfkcQuickCheck::Maybe),
+    (0x0B56, 0x0B57, NfkcQuickCheck::Maybe),
+    (0x0B5C, 0x0B5D, NfkcQuickCheck::No),
+    (0x0BBE, 0x0BBE, NfkcQuickCheck::Maybe),
+    (0x0BD7, 0x0BD7, NfkcQuickCheck::Maybe),
+    (0x0C56, 0x0C56, NfkcQuickCheck::Maybe),
+    (0x0CC2, 0x0CC2, NfkcQuickCheck::Maybe),
+    (0x0CD5, 0x0CD6, NfkcQuickCheck::Maybe),
+    (0x0D3E, 0x0D3E, NfkcQuickCheck::Maybe),
+    (0x0D57, 0x0D57, NfkcQuickCheck::Maybe),
+    (0x0DCA, 0x0DCA, NfkcQuickCheck::Maybe),
+    (0x0DCF, 0x0DCF, NfkcQuickCheck::Maybe),
+    (0x0DDF, 0x0DDF, NfkcQuickCheck::Maybe),
+    (0x0E33, 0x0E33, NfkcQuickCheck::No),
+    (0x0EB3, 0x0EB3, NfkcQuickCheck::No),
+    (0x0EDC, 0x0EDD, NfkcQuickCheck::No),
+    (0x0F0C, 0x0F0C, NfkcQuickCheck::No),
+    (0x0F43, 0x0F43, NfkcQuickCheck::No),
+    (0x0F4D, 0x0F4D, NfkcQuickCheck::No),
+    (0x0F52, 0x0F52, NfkcQuickCheck::No),
+    (0x0F57, 0x0F57, NfkcQuickCheck::No),
+    (0x0F5C, 0x0F5C, NfkcQuickCheck::No),
+    (0x0F69, 0x0F69, NfkcQuickCheck::No),
+    (0x0F73, 0x0F73, NfkcQuickCheck::No),
+    (0x0F75, 0x0F79, NfkcQuickCheck::No),
+    (0x0F81, 0x0F81, NfkcQuickCheck::No),
+    (0x0F93, 0x0F93, NfkcQuickCheck::No),
+    (0x0F9D, 0x0F9D, NfkcQuickCheck::No),
+    (0x0FA2, 0x0FA2, NfkcQuickCheck::No),
+    (0x0FA7, 0x0FA7, NfkcQuickCheck::No),
+    (0x0FAC, 0x0FAC, NfkcQuickCheck::No),
+    (0x0FB9, 0x0FB9, NfkcQuickCheck::No),
+    (0x102E, 0x102E, NfkcQuickCheck::Maybe),
+    (0x10FC, 0x10FC, NfkcQuickCheck::No),
+    (0x1161, 0x1175, NfkcQuickCheck::Maybe),
+    (0x11A8, 0x11C2, NfkcQuickCheck::Maybe),
+    (0x1B35, 0x1B35, NfkcQuickCheck::Maybe),
+    (0x1D2C, 0x1D2E, NfkcQuickCheck::No),
+    (0x1D30, 0x1D3A, NfkcQuickCheck::No),
+    (0x1D3C, 0x1D4D, NfkcQuickCheck::No),
+    (0x1D4F, 0x1D6A, NfkcQuickCheck::No),
+    (0x1D78, 0x1D78, NfkcQuickCheck::No),
+    (0x1D9B, 0x1DBF, NfkcQuickCheck::No),
+    (0x1E9A, 0x1E9B, NfkcQuickCheck::No),
+    (0x1F71, 0x1F71, NfkcQuickCheck::No),
+    (0x1F73, 0x1F73, NfkcQuickCheck::No),
+    (0x1F75, 0x1F75, NfkcQuickCheck::No),
+    (0x1F77, 0x1F77, NfkcQuickCheck::No),
+    (0x1F79, 0x1F79, NfkcQuickCheck::No),
+    (0x1F7B, 0x1F7B, NfkcQuickCheck::No),
+    (0x1F7D, 0x1F7D, NfkcQuickCheck::No),
+    (0x1FBB, 0x1FBB, NfkcQuickCheck::No),
+    (0x1FBD, 0x1FC1, NfkcQuickCheck::No),
+    (0x1FC9, 0x1FC9, NfkcQuickCheck::No),
+    (0x1FCB, 0x1FCB, NfkcQuickCheck::No),
+    (0x1FCD, 0x1FCF, NfkcQuickCheck::No),
+    (0x1FD3, 0x1FD3, NfkcQuickCheck::No),
+    (0x1FDB, 0x1FDB, NfkcQuickCheck::No),
+    (0x1FDD, 0x1FDF, NfkcQuickCheck::No),
+    (0x1FE3, 0x1FE3, NfkcQuickCheck::No),
+    (0x1FEB, 0x1FEB, NfkcQuickCheck::No),
+    (0x1FED, 0x1FEF, NfkcQuickCheck::No),
+    (0x1FF9, 0x1FF9, NfkcQuickCheck::No),
+    (0x1FFB, 0x1FFB, NfkcQuickCheck::No),
+    (0x1FFD, 0x1FFE, NfkcQuickCheck::No),
+    (0x2000, 0x200A, NfkcQuickCheck::No),
+    (0x2011, 0x2011, NfkcQuickCheck::No),
+    (0x2017, 0x2017, NfkcQuickCheck::No),
+    (0x2024, 0x2026, NfkcQuickCheck::No),
+    (0x202F, 0x202F, NfkcQuickCheck::No),
+    (0x2033, 0x2034, NfkcQuickCheck::No),
+    (0x2036, 0x2037, NfkcQuickCheck::No),
+    (0x203C, 0x203C, NfkcQuickCheck::No),
+    (0x203E, 0x203E, NfkcQuickCheck::No),
+    (0x2047, 0x2049, NfkcQuickCheck::No),
+    (0x2057, 0x2057, NfkcQuickCheck::No),
+    (0x205F, 0x205F, NfkcQuickCheck::No),
+    (0x2070, 0x2071, NfkcQuickCheck::No),
+    (0x2074, 0x208E, NfkcQuickCheck::No),
+    (0x2090, 0x209C, NfkcQuickCheck::No),
+    (0x20A8, 0x20A8, NfkcQuickCheck::No),
+    (0x2100, 0x2103, NfkcQuickCheck::No),
+    (0x2105, 0x2107, NfkcQuickCheck::No),
+    (0x2109, 0x2113, NfkcQuickCheck::No),
+    (0x2115, 0x2116, NfkcQuickCheck::No),
+    (0x2119, 0x211D, NfkcQuickCheck::No),
+    (0x2120, 0x2122, NfkcQuickCheck::No),
+    (0x2124, 0x2124, NfkcQuickCheck::No),
+    (0x2126, 0x2126, NfkcQuickCheck::No),
+    (0x2128, 0x2128, NfkcQuickCheck::No),
+    (0x212A, 0x212D, NfkcQuickCheck::No),
+    (0x212F, 0x2131, NfkcQuickCheck::No),
+    (0x2133, 0x2139, NfkcQuickCheck::No),
+    (0x213B, 0x2140, NfkcQuickCheck::No),
+    (0x2145, 0x2149, NfkcQuickCheck::No),
+    (0x2150, 0x217F, NfkcQuickCheck::No),
+    (0x2189, 0x2189, NfkcQuickCheck::No),
+    (0x222C, 0x222D, NfkcQuickCheck::No),
+    (0x222F, 0x2230, NfkcQuickCheck::No),
+    (0x2329, 0x232A, NfkcQuickCheck::No),
+    (0x2460, 0x24EA, NfkcQuickCheck::No),
+    (0x2A0C, 0x2A0C, NfkcQuickCheck::No),
+    (0x2A74, 0x2A76, NfkcQuickCheck::No),
+    (0x2ADC, 0x2ADC, NfkcQuickCheck::No),
+    (0x2C7C, 0x2C7D, NfkcQuickCheck::No),
+    (0x2D6F, 0x2D6F, NfkcQuickCheck::No),
+    (0x2E9F, 0x2E9F, NfkcQuickCheck::No),
+    (0x2EF3, 0x2EF3, NfkcQuickCheck::No),
+    (0x2F00, 0x2FD5, NfkcQuickCheck::No),
+    (0x3000, 0x3000, NfkcQuickCheck::No),
+    (0x3036, 0x3036, NfkcQuickCheck::No),
+    (0x3038, 0x303A, NfkcQuickCheck::No),
+    (0x3099, 0x309A, NfkcQuickCheck::Maybe),
+    (0x309B, 0x309C, NfkcQuickCheck::No),
+    (0x309F, 0x309F, NfkcQuickCheck::No),
+    (0x30FF, 0x30FF, NfkcQuickCheck::No),
+    (0x3131, 0x318E, NfkcQuickCheck::No),
+    (0x3192, 0x319F, NfkcQuickCheck::No),
+    (0x3200, 0x321E, NfkcQuickCheck::No),
+    (0x3220, 0x3247, NfkcQuickCheck::No),
+    (0x3250, 0x327E, NfkcQuickCheck::No),
+    (0x3280, 0x33FF, NfkcQuickCheck::No),
+    (0xA69C, 0xA69D, NfkcQuickCheck::No),
+    (0xA770, 0xA770, NfkcQuickCheck::No),
+    (0xA7F8, 0xA7F9, NfkcQuickCheck::No),
+    (0xAB5C, 0xAB5F, NfkcQuickCheck::No),
+    (0xAB69, 0xAB69, NfkcQuickCheck::No),
+    (0xF900, 0xFA0D, NfkcQuickCheck::No),
+    (0xFA10, 0xFA10, NfkcQuickCheck::No),
+    (0xFA12, 0xFA12, NfkcQuickCheck::No),
+    (0xFA15, 0xFA1E, NfkcQuickCheck::No),
+    (0xFA20, 0xFA20, NfkcQuickCheck::No),
+    (0xFA22, 0xFA22, NfkcQuickCheck::No),
+    (0xFA25, 0xFA26, NfkcQuickCheck::No),
+    (0xFA2A, 0xFA6D, NfkcQuickCheck::No),
+    (0xFA70, 0xFAD9, NfkcQuickCheck::No),
+    (0xFB00, 0xFB06, NfkcQuickCheck::No),
+    (0xFB13, 0xFB17, NfkcQuickCheck::No),
+    (0xFB1D, 0xFB1D, NfkcQuickCheck::No),
+    (0xFB1F, 0xFB36, NfkcQuickCheck::No),
+    (0xFB38, 0xFB3C, NfkcQuickCheck::No),
+    (0xFB3E, 0xFB3E, NfkcQuickCheck::No),
+    (0xFB40, 0xFB41, NfkcQuickCheck::No),
+    (0xFB43, 0xFB44, NfkcQuickCheck::No),
+    (0xFB46, 0xFBB1, NfkcQuickCheck::No),
+    (0xFBD3, 0xFD3D, NfkcQuickCheck::No),
+    (0xFD50, 0xFD8F, NfkcQuickCheck::No),
+    (0xFD92, 0xFDC7, NfkcQuickCheck::No),
+    (0xFDF0, 0xFDFC, NfkcQuickCheck::No),
+    (0xFE10, 0xFE19, NfkcQuickCheck::No),
+    (0xFE30, 0xFE44, NfkcQuickCheck::No),
+    (0xFE47, 0xFE52, NfkcQuickCheck::No),
+    (0xFE54, 0xFE66, NfkcQuickCheck::No),
+    (0xFE68, 0xFE6B, NfkcQuickCheck::No),
+    (0xFE70, 0xFE72, NfkcQuickCheck::No),
+    (0xFE74, 0xFE74, NfkcQuickCheck::No),
+    (0xFE76, 0xFEFC, NfkcQuickCheck::No),
+    (0xFF01, 0xFFBE, NfkcQuickCheck::No),
+    (0xFFC2, 0xFFC7, NfkcQuickCheck::No),
+    (0xFFCA, 0xFFCF, NfkcQuickCheck::No),
+    (0xFFD2, 0xFFD7, NfkcQuickCheck::No),
+    (0xFFDA, 0xFFDC, NfkcQuickCheck::No),
+    (0xFFE0, 0xFFE6, NfkcQuickCheck::No),
+    (0xFFE8, 0xFFEE, NfkcQuickCheck::No),
+    (0x110BA, 0x110BA, NfkcQuickCheck::Maybe),
+    (0x11127, 0x11127, NfkcQuickCheck::Maybe),
+    (0x1133E, 0x1133E, NfkcQuickCheck::Maybe),
+    (0x11357, 0x11357, NfkcQuickCheck::Maybe),
+    (0x114B0, 0x114B0, NfkcQuickCheck::Maybe),
+    (0x114BA, 0x114BA, NfkcQuickCheck::Maybe),
+    (0x114BD, 0x114BD, NfkcQuickCheck::Maybe),
+    (0x115AF, 0x115AF, NfkcQuickCheck::Maybe),
+    (0x11930, 0x11930, NfkcQuickCheck::Maybe),
+    (0x1D15E, 0x1D164, NfkcQuickCheck::No),
+    (0x1D1BB, 0x1D1C0, NfkcQuickCheck::No),
+    (0x1D400, 0x1D454, NfkcQuickCheck::No),
+    (0x1D456, 0x1D49C, NfkcQuickCheck::No),
+    (0x1D49E, 0x1D49F, NfkcQuickCheck::No),
+    (0x1D4A2, 0x1D4A2, NfkcQuickCheck::No),
+    (0x1D4A5, 0x1D4A6, NfkcQuickCheck::No),
+    (0x1D4A9, 0x1D4AC, NfkcQuickCheck::No),
+    (0x1D4AE, 0x1D4B9, NfkcQuickCheck::No),
+    (0x1D4BB, 0x1D4BB, NfkcQuickCheck::No),
+    (0x1D4BD, 0x1D4C3, NfkcQuickCheck::No),
+    (0x1D4C5, 0x1D505, NfkcQuickCheck::No),
+    (0x1D507, 0x1D50A, NfkcQuickCheck::No),
+    (0x1D50D, 0x1D514, NfkcQuickCheck::No),
+    (0x1D516, 0x1D51C, NfkcQuickCheck::No),
+    (0x1D51E, 0x1D539, NfkcQuickCheck::No),
+    (0x1D53B, 0x1D53E, NfkcQuickCheck::No),
+    (0x1D540, 0x1D544, NfkcQuickCheck::No),
+    (0x1D546, 0x1D546, NfkcQuickCheck::No),
+    (0x1D54A, 0x1D550, NfkcQuickCheck::No),
+    (0x1D552, 0x1D6A5, NfkcQuickCheck::No),
+    (0x1D6A8, 0x1D7CB, NfkcQuickCheck::No),
+    (0x1D7CE, 0x1D7FF, NfkcQuickCheck::No),
+    (0x1EE00, 0x1EE03, NfkcQuickCheck::No),
+    (0x1EE05, 0x1EE1F, NfkcQuickCheck::No),
+    (0x1EE21, 0x1EE22, NfkcQuickCheck::No),
+    (0x1EE24, 0x1EE24, NfkcQuickCheck::No),
+    (0x1EE27, 0x1EE27, NfkcQuickCheck::No),
+    (0x1EE29, 0x1EE32, NfkcQuickCheck::No),
+    (0x1EE34, 0x1EE37, NfkcQuickCheck::No),
+    (0x1EE39, 0x1EE39, NfkcQuickCheck::No),
+    (0x1EE3B, 0x1EE3B, NfkcQuickCheck::No),
+    (0x1EE42, 0x1EE42, NfkcQuickCheck::No),
+    (0x1EE47, 0x1EE47, NfkcQuickCheck::No),
+    (0x1EE49, 0x1EE49, NfkcQuickCheck::No),
+    (0x1EE4B, 0x1EE4B, NfkcQuickCheck::No),
+    (0x1EE4D, 0x1EE4F, NfkcQuickCheck::No),
+    (0x1EE51, 0x1EE52, NfkcQuickCheck::No),
+    (0x1EE54, 0x1EE54, NfkcQuickCheck::No),
+    (0x1EE57, 0x1EE57, NfkcQuickCheck::No),
+    (0x1EE59, 0x1EE59, NfkcQuickCheck::No),
+    (0x1EE5B, 0x1EE5B, NfkcQuickCheck::No),
+    (0x1EE5D, 0x1EE5D, NfkcQuickCheck::No),
+    (0x1EE5F, 0x1EE5F, NfkcQuickCheck::No),
+    (0x1EE61, 0x1EE62, NfkcQuickCheck::No),
+    (0x1EE64, 0x1EE64, NfkcQuickCheck::No),
+    (0x1EE67, 0x1EE6A, NfkcQuickCheck::No),
+    (0x1EE6C, 0x1EE72, NfkcQuickCheck::No),
+    (0x1EE74, 0x1EE77, NfkcQuickCheck::No),
+    (0x1EE79, 0x1EE7C, NfkcQuickCheck::No),
+    (0x1EE7E, 0x1EE7E, NfkcQuickCheck::No),
+    (0x1EE80, 0x1EE89, NfkcQuickCheck::No),
+    (0x1EE8B, 0x1EE9B, NfkcQuickCheck::No),
+    (0x1EEA1, 0x1EEA3, NfkcQuickCheck::No),
+    (0x1EEA5, 0x1EEA9, NfkcQuickCheck::No),
+    (0x1EEAB, 0x1EEBB, NfkcQuickCheck::No),
+    (0x1F100, 0x1F10A, NfkcQuickCheck::No),
+    (0x1F110, 0x1F12E, NfkcQuickCheck::No),
+    (0x1F130, 0x1F14F, NfkcQuickCheck::No),
+    (0x1F16A, 0x1F16C, NfkcQuickCheck::No),
+    (0x1F190, 0x1F190, NfkcQuickCheck::No),
+    (0x1F200, 0x1F202, NfkcQuickCheck::No),
+    (0x1F210, 0x1F23B, NfkcQuickCheck::No),
+    (0x1F240, 0x1F248, NfkcQuickCheck::No),
+    (0x1F250, 0x1F251, NfkcQuickCheck::No),
+    (0x1FBF0, 0x1FBF9, NfkcQuickCheck::No),
+    (0x2F800, 0x2FA1D, NfkcQuickCheck::No),
+];
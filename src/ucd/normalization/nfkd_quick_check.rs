@@ -0,0 +1,418 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (DerivedNormalizationProps.txt and
+// PropertyValueAliases.txt), limited to the characters assigned in Unicode 13.0.
+
+use super::NfkdQuickCheck;
+
+pub(super) const VALUES: &[NfkdQuickCheck] = &[NfkdQuickCheck::No, NfkdQuickCheck::Yes];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[("N", "No", "No"), ("Y", "Yes", "Yes")];
+
+pub(super) const RANGES: &[(u32, u32, NfkdQuickCheck)] = &[
+    (0x00A0, 0x00A0, NfkdQuickCheck::No),
+    (0x00A8, 0x00A8, NfkdQuickCheck::No),
+    (0x00AA, 0x00AA, NfkdQuickCheck::No),
+    (0x00AF, 0x00AF, NfkdQuickCheck::No),
+    (0x00B2, 0x00B5, NfkdQuickCheck::No),
+    (0x00B8, 0x00BA, NfkdQuickCheck::No),
+    (0x00BC, 0x00BE, NfkdQuickCheck::No),
+    (0x00C0, 0x00C5, NfkdQuickCheck::No),
+    (0x00C7, 0x00CF, NfkdQuickCheck::No),
+    (0x00D1, 0x00D6, NfkdQuickCheck::No),
+    (0x00D9, 0x00DD, NfkdQuickCheck::No),
+    (0x00E0, 0x00E5, NfkdQuickCheck::No),
+    (0x00E7, 0x00EF, NfkdQuickCheck::No),
+    (0x00F1, 0x00F6, NfkdQuickCheck::No),
+    (0x00F9, 0x00FD, NfkdQuickCheck::No),
+    (0x00FF, 0x010F, NfkdQuickCheck::No),
+    (0x0112, 0x0125, NfkdQuickCheck::No),
+    (0x0128, 0x0130, NfkdQuickCheck::No),
+    (0x0132, 0x0137, NfkdQuickCheck::No),
+    (0x0139, 0x0140, NfkdQuickCheck::No),
+    (0x0143, 0x0149, NfkdQuickCheck::No),
+    (0x014C, 0x0151, NfkdQuickCheck::No),
+    (0x0154, 0x0165, NfkdQuickCheck::No),
+    (0x0168, 0x017F, NfkdQuickCheck::No),
+    (0x01A0, 0x01A1, NfkdQuickCheck::No),
+    (0x01AF, 0x01B0, NfkdQuickCheck::No),
+    (0x01C4, 0x01DC, NfkdQuickCheck::No),
+    (0x01DE, 0x01E3, NfkdQuickCheck::No),
+    (0x01E6, 0x01F5, NfkdQuickCheck::No),
+    (0x01F8, 0x021B, NfkdQuickCheck::No),
+    (0x021E, 0x021F, NfkdQuickCheck::No),
+    (0x0226, 0x0233, NfkdQuickCheck::No),
+    (0x02B0, 0x02B8, NfkdQuickCheck::No),
+    (0x02D8, 0x02DD, NfkdQuickCheck::No),
+    (0x02E0, 0x02E4, NfkdQuickCheck::No),
+    (0x0340, 0x0341, NfkdQuickCheck::No),
+    (0x0343, 0x0344, NfkdQuickCheck::No),
+    (0x0374, 0x0374, NfkdQuickCheck::No),
+    (0x037A, 0x037A, NfkdQuickCheck::No),
+    (0x037E, 0x037E, NfkdQuickCheck::No),
+    (0x0384, 0x038A, NfkdQuickCheck::No),
+    (0x038C, 0x038C, NfkdQuickCheck::No),
+    (0x038E, 0x0390, NfkdQuickCheck::No),
+    (0x03AA, 0x03B0, NfkdQuickCheck::No),
+    (0x03CA, 0x03CE, NfkdQuickCheck::No),
+    (0x03D0, 0x03D6, NfkdQuickCheck::No),
+    (0x03F0, 0x03F2, NfkdQuickCheck::No),
+    (0x03F4, 0x03F5, NfkdQuickCheck::No),
+    (0x03F9, 0x03F9, NfkdQuickCheck::No),
+    (0x0400, 0x0401, NfkdQuickCheck::No),
+    (0x0403, 0x0403, NfkdQuickCheck::No),
+    (0x0407, 0x0407, NfkdQuickCheck::No),
+    (0x040C, 0x040E, NfkdQuickCheck::No),
+    (0x0419, 0x0419, NfkdQuickCheck::No),
+    (0x0439, 0x0439, NfkdQuickCheck::No),
+    (0x0450, 0x0451, NfkdQuickCheck::No),
+    (0x0453, 0x0453, NfkdQuickCheck::No),
+    (0x0457, 0x0457, NfkdQuickCheck::No),
+    (0x045C, 0x045E, NfkdQuickCheck::No),
+    (0x0476, 0x0477, NfkdQuickCheck::No),
+    (0x04C1, 0x04C2, NfkdQuickCheck::No),
+    (0x04D0, 0x04D3, NfkdQuickCheck::No),
+    (0x04D6, 0x04D7, NfkdQuickCheck::No),
+    (0x04DA, 0x04DF, NfkdQuickCheck::No),
+    (0x04E2, 0x04E7, NfkdQuickCheck::No),
+    (0x04EA, 0x04F5, NfkdQuickCheck::No),
+    (0x04F8, 0x04F9, NfkdQuickCheck::No),
+    (0x0587, 0x0587, NfkdQuickCheck::No),
+    (0x0622, 0x0626, NfkdQuickCheck::No),
+    (0x0675, 0x0678, NfkdQuickCheck::No),
+    (0x06C0, 0x06C0, NfkdQuickCheck::No),
+    (0x06C2, 0x06C2, NfkdQuickCheck::No),
+    (0x06D3, 0x06D3, NfkdQuickCheck::No),
+    (0x0929, 0x0929, NfkdQuickCheck::No),
+    (0x0931, 0x0931, NfkdQuickCheck::No),
+    (0x0934, 0x0934, NfkdQuickCheck::No),
+    (0x0958, 0x095F, NfkdQuickCheck::No),
+    (0x09CB, 0x09CC, NfkdQuickCheck::No),
+    (0x09DC, 0x09DD, NfkdQuickCheck::No),
+    (0x09DF, 0x09DF, NfkdQuickCheck::No),
+    (0x0A33, 0x0A33, NfkdQuickCheck::No),
+    (0x0A36, 0x0A36, NfkdQuickCheck::No),
+    (0x0A59, 0x0A5B, NfkdQuickCheck::No),
+    (0x0A5E, 0x0A5E, NfkdQuickCheck::No),
+    (0x0B48, 0x0B48, NfkdQuickCheck::No),
+    (0x0B4B, 0x0B4C, NfkdQuickCheck::No),
+    (0x0B5C, 0x0B5D, NfkdQuickCheck::No),
+    (0x0B94, 0x0B94, NfkdQuickCheck::No),
+    (0x0BCA, 0x0BCC, NfkdQuickCheck::No),
+    (0x0C48, 0x0C48, NfkdQuickCheck::No),
+    (0x0CC0, 0x0CC0, NfkdQuickCheck::No),
+    (0x0CC7, 0x0CC8, NfkdQuickCheck::No),
+    (0x0CCA, 0x0CCB, NfkdQuickCheck::No),
+    (0x0D4A, 0x0D4C, NfkdQuickCheck::No),
+    (0x0DDA, 0x0DDA, NfkdQuickCheck::No),
+    (0x0DDC, 0x0DDE, NfkdQuickCheck::No),
+    (0x0E33, 0x0E33, NfkdQuickCheck::No),
+    (0x0EB3, 0x0EB3, NfkdQuickCheck::No),
+    (0x0EDC, 0x0EDD, NfkdQuickCheck::No),
+    (0x0F0C, 0x0F0C, NfkdQuickCheck::No),
+    (0x0F43, 0x0F43, NfkdQuickCheck::No),
+    (0x0F4D, 0x0F4D, NfkdQuickCheck::No),
+    (0x0F52, 0x0F52, NfkdQuickCheck::No),
+    (0x0F57, 0x0F57, NfkdQuickCheck::No),
+    (0x0F5C, 0x0F5C, NfkdQuickCheck::No),
+    (0x0F69, 0x0F69, NfkdQuickCheck::No),
+    (0x0F73, 0x0F73, NfkdQuickCheck::No),
+    (0x0F75, 0x0F79, NfkdQuickCheck::No),
+    (0x0F81, 0x0F81, NfkdQuickCheck::No),
+    (0x0F93, 0x0F93, NfkdQuickCheck::No),
+    (0x0F9D, 0x0F9D, NfkdQuickCheck::No),
+    (0x0FA2, 0x0FA2, NfkdQuickCheck::No),
+    (0x0FA7, 0x0FA7, NfkdQuickCheck::No),
+    (0x0FAC, 0x0FAC, NfkdQuickCheck::No),
+    (0x0FB9, 0x0FB9, NfkdQuickCheck::No),
+    (0x1026, 0x1026, NfkdQuickCheck::No),
+    (0x10FC, 0x10FC, NfkdQuickCheck::No),
+    (0x1B06, 0x1B06, NfkdQuickCheck::No),
+    (0x1B08, 0x1B08, NfkdQuickCheck::No),
+    (0x1B0A, 0x1B0A, NfkdQuickCheck::No),
+    (0x1B0C, 0x1B0C, NfkdQuickCheck::No),
+    (0x1B0E, 0x1B0E, NfkdQuickCheck::No),
+    (0x1B12, 0x1B12, NfkdQuickCheck::No),
+    (0x1B3B, 0x1B3B, NfkdQuickCheck::No),
+    (0x1B3D, 0x1B3D, NfkdQuickCheck::No),
+    (0x1B40, 0x1B41, NfkdQuickCheck::No),
+    (0x1B43, 0x1B43, NfkdQuickCheck::No),
+    (0x1D2C, 0x1D2E, NfkdQuickCheck::No),
+    (0x1D30, 0x1D3A, NfkdQuickCheck::No),
+    (0x1D3C, 0x1D4D, NfkdQuickCheck::No),
+    (0x1D4F, 0x1D6A, NfkdQuickCheck::No),
+    (0x1D78, 0x1D78, NfkdQuickCheck::No),
+    (0x1D9B, 0x1DBF, NfkdQuickCheck::No),
+    (0x1E00, 0x1E9B, NfkdQuickCheck::No),
+    (0x1EA0, 0x1EF9, NfkdQuickCheck::No),
+    (0x1F00, 0x1F15, NfkdQuickCheck::No),
+    (0x1F18, 0x1F1D, NfkdQuickCheck::No),
+    (0x1F20, 0x1F45, NfkdQuickCheck::No),
+    (0x1F48, 0x1F4D, NfkdQuickCheck::No),
+    (0x1F50, 0x1F57, NfkdQuickCheck::No),
+    (0x1F59, 0x1F59, NfkdQuickCheck::No),
+    (0x1F5B, 0x1F5B, NfkdQuickCheck::No),
+    (0x1F5D, 0x1F5D, NfkdQuickCheck::No),
+    (0x1F5F, 0x1F7D, NfkdQuickCheck::No),
+    (0x1F80, 0x1FB4, NfkdQuickCheck::No),
+    (0x1FB6, 0x1FC4, NfkdQuickCheck::No),
+    (0x1FC6, 0x1FD3, NfkdQuickCheck::No),
+    (0x1FD6, 0x1FDB, NfkdQuickCheck::No),
+    (0x1FDD, 0x1FEF, NfkdQuickCheck::No),
+    (0x1FF2, 0x1FF4, NfkdQuickCheck::No),
+    (0x1FF6, 0x1FFE, NfkdQuickCheck::No),
+    (0x2000, 0x200A, NfkdQuickCheck::No),
+    (0x2011, 0x2011, NfkdQuickCheck::No),
+    (0x2017, 0x2017, NfkdQuickCheck::No),
+    (0x2024, 0x2026, NfkdQuickCheck::No),
+    (0x202F, 0x202F, NfkdQuickCheck::No),
+    (0x2033, 0x2034, NfkdQuickCheck::No),
+    (0x2036, 0x2037, NfkdQuickCheck::No),
+    (0x203C, 0x203C, NfkdQuickCheck::No),
+    (0x203E, 0x203E, NfkdQuickCheck::No),
+    (0x2047, 0x2049, NfkdQuickCheck::No),
+    (0x2057, 0x2057, NfkdQuickCheck::No),
+    (0x205F, 0x205F, NfkdQuickCheck::No),
+    (0x2070, 0x2071, NfkdQuickCheck::No),
+    (0x2074, 0x208E, NfkdQuickCheck::No),
+    (0x2090, 0x209C, NfkdQuickCheck::No),
+    (0x20A8, 0x20A8, NfkdQuickCheck::No),
+    (0x2100, 0x2103, NfkdQuickCheck::No),
+    (0x2105, 0x2107, NfkdQuickCheck::No),
+    (0x2109, 0x2113, NfkdQuickCheck::No),
+    (0x2115, 0x2116, NfkdQuickCheck::No),
+    (0x2119, 0x211D, NfkdQuickCheck::No),
+    (0x2120, 0x2122, NfkdQuickCheck::No),
+    (0x2124, 0x2124, NfkdQuickCheck::No),
+    (0x2126, 0x2126, NfkdQuickCheck::No),
+    (0x2128, 0x2128, NfkdQuickCheck::No),
+    (0x212A, 0x212D, NfkdQuickCheck::No),
+    (0x212F, 0x2131, NfkdQuickCheck::No),
+    (0x2133, 0x2139, NfkdQuickCheck::No),
+    (0x213B, 0x2140, NfkdQuickCheck::No),
+    (0x2145, 0x2149, NfkdQuickCheck::No),
+    (0x2150, 0x217F, NfkdQuickCheck::No),
+    (0x2189, 0x2189, NfkdQuickCheck::No),
+    (0x219A, 0x219B, NfkdQuickCheck::No),
+    (0x21AE, 0x21AE, NfkdQuickCheck::No),
+    (0x21CD, 0x21CF, NfkdQuickCheck::No),
+    (0x2204, 0x2204, NfkdQuickCheck::No),
+    (0x2209, 0x2209, NfkdQuickCheck::No),
+    (0x220C, 0x220C, NfkdQuickCheck::No),
+    (0x2224, 0x2224, NfkdQuickCheck::No),
+    (0x2226, 0x2226, NfkdQuickCheck::No),
+    (0x222C, 0x222D, NfkdQuickCheck::No),
+    (0x222F, 0x2230, NfkdQuickCheck::No),
+    (0x2241, 0x2241, NfkdQuickCheck::No),
+    (0x2244, 0x2244, NfkdQuickCheck::No),
+    (0x2247, 0x2247, NfkdQuickCheck::No),
+    (0x2249, 0x2249, NfkdQuickCheck::No),
+    (0x2260, 0x2260, NfkdQuickCheck::No),
+    (0x2262, 0x2262, NfkdQuickCheck::No),
+    (0x226D, 0x2271, NfkdQuickCheck::No),
+    (0x2274, 0x2275, NfkdQuickCheck::No),
+    (0x2278, 0x2279, NfkdQuickCheck::No),
+    (0x2280, 0x2281, NfkdQuickCheck::No),
+    (0x2284, 0x2285, NfkdQuickCheck::No),
+    (0x2288, 0x2289, NfkdQuickCheck::No),
+    (0x22AC, 0x22AF, NfkdQuickCheck::No),
+    (0x22E0, 0x22E3, NfkdQuickCheck::No),
+    (0x22EA, 0x22ED, NfkdQuickCheck::No),
+    (0x2329, 0x232A, NfkdQuickCheck::No),
+    (0x2460, 0x24EA, NfkdQuickCheck::No),
+    (0x2A0C, 0x2A0C, NfkdQuickCheck::No),
+    (0x2A74, 0x2A76, NfkdQuickCheck::No),
+    (0x2ADC, 0x2ADC, NfkdQuickCheck::No),
+    (0x2C7C, 0x2C7D, NfkdQuickCheck::No),
+    (0x2D6F, 0x2D6F, NfkdQuickCheck::No),
+    (0x2E9F, 0x2E9F, NfkdQuickCheck::No),
+    (0x2EF3, 0x2EF3, NfkdQuickCheck::No),
+    (0x2F00, 0x2FD5, NfkdQuickCheck::No),
+    (0x3000, 0x3000, NfkdQuickCheck::No),
+    (0x3036, 0x3036, NfkdQuickCheck::No),
+    (0x3038, 0x303A, NfkdQuickCheck::No),
+    (0x304C, 0x304C, NfkdQuickCheck::No),
+    (0x304E, 0x304E, NfkdQuickCheck::No),
+    (0x3050, 0x3050, NfkdQuickCheck::No),
+    (0x3052, 0x3052, NfkdQuickCheck::No),
+    (0x3054, 0x3054, NfkdQuickCheck::No),
+    (0x3056, 0x3056, NfkdQuickCheck::No),
+    (0x3058, 0x3058, NfkdQuickCheck::No),
+    (0x305A, 0x305A, NfkdQuickCheck::No),
+    (0x305C, 0x305C, NfkdQuickCheck::No),
+    (0x305E, 0x305E, NfkdQuickCheck::No),
+    (0x3060, 0x3060, NfkdQuickCheck::No),
+    (0x3062, 0x3062, NfkdQuickCheck::No),
+    (0x3065, 0x3065, NfkdQuickCheck::No),
+    (0x3067, 0x3067, NfkdQuickCheck::No),
+    (0x3069, 0x3069, NfkdQuickCheck::No),
+    (0x3070, 0x3071, NfkdQuickCheck::No),
+    (0x3073, 0x3074, NfkdQuickCheck::No),
+    (0x3076, 0x3077, NfkdQuickCheck::No),
+    (0x3079, 0x307A, NfkdQuickCheck::No),
+    (0x307C, 0x307D, NfkdQuickCheck::No),
+    (0x3094, 0x3094, NfkdQuickCheck::No),
+    (0x309B, 0x309C, NfkdQuickCheck::No),
+    (0x309E, 0x309F, NfkdQuickCheck::No),
+    (0x30AC, 0x30AC, NfkdQuickCheck::No),
+    (0x30AE, 0x30AE, NfkdQuickCheck::No),
+    (0x30B0, 0x30B0, NfkdQuickCheck::No),
+    (0x30B2, 0x30B2, NfkdQuickCheck::No),
+    (0x30B4, 0x30B4, NfkdQuickCheck::No),
+    (0x30B6, 0x30B6, NfkdQuickCheck::No),
+    (0x30B8, 0x30B8, NfkdQuickCheck::No),
+    (0x30BA, 0x30BA, NfkdQuickCheck::No),
+    (0x30BC, 0x30BC, NfkdQuickCheck::No),
+    (0x30BE, 0x30BE, NfkdQuickCheck::No),
+    (0x30C0, 0x30C0, NfkdQuickCheck::No),
+    (0x30C2, 0x30C2, NfkdQuickCheck::No),
+    (0x30C5, 0x30C5, NfkdQuickCheck::No),
+    (0x30C7, 0x30C7, NfkdQuickCheck::No),
+    (0x30C9, 0x30C9, NfkdQuickCheck::No),
+    (0x30D0, 0x30D1, NfkdQuickCheck::No),
+    (0x30D3, 0x30D4, NfkdQuickCheck::No),
+    (0x30D6, 0x30D7, NfkdQuickCheck::No),
+    (0x30D9, 0x30DA, NfkdQuickCheck::No),
+    (0x30DC, 0x30DD, NfkdQuickCheck::No),
+    (0x30F4, 0x30F4, NfkdQuickCheck::No),
+    (0x30F7, 0x30FA, NfkdQuickCheck::No),
+    (0x30FE, 0x30FF, NfkdQuickCheck::No),
+    (0x3131, 0x318E, NfkdQuickCheck::No),
+    (0x3192, 0x319F, NfkdQuickCheck::No),
+    (0x3200, 0x321E, NfkdQuickCheck::No),
+    (0x3220, 0x3247, NfkdQuickCheck::No),
+    (0x3250, 0x327E, NfkdQuickCheck::No),
+    (0x3280, 0x33FF, NfkdQuickCheck::No),
+    (0xA69C, 0xA69D, NfkdQuickCheck::No),
+    (0xA770, 0xA770, NfkdQuickCheck::No),
+    (0xA7F8, 0xA7F9, NfkdQuickCheck::No),
+    (0xAB5C, 0xAB5F, NfkdQuickCheck::No),
+    (0xAB69, 0xAB69, NfkdQuickCheck::No),
+    (0xAC00, 0xD7A3, NfkdQuickCheck::No),
+    (0xF900, 0xFA0D, NfkdQuickCheck::No),
+    (0xFA10, 0xFA10, NfkdQuickCheck::No),
+    (0xFA12, 0xFA12, NfkdQuickCheck::No),
+    (0xFA15, 0xFA1E, NfkdQuickCheck::No),
+    (0xFA20, 0xFA20, NfkdQuickCheck::No),
+    (0xFA22, 0xFA22, NfkdQuickCheck::No),
+    (0xFA25, 0xFA26, NfkdQuickCheck::No),
+    (0xFA2A, 0xFA6D, NfkdQuickCheck::No),
+    (0xFA70, 0xFAD9, NfkdQuickCheck::No),
+    (0xFB00, 0xFB06, NfkdQuickCheck::No),
+    (0xFB13, 0xFB17, NfkdQuickCheck::No),
+    (0xFB1D, 0xFB1D, NfkdQuickCheck::No),
+    (0xFB1F, 0xFB36, NfkdQuickCheck::No),
+    (0xFB38, 0xFB3C, NfkdQuickCheck::No),
+    (0xFB3E, 0xFB3E, NfkdQuickCheck::No),
+    (0xFB40, 0xFB41, NfkdQuickCheck::No),
+    (0xFB43, 0xFB44, NfkdQuickCheck::No),
+    (0xFB46, 0xFBB1, NfkdQuickCheck::No),
+    (0xFBD3, 0xFD3D, NfkdQuickCheck::No),
+    (0xFD50, 0xFD8F, NfkdQuickCheck::No),
+    (0xFD92, 0xFDC7, NfkdQuickCheck::No),
+    (0xFDF0, 0xFDFC, NfkdQuickCheck::No),
+    (0xFE10, 0xFE19, NfkdQuickCheck::No),
+    (0xFE30, 0xFE44, NfkdQuickCheck::No),
+    (0xFE47, 0xFE52, NfkdQuickCheck::No),
+    (0xFE54, 0xFE66, NfkdQuickCheck::No),
+    (0xFE68, 0xFE6B, NfkdQuickCheck::No),
+    (0xFE70, 0xFE72, NfkdQuickCheck::No),
+    (0xFE74, 0xFE74, NfkdQuickCheck::No),
+    (0xFE76, 0xFEFC, NfkdQuickCheck::No),
+    (0xFF01, 0xFFBE, NfkdQuickCheck::No),
+    (0xFFC2, 0xFFC7, NfkdQuickCheck::No),
+    (0xFFCA, 0xFFCF, NfkdQuickCheck::No),
+    (0xFFD2, 0xFFD7, NfkdQuickCheck::No),
+    (0xFFDA, 0xFFDC, NfkdQuickCheck::No),
+    (0xFFE0, 0xFFE6, NfkdQuickCheck::No),
+    (0xFFE8, 0xFFEE, NfkdQuickCheck::No),
+    (0x1109A, 0x1109A, NfkdQuickCheck::No),
+    (0x1109C, 0x1109C, NfkdQuickCheck::No),
+    (0x110AB, 0x110AB, NfkdQuickCheck::No),
+    (0x1112E, 0x1112F, NfkdQuickCheck::No),
+    (0x1134B, 0x1134C, NfkdQuickCheck::No),
+    (0x114BB, 0x114BC, NfkdQuickCheck::No),
+    (0x114BE, 0x114BE, NfkdQuickCheck::No),
+    (0x115BA, 0x115BB, NfkdQuickCheck::No),
+    (0x11938, 0x11938, NfkdQuickCheck::No),
+    (0x1D15E, 0x1D164, NfkdQuickCheck::No),
+    (0x1D1BB, 0x1D1C0, NfkdQuickCheck::No),
+    (0x1D400, 0x1D454, NfkdQuickCheck::No),
+    (0x1D456, 0x1D49C, NfkdQuickCheck::No),
+    (0x1D49E, 0x1D49F, NfkdQuickCheck::No),
+    (0x1D4A2, 0x1D4A2, NfkdQuickCheck::No),
+    (0x1D4A5, 0x1D4A6, NfkdQuickCheck::No),
+    (0x1D4A9, 0x1D4AC, NfkdQuickCheck::No),
+    (0x1D4AE, 0x1D4B9, NfkdQuickCheck::No),
+    (0x1D4BB, 0x1D4BB, NfkdQuickCheck::No),
+    (0x1D4BD, 0x1D4C3, NfkdQuickCheck::No),
+    (0x1D4C5, 0x1D505, NfkdQuickCheck::No),
+    (0x1D507, 0x1D50A, NfkdQuickCheck::No),
+    (0x1D50D, 0x1D514, NfkdQuickCheck::No),
+    (0x1D516, 0x1D51C, NfkdQuickCheck::No),
+    (0x1D51E, 0x1D539, NfkdQuickCheck::No),
+    (0x1D53B, 0x1D53E, NfkdQuickCheck::No),
+    (0x1D540, 0x1D544, NfkdQuickCheck::No),
+    (0x1D546, 0x1D546, NfkdQuickCheck::No),
+    (0x1D54A, 0x1D550, NfkdQuickCheck::No),
+    (0x1D552, 0x1D6A5, NfkdQuickCheck::No),
+    (0x1D6A8, 0x1D7CB, NfkdQuickCheck::No),
+    (0x1D7CE, 0x1D7FF, NfkdQuickCheck::No),
+    (0x1EE00, 0x1EE03, NfkdQuickCheck::No),
+    (0x1EE05, 0x1EE1F, NfkdQuickCheck::No),
+    (0x1EE21, 0x1EE22, NfkdQuickCheck::No),
+    (0x1EE24, 0x1EE24, NfkdQuickCheck::No),
+    (0x1EE27, 0x1EE27, NfkdQuickCheck::No),
+    (0x1EE29, 0x1EE32, NfkdQuickCheck::No),
+    (0x1EE34, 0x1EE37, NfkdQuickCheck::No),
+    (0x1EE39, 0x1EE39, NfkdQuickCheck::No),
+    (0x1EE3B, 0x1EE3B, NfkdQuickCheck::No),
+    (0x1EE42, 0x1EE42, NfkdQuickCheck::No),
+    (0x1EE47, 0x1EE47, NfkdQuickCheck::No),
+    (0x1EE49, 0x1EE49, NfkdQuickCheck::No),
+    (0x1EE4B, 0x1EE4B, NfkdQuickCheck::No),
+    (0x1EE4D, 0x1EE4F, NfkdQuickCheck::No),
+    (0x1EE51, 0x1EE52, NfkdQuickCheck::No),
+    (0x1EE54, 0x1EE54, NfkdQuickCheck::No),
+    (0x1EE57, 0x1EE57, NfkdQuickCheck::No),
+    (0x1EE59, 0x1EE59, NfkdQuickCheck::No),
+    (0x1EE5B, 0x1EE5B, NfkdQuickCheck::No),
+    (0x1EE5D, 0x1EE5D, NfkdQuickCheck::No),
+    (0x1EE5F, 0x1EE5F, NfkdQuickCheck::No),
+    (0x1EE61, 0x1EE62, NfkdQuickCheck::No),
+    (0x1EE64, 0x1EE64, NfkdQuickCheck::No),
+    (0x1EE67, 0x1EE6A, NfkdQuickCheck::No),
+    (0x1EE6C, 0x1EE72, NfkdQuickCheck::No),
+    (0x1EE74, 0x1EE77, NfkdQuickCheck::No),
+    (0x1EE79, 0x1EE7C, NfkdQuickCheck::No),
+    (0x1EE7E, 0x1EE7E, NfkdQuickCheck::No),
+    (0x1EE80, 0x1EE89, NfkdQuickCheck::No),
+    (0x1EE8B, 0x1EE9B, NfkdQuickCheck::No),
+    (0x1EEA1, 0x1EEA3, NfkdQuickCheck::No),
+    (0x1EEA5, 0x1EEA9, NfkdQuickCheck::No),
+    (0x1EEAB, 0x1EEBB, NfkdQuickCheck::No),
+    (0x1F100, 0x1F10A, NfkdQuickCheck::No),
+    (0x1F110, 0x1F12E, NfkdQuickCheck::No),
+    (0x1F130, 0x1F14F, NfkdQuickCheck::No),
+    (0x1F16A, 0x1F16C, NfkdQuickCheck::No),
+    (0x1F190, 0x1F190, NfkdQuickCheck::No),
+    (0x1F200, 0x1F202, NfkdQuickCheck::No),
+    (0x1F210, 0x1F23B, NfkdQuickCheck::No),
+    (0x1F240, 0x1F248, NfkdQuickCheck::No),
+    (0x1F250, 0x1F251, NfkdQuickCheck::No),
+    (0x1FBF0, 0x1FBF9, NfkdQuickCheck::No),
+    (0x2F800, 0x2FA1D, NfkdQuickCheck::No),
+];
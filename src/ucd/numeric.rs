@@ -0,0 +1,112 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! The Numeric_Type and Numeric_Value properties, which are not yet provided by `unic::ucd`.
+//!
+
+mod tables;
+
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+use super::find_range;
+
+enumerated_property! {
+    /// Values of the Numeric_Type property, e.g. `Decimal` for the digits of decimal numbers in
+    /// any script and `Numeric` for fractions and Han numerals.
+    pub enum NumericType {
+        abbr => "nt",
+        long => "Numeric_Type",
+        human => "Numeric Type",
+        default => None,
+        values => {
+            Decimal,
+            Digit,
+            None,
+            Numeric,
+        }
+    }
+}
+
+/// The number a character represents, which is a fraction for characters like U+00BD VULGAR
+/// FRACTION ONE HALF.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct NumericValue {
+    pub numerator: i64,
+    pub denominator: u64,
+}
+
+impl NumericValue {
+    pub fn of(chr: char) -> Option<Self> {
+        find_range(tables::NUMERIC_VALUE_RANGES, chr).map(|(_, _, (numerator, denominator))| {
+            NumericValue {
+                numerator: *numerator,
+                denominator: *denominator,
+            }
+        })
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denominator == 1
+    }
+}
+
+impl fmt::Display for NumericValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// Numeric values are serialized the way the Unicode Character Database writes them, e.g. "1/2",
+/// so that fractions are exact.
+impl Serialize for NumericValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unic::char::property::EnumeratedCharProperty;
+
+    #[test]
+    fn test_numeric_type() {
+        assert_eq!(NumericType::of('7'), NumericType::Decimal);
+        assert_eq!(NumericType::of('\u{0667}').abbr_name(), "De");
+        assert_eq!(NumericType::of('\u{00B2}'), NumericType::Digit);
+        assert_eq!(NumericType::of('\u{00BD}'), NumericType::Numeric);
+        assert_eq!(NumericType::of('\u{4E5D}'), NumericType::Numeric);
+        assert_eq!(NumericType::of('a'), NumericType::None);
+    }
+
+    #[test]
+    fn test_numeric_value() {
+        let value = |chr| NumericValue::of(chr).map(|value| value.to_string());
+        assert_eq!(value('7'), Some("7".to_owned()));
+        assert_eq!(value('\u{0667}'), Some("7".to_owned()));
+        assert_eq!(value('\u{00BD}'), Some("1/2".to_owned()));
+        assert_eq!(value('\u{0F33}'), Some("-1/2".to_owned()));
+        assert_eq!(value('\u{216F}'), Some("1000".to_owned()));
+        assert_eq!(value('\u{4E5D}'), Some("9".to_owned()));
+        assert_eq!(value('\u{5146}'), Some("1000000000000".to_owned()));
+        assert_eq!(value('a'), None);
+    }
+}
@@ -0,0 +1,2090 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (DerivedNumericType.txt, DerivedNumericValues.txt
+// and PropertyValueAliases.txt), limited to the characters assigned in Unicode 13.0.
+
+use super::NumericType;
+
+pub(super) const VALUES: &[NumericType] = &[
+    NumericType::Decimal,
+    NumericType::Digit,
+    NumericType::None,
+    NumericType::Numeric,
+];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[
+    ("De", "Decimal", "Decimal"),
+    ("Di", "Digit", "Digit"),
+    ("None", "None", "None"),
+    ("Nu", "Numeric", "Numeric"),
+];
+
+pub(super) const RANGES: &[(u32, u32, NumericType)] = &[
+    (0x0030, 0x0039, NumericType::Decimal),
+    (0x00B2, 0x00B3, NumericType::Digit),
+    (0x00B9, 0x00B9, NumericType::Digit),
+    (0x00BC, 0x00BE, NumericType::Numeric),
+    (0x0660, 0x0669, NumericType::Decimal),
+    (0x06F0, 0x06F9, NumericType::Decimal),
+    (0x07C0, 0x07C9, NumericType::Decimal),
+    (0x0966, 0x096F, NumericType::Decimal),
+    (0x09E6, 0x09EF, NumericType::Decimal),
+    (0x09F4, 0x09F9, NumericType::Numeric),
+    (0x0A66, 0x0A6F, NumericType::Decimal),
+    (0x0AE6, 0x0AEF, NumericType::Decimal),
+    (0x0B66, 0x0B6F, NumericType::Decimal),
+    (0x0B72, 0x0B77, NumericType::Numeric),
+    (0x0BE6, 0x0BEF, NumericType::Decimal),
+    (0x0BF0, 0x0BF2, NumericType::Numeric),
+    (0x0C66, 0x0C6F, NumericType::Decimal),
+    (0x0C78, 0x0C7E, NumericType::Numeric),
+    (0x0CE6, 0x0CEF, NumericType::Decimal),
+    (0x0D58, 0x0D5E, NumericType::Numeric),
+    (0x0D66, 0x0D6F, NumericType::Decimal),
+    (0x0D70, 0x0D78, NumericType::Numeric),
+    (0x0DE6, 0x0DEF, NumericType::Decimal),
+    (0x0E50, 0x0E59, NumericType::Decimal),
+    (0x0ED0, 0x0ED9, NumericType::Decimal),
+    (0x0F20, 0x0F29, NumericType::Decimal),
+    (0x0F2A, 0x0F33, NumericType::Numeric),
+    (0x1040, 0x1049, NumericType::Decimal),
+    (0x1090, 0x1099, NumericType::Decimal),
+    (0x1369, 0x1371, NumericType::Digit),
+    (0x1372, 0x137C, NumericType::Numeric),
+    (0x16EE, 0x16F0, NumericType::Numeric),
+    (0x17E0, 0x17E9, NumericType::Decimal),
+    (0x17F0, 0x17F9, NumericType::Numeric),
+    (0x1810, 0x1819, NumericType::Decimal),
+    (0x1946, 0x194F, NumericType::Decimal),
+    (0x19D0, 0x19D9, NumericType::Decimal),
+    (0x19DA, 0x19DA, NumericType::Digit),
+    (0x1A80, 0x1A89, NumericType::Decimal),
+    (0x1A90, 0x1A99, NumericType::Decimal),
+    (0x1B50, 0x1B59, NumericType::Decimal),
+    (0x1BB0, 0x1BB9, NumericType::Decimal),
+    (0x1C40, 0x1C49, NumericType::Decimal),
+    (0x1C50, 0x1C59, NumericType::Decimal),
+    (0x2070, 0x2070, NumericType::Digit),
+    (0x2074, 0x2079, NumericType::Digit),
+    (0x2080, 0x2089, NumericType::Digit),
+    (0x2150, 0x2182, NumericType::Numeric),
+    (0x2185, 0x2189, NumericType::Numeric),
+    (0x2460, 0x2468, NumericType::Digit),
+    (0x2469, 0x2473, NumericType::Numeric),
+    (0x2474, 0x247C, NumericType::Digit),
+    (0x247D, 0x2487, NumericType::Numeric),
+    (0x2488, 0x2490, NumericType::Digit),
+    (0x2491, 0x249B, NumericType::Numeric),
+    (0x24EA, 0x24EA, NumericType::Digit),
+    (0x24EB, 0x24F4, NumericType::Numeric),
+    (0x24F5, 0x24FD, NumericType::Digit),
+    (0x24FE, 0x24FE, NumericType::Numeric),
+    (0x24FF, 0x24FF, NumericType::Digit),
+    (0x2776, 0x277E, NumericType::Digit),
+    (0x277F, 0x277F, NumericType::Numeric),
+    (0x2780, 0x2788, NumericType::Digit),
+    (0x2789, 0x2789, NumericType::Numeric),
+    (0x278A, 0x2792, NumericType::Digit),
+    (0x2793, 0x2793, NumericType::Numeric),
+    (0x2CFD, 0x2CFD, NumericType::Numeric),
+    (0x3007, 0x3007, NumericType::Numeric),
+    (0x3021, 0x3029, NumericType::Numeric),
+    (0x3038, 0x303A, NumericType::Numeric),
+    (0x3192, 0x3195, NumericType::Numeric),
+    (0x3220, 0x3229, NumericType::Numeric),
+    (0x3248, 0x324F, NumericType::Numeric),
+    (0x3251, 0x325F, NumericType::Numeric),
+    (0x3280, 0x3289, NumericType::Numeric),
+    (0x32B1, 0x32BF, NumericType::Numeric),
+    (0x3405, 0x3405, NumericType::Numeric),
+    (0x3483, 0x3483, NumericType::Numeric),
+    (0x382A, 0x382A, NumericType::Numeric),
+    (0x3B4D, 0x3B4D, NumericType::Numeric),
+    (0x4E00, 0x4E00, NumericType::Numeric),
+    (0x4E03, 0x4E03, NumericType::Numeric),
+    (0x4E07, 0x4E07, NumericType::Numeric),
+    (0x4E09, 0x4E09, NumericType::Numeric),
+    (0x4E5D, 0x4E5D, NumericType::Numeric),
+    (0x4E8C, 0x4E8C, NumericType::Numeric),
+    (0x4E94, 0x4E94, NumericType::Numeric),
+    (0x4E96, 0x4E96, NumericType::Numeric),
+    (0x4EBF, 0x4EC0, NumericType::Numeric),
+    (0x4EDF, 0x4EDF, NumericType::Numeric),
+    (0x4EE8, 0x4EE8, NumericType::Numeric),
+    (0x4F0D, 0x4F0D, NumericType::Numeric),
+    (0x4F70, 0x4F70, NumericType::Numeric),
+    (0x5104, 0x5104, NumericType::Numeric),
+    (0x5146, 0x5146, NumericType::Numeric),
+    (0x5169, 0x5169, NumericType::Numeric),
+    (0x516B, 0x516B, NumericType::Numeric),
+    (0x516D, 0x516D, NumericType::Numeric),
+    (0x5341, 0x5341, NumericType::Numeric),
+    (0x5343, 0x5345, NumericType::Numeric),
+    (0x534C, 0x534C, NumericType::Numeric),
+    (0x53C1, 0x53C4, NumericType::Numeric),
+    (0x56DB, 0x56DB, NumericType::Numeric),
+    (0x58F1, 0x58F1, NumericType::Numeric),
+    (0x58F9, 0x58F9, NumericType::Numeric),
+    (0x5E7A, 0x5E7A, NumericType::Numeric),
+    (0x5EFE, 0x5EFF, NumericType::Numeric),
+    (0x5F0C, 0x5F0E, NumericType::Numeric),
+    (0x5F10, 0x5F10, NumericType::Numeric),
+    (0x62FE, 0x62FE, NumericType::Numeric),
+    (0x634C, 0x634C, NumericType::Numeric),
+    (0x67D2, 0x67D2, NumericType::Numeric),
+    (0x6F06, 0x6F06, NumericType::Numeric),
+    (0x7396, 0x7396, NumericType::Numeric),
+    (0x767E, 0x767E, NumericType::Numeric),
+    (0x8086, 0x8086, NumericType::Numeric),
+    (0x842C, 0x842C, NumericType::Numeric),
+    (0x8CAE, 0x8CAE, NumericType::Numeric),
+    (0x8CB3, 0x8CB3, NumericType::Numeric),
+    (0x8D30, 0x8D30, NumericType::Numeric),
+    (0x9621, 0x9621, NumericType::Numeric),
+    (0x9646, 0x9646, NumericType::Numeric),
+    (0x964C, 0x964C, NumericType::Numeric),
+    (0x9678, 0x9678, NumericType::Numeric),
+    (0x96F6, 0x96F6, NumericType::Numeric),
+    (0xA620, 0xA629, NumericType::Decimal),
+    (0xA6E6, 0xA6EF, NumericType::Numeric),
+    (0xA830, 0xA835, NumericType::Numeric),
+    (0xA8D0, 0xA8D9, NumericType::Decimal),
+    (0xA900, 0xA909, NumericType::Decimal),
+    (0xA9D0, 0xA9D9, NumericType::Decimal),
+    (0xA9F0, 0xA9F9, NumericType::Decimal),
+    (0xAA50, 0xAA59, NumericType::Decimal),
+    (0xABF0, 0xABF9, NumericType::Decimal),
+    (0xF96B, 0xF96B, NumericType::Numeric),
+    (0xF973, 0xF973, NumericType::Numeric),
+    (0xF978, 0xF978, NumericType::Numeric),
+    (0xF9B2, 0xF9B2, NumericType::Numeric),
+    (0xF9D1, 0xF9D1, NumericType::Numeric),
+    (0xF9D3, 0xF9D3, NumericType::Numeric),
+    (0xF9FD, 0xF9FD, NumericType::Numeric),
+    (0xFF10, 0xFF19, NumericType::Decimal),
+    (0x10107, 0x10133, NumericType::Numeric),
+    (0x10140, 0x10178, NumericType::Numeric),
+    (0x1018A, 0x1018B, NumericType::Numeric),
+    (0x102E1, 0x102FB, NumericType::Numeric),
+    (0x10320, 0x10323, NumericType::Numeric),
+    (0x10341, 0x10341, NumericType::Numeric),
+    (0x1034A, 0x1034A, NumericType::Numeric),
+    (0x103D1, 0x103D5, NumericType::Numeric),
+    (0x104A0, 0x104A9, NumericType::Decimal),
+    (0x10858, 0x1085F, NumericType::Numeric),
+    (0x10879, 0x1087F, NumericType::Numeric),
+    (0x108A7, 0x108AF, NumericType::Numeric),
+    (0x108FB, 0x108FF, NumericType::Numeric),
+    (0x10916, 0x1091B, NumericType::Numeric),
+    (0x109BC, 0x109BD, NumericType::Numeric),
+    (0x109C0, 0x109CF, NumericType::Numeric),
+    (0x109D2, 0x109FF, NumericType::Numeric),
+    (0x10A40, 0x10A43, NumericType::Digit),
+    (0x10A44, 0x10A48, NumericType::Numeric),
+    (0x10A7D, 0x10A7E, NumericType::Numeric),
+    (0x10A9D, 0x10A9F, NumericType::Numeric),
+    (0x10AEB, 0x10AEF, NumericType::Numeric),
+    (0x10B58, 0x10B5F, NumericType::Numeric),
+    (0x10B78, 0x10B7F, NumericType::Numeric),
+    (0x10BA9, 0x10BAF, NumericType::Numeric),
+    (0x10CFA, 0x10CFF, NumericType::Numeric),
+    (0x10D30, 0x10D39, NumericType::Decimal),
+    (0x10E60, 0x10E68, NumericType::Digit),
+    (0x10E69, 0x10E7E, NumericType::Numeric),
+    (0x10F1D, 0x10F26, NumericType::Numeric),
+    (0x10F51, 0x10F54, NumericType::Numeric),
+    (0x10FC5, 0x10FCB, NumericType::Numeric),
+    (0x11052, 0x1105A, NumericType::Digit),
+    (0x1105B, 0x11065, NumericType::Numeric),
+    (0x11066, 0x1106F, NumericType::Decimal),
+    (0x110F0, 0x110F9, NumericType::Decimal),
+    (0x11136, 0x1113F, NumericType::Decimal),
+    (0x111D0, 0x111D9, NumericType::Decimal),
+    (0x111E1, 0x111F4, NumericType::Numeric),
+    (0x112F0, 0x112F9, NumericType::Decimal),
+    (0x11450, 0x11459, NumericType::Decimal),
+    (0x114D0, 0x114D9, NumericType::Decimal),
+    (0x11650, 0x11659, NumericType::Decimal),
+    (0x116C0, 0x116C9, NumericType::Decimal),
+    (0x11730, 0x11739, NumericType::Decimal),
+    (0x1173A, 0x1173B, NumericType::Numeric),
+    (0x118E0, 0x118E9, NumericType::Decimal),
+    (0x118EA, 0x118F2, NumericType::Numeric),
+    (0x11950, 0x11959, NumericType::Decimal),
+    (0x11C50, 0x11C59, NumericType::Decimal),
+    (0x11C5A, 0x11C6C, NumericType::Numeric),
+    (0x11D50, 0x11D59, NumericType::Decimal),
+    (0x11DA0, 0x11DA9, NumericType::Decimal),
+    (0x11FC0, 0x11FD4, NumericType::Numeric),
+    (0x12400, 0x1246E, NumericType::Numeric),
+    (0x16A60, 0x16A69, NumericType::Decimal),
+    (0x16B50, 0x16B59, NumericType::Decimal),
+    (0x16B5B, 0x16B61, NumericType::Numeric),
+    (0x16E80, 0x16E96, NumericType::Numeric),
+    (0x1D2E0, 0x1D2F3, NumericType::Numeric),
+    (0x1D360, 0x1D378, NumericType::Numeric),
+    (0x1D7CE, 0x1D7FF, NumericType::Decimal),
+    (0x1E140, 0x1E149, NumericType::Decimal),
+    (0x1E2F0, 0x1E2F9, NumericType::Decimal),
+    (0x1E8C7, 0x1E8CF, NumericType::Numeric),
+    (0x1E950, 0x1E959, NumericType::Decimal),
+    (0x1EC71, 0x1ECAB, NumericType::Numeric),
+    (0x1ECAD, 0x1ECAF, NumericType::Numeric),
+    (0x1ECB1, 0x1ECB4, NumericType::Numeric),
+    (0x1ED01, 0x1ED2D, NumericType::Numeric),
+    (0x1ED2F, 0x1ED3D, NumericType::Numeric),
+    (0x1F100, 0x1F10A, NumericType::Digit),
+    (0x1F10B, 0x1F10C, NumericType::Numeric),
+    (0x1FBF0, 0x1FBF9, NumericType::Decimal),
+    (0x20001, 0x20001, NumericType::Numeric),
+    (0x20064, 0x20064, NumericType::Numeric),
+    (0x200E2, 0x200E2, NumericType::Numeric),
+    (0x20121, 0x20121, NumericType::Numeric),
+    (0x2092A, 0x2092A, NumericType::Numeric),
+    (0x20983, 0x20983, NumericType::Numeric),
+    (0x2098C, 0x2098C, NumericType::Numeric),
+    (0x2099C, 0x2099C, NumericType::Numeric),
+    (0x20AEA, 0x20AEA, NumericType::Numeric),
+    (0x20AFD, 0x20AFD, NumericType::Numeric),
+    (0x20B19, 0x20B19, NumericType::Numeric),
+    (0x22390, 0x22390, NumericType::Numeric),
+    (0x22998, 0x22998, NumericType::Numeric),
+    (0x23B1B, 0x23B1B, NumericType::Numeric),
+    (0x2626D, 0x2626D, NumericType::Numeric),
+    (0x2F890, 0x2F890, NumericType::Numeric),
+];
+
+pub(super) const NUMERIC_VALUE_RANGES: &[(u32, u32, (i64, u64))] = &[
+    (0x0030, 0x0030, (0, 1)),
+    (0x0031, 0x0031, (1, 1)),
+    (0x0032, 0x0032, (2, 1)),
+    (0x0033, 0x0033, (3, 1)),
+    (0x0034, 0x0034, (4, 1)),
+    (0x0035, 0x0035, (5, 1)),
+    (0x0036, 0x0036, (6, 1)),
+    (0x0037, 0x0037, (7, 1)),
+    (0x0038, 0x0038, (8, 1)),
+    (0x0039, 0x0039, (9, 1)),
+    (0x00B2, 0x00B2, (2, 1)),
+    (0x00B3, 0x00B3, (3, 1)),
+    (0x00B9, 0x00B9, (1, 1)),
+    (0x00BC, 0x00BC, (1, 4)),
+    (0x00BD, 0x00BD, (1, 2)),
+    (0x00BE, 0x00BE, (3, 4)),
+    (0x0660, 0x0660, (0, 1)),
+    (0x0661, 0x0661, (1, 1)),
+    (0x0662, 0x0662, (2, 1)),
+    (0x0663, 0x0663, (3, 1)),
+    (0x0664, 0x0664, (4, 1)),
+    (0x0665, 0x0665, (5, 1)),
+    (0x0666, 0x0666, (6, 1)),
+    (0x0667, 0x0667, (7, 1)),
+    (0x0668, 0x0668, (8, 1)),
+    (0x0669, 0x0669, (9, 1)),
+    (0x06F0, 0x06F0, (0, 1)),
+    (0x06F1, 0x06F1, (1, 1)),
+    (0x06F2, 0x06F2, (2, 1)),
+    (0x06F3, 0x06F3, (3, 1)),
+    (0x06F4, 0x06F4, (4, 1)),
+    (0x06F5, 0x06F5, (5, 1)),
+    (0x06F6, 0x06F6, (6, 1)),
+    (0x06F7, 0x06F7, (7, 1)),
+    (0x06F8, 0x06F8, (8, 1)),
+    (0x06F9, 0x06F9, (9, 1)),
+    (0x07C0, 0x07C0, (0, 1)),
+    (0x07C1, 0x07C1, (1, 1)),
+    (0x07C2, 0x07C2, (2, 1)),
+    (0x07C3, 0x07C3, (3, 1)),
+    (0x07C4, 0x07C4, (4, 1)),
+    (0x07C5, 0x07C5, (5, 1)),
+    (0x07C6, 0x07C6, (6, 1)),
+    (0x07C7, 0x07C7, (7, 1)),
+    (0x07C8, 0x07C8, (8, 1)),
+    (0x07C9, 0x07C9, (9, 1)),
+    (0x0966, 0x0966, (0, 1)),
+    (0x0967, 0x0967, (1, 1)),
+    (0x0968, 0x0968, (2, 1)),
+    (0x0969, 0x0969, (3, 1)),
+    (0x096A, 0x096A, (4, 1)),
+    (0x096B, 0x096B, (5, 1)),
+    (0x096C, 0x096C, (6, 1)),
+    (0x096D, 0x096D, (7, 1)),
+    (0x096E, 0x096E, (8, 1)),
+    (0x096F, 0x096F, (9, 1)),
+    (0x09E6, 0x09E6, (0, 1)),
+    (0x09E7, 0x09E7, (1, 1)),
+    (0x09E8, 0x09E8, (2, 1)),
+    (0x09E9, 0x09E9, (3, 1)),
+    (0x09EA, 0x09EA, (4, 1)),
+    (0x09EB, 0x09EB, (5, 1)),
+    (0x09EC, 0x09EC, (6, 1)),
+    (0x09ED, 0x09ED, (7, 1)),
+    (0x09EE, 0x09EE, (8, 1)),
+    (0x09EF, 0x09EF, (9, 1)),
+    (0x09F4, 0x09F4, (1, 16)),
+    (0x09F5, 0x09F5, (1, 8)),
+    (0x09F6, 0x09F6, (3, 16)),
+    (0x09F7, 0x09F7, (1, 4)),
+    (0x09F8, 0x09F8, (3, 4)),
+    (0x09F9, 0x09F9, (16, 1)),
+    (0x0A66, 0x0A66, (0, 1)),
+    (0x0A67, 0x0A67, (1, 1)),
+    (0x0A68, 0x0A68, (2, 1)),
+    (0x0A69, 0x0A69, (3, 1)),
+    (0x0A6A, 0x0A6A, (4, 1)),
+    (0x0A6B, 0x0A6B, (5, 1)),
+    (0x0A6C, 0x0A6C, (6, 1)),
+    (0x0A6D, 0x0A6D, (7, 1)),
+    (0x0A6E, 0x0A6E, (8, 1)),
+    (0x0A6F, 0x0A6F, (9, 1)),
+    (0x0AE6, 0x0AE6, (0, 1)),
+    (0x0AE7, 0x0AE7, (1, 1)),
+    (0x0AE8, 0x0AE8, (2, 1)),
+    (0x0AE9, 0x0AE9, (3, 1)),
+    (0x0AEA, 0x0AEA, (4, 1)),
+    (0x0AEB, 0x0AEB, (5, 1)),
+    (0x0AEC, 0x0AEC, (6, 1)),
+    (0x0AED, 0x0AED, (7, 1)),
+    (0x0AEE, 0x0AEE, (8, 1)),
+    (0x0AEF, 0x0AEF, (9, 1)),
+    (0x0B66, 0x0B66, (0, 1)),
+    (0x0B67, 0x0B67, (1, 1)),
+    (0x0B68, 0x0B68, (2, 1)),
+    (0x0B69, 0x0B69, (3, 1)),
+    (0x0B6A, 0x0B6A, (4, 1)),
+    (0x0B6B, 0x0B6B, (5, 1)),
+    (0x0B6C, 0x0B6C, (6, 1)),
+    (0x0B6D, 0x0B6D, (7, 1)),
+    (0x0B6E, 0x0B6E, (8, 1)),
+    (0x0B6F, 0x0B6F, (9, 1)),
+    (0x0B72, 0x0B72, (1, 4)),
+    (0x0B73, 0x0B73, (1, 2)),
+    (0x0B74, 0x0B74, (3, 4)),
+    (0x0B75, 0x0B75, (1, 16)),
+    (0x0B76, 0x0B76, (1, 8)),
+    (0x0B77, 0x0B77, (3, 16)),
+    (0x0BE6, 0x0BE6, (0, 1)),
+    (0x0BE7, 0x0BE7, (1, 1)),
+    (0x0BE8, 0x0BE8, (2, 1)),
+    (0x0BE9, 0x0BE9, (3, 1)),
+    (0x0BEA, 0x0BEA, (4, 1)),
+    (0x0BEB, 0x0BEB, (5, 1)),
+    (0x0BEC, 0x0BEC, (6, 1)),
+    (0x0BED, 0x0BED, (7, 1)),
+    (0x0BEE, 0x0BEE, (8, 1)),
+    (0x0BEF, 0x0BEF, (9, 1)),
+    (0x0BF0, 0x0BF0, (10, 1)),
+    (0x0BF1, 0x0BF1, (100, 1)),
+    (0x0BF2, 0x0BF2, (1000, 1)),
+    (0x0C66, 0x0C66, (0, 1)),
+    (0x0C67, 0x0C67, (1, 1)),
+    (0x0C68, 0x0C68, (2, 1)),
+    (0x0C69, 0x0C69, (3, 1)),
+    (0x0C6A, 0x0C6A, (4, 1)),
+    (0x0C6B, 0x0C6B, (5, 1)),
+    (0x0C6C, 0x0C6C, (6, 1)),
+    (0x0C6D, 0x0C6D, (7, 1)),
+    (0x0C6E, 0x0C6E, (8, 1)),
+    (0x0C6F, 0x0C6F, (9, 1)),
+    (0x0C78, 0x0C78, (0, 1)),
+    (0x0C79, 0x0C79, (1, 1)),
+    (0x0C7A, 0x0C7A, (2, 1)),
+    (0x0C7B, 0x0C7B, (3, 1)),
+    (0x0C7C, 0x0C7C, (1, 1)),
+    (0x0C7D, 0x0C7D, (2, 1)),
+    (0x0C7E, 0x0C7E, (3, 1)),
+    (0x0CE6, 0x0CE6, (0, 1)),
+    (0x0CE7, 0x0CE7, (1, 1)),
+    (0x0CE8, 0x0CE8, (2, 1)),
+    (0x0CE9, 0x0CE9, (3, 1)),
+    (0x0CEA, 0x0CEA, (4, 1)),
+    (0x0CEB, 0x0CEB, (5, 1)),
+    (0x0CEC, 0x0CEC, (6, 1)),
+    (0x0CED, 0x0CED, (7, 1)),
+    (0x0CEE, 0x0CEE, (8, 1)),
+    (0x0CEF, 0x0CEF, (9, 1)),
+    (0x0D58, 0x0D58, (1, 160)),
+    (0x0D59, 0x0D59, (1, 40)),
+    (0x0D5A, 0x0D5A, (3, 80)),
+    (0x0D5B, 0x0D5B, (1, 20)),
+    (0x0D5C, 0x0D5C, (1, 10)),
+    (0x0D5D, 0x0D5D, (3, 20)),
+    (0x0D5E, 0x0D5E, (1, 5)),
+    (0x0D66, 0x0D66, (0, 1)),
+    (0x0D67, 0x0D67, (1, 1)),
+    (0x0D68, 0x0D68, (2, 1)),
+    (0x0D69, 0x0D69, (3, 1)),
+    (0x0D6A, 0x0D6A, (4, 1)),
+    (0x0D6B, 0x0D6B, (5, 1)),
+    (0x0D6C, 0x0D6C, (6, 1)),
+    (0x0D6D, 0x0D6D, (7, 1)),
+    (0x0D6E, 0x0D6E, (8, 1)),
+    (0x0D6F, 0x0D6F, (9, 1)),
+    (0x0D70, 0x0D70, (10, 1)),
+    (0x0D71, 0x0D71, (100, 1)),
+    (0x0D72, 0x0D72, (1000, 1)),
+    (0x0D73, 0x0D73, (1, 4)),
+    (0x0D74, 0x0D74, (1, 2)),
+    (0x0D75, 0x0D75, (3, 4)),
+    (0x0D76, 0x0D76, (1, 16)),
+    (0x0D77, 0x0D77, (1, 8)),
+    (0x0D78, 0x0D78, (3, 16)),
+    (0x0DE6, 0x0DE6, (0, 1)),
+    (0x0DE7, 0x0DE7, (1, 1)),
+    (0x0DE8, 0x0DE8, (2, 1)),
+    (0x0DE9, 0x0DE9, (3, 1)),
+    (0x0DEA, 0x0DEA, (4, 1)),
+    (0x0DEB, 0x0DEB, (5, 1)),
+    (0x0DEC, 0x0DEC, (6, 1)),
+    (0x0DED, 0x0DED, (7, 1)),
+    (0x0DEE, 0x0DEE, (8, 1)),
+    (0x0DEF, 0x0DEF, (9, 1)),
+    (0x0E50, 0x0E50, (0, 1)),
+    (0x0E51, 0x0E51, (1, 1)),
+    (0x0E52, 0x0E52, (2, 1)),
+    (0x0E53, 0x0E53, (3, 1)),
+    (0x0E54, 0x0E54, (4, 1)),
+    (0x0E55, 0x0E55, (5, 1)),
+    (0x0E56, 0x0E56, (6, 1)),
+    (0x0E57, 0x0E57, (7, 1)),
+    (0x0E58, 0x0E58, (8, 1)),
+    (0x0E59, 0x0E59, (9, 1)),
+    (0x0ED0, 0x0ED0, (0, 1)),
+    (0x0ED1, 0x0ED1, (1, 1)),
+    (0x0ED2, 0x0ED2, (2, 1)),
+    (0x0ED3, 0x0ED3, (3, 1)),
+    (0x0ED4, 0x0ED4, (4, 1)),
+    (0x0ED5, 0x0ED5, (5, 1)),
+    (0x0ED6, 0x0ED6, (6, 1)),
+    (0x0ED7, 0x0ED7, (7, 1)),
+    (0x0ED8, 0x0ED8, (8, 1)),
+    (0x0ED9, 0x0ED9, (9, 1)),
+    (0x0F20, 0x0F20, (0, 1)),
+    (0x0F21, 0x0F21, (1, 1)),
+    (0x0F22, 0x0F22, (2, 1)),
+    (0x0F23, 0x0F23, (3, 1)),
+    (0x0F24, 0x0F24, (4, 1)),
+    (0x0F25, 0x0F25, (5, 1)),
+    (0x0F26, 0x0F26, (6, 1)),
+    (0x0F27, 0x0F27, (7, 1)),
+    (0x0F28, 0x0F28, (8, 1)),
+    (0x0F29, 0x0F29, (9, 1)),
+    (0x0F2A, 0x0F2A, (1, 2)),
+    (0x0F2B, 0x0F2B, (3, 2)),
+    (0x0F2C, 0x0F2C, (5, 2)),
+    (0x0F2D, 0x0F2D, (7, 2)),
+    (0x0F2E, 0x0F2E, (9, 2)),
+    (0x0F2F, 0x0F2F, (11, 2)),
+    (0x0F30, 0x0F30, (13, 2)),
+    (0x0F31, 0x0F31, (15, 2)),
+    (0x0F32, 0x0F32, (17, 2)),
+    (0x0F33, 0x0F33, (-1, 2)),
+    (0x1040, 0x1040, (0, 1)),
+    (0x1041, 0x1041, (1, 1)),
+    (0x1042, 0x1042, (2, 1)),
+    (0x1043, 0x1043, (3, 1)),
+    (0x1044, 0x1044, (4, 1)),
+    (0x1045, 0x1045, (5, 1)),
+    (0x1046, 0x1046, (6, 1)),
+    (0x1047, 0x1047, (7, 1)),
+    (0x1048, 0x1048, (8, 1)),
+    (0x1049, 0x1049, (9, 1)),
+    (0x1090, 0x1090, (0, 1)),
+    (0x1091, 0x1091, (1, 1)),
+    (0x1092, 0x1092, (2, 1)),
+    (0x1093, 0x1093, (3, 1)),
+    (0x1094, 0x1094, (4, 1)),
+    (0x1095, 0x1095, (5, 1)),
+    (0x1096, 0x1096, (6, 1)),
+    (0x1097, 0x1097, (7, 1)),
+    (0x1098, 0x1098, (8, 1)),
+    (0x1099, 0x1099, (9, 1)),
+    (0x1369, 0x1369, (1, 1)),
+    (0x136A, 0x136A, (2, 1)),
+    (0x136B, 0x136B, (3, 1)),
+    (0x136C, 0x136C, (4, 1)),
+    (0x136D, 0x136D, (5, 1)),
+    (0x136E, 0x136E, (6, 1)),
+    (0x136F, 0x136F, (7, 1)),
+    (0x1370, 0x1370, (8, 1)),
+    (0x1371, 0x1371, (9, 1)),
+    (0x1372, 0x1372, (10, 1)),
+    (0x1373, 0x1373, (20, 1)),
+    (0x1374, 0x1374, (30, 1)),
+    (0x1375, 0x1375, (40, 1)),
+    (0x1376, 0x1376, (50, 1)),
+    (0x1377, 0x1377, (60, 1)),
+    (0x1378, 0x1378, (70, 1)),
+    (0x1379, 0x1379, (80, 1)),
+    (0x137A, 0x137A, (90, 1)),
+    (0x137B, 0x137B, (100, 1)),
+    (0x137C, 0x137C, (10000, 1)),
+    (0x16EE, 0x16EE, (17, 1)),
+    (0x16EF, 0x16EF, (18, 1)),
+    (0x16F0, 0x16F0, (19, 1)),
+    (0x17E0, 0x17E0, (0, 1)),
+    (0x17E1, 0x17E1, (1, 1)),
+    (0x17E2, 0x17E2, (2, 1)),
+    (0x17E3, 0x17E3, (3, 1)),
+    (0x17E4, 0x17E4, (4, 1)),
+    (0x17E5, 0x17E5, (5, 1)),
+    (0x17E6, 0x17E6, (6, 1)),
+    (0x17E7, 0x17E7, (7, 1)),
+    (0x17E8, 0x17E8, (8, 1)),
+    (0x17E9, 0x17E9, (9, 1)),
+    (0x17F0, 0x17F0, (0, 1)),
+    (0x17F1, 0x17F1, (1, 1)),
+    (0x17F2, 0x17F2, (2, 1)),
+    (0x17F3, 0x17F3, (3, 1)),
+    (0x17F4, 0x17F4, (4, 1)),
+    (0x17F5, 0x17F5, (5, 1)),
+    (0x17F6, 0x17F6, (6, 1)),
+    (0x17F7, 0x17F7, (7, 1)),
+    (0x17F8, 0x17F8, (8, 1)),
+    (0x17F9, 0x17F9, (9, 1)),
+    (0x1810, 0x1810, (0, 1)),
+    (0x1811, 0x1811, (1, 1)),
+    (0x1812, 0x1812, (2, 1)),
+    (0x1813, 0x1813, (3, 1)),
+    (0x1814, 0x1814, (4, 1)),
+    (0x1815, 0x1815, (5, 1)),
+    (0x1816, 0x1816, (6, 1)),
+    (0x1817, 0x1817, (7, 1)),
+    (0x1818, 0x1818, (8, 1)),
+    (0x1819, 0x1819, (9, 1)),
+    (0x1946, 0x1946, (0, 1)),
+    (0x1947, 0x1947, (1, 1)),
+    (0x1948, 0x1948, (2, 1)),
+    (0x1949, 0x1949, (3, 1)),
+    (0x194A, 0x194A, (4, 1)),
+    (0x194B, 0x194B, (5, 1)),
+    (0x194C, 0x194C, (6, 1)),
+    (0x194D, 0x194D, (7, 1)),
+    (0x194E, 0x194E, (8, 1)),
+    (0x194F, 0x194F, (9, 1)),
+    (0x19D0, 0x19D0, (0, 1)),
+    (0x19D1, 0x19D1, (1, 1)),
+    (0x19D2, 0x19D2, (2, 1)),
+    (0x19D3, 0x19D3, (3, 1)),
+    (0x19D4, 0x19D4, (4, 1)),
+    (0x19D5, 0x19D5, (5, 1)),
+    (0x19D6, 0x19D6, (6, 1)),
+    (0x19D7, 0x19D7, (7, 1)),
+    (0x19D8, 0x19D8, (8, 1)),
+    (0x19D9, 0x19D9, (9, 1)),
+    (0x19DA, 0x19DA, (1, 1)),
+    (0x1A80, 0x1A80, (0, 1)),
+    (0x1A81, 0x1A81, (1, 1)),
+    (0x1A82, 0x1A82, (2, 1)),
+    (0x1A83, 0x1A83, (3, 1)),
+    (0x1A84, 0x1A84, (4, 1)),
+    (0x1A85, 0x1A85, (5, 1)),
+    (0x1A86, 0x1A86, (6, 1)),
+    (0x1A87, 0x1A87, (7, 1)),
+    (0x1A88, 0x1A88, (8, 1)),
+    (0x1A89, 0x1A89, (9, 1)),
+    (0x1A90, 0x1A90, (0, 1)),
+    (0x1A91, 0x1A91, (1, 1)),
+    (0x1A92, 0x1A92, (2, 1)),
+    (0x1A93, 0x1A93, (3, 1)),
+    (0x1A94, 0x1A94, (4, 1)),
+    (0x1A95, 0x1A95, (5, 1)),
+    (0x1A96, 0x1A96, (6, 1)),
+    (0x1A97, 0x1A97, (7, 1)),
+    (0x1A98, 0x1A98, (8, 1)),
+    (0x1A99, 0x1A99, (9, 1)),
+    (0x1B50, 0x1B50, (0, 1)),
+    (0x1B51, 0x1B51, (1, 1)),
+    (0x1B52, 0x1B52, (2, 1)),
+    (0x1B53, 0x1B53, (3, 1)),
+    (0x1B54, 0x1B54, (4, 1)),
+    (0x1B55, 0x1B55, (5, 1)),
+    (0x1B56, 0x1B56, (6, 1)),
+    (0x1B57, 0x1B57, (7, 1)),
+    (0x1B58, 0x1B58, (8, 1)),
+    (0x1B59, 0x1B59, (9, 1)),
+    (0x1BB0, 0x1BB0, (0, 1)),
+    (0x1BB1, 0x1BB1, (1, 1)),
+    (0x1BB2, 0x1BB2, (2, 1)),
+    (0x1BB3, 0x1BB3, (3, 1)),
+    (0x1BB4, 0x1BB4, (4, 1)),
+    (0x1BB5, 0x1BB5, (5, 1)),
+    (0x1BB6, 0x1BB6, (6, 1)),
+    (0x1BB7, 0x1BB7, (7, 1)),
+    (0x1BB8, 0x1BB8, (8, 1)),
+    (0x1BB9, 0x1BB9, (9, 1)),
+    (0x1C40, 0x1C40, (0, 1)),
+    (0x1C41, 0x1C41, (1, 1)),
+    (0x1C42, 0x1C42, (2, 1)),
+    (0x1C43, 0x1C43, (3, 1)),
+    (0x1C44, 0x1C44, (4, 1)),
+    (0x1C45, 0x1C45, (5, 1)),
+    (0x1C46, 0x1C46, (6, 1)),
+    (0x1C47, 0x1C47, (7, 1)),
+    (0x1C48, 0x1C48, (8, 1)),
+    (0x1C49, 0x1C49, (9, 1)),
+    (0x1C50, 0x1C50, (0, 1)),
+    (0x1C51, 0x1C51, (1, 1)),
+    (0x1C52, 0x1C52, (2, 1)),
+    (0x1C53, 0x1C53, (3, 1)),
+    (0x1C54, 0x1C54, (4, 1)),
+    (0x1C55, 0x1C55, (5, 1)),
+    (0x1C56, 0x1C56, (6, 1)),
+    (0x1C57, 0x1C57, (7, 1)),
+    (0x1C58, 0x1C58, (8, 1)),
+    (0x1C59, 0x1C59, (9, 1)),
+    (0x2070, 0x2070, (0, 1)),
+    (0x2074, 0x2074, (4, 1)),
+    (0x2075, 0x2075, (5, 1)),
+    (0x2076, 0x2076, (6, 1)),
+    (0x2077, 0x2077, (7, 1)),
+    (0x2078, 0x2078, (8, 1)),
+    (0x2079, 0x2079, (9, 1)),
+    (0x2080, 0x2080, (0, 1)),
+    (0x2081, 0x2081, (1, 1)),
+    (0x2082, 0x2082, (2, 1)),
+    (0x2083, 0x2083, (3, 1)),
+    (0x2084, 0x2084, (4, 1)),
+    (0x2085, 0x2085, (5, 1)),
+    (0x2086, 0x2086, (6, 1)),
+    (0x2087, 0x2087, (7, 1)),
+    (0x2088, 0x2088, (8, 1)),
+    (0x2089, 0x2089, (9, 1)),
+    (0x2150, 0x2150, (1, 7)),
+    (0x2151, 0x2151, (1, 9)),
+    (0x2152, 0x2152, (1, 10)),
+    (0x2153, 0x2153, (1, 3)),
+    (0x2154, 0x2154, (2, 3)),
+    (0x2155, 0x2155, (1, 5)),
+    (0x2156, 0x2156, (2, 5)),
+    (0x2157, 0x2157, (3, 5)),
+    (0x2158, 0x2158, (4, 5)),
+    (0x2159, 0x2159, (1, 6)),
+    (0x215A, 0x215A, (5, 6)),
+    (0x215B, 0x215B, (1, 8)),
+    (0x215C, 0x215C, (3, 8)),
+    (0x215D, 0x215D, (5, 8)),
+    (0x215E, 0x215E, (7, 8)),
+    (0x215F, 0x2160, (1, 1)),
+    (0x2161, 0x2161, (2, 1)),
+    (0x2162, 0x2162, (3, 1)),
+    (0x2163, 0x2163, (4, 1)),
+    (0x2164, 0x2164, (5, 1)),
+    (0x2165, 0x2165, (6, 1)),
+    (0x2166, 0x2166, (7, 1)),
+    (0x2167, 0x2167, (8, 1)),
+    (0x2168, 0x2168, (9, 1)),
+    (0x2169, 0x2169, (10, 1)),
+    (0x216A, 0x216A, (11, 1)),
+    (0x216B, 0x216B, (12, 1)),
+    (0x216C, 0x216C, (50, 1)),
+    (0x216D, 0x216D, (100, 1)),
+    (0x216E, 0x216E, (500, 1)),
+    (0x216F, 0x216F, (1000, 1)),
+    (0x2170, 0x2170, (1, 1)),
+    (0x2171, 0x2171, (2, 1)),
+    (0x2172, 0x2172, (3, 1)),
+    (0x2173, 0x2173, (4, 1)),
+    (0x2174, 0x2174, (5, 1)),
+    (0x2175, 0x2175, (6, 1)),
+    (0x2176, 0x2176, (7, 1)),
+    (0x2177, 0x2177, (8, 1)),
+    (0x2178, 0x2178, (9, 1)),
+    (0x2179, 0x2179, (10, 1)),
+    (0x217A, 0x217A, (11, 1)),
+    (0x217B, 0x217B, (12, 1)),
+    (0x217C, 0x217C, (50, 1)),
+    (0x217D, 0x217D, (100, 1)),
+    (0x217E, 0x217E, (500, 1)),
+    (0x217F, 0x2180, (1000, 1)),
+    (0x2181, 0x2181, (5000, 1)),
+    (0x2182, 0x2182, (10000, 1)),
+    (0x2185, 0x2185, (6, 1)),
+    (0x2186, 0x2186, (50, 1)),
+    (0x2187, 0x2187, (50000, 1)),
+    (0x2188, 0x2188, (100000, 1)),
+    (0x2189, 0x2189, (0, 1)),
+    (0x2460, 0x2460, (1, 1)),
+    (0x2461, 0x2461, (2, 1)),
+    (0x2462, 0x2462, (3, 1)),
+    (0x2463, 0x2463, (4, 1)),
+    (0x2464, 0x2464, (5, 1)),
+    (0x2465, 0x2465, (6, 1)),
+    (0x2466, 0x2466, (7, 1)),
+    (0x2467, 0x2467, (8, 1)),
+    (0x2468, 0x2468, (9, 1)),
+    (0x2469, 0x2469, (10, 1)),
+    (0x246A, 0x246A, (11, 1)),
+    (0x246B, 0x246B, (12, 1)),
+    (0x246C, 0x246C, (13, 1)),
+    (0x246D, 0x246D, (14, 1)),
+    (0x246E, 0x246E, (15, 1)),
+    (0x246F, 0x246F, (16, 1)),
+    (0x2470, 0x2470, (17, 1)),
+    (0x2471, 0x2471, (18, 1)),
+    (0x2472, 0x2472, (19, 1)),
+    (0x2473, 0x2473, (20, 1)),
+    (0x2474, 0x2474, (1, 1)),
+    (0x2475, 0x2475, (2, 1)),
+    (0x2476, 0x2476, (3, 1)),
+    (0x2477, 0x2477, (4, 1)),
+    (0x2478, 0x2478, (5, 1)),
+    (0x2479, 0x2479, (6, 1)),
+    (0x247A, 0x247A, (7, 1)),
+    (0x247B, 0x247B, (8, 1)),
+    (0x247C, 0x247C, (9, 1)),
+    (0x247D, 0x247D, (10, 1)),
+    (0x247E, 0x247E, (11, 1)),
+    (0x247F, 0x247F, (12, 1)),
+    (0x2480, 0x2480, (13, 1)),
+    (0x2481, 0x2481, (14, 1)),
+    (0x2482, 0x2482, (15, 1)),
+    (0x2483, 0x2483, (16, 1)),
+    (0x2484, 0x2484, (17, 1)),
+    (0x2485, 0x2485, (18, 1)),
+    (0x2486, 0x2486, (19, 1)),
+    (0x2487, 0x2487, (20, 1)),
+    (0x2488, 0x2488, (1, 1)),
+    (0x2489, 0x2489, (2, 1)),
+    (0x248A, 0x248A, (3, 1)),
+    (0x248B, 0x248B, (4, 1)),
+    (0x248C, 0x248C, (5, 1)),
+    (0x248D, 0x248D, (6, 1)),
+    (0x248E, 0x248E, (7, 1)),
+    (0x248F, 0x248F, (8, 1)),
+    (0x2490, 0x2490, (9, 1)),
+    (0x2491, 0x2491, (10, 1)),
+    (0x2492, 0x2492, (11, 1)),
+    (0x2493, 0x2493, (12, 1)),
+    (0x2494, 0x2494, (13, 1)),
+    (0x2495, 0x2495, (14, 1)),
+    (0x2496, 0x2496, (15, 1)),
+    (0x2497, 0x2497, (16, 1)),
+    (0x2498, 0x2498, (17, 1)),
+    (0x2499, 0x2499, (18, 1)),
+    (0x249A, 0x249A, (19, 1)),
+    (0x249B, 0x249B, (20, 1)),
+    (0x24EA, 0x24EA, (0, 1)),
+    (0x24EB, 0x24EB, (11, 1)),
+    (0x24EC, 0x24EC, (12, 1)),
+    (0x24ED, 0x24ED, (13, 1)),
+    (0x24EE, 0x24EE, (14, 1)),
+    (0x24EF, 0x24EF, (15, 1)),
+    (0x24F0, 0x24F0, (16, 1)),
+    (0x24F1, 0x24F1, (17, 1)),
+    (0x24F2, 0x24F2, (18, 1)),
+    (0x24F3, 0x24F3, (19, 1)),
+    (0x24F4, 0x24F4, (20, 1)),
+    (0x24F5, 0x24F5, (1, 1)),
+    (0x24F6, 0x24F6, (2, 1)),
+    (0x24F7, 0x24F7, (3, 1)),
+    (0x24F8, 0x24F8, (4, 1)),
+    (0x24F9, 0x24F9, (5, 1)),
+    (0x24FA, 0x24FA, (6, 1)),
+    (0x24FB, 0x24FB, (7, 1)),
+    (0x24FC, 0x24FC, (8, 1)),
+    (0x24FD, 0x24FD, (9, 1)),
+    (0x24FE, 0x24FE, (10, 1)),
+    (0x24FF, 0x24FF, (0, 1)),
+    (0x2776, 0x2776, (1, 1)),
+    (0x2777, 0x2777, (2, 1)),
+    (0x2778, 0x2778, (3, 1)),
+    (0x2779, 0x2779, (4, 1)),
+    (0x277A, 0x277A, (5, 1)),
+    (0x277B, 0x277B, (6, 1)),
+    (0x277C, 0x277C, (7, 1)),
+    (0x277D, 0x277D, (8, 1)),
+    (0x277E, 0x277E, (9, 1)),
+    (0x277F, 0x277F, (10, 1)),
+    (0x2780, 0x2780, (1, 1)),
+    (0x2781, 0x2781, (2, 1)),
+    (0x2782, 0x2782, (3, 1)),
+    (0x2783, 0x2783, (4, 1)),
+    (0x2784, 0x2784, (5, 1)),
+    (0x2785, 0x2785, (6, 1)),
+    (0x2786, 0x2786, (7, 1)),
+    (0x2787, 0x2787, (8, 1)),
+    (0x2788, 0x2788, (9, 1)),
+    (0x2789, 0x2789, (10, 1)),
+    (0x278A, 0x278A, (1, 1)),
+    (0x278B, 0x278B, (2, 1)),
+    (0x278C, 0x278C, (3, 1)),
+    (0x278D, 0x278D, (4, 1)),
+    (0x278E, 0x278E, (5, 1)),
+    (0x278F, 0x278F, (6, 1)),
+    (0x2790, 0x2790, (7, 1)),
+    (0x2791, 0x2791, (8, 1)),
+    (0x2792, 0x2792, (9, 1)),
+    (0x2793, 0x2793, (10, 1)),
+    (0x2CFD, 0x2CFD, (1, 2)),
+    (0x3007, 0x3007, (0, 1)),
+    (0x3021, 0x3021, (1, 1)),
+    (0x3022, 0x3022, (2, 1)),
+    (0x3023, 0x3023, (3, 1)),
+    (0x3024, 0x3024, (4, 1)),
+    (0x3025, 0x3025, (5, 1)),
+    (0x3026, 0x3026, (6, 1)),
+    (0x3027, 0x3027, (7, 1)),
+    (0x3028, 0x3028, (8, 1)),
+    (0x3029, 0x3029, (9, 1)),
+    (0x3038, 0x3038, (10, 1)),
+    (0x3039, 0x3039, (20, 1)),
+    (0x303A, 0x303A, (30, 1)),
+    (0x3192, 0x3192, (1, 1)),
+    (0x3193, 0x3193, (2, 1)),
+    (0x3194, 0x3194, (3, 1)),
+    (0x3195, 0x3195, (4, 1)),
+    (0x3220, 0x3220, (1, 1)),
+    (0x3221, 0x3221, (2, 1)),
+    (0x3222, 0x3222, (3, 1)),
+    (0x3223, 0x3223, (4, 1)),
+    (0x3224, 0x3224, (5, 1)),
+    (0x3225, 0x3225, (6, 1)),
+    (0x3226, 0x3226, (7, 1)),
+    (0x3227, 0x3227, (8, 1)),
+    (0x3228, 0x3228, (9, 1)),
+    (0x3229, 0x3229, (10, 1)),
+    (0x3248, 0x3248, (10, 1)),
+    (0x3249, 0x3249, (20, 1)),
+    (0x324A, 0x324A, (30, 1)),
+    (0x324B, 0x324B, (40, 1)),
+    (0x324C, 0x324C, (50, 1)),
+    (0x324D, 0x324D, (60, 1)),
+    (0x324E, 0x324E, (70, 1)),
+    (0x324F, 0x324F, (80, 1)),
+    (0x3251, 0x3251, (21, 1)),
+    (0x3252, 0x3252, (22, 1)),
+    (0x3253, 0x3253, (23, 1)),
+    (0x3254, 0x3254, (24, 1)),
+    (0x3255, 0x3255, (25, 1)),
+    (0x3256, 0x3256, (26, 1)),
+    (0x3257, 0x3257, (27, 1)),
+    (0x3258, 0x3258, (28, 1)),
+    (0x3259, 0x3259, (29, 1)),
+    (0x325A, 0x325A, (30, 1)),
+    (0x325B, 0x325B, (31, 1)),
+    (0x325C, 0x325C, (32, 1)),
+    (0x325D, 0x325D, (33, 1)),
+    (0x325E, 0x325E, (34, 1)),
+    (0x325F, 0x325F, (35, 1)),
+    (0x3280, 0x3280, (1, 1)),
+    (0x3281, 0x3281, (2, 1)),
+    (0x3282, 0x3282, (3, 1)),
+    (0x3283, 0x3283, (4, 1)),
+    (0x3284, 0x3284, (5, 1)),
+    (0x3285, 0x3285, (6, 1)),
+    (0x3286, 0x3286, (7, 1)),
+    (0x3287, 0x3287, (8, 1)),
+    (0x3288, 0x3288, (9, 1)),
+    (0x3289, 0x3289, (10, 1)),
+    (0x32B1, 0x32B1, (36, 1)),
+    (0x32B2, 0x32B2, (37, 1)),
+    (0x32B3, 0x32B3, (38, 1)),
+    (0x32B4, 0x32B4, (39, 1)),
+    (0x32B5, 0x32B5, (40, 1)),
+    (0x32B6, 0x32B6, (41, 1)),
+    (0x32B7, 0x32B7, (42, 1)),
+    (0x32B8, 0x32B8, (43, 1)),
+    (0x32B9, 0x32B9, (44, 1)),
+    (0x32BA, 0x32BA, (45, 1)),
+    (0x32BB, 0x32BB, (46, 1)),
+    (0x32BC, 0x32BC, (47, 1)),
+    (0x32BD, 0x32BD, (48, 1)),
+    (0x32BE, 0x32BE, (49, 1)),
+    (0x32BF, 0x32BF, (50, 1)),
+    (0x3405, 0x3405, (5, 1)),
+    (0x3483, 0x3483, (2, 1)),
+    (0x382A, 0x382A, (5, 1)),
+    (0x3B4D, 0x3B4D, (7, 1)),
+    (0x4E00, 0x4E00, (1, 1)),
+    (0x4E03, 0x4E03, (7, 1)),
+    (0x4E07, 0x4E07, (10000, 1)),
+    (0x4E09, 0x4E09, (3, 1)),
+    (0x4E5D, 0x4E5D, (9, 1)),
+    (0x4E8C, 0x4E8C, (2, 1)),
+    (0x4E94, 0x4E94, (5, 1)),
+    (0x4E96, 0x4E96, (4, 1)),
+    (0x4EBF, 0x4EBF, (100000000, 1)),
+    (0x4EC0, 0x4EC0, (10, 1)),
+    (0x4EDF, 0x4EDF, (1000, 1)),
+    (0x4EE8, 0x4EE8, (3, 1)),
+    (0x4F0D, 0x4F0D, (5, 1)),
+    (0x4F70, 0x4F70, (100, 1)),
+    (0x5104, 0x5104, (100000000, 1)),
+    (0x5146, 0x5146, (1000000000000, 1)),
+    (0x5169, 0x5169, (2, 1)),
+    (0x516B, 0x516B, (8, 1)),
+    (0x516D, 0x516D, (6, 1)),
+    (0x5341, 0x5341, (10, 1)),
+    (0x5343, 0x5343, (1000, 1)),
+    (0x5344, 0x5344, (20, 1)),
+    (0x5345, 0x5345, (30, 1)),
+    (0x534C, 0x534C, (40, 1)),
+    (0x53C1, 0x53C4, (3, 1)),
+    (0x56DB, 0x56DB, (4, 1)),
+    (0x58F1, 0x58F1, (1, 1)),
+    (0x58F9, 0x58F9, (1, 1)),
+    (0x5E7A, 0x5E7A, (1, 1)),
+    (0x5EFE, 0x5EFE, (9, 1)),
+    (0x5EFF, 0x5EFF, (20, 1)),
+    (0x5F0C, 0x5F0C, (1, 1)),
+    (0x5F0D, 0x5F0D, (2, 1)),
+    (0x5F0E, 0x5F0E, (3, 1)),
+    (0x5F10, 0x5F10, (2, 1)),
+    (0x62FE, 0x62FE, (10, 1)),
+    (0x634C, 0x634C, (8, 1)),
+    (0x67D2, 0x67D2, (7, 1)),
+    (0x6F06, 0x6F06, (7, 1)),
+    (0x7396, 0x7396, (9, 1)),
+    (0x767E, 0x767E, (100, 1)),
+    (0x8086, 0x8086, (4, 1)),
+    (0x842C, 0x842C, (10000, 1)),
+    (0x8CAE, 0x8CAE, (2, 1)),
+    (0x8CB3, 0x8CB3, (2, 1)),
+    (0x8D30, 0x8D30, (2, 1)),
+    (0x9621, 0x9621, (1000, 1)),
+    (0x9646, 0x9646, (6, 1)),
+    (0x964C, 0x964C, (100, 1)),
+    (0x9678, 0x9678, (6, 1)),
+    (0x96F6, 0x96F6, (0, 1)),
+    (0xA620, 0xA620, (0, 1)),
+    (0xA621, 0xA621, (1, 1)),
+    (0xA622, 0xA622, (2, 1)),
+    (0xA623, 0xA623, (3, 1)),
+    (0xA624, 0xA624, (4, 1)),
+    (0xA625, 0xA625, (5, 1)),
+    (0xA626, 0xA626, (6, 1)),
+    (0xA627, 0xA627, (7, 1)),
+    (0xA628, 0xA628, (8, 1)),
+    (0xA629, 0xA629, (9, 1)),
+    (0xA6E6, 0xA6E6, (1, 1)),
+    (0xA6E7, 0xA6E7, (2, 1)),
+    (0xA6E8, 0xA6E8, (3, 1)),
+    (0xA6E9, 0xA6E9, (4, 1)),
+    (0xA6EA, 0xA6EA, (5, 1)),
+    (0xA6EB, 0xA6EB, (6, 1)),
+    (0xA6EC, 0xA6EC, (7, 1)),
+    (0xA6ED, 0xA6ED, (8, 1)),
+    (0xA6EE, 0xA6EE, (9, 1)),
+    (0xA6EF, 0xA6EF, (0, 1)),
+    (0xA830, 0xA830, (1, 4)),
+    (0xA831, 0xA831, (1, 2)),
+    (0xA832, 0xA832, (3, 4)),
+    (0xA833, 0xA833, (1, 16)),
+    (0xA834, 0xA834, (1, 8)),
+    (0xA835, 0xA835, (3, 16)),
+    (0xA8D0, 0xA8D0, (0, 1)),
+    (0xA8D1, 0xA8D1, (1, 1)),
+    (0xA8D2, 0xA8D2, (2, 1)),
+    (0xA8D3, 0xA8D3, (3, 1)),
+    (0xA8D4, 0xA8D4, (4, 1)),
+    (0xA8D5, 0xA8D5, (5, 1)),
+    (0xA8D6, 0xA8D6, (6, 1)),
+    (0xA8D7, 0xA8D7, (7, 1)),
+    (0xA8D8, 0xA8D8, (8, 1)),
+    (0xA8D9, 0xA8D9, (9, 1)),
+    (0xA900, 0xA900, (0, 1)),
+    (0xA901, 0xA901, (1, 1)),
+    (0xA902, 0xA902, (2, 1)),
+    (0xA903, 0xA903, (3, 1)),
+    (0xA904, 0xA904, (4, 1)),
+    (0xA905, 0xA905, (5, 1)),
+    (0xA906, 0xA906, (6, 1)),
+    (0xA907, 0xA907, (7, 1)),
+    (0xA908, 0xA908, (8, 1)),
+    (0xA909, 0xA909, (9, 1)),
+    (0xA9D0, 0xA9D0, (0, 1)),
+    (0xA9D1, 0xA9D1, (1, 1)),
+    (0xA9D2, 0xA9D2, (2, 1)),
+    (0xA9D3, 0xA9D3, (3, 1)),
+    (0xA9D4, 0xA9D4, (4, 1)),
+    (0xA9D5, 0xA9D5, (5, 1)),
+    (0xA9D6, 0xA9D6, (6, 1)),
+    (0xA9D7, 0xA9D7, (7, 1)),
+    (0xA9D8, 0xA9D8, (8, 1)),
+    (0xA9D9, 0xA9D9, (9, 1)),
+    (0xA9F0, 0xA9F0, (0, 1)),
+    (0xA9F1, 0xA9F1, (1, 1)),
+    (0xA9F2, 0xA9F2, (2, 1)),
+    (0xA9F3, 0xA9F3, (3, 1)),
+    (0xA9F4, 0xA9F4, (4, 1)),
+    (0xA9F5, 0xA9F5, (5, 1)),
+    (0xA9F6, 0xA9F6, (6, 1)),
+    (0xA9F7, 0xA9F7, (7, 1)),
+    (0xA9F8, 0xA9F8, (8, 1)),
+    (0xA9F9, 0xA9F9, (9, 1)),
+    (0xAA50, 0xAA50, (0, 1)),
+    (0xAA51, 0xAA51, (1, 1)),
+    (0xAA52, 0xAA52, (2, 1)),
+    (0xAA53, 0xAA53, (3, 1)),
+    (0xAA54, 0xAA54, (4, 1)),
+    (0xAA55, 0xAA55, (5, 1)),
+    (0xAA56, 0xAA56, (6, 1)),
+    (0xAA57, 0xAA57, (7, 1)),
+    (0xAA58, 0xAA58, (8, 1)),
+    (0xAA59, 0xAA59, (9, 1)),
+    (0xABF0, 0xABF0, (0, 1)),
+    (0xABF1, 0xABF1, (1, 1)),
+    (0xABF2, 0xABF2, (2, 1)),
+    (0xABF3, 0xABF3, (3, 1)),
+    (0xABF4, 0xABF4, (4, 1)),
+    (0xABF5, 0xABF5, (5, 1)),
+    (0xABF6, 0xABF6, (6, 1)),
+    (0xABF7, 0xABF7, (7, 1)),
+    (0xABF8, 0xABF8, (8, 1)),
+    (0xABF9, 0xABF9, (9, 1)),
+    (0xF96B, 0xF96B, (3, 1)),
+    (0xF973, 0xF973, (10, 1)),
+    (0xF978, 0xF978, (2, 1)),
+    (0xF9B2, 0xF9B2, (0, 1)),
+    (0xF9D1, 0xF9D1, (6, 1)),
+    (0xF9D3, 0xF9D3, (6, 1)),
+    (0xF9FD, 0xF9FD, (10, 1)),
+    (0xFF10, 0xFF10, (0, 1)),
+    (0xFF11, 0xFF11, (1, 1)),
+    (0xFF12, 0xFF12, (2, 1)),
+    (0xFF13, 0xFF13, (3, 1)),
+    (0xFF14, 0xFF14, (4, 1)),
+    (0xFF15, 0xFF15, (5, 1)),
+    (0xFF16, 0xFF16, (6, 1)),
+    (0xFF17, 0xFF17, (7, 1)),
+    (0xFF18, 0xFF18, (8, 1)),
+    (0xFF19, 0xFF19, (9, 1)),
+    (0x10107, 0x10107, (1, 1)),
+    (0x10108, 0x10108, (2, 1)),
+    (0x10109, 0x10109, (3, 1)),
+    (0x1010A, 0x1010A, (4, 1)),
+    (0x1010B, 0x1010B, (5, 1)),
+    (0x1010C, 0x1010C, (6, 1)),
+    (0x1010D, 0x1010D, (7, 1)),
+    (0x1010E, 0x1010E, (8, 1)),
+    (0x1010F, 0x1010F, (9, 1)),
+    (0x10110, 0x10110, (10, 1)),
+    (0x10111, 0x10111, (20, 1)),
+    (0x10112, 0x10112, (30, 1)),
+    (0x10113, 0x10113, (40, 1)),
+    (0x10114, 0x10114, (50, 1)),
+    (0x10115, 0x10115, (60, 1)),
+    (0x10116, 0x10116, (70, 1)),
+    (0x10117, 0x10117, (80, 1)),
+    (0x10118, 0x10118, (90, 1)),
+    (0x10119, 0x10119, (100, 1)),
+    (0x1011A, 0x1011A, (200, 1)),
+    (0x1011B, 0x1011B, (300, 1)),
+    (0x1011C, 0x1011C, (400, 1)),
+    (0x1011D, 0x1011D, (500, 1)),
+    (0x1011E, 0x1011E, (600, 1)),
+    (0x1011F, 0x1011F, (700, 1)),
+    (0x10120, 0x10120, (800, 1)),
+    (0x10121, 0x10121, (900, 1)),
+    (0x10122, 0x10122, (1000, 1)),
+    (0x10123, 0x10123, (2000, 1)),
+    (0x10124, 0x10124, (3000, 1)),
+    (0x10125, 0x10125, (4000, 1)),
+    (0x10126, 0x10126, (5000, 1)),
+    (0x10127, 0x10127, (6000, 1)),
+    (0x10128, 0x10128, (7000, 1)),
+    (0x10129, 0x10129, (8000, 1)),
+    (0x1012A, 0x1012A, (9000, 1)),
+    (0x1012B, 0x1012B, (10000, 1)),
+    (0x1012C, 0x1012C, (20000, 1)),
+    (0x1012D, 0x1012D, (30000, 1)),
+    (0x1012E, 0x1012E, (40000, 1)),
+    (0x1012F, 0x1012F, (50000, 1)),
+    (0x10130, 0x10130, (60000, 1)),
+    (0x10131, 0x10131, (70000, 1)),
+    (0x10132, 0x10132, (80000, 1)),
+    (0x10133, 0x10133, (90000, 1)),
+    (0x10140, 0x10140, (1, 4)),
+    (0x10141, 0x10141, (1, 2)),
+    (0x10142, 0x10142, (1, 1)),
+    (0x10143, 0x10143, (5, 1)),
+    (0x10144, 0x10144, (50, 1)),
+    (0x10145, 0x10145, (500, 1)),
+    (0x10146, 0x10146, (5000, 1)),
+    (0x10147, 0x10147, (50000, 1)),
+    (0x10148, 0x10148, (5, 1)),
+    (0x10149, 0x10149, (10, 1)),
+    (0x1014A, 0x1014A, (50, 1)),
+    (0x1014B, 0x1014B, (100, 1)),
+    (0x1014C, 0x1014C, (500, 1)),
+    (0x1014D, 0x1014D, (1000, 1)),
+    (0x1014E, 0x1014E, (5000, 1)),
+    (0x1014F, 0x1014F, (5, 1)),
+    (0x10150, 0x10150, (10, 1)),
+    (0x10151, 0x10151, (50, 1)),
+    (0x10152, 0x10152, (100, 1)),
+    (0x10153, 0x10153, (500, 1)),
+    (0x10154, 0x10154, (1000, 1)),
+    (0x10155, 0x10155, (10000, 1)),
+    (0x10156, 0x10156, (50000, 1)),
+    (0x10157, 0x10157, (10, 1)),
+    (0x10158, 0x1015A, (1, 1)),
+    (0x1015B, 0x1015E, (2, 1)),
+    (0x1015F, 0x1015F, (5, 1)),
+    (0x10160, 0x10164, (10, 1)),
+    (0x10165, 0x10165, (30, 1)),
+    (0x10166, 0x10169, (50, 1)),
+    (0x1016A, 0x1016A, (100, 1)),
+    (0x1016B, 0x1016B, (300, 1)),
+    (0x1016C, 0x10170, (500, 1)),
+    (0x10171, 0x10171, (1000, 1)),
+    (0x10172, 0x10172, (5000, 1)),
+    (0x10173, 0x10173, (5, 1)),
+    (0x10174, 0x10174, (50, 1)),
+    (0x10175, 0x10176, (1, 2)),
+    (0x10177, 0x10177, (2, 3)),
+    (0x10178, 0x10178, (3, 4)),
+    (0x1018A, 0x1018A, (0, 1)),
+    (0x1018B, 0x1018B, (1, 4)),
+    (0x102E1, 0x102E1, (1, 1)),
+    (0x102E2, 0x102E2, (2, 1)),
+    (0x102E3, 0x102E3, (3, 1)),
+    (0x102E4, 0x102E4, (4, 1)),
+    (0x102E5, 0x102E5, (5, 1)),
+    (0x102E6, 0x102E6, (6, 1)),
+    (0x102E7, 0x102E7, (7, 1)),
+    (0x102E8, 0x102E8, (8, 1)),
+    (0x102E9, 0x102E9, (9, 1)),
+    (0x102EA, 0x102EA, (10, 1)),
+    (0x102EB, 0x102EB, (20, 1)),
+    (0x102EC, 0x102EC, (30, 1)),
+    (0x102ED, 0x102ED, (40, 1)),
+    (0x102EE, 0x102EE, (50, 1)),
+    (0x102EF, 0x102EF, (60, 1)),
+    (0x102F0, 0x102F0, (70, 1)),
+    (0x102F1, 0x102F1, (80, 1)),
+    (0x102F2, 0x102F2, (90, 1)),
+    (0x102F3, 0x102F3, (100, 1)),
+    (0x102F4, 0x102F4, (200, 1)),
+    (0x102F5, 0x102F5, (300, 1)),
+    (0x102F6, 0x102F6, (400, 1)),
+    (0x102F7, 0x102F7, (500, 1)),
+    (0x102F8, 0x102F8, (600, 1)),
+    (0x102F9, 0x102F9, (700, 1)),
+    (0x102FA, 0x102FA, (800, 1)),
+    (0x102FB, 0x102FB, (900, 1)),
+    (0x10320, 0x10320, (1, 1)),
+    (0x10321, 0x10321, (5, 1)),
+    (0x10322, 0x10322, (10, 1)),
+    (0x10323, 0x10323, (50, 1)),
+    (0x10341, 0x10341, (90, 1)),
+    (0x1034A, 0x1034A, (900, 1)),
+    (0x103D1, 0x103D1, (1, 1)),
+    (0x103D2, 0x103D2, (2, 1)),
+    (0x103D3, 0x103D3, (10, 1)),
+    (0x103D4, 0x103D4, (20, 1)),
+    (0x103D5, 0x103D5, (100, 1)),
+    (0x104A0, 0x104A0, (0, 1)),
+    (0x104A1, 0x104A1, (1, 1)),
+    (0x104A2, 0x104A2, (2, 1)),
+    (0x104A3, 0x104A3, (3, 1)),
+    (0x104A4, 0x104A4, (4, 1)),
+    (0x104A5, 0x104A5, (5, 1)),
+    (0x104A6, 0x104A6, (6, 1)),
+    (0x104A7, 0x104A7, (7, 1)),
+    (0x104A8, 0x104A8, (8, 1)),
+    (0x104A9, 0x104A9, (9, 1)),
+    (0x10858, 0x10858, (1, 1)),
+    (0x10859, 0x10859, (2, 1)),
+    (0x1085A, 0x1085A, (3, 1)),
+    (0x1085B, 0x1085B, (10, 1)),
+    (0x1085C, 0x1085C, (20, 1)),
+    (0x1085D, 0x1085D, (100, 1)),
+    (0x1085E, 0x1085E, (1000, 1)),
+    (0x1085F, 0x1085F, (10000, 1)),
+    (0x10879, 0x10879, (1, 1)),
+    (0x1087A, 0x1087A, (2, 1)),
+    (0x1087B, 0x1087B, (3, 1)),
+    (0x1087C, 0x1087C, (4, 1)),
+    (0x1087D, 0x1087D, (5, 1)),
+    (0x1087E, 0x1087E, (10, 1)),
+    (0x1087F, 0x1087F, (20, 1)),
+    (0x108A7, 0x108A7, (1, 1)),
+    (0x108A8, 0x108A8, (2, 1)),
+    (0x108A9, 0x108A9, (3, 1)),
+    (0x108AA, 0x108AB, (4, 1)),
+    (0x108AC, 0x108AC, (5, 1)),
+    (0x108AD, 0x108AD, (10, 1)),
+    (0x108AE, 0x108AE, (20, 1)),
+    (0x108AF, 0x108AF, (100, 1)),
+    (0x108FB, 0x108FB, (1, 1)),
+    (0x108FC, 0x108FC, (5, 1)),
+    (0x108FD, 0x108FD, (10, 1)),
+    (0x108FE, 0x108FE, (20, 1)),
+    (0x108FF, 0x108FF, (100, 1)),
+    (0x10916, 0x10916, (1, 1)),
+    (0x10917, 0x10917, (10, 1)),
+    (0x10918, 0x10918, (20, 1)),
+    (0x10919, 0x10919, (100, 1)),
+    (0x1091A, 0x1091A, (2, 1)),
+    (0x1091B, 0x1091B, (3, 1)),
+    (0x109BC, 0x109BC, (11, 12)),
+    (0x109BD, 0x109BD, (1, 2)),
+    (0x109C0, 0x109C0, (1, 1)),
+    (0x109C1, 0x109C1, (2, 1)),
+    (0x109C2, 0x109C2, (3, 1)),
+    (0x109C3, 0x109C3, (4, 1)),
+    (0x109C4, 0x109C4, (5, 1)),
+    (0x109C5, 0x109C5, (6, 1)),
+    (0x109C6, 0x109C6, (7, 1)),
+    (0x109C7, 0x109C7, (8, 1)),
+    (0x109C8, 0x109C8, (9, 1)),
+    (0x109C9, 0x109C9, (10, 1)),
+    (0x109CA, 0x109CA, (20, 1)),
+    (0x109CB, 0x109CB, (30, 1)),
+    (0x109CC, 0x109CC, (40, 1)),
+    (0x109CD, 0x109CD, (50, 1)),
+    (0x109CE, 0x109CE, (60, 1)),
+    (0x109CF, 0x109CF, (70, 1)),
+    (0x109D2, 0x109D2, (100, 1)),
+    (0x109D3, 0x109D3, (200, 1)),
+    (0x109D4, 0x109D4, (300, 1)),
+    (0x109D5, 0x109D5, (400, 1)),
+    (0x109D6, 0x109D6, (500, 1)),
+    (0x109D7, 0x109D7, (600, 1)),
+    (0x109D8, 0x109D8, (700, 1)),
+    (0x109D9, 0x109D9, (800, 1)),
+    (0x109DA, 0x109DA, (900, 1)),
+    (0x109DB, 0x109DB, (1000, 1)),
+    (0x109DC, 0x109DC, (2000, 1)),
+    (0x109DD, 0x109DD, (3000, 1)),
+    (0x109DE, 0x109DE, (4000, 1)),
+    (0x109DF, 0x109DF, (5000, 1)),
+    (0x109E0, 0x109E0, (6000, 1)),
+    (0x109E1, 0x109E1, (7000, 1)),
+    (0x109E2, 0x109E2, (8000, 1)),
+    (0x109E3, 0x109E3, (9000, 1)),
+    (0x109E4, 0x109E4, (10000, 1)),
+    (0x109E5, 0x109E5, (20000, 1)),
+    (0x109E6, 0x109E6, (30000, 1)),
+    (0x109E7, 0x109E7, (40000, 1)),
+    (0x109E8, 0x109E8, (50000, 1)),
+    (0x109E9, 0x109E9, (60000, 1)),
+    (0x109EA, 0x109EA, (70000, 1)),
+    (0x109EB, 0x109EB, (80000, 1)),
+    (0x109EC, 0x109EC, (90000, 1)),
+    (0x109ED, 0x109ED, (100000, 1)),
+    (0x109EE, 0x109EE, (200000, 1)),
+    (0x109EF, 0x109EF, (300000, 1)),
+    (0x109F0, 0x109F0, (400000, 1)),
+    (0x109F1, 0x109F1, (500000, 1)),
+    (0x109F2, 0x109F2, (600000, 1)),
+    (0x109F3, 0x109F3, (700000, 1)),
+    (0x109F4, 0x109F4, (800000, 1)),
+    (0x109F5, 0x109F5, (900000, 1)),
+    (0x109F6, 0x109F6, (1, 12)),
+    (0x109F7, 0x109F7, (1, 6)),
+    (0x109F8, 0x109F8, (1, 4)),
+    (0x109F9, 0x109F9, (1, 3)),
+    (0x109FA, 0x109FA, (5, 12)),
+    (0x109FB, 0x109FB, (1, 2)),
+    (0x109FC, 0x109FC, (7, 12)),
+    (0x109FD, 0x109FD, (2, 3)),
+    (0x109FE, 0x109FE, (3, 4)),
+    (0x109FF, 0x109FF, (5, 6)),
+    (0x10A40, 0x10A40, (1, 1)),
+    (0x10A41, 0x10A41, (2, 1)),
+    (0x10A42, 0x10A42, (3, 1)),
+    (0x10A43, 0x10A43, (4, 1)),
+    (0x10A44, 0x10A44, (10, 1)),
+    (0x10A45, 0x10A45, (20, 1)),
+    (0x10A46, 0x10A46, (100, 1)),
+    (0x10A47, 0x10A47, (1000, 1)),
+    (0x10A48, 0x10A48, (1, 2)),
+    (0x10A7D, 0x10A7D, (1, 1)),
+    (0x10A7E, 0x10A7E, (50, 1)),
+    (0x10A9D, 0x10A9D, (1, 1)),
+    (0x10A9E, 0x10A9E, (10, 1)),
+    (0x10A9F, 0x10A9F, (20, 1)),
+    (0x10AEB, 0x10AEB, (1, 1)),
+    (0x10AEC, 0x10AEC, (5, 1)),
+    (0x10AED, 0x10AED, (10, 1)),
+    (0x10AEE, 0x10AEE, (20, 1)),
+    (0x10AEF, 0x10AEF, (100, 1)),
+    (0x10B58, 0x10B58, (1, 1)),
+    (0x10B59, 0x10B59, (2, 1)),
+    (0x10B5A, 0x10B5A, (3, 1)),
+    (0x10B5B, 0x10B5B, (4, 1)),
+    (0x10B5C, 0x10B5C, (10, 1)),
+    (0x10B5D, 0x10B5D, (20, 1)),
+    (0x10B5E, 0x10B5E, (100, 1)),
+    (0x10B5F, 0x10B5F, (1000, 1)),
+    (0x10B78, 0x10B78, (1, 1)),
+    (0x10B79, 0x10B79, (2, 1)),
+    (0x10B7A, 0x10B7A, (3, 1)),
+    (0x10B7B, 0x10B7B, (4, 1)),
+    (0x10B7C, 0x10B7C, (10, 1)),
+    (0x10B7D, 0x10B7D, (20, 1)),
+    (0x10B7E, 0x10B7E, (100, 1)),
+    (0x10B7F, 0x10B7F, (1000, 1)),
+    (0x10BA9, 0x10BA9, (1, 1)),
+    (0x10BAA, 0x10BAA, (2, 1)),
+    (0x10BAB, 0x10BAB, (3, 1)),
+    (0x10BAC, 0x10BAC, (4, 1)),
+    (0x10BAD, 0x10BAD, (10, 1)),
+    (0x10BAE, 0x10BAE, (20, 1)),
+    (0x10BAF, 0x10BAF, (100, 1)),
+    (0x10CFA, 0x10CFA, (1, 1)),
+    (0x10CFB, 0x10CFB, (5, 1)),
+    (0x10CFC, 0x10CFC, (10, 1)),
+    (0x10CFD, 0x10CFD, (50, 1)),
+    (0x10CFE, 0x10CFE, (100, 1)),
+    (0x10CFF, 0x10CFF, (1000, 1)),
+    (0x10D30, 0x10D30, (0, 1)),
+    (0x10D31, 0x10D31, (1, 1)),
+    (0x10D32, 0x10D32, (2, 1)),
+    (0x10D33, 0x10D33, (3, 1)),
+    (0x10D34, 0x10D34, (4, 1)),
+    (0x10D35, 0x10D35, (5, 1)),
+    (0x10D36, 0x10D36, (6, 1)),
+    (0x10D37, 0x10D37, (7, 1)),
+    (0x10D38, 0x10D38, (8, 1)),
+    (0x10D39, 0x10D39, (9, 1)),
+    (0x10E60, 0x10E60, (1, 1)),
+    (0x10E61, 0x10E61, (2, 1)),
+    (0x10E62, 0x10E62, (3, 1)),
+    (0x10E63, 0x10E63, (4, 1)),
+    (0x10E64, 0x10E64, (5, 1)),
+    (0x10E65, 0x10E65, (6, 1)),
+    (0x10E66, 0x10E66, (7, 1)),
+    (0x10E67, 0x10E67, (8, 1)),
+    (0x10E68, 0x10E68, (9, 1)),
+    (0x10E69, 0x10E69, (10, 1)),
+    (0x10E6A, 0x10E6A, (20, 1)),
+    (0x10E6B, 0x10E6B, (30, 1)),
+    (0x10E6C, 0x10E6C, (40, 1)),
+    (0x10E6D, 0x10E6D, (50, 1)),
+    (0x10E6E, 0x10E6E, (60, 1)),
+    (0x10E6F, 0x10E6F, (70, 1)),
+    (0x10E70, 0x10E70, (80, 1)),
+    (0x10E71, 0x10E71, (90, 1)),
+    (0x10E72, 0x10E72, (100, 1)),
+    (0x10E73, 0x10E73, (200, 1)),
+    (0x10E74, 0x10E74, (300, 1)),
+    (0x10E75, 0x10E75, (400, 1)),
+    (0x10E76, 0x10E76, (500, 1)),
+    (0x10E77, 0x10E77, (600, 1)),
+    (0x10E78, 0x10E78, (700, 1)),
+    (0x10E79, 0x10E79, (800, 1)),
+    (0x10E7A, 0x10E7A, (900, 1)),
+    (0x10E7B, 0x10E7B, (1, 2)),
+    (0x10E7C, 0x10E7C, (1, 4)),
+    (0x10E7D, 0x10E7D, (1, 3)),
+    (0x10E7E, 0x10E7E, (2, 3)),
+    (0x10F1D, 0x10F1D, (1, 1)),
+    (0x10F1E, 0x10F1E, (2, 1)),
+    (0x10F1F, 0x10F1F, (3, 1)),
+    (0x10F20, 0x10F20, (4, 1)),
+    (0x10F21, 0x10F21, (5, 1)),
+    (0x10F22, 0x10F22, (10, 1)),
+    (0x10F23, 0x10F23, (20, 1)),
+    (0x10F24, 0x10F24, (30, 1)),
+    (0x10F25, 0x10F25, (100, 1)),
+    (0x10F26, 0x10F26, (1, 2)),
+    (0x10F51, 0x10F51, (1, 1)),
+    (0x10F52, 0x10F52, (10, 1)),
+    (0x10F53, 0x10F53, (20, 1)),
+    (0x10F54, 0x10F54, (100, 1)),
+    (0x10FC5, 0x10FC5, (1, 1)),
+    (0x10FC6, 0x10FC6, (2, 1)),
+    (0x10FC7, 0x10FC7, (3, 1)),
+    (0x10FC8, 0x10FC8, (4, 1)),
+    (0x10FC9, 0x10FC9, (10, 1)),
+    (0x10FCA, 0x10FCA, (20, 1)),
+    (0x10FCB, 0x10FCB, (100, 1)),
+    (0x11052, 0x11052, (1, 1)),
+    (0x11053, 0x11053, (2, 1)),
+    (0x11054, 0x11054, (3, 1)),
+    (0x11055, 0x11055, (4, 1)),
+    (0x11056, 0x11056, (5, 1)),
+    (0x11057, 0x11057, (6, 1)),
+    (0x11058, 0x11058, (7, 1)),
+    (0x11059, 0x11059, (8, 1)),
+    (0x1105A, 0x1105A, (9, 1)),
+    (0x1105B, 0x1105B, (10, 1)),
+    (0x1105C, 0x1105C, (20, 1)),
+    (0x1105D, 0x1105D, (30, 1)),
+    (0x1105E, 0x1105E, (40, 1)),
+    (0x1105F, 0x1105F, (50, 1)),
+    (0x11060, 0x11060, (60, 1)),
+    (0x11061, 0x11061, (70, 1)),
+    (0x11062, 0x11062, (80, 1)),
+    (0x11063, 0x11063, (90, 1)),
+    (0x11064, 0x11064, (100, 1)),
+    (0x11065, 0x11065, (1000, 1)),
+    (0x11066, 0x11066, (0, 1)),
+    (0x11067, 0x11067, (1, 1)),
+    (0x11068, 0x11068, (2, 1)),
+    (0x11069, 0x11069, (3, 1)),
+    (0x1106A, 0x1106A, (4, 1)),
+    (0x1106B, 0x1106B, (5, 1)),
+    (0x1106C, 0x1106C, (6, 1)),
+    (0x1106D, 0x1106D, (7, 1)),
+    (0x1106E, 0x1106E, (8, 1)),
+    (0x1106F, 0x1106F, (9, 1)),
+    (0x110F0, 0x110F0, (0, 1)),
+    (0x110F1, 0x110F1, (1, 1)),
+    (0x110F2, 0x110F2, (2, 1)),
+    (0x110F3, 0x110F3, (3, 1)),
+    (0x110F4, 0x110F4, (4, 1)),
+    (0x110F5, 0x110F5, (5, 1)),
+    (0x110F6, 0x110F6, (6, 1)),
+    (0x110F7, 0x110F7, (7, 1)),
+    (0x110F8, 0x110F8, (8, 1)),
+    (0x110F9, 0x110F9, (9, 1)),
+    (0x11136, 0x11136, (0, 1)),
+    (0x11137, 0x11137, (1, 1)),
+    (0x11138, 0x11138, (2, 1)),
+    (0x11139, 0x11139, (3, 1)),
+    (0x1113A, 0x1113A, (4, 1)),
+    (0x1113B, 0x1113B, (5, 1)),
+    (0x1113C, 0x1113C, (6, 1)),
+    (0x1113D, 0x1113D, (7, 1)),
+    (0x1113E, 0x1113E, (8, 1)),
+    (0x1113F, 0x1113F, (9, 1)),
+    (0x111D0, 0x111D0, (0, 1)),
+    (0x111D1, 0x111D1, (1, 1)),
+    (0x111D2, 0x111D2, (2, 1)),
+    (0x111D3, 0x111D3, (3, 1)),
+    (0x111D4, 0x111D4, (4, 1)),
+    (0x111D5, 0x111D5, (5, 1)),
+    (0x111D6, 0x111D6, (6, 1)),
+    (0x111D7, 0x111D7, (7, 1)),
+    (0x111D8, 0x111D8, (8, 1)),
+    (0x111D9, 0x111D9, (9, 1)),
+    (0x111E1, 0x111E1, (1, 1)),
+    (0x111E2, 0x111E2, (2, 1)),
+    (0x111E3, 0x111E3, (3, 1)),
+    (0x111E4, 0x111E4, (4, 1)),
+    (0x111E5, 0x111E5, (5, 1)),
+    (0x111E6, 0x111E6, (6, 1)),
+    (0x111E7, 0x111E7, (7, 1)),
+    (0x111E8, 0x111E8, (8, 1)),
+    (0x111E9, 0x111E9, (9, 1)),
+    (0x111EA, 0x111EA, (10, 1)),
+    (0x111EB, 0x111EB, (20, 1)),
+    (0x111EC, 0x111EC, (30, 1)),
+    (0x111ED, 0x111ED, (40, 1)),
+    (0x111EE, 0x111EE, (50, 1)),
+    (0x111EF, 0x111EF, (60, 1)),
+    (0x111F0, 0x111F0, (70, 1)),
+    (0x111F1, 0x111F1, (80, 1)),
+    (0x111F2, 0x111F2, (90, 1)),
+    (0x111F3, 0x111F3, (100, 1)),
+    (0x111F4, 0x111F4, (1000, 1)),
+    (0x112F0, 0x112F0, (0, 1)),
+    (0x112F1, 0x112F1, (1, 1)),
+    (0x112F2, 0x112F2, (2, 1)),
+    (0x112F3, 0x112F3, (3, 1)),
+    (0x112F4, 0x112F4, (4, 1)),
+    (0x112F5, 0x112F5, (5, 1)),
+    (0x112F6, 0x112F6, (6, 1)),
+    (0x112F7, 0x112F7, (7, 1)),
+    (0x112F8, 0x112F8, (8, 1)),
+    (0x112F9, 0x112F9, (9, 1)),
+    (0x11450, 0x11450, (0, 1)),
+    (0x11451, 0x11451, (1, 1)),
+    (0x11452, 0x11452, (2, 1)),
+    (0x11453, 0x11453, (3, 1)),
+    (0x11454, 0x11454, (4, 1)),
+    (0x11455, 0x11455, (5, 1)),
+    (0x11456, 0x11456, (6, 1)),
+    (0x11457, 0x11457, (7, 1)),
+    (0x11458, 0x11458, (8, 1)),
+    (0x11459, 0x11459, (9, 1)),
+    (0x114D0, 0x114D0, (0, 1)),
+    (0x114D1, 0x114D1, (1, 1)),
+    (0x114D2, 0x114D2, (2, 1)),
+    (0x114D3, 0x114D3, (3, 1)),
+    (0x114D4, 0x114D4, (4, 1)),
+    (0x114D5, 0x114D5, (5, 1)),
+    (0x114D6, 0x114D6, (6, 1)),
+    (0x114D7, 0x114D7, (7, 1)),
+    (0x114D8, 0x114D8, (8, 1)),
+    (0x114D9, 0x114D9, (9, 1)),
+    (0x11650, 0x11650, (0, 1)),
+    (0x11651, 0x11651, (1, 1)),
+    (0x11652, 0x11652, (2, 1)),
+    (0x11653, 0x11653, (3, 1)),
+    (0x11654, 0x11654, (4, 1)),
+    (0x11655, 0x11655, (5, 1)),
+    (0x11656, 0x11656, (6, 1)),
+    (0x11657, 0x11657, (7, 1)),
+    (0x11658, 0x11658, (8, 1)),
+    (0x11659, 0x11659, (9, 1)),
+    (0x116C0, 0x116C0, (0, 1)),
+    (0x116C1, 0x116C1, (1, 1)),
+    (0x116C2, 0x116C2, (2, 1)),
+    (0x116C3, 0x116C3, (3, 1)),
+    (0x116C4, 0x116C4, (4, 1)),
+    (0x116C5, 0x116C5, (5, 1)),
+    (0x116C6, 0x116C6, (6, 1)),
+    (0x116C7, 0x116C7, (7, 1)),
+    (0x116C8, 0x116C8, (8, 1)),
+    (0x116C9, 0x116C9, (9, 1)),
+    (0x11730, 0x11730, (0, 1)),
+    (0x11731, 0x11731, (1, 1)),
+    (0x11732, 0x11732, (2, 1)),
+    (0x11733, 0x11733, (3, 1)),
+    (0x11734, 0x11734, (4, 1)),
+    (0x11735, 0x11735, (5, 1)),
+    (0x11736, 0x11736, (6, 1)),
+    (0x11737, 0x11737, (7, 1)),
+    (0x11738, 0x11738, (8, 1)),
+    (0x11739, 0x11739, (9, 1)),
+    (0x1173A, 0x1173A, (10, 1)),
+    (0x1173B, 0x1173B, (20, 1)),
+    (0x118E0, 0x118E0, (0, 1)),
+    (0x118E1, 0x118E1, (1, 1)),
+    (0x118E2, 0x118E2, (2, 1)),
+    (0x118E3, 0x118E3, (3, 1)),
+    (0x118E4, 0x118E4, (4, 1)),
+    (0x118E5, 0x118E5, (5, 1)),
+    (0x118E6, 0x118E6, (6, 1)),
+    (0x118E7, 0x118E7, (7, 1)),
+    (0x118E8, 0x118E8, (8, 1)),
+    (0x118E9, 0x118E9, (9, 1)),
+    (0x118EA, 0x118EA, (10, 1)),
+    (0x118EB, 0x118EB, (20, 1)),
+    (0x118EC, 0x118EC, (30, 1)),
+    (0x118ED, 0x118ED, (40, 1)),
+    (0x118EE, 0x118EE, (50, 1)),
+    (0x118EF, 0x118EF, (60, 1)),
+    (0x118F0, 0x118F0, (70, 1)),
+    (0x118F1, 0x118F1, (80, 1)),
+    (0x118F2, 0x118F2, (90, 1)),
+    (0x11950, 0x11950, (0, 1)),
+    (0x11951, 0x11951, (1, 1)),
+    (0x11952, 0x11952, (2, 1)),
+    (0x11953, 0x11953, (3, 1)),
+    (0x11954, 0x11954, (4, 1)),
+    (0x11955, 0x11955, (5, 1)),
+    (0x11956, 0x11956, (6, 1)),
+    (0x11957, 0x11957, (7, 1)),
+    (0x11958, 0x11958, (8, 1)),
+    (0x11959, 0x11959, (9, 1)),
+    (0x11C50, 0x11C50, (0, 1)),
+    (0x11C51, 0x11C51, (1, 1)),
+    (0x11C52, 0x11C52, (2, 1)),
+    (0x11C53, 0x11C53, (3, 1)),
+    (0x11C54, 0x11C54, (4, 1)),
+    (0x11C55, 0x11C55, (5, 1)),
+    (0x11C56, 0x11C56, (6, 1)),
+    (0x11C57, 0x11C57, (7, 1)),
+    (0x11C58, 0x11C58, (8, 1)),
+    (0x11C59, 0x11C59, (9, 1)),
+    (0x11C5A, 0x11C5A, (1, 1)),
+    (0x11C5B, 0x11C5B, (2, 1)),
+    (0x11C5C, 0x11C5C, (3, 1)),
+    (0x11C5D, 0x11C5D, (4, 1)),
+    (0x11C5E, 0x11C5E, (5, 1)),
+    (0x11C5F, 0x11C5F, (6, 1)),
+    (0x11C60, 0x11C60, (7, 1)),
+    (0x11C61, 0x11C61, (8, 1)),
+    (0x11C62, 0x11C62, (9, 1)),
+    (0x11C63, 0x11C63, (10, 1)),
+    (0x11C64, 0x11C64, (20, 1)),
+    (0x11C65, 0x11C65, (30, 1)),
+    (0x11C66, 0x11C66, (40, 1)),
+    (0x11C67, 0x11C67, (50, 1)),
+    (0x11C68, 0x11C68, (60, 1)),
+    (0x11C69, 0x11C69, (70, 1)),
+    (0x11C6A, 0x11C6A, (80, 1)),
+    (0x11C6B, 0x11C6B, (90, 1)),
+    (0x11C6C, 0x11C6C, (100, 1)),
+    (0x11D50, 0x11D50, (0, 1)),
+    (0x11D51, 0x11D51, (1, 1)),
+    (0x11D52, 0x11D52, (2, 1)),
+    (0x11D53, 0x11D53, (3, 1)),
+    (0x11D54, 0x11D54, (4, 1)),
+    (0x11D55, 0x11D55, (5, 1)),
+    (0x11D56, 0x11D56, (6, 1)),
+    (0x11D57, 0x11D57, (7, 1)),
+    (0x11D58, 0x11D58, (8, 1)),
+    (0x11D59, 0x11D59, (9, 1)),
+    (0x11DA0, 0x11DA0, (0, 1)),
+    (0x11DA1, 0x11DA1, (1, 1)),
+    (0x11DA2, 0x11DA2, (2, 1)),
+    (0x11DA3, 0x11DA3, (3, 1)),
+    (0x11DA4, 0x11DA4, (4, 1)),
+    (0x11DA5, 0x11DA5, (5, 1)),
+    (0x11DA6, 0x11DA6, (6, 1)),
+    (0x11DA7, 0x11DA7, (7, 1)),
+    (0x11DA8, 0x11DA8, (8, 1)),
+    (0x11DA9, 0x11DA9, (9, 1)),
+    (0x11FC0, 0x11FC0, (1, 320)),
+    (0x11FC1, 0x11FC1, (1, 160)),
+    (0x11FC2, 0x11FC2, (1, 80)),
+    (0x11FC3, 0x11FC3, (1, 64)),
+    (0x11FC4, 0x11FC4, (1, 40)),
+    (0x11FC5, 0x11FC5, (1, 32)),
+    (0x11FC6, 0x11FC6, (3, 80)),
+    (0x11FC7, 0x11FC7, (3, 64)),
+    (0x11FC8, 0x11FC8, (1, 20)),
+    (0x11FC9, 0x11FCA, (1, 16)),
+    (0x11FCB, 0x11FCB, (1, 10)),
+    (0x11FCC, 0x11FCC, (1, 8)),
+    (0x11FCD, 0x11FCD, (3, 20)),
+    (0x11FCE, 0x11FCE, (3, 16)),
+    (0x11FCF, 0x11FCF, (1, 5)),
+    (0x11FD0, 0x11FD0, (1, 4)),
+    (0x11FD1, 0x11FD2, (1, 2)),
+    (0x11FD3, 0x11FD3, (3, 4)),
+    (0x11FD4, 0x11FD4, (1, 320)),
+    (0x12400, 0x12400, (2, 1)),
+    (0x12401, 0x12401, (3, 1)),
+    (0x12402, 0x12402, (4, 1)),
+    (0x12403, 0x12403, (5, 1)),
+    (0x12404, 0x12404, (6, 1)),
+    (0x12405, 0x12405, (7, 1)),
+    (0x12406, 0x12406, (8, 1)),
+    (0x12407, 0x12407, (9, 1)),
+    (0x12408, 0x12408, (3, 1)),
+    (0x12409, 0x12409, (4, 1)),
+    (0x1240A, 0x1240A, (5, 1)),
+    (0x1240B, 0x1240B, (6, 1)),
+    (0x1240C, 0x1240C, (7, 1)),
+    (0x1240D, 0x1240D, (8, 1)),
+    (0x1240E, 0x1240E, (9, 1)),
+    (0x1240F, 0x1240F, (4, 1)),
+    (0x12410, 0x12410, (5, 1)),
+    (0x12411, 0x12411, (6, 1)),
+    (0x12412, 0x12412, (7, 1)),
+    (0x12413, 0x12413, (8, 1)),
+    (0x12414, 0x12414, (9, 1)),
+    (0x12415, 0x12415, (1, 1)),
+    (0x12416, 0x12416, (2, 1)),
+    (0x12417, 0x12417, (3, 1)),
+    (0x12418, 0x12418, (4, 1)),
+    (0x12419, 0x12419, (5, 1)),
+    (0x1241A, 0x1241A, (6, 1)),
+    (0x1241B, 0x1241B, (7, 1)),
+    (0x1241C, 0x1241C, (8, 1)),
+    (0x1241D, 0x1241D, (9, 1)),
+    (0x1241E, 0x1241E, (1, 1)),
+    (0x1241F, 0x1241F, (2, 1)),
+    (0x12420, 0x12420, (3, 1)),
+    (0x12421, 0x12421, (4, 1)),
+    (0x12422, 0x12422, (5, 1)),
+    (0x12423, 0x12423, (2, 1)),
+    (0x12424, 0x12425, (3, 1)),
+    (0x12426, 0x12426, (4, 1)),
+    (0x12427, 0x12427, (5, 1)),
+    (0x12428, 0x12428, (6, 1)),
+    (0x12429, 0x12429, (7, 1)),
+    (0x1242A, 0x1242A, (8, 1)),
+    (0x1242B, 0x1242B, (9, 1)),
+    (0x1242C, 0x1242C, (1, 1)),
+    (0x1242D, 0x1242D, (2, 1)),
+    (0x1242E, 0x1242F, (3, 1)),
+    (0x12430, 0x12430, (4, 1)),
+    (0x12431, 0x12431, (5, 1)),
+    (0x12432, 0x12432, (216000, 1)),
+    (0x12433, 0x12433, (432000, 1)),
+    (0x12434, 0x12434, (1, 1)),
+    (0x12435, 0x12435, (2, 1)),
+    (0x12436, 0x12437, (3, 1)),
+    (0x12438, 0x12438, (4, 1)),
+    (0x12439, 0x12439, (5, 1)),
+    (0x1243A, 0x1243B, (3, 1)),
+    (0x1243C, 0x1243F, (4, 1)),
+    (0x12440, 0x12440, (6, 1)),
+    (0x12441, 0x12443, (7, 1)),
+    (0x12444, 0x12445, (8, 1)),
+    (0x12446, 0x12449, (9, 1)),
+    (0x1244A, 0x1244A, (2, 1)),
+    (0x1244B, 0x1244B, (3, 1)),
+    (0x1244C, 0x1244C, (4, 1)),
+    (0x1244D, 0x1244D, (5, 1)),
+    (0x1244E, 0x1244E, (6, 1)),
+    (0x1244F, 0x1244F, (1, 1)),
+    (0x12450, 0x12450, (2, 1)),
+    (0x12451, 0x12451, (3, 1)),
+    (0x12452, 0x12453, (4, 1)),
+    (0x12454, 0x12455, (5, 1)),
+    (0x12456, 0x12456, (2, 1)),
+    (0x12457, 0x12457, (3, 1)),
+    (0x12458, 0x12458, (1, 1)),
+    (0x12459, 0x12459, (2, 1)),
+    (0x1245A, 0x1245A, (1, 3)),
+    (0x1245B, 0x1245B, (2, 3)),
+    (0x1245C, 0x1245C, (5, 6)),
+    (0x1245D, 0x1245D, (1, 3)),
+    (0x1245E, 0x1245E, (2, 3)),
+    (0x1245F, 0x1245F, (1, 8)),
+    (0x12460, 0x12460, (1, 4)),
+    (0x12461, 0x12461, (1, 6)),
+    (0x12462, 0x12463, (1, 4)),
+    (0x12464, 0x12464, (1, 2)),
+    (0x12465, 0x12465, (1, 3)),
+    (0x12466, 0x12466, (2, 3)),
+    (0x12467, 0x12467, (40, 1)),
+    (0x12468, 0x12468, (50, 1)),
+    (0x12469, 0x12469, (4, 1)),
+    (0x1246A, 0x1246A, (5, 1)),
+    (0x1246B, 0x1246B, (6, 1)),
+    (0x1246C, 0x1246C, (7, 1)),
+    (0x1246D, 0x1246D, (8, 1)),
+    (0x1246E, 0x1246E, (9, 1)),
+    (0x16A60, 0x16A60, (0, 1)),
+    (0x16A61, 0x16A61, (1, 1)),
+    (0x16A62, 0x16A62, (2, 1)),
+    (0x16A63, 0x16A63, (3, 1)),
+    (0x16A64, 0x16A64, (4, 1)),
+    (0x16A65, 0x16A65, (5, 1)),
+    (0x16A66, 0x16A66, (6, 1)),
+    (0x16A67, 0x16A67, (7, 1)),
+    (0x16A68, 0x16A68, (8, 1)),
+    (0x16A69, 0x16A69, (9, 1)),
+    (0x16B50, 0x16B50, (0, 1)),
+    (0x16B51, 0x16B51, (1, 1)),
+    (0x16B52, 0x16B52, (2, 1)),
+    (0x16B53, 0x16B53, (3, 1)),
+    (0x16B54, 0x16B54, (4, 1)),
+    (0x16B55, 0x16B55, (5, 1)),
+    (0x16B56, 0x16B56, (6, 1)),
+    (0x16B57, 0x16B57, (7, 1)),
+    (0x16B58, 0x16B58, (8, 1)),
+    (0x16B59, 0x16B59, (9, 1)),
+    (0x16B5B, 0x16B5B, (10, 1)),
+    (0x16B5C, 0x16B5C, (100, 1)),
+    (0x16B5D, 0x16B5D, (10000, 1)),
+    (0x16B5E, 0x16B5E, (1000000, 1)),
+    (0x16B5F, 0x16B5F, (100000000, 1)),
+    (0x16B60, 0x16B60, (10000000000, 1)),
+    (0x16B61, 0x16B61, (1000000000000, 1)),
+    (0x16E80, 0x16E80, (0, 1)),
+    (0x16E81, 0x16E81, (1, 1)),
+    (0x16E82, 0x16E82, (2, 1)),
+    (0x16E83, 0x16E83, (3, 1)),
+    (0x16E84, 0x16E84, (4, 1)),
+    (0x16E85, 0x16E85, (5, 1)),
+    (0x16E86, 0x16E86, (6, 1)),
+    (0x16E87, 0x16E87, (7, 1)),
+    (0x16E88, 0x16E88, (8, 1)),
+    (0x16E89, 0x16E89, (9, 1)),
+    (0x16E8A, 0x16E8A, (10, 1)),
+    (0x16E8B, 0x16E8B, (11, 1)),
+    (0x16E8C, 0x16E8C, (12, 1)),
+    (0x16E8D, 0x16E8D, (13, 1)),
+    (0x16E8E, 0x16E8E, (14, 1)),
+    (0x16E8F, 0x16E8F, (15, 1)),
+    (0x16E90, 0x16E90, (16, 1)),
+    (0x16E91, 0x16E91, (17, 1)),
+    (0x16E92, 0x16E92, (18, 1)),
+    (0x16E93, 0x16E93, (19, 1)),
+    (0x16E94, 0x16E94, (1, 1)),
+    (0x16E95, 0x16E95, (2, 1)),
+    (0x16E96, 0x16E96, (3, 1)),
+    (0x1D2E0, 0x1D2E0, (0, 1)),
+    (0x1D2E1, 0x1D2E1, (1, 1)),
+    (0x1D2E2, 0x1D2E2, (2, 1)),
+    (0x1D2E3, 0x1D2E3, (3, 1)),
+    (0x1D2E4, 0x1D2E4, (4, 1)),
+    (0x1D2E5, 0x1D2E5, (5, 1)),
+    (0x1D2E6, 0x1D2E6, (6, 1)),
+    (0x1D2E7, 0x1D2E7, (7, 1)),
+    (0x1D2E8, 0x1D2E8, (8, 1)),
+    (0x1D2E9, 0x1D2E9, (9, 1)),
+    (0x1D2EA, 0x1D2EA, (10, 1)),
+    (0x1D2EB, 0x1D2EB, (11, 1)),
+    (0x1D2EC, 0x1D2EC, (12, 1)),
+    (0x1D2ED, 0x1D2ED, (13, 1)),
+    (0x1D2EE, 0x1D2EE, (14, 1)),
+    (0x1D2EF, 0x1D2EF, (15, 1)),
+    (0x1D2F0, 0x1D2F0, (16, 1)),
+    (0x1D2F1, 0x1D2F1, (17, 1)),
+    (0x1D2F2, 0x1D2F2, (18, 1)),
+    (0x1D2F3, 0x1D2F3, (19, 1)),
+    (0x1D360, 0x1D360, (1, 1)),
+    (0x1D361, 0x1D361, (2, 1)),
+    (0x1D362, 0x1D362, (3, 1)),
+    (0x1D363, 0x1D363, (4, 1)),
+    (0x1D364, 0x1D364, (5, 1)),
+    (0x1D365, 0x1D365, (6, 1)),
+    (0x1D366, 0x1D366, (7, 1)),
+    (0x1D367, 0x1D367, (8, 1)),
+    (0x1D368, 0x1D368, (9, 1)),
+    (0x1D369, 0x1D369, (10, 1)),
+    (0x1D36A, 0x1D36A, (20, 1)),
+    (0x1D36B, 0x1D36B, (30, 1)),
+    (0x1D36C, 0x1D36C, (40, 1)),
+    (0x1D36D, 0x1D36D, (50, 1)),
+    (0x1D36E, 0x1D36E, (60, 1)),
+    (0x1D36F, 0x1D36F, (70, 1)),
+    (0x1D370, 0x1D370, (80, 1)),
+    (0x1D371, 0x1D371, (90, 1)),
+    (0x1D372, 0x1D372, (1, 1)),
+    (0x1D373, 0x1D373, (2, 1)),
+    (0x1D374, 0x1D374, (3, 1)),
+    (0x1D375, 0x1D375, (4, 1)),
+    (0x1D376, 0x1D376, (5, 1)),
+    (0x1D377, 0x1D377, (1, 1)),
+    (0x1D378, 0x1D378, (5, 1)),
+    (0x1D7CE, 0x1D7CE, (0, 1)),
+    (0x1D7CF, 0x1D7CF, (1, 1)),
+    (0x1D7D0, 0x1D7D0, (2, 1)),
+    (0x1D7D1, 0x1D7D1, (3, 1)),
+    (0x1D7D2, 0x1D7D2, (4, 1)),
+    (0x1D7D3, 0x1D7D3, (5, 1)),
+    (0x1D7D4, 0x1D7D4, (6, 1)),
+    (0x1D7D5, 0x1D7D5, (7, 1)),
+    (0x1D7D6, 0x1D7D6, (8, 1)),
+    (0x1D7D7, 0x1D7D7, (9, 1)),
+    (0x1D7D8, 0x1D7D8, (0, 1)),
+    (0x1D7D9, 0x1D7D9, (1, 1)),
+    (0x1D7DA, 0x1D7DA, (2, 1)),
+    (0x1D7DB, 0x1D7DB, (3, 1)),
+    (0x1D7DC, 0x1D7DC, (4, 1)),
+    (0x1D7DD, 0x1D7DD, (5, 1)),
+    (0x1D7DE, 0x1D7DE, (6, 1)),
+    (0x1D7DF, 0x1D7DF, (7, 1)),
+    (0x1D7E0, 0x1D7E0, (8, 1)),
+    (0x1D7E1, 0x1D7E1, (9, 1)),
+    (0x1D7E2, 0x1D7E2, (0, 1)),
+    (0x1D7E3, 0x1D7E3, (1, 1)),
+    (0x1D7E4, 0x1D7E4, (2, 1)),
+    (0x1D7E5, 0x1D7E5, (3, 1)),
+    (0x1D7E6, 0x1D7E6, (4, 1)),
+    (0x1D7E7, 0x1D7E7, (5, 1)),
+    (0x1D7E8, 0x1D7E8, (6, 1)),
+    (0x1D7E9, 0x1D7E9, (7, 1)),
+    (0x1D7EA, 0x1D7EA, (8, 1)),
+    (0x1D7EB, 0x1D7EB, (9, 1)),
+    (0x1D7EC, 0x1D7EC, (0, 1)),
+    (0x1D7ED, 0x1D7ED, (1, 1)),
+    (0x1D7EE, 0x1D7EE, (2, 1)),
+    (0x1D7EF, 0x1D7EF, (3, 1)),
+    (0x1D7F0, 0x1D7F0, (4, 1)),
+    (0x1D7F1, 0x1D7F1, (5, 1)),
+    (0x1D7F2, 0x1D7F2, (6, 1)),
+    (0x1D7F3, 0x1D7F3, (7, 1)),
+    (0x1D7F4, 0x1D7F4, (8, 1)),
+    (0x1D7F5, 0x1D7F5, (9, 1)),
+    (0x1D7F6, 0x1D7F6, (0, 1)),
+    (0x1D7F7, 0x1D7F7, (1, 1)),
+    (0x1D7F8, 0x1D7F8, (2, 1)),
+    (0x1D7F9, 0x1D7F9, (3, 1)),
+    (0x1D7FA, 0x1D7FA, (4, 1)),
+    (0x1D7FB, 0x1D7FB, (5, 1)),
+    (0x1D7FC, 0x1D7FC, (6, 1)),
+    (0x1D7FD, 0x1D7FD, (7, 1)),
+    (0x1D7FE, 0x1D7FE, (8, 1)),
+    (0x1D7FF, 0x1D7FF, (9, 1)),
+    (0x1E140, 0x1E140, (0, 1)),
+    (0x1E141, 0x1E141, (1, 1)),
+    (0x1E142, 0x1E142, (2, 1)),
+    (0x1E143, 0x1E143, (3, 1)),
+    (0x1E144, 0x1E144, (4, 1)),
+    (0x1E145, 0x1E145, (5, 1)),
+    (0x1E146, 0x1E146, (6, 1)),
+    (0x1E147, 0x1E147, (7, 1)),
+    (0x1E148, 0x1E148, (8, 1)),
+    (0x1E149, 0x1E149, (9, 1)),
+    (0x1E2F0, 0x1E2F0, (0, 1)),
+    (0x1E2F1, 0x1E2F1, (1, 1)),
+    (0x1E2F2, 0x1E2F2, (2, 1)),
+    (0x1E2F3, 0x1E2F3, (3, 1)),
+    (0x1E2F4, 0x1E2F4, (4, 1)),
+    (0x1E2F5, 0x1E2F5, (5, 1)),
+    (0x1E2F6, 0x1E2F6, (6, 1)),
+    (0x1E2F7, 0x1E2F7, (7, 1)),
+    (0x1E2F8, 0x1E2F8, (8, 1)),
+    (0x1E2F9, 0x1E2F9, (9, 1)),
+    (0x1E8C7, 0x1E8C7, (1, 1)),
+    (0x1E8C8, 0x1E8C8, (2, 1)),
+    (0x1E8C9, 0x1E8C9, (3, 1)),
+    (0x1E8CA, 0x1E8CA, (4, 1)),
+    (0x1E8CB, 0x1E8CB, (5, 1)),
+    (0x1E8CC, 0x1E8CC, (6, 1)),
+    (0x1E8CD, 0x1E8CD, (7, 1)),
+    (0x1E8CE, 0x1E8CE, (8, 1)),
+    (0x1E8CF, 0x1E8CF, (9, 1)),
+    (0x1E950, 0x1E950, (0, 1)),
+    (0x1E951, 0x1E951, (1, 1)),
+    (0x1E952, 0x1E952, (2, 1)),
+    (0x1E953, 0x1E953, (3, 1)),
+    (0x1E954, 0x1E954, (4, 1)),
+    (0x1E955, 0x1E955, (5, 1)),
+    (0x1E956, 0x1E956, (6, 1)),
+    (0x1E957, 0x1E957, (7, 1)),
+    (0x1E958, 0x1E958, (8, 1)),
+    (0x1E959, 0x1E959, (9, 1)),
+    (0x1EC71, 0x1EC71, (1, 1)),
+    (0x1EC72, 0x1EC72, (2, 1)),
+    (0x1EC73, 0x1EC73, (3, 1)),
+    (0x1EC74, 0x1EC74, (4, 1)),
+    (0x1EC75, 0x1EC75, (5, 1)),
+    (0x1EC76, 0x1EC76, (6, 1)),
+    (0x1EC77, 0x1EC77, (7, 1)),
+    (0x1EC78, 0x1EC78, (8, 1)),
+    (0x1EC79, 0x1EC79, (9, 1)),
+    (0x1EC7A, 0x1EC7A, (10, 1)),
+    (0x1EC7B, 0x1EC7B, (20, 1)),
+    (0x1EC7C, 0x1EC7C, (30, 1)),
+    (0x1EC7D, 0x1EC7D, (40, 1)),
+    (0x1EC7E, 0x1EC7E, (50, 1)),
+    (0x1EC7F, 0x1EC7F, (60, 1)),
+    (0x1EC80, 0x1EC80, (70, 1)),
+    (0x1EC81, 0x1EC81, (80, 1)),
+    (0x1EC82, 0x1EC82, (90, 1)),
+    (0x1EC83, 0x1EC83, (100, 1)),
+    (0x1EC84, 0x1EC84, (200, 1)),
+    (0x1EC85, 0x1EC85, (300, 1)),
+    (0x1EC86, 0x1EC86, (400, 1)),
+    (0x1EC87, 0x1EC87, (500, 1)),
+    (0x1EC88, 0x1EC88, (600, 1)),
+    (0x1EC89, 0x1EC89, (700, 1)),
+    (0x1EC8A, 0x1EC8A, (800, 1)),
+    (0x1EC8B, 0x1EC8B, (900, 1)),
+    (0x1EC8C, 0x1EC8C, (1000, 1)),
+    (0x1EC8D, 0x1EC8D, (2000, 1)),
+    (0x1EC8E, 0x1EC8E, (3000, 1)),
+    (0x1EC8F, 0x1EC8F, (4000, 1)),
+    (0x1EC90, 0x1EC90, (5000, 1)),
+    (0x1EC91, 0x1EC91, (6000, 1)),
+    (0x1EC92, 0x1EC92, (7000, 1)),
+    (0x1EC93, 0x1EC93, (8000, 1)),
+    (0x1EC94, 0x1EC94, (9000, 1)),
+    (0x1EC95, 0x1EC95, (10000, 1)),
+    (0x1EC96, 0x1EC96, (20000, 1)),
+    (0x1EC97, 0x1EC97, (30000, 1)),
+    (0x1EC98, 0x1EC98, (40000, 1)),
+    (0x1EC99, 0x1EC99, (50000, 1)),
+    (0x1EC9A, 0x1EC9A, (60000, 1)),
+    (0x1EC9B, 0x1EC9B, (70000, 1)),
+    (0x1EC9C, 0x1EC9C, (80000, 1)),
+    (0x1EC9D, 0x1EC9D, (90000, 1)),
+    (0x1EC9E, 0x1EC9E, (100000, 1)),
+    (0x1EC9F, 0x1EC9F, (200000, 1)),
+    (0x1ECA0, 0x1ECA0, (100000, 1)),
+    (0x1ECA1, 0x1ECA1, (10000000, 1)),
+    (0x1ECA2, 0x1ECA2, (20000000, 1)),
+    (0x1ECA3, 0x1ECA3, (1, 1)),
+    (0x1ECA4, 0x1ECA4, (2, 1)),
+    (0x1ECA5, 0x1ECA5, (3, 1)),
+    (0x1ECA6, 0x1ECA6, (4, 1)),
+    (0x1ECA7, 0x1ECA7, (5, 1)),
+    (0x1ECA8, 0x1ECA8, (6, 1)),
+    (0x1ECA9, 0x1ECA9, (7, 1)),
+    (0x1ECAA, 0x1ECAA, (8, 1)),
+    (0x1ECAB, 0x1ECAB, (9, 1)),
+    (0x1ECAD, 0x1ECAD, (1, 4)),
+    (0x1ECAE, 0x1ECAE, (1, 2)),
+    (0x1ECAF, 0x1ECAF, (3, 4)),
+    (0x1ECB1, 0x1ECB1, (1, 1)),
+    (0x1ECB2, 0x1ECB2, (2, 1)),
+    (0x1ECB3, 0x1ECB3, (10000, 1)),
+    (0x1ECB4, 0x1ECB4, (100000, 1)),
+    (0x1ED01, 0x1ED01, (1, 1)),
+    (0x1ED02, 0x1ED02, (2, 1)),
+    (0x1ED03, 0x1ED03, (3, 1)),
+    (0x1ED04, 0x1ED04, (4, 1)),
+    (0x1ED05, 0x1ED05, (5, 1)),
+    (0x1ED06, 0x1ED06, (6, 1)),
+    (0x1ED07, 0x1ED07, (7, 1)),
+    (0x1ED08, 0x1ED08, (8, 1)),
+    (0x1ED09, 0x1ED09, (9, 1)),
+    (0x1ED0A, 0x1ED0A, (10, 1)),
+    (0x1ED0B, 0x1ED0B, (20, 1)),
+    (0x1ED0C, 0x1ED0C, (30, 1)),
+    (0x1ED0D, 0x1ED0D, (40, 1)),
+    (0x1ED0E, 0x1ED0E, (50, 1)),
+    (0x1ED0F, 0x1ED0F, (60, 1)),
+    (0x1ED10, 0x1ED10, (70, 1)),
+    (0x1ED11, 0x1ED11, (80, 1)),
+    (0x1ED12, 0x1ED12, (90, 1)),
+    (0x1ED13, 0x1ED13, (100, 1)),
+    (0x1ED14, 0x1ED14, (200, 1)),
+    (0x1ED15, 0x1ED15, (300, 1)),
+    (0x1ED16, 0x1ED16, (400, 1)),
+    (0x1ED17, 0x1ED17, (500, 1)),
+    (0x1ED18, 0x1ED18, (600, 1)),
+    (0x1ED19, 0x1ED19, (700, 1)),
+    (0x1ED1A, 0x1ED1A, (800, 1)),
+    (0x1ED1B, 0x1ED1B, (900, 1)),
+    (0x1ED1C, 0x1ED1C, (1000, 1)),
+    (0x1ED1D, 0x1ED1D, (2000, 1)),
+    (0x1ED1E, 0x1ED1E, (3000, 1)),
+    (0x1ED1F, 0x1ED1F, (4000, 1)),
+    (0x1ED20, 0x1ED20, (5000, 1)),
+    (0x1ED21, 0x1ED21, (6000, 1)),
+    (0x1ED22, 0x1ED22, (7000, 1)),
+    (0x1ED23, 0x1ED23, (8000, 1)),
+    (0x1ED24, 0x1ED24, (9000, 1)),
+    (0x1ED25, 0x1ED25, (10000, 1)),
+    (0x1ED26, 0x1ED26, (20000, 1)),
+    (0x1ED27, 0x1ED27, (30000, 1)),
+    (0x1ED28, 0x1ED28, (40000, 1)),
+    (0x1ED29, 0x1ED29, (50000, 1)),
+    (0x1ED2A, 0x1ED2A, (60000, 1)),
+    (0x1ED2B, 0x1ED2B, (70000, 1)),
+    (0x1ED2C, 0x1ED2C, (80000, 1)),
+    (0x1ED2D, 0x1ED2D, (90000, 1)),
+    (0x1ED2F, 0x1ED2F, (2, 1)),
+    (0x1ED30, 0x1ED30, (3, 1)),
+    (0x1ED31, 0x1ED31, (4, 1)),
+    (0x1ED32, 0x1ED32, (5, 1)),
+    (0x1ED33, 0x1ED33, (6, 1)),
+    (0x1ED34, 0x1ED34, (7, 1)),
+    (0x1ED35, 0x1ED35, (8, 1)),
+    (0x1ED36, 0x1ED36, (9, 1)),
+    (0x1ED37, 0x1ED37, (10, 1)),
+    (0x1ED38, 0x1ED38, (400, 1)),
+    (0x1ED39, 0x1ED39, (600, 1)),
+    (0x1ED3A, 0x1ED3A, (2000, 1)),
+    (0x1ED3B, 0x1ED3B, (10000, 1)),
+    (0x1ED3C, 0x1ED3C, (1, 2)),
+    (0x1ED3D, 0x1ED3D, (1, 6)),
+    (0x1F100, 0x1F101, (0, 1)),
+    (0x1F102, 0x1F102, (1, 1)),
+    (0x1F103, 0x1F103, (2, 1)),
+    (0x1F104, 0x1F104, (3, 1)),
+    (0x1F105, 0x1F105, (4, 1)),
+    (0x1F106, 0x1F106, (5, 1)),
+    (0x1F107, 0x1F107, (6, 1)),
+    (0x1F108, 0x1F108, (7, 1)),
+    (0x1F109, 0x1F109, (8, 1)),
+    (0x1F10A, 0x1F10A, (9, 1)),
+    (0x1F10B, 0x1F10C, (0, 1)),
+    (0x1FBF0, 0x1FBF0, (0, 1)),
+    (0x1FBF1, 0x1FBF1, (1, 1)),
+    (0x1FBF2, 0x1FBF2, (2, 1)),
+    (0x1FBF3, 0x1FBF3, (3, 1)),
+    (0x1FBF4, 0x1FBF4, (4, 1)),
+    (0x1FBF5, 0x1FBF5, (5, 1)),
+    (0x1FBF6, 0x1FBF6, (6, 1)),
+    (0x1FBF7, 0x1FBF7, (7, 1)),
+    (0x1FBF8, 0x1FBF8, (8, 1)),
+    (0x1FBF9, 0x1FBF9, (9, 1)),
+    (0x20001, 0x20001, (7, 1)),
+    (0x20064, 0x20064, (4, 1)),
+    (0x200E2, 0x200E2, (4, 1)),
+    (0x20121, 0x20121, (5, 1)),
+    (0x2092A, 0x2092A, (1, 1)),
+    (0x20983, 0x20983, (30, 1)),
+    (0x2098C, 0x2098C, (40, 1)),
+    (0x2099C, 0x2099C, (40, 1)),
+    (0x20AEA, 0x20AEA, (6, 1)),
+    (0x20AFD, 0x20AFD, (3, 1)),
+    (0x20B19, 0x20B19, (3, 1)),
+    (0x22390, 0x22390, (2, 1)),
+    (0x22998, 0x22998, (3, 1)),
+    (0x23B1B, 0x23B1B, (3, 1)),
+    (0x2626D, 0x2626D, (4, 1)),
+    (0x2F890, 0x2F890, (9, 1)),
+];
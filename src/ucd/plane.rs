@@ -49,9 +49,14 @@ impl Plane {
     }
 
     pub fn of(chr: char) -> Self {
+        Self::of_code_point(chr as u32)
+    }
+
+    /// Same as `of`, but also works with surrogate code points.
+    pub fn of_code_point(code_point: u32) -> Self {
         assert_eq!(PLANE_NAMES.len(), PLANE_COUNT as usize);
 
-        let plane_index = chr as u32 / PLANE_SIZE;
+        let plane_index = code_point / PLANE_SIZE;
         Self::at(plane_index as usize)
     }
 
@@ -0,0 +1,251 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! The Script and Script_Extensions properties, which are not yet provided by `unic::ucd`.
+//!
+
+mod tables;
+
+use std::slice;
+
+use super::find_range;
+
+enumerated_property! {
+    /// Values of the Script property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    #[allow(clippy::enum_variant_names)] // For Khitan_Small_Script
+    pub enum Script {
+        abbr => "sc",
+        long => "Script",
+        human => "Script",
+        default => Unknown,
+        values => {
+            Adlam,
+            CaucasianAlbanian,
+            Ahom,
+            Arabic,
+            ImperialAramaic,
+            Armenian,
+            Avestan,
+            Balinese,
+            Bamum,
+            BassaVah,
+            Batak,
+            Bengali,
+            Bhaiksuki,
+            Bopomofo,
+            Brahmi,
+            Braille,
+            Buginese,
+            Buhid,
+            Chakma,
+            CanadianAboriginal,
+            Carian,
+            Cham,
+            Cherokee,
+            Chorasmian,
+            Coptic,
+            Cypriot,
+            Cyrillic,
+            Devanagari,
+            DivesAkuru,
+            Dogra,
+            Deseret,
+            Duployan,
+            EgyptianHieroglyphs,
+            Elbasan,
+            Elymaic,
+            Ethiopic,
+            Georgian,
+            Glagolitic,
+            GunjalaGondi,
+            MasaramGondi,
+            Gothic,
+            Grantha,
+            Greek,
+            Gujarati,
+            Gurmukhi,
+            Hangul,
+            Han,
+            Hanunoo,
+            Hatran,
+            Hebrew,
+            Hiragana,
+            AnatolianHieroglyphs,
+            PahawhHmong,
+            NyiakengPuachueHmong,
+            OldHungarian,
+            OldItalic,
+            Javanese,
+            KayahLi,
+            Katakana,
+            Kharoshthi,
+            Khmer,
+            Khojki,
+            KhitanSmallScript,
+            Kannada,
+            Kaithi,
+            TaiTham,
+            Lao,
+            Latin,
+            Lepcha,
+            Limbu,
+            LinearA,
+            LinearB,
+            Lisu,
+            Lycian,
+            Lydian,
+            Mahajani,
+            Makasar,
+            Mandaic,
+            Manichaean,
+            Marchen,
+            Medefaidrin,
+            MendeKikakui,
+            MeroiticCursive,
+            MeroiticHieroglyphs,
+            Malayalam,
+            Modi,
+            Mongolian,
+            Mro,
+            MeeteiMayek,
+            Multani,
+            Myanmar,
+            Nandinagari,
+            OldNorthArabian,
+            Nabataean,
+            Newa,
+            Nko,
+            Nushu,
+            Ogham,
+            OlChiki,
+            OldTurkic,
+            Oriya,
+            Osage,
+            Osmanya,
+            Palmyrene,
+            PauCinHau,
+            OldPermic,
+            PhagsPa,
+            InscriptionalPahlavi,
+            PsalterPahlavi,
+            Phoenician,
+            Miao,
+            InscriptionalParthian,
+            Rejang,
+            HanifiRohingya,
+            Runic,
+            Samaritan,
+            OldSouthArabian,
+            Saurashtra,
+            SignWriting,
+            Shavian,
+            Sharada,
+            Siddham,
+            Khudawadi,
+            Sinhala,
+            Sogdian,
+            OldSogdian,
+            SoraSompeng,
+            Soyombo,
+            Sundanese,
+            SylotiNagri,
+            Syriac,
+            Tagbanwa,
+            Takri,
+            TaiLe,
+            NewTaiLue,
+            Tamil,
+            Tangut,
+            TaiViet,
+            Telugu,
+            Tifinagh,
+            Tagalog,
+            Thaana,
+            Thai,
+            Tibetan,
+            Tirhuta,
+            Ugaritic,
+            Vai,
+            WarangCiti,
+            Wancho,
+            OldPersian,
+            Cuneiform,
+            Yezidi,
+            Yi,
+            ZanabazarSquare,
+            Inherited,
+            Common,
+            Unknown,
+        }
+    }
+}
+
+impl Script {
+    /// The scripts `chr` is used with, which are the same as `Script::of(chr)` for most characters
+    /// but e.g. are Arabic and Syriac for U+064B ARABIC FATHATAN rather than Inherited.
+    pub fn extensions_of(chr: char) -> &'static [Script] {
+        match find_range(tables::EXTENSIONS_RANGES, chr) {
+            Some((_, _, scripts)) => scripts,
+            None => match find_range(tables::RANGES, chr) {
+                Some((_, _, script)) => slice::from_ref(script),
+                None => &[Script::Unknown],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unic::char::property::EnumeratedCharProperty;
+
+    use super::*;
+
+    #[test]
+    fn test_script_names() {
+        assert_eq!(Script::Latin.abbr_name(), "Latn");
+        assert_eq!(Script::OldItalic.long_name(), "Old_Italic");
+        assert_eq!(Script::OldItalic.human_name(), "Old Italic");
+    }
+
+    #[test]
+    fn test_script_of() {
+        assert_eq!(Script::of('a'), Script::Latin);
+        assert_eq!(Script::of('α'), Script::Greek);
+        assert_eq!(Script::of(' '), Script::Common);
+        assert_eq!(Script::of('\u{0301}'), Script::Inherited);
+        assert_eq!(Script::of('中'), Script::Han);
+        assert_eq!(Script::of('\u{0378}'), Script::Unknown);
+        assert_eq!(Script::of('\u{10FFFF}'), Script::Unknown);
+    }
+
+    #[test]
+    fn test_extensions_of() {
+        assert_eq!(Script::extensions_of('a'), &[Script::Latin]);
+        assert_eq!(Script::extensions_of('\u{0378}'), &[Script::Unknown]);
+        assert_eq!(
+            Script::extensions_of('\u{3001}'),
+            &[
+                Script::Bopomofo,
+                Script::Hangul,
+                Script::Han,
+                Script::Hiragana,
+                Script::Katakana,
+                Script::Yi
+            ]
+        );
+        assert!(Script::extensions_of('\u{064B}').contains(&Script::Syriac));
+    }
+}
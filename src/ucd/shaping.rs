@@ -0,0 +1,384 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Properties used for shaping text, e.g. joining Arabic letters and reordering Indic syllables,
+//! which are not yet provided by `unic::ucd`.
+//!
+
+mod hangul_syllable_type;
+mod indic_positional_category;
+mod indic_syllabic_category;
+mod joining_group;
+mod joining_type;
+mod vertical_orientation;
+
+enumerated_property! {
+    /// Values of the Joining_Type property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    pub enum JoiningType {
+        abbr => "jt",
+        long => "Joining_Type",
+        human => "Joining Type",
+        default => NonJoining,
+        tables => joining_type,
+        values => {
+            JoinCausing,
+            DualJoining,
+            LeftJoining,
+            RightJoining,
+            Transparent,
+            NonJoining,
+        }
+    }
+}
+
+enumerated_property! {
+    /// Values of the Joining_Group property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    #[allow(clippy::enum_variant_names)] // For No_Joining_Group
+    pub enum JoiningGroup {
+        abbr => "jg",
+        long => "Joining_Group",
+        human => "Joining Group",
+        default => NoJoiningGroup,
+        tables => joining_group,
+        values => {
+            AfricanFeh,
+            AfricanNoon,
+            AfricanQaf,
+            Ain,
+            Alaph,
+            Alef,
+            Beh,
+            Beth,
+            BurushaskiYehBarree,
+            Dal,
+            DalathRish,
+            E,
+            FarsiYeh,
+            Fe,
+            Feh,
+            FinalSemkath,
+            Gaf,
+            Gamal,
+            Hah,
+            HanifiRohingyaKinnaYa,
+            HanifiRohingyaPa,
+            He,
+            Heh,
+            HehGoal,
+            Heth,
+            Kaf,
+            Kaph,
+            Khaph,
+            KnottedHeh,
+            Lam,
+            Lamadh,
+            MalayalamBha,
+            MalayalamJa,
+            MalayalamLla,
+            MalayalamLlla,
+            MalayalamNga,
+            MalayalamNna,
+            MalayalamNnna,
+            MalayalamNya,
+            MalayalamRa,
+            MalayalamSsa,
+            MalayalamTta,
+            ManichaeanAleph,
+            ManichaeanAyin,
+            ManichaeanBeth,
+            ManichaeanDaleth,
+            ManichaeanDhamedh,
+            ManichaeanFive,
+            ManichaeanGimel,
+            ManichaeanHeth,
+            ManichaeanHundred,
+            ManichaeanKaph,
+            ManichaeanLamedh,
+            ManichaeanMem,
+            ManichaeanNun,
+            ManichaeanOne,
+            ManichaeanPe,
+            ManichaeanQoph,
+            ManichaeanResh,
+            ManichaeanSadhe,
+            ManichaeanSamekh,
+            ManichaeanTaw,
+            ManichaeanTen,
+            ManichaeanTeth,
+            ManichaeanThamedh,
+            ManichaeanTwenty,
+            ManichaeanWaw,
+            ManichaeanYodh,
+            ManichaeanZayin,
+            Meem,
+            Mim,
+            NoJoiningGroup,
+            Noon,
+            Nun,
+            Nya,
+            Pe,
+            Qaf,
+            Qaph,
+            Reh,
+            ReversedPe,
+            RohingyaYeh,
+            Sad,
+            Sadhe,
+            Seen,
+            Semkath,
+            Shin,
+            StraightWaw,
+            SwashKaf,
+            SyriacWaw,
+            Tah,
+            Taw,
+            TehMarbuta,
+            HamzaOnHehGoal,
+            Teth,
+            Waw,
+            Yeh,
+            YehBarree,
+            YehWithTail,
+            Yudh,
+            YudhHe,
+            Zain,
+            Zhain,
+        }
+    }
+}
+
+enumerated_property! {
+    /// Values of the Hangul_Syllable_Type property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    pub enum HangulSyllableType {
+        abbr => "hst",
+        long => "Hangul_Syllable_Type",
+        human => "Hangul Syllable Type",
+        default => NotApplicable,
+        tables => hangul_syllable_type,
+        values => {
+            LeadingJamo,
+            LVSyllable,
+            LVTSyllable,
+            NotApplicable,
+            TrailingJamo,
+            VowelJamo,
+        }
+    }
+}
+
+enumerated_property! {
+    /// Values of the Indic_Syllabic_Category property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    pub enum IndicSyllabicCategory {
+        abbr => "InSC",
+        long => "Indic_Syllabic_Category",
+        human => "Indic Syllabic Category",
+        default => Other,
+        tables => indic_syllabic_category,
+        values => {
+            Avagraha,
+            Bindu,
+            BrahmiJoiningNumber,
+            CantillationMark,
+            Consonant,
+            ConsonantDead,
+            ConsonantFinal,
+            ConsonantHeadLetter,
+            ConsonantInitialPostfixed,
+            ConsonantKiller,
+            ConsonantMedial,
+            ConsonantPlaceholder,
+            ConsonantPrecedingRepha,
+            ConsonantPrefixed,
+            ConsonantSubjoined,
+            ConsonantSucceedingRepha,
+            ConsonantWithStacker,
+            GeminationMark,
+            InvisibleStacker,
+            Joiner,
+            ModifyingLetter,
+            NonJoiner,
+            Nukta,
+            Number,
+            NumberJoiner,
+            Other,
+            PureKiller,
+            RegisterShifter,
+            SyllableModifier,
+            ToneLetter,
+            ToneMark,
+            Virama,
+            Visarga,
+            Vowel,
+            VowelDependent,
+            VowelIndependent,
+        }
+    }
+}
+
+enumerated_property! {
+    /// Values of the Indic_Positional_Category property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    pub enum IndicPositionalCategory {
+        abbr => "InPC",
+        long => "Indic_Positional_Category",
+        human => "Indic Positional Category",
+        default => NotApplicable,
+        tables => indic_positional_category,
+        values => {
+            Bottom,
+            BottomAndLeft,
+            BottomAndRight,
+            Left,
+            LeftAndRight,
+            NotApplicable,
+            Overstruck,
+            Right,
+            Top,
+            TopAndBottom,
+            TopAndBottomAndLeft,
+            TopAndBottomAndRight,
+            TopAndLeft,
+            TopAndLeftAndRight,
+            TopAndRight,
+            VisualOrderLeft,
+        }
+    }
+}
+
+enumerated_property! {
+    /// Values of the Vertical_Orientation property, ordered by their abbreviated names as in
+    /// PropertyValueAliases.txt.
+    pub enum VerticalOrientation {
+        abbr => "vo",
+        long => "Vertical_Orientation",
+        human => "Vertical Orientation",
+        default => Rotated,
+        tables => vertical_orientation,
+        values => {
+            Rotated,
+            TransformedRotated,
+            TransformedUpright,
+            Upright,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unic::char::property::EnumeratedCharProperty;
+
+    use super::*;
+
+    #[test]
+    fn test_joining_properties_of() {
+        // U+0628 ARABIC LETTER BEH
+        assert_eq!(JoiningType::of('\u{0628}'), JoiningType::DualJoining);
+        assert_eq!(JoiningGroup::of('\u{0628}'), JoiningGroup::Beh);
+        // U+0627 ARABIC LETTER ALEF
+        assert_eq!(JoiningType::of('\u{0627}'), JoiningType::RightJoining);
+        assert_eq!(JoiningGroup::of('\u{0627}'), JoiningGroup::Alef);
+        assert_eq!(JoiningType::of('\u{200D}'), JoiningType::JoinCausing);
+        assert_eq!(JoiningType::of('\u{064E}'), JoiningType::Transparent);
+        assert_eq!(JoiningType::of('a'), JoiningType::NonJoining);
+        assert_eq!(JoiningGroup::of('a'), JoiningGroup::NoJoiningGroup);
+
+        assert_eq!(JoiningType::DualJoining.abbr_name(), "D");
+        assert_eq!(JoiningGroup::HamzaOnHehGoal.abbr_name(), "Teh_Marbuta_Goal");
+    }
+
+    #[test]
+    fn test_hangul_syllable_type_of() {
+        assert_eq!(
+            HangulSyllableType::of('\u{1100}'),
+            HangulSyllableType::LeadingJamo
+        );
+        assert_eq!(
+            HangulSyllableType::of('\u{1161}'),
+            HangulSyllableType::VowelJamo
+        );
+        assert_eq!(
+            HangulSyllableType::of('\u{11A8}'),
+            HangulSyllableType::TrailingJamo
+        );
+        assert_eq!(
+            HangulSyllableType::of('\u{AC00}'),
+            HangulSyllableType::LVSyllable
+        );
+        assert_eq!(
+            HangulSyllableType::of('\u{AC01}'),
+            HangulSyllableType::LVTSyllable
+        );
+        assert_eq!(
+            HangulSyllableType::of('a'),
+            HangulSyllableType::NotApplicable
+        );
+    }
+
+    #[test]
+    fn test_indic_properties_of() {
+        // U+0915 DEVANAGARI LETTER KA, U+093F DEVANAGARI VOWEL SIGN I and U+094D DEVANAGARI SIGN
+        // VIRAMA
+        assert_eq!(
+            IndicSyllabicCategory::of('\u{0915}'),
+            IndicSyllabicCategory::Consonant
+        );
+        assert_eq!(
+            IndicSyllabicCategory::of('\u{093F}'),
+            IndicSyllabicCategory::VowelDependent
+        );
+        assert_eq!(
+            IndicSyllabicCategory::of('\u{094D}'),
+            IndicSyllabicCategory::Virama
+        );
+        assert_eq!(IndicSyllabicCategory::of('a'), IndicSyllabicCategory::Other);
+        assert_eq!(
+            IndicPositionalCategory::of('\u{093F}'),
+            IndicPositionalCategory::Left
+        );
+        assert_eq!(
+            IndicPositionalCategory::of('\u{094D}'),
+            IndicPositionalCategory::Bottom
+        );
+        assert_eq!(
+            IndicPositionalCategory::of('\u{0915}'),
+            IndicPositionalCategory::NotApplicable
+        );
+        assert_eq!(IndicPositionalCategory::NotApplicable.abbr_name(), "NA");
+    }
+
+    #[test]
+    fn test_vertical_orientation_of() {
+        assert_eq!(VerticalOrientation::of('a'), VerticalOrientation::Rotated);
+        assert_eq!(VerticalOrientation::of('中'), VerticalOrientation::Upright);
+        // U+3001 IDEOGRAPHIC COMMA and U+30FC KATAKANA-HIRAGANA PROLONGED SOUND MARK
+        assert_eq!(
+            VerticalOrientation::of('\u{3001}'),
+            VerticalOrientation::TransformedUpright
+        );
+        assert_eq!(
+            VerticalOrientation::of('\u{30FC}'),
+            VerticalOrientation::TransformedRotated
+        );
+        // Unassigned code points in CJK blocks are upright
+        assert_eq!(
+            VerticalOrientation::of('\u{2FFFD}'),
+            VerticalOrientation::Upright
+        );
+    }
+}
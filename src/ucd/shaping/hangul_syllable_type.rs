@@ -0,0 +1,844 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (HangulSyllableType.txt and PropertyValueAliases.txt),
+// limited to the characters assigned in Unicode 13.0.
+
+use super::HangulSyllableType;
+
+pub(super) const VALUES: &[HangulSyllableType] = &[
+    HangulSyllableType::LeadingJamo,
+    HangulSyllableType::LVSyllable,
+    HangulSyllableType::LVTSyllable,
+    HangulSyllableType::NotApplicable,
+    HangulSyllableType::TrailingJamo,
+    HangulSyllableType::VowelJamo,
+];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[
+    ("L", "Leading_Jamo", "Leading Jamo"),
+    ("LV", "LV_Syllable", "LV Syllable"),
+    ("LVT", "LVT_Syllable", "LVT Syllable"),
+    ("NA", "Not_Applicable", "Not Applicable"),
+    ("T", "Trailing_Jamo", "Trailing Jamo"),
+    ("V", "Vowel_Jamo", "Vowel Jamo"),
+];
+
+pub(super) const RANGES: &[(u32, u32, HangulSyllableType)] = &[
+    (0x1100, 0x115F, HangulSyllableType::LeadingJamo),
+    (0x1160, 0x11A7, HangulSyllableType::VowelJamo),
+    (0x11A8, 0x11FF, HangulSyllableType::TrailingJamo),
+    (0xA960, 0xA97C, HangulSyllableType::LeadingJamo),
+    (0xAC00, 0xAC00, HangulSyllableType::LVSyllable),
+    (0xAC01, 0xAC1B, HangulSyllableType::LVTSyllable),
+    (0xAC1C, 0xAC1C, HangulSyllableType::LVSyllable),
+    (0xAC1D, 0xAC37, HangulSyllableType::LVTSyllable),
+    (0xAC38, 0xAC38, HangulSyllableType::LVSyllable),
+    (0xAC39, 0xAC53, HangulSyllableType::LVTSyllable),
+    (0xAC54, 0xAC54, HangulSyllableType::LVSyllable),
+    (0xAC55, 0xAC6F, HangulSyllableType::LVTSyllable),
+    (0xAC70, 0xAC70, HangulSyllableType::LVSyllable),
+    (0xAC71, 0xAC8B, HangulSyllableType::LVTSyllable),
+    (0xAC8C, 0xAC8C, HangulSyllableType::LVSyllable),
+    (0xAC8D, 0xACA7, HangulSyllableType::LVTSyllable),
+    (0xACA8, 0xACA8, HangulSyllableType::LVSyllable),
+    (0xACA9, 0xACC3, HangulSyllableType::LVTSyllable),
+    (0xACC4, 0xACC4, HangulSyllableType::LVSyllable),
+    (0xACC5, 0xACDF, HangulSyllableType::LVTSyllable),
+    (0xACE0, 0xACE0, HangulSyllableType::LVSyllable),
+    (0xACE1, 0xACFB, HangulSyllableType::LVTSyllable),
+    (0xACFC, 0xACFC, HangulSyllableType::LVSyllable),
+    (0xACFD, 0xAD17, HangulSyllableType::LVTSyllable),
+    (0xAD18, 0xAD18, HangulSyllableType::LVSyllable),
+    (0xAD19, 0xAD33, HangulSyllableType::LVTSyllable),
+    (0xAD34, 0xAD34, HangulSyllableType::LVSyllable),
+    (0xAD35, 0xAD4F, HangulSyllableType::LVTSyllable),
+    (0xAD50, 0xAD50, HangulSyllableType::LVSyllable),
+    (0xAD51, 0xAD6B, HangulSyllableType::LVTSyllable),
+    (0xAD6C, 0xAD6C, HangulSyllableType::LVSyllable),
+    (0xAD6D, 0xAD87, HangulSyllableType::LVTSyllable),
+    (0xAD88, 0xAD88, HangulSyllableType::LVSyllable),
+    (0xAD89, 0xADA3, HangulSyllableType::LVTSyllable),
+    (0xADA4, 0xADA4, HangulSyllableType::LVSyllable),
+    (0xADA5, 0xADBF, HangulSyllableType::LVTSyllable),
+    (0xADC0, 0xADC0, HangulSyllableType::LVSyllable),
+    (0xADC1, 0xADDB, HangulSyllableType::LVTSyllable),
+    (0xADDC, 0xADDC, HangulSyllableType::LVSyllable),
+    (0xADDD, 0xADF7, HangulSyllableType::LVTSyllable),
+    (0xADF8, 0xADF8, HangulSyllableType::LVSyllable),
+    (0xADF9, 0xAE13, HangulSyllableType::LVTSyllable),
+    (0xAE14, 0xAE14, HangulSyllableType::LVSyllable),
+    (0xAE15, 0xAE2F, HangulSyllableType::LVTSyllable),
+    (0xAE30, 0xAE30, HangulSyllableType::LVSyllable),
+    (0xAE31, 0xAE4B, HangulSyllableType::LVTSyllable),
+    (0xAE4C, 0xAE4C, HangulSyllableType::LVSyllable),
+    (0xAE4D, 0xAE67, HangulSyllableType::LVTSyllable),
+    (0xAE68, 0xAE68, HangulSyllableType::LVSyllable),
+    (0xAE69, 0xAE83, HangulSyllableType::LVTSyllable),
+    (0xAE84, 0xAE84, HangulSyllableType::LVSyllable),
+    (0xAE85, 0xAE9F, HangulSyllableType::LVTSyllable),
+    (0xAEA0, 0xAEA0, HangulSyllableType::LVSyllable),
+    (0xAEA1, 0xAEBB, HangulSyllableType::LVTSyllable),
+    (0xAEBC, 0xAEBC, HangulSyllableType::LVSyllable),
+    (0xAEBD, 0xAED7, HangulSyllableType::LVTSyllable),
+    (0xAED8, 0xAED8, HangulSyllableType::LVSyllable),
+    (0xAED9, 0xAEF3, HangulSyllableType::LVTSyllable),
+    (0xAEF4, 0xAEF4, HangulSyllableType::LVSyllable),
+    (0xAEF5, 0xAF0F, HangulSyllableType::LVTSyllable),
+    (0xAF10, 0xAF10, HangulSyllableType::LVSyllable),
+    (0xAF11, 0xAF2B, HangulSyllableType::LVTSyllable),
+    (0xAF2C, 0xAF2C, HangulSyllableType::LVSyllable),
+    (0xAF2D, 0xAF47, HangulSyllableType::LVTSyllable),
+    (0xAF48, 0xAF48, HangulSyllableType::LVSyllable),
+    (0xAF49, 0xAF63, HangulSyllableType::LVTSyllable),
+    (0xAF64, 0xAF64, HangulSyllableType::LVSyllable),
+    (0xAF65, 0xAF7F, HangulSyllableType::LVTSyllable),
+    (0xAF80, 0xAF80, HangulSyllableType::LVSyllable),
+    (0xAF81, 0xAF9B, HangulSyllableType::LVTSyllable),
+    (0xAF9C, 0xAF9C, HangulSyllableType::LVSyllable),
+    (0xAF9D, 0xAFB7, HangulSyllableType::LVTSyllable),
+    (0xAFB8, 0xAFB8, HangulSyllableType::LVSyllable),
+    (0xAFB9, 0xAFD3, HangulSyllableType::LVTSyllable),
+    (0xAFD4, 0xAFD4, HangulSyllableType::LVSyllable),
+    (0xAFD5, 0xAFEF, HangulSyllableType::LVTSyllable),
+    (0xAFF0, 0xAFF0, HangulSyllableType::LVSyllable),
+    (0xAFF1, 0xB00B, HangulSyllableType::LVTSyllable),
+    (0xB00C, 0xB00C, HangulSyllableType::LVSyllable),
+    (0xB00D, 0xB027, HangulSyllableType::LVTSyllable),
+    (0xB028, 0xB028, HangulSyllableType::LVSyllable),
+    (0xB029, 0xB043, HangulSyllableType::LVTSyllable),
+    (0xB044, 0xB044, HangulSyllableType::LVSyllable),
+    (0xB045, 0xB05F, HangulSyllableType::LVTSyllable),
+    (0xB060, 0xB060, HangulSyllableType::LVSyllable),
+    (0xB061, 0xB07B, HangulSyllableType::LVTSyllable),
+    (0xB07C, 0xB07C, HangulSyllableType::LVSyllable),
+    (0xB07D, 0xB097, HangulSyllableType::LVTSyllable),
+    (0xB098, 0xB098, HangulSyllableType::LVSyllable),
+    (0xB099, 0xB0B3, HangulSyllableType::LVTSyllable),
+    (0xB0B4, 0xB0B4, HangulSyllableType::LVSyllable),
+    (0xB0B5, 0xB0CF, HangulSyllableType::LVTSyllable),
+    (0xB0D0, 0xB0D0, HangulSyllableType::LVSyllable),
+    (0xB0D1, 0xB0EB, HangulSyllableType::LVTSyllable),
+    (0xB0EC, 0xB0EC, HangulSyllableType::LVSyllable),
+    (0xB0ED, 0xB107, HangulSyllableType::LVTSyllable),
+    (0xB108, 0xB108, HangulSyllableType::LVSyllable),
+    (0xB109, 0xB123, HangulSyllableType::LVTSyllable),
+    (0xB124, 0xB124, HangulSyllableType::LVSyllable),
+    (0xB125, 0xB13F, HangulSyllableType::LVTSyllable),
+    (0xB140, 0xB140, HangulSyllableType::LVSyllable),
+    (0xB141, 0xB15B, HangulSyllableType::LVTSyllable),
+    (0xB15C, 0xB15C, HangulSyllableType::LVSyllable),
+    (0xB15D, 0xB177, HangulSyllableType::LVTSyllable),
+    (0xB178, 0xB178, HangulSyllableType::LVSyllable),
+    (0xB179, 0xB193, HangulSyllableType::LVTSyllable),
+    (0xB194, 0xB194, HangulSyllableType::LVSyllable),
+    (0xB195, 0xB1AF, HangulSyllableType::LVTSyllable),
+    (0xB1B0, 0xB1B0, HangulSyllableType::LVSyllable),
+    (0xB1B1, 0xB1CB, HangulSyllableType::LVTSyllable),
+    (0xB1CC, 0xB1CC, HangulSyllableType::LVSyllable),
+    (0xB1CD, 0xB1E7, HangulSyllableType::LVTSyllable),
+    (0xB1E8, 0xB1E8, HangulSyllableType::LVSyllable),
+    (0xB1E9, 0xB203, HangulSyllableType::LVTSyllable),
+    (0xB204, 0xB204, HangulSyllableType::LVSyllable),
+    (0xB205, 0xB21F, HangulSyllableType::LVTSyllable),
+    (0xB220, 0xB220, HangulSyllableType::LVSyllable),
+    (0xB221, 0xB23B, HangulSyllableType::LVTSyllable),
+    (0xB23C, 0xB23C, HangulSyllableType::LVSyllable),
+    (0xB23D, 0xB257, HangulSyllableType::LVTSyllable),
+    (0xB258, 0xB258, HangulSyllableType::LVSyllable),
+    (0xB259, 0xB273, HangulSyllableType::LVTSyllable),
+    (0xB274, 0xB274, HangulSyllableType::LVSyllable),
+    (0xB275, 0xB28F, HangulSyllableType::LVTSyllable),
+    (0xB290, 0xB290, HangulSyllableType::LVSyllable),
+    (0xB291, 0xB2AB, HangulSyllableType::LVTSyllable),
+    (0xB2AC, 0xB2AC, HangulSyllableType::LVSyllable),
+    (0xB2AD, 0xB2C7, HangulSyllableType::LVTSyllable),
+    (0xB2C8, 0xB2C8, HangulSyllableType::LVSyllable),
+    (0xB2C9, 0xB2E3, HangulSyllableType::LVTSyllable),
+    (0xB2E4, 0xB2E4, HangulSyllableType::LVSyllable),
+    (0xB2E5, 0xB2FF, HangulSyllableType::LVTSyllable),
+    (0xB300, 0xB300, HangulSyllableType::LVSyllable),
+    (0xB301, 0xB31B, HangulSyllableType::LVTSyllable),
+    (0xB31C, 0xB31C, HangulSyllableType::LVSyllable),
+    (0xB31D, 0xB337, HangulSyllableType::LVTSyllable),
+    (0xB338, 0xB338, HangulSyllableType::LVSyllable),
+    (0xB339, 0xB353, HangulSyllableType::LVTSyllable),
+    (0xB354, 0xB354, HangulSyllableType::LVSyllable),
+    (0xB355, 0xB36F, HangulSyllableType::LVTSyllable),
+    (0xB370, 0xB370, HangulSyllableType::LVSyllable),
+    (0xB371, 0xB38B, HangulSyllableType::LVTSyllable),
+    (0xB38C, 0xB38C, HangulSyllableType::LVSyllable),
+    (0xB38D, 0xB3A7, HangulSyllableType::LVTSyllable),
+    (0xB3A8, 0xB3A8, HangulSyllableType::LVSyllable),
+    (0xB3A9, 0xB3C3, HangulSyllableType::LVTSyllable),
+    (0xB3C4, 0xB3C4, HangulSyllableType::LVSyllable),
+    (0xB3C5, 0xB3DF, HangulSyllableType::LVTSyllable),
+    (0xB3E0, 0xB3E0, HangulSyllableType::LVSyllable),
+    (0xB3E1, 0xB3FB, HangulSyllableType::LVTSyllable),
+    (0xB3FC, 0xB3FC, HangulSyllableType::LVSyllable),
+    (0xB3FD, 0xB417, HangulSyllableType::LVTSyllable),
+    (0xB418, 0xB418, HangulSyllableType::LVSyllable),
+    (0xB419, 0xB433, HangulSyllableType::LVTSyllable),
+    (0xB434, 0xB434, HangulSyllableType::LVSyllable),
+    (0xB435, 0xB44F, HangulSyllableType::LVTSyllable),
+    (0xB450, 0xB450, HangulSyllableType::LVSyllable),
+    (0xB451, 0xB46B, HangulSyllableType::LVTSyllable),
+    (0xB46C, 0xB46C, HangulSyllableType::LVSyllable),
+    (0xB46D, 0xB487, HangulSyllableType::LVTSyllable),
+    (0xB488, 0xB488, HangulSyllableType::LVSyllable),
+    (0xB489, 0xB4A3, HangulSyllableType::LVTSyllable),
+    (0xB4A4, 0xB4A4, HangulSyllableType::LVSyllable),
+    (0xB4A5, 0xB4BF, HangulSyllableType::LVTSyllable),
+    (0xB4C0, 0xB4C0, HangulSyllableType::LVSyllable),
+    (0xB4C1, 0xB4DB, HangulSyllableType::LVTSyllable),
+    (0xB4DC, 0xB4DC, HangulSyllableType::LVSyllable),
+    (0xB4DD, 0xB4F7, HangulSyllableType::LVTSyllable),
+    (0xB4F8, 0xB4F8, HangulSyllableType::LVSyllable),
+    (0xB4F9, 0xB513, HangulSyllableType::LVTSyllable),
+    (0xB514, 0xB514, HangulSyllableType::LVSyllable),
+    (0xB515, 0xB52F, HangulSyllableType::LVTSyllable),
+    (0xB530, 0xB530, HangulSyllableType::LVSyllable),
+    (0xB531, 0xB54B, HangulSyllableType::LVTSyllable),
+    (0xB54C, 0xB54C, HangulSyllableType::LVSyllable),
+    (0xB54D, 0xB567, HangulSyllableType::LVTSyllable),
+    (0xB568, 0xB568, HangulSyllableType::LVSyllable),
+    (0xB569, 0xB583, HangulSyllableType::LVTSyllable),
+    (0xB584, 0xB584, HangulSyllableType::LVSyllable),
+    (0xB585, 0xB59F, HangulSyllableType::LVTSyllable),
+    (0xB5A0, 0xB5A0, HangulSyllableType::LVSyllable),
+    (0xB5A1, 0xB5BB, HangulSyllableType::LVTSyllable),
+    (0xB5BC, 0xB5BC, HangulSyllableType::LVSyllable),
+    (0xB5BD, 0xB5D7, HangulSyllableType::LVTSyllable),
+    (0xB5D8, 0xB5D8, HangulSyllableType::LVSyllable),
+    (0xB5D9, 0xB5F3, HangulSyllableType::LVTSyllable),
+    (0xB5F4, 0xB5F4, HangulSyllableType::LVSyllable),
+    (0xB5F5, 0xB60F, HangulSyllableType::LVTSyllable),
+    (0xB610, 0xB610, HangulSyllableType::LVSyllable),
+    (0xB611, 0xB62B, HangulSyllableType::LVTSyllable),
+    (0xB62C, 0xB62C, HangulSyllableType::LVSyllable),
+    (0xB62D, 0xB647, HangulSyllableType::LVTSyllable),
+    (0xB648, 0xB648, HangulSyllableType::LVSyllable),
+    (0xB649, 0xB663, HangulSyllableType::LVTSyllable),
+    (0xB664, 0xB664, HangulSyllableType::LVSyllable),
+    (0xB665, 0xB67F, HangulSyllableType::LVTSyllable),
+    (0xB680, 0xB680, HangulSyllableType::LVSyllable),
+    (0xB681, 0xB69B, HangulSyllableType::LVTSyllable),
+    (0xB69C, 0xB69C, HangulSyllableType::LVSyllable),
+    (0xB69D, 0xB6B7, HangulSyllableType::LVTSyllable),
+    (0xB6B8, 0xB6B8, HangulSyllableType::LVSyllable),
+    (0xB6B9, 0xB6D3, HangulSyllableType::LVTSyllable),
+    (0xB6D4, 0xB6D4, HangulSyllableType::LVSyllable),
+    (0xB6D5, 0xB6EF, HangulSyllableType::LVTSyllable),
+    (0xB6F0, 0xB6F0, HangulSyllableType::LVSyllable),
+    (0xB6F1, 0xB70B, HangulSyllableType::LVTSyllable),
+    (0xB70C, 0xB70C, HangulSyllableType::LVSyllable),
+    (0xB70D, 0xB727, HangulSyllableType::LVTSyllable),
+    (0xB728, 0xB728, HangulSyllableType::LVSyllable),
+    (0xB729, 0xB743, HangulSyllableType::LVTSyllable),
+    (0xB744, 0xB744, HangulSyllableType::LVSyllable),
+    (0xB745, 0xB75F, HangulSyllableType::LVTSyllable),
+    (0xB760, 0xB760, HangulSyllableType::LVSyllable),
+    (0xB761, 0xB77B, HangulSyllableType::LVTSyllable),
+    (0xB77C, 0xB77C, HangulSyllableType::LVSyllable),
+    (0xB77D, 0xB797, HangulSyllableType::LVTSyllable),
+    (0xB798, 0xB798, HangulSyllableType::LVSyllable),
+    (0xB799, 0xB7B3, HangulSyllableType::LVTSyllable),
+    (0xB7B4, 0xB7B4, HangulSyllableType::LVSyllable),
+    (0xB7B5, 0xB7CF, HangulSyllableType::LVTSyllable),
+    (0xB7D0, 0xB7D0, HangulSyllableType::LVSyllable),
+    (0xB7D1, 0xB7EB, HangulSyllableType::LVTSyllable),
+    (0xB7EC, 0xB7EC, HangulSyllableType::LVSyllable),
+    (0xB7ED, 0xB807, HangulSyllableType::LVTSyllable),
+    (0xB808, 0xB808, HangulSyllableType::LVSyllable),
+    (0xB809, 0xB823, HangulSyllableType::LVTSyllable),
+    (0xB824, 0xB824, HangulSyllableType::LVSyllable),
+    (0xB825, 0xB83F, HangulSyllableType::LVTSyllable),
+    (0xB840, 0xB840, HangulSyllableType::LVSyllable),
+    (0xB841, 0xB85B, HangulSyllableType::LVTSyllable),
+    (0xB85C, 0xB85C, HangulSyllableType::LVSyllable),
+    (0xB85D, 0xB877, HangulSyllableType::LVTSyllable),
+    (0xB878, 0xB878, HangulSyllableType::LVSyllable),
+    (0xB879, 0xB893, HangulSyllableType::LVTSyllable),
+    (0xB894, 0xB894, HangulSyllableType::LVSyllable),
+    (0xB895, 0xB8AF, HangulSyllableType::LVTSyllable),
+    (0xB8B0, 0xB8B0, HangulSyllableType::LVSyllable),
+    (0xB8B1, 0xB8CB, HangulSyllableType::LVTSyllable),
+    (0xB8CC, 0xB8CC, HangulSyllableType::LVSyllable),
+    (0xB8CD, 0xB8E7, HangulSyllableType::LVTSyllable),
+    (0xB8E8, 0xB8E8, HangulSyllableType::LVSyllable),
+    (0xB8E9, 0xB903, HangulSyllableType::LVTSyllable),
+    (0xB904, 0xB904, HangulSyllableType::LVSyllable),
+    (0xB905, 0xB91F, HangulSyllableType::LVTSyllable),
+    (0xB920, 0xB920, HangulSyllableType::LVSyllable),
+    (0xB921, 0xB93B, HangulSyllableType::LVTSyllable),
+    (0xB93C, 0xB93C, HangulSyllableType::LVSyllable),
+    (0xB93D, 0xB957, HangulSyllableType::LVTSyllable),
+    (0xB958, 0xB958, HangulSyllableType::LVSyllable),
+    (0xB959, 0xB973, HangulSyllableType::LVTSyllable),
+    (0xB974, 0xB974, HangulSyllableType::LVSyllable),
+    (0xB975, 0xB98F, HangulSyllableType::LVTSyllable),
+    (0xB990, 0xB990, HangulSyllableType::LVSyllable),
+    (0xB991, 0xB9AB, HangulSyllableType::LVTSyllable),
+    (0xB9AC, 0xB9AC, HangulSyllableType::LVSyllable),
+    (0xB9AD, 0xB9C7, HangulSyllableType::LVTSyllable),
+    (0xB9C8, 0xB9C8, HangulSyllableType::LVSyllable),
+    (0xB9C9, 0xB9E3, HangulSyllableType::LVTSyllable),
+    (0xB9E4, 0xB9E4, HangulSyllableType::LVSyllable),
+    (0xB9E5, 0xB9FF, HangulSyllableType::LVTSyllable),
+    (0xBA00, 0xBA00, HangulSyllableType::LVSyllable),
+    (0xBA01, 0xBA1B, HangulSyllableType::LVTSyllable),
+    (0xBA1C, 0xBA1C, HangulSyllableType::LVSyllable),
+    (0xBA1D, 0xBA37, HangulSyllableType::LVTSyllable),
+    (0xBA38, 0xBA38, HangulSyllableType::LVSyllable),
+    (0xBA39, 0xBA53, HangulSyllableType::LVTSyllable),
+    (0xBA54, 0xBA54, HangulSyllableType::LVSyllable),
+    (0xBA55, 0xBA6F, HangulSyllableType::LVTSyllable),
+    (0xBA70, 0xBA70, HangulSyllableType::LVSyllable),
+    (0xBA71, 0xBA8B, HangulSyllableType::LVTSyllable),
+    (0xBA8C, 0xBA8C, HangulSyllableType::LVSyllable),
+    (0xBA8D, 0xBAA7, HangulSyllableType::LVTSyllable),
+    (0xBAA8, 0xBAA8, HangulSyllableType::LVSyllable),
+    (0xBAA9, 0xBAC3, HangulSyllableType::LVTSyllable),
+    (0xBAC4, 0xBAC4, HangulSyllableType::LVSyllable),
+    (0xBAC5, 0xBADF, HangulSyllableType::LVTSyllable),
+    (0xBAE0, 0xBAE0, HangulSyllableType::LVSyllable),
+    (0xBAE1, 0xBAFB, HangulSyllableType::LVTSyllable),
+    (0xBAFC, 0xBAFC, HangulSyllableType::LVSyllable),
+    (0xBAFD, 0xBB17, HangulSyllableType::LVTSyllable),
+    (0xBB18, 0xBB18, HangulSyllableType::LVSyllable),
+    (0xBB19, 0xBB33, HangulSyllableType::LVTSyllable),
+    (0xBB34, 0xBB34, HangulSyllableType::LVSyllable),
+    (0xBB35, 0xBB4F, HangulSyllableType::LVTSyllable),
+    (0xBB50, 0xBB50, HangulSyllableType::LVSyllable),
+    (0xBB51, 0xBB6B, HangulSyllableType::LVTSyllable),
+    (0xBB6C, 0xBB6C, HangulSyllableType::LVSyllable),
+    (0xBB6D, 0xBB87, HangulSyllableType::LVTSyllable),
+    (0xBB88, 0xBB88, HangulSyllableType::LVSyllable),
+    (0xBB89, 0xBBA3, HangulSyllableType::LVTSyllable),
+    (0xBBA4, 0xBBA4, HangulSyllableType::LVSyllable),
+    (0xBBA5, 0xBBBF, HangulSyllableType::LVTSyllable),
+    (0xBBC0, 0xBBC0, HangulSyllableType::LVSyllable),
+    (0xBBC1, 0xBBDB, HangulSyllableType::LVTSyllable),
+    (0xBBDC, 0xBBDC, HangulSyllableType::LVSyllable),
+    (0xBBDD, 0xBBF7, HangulSyllableType::LVTSyllable),
+    (0xBBF8, 0xBBF8, HangulSyllableType::LVSyllable),
+    (0xBBF9, 0xBC13, HangulSyllableType::LVTSyllable),
+    (0xBC14, 0xBC14, HangulSyllableType::LVSyllable),
+    (0xBC15, 0xBC2F, HangulSyllableType::LVTSyllable),
+    (0xBC30, 0xBC30, HangulSyllableType::LVSyllable),
+    (0xBC31, 0xBC4B, HangulSyllableType::LVTSyllable),
+    (0xBC4C, 0xBC4C, HangulSyllableType::LVSyllable),
+    (0xBC4D, 0xBC67, HangulSyllableType::LVTSyllable),
+    (0xBC68, 0xBC68, HangulSyllableType::LVSyllable),
+    (0xBC69, 0xBC83, HangulSyllableType::LVTSyllable),
+    (0xBC84, 0xBC84, HangulSyllableType::LVSyllable),
+    (0xBC85, 0xBC9F, HangulSyllableType::LVTSyllable),
+    (0xBCA0, 0xBCA0, HangulSyllableType::LVSyllable),
+    (0xBCA1, 0xBCBB, HangulSyllableType::LVTSyllable),
+    (0xBCBC, 0xBCBC, HangulSyllableType::LVSyllable),
+    (0xBCBD, 0xBCD7, HangulSyllableType::LVTSyllable),
+    (0xBCD8, 0xBCD8, HangulSyllableType::LVSyllable),
+    (0xBCD9, 0xBCF3, HangulSyllableType::LVTSyllable),
+    (0xBCF4, 0xBCF4, HangulSyllableType::LVSyllable),
+    (0xBCF5, 0xBD0F, HangulSyllableType::LVTSyllable),
+    (0xBD10, 0xBD10, HangulSyllableType::LVSyllable),
+    (0xBD11, 0xBD2B, HangulSyllableType::LVTSyllable),
+    (0xBD2C, 0xBD2C, HangulSyllableType::LVSyllable),
+    (0xBD2D, 0xBD47, HangulSyllableType::LVTSyllable),
+    (0xBD48, 0xBD48, HangulSyllableType::LVSyllable),
+    (0xBD49, 0xBD63, HangulSyllableType::LVTSyllable),
+    (0xBD64, 0xBD64, HangulSyllableType::LVSyllable),
+    (0xBD65, 0xBD7F, HangulSyllableType::LVTSyllable),
+    (0xBD80, 0xBD80, HangulSyllableType::LVSyllable),
+    (0xBD81, 0xBD9B, HangulSyllableType::LVTSyllable),
+    (0xBD9C, 0xBD9C, HangulSyllableType::LVSyllable),
+    (0xBD9D, 0xBDB7, HangulSyllableType::LVTSyllable),
+    (0xBDB8, 0xBDB8, HangulSyllableType::LVSyllable),
+    (0xBDB9, 0xBDD3, HangulSyllableType::LVTSyllable),
+    (0xBDD4, 0xBDD4, HangulSyllableType::LVSyllable),
+    (0xBDD5, 0xBDEF, HangulSyllableType::LVTSyllable),
+    (0xBDF0, 0xBDF0, HangulSyllableType::LVSyllable),
+    (0xBDF1, 0xBE0B, HangulSyllableType::LVTSyllable),
+    (0xBE0C, 0xBE0C, HangulSyllableType::LVSyllable),
+    (0xBE0D, 0xBE27, HangulSyllableType::LVTSyllable),
+    (0xBE28, 0xBE28, HangulSyllableType::LVSyllable),
+    (0xBE29, 0xBE43, HangulSyllableType::LVTSyllable),
+    (0xBE44, 0xBE44, HangulSyllableType::LVSyllable),
+    (0xBE45, 0xBE5F, HangulSyllableType::LVTSyllable),
+    (0xBE60, 0xBE60, HangulSyllableType::LVSyllable),
+    (0xBE61, 0xBE7B, HangulSyllableType::LVTSyllable),
+    (0xBE7C, 0xBE7C, HangulSyllableType::LVSyllable),
+    (0xBE7D, 0xBE97, HangulSyllableType::LVTSyllable),
+    (0xBE98, 0xBE98, HangulSyllableType::LVSyllable),
+    (0xBE99, 0xBEB3, HangulSyllableType::LVTSyllable),
+    (0xBEB4, 0xBEB4, HangulSyllableType::LVSyllable),
+    (0xBEB5, 0xBECF, HangulSyllableType::LVTSyllable),
+    (0xBED0, 0xBED0, HangulSyllableType::LVSyllable),
+    (0xBED1, 0xBEEB, HangulSyllableType::LVTSyllable),
+    (0xBEEC, 0xBEEC, HangulSyllableType::LVSyllable),
+    (0xBEED, 0xBF07, HangulSyllableType::LVTSyllable),
+    (0xBF08, 0xBF08, HangulSyllableType::LVSyllable),
+    (0xBF09, 0xBF23, HangulSyllableType::LVTSyllable),
+    (0xBF24, 0xBF24, HangulSyllableType::LVSyllable),
+    (0xBF25, 0xBF3F, HangulSyllableType::LVTSyllable),
+    (0xBF40, 0xBF40, HangulSyllableType::LVSyllable),
+    (0xBF41, 0xBF5B, HangulSyllableType::LVTSyllable),
+    (0xBF5C, 0xBF5C, HangulSyllableType::LVSyllable),
+    (0xBF5D, 0xBF77, HangulSyllableType::LVTSyllable),
+    (0xBF78, 0xBF78, HangulSyllableType::LVSyllable),
+    (0xBF79, 0xBF93, HangulSyllableType::LVTSyllable),
+    (0xBF94, 0xBF94, HangulSyllableType::LVSyllable),
+    (0xBF95, 0xBFAF, HangulSyllableType::LVTSyllable),
+    (0xBFB0, 0xBFB0, HangulSyllableType::LVSyllable),
+    (0xBFB1, 0xBFCB, HangulSyllableType::LVTSyllable),
+    (0xBFCC, 0xBFCC, HangulSyllableType::LVSyllable),
+    (0xBFCD, 0xBFE7, HangulSyllableType::LVTSyllable),
+    (0xBFE8, 0xBFE8, HangulSyllableType::LVSyllable),
+    (0xBFE9, 0xC003, HangulSyllableType::LVTSyllable),
+    (0xC004, 0xC004, HangulSyllableType::LVSyllable),
+    (0xC005, 0xC01F, HangulSyllableType::LVTSyllable),
+    (0xC020, 0xC020, HangulSyllableType::LVSyllable),
+    (0xC021, 0xC03B, HangulSyllableType::LVTSyllable),
+    (0xC03C, 0xC03C, HangulSyllableType::LVSyllable),
+    (0xC03D, 0xC057, HangulSyllableType::LVTSyllable),
+    (0xC058, 0xC058, HangulSyllableType::LVSyllable),
+    (0xC059, 0xC073, HangulSyllableType::LVTSyllable),
+    (0xC074, 0xC074, HangulSyllableType::LVSyllable),
+    (0xC075, 0xC08F, HangulSyllableType::LVTSyllable),
+    (0xC090, 0xC090, HangulSyllableType::LVSyllable),
+    (0xC091, 0xC0AB, HangulSyllableType::LVTSyllable),
+    (0xC0AC, 0xC0AC, HangulSyllableType::LVSyllable),
+    (0xC0AD, 0xC0C7, HangulSyllableType::LVTSyllable),
+    (0xC0C8, 0xC0C8, HangulSyllableType::LVSyllable),
+    (0xC0C9, 0xC0E3, HangulSyllableType::LVTSyllable),
+    (0xC0E4, 0xC0E4, HangulSyllableType::LVSyllable),
+    (0xC0E5, 0xC0FF, HangulSyllableType::LVTSyllable),
+    (0xC100, 0xC100, HangulSyllableType::LVSyllable),
+    (0xC101, 0xC11B, HangulSyllableType::LVTSyllable),
+    (0xC11C, 0xC11C, HangulSyllableType::LVSyllable),
+    (0xC11D, 0xC137, HangulSyllableType::LVTSyllable),
+    (0xC138, 0xC138, HangulSyllableType::LVSyllable),
+    (0xC139, 0xC153, HangulSyllableType::LVTSyllable),
+    (0xC154, 0xC154, HangulSyllableType::LVSyllable),
+    (0xC155, 0xC16F, HangulSyllableType::LVTSyllable),
+    (0xC170, 0xC170, HangulSyllableType::LVSyllable),
+    (0xC171, 0xC18B, HangulSyllableType::LVTSyllable),
+    (0xC18C, 0xC18C, HangulSyllableType::LVSyllable),
+    (0xC18D, 0xC1A7, HangulSyllableType::LVTSyllable),
+    (0xC1A8, 0xC1A8, HangulSyllableType::LVSyllable),
+    (0xC1A9, 0xC1C3, HangulSyllableType::LVTSyllable),
+    (0xC1C4, 0xC1C4, HangulSyllableType::LVSyllable),
+    (0xC1C5, 0xC1DF, HangulSyllableType::LVTSyllable),
+    (0xC1E0, 0xC1E0, HangulSyllableType::LVSyllable),
+    (0xC1E1, 0xC1FB, HangulSyllableType::LVTSyllable),
+    (0xC1FC, 0xC1FC, HangulSyllableType::LVSyllable),
+    (0xC1FD, 0xC217, HangulSyllableType::LVTSyllable),
+    (0xC218, 0xC218, HangulSyllableType::LVSyllable),
+    (0xC219, 0xC233, HangulSyllableType::LVTSyllable),
+    (0xC234, 0xC234, HangulSyllableType::LVSyllable),
+    (0xC235, 0xC24F, HangulSyllableType::LVTSyllable),
+    (0xC250, 0xC250, HangulSyllableType::LVSyllable),
+    (0xC251, 0xC26B, HangulSyllableType::LVTSyllable),
+    (0xC26C, 0xC26C, HangulSyllableType::LVSyllable),
+    (0xC26D, 0xC287, HangulSyllableType::LVTSyllable),
+    (0xC288, 0xC288, HangulSyllableType::LVSyllable),
+    (0xC289, 0xC2A3, HangulSyllableType::LVTSyllable),
+    (0xC2A4, 0xC2A4, HangulSyllableType::LVSyllable),
+    (0xC2A5, 0xC2BF, HangulSyllableType::LVTSyllable),
+    (0xC2C0, 0xC2C0, HangulSyllableType::LVSyllable),
+    (0xC2C1, 0xC2DB, HangulSyllableType::LVTSyllable),
+    (0xC2DC, 0xC2DC, HangulSyllableType::LVSyllable),
+    (0xC2DD, 0xC2F7, HangulSyllableType::LVTSyllable),
+    (0xC2F8, 0xC2F8, HangulSyllableType::LVSyllable),
+    (0xC2F9, 0xC313, HangulSyllableType::LVTSyllable),
+    (0xC314, 0xC314, HangulSyllableType::LVSyllable),
+    (0xC315, 0xC32F, HangulSyllableType::LVTSyllable),
+    (0xC330, 0xC330, HangulSyllableType::LVSyllable),
+    (0xC331, 0xC34B, HangulSyllableType::LVTSyllable),
+    (0xC34C, 0xC34C, HangulSyllableType::LVSyllable),
+    (0xC34D, 0xC367, HangulSyllableType::LVTSyllable),
+    (0xC368, 0xC368, HangulSyllableType::LVSyllable),
+    (0xC369, 0xC383, HangulSyllableType::LVTSyllable),
+    (0xC384, 0xC384, HangulSyllableType::LVSyllable),
+    (0xC385, 0xC39F, HangulSyllableType::LVTSyllable),
+    (0xC3A0, 0xC3A0, HangulSyllableType::LVSyllable),
+    (0xC3A1, 0xC3BB, HangulSyllableType::LVTSyllable),
+    (0xC3BC, 0xC3BC, HangulSyllableType::LVSyllable),
+    (0xC3BD, 0xC3D7, HangulSyllableType::LVTSyllable),
+    (0xC3D8, 0xC3D8, HangulSyllableType::LVSyllable),
+    (0xC3D9, 0xC3F3, HangulSyllableType::LVTSyllable),
+    (0xC3F4, 0xC3F4, HangulSyllableType::LVSyllable),
+    (0xC3F5, 0xC40F, HangulSyllableType::LVTSyllable),
+    (0xC410, 0xC410, HangulSyllableType::LVSyllable),
+    (0xC411, 0xC42B, HangulSyllableType::LVTSyllable),
+    (0xC42C, 0xC42C, HangulSyllableType::LVSyllable),
+    (0xC42D, 0xC447, HangulSyllableType::LVTSyllable),
+    (0xC448, 0xC448, HangulSyllableType::LVSyllable),
+    (0xC449, 0xC463, HangulSyllableType::LVTSyllable),
+    (0xC464, 0xC464, HangulSyllableType::LVSyllable),
+    (0xC465, 0xC47F, HangulSyllableType::LVTSyllable),
+    (0xC480, 0xC480, HangulSyllableType::LVSyllable),
+    (0xC481, 0xC49B, HangulSyllableType::LVTSyllable),
+    (0xC49C, 0xC49C, HangulSyllableType::LVSyllable),
+    (0xC49D, 0xC4B7, HangulSyllableType::LVTSyllable),
+    (0xC4B8, 0xC4B8, HangulSyllableType::LVSyllable),
+    (0xC4B9, 0xC4D3, HangulSyllableType::LVTSyllable),
+    (0xC4D4, 0xC4D4, HangulSyllableType::LVSyllable),
+    (0xC4D5, 0xC4EF, HangulSyllableType::LVTSyllable),
+    (0xC4F0, 0xC4F0, HangulSyllableType::LVSyllable),
+    (0xC4F1, 0xC50B, HangulSyllableType::LVTSyllable),
+    (0xC50C, 0xC50C, HangulSyllableType::LVSyllable),
+    (0xC50D, 0xC527, HangulSyllableType::LVTSyllable),
+    (0xC528, 0xC528, HangulSyllableType::LVSyllable),
+    (0xC529, 0xC543, HangulSyllableType::LVTSyllable),
+    (0xC544, 0xC544, HangulSyllableType::LVSyllable),
+    (0xC545, 0xC55F, HangulSyllableType::LVTSyllable),
+    (0xC560, 0xC560, HangulSyllableType::LVSyllable),
+    (0xC561, 0xC57B, HangulSyllableType::LVTSyllable),
+    (0xC57C, 0xC57C, HangulSyllableType::LVSyllable),
+    (0xC57D, 0xC597, HangulSyllableType::LVTSyllable),
+    (0xC598, 0xC598, HangulSyllableType::LVSyllable),
+    (0xC599, 0xC5B3, HangulSyllableType::LVTSyllable),
+    (0xC5B4, 0xC5B4, HangulSyllableType::LVSyllable),
+    (0xC5B5, 0xC5CF, HangulSyllableType::LVTSyllable),
+    (0xC5D0, 0xC5D0, HangulSyllableType::LVSyllable),
+    (0xC5D1, 0xC5EB, HangulSyllableType::LVTSyllable),
+    (0xC5EC, 0xC5EC, HangulSyllableType::LVSyllable),
+    (0xC5ED, 0xC607, HangulSyllableType::LVTSyllable),
+    (0xC608, 0xC608, HangulSyllableType::LVSyllable),
+    (0xC609, 0xC623, HangulSyllableType::LVTSyllable),
+    (0xC624, 0xC624, HangulSyllableType::LVSyllable),
+    (0xC625, 0xC63F, HangulSyllableType::LVTSyllable),
+    (0xC640, 0xC640, HangulSyllableType::LVSyllable),
+    (0xC641, 0xC65B, HangulSyllableType::LVTSyllable),
+    (0xC65C, 0xC65C, HangulSyllableType::LVSyllable),
+    (0xC65D, 0xC677, HangulSyllableType::LVTSyllable),
+    (0xC678, 0xC678, HangulSyllableType::LVSyllable),
+    (0xC679, 0xC693, HangulSyllableType::LVTSyllable),
+    (0xC694, 0xC694, HangulSyllableType::LVSyllable),
+    (0xC695, 0xC6AF, HangulSyllableType::LVTSyllable),
+    (0xC6B0, 0xC6B0, HangulSyllableType::LVSyllable),
+    (0xC6B1, 0xC6CB, HangulSyllableType::LVTSyllable),
+    (0xC6CC, 0xC6CC, HangulSyllableType::LVSyllable),
+    (0xC6CD, 0xC6E7, HangulSyllableType::LVTSyllable),
+    (0xC6E8, 0xC6E8, HangulSyllableType::LVSyllable),
+    (0xC6E9, 0xC703, HangulSyllableType::LVTSyllable),
+    (0xC704, 0xC704, HangulSyllableType::LVSyllable),
+    (0xC705, 0xC71F, HangulSyllableType::LVTSyllable),
+    (0xC720, 0xC720, HangulSyllableType::LVSyllable),
+    (0xC721, 0xC73B, HangulSyllableType::LVTSyllable),
+    (0xC73C, 0xC73C, HangulSyllableType::LVSyllable),
+    (0xC73D, 0xC757, HangulSyllableType::LVTSyllable),
+    (0xC758, 0xC758, HangulSyllableType::LVSyllable),
+    (0xC759, 0xC773, HangulSyllableType::LVTSyllable),
+    (0xC774, 0xC774, HangulSyllableType::LVSyllable),
+    (0xC775, 0xC78F, HangulSyllableType::LVTSyllable),
+    (0xC790, 0xC790, HangulSyllableType::LVSyllable),
+    (0xC791, 0xC7AB, HangulSyllableType::LVTSyllable),
+    (0xC7AC, 0xC7AC, HangulSyllableType::LVSyllable),
+    (0xC7AD, 0xC7C7, HangulSyllableType::LVTSyllable),
+    (0xC7C8, 0xC7C8, HangulSyllableType::LVSyllable),
+    (0xC7C9, 0xC7E3, HangulSyllableType::LVTSyllable),
+    (0xC7E4, 0xC7E4, HangulSyllableType::LVSyllable),
+    (0xC7E5, 0xC7FF, HangulSyllableType::LVTSyllable),
+    (0xC800, 0xC800, HangulSyllableType::LVSyllable),
+    (0xC801, 0xC81B, HangulSyllableType::LVTSyllable),
+    (0xC81C, 0xC81C, HangulSyllableType::LVSyllable),
+    (0xC81D, 0xC837, HangulSyllableType::LVTSyllable),
+    (0xC838, 0xC838, HangulSyllableType::LVSyllable),
+    (0xC839, 0xC853, HangulSyllableType::LVTSyllable),
+    (0xC854, 0xC854, HangulSyllableType::LVSyllable),
+    (0xC855, 0xC86F, HangulSyllableType::LVTSyllable),
+    (0xC870, 0xC870, HangulSyllableType::LVSyllable),
+    (0xC871, 0xC88B, HangulSyllableType::LVTSyllable),
+    (0xC88C, 0xC88C, HangulSyllableType::LVSyllable),
+    (0xC88D, 0xC8A7, HangulSyllableType::LVTSyllable),
+    (0xC8A8, 0xC8A8, HangulSyllableType::LVSyllable),
+    (0xC8A9, 0xC8C3, HangulSyllableType::LVTSyllable),
+    (0xC8C4, 0xC8C4, HangulSyllableType::LVSyllable),
+    (0xC8C5, 0xC8DF, HangulSyllableType::LVTSyllable),
+    (0xC8E0, 0xC8E0, HangulSyllableType::LVSyllable),
+    (0xC8E1, 0xC8FB, HangulSyllableType::LVTSyllable),
+    (0xC8FC, 0xC8FC, HangulSyllableType::LVSyllable),
+    (0xC8FD, 0xC917, HangulSyllableType::LVTSyllable),
+    (0xC918, 0xC918, HangulSyllableType::LVSyllable),
+    (0xC919, 0xC933, HangulSyllableType::LVTSyllable),
+    (0xC934, 0xC934, HangulSyllableType::LVSyllable),
+    (0xC935, 0xC94F, HangulSyllableType::LVTSyllable),
+    (0xC950, 0xC950, HangulSyllableType::LVSyllable),
+    (0xC951, 0xC96B, HangulSyllableType::LVTSyllable),
+    (0xC96C, 0xC96C, HangulSyllableType::LVSyllable),
+    (0xC96D, 0xC987, HangulSyllableType::LVTSyllable),
+    (0xC988, 0xC988, HangulSyllableType::LVSyllable),
+    (0xC989, 0xC9A3, HangulSyllableType::LVTSyllable),
+    (0xC9A4, 0xC9A4, HangulSyllableType::LVSyllable),
+    (0xC9A5, 0xC9BF, HangulSyllableType::LVTSyllable),
+    (0xC9C0, 0xC9C0, HangulSyllableType::LVSyllable),
+    (0xC9C1, 0xC9DB, HangulSyllableType::LVTSyllable),
+    (0xC9DC, 0xC9DC, HangulSyllableType::LVSyllable),
+    (0xC9DD, 0xC9F7, HangulSyllableType::LVTSyllable),
+    (0xC9F8, 0xC9F8, HangulSyllableType::LVSyllable),
+    (0xC9F9, 0xCA13, HangulSyllableType::LVTSyllable),
+    (0xCA14, 0xCA14, HangulSyllableType::LVSyllable),
+    (0xCA15, 0xCA2F, HangulSyllableType::LVTSyllable),
+    (0xCA30, 0xCA30, HangulSyllableType::LVSyllable),
+    (0xCA31, 0xCA4B, HangulSyllableType::LVTSyllable),
+    (0xCA4C, 0xCA4C, HangulSyllableType::LVSyllable),
+    (0xCA4D, 0xCA67, HangulSyllableType::LVTSyllable),
+    (0xCA68, 0xCA68, HangulSyllableType::LVSyllable),
+    (0xCA69, 0xCA83, HangulSyllableType::LVTSyllable),
+    (0xCA84, 0xCA84, HangulSyllableType::LVSyllable),
+    (0xCA85, 0xCA9F, HangulSyllableType::LVTSyllable),
+    (0xCAA0, 0xCAA0, HangulSyllableType::LVSyllable),
+    (0xCAA1, 0xCABB, HangulSyllableType::LVTSyllable),
+    (0xCABC, 0xCABC, HangulSyllableType::LVSyllable),
+    (0xCABD, 0xCAD7, HangulSyllableType::LVTSyllable),
+    (0xCAD8, 0xCAD8, HangulSyllableType::LVSyllable),
+    (0xCAD9, 0xCAF3, HangulSyllableType::LVTSyllable),
+    (0xCAF4, 0xCAF4, HangulSyllableType::LVSyllable),
+    (0xCAF5, 0xCB0F, HangulSyllableType::LVTSyllable),
+    (0xCB10, 0xCB10, HangulSyllableType::LVSyllable),
+    (0xCB11, 0xCB2B, HangulSyllableType::LVTSyllable),
+    (0xCB2C, 0xCB2C, HangulSyllableType::LVSyllable),
+    (0xCB2D, 0xCB47, HangulSyllableType::LVTSyllable),
+    (0xCB48, 0xCB48, HangulSyllableType::LVSyllable),
+    (0xCB49, 0xCB63, HangulSyllableType::LVTSyllable),
+    (0xCB64, 0xCB64, HangulSyllableType::LVSyllable),
+    (0xCB65, 0xCB7F, HangulSyllableType::LVTSyllable),
+    (0xCB80, 0xCB80, HangulSyllableType::LVSyllable),
+    (0xCB81, 0xCB9B, HangulSyllableType::LVTSyllable),
+    (0xCB9C, 0xCB9C, HangulSyllableType::LVSyllable),
+    (0xCB9D, 0xCBB7, HangulSyllableType::LVTSyllable),
+    (0xCBB8, 0xCBB8, HangulSyllableType::LVSyllable),
+    (0xCBB9, 0xCBD3, HangulSyllableType::LVTSyllable),
+    (0xCBD4, 0xCBD4, HangulSyllableType::LVSyllable),
+    (0xCBD5, 0xCBEF, HangulSyllableType::LVTSyllable),
+    (0xCBF0, 0xCBF0, HangulSyllableType::LVSyllable),
+    (0xCBF1, 0xCC0B, HangulSyllableType::LVTSyllable),
+    (0xCC0C, 0xCC0C, HangulSyllableType::LVSyllable),
+    (0xCC0D, 0xCC27, HangulSyllableType::LVTSyllable),
+    (0xCC28, 0xCC28, HangulSyllableType::LVSyllable),
+    (0xCC29, 0xCC43, HangulSyllableType::LVTSyllable),
+    (0xCC44, 0xCC44, HangulSyllableType::LVSyllable),
+    (0xCC45, 0xCC5F, HangulSyllableType::LVTSyllable),
+    (0xCC60, 0xCC60, HangulSyllableType::LVSyllable),
+    (0xCC61, 0xCC7B, HangulSyllableType::LVTSyllable),
+    (0xCC7C, 0xCC7C, HangulSyllableType::LVSyllable),
+    (0xCC7D, 0xCC97, HangulSyllableType::LVTSyllable),
+    (0xCC98, 0xCC98, HangulSyllableType::LVSyllable),
+    (0xCC99, 0xCCB3, HangulSyllableType::LVTSyllable),
+    (0xCCB4, 0xCCB4, HangulSyllableType::LVSyllable),
+    (0xCCB5, 0xCCCF, HangulSyllableType::LVTSyllable),
+    (0xCCD0, 0xCCD0, HangulSyllableType::LVSyllable),
+    (0xCCD1, 0xCCEB, HangulSyllableType::LVTSyllable),
+    (0xCCEC, 0xCCEC, HangulSyllableType::LVSyllable),
+    (0xCCED, 0xCD07, HangulSyllableType::LVTSyllable),
+    (0xCD08, 0xCD08, HangulSyllableType::LVSyllable),
+    (0xCD09, 0xCD23, HangulSyllableType::LVTSyllable),
+    (0xCD24, 0xCD24, HangulSyllableType::LVSyllable),
+    (0xCD25, 0xCD3F, HangulSyllableType::LVTSyllable),
+    (0xCD40, 0xCD40, HangulSyllableType::LVSyllable),
+    (0xCD41, 0xCD5B, HangulSyllableType::LVTSyllable),
+    (0xCD5C, 0xCD5C, HangulSyllableType::LVSyllable),
+    (0xCD5D, 0xCD77, HangulSyllableType::LVTSyllable),
+    (0xCD78, 0xCD78, HangulSyllableType::LVSyllable),
+    (0xCD79, 0xCD93, HangulSyllableType::LVTSyllable),
+    (0xCD94, 0xCD94, HangulSyllableType::LVSyllable),
+    (0xCD95, 0xCDAF, HangulSyllableType::LVTSyllable),
+    (0xCDB0, 0xCDB0, HangulSyllableType::LVSyllable),
+    (0xCDB1, 0xCDCB, HangulSyllableType::LVTSyllable),
+    (0xCDCC, 0xCDCC, HangulSyllableType::LVSyllable),
+    (0xCDCD, 0xCDE7, HangulSyllableType::LVTSyllable),
+    (0xCDE8, 0xCDE8, HangulSyllableType::LVSyllable),
+    (0xCDE9, 0xCE03, HangulSyllableType::LVTSyllable),
+    (0xCE04, 0xCE04, HangulSyllableType::LVSyllable),
+    (0xCE05, 0xCE1F, HangulSyllableType::LVTSyllable),
+    (0xCE20, 0xCE20, HangulSyllableType::LVSyllable),
+    (0xCE21, 0xCE3B, HangulSyllableType::LVTSyllable),
+    (0xCE3C, 0xCE3C, HangulSyllableType::LVSyllable),
+    (0xCE3D, 0xCE57, HangulSyllableType::LVTSyllable),
+    (0xCE58, 0xCE58, HangulSyllableType::LVSyllable),
+    (0xCE59, 0xCE73, HangulSyllableType::LVTSyllable),
+    (0xCE74, 0xCE74, HangulSyllableType::LVSyllable),
+    (0xCE75, 0xCE8F, HangulSyllableType::LVTSyllable),
+    (0xCE90, 0xCE90, HangulSyllableType::LVSyllable),
+    (0xCE91, 0xCEAB, HangulSyllableType::LVTSyllable),
+    (0xCEAC, 0xCEAC, HangulSyllableType::LVSyllable),
+    (0xCEAD, 0xCEC7, HangulSyllableType::LVTSyllable),
+    (0xCEC8, 0xCEC8, HangulSyllableType::LVSyllable),
+    (0xCEC9, 0xCEE3, HangulSyllableType::LVTSyllable),
+    (0xCEE4, 0xCEE4, HangulSyllableType::LVSyllable),
+    (0xCEE5, 0xCEFF, HangulSyllableType::LVTSyllable),
+    (0xCF00, 0xCF00, HangulSyllableType::LVSyllable),
+    (0xCF01, 0xCF1B, HangulSyllableType::LVTSyllable),
+    (0xCF1C, 0xCF1C, HangulSyllableType::LVSyllable),
+    (0xCF1D, 0xCF37, HangulSyllableType::LVTSyllable),
+    (0xCF38, 0xCF38, HangulSyllableType::LVSyllable),
+    (0xCF39, 0xCF53, HangulSyllableType::LVTSyllable),
+    (0xCF54, 0xCF54, HangulSyllableType::LVSyllable),
+    (0xCF55, 0xCF6F, HangulSyllableType::LVTSyllable),
+    (0xCF70, 0xCF70, HangulSyllableType::LVSyllable),
+    (0xCF71, 0xCF8B, HangulSyllableType::LVTSyllable),
+    (0xCF8C, 0xCF8C, HangulSyllableType::LVSyllable),
+    (0xCF8D, 0xCFA7, HangulSyllableType::LVTSyllable),
+    (0xCFA8, 0xCFA8, HangulSyllableType::LVSyllable),
+    (0xCFA9, 0xCFC3, HangulSyllableType::LVTSyllable),
+    (0xCFC4, 0xCFC4, HangulSyllableType::LVSyllable),
+    (0xCFC5, 0xCFDF, HangulSyllableType::LVTSyllable),
+    (0xCFE0, 0xCFE0, HangulSyllableType::LVSyllable),
+    (0xCFE1, 0xCFFB, HangulSyllableType::LVTSyllable),
+    (0xCFFC, 0xCFFC, HangulSyllableType::LVSyllable),
+    (0xCFFD, 0xD017, HangulSyllableType::LVTSyllable),
+    (0xD018, 0xD018, HangulSyllableType::LVSyllable),
+    (0xD019, 0xD033, HangulSyllableType::LVTSyllable),
+    (0xD034, 0xD034, HangulSyllableType::LVSyllable),
+    (0xD035, 0xD04F, HangulSyllableType::LVTSyllable),
+    (0xD050, 0xD050, HangulSyllableType::LVSyllable),
+    (0xD051, 0xD06B, HangulSyllableType::LVTSyllable),
+    (0xD06C, 0xD06C, HangulSyllableType::LVSyllable),
+    (0xD06D, 0xD087, HangulSyllableType::LVTSyllable),
+    (0xD088, 0xD088, HangulSyllableType::LVSyllable),
+    (0xD089, 0xD0A3, HangulSyllableType::LVTSyllable),
+    (0xD0A4, 0xD0A4, HangulSyllableType::LVSyllable),
+    (0xD0A5, 0xD0BF, HangulSyllableType::LVTSyllable),
+    (0xD0C0, 0xD0C0, HangulSyllableType::LVSyllable),
+    (0xD0C1, 0xD0DB, HangulSyllableType::LVTSyllable),
+    (0xD0DC, 0xD0DC, HangulSyllableType::LVSyllable),
+    (0xD0DD, 0xD0F7, HangulSyllableType::LVTSyllable),
+    (0xD0F8, 0xD0F8, HangulSyllableType::LVSyllable),
+    (0xD0F9, 0xD113, HangulSyllableType::LVTSyllable),
+    (0xD114, 0xD114, HangulSyllableType::LVSyllable),
+    (0xD115, 0xD12F, HangulSyllableType::LVTSyllable),
+    (0xD130, 0xD130, HangulSyllableType::LVSyllable),
+    (0xD131, 0xD14B, HangulSyllableType::LVTSyllable),
+    (0xD14C, 0xD14C, HangulSyllableType::LVSyllable),
+    (0xD14D, 0xD167, HangulSyllableType::LVTSyllable),
+    (0xD168, 0xD168, HangulSyllableType::LVSyllable),
+    (0xD169, 0xD183, HangulSyllableType::LVTSyllable),
+    (0xD184, 0xD184, HangulSyllableType::LVSyllable),
+    (0xD185, 0xD19F, HangulSyllableType::LVTSyllable),
+    (0xD1A0, 0xD1A0, HangulSyllableType::LVSyllable),
+    (0xD1A1, 0xD1BB, HangulSyllableType::LVTSyllable),
+    (0xD1BC, 0xD1BC, HangulSyllableType::LVSyllable),
+    (0xD1BD, 0xD1D7, HangulSyllableType::LVTSyllable),
+    (0xD1D8, 0xD1D8, HangulSyllableType::LVSyllable),
+    (0xD1D9, 0xD1F3, HangulSyllableType::LVTSyllable),
+    (0xD1F4, 0xD1F4, HangulSyllableType::LVSyllable),
+    (0xD1F5, 0xD20F, HangulSyllableType::LVTSyllable),
+    (0xD210, 0xD210, HangulSyllableType::LVSyllable),
+    (0xD211, 0xD22B, HangulSyllableType::LVTSyllable),
+    (0xD22C, 0xD22C, HangulSyllableType::LVSyllable),
+    (0xD22D, 0xD247, HangulSyllableType::LVTSyllable),
+    (0xD248, 0xD248, HangulSyllableType::LVSyllable),
+    (0xD249, 0xD263, HangulSyllableType::LVTSyllable),
+    (0xD264, 0xD264, HangulSyllableType::LVSyllable),
+    (0xD265, 0xD27F, HangulSyllableType::LVTSyllable),
+    (0xD280, 0xD280, HangulSyllableType::LVSyllable),
+    (0xD281, 0xD29B, HangulSyllableType::LVTSyllable),
+    (0xD29C, 0xD29C, HangulSyllableType::LVSyllable),
+    (0xD29D, 0xD2B7, HangulSyllableType::LVTSyllable),
+    (0xD2B8, 0xD2B8, HangulSyllableType::LVSyllable),
+    (0xD2B9, 0xD2D3, HangulSyllableType::LVTSyllable),
+    (0xD2D4, 0xD2D4, HangulSyllableType::LVSyllable),
+    (0xD2D5, 0xD2EF, HangulSyllableType::LVTSyllable),
+    (0xD2F0, 0xD2F0, HangulSyllableType::LVSyllable),
+    (0xD2F1, 0xD30B, HangulSyllableType::LVTSyllable),
+    (0xD30C, 0xD30C, HangulSyllableType::LVSyllable),
+    (0xD30D, 0xD327, HangulSyllableType::LVTSyllable),
+    (0xD328, 0xD328, HangulSyllableType::LVSyllable),
+    (0xD329, 0xD343, HangulSyllableType::LVTSyllable),
+    (0xD344, 0xD344, HangulSyllableType::LVSyllable),
+    (0xD345, 0xD35F, HangulSyllableType::LVTSyllable),
+    (0xD360, 0xD360, HangulSyllableType::LVSyllable),
+    (0xD361, 0xD37B, HangulSyllableType::LVTSyllable),
+    (0xD37C, 0xD37C, HangulSyllableType::LVSyllable),
+    (0xD37D, 0xD397, HangulSyllableType::LVTSyllable),
+    (0xD398, 0xD398, HangulSyllableType::LVSyllable),
+    (0xD399, 0xD3B3, HangulSyllableType::LVTSyllable),
+    (0xD3B4, 0xD3B4, HangulSyllableType::LVSyllable),
+    (0xD3B5, 0xD3CF, HangulSyllableType::LVTSyllable),
+    (0xD3D0, 0xD3D0, HangulSyllableType::LVSyllable),
+    (0xD3D1, 0xD3EB, HangulSyllableType::LVTSyllable),
+    (0xD3EC, 0xD3EC, HangulSyllableType::LVSyllable),
+    (0xD3ED, 0xD407, HangulSyllableType::LVTSyllable),
+    (0xD408, 0xD408, HangulSyllableType::LVSyllable),
+    (0xD409, 0xD423, HangulSyllableType::LVTSyllable),
+    (0xD424, 0xD424, HangulSyllableType::LVSyllable),
+    (0xD425, 0xD43F, HangulSyllableType::LVTSyllable),
+    (0xD440, 0xD440, HangulSyllableType::LVSyllable),
+    (0xD441, 0xD45B, HangulSyllableType::LVTSyllable),
+    (0xD45C, 0xD45C, HangulSyllableType::LVSyllable),
+    (0xD45D, 0xD477, HangulSyllableType::LVTSyllable),
+    (0xD478, 0xD478, HangulSyllableType::LVSyllable),
+    (0xD479, 0xD493, HangulSyllableType::LVTSyllable),
+    (0xD494, 0xD494, HangulSyllableType::LVSyllable),
+    (0xD495, 0xD4AF, HangulSyllableType::LVTSyllable),
+    (0xD4B0, 0xD4B0, HangulSyllableType::LVSyllable),
+    (0xD4B1, 0xD4CB, HangulSyllableType::LVTSyllable),
+    (0xD4CC, 0xD4CC, HangulSyllableType::LVSyllable),
+    (0xD4CD, 0xD4E7, HangulSyllableType::LVTSyllable),
+    (0xD4E8, 0xD4E8, HangulSyllableType::LVSyllable),
+    (0xD4E9, 0xD503, HangulSyllableType::LVTSyllable),
+    (0xD504, 0xD504, HangulSyllableType::LVSyllable),
+    (0xD505, 0xD51F, HangulSyllableType::LVTSyllable),
+    (0xD520, 0xD520, HangulSyllableType::LVSyllable),
+    (0xD521, 0xD53B, HangulSyllableType::LVTSyllable),
+    (0xD53C, 0xD53C, HangulSyllableType::LVSyllable),
+    (0xD53D, 0xD557, HangulSyllableType::LVTSyllable),
+    (0xD558, 0xD558, HangulSyllableType::LVSyllable),
+    (0xD559, 0xD573, HangulSyllableType::LVTSyllable),
+    (0xD574, 0xD574, HangulSyllableType::LVSyllable),
+    (0xD575, 0xD58F, HangulSyllableType::LVTSyllable),
+    (0xD590, 0xD590, HangulSyllableType::LVSyllable),
+    (0xD591, 0xD5AB, HangulSyllableType::LVTSyllable),
+    (0xD5AC, 0xD5AC, HangulSyllableType::LVSyllable),
+    (0xD5AD, 0xD5C7, HangulSyllableType::LVTSyllable),
+    (0xD5C8, 0xD5C8, HangulSyllableType::LVSyllable),
+    (0xD5C9, 0xD5E3, HangulSyllableType::LVTSyllable),
+    (0xD5E4, 0xD5E4, HangulSyllableType::LVSyllable),
+    (0xD5E5, 0xD5FF, HangulSyllableType::LVTSyllable),
+    (0xD600, 0xD600, HangulSyllableType::LVSyllable),
+    (0xD601, 0xD61B, HangulSyllableType::LVTSyllable),
+    (0xD61C, 0xD61C, HangulSyllableType::LVSyllable),
+    (0xD61D, 0xD637, HangulSyllableType::LVTSyllable),
+    (0xD638, 0xD638, HangulSyllableType::LVSyllable),
+    (0xD639, 0xD653, HangulSyllableType::LVTSyllable),
+    (0xD654, 0xD654, HangulSyllableType::LVSyllable),
+    (0xD655, 0xD66F, HangulSyllableType::LVTSyllable),
+    (0xD670, 0xD670, HangulSyllableType::LVSyllable),
+    (0xD671, 0xD68B, HangulSyllableType::LVTSyllable),
+    (0xD68C, 0xD68C, HangulSyllableType::LVSyllable),
+    (0xD68D, 0xD6A7, HangulSyllableType::LVTSyllable),
+    (0xD6A8, 0xD6A8, HangulSyllableType::LVSyllable),
+    (0xD6A9, 0xD6C3, HangulSyllableType::LVTSyllable),
+    (0xD6C4, 0xD6C4, HangulSyllableType::LVSyllable),
+    (0xD6C5, 0xD6DF, HangulSyllableType::LVTSyllable),
+    (0xD6E0, 0xD6E0, HangulSyllableType::LVSyllable),
+    (0xD6E1, 0xD6FB, HangulSyllableType::LVTSyllable),
+    (0xD6FC, 0xD6FC, HangulSyllableType::LVSyllable),
+    (0xD6FD, 0xD717, HangulSyllableType::LVTSyllable),
+    (0xD718, 0xD718, HangulSyllableType::LVSyllable),
+    (0xD719, 0xD733, HangulSyllableType::LVTSyllable),
+    (0xD734, 0xD734, HangulSyllableType::LVSyllable),
+    (0xD735, 0xD74F, HangulSyllableType::LVTSyllable),
+    (0xD750, 0xD750, HangulSyllableType::LVSyllable),
+    (0xD751, 0xD76B, HangulSyllableType::LVTSyllable),
+    (0xD76C, 0xD76C, HangulSyllableType::LVSyllable),
+    (0xD76D, 0xD787, HangulSyllableType::LVTSyllable),
+    (0xD788, 0xD788, HangulSyllableType::LVSyllable),
+    (0xD789, 0xD7A3, HangulSyllableType::LVTSyllable),
+    (0xD7B0, 0xD7C6, HangulSyllableType::VowelJamo),
+    (0xD7CB, 0xD7FB, HangulSyllableType::TrailingJamo),
+];
@@ -0,0 +1,694 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (IndicPositionalCategory.txt and PropertyValueAliases.txt),
+// limited to the characters assigned in Unicode 13.0.
+
+use super::IndicPositionalCategory;
+
+pub(super) const VALUES: &[IndicPositionalCategory] = &[
+    IndicPositionalCategory::Bottom,
+    IndicPositionalCategory::BottomAndLeft,
+    IndicPositionalCategory::BottomAndRight,
+    IndicPositionalCategory::Left,
+    IndicPositionalCategory::LeftAndRight,
+    IndicPositionalCategory::NotApplicable,
+    IndicPositionalCategory::Overstruck,
+    IndicPositionalCategory::Right,
+    IndicPositionalCategory::Top,
+    IndicPositionalCategory::TopAndBottom,
+    IndicPositionalCategory::TopAndBottomAndLeft,
+    IndicPositionalCategory::TopAndBottomAndRight,
+    IndicPositionalCategory::TopAndLeft,
+    IndicPositionalCategory::TopAndLeftAndRight,
+    IndicPositionalCategory::TopAndRight,
+    IndicPositionalCategory::VisualOrderLeft,
+];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[
+    ("Bottom", "Bottom", "Bottom"),
+    ("Bottom_And_Left", "Bottom_And_Left", "Bottom And Left"),
+    ("Bottom_And_Right", "Bottom_And_Right", "Bottom And Right"),
+    ("Left", "Left", "Left"),
+    ("Left_And_Right", "Left_And_Right", "Left And Right"),
+    ("NA", "NA", "Not Applicable"),
+    ("Overstruck", "Overstruck", "Overstruck"),
+    ("Right", "Right", "Right"),
+    ("Top", "Top", "Top"),
+    ("Top_And_Bottom", "Top_And_Bottom", "Top And Bottom"),
+    (
+        "Top_And_Bottom_And_Left",
+        "Top_And_Bottom_And_Left",
+        "Top And Bottom And Left",
+    ),
+    (
+        "Top_And_Bottom_And_Right",
+        "Top_And_Bottom_And_Right",
+        "Top And Bottom And Right",
+    ),
+    ("Top_And_Left", "Top_And_Left", "Top And Left"),
+    (
+        "Top_And_Left_And_Right",
+        "Top_And_Left_And_Right",
+        "Top And Left And Right",
+    ),
+    ("Top_And_Right", "Top_And_Right", "Top And Right"),
+    (
+        "Visual_Order_Left",
+        "Visual_Order_Left",
+        "Visual Order Left",
+    ),
+];
+
+pub(super) const RANGES: &[(u32, u32, IndicPositionalCategory)] = &[
+    (0x0900, 0x0902, IndicPositionalCategory::Top),
+    (0x0903, 0x0903, IndicPositionalCategory::Right),
+    (0x093A, 0x093A, IndicPositionalCategory::Top),
+    (0x093B, 0x093B, IndicPositionalCategory::Right),
+    (0x093C, 0x093C, IndicPositionalCategory::Bottom),
+    (0x093E, 0x093E, IndicPositionalCategory::Right),
+    (0x093F, 0x093F, IndicPositionalCategory::Left),
+    (0x0940, 0x0940, IndicPositionalCategory::Right),
+    (0x0941, 0x0944, IndicPositionalCategory::Bottom),
+    (0x0945, 0x0948, IndicPositionalCategory::Top),
+    (0x0949, 0x094C, IndicPositionalCategory::Right),
+    (0x094D, 0x094D, IndicPositionalCategory::Bottom),
+    (0x094E, 0x094E, IndicPositionalCategory::Left),
+    (0x094F, 0x094F, IndicPositionalCategory::Right),
+    (0x0951, 0x0951, IndicPositionalCategory::Top),
+    (0x0952, 0x0952, IndicPositionalCategory::Bottom),
+    (0x0953, 0x0955, IndicPositionalCategory::Top),
+    (0x0956, 0x0957, IndicPositionalCategory::Bottom),
+    (0x0962, 0x0963, IndicPositionalCategory::Bottom),
+    (0x0981, 0x0981, IndicPositionalCategory::Top),
+    (0x0982, 0x0983, IndicPositionalCategory::Right),
+    (0x09BC, 0x09BC, IndicPositionalCategory::Bottom),
+    (0x09BE, 0x09BE, IndicPositionalCategory::Right),
+    (0x09BF, 0x09BF, IndicPositionalCategory::Left),
+    (0x09C0, 0x09C0, IndicPositionalCategory::Right),
+    (0x09C1, 0x09C4, IndicPositionalCategory::Bottom),
+    (0x09C7, 0x09C8, IndicPositionalCategory::Left),
+    (0x09CB, 0x09CC, IndicPositionalCategory::LeftAndRight),
+    (0x09CD, 0x09CD, IndicPositionalCategory::Bottom),
+    (0x09D7, 0x09D7, IndicPositionalCategory::Right),
+    (0x09E2, 0x09E3, IndicPositionalCategory::Bottom),
+    (0x09FE, 0x09FE, IndicPositionalCategory::Top),
+    (0x0A01, 0x0A02, IndicPositionalCategory::Top),
+    (0x0A03, 0x0A03, IndicPositionalCategory::Right),
+    (0x0A3C, 0x0A3C, IndicPositionalCategory::Bottom),
+    (0x0A3E, 0x0A3E, IndicPositionalCategory::Right),
+    (0x0A3F, 0x0A3F, IndicPositionalCategory::Left),
+    (0x0A40, 0x0A40, IndicPositionalCategory::Right),
+    (0x0A41, 0x0A42, IndicPositionalCategory::Bottom),
+    (0x0A47, 0x0A48, IndicPositionalCategory::Top),
+    (0x0A4B, 0x0A4C, IndicPositionalCategory::Top),
+    (0x0A4D, 0x0A4D, IndicPositionalCategory::Bottom),
+    (0x0A51, 0x0A51, IndicPositionalCategory::Bottom),
+    (0x0A70, 0x0A71, IndicPositionalCategory::Top),
+    (0x0A75, 0x0A75, IndicPositionalCategory::Bottom),
+    (0x0A81, 0x0A82, IndicPositionalCategory::Top),
+    (0x0A83, 0x0A83, IndicPositionalCategory::Right),
+    (0x0ABC, 0x0ABC, IndicPositionalCategory::Bottom),
+    (0x0ABE, 0x0ABE, IndicPositionalCategory::Right),
+    (0x0ABF, 0x0ABF, IndicPositionalCategory::Left),
+    (0x0AC0, 0x0AC0, IndicPositionalCategory::Right),
+    (0x0AC1, 0x0AC4, IndicPositionalCategory::Bottom),
+    (0x0AC5, 0x0AC5, IndicPositionalCategory::Top),
+    (0x0AC7, 0x0AC8, IndicPositionalCategory::Top),
+    (0x0AC9, 0x0AC9, IndicPositionalCategory::TopAndRight),
+    (0x0ACB, 0x0ACC, IndicPositionalCategory::Right),
+    (0x0ACD, 0x0ACD, IndicPositionalCategory::Bottom),
+    (0x0AE2, 0x0AE3, IndicPositionalCategory::Bottom),
+    (0x0AFA, 0x0AFF, IndicPositionalCategory::Top),
+    (0x0B01, 0x0B01, IndicPositionalCategory::Top),
+    (0x0B02, 0x0B03, IndicPositionalCategory::Right),
+    (0x0B3C, 0x0B3C, IndicPositionalCategory::Bottom),
+    (0x0B3E, 0x0B3E, IndicPositionalCategory::Right),
+    (0x0B3F, 0x0B3F, IndicPositionalCategory::Top),
+    (0x0B40, 0x0B40, IndicPositionalCategory::Right),
+    (0x0B41, 0x0B44, IndicPositionalCategory::Bottom),
+    (0x0B47, 0x0B47, IndicPositionalCategory::Left),
+    (0x0B48, 0x0B48, IndicPositionalCategory::TopAndLeft),
+    (0x0B4B, 0x0B4B, IndicPositionalCategory::LeftAndRight),
+    (0x0B4C, 0x0B4C, IndicPositionalCategory::TopAndLeftAndRight),
+    (0x0B4D, 0x0B4D, IndicPositionalCategory::Bottom),
+    (0x0B55, 0x0B56, IndicPositionalCategory::Top),
+    (0x0B57, 0x0B57, IndicPositionalCategory::TopAndRight),
+    (0x0B62, 0x0B63, IndicPositionalCategory::Bottom),
+    (0x0B82, 0x0B82, IndicPositionalCategory::Top),
+    (0x0BBE, 0x0BBF, IndicPositionalCategory::Right),
+    (0x0BC0, 0x0BC0, IndicPositionalCategory::Top),
+    (0x0BC1, 0x0BC2, IndicPositionalCategory::Right),
+    (0x0BC6, 0x0BC8, IndicPositionalCategory::Left),
+    (0x0BCA, 0x0BCC, IndicPositionalCategory::LeftAndRight),
+    (0x0BCD, 0x0BCD, IndicPositionalCategory::Top),
+    (0x0BD7, 0x0BD7, IndicPositionalCategory::Right),
+    (0x0C00, 0x0C00, IndicPositionalCategory::Top),
+    (0x0C01, 0x0C03, IndicPositionalCategory::Right),
+    (0x0C04, 0x0C04, IndicPositionalCategory::Top),
+    (0x0C3E, 0x0C40, IndicPositionalCategory::Top),
+    (0x0C41, 0x0C44, IndicPositionalCategory::Right),
+    (0x0C46, 0x0C47, IndicPositionalCategory::Top),
+    (0x0C48, 0x0C48, IndicPositionalCategory::TopAndBottom),
+    (0x0C4A, 0x0C4D, IndicPositionalCategory::Top),
+    (0x0C55, 0x0C55, IndicPositionalCategory::Top),
+    (0x0C56, 0x0C56, IndicPositionalCategory::Bottom),
+    (0x0C62, 0x0C63, IndicPositionalCategory::Bottom),
+    (0x0C81, 0x0C81, IndicPositionalCategory::Top),
+    (0x0C82, 0x0C83, IndicPositionalCategory::Right),
+    (0x0CBC, 0x0CBC, IndicPositionalCategory::Bottom),
+    (0x0CBE, 0x0CBE, IndicPositionalCategory::Right),
+    (0x0CBF, 0x0CBF, IndicPositionalCategory::Top),
+    (0x0CC0, 0x0CC0, IndicPositionalCategory::TopAndRight),
+    (0x0CC1, 0x0CC4, IndicPositionalCategory::Right),
+    (0x0CC6, 0x0CC6, IndicPositionalCategory::Top),
+    (0x0CC7, 0x0CC8, IndicPositionalCategory::TopAndRight),
+    (0x0CCA, 0x0CCB, IndicPositionalCategory::TopAndRight),
+    (0x0CCC, 0x0CCD, IndicPositionalCategory::Top),
+    (0x0CD5, 0x0CD6, IndicPositionalCategory::Right),
+    (0x0CE2, 0x0CE3, IndicPositionalCategory::Bottom),
+    (0x0D00, 0x0D01, IndicPositionalCategory::Top),
+    (0x0D02, 0x0D03, IndicPositionalCategory::Right),
+    (0x0D3B, 0x0D3C, IndicPositionalCategory::Top),
+    (0x0D3E, 0x0D42, IndicPositionalCategory::Right),
+    (0x0D43, 0x0D44, IndicPositionalCategory::Bottom),
+    (0x0D46, 0x0D48, IndicPositionalCategory::Left),
+    (0x0D4A, 0x0D4C, IndicPositionalCategory::LeftAndRight),
+    (0x0D4D, 0x0D4E, IndicPositionalCategory::Top),
+    (0x0D57, 0x0D57, IndicPositionalCategory::Right),
+    (0x0D62, 0x0D63, IndicPositionalCategory::Bottom),
+    (0x0D81, 0x0D81, IndicPositionalCategory::Top),
+    (0x0D82, 0x0D83, IndicPositionalCategory::Right),
+    (0x0DCA, 0x0DCA, IndicPositionalCategory::Top),
+    (0x0DCF, 0x0DD1, IndicPositionalCategory::Right),
+    (0x0DD2, 0x0DD3, IndicPositionalCategory::Top),
+    (0x0DD4, 0x0DD4, IndicPositionalCategory::Bottom),
+    (0x0DD6, 0x0DD6, IndicPositionalCategory::Bottom),
+    (0x0DD8, 0x0DD8, IndicPositionalCategory::Right),
+    (0x0DD9, 0x0DD9, IndicPositionalCategory::Left),
+    (0x0DDA, 0x0DDA, IndicPositionalCategory::TopAndLeft),
+    (0x0DDB, 0x0DDB, IndicPositionalCategory::Left),
+    (0x0DDC, 0x0DDC, IndicPositionalCategory::LeftAndRight),
+    (0x0DDD, 0x0DDD, IndicPositionalCategory::TopAndLeftAndRight),
+    (0x0DDE, 0x0DDE, IndicPositionalCategory::LeftAndRight),
+    (0x0DDF, 0x0DDF, IndicPositionalCategory::Right),
+    (0x0DF2, 0x0DF3, IndicPositionalCategory::Right),
+    (0x0E30, 0x0E30, IndicPositionalCategory::Right),
+    (0x0E31, 0x0E31, IndicPositionalCategory::Top),
+    (0x0E32, 0x0E33, IndicPositionalCategory::Right),
+    (0x0E34, 0x0E37, IndicPositionalCategory::Top),
+    (0x0E38, 0x0E3A, IndicPositionalCategory::Bottom),
+    (0x0E40, 0x0E44, IndicPositionalCategory::VisualOrderLeft),
+    (0x0E45, 0x0E45, IndicPositionalCategory::Right),
+    (0x0E47, 0x0E4E, IndicPositionalCategory::Top),
+    (0x0EB0, 0x0EB0, IndicPositionalCategory::Right),
+    (0x0EB1, 0x0EB1, IndicPositionalCategory::Top),
+    (0x0EB2, 0x0EB3, IndicPositionalCategory::Right),
+    (0x0EB4, 0x0EB7, IndicPositionalCategory::Top),
+    (0x0EB8, 0x0EBA, IndicPositionalCategory::Bottom),
+    (0x0EBB, 0x0EBB, IndicPositionalCategory::Top),
+    (0x0EBC, 0x0EBC, IndicPositionalCategory::Bottom),
+    (0x0EC0, 0x0EC4, IndicPositionalCategory::VisualOrderLeft),
+    (0x0EC8, 0x0ECD, IndicPositionalCategory::Top),
+    (0x0F18, 0x0F19, IndicPositionalCategory::Bottom),
+    (0x0F35, 0x0F35, IndicPositionalCategory::Bottom),
+    (0x0F37, 0x0F37, IndicPositionalCategory::Bottom),
+    (0x0F39, 0x0F39, IndicPositionalCategory::Top),
+    (0x0F3E, 0x0F3E, IndicPositionalCategory::Right),
+    (0x0F3F, 0x0F3F, IndicPositionalCategory::Left),
+    (0x0F71, 0x0F71, IndicPositionalCategory::Bottom),
+    (0x0F72, 0x0F72, IndicPositionalCategory::Top),
+    (0x0F73, 0x0F73, IndicPositionalCategory::TopAndBottom),
+    (0x0F74, 0x0F75, IndicPositionalCategory::Bottom),
+    (0x0F76, 0x0F79, IndicPositionalCategory::TopAndBottom),
+    (0x0F7A, 0x0F7E, IndicPositionalCategory::Top),
+    (0x0F7F, 0x0F7F, IndicPositionalCategory::Right),
+    (0x0F80, 0x0F80, IndicPositionalCategory::Top),
+    (0x0F81, 0x0F81, IndicPositionalCategory::TopAndBottom),
+    (0x0F82, 0x0F83, IndicPositionalCategory::Top),
+    (0x0F84, 0x0F84, IndicPositionalCategory::Bottom),
+    (0x0F86, 0x0F87, IndicPositionalCategory::Top),
+    (0x0F8D, 0x0F97, IndicPositionalCategory::Bottom),
+    (0x0F99, 0x0FBC, IndicPositionalCategory::Bottom),
+    (0x0FC6, 0x0FC6, IndicPositionalCategory::Bottom),
+    (0x102B, 0x102C, IndicPositionalCategory::Right),
+    (0x102D, 0x102E, IndicPositionalCategory::Top),
+    (0x102F, 0x1030, IndicPositionalCategory::Bottom),
+    (0x1031, 0x1031, IndicPositionalCategory::Left),
+    (0x1032, 0x1036, IndicPositionalCategory::Top),
+    (0x1037, 0x1037, IndicPositionalCategory::Bottom),
+    (0x1038, 0x1038, IndicPositionalCategory::Right),
+    (0x103A, 0x103A, IndicPositionalCategory::Top),
+    (0x103B, 0x103B, IndicPositionalCategory::Right),
+    (0x103C, 0x103C, IndicPositionalCategory::TopAndBottomAndLeft),
+    (0x103D, 0x103E, IndicPositionalCategory::Bottom),
+    (0x1056, 0x1057, IndicPositionalCategory::Right),
+    (0x1058, 0x1059, IndicPositionalCategory::Bottom),
+    (0x105E, 0x1060, IndicPositionalCategory::Bottom),
+    (0x1062, 0x1064, IndicPositionalCategory::Right),
+    (0x1067, 0x106D, IndicPositionalCategory::Right),
+    (0x1071, 0x1074, IndicPositionalCategory::Top),
+    (0x1082, 0x1082, IndicPositionalCategory::Bottom),
+    (0x1083, 0x1083, IndicPositionalCategory::Right),
+    (0x1084, 0x1084, IndicPositionalCategory::Left),
+    (0x1085, 0x1086, IndicPositionalCategory::Top),
+    (0x1087, 0x108C, IndicPositionalCategory::Right),
+    (0x108D, 0x108D, IndicPositionalCategory::Bottom),
+    (0x108F, 0x108F, IndicPositionalCategory::Right),
+    (0x109A, 0x109C, IndicPositionalCategory::Right),
+    (0x109D, 0x109D, IndicPositionalCategory::Top),
+    (0x1712, 0x1712, IndicPositionalCategory::Top),
+    (0x1713, 0x1714, IndicPositionalCategory::Bottom),
+    (0x1732, 0x1732, IndicPositionalCategory::Top),
+    (0x1733, 0x1733, IndicPositionalCategory::Bottom),
+    (0x1734, 0x1734, IndicPositionalCategory::Right),
+    (0x1752, 0x1752, IndicPositionalCategory::Top),
+    (0x1753, 0x1753, IndicPositionalCategory::Bottom),
+    (0x1772, 0x1772, IndicPositionalCategory::Top),
+    (0x1773, 0x1773, IndicPositionalCategory::Bottom),
+    (0x17B6, 0x17B6, IndicPositionalCategory::Right),
+    (0x17B7, 0x17BA, IndicPositionalCategory::Top),
+    (0x17BB, 0x17BD, IndicPositionalCategory::Bottom),
+    (0x17BE, 0x17BE, IndicPositionalCategory::TopAndLeft),
+    (0x17BF, 0x17BF, IndicPositionalCategory::TopAndLeftAndRight),
+    (0x17C0, 0x17C0, IndicPositionalCategory::LeftAndRight),
+    (0x17C1, 0x17C3, IndicPositionalCategory::Left),
+    (0x17C4, 0x17C5, IndicPositionalCategory::LeftAndRight),
+    (0x17C6, 0x17C6, IndicPositionalCategory::Top),
+    (0x17C7, 0x17C8, IndicPositionalCategory::Right),
+    (0x17C9, 0x17D1, IndicPositionalCategory::Top),
+    (0x17D3, 0x17D3, IndicPositionalCategory::Top),
+    (0x17DD, 0x17DD, IndicPositionalCategory::Top),
+    (0x1920, 0x1921, IndicPositionalCategory::Top),
+    (0x1922, 0x1922, IndicPositionalCategory::Bottom),
+    (0x1923, 0x1924, IndicPositionalCategory::Right),
+    (0x1925, 0x1926, IndicPositionalCategory::TopAndRight),
+    (0x1927, 0x1928, IndicPositionalCategory::Top),
+    (0x1929, 0x192B, IndicPositionalCategory::Right),
+    (0x1930, 0x1931, IndicPositionalCategory::Right),
+    (0x1932, 0x1932, IndicPositionalCategory::Bottom),
+    (0x1933, 0x1938, IndicPositionalCategory::Right),
+    (0x1939, 0x1939, IndicPositionalCategory::Bottom),
+    (0x193A, 0x193A, IndicPositionalCategory::Top),
+    (0x193B, 0x193B, IndicPositionalCategory::Bottom),
+    (0x19B0, 0x19B4, IndicPositionalCategory::Right),
+    (0x19B5, 0x19B7, IndicPositionalCategory::VisualOrderLeft),
+    (0x19B8, 0x19B9, IndicPositionalCategory::Right),
+    (0x19BA, 0x19BA, IndicPositionalCategory::VisualOrderLeft),
+    (0x19BB, 0x19C0, IndicPositionalCategory::Right),
+    (0x19C8, 0x19C9, IndicPositionalCategory::Right),
+    (0x1A17, 0x1A17, IndicPositionalCategory::Top),
+    (0x1A18, 0x1A18, IndicPositionalCategory::Bottom),
+    (0x1A19, 0x1A19, IndicPositionalCategory::Left),
+    (0x1A1A, 0x1A1A, IndicPositionalCategory::Right),
+    (0x1A1B, 0x1A1B, IndicPositionalCategory::Top),
+    (0x1A55, 0x1A55, IndicPositionalCategory::Left),
+    (0x1A56, 0x1A56, IndicPositionalCategory::Bottom),
+    (0x1A57, 0x1A57, IndicPositionalCategory::Right),
+    (0x1A58, 0x1A5A, IndicPositionalCategory::Top),
+    (0x1A5B, 0x1A5E, IndicPositionalCategory::Bottom),
+    (0x1A61, 0x1A61, IndicPositionalCategory::Right),
+    (0x1A62, 0x1A62, IndicPositionalCategory::Top),
+    (0x1A63, 0x1A64, IndicPositionalCategory::Right),
+    (0x1A65, 0x1A68, IndicPositionalCategory::Top),
+    (0x1A69, 0x1A6A, IndicPositionalCategory::Bottom),
+    (0x1A6B, 0x1A6B, IndicPositionalCategory::Top),
+    (0x1A6C, 0x1A6C, IndicPositionalCategory::Bottom),
+    (0x1A6D, 0x1A6D, IndicPositionalCategory::Right),
+    (0x1A6E, 0x1A72, IndicPositionalCategory::Left),
+    (0x1A73, 0x1A7C, IndicPositionalCategory::Top),
+    (0x1A7F, 0x1A7F, IndicPositionalCategory::Bottom),
+    (0x1B00, 0x1B03, IndicPositionalCategory::Top),
+    (0x1B04, 0x1B04, IndicPositionalCategory::Right),
+    (0x1B34, 0x1B34, IndicPositionalCategory::Top),
+    (0x1B35, 0x1B35, IndicPositionalCategory::Right),
+    (0x1B36, 0x1B37, IndicPositionalCategory::Top),
+    (0x1B38, 0x1B3A, IndicPositionalCategory::Bottom),
+    (0x1B3B, 0x1B3B, IndicPositionalCategory::BottomAndRight),
+    (0x1B3C, 0x1B3C, IndicPositionalCategory::TopAndBottom),
+    (
+        0x1B3D,
+        0x1B3D,
+        IndicPositionalCategory::TopAndBottomAndRight,
+    ),
+    (0x1B3E, 0x1B3F, IndicPositionalCategory::Left),
+    (0x1B40, 0x1B41, IndicPositionalCategory::LeftAndRight),
+    (0x1B42, 0x1B42, IndicPositionalCategory::Top),
+    (0x1B43, 0x1B43, IndicPositionalCategory::TopAndRight),
+    (0x1B44, 0x1B44, IndicPositionalCategory::Right),
+    (0x1B6B, 0x1B6B, IndicPositionalCategory::Top),
+    (0x1B6C, 0x1B6C, IndicPositionalCategory::Bottom),
+    (0x1B6D, 0x1B73, IndicPositionalCategory::Top),
+    (0x1B80, 0x1B81, IndicPositionalCategory::Top),
+    (0x1B82, 0x1B82, IndicPositionalCategory::Right),
+    (0x1BA1, 0x1BA1, IndicPositionalCategory::Right),
+    (0x1BA2, 0x1BA3, IndicPositionalCategory::Bottom),
+    (0x1BA4, 0x1BA4, IndicPositionalCategory::Top),
+    (0x1BA5, 0x1BA5, IndicPositionalCategory::Bottom),
+    (0x1BA6, 0x1BA6, IndicPositionalCategory::Left),
+    (0x1BA7, 0x1BA7, IndicPositionalCategory::Right),
+    (0x1BA8, 0x1BA9, IndicPositionalCategory::Top),
+    (0x1BAA, 0x1BAA, IndicPositionalCategory::Right),
+    (0x1BAC, 0x1BAD, IndicPositionalCategory::Bottom),
+    (0x1BE6, 0x1BE6, IndicPositionalCategory::Top),
+    (0x1BE7, 0x1BE7, IndicPositionalCategory::Right),
+    (0x1BE8, 0x1BE9, IndicPositionalCategory::Top),
+    (0x1BEA, 0x1BEC, IndicPositionalCategory::Right),
+    (0x1BED, 0x1BED, IndicPositionalCategory::Top),
+    (0x1BEE, 0x1BEE, IndicPositionalCategory::Right),
+    (0x1BEF, 0x1BF1, IndicPositionalCategory::Top),
+    (0x1BF2, 0x1BF3, IndicPositionalCategory::Right),
+    (0x1C24, 0x1C26, IndicPositionalCategory::Right),
+    (0x1C27, 0x1C28, IndicPositionalCategory::Left),
+    (0x1C29, 0x1C29, IndicPositionalCategory::TopAndLeft),
+    (0x1C2A, 0x1C2B, IndicPositionalCategory::Right),
+    (0x1C2C, 0x1C2C, IndicPositionalCategory::Bottom),
+    (0x1C2D, 0x1C33, IndicPositionalCategory::Top),
+    (0x1C34, 0x1C35, IndicPositionalCategory::Left),
+    (0x1C36, 0x1C36, IndicPositionalCategory::Top),
+    (0x1C37, 0x1C37, IndicPositionalCategory::Bottom),
+    (0x1CD0, 0x1CD2, IndicPositionalCategory::Top),
+    (0x1CD4, 0x1CD4, IndicPositionalCategory::Overstruck),
+    (0x1CD5, 0x1CD9, IndicPositionalCategory::Bottom),
+    (0x1CDA, 0x1CDB, IndicPositionalCategory::Top),
+    (0x1CDC, 0x1CDF, IndicPositionalCategory::Bottom),
+    (0x1CE0, 0x1CE0, IndicPositionalCategory::Top),
+    (0x1CE1, 0x1CE1, IndicPositionalCategory::Right),
+    (0x1CE2, 0x1CE8, IndicPositionalCategory::Overstruck),
+    (0x1CED, 0x1CED, IndicPositionalCategory::Bottom),
+    (0x1CF4, 0x1CF4, IndicPositionalCategory::Top),
+    (0x1CF7, 0x1CF7, IndicPositionalCategory::Right),
+    (0x1DFB, 0x1DFB, IndicPositionalCategory::Top),
+    (0x20F0, 0x20F0, IndicPositionalCategory::Top),
+    (0xA802, 0xA802, IndicPositionalCategory::Top),
+    (0xA806, 0xA806, IndicPositionalCategory::Top),
+    (0xA80B, 0xA80B, IndicPositionalCategory::Top),
+    (0xA823, 0xA824, IndicPositionalCategory::Right),
+    (0xA825, 0xA825, IndicPositionalCategory::Bottom),
+    (0xA826, 0xA826, IndicPositionalCategory::Top),
+    (0xA827, 0xA827, IndicPositionalCategory::Right),
+    (0xA82C, 0xA82C, IndicPositionalCategory::Bottom),
+    (0xA880, 0xA881, IndicPositionalCategory::Right),
+    (0xA8B4, 0xA8C3, IndicPositionalCategory::Right),
+    (0xA8C4, 0xA8C4, IndicPositionalCategory::Bottom),
+    (0xA8C5, 0xA8C5, IndicPositionalCategory::Top),
+    (0xA8E0, 0xA8F1, IndicPositionalCategory::Top),
+    (0xA8FF, 0xA8FF, IndicPositionalCategory::Top),
+    (0xA92B, 0xA92D, IndicPositionalCategory::Bottom),
+    (0xA947, 0xA949, IndicPositionalCategory::Bottom),
+    (0xA94A, 0xA94A, IndicPositionalCategory::Top),
+    (0xA94B, 0xA94E, IndicPositionalCategory::Bottom),
+    (0xA94F, 0xA951, IndicPositionalCategory::Top),
+    (0xA952, 0xA953, IndicPositionalCategory::Right),
+    (0xA980, 0xA982, IndicPositionalCategory::Top),
+    (0xA983, 0xA983, IndicPositionalCategory::Right),
+    (0xA9B3, 0xA9B3, IndicPositionalCategory::Top),
+    (0xA9B4, 0xA9B5, IndicPositionalCategory::Right),
+    (0xA9B6, 0xA9B7, IndicPositionalCategory::Top),
+    (0xA9B8, 0xA9B9, IndicPositionalCategory::Bottom),
+    (0xA9BA, 0xA9BB, IndicPositionalCategory::Left),
+    (0xA9BC, 0xA9BC, IndicPositionalCategory::Top),
+    (0xA9BD, 0xA9BD, IndicPositionalCategory::Bottom),
+    (0xA9BE, 0xA9BE, IndicPositionalCategory::BottomAndRight),
+    (0xA9BF, 0xA9BF, IndicPositionalCategory::BottomAndLeft),
+    (0xA9C0, 0xA9C0, IndicPositionalCategory::BottomAndRight),
+    (0xA9E5, 0xA9E5, IndicPositionalCategory::Top),
+    (0xAA29, 0xAA2C, IndicPositionalCategory::Top),
+    (0xAA2D, 0xAA2D, IndicPositionalCategory::Bottom),
+    (0xAA2E, 0xAA2E, IndicPositionalCategory::Top),
+    (0xAA2F, 0xAA30, IndicPositionalCategory::Left),
+    (0xAA31, 0xAA31, IndicPositionalCategory::Top),
+    (0xAA32, 0xAA32, IndicPositionalCategory::Bottom),
+    (0xAA33, 0xAA33, IndicPositionalCategory::Right),
+    (0xAA34, 0xAA34, IndicPositionalCategory::Left),
+    (0xAA35, 0xAA36, IndicPositionalCategory::Bottom),
+    (0xAA43, 0xAA43, IndicPositionalCategory::Top),
+    (0xAA4C, 0xAA4C, IndicPositionalCategory::Top),
+    (0xAA4D, 0xAA4D, IndicPositionalCategory::Right),
+    (0xAA7B, 0xAA7B, IndicPositionalCategory::Right),
+    (0xAA7C, 0xAA7C, IndicPositionalCategory::Top),
+    (0xAA7D, 0xAA7D, IndicPositionalCategory::Right),
+    (0xAAB0, 0xAAB0, IndicPositionalCategory::Top),
+    (0xAAB1, 0xAAB1, IndicPositionalCategory::Right),
+    (0xAAB2, 0xAAB3, IndicPositionalCategory::Top),
+    (0xAAB4, 0xAAB4, IndicPositionalCategory::Bottom),
+    (0xAAB5, 0xAAB6, IndicPositionalCategory::VisualOrderLeft),
+    (0xAAB7, 0xAAB8, IndicPositionalCategory::Top),
+    (0xAAB9, 0xAAB9, IndicPositionalCategory::VisualOrderLeft),
+    (0xAABA, 0xAABA, IndicPositionalCategory::Right),
+    (0xAABB, 0xAABC, IndicPositionalCategory::VisualOrderLeft),
+    (0xAABD, 0xAABD, IndicPositionalCategory::Right),
+    (0xAABE, 0xAABF, IndicPositionalCategory::Top),
+    (0xAAC1, 0xAAC1, IndicPositionalCategory::Top),
+    (0xAAEB, 0xAAEB, IndicPositionalCategory::Left),
+    (0xAAEC, 0xAAEC, IndicPositionalCategory::Bottom),
+    (0xAAED, 0xAAED, IndicPositionalCategory::Top),
+    (0xAAEE, 0xAAEE, IndicPositionalCategory::Left),
+    (0xAAEF, 0xAAEF, IndicPositionalCategory::Right),
+    (0xAAF5, 0xAAF5, IndicPositionalCategory::Right),
+    (0xABE3, 0xABE4, IndicPositionalCategory::Right),
+    (0xABE5, 0xABE5, IndicPositionalCategory::Top),
+    (0xABE6, 0xABE7, IndicPositionalCategory::Right),
+    (0xABE8, 0xABE8, IndicPositionalCategory::Bottom),
+    (0xABE9, 0xABEA, IndicPositionalCategory::Right),
+    (0xABEC, 0xABEC, IndicPositionalCategory::Right),
+    (0xABED, 0xABED, IndicPositionalCategory::Bottom),
+    (0x10A01, 0x10A01, IndicPositionalCategory::Overstruck),
+    (0x10A02, 0x10A03, IndicPositionalCategory::Bottom),
+    (0x10A05, 0x10A05, IndicPositionalCategory::Top),
+    (0x10A06, 0x10A06, IndicPositionalCategory::Overstruck),
+    (0x10A0C, 0x10A0E, IndicPositionalCategory::Bottom),
+    (0x10A0F, 0x10A0F, IndicPositionalCategory::Top),
+    (0x10A38, 0x10A38, IndicPositionalCategory::Top),
+    (0x10A39, 0x10A3A, IndicPositionalCategory::Bottom),
+    (0x11000, 0x11000, IndicPositionalCategory::Right),
+    (0x11001, 0x11001, IndicPositionalCategory::Top),
+    (0x11002, 0x11002, IndicPositionalCategory::Right),
+    (0x11038, 0x1103B, IndicPositionalCategory::Top),
+    (0x1103C, 0x11041, IndicPositionalCategory::Bottom),
+    (0x11042, 0x11046, IndicPositionalCategory::Top),
+    (0x11080, 0x11081, IndicPositionalCategory::Top),
+    (0x11082, 0x11082, IndicPositionalCategory::Right),
+    (0x110B0, 0x110B0, IndicPositionalCategory::Right),
+    (0x110B1, 0x110B1, IndicPositionalCategory::Left),
+    (0x110B2, 0x110B2, IndicPositionalCategory::Right),
+    (0x110B3, 0x110B4, IndicPositionalCategory::Bottom),
+    (0x110B5, 0x110B6, IndicPositionalCategory::Top),
+    (0x110B7, 0x110B8, IndicPositionalCategory::Right),
+    (0x110B9, 0x110BA, IndicPositionalCategory::Bottom),
+    (0x11100, 0x11102, IndicPositionalCategory::Top),
+    (0x11127, 0x11129, IndicPositionalCategory::Top),
+    (0x1112A, 0x1112B, IndicPositionalCategory::Bottom),
+    (0x1112C, 0x1112C, IndicPositionalCategory::Left),
+    (0x1112D, 0x1112D, IndicPositionalCategory::Top),
+    (0x1112E, 0x1112F, IndicPositionalCategory::TopAndBottom),
+    (0x11130, 0x11130, IndicPositionalCategory::Top),
+    (0x11131, 0x11132, IndicPositionalCategory::Bottom),
+    (0x11134, 0x11134, IndicPositionalCategory::Top),
+    (0x11145, 0x11146, IndicPositionalCategory::Right),
+    (0x11173, 0x11173, IndicPositionalCategory::Bottom),
+    (0x11180, 0x11181, IndicPositionalCategory::Top),
+    (0x11182, 0x11182, IndicPositionalCategory::Right),
+    (0x111B3, 0x111B3, IndicPositionalCategory::Right),
+    (0x111B4, 0x111B4, IndicPositionalCategory::Left),
+    (0x111B5, 0x111B5, IndicPositionalCategory::Right),
+    (0x111B6, 0x111BB, IndicPositionalCategory::Bottom),
+    (0x111BC, 0x111BE, IndicPositionalCategory::Top),
+    (0x111BF, 0x111BF, IndicPositionalCategory::TopAndRight),
+    (0x111C0, 0x111C0, IndicPositionalCategory::Right),
+    (0x111C2, 0x111C3, IndicPositionalCategory::Top),
+    (0x111C9, 0x111CA, IndicPositionalCategory::Bottom),
+    (0x111CB, 0x111CB, IndicPositionalCategory::Top),
+    (0x111CC, 0x111CC, IndicPositionalCategory::Bottom),
+    (0x111CE, 0x111CE, IndicPositionalCategory::Left),
+    (0x111CF, 0x111CF, IndicPositionalCategory::Top),
+    (0x1122C, 0x1122E, IndicPositionalCategory::Right),
+    (0x1122F, 0x1122F, IndicPositionalCategory::Bottom),
+    (0x11230, 0x11231, IndicPositionalCategory::Top),
+    (0x11232, 0x11233, IndicPositionalCategory::TopAndRight),
+    (0x11234, 0x11234, IndicPositionalCategory::Top),
+    (0x11235, 0x11235, IndicPositionalCategory::Right),
+    (0x11236, 0x11237, IndicPositionalCategory::Top),
+    (0x1123E, 0x1123E, IndicPositionalCategory::Top),
+    (0x112DF, 0x112DF, IndicPositionalCategory::Top),
+    (0x112E0, 0x112E0, IndicPositionalCategory::Right),
+    (0x112E1, 0x112E1, IndicPositionalCategory::Left),
+    (0x112E2, 0x112E2, IndicPositionalCategory::Right),
+    (0x112E3, 0x112E4, IndicPositionalCategory::Bottom),
+    (0x112E5, 0x112E8, IndicPositionalCategory::Top),
+    (0x112E9, 0x112EA, IndicPositionalCategory::Bottom),
+    (0x11300, 0x11301, IndicPositionalCategory::Top),
+    (0x11302, 0x11303, IndicPositionalCategory::Right),
+    (0x1133B, 0x1133C, IndicPositionalCategory::Bottom),
+    (0x1133E, 0x1133F, IndicPositionalCategory::Right),
+    (0x11340, 0x11340, IndicPositionalCategory::Top),
+    (0x11341, 0x11344, IndicPositionalCategory::Right),
+    (0x11347, 0x11348, IndicPositionalCategory::Left),
+    (0x1134B, 0x1134C, IndicPositionalCategory::LeftAndRight),
+    (0x1134D, 0x1134D, IndicPositionalCategory::Right),
+    (0x11357, 0x11357, IndicPositionalCategory::Right),
+    (0x11362, 0x11363, IndicPositionalCategory::Right),
+    (0x11366, 0x1136C, IndicPositionalCategory::Top),
+    (0x11370, 0x11374, IndicPositionalCategory::Top),
+    (0x11435, 0x11435, IndicPositionalCategory::Right),
+    (0x11436, 0x11436, IndicPositionalCategory::Left),
+    (0x11437, 0x11437, IndicPositionalCategory::Right),
+    (0x11438, 0x1143D, IndicPositionalCategory::Bottom),
+    (0x1143E, 0x1143F, IndicPositionalCategory::Top),
+    (0x11440, 0x11441, IndicPositionalCategory::Right),
+    (0x11442, 0x11442, IndicPositionalCategory::Bottom),
+    (0x11443, 0x11444, IndicPositionalCategory::Top),
+    (0x11445, 0x11445, IndicPositionalCategory::Right),
+    (0x11446, 0x11446, IndicPositionalCategory::Bottom),
+    (0x1145E, 0x1145E, IndicPositionalCategory::Top),
+    (0x114B0, 0x114B0, IndicPositionalCategory::Right),
+    (0x114B1, 0x114B1, IndicPositionalCategory::Left),
+    (0x114B2, 0x114B2, IndicPositionalCategory::Right),
+    (0x114B3, 0x114B8, IndicPositionalCategory::Bottom),
+    (0x114B9, 0x114B9, IndicPositionalCategory::Left),
+    (0x114BA, 0x114BA, IndicPositionalCategory::Top),
+    (0x114BB, 0x114BB, IndicPositionalCategory::TopAndLeft),
+    (0x114BC, 0x114BC, IndicPositionalCategory::LeftAndRight),
+    (0x114BD, 0x114BD, IndicPositionalCategory::Right),
+    (0x114BE, 0x114BE, IndicPositionalCategory::LeftAndRight),
+    (0x114BF, 0x114C0, IndicPositionalCategory::Top),
+    (0x114C1, 0x114C1, IndicPositionalCategory::Right),
+    (0x114C2, 0x114C3, IndicPositionalCategory::Bottom),
+    (0x115AF, 0x115AF, IndicPositionalCategory::Right),
+    (0x115B0, 0x115B0, IndicPositionalCategory::Left),
+    (0x115B1, 0x115B1, IndicPositionalCategory::Right),
+    (0x115B2, 0x115B5, IndicPositionalCategory::Bottom),
+    (0x115B8, 0x115B8, IndicPositionalCategory::Left),
+    (0x115B9, 0x115B9, IndicPositionalCategory::TopAndLeft),
+    (0x115BA, 0x115BA, IndicPositionalCategory::LeftAndRight),
+    (
+        0x115BB,
+        0x115BB,
+        IndicPositionalCategory::TopAndLeftAndRight,
+    ),
+    (0x115BC, 0x115BD, IndicPositionalCategory::Top),
+    (0x115BE, 0x115BE, IndicPositionalCategory::Right),
+    (0x115BF, 0x115C0, IndicPositionalCategory::Bottom),
+    (0x115DC, 0x115DD, IndicPositionalCategory::Bottom),
+    (0x11630, 0x11632, IndicPositionalCategory::Right),
+    (0x11633, 0x11638, IndicPositionalCategory::Bottom),
+    (0x11639, 0x1163A, IndicPositionalCategory::Top),
+    (0x1163B, 0x1163C, IndicPositionalCategory::Right),
+    (0x1163D, 0x1163D, IndicPositionalCategory::Top),
+    (0x1163E, 0x1163E, IndicPositionalCategory::Right),
+    (0x1163F, 0x1163F, IndicPositionalCategory::Bottom),
+    (0x11640, 0x11640, IndicPositionalCategory::Top),
+    (0x116AB, 0x116AB, IndicPositionalCategory::Top),
+    (0x116AC, 0x116AC, IndicPositionalCategory::Right),
+    (0x116AD, 0x116AD, IndicPositionalCategory::Top),
+    (0x116AE, 0x116AE, IndicPositionalCategory::Left),
+    (0x116AF, 0x116AF, IndicPositionalCategory::Right),
+    (0x116B0, 0x116B1, IndicPositionalCategory::Bottom),
+    (0x116B2, 0x116B5, IndicPositionalCategory::Top),
+    (0x116B6, 0x116B6, IndicPositionalCategory::Right),
+    (0x116B7, 0x116B7, IndicPositionalCategory::Bottom),
+    (0x1171D, 0x1171D, IndicPositionalCategory::Bottom),
+    (
+        0x1171E,
+        0x1171E,
+        IndicPositionalCategory::TopAndBottomAndLeft,
+    ),
+    (0x1171F, 0x1171F, IndicPositionalCategory::Top),
+    (0x11720, 0x11721, IndicPositionalCategory::Right),
+    (0x11722, 0x11723, IndicPositionalCategory::Top),
+    (0x11724, 0x11725, IndicPositionalCategory::Bottom),
+    (0x11726, 0x11726, IndicPositionalCategory::Left),
+    (0x11727, 0x11727, IndicPositionalCategory::Top),
+    (0x11728, 0x11728, IndicPositionalCategory::Bottom),
+    (0x11729, 0x1172B, IndicPositionalCategory::Top),
+    (0x1182C, 0x1182C, IndicPositionalCategory::Right),
+    (0x1182D, 0x1182D, IndicPositionalCategory::Left),
+    (0x1182E, 0x1182E, IndicPositionalCategory::Right),
+    (0x1182F, 0x11832, IndicPositionalCategory::Bottom),
+    (0x11833, 0x11837, IndicPositionalCategory::Top),
+    (0x11838, 0x11838, IndicPositionalCategory::Right),
+    (0x11839, 0x1183A, IndicPositionalCategory::Bottom),
+    (0x11930, 0x11934, IndicPositionalCategory::Right),
+    (0x11935, 0x11935, IndicPositionalCategory::Left),
+    (0x11937, 0x11937, IndicPositionalCategory::Left),
+    (0x11938, 0x11938, IndicPositionalCategory::LeftAndRight),
+    (0x1193B, 0x1193C, IndicPositionalCategory::Top),
+    (0x1193D, 0x1193D, IndicPositionalCategory::Right),
+    (0x1193F, 0x1193F, IndicPositionalCategory::Top),
+    (0x11940, 0x11940, IndicPositionalCategory::Right),
+    (0x11941, 0x11941, IndicPositionalCategory::Top),
+    (0x11942, 0x11942, IndicPositionalCategory::BottomAndRight),
+    (0x11943, 0x11943, IndicPositionalCategory::Bottom),
+    (0x119D1, 0x119D1, IndicPositionalCategory::Right),
+    (0x119D2, 0x119D2, IndicPositionalCategory::Left),
+    (0x119D3, 0x119D3, IndicPositionalCategory::Right),
+    (0x119D4, 0x119D7, IndicPositionalCategory::Bottom),
+    (0x119DA, 0x119DB, IndicPositionalCategory::Top),
+    (0x119DC, 0x119DF, IndicPositionalCategory::Right),
+    (0x119E0, 0x119E0, IndicPositionalCategory::Bottom),
+    (0x119E4, 0x119E4, IndicPositionalCategory::Left),
+    (0x11A01, 0x11A01, IndicPositionalCategory::Top),
+    (0x11A02, 0x11A03, IndicPositionalCategory::Bottom),
+    (0x11A04, 0x11A09, IndicPositionalCategory::Top),
+    (0x11A0A, 0x11A0A, IndicPositionalCategory::Bottom),
+    (0x11A33, 0x11A34, IndicPositionalCategory::Bottom),
+    (0x11A35, 0x11A38, IndicPositionalCategory::Top),
+    (0x11A39, 0x11A39, IndicPositionalCategory::Right),
+    (0x11A3A, 0x11A3A, IndicPositionalCategory::Top),
+    (0x11A3B, 0x11A3E, IndicPositionalCategory::Bottom),
+    (0x11A51, 0x11A51, IndicPositionalCategory::Top),
+    (0x11A52, 0x11A53, IndicPositionalCategory::Bottom),
+    (0x11A54, 0x11A56, IndicPositionalCategory::Top),
+    (0x11A57, 0x11A58, IndicPositionalCategory::Right),
+    (0x11A59, 0x11A5B, IndicPositionalCategory::Bottom),
+    (0x11A84, 0x11A89, IndicPositionalCategory::Top),
+    (0x11A8A, 0x11A95, IndicPositionalCategory::Bottom),
+    (0x11A96, 0x11A96, IndicPositionalCategory::Top),
+    (0x11A97, 0x11A97, IndicPositionalCategory::Right),
+    (0x11A98, 0x11A98, IndicPositionalCategory::Top),
+    (0x11C2F, 0x11C2F, IndicPositionalCategory::Right),
+    (0x11C30, 0x11C31, IndicPositionalCategory::Top),
+    (0x11C32, 0x11C36, IndicPositionalCategory::Bottom),
+    (0x11C38, 0x11C3D, IndicPositionalCategory::Top),
+    (0x11C3E, 0x11C3E, IndicPositionalCategory::Right),
+    (0x11C3F, 0x11C3F, IndicPositionalCategory::Bottom),
+    (0x11C92, 0x11CA7, IndicPositionalCategory::Bottom),
+    (0x11CA9, 0x11CA9, IndicPositionalCategory::Right),
+    (0x11CAA, 0x11CB0, IndicPositionalCategory::Bottom),
+    (0x11CB1, 0x11CB1, IndicPositionalCategory::Left),
+    (0x11CB2, 0x11CB2, IndicPositionalCategory::Bottom),
+    (0x11CB3, 0x11CB3, IndicPositionalCategory::Top),
+    (0x11CB4, 0x11CB4, IndicPositionalCategory::Right),
+    (0x11CB5, 0x11CB6, IndicPositionalCategory::Top),
+    (0x11D31, 0x11D35, IndicPositionalCategory::Top),
+    (0x11D36, 0x11D36, IndicPositionalCategory::Bottom),
+    (0x11D3A, 0x11D3A, IndicPositionalCategory::Top),
+    (0x11D3C, 0x11D3D, IndicPositionalCategory::Top),
+    (0x11D3F, 0x11D41, IndicPositionalCategory::Top),
+    (0x11D42, 0x11D42, IndicPositionalCategory::Bottom),
+    (0x11D43, 0x11D43, IndicPositionalCategory::Top),
+    (0x11D44, 0x11D44, IndicPositionalCategory::Bottom),
+    (0x11D46, 0x11D46, IndicPositionalCategory::Right),
+    (0x11D47, 0x11D47, IndicPositionalCategory::Bottom),
+    (0x11D8A, 0x11D8E, IndicPositionalCategory::Right),
+    (0x11D90, 0x11D91, IndicPositionalCategory::Top),
+    (0x11D93, 0x11D94, IndicPositionalCategory::Right),
+    (0x11D95, 0x11D95, IndicPositionalCategory::Top),
+    (0x11D96, 0x11D96, IndicPositionalCategory::Right),
+    (0x11EF3, 0x11EF3, IndicPositionalCategory::Top),
+    (0x11EF4, 0x11EF4, IndicPositionalCategory::Bottom),
+    (0x11EF5, 0x11EF5, IndicPositionalCategory::Left),
+    (0x11EF6, 0x11EF6, IndicPositionalCategory::Right),
+];
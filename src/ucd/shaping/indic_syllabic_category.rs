@@ -0,0 +1,941 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (IndicSyllabicCategory.txt and PropertyValueAliases.txt),
+// limited to the characters assigned in Unicode 13.0.
+
+use super::IndicSyllabicCategory;
+
+pub(super) const VALUES: &[IndicSyllabicCategory] = &[
+    IndicSyllabicCategory::Avagraha,
+    IndicSyllabicCategory::Bindu,
+    IndicSyllabicCategory::BrahmiJoiningNumber,
+    IndicSyllabicCategory::CantillationMark,
+    IndicSyllabicCategory::Consonant,
+    IndicSyllabicCategory::ConsonantDead,
+    IndicSyllabicCategory::ConsonantFinal,
+    IndicSyllabicCategory::ConsonantHeadLetter,
+    IndicSyllabicCategory::ConsonantInitialPostfixed,
+    IndicSyllabicCategory::ConsonantKiller,
+    IndicSyllabicCategory::ConsonantMedial,
+    IndicSyllabicCategory::ConsonantPlaceholder,
+    IndicSyllabicCategory::ConsonantPrecedingRepha,
+    IndicSyllabicCategory::ConsonantPrefixed,
+    IndicSyllabicCategory::ConsonantSubjoined,
+    IndicSyllabicCategory::ConsonantSucceedingRepha,
+    IndicSyllabicCategory::ConsonantWithStacker,
+    IndicSyllabicCategory::GeminationMark,
+    IndicSyllabicCategory::InvisibleStacker,
+    IndicSyllabicCategory::Joiner,
+    IndicSyllabicCategory::ModifyingLetter,
+    IndicSyllabicCategory::NonJoiner,
+    IndicSyllabicCategory::Nukta,
+    IndicSyllabicCategory::Number,
+    IndicSyllabicCategory::NumberJoiner,
+    IndicSyllabicCategory::Other,
+    IndicSyllabicCategory::PureKiller,
+    IndicSyllabicCategory::RegisterShifter,
+    IndicSyllabicCategory::SyllableModifier,
+    IndicSyllabicCategory::ToneLetter,
+    IndicSyllabicCategory::ToneMark,
+    IndicSyllabicCategory::Virama,
+    IndicSyllabicCategory::Visarga,
+    IndicSyllabicCategory::Vowel,
+    IndicSyllabicCategory::VowelDependent,
+    IndicSyllabicCategory::VowelIndependent,
+];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[
+    ("Avagraha", "Avagraha", "Avagraha"),
+    ("Bindu", "Bindu", "Bindu"),
+    (
+        "Brahmi_Joining_Number",
+        "Brahmi_Joining_Number",
+        "Brahmi Joining Number",
+    ),
+    (
+        "Cantillation_Mark",
+        "Cantillation_Mark",
+        "Cantillation Mark",
+    ),
+    ("Consonant", "Consonant", "Consonant"),
+    ("Consonant_Dead", "Consonant_Dead", "Consonant Dead"),
+    ("Consonant_Final", "Consonant_Final", "Consonant Final"),
+    (
+        "Consonant_Head_Letter",
+        "Consonant_Head_Letter",
+        "Consonant Head Letter",
+    ),
+    (
+        "Consonant_Initial_Postfixed",
+        "Consonant_Initial_Postfixed",
+        "Consonant Initial Postfixed",
+    ),
+    ("Consonant_Killer", "Consonant_Killer", "Consonant Killer"),
+    ("Consonant_Medial", "Consonant_Medial", "Consonant Medial"),
+    (
+        "Consonant_Placeholder",
+        "Consonant_Placeholder",
+        "Consonant Placeholder",
+    ),
+    (
+        "Consonant_Preceding_Repha",
+        "Consonant_Preceding_Repha",
+        "Consonant Preceding Repha",
+    ),
+    (
+        "Consonant_Prefixed",
+        "Consonant_Prefixed",
+        "Consonant Prefixed",
+    ),
+    (
+        "Consonant_Subjoined",
+        "Consonant_Subjoined",
+        "Consonant Subjoined",
+    ),
+    (
+        "Consonant_Succeeding_Repha",
+        "Consonant_Succeeding_Repha",
+        "Consonant Succeeding Repha",
+    ),
+    (
+        "Consonant_With_Stacker",
+        "Consonant_With_Stacker",
+        "Consonant With Stacker",
+    ),
+    ("Gemination_Mark", "Gemination_Mark", "Gemination Mark"),
+    (
+        "Invisible_Stacker",
+        "Invisible_Stacker",
+        "Invisible Stacker",
+    ),
+    ("Joiner", "Joiner", "Joiner"),
+    ("Modifying_Letter", "Modifying_Letter", "Modifying Letter"),
+    ("Non_Joiner", "Non_Joiner", "Non Joiner"),
+    ("Nukta", "Nukta", "Nukta"),
+    ("Number", "Number", "Number"),
+    ("Number_Joiner", "Number_Joiner", "Number Joiner"),
+    ("Other", "Other", "Other"),
+    ("Pure_Killer", "Pure_Killer", "Pure Killer"),
+    ("Register_Shifter", "Register_Shifter", "Register Shifter"),
+    (
+        "Syllable_Modifier",
+        "Syllable_Modifier",
+        "Syllable Modifier",
+    ),
+    ("Tone_Letter", "Tone_Letter", "Tone Letter"),
+    ("Tone_Mark", "Tone_Mark", "Tone Mark"),
+    ("Virama", "Virama", "Virama"),
+    ("Visarga", "Visarga", "Visarga"),
+    ("Vowel", "Vowel", "Vowel"),
+    ("Vowel_Dependent", "Vowel_Dependent", "Vowel Dependent"),
+    (
+        "Vowel_Independent",
+        "Vowel_Independent",
+        "Vowel Independent",
+    ),
+];
+
+pub(super) const RANGES: &[(u32, u32, IndicSyllabicCategory)] = &[
+    (0x002D, 0x002D, IndicSyllabicCategory::ConsonantPlaceholder),
+    (0x0030, 0x0039, IndicSyllabicCategory::Number),
+    (0x00A0, 0x00A0, IndicSyllabicCategory::ConsonantPlaceholder),
+    (0x00B2, 0x00B3, IndicSyllabicCategory::SyllableModifier),
+    (0x00D7, 0x00D7, IndicSyllabicCategory::ConsonantPlaceholder),
+    (0x0900, 0x0902, IndicSyllabicCategory::Bindu),
+    (0x0903, 0x0903, IndicSyllabicCategory::Visarga),
+    (0x0904, 0x0914, IndicSyllabicCategory::VowelIndependent),
+    (0x0915, 0x0939, IndicSyllabicCategory::Consonant),
+    (0x093A, 0x093B, IndicSyllabicCategory::VowelDependent),
+    (0x093C, 0x093C, IndicSyllabicCategory::Nukta),
+    (0x093D, 0x093D, IndicSyllabicCategory::Avagraha),
+    (0x093E, 0x094C, IndicSyllabicCategory::VowelDependent),
+    (0x094D, 0x094D, IndicSyllabicCategory::Virama),
+    (0x094E, 0x094F, IndicSyllabicCategory::VowelDependent),
+    (0x0951, 0x0952, IndicSyllabicCategory::CantillationMark),
+    (0x0955, 0x0957, IndicSyllabicCategory::VowelDependent),
+    (0x0958, 0x095F, IndicSyllabicCategory::Consonant),
+    (0x0960, 0x0961, IndicSyllabicCategory::VowelIndependent),
+    (0x0962, 0x0963, IndicSyllabicCategory::VowelDependent),
+    (0x0966, 0x096F, IndicSyllabicCategory::Number),
+    (0x0972, 0x0977, IndicSyllabicCategory::VowelIndependent),
+    (0x0978, 0x097F, IndicSyllabicCategory::Consonant),
+    (0x0980, 0x0980, IndicSyllabicCategory::ConsonantPlaceholder),
+    (0x0981, 0x0982, IndicSyllabicCategory::Bindu),
+    (0x0983, 0x0983, IndicSyllabicCategory::Visarga),
+    (0x0985, 0x098C, IndicSyllabicCategory::VowelIndependent),
+    (0x098F, 0x0990, IndicSyllabicCategory::VowelIndependent),
+    (0x0993, 0x0994, IndicSyllabicCategory::VowelIndependent),
+    (0x0995, 0x09A8, IndicSyllabicCategory::Consonant),
+    (0x09AA, 0x09B0, IndicSyllabicCategory::Consonant),
+    (0x09B2, 0x09B2, IndicSyllabicCategory::Consonant),
+    (0x09B6, 0x09B9, IndicSyllabicCategory::Consonant),
+    (0x09BC, 0x09BC, IndicSyllabicCategory::Nukta),
+    (0x09BD, 0x09BD, IndicSyllabicCategory::Avagraha),
+    (0x09BE, 0x09C4, IndicSyllabicCategory::VowelDependent),
+    (0x09C7, 0x09C8, IndicSyllabicCategory::VowelDependent),
+    (0x09CB, 0x09CC, IndicSyllabicCategory::VowelDependent),
+    (0x09CD, 0x09CD, IndicSyllabicCategory::Virama),
+    (0x09CE, 0x09CE, IndicSyllabicCategory::ConsonantDead),
+    (0x09D7, 0x09D7, IndicSyllabicCategory::VowelDependent),
+    (0x09DC, 0x09DD, IndicSyllabicCategory::Consonant),
+    (0x09DF, 0x09DF, IndicSyllabicCategory::Consonant),
+    (0x09E0, 0x09E1, IndicSyllabicCategory::VowelIndependent),
+    (0x09E2, 0x09E3, IndicSyllabicCategory::VowelDependent),
+    (0x09E6, 0x09EF, IndicSyllabicCategory::Number),
+    (0x09F0, 0x09F1, IndicSyllabicCategory::Consonant),
+    (0x09FC, 0x09FC, IndicSyllabicCategory::Bindu),
+    (0x09FE, 0x09FE, IndicSyllabicCategory::SyllableModifier),
+    (0x0A01, 0x0A02, IndicSyllabicCategory::Bindu),
+    (0x0A03, 0x0A03, IndicSyllabicCategory::Visarga),
+    (0x0A05, 0x0A0A, IndicSyllabicCategory::VowelIndependent),
+    (0x0A0F, 0x0A10, IndicSyllabicCategory::VowelIndependent),
+    (0x0A13, 0x0A14, IndicSyllabicCategory::VowelIndependent),
+    (0x0A15, 0x0A28, IndicSyllabicCategory::Consonant),
+    (0x0A2A, 0x0A30, IndicSyllabicCategory::Consonant),
+    (0x0A32, 0x0A33, IndicSyllabicCategory::Consonant),
+    (0x0A35, 0x0A36, IndicSyllabicCategory::Consonant),
+    (0x0A38, 0x0A39, IndicSyllabicCategory::Consonant),
+    (0x0A3C, 0x0A3C, IndicSyllabicCategory::Nukta),
+    (0x0A3E, 0x0A42, IndicSyllabicCategory::VowelDependent),
+    (0x0A47, 0x0A48, IndicSyllabicCategory::VowelDependent),
+    (0x0A4B, 0x0A4C, IndicSyllabicCategory::VowelDependent),
+    (0x0A4D, 0x0A4D, IndicSyllabicCategory::Virama),
+    (0x0A51, 0x0A51, IndicSyllabicCategory::CantillationMark),
+    (0x0A59, 0x0A5C, IndicSyllabicCategory::Consonant),
+    (0x0A5E, 0x0A5E, IndicSyllabicCategory::Consonant),
+    (0x0A66, 0x0A6F, IndicSyllabicCategory::Number),
+    (0x0A70, 0x0A70, IndicSyllabicCategory::Bindu),
+    (0x0A71, 0x0A71, IndicSyllabicCategory::GeminationMark),
+    (0x0A72, 0x0A73, IndicSyllabicCategory::ConsonantPlaceholder),
+    (0x0A75, 0x0A75, IndicSyllabicCategory::ConsonantMedial),
+    (0x0A81, 0x0A82, IndicSyllabicCategory::Bindu),
+    (0x0A83, 0x0A83, IndicSyllabicCategory::Visarga),
+    (0x0A85, 0x0A8D, IndicSyllabicCategory::VowelIndependent),
+    (0x0A8F, 0x0A91, IndicSyllabicCategory::VowelIndependent),
+    (0x0A93, 0x0A94, IndicSyllabicCategory::VowelIndependent),
+    (0x0A95, 0x0AA8, IndicSyllabicCategory::Consonant),
+    (0x0AAA, 0x0AB0, IndicSyllabicCategory::Consonant),
+    (0x0AB2, 0x0AB3, IndicSyllabicCategory::Consonant),
+    (0x0AB5, 0x0AB9, IndicSyllabicCategory::Consonant),
+    (0x0ABC, 0x0ABC, IndicSyllabicCategory::Nukta),
+    (0x0ABD, 0x0ABD, IndicSyllabicCategory::Avagraha),
+    (0x0ABE, 0x0AC5, IndicSyllabicCategory::VowelDependent),
+    (0x0AC7, 0x0AC9, IndicSyllabicCategory::VowelDependent),
+    (0x0ACB, 0x0ACC, IndicSyllabicCategory::VowelDependent),
+    (0x0ACD, 0x0ACD, IndicSyllabicCategory::Virama),
+    (0x0AE0, 0x0AE1, IndicSyllabicCategory::VowelIndependent),
+    (0x0AE2, 0x0AE3, IndicSyllabicCategory::VowelDependent),
+    (0x0AE6, 0x0AEF, IndicSyllabicCategory::Number),
+    (0x0AF9, 0x0AF9, IndicSyllabicCategory::Consonant),
+    (0x0AFA, 0x0AFC, IndicSyllabicCategory::CantillationMark),
+    (0x0AFD, 0x0AFF, IndicSyllabicCategory::Nukta),
+    (0x0B01, 0x0B02, IndicSyllabicCategory::Bindu),
+    (0x0B03, 0x0B03, IndicSyllabicCategory::Visarga),
+    (0x0B05, 0x0B0C, IndicSyllabicCategory::VowelIndependent),
+    (0x0B0F, 0x0B10, IndicSyllabicCategory::VowelIndependent),
+    (0x0B13, 0x0B14, IndicSyllabicCategory::VowelIndependent),
+    (0x0B15, 0x0B28, IndicSyllabicCategory::Consonant),
+    (0x0B2A, 0x0B30, IndicSyllabicCategory::Consonant),
+    (0x0B32, 0x0B33, IndicSyllabicCategory::Consonant),
+    (0x0B35, 0x0B39, IndicSyllabicCategory::Consonant),
+    (0x0B3C, 0x0B3C, IndicSyllabicCategory::Nukta),
+    (0x0B3D, 0x0B3D, IndicSyllabicCategory::Avagraha),
+    (0x0B3E, 0x0B44, IndicSyllabicCategory::VowelDependent),
+    (0x0B47, 0x0B48, IndicSyllabicCategory::VowelDependent),
+    (0x0B4B, 0x0B4C, IndicSyllabicCategory::VowelDependent),
+    (0x0B4D, 0x0B4D, IndicSyllabicCategory::Virama),
+    (0x0B55, 0x0B57, IndicSyllabicCategory::VowelDependent),
+    (0x0B5C, 0x0B5D, IndicSyllabicCategory::Consonant),
+    (0x0B5F, 0x0B5F, IndicSyllabicCategory::Consonant),
+    (0x0B60, 0x0B61, IndicSyllabicCategory::VowelIndependent),
+    (0x0B62, 0x0B63, IndicSyllabicCategory::VowelDependent),
+    (0x0B66, 0x0B6F, IndicSyllabicCategory::Number),
+    (0x0B71, 0x0B71, IndicSyllabicCategory::Consonant),
+    (0x0B82, 0x0B82, IndicSyllabicCategory::Bindu),
+    (0x0B83, 0x0B83, IndicSyllabicCategory::ModifyingLetter),
+    (0x0B85, 0x0B8A, IndicSyllabicCategory::VowelIndependent),
+    (0x0B8E, 0x0B90, IndicSyllabicCategory::VowelIndependent),
+    (0x0B92, 0x0B94, IndicSyllabicCategory::VowelIndependent),
+    (0x0B95, 0x0B95, IndicSyllabicCategory::Consonant),
+    (0x0B99, 0x0B9A, IndicSyllabicCategory::Consonant),
+    (0x0B9C, 0x0B9C, IndicSyllabicCategory::Consonant),
+    (0x0B9E, 0x0B9F, IndicSyllabicCategory::Consonant),
+    (0x0BA3, 0x0BA4, IndicSyllabicCategory::Consonant),
+    (0x0BA8, 0x0BAA, IndicSyllabicCategory::Consonant),
+    (0x0BAE, 0x0BB9, IndicSyllabicCategory::Consonant),
+    (0x0BBE, 0x0BC2, IndicSyllabicCategory::VowelDependent),
+    (0x0BC6, 0x0BC8, IndicSyllabicCategory::VowelDependent),
+    (0x0BCA, 0x0BCC, IndicSyllabicCategory::VowelDependent),
+    (0x0BCD, 0x0BCD, IndicSyllabicCategory::Virama),
+    (0x0BD7, 0x0BD7, IndicSyllabicCategory::VowelDependent),
+    (0x0BE6, 0x0BEF, IndicSyllabicCategory::Number),
+    (0x0C00, 0x0C02, IndicSyllabicCategory::Bindu),
+    (0x0C03, 0x0C03, IndicSyllabicCategory::Visarga),
+    (0x0C04, 0x0C04, IndicSyllabicCategory::Bindu),
+    (0x0C05, 0x0C0C, IndicSyllabicCategory::VowelIndependent),
+    (0x0C0E, 0x0C10, IndicSyllabicCategory::VowelIndependent),
+    (0x0C12, 0x0C14, IndicSyllabicCategory::VowelIndependent),
+    (0x0C15, 0x0C28, IndicSyllabicCategory::Consonant),
+    (0x0C2A, 0x0C39, IndicSyllabicCategory::Consonant),
+    (0x0C3D, 0x0C3D, IndicSyllabicCategory::Avagraha),
+    (0x0C3E, 0x0C44, IndicSyllabicCategory::VowelDependent),
+    (0x0C46, 0x0C48, IndicSyllabicCategory::VowelDependent),
+    (0x0C4A, 0x0C4C, IndicSyllabicCategory::VowelDependent),
+    (0x0C4D, 0x0C4D, IndicSyllabicCategory::Virama),
+    (0x0C55, 0x0C56, IndicSyllabicCategory::VowelDependent),
+    (0x0C58, 0x0C5A, IndicSyllabicCategory::Consonant),
+    (0x0C60, 0x0C61, IndicSyllabicCategory::VowelIndependent),
+    (0x0C62, 0x0C63, IndicSyllabicCategory::VowelDependent),
+    (0x0C66, 0x0C6F, IndicSyllabicCategory::Number),
+    (0x0C80, 0x0C82, IndicSyllabicCategory::Bindu),
+    (0x0C83, 0x0C83, IndicSyllabicCategory::Visarga),
+    (0x0C85, 0x0C8C, IndicSyllabicCategory::VowelIndependent),
+    (0x0C8E, 0x0C90, IndicSyllabicCategory::VowelIndependent),
+    (0x0C92, 0x0C94, IndicSyllabicCategory::VowelIndependent),
+    (0x0C95, 0x0CA8, IndicSyllabicCategory::Consonant),
+    (0x0CAA, 0x0CB3, IndicSyllabicCategory::Consonant),
+    (0x0CB5, 0x0CB9, IndicSyllabicCategory::Consonant),
+    (0x0CBC, 0x0CBC, IndicSyllabicCategory::Nukta),
+    (0x0CBD, 0x0CBD, IndicSyllabicCategory::Avagraha),
+    (0x0CBE, 0x0CC4, IndicSyllabicCategory::VowelDependent),
+    (0x0CC6, 0x0CC8, IndicSyllabicCategory::VowelDependent),
+    (0x0CCA, 0x0CCC, IndicSyllabicCategory::VowelDependent),
+    (0x0CCD, 0x0CCD, IndicSyllabicCategory::Virama),
+    (0x0CD5, 0x0CD6, IndicSyllabicCategory::VowelDependent),
+    (0x0CDE, 0x0CDE, IndicSyllabicCategory::Consonant),
+    (0x0CE0, 0x0CE1, IndicSyllabicCategory::VowelIndependent),
+    (0x0CE2, 0x0CE3, IndicSyllabicCategory::VowelDependent),
+    (0x0CE6, 0x0CEF, IndicSyllabicCategory::Number),
+    (0x0CF1, 0x0CF2, IndicSyllabicCategory::ConsonantWithStacker),
+    (0x0D00, 0x0D02, IndicSyllabicCategory::Bindu),
+    (0x0D03, 0x0D03, IndicSyllabicCategory::Visarga),
+    (0x0D04, 0x0D04, IndicSyllabicCategory::Bindu),
+    (0x0D05, 0x0D0C, IndicSyllabicCategory::VowelIndependent),
+    (0x0D0E, 0x0D10, IndicSyllabicCategory::VowelIndependent),
+    (0x0D12, 0x0D14, IndicSyllabicCategory::VowelIndependent),
+    (0x0D15, 0x0D3A, IndicSyllabicCategory::Consonant),
+    (0x0D3B, 0x0D3C, IndicSyllabicCategory::PureKiller),
+    (0x0D3D, 0x0D3D, IndicSyllabicCategory::Avagraha),
+    (0x0D3E, 0x0D44, IndicSyllabicCategory::VowelDependent),
+    (0x0D46, 0x0D48, IndicSyllabicCategory::VowelDependent),
+    (0x0D4A, 0x0D4C, IndicSyllabicCategory::VowelDependent),
+    (0x0D4D, 0x0D4D, IndicSyllabicCategory::Virama),
+    (
+        0x0D4E,
+        0x0D4E,
+        IndicSyllabicCategory::ConsonantPrecedingRepha,
+    ),
+    (0x0D54, 0x0D56, IndicSyllabicCategory::ConsonantDead),
+    (0x0D57, 0x0D57, IndicSyllabicCategory::VowelDependent),
+    (0x0D5F, 0x0D61, IndicSyllabicCategory::VowelIndependent),
+    (0x0D62, 0x0D63, IndicSyllabicCategory::VowelDependent),
+    (0x0D66, 0x0D6F, IndicSyllabicCategory::Number),
+    (0x0D7A, 0x0D7F, IndicSyllabicCategory::ConsonantDead),
+    (0x0D81, 0x0D82, IndicSyllabicCategory::Bindu),
+    (0x0D83, 0x0D83, IndicSyllabicCategory::Visarga),
+    (0x0D85, 0x0D96, IndicSyllabicCategory::VowelIndependent),
+    (0x0D9A, 0x0DB1, IndicSyllabicCategory::Consonant),
+    (0x0DB3, 0x0DBB, IndicSyllabicCategory::Consonant),
+    (0x0DBD, 0x0DBD, IndicSyllabicCategory::Consonant),
+    (0x0DC0, 0x0DC6, IndicSyllabicCategory::Consonant),
+    (0x0DCA, 0x0DCA, IndicSyllabicCategory::Virama),
+    (0x0DCF, 0x0DD4, IndicSyllabicCategory::VowelDependent),
+    (0x0DD6, 0x0DD6, IndicSyllabicCategory::VowelDependent),
+    (0x0DD8, 0x0DDF, IndicSyllabicCategory::VowelDependent),
+    (0x0DE6, 0x0DEF, IndicSyllabicCategory::Number),
+    (0x0DF2, 0x0DF3, IndicSyllabicCategory::VowelDependent),
+    (0x0E01, 0x0E2E, IndicSyllabicCategory::Consonant),
+    (0x0E30, 0x0E39, IndicSyllabicCategory::VowelDependent),
+    (0x0E3A, 0x0E3A, IndicSyllabicCategory::PureKiller),
+    (0x0E40, 0x0E45, IndicSyllabicCategory::VowelDependent),
+    (0x0E47, 0x0E47, IndicSyllabicCategory::VowelDependent),
+    (0x0E48, 0x0E4B, IndicSyllabicCategory::ToneMark),
+    (0x0E4C, 0x0E4C, IndicSyllabicCategory::ConsonantKiller),
+    (0x0E4D, 0x0E4D, IndicSyllabicCategory::Bindu),
+    (0x0E4E, 0x0E4E, IndicSyllabicCategory::PureKiller),
+    (0x0E50, 0x0E59, IndicSyllabicCategory::Number),
+    (0x0E81, 0x0E82, IndicSyllabicCategory::Consonant),
+    (0x0E84, 0x0E84, IndicSyllabicCategory::Consonant),
+    (0x0E86, 0x0E8A, IndicSyllabicCategory::Consonant),
+    (0x0E8C, 0x0EA3, IndicSyllabicCategory::Consonant),
+    (0x0EA5, 0x0EA5, IndicSyllabicCategory::Consonant),
+    (0x0EA7, 0x0EAE, IndicSyllabicCategory::Consonant),
+    (0x0EB0, 0x0EB9, IndicSyllabicCategory::VowelDependent),
+    (0x0EBA, 0x0EBA, IndicSyllabicCategory::PureKiller),
+    (0x0EBB, 0x0EBB, IndicSyllabicCategory::VowelDependent),
+    (0x0EBC, 0x0EBD, IndicSyllabicCategory::ConsonantMedial),
+    (0x0EC0, 0x0EC4, IndicSyllabicCategory::VowelDependent),
+    (0x0EC8, 0x0ECB, IndicSyllabicCategory::ToneMark),
+    (0x0ECD, 0x0ECD, IndicSyllabicCategory::Bindu),
+    (0x0ED0, 0x0ED9, IndicSyllabicCategory::Number),
+    (0x0EDC, 0x0EDF, IndicSyllabicCategory::Consonant),
+    (0x0F20, 0x0F33, IndicSyllabicCategory::Number),
+    (0x0F35, 0x0F35, IndicSyllabicCategory::SyllableModifier),
+    (0x0F37, 0x0F37, IndicSyllabicCategory::SyllableModifier),
+    (0x0F39, 0x0F39, IndicSyllabicCategory::Nukta),
+    (0x0F40, 0x0F47, IndicSyllabicCategory::Consonant),
+    (0x0F49, 0x0F6C, IndicSyllabicCategory::Consonant),
+    (0x0F71, 0x0F7D, IndicSyllabicCategory::VowelDependent),
+    (0x0F7E, 0x0F7E, IndicSyllabicCategory::Bindu),
+    (0x0F7F, 0x0F7F, IndicSyllabicCategory::Visarga),
+    (0x0F80, 0x0F81, IndicSyllabicCategory::VowelDependent),
+    (0x0F82, 0x0F83, IndicSyllabicCategory::Bindu),
+    (0x0F84, 0x0F84, IndicSyllabicCategory::PureKiller),
+    (0x0F85, 0x0F85, IndicSyllabicCategory::Avagraha),
+    (0x0F88, 0x0F8C, IndicSyllabicCategory::ConsonantHeadLetter),
+    (0x0F8D, 0x0F97, IndicSyllabicCategory::ConsonantSubjoined),
+    (0x0F99, 0x0FBC, IndicSyllabicCategory::ConsonantSubjoined),
+    (0x0FC6, 0x0FC6, IndicSyllabicCategory::SyllableModifier),
+    (0x1000, 0x1020, IndicSyllabicCategory::Consonant),
+    (0x1021, 0x102A, IndicSyllabicCategory::VowelIndependent),
+    (0x102B, 0x1035, IndicSyllabicCategory::VowelDependent),
+    (0x1036, 0x1036, IndicSyllabicCategory::Bindu),
+    (0x1037, 0x1037, IndicSyllabicCategory::ToneMark),
+    (0x1038, 0x1038, IndicSyllabicCategory::Visarga),
+    (0x1039, 0x1039, IndicSyllabicCategory::InvisibleStacker),
+    (0x103A, 0x103A, IndicSyllabicCategory::PureKiller),
+    (0x103B, 0x103E, IndicSyllabicCategory::ConsonantMedial),
+    (0x103F, 0x103F, IndicSyllabicCategory::Consonant),
+    (0x1040, 0x1049, IndicSyllabicCategory::Number),
+    (0x104B, 0x104B, IndicSyllabicCategory::ConsonantPlaceholder),
+    (0x104E, 0x104E, IndicSyllabicCategory::ConsonantPlaceholder),
+    (0x1050, 0x1051, IndicSyllabicCategory::Consonant),
+    (0x1052, 0x1055, IndicSyllabicCategory::VowelIndependent),
+    (0x1056, 0x1059, IndicSyllabicCategory::VowelDependent),
+    (0x105A, 0x105D, IndicSyllabicCategory::Consonant),
+    (0x105E, 0x1060, IndicSyllabicCategory::ConsonantMedial),
+    (0x1061, 0x1061, IndicSyllabicCategory::Consonant),
+    (0x1062, 0x1062, IndicSyllabicCategory::VowelDependent),
+    (0x1063, 0x1064, IndicSyllabicCategory::ToneMark),
+    (0x1065, 0x1066, IndicSyllabicCategory::Consonant),
+    (0x1067, 0x1068, IndicSyllabicCategory::VowelDependent),
+    (0x1069, 0x106D, IndicSyllabicCategory::ToneMark),
+    (0x106E, 0x1070, IndicSyllabicCategory::Consonant),
+    (0x1071, 0x1074, IndicSyllabicCategory::VowelDependent),
+    (0x1075, 0x1081, IndicSyllabicCategory::Consonant),
+    (0x1082, 0x1082, IndicSyllabicCategory::ConsonantMedial),
+    (0x1083, 0x1086, IndicSyllabicCategory::VowelDependent),
+    (0x1087, 0x108D, IndicSyllabicCategory::ToneMark),
+    (0x108E, 0x108E, IndicSyllabicCategory::Consonant),
+    (0x108F, 0x108F, IndicSyllabicCategory::ToneMark),
+    (0x1090, 0x1099, IndicSyllabicCategory::Number),
+    (0x109A, 0x109B, IndicSyllabicCategory::ToneMark),
+    (0x109C, 0x109D, IndicSyllabicCategory::VowelDependent),
+    (0x1700, 0x1702, IndicSyllabicCategory::VowelIndependent),
+    (0x1703, 0x170C, IndicSyllabicCategory::Consonant),
+    (0x170E, 0x1711, IndicSyllabicCategory::Consonant),
+    (0x1712, 0x1713, IndicSyllabicCategory::VowelDependent),
+    (0x1714, 0x1714, IndicSyllabicCategory::PureKiller),
+    (0x1720, 0x1722, IndicSyllabicCategory::VowelIndependent),
+    (0x1723, 0x1731, IndicSyllabicCategory::Consonant),
+    (0x1732, 0x1733, IndicSyllabicCategory::VowelDependent),
+    (0x1734, 0x1734, IndicSyllabicCategory::PureKiller),
+    (0x1740, 0x1742, IndicSyllabicCategory::VowelIndependent),
+    (0x1743, 0x1751, IndicSyllabicCategory::Consonant),
+    (0x1752, 0x1753, IndicSyllabicCategory::VowelDependent),
+    (0x1760, 0x1762, IndicSyllabicCategory::VowelIndependent),
+    (0x1763, 0x176C, IndicSyllabicCategory::Consonant),
+    (0x176E, 0x1770, IndicSyllabicCategory::Consonant),
+    (0x1772, 0x1773, IndicSyllabicCategory::VowelDependent),
+    (0x1780, 0x17A2, IndicSyllabicCategory::Consonant),
+    (0x17A3, 0x17B3, IndicSyllabicCategory::VowelIndependent),
+    (0x17B6, 0x17C5, IndicSyllabicCategory::VowelDependent),
+    (0x17C6, 0x17C6, IndicSyllabicCategory::Bindu),
+    (0x17C7, 0x17C7, IndicSyllabicCategory::Visarga),
+    (0x17C8, 0x17C8, IndicSyllabicCategory::VowelDependent),
+    (0x17C9, 0x17CA, IndicSyllabicCategory::RegisterShifter),
+    (0x17CB, 0x17CB, IndicSyllabicCategory::SyllableModifier),
+    (
+        0x17CC,
+        0x17CC,
+        IndicSyllabicCategory::ConsonantSucceedingRepha,
+    ),
+    (0x17CD, 0x17CD, IndicSyllabicCategory::ConsonantKiller),
+    (0x17CE, 0x17D0, IndicSyllabicCategory::SyllableModifier),
+    (0x17D1, 0x17D1, IndicSyllabicCategory::PureKiller),
+    (0x17D2, 0x17D2, IndicSyllabicCategory::InvisibleStacker),
+    (0x17D3, 0x17D3, IndicSyllabicCategory::SyllableModifier),
+    (0x17DC, 0x17DC, IndicSyllabicCategory::Avagraha),
+    (0x17DD, 0x17DD, IndicSyllabicCategory::SyllableModifier),
+    (0x17E0, 0x17E9, IndicSyllabicCategory::Number),
+    (0x1900, 0x1900, IndicSyllabicCategory::ConsonantPlaceholder),
+    (0x1901, 0x191E, IndicSyllabicCategory::Consonant),
+    (0x1920, 0x1928, IndicSyllabicCategory::VowelDependent),
+    (0x1929, 0x192B, IndicSyllabicCategory::ConsonantSubjoined),
+    (0x1930, 0x1931, IndicSyllabicCategory::ConsonantFinal),
+    (0x1932, 0x1932, IndicSyllabicCategory::Bindu),
+    (0x1933, 0x1939, IndicSyllabicCategory::ConsonantFinal),
+    (0x193A, 0x193A, IndicSyllabicCategory::VowelDependent),
+    (0x193B, 0x193B, IndicSyllabicCategory::SyllableModifier),
+    (0x1946, 0x194F, IndicSyllabicCategory::Number),
+    (0x1950, 0x1962, IndicSyllabicCategory::Consonant),
+    (0x1963, 0x196D, IndicSyllabicCategory::Vowel),
+    (0x1970, 0x1974, IndicSyllabicCategory::ToneLetter),
+    (0x1980, 0x19AB, IndicSyllabicCategory::Consonant),
+    (0x19B0, 0x19C0, IndicSyllabicCategory::VowelDependent),
+    (0x19C1, 0x19C7, IndicSyllabicCategory::ConsonantFinal),
+    (0x19C8, 0x19C9, IndicSyllabicCategory::ToneMark),
+    (0x19D0, 0x19DA, IndicSyllabicCategory::Number),
+    (0x1A00, 0x1A16, IndicSyllabicCategory::Consonant),
+    (0x1A17, 0x1A1B, IndicSyllabicCategory::VowelDependent),
+    (0x1A20, 0x1A4C, IndicSyllabicCategory::Consonant),
+    (0x1A4D, 0x1A52, IndicSyllabicCategory::VowelIndependent),
+    (0x1A53, 0x1A54, IndicSyllabicCategory::Consonant),
+    (0x1A55, 0x1A56, IndicSyllabicCategory::ConsonantMedial),
+    (0x1A57, 0x1A57, IndicSyllabicCategory::ConsonantSubjoined),
+    (0x1A58, 0x1A59, IndicSyllabicCategory::ConsonantFinal),
+    (
+        0x1A5A,
+        0x1A5A,
+        IndicSyllabicCategory::ConsonantInitialPostfixed,
+    ),
+    (0x1A5B, 0x1A5E, IndicSyllabicCategory::ConsonantSubjoined),
+    (0x1A60, 0x1A60, IndicSyllabicCategory::InvisibleStacker),
+    (0x1A61, 0x1A73, IndicSyllabicCategory::VowelDependent),
+    (0x1A74, 0x1A74, IndicSyllabicCategory::Bindu),
+    (0x1A75, 0x1A79, IndicSyllabicCategory::ToneMark),
+    (0x1A7A, 0x1A7A, IndicSyllabicCategory::PureKiller),
+    (0x1A7B, 0x1A7C, IndicSyllabicCategory::SyllableModifier),
+    (0x1A7F, 0x1A7F, IndicSyllabicCategory::SyllableModifier),
+    (0x1A80, 0x1A89, IndicSyllabicCategory::Number),
+    (0x1A90, 0x1A99, IndicSyllabicCategory::Number),
+    (0x1B00, 0x1B02, IndicSyllabicCategory::Bindu),
+    (0x1B03, 0x1B03, IndicSyllabicCategory::ConsonantFinal),
+    (0x1B04, 0x1B04, IndicSyllabicCategory::Visarga),
+    (0x1B05, 0x1B12, IndicSyllabicCategory::VowelIndependent),
+    (0x1B13, 0x1B33, IndicSyllabicCategory::Consonant),
+    (0x1B34, 0x1B34, IndicSyllabicCategory::Nukta),
+    (0x1B35, 0x1B43, IndicSyllabicCategory::VowelDependent),
+    (0x1B44, 0x1B44, IndicSyllabicCategory::Virama),
+    (0x1B45, 0x1B4B, IndicSyllabicCategory::Consonant),
+    (0x1B50, 0x1B59, IndicSyllabicCategory::Number),
+    (0x1B80, 0x1B80, IndicSyllabicCategory::Bindu),
+    (0x1B81, 0x1B81, IndicSyllabicCategory::ConsonantFinal),
+    (0x1B82, 0x1B82, IndicSyllabicCategory::Visarga),
+    (0x1B83, 0x1B89, IndicSyllabicCategory::VowelIndependent),
+    (0x1B8A, 0x1BA0, IndicSyllabicCategory::Consonant),
+    (0x1BA1, 0x1BA3, IndicSyllabicCategory::ConsonantSubjoined),
+    (0x1BA4, 0x1BA9, IndicSyllabicCategory::VowelDependent),
+    (0x1BAA, 0x1BAA, IndicSyllabicCategory::PureKiller),
+    (0x1BAB, 0x1BAB, IndicSyllabicCategory::InvisibleStacker),
+    (0x1BAC, 0x1BAD, IndicSyllabicCategory::ConsonantSubjoined),
+    (0x1BAE, 0x1BAF, IndicSyllabicCategory::Consonant),
+    (0x1BB0, 0x1BB9, IndicSyllabicCategory::Number),
+    (0x1BBA, 0x1BBA, IndicSyllabicCategory::Avagraha),
+    (0x1BBB, 0x1BBD, IndicSyllabicCategory::Consonant),
+    (0x1BBE, 0x1BBF, IndicSyllabicCategory::ConsonantFinal),
+    (0x1BC0, 0x1BE3, IndicSyllabicCategory::Consonant),
+    (0x1BE4, 0x1BE5, IndicSyllabicCategory::VowelIndependent),
+    (0x1BE6, 0x1BE6, IndicSyllabicCategory::Nukta),
+    (0x1BE7, 0x1BEF, IndicSyllabicCategory::VowelDependent),
+    (0x1BF0, 0x1BF1, IndicSyllabicCategory::ConsonantFinal),
+    (0x1BF2, 0x1BF3, IndicSyllabicCategory::PureKiller),
+    (0x1C00, 0x1C23, IndicSyllabicCategory::Consonant),
+    (0x1C24, 0x1C25, IndicSyllabicCategory::ConsonantSubjoined),
+    (0x1C26, 0x1C2C, IndicSyllabicCategory::VowelDependent),
+    (0x1C2D, 0x1C33, IndicSyllabicCategory::ConsonantFinal),
+    (0x1C34, 0x1C35, IndicSyllabicCategory::Bindu),
+    (0x1C36, 0x1C36, IndicSyllabicCategory::SyllableModifier),
+    (0x1C37, 0x1C37, IndicSyllabicCategory::Nukta),
+    (0x1C40, 0x1C49, IndicSyllabicCategory::Number),
+    (0x1C4D, 0x1C4F, IndicSyllabicCategory::Consonant),
+    (0x1CD0, 0x1CD2, IndicSyllabicCategory::CantillationMark),
+    (0x1CD4, 0x1CE1, IndicSyllabicCategory::CantillationMark),
+    (0x1CF2, 0x1CF3, IndicSyllabicCategory::ConsonantDead),
+    (0x1CF4, 0x1CF4, IndicSyllabicCategory::CantillationMark),
+    (0x1CF5, 0x1CF6, IndicSyllabicCategory::ConsonantWithStacker),
+    (0x1CF7, 0x1CF9, IndicSyllabicCategory::CantillationMark),
+    (0x1CFA, 0x1CFA, IndicSyllabicCategory::ConsonantPlaceholder),
+    (0x1DFB, 0x1DFB, IndicSyllabicCategory::SyllableModifier),
+    (0x200C, 0x200C, IndicSyllabicCategory::NonJoiner),
+    (0x200D, 0x200D, IndicSyllabicCategory::Joiner),
+    (0x2010, 0x2014, IndicSyllabicCategory::ConsonantPlaceholder),
+    (0x2074, 0x2074, IndicSyllabicCategory::SyllableModifier),
+    (0x2082, 0x2084, IndicSyllabicCategory::SyllableModifier),
+    (0x20F0, 0x20F0, IndicSyllabicCategory::CantillationMark),
+    (0x25CC, 0x25CC, IndicSyllabicCategory::ConsonantPlaceholder),
+    (0xA800, 0xA801, IndicSyllabicCategory::VowelIndependent),
+    (0xA802, 0xA802, IndicSyllabicCategory::VowelDependent),
+    (0xA803, 0xA805, IndicSyllabicCategory::VowelIndependent),
+    (0xA806, 0xA806, IndicSyllabicCategory::Virama),
+    (0xA807, 0xA80A, IndicSyllabicCategory::Consonant),
+    (0xA80B, 0xA80B, IndicSyllabicCategory::Bindu),
+    (0xA80C, 0xA822, IndicSyllabicCategory::Consonant),
+    (0xA823, 0xA827, IndicSyllabicCategory::VowelDependent),
+    (0xA82C, 0xA82C, IndicSyllabicCategory::PureKiller),
+    (0xA840, 0xA85D, IndicSyllabicCategory::Consonant),
+    (0xA85E, 0xA861, IndicSyllabicCategory::Vowel),
+    (0xA862, 0xA865, IndicSyllabicCategory::Consonant),
+    (0xA866, 0xA866, IndicSyllabicCategory::Vowel),
+    (0xA867, 0xA868, IndicSyllabicCategory::ConsonantSubjoined),
+    (0xA869, 0xA870, IndicSyllabicCategory::Consonant),
+    (0xA871, 0xA871, IndicSyllabicCategory::ConsonantSubjoined),
+    (0xA872, 0xA872, IndicSyllabicCategory::Consonant),
+    (0xA873, 0xA873, IndicSyllabicCategory::Bindu),
+    (0xA880, 0xA880, IndicSyllabicCategory::Bindu),
+    (0xA881, 0xA881, IndicSyllabicCategory::Visarga),
+    (0xA882, 0xA891, IndicSyllabicCategory::VowelIndependent),
+    (0xA892, 0xA8B3, IndicSyllabicCategory::Consonant),
+    (0xA8B4, 0xA8B4, IndicSyllabicCategory::ConsonantMedial),
+    (0xA8B5, 0xA8C3, IndicSyllabicCategory::VowelDependent),
+    (0xA8C4, 0xA8C4, IndicSyllabicCategory::Virama),
+    (0xA8C5, 0xA8C5, IndicSyllabicCategory::Bindu),
+    (0xA8D0, 0xA8D9, IndicSyllabicCategory::Number),
+    (0xA8E0, 0xA8F1, IndicSyllabicCategory::CantillationMark),
+    (0xA8F2, 0xA8F3, IndicSyllabicCategory::Bindu),
+    (0xA8FE, 0xA8FE, IndicSyllabicCategory::VowelIndependent),
+    (0xA8FF, 0xA8FF, IndicSyllabicCategory::VowelDependent),
+    (0xA900, 0xA909, IndicSyllabicCategory::Number),
+    (0xA90A, 0xA921, IndicSyllabicCategory::Consonant),
+    (0xA922, 0xA92A, IndicSyllabicCategory::Vowel),
+    (0xA92B, 0xA92D, IndicSyllabicCategory::ToneMark),
+    (0xA930, 0xA946, IndicSyllabicCategory::Consonant),
+    (0xA947, 0xA94E, IndicSyllabicCategory::VowelDependent),
+    (0xA94F, 0xA952, IndicSyllabicCategory::ConsonantFinal),
+    (0xA953, 0xA953, IndicSyllabicCategory::PureKiller),
+    (0xA980, 0xA981, IndicSyllabicCategory::Bindu),
+    (0xA982, 0xA982, IndicSyllabicCategory::ConsonantFinal),
+    (0xA983, 0xA983, IndicSyllabicCategory::Visarga),
+    (0xA984, 0xA988, IndicSyllabicCategory::VowelIndependent),
+    (0xA989, 0xA98B, IndicSyllabicCategory::Consonant),
+    (0xA98C, 0xA98E, IndicSyllabicCategory::VowelIndependent),
+    (0xA98F, 0xA9B2, IndicSyllabicCategory::Consonant),
+    (0xA9B3, 0xA9B3, IndicSyllabicCategory::Nukta),
+    (0xA9B4, 0xA9BC, IndicSyllabicCategory::VowelDependent),
+    (0xA9BD, 0xA9BF, IndicSyllabicCategory::ConsonantMedial),
+    (0xA9C0, 0xA9C0, IndicSyllabicCategory::Virama),
+    (0xA9D0, 0xA9D9, IndicSyllabicCategory::Number),
+    (0xA9E0, 0xA9E4, IndicSyllabicCategory::Consonant),
+    (0xA9E5, 0xA9E5, IndicSyllabicCategory::VowelDependent),
+    (0xA9E7, 0xA9EF, IndicSyllabicCategory::Consonant),
+    (0xA9F0, 0xA9F9, IndicSyllabicCategory::Number),
+    (0xA9FA, 0xA9FE, IndicSyllabicCategory::Consonant),
+    (0xAA00, 0xAA05, IndicSyllabicCategory::VowelIndependent),
+    (0xAA06, 0xAA28, IndicSyllabicCategory::Consonant),
+    (0xAA29, 0xAA32, IndicSyllabicCategory::VowelDependent),
+    (0xAA33, 0xAA36, IndicSyllabicCategory::ConsonantMedial),
+    (0xAA40, 0xAA4D, IndicSyllabicCategory::ConsonantFinal),
+    (0xAA50, 0xAA59, IndicSyllabicCategory::Number),
+    (0xAA60, 0xAA6F, IndicSyllabicCategory::Consonant),
+    (0xAA71, 0xAA73, IndicSyllabicCategory::Consonant),
+    (0xAA74, 0xAA76, IndicSyllabicCategory::ConsonantPlaceholder),
+    (0xAA7A, 0xAA7A, IndicSyllabicCategory::Consonant),
+    (0xAA7B, 0xAA7D, IndicSyllabicCategory::ToneMark),
+    (0xAA7E, 0xAAAF, IndicSyllabicCategory::Consonant),
+    (0xAAB0, 0xAABE, IndicSyllabicCategory::VowelDependent),
+    (0xAABF, 0xAABF, IndicSyllabicCategory::ToneMark),
+    (0xAAC0, 0xAAC0, IndicSyllabicCategory::ToneLetter),
+    (0xAAC1, 0xAAC1, IndicSyllabicCategory::ToneMark),
+    (0xAAC2, 0xAAC2, IndicSyllabicCategory::ToneLetter),
+    (0xAAE0, 0xAAE1, IndicSyllabicCategory::VowelIndependent),
+    (0xAAE2, 0xAAEA, IndicSyllabicCategory::Consonant),
+    (0xAAEB, 0xAAEF, IndicSyllabicCategory::VowelDependent),
+    (0xAAF5, 0xAAF5, IndicSyllabicCategory::Visarga),
+    (0xAAF6, 0xAAF6, IndicSyllabicCategory::InvisibleStacker),
+    (0xABC0, 0xABCD, IndicSyllabicCategory::Consonant),
+    (0xABCE, 0xABCF, IndicSyllabicCategory::VowelIndependent),
+    (0xABD0, 0xABD0, IndicSyllabicCategory::Consonant),
+    (0xABD1, 0xABD1, IndicSyllabicCategory::VowelIndependent),
+    (0xABD2, 0xABDA, IndicSyllabicCategory::Consonant),
+    (0xABDB, 0xABE2, IndicSyllabicCategory::ConsonantFinal),
+    (0xABE3, 0xABEA, IndicSyllabicCategory::VowelDependent),
+    (0xABEC, 0xABEC, IndicSyllabicCategory::ToneMark),
+    (0xABED, 0xABED, IndicSyllabicCategory::PureKiller),
+    (0xABF0, 0xABF9, IndicSyllabicCategory::Number),
+    (0x10A00, 0x10A00, IndicSyllabicCategory::Consonant),
+    (0x10A01, 0x10A03, IndicSyllabicCategory::VowelDependent),
+    (0x10A05, 0x10A06, IndicSyllabicCategory::VowelDependent),
+    (0x10A0C, 0x10A0D, IndicSyllabicCategory::VowelDependent),
+    (0x10A0E, 0x10A0E, IndicSyllabicCategory::Bindu),
+    (0x10A0F, 0x10A0F, IndicSyllabicCategory::Visarga),
+    (0x10A10, 0x10A13, IndicSyllabicCategory::Consonant),
+    (0x10A15, 0x10A17, IndicSyllabicCategory::Consonant),
+    (0x10A19, 0x10A35, IndicSyllabicCategory::Consonant),
+    (0x10A38, 0x10A3A, IndicSyllabicCategory::Nukta),
+    (0x10A3F, 0x10A3F, IndicSyllabicCategory::InvisibleStacker),
+    (0x10A40, 0x10A48, IndicSyllabicCategory::Number),
+    (0x11000, 0x11001, IndicSyllabicCategory::Bindu),
+    (0x11002, 0x11002, IndicSyllabicCategory::Visarga),
+    (
+        0x11003,
+        0x11004,
+        IndicSyllabicCategory::ConsonantWithStacker,
+    ),
+    (0x11005, 0x11012, IndicSyllabicCategory::VowelIndependent),
+    (0x11013, 0x11037, IndicSyllabicCategory::Consonant),
+    (0x11038, 0x11045, IndicSyllabicCategory::VowelDependent),
+    (0x11046, 0x11046, IndicSyllabicCategory::Virama),
+    (0x11052, 0x11065, IndicSyllabicCategory::BrahmiJoiningNumber),
+    (0x11066, 0x1106F, IndicSyllabicCategory::Number),
+    (0x1107F, 0x1107F, IndicSyllabicCategory::NumberJoiner),
+    (0x11080, 0x11081, IndicSyllabicCategory::Bindu),
+    (0x11082, 0x11082, IndicSyllabicCategory::Visarga),
+    (0x11083, 0x1108C, IndicSyllabicCategory::VowelIndependent),
+    (0x1108D, 0x110AF, IndicSyllabicCategory::Consonant),
+    (0x110B0, 0x110B8, IndicSyllabicCategory::VowelDependent),
+    (0x110B9, 0x110B9, IndicSyllabicCategory::Virama),
+    (0x110BA, 0x110BA, IndicSyllabicCategory::Nukta),
+    (0x11100, 0x11101, IndicSyllabicCategory::Bindu),
+    (0x11102, 0x11102, IndicSyllabicCategory::Visarga),
+    (0x11103, 0x11106, IndicSyllabicCategory::VowelIndependent),
+    (0x11107, 0x11126, IndicSyllabicCategory::Consonant),
+    (0x11127, 0x11132, IndicSyllabicCategory::VowelDependent),
+    (0x11133, 0x11133, IndicSyllabicCategory::InvisibleStacker),
+    (0x11134, 0x11134, IndicSyllabicCategory::PureKiller),
+    (0x11136, 0x1113F, IndicSyllabicCategory::Number),
+    (0x11144, 0x11144, IndicSyllabicCategory::Consonant),
+    (0x11145, 0x11146, IndicSyllabicCategory::VowelDependent),
+    (0x11147, 0x11147, IndicSyllabicCategory::Consonant),
+    (0x11150, 0x11154, IndicSyllabicCategory::Vowel),
+    (0x11155, 0x11172, IndicSyllabicCategory::Consonant),
+    (0x11173, 0x11173, IndicSyllabicCategory::Nukta),
+    (0x11180, 0x11181, IndicSyllabicCategory::Bindu),
+    (0x11182, 0x11182, IndicSyllabicCategory::Visarga),
+    (0x11183, 0x11190, IndicSyllabicCategory::VowelIndependent),
+    (0x11191, 0x111B2, IndicSyllabicCategory::Consonant),
+    (0x111B3, 0x111BF, IndicSyllabicCategory::VowelDependent),
+    (0x111C0, 0x111C0, IndicSyllabicCategory::Virama),
+    (0x111C1, 0x111C1, IndicSyllabicCategory::Avagraha),
+    (0x111C2, 0x111C3, IndicSyllabicCategory::ConsonantPrefixed),
+    (0x111C9, 0x111C9, IndicSyllabicCategory::SyllableModifier),
+    (0x111CA, 0x111CA, IndicSyllabicCategory::Nukta),
+    (0x111CB, 0x111CC, IndicSyllabicCategory::VowelDependent),
+    (0x111CE, 0x111CE, IndicSyllabicCategory::VowelDependent),
+    (0x111CF, 0x111CF, IndicSyllabicCategory::Bindu),
+    (0x111D0, 0x111D9, IndicSyllabicCategory::Number),
+    (0x111E1, 0x111F4, IndicSyllabicCategory::Number),
+    (0x11200, 0x11207, IndicSyllabicCategory::VowelIndependent),
+    (0x11208, 0x11211, IndicSyllabicCategory::Consonant),
+    (0x11213, 0x1122B, IndicSyllabicCategory::Consonant),
+    (0x1122C, 0x11233, IndicSyllabicCategory::VowelDependent),
+    (0x11234, 0x11234, IndicSyllabicCategory::Bindu),
+    (0x11235, 0x11235, IndicSyllabicCategory::Virama),
+    (0x11236, 0x11236, IndicSyllabicCategory::Nukta),
+    (0x11237, 0x11237, IndicSyllabicCategory::GeminationMark),
+    (0x1123E, 0x1123E, IndicSyllabicCategory::CantillationMark),
+    (0x11280, 0x11283, IndicSyllabicCategory::VowelIndependent),
+    (0x11284, 0x11286, IndicSyllabicCategory::Consonant),
+    (0x11288, 0x11288, IndicSyllabicCategory::Consonant),
+    (0x1128A, 0x1128D, IndicSyllabicCategory::Consonant),
+    (0x1128F, 0x1129D, IndicSyllabicCategory::Consonant),
+    (0x1129F, 0x112A8, IndicSyllabicCategory::Consonant),
+    (0x112B0, 0x112B9, IndicSyllabicCategory::VowelIndependent),
+    (0x112BA, 0x112DE, IndicSyllabicCategory::Consonant),
+    (0x112DF, 0x112DF, IndicSyllabicCategory::Bindu),
+    (0x112E0, 0x112E8, IndicSyllabicCategory::VowelDependent),
+    (0x112E9, 0x112E9, IndicSyllabicCategory::Nukta),
+    (0x112EA, 0x112EA, IndicSyllabicCategory::PureKiller),
+    (0x112F0, 0x112F9, IndicSyllabicCategory::Number),
+    (0x11300, 0x11302, IndicSyllabicCategory::Bindu),
+    (0x11303, 0x11303, IndicSyllabicCategory::Visarga),
+    (0x11305, 0x1130C, IndicSyllabicCategory::VowelIndependent),
+    (0x1130F, 0x11310, IndicSyllabicCategory::VowelIndependent),
+    (0x11313, 0x11314, IndicSyllabicCategory::VowelIndependent),
+    (0x11315, 0x11328, IndicSyllabicCategory::Consonant),
+    (0x1132A, 0x11330, IndicSyllabicCategory::Consonant),
+    (0x11332, 0x11333, IndicSyllabicCategory::Consonant),
+    (0x11335, 0x11339, IndicSyllabicCategory::Consonant),
+    (0x1133B, 0x1133C, IndicSyllabicCategory::Nukta),
+    (0x1133D, 0x1133D, IndicSyllabicCategory::Avagraha),
+    (0x1133E, 0x11344, IndicSyllabicCategory::VowelDependent),
+    (0x11347, 0x11348, IndicSyllabicCategory::VowelDependent),
+    (0x1134B, 0x1134C, IndicSyllabicCategory::VowelDependent),
+    (0x1134D, 0x1134D, IndicSyllabicCategory::Virama),
+    (0x11357, 0x11357, IndicSyllabicCategory::VowelDependent),
+    (0x1135E, 0x1135F, IndicSyllabicCategory::Bindu),
+    (0x11360, 0x11361, IndicSyllabicCategory::VowelIndependent),
+    (0x11362, 0x11363, IndicSyllabicCategory::VowelDependent),
+    (0x11366, 0x1136C, IndicSyllabicCategory::CantillationMark),
+    (0x11370, 0x11374, IndicSyllabicCategory::CantillationMark),
+    (0x11400, 0x1140D, IndicSyllabicCategory::VowelIndependent),
+    (0x1140E, 0x11434, IndicSyllabicCategory::Consonant),
+    (0x11435, 0x11441, IndicSyllabicCategory::VowelDependent),
+    (0x11442, 0x11442, IndicSyllabicCategory::Virama),
+    (0x11443, 0x11444, IndicSyllabicCategory::Bindu),
+    (0x11445, 0x11445, IndicSyllabicCategory::Visarga),
+    (0x11446, 0x11446, IndicSyllabicCategory::Nukta),
+    (0x11447, 0x11447, IndicSyllabicCategory::Avagraha),
+    (0x11450, 0x11459, IndicSyllabicCategory::Number),
+    (0x1145E, 0x1145E, IndicSyllabicCategory::SyllableModifier),
+    (0x1145F, 0x1145F, IndicSyllabicCategory::Bindu),
+    (
+        0x11460,
+        0x11461,
+        IndicSyllabicCategory::ConsonantWithStacker,
+    ),
+    (0x11481, 0x1148E, IndicSyllabicCategory::VowelIndependent),
+    (0x1148F, 0x114AF, IndicSyllabicCategory::Consonant),
+    (0x114B0, 0x114BE, IndicSyllabicCategory::VowelDependent),
+    (0x114BF, 0x114C0, IndicSyllabicCategory::Bindu),
+    (0x114C1, 0x114C1, IndicSyllabicCategory::Visarga),
+    (0x114C2, 0x114C2, IndicSyllabicCategory::Virama),
+    (0x114C3, 0x114C3, IndicSyllabicCategory::Nukta),
+    (0x114C4, 0x114C4, IndicSyllabicCategory::Avagraha),
+    (0x114D0, 0x114D9, IndicSyllabicCategory::Number),
+    (0x11580, 0x1158D, IndicSyllabicCategory::VowelIndependent),
+    (0x1158E, 0x115AE, IndicSyllabicCategory::Consonant),
+    (0x115AF, 0x115B5, IndicSyllabicCategory::VowelDependent),
+    (0x115B8, 0x115BB, IndicSyllabicCategory::VowelDependent),
+    (0x115BC, 0x115BD, IndicSyllabicCategory::Bindu),
+    (0x115BE, 0x115BE, IndicSyllabicCategory::Visarga),
+    (0x115BF, 0x115BF, IndicSyllabicCategory::Virama),
+    (0x115C0, 0x115C0, IndicSyllabicCategory::Nukta),
+    (0x115D8, 0x115DB, IndicSyllabicCategory::VowelIndependent),
+    (0x115DC, 0x115DD, IndicSyllabicCategory::VowelDependent),
+    (0x11600, 0x1160D, IndicSyllabicCategory::VowelIndependent),
+    (0x1160E, 0x1162F, IndicSyllabicCategory::Consonant),
+    (0x11630, 0x1163C, IndicSyllabicCategory::VowelDependent),
+    (0x1163D, 0x1163D, IndicSyllabicCategory::Bindu),
+    (0x1163E, 0x1163E, IndicSyllabicCategory::Visarga),
+    (0x1163F, 0x1163F, IndicSyllabicCategory::Virama),
+    (0x11640, 0x11640, IndicSyllabicCategory::VowelDependent),
+    (0x11650, 0x11659, IndicSyllabicCategory::Number),
+    (0x11680, 0x11689, IndicSyllabicCategory::VowelIndependent),
+    (0x1168A, 0x116AA, IndicSyllabicCategory::Consonant),
+    (0x116AB, 0x116AB, IndicSyllabicCategory::Bindu),
+    (0x116AC, 0x116AC, IndicSyllabicCategory::Visarga),
+    (0x116AD, 0x116B5, IndicSyllabicCategory::VowelDependent),
+    (0x116B6, 0x116B6, IndicSyllabicCategory::Virama),
+    (0x116B7, 0x116B7, IndicSyllabicCategory::Nukta),
+    (0x116B8, 0x116B8, IndicSyllabicCategory::Consonant),
+    (0x116C0, 0x116C9, IndicSyllabicCategory::Number),
+    (0x11700, 0x1171A, IndicSyllabicCategory::Consonant),
+    (0x1171D, 0x1171F, IndicSyllabicCategory::ConsonantMedial),
+    (0x11720, 0x1172A, IndicSyllabicCategory::VowelDependent),
+    (0x1172B, 0x1172B, IndicSyllabicCategory::PureKiller),
+    (0x11730, 0x1173B, IndicSyllabicCategory::Number),
+    (0x11800, 0x11809, IndicSyllabicCategory::VowelIndependent),
+    (0x1180A, 0x1182B, IndicSyllabicCategory::Consonant),
+    (0x1182C, 0x11836, IndicSyllabicCategory::VowelDependent),
+    (0x11837, 0x11837, IndicSyllabicCategory::Bindu),
+    (0x11838, 0x11838, IndicSyllabicCategory::Visarga),
+    (0x11839, 0x11839, IndicSyllabicCategory::Virama),
+    (0x1183A, 0x1183A, IndicSyllabicCategory::Nukta),
+    (0x11900, 0x11906, IndicSyllabicCategory::VowelIndependent),
+    (0x11909, 0x11909, IndicSyllabicCategory::VowelIndependent),
+    (0x1190C, 0x11913, IndicSyllabicCategory::Consonant),
+    (0x11915, 0x11916, IndicSyllabicCategory::Consonant),
+    (0x11918, 0x1192F, IndicSyllabicCategory::Consonant),
+    (0x11930, 0x11935, IndicSyllabicCategory::VowelDependent),
+    (0x11937, 0x11938, IndicSyllabicCategory::VowelDependent),
+    (0x1193B, 0x1193C, IndicSyllabicCategory::Bindu),
+    (0x1193D, 0x1193D, IndicSyllabicCategory::PureKiller),
+    (0x1193E, 0x1193E, IndicSyllabicCategory::InvisibleStacker),
+    (0x1193F, 0x1193F, IndicSyllabicCategory::ConsonantPrefixed),
+    (0x11940, 0x11940, IndicSyllabicCategory::ConsonantMedial),
+    (
+        0x11941,
+        0x11941,
+        IndicSyllabicCategory::ConsonantPrecedingRepha,
+    ),
+    (0x11942, 0x11942, IndicSyllabicCategory::ConsonantMedial),
+    (0x11943, 0x11943, IndicSyllabicCategory::Nukta),
+    (0x11950, 0x11959, IndicSyllabicCategory::Number),
+    (0x119A0, 0x119A7, IndicSyllabicCategory::VowelIndependent),
+    (0x119AA, 0x119AD, IndicSyllabicCategory::VowelIndependent),
+    (0x119AE, 0x119D0, IndicSyllabicCategory::Consonant),
+    (0x119D1, 0x119D7, IndicSyllabicCategory::VowelDependent),
+    (0x119DA, 0x119DD, IndicSyllabicCategory::VowelDependent),
+    (0x119DE, 0x119DE, IndicSyllabicCategory::Bindu),
+    (0x119DF, 0x119DF, IndicSyllabicCategory::Visarga),
+    (0x119E0, 0x119E0, IndicSyllabicCategory::Virama),
+    (0x119E1, 0x119E1, IndicSyllabicCategory::Avagraha),
+    (0x119E4, 0x119E4, IndicSyllabicCategory::VowelDependent),
+    (0x11A00, 0x11A00, IndicSyllabicCategory::VowelIndependent),
+    (0x11A01, 0x11A0A, IndicSyllabicCategory::VowelDependent),
+    (0x11A0B, 0x11A32, IndicSyllabicCategory::Consonant),
+    (0x11A33, 0x11A33, IndicSyllabicCategory::SyllableModifier),
+    (0x11A34, 0x11A34, IndicSyllabicCategory::PureKiller),
+    (0x11A35, 0x11A38, IndicSyllabicCategory::Bindu),
+    (0x11A39, 0x11A39, IndicSyllabicCategory::Visarga),
+    (0x11A3A, 0x11A3A, IndicSyllabicCategory::ConsonantPrefixed),
+    (0x11A3B, 0x11A3E, IndicSyllabicCategory::ConsonantMedial),
+    (
+        0x11A3F,
+        0x11A3F,
+        IndicSyllabicCategory::ConsonantPlaceholder,
+    ),
+    (
+        0x11A45,
+        0x11A45,
+        IndicSyllabicCategory::ConsonantPlaceholder,
+    ),
+    (0x11A47, 0x11A47, IndicSyllabicCategory::InvisibleStacker),
+    (0x11A50, 0x11A50, IndicSyllabicCategory::VowelIndependent),
+    (0x11A51, 0x11A5B, IndicSyllabicCategory::VowelDependent),
+    (0x11A5C, 0x11A83, IndicSyllabicCategory::Consonant),
+    (0x11A84, 0x11A89, IndicSyllabicCategory::ConsonantPrefixed),
+    (0x11A8A, 0x11A95, IndicSyllabicCategory::ConsonantFinal),
+    (0x11A96, 0x11A96, IndicSyllabicCategory::Bindu),
+    (0x11A97, 0x11A97, IndicSyllabicCategory::Visarga),
+    (0x11A98, 0x11A98, IndicSyllabicCategory::GeminationMark),
+    (0x11A99, 0x11A99, IndicSyllabicCategory::InvisibleStacker),
+    (0x11A9D, 0x11A9D, IndicSyllabicCategory::Avagraha),
+    (0x11C00, 0x11C08, IndicSyllabicCategory::VowelIndependent),
+    (0x11C0A, 0x11C0D, IndicSyllabicCategory::VowelIndependent),
+    (0x11C0E, 0x11C2E, IndicSyllabicCategory::Consonant),
+    (0x11C2F, 0x11C36, IndicSyllabicCategory::VowelDependent),
+    (0x11C38, 0x11C3B, IndicSyllabicCategory::VowelDependent),
+    (0x11C3C, 0x11C3D, IndicSyllabicCategory::Bindu),
+    (0x11C3E, 0x11C3E, IndicSyllabicCategory::Visarga),
+    (0x11C3F, 0x11C3F, IndicSyllabicCategory::Virama),
+    (0x11C40, 0x11C40, IndicSyllabicCategory::Avagraha),
+    (0x11C50, 0x11C6C, IndicSyllabicCategory::Number),
+    (0x11C72, 0x11C8F, IndicSyllabicCategory::Consonant),
+    (0x11C92, 0x11CA7, IndicSyllabicCategory::ConsonantSubjoined),
+    (0x11CA9, 0x11CAF, IndicSyllabicCategory::ConsonantSubjoined),
+    (0x11CB0, 0x11CB4, IndicSyllabicCategory::VowelDependent),
+    (0x11CB5, 0x11CB6, IndicSyllabicCategory::Bindu),
+    (0x11D00, 0x11D06, IndicSyllabicCategory::VowelIndependent),
+    (0x11D08, 0x11D09, IndicSyllabicCategory::VowelIndependent),
+    (0x11D0B, 0x11D0B, IndicSyllabicCategory::VowelIndependent),
+    (0x11D0C, 0x11D30, IndicSyllabicCategory::Consonant),
+    (0x11D31, 0x11D36, IndicSyllabicCategory::VowelDependent),
+    (0x11D3A, 0x11D3A, IndicSyllabicCategory::VowelDependent),
+    (0x11D3C, 0x11D3D, IndicSyllabicCategory::VowelDependent),
+    (0x11D3F, 0x11D3F, IndicSyllabicCategory::VowelDependent),
+    (0x11D40, 0x11D40, IndicSyllabicCategory::Bindu),
+    (0x11D41, 0x11D41, IndicSyllabicCategory::Visarga),
+    (0x11D42, 0x11D42, IndicSyllabicCategory::Nukta),
+    (0x11D43, 0x11D43, IndicSyllabicCategory::VowelDependent),
+    (0x11D44, 0x11D44, IndicSyllabicCategory::PureKiller),
+    (0x11D45, 0x11D45, IndicSyllabicCategory::InvisibleStacker),
+    (
+        0x11D46,
+        0x11D46,
+        IndicSyllabicCategory::ConsonantPrecedingRepha,
+    ),
+    (0x11D47, 0x11D47, IndicSyllabicCategory::ConsonantMedial),
+    (0x11D50, 0x11D59, IndicSyllabicCategory::Number),
+    (0x11D60, 0x11D65, IndicSyllabicCategory::VowelIndependent),
+    (0x11D67, 0x11D68, IndicSyllabicCategory::VowelIndependent),
+    (0x11D6A, 0x11D6B, IndicSyllabicCategory::VowelIndependent),
+    (0x11D6C, 0x11D89, IndicSyllabicCategory::Consonant),
+    (0x11D8A, 0x11D8E, IndicSyllabicCategory::VowelDependent),
+    (0x11D90, 0x11D91, IndicSyllabicCategory::VowelDependent),
+    (0x11D93, 0x11D94, IndicSyllabicCategory::VowelDependent),
+    (0x11D95, 0x11D95, IndicSyllabicCategory::Bindu),
+    (0x11D96, 0x11D96, IndicSyllabicCategory::Visarga),
+    (0x11D97, 0x11D97, IndicSyllabicCategory::InvisibleStacker),
+    (0x11DA0, 0x11DA9, IndicSyllabicCategory::Number),
+    (0x11EE0, 0x11EF1, IndicSyllabicCategory::Consonant),
+    (
+        0x11EF2,
+        0x11EF2,
+        IndicSyllabicCategory::ConsonantPlaceholder,
+    ),
+    (0x11EF3, 0x11EF6, IndicSyllabicCategory::VowelDependent),
+];
@@ -0,0 +1,474 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (ArabicShaping.txt and PropertyValueAliases.txt),
+// limited to the characters assigned in Unicode 13.0.
+
+use super::JoiningGroup;
+
+pub(super) const VALUES: &[JoiningGroup] = &[
+    JoiningGroup::AfricanFeh,
+    JoiningGroup::AfricanNoon,
+    JoiningGroup::AfricanQaf,
+    JoiningGroup::Ain,
+    JoiningGroup::Alaph,
+    JoiningGroup::Alef,
+    JoiningGroup::Beh,
+    JoiningGroup::Beth,
+    JoiningGroup::BurushaskiYehBarree,
+    JoiningGroup::Dal,
+    JoiningGroup::DalathRish,
+    JoiningGroup::E,
+    JoiningGroup::FarsiYeh,
+    JoiningGroup::Fe,
+    JoiningGroup::Feh,
+    JoiningGroup::FinalSemkath,
+    JoiningGroup::Gaf,
+    JoiningGroup::Gamal,
+    JoiningGroup::Hah,
+    JoiningGroup::HanifiRohingyaKinnaYa,
+    JoiningGroup::HanifiRohingyaPa,
+    JoiningGroup::He,
+    JoiningGroup::Heh,
+    JoiningGroup::HehGoal,
+    JoiningGroup::Heth,
+    JoiningGroup::Kaf,
+    JoiningGroup::Kaph,
+    JoiningGroup::Khaph,
+    JoiningGroup::KnottedHeh,
+    JoiningGroup::Lam,
+    JoiningGroup::Lamadh,
+    JoiningGroup::MalayalamBha,
+    JoiningGroup::MalayalamJa,
+    JoiningGroup::MalayalamLla,
+    JoiningGroup::MalayalamLlla,
+    JoiningGroup::MalayalamNga,
+    JoiningGroup::MalayalamNna,
+    JoiningGroup::MalayalamNnna,
+    JoiningGroup::MalayalamNya,
+    JoiningGroup::MalayalamRa,
+    JoiningGroup::MalayalamSsa,
+    JoiningGroup::MalayalamTta,
+    JoiningGroup::ManichaeanAleph,
+    JoiningGroup::ManichaeanAyin,
+    JoiningGroup::ManichaeanBeth,
+    JoiningGroup::ManichaeanDaleth,
+    JoiningGroup::ManichaeanDhamedh,
+    JoiningGroup::ManichaeanFive,
+    JoiningGroup::ManichaeanGimel,
+    JoiningGroup::ManichaeanHeth,
+    JoiningGroup::ManichaeanHundred,
+    JoiningGroup::ManichaeanKaph,
+    JoiningGroup::ManichaeanLamedh,
+    JoiningGroup::ManichaeanMem,
+    JoiningGroup::ManichaeanNun,
+    JoiningGroup::ManichaeanOne,
+    JoiningGroup::ManichaeanPe,
+    JoiningGroup::ManichaeanQoph,
+    JoiningGroup::ManichaeanResh,
+    JoiningGroup::ManichaeanSadhe,
+    JoiningGroup::ManichaeanSamekh,
+    JoiningGroup::ManichaeanTaw,
+    JoiningGroup::ManichaeanTen,
+    JoiningGroup::ManichaeanTeth,
+    JoiningGroup::ManichaeanThamedh,
+    JoiningGroup::ManichaeanTwenty,
+    JoiningGroup::ManichaeanWaw,
+    JoiningGroup::ManichaeanYodh,
+    JoiningGroup::ManichaeanZayin,
+    JoiningGroup::Meem,
+    JoiningGroup::Mim,
+    JoiningGroup::NoJoiningGroup,
+    JoiningGroup::Noon,
+    JoiningGroup::Nun,
+    JoiningGroup::Nya,
+    JoiningGroup::Pe,
+    JoiningGroup::Qaf,
+    JoiningGroup::Qaph,
+    JoiningGroup::Reh,
+    JoiningGroup::ReversedPe,
+    JoiningGroup::RohingyaYeh,
+    JoiningGroup::Sad,
+    JoiningGroup::Sadhe,
+    JoiningGroup::Seen,
+    JoiningGroup::Semkath,
+    JoiningGroup::Shin,
+    JoiningGroup::StraightWaw,
+    JoiningGroup::SwashKaf,
+    JoiningGroup::SyriacWaw,
+    JoiningGroup::Tah,
+    JoiningGroup::Taw,
+    JoiningGroup::TehMarbuta,
+    JoiningGroup::HamzaOnHehGoal,
+    JoiningGroup::Teth,
+    JoiningGroup::Waw,
+    JoiningGroup::Yeh,
+    JoiningGroup::YehBarree,
+    JoiningGroup::YehWithTail,
+    JoiningGroup::Yudh,
+    JoiningGroup::YudhHe,
+    JoiningGroup::Zain,
+    JoiningGroup::Zhain,
+];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[
+    ("African_Feh", "African_Feh", "African Feh"),
+    ("African_Noon", "African_Noon", "African Noon"),
+    ("African_Qaf", "African_Qaf", "African Qaf"),
+    ("Ain", "Ain", "Ain"),
+    ("Alaph", "Alaph", "Alaph"),
+    ("Alef", "Alef", "Alef"),
+    ("Beh", "Beh", "Beh"),
+    ("Beth", "Beth", "Beth"),
+    (
+        "Burushaski_Yeh_Barree",
+        "Burushaski_Yeh_Barree",
+        "Burushaski Yeh Barree",
+    ),
+    ("Dal", "Dal", "Dal"),
+    ("Dalath_Rish", "Dalath_Rish", "Dalath Rish"),
+    ("E", "E", "E"),
+    ("Farsi_Yeh", "Farsi_Yeh", "Farsi Yeh"),
+    ("Fe", "Fe", "Fe"),
+    ("Feh", "Feh", "Feh"),
+    ("Final_Semkath", "Final_Semkath", "Final Semkath"),
+    ("Gaf", "Gaf", "Gaf"),
+    ("Gamal", "Gamal", "Gamal"),
+    ("Hah", "Hah", "Hah"),
+    (
+        "Hanifi_Rohingya_Kinna_Ya",
+        "Hanifi_Rohingya_Kinna_Ya",
+        "Hanifi Rohingya Kinna Ya",
+    ),
+    (
+        "Hanifi_Rohingya_Pa",
+        "Hanifi_Rohingya_Pa",
+        "Hanifi Rohingya Pa",
+    ),
+    ("He", "He", "He"),
+    ("Heh", "Heh", "Heh"),
+    ("Heh_Goal", "Heh_Goal", "Heh Goal"),
+    ("Heth", "Heth", "Heth"),
+    ("Kaf", "Kaf", "Kaf"),
+    ("Kaph", "Kaph", "Kaph"),
+    ("Khaph", "Khaph", "Khaph"),
+    ("Knotted_Heh", "Knotted_Heh", "Knotted Heh"),
+    ("Lam", "Lam", "Lam"),
+    ("Lamadh", "Lamadh", "Lamadh"),
+    ("Malayalam_Bha", "Malayalam_Bha", "Malayalam Bha"),
+    ("Malayalam_Ja", "Malayalam_Ja", "Malayalam Ja"),
+    ("Malayalam_Lla", "Malayalam_Lla", "Malayalam Lla"),
+    ("Malayalam_Llla", "Malayalam_Llla", "Malayalam Llla"),
+    ("Malayalam_Nga", "Malayalam_Nga", "Malayalam Nga"),
+    ("Malayalam_Nna", "Malayalam_Nna", "Malayalam Nna"),
+    ("Malayalam_Nnna", "Malayalam_Nnna", "Malayalam Nnna"),
+    ("Malayalam_Nya", "Malayalam_Nya", "Malayalam Nya"),
+    ("Malayalam_Ra", "Malayalam_Ra", "Malayalam Ra"),
+    ("Malayalam_Ssa", "Malayalam_Ssa", "Malayalam Ssa"),
+    ("Malayalam_Tta", "Malayalam_Tta", "Malayalam Tta"),
+    ("Manichaean_Aleph", "Manichaean_Aleph", "Manichaean Aleph"),
+    ("Manichaean_Ayin", "Manichaean_Ayin", "Manichaean Ayin"),
+    ("Manichaean_Beth", "Manichaean_Beth", "Manichaean Beth"),
+    (
+        "Manichaean_Daleth",
+        "Manichaean_Daleth",
+        "Manichaean Daleth",
+    ),
+    (
+        "Manichaean_Dhamedh",
+        "Manichaean_Dhamedh",
+        "Manichaean Dhamedh",
+    ),
+    ("Manichaean_Five", "Manichaean_Five", "Manichaean Five"),
+    ("Manichaean_Gimel", "Manichaean_Gimel", "Manichaean Gimel"),
+    ("Manichaean_Heth", "Manichaean_Heth", "Manichaean Heth"),
+    (
+        "Manichaean_Hundred",
+        "Manichaean_Hundred",
+        "Manichaean Hundred",
+    ),
+    ("Manichaean_Kaph", "Manichaean_Kaph", "Manichaean Kaph"),
+    (
+        "Manichaean_Lamedh",
+        "Manichaean_Lamedh",
+        "Manichaean Lamedh",
+    ),
+    ("Manichaean_Mem", "Manichaean_Mem", "Manichaean Mem"),
+    ("Manichaean_Nun", "Manichaean_Nun", "Manichaean Nun"),
+    ("Manichaean_One", "Manichaean_One", "Manichaean One"),
+    ("Manichaean_Pe", "Manichaean_Pe", "Manichaean Pe"),
+    ("Manichaean_Qoph", "Manichaean_Qoph", "Manichaean Qoph"),
+    ("Manichaean_Resh", "Manichaean_Resh", "Manichaean Resh"),
+    ("Manichaean_Sadhe", "Manichaean_Sadhe", "Manichaean Sadhe"),
+    (
+        "Manichaean_Samekh",
+        "Manichaean_Samekh",
+        "Manichaean Samekh",
+    ),
+    ("Manichaean_Taw", "Manichaean_Taw", "Manichaean Taw"),
+    ("Manichaean_Ten", "Manichaean_Ten", "Manichaean Ten"),
+    ("Manichaean_Teth", "Manichaean_Teth", "Manichaean Teth"),
+    (
+        "Manichaean_Thamedh",
+        "Manichaean_Thamedh",
+        "Manichaean Thamedh",
+    ),
+    (
+        "Manichaean_Twenty",
+        "Manichaean_Twenty",
+        "Manichaean Twenty",
+    ),
+    ("Manichaean_Waw", "Manichaean_Waw", "Manichaean Waw"),
+    ("Manichaean_Yodh", "Manichaean_Yodh", "Manichaean Yodh"),
+    ("Manichaean_Zayin", "Manichaean_Zayin", "Manichaean Zayin"),
+    ("Meem", "Meem", "Meem"),
+    ("Mim", "Mim", "Mim"),
+    ("No_Joining_Group", "No_Joining_Group", "No Joining Group"),
+    ("Noon", "Noon", "Noon"),
+    ("Nun", "Nun", "Nun"),
+    ("Nya", "Nya", "Nya"),
+    ("Pe", "Pe", "Pe"),
+    ("Qaf", "Qaf", "Qaf"),
+    ("Qaph", "Qaph", "Qaph"),
+    ("Reh", "Reh", "Reh"),
+    ("Reversed_Pe", "Reversed_Pe", "Reversed Pe"),
+    ("Rohingya_Yeh", "Rohingya_Yeh", "Rohingya Yeh"),
+    ("Sad", "Sad", "Sad"),
+    ("Sadhe", "Sadhe", "Sadhe"),
+    ("Seen", "Seen", "Seen"),
+    ("Semkath", "Semkath", "Semkath"),
+    ("Shin", "Shin", "Shin"),
+    ("Straight_Waw", "Straight_Waw", "Straight Waw"),
+    ("Swash_Kaf", "Swash_Kaf", "Swash Kaf"),
+    ("Syriac_Waw", "Syriac_Waw", "Syriac Waw"),
+    ("Tah", "Tah", "Tah"),
+    ("Taw", "Taw", "Taw"),
+    ("Teh_Marbuta", "Teh_Marbuta", "Teh Marbuta"),
+    ("Teh_Marbuta_Goal", "Hamza_On_Heh_Goal", "Hamza On Heh Goal"),
+    ("Teth", "Teth", "Teth"),
+    ("Waw", "Waw", "Waw"),
+    ("Yeh", "Yeh", "Yeh"),
+    ("Yeh_Barree", "Yeh_Barree", "Yeh Barree"),
+    ("Yeh_With_Tail", "Yeh_With_Tail", "Yeh With Tail"),
+    ("Yudh", "Yudh", "Yudh"),
+    ("Yudh_He", "Yudh_He", "Yudh He"),
+    ("Zain", "Zain", "Zain"),
+    ("Zhain", "Zhain", "Zhain"),
+];
+
+pub(super) const RANGES: &[(u32, u32, JoiningGroup)] = &[
+    (0x0620, 0x0620, JoiningGroup::Yeh),
+    (0x0622, 0x0623, JoiningGroup::Alef),
+    (0x0624, 0x0624, JoiningGroup::Waw),
+    (0x0625, 0x0625, JoiningGroup::Alef),
+    (0x0626, 0x0626, JoiningGroup::Yeh),
+    (0x0627, 0x0627, JoiningGroup::Alef),
+    (0x0628, 0x0628, JoiningGroup::Beh),
+    (0x0629, 0x0629, JoiningGroup::TehMarbuta),
+    (0x062A, 0x062B, JoiningGroup::Beh),
+    (0x062C, 0x062E, JoiningGroup::Hah),
+    (0x062F, 0x0630, JoiningGroup::Dal),
+    (0x0631, 0x0632, JoiningGroup::Reh),
+    (0x0633, 0x0634, JoiningGroup::Seen),
+    (0x0635, 0x0636, JoiningGroup::Sad),
+    (0x0637, 0x0638, JoiningGroup::Tah),
+    (0x0639, 0x063A, JoiningGroup::Ain),
+    (0x063B, 0x063C, JoiningGroup::Gaf),
+    (0x063D, 0x063F, JoiningGroup::FarsiYeh),
+    (0x0641, 0x0641, JoiningGroup::Feh),
+    (0x0642, 0x0642, JoiningGroup::Qaf),
+    (0x0643, 0x0643, JoiningGroup::Kaf),
+    (0x0644, 0x0644, JoiningGroup::Lam),
+    (0x0645, 0x0645, JoiningGroup::Meem),
+    (0x0646, 0x0646, JoiningGroup::Noon),
+    (0x0647, 0x0647, JoiningGroup::Heh),
+    (0x0648, 0x0648, JoiningGroup::Waw),
+    (0x0649, 0x064A, JoiningGroup::Yeh),
+    (0x066E, 0x066E, JoiningGroup::Beh),
+    (0x066F, 0x066F, JoiningGroup::Qaf),
+    (0x0671, 0x0673, JoiningGroup::Alef),
+    (0x0675, 0x0675, JoiningGroup::Alef),
+    (0x0676, 0x0677, JoiningGroup::Waw),
+    (0x0678, 0x0678, JoiningGroup::Yeh),
+    (0x0679, 0x0680, JoiningGroup::Beh),
+    (0x0681, 0x0687, JoiningGroup::Hah),
+    (0x0688, 0x0690, JoiningGroup::Dal),
+    (0x0691, 0x0699, JoiningGroup::Reh),
+    (0x069A, 0x069C, JoiningGroup::Seen),
+    (0x069D, 0x069E, JoiningGroup::Sad),
+    (0x069F, 0x069F, JoiningGroup::Tah),
+    (0x06A0, 0x06A0, JoiningGroup::Ain),
+    (0x06A1, 0x06A6, JoiningGroup::Feh),
+    (0x06A7, 0x06A8, JoiningGroup::Qaf),
+    (0x06A9, 0x06A9, JoiningGroup::Gaf),
+    (0x06AA, 0x06AA, JoiningGroup::SwashKaf),
+    (0x06AB, 0x06AB, JoiningGroup::Gaf),
+    (0x06AC, 0x06AE, JoiningGroup::Kaf),
+    (0x06AF, 0x06B4, JoiningGroup::Gaf),
+    (0x06B5, 0x06B8, JoiningGroup::Lam),
+    (0x06B9, 0x06BC, JoiningGroup::Noon),
+    (0x06BD, 0x06BD, JoiningGroup::Nya),
+    (0x06BE, 0x06BE, JoiningGroup::KnottedHeh),
+    (0x06BF, 0x06BF, JoiningGroup::Hah),
+    (0x06C0, 0x06C0, JoiningGroup::TehMarbuta),
+    (0x06C1, 0x06C2, JoiningGroup::HehGoal),
+    (0x06C3, 0x06C3, JoiningGroup::HamzaOnHehGoal),
+    (0x06C4, 0x06CB, JoiningGroup::Waw),
+    (0x06CC, 0x06CC, JoiningGroup::FarsiYeh),
+    (0x06CD, 0x06CD, JoiningGroup::YehWithTail),
+    (0x06CE, 0x06CE, JoiningGroup::FarsiYeh),
+    (0x06CF, 0x06CF, JoiningGroup::Waw),
+    (0x06D0, 0x06D1, JoiningGroup::Yeh),
+    (0x06D2, 0x06D3, JoiningGroup::YehBarree),
+    (0x06D5, 0x06D5, JoiningGroup::TehMarbuta),
+    (0x06EE, 0x06EE, JoiningGroup::Dal),
+    (0x06EF, 0x06EF, JoiningGroup::Reh),
+    (0x06FA, 0x06FA, JoiningGroup::Seen),
+    (0x06FB, 0x06FB, JoiningGroup::Sad),
+    (0x06FC, 0x06FC, JoiningGroup::Ain),
+    (0x06FF, 0x06FF, JoiningGroup::KnottedHeh),
+    (0x0710, 0x0710, JoiningGroup::Alaph),
+    (0x0712, 0x0712, JoiningGroup::Beth),
+    (0x0713, 0x0714, JoiningGroup::Gamal),
+    (0x0715, 0x0716, JoiningGroup::DalathRish),
+    (0x0717, 0x0717, JoiningGroup::He),
+    (0x0718, 0x0718, JoiningGroup::SyriacWaw),
+    (0x0719, 0x0719, JoiningGroup::Zain),
+    (0x071A, 0x071A, JoiningGroup::Heth),
+    (0x071B, 0x071C, JoiningGroup::Teth),
+    (0x071D, 0x071D, JoiningGroup::Yudh),
+    (0x071E, 0x071E, JoiningGroup::YudhHe),
+    (0x071F, 0x071F, JoiningGroup::Kaph),
+    (0x0720, 0x0720, JoiningGroup::Lamadh),
+    (0x0721, 0x0721, JoiningGroup::Mim),
+    (0x0722, 0x0722, JoiningGroup::Nun),
+    (0x0723, 0x0723, JoiningGroup::Semkath),
+    (0x0724, 0x0724, JoiningGroup::FinalSemkath),
+    (0x0725, 0x0725, JoiningGroup::E),
+    (0x0726, 0x0726, JoiningGroup::Pe),
+    (0x0727, 0x0727, JoiningGroup::ReversedPe),
+    (0x0728, 0x0728, JoiningGroup::Sadhe),
+    (0x0729, 0x0729, JoiningGroup::Qaph),
+    (0x072A, 0x072A, JoiningGroup::DalathRish),
+    (0x072B, 0x072B, JoiningGroup::Shin),
+    (0x072C, 0x072C, JoiningGroup::Taw),
+    (0x072D, 0x072D, JoiningGroup::Beth),
+    (0x072E, 0x072E, JoiningGroup::Gamal),
+    (0x072F, 0x072F, JoiningGroup::DalathRish),
+    (0x074D, 0x074D, JoiningGroup::Zhain),
+    (0x074E, 0x074E, JoiningGroup::Khaph),
+    (0x074F, 0x074F, JoiningGroup::Fe),
+    (0x0750, 0x0756, JoiningGroup::Beh),
+    (0x0757, 0x0758, JoiningGroup::Hah),
+    (0x0759, 0x075A, JoiningGroup::Dal),
+    (0x075B, 0x075B, JoiningGroup::Reh),
+    (0x075C, 0x075C, JoiningGroup::Seen),
+    (0x075D, 0x075F, JoiningGroup::Ain),
+    (0x0760, 0x0761, JoiningGroup::Feh),
+    (0x0762, 0x0764, JoiningGroup::Gaf),
+    (0x0765, 0x0766, JoiningGroup::Meem),
+    (0x0767, 0x0769, JoiningGroup::Noon),
+    (0x076A, 0x076A, JoiningGroup::Lam),
+    (0x076B, 0x076C, JoiningGroup::Reh),
+    (0x076D, 0x076D, JoiningGroup::Seen),
+    (0x076E, 0x076F, JoiningGroup::Hah),
+    (0x0770, 0x0770, JoiningGroup::Seen),
+    (0x0771, 0x0771, JoiningGroup::Reh),
+    (0x0772, 0x0772, JoiningGroup::Hah),
+    (0x0773, 0x0774, JoiningGroup::Alef),
+    (0x0775, 0x0776, JoiningGroup::FarsiYeh),
+    (0x0777, 0x0777, JoiningGroup::Yeh),
+    (0x0778, 0x0779, JoiningGroup::Waw),
+    (0x077A, 0x077B, JoiningGroup::BurushaskiYehBarree),
+    (0x077C, 0x077C, JoiningGroup::Hah),
+    (0x077D, 0x077E, JoiningGroup::Seen),
+    (0x077F, 0x077F, JoiningGroup::Kaf),
+    (0x0860, 0x0860, JoiningGroup::MalayalamNga),
+    (0x0861, 0x0861, JoiningGroup::MalayalamJa),
+    (0x0862, 0x0862, JoiningGroup::MalayalamNya),
+    (0x0863, 0x0863, JoiningGroup::MalayalamTta),
+    (0x0864, 0x0864, JoiningGroup::MalayalamNna),
+    (0x0865, 0x0865, JoiningGroup::MalayalamNnna),
+    (0x0866, 0x0866, JoiningGroup::MalayalamBha),
+    (0x0867, 0x0867, JoiningGroup::MalayalamRa),
+    (0x0868, 0x0868, JoiningGroup::MalayalamLla),
+    (0x0869, 0x0869, JoiningGroup::MalayalamLlla),
+    (0x086A, 0x086A, JoiningGroup::MalayalamSsa),
+    (0x08A0, 0x08A1, JoiningGroup::Beh),
+    (0x08A2, 0x08A2, JoiningGroup::Hah),
+    (0x08A3, 0x08A3, JoiningGroup::Tah),
+    (0x08A4, 0x08A4, JoiningGroup::Feh),
+    (0x08A5, 0x08A5, JoiningGroup::Qaf),
+    (0x08A6, 0x08A6, JoiningGroup::Lam),
+    (0x08A7, 0x08A7, JoiningGroup::Meem),
+    (0x08A8, 0x08A9, JoiningGroup::Yeh),
+    (0x08AA, 0x08AA, JoiningGroup::Reh),
+    (0x08AB, 0x08AB, JoiningGroup::Waw),
+    (0x08AC, 0x08AC, JoiningGroup::RohingyaYeh),
+    (0x08AE, 0x08AE, JoiningGroup::Dal),
+    (0x08AF, 0x08AF, JoiningGroup::Sad),
+    (0x08B0, 0x08B0, JoiningGroup::Gaf),
+    (0x08B1, 0x08B1, JoiningGroup::StraightWaw),
+    (0x08B2, 0x08B2, JoiningGroup::Reh),
+    (0x08B3, 0x08B3, JoiningGroup::Ain),
+    (0x08B4, 0x08B4, JoiningGroup::Kaf),
+    (0x08B6, 0x08B8, JoiningGroup::Beh),
+    (0x08B9, 0x08B9, JoiningGroup::Reh),
+    (0x08BA, 0x08BA, JoiningGroup::Yeh),
+    (0x08BB, 0x08BB, JoiningGroup::AfricanFeh),
+    (0x08BC, 0x08BC, JoiningGroup::AfricanQaf),
+    (0x08BD, 0x08BD, JoiningGroup::AfricanNoon),
+    (0x08BE, 0x08C0, JoiningGroup::Beh),
+    (0x08C1, 0x08C1, JoiningGroup::Hah),
+    (0x08C2, 0x08C2, JoiningGroup::Gaf),
+    (0x08C3, 0x08C3, JoiningGroup::Ain),
+    (0x08C4, 0x08C4, JoiningGroup::AfricanQaf),
+    (0x08C5, 0x08C6, JoiningGroup::Hah),
+    (0x08C7, 0x08C7, JoiningGroup::Lam),
+    (0x10AC0, 0x10AC0, JoiningGroup::ManichaeanAleph),
+    (0x10AC1, 0x10AC2, JoiningGroup::ManichaeanBeth),
+    (0x10AC3, 0x10AC4, JoiningGroup::ManichaeanGimel),
+    (0x10AC5, 0x10AC5, JoiningGroup::ManichaeanDaleth),
+    (0x10AC7, 0x10AC7, JoiningGroup::ManichaeanWaw),
+    (0x10AC9, 0x10ACA, JoiningGroup::ManichaeanZayin),
+    (0x10ACD, 0x10ACD, JoiningGroup::ManichaeanHeth),
+    (0x10ACE, 0x10ACE, JoiningGroup::ManichaeanTeth),
+    (0x10ACF, 0x10ACF, JoiningGroup::ManichaeanYodh),
+    (0x10AD0, 0x10AD2, JoiningGroup::ManichaeanKaph),
+    (0x10AD3, 0x10AD3, JoiningGroup::ManichaeanLamedh),
+    (0x10AD4, 0x10AD4, JoiningGroup::ManichaeanDhamedh),
+    (0x10AD5, 0x10AD5, JoiningGroup::ManichaeanThamedh),
+    (0x10AD6, 0x10AD6, JoiningGroup::ManichaeanMem),
+    (0x10AD7, 0x10AD7, JoiningGroup::ManichaeanNun),
+    (0x10AD8, 0x10AD8, JoiningGroup::ManichaeanSamekh),
+    (0x10AD9, 0x10ADA, JoiningGroup::ManichaeanAyin),
+    (0x10ADB, 0x10ADC, JoiningGroup::ManichaeanPe),
+    (0x10ADD, 0x10ADD, JoiningGroup::ManichaeanSadhe),
+    (0x10ADE, 0x10AE0, JoiningGroup::ManichaeanQoph),
+    (0x10AE1, 0x10AE1, JoiningGroup::ManichaeanResh),
+    (0x10AE4, 0x10AE4, JoiningGroup::ManichaeanTaw),
+    (0x10AEB, 0x10AEB, JoiningGroup::ManichaeanOne),
+    (0x10AEC, 0x10AEC, JoiningGroup::ManichaeanFive),
+    (0x10AED, 0x10AED, JoiningGroup::ManichaeanTen),
+    (0x10AEE, 0x10AEE, JoiningGroup::ManichaeanTwenty),
+    (0x10AEF, 0x10AEF, JoiningGroup::ManichaeanHundred),
+    (0x10D02, 0x10D02, JoiningGroup::HanifiRohingyaPa),
+    (0x10D09, 0x10D09, JoiningGroup::HanifiRohingyaPa),
+    (0x10D19, 0x10D19, JoiningGroup::HanifiRohingyaKinnaYa),
+    (0x10D1C, 0x10D1C, JoiningGroup::HanifiRohingyaPa),
+    (0x10D1E, 0x10D1E, JoiningGroup::HanifiRohingyaKinnaYa),
+    (0x10D20, 0x10D20, JoiningGroup::HanifiRohingyaKinnaYa),
+    (0x10D23, 0x10D23, JoiningGroup::HanifiRohingyaKinnaYa),
+];
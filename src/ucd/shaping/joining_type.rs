@@ -0,0 +1,521 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (ArabicShaping.txt and PropertyValueAliases.txt),
+// limited to the characters assigned in Unicode 13.0.
+
+use super::JoiningType;
+
+pub(super) const VALUES: &[JoiningType] = &[
+    JoiningType::JoinCausing,
+    JoiningType::DualJoining,
+    JoiningType::LeftJoining,
+    JoiningType::RightJoining,
+    JoiningType::Transparent,
+    JoiningType::NonJoining,
+];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[
+    ("C", "Join_Causing", "Join Causing"),
+    ("D", "Dual_Joining", "Dual Joining"),
+    ("L", "Left_Joining", "Left Joining"),
+    ("R", "Right_Joining", "Right Joining"),
+    ("T", "Transparent", "Transparent"),
+    ("U", "Non_Joining", "Non Joining"),
+];
+
+pub(super) const RANGES: &[(u32, u32, JoiningType)] = &[
+    (0x00AD, 0x00AD, JoiningType::Transparent),
+    (0x0300, 0x036F, JoiningType::Transparent),
+    (0x0483, 0x0489, JoiningType::Transparent),
+    (0x0591, 0x05BD, JoiningType::Transparent),
+    (0x05BF, 0x05BF, JoiningType::Transparent),
+    (0x05C1, 0x05C2, JoiningType::Transparent),
+    (0x05C4, 0x05C5, JoiningType::Transparent),
+    (0x05C7, 0x05C7, JoiningType::Transparent),
+    (0x0610, 0x061A, JoiningType::Transparent),
+    (0x061C, 0x061C, JoiningType::Transparent),
+    (0x0620, 0x0620, JoiningType::DualJoining),
+    (0x0622, 0x0625, JoiningType::RightJoining),
+    (0x0626, 0x0626, JoiningType::DualJoining),
+    (0x0627, 0x0627, JoiningType::RightJoining),
+    (0x0628, 0x0628, JoiningType::DualJoining),
+    (0x0629, 0x0629, JoiningType::RightJoining),
+    (0x062A, 0x062E, JoiningType::DualJoining),
+    (0x062F, 0x0632, JoiningType::RightJoining),
+    (0x0633, 0x063F, JoiningType::DualJoining),
+    (0x0640, 0x0640, JoiningType::JoinCausing),
+    (0x0641, 0x0647, JoiningType::DualJoining),
+    (0x0648, 0x0648, JoiningType::RightJoining),
+    (0x0649, 0x064A, JoiningType::DualJoining),
+    (0x064B, 0x065F, JoiningType::Transparent),
+    (0x066E, 0x066F, JoiningType::DualJoining),
+    (0x0670, 0x0670, JoiningType::Transparent),
+    (0x0671, 0x0673, JoiningType::RightJoining),
+    (0x0675, 0x0677, JoiningType::RightJoining),
+    (0x0678, 0x0687, JoiningType::DualJoining),
+    (0x0688, 0x0699, JoiningType::RightJoining),
+    (0x069A, 0x06BF, JoiningType::DualJoining),
+    (0x06C0, 0x06C0, JoiningType::RightJoining),
+    (0x06C1, 0x06C2, JoiningType::DualJoining),
+    (0x06C3, 0x06CB, JoiningType::RightJoining),
+    (0x06CC, 0x06CC, JoiningType::DualJoining),
+    (0x06CD, 0x06CD, JoiningType::RightJoining),
+    (0x06CE, 0x06CE, JoiningType::DualJoining),
+    (0x06CF, 0x06CF, JoiningType::RightJoining),
+    (0x06D0, 0x06D1, JoiningType::DualJoining),
+    (0x06D2, 0x06D3, JoiningType::RightJoining),
+    (0x06D5, 0x06D5, JoiningType::RightJoining),
+    (0x06D6, 0x06DC, JoiningType::Transparent),
+    (0x06DF, 0x06E4, JoiningType::Transparent),
+    (0x06E7, 0x06E8, JoiningType::Transparent),
+    (0x06EA, 0x06ED, JoiningType::Transparent),
+    (0x06EE, 0x06EF, JoiningType::RightJoining),
+    (0x06FA, 0x06FC, JoiningType::DualJoining),
+    (0x06FF, 0x06FF, JoiningType::DualJoining),
+    (0x070F, 0x070F, JoiningType::Transparent),
+    (0x0710, 0x0710, JoiningType::RightJoining),
+    (0x0711, 0x0711, JoiningType::Transparent),
+    (0x0712, 0x0714, JoiningType::DualJoining),
+    (0x0715, 0x0719, JoiningType::RightJoining),
+    (0x071A, 0x071D, JoiningType::DualJoining),
+    (0x071E, 0x071E, JoiningType::RightJoining),
+    (0x071F, 0x0727, JoiningType::DualJoining),
+    (0x0728, 0x0728, JoiningType::RightJoining),
+    (0x0729, 0x0729, JoiningType::DualJoining),
+    (0x072A, 0x072A, JoiningType::RightJoining),
+    (0x072B, 0x072B, JoiningType::DualJoining),
+    (0x072C, 0x072C, JoiningType::RightJoining),
+    (0x072D, 0x072E, JoiningType::DualJoining),
+    (0x072F, 0x072F, JoiningType::RightJoining),
+    (0x0730, 0x074A, JoiningType::Transparent),
+    (0x074D, 0x074D, JoiningType::RightJoining),
+    (0x074E, 0x0758, JoiningType::DualJoining),
+    (0x0759, 0x075B, JoiningType::RightJoining),
+    (0x075C, 0x076A, JoiningType::DualJoining),
+    (0x076B, 0x076C, JoiningType::RightJoining),
+    (0x076D, 0x0770, JoiningType::DualJoining),
+    (0x0771, 0x0771, JoiningType::RightJoining),
+    (0x0772, 0x0772, JoiningType::DualJoining),
+    (0x0773, 0x0774, JoiningType::RightJoining),
+    (0x0775, 0x0777, JoiningType::DualJoining),
+    (0x0778, 0x0779, JoiningType::RightJoining),
+    (0x077A, 0x077F, JoiningType::DualJoining),
+    (0x07A6, 0x07B0, JoiningType::Transparent),
+    (0x07CA, 0x07EA, JoiningType::DualJoining),
+    (0x07EB, 0x07F3, JoiningType::Transparent),
+    (0x07FA, 0x07FA, JoiningType::JoinCausing),
+    (0x07FD, 0x07FD, JoiningType::Transparent),
+    (0x0816, 0x0819, JoiningType::Transparent),
+    (0x081B, 0x0823, JoiningType::Transparent),
+    (0x0825, 0x0827, JoiningType::Transparent),
+    (0x0829, 0x082D, JoiningType::Transparent),
+    (0x0840, 0x0840, JoiningType::RightJoining),
+    (0x0841, 0x0845, JoiningType::DualJoining),
+    (0x0846, 0x0847, JoiningType::RightJoining),
+    (0x0848, 0x0848, JoiningType::DualJoining),
+    (0x0849, 0x0849, JoiningType::RightJoining),
+    (0x084A, 0x0853, JoiningType::DualJoining),
+    (0x0854, 0x0854, JoiningType::RightJoining),
+    (0x0855, 0x0855, JoiningType::DualJoining),
+    (0x0856, 0x0858, JoiningType::RightJoining),
+    (0x0859, 0x085B, JoiningType::Transparent),
+    (0x0860, 0x0860, JoiningType::DualJoining),
+    (0x0862, 0x0865, JoiningType::DualJoining),
+    (0x0867, 0x0867, JoiningType::RightJoining),
+    (0x0868, 0x0868, JoiningType::DualJoining),
+    (0x0869, 0x086A, JoiningType::RightJoining),
+    (0x08A0, 0x08A9, JoiningType::DualJoining),
+    (0x08AA, 0x08AC, JoiningType::RightJoining),
+    (0x08AE, 0x08AE, JoiningType::RightJoining),
+    (0x08AF, 0x08B0, JoiningType::DualJoining),
+    (0x08B1, 0x08B2, JoiningType::RightJoining),
+    (0x08B3, 0x08B4, JoiningType::DualJoining),
+    (0x08B6, 0x08B8, JoiningType::DualJoining),
+    (0x08B9, 0x08B9, JoiningType::RightJoining),
+    (0x08BA, 0x08C7, JoiningType::DualJoining),
+    (0x08D3, 0x08E1, JoiningType::Transparent),
+    (0x08E3, 0x0902, JoiningType::Transparent),
+    (0x093A, 0x093A, JoiningType::Transparent),
+    (0x093C, 0x093C, JoiningType::Transparent),
+    (0x0941, 0x0948, JoiningType::Transparent),
+    (0x094D, 0x094D, JoiningType::Transparent),
+    (0x0951, 0x0957, JoiningType::Transparent),
+    (0x0962, 0x0963, JoiningType::Transparent),
+    (0x0981, 0x0981, JoiningType::Transparent),
+    (0x09BC, 0x09BC, JoiningType::Transparent),
+    (0x09C1, 0x09C4, JoiningType::Transparent),
+    (0x09CD, 0x09CD, JoiningType::Transparent),
+    (0x09E2, 0x09E3, JoiningType::Transparent),
+    (0x09FE, 0x09FE, JoiningType::Transparent),
+    (0x0A01, 0x0A02, JoiningType::Transparent),
+    (0x0A3C, 0x0A3C, JoiningType::Transparent),
+    (0x0A41, 0x0A42, JoiningType::Transparent),
+    (0x0A47, 0x0A48, JoiningType::Transparent),
+    (0x0A4B, 0x0A4D, JoiningType::Transparent),
+    (0x0A51, 0x0A51, JoiningType::Transparent),
+    (0x0A70, 0x0A71, JoiningType::Transparent),
+    (0x0A75, 0x0A75, JoiningType::Transparent),
+    (0x0A81, 0x0A82, JoiningType::Transparent),
+    (0x0ABC, 0x0ABC, JoiningType::Transparent),
+    (0x0AC1, 0x0AC5, JoiningType::Transparent),
+    (0x0AC7, 0x0AC8, JoiningType::Transparent),
+    (0x0ACD, 0x0ACD, JoiningType::Transparent),
+    (0x0AE2, 0x0AE3, JoiningType::Transparent),
+    (0x0AFA, 0x0AFF, JoiningType::Transparent),
+    (0x0B01, 0x0B01, JoiningType::Transparent),
+    (0x0B3C, 0x0B3C, JoiningType::Transparent),
+    (0x0B3F, 0x0B3F, JoiningType::Transparent),
+    (0x0B41, 0x0B44, JoiningType::Transparent),
+    (0x0B4D, 0x0B4D, JoiningType::Transparent),
+    (0x0B55, 0x0B56, JoiningType::Transparent),
+    (0x0B62, 0x0B63, JoiningType::Transparent),
+    (0x0B82, 0x0B82, JoiningType::Transparent),
+    (0x0BC0, 0x0BC0, JoiningType::Transparent),
+    (0x0BCD, 0x0BCD, JoiningType::Transparent),
+    (0x0C00, 0x0C00, JoiningType::Transparent),
+    (0x0C04, 0x0C04, JoiningType::Transparent),
+    (0x0C3E, 0x0C40, JoiningType::Transparent),
+    (0x0C46, 0x0C48, JoiningType::Transparent),
+    (0x0C4A, 0x0C4D, JoiningType::Transparent),
+    (0x0C55, 0x0C56, JoiningType::Transparent),
+    (0x0C62, 0x0C63, JoiningType::Transparent),
+    (0x0C81, 0x0C81, JoiningType::Transparent),
+    (0x0CBC, 0x0CBC, JoiningType::Transparent),
+    (0x0CBF, 0x0CBF, JoiningType::Transparent),
+    (0x0CC6, 0x0CC6, JoiningType::Transparent),
+    (0x0CCC, 0x0CCD, JoiningType::Transparent),
+    (0x0CE2, 0x0CE3, JoiningType::Transparent),
+    (0x0D00, 0x0D01, JoiningType::Transparent),
+    (0x0D3B, 0x0D3C, JoiningType::Transparent),
+    (0x0D41, 0x0D44, JoiningType::Transparent),
+    (0x0D4D, 0x0D4D, JoiningType::Transparent),
+    (0x0D62, 0x0D63, JoiningType::Transparent),
+    (0x0D81, 0x0D81, JoiningType::Transparent),
+    (0x0DCA, 0x0DCA, JoiningType::Transparent),
+    (0x0DD2, 0x0DD4, JoiningType::Transparent),
+    (0x0DD6, 0x0DD6, JoiningType::Transparent),
+    (0x0E31, 0x0E31, JoiningType::Transparent),
+    (0x0E34, 0x0E3A, JoiningType::Transparent),
+    (0x0E47, 0x0E4E, JoiningType::Transparent),
+    (0x0EB1, 0x0EB1, JoiningType::Transparent),
+    (0x0EB4, 0x0EBC, JoiningType::Transparent),
+    (0x0EC8, 0x0ECD, JoiningType::Transparent),
+    (0x0F18, 0x0F19, JoiningType::Transparent),
+    (0x0F35, 0x0F35, JoiningType::Transparent),
+    (0x0F37, 0x0F37, JoiningType::Transparent),
+    (0x0F39, 0x0F39, JoiningType::Transparent),
+    (0x0F71, 0x0F7E, JoiningType::Transparent),
+    (0x0F80, 0x0F84, JoiningType::Transparent),
+    (0x0F86, 0x0F87, JoiningType::Transparent),
+    (0x0F8D, 0x0F97, JoiningType::Transparent),
+    (0x0F99, 0x0FBC, JoiningType::Transparent),
+    (0x0FC6, 0x0FC6, JoiningType::Transparent),
+    (0x102D, 0x1030, JoiningType::Transparent),
+    (0x1032, 0x1037, JoiningType::Transparent),
+    (0x1039, 0x103A, JoiningType::Transparent),
+    (0x103D, 0x103E, JoiningType::Transparent),
+    (0x1058, 0x1059, JoiningType::Transparent),
+    (0x105E, 0x1060, JoiningType::Transparent),
+    (0x1071, 0x1074, JoiningType::Transparent),
+    (0x1082, 0x1082, JoiningType::Transparent),
+    (0x1085, 0x1086, JoiningType::Transparent),
+    (0x108D, 0x108D, JoiningType::Transparent),
+    (0x109D, 0x109D, JoiningType::Transparent),
+    (0x135D, 0x135F, JoiningType::Transparent),
+    (0x1712, 0x1714, JoiningType::Transparent),
+    (0x1732, 0x1733, JoiningType::Transparent),
+    (0x1752, 0x1753, JoiningType::Transparent),
+    (0x1772, 0x1773, JoiningType::Transparent),
+    (0x17B4, 0x17B5, JoiningType::Transparent),
+    (0x17B7, 0x17BD, JoiningType::Transparent),
+    (0x17C6, 0x17C6, JoiningType::Transparent),
+    (0x17C9, 0x17D3, JoiningType::Transparent),
+    (0x17DD, 0x17DD, JoiningType::Transparent),
+    (0x1807, 0x1807, JoiningType::DualJoining),
+    (0x180A, 0x180A, JoiningType::JoinCausing),
+    (0x180B, 0x180D, JoiningType::Transparent),
+    (0x1820, 0x1878, JoiningType::DualJoining),
+    (0x1885, 0x1886, JoiningType::Transparent),
+    (0x1887, 0x18A8, JoiningType::DualJoining),
+    (0x18A9, 0x18A9, JoiningType::Transparent),
+    (0x18AA, 0x18AA, JoiningType::DualJoining),
+    (0x1920, 0x1922, JoiningType::Transparent),
+    (0x1927, 0x1928, JoiningType::Transparent),
+    (0x1932, 0x1932, JoiningType::Transparent),
+    (0x1939, 0x193B, JoiningType::Transparent),
+    (0x1A17, 0x1A18, JoiningType::Transparent),
+    (0x1A1B, 0x1A1B, JoiningType::Transparent),
+    (0x1A56, 0x1A56, JoiningType::Transparent),
+    (0x1A58, 0x1A5E, JoiningType::Transparent),
+    (0x1A60, 0x1A60, JoiningType::Transparent),
+    (0x1A62, 0x1A62, JoiningType::Transparent),
+    (0x1A65, 0x1A6C, JoiningType::Transparent),
+    (0x1A73, 0x1A7C, JoiningType::Transparent),
+    (0x1A7F, 0x1A7F, JoiningType::Transparent),
+    (0x1AB0, 0x1AC0, JoiningType::Transparent),
+    (0x1B00, 0x1B03, JoiningType::Transparent),
+    (0x1B34, 0x1B34, JoiningType::Transparent),
+    (0x1B36, 0x1B3A, JoiningType::Transparent),
+    (0x1B3C, 0x1B3C, JoiningType::Transparent),
+    (0x1B42, 0x1B42, JoiningType::Transparent),
+    (0x1B6B, 0x1B73, JoiningType::Transparent),
+    (0x1B80, 0x1B81, JoiningType::Transparent),
+    (0x1BA2, 0x1BA5, JoiningType::Transparent),
+    (0x1BA8, 0x1BA9, JoiningType::Transparent),
+    (0x1BAB, 0x1BAD, JoiningType::Transparent),
+    (0x1BE6, 0x1BE6, JoiningType::Transparent),
+    (0x1BE8, 0x1BE9, JoiningType::Transparent),
+    (0x1BED, 0x1BED, JoiningType::Transparent),
+    (0x1BEF, 0x1BF1, JoiningType::Transparent),
+    (0x1C2C, 0x1C33, JoiningType::Transparent),
+    (0x1C36, 0x1C37, JoiningType::Transparent),
+    (0x1CD0, 0x1CD2, JoiningType::Transparent),
+    (0x1CD4, 0x1CE0, JoiningType::Transparent),
+    (0x1CE2, 0x1CE8, JoiningType::Transparent),
+    (0x1CED, 0x1CED, JoiningType::Transparent),
+    (0x1CF4, 0x1CF4, JoiningType::Transparent),
+    (0x1CF8, 0x1CF9, JoiningType::Transparent),
+    (0x1DC0, 0x1DF9, JoiningType::Transparent),
+    (0x1DFB, 0x1DFF, JoiningType::Transparent),
+    (0x200B, 0x200B, JoiningType::Transparent),
+    (0x200D, 0x200D, JoiningType::JoinCausing),
+    (0x200E, 0x200F, JoiningType::Transparent),
+    (0x202A, 0x202E, JoiningType::Transparent),
+    (0x2060, 0x2064, JoiningType::Transparent),
+    (0x206A, 0x206F, JoiningType::Transparent),
+    (0x20D0, 0x20F0, JoiningType::Transparent),
+    (0x2CEF, 0x2CF1, JoiningType::Transparent),
+    (0x2D7F, 0x2D7F, JoiningType::Transparent),
+    (0x2DE0, 0x2DFF, JoiningType::Transparent),
+    (0x302A, 0x302D, JoiningType::Transparent),
+    (0x3099, 0x309A, JoiningType::Transparent),
+    (0xA66F, 0xA672, JoiningType::Transparent),
+    (0xA674, 0xA67D, JoiningType::Transparent),
+    (0xA69E, 0xA69F, JoiningType::Transparent),
+    (0xA6F0, 0xA6F1, JoiningType::Transparent),
+    (0xA802, 0xA802, JoiningType::Transparent),
+    (0xA806, 0xA806, JoiningType::Transparent),
+    (0xA80B, 0xA80B, JoiningType::Transparent),
+    (0xA825, 0xA826, JoiningType::Transparent),
+    (0xA82C, 0xA82C, JoiningType::Transparent),
+    (0xA840, 0xA871, JoiningType::DualJoining),
+    (0xA872, 0xA872, JoiningType::LeftJoining),
+    (0xA8C4, 0xA8C5, JoiningType::Transparent),
+    (0xA8E0, 0xA8F1, JoiningType::Transparent),
+    (0xA8FF, 0xA8FF, JoiningType::Transparent),
+    (0xA926, 0xA92D, JoiningType::Transparent),
+    (0xA947, 0xA951, JoiningType::Transparent),
+    (0xA980, 0xA982, JoiningType::Transparent),
+    (0xA9B3, 0xA9B3, JoiningType::Transparent),
+    (0xA9B6, 0xA9B9, JoiningType::Transparent),
+    (0xA9BC, 0xA9BD, JoiningType::Transparent),
+    (0xA9E5, 0xA9E5, JoiningType::Transparent),
+    (0xAA29, 0xAA2E, JoiningType::Transparent),
+    (0xAA31, 0xAA32, JoiningType::Transparent),
+    (0xAA35, 0xAA36, JoiningType::Transparent),
+    (0xAA43, 0xAA43, JoiningType::Transparent),
+    (0xAA4C, 0xAA4C, JoiningType::Transparent),
+    (0xAA7C, 0xAA7C, JoiningType::Transparent),
+    (0xAAB0, 0xAAB0, JoiningType::Transparent),
+    (0xAAB2, 0xAAB4, JoiningType::Transparent),
+    (0xAAB7, 0xAAB8, JoiningType::Transparent),
+    (0xAABE, 0xAABF, JoiningType::Transparent),
+    (0xAAC1, 0xAAC1, JoiningType::Transparent),
+    (0xAAEC, 0xAAED, JoiningType::Transparent),
+    (0xAAF6, 0xAAF6, JoiningType::Transparent),
+    (0xABE5, 0xABE5, JoiningType::Transparent),
+    (0xABE8, 0xABE8, JoiningType::Transparent),
+    (0xABED, 0xABED, JoiningType::Transparent),
+    (0xFB1E, 0xFB1E, JoiningType::Transparent),
+    (0xFE00, 0xFE0F, JoiningType::Transparent),
+    (0xFE20, 0xFE2F, JoiningType::Transparent),
+    (0xFEFF, 0xFEFF, JoiningType::Transparent),
+    (0xFFF9, 0xFFFB, JoiningType::Transparent),
+    (0x101FD, 0x101FD, JoiningType::Transparent),
+    (0x102E0, 0x102E0, JoiningType::Transparent),
+    (0x10376, 0x1037A, JoiningType::Transparent),
+    (0x10A01, 0x10A03, JoiningType::Transparent),
+    (0x10A05, 0x10A06, JoiningType::Transparent),
+    (0x10A0C, 0x10A0F, JoiningType::Transparent),
+    (0x10A38, 0x10A3A, JoiningType::Transparent),
+    (0x10A3F, 0x10A3F, JoiningType::Transparent),
+    (0x10AC0, 0x10AC4, JoiningType::DualJoining),
+    (0x10AC5, 0x10AC5, JoiningType::RightJoining),
+    (0x10AC7, 0x10AC7, JoiningType::RightJoining),
+    (0x10AC9, 0x10ACA, JoiningType::RightJoining),
+    (0x10ACD, 0x10ACD, JoiningType::LeftJoining),
+    (0x10ACE, 0x10AD2, JoiningType::RightJoining),
+    (0x10AD3, 0x10AD6, JoiningType::DualJoining),
+    (0x10AD7, 0x10AD7, JoiningType::LeftJoining),
+    (0x10AD8, 0x10ADC, JoiningType::DualJoining),
+    (0x10ADD, 0x10ADD, JoiningType::RightJoining),
+    (0x10ADE, 0x10AE0, JoiningType::DualJoining),
+    (0x10AE1, 0x10AE1, JoiningType::RightJoining),
+    (0x10AE4, 0x10AE4, JoiningType::RightJoining),
+    (0x10AE5, 0x10AE6, JoiningType::Transparent),
+    (0x10AEB, 0x10AEE, JoiningType::DualJoining),
+    (0x10AEF, 0x10AEF, JoiningType::RightJoining),
+    (0x10B80, 0x10B80, JoiningType::DualJoining),
+    (0x10B81, 0x10B81, JoiningType::RightJoining),
+    (0x10B82, 0x10B82, JoiningType::DualJoining),
+    (0x10B83, 0x10B85, JoiningType::RightJoining),
+    (0x10B86, 0x10B88, JoiningType::DualJoining),
+    (0x10B89, 0x10B89, JoiningType::RightJoining),
+    (0x10B8A, 0x10B8B, JoiningType::DualJoining),
+    (0x10B8C, 0x10B8C, JoiningType::RightJoining),
+    (0x10B8D, 0x10B8D, JoiningType::DualJoining),
+    (0x10B8E, 0x10B8F, JoiningType::RightJoining),
+    (0x10B90, 0x10B90, JoiningType::DualJoining),
+    (0x10B91, 0x10B91, JoiningType::RightJoining),
+    (0x10BA9, 0x10BAC, JoiningType::RightJoining),
+    (0x10BAD, 0x10BAE, JoiningType::DualJoining),
+    (0x10D00, 0x10D00, JoiningType::LeftJoining),
+    (0x10D01, 0x10D21, JoiningType::DualJoining),
+    (0x10D22, 0x10D22, JoiningType::RightJoining),
+    (0x10D23, 0x10D23, JoiningType::DualJoining),
+    (0x10D24, 0x10D27, JoiningType::Transparent),
+    (0x10EAB, 0x10EAC, JoiningType::Transparent),
+    (0x10F30, 0x10F32, JoiningType::DualJoining),
+    (0x10F33, 0x10F33, JoiningType::RightJoining),
+    (0x10F34, 0x10F44, JoiningType::DualJoining),
+    (0x10F46, 0x10F50, JoiningType::Transparent),
+    (0x10F51, 0x10F53, JoiningType::DualJoining),
+    (0x10F54, 0x10F54, JoiningType::RightJoining),
+    (0x10FB0, 0x10FB0, JoiningType::DualJoining),
+    (0x10FB2, 0x10FB3, JoiningType::DualJoining),
+    (0x10FB4, 0x10FB6, JoiningType::RightJoining),
+    (0x10FB8, 0x10FB8, JoiningType::DualJoining),
+    (0x10FB9, 0x10FBA, JoiningType::RightJoining),
+    (0x10FBB, 0x10FBC, JoiningType::DualJoining),
+    (0x10FBD, 0x10FBD, JoiningType::RightJoining),
+    (0x10FBE, 0x10FBF, JoiningType::DualJoining),
+    (0x10FC1, 0x10FC1, JoiningType::DualJoining),
+    (0x10FC2, 0x10FC3, JoiningType::RightJoining),
+    (0x10FC4, 0x10FC4, JoiningType::DualJoining),
+    (0x10FC9, 0x10FC9, JoiningType::RightJoining),
+    (0x10FCA, 0x10FCA, JoiningType::DualJoining),
+    (0x10FCB, 0x10FCB, JoiningType::LeftJoining),
+    (0x11001, 0x11001, JoiningType::Transparent),
+    (0x11038, 0x11046, JoiningType::Transparent),
+    (0x1107F, 0x11081, JoiningType::Transparent),
+    (0x110B3, 0x110B6, JoiningType::Transparent),
+    (0x110B9, 0x110BA, JoiningType::Transparent),
+    (0x11100, 0x11102, JoiningType::Transparent),
+    (0x11127, 0x1112B, JoiningType::Transparent),
+    (0x1112D, 0x11134, JoiningType::Transparent),
+    (0x11173, 0x11173, JoiningType::Transparent),
+    (0x11180, 0x11181, JoiningType::Transparent),
+    (0x111B6, 0x111BE, JoiningType::Transparent),
+    (0x111C9, 0x111CC, JoiningType::Transparent),
+    (0x111CF, 0x111CF, JoiningType::Transparent),
+    (0x1122F, 0x11231, JoiningType::Transparent),
+    (0x11234, 0x11234, JoiningType::Transparent),
+    (0x11236, 0x11237, JoiningType::Transparent),
+    (0x1123E, 0x1123E, JoiningType::Transparent),
+    (0x112DF, 0x112DF, JoiningType::Transparent),
+    (0x112E3, 0x112EA, JoiningType::Transparent),
+    (0x11300, 0x11301, JoiningType::Transparent),
+    (0x1133B, 0x1133C, JoiningType::Transparent),
+    (0x11340, 0x11340, JoiningType::Transparent),
+    (0x11366, 0x1136C, JoiningType::Transparent),
+    (0x11370, 0x11374, JoiningType::Transparent),
+    (0x11438, 0x1143F, JoiningType::Transparent),
+    (0x11442, 0x11444, JoiningType::Transparent),
+    (0x11446, 0x11446, JoiningType::Transparent),
+    (0x1145E, 0x1145E, JoiningType::Transparent),
+    (0x114B3, 0x114B8, JoiningType::Transparent),
+    (0x114BA, 0x114BA, JoiningType::Transparent),
+    (0x114BF, 0x114C0, JoiningType::Transparent),
+    (0x114C2, 0x114C3, JoiningType::Transparent),
+    (0x115B2, 0x115B5, JoiningType::Transparent),
+    (0x115BC, 0x115BD, JoiningType::Transparent),
+    (0x115BF, 0x115C0, JoiningType::Transparent),
+    (0x115DC, 0x115DD, JoiningType::Transparent),
+    (0x11633, 0x1163A, JoiningType::Transparent),
+    (0x1163D, 0x1163D, JoiningType::Transparent),
+    (0x1163F, 0x11640, JoiningType::Transparent),
+    (0x116AB, 0x116AB, JoiningType::Transparent),
+    (0x116AD, 0x116AD, JoiningType::Transparent),
+    (0x116B0, 0x116B5, JoiningType::Transparent),
+    (0x116B7, 0x116B7, JoiningType::Transparent),
+    (0x1171D, 0x1171F, JoiningType::Transparent),
+    (0x11722, 0x11725, JoiningType::Transparent),
+    (0x11727, 0x1172B, JoiningType::Transparent),
+    (0x1182F, 0x11837, JoiningType::Transparent),
+    (0x11839, 0x1183A, JoiningType::Transparent),
+    (0x1193B, 0x1193C, JoiningType::Transparent),
+    (0x1193E, 0x1193E, JoiningType::Transparent),
+    (0x11943, 0x11943, JoiningType::Transparent),
+    (0x119D4, 0x119D7, JoiningType::Transparent),
+    (0x119DA, 0x119DB, JoiningType::Transparent),
+    (0x119E0, 0x119E0, JoiningType::Transparent),
+    (0x11A01, 0x11A0A, JoiningType::Transparent),
+    (0x11A33, 0x11A38, JoiningType::Transparent),
+    (0x11A3B, 0x11A3E, JoiningType::Transparent),
+    (0x11A47, 0x11A47, JoiningType::Transparent),
+    (0x11A51, 0x11A56, JoiningType::Transparent),
+    (0x11A59, 0x11A5B, JoiningType::Transparent),
+    (0x11A8A, 0x11A96, JoiningType::Transparent),
+    (0x11A98, 0x11A99, JoiningType::Transparent),
+    (0x11C30, 0x11C36, JoiningType::Transparent),
+    (0x11C38, 0x11C3D, JoiningType::Transparent),
+    (0x11C3F, 0x11C3F, JoiningType::Transparent),
+    (0x11C92, 0x11CA7, JoiningType::Transparent),
+    (0x11CAA, 0x11CB0, JoiningType::Transparent),
+    (0x11CB2, 0x11CB3, JoiningType::Transparent),
+    (0x11CB5, 0x11CB6, JoiningType::Transparent),
+    (0x11D31, 0x11D36, JoiningType::Transparent),
+    (0x11D3A, 0x11D3A, JoiningType::Transparent),
+    (0x11D3C, 0x11D3D, JoiningType::Transparent),
+    (0x11D3F, 0x11D45, JoiningType::Transparent),
+    (0x11D47, 0x11D47, JoiningType::Transparent),
+    (0x11D90, 0x11D91, JoiningType::Transparent),
+    (0x11D95, 0x11D95, JoiningType::Transparent),
+    (0x11D97, 0x11D97, JoiningType::Transparent),
+    (0x11EF3, 0x11EF4, JoiningType::Transparent),
+    (0x13430, 0x13438, JoiningType::Transparent),
+    (0x16AF0, 0x16AF4, JoiningType::Transparent),
+    (0x16B30, 0x16B36, JoiningType::Transparent),
+    (0x16F4F, 0x16F4F, JoiningType::Transparent),
+    (0x16F8F, 0x16F92, JoiningType::Transparent),
+    (0x16FE4, 0x16FE4, JoiningType::Transparent),
+    (0x1BC9D, 0x1BC9E, JoiningType::Transparent),
+    (0x1BCA0, 0x1BCA3, JoiningType::Transparent),
+    (0x1D167, 0x1D169, JoiningType::Transparent),
+    (0x1D173, 0x1D182, JoiningType::Transparent),
+    (0x1D185, 0x1D18B, JoiningType::Transparent),
+    (0x1D1AA, 0x1D1AD, JoiningType::Transparent),
+    (0x1D242, 0x1D244, JoiningType::Transparent),
+    (0x1DA00, 0x1DA36, JoiningType::Transparent),
+    (0x1DA3B, 0x1DA6C, JoiningType::Transparent),
+    (0x1DA75, 0x1DA75, JoiningType::Transparent),
+    (0x1DA84, 0x1DA84, JoiningType::Transparent),
+    (0x1DA9B, 0x1DA9F, JoiningType::Transparent),
+    (0x1DAA1, 0x1DAAF, JoiningType::Transparent),
+    (0x1E000, 0x1E006, JoiningType::Transparent),
+    (0x1E008, 0x1E018, JoiningType::Transparent),
+    (0x1E01B, 0x1E021, JoiningType::Transparent),
+    (0x1E023, 0x1E024, JoiningType::Transparent),
+    (0x1E026, 0x1E02A, JoiningType::Transparent),
+    (0x1E130, 0x1E136, JoiningType::Transparent),
+    (0x1E2EC, 0x1E2EF, JoiningType::Transparent),
+    (0x1E8D0, 0x1E8D6, JoiningType::Transparent),
+    (0x1E900, 0x1E943, JoiningType::DualJoining),
+    (0x1E944, 0x1E94B, JoiningType::Transparent),
+    (0xE0001, 0xE0001, JoiningType::Transparent),
+    (0xE0020, 0xE007F, JoiningType::Transparent),
+    (0xE0100, 0xE01EF, JoiningType::Transparent),
+];
@@ -0,0 +1,212 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+// Generated from the Unicode Character Database (VerticalOrientation.txt and PropertyValueAliases.txt),
+// limited to the characters assigned in Unicode 13.0.
+
+use super::VerticalOrientation;
+
+pub(super) const VALUES: &[VerticalOrientation] = &[
+    VerticalOrientation::Rotated,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+];
+
+// In the same order as `VALUES`, as (abbreviated, long, human-readable) names
+pub(super) const NAMES: &[(&str, &str, &str)] = &[
+    ("R", "Rotated", "Rotated"),
+    ("Tr", "Transformed_Rotated", "Transformed Rotated"),
+    ("Tu", "Transformed_Upright", "Transformed Upright"),
+    ("U", "Upright", "Upright"),
+];
+
+pub(super) const RANGES: &[(u32, u32, VerticalOrientation)] = &[
+    (0x00A7, 0x00A7, VerticalOrientation::Upright),
+    (0x00A9, 0x00A9, VerticalOrientation::Upright),
+    (0x00AE, 0x00AE, VerticalOrientation::Upright),
+    (0x00B1, 0x00B1, VerticalOrientation::Upright),
+    (0x00BC, 0x00BE, VerticalOrientation::Upright),
+    (0x00D7, 0x00D7, VerticalOrientation::Upright),
+    (0x00F7, 0x00F7, VerticalOrientation::Upright),
+    (0x02EA, 0x02EB, VerticalOrientation::Upright),
+    (0x1100, 0x11FF, VerticalOrientation::Upright),
+    (0x1401, 0x167F, VerticalOrientation::Upright),
+    (0x18B0, 0x18FF, VerticalOrientation::Upright),
+    (0x2016, 0x2016, VerticalOrientation::Upright),
+    (0x2020, 0x2021, VerticalOrientation::Upright),
+    (0x2030, 0x2031, VerticalOrientation::Upright),
+    (0x203B, 0x203C, VerticalOrientation::Upright),
+    (0x2042, 0x2042, VerticalOrientation::Upright),
+    (0x2047, 0x2049, VerticalOrientation::Upright),
+    (0x2051, 0x2051, VerticalOrientation::Upright),
+    (0x2065, 0x2065, VerticalOrientation::Upright),
+    (0x20DD, 0x20E0, VerticalOrientation::Upright),
+    (0x20E2, 0x20E4, VerticalOrientation::Upright),
+    (0x2100, 0x2101, VerticalOrientation::Upright),
+    (0x2103, 0x2109, VerticalOrientation::Upright),
+    (0x210F, 0x210F, VerticalOrientation::Upright),
+    (0x2113, 0x2114, VerticalOrientation::Upright),
+    (0x2116, 0x2117, VerticalOrientation::Upright),
+    (0x211E, 0x2123, VerticalOrientation::Upright),
+    (0x2125, 0x2125, VerticalOrientation::Upright),
+    (0x2127, 0x2127, VerticalOrientation::Upright),
+    (0x2129, 0x2129, VerticalOrientation::Upright),
+    (0x212E, 0x212E, VerticalOrientation::Upright),
+    (0x2135, 0x213F, VerticalOrientation::Upright),
+    (0x2145, 0x214A, VerticalOrientation::Upright),
+    (0x214C, 0x214D, VerticalOrientation::Upright),
+    (0x214F, 0x2189, VerticalOrientation::Upright),
+    (0x218C, 0x218F, VerticalOrientation::Upright),
+    (0x221E, 0x221E, VerticalOrientation::Upright),
+    (0x2234, 0x2235, VerticalOrientation::Upright),
+    (0x2300, 0x2307, VerticalOrientation::Upright),
+    (0x230C, 0x231F, VerticalOrientation::Upright),
+    (0x2324, 0x2328, VerticalOrientation::Upright),
+    (0x2329, 0x232A, VerticalOrientation::TransformedRotated),
+    (0x232B, 0x232B, VerticalOrientation::Upright),
+    (0x237D, 0x239A, VerticalOrientation::Upright),
+    (0x23BE, 0x23CD, VerticalOrientation::Upright),
+    (0x23CF, 0x23CF, VerticalOrientation::Upright),
+    (0x23D1, 0x23DB, VerticalOrientation::Upright),
+    (0x23E2, 0x2422, VerticalOrientation::Upright),
+    (0x2424, 0x24FF, VerticalOrientation::Upright),
+    (0x25A0, 0x2619, VerticalOrientation::Upright),
+    (0x2620, 0x2767, VerticalOrientation::Upright),
+    (0x2776, 0x2793, VerticalOrientation::Upright),
+    (0x2B12, 0x2B2F, VerticalOrientation::Upright),
+    (0x2B50, 0x2B59, VerticalOrientation::Upright),
+    (0x2B97, 0x2B97, VerticalOrientation::Upright),
+    (0x2BB8, 0x2BD1, VerticalOrientation::Upright),
+    (0x2BD3, 0x2BEB, VerticalOrientation::Upright),
+    (0x2BF0, 0x2BFF, VerticalOrientation::Upright),
+    (0x2E50, 0x2E51, VerticalOrientation::Upright),
+    (0x2E80, 0x3000, VerticalOrientation::Upright),
+    (0x3001, 0x3002, VerticalOrientation::TransformedUpright),
+    (0x3003, 0x3007, VerticalOrientation::Upright),
+    (0x3008, 0x3011, VerticalOrientation::TransformedRotated),
+    (0x3012, 0x3013, VerticalOrientation::Upright),
+    (0x3014, 0x301F, VerticalOrientation::TransformedRotated),
+    (0x3020, 0x302F, VerticalOrientation::Upright),
+    (0x3030, 0x3030, VerticalOrientation::TransformedRotated),
+    (0x3031, 0x3040, VerticalOrientation::Upright),
+    (0x3041, 0x3041, VerticalOrientation::TransformedUpright),
+    (0x3042, 0x3042, VerticalOrientation::Upright),
+    (0x3043, 0x3043, VerticalOrientation::TransformedUpright),
+    (0x3044, 0x3044, VerticalOrientation::Upright),
+    (0x3045, 0x3045, VerticalOrientation::TransformedUpright),
+    (0x3046, 0x3046, VerticalOrientation::Upright),
+    (0x3047, 0x3047, VerticalOrientation::TransformedUpright),
+    (0x3048, 0x3048, VerticalOrientation::Upright),
+    (0x3049, 0x3049, VerticalOrientation::TransformedUpright),
+    (0x304A, 0x3062, VerticalOrientation::Upright),
+    (0x3063, 0x3063, VerticalOrientation::TransformedUpright),
+    (0x3064, 0x3082, VerticalOrientation::Upright),
+    (0x3083, 0x3083, VerticalOrientation::TransformedUpright),
+    (0x3084, 0x3084, VerticalOrientation::Upright),
+    (0x3085, 0x3085, VerticalOrientation::TransformedUpright),
+    (0x3086, 0x3086, VerticalOrientation::Upright),
+    (0x3087, 0x3087, VerticalOrientation::TransformedUpright),
+    (0x3088, 0x308D, VerticalOrientation::Upright),
+    (0x308E, 0x308E, VerticalOrientation::TransformedUpright),
+    (0x308F, 0x3094, VerticalOrientation::Upright),
+    (0x3095, 0x3096, VerticalOrientation::TransformedUpright),
+    (0x3097, 0x309A, VerticalOrientation::Upright),
+    (0x309B, 0x309C, VerticalOrientation::TransformedUpright),
+    (0x309D, 0x309F, VerticalOrientation::Upright),
+    (0x30A0, 0x30A0, VerticalOrientation::TransformedRotated),
+    (0x30A1, 0x30A1, VerticalOrientation::TransformedUpright),
+    (0x30A2, 0x30A2, VerticalOrientation::Upright),
+    (0x30A3, 0x30A3, VerticalOrientation::TransformedUpright),
+    (0x30A4, 0x30A4, VerticalOrientation::Upright),
+    (0x30A5, 0x30A5, VerticalOrientation::TransformedUpright),
+    (0x30A6, 0x30A6, VerticalOrientation::Upright),
+    (0x30A7, 0x30A7, VerticalOrientation::TransformedUpright),
+    (0x30A8, 0x30A8, VerticalOrientation::Upright),
+    (0x30A9, 0x30A9, VerticalOrientation::TransformedUpright),
+    (0x30AA, 0x30C2, VerticalOrientation::Upright),
+    (0x30C3, 0x30C3, VerticalOrientation::TransformedUpright),
+    (0x30C4, 0x30E2, VerticalOrientation::Upright),
+    (0x30E3, 0x30E3, VerticalOrientation::TransformedUpright),
+    (0x30E4, 0x30E4, VerticalOrientation::Upright),
+    (0x30E5, 0x30E5, VerticalOrientation::TransformedUpright),
+    (0x30E6, 0x30E6, VerticalOrientation::Upright),
+    (0x30E7, 0x30E7, VerticalOrientation::TransformedUpright),
+    (0x30E8, 0x30ED, VerticalOrientation::Upright),
+    (0x30EE, 0x30EE, VerticalOrientation::TransformedUpright),
+    (0x30EF, 0x30F4, VerticalOrientation::Upright),
+    (0x30F5, 0x30F6, VerticalOrientation::TransformedUpright),
+    (0x30F7, 0x30FB, VerticalOrientation::Upright),
+    (0x30FC, 0x30FC, VerticalOrientation::TransformedRotated),
+    (0x30FD, 0x3126, VerticalOrientation::Upright),
+    (0x3127, 0x3127, VerticalOrientation::TransformedUpright),
+    (0x3128, 0x31EF, VerticalOrientation::Upright),
+    (0x31F0, 0x31FF, VerticalOrientation::TransformedUpright),
+    (0x3200, 0x32FE, VerticalOrientation::Upright),
+    (0x32FF, 0x3357, VerticalOrientation::TransformedUpright),
+    (0x3358, 0x337A, VerticalOrientation::Upright),
+    (0x337B, 0x337F, VerticalOrientation::TransformedUpright),
+    (0x3380, 0xA4CF, VerticalOrientation::Upright),
+    (0xA960, 0xA97F, VerticalOrientation::Upright),
+    (0xAC00, 0xD7FF, VerticalOrientation::Upright),
+    (0xE000, 0xFAFF, VerticalOrientation::Upright),
+    (0xFE10, 0xFE1F, VerticalOrientation::Upright),
+    (0xFE30, 0xFE48, VerticalOrientation::Upright),
+    (0xFE50, 0xFE52, VerticalOrientation::TransformedUpright),
+    (0xFE53, 0xFE57, VerticalOrientation::Upright),
+    (0xFE59, 0xFE5E, VerticalOrientation::TransformedRotated),
+    (0xFE5F, 0xFE62, VerticalOrientation::Upright),
+    (0xFE67, 0xFE6F, VerticalOrientation::Upright),
+    (0xFF01, 0xFF01, VerticalOrientation::TransformedUpright),
+    (0xFF02, 0xFF07, VerticalOrientation::Upright),
+    (0xFF08, 0xFF09, VerticalOrientation::TransformedRotated),
+    (0xFF0A, 0xFF0B, VerticalOrientation::Upright),
+    (0xFF0C, 0xFF0C, VerticalOrientation::TransformedUpright),
+    (0xFF0E, 0xFF0E, VerticalOrientation::TransformedUpright),
+    (0xFF0F, 0xFF19, VerticalOrientation::Upright),
+    (0xFF1A, 0xFF1B, VerticalOrientation::TransformedRotated),
+    (0xFF1F, 0xFF1F, VerticalOrientation::TransformedUpright),
+    (0xFF20, 0xFF3A, VerticalOrientation::Upright),
+    (0xFF3B, 0xFF3B, VerticalOrientation::TransformedRotated),
+    (0xFF3C, 0xFF3C, VerticalOrientation::Upright),
+    (0xFF3D, 0xFF3D, VerticalOrientation::TransformedRotated),
+    (0xFF3E, 0xFF3E, VerticalOrientation::Upright),
+    (0xFF3F, 0xFF3F, VerticalOrientation::TransformedRotated),
+    (0xFF40, 0xFF5A, VerticalOrientation::Upright),
+    (0xFF5B, 0xFF60, VerticalOrientation::TransformedRotated),
+    (0xFFE0, 0xFFE2, VerticalOrientation::Upright),
+    (0xFFE3, 0xFFE3, VerticalOrientation::TransformedRotated),
+    (0xFFE4, 0xFFE7, VerticalOrientation::Upright),
+    (0xFFF0, 0xFFF8, VerticalOrientation::Upright),
+    (0xFFFC, 0xFFFD, VerticalOrientation::Upright),
+    (0x10980, 0x1099F, VerticalOrientation::Upright),
+    (0x11580, 0x115FF, VerticalOrientation::Upright),
+    (0x11A00, 0x11ABF, VerticalOrientation::Upright),
+    (0x13000, 0x1343F, VerticalOrientation::Upright),
+    (0x14400, 0x1467F, VerticalOrientation::Upright),
+    (0x16FE0, 0x18D7F, VerticalOrientation::Upright),
+    (0x1AFF0, 0x1B2FF, VerticalOrientation::Upright),
+    (0x1CF00, 0x1CFCF, VerticalOrientation::Upright),
+    (0x1D000, 0x1D1FF, VerticalOrientation::Upright),
+    (0x1D2E0, 0x1D37F, VerticalOrientation::Upright),
+    (0x1D800, 0x1DAAF, VerticalOrientation::Upright),
+    (0x1F000, 0x1F1FF, VerticalOrientation::Upright),
+    (0x1F200, 0x1F201, VerticalOrientation::TransformedUpright),
+    (0x1F202, 0x1F7FF, VerticalOrientation::Upright),
+    (0x1F900, 0x1FAFF, VerticalOrientation::Upright),
+    (0x20000, 0x2FFFD, VerticalOrientation::Upright),
+    (0x30000, 0x3FFFD, VerticalOrientation::Upright),
+    (0xF0000, 0xFFFFD, VerticalOrientation::Upright),
+    (0x100000, 0x10FFFD, VerticalOrientation::Upright),
+];
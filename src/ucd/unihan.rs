@@ -0,0 +1,279 @@
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+//!
+//! Readings, definitions, stroke counts and variants of CJK ideographs from the Unihan database,
+//! beyond the Mandarin readings and simplified/traditional variants provided by `unic::ucd`.
+//!
+//! The Unihan database is too large to be built into Cicero, it is read from the directory of
+//! the extracted Unihan.zip set as `unihan_data_path` in the settings, the first time Unihan data
+//! is looked up, or in the background with `load_unihan_database_in_background` (e.g. by the TUI).
+//!
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::thread;
+
+use serde::Serialize;
+
+use super::{string_to_code_point, Script};
+use crate::settings::get_settings;
+
+const UNIHAN_FILE_NAME_PREFIX: &str = "Unihan";
+const UNIHAN_FILE_NAME_EXTENSION: &str = "txt";
+
+// Keys of the Unihan fields in `UnihanEntry`, other fields are skipped when loading
+const UNIHAN_KEYS: &[&str] = &[
+    "kDefinition",
+    "kCantonese",
+    "kJapaneseOn",
+    "kJapaneseKun",
+    "kKorean",
+    "kHangul",
+    "kVietnamese",
+    "kRSUnicode",
+    "kTotalStrokes",
+    "kSemanticVariant",
+    "kSpecializedSemanticVariant",
+    "kZVariant",
+];
+
+/// Unihan data of a CJK ideograph, values are as in the Unihan database except for variants,
+/// e.g. `japanese_on` is "ICHI ITSU" for U+4E00.
+#[derive(Serialize, Debug, Default, Clone, Eq, PartialEq)]
+pub struct UnihanEntry {
+    pub definition: Option<String>,
+    pub cantonese: Option<String>,
+    pub japanese_on: Option<String>,
+    pub japanese_kun: Option<String>,
+    pub korean: Option<String>,
+    pub hangul_reading: Option<String>,
+    pub vietnamese: Option<String>,
+    pub radical_stroke_counts: Option<String>,
+    pub total_strokes: Option<String>,
+    pub semantic_variants: Vec<char>,
+    pub specialized_semantic_variants: Vec<char>,
+    pub z_variants: Vec<char>,
+}
+
+/// Variants are listed as code points, optionally followed by their sources, e.g.
+/// "U+5B78<kMatthews,kMeyerWempe U+6588".
+fn parse_variants(value: &str) -> Vec<char> {
+    value
+        .split_whitespace()
+        .filter_map(|variant| string_to_code_point(variant.split('<').next().unwrap_or_default()))
+        .filter_map(char::from_u32)
+        .collect()
+}
+
+#[derive(Debug, Default)]
+pub struct UnihanDatabase {
+    entries: HashMap<char, UnihanEntry>,
+}
+
+impl UnihanDatabase {
+    /// Reads all Unihan*.txt files in `directory`, other files are ignored.
+    fn load(directory: &Path) -> io::Result<Self> {
+        let mut database = UnihanDatabase::default();
+        for dir_entry in fs::read_dir(directory)? {
+            let path = dir_entry?.path();
+            let is_unihan_file = path.extension().and_then(|extension| extension.to_str())
+                == Some(UNIHAN_FILE_NAME_EXTENSION)
+                && path
+                    .file_name()
+                    .and_then(|file_name| file_name.to_str())
+                    .unwrap_or_default()
+                    .starts_with(UNIHAN_FILE_NAME_PREFIX);
+            if is_unihan_file {
+                database.add_entries(BufReader::new(File::open(&path)?))?;
+            }
+        }
+        Ok(database)
+    }
+
+    /// Adds entries from a Unihan file, whose lines are tab separated code points, field names and
+    /// values, e.g. "U+4E00<TAB>kDefinition<TAB>one; a, an; alone".
+    fn add_entries<R: BufRead>(&mut self, reader: R) -> io::Result<()> {
+        for line in reader.lines() {
+            let line = line?;
+            if line.starts_with('#') {
+                continue;
+            }
+            let mut columns = line.splitn(3, '\t');
+            let (code_point, key, value) = match (columns.next(), columns.next(), columns.next()) {
+                (Some(code_point), Some(key), Some(value)) => (code_point, key, value),
+                _ => continue,
+            };
+            let chr = match string_to_code_point(code_point).and_then(char::from_u32) {
+                Some(chr) => chr,
+                None => continue,
+            };
+
+            if !UNIHAN_KEYS.contains(&key) {
+                continue;
+            }
+
+            let entry = self.entries.entry(chr).or_default();
+            match key {
+                "kDefinition" => entry.definition = Some(value.to_owned()),
+                "kCantonese" => entry.cantonese = Some(value.to_owned()),
+                "kJapaneseOn" => entry.japanese_on = Some(value.to_owned()),
+                "kJapaneseKun" => entry.japanese_kun = Some(value.to_owned()),
+                "kKorean" => entry.korean = Some(value.to_owned()),
+                "kHangul" => entry.hangul_reading = Some(value.to_owned()),
+                "kVietnamese" => entry.vietnamese = Some(value.to_owned()),
+                "kRSUnicode" => entry.radical_stroke_counts = Some(value.to_owned()),
+                "kTotalStrokes" => entry.total_strokes = Some(value.to_owned()),
+                "kSemanticVariant" => entry.semantic_variants = parse_variants(value),
+                "kSpecializedSemanticVariant" => {
+                    entry.specialized_semantic_variants = parse_variants(value)
+                }
+                "kZVariant" => entry.z_variants = parse_variants(value),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn entry_of(&self, chr: char) -> Option<&UnihanEntry> {
+        self.entries.get(&chr)
+    }
+}
+
+/// Why the Unihan database set in the settings couldn't be loaded.
+#[derive(Debug)]
+pub struct UnihanLoadError {
+    pub path: PathBuf,
+    pub error: io::Error,
+}
+
+static UNIHAN_DATABASE: OnceLock<Result<UnihanDatabase, UnihanLoadError>> = OnceLock::new();
+static LOADS_IN_BACKGROUND: AtomicBool = AtomicBool::new(false);
+
+/// The Unihan database set in the settings, which is empty if it's not set.
+fn unihan_database() -> &'static Result<UnihanDatabase, UnihanLoadError> {
+    UNIHAN_DATABASE.get_or_init(|| match get_settings().unihan_data_path {
+        Some(path) => UnihanDatabase::load(&path).map_err(|error| UnihanLoadError { path, error }),
+        None => Ok(UnihanDatabase::default()),
+    })
+}
+
+/// Starts loading the Unihan database on another thread, lookups don't wait for it to be loaded
+/// from then on, so that they never block e.g. a user interface.
+pub fn load_unihan_database_in_background() {
+    LOADS_IN_BACKGROUND.store(true, Ordering::SeqCst);
+    thread::spawn(unihan_database);
+}
+
+/// Whether the Unihan database is being loaded in the background.
+pub fn is_unihan_database_loading() -> bool {
+    LOADS_IN_BACKGROUND.load(Ordering::SeqCst) && UNIHAN_DATABASE.get().is_none()
+}
+
+/// The error of loading the Unihan database, if it failed to load. Nothing is loaded until Unihan
+/// data is looked up, so this is `None` before.
+pub fn unihan_load_error() -> Option<&'static UnihanLoadError> {
+    UNIHAN_DATABASE.get()?.as_ref().err()
+}
+
+/// Looks up the Unihan data of `chr`, which is `None` if `chr` is not a CJK ideograph, or if the
+/// Unihan database is not set in the settings, fails to load (see `unihan_load_error`) or is still
+/// being loaded in the background.
+pub fn unihan_entry_of(chr: char) -> Option<&'static UnihanEntry> {
+    // Avoid loading the database for characters which can't be in it
+    if Script::of(chr) != Script::Han {
+        return None;
+    }
+
+    let database = if LOADS_IN_BACKGROUND.load(Ordering::SeqCst) {
+        UNIHAN_DATABASE.get()?
+    } else {
+        unihan_database()
+    };
+    database.as_ref().ok()?.entry_of(chr)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    const TEST_UNIHAN_CONTENT: &str = "\
+# Unihan_Readings.txt
+U+4E00	kCantonese	jat1
+U+4E00	kDefinition	one; a, an; alone
+U+4E00	kJapaneseOn	ICHI ITSU
+U+4E00	kMandarin	yī
+U+5B66	kSemanticVariant	U+5B78<kMatthews,kMeyerWempe U+6588
+U+5B66	kTotalStrokes	8
+";
+
+    #[test]
+    fn test_add_entries() {
+        let mut database = UnihanDatabase::default();
+        database
+            .add_entries(TEST_UNIHAN_CONTENT.as_bytes())
+            .unwrap();
+
+        let entry = database.entry_of('一').unwrap();
+        assert_eq!(entry.cantonese.as_deref(), Some("jat1"));
+        assert_eq!(entry.definition.as_deref(), Some("one; a, an; alone"));
+        assert_eq!(entry.japanese_on.as_deref(), Some("ICHI ITSU"));
+        assert_eq!(entry.japanese_kun, None);
+
+        let entry = database.entry_of('学').unwrap();
+        assert_eq!(entry.semantic_variants, vec!['學', '斈']);
+        assert_eq!(entry.total_strokes.as_deref(), Some("8"));
+
+        assert_eq!(database.entry_of('a'), None);
+    }
+
+    #[test]
+    fn test_load() {
+        let directory = env::temp_dir().join(format!("cicero-unihan-test-{}", process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("Unihan_Readings.txt"), TEST_UNIHAN_CONTENT).unwrap();
+        fs::write(
+            directory.join("Unihan_IRGSources.txt"),
+            "U+4E00\tkTotalStrokes\t1\n",
+        )
+        .unwrap();
+        // Not a Unihan file, even though its content looks like one
+        fs::write(
+            directory.join("ReadMe.txt"),
+            "U+4E00\tkDefinition\tnot from Unihan\n",
+        )
+        .unwrap();
+
+        let database = UnihanDatabase::load(&directory);
+        fs::remove_dir_all(&directory).unwrap();
+
+        let database = database.unwrap();
+        let entry = database.entry_of('一').unwrap();
+        assert_eq!(entry.definition.as_deref(), Some("one; a, an; alone"));
+        assert_eq!(entry.total_strokes.as_deref(), Some("1"));
+        assert!(database.entry_of('学').is_some());
+
+        let missing_directory = env::temp_dir().join("cicero-unihan-test-missing");
+        assert_eq!(
+            UnihanDatabase::load(&missing_directory).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+}
@@ -0,0 +1,475 @@
+#!/usr/bin/env perl
+
+# This file is part of Cicero.
+#
+# Cicero is free software: you can redistribute it and/or modify it under the
+# terms of the GNU General Public License as published by the Free Software
+# Foundation, either version 3 of the License, or (at your option) any later
+# version.
+#
+# Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+# WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+# A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+#
+# You should have received a copy of the GNU General Public License along with
+# Cicero. If not, see <https://www.gnu.org/licenses/>.
+
+#
+# Generates the property tables in `src/ucd` which are not provided by `unic::ucd`.
+#
+# The data is read from the files of the Unicode Character Database 13.0.0, the version of Unicode
+# which the rest of Cicero (and `unic::ucd`) follows, i.e. from the extracted
+# https://www.unicode.org/Public/13.0.0/ucd/UCD.zip. Values of code points which are not listed in
+# a file are taken from its `@missing` lines.
+#
+# Usage (from the root of the repository):
+#
+#     perl tools/generate_ucd_tables.pl <directory of the extracted UCD.zip>
+#
+# Every generated file is overwritten and formatted with rustfmt.
+#
+
+use strict;
+use warnings;
+
+use File::Basename qw(dirname);
+use File::Spec::Functions qw(catfile rel2abs);
+use Text::Wrap qw(wrap);
+
+use constant UCD_VERSION => "13.0.0";
+use constant MAX_CODE_POINT => 0x10FFFF;
+
+my $ucd_dir = shift @ARGV or die "usage: $0 <directory of the extracted UCD.zip>\n";
+my $source_dir = rel2abs(catfile(dirname(__FILE__), "..", "src", "ucd"));
+
+# Rust identifiers and human-readable names of values whose long names are abbreviations
+my %RENAMED_VALUES = (
+    Indic_Positional_Category => { NA => ["NotApplicable", "Not Applicable"] },
+);
+
+# Calls `callback` with the fields of each line of the UCD file `name`, and `missing_callback`
+# likewise for its `@missing` lines. Files which name their version in their header must be of
+# `UCD_VERSION`.
+sub read_ucd_file {
+    my ($name, $callback, $missing_callback) = @_;
+    my $path = catfile($ucd_dir, split m{/}, $name);
+    open(my $input, "<:encoding(UTF-8)", $path) or die "can't read $path: $!\n";
+
+    my $is_header = 1;
+    while (my $line = <$input>) {
+        $line =~ s/\s+$//;
+        if ($is_header && $line =~ /^# (?:\S+-(\d+\.\d+\.\d+)\.txt|Version: (\d+\.\d+(?:\.\d+)?))$/) {
+            my $version = $1 // $2;
+            die "$path is from UCD $version, expected " . UCD_VERSION . "\n"
+                unless UCD_VERSION =~ /^\Q$version\E(?:\.0)?$/;
+        }
+        if ($line =~ /^#\s*\@missing:\s*(.*)/) {
+            $missing_callback->(split_fields($1)) if $missing_callback;
+            next;
+        }
+        $line =~ s/#.*//;
+        next unless $line =~ /\S/;
+        $is_header = 0;
+        $callback->(split_fields($line));
+    }
+    close($input);
+}
+
+sub split_fields {
+    return map { s/^\s+|\s+$//gr } split /;/, shift, -1;
+}
+
+# Like `read_ucd_file`, for files whose first field is a code point or a range of code points,
+# which is passed to the callbacks as its first and last code point
+sub read_code_point_file {
+    my ($name, $callback, $missing_callback) = @_;
+    my $with_range = sub {
+        my $callback = shift;
+        return sub {
+            my ($code_points, @fields) = @_;
+            my ($first, $last) = map { hex } split /\.\./, $code_points;
+            $callback->($first, $last // $first, @fields);
+        };
+    };
+    read_ucd_file($name, $with_range->($callback), $missing_callback && $with_range->($missing_callback));
+}
+
+# Aliases of properties and property values (PropertyAliases.txt and PropertyValueAliases.txt)
+my (%property_short_names, %value_aliases);
+
+# Loose matching of names, as in UAX #44, e.g. "Non_Joining" matches "non-joining"
+sub loose_name {
+    (my $name = lc shift) =~ s/[\s_-]//g;
+    return $name;
+}
+
+read_ucd_file("PropertyAliases.txt", sub {
+    my ($short_name, $long_name) = @_;
+    $property_short_names{$long_name} = $short_name;
+});
+
+read_ucd_file("PropertyValueAliases.txt", sub {
+    my ($property, @aliases) = @_;
+    # The canonical combining class has its number as first alias
+    shift @aliases if $property eq "ccc";
+    my ($abbreviated_name, $long_name) = @aliases;
+    for my $alias (grep { $_ ne "" } @aliases) {
+        $value_aliases{$property}{loose_name($alias)} = [$abbreviated_name, $long_name];
+    }
+});
+
+# The abbreviated and long name of a value of `property`
+sub value_names {
+    my ($property, $value) = @_;
+    my $short_name = $property_short_names{$property} // die "unknown property $property\n";
+    return @{$value_aliases{$short_name}{loose_name($value)} // die "unknown value '$value' of $property\n"};
+}
+
+sub abbreviated_name {
+    return (value_names(@_))[0];
+}
+
+# Values of an enumerated property, as long names, indexed by code point. Code points which are
+# neither listed nor covered by an `@missing` line have the value `default`. Files which list
+# several properties (e.g. DerivedNormalizationProps.txt) name the property in the first field,
+# which is only read from lines with `field`.
+sub enumerated_values {
+    my ($property, $file, $default, $field) = @_;
+    my (@values, @missing_values);
+    my $set_values = sub {
+        my $values = shift;
+        return sub {
+            my ($first, $last, @fields) = @_;
+            if (defined $field) {
+                return unless @fields > 1 && shift(@fields) eq $field;
+            }
+            my $value = (value_names($property, $fields[0]))[1];
+            $values->[$_] = $value for $first .. $last;
+        };
+    };
+    read_code_point_file($file, $set_values->(\@values), $set_values->(\@missing_values));
+
+    for my $code_point (0 .. MAX_CODE_POINT) {
+        $values[$code_point] //= $missing_values[$code_point] // $default;
+    }
+    return @values;
+}
+
+# Whether each code point has a binary property, from the lines of `file` listing `property`
+sub binary_values {
+    my ($property, $file) = @_;
+    my @values = (0) x (MAX_CODE_POINT + 1);
+    read_code_point_file($file, sub {
+        my ($first, $last, $name) = @_;
+        return unless $name eq $property;
+        $values[$_] = 1 for $first .. $last;
+    });
+    return @values;
+}
+
+# Rust identifier of a long property value name, e.g. `OldItalic` for "Old_Italic"
+sub identifier {
+    (my $name = shift) =~ s/_//g;
+    return $name;
+}
+
+# Ranges of equal values as [first, last, value], without the code points for which `skip` returns
+# true
+sub value_ranges {
+    my ($values, $skip) = @_;
+    my @ranges;
+    my $first = 0;
+    for my $code_point (1 .. MAX_CODE_POINT + 1) {
+        next if $code_point <= MAX_CODE_POINT
+            && $values->[$code_point] eq $values->[$first]
+            && !$skip->($code_point) == !$skip->($first);
+        push @ranges, [$first, $code_point - 1, $values->[$first]] unless $skip->($first);
+        $first = $code_point;
+    }
+    return @ranges;
+}
+
+# The `VALUES`, `NAMES` and `RANGES` tables read by `enumerated_property!`
+sub enumerated_tables {
+    my ($property, $type, $default, @values) = @_;
+    my %used = map { $_ => 1 } @values;
+    my @names = sort { abbreviated_name($property, $a) cmp abbreviated_name($property, $b) } keys %used;
+
+    my %renamed = %{$RENAMED_VALUES{$property} // {}};
+    my %identifiers = map { $_ => $renamed{$_} ? $renamed{$_}[0] : identifier($_) } @names;
+
+    my $tables = "pub(super) const VALUES: &[$type] = &[\n";
+    $tables .= "    ${type}::$identifiers{$_},\n" for @names;
+    $tables .= "];\n\n";
+
+    $tables .= "// In the same order as `VALUES`, as (abbreviated, long, human-readable) names\n";
+    $tables .= "pub(super) const NAMES: &[(&str, &str, &str)] = &[\n";
+    for my $name (@names) {
+        (my $human_readable_name = $name) =~ s/_/ /g;
+        $human_readable_name = $renamed{$name}[1] if $renamed{$name};
+        $tables .= sprintf "    (\"%s\", \"%s\", \"%s\"),\n",
+            abbreviated_name($property, $name), $name, $human_readable_name;
+    }
+    $tables .= "];\n\n";
+
+    $tables .= "pub(super) const RANGES: &[(u32, u32, $type)] = &[\n";
+    for my $range (value_ranges(\@values, sub { $values[shift] eq $default })) {
+        $tables .= sprintf "    (0x%04X, 0x%04X, %s::%s),\n", @$range[0, 1], $type, $identifiers{$range->[2]};
+    }
+    $tables .= "];\n";
+    return $tables;
+}
+
+# Ranges of code points which have a binary property, as a `&[(u32, u32)]` table
+sub binary_table {
+    my ($property, $file, $name) = @_;
+    my @values = binary_values($property, $file);
+
+    my $table = "pub(super) const $name: &[(u32, u32)] = &[\n";
+    for my $range (value_ranges(\@values, sub { !$values[shift] })) {
+        $table .= sprintf "    (0x%04X, 0x%04X),\n", @$range[0, 1];
+    }
+    $table .= "];\n";
+    return $table;
+}
+
+sub numeric_value_table {
+    my @values;
+    read_code_point_file("extracted/DerivedNumericValues.txt", sub {
+        my ($first, $last, undef, undef, $value) = @_;
+        $values[$_] = $value for $first .. $last;
+    });
+
+    my $table = "pub(super) const NUMERIC_VALUE_RANGES: &[(u32, u32, (i64, u64))] = &[\n";
+    for my $range (value_ranges([map { $_ // "" } @values[0 .. MAX_CODE_POINT]], sub { !defined $values[shift] })) {
+        my ($numerator, $denominator) = split m{/}, $range->[2];
+        $table .= sprintf "    (0x%04X, 0x%04X, (%s, %s)),\n", @$range[0, 1], $numerator, $denominator // 1;
+    }
+    $table .= "];\n";
+    return $table;
+}
+
+sub char_literal {
+    return sprintf "'\\u{%04X}'", shift;
+}
+
+# Case mappings by code point, as arrays of code points for the characters which are not mapped to
+# themselves: the simple mappings of UnicodeData.txt, overridden by the unconditional mappings of
+# SpecialCasing.txt for full mappings, and the mappings of CaseFolding.txt with `statuses`.
+my (%simple_case_mappings, %special_case_mappings);
+
+read_code_point_file("UnicodeData.txt", sub {
+    my ($code_point, undef, @fields) = @_;
+    my ($uppercase, $lowercase, $titlecase) = @fields[11 .. 13];
+    # The titlecase mapping is the uppercase mapping if it's missing
+    $titlecase = $uppercase if $titlecase eq "";
+    $simple_case_mappings{Uppercase_Mapping}{$code_point} = [hex $uppercase] if $uppercase ne "";
+    $simple_case_mappings{Lowercase_Mapping}{$code_point} = [hex $lowercase] if $lowercase ne "";
+    $simple_case_mappings{Titlecase_Mapping}{$code_point} = [hex $titlecase] if $titlecase ne "";
+});
+
+read_code_point_file("SpecialCasing.txt", sub {
+    my ($code_point, undef, $lowercase, $titlecase, $uppercase, $conditions) = @_;
+    # Conditional mappings are implemented in `case.rs`
+    return if defined $conditions && $conditions ne "";
+    $special_case_mappings{Lowercase_Mapping}{$code_point} = [map { hex } split " ", $lowercase];
+    $special_case_mappings{Titlecase_Mapping}{$code_point} = [map { hex } split " ", $titlecase];
+    $special_case_mappings{Uppercase_Mapping}{$code_point} = [map { hex } split " ", $uppercase];
+});
+
+sub case_foldings {
+    my %statuses = map { $_ => 1 } @_;
+    my %foldings;
+    read_code_point_file("CaseFolding.txt", sub {
+        my ($code_point, undef, $status, $mapping) = @_;
+        $foldings{$code_point} = [map { hex } split " ", $mapping] if $statuses{$status};
+    });
+    return \%foldings;
+}
+
+# Mappings of the characters which are changed by a case mapping, as `&[(char, &[char])]` if `full`
+# is set, or as `&[(char, char)]` otherwise
+sub case_mapping_table {
+    my ($mappings, $name, $full) = @_;
+    my $table = $full
+        ? "pub(super) const $name: &[(char, &[char])] = &[\n"
+        : "pub(super) const $name: &[(char, char)] = &[\n";
+    for my $code_point (sort { $a <=> $b } keys %$mappings) {
+        my @mapping = @{$mappings->{$code_point}};
+        next if @mapping == 1 && $mapping[0] == $code_point;
+
+        if ($full) {
+            $table .= sprintf "    (%s, &[%s]),\n", char_literal($code_point), join(", ", map { char_literal($_) } @mapping);
+        } else {
+            $table .= sprintf "    (%s, %s),\n", char_literal($code_point), char_literal($mapping[0]);
+        }
+    }
+    $table .= "];\n";
+    return $table;
+}
+
+sub full_case_mapping_table {
+    my ($property, $name) = @_;
+    my %mappings = (%{$simple_case_mappings{$property}}, %{$special_case_mappings{$property}});
+    return case_mapping_table(\%mappings, $name, 1);
+}
+
+# The tables read by `enumerated_property!` for `Script`, and its `EXTENSIONS_RANGES` table
+sub script_tables {
+    my @scripts = enumerated_values("Script", "Scripts.txt", "Unknown");
+    my %abbreviated_names;
+    $abbreviated_names{$_} //= abbreviated_name("Script", $_) for @scripts;
+
+    # Characters which are not listed have their script as only extension
+    my @extensions = map { $abbreviated_names{$_} } @scripts;
+    read_code_point_file("ScriptExtensions.txt", sub {
+        my ($first, $last, $scripts) = @_;
+        my $sorted_scripts = join " ", sort map { abbreviated_name("Script", $_) } split " ", $scripts;
+        $extensions[$_] = $sorted_scripts for $first .. $last;
+    });
+
+    my $tables = enumerated_tables("Script", "Script", "Unknown", @scripts);
+    # Only the characters whose extensions are not their script are listed
+    $tables .= "\npub(super) const EXTENSIONS_RANGES: &[(u32, u32, &[Script])] = &[\n";
+    my $is_script = sub { $extensions[$_[0]] eq $abbreviated_names{$scripts[$_[0]]} };
+    for my $range (value_ranges(\@extensions, $is_script)) {
+        my @identifiers = map { "Script::" . identifier((value_names("Script", $_))[1]) } split " ", $range->[2];
+        $tables .= sprintf "    (0x%04X, 0x%04X, &[%s]),\n", @$range[0, 1], join(", ", @identifiers);
+    }
+    $tables .= "];\n";
+    return $tables;
+}
+
+sub license_header {
+    return <<'END';
+// This file is part of Cicero.
+//
+// Cicero is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// Cicero is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// Cicero. If not, see <https://www.gnu.org/licenses/>.
+END
+}
+
+# Writes a generated file to `src/ucd/<path>`, `source_files` are the UCD files the data comes
+# from, `use_type` is the property type imported from the parent module, if any.
+sub write_tables {
+    my (%file) = @_;
+    my $path = catfile($source_dir, $file{path});
+
+    my $sources = join(", ", @{$file{source_files}}[0 .. $#{$file{source_files}} - 1]);
+    $sources .= ($sources ? " and " : "") . $file{source_files}[-1];
+    local $Text::Wrap::columns = 100;
+    local $Text::Wrap::huge = "overflow";
+    my $comment = wrap("// ", "// ",
+        "Generated by tools/generate_ucd_tables.pl from the Unicode Character Database "
+            . UCD_VERSION . " ($sources)" . ($file{note} ? ". $file{note}" : "."));
+
+    open(my $output, ">", $path) or die "can't write $path: $!\n";
+    print $output license_header(), "\n", $comment, "\n\n";
+    print $output "use super::$file{use_type};\n\n" if $file{use_type};
+    print $output join("\n", @{$file{tables}});
+    close($output);
+
+    system("rustfmt", "--edition", "2018", $path) == 0 or die "can't format $path\n";
+    print "$path\n";
+}
+
+my %ENUMERATED_PROPERTIES = (
+    "line_break/tables.rs" => ["Line_Break", "LineBreak", "Unknown", "LineBreak.txt"],
+    "east_asian_width/tables.rs" => ["East_Asian_Width", "EastAsianWidth", "Neutral", "EastAsianWidth.txt"],
+    "normalization/nfc_quick_check.rs" => ["NFC_Quick_Check", "NfcQuickCheck", "Yes", "DerivedNormalizationProps.txt", "NFC_QC"],
+    "normalization/nfd_quick_check.rs" => ["NFD_Quick_Check", "NfdQuickCheck", "Yes", "DerivedNormalizationProps.txt", "NFD_QC"],
+    "normalization/nfkc_quick_check.rs" => ["NFKC_Quick_Check", "NfkcQuickCheck", "Yes", "DerivedNormalizationProps.txt", "NFKC_QC"],
+    "normalization/nfkd_quick_check.rs" => ["NFKD_Quick_Check", "NfkdQuickCheck", "Yes", "DerivedNormalizationProps.txt", "NFKD_QC"],
+    "shaping/joining_type.rs" => ["Joining_Type", "JoiningType", "Non_Joining", "extracted/DerivedJoiningType.txt"],
+    "shaping/joining_group.rs" => ["Joining_Group", "JoiningGroup", "No_Joining_Group", "extracted/DerivedJoiningGroup.txt"],
+    "shaping/hangul_syllable_type.rs" => ["Hangul_Syllable_Type", "HangulSyllableType", "Not_Applicable", "HangulSyllableType.txt"],
+    "shaping/indic_syllabic_category.rs" => ["Indic_Syllabic_Category", "IndicSyllabicCategory", "Other", "IndicSyllabicCategory.txt"],
+    "shaping/indic_positional_category.rs" => ["Indic_Positional_Category", "IndicPositionalCategory", "NA", "IndicPositionalCategory.txt"],
+    "shaping/vertical_orientation.rs" => ["Vertical_Orientation", "VerticalOrientation", "Rotated", "VerticalOrientation.txt"],
+);
+
+for my $path (sort keys %ENUMERATED_PROPERTIES) {
+    my ($property, $type, $default, $source_file, $field) = @{$ENUMERATED_PROPERTIES{$path}};
+    my @values = enumerated_values($property, $source_file, $default, $field);
+    write_tables(
+        path => $path,
+        source_files => [$source_file, "PropertyValueAliases.txt"],
+        use_type => $type,
+        tables => [enumerated_tables($property, $type, $default, @values)],
+    );
+}
+
+write_tables(
+    path => "script/tables.rs",
+    source_files => ["Scripts.txt", "ScriptExtensions.txt", "PropertyValueAliases.txt"],
+    use_type => "Script",
+    tables => [script_tables()],
+);
+
+write_tables(
+    path => "numeric/tables.rs",
+    source_files => ["extracted/DerivedNumericType.txt", "extracted/DerivedNumericValues.txt", "PropertyValueAliases.txt"],
+    use_type => "NumericType",
+    tables => [
+        enumerated_tables("Numeric_Type", "NumericType", "None",
+            enumerated_values("Numeric_Type", "extracted/DerivedNumericType.txt", "None")),
+        numeric_value_table(),
+    ],
+);
+
+write_tables(
+    path => "binary_properties/tables.rs",
+    source_files => ["PropList.txt", "DerivedCoreProperties.txt"],
+    tables => [
+        binary_table("Default_Ignorable_Code_Point", "DerivedCoreProperties.txt", "DEFAULT_IGNORABLE_CODE_POINT_RANGES"),
+        binary_table("Noncharacter_Code_Point", "PropList.txt", "NONCHARACTER_CODE_POINT_RANGES"),
+        binary_table("Dash", "PropList.txt", "DASH_RANGES"),
+        binary_table("Hyphen", "PropList.txt", "HYPHEN_RANGES"),
+        binary_table("Quotation_Mark", "PropList.txt", "QUOTATION_MARK_RANGES"),
+        binary_table("Diacritic", "PropList.txt", "DIACRITIC_RANGES"),
+        binary_table("Extender", "PropList.txt", "EXTENDER_RANGES"),
+        binary_table("Join_Control", "PropList.txt", "JOIN_CONTROL_RANGES"),
+        binary_table("Variation_Selector", "PropList.txt", "VARIATION_SELECTOR_RANGES"),
+    ],
+);
+
+write_tables(
+    path => "emoji/tables.rs",
+    source_files => ["emoji/emoji-data.txt"],
+    note => "Extended_Pictographic includes reserved code points.",
+    tables => [binary_table("Extended_Pictographic", "emoji/emoji-data.txt", "EXTENDED_PICTOGRAPHIC_RANGES")],
+);
+
+write_tables(
+    path => "case/tables.rs",
+    source_files => [
+        "UnicodeData.txt", "SpecialCasing.txt", "CaseFolding.txt", "PropList.txt", "DerivedCoreProperties.txt",
+    ],
+    note => "Mappings only list characters which are changed, and the conditional mappings of "
+        . "SpecialCasing.txt are implemented in `case.rs`.",
+    tables => [
+        full_case_mapping_table("Uppercase_Mapping", "UPPERCASE_MAPPINGS"),
+        full_case_mapping_table("Lowercase_Mapping", "LOWERCASE_MAPPINGS"),
+        full_case_mapping_table("Titlecase_Mapping", "TITLECASE_MAPPINGS"),
+        case_mapping_table(case_foldings("C", "F"), "CASE_FOLDINGS", 1),
+        case_mapping_table(case_foldings("C", "S"), "SIMPLE_CASE_FOLDINGS", 0),
+        binary_table("Cased", "DerivedCoreProperties.txt", "CASED_RANGES"),
+        binary_table("Case_Ignorable", "DerivedCoreProperties.txt", "CASE_IGNORABLE_RANGES"),
+        binary_table("Soft_Dotted", "PropList.txt", "SOFT_DOTTED_RANGES"),
+        binary_table("Changes_When_Lowercased", "DerivedCoreProperties.txt", "CHANGES_WHEN_LOWERCASED_RANGES"),
+        binary_table("Changes_When_Uppercased", "DerivedCoreProperties.txt", "CHANGES_WHEN_UPPERCASED_RANGES"),
+        binary_table("Changes_When_Titlecased", "DerivedCoreProperties.txt", "CHANGES_WHEN_TITLECASED_RANGES"),
+        binary_table("Changes_When_Casefolded", "DerivedCoreProperties.txt", "CHANGES_WHEN_CASEFOLDED_RANGES"),
+        binary_table("Changes_When_Casemapped", "DerivedCoreProperties.txt", "CHANGES_WHEN_CASEMAPPED_RANGES"),
+        binary_table("Changes_When_NFKC_Casefolded", "DerivedCoreProperties.txt", "CHANGES_WHEN_NFKC_CASEFOLDED_RANGES"),
+    ],
+);